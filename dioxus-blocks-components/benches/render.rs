@@ -0,0 +1,94 @@
+//! 渲染性能基准测试
+//!
+//! 测量典型组件树从构建组件（`to_element`）到 VirtualDom 首次渲染（rebuild）的耗时，
+//! 用于验证减少 Rc 克隆、字符串拼接等性能优化的效果。
+//!
+//! ```sh
+//! cargo bench -p dioxus-blocks-components --bench render
+//! ```
+
+use std::hint::black_box;
+
+use criterion::{BenchmarkId, Criterion, criterion_group, criterion_main};
+use dioxus::prelude::*;
+use dioxus_blocks_components::{InputNumber, InputNumberValue, Text, ToElement, View};
+
+/// 渲染根组件并完成首次渲染
+fn rebuild(app: fn(usize) -> Element, size: usize) {
+    let mut dom = VirtualDom::new_with_props(app, size);
+    dom.rebuild_in_place();
+    black_box(&dom);
+}
+
+/// 表格：`rows` 行，每行 5 个单元格
+fn table(rows: usize) -> Element {
+    let body = (0..rows).fold(View::new().class("bench-table"), |table, row| {
+        let cells = (0..5).fold(View::new().class("bench-row"), |cells, col| {
+            cells.children(
+                View::new()
+                    .class("bench-cell")
+                    .style(|s| s.padding("8px 12px").border_bottom("1px solid #eee"))
+                    .children(Text::span(format!("{row}-{col}"))),
+            )
+        });
+        table.children(cells)
+    });
+    body.to_element()
+}
+
+/// 深层嵌套的 View
+fn nested_views(depth: usize) -> Element {
+    let leaf = View::new().children(Text::p("leaf"));
+    (0..depth)
+        .fold(leaf, |child, level| {
+            View::new()
+                .class(format!("level-{level}"))
+                .style(|s| s.padding("1px"))
+                .children(child)
+        })
+        .to_element()
+}
+
+/// 大量数字输入框
+fn input_numbers(count: usize) -> Element {
+    let value = use_signal(|| InputNumberValue::Int(0));
+    (0..count)
+        .fold(View::new(), |view, i| {
+            view.children(
+                InputNumber::new()
+                    .value(value)
+                    .min_int(0)
+                    .max_int(i as i64 + 100)
+                    .step_int(1),
+            )
+        })
+        .to_element()
+}
+
+fn bench_render(c: &mut Criterion) {
+    let mut group = c.benchmark_group("render");
+    group.sample_size(20);
+
+    for rows in [100, 1000] {
+        group.bench_with_input(BenchmarkId::new("table_rows", rows), &rows, |b, &rows| {
+            b.iter(|| rebuild(table, rows))
+        });
+    }
+
+    for depth in [50, 200] {
+        group.bench_with_input(
+            BenchmarkId::new("nested_views", depth),
+            &depth,
+            |b, &depth| b.iter(|| rebuild(nested_views, depth)),
+        );
+    }
+
+    group.bench_with_input(BenchmarkId::new("input_numbers", 500), &500, |b, &count| {
+        b.iter(|| rebuild(input_numbers, count))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, bench_render);
+criterion_main!(benches);
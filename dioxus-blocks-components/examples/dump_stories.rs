@@ -0,0 +1,40 @@
+//! 把全部组件用例渲染为 HTML 文件，供视觉对比工具截图和比较
+//!
+//! 每个用例输出为 `<输出目录>/<组件>/<用例>.html`，如 `button/disabled.html`。
+//! 可选的第二个参数为编译后的组件样式表地址，会以 `<link>` 标签写入每个文件。
+//!
+//! ```sh
+//! cargo run -p dioxus-blocks-components --example dump_stories -- target/stories
+//! cargo run -p dioxus-blocks-components --example dump_stories -- target/stories ../dist/index.css
+//! ```
+
+use std::path::PathBuf;
+
+use dioxus_blocks_components::stories;
+
+fn main() -> std::io::Result<()> {
+    let mut args = std::env::args().skip(1);
+    let out_dir = PathBuf::from(args.next().unwrap_or_else(|| "target/stories".to_string()));
+    let stylesheet = args
+        .next()
+        .map(|href| format!("<link rel=\"stylesheet\" href=\"{href}\">"))
+        .unwrap_or_default();
+
+    let stories = stories::all();
+    for story in &stories {
+        let file = out_dir.join(format!("{}.html", story.id()));
+        if let Some(dir) = file.parent() {
+            std::fs::create_dir_all(dir)?;
+        }
+        let html = format!(
+            "<!DOCTYPE html>\n<html>\n<head>\n<meta charset=\"utf-8\">\n<title>{} / {}</title>\n{stylesheet}\n</head>\n<body>\n{}\n</body>\n</html>\n",
+            story.component(),
+            story.name(),
+            story.render_html(),
+        );
+        std::fs::write(&file, html)?;
+    }
+
+    println!("已渲染 {} 个用例到 {}", stories.len(), out_dir.display());
+    Ok(())
+}
@@ -0,0 +1,267 @@
+//! # 异步提交
+//!
+//! [`use_server_action`] 把一个返回 `Result` 的异步函数（通常是 dioxus-fullstack 的服务端函数）包装为
+//! 可在事件中调用的提交动作，并记录执行中、错误和返回值等状态，可直接用于按钮的加载状态和错误提示。
+//!
+//! 动作只依赖 `Future`，不依赖具体平台：在 web、桌面端和服务端渲染中行为一致。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus::core::Mutations;
+//! use dioxus_blocks_components::{Button, ToElement, View, use_server_action};
+//!
+//! // 实际项目中通常是 `#[server]` 标注的服务端函数
+//! async fn save(name: String) -> Result<usize, String> {
+//!     if name.is_empty() {
+//!         return Err("名称不能为空".to_string());
+//!     }
+//!     Ok(name.len())
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let action = use_server_action(save);
+//!     let mut view = View::new();
+//!     if let Some(alert) = action.alert() {
+//!         view = view.children(alert);
+//!     }
+//!     view.children(
+//!         action.button(Button::new().text("保存").onclick(move |_| action.dispatch("张三".to_string()))),
+//!     )
+//!     .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+
+use dioxus::prelude::*;
+
+#[cfg(feature = "alert")]
+use crate::Alert;
+#[cfg(feature = "button")]
+use crate::Button;
+#[cfg(feature = "route_progress")]
+use crate::ProgressTracker;
+
+/// 动作返回的 Future，错误已转换为字符串
+type ActionFuture<O> = Pin<Box<dyn Future<Output = Result<O, String>>>>;
+
+/// 异步提交动作
+///
+/// 由 [`use_server_action`] 创建，内部状态都是信号，可以自由复制到事件处理器中。
+pub struct ServerAction<I: 'static, O: 'static> {
+    /// 动作函数
+    action: Callback<I, ActionFuture<O>>,
+    /// 是否正在执行
+    pending: Signal<bool>,
+    /// 最近一次执行的错误信息
+    error: Signal<Option<String>>,
+    /// 最近一次成功执行的返回值
+    value: Signal<Option<O>>,
+    /// 执行期间登记加载的进度条
+    #[cfg(feature = "route_progress")]
+    progress: Option<ProgressTracker>,
+}
+
+impl<I: 'static, O: 'static> Clone for ServerAction<I, O> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<I: 'static, O: 'static> Copy for ServerAction<I, O> {}
+
+impl<I: 'static, O: 'static> PartialEq for ServerAction<I, O> {
+    fn eq(&self, other: &Self) -> bool {
+        self.action == other.action
+            && self.pending == other.pending
+            && self.error == other.error
+            && self.value == other.value
+            && self.progress_eq(other)
+    }
+}
+
+impl<I: 'static, O: 'static> ServerAction<I, O> {
+    #[cfg(feature = "route_progress")]
+    fn progress_eq(&self, other: &Self) -> bool {
+        self.progress == other.progress
+    }
+
+    #[cfg(not(feature = "route_progress"))]
+    fn progress_eq(&self, _other: &Self) -> bool {
+        true
+    }
+}
+
+impl<I: 'static, O: 'static> std::fmt::Debug for ServerAction<I, O> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ServerAction")
+            .field("pending", &*self.pending.peek())
+            .field("error", &*self.error.peek())
+            .finish_non_exhaustive()
+    }
+}
+
+/// 创建异步提交动作
+///
+/// `action` 的错误类型只需实现 `Display`，服务端函数的 `ServerFnError` 可以直接使用。
+pub fn use_server_action<I, O, E, F, Fut>(mut action: F) -> ServerAction<I, O>
+where
+    I: 'static,
+    O: 'static,
+    E: Display + 'static,
+    F: FnMut(I) -> Fut + 'static,
+    Fut: Future<Output = Result<O, E>> + 'static,
+{
+    let action = use_callback(move |input: I| {
+        let future = action(input);
+        Box::pin(async move { future.await.map_err(|e| e.to_string()) }) as ActionFuture<O>
+    });
+    ServerAction {
+        action,
+        pending: use_signal(|| false),
+        error: use_signal(|| None),
+        value: use_signal(|| None),
+        #[cfg(feature = "route_progress")]
+        progress: None,
+    }
+}
+
+impl<I: 'static, O: 'static> ServerAction<I, O> {
+    /// 执行动作
+    ///
+    /// 执行期间再次调用会被忽略，避免重复提交。开始执行时会清空上一次的错误信息。
+    pub fn dispatch(&self, input: I) {
+        if *self.pending.peek() {
+            return;
+        }
+        let mut pending = self.pending;
+        let mut error = self.error;
+        let mut value = self.value;
+        pending.set(true);
+        error.set(None);
+        let future = self.action.call(input);
+        #[cfg(feature = "route_progress")]
+        let guard = self.progress.map(|progress| progress.start());
+        spawn(async move {
+            #[cfg(feature = "route_progress")]
+            let _guard = guard;
+            match future.await {
+                Ok(output) => value.set(Some(output)),
+                Err(message) => error.set(Some(message)),
+            }
+            pending.set(false);
+        });
+    }
+
+    /// 是否正在执行
+    pub fn pending(&self) -> bool {
+        (self.pending)()
+    }
+
+    /// 最近一次执行的错误信息
+    pub fn error(&self) -> Option<String> {
+        self.error.read().clone()
+    }
+
+    /// 清空错误信息
+    pub fn clear_error(&self) {
+        let mut error = self.error;
+        error.set(None);
+    }
+
+    /// 最近一次成功执行的返回值
+    pub fn value(&self) -> Option<O>
+    where
+        O: Clone,
+    {
+        self.value.read().clone()
+    }
+
+    /// 执行期间在上下文中的 [`RouteProgress`](crate::RouteProgress) 上显示进度条
+    ///
+    /// 需要在渲染期间调用，不在 RouteProgress 中使用时没有效果。
+    #[cfg(feature = "route_progress")]
+    pub fn with_progress(mut self) -> Self {
+        self.progress = ProgressTracker::current();
+        self
+    }
+
+    /// 为按钮绑定执行状态，执行期间显示加载状态并禁用按钮
+    #[cfg(feature = "button")]
+    pub fn button(&self, button: Button) -> Button {
+        let pending = self.pending();
+        button.loading(pending).disabled(pending)
+    }
+
+    /// 执行失败时返回可关闭的错误提示，关闭后清空错误信息
+    #[cfg(feature = "alert")]
+    pub fn alert(&self) -> Option<Alert> {
+        let action = *self;
+        self.error().map(|message| {
+            Alert::new(message)
+                .as_error()
+                .closable(true)
+                .onclose(move |_| action.clear_error())
+        })
+    }
+}
+
+#[cfg(all(test, feature = "button", feature = "alert"))]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{ToElement, View, classnames};
+
+    #[test]
+    fn test_action_error_and_value() {
+        async fn save(name: String) -> Result<usize, String> {
+            if name.is_empty() {
+                Err("名称不能为空".to_string())
+            } else {
+                Ok(name.len())
+            }
+        }
+
+        fn app() -> Element {
+            let action = use_server_action(save);
+            let mut view = View::new();
+            if let Some(alert) = action.alert() {
+                view = view.children(alert);
+            }
+            if let Some(value) = action.value() {
+                view = view.children(crate::Text::span(format!("已保存 {value}")));
+            }
+            view.children(
+                action.button(
+                    Button::new()
+                        .class("empty")
+                        .onclick(move |_| action.dispatch(String::new())),
+                ),
+            )
+            .children(
+                Button::new()
+                    .class("valid")
+                    .onclick(move |_| action.dispatch("abc".to_string())),
+            )
+            .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::ALERT).is_none());
+
+        let empty = harness.find_by_class("empty").unwrap();
+        harness.click(&empty);
+        assert!(harness.find_by_text("名称不能为空").is_some());
+
+        let valid = harness.find_by_class("valid").unwrap();
+        harness.click(&valid);
+        assert!(harness.find_by_class(classnames::ALERT).is_none());
+        assert!(harness.find_by_text("已保存 3").is_some());
+    }
+}
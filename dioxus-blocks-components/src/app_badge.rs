@@ -0,0 +1,97 @@
+//! # 应用角标
+//!
+//! 在浏览器标签页图标上显示未读数量。[`use_app_badge`] 在 canvas 上把数量绘制为图标右上角的红色圆点，
+//! 替换页面的 favicon；浏览器支持 Badging API（如已安装的 PWA）时同时设置应用图标角标。
+//! 数量为 0 时恢复原来的图标并清除角标。
+//!
+//! 启用 `server` 特性进行服务端渲染时不做任何处理。桌面端的 webview 没有标签页图标，
+//! 只有 webview 支持 Badging API 时才会显示角标。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Notification, Text, ToElement, use_app_badge, use_notification};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct Inbox;
+//!
+//! impl ToElement for Inbox {
+//!     fn to_element(&self) -> Element {
+//!         let notifier = use_notification();
+//!         use_app_badge(notifier.unread());
+//!         Text::p(format!("{} 条未读通知", notifier.unread())).to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| Notification::new().children(Inbox).to_element());
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use dioxus::prelude::*;
+
+/// 角标中显示的文本，超过 99 时显示为 `99+`
+fn badge_text(count: usize) -> String {
+    if count > 99 {
+        "99+".to_string()
+    } else {
+        count.to_string()
+    }
+}
+
+/// 更新标签页图标和应用角标
+fn update_badge(count: usize) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    let text = badge_text(count);
+    document::eval(&format!(
+        "const count = {count}; const text = {text:?}; \
+         if (navigator.setAppBadge) {{ \
+           (count > 0 ? navigator.setAppBadge(count) : navigator.clearAppBadge()).catch(() => {{}}); \
+         }} \
+         let link = document.querySelector(\"link[rel~='icon']\"); \
+         if (!link) {{ link = document.createElement('link'); link.rel = 'icon'; document.head.appendChild(link); }} \
+         if (window.__tAppBadgeIcon === undefined) window.__tAppBadgeIcon = link.getAttribute('href') || ''; \
+         const original = window.__tAppBadgeIcon; \
+         const seq = (window.__tAppBadgeSeq || 0) + 1; window.__tAppBadgeSeq = seq; \
+         if (count <= 0) {{ link.href = original; return; }} \
+         const draw = (icon) => {{ \
+           if (window.__tAppBadgeSeq !== seq) return; \
+           const canvas = document.createElement('canvas'); canvas.width = 32; canvas.height = 32; \
+           const ctx = canvas.getContext('2d'); \
+           if (icon) ctx.drawImage(icon, 0, 0, 32, 32); \
+           const radius = text.length > 2 ? 11 : 9; \
+           ctx.fillStyle = '#f56c6c'; ctx.beginPath(); \
+           ctx.arc(32 - radius, radius, radius, 0, Math.PI * 2); ctx.fill(); \
+           ctx.fillStyle = '#fff'; ctx.font = `bold ${{text.length > 2 ? 10 : 13}}px sans-serif`; \
+           ctx.textAlign = 'center'; ctx.textBaseline = 'middle'; \
+           ctx.fillText(text, 32 - radius, radius + 1); \
+           link.href = canvas.toDataURL('image/png'); \
+         }}; \
+         if (!original) {{ draw(null); return; }} \
+         const image = new Image(); image.crossOrigin = 'anonymous'; \
+         image.onload = () => draw(image); image.onerror = () => draw(null); \
+         image.src = original;"
+    ));
+}
+
+/// 在标签页图标上显示数量角标，数量变化时更新，为 0 时恢复原来的图标
+pub fn use_app_badge(count: usize) {
+    use_effect(use_reactive!(|count| update_badge(count)));
+    use_drop(|| update_badge(0));
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_badge_text() {
+        assert_eq!(badge_text(1), "1");
+        assert_eq!(badge_text(99), "99");
+        assert_eq!(badge_text(100), "99+");
+    }
+}
@@ -0,0 +1,22 @@
+//! # 浏览器脚本
+//!
+//! 延时、预下载资源等功能依赖在浏览器中执行 JavaScript。启用 `server` 特性进行服务端渲染时，
+//! 这些函数直接返回，不会在服务端创建无法完成的脚本请求。
+
+use dioxus::prelude::*;
+
+/// 在浏览器中执行脚本，服务端或执行失败时返回 `false`
+pub async fn run_script(js: &str) -> bool {
+    if cfg!(feature = "server") {
+        return false;
+    }
+    document::eval(js).await.is_ok()
+}
+
+/// 等待指定的毫秒数，服务端或非浏览器环境立即返回 `false`
+pub async fn sleep(ms: u32) -> bool {
+    run_script(&format!(
+        "await new Promise((r) => setTimeout(r, {ms})); return true;"
+    ))
+    .await
+}
@@ -0,0 +1,618 @@
+//! 组件类名常量
+//!
+//! 组件渲染时使用的 `t-*` 类名。覆盖组件样式或在测试中断言渲染结果时，
+//! 应引用这里的常量，而不是直接书写字符串。
+//!
+//! 类名遵循 BEM 规范：块为 `t-input`，元素为 `t-input__inner`，修饰符为 `t-input--disabled`，
+//! 可以使用 [`bem`] 拼接。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus_blocks_components::classnames::{self, bem};
+//!
+//! assert_eq!(classnames::INPUT_INNER, "t-input__inner");
+//! assert_eq!(
+//!     bem("input", "inner", ["disabled"]),
+//!     "t-input__inner t-input__inner--disabled"
+//! );
+//! assert_eq!(bem("button", "", ["primary"]), "t-button t-button--primary");
+//! ```
+
+/// 类名前缀
+pub const PREFIX: &str = "t-";
+
+/// 拼接 BEM 类名
+///
+/// `element` 为空时返回块类名，随后追加每个修饰符对应的类名，空修饰符会被忽略。
+pub fn bem<'a>(block: &str, element: &str, modifiers: impl IntoIterator<Item = &'a str>) -> String {
+    let mut base = format!("{PREFIX}{block}");
+    if !element.is_empty() {
+        base.push_str("__");
+        base.push_str(element);
+    }
+
+    let mut class_names = vec![base.clone()];
+    class_names.extend(
+        modifiers
+            .into_iter()
+            .filter(|modifier| !modifier.is_empty())
+            .map(|modifier| format!("{base}--{modifier}")),
+    );
+    class_names.join(" ")
+}
+
+// 打印
+pub const PRINTING: &str = "t-printing";
+pub const PRINT_TARGET: &str = "t-print-target";
+
+// 按钮
+pub const BUTTON: &str = "t-button";
+pub const BUTTON_DISABLED: &str = "t-button--disabled";
+pub const BUTTON_LOADING: &str = "t-button--loading";
+pub const BUTTON_CUSTOM: &str = "t-button--custom";
+
+// 卡片
+pub const CARD: &str = "t-card";
+pub const CARD_HEADER: &str = "t-card-header";
+pub const CARD_BODY: &str = "t-card-body";
+pub const CARD_FOOTER: &str = "t-card-footer";
+pub const CARD_HEADER_DIVIDER: &str = "t-card-header-divider";
+pub const CARD_PRINT_FRIENDLY: &str = "t-card-print-friendly";
+
+// 链接
+pub const LINK: &str = "t-link";
+pub const LINK_DISABLED: &str = "t-link--disabled";
+
+// 图片
+pub const IMAGE: &str = "t-image";
+
+// 网格
+pub const GRID: &str = "t-grid";
+pub const GRID_ITEM: &str = "t-grid-item";
+
+// 布局
+pub const ROW: &str = "t-row";
+pub const COL: &str = "t-col";
+
+// 文本
+pub const TEXT: &str = "t-text";
+pub const TEXT_SELECTABLE: &str = "t-text--selectable";
+pub const TEXT_UNSELECTABLE: &str = "t-text--unselectable";
+pub const TEXT_COPYABLE: &str = "t-text--copyable";
+pub const TEXT_COPY: &str = "t-text__copy";
+pub const TEXT_COPY_DONE: &str = "t-text__copy--done";
+
+// 溢出提示
+pub const OVERFLOW_TOOLTIP: &str = "t-overflow-tooltip";
+pub const OVERFLOW_TOOLTIP_CONTENT: &str = "t-overflow-tooltip__content";
+pub const OVERFLOW_TOOLTIP_CONTENT_CLAMP: &str = "t-overflow-tooltip__content--clamp";
+
+// 输入框
+pub const INPUT: &str = "t-input";
+pub const INPUT_PREPEND: &str = "t-input__prepend";
+pub const INPUT_WRAPPER: &str = "t-input__wrapper";
+pub const INPUT_PREFIX: &str = "t-input__prefix";
+pub const INPUT_ICON: &str = "t-input__icon";
+pub const INPUT_INNER: &str = "t-input__inner";
+pub const INPUT_SUFFIX: &str = "t-input__suffix";
+pub const INPUT_CLEAR: &str = "t-input__clear";
+pub const INPUT_COUNT: &str = "t-input__count";
+pub const INPUT_COUNT_SEPARATOR: &str = "t-input__count-separator";
+pub const INPUT_APPEND: &str = "t-input__append";
+pub const INPUT_DISABLED: &str = "t-input--disabled";
+pub const INPUT_CLEARABLE: &str = "t-input--clearable";
+pub const INPUT_HAS_PREFIX: &str = "t-input--prefix";
+pub const INPUT_HAS_SUFFIX: &str = "t-input--suffix";
+
+// 银行卡输入框
+pub const CREDIT_CARD_INPUT: &str = "t-credit-card-input";
+pub const CREDIT_CARD_INPUT_FIELD: &str = "t-credit-card-input__field";
+pub const CREDIT_CARD_INPUT_NUMBER: &str = "t-credit-card-input__number";
+pub const CREDIT_CARD_INPUT_ROW: &str = "t-credit-card-input__row";
+pub const CREDIT_CARD_INPUT_EXPIRY: &str = "t-credit-card-input__expiry";
+pub const CREDIT_CARD_INPUT_CVC: &str = "t-credit-card-input__cvc";
+pub const CREDIT_CARD_INPUT_CONTROL: &str = "t-credit-card-input__control";
+pub const CREDIT_CARD_INPUT_ERROR: &str = "t-credit-card-input__error";
+
+// 手机号码输入
+pub const PHONE_INPUT: &str = "t-phone-input";
+pub const PHONE_INPUT_ROW: &str = "t-phone-input__row";
+pub const PHONE_INPUT_COUNTRY: &str = "t-phone-input__country";
+pub const PHONE_INPUT_CONTROL: &str = "t-phone-input__control";
+pub const PHONE_INPUT_ERROR: &str = "t-phone-input__error";
+
+// 条码输入
+pub const BARCODE_INPUT: &str = "t-barcode-input";
+pub const BARCODE_INPUT_ROW: &str = "t-barcode-input__row";
+pub const BARCODE_INPUT_CONTROL: &str = "t-barcode-input__control";
+pub const BARCODE_INPUT_BUTTON: &str = "t-barcode-input__button";
+pub const BARCODE_INPUT_CAMERA: &str = "t-barcode-input__camera";
+pub const BARCODE_INPUT_VIDEO: &str = "t-barcode-input__video";
+pub const BARCODE_INPUT_FRAME: &str = "t-barcode-input__frame";
+pub const BARCODE_INPUT_CANCEL: &str = "t-barcode-input__cancel";
+pub const BARCODE_INPUT_ERROR: &str = "t-barcode-input__error";
+
+// 地址选择器
+pub const ADDRESS_PICKER: &str = "t-address-picker";
+pub const ADDRESS_PICKER_LEVEL: &str = "t-address-picker__level";
+
+// 搜索框
+pub const SEARCH_INPUT: &str = "t-search-input";
+pub const SEARCH_INPUT_BAR: &str = "t-search-input__bar";
+pub const SEARCH_INPUT_SCOPE: &str = "t-search-input__scope";
+pub const SEARCH_INPUT_CONTROL: &str = "t-search-input__control";
+pub const SEARCH_INPUT_BUTTON: &str = "t-search-input__button";
+pub const SEARCH_INPUT_DROPDOWN: &str = "t-search-input__dropdown";
+pub const SEARCH_INPUT_DROPDOWN_HEADER: &str = "t-search-input__dropdown-header";
+pub const SEARCH_INPUT_CLEAR_HISTORY: &str = "t-search-input__clear-history";
+pub const SEARCH_INPUT_RECENT_ITEM: &str = "t-search-input__recent-item";
+
+// 工具栏
+pub const TOOLBAR: &str = "t-toolbar";
+pub const TOOLBAR_LEFT: &str = "t-toolbar__left";
+pub const TOOLBAR_CENTER: &str = "t-toolbar__center";
+pub const TOOLBAR_RIGHT: &str = "t-toolbar__right";
+pub const TOOLBAR_MORE: &str = "t-toolbar__more";
+pub const TOOLBAR_MORE_TRIGGER: &str = "t-toolbar__more-trigger";
+pub const TOOLBAR_MENU: &str = "t-toolbar__menu";
+pub const TOOLBAR_MENU_ITEM: &str = "t-toolbar__menu-item";
+
+// 瀑布流布局
+pub const MASONRY: &str = "t-masonry";
+pub const MASONRY_COLUMNS: &str = "t-masonry--columns";
+pub const MASONRY_MEASURE: &str = "t-masonry--measure";
+pub const MASONRY_COLUMN: &str = "t-masonry__column";
+pub const MASONRY_ITEM: &str = "t-masonry__item";
+
+// 筛选栏
+pub const FILTER_BAR: &str = "t-filter-bar";
+pub const FILTER_BAR_FIELDS: &str = "t-filter-bar__fields";
+pub const FILTER_BAR_ITEM: &str = "t-filter-bar__item";
+pub const FILTER_BAR_LABEL: &str = "t-filter-bar__label";
+pub const FILTER_BAR_CONTROL: &str = "t-filter-bar__control";
+pub const FILTER_BAR_SELECT: &str = "t-filter-bar__select";
+pub const FILTER_BAR_RANGE: &str = "t-filter-bar__range";
+pub const FILTER_BAR_RANGE_INPUT: &str = "t-filter-bar__range-input";
+pub const FILTER_BAR_RANGE_SEPARATOR: &str = "t-filter-bar__range-separator";
+pub const FILTER_BAR_ACTIONS: &str = "t-filter-bar__actions";
+pub const FILTER_BAR_SEARCH: &str = "t-filter-bar__search";
+pub const FILTER_BAR_RESET: &str = "t-filter-bar__reset";
+pub const FILTER_BAR_TOGGLE: &str = "t-filter-bar__toggle";
+
+// 已选筛选条件
+pub const ACTIVE_FILTERS: &str = "t-active-filters";
+pub const ACTIVE_FILTERS_TAG: &str = "t-active-filters__tag";
+pub const ACTIVE_FILTERS_LABEL: &str = "t-active-filters__label";
+pub const ACTIVE_FILTERS_VALUE: &str = "t-active-filters__value";
+pub const ACTIVE_FILTERS_REMOVE: &str = "t-active-filters__remove";
+pub const ACTIVE_FILTERS_CLEAR: &str = "t-active-filters__clear";
+
+// 数字输入框
+pub const INPUT_NUMBER: &str = "t-input-number";
+pub const INPUT_NUMBER_WRAPPER: &str = "t-input-number__wrapper";
+pub const INPUT_NUMBER_INNER: &str = "t-input-number__inner";
+pub const INPUT_NUMBER_CONTROLS: &str = "t-input-number__controls";
+pub const INPUT_NUMBER_DECREASE: &str = "t-input-number__decrease";
+pub const INPUT_NUMBER_INCREASE: &str = "t-input-number__increase";
+pub const INPUT_NUMBER_DISABLED: &str = "t-input-number--disabled";
+
+// 多行文本输入框
+pub const TEXTAREA: &str = "t-textarea";
+pub const TEXTAREA_COUNT: &str = "t-textarea__count";
+pub const TEXTAREA_COUNT_SEPARATOR: &str = "t-textarea__count-separator";
+pub const TEXTAREA_INNER: &str = "t-textarea__inner";
+pub const TEXTAREA_DISABLED: &str = "t-textarea--disabled";
+pub const TEXTAREA_LIMIT: &str = "t-textarea--limit";
+pub const TEXTAREA_AUTOSIZE: &str = "t-textarea--autosize";
+
+// 选择器
+pub const SELECT: &str = "t-select";
+pub const SELECT_WRAPPER: &str = "t-select__wrapper";
+pub const SELECT_SELECTION: &str = "t-select__selection";
+pub const SELECT_LABEL: &str = "t-select__label";
+pub const SELECT_PLACEHOLDER: &str = "t-select__placeholder";
+pub const SELECT_INPUT: &str = "t-select__input";
+pub const SELECT_TAG: &str = "t-select__tag";
+pub const SELECT_TAG_LABEL: &str = "t-select__tag-label";
+pub const SELECT_TAG_CLOSE: &str = "t-select__tag-close";
+pub const SELECT_TAG_COLLAPSE: &str = "t-select__tag--collapse";
+pub const SELECT_COLLAPSE_TOOLTIP: &str = "t-select__collapse-tooltip";
+pub const SELECT_CLEAR: &str = "t-select__clear";
+pub const SELECT_ARROW: &str = "t-select__arrow";
+pub const SELECT_DROPDOWN: &str = "t-select__dropdown";
+pub const SELECT_GROUP: &str = "t-select__group";
+pub const SELECT_GROUP_TITLE: &str = "t-select__group-title";
+pub const SELECT_OPTION: &str = "t-select__option";
+pub const SELECT_EMPTY: &str = "t-select__empty";
+
+// 时间选择器
+pub const TIME_PICKER: &str = "t-time-picker";
+pub const TIME_PICKER_WRAPPER: &str = "t-time-picker__wrapper";
+pub const TIME_PICKER_ICON: &str = "t-time-picker__icon";
+pub const TIME_PICKER_INPUT: &str = "t-time-picker__input";
+pub const TIME_PICKER_CLEAR: &str = "t-time-picker__clear";
+pub const TIME_PICKER_PANEL: &str = "t-time-picker__panel";
+pub const TIME_PICKER_COLUMNS: &str = "t-time-picker__columns";
+pub const TIME_PICKER_COLUMN: &str = "t-time-picker__column";
+pub const TIME_PICKER_CELL: &str = "t-time-picker__cell";
+pub const TIME_PICKER_CELL_SELECTED: &str = "is-selected";
+pub const TIME_PICKER_FOOTER: &str = "t-time-picker__footer";
+pub const TIME_PICKER_CONFIRM: &str = "t-time-picker__confirm";
+
+// 日期时间选择器
+pub const DATE_TIME_PICKER: &str = "t-date-time-picker";
+pub const DATE_TIME_PICKER_DATE: &str = "t-date-time-picker__date";
+pub const DATE_TIME_PICKER_TIME: &str = "t-date-time-picker__time";
+pub const DATE_PICKER_TIMEZONE: &str = "t-date-picker__timezone";
+pub const DATE_RANGE_PICKER: &str = "t-date-range-picker";
+pub const DATE_RANGE_PICKER_PRESETS: &str = "t-date-range-picker__presets";
+pub const DATE_RANGE_PICKER_PRESET: &str = "t-date-range-picker__preset";
+pub const DATE_RANGE_PICKER_RANGE: &str = "t-date-range-picker__range";
+pub const DATE_RANGE_PICKER_INPUT: &str = "t-date-range-picker__input";
+pub const DATE_RANGE_PICKER_SEPARATOR: &str = "t-date-range-picker__separator";
+
+// 单选框
+pub const RADIO: &str = "t-radio";
+pub const RADIO_GROUP: &str = "t-radio-group";
+pub const RADIO_INPUT: &str = "t-radio__input";
+pub const RADIO_INNER: &str = "t-radio__inner";
+pub const RADIO_LABEL: &str = "t-radio__label";
+pub const RADIO_BODY: &str = "t-radio__body";
+pub const RADIO_DESCRIPTION: &str = "t-radio__description";
+pub const RADIO_CARD: &str = "t-radio--card";
+pub const RADIO_BUTTON: &str = "t-radio--button";
+pub const RADIO_BUTTON_BORDER: &str = "t-radio--button__border";
+pub const RADIO_GROUP_DISABLED: &str = "t-radio-group--disabled";
+pub const RADIO_GROUP_VERTICAL: &str = "t-radio-group--vertical";
+
+// 多选框
+pub const CHECKBOX: &str = "t-checkbox";
+pub const CHECKBOX_GROUP: &str = "t-checkbox-group";
+pub const CHECKBOX_INPUT: &str = "t-checkbox__input";
+pub const CHECKBOX_INNER: &str = "t-checkbox__inner";
+pub const CHECKBOX_BUTTON_LABEL: &str = "t-checkbox__button";
+pub const CHECKBOX_LABEL: &str = "t-checkbox__label";
+pub const CHECKBOX_BUTTON: &str = "t-checkbox--button";
+pub const CHECKBOX_GROUP_DISABLED: &str = "t-checkbox-group--disabled";
+pub const CHECKBOX_GROUP_ALL: &str = "t-checkbox-group__all";
+
+// 日历热力图
+pub const CALENDAR_HEATMAP: &str = "t-calendar-heatmap";
+pub const CALENDAR_HEATMAP_BODY: &str = "t-calendar-heatmap__body";
+pub const CALENDAR_HEATMAP_WEEKDAYS: &str = "t-calendar-heatmap__weekdays";
+pub const CALENDAR_HEATMAP_MONTH_PLACEHOLDER: &str = "t-calendar-heatmap__month-placeholder";
+pub const CALENDAR_HEATMAP_WEEKDAY: &str = "t-calendar-heatmap__weekday";
+pub const CALENDAR_HEATMAP_GRID: &str = "t-calendar-heatmap__grid";
+pub const CALENDAR_HEATMAP_WEEK: &str = "t-calendar-heatmap__week";
+pub const CALENDAR_HEATMAP_MONTH: &str = "t-calendar-heatmap__month";
+pub const CALENDAR_HEATMAP_CELL: &str = "t-calendar-heatmap__cell";
+pub const CALENDAR_HEATMAP_LEGEND: &str = "t-calendar-heatmap__legend";
+pub const CALENDAR_HEATMAP_LEGEND_TEXT: &str = "t-calendar-heatmap__legend-text";
+
+// 组织结构图
+pub const ORG_CHART: &str = "t-org-chart";
+pub const ORG_CHART_LABEL: &str = "t-org-chart__label";
+pub const ORG_CHART_DESCRIPTION: &str = "t-org-chart__description";
+pub const ORG_CHART_NODE: &str = "t-org-chart__node";
+pub const ORG_CHART_ITEM: &str = "t-org-chart__item";
+pub const ORG_CHART_TOGGLE: &str = "t-org-chart__toggle";
+pub const ORG_CHART_CHILDREN: &str = "t-org-chart__children";
+pub const ORG_CHART_TREE: &str = "t-org-chart__tree";
+pub const ORG_CHART_NODE_DEFAULT: &str = "t-org-chart__node--default";
+
+// 分步表单
+pub const FORM_WIZARD: &str = "t-form-wizard";
+pub const FORM_WIZARD_STEP: &str = "t-form-wizard__step";
+pub const FORM_WIZARD_STEP_HEAD: &str = "t-form-wizard__step-head";
+pub const FORM_WIZARD_STEP_ICON: &str = "t-form-wizard__step-icon";
+pub const FORM_WIZARD_STEP_LINE: &str = "t-form-wizard__step-line";
+pub const FORM_WIZARD_STEP_MAIN: &str = "t-form-wizard__step-main";
+pub const FORM_WIZARD_STEP_TITLE: &str = "t-form-wizard__step-title";
+pub const FORM_WIZARD_STEP_DESCRIPTION: &str = "t-form-wizard__step-description";
+pub const FORM_WIZARD_PANEL: &str = "t-form-wizard__panel";
+pub const FORM_WIZARD_PREV: &str = "t-form-wizard__prev";
+pub const FORM_WIZARD_NEXT: &str = "t-form-wizard__next";
+pub const FORM_WIZARD_STEPS: &str = "t-form-wizard__steps";
+pub const FORM_WIZARD_BODY: &str = "t-form-wizard__body";
+pub const FORM_WIZARD_ERROR: &str = "t-form-wizard__error";
+pub const FORM_WIZARD_FOOTER: &str = "t-form-wizard__footer";
+
+// 提示
+pub const ALERT: &str = "t-alert";
+pub const ALERT_ICON: &str = "t-alert__icon";
+pub const ALERT_CONTENT: &str = "t-alert__content";
+pub const ALERT_TITLE: &str = "t-alert__title";
+pub const ALERT_DESCRIPTION: &str = "t-alert__description";
+pub const ALERT_CLOSE: &str = "t-alert__close";
+
+// 表单错误汇总
+pub const FORM_ERROR_SUMMARY: &str = "t-form-error-summary";
+pub const FORM_ERROR_SUMMARY_LIST: &str = "t-form-error-summary__list";
+pub const FORM_ERROR_SUMMARY_ITEM: &str = "t-form-error-summary__item";
+pub const FORM_ERROR_SUMMARY_LINK: &str = "t-form-error-summary__link";
+
+// 表单
+pub const FORM: &str = "t-form";
+pub const FORM_ITEM: &str = "t-form-item";
+pub const FORM_ITEM_LABEL: &str = "t-form-item__label";
+pub const FORM_ITEM_CONTENT: &str = "t-form-item__content";
+pub const FORM_ITEM_ERROR: &str = "t-form-item__error";
+
+// 配置表单
+pub const SCHEMA_FORM: &str = "t-schema-form";
+pub const SCHEMA_FORM_ACTIONS: &str = "t-schema-form__actions";
+
+// 上传
+pub const UPLOAD: &str = "t-upload";
+pub const UPLOAD_DRAGGER: &str = "t-upload__dragger";
+pub const UPLOAD_INPUT: &str = "t-upload__input";
+pub const UPLOAD_ICON: &str = "t-upload__icon";
+pub const UPLOAD_TEXT: &str = "t-upload__text";
+pub const UPLOAD_TIP: &str = "t-upload__tip";
+pub const UPLOAD_ERRORS: &str = "t-upload__errors";
+pub const UPLOAD_LIST: &str = "t-upload__list";
+pub const UPLOAD_ITEM: &str = "t-upload__item";
+pub const UPLOAD_THUMBNAIL: &str = "t-upload__thumbnail";
+pub const UPLOAD_NAME: &str = "t-upload__name";
+pub const UPLOAD_SIZE: &str = "t-upload__size";
+pub const UPLOAD_MESSAGE: &str = "t-upload__message";
+pub const UPLOAD_REMOVE: &str = "t-upload__remove";
+pub const UPLOAD_PROGRESS: &str = "t-upload__progress";
+pub const UPLOAD_PROGRESS_BAR: &str = "t-upload__progress-bar";
+
+// 结果
+pub const RESULT: &str = "t-result";
+pub const RESULT_ICON_DEFAULT: &str = "t-result__icon-default";
+pub const RESULT_ICON: &str = "t-result__icon";
+pub const RESULT_TITLE: &str = "t-result__title";
+pub const RESULT_SUBTITLE: &str = "t-result__subtitle";
+pub const RESULT_CONTENT: &str = "t-result__content";
+pub const RESULT_EXTRA: &str = "t-result__extra";
+
+// 路由进度条
+pub const ROUTE_PROGRESS: &str = "t-route-progress";
+pub const ROUTE_PROGRESS_BAR: &str = "t-route-progress__bar";
+pub const ROUTE_PROGRESS_LOADING: &str = "t-route-progress--loading";
+pub const ROUTE_PROGRESS_DONE: &str = "t-route-progress--done";
+
+// 网络连接状态
+pub const CONNECTION_STATUS: &str = "t-connection-status";
+pub const CONNECTION_STATUS_BANNER: &str = "t-connection-status__banner";
+pub const CONNECTION_STATUS_TOAST: &str = "t-connection-status__toast";
+
+// Cookie 同意横幅
+pub const CONSENT_BANNER: &str = "t-consent-banner";
+pub const CONSENT_BANNER_BODY: &str = "t-consent-banner__body";
+pub const CONSENT_BANNER_TITLE: &str = "t-consent-banner__title";
+pub const CONSENT_BANNER_DESC: &str = "t-consent-banner__desc";
+pub const CONSENT_BANNER_CATEGORIES: &str = "t-consent-banner__categories";
+pub const CONSENT_BANNER_CATEGORY: &str = "t-consent-banner__category";
+pub const CONSENT_BANNER_CATEGORY_DESC: &str = "t-consent-banner__category-desc";
+pub const CONSENT_BANNER_ACTIONS: &str = "t-consent-banner__actions";
+
+// 确认按钮
+pub const CONFIRM_BUTTON: &str = "t-confirm-button";
+pub const CONFIRM_BUTTON_TRIGGER: &str = "t-confirm-button__trigger";
+pub const CONFIRM_BUTTON_PROMPT: &str = "t-confirm-button__prompt";
+pub const CONFIRM_BUTTON_CANCEL: &str = "t-confirm-button__cancel";
+pub const CONFIRM_BUTTON_CONFIRM: &str = "t-confirm-button__confirm";
+
+// 通知
+pub const NOTIFICATION: &str = "t-notification";
+pub const NOTIFICATION_ITEM: &str = "t-notification__item";
+pub const NOTIFICATION_CONTENT: &str = "t-notification__content";
+pub const NOTIFICATION_TITLE: &str = "t-notification__title";
+pub const NOTIFICATION_MESSAGE: &str = "t-notification__message";
+pub const NOTIFICATION_CLOSE: &str = "t-notification__close";
+pub const NOTIFICATION_UNDO: &str = "t-notification__undo";
+
+// 消息提示，位置类名见 `MessagePlacement::as_class`
+pub const MESSAGE: &str = "t-message";
+pub const MESSAGE_ITEM: &str = "t-message__item";
+pub const MESSAGE_ICON: &str = "t-message__icon";
+pub const MESSAGE_CONTENT: &str = "t-message__content";
+pub const MESSAGE_CLOSE: &str = "t-message__close";
+
+// 空闲超时
+pub const IDLE_GUARD: &str = "t-idle-guard";
+pub const IDLE_GUARD_DESC: &str = "t-idle-guard__desc";
+pub const IDLE_GUARD_COUNTDOWN: &str = "t-idle-guard__countdown";
+pub const IDLE_GUARD_SECONDS: &str = "t-idle-guard__seconds";
+
+// 对话框
+pub const DIALOG: &str = "t-dialog";
+pub const DIALOG_PANEL: &str = "t-dialog__panel";
+pub const DIALOG_HEADER: &str = "t-dialog__header";
+pub const DIALOG_TITLE: &str = "t-dialog__title";
+pub const DIALOG_CLOSE: &str = "t-dialog__close";
+pub const DIALOG_BODY: &str = "t-dialog__body";
+pub const DIALOG_FOOTER: &str = "t-dialog__footer";
+pub const DIALOG_FOCUS_GUARD: &str = "t-dialog__focus-guard";
+
+// 骨架屏
+pub const SKELETON: &str = "t-skeleton";
+pub const SKELETON_ANIMATED: &str = "t-skeleton--animated";
+pub const SKELETON_PAGE: &str = "t-skeleton--page";
+pub const SKELETON_AVATAR: &str = "t-skeleton__avatar";
+pub const SKELETON_CONTENT: &str = "t-skeleton__content";
+pub const SKELETON_TITLE: &str = "t-skeleton__title";
+pub const SKELETON_ROW: &str = "t-skeleton__row";
+pub const SKELETON_HEADING: &str = "t-skeleton__heading";
+pub const SKELETON_CARD: &str = "t-skeleton__card";
+
+// 加载中，尺寸类名见 `SpinSize::as_class`
+pub const SPIN: &str = "t-spin";
+pub const SPIN_NESTED: &str = "t-spin--nested";
+pub const SPIN_DOT: &str = "t-spin__dot";
+pub const SPIN_TIP: &str = "t-spin__tip";
+pub const SPIN_CONTENT: &str = "t-spin__content";
+pub const SPIN_OVERLAY: &str = "t-spin__overlay";
+
+// 全局配置
+pub const CONFIG_PROVIDER: &str = "t-config-provider";
+pub const MOTION_REDUCED: &str = "t-motion--reduced";
+
+// 属性表
+pub const PROPS_TABLE: &str = "t-props-table";
+pub const PROPS_TABLE_PRINT_FRIENDLY: &str = "t-props-table--print-friendly";
+pub const PROPS_TABLE_TITLE: &str = "t-props-table__title";
+pub const PROPS_TABLE_WRAPPER: &str = "t-props-table__wrapper";
+pub const PROPS_TABLE_TABLE: &str = "t-props-table__table";
+pub const PROPS_TABLE_NAME: &str = "t-props-table__name";
+pub const PROPS_TABLE_TYPE: &str = "t-props-table__type";
+
+// JSON 查看器
+pub const JSON_VIEWER: &str = "t-json-viewer";
+pub const JSON_VIEWER_SEARCH: &str = "t-json-viewer__search";
+pub const JSON_VIEWER_SEARCH_COUNT: &str = "t-json-viewer__search-count";
+pub const JSON_VIEWER_TREE: &str = "t-json-viewer__tree";
+pub const JSON_VIEWER_NODE: &str = "t-json-viewer__node";
+pub const JSON_VIEWER_LINE: &str = "t-json-viewer__line";
+pub const JSON_VIEWER_CHILDREN: &str = "t-json-viewer__children";
+pub const JSON_VIEWER_TOGGLE: &str = "t-json-viewer__toggle";
+pub const JSON_VIEWER_KEY: &str = "t-json-viewer__key";
+pub const JSON_VIEWER_COLON: &str = "t-json-viewer__colon";
+pub const JSON_VIEWER_BRACKET: &str = "t-json-viewer__bracket";
+pub const JSON_VIEWER_SUMMARY: &str = "t-json-viewer__summary";
+pub const JSON_VIEWER_VALUE: &str = "t-json-viewer__value";
+pub const JSON_VIEWER_STRING: &str = "t-json-viewer__value--string";
+pub const JSON_VIEWER_NUMBER: &str = "t-json-viewer__value--number";
+pub const JSON_VIEWER_BOOLEAN: &str = "t-json-viewer__value--boolean";
+pub const JSON_VIEWER_NULL: &str = "t-json-viewer__value--null";
+pub const JSON_VIEWER_COPY: &str = "t-json-viewer__copy";
+
+// 日志查看器
+pub const LOG_VIEWER: &str = "t-log-viewer";
+pub const LOG_VIEWER_TOOLBAR: &str = "t-log-viewer__toolbar";
+pub const LOG_VIEWER_LEVELS: &str = "t-log-viewer__levels";
+pub const LOG_VIEWER_LEVEL: &str = "t-log-viewer__level";
+pub const LOG_VIEWER_COUNT: &str = "t-log-viewer__count";
+pub const LOG_VIEWER_BUTTON: &str = "t-log-viewer__button";
+pub const LOG_VIEWER_BODY: &str = "t-log-viewer__body";
+pub const LOG_VIEWER_LINES: &str = "t-log-viewer__lines";
+pub const LOG_VIEWER_LINE: &str = "t-log-viewer__line";
+pub const LOG_VIEWER_NUMBER: &str = "t-log-viewer__number";
+pub const LOG_VIEWER_TEXT: &str = "t-log-viewer__text";
+pub const LOG_VIEWER_EMPTY: &str = "t-log-viewer__empty";
+
+// 表格
+pub const TABLE: &str = "t-table";
+pub const TABLE_WRAPPER: &str = "t-table__wrapper";
+pub const TABLE_INNER: &str = "t-table__inner";
+pub const TABLE_HEADER_CELL: &str = "t-table__header-cell";
+pub const TABLE_BODY: &str = "t-table__body";
+pub const TABLE_ROW: &str = "t-table__row";
+pub const TABLE_CELL: &str = "t-table__cell";
+pub const TABLE_CELL_ELLIPSIS: &str = "t-table__cell--ellipsis";
+pub const TABLE_CELL_CONTENT: &str = "t-table__cell-content";
+pub const TABLE_EMPTY: &str = "t-table__empty";
+pub const TABLE_INDENT: &str = "t-table__indent";
+pub const TABLE_EXPAND: &str = "t-table__expand";
+pub const TABLE_EXPAND_PLACEHOLDER: &str = "t-table__expand-placeholder";
+pub const TABLE_TOOLBAR: &str = "t-table__toolbar";
+pub const TABLE_COLUMN_CHOOSER: &str = "t-table__column-chooser";
+pub const TABLE_COLUMN_CHOOSER_TRIGGER: &str = "t-table__column-chooser-trigger";
+pub const TABLE_COLUMN_CHOOSER_PANEL: &str = "t-table__column-chooser-panel";
+pub const TABLE_COLUMN_CHOOSER_LIST: &str = "t-table__column-chooser-list";
+pub const TABLE_COLUMN_CHOOSER_ITEM: &str = "t-table__column-chooser-item";
+pub const TABLE_COLUMN_CHOOSER_HANDLE: &str = "t-table__column-chooser-handle";
+pub const TABLE_COLUMN_CHOOSER_RESET: &str = "t-table__column-chooser-reset";
+pub const TABLE_MENU: &str = "t-table__menu";
+pub const TABLE_MENU_ITEM: &str = "t-table__menu-item";
+pub const TABLE_MENU_BACKDROP: &str = "t-table__menu-backdrop";
+pub const TABLE_SELECTION: &str = "t-table__selection";
+pub const TABLE_STRIPED: &str = "t-table--striped";
+pub const TABLE_BORDERED: &str = "t-table--bordered";
+pub const TABLE_PRINT_FRIENDLY: &str = "t-table--print-friendly";
+pub const TABLE_SORT: &str = "t-table__sort";
+pub const TABLE_SORT_CARET: &str = "t-table__sort-caret";
+
+// 批量操作栏
+pub const SELECTION_BAR: &str = "t-selection-bar";
+pub const SELECTION_BAR_COUNT: &str = "t-selection-bar__count";
+pub const SELECTION_BAR_LINK: &str = "t-selection-bar__link";
+pub const SELECTION_BAR_ACTIONS: &str = "t-selection-bar__actions";
+
+// 增删改查页面
+pub const CRUD_PAGE: &str = "t-crud-page";
+pub const CRUD_PAGE_TOOLBAR: &str = "t-crud-page__toolbar";
+pub const CRUD_PAGE_CREATE: &str = "t-crud-page__create";
+pub const CRUD_PAGE_ERROR: &str = "t-crud-page__error";
+pub const CRUD_PAGE_ACTIONS: &str = "t-crud-page__actions";
+pub const CRUD_PAGE_EDIT: &str = "t-crud-page__edit";
+pub const CRUD_PAGE_PAGER: &str = "t-crud-page__pager";
+pub const CRUD_PAGE_TOTAL: &str = "t-crud-page__total";
+pub const CRUD_PAGE_PREV: &str = "t-crud-page__prev";
+pub const CRUD_PAGE_CURRENT: &str = "t-crud-page__current";
+pub const CRUD_PAGE_NEXT: &str = "t-crud-page__next";
+pub const CRUD_PAGE_FORM_ERROR: &str = "t-crud-page__form-error";
+
+// 按键和快捷键提示
+pub const KBD: &str = "t-kbd";
+pub const SHORTCUT_HINT: &str = "t-shortcut-hint";
+pub const SHORTCUT_HINT_PLAIN: &str = "t-shortcut-hint--plain";
+pub const SHORTCUT_HINT_SEPARATOR: &str = "t-shortcut-hint__separator";
+
+// 文字提示，位置类名见 `TooltipPlacement::as_class`
+pub const TOOLTIP: &str = "t-tooltip";
+pub const TOOLTIP_POPUP: &str = "t-tooltip__popup";
+pub const TOOLTIP_ARROW: &str = "t-tooltip__arrow";
+pub const TOOLTIP_BACKDROP: &str = "t-tooltip__backdrop";
+pub const TOOLTIP_SHORTCUT: &str = "t-tooltip__shortcut";
+
+// 标签页
+pub const TABS: &str = "t-tabs";
+pub const TABS_NAV: &str = "t-tabs__nav";
+pub const TABS_LIST: &str = "t-tabs__list";
+pub const TABS_TAB: &str = "t-tabs__tab";
+pub const TABS_LABEL: &str = "t-tabs__label";
+pub const TABS_CLOSE: &str = "t-tabs__close";
+pub const TABS_CONTENT: &str = "t-tabs__content";
+pub const TABS_PANE: &str = "t-tabs__pane";
+
+// 下拉菜单
+pub const DROPDOWN: &str = "t-dropdown";
+pub const DROPDOWN_MENU: &str = "t-dropdown__menu";
+pub const DROPDOWN_SUBMENU: &str = "t-dropdown__submenu";
+pub const DROPDOWN_ITEM: &str = "t-dropdown__item";
+pub const DROPDOWN_ITEM_SUBMENU: &str = "t-dropdown__item--submenu";
+pub const DROPDOWN_DIVIDER: &str = "t-dropdown__divider";
+pub const DROPDOWN_ICON: &str = "t-dropdown__icon";
+pub const DROPDOWN_LABEL: &str = "t-dropdown__label";
+pub const DROPDOWN_ARROW: &str = "t-dropdown__arrow";
+pub const DROPDOWN_SHORTCUT: &str = "t-dropdown__shortcut";
+pub const DROPDOWN_BACKDROP: &str = "t-dropdown__backdrop";
+
+// 分页
+pub const PAGINATION: &str = "t-pagination";
+pub const PAGINATION_TOTAL: &str = "t-pagination__total";
+pub const PAGINATION_SIZES: &str = "t-pagination__sizes";
+pub const PAGINATION_PREV: &str = "t-pagination__prev";
+pub const PAGINATION_NEXT: &str = "t-pagination__next";
+pub const PAGINATION_PAGER: &str = "t-pagination__pager";
+pub const PAGINATION_ITEM: &str = "t-pagination__item";
+pub const PAGINATION_MORE: &str = "t-pagination__more";
+pub const PAGINATION_CURRENT: &str = "t-pagination__current";
+pub const PAGINATION_JUMPER: &str = "t-pagination__jumper";
+
+// 气泡确认框
+pub const POPCONFIRM: &str = "t-popconfirm";
+pub const POPCONFIRM_POPUP: &str = "t-popconfirm__popup";
+pub const POPCONFIRM_MAIN: &str = "t-popconfirm__main";
+pub const POPCONFIRM_ICON: &str = "t-popconfirm__icon";
+pub const POPCONFIRM_TITLE: &str = "t-popconfirm__title";
+pub const POPCONFIRM_DESCRIPTION: &str = "t-popconfirm__description";
+pub const POPCONFIRM_ACTIONS: &str = "t-popconfirm__actions";
+pub const POPCONFIRM_CANCEL: &str = "t-popconfirm__cancel";
+pub const POPCONFIRM_CONFIRM: &str = "t-popconfirm__confirm";
+pub const POPCONFIRM_ARROW: &str = "t-popconfirm__arrow";
+pub const POPCONFIRM_BACKDROP: &str = "t-popconfirm__backdrop";
+
+// ANSI 终端样式，颜色类名后接颜色编号，如 `t-ansi-fg-1`
+pub const ANSI_FG: &str = "t-ansi-fg";
+pub const ANSI_BG: &str = "t-ansi-bg";
+pub const ANSI_BOLD: &str = "t-ansi-bold";
+pub const ANSI_DIM: &str = "t-ansi-dim";
+pub const ANSI_ITALIC: &str = "t-ansi-italic";
+pub const ANSI_UNDERLINE: &str = "t-ansi-underline";
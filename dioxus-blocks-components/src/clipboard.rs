@@ -0,0 +1,94 @@
+//! # 剪贴板
+//!
+//! 在浏览器中把文本写入剪贴板。优先使用 `navigator.clipboard`，在非安全上下文等不支持的环境中
+//! 退回到 `document.execCommand("copy")`。启用 `server` 特性进行服务端渲染时直接返回 `false`。
+//!
+//! 组件内部通过 `pasted_files` 读取粘贴事件中的文件，如截图或从文件管理器复制的文件，
+//! 目前只支持 web 平台。
+//!
+//! # 示例
+//!
+//! ```rust,ignore
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::copy_text;
+//!
+//! spawn(async move {
+//!     if copy_text("order-20240101").await {
+//!         // 提示已复制
+//!     }
+//! });
+//! ```
+
+use dioxus::prelude::*;
+
+/// 把文本写入剪贴板，成功时返回 `true`
+pub async fn copy_text(text: &str) -> bool {
+    if cfg!(feature = "server") {
+        return false;
+    }
+    let js = format!(
+        "const text = {text:?}; \
+         try {{ await navigator.clipboard.writeText(text); return true; }} catch (_) {{}} \
+         const el = document.createElement('textarea'); \
+         el.value = text; el.setAttribute('readonly', ''); \
+         el.style.position = 'fixed'; el.style.opacity = '0'; \
+         document.body.appendChild(el); el.select(); \
+         const ok = document.execCommand('copy'); \
+         el.remove(); return ok;"
+    );
+    document::eval(&js).join::<bool>().await.unwrap_or(false)
+}
+
+/// 粘贴的文件，交互测试通过它向粘贴事件传入文件
+#[cfg(any(test, feature = "testing"))]
+#[cfg_attr(not(feature = "upload"), allow(dead_code))]
+#[derive(Clone)]
+pub(crate) struct PastedFiles(pub(crate) Vec<dioxus::html::FileData>);
+
+#[cfg(any(test, feature = "testing"))]
+impl dioxus::html::HasClipboardData for PastedFiles {
+    fn as_any(&self) -> &dyn std::any::Any {
+        self
+    }
+}
+
+/// 读取粘贴事件中的文件，粘贴的是文本时返回空列表
+#[cfg(feature = "upload")]
+pub(crate) fn pasted_files(event: &ClipboardEvent) -> Vec<dioxus::html::FileData> {
+    #[cfg(any(test, feature = "testing"))]
+    if let Some(files) = event.data().downcast::<PastedFiles>() {
+        return files.0.clone();
+    }
+    platform_pasted_files(event)
+}
+
+/// 读取浏览器粘贴事件中 `clipboardData.files` 的文件
+#[cfg(all(feature = "upload", feature = "web"))]
+fn platform_pasted_files(event: &ClipboardEvent) -> Vec<dioxus::html::FileData> {
+    use web_sys::wasm_bindgen::JsCast;
+
+    let Some(files) = event
+        .data()
+        .downcast::<web_sys::Event>()
+        .and_then(|event| event.dyn_ref::<web_sys::ClipboardEvent>())
+        .and_then(|event| event.clipboard_data())
+        .and_then(|data| data.files())
+    else {
+        return Vec::new();
+    };
+    (0..files.length())
+        .filter_map(|index| files.get(index))
+        .filter_map(|file| {
+            let reader = web_sys::FileReader::new().ok()?;
+            Some(dioxus::html::FileData::new(dioxus::web::WebFileData::new(
+                file, reader,
+            )))
+        })
+        .collect()
+}
+
+/// 其他平台的粘贴事件不包含文件
+#[cfg(all(feature = "upload", not(feature = "web")))]
+fn platform_pasted_files(_event: &ClipboardEvent) -> Vec<dioxus::html::FileData> {
+    Vec::new()
+}
@@ -0,0 +1,180 @@
+//! # 颜色工具
+//!
+//! 用户自定义的背景色可能很浅也可能很深，固定使用白色文字时很容易出现看不清的情况。
+//! [`Color`] 按 WCAG 2.1 的定义计算两个颜色之间的对比度，[`Color::readable_text_on`]
+//! 在黑色和白色中选出对比度更高的一个作为文字颜色，结果至少为 4.5:1，满足 AA 级正文的要求。
+//!
+//! [`Button::color`](crate::Button::color) 的自定义颜色模式使用该工具自动选择文字颜色。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus_blocks_components::Color;
+//!
+//! let bg = Color::parse("#ffd666").unwrap();
+//! assert_eq!(Color::readable_text_on(bg), Color::BLACK);
+//! assert!(Color::BLACK.contrast_ratio(bg) >= Color::AA_CONTRAST);
+//!
+//! let bg = Color::parse("rgb(24, 144, 255)").unwrap();
+//! assert_eq!(Color::readable_text_on(bg).to_string(), "#000000");
+//! ```
+
+use std::fmt;
+
+/// 不透明的 sRGB 颜色
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Color {
+    /// 红色分量
+    pub r: u8,
+    /// 绿色分量
+    pub g: u8,
+    /// 蓝色分量
+    pub b: u8,
+}
+
+impl Color {
+    /// 黑色
+    pub const BLACK: Color = Color::rgb(0, 0, 0);
+    /// 白色
+    pub const WHITE: Color = Color::rgb(255, 255, 255);
+    /// WCAG AA 级正文要求的最小对比度
+    pub const AA_CONTRAST: f64 = 4.5;
+
+    /// 使用红、绿、蓝分量创建颜色
+    pub const fn rgb(r: u8, g: u8, b: u8) -> Self {
+        Self { r, g, b }
+    }
+
+    /// 解析 CSS 颜色，支持 `#rgb`、`#rrggbb` 和 `rgb(r, g, b)`，无法解析时返回 `None`
+    pub fn parse(value: &str) -> Option<Self> {
+        let value = value.trim();
+        if let Some(hex) = value.strip_prefix('#') {
+            return Self::parse_hex(hex);
+        }
+        let args = value
+            .strip_prefix("rgb(")
+            .or_else(|| value.strip_prefix("rgba("))?
+            .strip_suffix(')')?;
+        let mut parts = args.split([',', ' ']).filter(|s| !s.is_empty());
+        let mut channel = || parts.next()?.parse::<u8>().ok();
+        Some(Self::rgb(channel()?, channel()?, channel()?))
+    }
+
+    fn parse_hex(hex: &str) -> Option<Self> {
+        if !hex.is_ascii() {
+            return None;
+        }
+        let channel = |s: &str| u8::from_str_radix(s, 16).ok();
+        match hex.len() {
+            3 => {
+                let mut digits = hex.chars().map(|c| c.to_digit(16).map(|d| d as u8 * 17));
+                Some(Self::rgb(digits.next()??, digits.next()??, digits.next()??))
+            }
+            6 => Some(Self::rgb(
+                channel(&hex[0..2])?,
+                channel(&hex[2..4])?,
+                channel(&hex[4..6])?,
+            )),
+            _ => None,
+        }
+    }
+
+    /// 与另一个颜色混合，`weight` 为另一个颜色所占的比例，范围为 0～1
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Color;
+    /// let primary = Color::rgb(64, 158, 255);
+    /// assert_eq!(primary.mix(Color::WHITE, 0.9).to_string(), "#ecf5ff");
+    /// ```
+    pub fn mix(&self, other: Color, weight: f64) -> Color {
+        let weight = weight.clamp(0.0, 1.0);
+        let channel =
+            |a: u8, b: u8| (f64::from(a) * (1.0 - weight) + f64::from(b) * weight).round() as u8;
+        Color::rgb(
+            channel(self.r, other.r),
+            channel(self.g, other.g),
+            channel(self.b, other.b),
+        )
+    }
+
+    /// 相对亮度，范围为 0（黑色）到 1（白色）
+    pub fn relative_luminance(&self) -> f64 {
+        let linear = |c: u8| {
+            let c = f64::from(c) / 255.0;
+            if c <= 0.04045 {
+                c / 12.92
+            } else {
+                ((c + 0.055) / 1.055).powf(2.4)
+            }
+        };
+        0.2126 * linear(self.r) + 0.7152 * linear(self.g) + 0.0722 * linear(self.b)
+    }
+
+    /// 与另一个颜色之间的对比度，范围为 1 到 21，与参数顺序无关
+    pub fn contrast_ratio(&self, other: Color) -> f64 {
+        let a = self.relative_luminance();
+        let b = other.relative_luminance();
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// 在背景色上可读的文字颜色，返回黑色和白色中对比度更高的一个，对比度相同时使用白色
+    pub fn readable_text_on(bg: Color) -> Color {
+        if Color::BLACK.contrast_ratio(bg) > Color::WHITE.contrast_ratio(bg) {
+            Color::BLACK
+        } else {
+            Color::WHITE
+        }
+    }
+}
+
+impl fmt::Display for Color {
+    /// 输出为 `#rrggbb` 格式
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse() {
+        assert_eq!(Color::parse("#fff"), Some(Color::WHITE));
+        assert_eq!(Color::parse("#1890FF"), Some(Color::rgb(24, 144, 255)));
+        assert_eq!(
+            Color::parse(" rgb(24, 144, 255) "),
+            Some(Color::rgb(24, 144, 255))
+        );
+        assert_eq!(Color::parse("#12345"), None);
+        assert_eq!(Color::parse("red"), None);
+        assert_eq!(Color::parse("rgb(300, 0, 0)"), None);
+        assert_eq!(Color::rgb(24, 144, 255).to_string(), "#1890ff");
+    }
+
+    #[test]
+    fn test_contrast_ratio() {
+        assert!((Color::BLACK.contrast_ratio(Color::WHITE) - 21.0).abs() < 1e-9);
+        assert!((Color::WHITE.contrast_ratio(Color::WHITE) - 1.0).abs() < 1e-9);
+        // #777 与白色的对比度约为 4.48，略低于 AA 要求
+        let gray = Color::parse("#777").unwrap();
+        let ratio = gray.contrast_ratio(Color::WHITE);
+        assert!(ratio > 4.4 && ratio < Color::AA_CONTRAST);
+    }
+
+    #[test]
+    fn test_readable_text_on() {
+        for (bg, text) in [
+            ("#ffffff", Color::BLACK),
+            ("#ffd666", Color::BLACK),
+            ("#000000", Color::WHITE),
+            ("#722ed1", Color::WHITE),
+            ("#f56c6c", Color::BLACK),
+        ] {
+            let bg = Color::parse(bg).unwrap();
+            let chosen = Color::readable_text_on(bg);
+            assert_eq!(chosen, text);
+            assert!(chosen.contrast_ratio(bg) >= Color::AA_CONTRAST);
+        }
+    }
+}
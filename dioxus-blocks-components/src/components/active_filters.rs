@@ -0,0 +1,289 @@
+//! ActiveFilters 已选筛选条件组件
+//!
+//! 把当前的筛选条件显示为一排可移除的标签，并提供「清除全部」操作，通常放在 [`FilterBar`](crate::FilterBar) 和表格之间。
+//!
+//! # 组件模式
+//!
+//! 与 FilterBar 共用同一个 `Signal<FilterQuery>`：FilterBar 中修改条件时标签实时更新，
+//! 移除标签或清除全部时条件从 Signal 中删除，FilterBar 中对应的控件也会随之清空。
+//! 标签的名称和显示文本来自传入的 [`FilterField`]，未配置的字段直接显示字段名和值。
+//! 没有筛选条件时不渲染任何内容。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     ActiveFilters, FilterBar, FilterField, FilterQuery, ToElement, View,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let query = use_signal(FilterQuery::new);
+//!     let fields = vec![
+//!         FilterField::input("name", "名称"),
+//!         FilterField::select("status", "状态", [("on", "启用"), ("off", "停用")]),
+//!     ];
+//!     View::new()
+//!         .children(FilterBar::new().fields(fields.clone()).value(query))
+//!         .children(
+//!             ActiveFilters::new()
+//!                 .fields(fields)
+//!                 .value(query)
+//!                 .onchange(|query: FilterQuery| println!("{} 个条件", query.len())),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{FilterField, FilterQuery, Style, classnames, traits::ToElement};
+
+/// 已选筛选条件组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct ActiveFilters {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 筛选字段，用于显示标签名称和值
+    fields: Vec<FilterField>,
+    /// 查询条件的 Signal
+    value: Option<Signal<FilterQuery>>,
+    /// 清除全部按钮文本
+    clear_text: String,
+    /// 移除条件后的回调，参数为移除后的查询条件
+    onchange: Option<EventHandler<FilterQuery>>,
+}
+
+impl Default for ActiveFilters {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::ACTIVE_FILTERS),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            fields: Vec::new(),
+            value: None,
+            clear_text: "清除全部".to_string(),
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl ActiveFilters {
+    /// 创建已选筛选条件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加筛选字段
+    pub fn field(mut self, field: FilterField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// 设置筛选字段，通常与 FilterBar 使用相同的字段
+    pub fn fields(mut self, fields: Vec<FilterField>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// 设置查询条件的 Signal
+    pub fn value(mut self, value: Signal<FilterQuery>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置清除全部按钮文本
+    #[prop(default = "\"清除全部\"")]
+    pub fn clear_text(mut self, text: impl Into<String>) -> Self {
+        self.clear_text = text.into();
+        self
+    }
+
+    /// 设置移除条件后的回调，可以在这里重新查询表格数据
+    pub fn onchange(mut self, handler: impl FnMut(FilterQuery) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for ActiveFilters {
+    fn to_element(&self) -> Element {
+        let inner_value = use_signal(FilterQuery::new);
+        let mut value = self.value.unwrap_or(inner_value);
+        let onchange = self.onchange;
+
+        let mut remove = move |key: Option<String>| {
+            match key {
+                Some(key) => {
+                    value.write().remove(&key);
+                }
+                None => value.write().clear(),
+            }
+            if let Some(handler) = onchange {
+                handler.call(value.peek().clone());
+            }
+        };
+
+        let query = value.read().clone();
+        if query.is_empty() {
+            return rsx! {};
+        }
+
+        // 按字段的配置顺序显示，未配置的字段排在最后
+        let mut tags = self
+            .fields
+            .iter()
+            .filter_map(|field| {
+                query.get(field.key()).map(|v| {
+                    (
+                        field.key().to_string(),
+                        field.label().to_string(),
+                        field.display_value(v),
+                    )
+                })
+            })
+            .collect::<Vec<_>>();
+        // 未配置的字段按输入框的方式显示
+        for (key, v) in &query {
+            if !self.fields.iter().any(|field| field.key() == key) {
+                let text = FilterField::input(key.clone(), key.clone()).display_value(v);
+                tags.push((key.clone(), key.clone(), text));
+            }
+        }
+
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let clear_text = self.clear_text.clone();
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                for (key , label , text) in tags {
+                    span { key: "{key}", class: classnames::ACTIVE_FILTERS_TAG,
+                        span { class: classnames::ACTIVE_FILTERS_LABEL, "{label}：" }
+                        span { class: classnames::ACTIVE_FILTERS_VALUE, "{text}" }
+                        button {
+                            r#type: "button",
+                            class: classnames::ACTIVE_FILTERS_REMOVE,
+                            aria_label: "移除{label}",
+                            onclick: {
+                                let key = key.clone();
+                                move |_| remove(Some(key.clone()))
+                            },
+                            "×"
+                        }
+                    }
+                }
+                button {
+                    r#type: "button",
+                    class: classnames::ACTIVE_FILTERS_CLEAR,
+                    onclick: move |_| remove(None),
+                    "{clear_text}"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{FilterBar, FilterValue};
+
+    #[test]
+    fn test_display_value() {
+        let field = FilterField::select("status", "状态", [("on", "启用")]);
+        assert_eq!(
+            field.display_value(&FilterValue::Select("on".to_string())),
+            "启用"
+        );
+        let field = FilterField::number_range("price", "价格");
+        assert_eq!(
+            field.display_value(&FilterValue::NumberRange(Some(10.0), Some(20.5))),
+            "10 ~ 20.5"
+        );
+        assert_eq!(
+            field.display_value(&FilterValue::NumberRange(None, Some(5.0))),
+            "≤ 5"
+        );
+    }
+
+    #[test]
+    fn test_active_filters_sync() {
+        fn app() -> Element {
+            let mut initial = FilterQuery::new();
+            initial.insert("name".to_string(), FilterValue::Text("dioxus".to_string()));
+            initial.insert("status".to_string(), FilterValue::Select("on".to_string()));
+            let query = use_signal(|| initial);
+            let fields = vec![
+                FilterField::input("name", "名称"),
+                FilterField::select("status", "状态", [("on", "启用"), ("off", "停用")]),
+            ];
+            rsx! {
+                {FilterBar::new().fields(fields.clone()).value(query).to_element()}
+                {ActiveFilters::new().fields(fields).value(query).to_element()}
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let tags = harness.find_all_by_class(classnames::ACTIVE_FILTERS_TAG);
+        assert_eq!(tags.len(), 2);
+        assert!(harness.find_by_text("启用").is_some());
+
+        // 移除标签后，FilterBar 中的输入框同步清空
+        let remove = harness.find_by_attr("aria-label", "移除名称").unwrap();
+        harness.click(&remove);
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::ACTIVE_FILTERS_TAG)
+                .len(),
+            1
+        );
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.attr("value"), Some(""));
+
+        // 在 FilterBar 中输入时，标签同步出现
+        harness.input(&inner, "blocks");
+        assert!(harness.find_by_text("blocks").is_some());
+
+        let clear = harness
+            .find_by_class(classnames::ACTIVE_FILTERS_CLEAR)
+            .unwrap();
+        harness.click(&clear);
+        assert!(harness.find_by_class(classnames::ACTIVE_FILTERS).is_none());
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.attr("value"), Some(""));
+    }
+}
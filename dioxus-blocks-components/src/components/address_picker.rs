@@ -0,0 +1,454 @@
+//! AddressPicker 地址选择器组件
+//!
+//! 由省份、城市和区县三个可筛选的选择器组成，按 [`RegionSource`] 逐级加载下级选项，
+//! 选择上级后清空下级，输出包含各级代码和名称的 [`AddressValue`]。
+//!
+//! # 组件模式
+//!
+//! AddressPicker 是一个**受控组件**，通过 `Signal<AddressValue>` 传递选中的地区。
+//! 没有下级的地区（如香港）不显示下一级的选择器，`levels(2)` 只选择到城市。
+//!
+//! # 数据源
+//!
+//! 通过 [`AddressPicker::source`] 设置数据集，见 [`RegionSource`]，未设置时没有可选的地区。
+//! 启用 `regions` 特性后可以使用内置的 `ChinaRegionsSample` 示例数据，它只收录了部分城市和区县，
+//! 生产环境需要接入完整的行政区划数据。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{AddressPicker, AddressValue, Region, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let address = use_signal(AddressValue::default);
+//!     AddressPicker::new()
+//!         .value(address)
+//!         .source(|parent: Option<&str>| match parent {
+//!             None => vec![Region::new("440000", "广东省")],
+//!             Some("440000") => vec![Region::new("440300", "深圳市")],
+//!             Some(_) => Vec::new(),
+//!         })
+//!         .onchange(|value: AddressValue| println!("{:?}", value.codes()))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::regions::SharedRegionSource;
+use crate::{
+    Region, RegionSource, Select, SelectOption, SelectSize, SelectValue, Style, classnames,
+    traits::ToElement,
+};
+
+/// 地址选择器的值
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct AddressValue {
+    /// 省份
+    pub province: Option<Region>,
+    /// 城市
+    pub city: Option<Region>,
+    /// 区县
+    pub district: Option<Region>,
+}
+
+impl AddressValue {
+    /// 从第一级开始的行政区划创建值，超过三级的部分会被忽略
+    pub fn from_path(path: Vec<Region>) -> Self {
+        let mut path = path.into_iter();
+        Self {
+            province: path.next(),
+            city: path.next(),
+            district: path.next(),
+        }
+    }
+
+    /// 按最后一级的代码从数据源查找各级行政区划，用于回显已保存的地址
+    pub fn from_code(source: &impl RegionSource, code: &str) -> Self {
+        Self::from_path(source.path(code))
+    }
+
+    /// 已选的各级行政区划
+    pub fn regions(&self) -> Vec<&Region> {
+        [&self.province, &self.city, &self.district]
+            .into_iter()
+            .map_while(Option::as_ref)
+            .collect()
+    }
+
+    /// 已选的各级代码
+    pub fn codes(&self) -> Vec<&str> {
+        self.regions()
+            .into_iter()
+            .map(|region| region.code.as_str())
+            .collect()
+    }
+
+    /// 最后一级的代码
+    pub fn code(&self) -> Option<&str> {
+        self.codes().pop()
+    }
+
+    /// 以分隔符连接的各级名称，如 `广东省 / 深圳市 / 南山区`
+    pub fn text(&self, separator: &str) -> String {
+        self.regions()
+            .into_iter()
+            .map(|region| region.name.as_str())
+            .collect::<Vec<_>>()
+            .join(separator)
+    }
+
+    /// 未选择任何地区
+    pub fn is_empty(&self) -> bool {
+        self.province.is_none()
+    }
+
+    /// 第 `level` 级（从 0 开始）的行政区划
+    fn get(&self, level: usize) -> Option<&Region> {
+        self.regions().get(level).copied()
+    }
+
+    /// 选择第 `level` 级的行政区划，清空下级
+    fn select(&self, level: usize, region: Option<Region>) -> Self {
+        let mut path: Vec<Region> = self.regions().into_iter().take(level).cloned().collect();
+        path.extend(region);
+        Self::from_path(path)
+    }
+}
+
+/// 默认的数据源，没有任何地区
+fn default_source() -> SharedRegionSource {
+    SharedRegionSource::new(|_: Option<&str>| Vec::new())
+}
+
+/// 地址选择器组件
+///
+/// 逐级选择省份、城市和区县，每一级都是可筛选的选择器。
+#[derive(Debug, Clone, ComponentBase)]
+pub struct AddressPicker {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值的 Signal（受控状态）
+    value: Option<Signal<AddressValue>>,
+    /// 行政区划数据源，默认没有任何地区
+    source: SharedRegionSource,
+    /// 选择的级数，1 到 3
+    levels: usize,
+    /// 省份占位符
+    province_placeholder: String,
+    /// 城市占位符
+    city_placeholder: String,
+    /// 区县占位符
+    district_placeholder: String,
+    /// 是否禁用
+    disabled: bool,
+    /// 是否可清空
+    clearable: bool,
+    /// 选择器尺寸
+    size: SelectSize,
+    /// 值改变事件
+    onchange: Option<EventHandler<AddressValue>>,
+}
+
+impl Default for AddressPicker {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::ADDRESS_PICKER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            source: default_source(),
+            levels: 3,
+            province_placeholder: "省份".to_string(),
+            city_placeholder: "城市".to_string(),
+            district_placeholder: "区县".to_string(),
+            disabled: false,
+            clearable: false,
+            size: SelectSize::default(),
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl AddressPicker {
+    /// 创建一个新的地址选择器
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<AddressValue>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置行政区划数据源，未设置时没有可选的地区
+    pub fn source(mut self, source: impl RegionSource) -> Self {
+        self.source = SharedRegionSource::new(source);
+        self
+    }
+
+    /// 设置选择的级数，1 为只选择省份，2 为选择到城市，3 为选择到区县
+    #[prop(default = "3")]
+    pub fn levels(mut self, levels: usize) -> Self {
+        self.levels = levels.clamp(1, 3);
+        self
+    }
+
+    /// 设置省份占位符
+    #[prop(default = "\"省份\"")]
+    pub fn province_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.province_placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置城市占位符
+    #[prop(default = "\"城市\"")]
+    pub fn city_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.city_placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置区县占位符
+    #[prop(default = "\"区县\"")]
+    pub fn district_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.district_placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置是否可清空，清空某一级时同时清空下级
+    #[prop(default = "false")]
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// 设置选择器尺寸
+    #[prop(default = "SelectSize::Medium")]
+    pub fn size(mut self, size: SelectSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置值改变事件，选择或清空任意一级时触发
+    pub fn onchange(mut self, handler: impl FnMut(AddressValue) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for AddressPicker {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(AddressValue::default);
+        let mut value = self.value.unwrap_or(inner_value);
+
+        // 各级选择器的值，外部修改地址时同步更新
+        let selected = |level: usize| {
+            value
+                .peek()
+                .get(level)
+                .map(|region| SelectValue::from(region.code.as_str()))
+        };
+        let mut levels = [
+            use_signal(|| selected(0)),
+            use_signal(|| selected(1)),
+            use_signal(|| selected(2)),
+        ];
+        use_effect(move || {
+            let current = value.read();
+            for (level, signal) in levels.iter_mut().enumerate() {
+                let code = current
+                    .get(level)
+                    .map(|region| SelectValue::from(region.code.as_str()));
+                if *signal.peek() != code {
+                    signal.set(code);
+                }
+            }
+        });
+
+        let current = value.read().clone();
+        let onchange = self.onchange;
+        let placeholders = [
+            &self.province_placeholder,
+            &self.city_placeholder,
+            &self.district_placeholder,
+        ];
+        let mut pickers = Vec::new();
+        for level in 0..self.levels {
+            let parent = match level {
+                0 => None,
+                _ => match current.get(level - 1) {
+                    Some(region) => Some(region.code.as_str()),
+                    None => break,
+                },
+            };
+            let regions = self.source.children(parent);
+            if regions.is_empty() && level > 0 {
+                break;
+            }
+
+            let options = regions
+                .iter()
+                .map(|region| SelectOption::new(region.code.as_str()).label(region.name.clone()))
+                .collect();
+            let picker = Select::new()
+                .class(classnames::ADDRESS_PICKER_LEVEL)
+                .value(levels[level])
+                .options(options)
+                .filterable(true)
+                .clearable(self.clearable)
+                .disabled(self.disabled)
+                .size(self.size)
+                .placeholder(placeholders[level].clone())
+                .onchange(move |selected: Option<SelectValue>| {
+                    let region = selected.and_then(|selected| {
+                        let code = selected.to_string();
+                        regions.iter().find(|region| region.code == code).cloned()
+                    });
+                    let next = value.peek().select(level, region);
+                    value.set(next.clone());
+                    if let Some(handler) = onchange {
+                        handler.call(next);
+                    }
+                });
+            pickers.push(picker);
+        }
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                "data-code": current.code().map(str::to_string),
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                for picker in pickers {
+                    {picker.to_element()}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    /// 两个省份，广东有城市和区县，香港没有下级
+    fn source(parent: Option<&str>) -> Vec<Region> {
+        match parent {
+            None => vec![
+                Region::new("440000", "广东省"),
+                Region::new("810000", "香港特别行政区"),
+            ],
+            Some("440000") => vec![
+                Region::new("440100", "广州市"),
+                Region::new("440300", "深圳市"),
+            ],
+            Some("440300") => vec![Region::new("440305", "南山区")],
+            Some(_) => Vec::new(),
+        }
+    }
+
+    #[test]
+    fn test_address_value() {
+        let value = AddressValue::from_code(&source, "440305");
+        assert_eq!(value.codes(), ["440000", "440300", "440305"]);
+        assert_eq!(value.code(), Some("440305"));
+        assert_eq!(value.text(" / "), "广东省 / 深圳市 / 南山区");
+
+        let value = value.select(1, Some(Region::new("440100", "广州市")));
+        assert_eq!(value.codes(), ["440000", "440100"]);
+        assert!(value.district.is_none());
+        assert!(value.select(0, None).is_empty());
+        assert!(AddressValue::from_code(&source, "999999").is_empty());
+    }
+
+    #[test]
+    fn test_address_picker() {
+        fn app() -> Element {
+            let address = use_signal(AddressValue::default);
+            rsx! {
+                {AddressPicker::new().value(address).source(source).to_element()}
+                span { class: "codes", "{address.read().codes().join(\",\")}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let pick = |harness: &mut Harness, level: usize, label: &str| {
+            let wrappers = harness.find_all_by_class(classnames::SELECT_WRAPPER);
+            harness.click(&wrappers[level]);
+            let option = harness
+                .find_all_by_class(classnames::SELECT_OPTION)
+                .into_iter()
+                .find(|option| option.text() == label)
+                .unwrap();
+            harness.click(&option);
+        };
+
+        assert_eq!(
+            harness.find_all_by_class(classnames::SELECT_WRAPPER).len(),
+            1
+        );
+        pick(&mut harness, 0, "广东省");
+        assert_eq!(
+            harness.find_all_by_class(classnames::SELECT_WRAPPER).len(),
+            2
+        );
+        pick(&mut harness, 1, "深圳市");
+        pick(&mut harness, 2, "南山区");
+        assert_eq!(
+            harness.find_by_class("codes").unwrap().text(),
+            "440000,440300,440305"
+        );
+        let root = harness.find_by_class(classnames::ADDRESS_PICKER).unwrap();
+        assert_eq!(root.attr("data-code"), Some("440305"));
+
+        // 切换省份后清空下级，没有下级时不显示下一级
+        pick(&mut harness, 0, "香港特别行政区");
+        assert_eq!(harness.find_by_class("codes").unwrap().text(), "810000");
+        assert_eq!(
+            harness.find_all_by_class(classnames::SELECT_WRAPPER).len(),
+            1
+        );
+    }
+}
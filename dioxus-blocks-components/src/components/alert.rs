@@ -0,0 +1,321 @@
+//! Alert 提示组件
+//!
+//! 用于页面中展示重要的提示信息，支持成功、信息、警告和错误四种类型，
+//! 可以设置标题、描述以及是否可关闭。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Alert, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Alert::new("操作成功").as_success().to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 带描述且可关闭
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Alert, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let visible = use_signal(|| true);
+//!     Alert::new("登录失败")
+//!         .as_error()
+//!         .visible(visible)
+//!         .description("用户名或密码错误")
+//!         .closable(true)
+//!         .onclose(|_| println!("closed"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 提示类型枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum AlertType {
+    /// 成功提示
+    Success,
+    /// 信息提示
+    #[default]
+    Info,
+    /// 警告提示
+    Warning,
+    /// 错误提示
+    Error,
+}
+
+impl AlertType {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            AlertType::Success => "t-alert--success",
+            AlertType::Info => "t-alert--info",
+            AlertType::Warning => "t-alert--warning",
+            AlertType::Error => "t-alert--error",
+        }
+    }
+}
+
+impl std::fmt::Display for AlertType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+impl AlertType {
+    /// 类型对应的图标
+    fn icon(&self) -> &'static str {
+        match self {
+            AlertType::Success => "✓",
+            AlertType::Info => "i",
+            AlertType::Warning => "!",
+            AlertType::Error => "✕",
+        }
+    }
+}
+
+/// Alert 提示组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Alert {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 提示标题
+    title: String,
+    /// 提示描述
+    description: Option<String>,
+    /// 提示类型
+    alert_type: AlertType,
+    /// 是否显示图标
+    show_icon: bool,
+    /// 是否可关闭
+    closable: bool,
+    /// 是否显示，关闭时会被设置为 false
+    visible: Option<Signal<bool>>,
+    /// 关闭事件
+    onclose: Option<EventHandler<MouseEvent>>,
+}
+
+impl Default for Alert {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::ALERT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            title: String::new(),
+            description: None,
+            alert_type: AlertType::default(),
+            show_icon: true,
+            closable: false,
+            visible: None,
+            onclose: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Alert {
+    /// 创建一个新的提示
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置提示描述
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 设置提示类型
+    #[prop(default = "AlertType::Info")]
+    pub fn alert_type(mut self, alert_type: AlertType) -> Self {
+        self.alert_type = alert_type;
+        self
+    }
+
+    /// 设置是否显示图标
+    #[prop(default = "true")]
+    pub fn show_icon(mut self, show_icon: bool) -> Self {
+        self.show_icon = show_icon;
+        self
+    }
+
+    /// 设置是否可关闭
+    #[prop(default = "false")]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// 设置是否显示（受控模式），点击关闭按钮时会被设置为 false
+    pub fn visible(mut self, visible: Signal<bool>) -> Self {
+        self.visible = Some(visible);
+        self
+    }
+
+    /// 设置关闭事件
+    pub fn onclose(mut self, handler: impl FnMut(MouseEvent) + 'static) -> Self {
+        self.onclose = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置关闭事件
+    #[prop(skip)]
+    pub fn onclose2(mut self, handler: EventHandler<MouseEvent>) -> Self {
+        self.onclose = Some(handler);
+        self
+    }
+}
+
+/// 便捷方法
+impl Alert {
+    /// 设置为成功提示
+    pub fn as_success(mut self) -> Self {
+        self.alert_type = AlertType::Success;
+        self
+    }
+
+    /// 设置为信息提示
+    pub fn as_info(mut self) -> Self {
+        self.alert_type = AlertType::Info;
+        self
+    }
+
+    /// 设置为警告提示
+    pub fn as_warning(mut self) -> Self {
+        self.alert_type = AlertType::Warning;
+        self
+    }
+
+    /// 设置为错误提示
+    pub fn as_error(mut self) -> Self {
+        self.alert_type = AlertType::Error;
+        self
+    }
+}
+
+impl ToElement for Alert {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = [&*self.class, self.alert_type.as_class()].join(" ");
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let onclose_handler = self.onclose;
+        let visible = self.visible;
+
+        if visible.is_some_and(|v| !v()) {
+            return rsx! {};
+        }
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                role: "alert",
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if self.show_icon {
+                    span { class: classnames::ALERT_ICON, {self.alert_type.icon()} }
+                }
+                div { class: classnames::ALERT_CONTENT,
+                    if !self.title.is_empty() {
+                        div { class: classnames::ALERT_TITLE, "{self.title}" }
+                    }
+                    if let Some(description) = &self.description {
+                        div { class: classnames::ALERT_DESCRIPTION, "{description}" }
+                    }
+                    {self.childrens_to_element()}
+                }
+                if self.closable {
+                    span {
+                        class: classnames::ALERT_CLOSE,
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            if let Some(mut visible) = visible {
+                                visible.set(false);
+                            }
+                            if let Some(handler) = onclose_handler {
+                                handler.call(event);
+                            }
+                        },
+                        "×"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_error_alert() {
+        fn app() -> Element {
+            Alert::new("登录失败")
+                .as_error()
+                .description("用户名或密码错误")
+                .closable(true)
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-alert t-alert--error"));
+        assert!(html.contains("用户名或密码错误"));
+        assert!(html.contains("t-alert__close"));
+    }
+
+    #[test]
+    fn test_hidden_alert() {
+        fn app() -> Element {
+            let visible = use_signal(|| false);
+            Alert::new("已关闭").visible(visible).to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(!html.contains("t-alert"));
+    }
+}
@@ -0,0 +1,238 @@
+//! ANSI 转义序列
+//!
+//! 解析终端输出中的 SGR 序列（`ESC [ ... m`），把文本拆分为带样式的片段，
+//! 支持粗体、暗淡、斜体、下划线，以及 16 色、256 色和 24 位真彩色的前景色和背景色。
+//! 其它控制序列会被丢弃。
+
+use crate::classnames;
+
+/// 终端颜色
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum AnsiColor {
+    /// 256 色调色板中的颜色，前 16 个为标准色和亮色
+    Index(u8),
+    /// 24 位真彩色
+    Rgb(u8, u8, u8),
+}
+
+impl AnsiColor {
+    /// 不能用类名表示的颜色转换为 CSS 颜色，标准色返回 `None`
+    fn css(&self) -> Option<String> {
+        let (r, g, b) = match *self {
+            AnsiColor::Index(n) if n < 16 => return None,
+            AnsiColor::Index(n) if n < 232 => {
+                // 6×6×6 颜色立方
+                const LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+                let n = n - 16;
+                (
+                    LEVELS[(n / 36) as usize],
+                    LEVELS[(n / 6 % 6) as usize],
+                    LEVELS[(n % 6) as usize],
+                )
+            }
+            AnsiColor::Index(n) => {
+                let gray = 8 + (n - 232) * 10;
+                (gray, gray, gray)
+            }
+            AnsiColor::Rgb(r, g, b) => (r, g, b),
+        };
+        Some(format!("rgb({r}, {g}, {b})"))
+    }
+}
+
+/// 文本片段的样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub(crate) struct AnsiStyle {
+    pub(crate) fg: Option<AnsiColor>,
+    pub(crate) bg: Option<AnsiColor>,
+    pub(crate) bold: bool,
+    pub(crate) dim: bool,
+    pub(crate) italic: bool,
+    pub(crate) underline: bool,
+}
+
+impl AnsiStyle {
+    /// 样式对应的类名，标准色使用 `t-ansi-fg-N`、`t-ansi-bg-N` 类名
+    pub(crate) fn class(&self) -> String {
+        let mut class_names = Vec::new();
+        if let Some(AnsiColor::Index(n)) = self.fg.filter(|c| c.css().is_none()) {
+            class_names.push(format!("{}-{n}", classnames::ANSI_FG));
+        }
+        if let Some(AnsiColor::Index(n)) = self.bg.filter(|c| c.css().is_none()) {
+            class_names.push(format!("{}-{n}", classnames::ANSI_BG));
+        }
+        for (enabled, class) in [
+            (self.bold, classnames::ANSI_BOLD),
+            (self.dim, classnames::ANSI_DIM),
+            (self.italic, classnames::ANSI_ITALIC),
+            (self.underline, classnames::ANSI_UNDERLINE),
+        ] {
+            if enabled {
+                class_names.push(class.to_string());
+            }
+        }
+        class_names.join(" ")
+    }
+
+    /// 256 色和真彩色使用的内联样式
+    pub(crate) fn inline_style(&self) -> Option<String> {
+        let mut style = String::new();
+        if let Some(color) = self.fg.and_then(|c| c.css()) {
+            style.push_str(&format!("color: {color};"));
+        }
+        if let Some(color) = self.bg.and_then(|c| c.css()) {
+            style.push_str(&format!("background-color: {color};"));
+        }
+        (!style.is_empty()).then_some(style)
+    }
+
+    /// 应用一组 SGR 参数
+    fn apply(&mut self, params: &[u16]) {
+        let mut params = params.iter().copied();
+        while let Some(code) = params.next() {
+            match code {
+                0 => *self = Self::default(),
+                1 => self.bold = true,
+                2 => self.dim = true,
+                3 => self.italic = true,
+                4 => self.underline = true,
+                22 => {
+                    self.bold = false;
+                    self.dim = false;
+                }
+                23 => self.italic = false,
+                24 => self.underline = false,
+                30..=37 => self.fg = Some(AnsiColor::Index((code - 30) as u8)),
+                38 => self.fg = extended_color(&mut params),
+                39 => self.fg = None,
+                40..=47 => self.bg = Some(AnsiColor::Index((code - 40) as u8)),
+                48 => self.bg = extended_color(&mut params),
+                49 => self.bg = None,
+                90..=97 => self.fg = Some(AnsiColor::Index((code - 90 + 8) as u8)),
+                100..=107 => self.bg = Some(AnsiColor::Index((code - 100 + 8) as u8)),
+                _ => {}
+            }
+        }
+    }
+}
+
+/// 解析 `38;5;N` 和 `38;2;R;G;B` 形式的扩展颜色
+fn extended_color(params: &mut impl Iterator<Item = u16>) -> Option<AnsiColor> {
+    let byte = |v: Option<u16>| v.map(|v| v.min(255) as u8);
+    match params.next()? {
+        5 => byte(params.next()).map(AnsiColor::Index),
+        2 => Some(AnsiColor::Rgb(
+            byte(params.next())?,
+            byte(params.next())?,
+            byte(params.next())?,
+        )),
+        _ => None,
+    }
+}
+
+/// 带样式的文本片段
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct AnsiSpan {
+    pub(crate) text: String,
+    pub(crate) style: AnsiStyle,
+}
+
+/// 把包含 ANSI 转义序列的文本拆分为带样式的片段，相邻的同样式文本合并为一个片段
+pub(crate) fn parse(input: &str) -> Vec<AnsiSpan> {
+    let mut spans: Vec<AnsiSpan> = Vec::new();
+    let mut style = AnsiStyle::default();
+    let mut text = String::new();
+    let mut chars = input.chars().peekable();
+
+    let mut flush = |text: &mut String, style: AnsiStyle| {
+        if text.is_empty() {
+            return;
+        }
+        match spans.last_mut() {
+            Some(last) if last.style == style => last.text.push_str(text),
+            _ => spans.push(AnsiSpan {
+                text: text.clone(),
+                style,
+            }),
+        }
+        text.clear();
+    };
+
+    while let Some(c) = chars.next() {
+        if c != '\x1b' {
+            if !c.is_control() || c == '\t' {
+                text.push(c);
+            }
+            continue;
+        }
+        if chars.peek() != Some(&'[') {
+            continue;
+        }
+        chars.next();
+        let mut raw = String::new();
+        let mut terminator = None;
+        for c in chars.by_ref() {
+            if ('\x40'..='\x7e').contains(&c) {
+                terminator = Some(c);
+                break;
+            }
+            raw.push(c);
+        }
+        if terminator == Some('m') {
+            flush(&mut text, style);
+            let params = raw
+                .split(';')
+                .map(|p| p.parse::<u16>().unwrap_or(0))
+                .collect::<Vec<_>>();
+            style.apply(&params);
+        }
+    }
+    flush(&mut text, style);
+    spans
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_plain_text() {
+        let spans = parse("hello world");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "hello world");
+        assert_eq!(spans[0].style, AnsiStyle::default());
+    }
+
+    #[test]
+    fn test_parse_colors_and_reset() {
+        let spans = parse("\x1b[1;31mERROR\x1b[0m done \x1b[92mok\x1b[m");
+        assert_eq!(spans.len(), 3);
+        assert_eq!(spans[0].text, "ERROR");
+        assert_eq!(spans[0].style.fg, Some(AnsiColor::Index(1)));
+        assert!(spans[0].style.bold);
+        assert_eq!(spans[0].style.class(), "t-ansi-fg-1 t-ansi-bold");
+        assert_eq!(spans[1].text, " done ");
+        assert_eq!(spans[1].style, AnsiStyle::default());
+        assert_eq!(spans[2].style.fg, Some(AnsiColor::Index(10)));
+    }
+
+    #[test]
+    fn test_parse_extended_colors() {
+        let spans = parse("\x1b[38;5;196mred\x1b[48;2;0;128;255mblue");
+        assert_eq!(
+            spans[0].style.inline_style().unwrap(),
+            "color: rgb(255, 0, 0);"
+        );
+        assert_eq!(
+            spans[1].style.inline_style().unwrap(),
+            "color: rgb(255, 0, 0);background-color: rgb(0, 128, 255);"
+        );
+    }
+
+    #[test]
+    fn test_parse_drops_other_sequences() {
+        let spans = parse("\x1b[2Kprogress\r\x1b[1A 100%");
+        assert_eq!(spans.len(), 1);
+        assert_eq!(spans[0].text, "progress 100%");
+    }
+}
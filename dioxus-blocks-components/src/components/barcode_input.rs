@@ -0,0 +1,422 @@
+//! BarcodeInput 条码输入组件
+//!
+//! 用于录入商品条码、二维码等编码的输入框。手动输入或使用扫码枪（输入后自动回车）时，按回车触发
+//! [`BarcodeInput::onscan`]；启用 `camera` 特性后输入框旁显示扫码按钮，在浏览器中打开摄像头识别条码，
+//! 识别成功后写入输入框并触发同一个回调。
+//!
+//! # 摄像头扫码
+//!
+//! 摄像头扫码通过 `getUserMedia` 获取视频流，使用浏览器的 `BarcodeDetector` 识别条码，
+//! 需要在 HTTPS 或 localhost 页面中使用。浏览器不支持、用户拒绝授权或摄像头无法打开时显示提示，
+//! 此时仍可以手动输入。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{BarcodeInput, BarcodeScan, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let code = use_signal(String::new);
+//!     BarcodeInput::new()
+//!         .value(code)
+//!         .formats(vec!["ean_13", "qr_code"])
+//!         .onscan(|scan: BarcodeScan| println!("{} {:?}", scan.value, scan.format))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+#[cfg(feature = "camera")]
+use crate::{Button, use_unique_id};
+use crate::{Input, InputSize, Style, classnames, traits::ToElement};
+
+/// 扫码结果
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct BarcodeScan {
+    /// 条码内容，已去除首尾空白
+    pub value: String,
+    /// 条码格式，如 `qr_code`、`ean_13`，手动输入时为 `None`
+    pub format: Option<String>,
+}
+
+/// 摄像头扫码失败的原因
+#[cfg(feature = "camera")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScanError {
+    /// 浏览器不支持摄像头或条码识别
+    Unsupported,
+    /// 用户拒绝了摄像头授权
+    Denied,
+    /// 摄像头无法打开或识别出错
+    Failed,
+}
+
+#[cfg(feature = "camera")]
+impl ScanError {
+    /// 从脚本返回的状态转换
+    fn from_js(value: &str) -> Self {
+        match value {
+            "unsupported" => ScanError::Unsupported,
+            "denied" => ScanError::Denied,
+            _ => ScanError::Failed,
+        }
+    }
+
+    /// 显示给用户的提示
+    pub fn message(&self) -> &'static str {
+        match self {
+            ScanError::Unsupported => "当前浏览器不支持扫码，请手动输入",
+            ScanError::Denied => "未获得摄像头权限，请手动输入",
+            ScanError::Failed => "无法打开摄像头，请手动输入",
+        }
+    }
+}
+
+/// 打开摄像头识别条码，识别到第一个条码后关闭摄像头
+///
+/// 等待 `video_id` 对应的视频元素渲染后开始识别，视频元素被移除时停止并返回 `Ok(None)`。
+#[cfg(feature = "camera")]
+async fn scan(video_id: &str, formats: &[String]) -> Result<Option<BarcodeScan>, ScanError> {
+    if cfg!(feature = "server") {
+        return Err(ScanError::Unsupported);
+    }
+    let result = document::eval(&format!(
+        "if (!('BarcodeDetector' in window) || !navigator.mediaDevices || !navigator.mediaDevices.getUserMedia) \
+           return ['unsupported', '', '']; \
+         const formats = {formats:?}; \
+         let video = null; \
+         for (let i = 0; i < 60 && !(video = document.getElementById({video_id:?})); i++) \
+           await new Promise(requestAnimationFrame); \
+         if (!video) return ['cancelled', '', '']; \
+         let stream; \
+         try {{ stream = await navigator.mediaDevices.getUserMedia({{ video: {{ facingMode: 'environment' }} }}); }} \
+         catch (e) {{ return [e && e.name === 'NotAllowedError' ? 'denied' : 'failed', '', '']; }} \
+         try {{ \
+           if (!video.isConnected) return ['cancelled', '', '']; \
+           video.srcObject = stream; \
+           await video.play(); \
+           const detector = formats.length ? new BarcodeDetector({{ formats }}) : new BarcodeDetector(); \
+           while (video.isConnected) {{ \
+             const codes = await detector.detect(video).catch(() => []); \
+             if (codes.length) return ['ok', codes[0].rawValue, codes[0].format]; \
+             await new Promise((r) => setTimeout(r, 200)); \
+           }} \
+           return ['cancelled', '', '']; \
+         }} catch (_) {{ return ['failed', '', '']; }} \
+         finally {{ stream.getTracks().forEach((track) => track.stop()); }}"
+    ))
+    .join::<(String, String, String)>()
+    .await
+    .map_err(|_| ScanError::Failed)?;
+    match result {
+        (status, value, format) if status == "ok" => Ok(Some(BarcodeScan {
+            value: value.trim().to_string(),
+            format: Some(format),
+        })),
+        (status, ..) if status == "cancelled" => Ok(None),
+        (status, ..) => Err(ScanError::from_js(&status)),
+    }
+}
+
+/// 条码输入组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct BarcodeInput {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值的 Signal（受控状态）
+    value: Option<Signal<String>>,
+    /// 占位符
+    placeholder: String,
+    /// 是否禁用
+    disabled: bool,
+    /// 输入框尺寸
+    size: InputSize,
+    /// 摄像头识别的条码格式，为空时识别浏览器支持的全部格式
+    formats: Vec<String>,
+    /// 扫码按钮文本
+    scan_text: String,
+    /// 扫码或手动输入完成事件
+    onscan: Option<EventHandler<BarcodeScan>>,
+}
+
+impl Default for BarcodeInput {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::BARCODE_INPUT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            placeholder: "请输入或扫描条码".to_string(),
+            disabled: false,
+            size: InputSize::default(),
+            formats: Vec::new(),
+            scan_text: "扫码".to_string(),
+            onscan: None,
+        }
+    }
+}
+
+#[builder_props]
+impl BarcodeInput {
+    /// 创建一个新的条码输入组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<String>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置占位符
+    #[prop(default = "\"请输入或扫描条码\"")]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置输入框尺寸
+    #[prop(default = "InputSize::Medium")]
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置摄像头识别的条码格式，如 `qr_code`、`ean_13`、`code_128`
+    pub fn formats(mut self, formats: Vec<&str>) -> Self {
+        self.formats = formats.into_iter().map(String::from).collect();
+        self
+    }
+
+    /// 设置扫码按钮文本
+    #[prop(default = "\"扫码\"")]
+    pub fn scan_text(mut self, text: impl Into<String>) -> Self {
+        self.scan_text = text.into();
+        self
+    }
+
+    /// 设置扫码事件，摄像头识别成功或手动输入后按回车时触发
+    pub fn onscan(mut self, handler: impl FnMut(BarcodeScan) + 'static) -> Self {
+        self.onscan = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for BarcodeInput {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(String::new);
+        let value = self.value.unwrap_or(inner_value);
+        let onscan = self.onscan;
+        let disabled = self.disabled;
+
+        let input = Input::new()
+            .class(classnames::BARCODE_INPUT_CONTROL)
+            .value(value)
+            .size(self.size)
+            .disabled(disabled)
+            .placeholder(self.placeholder.clone())
+            .clearable(true)
+            .onkeydown(move |event: KeyboardEvent| {
+                let code = value.peek().trim().to_string();
+                if event.key() != Key::Enter || code.is_empty() {
+                    return;
+                }
+                if let Some(handler) = onscan {
+                    handler.call(BarcodeScan {
+                        value: code,
+                        format: None,
+                    });
+                }
+            });
+
+        let mut class_names = vec![&*self.class];
+        if disabled {
+            class_names.push("is-disabled");
+        }
+        let class = class_names.join(" ");
+
+        #[cfg(feature = "camera")]
+        let camera = {
+            let mut value = value;
+            let mut scanning = use_signal(|| false);
+            let mut error = use_signal(|| None::<ScanError>);
+            // 每次打开摄像头递增，取消后重新打开时忽略上一次的结果
+            let mut session = use_signal(|| 0u64);
+            let video_id = use_unique_id("barcode-input-video");
+            let formats = self.formats.clone();
+
+            let start = {
+                let video_id = video_id.clone();
+                move |_| {
+                    session += 1;
+                    let current = *session.peek();
+                    scanning.set(true);
+                    error.set(None);
+                    let video_id = video_id.clone();
+                    let formats = formats.clone();
+                    spawn(async move {
+                        let result = scan(&video_id, &formats).await;
+                        if *session.peek() != current {
+                            return;
+                        }
+                        scanning.set(false);
+                        match result {
+                            Ok(Some(scan)) => {
+                                value.set(scan.value.clone());
+                                if let Some(handler) = onscan {
+                                    handler.call(scan);
+                                }
+                            }
+                            Ok(None) => {}
+                            Err(err) => error.set(Some(err)),
+                        }
+                    });
+                }
+            };
+            let button = Button::new()
+                .class(classnames::BARCODE_INPUT_BUTTON)
+                .text(self.scan_text.clone())
+                .disabled(disabled || scanning())
+                .onclick(start);
+            let cancel = Button::new()
+                .class(classnames::BARCODE_INPUT_CANCEL)
+                .text("取消")
+                .onclick(move |_| {
+                    session += 1;
+                    scanning.set(false);
+                });
+            let message = error().map(|err| err.message());
+
+            rsx! {
+                {button.to_element()}
+                if scanning() {
+                    div { class: classnames::BARCODE_INPUT_CAMERA,
+                        video {
+                            id: video_id,
+                            class: classnames::BARCODE_INPUT_VIDEO,
+                            autoplay: true,
+                            muted: true,
+                            playsinline: true,
+                        }
+                        div { class: classnames::BARCODE_INPUT_FRAME }
+                        {cancel.to_element()}
+                    }
+                }
+                if let Some(message) = message {
+                    div { class: classnames::BARCODE_INPUT_ERROR, role: "alert", "{message}" }
+                }
+            }
+        };
+        #[cfg(not(feature = "camera"))]
+        let camera = rsx! {};
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::BARCODE_INPUT_ROW, {input.to_element()} }
+                {camera}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_manual_entry() {
+        fn app() -> Element {
+            let code = use_signal(String::new);
+            let mut last = use_signal(BarcodeScan::default);
+            rsx! {
+                {BarcodeInput::new().value(code).onscan(move |scan| last.set(scan)).to_element()}
+                span { class: "last", "{last.read().value}:{last.read().format.is_none()}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&input, "  6901234567892 ");
+        assert_eq!(harness.find_by_class("last").unwrap().text(), ":true");
+
+        // 扫码枪输入后自动回车
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.keydown(&input, "Enter");
+        assert_eq!(
+            harness.find_by_class("last").unwrap().text(),
+            "6901234567892:true"
+        );
+    }
+
+    #[cfg(feature = "camera")]
+    #[test]
+    fn test_camera_fallback() {
+        let mut harness = Harness::with_element(BarcodeInput::new());
+        assert!(
+            harness
+                .find_by_class(classnames::BARCODE_INPUT_CAMERA)
+                .is_none()
+        );
+
+        // 没有浏览器环境时无法打开摄像头，关闭取景框并提示手动输入
+        let button = harness
+            .find_by_class(classnames::BARCODE_INPUT_BUTTON)
+            .unwrap();
+        harness.click(&button);
+        assert!(
+            harness
+                .find_by_class(classnames::BARCODE_INPUT_CAMERA)
+                .is_none()
+        );
+        let error = harness
+            .find_by_class(classnames::BARCODE_INPUT_ERROR)
+            .unwrap();
+        assert_eq!(error.text(), ScanError::Failed.message());
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(input.attr("disabled"), Some("false"));
+    }
+}
@@ -56,6 +56,18 @@
 //!     .shape(ButtonShape::Text);
 //! ```
 //!
+//! ## 自定义颜色
+//!
+//! 文字颜色根据背景色自动选择黑色或白色，保证对比度满足 WCAG AA 级要求。
+//!
+//! ```rust
+//! use dioxus_blocks_components::{Button, Color};
+//!
+//! let button = Button::new()
+//!     .text("Custom")
+//!     .color(Color::parse("#ffd666").unwrap());
+//! ```
+//!
 //! ## 朴素按钮
 //!
 //! ```rust
@@ -66,13 +78,14 @@
 //!     .btn_type(ButtonType::Primary)
 //!     .shape(ButtonShape::Plain);
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{Color, Style, classnames, traits::ToElement};
 
 /// 按钮类型枚举
 ///
@@ -94,19 +107,26 @@ pub enum ButtonType {
     Danger,
 }
 
-impl std::fmt::Display for ButtonType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ButtonType {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            ButtonType::Default => write!(f, "t-button--default"),
-            ButtonType::Primary => write!(f, "t-button--primary"),
-            ButtonType::Success => write!(f, "t-button--success"),
-            ButtonType::Info => write!(f, "t-button--info"),
-            ButtonType::Warning => write!(f, "t-button--warning"),
-            ButtonType::Danger => write!(f, "t-button--danger"),
+            ButtonType::Default => "t-button--default",
+            ButtonType::Primary => "t-button--primary",
+            ButtonType::Success => "t-button--success",
+            ButtonType::Info => "t-button--info",
+            ButtonType::Warning => "t-button--warning",
+            ButtonType::Danger => "t-button--danger",
         }
     }
 }
 
+impl std::fmt::Display for ButtonType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 按钮形状枚举
 ///
 /// 定义按钮的圆角风格和类型。
@@ -127,19 +147,26 @@ pub enum ButtonShape {
     Text,
 }
 
-impl std::fmt::Display for ButtonShape {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ButtonShape {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            ButtonShape::Default => write!(f, ""),
-            ButtonShape::Plain => write!(f, "t-button--plain"),
-            ButtonShape::Round => write!(f, "t-button--round"),
-            ButtonShape::Circle => write!(f, "t-button--circle"),
-            ButtonShape::Link => write!(f, "t-button--link"),
-            ButtonShape::Text => write!(f, "t-button--text"),
+            ButtonShape::Default => "",
+            ButtonShape::Plain => "t-button--plain",
+            ButtonShape::Round => "t-button--round",
+            ButtonShape::Circle => "t-button--circle",
+            ButtonShape::Link => "t-button--link",
+            ButtonShape::Text => "t-button--text",
         }
     }
 }
 
+impl std::fmt::Display for ButtonShape {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 按钮尺寸枚举
 ///
 /// 定义按钮的大小。
@@ -154,16 +181,23 @@ pub enum ButtonSize {
     Large,
 }
 
-impl std::fmt::Display for ButtonSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ButtonSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            ButtonSize::Medium => write!(f, ""),
-            ButtonSize::Small => write!(f, "t-button--small"),
-            ButtonSize::Large => write!(f, "t-button--large"),
+            ButtonSize::Medium => "",
+            ButtonSize::Small => "t-button--small",
+            ButtonSize::Large => "t-button--large",
         }
     }
 }
 
+impl std::fmt::Display for ButtonSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 按钮组件结构体
 ///
 /// 提供一个可自定义的按钮，支持多种类型、样式、形状和尺寸。
@@ -172,7 +206,7 @@ pub struct Button {
     /// 按钮的唯一标识符
     id: Option<String>,
     /// 按钮的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 按钮的内联样式
     style: Option<Style>,
     /// 按钮的子元素列表
@@ -191,13 +225,15 @@ pub struct Button {
     disabled: bool,
     /// 是否加载中
     loading: bool,
+    /// 自定义颜色，设置后代替按钮类型的颜色
+    color: Option<Color>,
 }
 
 impl Default for Button {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-button".to_string(),
+            class: Cow::Borrowed(classnames::BUTTON),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -207,10 +243,12 @@ impl Default for Button {
             size: ButtonSize::default(),
             disabled: false,
             loading: false,
+            color: None,
         }
     }
 }
 
+#[builder_props]
 impl Button {
     /// 创建一个新的按钮实例
     ///
@@ -267,6 +305,7 @@ impl Button {
     /// # use dioxus_blocks_components::{Button, ButtonType};
     /// Button::new().btn_type(ButtonType::Primary);
     /// ```
+    #[prop(default = "ButtonType::Default")]
     pub fn btn_type(mut self, btn_type: ButtonType) -> Self {
         self.btn_type = btn_type;
         self
@@ -288,6 +327,7 @@ impl Button {
     /// # use dioxus_blocks_components::{Button, ButtonShape};
     /// Button::new().shape(ButtonShape::Round);
     /// ```
+    #[prop(default = "ButtonShape::Default")]
     pub fn shape(mut self, shape: ButtonShape) -> Self {
         self.shape = shape;
         self
@@ -309,6 +349,7 @@ impl Button {
     /// # use dioxus_blocks_components::{Button, ButtonSize};
     /// Button::new().size(ButtonSize::Large);
     /// ```
+    #[prop(default = "ButtonSize::Medium")]
     pub fn size(mut self, size: ButtonSize) -> Self {
         self.size = size;
         self
@@ -330,6 +371,7 @@ impl Button {
     /// # use dioxus_blocks_components::Button;
     /// Button::new().disabled(true);
     /// ```
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -351,10 +393,35 @@ impl Button {
     /// # use dioxus_blocks_components::Button;
     /// Button::new().loading(true);
     /// ```
+    #[prop(default = "false")]
     pub fn loading(mut self, loading: bool) -> Self {
         self.loading = loading;
         self
     }
+
+    /// 设置自定义颜色
+    ///
+    /// 设置后忽略按钮类型，实心按钮使用该颜色作为背景色，文字颜色通过
+    /// [`Color::readable_text_on`] 自动选择；朴素、链接和文字按钮使用该颜色作为文字颜色。
+    ///
+    /// # 参数
+    ///
+    /// * `color` - 按钮颜色
+    ///
+    /// # 返回值
+    ///
+    /// 返回修改后的按钮实例，支持链式调用
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::{Button, Color};
+    /// Button::new().color(Color::rgb(114, 46, 209));
+    /// ```
+    pub fn color(mut self, color: Color) -> Self {
+        self.color = Some(color);
+        self
+    }
 }
 
 /// 便捷方法
@@ -584,24 +651,42 @@ impl Button {
 impl ToElement for Button {
     fn to_element(&self) -> Element {
         // 构建完整的 class 列表
+        let type_class = match self.color {
+            Some(_) => classnames::BUTTON_CUSTOM,
+            None => self.btn_type.as_class(),
+        };
         let mut class_names = vec![
-            self.class.clone(),
-            self.btn_type.to_string(), // 添加类型 class
-            self.shape.to_string(),    // 添加形状 class
-            self.size.to_string(),     // 添加尺寸 class
+            &*self.class,
+            type_class,            // 添加类型 class
+            self.shape.as_class(), // 添加形状 class
+            self.size.as_class(),  // 添加尺寸 class
         ];
 
         // 添加状态 class
         if self.disabled {
-            class_names.push("t-button--disabled".to_string());
+            class_names.push(classnames::BUTTON_DISABLED);
         }
         if self.loading {
-            class_names.push("t-button--loading".to_string());
+            class_names.push(classnames::BUTTON_LOADING);
         }
 
         let id = self.id.clone();
         let class = class_names.join(" ");
         let style = self.style.clone().map(|s| s.to_string());
+        // 自定义颜色通过 CSS 变量传给样式表
+        let style = match self.color {
+            Some(color) => {
+                let vars = format!(
+                    "--t-button-color: {color}; --t-button-text-color: {};",
+                    Color::readable_text_on(color)
+                );
+                match style {
+                    Some(style) => Some(format!("{vars} {style}")),
+                    None => Some(vars),
+                }
+            }
+            None => style,
+        };
         let onclick_handler = self.onclick;
         let childrens = self.childrens_to_element();
         let text = self.text.clone();
@@ -629,7 +714,6 @@ mod tests {
     use std::{any::Any, rc::Rc};
 
     use dioxus::core::{ElementId, Mutations};
-    use dioxus_html::SerializedHtmlEventConverter;
 
     use super::*;
 
@@ -652,6 +736,23 @@ mod tests {
         assert!(button.class.contains("t-button"));
     }
 
+    #[test]
+    fn test_button_custom_color() {
+        let mut dom = VirtualDom::new(|| {
+            Button::new()
+                .text("Custom")
+                .btn_type(ButtonType::Primary)
+                .color(Color::rgb(255, 214, 102))
+                .to_element()
+        });
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-button--custom"));
+        assert!(!html.contains("t-button--primary"));
+        assert!(html.contains("--t-button-color: #ffd666; --t-button-text-color: #000000;"));
+    }
+
     #[test]
     fn test_button_default_values() {
         let button = Button::new();
@@ -682,7 +783,7 @@ mod tests {
         let button_id = ElementId(1);
 
         // 告诉 dioxus 使用序列化事件转换器
-        dioxus::html::set_event_converter(Box::new(SerializedHtmlEventConverter));
+        crate::testing::install_event_converter();
 
         // 构造一个事件
         let payload = PlatformEventData::new(Box::<SerializedMouseData>::default());
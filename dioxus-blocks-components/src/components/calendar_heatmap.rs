@@ -0,0 +1,482 @@
+//! CalendarHeatmap 日历热力图组件
+//!
+//! 提供类似 GitHub 贡献图的日历热力图组件，按周为列、按星期为行渲染一整年的日期格子，
+//! 根据每日数值显示不同深浅的颜色，并支持悬停提示和颜色图例。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{CalendarHeatmap, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     CalendarHeatmap::new()
+//!         .year(2025)
+//!         .value(NaiveDate::from_ymd_opt(2025, 1, 1).unwrap(), 3)
+//!         .value(NaiveDate::from_ymd_opt(2025, 1, 2).unwrap(), 8)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 自定义提示文本
+//!
+//! ```rust
+//! use chrono::NaiveDate;
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{CalendarHeatmap, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap();
+//!     let end = NaiveDate::from_ymd_opt(2025, 3, 31).unwrap();
+//!     CalendarHeatmap::new()
+//!         .range(start, end)
+//!         .data(vec![(start, 2), (end, 5)])
+//!         .tooltip(|(date, value)| format!("{} 提交了 {} 次", date, value))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use chrono::{Datelike, Duration, Local, NaiveDate};
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{
+    Style,
+    classnames::{self, bem},
+    traits::ToElement,
+};
+
+/// 星期标签（从周日开始）
+const WEEKDAY_LABELS: [&str; 7] = ["日", "一", "二", "三", "四", "五", "六"];
+
+/// 月份标签
+const MONTH_LABELS: [&str; 12] = [
+    "1月", "2月", "3月", "4月", "5月", "6月", "7月", "8月", "9月", "10月", "11月", "12月",
+];
+
+/// CalendarHeatmap 日历热力图组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct CalendarHeatmap {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 起始日期（包含）
+    start: NaiveDate,
+    /// 结束日期（包含）
+    end: NaiveDate,
+    /// 日期到数值的映射
+    data: BTreeMap<NaiveDate, u32>,
+    /// 颜色等级数量（包含 0 值等级）
+    levels: u8,
+    /// 自定义颜色刻度，数量应与等级数量一致
+    colors: Vec<String>,
+    /// 是否显示颜色图例
+    show_legend: bool,
+    /// 是否显示星期标签
+    show_weekday: bool,
+    /// 是否显示月份标签
+    show_month: bool,
+    /// 自定义提示文本
+    tooltip: Option<Callback<(NaiveDate, u32), String>>,
+    /// 格子点击事件
+    oncell_click: Option<EventHandler<(NaiveDate, u32)>>,
+}
+
+impl Default for CalendarHeatmap {
+    fn default() -> Self {
+        let end = Local::now().date_naive();
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CALENDAR_HEATMAP),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            start: end - Duration::days(364),
+            end,
+            data: BTreeMap::new(),
+            levels: 5,
+            colors: Vec::new(),
+            show_legend: true,
+            show_weekday: true,
+            show_month: true,
+            tooltip: None,
+            oncell_click: None,
+        }
+    }
+}
+
+#[builder_props]
+impl CalendarHeatmap {
+    /// 创建一个新的日历热力图实例，默认显示截至今天的最近一年
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置显示的年份（1 月 1 日至 12 月 31 日）
+    pub fn year(mut self, year: i32) -> Self {
+        if let (Some(start), Some(end)) = (
+            NaiveDate::from_ymd_opt(year, 1, 1),
+            NaiveDate::from_ymd_opt(year, 12, 31),
+        ) {
+            self.start = start;
+            self.end = end;
+        }
+        self
+    }
+
+    /// 设置显示的日期范围（包含首尾）
+    pub fn range(mut self, start: NaiveDate, end: NaiveDate) -> Self {
+        if start <= end {
+            self.start = start;
+            self.end = end;
+        } else {
+            self.start = end;
+            self.end = start;
+        }
+        self
+    }
+
+    /// 批量设置日期数据，相同日期的数值会被累加
+    pub fn data(mut self, data: impl IntoIterator<Item = (NaiveDate, u32)>) -> Self {
+        for (date, value) in data {
+            *self.data.entry(date).or_default() += value;
+        }
+        self
+    }
+
+    /// 设置单个日期的数值，相同日期的数值会被累加
+    pub fn value(mut self, date: NaiveDate, value: u32) -> Self {
+        *self.data.entry(date).or_default() += value;
+        self
+    }
+
+    /// 设置颜色等级数量（2-10，包含 0 值等级）
+    #[prop(default = "5")]
+    pub fn levels(mut self, levels: u8) -> Self {
+        self.levels = levels.clamp(2, 10);
+        self
+    }
+
+    /// 设置自定义颜色刻度，从 0 值到最大值排列，同时会将等级数量设置为颜色数量
+    pub fn colors<T: Into<String>>(mut self, colors: Vec<T>) -> Self {
+        self.colors = colors.into_iter().map(Into::into).collect();
+        if self.colors.len() >= 2 {
+            self.levels = self.colors.len().min(10) as u8;
+        }
+        self
+    }
+
+    /// 设置是否显示颜色图例
+    #[prop(default = "true")]
+    pub fn show_legend(mut self, show: bool) -> Self {
+        self.show_legend = show;
+        self
+    }
+
+    /// 设置是否显示星期标签
+    #[prop(default = "true")]
+    pub fn show_weekday(mut self, show: bool) -> Self {
+        self.show_weekday = show;
+        self
+    }
+
+    /// 设置是否显示月份标签
+    #[prop(default = "true")]
+    pub fn show_month(mut self, show: bool) -> Self {
+        self.show_month = show;
+        self
+    }
+
+    /// 设置自定义提示文本
+    pub fn tooltip(mut self, f: impl FnMut((NaiveDate, u32)) -> String + 'static) -> Self {
+        self.tooltip = Some(Callback::new(f));
+        self
+    }
+
+    /// 设置格子点击事件
+    pub fn oncell_click(mut self, handler: impl FnMut((NaiveDate, u32)) + 'static) -> Self {
+        self.oncell_click = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置格子点击事件
+    #[prop(skip)]
+    pub fn oncell_click2(mut self, handler: EventHandler<(NaiveDate, u32)>) -> Self {
+        self.oncell_click = Some(handler);
+        self
+    }
+}
+
+impl CalendarHeatmap {
+    /// 范围内的最大值
+    fn max_value(&self) -> u32 {
+        self.data
+            .range(self.start..=self.end)
+            .map(|(_, v)| *v)
+            .max()
+            .unwrap_or(0)
+    }
+
+    /// 按周分组的日期列表，每周从周日开始，范围外的日期为 None
+    fn weeks(&self) -> Vec<[Option<NaiveDate>; 7]> {
+        let offset = self.start.weekday().num_days_from_sunday() as i64;
+        let mut cursor = self.start - Duration::days(offset);
+        let mut weeks = Vec::new();
+        while cursor <= self.end {
+            let mut week = [None; 7];
+            for (i, day) in week.iter_mut().enumerate() {
+                let date = cursor + Duration::days(i as i64);
+                if date >= self.start && date <= self.end {
+                    *day = Some(date);
+                }
+            }
+            weeks.push(week);
+            cursor += Duration::days(7);
+        }
+        weeks
+    }
+
+    /// 每一周对应的月份标签，仅在该月第一天所在的周显示
+    fn month_labels(weeks: &[[Option<NaiveDate>; 7]]) -> Vec<Option<&'static str>> {
+        weeks
+            .iter()
+            .enumerate()
+            .map(|(index, week)| {
+                week.iter().flatten().find_map(|date| {
+                    if date.day() == 1 || (index == 0 && date.day() <= 7) {
+                        Some(MONTH_LABELS[date.month0() as usize])
+                    } else {
+                        None
+                    }
+                })
+            })
+            .collect()
+    }
+
+    /// 等级对应的内联背景色
+    fn level_style(&self, level: u8) -> Option<String> {
+        self.colors
+            .get(level as usize)
+            .map(|color| format!("background-color: {};", color))
+    }
+}
+
+/// 计算数值对应的颜色等级
+///
+/// 0 值固定为 0 级，其余数值按与最大值的比例映射到 1..levels-1 级。
+fn level_of(value: u32, max: u32, levels: u8) -> u8 {
+    if value == 0 || max == 0 || levels < 2 {
+        return 0;
+    }
+    let steps = (levels - 1) as u32;
+    let level = (value as u64 * steps as u64).div_ceil(max as u64) as u32;
+    level.clamp(1, steps) as u8
+}
+
+impl ToElement for CalendarHeatmap {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let oncell_click = self.oncell_click;
+        let tooltip = self.tooltip;
+
+        let max = self.max_value();
+        let weeks = self.weeks();
+        let month_labels = Self::month_labels(&weeks);
+        let levels = self.levels;
+        let legend = (0..levels)
+            .map(|level| (level, self.level_style(level)))
+            .collect::<Vec<_>>();
+
+        let cells = weeks
+            .iter()
+            .map(|week| {
+                week.iter()
+                    .map(|day| {
+                        day.map(|date| {
+                            let value = self.data.get(&date).copied().unwrap_or(0);
+                            let level = level_of(value, max, levels);
+                            let title = match tooltip {
+                                Some(f) => f.call((date, value)),
+                                None => format!("{}: {}", date.format("%Y-%m-%d"), value),
+                            };
+                            (date, value, level, title, self.level_style(level))
+                        })
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::CALENDAR_HEATMAP_BODY,
+                    if self.show_weekday {
+                        div { class: classnames::CALENDAR_HEATMAP_WEEKDAYS,
+                            if self.show_month {
+                                span { class: classnames::CALENDAR_HEATMAP_MONTH_PLACEHOLDER }
+                            }
+                            for (index , label) in WEEKDAY_LABELS.iter().enumerate() {
+                                span { class: classnames::CALENDAR_HEATMAP_WEEKDAY,
+                                    if index % 2 == 1 {
+                                        "{label}"
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    div { class: classnames::CALENDAR_HEATMAP_GRID,
+                        for (week , label) in cells.into_iter().zip(month_labels) {
+                            div { class: classnames::CALENDAR_HEATMAP_WEEK,
+                                if self.show_month {
+                                    span { class: classnames::CALENDAR_HEATMAP_MONTH,
+                                        if let Some(label) = label {
+                                            "{label}"
+                                        }
+                                    }
+                                }
+                                for cell in week {
+                                    if let Some((date , value , level , title , cell_style)) = cell {
+                                        div {
+                                            class: bem("calendar-heatmap", "cell", [format!("level-{level}").as_str()]),
+                                            style: cell_style,
+                                            title,
+                                            "data-date": "{date}",
+                                            "data-value": "{value}",
+                                            onclick: move |event: MouseEvent| {
+                                                event.stop_propagation();
+                                                if let Some(handler) = oncell_click {
+                                                    handler.call((date, value));
+                                                }
+                                            },
+                                        }
+                                    } else {
+                                        div { class: "{classnames::CALENDAR_HEATMAP_CELL} is-empty" }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                if self.show_legend {
+                    div { class: classnames::CALENDAR_HEATMAP_LEGEND,
+                        span { class: classnames::CALENDAR_HEATMAP_LEGEND_TEXT, "少" }
+                        for (level , legend_style) in legend {
+                            span {
+                                class: bem("calendar-heatmap", "cell", [format!("level-{level}").as_str()]),
+                                style: legend_style,
+                            }
+                        }
+                        span { class: classnames::CALENDAR_HEATMAP_LEGEND_TEXT, "多" }
+                    }
+                }
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_level_of() {
+        assert_eq!(level_of(0, 10, 5), 0);
+        assert_eq!(level_of(1, 10, 5), 1);
+        assert_eq!(level_of(5, 10, 5), 2);
+        assert_eq!(level_of(10, 10, 5), 4);
+        assert_eq!(level_of(3, 0, 5), 0);
+    }
+
+    #[test]
+    fn test_year_weeks() {
+        let heatmap = CalendarHeatmap::new().year(2025);
+        let weeks = heatmap.weeks();
+
+        // 2025-01-01 是周三，前三格为空
+        assert_eq!(weeks[0][0], None);
+        assert_eq!(weeks[0][3], Some(date(2025, 1, 1)));
+        assert_eq!(weeks.len(), 53);
+        assert_eq!(
+            weeks.iter().flatten().flatten().count(),
+            365,
+            "每一天都应出现一次"
+        );
+    }
+
+    #[test]
+    fn test_data_accumulates() {
+        let heatmap = CalendarHeatmap::new()
+            .year(2025)
+            .value(date(2025, 3, 1), 2)
+            .data(vec![(date(2025, 3, 1), 3), (date(2025, 3, 2), 1)]);
+
+        assert_eq!(heatmap.data.get(&date(2025, 3, 1)), Some(&5));
+        assert_eq!(heatmap.max_value(), 5);
+    }
+
+    #[test]
+    fn test_colors_set_levels() {
+        let heatmap = CalendarHeatmap::new().colors(vec!["#eee", "#9be9a8", "#216e39"]);
+        assert_eq!(heatmap.levels, 3);
+        assert_eq!(
+            heatmap.level_style(2),
+            Some("background-color: #216e39;".to_string())
+        );
+    }
+
+    #[test]
+    fn test_render() {
+        let mut dom = VirtualDom::new(|| {
+            CalendarHeatmap::new()
+                .range(date(2025, 1, 1), date(2025, 1, 31))
+                .value(date(2025, 1, 15), 4)
+                .to_element()
+        });
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-calendar-heatmap"));
+        assert!(html.contains("2025-01-15: 4"));
+        assert!(html.contains("t-calendar-heatmap__cell--level-4"));
+        assert!(html.contains("t-calendar-heatmap__legend"));
+    }
+}
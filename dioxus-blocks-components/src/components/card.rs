@@ -18,26 +18,29 @@
 //!         .to_element()
 //! }
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{Elevation, Style, classnames, traits::ToElement};
 
 /// 卡片阴影效果枚举
 ///
-/// 定义卡片在不同状态下的阴影效果
+/// 定义卡片在不同状态下的阴影效果，阴影使用 [`Elevation`] 的层级，随亮色、暗色主题变化。
 #[derive(Debug, Clone, PartialEq, Default)]
 pub enum CardShadow {
-    /// 始终显示阴影
+    /// 始终显示阴影，层级为 [`Elevation::Two`]
     #[default]
     Always,
-    /// 仅在悬停时显示阴影
+    /// 仅在悬停时显示 [`Elevation::Two`] 的阴影
     Hover,
     /// 从不显示阴影
     Never,
+    /// 始终显示指定层级的阴影
+    Elevation(Elevation),
 }
 
 impl CardShadow {
@@ -58,6 +61,25 @@ impl CardShadow {
             CardShadow::Always => "t-card-shadow-always",
             CardShadow::Hover => "t-card-shadow-hover",
             CardShadow::Never => "t-card-shadow-never",
+            CardShadow::Elevation(_) => "t-card-shadow-elevation",
+        }
+    }
+
+    /// 获取未悬停时的阴影层级
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::{CardShadow, Elevation};
+    /// assert_eq!(CardShadow::Always.elevation(), Elevation::Two);
+    /// assert_eq!(CardShadow::Hover.elevation(), Elevation::Zero);
+    /// assert_eq!(CardShadow::Elevation(Elevation::Four).elevation(), Elevation::Four);
+    /// ```
+    pub fn elevation(&self) -> Elevation {
+        match self {
+            CardShadow::Always => Elevation::Two,
+            CardShadow::Hover | CardShadow::Never => Elevation::Zero,
+            CardShadow::Elevation(elevation) => *elevation,
         }
     }
 }
@@ -70,7 +92,7 @@ pub struct Card {
     /// 卡片的唯一标识符
     id: Option<String>,
     /// 卡片的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 卡片的内联样式
     style: Option<Style>,
     /// 卡片的子元素列表
@@ -90,13 +112,15 @@ pub struct Card {
     header_divider: bool,
     /// 是否显示边框
     border: bool,
+    /// 是否启用打印样式
+    print_friendly: bool,
 }
 
 impl Default for Card {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-card".to_string(),
+            class: Cow::Borrowed(classnames::CARD),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -105,6 +129,7 @@ impl Default for Card {
             shadow: CardShadow::default(),
             header_divider: true,
             border: false,
+            print_friendly: false,
         }
     }
 }
@@ -112,13 +137,23 @@ impl Default for Card {
 impl ToElement for Card {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let mut class = self.class.clone();
-        let style = self.style.clone().map(|s| s.to_string());
+        let mut class = self.class.to_string();
+        // 指定层级的阴影通过内联样式设置，用户设置的样式优先
+        let style = match &self.shadow {
+            CardShadow::Elevation(elevation) => Some(
+                Style::default()
+                    .elevation(*elevation)
+                    .merge(self.style.clone().unwrap_or_default())
+                    .to_string(),
+            ),
+            _ => self.style.clone().map(|s| s.to_string()),
+        };
         let onclick_handler = self.onclick;
         let childrens = self.childrens_to_element();
 
         // 添加阴影效果
-        class.push_str(&format!(" {}", self.shadow.as_class()));
+        class.push(' ');
+        class.push_str(self.shadow.as_class());
 
         // 添加边框
         if self.border {
@@ -127,6 +162,12 @@ impl ToElement for Card {
             class.push_str(" t-card-no-border");
         }
 
+        // 添加打印样式
+        if self.print_friendly {
+            class.push(' ');
+            class.push_str(classnames::CARD_PRINT_FRIENDLY);
+        }
+
         rsx! {
             div {
                 id,
@@ -140,23 +181,24 @@ impl ToElement for Card {
 
                 // Header section
                 if let Some(header) = &self.header {
-                    div { class: if self.header_divider { "t-card-header t-card-header-divider" } else { "t-card-header" },
+                    div { class: if self.header_divider { format!("{} {}", classnames::CARD_HEADER, classnames::CARD_HEADER_DIVIDER) } else { classnames::CARD_HEADER.to_string() },
                         {header.to_element()}
                     }
                 }
 
                 // Body section
-                div { class: "t-card-body", {childrens} }
+                div { class: classnames::CARD_BODY, {childrens} }
 
                 // Footer section
                 if let Some(footer) = &self.footer {
-                    div { class: "t-card-footer", {footer.to_element()} }
+                    div { class: classnames::CARD_FOOTER, {footer.to_element()} }
                 }
             }
         }
     }
 }
 
+#[builder_props]
 impl Card {
     /// 创建一个新的卡片实例
     ///
@@ -261,7 +303,8 @@ impl Card {
     ///
     /// # 参数
     ///
-    /// * `shadow` - CardShadow 枚举值：Always（始终显示）、Hover（悬停时显示）或 Never（从不显示）
+    /// * `shadow` - CardShadow 枚举值：Always（始终显示）、Hover（悬停时显示）、Never（从不显示）
+    ///   或 Elevation（始终显示指定层级的阴影）
     ///
     /// # 返回值
     ///
@@ -273,6 +316,12 @@ impl Card {
     /// # use dioxus_blocks_components::{Card, CardShadow};
     /// Card::new().shadow(CardShadow::Hover);
     /// ```
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::{Card, CardShadow, Elevation};
+    /// Card::new().shadow(CardShadow::Elevation(Elevation::Four));
+    /// ```
+    #[prop(default = "CardShadow::Always")]
     pub fn shadow(mut self, shadow: CardShadow) -> Self {
         self.shadow = shadow.clone();
         self
@@ -294,6 +343,7 @@ impl Card {
     /// # use dioxus_blocks_components::Card;
     /// Card::new().border(false);
     /// ```
+    #[prop(default = "false")]
     pub fn border(mut self, border: bool) -> Self {
         self.border = border;
         self
@@ -315,8 +365,42 @@ impl Card {
     /// # use dioxus_blocks_components::Card;
     /// Card::new().header_divider(false);
     /// ```
+    #[prop(default = "true")]
     pub fn header_divider(mut self, divider: bool) -> Self {
         self.header_divider = divider;
         self
     }
+
+    /// 设置是否启用打印样式
+    ///
+    /// 启用后打印时去掉阴影和背景色，并避免卡片被分页截断，适合需要通过
+    /// [`export_to_pdf`](crate::export_to_pdf) 导出的报表。只影响打印，不改变屏幕上的显示。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Card;
+    /// Card::new().id("report").print_friendly(true);
+    /// ```
+    #[prop(default = "false")]
+    pub fn print_friendly(mut self, print_friendly: bool) -> Self {
+        self.print_friendly = print_friendly;
+        self
+    }
+
+    /// 设置卡片的锚点，id 由标题通过 [`anchor_id`](crate::anchor_id) 生成
+    ///
+    /// 同一标题始终得到相同的 id，地址中带有对应锚点（如 `/button#不同尺寸`）时，
+    /// [`Outlet`](crate::Outlet) 在页面加载后滚动到该卡片。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Card;
+    /// Card::new().anchor("Sizes");
+    /// ```
+    pub fn anchor(mut self, title: &str) -> Self {
+        self.id = Some(crate::anchor_id(title));
+        self
+    }
 }
@@ -12,71 +12,111 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Checkbox, CheckboxGroup, CheckboxValue};
+//! use dioxus_blocks_components::{Checkbox, CheckboxGroup, CheckboxValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut checkbox = use_signal(|| vec![CheckboxValue::String("Option 1".to_string())]);
-//! rsx! {
-//!     CheckboxGroup { value: checkbox, onchange: move |v| checkbox.set(v),
-//!         Checkbox { value: "Option 1", "Option 1" }
-//!         Checkbox { value: "Option 2", "Option 2" }
-//!         Checkbox { value: "Option 3", "Option 3" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut checkbox = use_signal(|| vec![CheckboxValue::from("Option 1")]);
+//!     CheckboxGroup::new()
+//!         .value(checkbox)
+//!         .onchange(move |v| checkbox.set(v))
+//!         .checkbox(Checkbox::new().value("Option 1").label("Option 1"))
+//!         .checkbox(Checkbox::new().value("Option 2").label("Option 2"))
+//!         .checkbox(Checkbox::new().value("Option 3").label("Option 3"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 独立使用
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Checkbox, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let agreed = use_signal(|| false);
+//!     Checkbox::new()
+//!         .label("我已阅读并同意服务条款")
+//!         .checked(agreed)
+//!         .onchange_checked(|checked| println!("同意：{checked}"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 //!
 //! ## 禁用状态
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Checkbox, CheckboxGroup};
+//! use dioxus_blocks_components::{Checkbox, CheckboxGroup, CheckboxValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut checkbox = use_signal(|| vec![1]);
-//! rsx! {
-//!     CheckboxGroup { value: checkbox, onchange: move |v| checkbox.set(v),
-//!         Checkbox { value: 1, disabled: true, "Option A" }
-//!         Checkbox { value: 2, disabled: true, "Option B" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut checkbox = use_signal(|| vec![CheckboxValue::from(1)]);
+//!     CheckboxGroup::new()
+//!         .value(checkbox)
+//!         .onchange(move |v| checkbox.set(v))
+//!         .checkbox(Checkbox::new().value(1).disabled(true).label("Option A"))
+//!         .checkbox(Checkbox::new().value(2).disabled(true).label("Option B"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 //!
 //! ## 按钮样式
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Checkbox, CheckboxGroup};
+//! use dioxus_blocks_components::{Checkbox, CheckboxGroup, CheckboxValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut checkbox = use_signal(|| vec!["Shanghai"]);
-//! rsx! {
-//!     CheckboxGroup { value: checkbox, onchange: move |v| checkbox.set(v),
-//!         Checkbox { value: "Shanghai", button: true, "Shanghai" }
-//!         Checkbox { value: "Beijing", button: true, "Beijing" }
-//!         Checkbox { value: "Guangzhou", button: true, "Guangzhou" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut checkbox = use_signal(|| vec![CheckboxValue::from("Shanghai")]);
+//!     CheckboxGroup::new()
+//!         .value(checkbox)
+//!         .onchange(move |v| checkbox.set(v))
+//!         .checkbox(Checkbox::new().value("Shanghai").button(true).label("Shanghai"))
+//!         .checkbox(Checkbox::new().value("Beijing").button(true).label("Beijing"))
+//!         .checkbox(Checkbox::new().value("Guangzhou").button(true).label("Guangzhou"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 //!
 //! ## 带有边框
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Checkbox, CheckboxGroup};
+//! use dioxus_blocks_components::{Checkbox, CheckboxGroup, CheckboxValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut checkbox = use_signal(|| vec![1]);
-//! rsx! {
-//!     CheckboxGroup { value: checkbox, onchange: move |v| checkbox.set(v),
-//!         Checkbox { value: 1, border: true, "Option A" }
-//!         Checkbox { value: 2, border: true, "Option B" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut checkbox = use_signal(|| vec![CheckboxValue::from(1)]);
+//!     CheckboxGroup::new()
+//!         .value(checkbox)
+//!         .onchange(move |v| checkbox.set(v))
+//!         .checkbox(Checkbox::new().value(1).border(true).label("Option A"))
+//!         .checkbox(Checkbox::new().value(2).border(true).label("Option B"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, Text, traits::ToElement};
+use crate::{Style, Text, classnames, traits::ToElement, use_unique_id};
 
 /// 多选框尺寸枚举
 ///
@@ -92,16 +132,23 @@ pub enum CheckboxSize {
     Large,
 }
 
-impl std::fmt::Display for CheckboxSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl CheckboxSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            CheckboxSize::Medium => write!(f, ""),
-            CheckboxSize::Small => write!(f, "t-checkbox--small"),
-            CheckboxSize::Large => write!(f, "t-checkbox--large"),
+            CheckboxSize::Medium => "",
+            CheckboxSize::Small => "t-checkbox--small",
+            CheckboxSize::Large => "t-checkbox--large",
         }
     }
 }
 
+impl std::fmt::Display for CheckboxSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 多选框值枚举
 ///
 /// 支持多种类型的值。
@@ -167,7 +214,7 @@ pub struct Checkbox {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -181,8 +228,10 @@ pub struct Checkbox {
     checked_values: Option<Signal<Vec<CheckboxValue>>>,
     /// 独立使用时的选中状态（用于单选模式）
     checked_bool: Option<Signal<bool>>,
-    /// 值改变时的回调（用于 CheckboxGroup 中）
-    onchange: Option<EventHandler<CheckboxValue>>,
+    /// 值改变时的回调，参数为 (多选框的值, 点击后是否选中)
+    onchange: Option<EventHandler<(CheckboxValue, bool)>>,
+    /// 选中状态改变时的回调，参数为点击后是否选中
+    onchange_checked: Option<EventHandler<bool>>,
     /// 多选框尺寸
     size: CheckboxSize,
     /// 是否禁用
@@ -199,7 +248,7 @@ impl Default for Checkbox {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-checkbox".to_string(),
+            class: Cow::Borrowed(classnames::CHECKBOX),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -207,6 +256,7 @@ impl Default for Checkbox {
             checked_values: None,
             checked_bool: None,
             onchange: None,
+            onchange_checked: None,
             size: CheckboxSize::Medium,
             disabled: false,
             border: false,
@@ -216,6 +266,7 @@ impl Default for Checkbox {
     }
 }
 
+#[builder_props]
 impl Checkbox {
     /// 创建一个新的多选框实例
     pub fn new() -> Self {
@@ -257,43 +308,57 @@ impl Checkbox {
         self
     }
 
-    /// 设置值改变回调（CheckboxGroup 内部使用）
-    pub fn onchange(mut self, handler: impl FnMut(CheckboxValue) + 'static) -> Self {
-        self.onchange = Some(EventHandler::new(handler));
+    /// 设置值改变回调，参数为多选框的值和点击后是否选中
+    pub fn onchange(mut self, mut handler: impl FnMut(CheckboxValue, bool) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(move |(value, checked)| {
+            handler(value, checked)
+        }));
         self
     }
 
-    /// 设置值改变事件
-    pub fn onchange2(mut self, handler: EventHandler<CheckboxValue>) -> Self {
+    /// 设置值改变事件，参数为 (多选框的值, 点击后是否选中)
+    #[prop(skip)]
+    pub fn onchange2(mut self, handler: EventHandler<(CheckboxValue, bool)>) -> Self {
         self.onchange = Some(handler);
         self
     }
 
+    /// 设置选中状态改变回调，参数为点击后是否选中，适合独立使用的多选框
+    pub fn onchange_checked(mut self, handler: impl FnMut(bool) + 'static) -> Self {
+        self.onchange_checked = Some(EventHandler::new(handler));
+        self
+    }
+
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置多选框尺寸
+    #[prop(default = "CheckboxSize::Medium")]
     pub fn size(mut self, size: CheckboxSize) -> Self {
         self.size = size;
         self
     }
 
     /// 设置是否显示边框
+    #[prop(default = "false")]
     pub fn border(mut self, border: bool) -> Self {
         self.border = border;
         self
     }
 
     /// 设置是否使用按钮样式
+    #[prop(default = "false")]
     pub fn button(mut self, button: bool) -> Self {
         self.button = button;
         self
     }
 
     /// 设置中间状态
+    #[prop(default = "false")]
     pub fn indeterminate(mut self, indeterminate: bool) -> Self {
         self.indeterminate = indeterminate;
         self
@@ -310,9 +375,10 @@ impl ToElement for Checkbox {
         // 判断是否选中 - 支持两种模式
         // 模式1: CheckboxGroup 中，使用 checked_values (Vec<CheckboxValue>)
         // 模式2: 独立使用，使用 checked_bool (bool)
-        let checked_values_signal = self.checked_values.clone();
-        let checked_bool_signal = self.checked_bool.clone();
+        let checked_values_signal = self.checked_values;
+        let checked_bool_signal = self.checked_bool;
         let item_value_for_check = item_value.clone();
+        let input_id = use_unique_id("checkbox");
         let is_checked = use_memo(move || {
             // 优先使用 checked_values (CheckboxGroup 模式)
             if let Some(signal) = &checked_values_signal {
@@ -328,32 +394,32 @@ impl ToElement for Checkbox {
         });
 
         // 计算样式类名
-        let mut class_names = vec![self.class.clone()];
+        let mut class_names = vec![&*self.class];
 
         if self.button {
-            class_names.push("t-checkbox--button".to_string());
+            class_names.push(classnames::CHECKBOX_BUTTON);
         }
 
         // 添加尺寸类名
-        let size_class = self.size.to_string();
+        let size_class = self.size.as_class();
         if !size_class.is_empty() {
             class_names.push(size_class);
         }
 
         if self.border {
-            class_names.push("is-bordered".to_string());
+            class_names.push("is-bordered");
         }
 
         if *is_checked.read() {
-            class_names.push("is-checked".to_string());
+            class_names.push("is-checked");
         }
 
         if self.disabled {
-            class_names.push("is-disabled".to_string());
+            class_names.push("is-disabled");
         }
 
         if self.indeterminate {
-            class_names.push("is-indeterminate".to_string());
+            class_names.push("is-indeterminate");
         }
 
         let class = class_names.join(" ");
@@ -366,11 +432,12 @@ impl ToElement for Checkbox {
 
         let disabled = self.disabled;
         let onchange_handler = self.onchange;
+        let onchange_checked_handler = self.onchange_checked;
         let item_value_for_onchange = item_value.clone();
         let item_value_for_input = item_value.to_string();
         let onclick_custom = self.onclick;
-        let checked_values_signal_for_onclick = self.checked_values.clone();
-        let checked_bool_signal_for_onclick = self.checked_bool.clone();
+        let checked_values_signal_for_onclick = self.checked_values;
+        let checked_bool_signal_for_onclick = self.checked_bool;
         let _indeterminate = self.indeterminate;
 
         // 点击事件
@@ -378,6 +445,7 @@ impl ToElement for Checkbox {
             if disabled {
                 return;
             }
+            let now_checked = !*is_checked.peek();
 
             // 更新 checked_values（如果在 CheckboxGroup 中）
             if let Some(mut signal) = checked_values_signal_for_onclick {
@@ -398,7 +466,10 @@ impl ToElement for Checkbox {
 
             // 触发 onchange 回调
             if let Some(handler) = &onchange_handler {
-                handler.call(item_value_for_onchange.clone());
+                handler.call((item_value_for_onchange.clone(), now_checked));
+            }
+            if let Some(handler) = &onchange_checked_handler {
+                handler.call(now_checked);
             }
 
             // 触发自定义 onclick
@@ -411,10 +482,11 @@ impl ToElement for Checkbox {
         let childrens = self.childrens_to_element();
 
         rsx! {
-            label { id, class, style: style_str,
-                span { class: "t-checkbox__input",
-                    span { class: "t-checkbox__inner" }
+            label { id, class, style: style_str, r#for: "{input_id}",
+                span { class: classnames::CHECKBOX_INPUT,
+                    span { class: classnames::CHECKBOX_INNER }
                     input {
+                        id: "{input_id}",
                         r#type: "checkbox",
                         value: item_value_for_input,
                         checked: *is_checked.read(),
@@ -422,7 +494,7 @@ impl ToElement for Checkbox {
                         onclick,
                     }
                 }
-                span { class: if self.button { "t-checkbox__button" } else { "t-checkbox__label" }, {childrens} }
+                span { class: if self.button { classnames::CHECKBOX_BUTTON_LABEL } else { classnames::CHECKBOX_LABEL }, {childrens} }
             }
         }
     }
@@ -434,7 +506,7 @@ pub struct CheckboxGroup {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -454,6 +526,8 @@ pub struct CheckboxGroup {
     min: Option<usize>,
     /// 最大可选数量
     max: Option<usize>,
+    /// 全选框的标签，设置后在选项前显示全选框
+    check_all: Option<String>,
     /// 绑定值变化时触发的事件
     onchange: Option<EventHandler<Vec<CheckboxValue>>>,
 }
@@ -462,7 +536,7 @@ impl Default for CheckboxGroup {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-checkbox-group".to_string(),
+            class: Cow::Borrowed(classnames::CHECKBOX_GROUP),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -472,11 +546,13 @@ impl Default for CheckboxGroup {
             size: CheckboxSize::default(),
             min: None,
             max: None,
+            check_all: None,
             onchange: None,
         }
     }
 }
 
+#[builder_props]
 impl CheckboxGroup {
     /// 创建一个新的多选框组实例
     pub fn new() -> Self {
@@ -497,6 +573,20 @@ impl CheckboxGroup {
         self
     }
 
+    /// 由数据生成多选框，闭包返回每一项的值和标签元素，生成的多选框追加到已有的多选框之后
+    pub fn options<I, V, T>(mut self, items: I, mut option: impl FnMut(I::Item) -> (V, T)) -> Self
+    where
+        I: IntoIterator,
+        V: Into<CheckboxValue>,
+        T: ToElement + Clone + 'static,
+    {
+        self.checkboxes.extend(items.into_iter().map(|item| {
+            let (value, label) = option(item);
+            Checkbox::new().value(value).label_element(label)
+        }));
+        self
+    }
+
     /// 设置当前值的 Signal（必需）
     pub fn value(mut self, value: Signal<Vec<CheckboxValue>>) -> Self {
         self.value = Some(value);
@@ -504,12 +594,14 @@ impl CheckboxGroup {
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置多选框尺寸
+    #[prop(default = "CheckboxSize::Medium")]
     pub fn size(mut self, size: CheckboxSize) -> Self {
         self.size = size;
         self
@@ -527,6 +619,15 @@ impl CheckboxGroup {
         self
     }
 
+    /// 在选项前显示全选框
+    ///
+    /// 全选框只作用于未禁用的选项：全部选中时为选中状态，部分选中时为中间状态；
+    /// 点击后选中全部或取消全部，禁用选项的状态保持不变，同时遵守 min/max 的限制。
+    pub fn with_check_all(mut self, label: impl Into<String>) -> Self {
+        self.check_all = Some(label.into());
+        self
+    }
+
     /// 设置值改变事件
     pub fn onchange(mut self, handler: impl FnMut(Vec<CheckboxValue>) + 'static) -> Self {
         self.onchange = Some(EventHandler::new(handler));
@@ -534,6 +635,7 @@ impl CheckboxGroup {
     }
 
     /// 设置值改变事件
+    #[prop(skip)]
     pub fn onchange2(mut self, handler: EventHandler<Vec<CheckboxValue>>) -> Self {
         self.onchange = Some(handler);
         self
@@ -565,16 +667,16 @@ impl ToElement for CheckboxGroup {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
 
-        let mut class_names = vec![self.class.clone(), self.size.to_string()];
+        let mut class_names = vec![&*self.class, self.size.as_class()];
         if self.disabled {
-            class_names.push("t-checkbox-group--disabled".to_string());
+            class_names.push(classnames::CHECKBOX_GROUP_DISABLED);
         }
         let class = class_names.join(" ");
 
         let style = self.style.clone().map(|s| s.to_string());
 
         // 获取 value signal，如果未设置则使用默认值
-        let value_signal = self
+        let mut value_signal = self
             .value
             .unwrap_or_else(|| Signal::new(Vec::<CheckboxValue>::new()));
         let disabled = self.disabled;
@@ -582,7 +684,31 @@ impl ToElement for CheckboxGroup {
         let onchange_handler = self.onchange;
         let min = self.min;
         let max = self.max;
-        let value_signal_for_check = value_signal.clone();
+
+        // 可以被全选框切换的选项
+        let enabled = self
+            .checkboxes
+            .iter()
+            .filter(|checkbox| !checkbox.disabled)
+            .map(|checkbox| checkbox.value.clone().unwrap_or_default())
+            .collect::<Vec<_>>();
+        let selected = enabled
+            .iter()
+            .filter(|value| value_signal.read().contains(value))
+            .count();
+        let all_checked = !enabled.is_empty() && selected == enabled.len();
+
+        // 全选框的选中状态跟随绑定值，绑定值在外部被修改时同样会同步
+        let mut check_all = use_signal(|| all_checked);
+        let enabled_for_sync = enabled.clone();
+        use_effect(move || {
+            let values = value_signal.read();
+            let all = !enabled_for_sync.is_empty()
+                && enabled_for_sync.iter().all(|value| values.contains(value));
+            if *check_all.peek() != all {
+                check_all.set(all);
+            }
+        });
 
         let checkboxes = self
             .checkboxes
@@ -591,45 +717,87 @@ impl ToElement for CheckboxGroup {
             .map(|checkbox: Checkbox| {
                 let old_disabled = checkbox.disabled;
 
-                let new_checkbox = checkbox
-                    .checked_values(value_signal.clone())
+                checkbox
+                    .checked_values(value_signal)
                     .disabled(old_disabled || disabled)
                     .size(size)
-                    .onchange(move |val| {
-                        // 检查 min/max 限制
-                        let current = value_signal_for_check.read().clone();
-
-                        // 如果是取消选中，检查最小限制
-                        if current.contains(&val) {
-                            if let Some(min_count) = min {
-                                if current.len() <= min_count {
-                                    // 不允许取消选中
-                                    return;
-                                }
-                            }
-                        }
-
-                        // 如果是选中，检查最大限制
-                        else {
-                            if let Some(max_count) = max {
-                                if current.len() >= max_count {
-                                    // 不允许选中
-                                    return;
-                                }
+                    .onchange(move |val, checked| {
+                        // 多选框在触发事件前已经更新了绑定值，超出 min/max 限制时撤销这次修改
+                        let current = value_signal.read().clone();
+                        if checked {
+                            if let Some(max_count) = max
+                                && current.len() > max_count
+                            {
+                                value_signal.write().retain(|v| v != &val);
+                                return;
                             }
+                        } else if let Some(min_count) = min
+                            && current.len() < min_count
+                        {
+                            value_signal.write().push(val);
+                            return;
                         }
 
                         // 触发 onchange 回调（传递完整列表）
                         if let Some(handler) = onchange_handler {
-                            handler.call(value_signal_for_check.read().clone());
+                            handler.call(current);
                         }
-                    });
-                new_checkbox
+                    })
             })
             .collect::<Vec<Checkbox>>();
 
+        let check_all_box = self.check_all.clone().map(|label| {
+            Checkbox::new()
+                .class(classnames::CHECKBOX_GROUP_ALL)
+                .label(label)
+                .checked(check_all)
+                .indeterminate(selected > 0 && !all_checked)
+                .disabled(disabled || enabled.is_empty())
+                .size(size)
+                .onchange(move |_, checked| {
+                    // 选中时补全未选中的选项，取消时移除全部可切换的选项
+                    let mut values = value_signal.read().clone();
+                    if checked {
+                        for value in &enabled {
+                            if max.is_some_and(|max_count| values.len() >= max_count) {
+                                break;
+                            }
+                            if !values.contains(value) {
+                                values.push(value.clone());
+                            }
+                        }
+                    } else {
+                        let keep = min.unwrap_or(0);
+                        let mut kept = values.len();
+                        values.retain(|value| {
+                            if kept > keep && enabled.contains(value) {
+                                kept -= 1;
+                                false
+                            } else {
+                                true
+                            }
+                        });
+                    }
+                    if *value_signal.peek() != values {
+                        value_signal.set(values.clone());
+                        if let Some(handler) = onchange_handler {
+                            handler.call(values);
+                        }
+                    }
+                    // 受 min/max 限制时可能无法全部切换，以实际的选中情况为准
+                    let all = !enabled.is_empty()
+                        && enabled
+                            .iter()
+                            .all(|value| value_signal.peek().contains(value));
+                    check_all.set(all);
+                })
+        });
+
         rsx! {
-            div { id, class, style,
+            div { id, class, style, role: "group",
+                if let Some(check_all_box) = check_all_box {
+                    {check_all_box.to_element()}
+                }
                 for checkbox in checkboxes.iter() {
                     {checkbox.to_element()}
                 }
@@ -637,3 +805,196 @@ impl ToElement for CheckboxGroup {
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::View;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_checkbox_group_click_updates_value() {
+        fn app() -> Element {
+            let value = use_signal(|| vec![CheckboxValue::from("a")]);
+            CheckboxGroup::new()
+                .value(value)
+                .checkboxes(vec![
+                    Checkbox::new().value("a").label("A"),
+                    Checkbox::new().value("b").label("B"),
+                ])
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let checked = |harness: &Harness| {
+            harness
+                .find_all_by_class(classnames::CHECKBOX)
+                .iter()
+                .map(|checkbox| checkbox.has_class("is-checked"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(checked(&harness), vec![true, false]);
+
+        let inputs = harness.find_all(|element| element.attr("type") == Some("checkbox"));
+        harness.click(&inputs[1]);
+        assert_eq!(checked(&harness), vec![true, true]);
+
+        harness.click(&inputs[0]);
+        assert_eq!(checked(&harness), vec![false, true]);
+    }
+
+    #[test]
+    fn test_checkbox_onchange_reports_checked_state() {
+        fn app() -> Element {
+            let checked = use_signal(|| false);
+            let mut events = use_signal(Vec::<(CheckboxValue, bool)>::new);
+            let mut states = use_signal(Vec::<bool>::new);
+            View::new()
+                .children(
+                    Checkbox::new()
+                        .value("agree")
+                        .label("同意")
+                        .checked(checked)
+                        .onchange(move |value, checked| events.write().push((value, checked)))
+                        .onchange_checked(move |checked| states.write().push(checked)),
+                )
+                .children(Text::span(format!("{:?}", events.read())))
+                .children(Text::span(format!("{:?}", states.read())))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let input = harness.find_by_attr("type", "checkbox").unwrap();
+        harness.click(&input);
+        harness.click(&input);
+        assert!(
+            harness
+                .find_by_text(r#"[(String("agree"), true), (String("agree"), false)]"#)
+                .is_some()
+        );
+        assert!(harness.find_by_text("[true, false]").is_some());
+    }
+
+    #[test]
+    fn test_checkbox_group_min_max() {
+        fn app() -> Element {
+            let value = use_signal(|| vec![CheckboxValue::from("a")]);
+            CheckboxGroup::new()
+                .value(value)
+                .min(1)
+                .max(2)
+                .checkboxes(vec![
+                    Checkbox::new().value("a").label("A"),
+                    Checkbox::new().value("b").label("B"),
+                    Checkbox::new().value("c").label("C"),
+                ])
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let checked = |harness: &Harness| {
+            harness
+                .find_all_by_class(classnames::CHECKBOX)
+                .iter()
+                .map(|checkbox| checkbox.has_class("is-checked"))
+                .collect::<Vec<_>>()
+        };
+
+        // 不能少于 min
+        let inputs = harness.find_all(|element| element.attr("type") == Some("checkbox"));
+        harness.click(&inputs[0]);
+        assert_eq!(checked(&harness), vec![true, false, false]);
+
+        // 不能多于 max
+        harness.click(&inputs[1]);
+        harness.click(&inputs[2]);
+        assert_eq!(checked(&harness), vec![true, true, false]);
+    }
+
+    #[test]
+    fn test_checkbox_group_check_all() {
+        fn app() -> Element {
+            let value = use_signal(|| vec![CheckboxValue::from("a"), CheckboxValue::from("c")]);
+            CheckboxGroup::new()
+                .value(value)
+                .with_check_all("全选")
+                .checkboxes(vec![
+                    Checkbox::new().value("a").label("A"),
+                    Checkbox::new().value("b").label("B"),
+                    Checkbox::new().value("c").label("C").disabled(true),
+                ])
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let state = |harness: &Harness| {
+            let all = harness
+                .find_by_class(classnames::CHECKBOX_GROUP_ALL)
+                .unwrap();
+            (
+                all.has_class("is-checked"),
+                all.has_class("is-indeterminate"),
+            )
+        };
+        let checked = |harness: &Harness| {
+            harness
+                .find_all_by_class(classnames::CHECKBOX)
+                .iter()
+                .skip(1)
+                .map(|checkbox| checkbox.has_class("is-checked"))
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(state(&harness), (false, true));
+
+        // 全选只作用于未禁用的选项
+        let inputs = harness.find_all(|element| element.attr("type") == Some("checkbox"));
+        harness.click(&inputs[0]);
+        assert_eq!(state(&harness), (true, false));
+        assert_eq!(checked(&harness), vec![true, true, true]);
+
+        harness.click(&inputs[0]);
+        assert_eq!(state(&harness), (false, false));
+        assert_eq!(checked(&harness), vec![false, false, true]);
+
+        // 逐个选中后全选框同步为选中状态
+        harness.click(&inputs[1]);
+        assert_eq!(state(&harness), (false, true));
+        harness.click(&inputs[2]);
+        assert_eq!(state(&harness), (true, false));
+    }
+
+    #[test]
+    fn test_checkbox_group_options() {
+        struct Fruit {
+            id: i64,
+            name: &'static str,
+        }
+
+        fn app() -> Element {
+            let value = use_signal(|| vec![CheckboxValue::from(2)]);
+            let fruits = vec![
+                Fruit {
+                    id: 1,
+                    name: "苹果",
+                },
+                Fruit {
+                    id: 2,
+                    name: "香蕉",
+                },
+            ];
+            CheckboxGroup::new()
+                .value(value)
+                .checkbox(Checkbox::new().value(0).label("全部"))
+                .options(fruits, |fruit| (fruit.id, Text::span(fruit.name)))
+                .to_element()
+        }
+
+        let harness = Harness::new(app);
+        let checkboxes = harness.find_all_by_class(classnames::CHECKBOX);
+        let labels = checkboxes.iter().map(|c| c.text()).collect::<Vec<_>>();
+        assert_eq!(labels, vec!["全部", "苹果", "香蕉"]);
+        assert!(checkboxes[2].has_class("is-checked"));
+        let inputs = harness.find_all(|element| element.attr("type") == Some("checkbox"));
+        assert_eq!(inputs[1].attr("value"), Some("1"));
+    }
+}
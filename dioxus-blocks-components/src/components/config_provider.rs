@@ -0,0 +1,447 @@
+//! ConfigProvider 全局配置组件
+//!
+//! 通过上下文为整个应用提供主题、组件密度、语言和通知等全局配置。
+//! 配置保存在 [`Settings`] 中，并以 `Signal<Settings>` 的形式共享，任意子组件都可以通过
+//! [`use_settings`] 读取或修改，修改后主题和密度会实时作用于 ConfigProvider 内的所有组件。
+//!
+//! 高对比度主题 [`Theme::HighContrast`] 加深文字、边框和主色，并通过 `--t-focus-ring-width` 等变量加粗焦点框。
+//! 系统开启“减少动态效果”或设置 [`Settings::reduced_motion`] 后，组件的过渡和动画缩短为瞬间完成，
+//! 浮层的离场动画同样立即结束。
+//!
+//! 设置主色或圆角后，ConfigProvider 通过内联的 CSS 变量覆盖默认的主题变量，主色的浅色变体
+//! 根据当前主题自动计算。ConfigProvider 可以嵌套，内层的配置只作用于内层的组件。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, ConfigProvider, Settings, Theme, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let settings = use_signal(|| Settings::default().theme(Theme::Dark));
+//!     ConfigProvider::new()
+//!         .settings(settings)
+//!         .children(Button::new().text("暗色主题按钮"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 在子组件中修改配置
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Density, ConfigProvider, ToElement, use_settings};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct DensityToggle;
+//!
+//! impl ToElement for DensityToggle {
+//!     fn to_element(&self) -> Element {
+//!         let mut settings = use_settings();
+//!         Button::new()
+//!             .text("紧凑模式")
+//!             .onclick(move |_| settings.write().density = Density::Compact)
+//!             .to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ConfigProvider::new()
+//!         .children(DensityToggle)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Color, Style, classnames, traits::ToElement};
+
+/// 主题枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Theme {
+    /// 亮色主题
+    #[default]
+    Light,
+    /// 暗色主题
+    Dark,
+    /// 高对比度主题，文字和边框接近纯黑，并加粗焦点框
+    HighContrast,
+}
+
+impl Theme {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            Theme::Light => "t-theme--light",
+            Theme::Dark => "t-theme--dark",
+            Theme::HighContrast => "t-theme--high-contrast",
+        }
+    }
+}
+
+impl std::fmt::Display for Theme {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+/// 组件密度枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Density {
+    /// 紧凑
+    Compact,
+    /// 默认
+    #[default]
+    Default,
+    /// 宽松
+    Comfortable,
+}
+
+impl Density {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            Density::Compact => "t-density--compact",
+            Density::Default => "t-density--default",
+            Density::Comfortable => "t-density--comfortable",
+        }
+    }
+}
+
+impl std::fmt::Display for Density {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+/// 表格的列布局，由用户在列设置中调整
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct TableLayout {
+    /// 列的显示顺序，元素为列的字段名
+    pub order: Vec<String>,
+    /// 隐藏的列的字段名
+    pub hidden: Vec<String>,
+}
+
+/// 全局配置
+#[derive(Debug, Clone, PartialEq)]
+pub struct Settings {
+    /// 主题
+    pub theme: Theme,
+    /// 组件密度
+    pub density: Density,
+    /// 语言，如 `zh-CN`、`en-US`
+    pub locale: String,
+    /// 是否开启通知
+    pub notifications: bool,
+    /// 通知提示音的地址，`None` 时不播放提示音
+    pub notification_sound: Option<String>,
+    /// 是否使用浏览器通知，需要用户授权，未授权时退回到应用内提示
+    pub browser_notifications: bool,
+    /// 最近搜索记录，键为搜索框的记录键，最近的搜索在前
+    pub recent_searches: BTreeMap<String, Vec<String>>,
+    /// 表格的列布局，键为表格的布局键
+    pub table_layouts: BTreeMap<String, TableLayout>,
+    /// Cookie 同意记录，键为类别，值为是否允许，`None` 表示用户尚未做出选择
+    pub consent: Option<BTreeMap<String, bool>>,
+    /// 主色，`None` 时使用样式表中的默认主色
+    pub primary_color: Option<Color>,
+    /// 组件圆角，单位为像素，`None` 时使用样式表中的默认圆角
+    pub radius: Option<u32>,
+    /// 是否减少动画，开启后与系统的“减少动态效果”设置一样跳过过渡和动画
+    pub reduced_motion: bool,
+}
+
+impl Default for Settings {
+    fn default() -> Self {
+        Self {
+            theme: Theme::default(),
+            density: Density::default(),
+            locale: "zh-CN".to_string(),
+            notifications: true,
+            notification_sound: None,
+            browser_notifications: false,
+            recent_searches: BTreeMap::new(),
+            table_layouts: BTreeMap::new(),
+            consent: None,
+            primary_color: None,
+            radius: None,
+            reduced_motion: false,
+        }
+    }
+}
+
+#[builder_props]
+impl Settings {
+    /// 设置主题
+    #[prop(default = "Theme::Light")]
+    pub fn theme(mut self, theme: Theme) -> Self {
+        self.theme = theme;
+        self
+    }
+
+    /// 设置组件密度
+    #[prop(default = "Density::Default")]
+    pub fn density(mut self, density: Density) -> Self {
+        self.density = density;
+        self
+    }
+
+    /// 设置语言
+    #[prop(default = "\"zh-CN\"")]
+    pub fn locale(mut self, locale: impl Into<String>) -> Self {
+        self.locale = locale.into();
+        self
+    }
+
+    /// 设置是否开启通知
+    #[prop(default = "true")]
+    pub fn notifications(mut self, notifications: bool) -> Self {
+        self.notifications = notifications;
+        self
+    }
+
+    /// 设置通知提示音的地址
+    pub fn notification_sound(mut self, url: impl Into<String>) -> Self {
+        self.notification_sound = Some(url.into());
+        self
+    }
+
+    /// 设置是否使用浏览器通知
+    #[prop(default = "false")]
+    pub fn browser_notifications(mut self, enabled: bool) -> Self {
+        self.browser_notifications = enabled;
+        self
+    }
+
+    /// 设置主色
+    pub fn primary_color(mut self, color: Color) -> Self {
+        self.primary_color = Some(color);
+        self
+    }
+
+    /// 设置组件圆角，单位为像素
+    pub fn radius(mut self, radius: u32) -> Self {
+        self.radius = Some(radius);
+        self
+    }
+
+    /// 设置是否减少动画，系统开启了减少动态效果时无论是否设置都会减少动画
+    #[prop(default = "false")]
+    pub fn reduced_motion(mut self, reduced_motion: bool) -> Self {
+        self.reduced_motion = reduced_motion;
+        self
+    }
+
+    /// 覆盖主题变量的内联样式，没有自定义主色和圆角时返回 `None`
+    fn theme_style(&self) -> Option<Style> {
+        let mut vars = Vec::new();
+        if let Some(primary) = self.primary_color {
+            // 浅色变体与页面背景色混合，暗色主题下变为深色
+            let background = match self.theme {
+                Theme::Light | Theme::HighContrast => Color::WHITE,
+                Theme::Dark => Color::rgb(20, 20, 20),
+            };
+            let light = |weight: f64| primary.mix(background, weight);
+            vars.push(format!("--t-color-primary: {primary}"));
+            vars.push(format!(
+                "--t-color-primary-dark: {}",
+                primary.mix(Color::BLACK, 0.1)
+            ));
+            vars.push(format!("--t-color-primary-border: {}", light(0.4)));
+            vars.push(format!("--t-color-primary-light: {}", light(0.9)));
+            for (level, weight) in [(9, 0.9), (8, 0.8), (7, 0.7), (6, 0.6)] {
+                vars.push(format!(
+                    "--t-color-primary-light-{level}: {}",
+                    light(weight)
+                ));
+            }
+        }
+        if let Some(radius) = self.radius {
+            vars.push(format!("--t-border-radius-base: {radius}px"));
+            vars.push(format!("--t-button-radius-default: {radius}px"));
+        }
+        (!vars.is_empty()).then(|| Style::new(vars.join("; ")))
+    }
+}
+
+/// 获取最近的 [`ConfigProvider`] 提供的全局配置
+///
+/// 必须在 ConfigProvider 的子组件中调用，否则会 panic。
+pub fn use_settings() -> Signal<Settings> {
+    use_context::<Signal<Settings>>()
+}
+
+/// ConfigProvider 全局配置组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct ConfigProvider {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 全局配置，未设置时使用默认配置
+    settings: Option<Signal<Settings>>,
+}
+
+impl Default for ConfigProvider {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CONFIG_PROVIDER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            settings: None,
+        }
+    }
+}
+
+#[builder_props]
+impl ConfigProvider {
+    /// 创建全局配置组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置全局配置
+    pub fn settings(mut self, settings: Signal<Settings>) -> Self {
+        self.settings = Some(settings);
+        self
+    }
+}
+
+impl ToElement for ConfigProvider {
+    fn to_element(&self) -> Element {
+        let initial = self.settings;
+        let settings = use_context_provider(move || {
+            initial.unwrap_or_else(|| Signal::new(Settings::default()))
+        });
+        let current = settings.read().clone();
+
+        let id = self.id.clone();
+        let mut class = vec![
+            &*self.class,
+            current.theme.as_class(),
+            current.density.as_class(),
+        ];
+        if current.reduced_motion {
+            class.push(classnames::MOTION_REDUCED);
+        }
+        let class = class.join(" ");
+        let style = match (current.theme_style(), self.style.clone()) {
+            (Some(theme), style) => Some(theme.merge(style.unwrap_or_default()).to_string()),
+            (None, style) => style.map(|s| s.to_string()),
+        };
+        let lang = current.locale.clone();
+        let onclick_handler = self.onclick;
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                lang,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_settings_classes() {
+        fn app() -> Element {
+            let settings = use_signal(|| {
+                Settings::default()
+                    .theme(Theme::Dark)
+                    .density(Density::Compact)
+                    .locale("en-US")
+            });
+            ConfigProvider::new().settings(settings).to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-config-provider t-theme--dark t-density--compact"));
+        assert!(html.contains("lang=\"en-US\""));
+    }
+
+    #[test]
+    fn test_render_accessibility_modes() {
+        fn app() -> Element {
+            let settings = use_signal(|| {
+                Settings::default()
+                    .theme(Theme::HighContrast)
+                    .reduced_motion(true)
+            });
+            ConfigProvider::new().settings(settings).to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains(
+            "t-config-provider t-theme--high-contrast t-density--default t-motion--reduced"
+        ));
+    }
+
+    #[test]
+    fn test_render_theme_variables() {
+        fn app() -> Element {
+            let settings = use_signal(|| {
+                Settings::default()
+                    .primary_color(Color::rgb(64, 158, 255))
+                    .radius(8)
+            });
+            ConfigProvider::new()
+                .settings(settings)
+                .style(|s| s.padding("8px"))
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("--t-color-primary: #409eff;"));
+        assert!(html.contains("--t-color-primary-light-9: #ecf5ff;"));
+        assert!(html.contains(
+            "--t-border-radius-base: 8px; --t-button-radius-default: 8px; padding: 8px;"
+        ));
+        assert!(Settings::default().theme_style().is_none());
+    }
+}
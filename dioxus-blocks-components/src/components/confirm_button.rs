@@ -0,0 +1,367 @@
+//! ConfirmButton 确认按钮组件
+//!
+//! 用于删除等危险操作的按钮：点击后原地切换为「取消」「确认」两个按钮，确认后执行异步操作并显示加载状态，
+//! 操作完成或在超时时间内没有确认时恢复为原来的按钮，省去为每个危险操作单独编写确认流程。
+//!
+//! 操作返回错误时通过 `onerror` 回调返回错误信息。无法等待定时器的环境（如服务端渲染）中不会自动恢复。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{ConfirmButton, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! async fn delete_post(id: u32) -> Result<(), String> {
+//!     println!("删除文章 {id}");
+//!     Ok(())
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ConfirmButton::new("删除")
+//!         .prompt("确定删除这篇文章？")
+//!         .confirm_text("删除")
+//!         .onconfirm(|| delete_post(1))
+//!         .onerror(|message: String| println!("删除失败：{message}"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::Display;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::{Button, ButtonSize, ButtonType, Style, classnames, traits::ToElement};
+
+/// 确认后执行的操作返回的 Future，错误已转换为字符串
+type ConfirmFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// 确认按钮的状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// 显示原来的按钮
+    Idle,
+    /// 等待确认
+    Confirming,
+    /// 正在执行操作
+    Running,
+}
+
+/// 确认按钮组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct ConfirmButton {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 按钮文本
+    text: String,
+    /// 按钮类型
+    btn_type: ButtonType,
+    /// 按钮尺寸，同时用于确认和取消按钮
+    size: ButtonSize,
+    /// 是否禁用
+    disabled: bool,
+    /// 等待确认时显示的提示文本
+    prompt: Option<String>,
+    /// 确认按钮文本
+    confirm_text: String,
+    /// 取消按钮文本
+    cancel_text: String,
+    /// 确认按钮类型
+    confirm_type: ButtonType,
+    /// 等待确认的超时时间，单位为毫秒，为 0 时不会自动恢复
+    timeout: u32,
+    /// 确认后执行的操作
+    onconfirm: Option<Callback<(), ConfirmFuture>>,
+    /// 操作失败的回调，参数为错误信息
+    onerror: Option<EventHandler<String>>,
+}
+
+impl Default for ConfirmButton {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CONFIRM_BUTTON),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            text: String::new(),
+            btn_type: ButtonType::Danger,
+            size: ButtonSize::default(),
+            disabled: false,
+            prompt: None,
+            confirm_text: "确认".to_string(),
+            cancel_text: "取消".to_string(),
+            confirm_type: ButtonType::Danger,
+            timeout: 3000,
+            onconfirm: None,
+            onerror: None,
+        }
+    }
+}
+
+#[builder_props]
+impl ConfirmButton {
+    /// 创建确认按钮，默认为危险按钮
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置按钮类型
+    #[prop(default = "ButtonType::Danger")]
+    pub fn btn_type(mut self, btn_type: ButtonType) -> Self {
+        self.btn_type = btn_type;
+        self
+    }
+
+    /// 设置按钮尺寸
+    #[prop(default = "ButtonSize::Medium")]
+    pub fn size(mut self, size: ButtonSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置是否禁用
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置等待确认时显示的提示文本
+    pub fn prompt(mut self, prompt: impl Into<String>) -> Self {
+        self.prompt = Some(prompt.into());
+        self
+    }
+
+    /// 设置确认按钮文本
+    #[prop(default = "\"确认\"")]
+    pub fn confirm_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_text = text.into();
+        self
+    }
+
+    /// 设置取消按钮文本
+    #[prop(default = "\"取消\"")]
+    pub fn cancel_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_text = text.into();
+        self
+    }
+
+    /// 设置确认按钮类型，默认为危险按钮
+    #[prop(default = "ButtonType::Danger")]
+    pub fn confirm_type(mut self, confirm_type: ButtonType) -> Self {
+        self.confirm_type = confirm_type;
+        self
+    }
+
+    /// 设置等待确认的超时时间，单位为毫秒，默认为 3000，为 0 时不会自动恢复
+    #[prop(default = "3000")]
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// 设置确认后执行的异步操作，错误类型只需实现 `Display`
+    pub fn onconfirm<F, Fut, E>(mut self, mut handler: F) -> Self
+    where
+        F: FnMut() -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display + 'static,
+    {
+        self.onconfirm = Some(Callback::new(move |()| {
+            let future = handler();
+            Box::pin(async move { future.await.map_err(|e| e.to_string()) }) as ConfirmFuture
+        }));
+        self
+    }
+
+    /// 设置操作失败的回调
+    pub fn onerror(mut self, handler: impl FnMut(String) + 'static) -> Self {
+        self.onerror = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for ConfirmButton {
+    fn to_element(&self) -> Element {
+        let mut phase = use_signal(|| Phase::Idle);
+        // 每次进入等待确认时递增，用于忽略过期的超时恢复
+        let mut round = use_signal(|| 0u32);
+
+        let timeout = self.timeout;
+        let onconfirm = self.onconfirm;
+        let onerror = self.onerror;
+
+        let ask = move |_| {
+            phase.set(Phase::Confirming);
+            round += 1;
+            let current = *round.peek();
+            if timeout > 0 {
+                spawn(async move {
+                    if sleep(timeout).await
+                        && *round.peek() == current
+                        && *phase.peek() == Phase::Confirming
+                    {
+                        phase.set(Phase::Idle);
+                    }
+                });
+            }
+        };
+        let confirm = move |_| {
+            let Some(onconfirm) = onconfirm else {
+                phase.set(Phase::Idle);
+                return;
+            };
+            phase.set(Phase::Running);
+            let future = onconfirm.call(());
+            spawn(async move {
+                let result = future.await;
+                phase.set(Phase::Idle);
+                if let (Err(message), Some(handler)) = (result, onerror) {
+                    handler.call(message);
+                }
+            });
+        };
+
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let content = match phase() {
+            Phase::Idle => Button::new()
+                .class(classnames::CONFIRM_BUTTON_TRIGGER)
+                .text(self.text.clone())
+                .btn_type(self.btn_type)
+                .size(self.size)
+                .disabled(self.disabled)
+                .onclick(ask)
+                .childrens2(self.childrens.clone())
+                .to_element(),
+            Phase::Confirming | Phase::Running => {
+                let running = phase() == Phase::Running;
+                let cancel = Button::new()
+                    .class(classnames::CONFIRM_BUTTON_CANCEL)
+                    .text(self.cancel_text.clone())
+                    .size(self.size)
+                    .disabled(running)
+                    .onclick(move |_| phase.set(Phase::Idle));
+                let ok = Button::new()
+                    .class(classnames::CONFIRM_BUTTON_CONFIRM)
+                    .text(self.confirm_text.clone())
+                    .btn_type(self.confirm_type)
+                    .size(self.size)
+                    .loading(running)
+                    .disabled(running)
+                    .onclick(confirm);
+                rsx! {
+                    if let Some(prompt) = &self.prompt {
+                        span { class: classnames::CONFIRM_BUTTON_PROMPT, "{prompt}" }
+                    }
+                    {cancel.to_element()}
+                    {ok.to_element()}
+                }
+            }
+        };
+
+        rsx! {
+            span {
+                id,
+                class,
+                style,
+                role: "group",
+                aria_busy: phase() == Phase::Running,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                {content}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_confirm_button_flow() {
+        fn app() -> Element {
+            let mut deleted = use_signal(|| 0);
+            let mut error = use_signal(String::new);
+            rsx! {
+                {ConfirmButton::new("删除")
+                    .prompt("确定删除？")
+                    .onconfirm(move || async move {
+                        deleted += 1;
+                        if deleted() > 1 { Err("已经删除") } else { Ok(()) }
+                    })
+                    .onerror(move |message| error.set(message))
+                    .to_element()}
+                span { class: "deleted", "{deleted}" }
+                span { class: "error", "{error}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let trigger = harness
+            .find_by_class(classnames::CONFIRM_BUTTON_TRIGGER)
+            .unwrap();
+        harness.click(&trigger);
+        assert!(harness.find_by_text("确定删除？").is_some());
+
+        // 取消后恢复为原来的按钮
+        let cancel = harness
+            .find_by_class(classnames::CONFIRM_BUTTON_CANCEL)
+            .unwrap();
+        harness.click(&cancel);
+        assert!(
+            harness
+                .find_by_class(classnames::CONFIRM_BUTTON_CONFIRM)
+                .is_none()
+        );
+        assert_eq!(harness.find_by_class("deleted").unwrap().text(), "0");
+
+        for expected in ["1", "2"] {
+            let trigger = harness
+                .find_by_class(classnames::CONFIRM_BUTTON_TRIGGER)
+                .unwrap();
+            harness.click(&trigger);
+            let confirm = harness
+                .find_by_class(classnames::CONFIRM_BUTTON_CONFIRM)
+                .unwrap();
+            harness.click(&confirm);
+            assert_eq!(harness.find_by_class("deleted").unwrap().text(), expected);
+            assert!(
+                harness
+                    .find_by_class(classnames::CONFIRM_BUTTON_TRIGGER)
+                    .is_some()
+            );
+        }
+        assert_eq!(harness.find_by_class("error").unwrap().text(), "已经删除");
+    }
+}
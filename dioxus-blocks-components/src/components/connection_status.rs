@@ -0,0 +1,349 @@
+//! ConnectionStatus 网络连接状态组件
+//!
+//! 监听网络连接状态：断开时在页面顶部显示可关闭的横幅，恢复连接后显示短暂的提示。
+//!
+//! 默认在浏览器中监听 `online`/`offline` 事件；桌面端等无法依赖浏览器事件的环境可以通过
+//! [`ConnectionStatus::ping`] 提供探测函数，组件会按 [`ConnectionStatus::ping_interval`] 定时调用。
+//!
+//! 连接状态通过上下文提供给子组件，业务代码使用 [`use_online`] 读取，
+//! 不在 ConnectionStatus 中调用时 `use_online` 会自行监听浏览器事件。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, ConnectionStatus, ToElement, use_online};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct SaveButton;
+//!
+//! impl ToElement for SaveButton {
+//!     fn to_element(&self) -> Element {
+//!         let online = use_online();
+//!         Button::new().text("保存").disabled(!online()).to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ConnectionStatus::new()
+//!         .ping(|| async { true })
+//!         .ping_interval(10_000)
+//!         .children(SaveButton)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::{Alert, Style, classnames, traits::ToElement};
+
+/// 连接探测函数，返回是否能连接到服务
+type Ping = Rc<dyn Fn() -> Pin<Box<dyn Future<Output = bool>>>>;
+
+/// 恢复连接提示的显示时长，单位为毫秒
+const TOAST_DURATION: u32 = 3000;
+
+/// 连接状态上下文
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct OnlineState {
+    online: Signal<bool>,
+}
+
+impl OnlineState {
+    /// 创建连接状态并开始监听，需要在 hook 中调用
+    fn watch(ping: Option<Ping>, interval: u32) -> Self {
+        let online = Signal::new(true);
+        spawn(async move {
+            match ping {
+                Some(ping) => poll(ping, interval, online).await,
+                None => listen(online).await,
+            }
+        });
+        Self { online }
+    }
+}
+
+/// 在浏览器中监听 `online`/`offline` 事件，非浏览器环境直接返回
+async fn listen(mut online: Signal<bool>) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    let mut eval = document::eval(
+        "const send = () => dioxus.send(navigator.onLine); \
+         window.addEventListener('online', send); \
+         window.addEventListener('offline', send); \
+         send(); \
+         await new Promise(() => {});",
+    );
+    while let Ok(value) = eval.recv::<bool>().await {
+        if *online.peek() != value {
+            online.set(value);
+        }
+    }
+}
+
+/// 定时调用探测函数，无法等待定时器的环境中只探测一次
+async fn poll(ping: Ping, interval: u32, mut online: Signal<bool>) {
+    loop {
+        let value = ping().await;
+        if *online.peek() != value {
+            online.set(value);
+        }
+        if !sleep(interval).await {
+            break;
+        }
+    }
+}
+
+/// 读取网络连接状态
+///
+/// 在 [`ConnectionStatus`] 中调用时返回组件提供的状态，否则自行监听浏览器的 `online`/`offline` 事件。
+/// 无法获取状态的环境（如服务端渲染）中始终为 `true`。
+pub fn use_online() -> ReadSignal<bool> {
+    let state = use_hook(|| {
+        try_consume_context::<OnlineState>().unwrap_or_else(|| OnlineState::watch(None, 0))
+    });
+    state.online.into()
+}
+
+/// 网络连接状态组件
+#[derive(Clone, ComponentBase)]
+pub struct ConnectionStatus {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 连接探测函数，未设置时监听浏览器事件
+    ping: Option<Ping>,
+    /// 探测间隔，单位为毫秒
+    ping_interval: u32,
+    /// 断开连接时的横幅标题
+    offline_text: String,
+    /// 断开连接时的横幅描述
+    offline_description: Option<String>,
+    /// 恢复连接时的提示文本
+    online_text: String,
+}
+
+impl std::fmt::Debug for ConnectionStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("ConnectionStatus")
+            .field("id", &self.id)
+            .field("class", &self.class)
+            .field("ping", &self.ping.is_some())
+            .field("ping_interval", &self.ping_interval)
+            .field("offline_text", &self.offline_text)
+            .field("online_text", &self.online_text)
+            .finish_non_exhaustive()
+    }
+}
+
+impl Default for ConnectionStatus {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CONNECTION_STATUS),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            ping: None,
+            ping_interval: 5000,
+            offline_text: "网络连接已断开".to_string(),
+            offline_description: Some("部分功能暂时不可用，恢复连接后即可继续操作。".to_string()),
+            online_text: "网络已恢复".to_string(),
+        }
+    }
+}
+
+#[builder_props]
+impl ConnectionStatus {
+    /// 创建网络连接状态组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置连接探测函数，适用于桌面端等没有浏览器网络事件的环境
+    pub fn ping<F, Fut>(mut self, ping: F) -> Self
+    where
+        F: Fn() -> Fut + 'static,
+        Fut: Future<Output = bool> + 'static,
+    {
+        self.ping = Some(Rc::new(move || Box::pin(ping())));
+        self
+    }
+
+    /// 设置探测间隔，单位为毫秒，默认为 5000
+    #[prop(default = "5000")]
+    pub fn ping_interval(mut self, ms: u32) -> Self {
+        self.ping_interval = ms;
+        self
+    }
+
+    /// 设置断开连接时的横幅标题
+    #[prop(default = "\"网络连接已断开\"")]
+    pub fn offline_text(mut self, text: impl Into<String>) -> Self {
+        self.offline_text = text.into();
+        self
+    }
+
+    /// 设置断开连接时的横幅描述
+    pub fn offline_description(mut self, description: impl Into<String>) -> Self {
+        self.offline_description = Some(description.into());
+        self
+    }
+
+    /// 设置恢复连接时的提示文本
+    #[prop(default = "\"网络已恢复\"")]
+    pub fn online_text(mut self, text: impl Into<String>) -> Self {
+        self.online_text = text.into();
+        self
+    }
+}
+
+impl ToElement for ConnectionStatus {
+    fn to_element(&self) -> Element {
+        let ping = self.ping.clone();
+        let interval = self.ping_interval;
+        let state = use_context_provider(move || OnlineState::watch(ping, interval));
+        let online = state.online;
+        let mut banner = use_signal(|| false);
+        let mut toast = use_signal(|| false);
+
+        // 断开时显示横幅，恢复时收起横幅并短暂显示提示
+        let mut was_online = use_signal(|| true);
+        use_effect(move || {
+            let now = online();
+            if now == *was_online.peek() {
+                return;
+            }
+            was_online.set(now);
+            banner.set(!now);
+            toast.set(now);
+            if now {
+                spawn(async move {
+                    if sleep(TOAST_DURATION).await {
+                        toast.set(false);
+                    }
+                });
+            }
+        });
+
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let mut alert = Alert::new(self.offline_text.clone())
+            .as_warning()
+            .closable(true)
+            .visible(banner);
+        if let Some(description) = &self.offline_description {
+            alert = alert.description(description.clone());
+        }
+        let online_text = self.online_text.clone();
+
+        rsx! {
+            if banner() || toast() {
+                div {
+                    id,
+                    class,
+                    style,
+                    onclick: move |event: MouseEvent| {
+                        if let Some(handler) = onclick_handler {
+                            handler.call(event);
+                        }
+                    },
+                    if banner() {
+                        div { class: classnames::CONNECTION_STATUS_BANNER, {alert.to_element()} }
+                    }
+                    if toast() {
+                        div {
+                            class: classnames::CONNECTION_STATUS_TOAST,
+                            role: "status",
+                            onclick: move |_| toast.set(false),
+                            "{online_text}"
+                        }
+                    }
+                }
+            }
+            {self.childrens_to_element()}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Text, View};
+
+    /// 直接修改上下文中的连接状态，模拟网络事件
+    #[derive(Debug, Clone)]
+    struct Network;
+
+    impl ToElement for Network {
+        fn to_element(&self) -> Element {
+            let mut state = use_context::<OnlineState>();
+            let online = use_online();
+            View::new()
+                .children(Text::span(if online() { "在线" } else { "离线" }))
+                .children(Text::span("断开").onclick(move |_| state.online.set(false)))
+                .children(Text::span("恢复").onclick(move |_| state.online.set(true)))
+                .to_element()
+        }
+    }
+
+    #[test]
+    fn test_connection_status_banner_and_toast() {
+        fn app() -> Element {
+            ConnectionStatus::new().children(Network).to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_text("在线").is_some());
+        assert!(
+            harness
+                .find_by_class(classnames::CONNECTION_STATUS)
+                .is_none()
+        );
+
+        harness.click(&harness.find_by_text("断开").unwrap());
+        assert!(harness.find_by_text("离线").is_some());
+        assert!(
+            harness
+                .find_by_class(classnames::CONNECTION_STATUS_BANNER)
+                .is_some()
+        );
+
+        // 关闭横幅后不再显示
+        harness.click(&harness.find_by_class(classnames::ALERT_CLOSE).unwrap());
+        assert!(
+            harness
+                .find_by_class(classnames::CONNECTION_STATUS_BANNER)
+                .is_none()
+        );
+
+        harness.click(&harness.find_by_text("恢复").unwrap());
+        assert!(harness.find_by_text("网络已恢复").is_some());
+    }
+}
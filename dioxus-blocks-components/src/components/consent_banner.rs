@@ -0,0 +1,372 @@
+//! ConsentBanner Cookie 同意横幅组件
+//!
+//! 固定在页面底部的横幅，提供「全部接受」「全部拒绝」和「自定义」三种操作，
+//! 自定义时按类别勾选允许的 Cookie，必需类别始终允许且不可取消。
+//!
+//! 用户的选择保存在 [`Settings::consent`] 中，做出选择后横幅不再显示，
+//! 把 `consent` 重置为 `None` 即可让用户重新选择。业务代码使用 [`use_consent`] 判断某个类别是否被允许，
+//! 例如只在允许统计类 Cookie 时加载统计脚本。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     ConfigProvider, ConsentBanner, ConsentCategory, Text, ToElement, use_consent,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct Analytics;
+//!
+//! impl ToElement for Analytics {
+//!     fn to_element(&self) -> Element {
+//!         let allowed = use_consent("analytics");
+//!         Text::span(if allowed() { "统计已开启" } else { "统计未开启" }).to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ConfigProvider::new()
+//!         .children(Analytics)
+//!         .children(
+//!             ConsentBanner::new()
+//!                 .category(ConsentCategory::new("necessary", "必需").required(true))
+//!                 .category(ConsentCategory::new("analytics", "统计").description("帮助我们了解网站的使用情况")),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{
+    Button, Checkbox, CheckboxValue, Settings, Style, Text, View, classnames, traits::ToElement,
+};
+
+/// Cookie 类别
+#[derive(Debug, Clone, PartialEq)]
+pub struct ConsentCategory {
+    /// 类别标识，用于 [`use_consent`]
+    key: String,
+    /// 名称
+    label: String,
+    /// 说明
+    description: Option<String>,
+    /// 是否为必需类别，必需类别始终允许
+    required: bool,
+}
+
+#[builder_props]
+impl ConsentCategory {
+    /// 创建 Cookie 类别
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            description: None,
+            required: false,
+        }
+    }
+
+    /// 设置说明
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 设置是否为必需类别
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+}
+
+/// 判断用户是否允许某个类别的 Cookie
+///
+/// 读取 [`ConfigProvider`](crate::ConfigProvider) 提供的 [`Settings::consent`]，
+/// 用户尚未做出选择或不在 ConfigProvider 中调用时返回 `false`。
+pub fn use_consent(category: impl Into<String>) -> Memo<bool> {
+    let category = use_hook(|| category.into());
+    let local_settings = use_signal(Settings::default);
+    let settings = try_consume_context::<Signal<Settings>>().unwrap_or(local_settings);
+    use_memo(move || {
+        settings
+            .read()
+            .consent
+            .as_ref()
+            .and_then(|consent| consent.get(&category).copied())
+            .unwrap_or(false)
+    })
+}
+
+/// Cookie 同意横幅组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct ConsentBanner {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在说明文字之后，如隐私政策链接
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// Cookie 类别
+    categories: Vec<ConsentCategory>,
+    /// 标题
+    title: String,
+    /// 说明
+    description: String,
+    /// 用户做出选择后的回调，参数为各类别是否允许
+    onchange: Option<EventHandler<BTreeMap<String, bool>>>,
+}
+
+impl Default for ConsentBanner {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CONSENT_BANNER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            categories: Vec::new(),
+            title: "我们使用 Cookie".to_string(),
+            description:
+                "我们使用 Cookie 来保证网站正常运行，并在您同意后用于统计分析和个性化内容。"
+                    .to_string(),
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl ConsentBanner {
+    /// 创建 Cookie 同意横幅
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加 Cookie 类别
+    pub fn category(mut self, category: ConsentCategory) -> Self {
+        self.categories.push(category);
+        self
+    }
+
+    /// 批量添加 Cookie 类别
+    pub fn categories(mut self, categories: impl IntoIterator<Item = ConsentCategory>) -> Self {
+        self.categories.extend(categories);
+        self
+    }
+
+    /// 设置标题
+    #[prop(default = "\"我们使用 Cookie\"")]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置说明
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// 设置用户做出选择后的回调
+    pub fn onchange(mut self, handler: impl FnMut(BTreeMap<String, bool>) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for ConsentBanner {
+    fn to_element(&self) -> Element {
+        let local_settings = use_signal(Settings::default);
+        let mut settings = try_consume_context::<Signal<Settings>>().unwrap_or(local_settings);
+        let mut customizing = use_signal(|| false);
+        let categories = self.categories.clone();
+        let required = categories
+            .iter()
+            .filter(|category| category.required)
+            .map(|category| CheckboxValue::from(category.key.as_str()))
+            .collect::<Vec<_>>();
+        let selected = use_signal(|| required);
+
+        let onchange = self.onchange;
+        let decide = use_callback(move |allow: Rc<dyn Fn(&ConsentCategory) -> bool>| {
+            let consent = categories
+                .iter()
+                .map(|category| (category.key.clone(), category.required || allow(category)))
+                .collect::<BTreeMap<_, _>>();
+            settings.write().consent = Some(consent.clone());
+            customizing.set(false);
+            if let Some(handler) = onchange {
+                handler.call(consent);
+            }
+        });
+
+        // 已经做出选择时不再显示
+        if settings.read().consent.is_some() {
+            return rsx! {};
+        }
+
+        let mut actions = View::new().class(classnames::CONSENT_BANNER_ACTIONS);
+        if customizing() {
+            actions = actions.children(Button::new().text("保存设置").as_primary().onclick(
+                move |_| {
+                    let selected = selected.peek().clone();
+                    decide.call(Rc::new(move |category: &ConsentCategory| {
+                        selected.contains(&CheckboxValue::from(category.key.as_str()))
+                    }));
+                },
+            ));
+        } else if !self.categories.is_empty() {
+            actions = actions.children(
+                Button::new()
+                    .text("自定义")
+                    .onclick(move |_| customizing.set(true)),
+            );
+        }
+        actions = actions
+            .children(
+                Button::new()
+                    .text("全部拒绝")
+                    .onclick(move |_| decide.call(Rc::new(|_: &ConsentCategory| false))),
+            )
+            .children(
+                Button::new()
+                    .text("全部接受")
+                    .as_primary()
+                    .onclick(move |_| decide.call(Rc::new(|_: &ConsentCategory| true))),
+            );
+
+        let mut panel = View::new().class(classnames::CONSENT_BANNER_CATEGORIES);
+        for category in &self.categories {
+            let mut item = View::new()
+                .class(classnames::CONSENT_BANNER_CATEGORY)
+                .children(
+                    Checkbox::new()
+                        .value(category.key.as_str())
+                        .label(category.label.clone())
+                        .checked_values(selected)
+                        .disabled(category.required),
+                );
+            if let Some(description) = &category.description {
+                item = item.children(
+                    Text::p(description.clone()).class(classnames::CONSENT_BANNER_CATEGORY_DESC),
+                );
+            }
+            panel = panel.children(item);
+        }
+
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let title = self.title.clone();
+        let description = self.description.clone();
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                role: "dialog",
+                aria_label: "{title}",
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::CONSENT_BANNER_BODY,
+                    div { class: classnames::CONSENT_BANNER_TITLE, "{title}" }
+                    div { class: classnames::CONSENT_BANNER_DESC,
+                        "{description}"
+                        {self.childrens_to_element()}
+                    }
+                    if customizing() {
+                        {panel.to_element()}
+                    }
+                }
+                {actions.to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigProvider;
+    use crate::testing::Harness;
+
+    #[derive(Debug, Clone)]
+    struct Analytics;
+
+    impl ToElement for Analytics {
+        fn to_element(&self) -> Element {
+            let allowed = use_consent("analytics");
+            Text::span(if allowed() {
+                "统计已开启"
+            } else {
+                "统计未开启"
+            })
+            .to_element()
+        }
+    }
+
+    fn app() -> Element {
+        ConfigProvider::new()
+            .children(Analytics)
+            .children(
+                ConsentBanner::new()
+                    .category(ConsentCategory::new("necessary", "必需").required(true))
+                    .category(ConsentCategory::new("analytics", "统计"))
+                    .category(ConsentCategory::new("marketing", "营销")),
+            )
+            .to_element()
+    }
+
+    #[test]
+    fn test_consent_banner_accept_and_reject() {
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_text("统计未开启").is_some());
+
+        harness.click(&harness.find_by_text("全部接受").unwrap());
+        assert!(harness.find_by_text("统计已开启").is_some());
+        assert!(harness.find_by_class(classnames::CONSENT_BANNER).is_none());
+
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("全部拒绝").unwrap());
+        assert!(harness.find_by_text("统计未开启").is_some());
+        assert!(harness.find_by_class(classnames::CONSENT_BANNER).is_none());
+    }
+
+    #[test]
+    fn test_consent_banner_customize() {
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("自定义").unwrap());
+        assert!(
+            harness
+                .find_by_class(classnames::CONSENT_BANNER_CATEGORIES)
+                .is_some()
+        );
+
+        harness.click(&harness.find_by_attr("value", "analytics").unwrap());
+        harness.click(&harness.find_by_text("保存设置").unwrap());
+        assert!(harness.find_by_text("统计已开启").is_some());
+        assert!(harness.find_by_class(classnames::CONSENT_BANNER).is_none());
+    }
+}
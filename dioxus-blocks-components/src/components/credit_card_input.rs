@@ -0,0 +1,684 @@
+//! CreditCardInput 银行卡输入组件
+//!
+//! 由卡号、有效期（MM/YY）和安全码三个输入框组成的复合输入组件，基于 [`InputMask`] 格式化输入。
+//! 卡号输入时会根据前缀识别卡组织，并按卡组织调整分组格式和安全码长度。
+//!
+//! # 组件模式
+//!
+//! CreditCardInput 是一个**受控组件**，通过 `Signal<CreditCardValue>` 传递结构化的值，
+//! 每个子字段的校验状态可以通过 [`CreditCardValue::validate`] 获取。
+//!
+//! 有效期早于当前月份时视为无效。[`CreditCardValue::validate`] 按本地时间判断，
+//! [`CreditCardValue::validate_at`] 可以指定当前的年份和月份。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{CreditCardInput, CreditCardValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let card = use_signal(CreditCardValue::default);
+//!     CreditCardInput::new()
+//!         .value(card)
+//!         .onchange(|value: CreditCardValue| {
+//!             if value.validate().is_valid() {
+//!                 println!("{} {}", value.brand.label(), value.last4());
+//!             }
+//!         })
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 卡组织识别和校验
+//!
+//! ```rust
+//! use dioxus_blocks_components::{CardBrand, CreditCardFieldState, CreditCardValue};
+//!
+//! assert_eq!(CardBrand::detect("3782 8224"), CardBrand::Amex);
+//! assert_eq!(CardBrand::Amex.mask().format("378282246310005"), "3782 822463 10005");
+//!
+//! let value = CreditCardValue::new("4242424242424242", "12/30", "123");
+//! assert_eq!(value.brand, CardBrand::Visa);
+//! assert!(value.validate_at(2026, 10).is_valid());
+//!
+//! // 已过期
+//! let value = CreditCardValue::new("4242424242424242", "01/20", "123");
+//! assert_eq!(value.validate_at(2026, 10).expiry, CreditCardFieldState::Invalid);
+//!
+//! let value = CreditCardValue::new("4242424242424241", "13/30", "12");
+//! let validation = value.validate_at(2026, 10);
+//! assert_eq!(validation.number, CreditCardFieldState::Invalid);
+//! assert_eq!(validation.expiry, CreditCardFieldState::Invalid);
+//! assert_eq!(validation.cvc, CreditCardFieldState::Incomplete);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use chrono::{Datelike, Local};
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Input, InputMask, InputSize, Style, classnames, traits::ToElement};
+
+/// 卡组织
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CardBrand {
+    /// Visa
+    Visa,
+    /// Mastercard
+    Mastercard,
+    /// American Express
+    Amex,
+    /// Discover
+    Discover,
+    /// JCB
+    Jcb,
+    /// 银联
+    UnionPay,
+    /// 未识别
+    #[default]
+    Unknown,
+}
+
+impl CardBrand {
+    /// 根据卡号前缀识别卡组织，非数字字符会被忽略
+    pub fn detect(number: &str) -> Self {
+        let digits = number
+            .chars()
+            .filter(char::is_ascii_digit)
+            .take(4)
+            .collect::<String>();
+        let prefix = |len: usize| digits.get(..len).and_then(|p| p.parse::<u32>().ok());
+
+        match (prefix(1), prefix(2), prefix(3), prefix(4)) {
+            (Some(4), ..) => CardBrand::Visa,
+            (_, Some(34 | 37), ..) => CardBrand::Amex,
+            (_, Some(51..=55), ..) | (.., Some(2221..=2720)) => CardBrand::Mastercard,
+            (_, Some(65), ..) | (_, _, Some(644..=649), _) | (.., Some(6011)) => {
+                CardBrand::Discover
+            }
+            (_, Some(62), ..) => CardBrand::UnionPay,
+            (.., Some(3528..=3589)) => CardBrand::Jcb,
+            _ => CardBrand::Unknown,
+        }
+    }
+
+    /// 卡组织名称
+    pub fn label(&self) -> &'static str {
+        match self {
+            CardBrand::Visa => "Visa",
+            CardBrand::Mastercard => "Mastercard",
+            CardBrand::Amex => "American Express",
+            CardBrand::Discover => "Discover",
+            CardBrand::Jcb => "JCB",
+            CardBrand::UnionPay => "银联",
+            CardBrand::Unknown => "",
+        }
+    }
+
+    /// 卡号的分组格式
+    pub fn mask(&self) -> InputMask {
+        match self {
+            CardBrand::Amex => InputMask::new("9999 999999 99999"),
+            CardBrand::Visa | CardBrand::Mastercard => InputMask::credit_card(),
+            _ => InputMask::new("9999 9999 9999 9999 999"),
+        }
+    }
+
+    /// 有效的卡号长度
+    pub fn lengths(&self) -> &'static [usize] {
+        match self {
+            CardBrand::Visa => &[13, 16],
+            CardBrand::Mastercard => &[16],
+            CardBrand::Amex => &[15],
+            CardBrand::Discover | CardBrand::Jcb | CardBrand::UnionPay => &[16, 17, 18, 19],
+            CardBrand::Unknown => &[12, 13, 14, 15, 16, 17, 18, 19],
+        }
+    }
+
+    /// 安全码长度
+    pub fn cvc_length(&self) -> usize {
+        match self {
+            CardBrand::Amex => 4,
+            _ => 3,
+        }
+    }
+}
+
+impl std::fmt::Display for CardBrand {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.label())
+    }
+}
+
+/// 子字段的校验状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum CreditCardFieldState {
+    /// 未填写
+    #[default]
+    Empty,
+    /// 未填写完整
+    Incomplete,
+    /// 填写完整但无效
+    Invalid,
+    /// 有效
+    Valid,
+}
+
+impl CreditCardFieldState {
+    /// 是否有效
+    pub fn is_valid(&self) -> bool {
+        *self == CreditCardFieldState::Valid
+    }
+}
+
+/// 各个子字段的校验状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct CreditCardValidation {
+    /// 卡号
+    pub number: CreditCardFieldState,
+    /// 有效期
+    pub expiry: CreditCardFieldState,
+    /// 安全码
+    pub cvc: CreditCardFieldState,
+}
+
+impl CreditCardValidation {
+    /// 所有子字段都有效
+    pub fn is_valid(&self) -> bool {
+        self.number.is_valid() && self.expiry.is_valid() && self.cvc.is_valid()
+    }
+}
+
+/// 银行卡输入的值
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct CreditCardValue {
+    /// 卡号，只包含数字
+    pub number: String,
+    /// 有效期，格式为 `MM/YY`
+    pub expiry: String,
+    /// 安全码
+    pub cvc: String,
+    /// 卡组织
+    pub brand: CardBrand,
+}
+
+impl CreditCardValue {
+    /// 创建银行卡输入的值，输入内容会按格式过滤，卡组织根据卡号自动识别
+    pub fn new(number: &str, expiry: &str, cvc: &str) -> Self {
+        let brand = CardBrand::detect(number);
+        Self {
+            number: brand.mask().unmask(number),
+            expiry: expiry_mask().format(expiry),
+            cvc: cvc_mask(brand).unmask(cvc),
+            brand,
+        }
+    }
+
+    /// 按卡组织分组后的卡号
+    pub fn formatted_number(&self) -> String {
+        self.brand.mask().format(&self.number)
+    }
+
+    /// 卡号后四位
+    pub fn last4(&self) -> &str {
+        &self.number[self.number.len().saturating_sub(4)..]
+    }
+
+    /// 有效期月份
+    pub fn expiry_month(&self) -> Option<u32> {
+        self.expiry.get(..2)?.parse().ok()
+    }
+
+    /// 有效期年份（四位）
+    pub fn expiry_year(&self) -> Option<u32> {
+        let year = self.expiry.get(3..5)?.parse::<u32>().ok()?;
+        Some(2000 + year)
+    }
+
+    /// 校验各个子字段，有效期按本地时间的当前月份判断是否过期
+    pub fn validate(&self) -> CreditCardValidation {
+        let today = Local::now().date_naive();
+        self.validate_at(today.year() as u32, today.month())
+    }
+
+    /// 以指定的年份（四位）和月份作为当前时间校验各个子字段
+    ///
+    /// 有效期当月仍然有效，早于当前月份时为 [`CreditCardFieldState::Invalid`]。
+    pub fn validate_at(&self, year: u32, month: u32) -> CreditCardValidation {
+        CreditCardValidation {
+            number: self.number_state(),
+            expiry: self.expiry_state(year, month),
+            cvc: self.cvc_state(),
+        }
+    }
+
+    fn number_state(&self) -> CreditCardFieldState {
+        let lengths = self.brand.lengths();
+        if self.number.is_empty() {
+            CreditCardFieldState::Empty
+        } else if !lengths.contains(&self.number.len()) {
+            if self.number.len() > lengths[lengths.len() - 1] {
+                CreditCardFieldState::Invalid
+            } else {
+                CreditCardFieldState::Incomplete
+            }
+        } else if luhn(&self.number) {
+            CreditCardFieldState::Valid
+        } else {
+            CreditCardFieldState::Invalid
+        }
+    }
+
+    fn expiry_state(&self, year: u32, month: u32) -> CreditCardFieldState {
+        if self.expiry.is_empty() {
+            return CreditCardFieldState::Empty;
+        }
+        // 月份的第一位只能是 0 或 1，输入时即可判断
+        if self.expiry.starts_with(|c: char| c > '1') {
+            return CreditCardFieldState::Invalid;
+        }
+        if !expiry_mask().is_complete(&self.expiry) {
+            return CreditCardFieldState::Incomplete;
+        }
+        match (self.expiry_year(), self.expiry_month()) {
+            (Some(expiry_year), Some(expiry_month @ 1..=12))
+                if (expiry_year, expiry_month) >= (year, month) =>
+            {
+                CreditCardFieldState::Valid
+            }
+            _ => CreditCardFieldState::Invalid,
+        }
+    }
+
+    fn cvc_state(&self) -> CreditCardFieldState {
+        let length = self.brand.cvc_length();
+        match self.cvc.len() {
+            0 => CreditCardFieldState::Empty,
+            len if len < length => CreditCardFieldState::Incomplete,
+            len if len == length => CreditCardFieldState::Valid,
+            _ => CreditCardFieldState::Invalid,
+        }
+    }
+}
+
+/// 有效期的格式
+fn expiry_mask() -> InputMask {
+    InputMask::new("99/99")
+}
+
+/// 安全码的格式
+fn cvc_mask(brand: CardBrand) -> InputMask {
+    InputMask::new("9".repeat(brand.cvc_length()))
+}
+
+/// Luhn 校验
+fn luhn(number: &str) -> bool {
+    let sum = number
+        .bytes()
+        .rev()
+        .enumerate()
+        .map(|(i, b)| {
+            let digit = u32::from(b - b'0');
+            if i % 2 == 1 {
+                let doubled = digit * 2;
+                if doubled > 9 { doubled - 9 } else { doubled }
+            } else {
+                digit
+            }
+        })
+        .sum::<u32>();
+    sum % 10 == 0
+}
+
+/// 银行卡输入组件
+///
+/// 由卡号、有效期和安全码三个输入框组成，输入完整但无效的子字段会显示错误提示。
+#[derive(Debug, Clone, ComponentBase)]
+pub struct CreditCardInput {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值的 Signal（受控状态）
+    value: Option<Signal<CreditCardValue>>,
+    /// 是否禁用
+    disabled: bool,
+    /// 输入框尺寸
+    size: InputSize,
+    /// 卡号占位符
+    number_placeholder: String,
+    /// 有效期占位符
+    expiry_placeholder: String,
+    /// 安全码占位符
+    cvc_placeholder: String,
+    /// 值改变事件
+    onchange: Option<EventHandler<CreditCardValue>>,
+}
+
+impl Default for CreditCardInput {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CREDIT_CARD_INPUT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            disabled: false,
+            size: InputSize::default(),
+            number_placeholder: "卡号".to_string(),
+            expiry_placeholder: "MM/YY".to_string(),
+            cvc_placeholder: "CVC".to_string(),
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl CreditCardInput {
+    /// 创建一个新的银行卡输入组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<CreditCardValue>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置输入框尺寸
+    #[prop(default = "InputSize::Medium")]
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置卡号占位符
+    #[prop(default = "\"卡号\"")]
+    pub fn number_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.number_placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置有效期占位符
+    #[prop(default = "\"MM/YY\"")]
+    pub fn expiry_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.expiry_placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置安全码占位符
+    #[prop(default = "\"CVC\"")]
+    pub fn cvc_placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.cvc_placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置值改变事件，任意子字段输入时触发
+    pub fn onchange(mut self, handler: impl FnMut(CreditCardValue) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 渲染一个子字段，无效时显示错误提示
+    fn field(
+        class: &'static str,
+        state: CreditCardFieldState,
+        message: &str,
+        input: Input,
+    ) -> Element {
+        let invalid = state == CreditCardFieldState::Invalid;
+        let mut class_names = vec![classnames::CREDIT_CARD_INPUT_FIELD, class];
+        if invalid {
+            class_names.push("is-invalid");
+        }
+        let class = class_names.join(" ");
+
+        rsx! {
+            div { class,
+                {input.to_element()}
+                if invalid {
+                    div { class: classnames::CREDIT_CARD_INPUT_ERROR, role: "alert", "{message}" }
+                }
+            }
+        }
+    }
+}
+
+impl ToElement for CreditCardInput {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(CreditCardValue::default);
+        let mut value = self.value.unwrap_or(inner_value);
+
+        // 输入框显示的内容，外部修改值时同步更新
+        let mut number = use_signal(|| value.peek().formatted_number());
+        let mut expiry = use_signal(|| value.peek().expiry.clone());
+        let mut cvc = use_signal(|| value.peek().cvc.clone());
+        use_effect(move || {
+            let current = value.read();
+            let formatted = current.formatted_number();
+            if *number.peek() != formatted {
+                number.set(formatted);
+            }
+            if *expiry.peek() != current.expiry {
+                expiry.set(current.expiry.clone());
+            }
+            if *cvc.peek() != current.cvc {
+                cvc.set(current.cvc.clone());
+            }
+        });
+
+        let current = value.read().clone();
+        let validation = current.validate();
+        let brand = current.brand;
+        let onchange = self.onchange;
+        let mut update = move |f: &dyn Fn(&mut CreditCardValue)| {
+            let next = {
+                let mut current = value.write();
+                f(&mut current);
+                current.clone()
+            };
+            if let Some(handler) = onchange {
+                handler.call(next);
+            }
+        };
+
+        let mut number_input = Input::new()
+            .class(classnames::CREDIT_CARD_INPUT_CONTROL)
+            .value(number)
+            .mask(brand.mask())
+            .size(self.size)
+            .disabled(self.disabled)
+            .placeholder(self.number_placeholder.clone())
+            .oninput(move |raw: String| {
+                // 卡组织可能随输入变化，按新的卡组织重新分组
+                let brand = CardBrand::detect(&raw);
+                let mask = brand.mask();
+                let digits = mask.unmask(&raw);
+                let formatted = mask.format(&digits);
+                if formatted != raw {
+                    number.set(formatted);
+                }
+                update(&|value| {
+                    value.number = digits.clone();
+                    value.brand = brand;
+                });
+            });
+        if brand != CardBrand::Unknown {
+            number_input = number_input.suffix_icon(brand.label());
+        }
+
+        let expiry_input = Input::new()
+            .class(classnames::CREDIT_CARD_INPUT_CONTROL)
+            .value(expiry)
+            .mask(expiry_mask())
+            .size(self.size)
+            .disabled(self.disabled)
+            .placeholder(self.expiry_placeholder.clone())
+            .oninput(move |raw: String| update(&|value| value.expiry = raw.clone()));
+
+        let cvc_input = Input::new()
+            .class(classnames::CREDIT_CARD_INPUT_CONTROL)
+            .value(cvc)
+            .mask(cvc_mask(brand))
+            .size(self.size)
+            .disabled(self.disabled)
+            .placeholder(self.cvc_placeholder.clone())
+            .oninput(move |raw: String| update(&|value| value.cvc = raw.clone()));
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                "data-brand": "{brand:?}",
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                {Self::field(classnames::CREDIT_CARD_INPUT_NUMBER, validation.number, "卡号无效", number_input)}
+                div { class: classnames::CREDIT_CARD_INPUT_ROW,
+                    {Self::field(classnames::CREDIT_CARD_INPUT_EXPIRY, validation.expiry, "有效期无效", expiry_input)}
+                    {Self::field(classnames::CREDIT_CARD_INPUT_CVC, validation.cvc, "安全码无效", cvc_input)}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_detect_brand() {
+        assert_eq!(CardBrand::detect("4"), CardBrand::Visa);
+        assert_eq!(CardBrand::detect("5105"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("2221 00"), CardBrand::Mastercard);
+        assert_eq!(CardBrand::detect("37"), CardBrand::Amex);
+        assert_eq!(CardBrand::detect("6011"), CardBrand::Discover);
+        assert_eq!(CardBrand::detect("6221"), CardBrand::UnionPay);
+        assert_eq!(CardBrand::detect("3530"), CardBrand::Jcb);
+        assert_eq!(CardBrand::detect("3"), CardBrand::Unknown);
+        assert_eq!(CardBrand::detect(""), CardBrand::Unknown);
+    }
+
+    #[test]
+    fn test_validate() {
+        let value = CreditCardValue::new("3782 822463 10005", "01/29", "1234");
+        assert_eq!(value.brand, CardBrand::Amex);
+        assert!(value.validate_at(2026, 10).is_valid());
+        assert_eq!(value.last4(), "0005");
+        assert_eq!(value.expiry_month(), Some(1));
+        assert_eq!(value.expiry_year(), Some(2029));
+
+        let value = CreditCardValue::new("4242 4242", "2", "1234");
+        let validation = value.validate_at(2026, 10);
+        assert_eq!(validation.number, CreditCardFieldState::Incomplete);
+        assert_eq!(validation.expiry, CreditCardFieldState::Invalid);
+        assert_eq!(value.cvc, "123");
+
+        // 卡组织变化后安全码长度不符
+        let value = CreditCardValue {
+            brand: CardBrand::Visa,
+            ..CreditCardValue::new("3782", "", "1234")
+        };
+        assert_eq!(
+            value.validate_at(2026, 10).cvc,
+            CreditCardFieldState::Invalid
+        );
+        assert_eq!(
+            CreditCardValue::default().validate_at(2026, 10),
+            CreditCardValidation::default()
+        );
+    }
+
+    #[test]
+    fn test_expiry() {
+        let expiry = |expiry: &str| {
+            CreditCardValue::new("", expiry, "")
+                .validate_at(2026, 10)
+                .expiry
+        };
+        // 已过期
+        assert_eq!(expiry("01/20"), CreditCardFieldState::Invalid);
+        assert_eq!(expiry("09/26"), CreditCardFieldState::Invalid);
+        assert_eq!(expiry("12/25"), CreditCardFieldState::Invalid);
+        // 当月仍然有效
+        assert_eq!(expiry("10/26"), CreditCardFieldState::Valid);
+        // 未过期
+        assert_eq!(expiry("11/26"), CreditCardFieldState::Valid);
+        assert_eq!(expiry("01/27"), CreditCardFieldState::Valid);
+        assert_eq!(expiry("13/30"), CreditCardFieldState::Invalid);
+        assert_eq!(expiry("00/30"), CreditCardFieldState::Invalid);
+    }
+
+    #[test]
+    fn test_credit_card_input() {
+        fn app() -> Element {
+            let card = use_signal(CreditCardValue::default);
+            rsx! {
+                {CreditCardInput::new().value(card).to_element()}
+                span { class: "number", "{card.read().number}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let inputs = harness.find_all_by_class(classnames::INPUT_INNER);
+        assert_eq!(inputs.len(), 3);
+
+        harness.input(&inputs[0], "378282246310005");
+        assert_eq!(
+            harness.find_by_class("number").unwrap().text(),
+            "378282246310005"
+        );
+        let root = harness
+            .find_by_class(classnames::CREDIT_CARD_INPUT)
+            .unwrap();
+        assert_eq!(root.attr("data-brand"), Some("Amex"));
+        let inputs = harness.find_all_by_class(classnames::INPUT_INNER);
+        assert_eq!(inputs[0].attr("value"), Some("3782 822463 10005"));
+        assert!(
+            harness
+                .find_by_class(classnames::CREDIT_CARD_INPUT_ERROR)
+                .is_none()
+        );
+
+        harness.input(&inputs[1], "13/30");
+        let error = harness
+            .find_by_class(classnames::CREDIT_CARD_INPUT_ERROR)
+            .unwrap();
+        assert_eq!(error.text(), "有效期无效");
+    }
+}
@@ -0,0 +1,1084 @@
+//! CrudPage 增删改查页面组件
+//!
+//! 把 [`FilterBar`]、[`Table`]、[`Dialog`] 中的 [`SchemaForm`] 和 [`ConfirmButton`] 组合为完整的列表页：
+//! 调用方提供查询、新建、更新、删除四个异步操作以及列和表单字段的定义，组件负责筛选、分页、
+//! 新建和编辑对话框、删除确认，并在操作完成后刷新列表。
+//!
+//! 记录类型实现 [`CrudRecord`]，提供行的唯一标识、各列显示的内容和编辑时表单的初始值。
+//! 查询收到 [`CrudQuery`]，返回当前页的记录和总数 [`CrudList`]；也可以通过 [`CrudPage::data_source`]
+//! 使用 [`DataSource`]，筛选条件转换为数据源查询条件中的字符串。
+//!
+//! 操作结果通过最近的 [`Notification`](crate::Notification) 以通知的形式提示，没有 Notification 时不提示；
+//! 保存失败时错误信息同时显示在对话框中，对话框保持打开以便修改后重试。
+//!
+//! 开启 [`CrudPage::optimistic`] 后删除采用乐观更新：确认后立即从列表中移除记录，删除失败时恢复记录并提示错误，
+//! 见 [`use_optimistic`]。
+//!
+//! 只设置了的操作才会显示对应的按钮：未设置 `create` 时不显示新建按钮，`update` 和 `delete`
+//! 都未设置时不显示操作列。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     CrudList, CrudPage, CrudQuery, CrudRecord, FilterField, FormSchema, SchemaField,
+//!     SchemaFormValues, TableColumn, ToElement,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone, PartialEq)]
+//! struct User {
+//!     id: u32,
+//!     name: String,
+//! }
+//!
+//! impl CrudRecord for User {
+//!     fn key(&self) -> String {
+//!         self.id.to_string()
+//!     }
+//!
+//!     fn cell(&self, prop: &str) -> String {
+//!         match prop {
+//!             "name" => self.name.clone(),
+//!             _ => String::new(),
+//!         }
+//!     }
+//!
+//!     fn form_values(&self) -> SchemaFormValues {
+//!         SchemaFormValues::from([("name".to_string(), self.name.as_str().into())])
+//!     }
+//! }
+//!
+//! async fn list_users(query: CrudQuery) -> Result<CrudList<User>, String> {
+//!     let users = vec![User { id: 1, name: "张三".to_string() }];
+//!     Ok(CrudList::new(users, 1))
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     CrudPage::<User>::new()
+//!         .item_name("用户")
+//!         .column(TableColumn::new("name", "姓名"))
+//!         .filter(FilterField::input("name", "姓名"))
+//!         .schema(FormSchema::new().field(SchemaField::new("name", "姓名").required(true)))
+//!         .fetch(list_users)
+//!         .create(|values: SchemaFormValues| async move {
+//!             println!("新建 {values:?}");
+//!             Ok::<_, String>(())
+//!         })
+//!         .update(|user: User, values: SchemaFormValues| async move {
+//!             println!("更新 {} {values:?}", user.id);
+//!             Ok::<_, String>(())
+//!         })
+//!         .delete(|user: User| async move {
+//!             println!("删除 {}", user.id);
+//!             Ok::<_, String>(())
+//!         })
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::fmt::{Debug, Display};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{
+    Button, ButtonSize, ConfirmButton, DataPage, DataQuery, DataSource, Dialog, FilterBar,
+    FilterField, FilterQuery, FilterValue, FormSchema, Notifier, SchemaForm, SchemaFormValues,
+    Style, Table, TableColumn, TableRow, View, classnames, traits::ToElement, use_optimistic,
+};
+
+/// 操作列的字段名
+const ACTIONS_PROP: &str = "__crud_actions";
+
+/// 异步操作返回的 Future，错误已转换为字符串
+type CrudFuture<O> = Pin<Box<dyn Future<Output = Result<O, String>>>>;
+
+/// 增删改查页面中的记录
+pub trait CrudRecord: Debug + Clone + PartialEq + 'static {
+    /// 记录的唯一标识，作为表格行的 key
+    fn key(&self) -> String;
+
+    /// 记录在 `prop` 列中显示的内容
+    fn cell(&self, prop: &str) -> String;
+
+    /// 编辑记录时表单字段的初始值
+    fn form_values(&self) -> SchemaFormValues;
+}
+
+/// 查询条件
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct CrudQuery {
+    /// 筛选条件，只包含已填写的字段
+    pub filters: FilterQuery,
+    /// 页码，从 1 开始
+    pub page: usize,
+    /// 每页的记录数
+    pub page_size: usize,
+}
+
+impl CrudQuery {
+    /// 当前页第一条记录的下标，用于切片或数据库查询的 offset
+    pub fn offset(&self) -> usize {
+        self.page.saturating_sub(1) * self.page_size
+    }
+}
+
+impl From<&CrudQuery> for DataQuery {
+    /// 文本和下拉选择的值按原样转换，日期范围转换为 `{key}_start` 和 `{key}_end`，
+    /// 数字范围转换为 `{key}_min` 和 `{key}_max`，未填写的一端省略
+    fn from(query: &CrudQuery) -> Self {
+        let mut data = DataQuery::new().page(query.page).page_size(query.page_size);
+        for (key, value) in &query.filters {
+            match value {
+                FilterValue::Text(value) | FilterValue::Select(value) => {
+                    data.filters.insert(key.clone(), value.clone());
+                }
+                FilterValue::DateRange(start, end) => {
+                    let bounds = [("start", start.clone()), ("end", end.clone())];
+                    for (suffix, bound) in bounds {
+                        if let Some(bound) = bound {
+                            data.filters.insert(format!("{key}_{suffix}"), bound);
+                        }
+                    }
+                }
+                FilterValue::NumberRange(min, max) => {
+                    for (suffix, bound) in [("min", min), ("max", max)] {
+                        if let Some(bound) = bound {
+                            data.filters
+                                .insert(format!("{key}_{suffix}"), bound.to_string());
+                        }
+                    }
+                }
+            }
+        }
+        data
+    }
+}
+
+/// 查询结果，与数据源的 [`DataPage`] 相同
+pub type CrudList<T> = DataPage<T>;
+
+/// 总页数，没有记录时为 1
+fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// 增删改查页面组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct CrudPage<R: CrudRecord> {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在工具栏中新建按钮之后
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 表格的列定义
+    columns: Vec<TableColumn>,
+    /// 筛选字段
+    filters: Vec<FilterField>,
+    /// 新建和编辑表单的配置
+    schema: FormSchema,
+    /// 记录的名称，用于按钮、对话框标题和提示
+    item_name: String,
+    /// 每页的记录数
+    page_size: usize,
+    /// 查询当前页的记录
+    fetch: Option<Callback<CrudQuery, CrudFuture<CrudList<R>>>>,
+    /// 新建记录
+    create: Option<Callback<SchemaFormValues, CrudFuture<()>>>,
+    /// 更新记录
+    update: Option<Callback<(R, SchemaFormValues), CrudFuture<()>>>,
+    /// 删除记录
+    delete: Option<Callback<R, CrudFuture<()>>>,
+    /// 删除时是否先从列表中移除记录
+    optimistic: bool,
+}
+
+impl<R: CrudRecord> Default for CrudPage<R> {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::CRUD_PAGE),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            columns: Vec::new(),
+            filters: Vec::new(),
+            schema: FormSchema::default(),
+            item_name: "记录".to_string(),
+            page_size: 10,
+            fetch: None,
+            create: None,
+            update: None,
+            delete: None,
+            optimistic: false,
+        }
+    }
+}
+
+#[builder_props]
+impl<R: CrudRecord> CrudPage<R> {
+    /// 创建增删改查页面
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加表格的列
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// 设置表格的列
+    pub fn columns(mut self, columns: Vec<TableColumn>) -> Self {
+        self.columns = columns;
+        self
+    }
+
+    /// 添加筛选字段，没有筛选字段时不显示筛选栏
+    pub fn filter(mut self, field: FilterField) -> Self {
+        self.filters.push(field);
+        self
+    }
+
+    /// 设置筛选字段
+    pub fn filters(mut self, fields: Vec<FilterField>) -> Self {
+        self.filters = fields;
+        self
+    }
+
+    /// 设置新建和编辑表单的配置
+    pub fn schema(mut self, schema: FormSchema) -> Self {
+        self.schema = schema;
+        self
+    }
+
+    /// 设置记录的名称，如「用户」，用于新建按钮、对话框标题和操作提示
+    #[prop(default = "\"记录\"")]
+    pub fn item_name(mut self, name: impl Into<String>) -> Self {
+        self.item_name = name.into();
+        self
+    }
+
+    /// 设置每页的记录数
+    #[prop(default = "10")]
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// 设置删除时是否采用乐观更新，确认后立即从列表中移除记录，删除失败时恢复
+    #[prop(default = "false")]
+    pub fn optimistic(mut self, optimistic: bool) -> Self {
+        self.optimistic = optimistic;
+        self
+    }
+
+    /// 设置查询操作，首次渲染、筛选、翻页以及新建、更新、删除成功后调用
+    pub fn fetch<F, Fut, E>(mut self, fetch: F) -> Self
+    where
+        F: Fn(CrudQuery) -> Fut + 'static,
+        Fut: Future<Output = Result<CrudList<R>, E>> + 'static,
+        E: Display + 'static,
+    {
+        self.fetch = Some(Callback::new(move |query: CrudQuery| {
+            let future = fetch(query);
+            Box::pin(async move { future.await.map_err(|e| e.to_string()) })
+                as CrudFuture<CrudList<R>>
+        }));
+        self
+    }
+
+    /// 设置查询使用的数据源，替代 [`CrudPage::fetch`]，查询条件按 `From<&CrudQuery>` 转换为 [`DataQuery`]
+    pub fn data_source(self, source: impl DataSource<R>) -> Self {
+        self.fetch(move |query: CrudQuery| source.query(DataQuery::from(&query)))
+    }
+
+    /// 设置新建操作，参数为表单提交的值
+    pub fn create<F, Fut, E>(mut self, create: F) -> Self
+    where
+        F: Fn(SchemaFormValues) -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display + 'static,
+    {
+        self.create = Some(Callback::new(move |values: SchemaFormValues| {
+            let future = create(values);
+            Box::pin(async move { future.await.map_err(|e| e.to_string()) }) as CrudFuture<()>
+        }));
+        self
+    }
+
+    /// 设置更新操作，参数为原来的记录和表单提交的值
+    pub fn update<F, Fut, E>(mut self, update: F) -> Self
+    where
+        F: Fn(R, SchemaFormValues) -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display + 'static,
+    {
+        self.update = Some(Callback::new(
+            move |(item, values): (R, SchemaFormValues)| {
+                let future = update(item, values);
+                Box::pin(async move { future.await.map_err(|e| e.to_string()) }) as CrudFuture<()>
+            },
+        ));
+        self
+    }
+
+    /// 设置删除操作，删除前需要在按钮上确认
+    pub fn delete<F, Fut, E>(mut self, delete: F) -> Self
+    where
+        F: Fn(R) -> Fut + 'static,
+        Fut: Future<Output = Result<(), E>> + 'static,
+        E: Display + 'static,
+    {
+        self.delete = Some(Callback::new(move |item: R| {
+            let future = delete(item);
+            Box::pin(async move { future.await.map_err(|e| e.to_string()) }) as CrudFuture<()>
+        }));
+        self
+    }
+}
+
+impl<R: CrudRecord> ToElement for CrudPage<R> {
+    fn to_element(&self) -> Element {
+        rsx! {
+            CrudPageElement::<R> { page: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props)]
+struct CrudPageElementProps<R: CrudRecord> {
+    page: Rc<CrudPage<R>>,
+}
+
+impl<R: CrudRecord> Clone for CrudPageElementProps<R> {
+    fn clone(&self) -> Self {
+        Self {
+            page: self.page.clone(),
+        }
+    }
+}
+
+impl<R: CrudRecord> PartialEq for CrudPageElementProps<R> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.page, &other.page)
+    }
+}
+
+/// 通过最近的 Notification 发送提示
+#[derive(Clone, Copy)]
+struct Toast(Option<Notifier>);
+
+impl Toast {
+    fn success(self, title: String) {
+        if let Some(notifier) = self.0 {
+            notifier.success(title, "");
+        }
+    }
+
+    fn error(self, title: String, message: &str) {
+        if let Some(notifier) = self.0 {
+            notifier.error(title, message);
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn CrudPageElement<R: CrudRecord>(props: CrudPageElementProps<R>) -> Element {
+    let page = props.page;
+    let toast = Toast(use_hook(try_consume_context::<Notifier>));
+
+    let mut list = use_signal(|| CrudList::<R>::new(Vec::new(), 0));
+    let mut current = use_signal(|| 1usize);
+    let mut filters = use_signal(FilterQuery::new);
+    let mut loading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    // 每次查询时递增，用于忽略过期的查询结果
+    let mut request = use_signal(|| 0u32);
+
+    let mut open = use_signal(|| false);
+    let mut editing = use_signal(|| None::<R>);
+    // 每次打开对话框时递增，用于重新创建表单
+    let mut session = use_signal(|| 0u32);
+    let mut saving = use_signal(|| false);
+    let mut save_error = use_signal(|| None::<String>);
+
+    let page_size = page.page_size;
+    let fetch = page.fetch;
+    let load = use_callback(move |()| {
+        let Some(fetch) = fetch else {
+            return;
+        };
+        request += 1;
+        let id = *request.peek();
+        loading.set(true);
+        let future = fetch.call(CrudQuery {
+            filters: filters.peek().clone(),
+            page: *current.peek(),
+            page_size,
+        });
+        spawn(async move {
+            let result = future.await;
+            if *request.peek() != id {
+                return;
+            }
+            loading.set(false);
+            match result {
+                Ok(result) => {
+                    list.set(result);
+                    error.set(None);
+                }
+                Err(message) => error.set(Some(message)),
+            }
+        });
+    });
+    use_hook(move || load.call(()));
+
+    let mut open_form = move |item: Option<R>| {
+        editing.set(item);
+        session += 1;
+        save_error.set(None);
+        open.set(true);
+    };
+
+    let item_name = page.item_name.clone();
+    let create = page.create;
+    let update = page.update;
+    let save = move |values: SchemaFormValues| {
+        if *saving.peek() {
+            return;
+        }
+        let (future, done) = match editing.peek().clone() {
+            Some(item) => match update {
+                Some(update) => (update.call((item, values)), "已更新"),
+                None => return,
+            },
+            None => match create {
+                Some(create) => (create.call(values), "已创建"),
+                None => return,
+            },
+        };
+        saving.set(true);
+        let item_name = item_name.clone();
+        spawn(async move {
+            let result = future.await;
+            saving.set(false);
+            match result {
+                Ok(()) => {
+                    open.set(false);
+                    toast.success(format!("{item_name}{done}"));
+                    load.call(());
+                }
+                Err(message) => {
+                    toast.error("保存失败".to_string(), &message);
+                    save_error.set(Some(message));
+                }
+            }
+        });
+    };
+
+    let remover = use_optimistic(list).with_error_title("删除失败");
+    let optimistic = page.optimistic;
+
+    let mut columns = page.columns.clone();
+    let delete = page.delete;
+    if update.is_some() || delete.is_some() {
+        let item_name = page.item_name.clone();
+        columns.push(
+            TableColumn::new(ACTIONS_PROP, "操作").render(move |row: TableRow| {
+                let Some(item) = list
+                    .peek()
+                    .items
+                    .iter()
+                    .find(|item| item.key() == row.key())
+                    .cloned()
+                else {
+                    return Rc::new(View::new());
+                };
+                let mut actions = View::new().class(classnames::CRUD_PAGE_ACTIONS);
+                if update.is_some() {
+                    let item = item.clone();
+                    actions = actions.children(
+                        Button::new()
+                            .text("编辑")
+                            .size(ButtonSize::Small)
+                            .class(classnames::CRUD_PAGE_EDIT)
+                            .onclick(move |_| open_form(Some(item.clone()))),
+                    );
+                }
+                if let Some(delete) = delete {
+                    let item_name = item_name.clone();
+                    actions = actions.children(
+                        ConfirmButton::new("删除")
+                            .size(ButtonSize::Small)
+                            .prompt(format!("确定删除该{item_name}？"))
+                            .confirm_text("删除")
+                            .onconfirm(move || {
+                                let future = delete.call(item.clone());
+                                let item_name = item_name.clone();
+                                let deleted = async move {
+                                    future.await?;
+                                    toast.success(format!("{item_name}已删除"));
+                                    // 删除当前页唯一的记录后回到上一页，乐观更新时记录已经移除
+                                    let remaining = list.peek().items.len();
+                                    if remaining <= usize::from(!optimistic) && *current.peek() > 1
+                                    {
+                                        current -= 1;
+                                    }
+                                    load.call(());
+                                    Ok::<_, String>(())
+                                };
+                                let deleted: CrudFuture<()> = if optimistic {
+                                    let key = item.key();
+                                    remover.apply(
+                                        move |list: &mut CrudList<R>| {
+                                            let before = list.items.len();
+                                            list.items.retain(|item| item.key() != key);
+                                            list.total -= before - list.items.len();
+                                        },
+                                        deleted,
+                                    );
+                                    Box::pin(async { Ok(()) })
+                                } else {
+                                    Box::pin(deleted)
+                                };
+                                deleted
+                            })
+                            .onerror(move |message: String| {
+                                toast.error("删除失败".to_string(), &message)
+                            }),
+                    );
+                }
+                Rc::new(actions)
+            }),
+        );
+    }
+
+    let rows = list
+        .read()
+        .items
+        .iter()
+        .map(|item| {
+            page.columns
+                .iter()
+                .fold(TableRow::new(item.key()), |row, column| {
+                    row.cell(column.prop(), item.cell(column.prop()))
+                })
+        })
+        .collect::<Vec<_>>();
+    let table = Table::new()
+        .columns(columns)
+        .rows(rows)
+        .manual_sort(true)
+        .empty_text(if loading() {
+            "加载中…"
+        } else {
+            "暂无数据"
+        });
+
+    let mut toolbar = View::new().class(classnames::CRUD_PAGE_TOOLBAR);
+    if create.is_some() {
+        toolbar = toolbar.children(
+            Button::new()
+                .text(format!("新建{}", page.item_name))
+                .as_primary()
+                .class(classnames::CRUD_PAGE_CREATE)
+                .onclick(move |_| open_form(None)),
+        );
+    }
+    toolbar = toolbar.childrens2(page.childrens.clone());
+
+    let total = list.read().total;
+    let pages = page_count(total, page_size);
+    let page_no = current();
+    let mut go = move |page_no: usize| {
+        current.set(page_no);
+        load.call(());
+    };
+
+    let form_title = match editing.read().is_some() {
+        true => format!("编辑{}", page.item_name),
+        false => format!("新建{}", page.item_name),
+    };
+    let form_values = editing
+        .read()
+        .as_ref()
+        .map(CrudRecord::form_values)
+        .unwrap_or_default();
+    let form = SchemaForm::new(page.schema.clone())
+        .values(form_values)
+        .submit_text(if saving() { "保存中…" } else { "保存" })
+        .onsubmit(save);
+
+    let mut class = vec![page.class.to_string()];
+    if loading() {
+        class.push("is-loading".to_string());
+    }
+    let class = class.join(" ");
+    let style = page.style.clone().map(|s| s.to_string());
+    let onclick_handler = page.onclick;
+
+    rsx! {
+        div {
+            id: page.id.clone(),
+            class,
+            style,
+            "aria-busy": loading(),
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            if !page.filters.is_empty() {
+                {
+                    FilterBar::new()
+                        .fields(page.filters.clone())
+                        .onsearch(move |query| {
+                            filters.set(query);
+                            current.set(1);
+                            load.call(());
+                        })
+                        .to_element()
+                }
+            }
+            {toolbar.to_element()}
+            if let Some(message) = error() {
+                div { class: classnames::CRUD_PAGE_ERROR, role: "alert",
+                    span { "加载失败：{message}" }
+                    {Button::new().text("重试").size(ButtonSize::Small).onclick(move |_| load.call(())).to_element()}
+                }
+            }
+            {table.to_element()}
+            div { class: classnames::CRUD_PAGE_PAGER,
+                span { class: classnames::CRUD_PAGE_TOTAL, "共 {total} 条" }
+                {
+                    Button::new()
+                        .text("上一页")
+                        .size(ButtonSize::Small)
+                        .class(classnames::CRUD_PAGE_PREV)
+                        .disabled(page_no <= 1)
+                        .onclick(move |_| go(page_no - 1))
+                        .to_element()
+                }
+                span { class: classnames::CRUD_PAGE_CURRENT, "{page_no} / {pages}" }
+                {
+                    Button::new()
+                        .text("下一页")
+                        .size(ButtonSize::Small)
+                        .class(classnames::CRUD_PAGE_NEXT)
+                        .disabled(page_no >= pages)
+                        .onclick(move |_| go(page_no + 1))
+                        .to_element()
+                }
+            }
+            {
+                Dialog::new(open)
+                    .title(form_title)
+                    .children(CrudForm {
+                        session: session(),
+                        error: save_error(),
+                        form,
+                    })
+                    .to_element()
+            }
+        }
+    }
+}
+
+/// 对话框中的表单
+///
+/// 表单只在首次渲染时读取初始值，每次打开对话框时以新的 key 渲染，重新创建表单的状态。
+#[derive(Debug, Clone)]
+struct CrudForm {
+    session: u32,
+    error: Option<String>,
+    form: SchemaForm,
+}
+
+impl ToElement for CrudForm {
+    fn to_element(&self) -> Element {
+        rsx! {
+            if let Some(message) = &self.error {
+                div { class: classnames::CRUD_PAGE_FORM_ERROR, role: "alert", "{message}" }
+            }
+            for session in [self.session] {
+                div { key: "{session}", {self.form.to_element()} }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{ElementRef, Harness};
+    use crate::{FormValue, Notification, SchemaField};
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct User {
+        id: u32,
+        name: String,
+    }
+
+    impl CrudRecord for User {
+        fn key(&self) -> String {
+            self.id.to_string()
+        }
+
+        fn cell(&self, prop: &str) -> String {
+            match prop {
+                "id" => self.id.to_string(),
+                "name" => self.name.clone(),
+                _ => String::new(),
+            }
+        }
+
+        fn form_values(&self) -> SchemaFormValues {
+            SchemaFormValues::from([("name".to_string(), self.name.as_str().into())])
+        }
+    }
+
+    fn name_of(values: &SchemaFormValues) -> String {
+        values
+            .get("name")
+            .map(FormValue::as_text)
+            .unwrap_or_default()
+    }
+
+    fn app() -> Element {
+        let mut store = use_signal(|| {
+            ["张三", "李四", "王五"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| User {
+                    id: i as u32 + 1,
+                    name: name.to_string(),
+                })
+                .collect::<Vec<_>>()
+        });
+
+        Notification::new()
+            .duration(0)
+            .children(
+                CrudPage::<User>::new()
+                    .item_name("用户")
+                    .page_size(2)
+                    .column(TableColumn::new("name", "姓名"))
+                    .filter(FilterField::input("name", "姓名"))
+                    .schema(
+                        FormSchema::new().field(SchemaField::new("name", "姓名").required(true)),
+                    )
+                    .fetch(move |query: CrudQuery| {
+                        let keyword = query
+                            .filters
+                            .get("name")
+                            .and_then(|value| value.as_str())
+                            .unwrap_or_default()
+                            .to_string();
+                        let matched = store
+                            .read()
+                            .iter()
+                            .filter(|user| user.name.contains(&keyword))
+                            .cloned()
+                            .collect::<Vec<_>>();
+                        let total = matched.len();
+                        let items = matched
+                            .into_iter()
+                            .skip(query.offset())
+                            .take(query.page_size)
+                            .collect();
+                        async move { Ok::<_, String>(CrudList::new(items, total)) }
+                    })
+                    .create(move |values: SchemaFormValues| {
+                        let id = store.peek().iter().map(|user| user.id).max().unwrap_or(0) + 1;
+                        let name = name_of(&values);
+                        async move {
+                            if name == "重名" {
+                                return Err("用户名已存在".to_string());
+                            }
+                            store.write().push(User { id, name });
+                            Ok(())
+                        }
+                    })
+                    .update(move |user: User, values: SchemaFormValues| {
+                        let name = name_of(&values);
+                        async move {
+                            if let Some(target) =
+                                store.write().iter_mut().find(|item| item.id == user.id)
+                            {
+                                target.name = name;
+                            }
+                            Ok::<_, String>(())
+                        }
+                    })
+                    .delete(move |user: User| async move {
+                        store.write().retain(|item| item.id != user.id);
+                        Ok::<_, String>(())
+                    }),
+            )
+            .to_element()
+    }
+
+    fn names(harness: &Harness) -> Vec<String> {
+        harness
+            .find_all(|element| element.tag() == "td")
+            .iter()
+            .filter(|cell| !cell.text().is_empty())
+            .map(|cell| cell.text().to_string())
+            .collect()
+    }
+
+    fn submit(harness: &mut Harness) {
+        let form: ElementRef = harness.find_by_class(classnames::SCHEMA_FORM).unwrap();
+        let data = dioxus_html::SerializedFormData::new(String::new(), Vec::new());
+        harness.dispatch(
+            &form,
+            "submit",
+            PlatformEventData::new(Box::new(data)),
+            false,
+        );
+        harness.flush();
+    }
+
+    fn click(harness: &mut Harness, class: &str) {
+        let element = harness.find_by_class(class).unwrap();
+        harness.click(&element);
+        harness.flush();
+    }
+
+    #[test]
+    fn test_page_count() {
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(10, 10), 1);
+        assert_eq!(page_count(11, 10), 2);
+        let query = CrudQuery {
+            page: 3,
+            page_size: 10,
+            ..Default::default()
+        };
+        assert_eq!(query.offset(), 20);
+    }
+
+    #[test]
+    fn test_crud_query_to_data_query() {
+        let query = CrudQuery {
+            filters: FilterQuery::from([
+                ("name".to_string(), FilterValue::Text("张".to_string())),
+                (
+                    "created".to_string(),
+                    FilterValue::DateRange(Some("2024-01-01".to_string()), None),
+                ),
+                (
+                    "age".to_string(),
+                    FilterValue::NumberRange(Some(18.0), Some(30.5)),
+                ),
+            ]),
+            page: 2,
+            page_size: 20,
+        };
+        let data = DataQuery::from(&query);
+        assert_eq!(data.offset(), 20);
+        assert_eq!(
+            data.filters.into_iter().collect::<Vec<_>>(),
+            [
+                ("age_max".to_string(), "30.5".to_string()),
+                ("age_min".to_string(), "18".to_string()),
+                ("created_start".to_string(), "2024-01-01".to_string()),
+                ("name".to_string(), "张".to_string()),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_crud_page_list_and_pagination() {
+        let mut harness = Harness::new(app);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_TOTAL)
+                .unwrap()
+                .text(),
+            "共 3 条"
+        );
+        assert!(names(&harness)[0].starts_with("张三"));
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_CURRENT)
+                .unwrap()
+                .text(),
+            "1 / 2"
+        );
+
+        click(&mut harness, classnames::CRUD_PAGE_NEXT);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_CURRENT)
+                .unwrap()
+                .text(),
+            "2 / 2"
+        );
+        assert_eq!(
+            harness.find_all_by_class(classnames::CRUD_PAGE_EDIT).len(),
+            1
+        );
+
+        // 筛选后回到第一页
+        let input = harness.find_by_attr("placeholder", "请输入姓名").unwrap();
+        harness.input(&input, "李");
+        click(&mut harness, classnames::FILTER_BAR_SEARCH);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_TOTAL)
+                .unwrap()
+                .text(),
+            "共 1 条"
+        );
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_CURRENT)
+                .unwrap()
+                .text(),
+            "1 / 1"
+        );
+    }
+
+    #[test]
+    fn test_crud_page_create_update_delete() {
+        let mut harness = Harness::new(app);
+
+        // 新建失败时对话框保持打开并显示错误
+        click(&mut harness, classnames::CRUD_PAGE_CREATE);
+        assert!(harness.find_by_text("新建用户").is_some());
+        let input = harness.find_by_attr("id", "name").unwrap();
+        harness.input(&input, "重名");
+        submit(&mut harness);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_FORM_ERROR)
+                .unwrap()
+                .text(),
+            "用户名已存在"
+        );
+        let input = harness.find_by_attr("id", "name").unwrap();
+        harness.input(&input, "赵六");
+        submit(&mut harness);
+        assert!(harness.find_by_class(classnames::SCHEMA_FORM).is_none());
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_TOTAL)
+                .unwrap()
+                .text(),
+            "共 4 条"
+        );
+        assert!(harness.find_by_text("用户已创建").is_some());
+
+        // 编辑时表单填入原来的值
+        click(&mut harness, classnames::CRUD_PAGE_EDIT);
+        let input = harness.find_by_attr("id", "name").unwrap();
+        assert_eq!(input.attr("value"), Some("张三"));
+        harness.input(&input, "张三丰");
+        submit(&mut harness);
+        assert!(names(&harness)[0].starts_with("张三丰"));
+
+        // 删除需要确认
+        click(&mut harness, classnames::CONFIRM_BUTTON_TRIGGER);
+        click(&mut harness, classnames::CONFIRM_BUTTON_CONFIRM);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::CRUD_PAGE_TOTAL)
+                .unwrap()
+                .text(),
+            "共 3 条"
+        );
+        assert!(names(&harness)[0].starts_with("李四"));
+        assert!(harness.find_by_text("用户已删除").is_some());
+    }
+
+    #[test]
+    fn test_crud_page_optimistic_delete() {
+        fn app() -> Element {
+            let users = ["张三", "李四"]
+                .into_iter()
+                .enumerate()
+                .map(|(i, name)| User {
+                    id: i as u32 + 1,
+                    name: name.to_string(),
+                })
+                .collect::<Vec<_>>();
+
+            Notification::new()
+                .duration(0)
+                .children(
+                    CrudPage::<User>::new()
+                        .optimistic(true)
+                        .column(TableColumn::new("name", "姓名"))
+                        .fetch(move |_query: CrudQuery| {
+                            let list = CrudList::new(users.clone(), users.len());
+                            async move { Ok::<_, String>(list) }
+                        })
+                        .delete(|user: User| async move {
+                            // 张三的删除一直未完成，李四的删除失败
+                            if user.id == 1 {
+                                std::future::pending::<()>().await;
+                            }
+                            Err::<(), _>("没有权限".to_string())
+                        }),
+                )
+                .to_element()
+        }
+
+        fn delete(harness: &mut Harness, index: usize) {
+            let trigger = &harness.find_all_by_class(classnames::CONFIRM_BUTTON_TRIGGER)[index];
+            harness.click(trigger);
+            harness.flush();
+            click(harness, classnames::CONFIRM_BUTTON_CONFIRM);
+        }
+
+        let mut harness = Harness::new(app);
+        let total = |harness: &Harness| {
+            harness
+                .find_by_class(classnames::CRUD_PAGE_TOTAL)
+                .unwrap()
+                .text()
+                .to_string()
+        };
+
+        // 删除完成前记录已经从列表中移除
+        delete(&mut harness, 0);
+        assert_eq!(names(&harness)[0], "李四");
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::CONFIRM_BUTTON_TRIGGER)
+                .len(),
+            1
+        );
+        assert_eq!(total(&harness), "共 1 条");
+
+        // 删除失败时恢复记录并提示错误，仍在进行的删除不受影响
+        delete(&mut harness, 0);
+        assert_eq!(names(&harness)[0], "李四");
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::CONFIRM_BUTTON_TRIGGER)
+                .len(),
+            1
+        );
+        assert_eq!(total(&harness), "共 1 条");
+        assert!(harness.find_by_text("删除失败").is_some());
+        assert!(harness.find_by_text("没有权限").is_some());
+    }
+}
@@ -0,0 +1,1132 @@
+//! DatePicker 日期时间选择组件
+//!
+//! 提供两个组件：
+//!
+//! - [`DateTimePicker`]：日期和时间组合选择，日期使用浏览器原生的日期输入框，时间使用 [`TimePicker`]，
+//!   值为带时区偏移的 `DateTime<FixedOffset>`
+//! - [`DateRangePicker`]：日期范围选择，提供「今天」「最近 7 天」「本月」等快捷范围，
+//!   值为 [`DateRange`]，可以直接转换为 [`FilterBar`](crate::FilterBar) 的日期范围条件
+//!
+//! # 时区
+//!
+//! 时区以相对 UTC 的固定偏移 [`Timezone`] 表示，不处理夏令时。两个组件默认使用浏览器（服务端渲染时为服务器）
+//! 的当前偏移，传入 `timezones` 后显示时区选择框。切换时区时保留输入框中的日期和时间，按新的时区重新解释，
+//! 即「选择的是该时区的 9 点」，而不是把同一时刻换算到新的时区。
+//!
+//! # 组件模式
+//!
+//! 两个组件都是**受控组件**，通过 Signal 传递值，输入后组件直接更新 Signal，并触发 change 事件。
+//! 在外部修改 Signal 也会同步到输入框。
+//!
+//! # 示例
+//!
+//! ## 日期时间
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{DateTimePicker, Timezone, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let value = use_signal(|| None);
+//!     DateTimePicker::new()
+//!         .value(value)
+//!         .timezone(Timezone::named("北京", 8 * 60))
+//!         .timezones(Timezone::common())
+//!         .onchange(move |time| println!("{time:?}"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 日期范围和快捷范围
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{DateRange, DateRangePicker, RangePreset, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let value = use_signal(|| None::<DateRange>);
+//!     DateRangePicker::new()
+//!         .value(value)
+//!         .presets(vec![
+//!             RangePreset::today(),
+//!             RangePreset::last_days(7).with_label("Last 7 days"),
+//!             RangePreset::this_month().with_label("This month"),
+//!         ])
+//!         .onchange(move |range: Option<DateRange>| {
+//!             if let Some(range) = range {
+//!                 println!("{} ~ {}", range.start(), range.end());
+//!             }
+//!         })
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use chrono::{
+    DateTime, Datelike, Days, FixedOffset, Local, Months, NaiveDate, NaiveDateTime, NaiveTime,
+    Offset, Timelike, Utc,
+};
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, TimePicker, TimeValue, classnames, traits::ToElement};
+
+/// 一天的最后一秒，作为日期范围结束日期的时间
+const END_OF_DAY: NaiveTime = match NaiveTime::from_hms_opt(23, 59, 59) {
+    Some(time) => time,
+    None => NaiveTime::MIN,
+};
+
+/// 时区，以相对 UTC 的固定偏移表示
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Timezone {
+    /// 显示名称
+    name: String,
+    /// 相对 UTC 的偏移
+    offset: FixedOffset,
+}
+
+impl Timezone {
+    /// 以相对 UTC 的分钟数创建时区，名称为 `UTC+08:00` 的形式，超出 ±24 小时的偏移按 UTC 处理
+    pub fn new(minutes: i32) -> Self {
+        let offset = FixedOffset::east_opt(minutes * 60).unwrap_or(Utc.fix());
+        Self {
+            name: offset_name(offset),
+            offset,
+        }
+    }
+
+    /// 以相对 UTC 的分钟数创建时区，名称显示在偏移之后，如 `UTC+08:00 北京`
+    pub fn named(name: impl Into<String>, minutes: i32) -> Self {
+        let timezone = Self::new(minutes);
+        Self {
+            name: format!("{} {}", timezone.name, name.into()),
+            ..timezone
+        }
+    }
+
+    /// UTC
+    pub fn utc() -> Self {
+        Self::new(0)
+    }
+
+    /// 当前环境的时区偏移
+    pub fn local() -> Self {
+        Self::new(Local::now().offset().local_minus_utc() / 60)
+    }
+
+    /// 常用的不使用夏令时的时区
+    pub fn common() -> Vec<Self> {
+        vec![
+            Self::named("圣保罗", -3 * 60),
+            Self::utc(),
+            Self::named("迪拜", 4 * 60),
+            Self::named("新德里", 5 * 60 + 30),
+            Self::named("曼谷", 7 * 60),
+            Self::named("北京", 8 * 60),
+            Self::named("东京", 9 * 60),
+        ]
+    }
+
+    /// 显示名称
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 相对 UTC 的偏移
+    pub fn offset(&self) -> FixedOffset {
+        self.offset
+    }
+
+    /// 相对 UTC 的分钟数
+    pub fn minutes(&self) -> i32 {
+        self.offset.local_minus_utc() / 60
+    }
+
+    /// 该时区的当前时间
+    pub fn now(&self) -> DateTime<FixedOffset> {
+        Utc::now().with_timezone(&self.offset)
+    }
+}
+
+impl Default for Timezone {
+    fn default() -> Self {
+        Self::local()
+    }
+}
+
+/// 偏移的显示名称，UTC 本身显示为 `UTC`
+fn offset_name(offset: FixedOffset) -> String {
+    let seconds = offset.local_minus_utc();
+    if seconds == 0 {
+        return "UTC".to_string();
+    }
+    let sign = if seconds < 0 { '-' } else { '+' };
+    let minutes = seconds.abs() / 60;
+    format!("UTC{sign}{:02}:{:02}", minutes / 60, minutes % 60)
+}
+
+/// 把本地日期时间解释为指定偏移下的时间
+fn localize(naive: NaiveDateTime, offset: FixedOffset) -> DateTime<FixedOffset> {
+    DateTime::from_naive_utc_and_offset(naive - offset, offset)
+}
+
+/// 日期时间范围，开始和结束都包含在范围内
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct DateRange {
+    /// 开始时间
+    start: DateTime<FixedOffset>,
+    /// 结束时间
+    end: DateTime<FixedOffset>,
+}
+
+impl DateRange {
+    /// 创建范围，开始晚于结束时交换两者
+    pub fn new(start: DateTime<FixedOffset>, end: DateTime<FixedOffset>) -> Self {
+        if start <= end {
+            Self { start, end }
+        } else {
+            Self {
+                start: end,
+                end: start,
+            }
+        }
+    }
+
+    /// 创建整天的范围，从开始日期的 00:00:00 到结束日期的 23:59:59
+    pub fn from_dates(start: NaiveDate, end: NaiveDate, offset: FixedOffset) -> Self {
+        Self::new(
+            localize(start.and_time(NaiveTime::MIN), offset),
+            localize(end.and_time(END_OF_DAY), offset),
+        )
+    }
+
+    /// 开始时间
+    pub fn start(&self) -> DateTime<FixedOffset> {
+        self.start
+    }
+
+    /// 结束时间
+    pub fn end(&self) -> DateTime<FixedOffset> {
+        self.end
+    }
+
+    /// 开始时间所在时区的开始日期
+    pub fn start_date(&self) -> NaiveDate {
+        self.start.date_naive()
+    }
+
+    /// 结束时间所在时区的结束日期
+    pub fn end_date(&self) -> NaiveDate {
+        self.end.date_naive()
+    }
+
+    /// 是否包含指定时刻
+    pub fn contains<Tz: chrono::TimeZone>(&self, time: &DateTime<Tz>) -> bool {
+        *time >= self.start && *time <= self.end
+    }
+
+    /// 把开始和结束换算到指定时区，表示的时刻不变
+    pub fn with_timezone(&self, timezone: &Timezone) -> Self {
+        Self {
+            start: self.start.with_timezone(&timezone.offset),
+            end: self.end.with_timezone(&timezone.offset),
+        }
+    }
+
+    /// 由筛选栏的日期范围条件创建整天的范围，两端都填写时才有值
+    #[cfg(feature = "filter_bar")]
+    pub fn from_filter_value(value: &crate::FilterValue, timezone: &Timezone) -> Option<Self> {
+        let (start, end) = value.as_date_range()?;
+        let parse = |date: Option<&str>| NaiveDate::parse_from_str(date?, "%Y-%m-%d").ok();
+        Some(Self::from_dates(
+            parse(start)?,
+            parse(end)?,
+            timezone.offset,
+        ))
+    }
+}
+
+/// 转换为筛选栏的日期范围条件，日期为开始和结束时间所在时区的日期
+#[cfg(feature = "filter_bar")]
+impl From<DateRange> for crate::FilterValue {
+    fn from(range: DateRange) -> Self {
+        let format = |date: NaiveDate| Some(date.format("%Y-%m-%d").to_string());
+        crate::FilterValue::DateRange(format(range.start_date()), format(range.end_date()))
+    }
+}
+
+/// 快捷范围，根据所选时区的当前时间计算范围
+#[derive(Clone)]
+pub struct RangePreset {
+    /// 显示文本
+    label: String,
+    /// 由当前时间计算范围
+    resolve: Rc<dyn Fn(DateTime<FixedOffset>) -> DateRange>,
+}
+
+impl std::fmt::Debug for RangePreset {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RangePreset")
+            .field("label", &self.label)
+            .finish_non_exhaustive()
+    }
+}
+
+impl PartialEq for RangePreset {
+    fn eq(&self, other: &Self) -> bool {
+        self.label == other.label && Rc::ptr_eq(&self.resolve, &other.resolve)
+    }
+}
+
+impl RangePreset {
+    /// 创建快捷范围，`resolve` 接收所选时区的当前时间并返回范围
+    pub fn new(
+        label: impl Into<String>,
+        resolve: impl Fn(DateTime<FixedOffset>) -> DateRange + 'static,
+    ) -> Self {
+        Self {
+            label: label.into(),
+            resolve: Rc::new(resolve),
+        }
+    }
+
+    /// 今天
+    pub fn today() -> Self {
+        Self::new("今天", |now| {
+            DateRange::from_dates(now.date_naive(), now.date_naive(), *now.offset())
+        })
+    }
+
+    /// 包含今天在内的最近若干天，`days` 为 0 时按 1 天处理
+    pub fn last_days(days: u32) -> Self {
+        Self::new(format!("最近 {days} 天"), move |now| {
+            let today = now.date_naive();
+            let back = Days::new(u64::from(days.max(1) - 1));
+            let start = today.checked_sub_days(back).unwrap_or(today);
+            DateRange::from_dates(start, today, *now.offset())
+        })
+    }
+
+    /// 本周，从周一到周日
+    pub fn this_week() -> Self {
+        Self::new("本周", |now| {
+            let today = now.date_naive();
+            let back = Days::new(u64::from(today.weekday().num_days_from_monday()));
+            let monday = today.checked_sub_days(back).unwrap_or(today);
+            let sunday = monday.checked_add_days(Days::new(6)).unwrap_or(today);
+            DateRange::from_dates(monday, sunday, *now.offset())
+        })
+    }
+
+    /// 本月，从 1 日到月末
+    pub fn this_month() -> Self {
+        Self::new("本月", |now| {
+            let (first, last) = month_bounds(now.date_naive());
+            DateRange::from_dates(first, last, *now.offset())
+        })
+    }
+
+    /// 上月，从 1 日到月末
+    pub fn last_month() -> Self {
+        Self::new("上月", |now| {
+            let (first, _) = month_bounds(now.date_naive());
+            let previous = first.pred_opt().unwrap_or(first);
+            let (first, last) = month_bounds(previous);
+            DateRange::from_dates(first, last, *now.offset())
+        })
+    }
+
+    /// 默认的快捷范围：今天、最近 7 天、最近 30 天、本月
+    pub fn defaults() -> Vec<Self> {
+        vec![
+            Self::today(),
+            Self::last_days(7),
+            Self::last_days(30),
+            Self::this_month(),
+        ]
+    }
+
+    /// 替换显示文本
+    pub fn with_label(mut self, label: impl Into<String>) -> Self {
+        self.label = label.into();
+        self
+    }
+
+    /// 显示文本
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 由当前时间计算范围
+    pub fn resolve(&self, now: DateTime<FixedOffset>) -> DateRange {
+        (self.resolve)(now)
+    }
+}
+
+/// 日期所在月份的第一天和最后一天
+fn month_bounds(date: NaiveDate) -> (NaiveDate, NaiveDate) {
+    let first = date.with_day(1).unwrap_or(date);
+    let last = first
+        .checked_add_months(Months::new(1))
+        .and_then(|next| next.pred_opt())
+        .unwrap_or(date);
+    (first, last)
+}
+
+/// 时区选择框，当前时区不在列表中时放在最前面
+fn timezone_select(
+    mut current: Signal<Timezone>,
+    timezones: &[Timezone],
+    disabled: bool,
+    onchange: Callback<()>,
+) -> Element {
+    let selected = current.read().clone();
+    let mut options = timezones.to_vec();
+    if !options.iter().any(|tz| tz.offset == selected.offset) {
+        options.insert(0, selected.clone());
+    }
+    let choices = options.clone();
+    rsx! {
+        select {
+            class: classnames::DATE_PICKER_TIMEZONE,
+            aria_label: "时区",
+            disabled,
+            onchange: move |event: Event<FormData>| {
+                let minutes = event.value().parse::<i32>().ok();
+                if let Some(tz) = choices.iter().find(|tz| Some(tz.minutes()) == minutes) {
+                    current.set(tz.clone());
+                    onchange.call(());
+                }
+            },
+            for tz in options {
+                option {
+                    key: "{tz.minutes()}",
+                    value: "{tz.minutes()}",
+                    selected: tz.offset == selected.offset,
+                    "{tz.name}"
+                }
+            }
+        }
+    }
+}
+
+/// 初始选择的时区：有值时使用值的偏移，与传入的时区偏移相同时沿用其名称
+fn initial_timezone_of(offset: Option<Timezone>, initial: Option<Timezone>) -> Timezone {
+    match (offset, initial) {
+        (Some(offset), Some(initial)) if initial.offset == offset.offset => initial,
+        (Some(offset), _) => offset,
+        (None, initial) => initial.unwrap_or_default(),
+    }
+}
+
+/// 日期时间选择器组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct DateTimePicker {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值的 Signal
+    value: Option<Signal<Option<DateTime<FixedOffset>>>>,
+    /// 值为空时使用的时区
+    timezone: Option<Timezone>,
+    /// 可选的时区，为空时不显示时区选择框
+    timezones: Vec<Timezone>,
+    /// 是否显示秒
+    show_seconds: bool,
+    /// 是否禁用
+    disabled: bool,
+    /// 值改变事件
+    onchange: Option<EventHandler<Option<DateTime<FixedOffset>>>>,
+}
+
+impl Default for DateTimePicker {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::DATE_TIME_PICKER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            timezone: None,
+            timezones: Vec::new(),
+            show_seconds: true,
+            disabled: false,
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl DateTimePicker {
+    /// 创建日期时间选择器
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<Option<DateTime<FixedOffset>>>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置初始时区，有值时使用值的偏移，默认为当前环境的时区
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// 设置可选的时区并显示时区选择框
+    pub fn timezones(mut self, timezones: Vec<Timezone>) -> Self {
+        self.timezones = timezones;
+        self
+    }
+
+    /// 设置是否显示秒
+    #[prop(default = "true")]
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// 设置是否禁用
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置值改变事件，只选择日期时时间按 00:00:00 处理，清空日期时为 `None`
+    pub fn onchange(
+        mut self,
+        handler: impl FnMut(Option<DateTime<FixedOffset>>) + 'static,
+    ) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+/// 日期时间在输入项中的内容：日期、时间和时区
+fn split_date_time(
+    value: Option<DateTime<FixedOffset>>,
+) -> (String, Option<TimeValue>, Option<Timezone>) {
+    match value {
+        Some(value) => (
+            value.format("%Y-%m-%d").to_string(),
+            Some(TimeValue::new(
+                value.hour() as u8,
+                value.minute() as u8,
+                value.second() as u8,
+            )),
+            Some(Timezone::new(value.offset().local_minus_utc() / 60)),
+        ),
+        None => (String::new(), None, None),
+    }
+}
+
+/// 由日期、时间和时区组合日期时间，日期为空或无法解析时返回 `None`
+fn join_date_time(
+    date: &str,
+    time: Option<TimeValue>,
+    timezone: &Timezone,
+) -> Option<DateTime<FixedOffset>> {
+    let date = NaiveDate::parse_from_str(date.trim(), "%Y-%m-%d").ok()?;
+    let time = time
+        .and_then(|t| {
+            NaiveTime::from_hms_opt(t.hour().into(), t.minute().into(), t.second().into())
+        })
+        .unwrap_or(NaiveTime::MIN);
+    Some(localize(date.and_time(time), timezone.offset))
+}
+
+impl ToElement for DateTimePicker {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(|| None);
+        let mut value = self.value.unwrap_or(inner_value);
+        let initial_timezone = self.timezone.clone();
+        let (mut date, mut time, mut timezone) = use_hook(|| {
+            let (date, time, offset) = split_date_time(*value.peek());
+            let timezone = initial_timezone_of(offset, initial_timezone);
+            (Signal::new(date), Signal::new(time), Signal::new(timezone))
+        });
+
+        // 值在外部被修改时同步回各输入项
+        use_effect(move || {
+            let current = *value.read();
+            if join_date_time(&date.peek(), *time.peek(), &timezone.peek()) == current {
+                return;
+            }
+            let (raw_date, raw_time, offset) = split_date_time(current);
+            date.set(raw_date);
+            time.set(raw_time);
+            if let Some(offset) = offset
+                && offset.offset != timezone.peek().offset
+            {
+                timezone.set(offset);
+            }
+        });
+
+        let onchange = self.onchange;
+        let update = use_callback(move |()| {
+            let next = join_date_time(&date.peek(), *time.peek(), &timezone.peek());
+            if *value.peek() == next {
+                return;
+            }
+            value.set(next);
+            if let Some(handler) = onchange {
+                handler.call(next);
+            }
+        });
+
+        let disabled = self.disabled;
+        let current_date = date.read().clone();
+        let time_picker = TimePicker::new()
+            .value(time)
+            .show_seconds(self.show_seconds)
+            .disabled(disabled)
+            .onchange(move |_| update.call(()))
+            .class(classnames::DATE_TIME_PICKER_TIME);
+        let timezones = self.timezones.clone();
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                input {
+                    r#type: "date",
+                    class: classnames::DATE_TIME_PICKER_DATE,
+                    aria_label: "日期",
+                    disabled,
+                    value: current_date,
+                    oninput: move |event: Event<FormData>| {
+                        date.set(event.value());
+                        update.call(());
+                    },
+                }
+                {time_picker.to_element()}
+                if !timezones.is_empty() {
+                    {timezone_select(timezone, &timezones, disabled, update)}
+                }
+            }
+        }
+    }
+}
+
+/// 日期范围选择器组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct DateRangePicker {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值的 Signal
+    value: Option<Signal<Option<DateRange>>>,
+    /// 快捷范围
+    presets: Vec<RangePreset>,
+    /// 是否同时选择时间
+    show_time: bool,
+    /// 值为空时使用的时区
+    timezone: Option<Timezone>,
+    /// 可选的时区，为空时不显示时区选择框
+    timezones: Vec<Timezone>,
+    /// 是否禁用
+    disabled: bool,
+    /// 值改变事件
+    onchange: Option<EventHandler<Option<DateRange>>>,
+}
+
+impl Default for DateRangePicker {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::DATE_RANGE_PICKER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            presets: RangePreset::defaults(),
+            show_time: false,
+            timezone: None,
+            timezones: Vec::new(),
+            disabled: false,
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl DateRangePicker {
+    /// 创建日期范围选择器
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<Option<DateRange>>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置快捷范围，默认为今天、最近 7 天、最近 30 天和本月，传入空列表时不显示
+    pub fn presets(mut self, presets: Vec<RangePreset>) -> Self {
+        self.presets = presets;
+        self
+    }
+
+    /// 添加快捷范围
+    pub fn preset(mut self, preset: RangePreset) -> Self {
+        self.presets.push(preset);
+        self
+    }
+
+    /// 设置是否同时选择时间，不选择时间时范围从开始日期的 00:00:00 到结束日期的 23:59:59
+    #[prop(default = "false")]
+    pub fn show_time(mut self, show_time: bool) -> Self {
+        self.show_time = show_time;
+        self
+    }
+
+    /// 设置初始时区，有值时使用开始时间的偏移，默认为当前环境的时区
+    pub fn timezone(mut self, timezone: Timezone) -> Self {
+        self.timezone = Some(timezone);
+        self
+    }
+
+    /// 设置可选的时区并显示时区选择框
+    pub fn timezones(mut self, timezones: Vec<Timezone>) -> Self {
+        self.timezones = timezones;
+        self
+    }
+
+    /// 设置是否禁用
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置值改变事件，开始或结束未填写时为 `None`
+    pub fn onchange(mut self, handler: impl FnMut(Option<DateRange>) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+/// 范围一端在输入框中的内容
+fn format_bound(time: DateTime<FixedOffset>, show_time: bool) -> String {
+    let format = if show_time {
+        "%Y-%m-%dT%H:%M:%S"
+    } else {
+        "%Y-%m-%d"
+    };
+    time.format(format).to_string()
+}
+
+/// 由两端的输入内容和时区组合范围，任一端为空或无法解析时返回 `None`，
+/// 只选择日期时从较早日期的 00:00:00 到较晚日期的 23:59:59
+fn join_range(start: &str, end: &str, show_time: bool, timezone: &Timezone) -> Option<DateRange> {
+    if !show_time {
+        let parse = |text: &str| NaiveDate::parse_from_str(text.trim(), "%Y-%m-%d").ok();
+        let (start, end) = (parse(start)?, parse(end)?);
+        return Some(DateRange::from_dates(
+            start.min(end),
+            start.max(end),
+            timezone.offset,
+        ));
+    }
+    let parse = |text: &str| {
+        let text = text.trim();
+        NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M:%S")
+            .or_else(|_| NaiveDateTime::parse_from_str(text, "%Y-%m-%dT%H:%M"))
+            .ok()
+    };
+    Some(DateRange::new(
+        localize(parse(start)?, timezone.offset),
+        localize(parse(end)?, timezone.offset),
+    ))
+}
+
+impl ToElement for DateRangePicker {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let show_time = self.show_time;
+        let inner_value = use_signal(|| None);
+        let mut value = self.value.unwrap_or(inner_value);
+        let initial_timezone = self.timezone.clone();
+        let (mut start, mut end, mut timezone) = use_hook(|| {
+            let current = *value.peek();
+            let bound = |time: Option<DateTime<FixedOffset>>| {
+                time.map(|t| format_bound(t, show_time)).unwrap_or_default()
+            };
+            let offset =
+                current.map(|range| Timezone::new(range.start.offset().local_minus_utc() / 60));
+            let timezone = initial_timezone_of(offset, initial_timezone);
+            (
+                Signal::new(bound(current.map(|r| r.start))),
+                Signal::new(bound(current.map(|r| r.end))),
+                Signal::new(timezone),
+            )
+        });
+
+        // 值在外部被修改时同步回各输入项，两端按开始时间的时区显示
+        use_effect(move || {
+            let current = *value.read();
+            let joined = join_range(&start.peek(), &end.peek(), show_time, &timezone.peek());
+            if joined == current {
+                return;
+            }
+            let Some(range) = current else {
+                start.set(String::new());
+                end.set(String::new());
+                return;
+            };
+            let offset = Timezone::new(range.start.offset().local_minus_utc() / 60);
+            let range = range.with_timezone(&offset);
+            start.set(format_bound(range.start, show_time));
+            end.set(format_bound(range.end, show_time));
+            if offset.offset != timezone.peek().offset {
+                timezone.set(offset);
+            }
+        });
+
+        let onchange = self.onchange;
+        let mut emit = move |next: Option<DateRange>| {
+            if *value.peek() == next {
+                return;
+            }
+            value.set(next);
+            if let Some(handler) = onchange {
+                handler.call(next);
+            }
+        };
+        let update = use_callback(move |()| {
+            emit(join_range(
+                &start.peek(),
+                &end.peek(),
+                show_time,
+                &timezone.peek(),
+            ));
+        });
+
+        let disabled = self.disabled;
+        let input_type = if show_time { "datetime-local" } else { "date" };
+        let step = show_time.then_some("1");
+        let now = timezone.read().now();
+        let current = *value.read();
+        let presets = self.presets.iter().map(|preset| {
+            let preset = preset.clone();
+            let range = preset.resolve(now);
+            let mut class = vec![classnames::DATE_RANGE_PICKER_PRESET];
+            if current == Some(range) {
+                class.push("is-active");
+            }
+            let class = class.join(" ");
+            rsx! {
+                button {
+                    key: "{preset.label}",
+                    r#type: "button",
+                    class,
+                    disabled,
+                    onclick: move |_| {
+                        // 快捷范围按点击时的当前时间计算
+                        let range = preset.resolve(timezone.peek().now());
+                        start.set(format_bound(range.start, show_time));
+                        end.set(format_bound(range.end, show_time));
+                        emit(Some(range));
+                    },
+                    "{preset.label}"
+                }
+            }
+        });
+        let bound_input = |mut bound: Signal<String>, label: &'static str| {
+            let current = bound.read().clone();
+            rsx! {
+                input {
+                    r#type: input_type,
+                    class: classnames::DATE_RANGE_PICKER_INPUT,
+                    aria_label: label,
+                    step,
+                    disabled,
+                    value: current,
+                    oninput: move |event: Event<FormData>| {
+                        bound.set(event.value());
+                        update.call(());
+                    },
+                }
+            }
+        };
+        let start_input = bound_input(start, "开始");
+        let end_input = bound_input(end, "结束");
+        let timezones = self.timezones.clone();
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if !self.presets.is_empty() {
+                    div { class: classnames::DATE_RANGE_PICKER_PRESETS, {presets} }
+                }
+                div { class: classnames::DATE_RANGE_PICKER_RANGE,
+                    {start_input}
+                    span { class: classnames::DATE_RANGE_PICKER_SEPARATOR, "~" }
+                    {end_input}
+                }
+                if !timezones.is_empty() {
+                    {timezone_select(timezone, &timezones, disabled, update)}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn date(y: i32, m: u32, d: u32) -> NaiveDate {
+        NaiveDate::from_ymd_opt(y, m, d).unwrap()
+    }
+
+    #[test]
+    fn test_range_presets() {
+        let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+        // 2026-02-18 是周三
+        let now = localize(date(2026, 2, 18).and_hms_opt(10, 30, 0).unwrap(), offset);
+
+        let range = RangePreset::last_days(7).resolve(now);
+        assert_eq!(range.start_date(), date(2026, 2, 12));
+        assert_eq!(range.end_date(), date(2026, 2, 18));
+        assert_eq!(range.start().to_rfc3339(), "2026-02-12T00:00:00+08:00");
+        assert_eq!(range.end().to_rfc3339(), "2026-02-18T23:59:59+08:00");
+
+        let range = RangePreset::this_month().resolve(now);
+        assert_eq!(
+            (range.start_date(), range.end_date()),
+            (date(2026, 2, 1), date(2026, 2, 28))
+        );
+        let range = RangePreset::last_month().resolve(now);
+        assert_eq!(
+            (range.start_date(), range.end_date()),
+            (date(2026, 1, 1), date(2026, 1, 31))
+        );
+        let range = RangePreset::this_week().resolve(now);
+        assert_eq!(
+            (range.start_date(), range.end_date()),
+            (date(2026, 2, 16), date(2026, 2, 22))
+        );
+        assert!(range.contains(&now.with_timezone(&Utc)));
+
+        let preset = RangePreset::last_days(7).with_label("Last 7 days");
+        assert_eq!(preset.label(), "Last 7 days");
+        assert_eq!(
+            RangePreset::last_days(0).resolve(now).start_date(),
+            now.date_naive()
+        );
+    }
+
+    #[test]
+    fn test_timezone_and_filter_value() {
+        assert_eq!(Timezone::new(0).name(), "UTC");
+        assert_eq!(Timezone::new(5 * 60 + 30).name(), "UTC+05:30");
+        assert_eq!(
+            Timezone::named("圣保罗", -3 * 60).name(),
+            "UTC-03:00 圣保罗"
+        );
+
+        let beijing = Timezone::new(8 * 60);
+        let range = DateRange::from_dates(date(2026, 3, 1), date(2026, 3, 31), beijing.offset());
+        // 同一时刻换算到 UTC 后开始日期落在前一天
+        let utc = range.with_timezone(&Timezone::utc());
+        assert_eq!(utc.start_date(), date(2026, 2, 28));
+        assert_eq!(utc, range);
+
+        #[cfg(feature = "filter_bar")]
+        {
+            use crate::FilterValue;
+
+            let value = FilterValue::from(range);
+            assert_eq!(
+                value,
+                FilterValue::DateRange(Some("2026-03-01".into()), Some("2026-03-31".into()))
+            );
+            assert_eq!(DateRange::from_filter_value(&value, &beijing), Some(range));
+            let open = FilterValue::DateRange(Some("2026-03-01".into()), None);
+            assert_eq!(DateRange::from_filter_value(&open, &beijing), None);
+        }
+    }
+
+    #[test]
+    fn test_date_time_picker() {
+        fn app() -> Element {
+            let value = use_signal(|| None::<DateTime<FixedOffset>>);
+            let text = value().map(|v| v.to_rfc3339()).unwrap_or_default();
+            rsx! {
+                {DateTimePicker::new()
+                    .value(value)
+                    .timezone(Timezone::new(8 * 60))
+                    .timezones(Timezone::common())
+                    .to_element()}
+                span { class: "value", "{text}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        // 当前时区在列表中时不重复添加
+        let options = harness.find_all(|e| e.tag() == "option");
+        assert_eq!(options.len(), Timezone::common().len());
+        assert_eq!(
+            harness.find_by_attr("selected", "true").unwrap().text(),
+            "UTC+08:00 北京"
+        );
+
+        let input = harness
+            .find_by_class(classnames::DATE_TIME_PICKER_DATE)
+            .unwrap();
+        harness.input(&input, "2026-05-20");
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "2026-05-20T00:00:00+08:00"
+        );
+
+        let time = harness
+            .find_by_class(classnames::TIME_PICKER_INPUT)
+            .unwrap();
+        harness.input(&time, "09:15:00");
+        harness.keydown(&time, "Enter");
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "2026-05-20T09:15:00+08:00"
+        );
+
+        // 切换时区时保留日期和时间
+        let select = harness
+            .find_by_class(classnames::DATE_PICKER_TIMEZONE)
+            .unwrap();
+        harness.change(&select, "540");
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "2026-05-20T09:15:00+09:00"
+        );
+
+        let input = harness
+            .find_by_class(classnames::DATE_TIME_PICKER_DATE)
+            .unwrap();
+        harness.input(&input, "");
+        assert_eq!(harness.find_by_class("value").unwrap().text(), "");
+    }
+
+    #[test]
+    fn test_date_range_picker() {
+        fn app() -> Element {
+            let mut value = use_signal(|| None::<DateRange>);
+            let text = value()
+                .map(|r| format!("{} {}", r.start().to_rfc3339(), r.end().to_rfc3339()))
+                .unwrap_or_default();
+            rsx! {
+                {DateRangePicker::new()
+                    .value(value)
+                    .timezone(Timezone::utc())
+                    .presets(vec![RangePreset::today(), RangePreset::last_days(7)])
+                    .to_element()}
+                span { class: "value", "{text}" }
+                button {
+                    class: "set",
+                    onclick: move |_| {
+                        let offset = FixedOffset::east_opt(8 * 3600).unwrap();
+                        value.set(Some(DateRange::from_dates(date(2026, 1, 1), date(2026, 1, 5), offset)));
+                    },
+                }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let presets = harness.find_all_by_class(classnames::DATE_RANGE_PICKER_PRESET);
+        assert_eq!(presets.len(), 2);
+        harness.click(&presets[1]);
+        let range = RangePreset::last_days(7).resolve(Timezone::utc().now());
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            format!(
+                "{} {}",
+                range.start().to_rfc3339(),
+                range.end().to_rfc3339()
+            )
+        );
+        let presets = harness.find_all_by_class(classnames::DATE_RANGE_PICKER_PRESET);
+        assert!(presets[1].has_class("is-active"));
+        assert!(!presets[0].has_class("is-active"));
+
+        let inputs = harness.find_all_by_class(classnames::DATE_RANGE_PICKER_INPUT);
+        assert_eq!(inputs[0].attr("type"), Some("date"));
+        // 结束早于开始时交换两端
+        harness.input(&inputs[0], "2026-04-10");
+        harness.input(&inputs[1], "2026-04-01");
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "2026-04-01T00:00:00+00:00 2026-04-10T23:59:59+00:00"
+        );
+        harness.input(&inputs[1], "");
+        assert_eq!(harness.find_by_class("value").unwrap().text(), "");
+
+        // 外部修改值时同步输入框和时区
+        harness.click(&harness.find_by_class("set").unwrap());
+        let inputs = harness.find_all_by_class(classnames::DATE_RANGE_PICKER_INPUT);
+        assert_eq!(inputs[0].attr("value"), Some("2026-01-01"));
+        assert_eq!(inputs[1].attr("value"), Some("2026-01-05"));
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "2026-01-01T00:00:00+08:00 2026-01-05T23:59:59+08:00"
+        );
+    }
+}
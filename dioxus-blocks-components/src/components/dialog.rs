@@ -0,0 +1,401 @@
+//! Dialog 对话框组件
+//!
+//! 打开状态由传入的 `Signal<bool>` 控制，打开时在页面上覆盖半透明的遮罩并居中显示对话框，
+//! 包含标题、子元素作为的主体内容和底部操作区。点击关闭按钮、按 Escape 或点击遮罩时关闭，
+//! 关闭时把信号设为 `false` 并调用 [`Dialog::onclose`]，遮罩和对话框播放完离场动画后再卸载，见 [`use_presence`]。
+//!
+//! 打开后焦点移到对话框内的第一个可聚焦元素，Tab 键只在对话框内循环，关闭后焦点回到打开前的元素。
+//! 启用 `server` 特性进行服务端渲染时不处理焦点。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Dialog, Text, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut open = use_signal(|| false);
+//!
+//!     View::new()
+//!         .children(Button::new().text("删除项目").onclick(move |_| open.set(true)))
+//!         .children(
+//!             Dialog::new(open)
+//!                 .title("删除项目")
+//!                 .width("420px")
+//!                 .children(Text::p("删除后无法恢复，确定要删除吗？"))
+//!                 .footer(Button::new().text("取消").onclick(move |_| open.set(false)))
+//!                 .footer(Button::new().text("删除").as_danger().onclick(move |_| open.set(false))),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement, use_presence, use_unique_id};
+
+/// 离场动画最多等待的毫秒数，略长于样式中 0.2s 的离场动画
+const LEAVE_TIMEOUT: u32 = 300;
+
+/// 对话框内可以获得焦点的元素
+const FOCUSABLE: &str = "a[href], button:not([disabled]), input:not([disabled]), select:not([disabled]), textarea:not([disabled]), [tabindex]:not([tabindex='-1'])";
+
+/// 记录打开前的焦点元素，并把焦点移到对话框内的第一个可聚焦元素
+fn capture_focus(panel: &str) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    document::eval(&format!(
+        "const panel = document.getElementById({panel:?}); if (!panel) return; \
+         window.__tDialogReturn = window.__tDialogReturn || {{}}; \
+         window.__tDialogReturn[{panel:?}] = document.activeElement; \
+         (panel.querySelector({FOCUSABLE:?}) || panel).focus();"
+    ));
+}
+
+/// 焦点移出对话框时移到对话框内的第一个或最后一个可聚焦元素，使 Tab 键在对话框内循环
+fn wrap_focus(panel: &str, last: bool) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    document::eval(&format!(
+        "const panel = document.getElementById({panel:?}); if (!panel) return; \
+         const items = panel.querySelectorAll({FOCUSABLE:?}); \
+         (items[{last} ? items.length - 1 : 0] || panel).focus();"
+    ));
+}
+
+/// 把焦点还给打开对话框前的元素
+fn restore_focus(panel: &str) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    document::eval(&format!(
+        "const store = window.__tDialogReturn; if (!store || !store[{panel:?}]) return; \
+         const target = store[{panel:?}]; delete store[{panel:?}]; \
+         if (document.contains(target)) target.focus();"
+    ));
+}
+
+/// 对话框组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Dialog {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，作为对话框的主体内容
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 是否打开，未设置时在组件内保存
+    open: Option<Signal<bool>>,
+    /// 标题
+    title: String,
+    /// 底部内容，通常为操作按钮
+    footer: Vec<Rc<dyn ToElement>>,
+    /// 是否显示关闭按钮并允许按 Escape 关闭
+    closable: bool,
+    /// 点击遮罩时是否关闭
+    close_on_backdrop: bool,
+    /// 是否为警告对话框
+    alert: bool,
+    /// 对话框的宽度
+    width: String,
+    /// 关闭时的回调
+    onclose: Option<EventHandler<()>>,
+}
+
+impl Default for Dialog {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::DIALOG),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            open: None,
+            title: String::new(),
+            footer: Vec::new(),
+            closable: true,
+            close_on_backdrop: true,
+            alert: false,
+            width: "500px".to_string(),
+            onclose: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Dialog {
+    /// 使用控制打开状态的信号创建对话框
+    pub fn new(open: Signal<bool>) -> Self {
+        Self {
+            open: Some(open),
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 添加底部内容，多次调用时依次靠右排列，通常为操作按钮
+    pub fn footer<T>(mut self, footer: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.footer.push(Rc::new(footer));
+        self
+    }
+
+    /// 设置是否显示关闭按钮并允许按 Escape 关闭
+    #[prop(default = "true")]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// 设置点击遮罩时是否关闭
+    #[prop(default = "true")]
+    pub fn close_on_backdrop(mut self, close_on_backdrop: bool) -> Self {
+        self.close_on_backdrop = close_on_backdrop;
+        self
+    }
+
+    /// 设置是否为警告对话框，用于需要用户明确响应的提示，无障碍角色为 `alertdialog` 并以主体内容作为描述
+    #[prop(default = "false")]
+    pub fn alert(mut self, alert: bool) -> Self {
+        self.alert = alert;
+        self
+    }
+
+    /// 设置对话框的宽度，如 `420px`、`60%`，超出视口时自动缩小
+    #[prop(default = "\"500px\"")]
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = width.into();
+        self
+    }
+
+    /// 设置关闭时的回调
+    pub fn onclose(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.onclose = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for Dialog {
+    fn to_element(&self) -> Element {
+        let local_open = use_signal(|| false);
+        let mut open = self.open.unwrap_or(local_open);
+        let panel_id = use_unique_id("dialog");
+        let title_id = format!("{panel_id}-title");
+
+        // 关闭后把焦点还给打开前的元素
+        let restore_id = panel_id.clone();
+        use_effect(move || {
+            if !open() {
+                restore_focus(&restore_id);
+            }
+        });
+
+        // 关闭后保持挂载直到离场动画结束
+        let presence = use_presence(open(), LEAVE_TIMEOUT);
+        if !presence.is_present() {
+            return rsx! {};
+        }
+
+        let onclose = self.onclose;
+        let mut close = move || {
+            // 离场动画播放期间不再重复关闭
+            if !*open.peek() {
+                return;
+            }
+            open.set(false);
+            if let Some(handler) = onclose {
+                handler.call(());
+            }
+        };
+
+        let id = self.id.clone();
+        let class = match presence.is_leaving() {
+            true => format!("{} is-leaving", self.class),
+            false => self.class.to_string(),
+        };
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let closable = self.closable;
+        let close_on_backdrop = self.close_on_backdrop;
+        let title = self.title.clone();
+        let width = format!("width: {};", self.width);
+        let labelledby = (!title.is_empty()).then(|| title_id.clone());
+        let role = if self.alert { "alertdialog" } else { "dialog" };
+        let body_id = self.alert.then(|| format!("{panel_id}-body"));
+        let (mount_id, first_id, last_id) = (panel_id.clone(), panel_id.clone(), panel_id.clone());
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if close_on_backdrop {
+                        close();
+                    }
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                onanimationend: move |_| presence.finish(),
+                onkeydown: move |event: KeyboardEvent| {
+                    if closable && event.key() == Key::Escape {
+                        event.stop_propagation();
+                        close();
+                    }
+                },
+                span {
+                    class: classnames::DIALOG_FOCUS_GUARD,
+                    tabindex: "0",
+                    onfocus: move |_| wrap_focus(&last_id, true),
+                }
+                div {
+                    id: panel_id,
+                    class: classnames::DIALOG_PANEL,
+                    style: width,
+                    role,
+                    aria_modal: "true",
+                    aria_labelledby: labelledby,
+                    aria_describedby: body_id.clone(),
+                    tabindex: "-1",
+                    onmounted: move |_| capture_focus(&mount_id),
+                    // 对话框内的点击不会冒泡到遮罩
+                    onclick: move |event: MouseEvent| event.stop_propagation(),
+                    if !title.is_empty() || closable {
+                        div { class: classnames::DIALOG_HEADER,
+                            span { id: title_id, class: classnames::DIALOG_TITLE, "{title}" }
+                            if closable {
+                                button {
+                                    r#type: "button",
+                                    class: classnames::DIALOG_CLOSE,
+                                    aria_label: "关闭",
+                                    onclick: move |_| close(),
+                                    "×"
+                                }
+                            }
+                        }
+                    }
+                    div { id: body_id, class: classnames::DIALOG_BODY, {self.childrens_to_element()} }
+                    if !self.footer.is_empty() {
+                        div { class: classnames::DIALOG_FOOTER,
+                            for footer in self.footer.iter() {
+                                {footer.to_element()}
+                            }
+                        }
+                    }
+                }
+                span {
+                    class: classnames::DIALOG_FOCUS_GUARD,
+                    tabindex: "0",
+                    onfocus: move |_| wrap_focus(&first_id, false),
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Button, Text, View};
+
+    #[test]
+    fn test_dialog_open_and_close() {
+        fn app() -> Element {
+            let mut open = use_signal(|| false);
+            let mut closes = use_signal(|| 0);
+            View::new()
+                .children(Button::new().text("打开").onclick(move |_| open.set(true)))
+                .children(
+                    Dialog::new(open)
+                        .title("编辑资料")
+                        .width("420px")
+                        .onclose(move |_| closes += 1)
+                        .children(Text::p("对话框内容"))
+                        .footer(Button::new().text("确定").onclick(move |_| open.set(false))),
+                )
+                .children(Text::span(format!("关闭 {closes} 次")))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::DIALOG).is_none());
+
+        harness.click(&harness.find_by_text("打开").unwrap());
+        let panel = harness.find_by_attr("role", "dialog").unwrap();
+        assert_eq!(panel.attr("aria-modal"), Some("true"));
+        assert_eq!(panel.attr("style"), Some("width: 420px;"));
+        let title = harness.find_by_class(classnames::DIALOG_TITLE).unwrap();
+        assert_eq!(title.text(), "编辑资料");
+        assert_eq!(panel.attr("aria-labelledby"), title.attr("id"));
+        assert!(harness.find_by_text("对话框内容").is_some());
+
+        // 对话框内的点击不会关闭
+        harness.click(&harness.find_by_text("对话框内容").unwrap());
+        assert!(harness.find_by_class(classnames::DIALOG).is_some());
+
+        harness.click(&harness.find_by_class(classnames::DIALOG_CLOSE).unwrap());
+        assert!(harness.find_by_class(classnames::DIALOG).is_none());
+        assert!(harness.find_by_text("关闭 1 次").is_some());
+
+        harness.click(&harness.find_by_text("打开").unwrap());
+        harness.click(&harness.find_by_class(classnames::DIALOG).unwrap());
+        assert!(harness.find_by_class(classnames::DIALOG).is_none());
+        assert!(harness.find_by_text("关闭 2 次").is_some());
+
+        harness.click(&harness.find_by_text("打开").unwrap());
+        harness.keydown(&harness.find_by_text("对话框内容").unwrap(), "Escape");
+        assert!(harness.find_by_class(classnames::DIALOG).is_none());
+        assert!(harness.find_by_text("关闭 3 次").is_some());
+
+        // 底部按钮直接修改信号，不调用关闭回调
+        harness.click(&harness.find_by_text("打开").unwrap());
+        harness.click(&harness.find_by_text("确定").unwrap());
+        assert!(harness.find_by_class(classnames::DIALOG).is_none());
+        assert!(harness.find_by_text("关闭 3 次").is_some());
+    }
+
+    #[test]
+    fn test_dialog_not_closable() {
+        fn app() -> Element {
+            let open = use_signal(|| true);
+            Dialog::new(open)
+                .title("处理中")
+                .closable(false)
+                .close_on_backdrop(false)
+                .children(Text::p("请稍候"))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::DIALOG_CLOSE).is_none());
+        assert!(harness.find_by_class(classnames::DIALOG_FOOTER).is_none());
+
+        harness.click(&harness.find_by_class(classnames::DIALOG).unwrap());
+        harness.keydown(&harness.find_by_text("请稍候").unwrap(), "Escape");
+        assert!(harness.find_by_class(classnames::DIALOG).is_some());
+    }
+}
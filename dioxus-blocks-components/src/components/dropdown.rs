@@ -0,0 +1,851 @@
+//! Dropdown 下拉菜单组件
+//!
+//! 包裹任意触发元素（按钮、链接或自定义内容），鼠标移入或点击时在触发元素旁显示由 [`DropdownItem`] 组成的菜单，
+//! 选择菜单项时以该项的 key 调用 [`Dropdown::onselect`]，默认随后关闭菜单。
+//!
+//! 菜单项可以禁用、显示图标，[`DropdownItem::divider`] 创建分隔线；通过 [`DropdownItem::item`]
+//! 添加子菜单项后成为子菜单，鼠标移入或点击时在右侧展开，同一层级同时只展开一个子菜单。
+//!
+//! 菜单与 Tooltip 使用相同的定位方式：下方或上方的空间不足时翻转到另一侧，
+//! 超出视口的左右边缘时平移回视口内。
+//!
+//! 菜单打开时按 Escape 关闭，菜单项获得焦点后按 Enter 或空格选择，方向右键展开子菜单，方向左键收起当前子菜单。
+//!
+//! 组件以独立的组件作用域渲染，可以在条件分支中使用。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     Button, Dropdown, DropdownItem, DropdownPlacement, DropdownTrigger, ToElement,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Dropdown::new()
+//!         .trigger(DropdownTrigger::Click)
+//!         .placement(DropdownPlacement::BottomEnd)
+//!         .item(DropdownItem::new("edit", "编辑").icon("✎"))
+//!         .item(DropdownItem::new("copy", "复制").disabled(true))
+//!         .item(
+//!             DropdownItem::new("export", "导出")
+//!                 .item(DropdownItem::new("csv", "CSV"))
+//!                 .item(DropdownItem::new("excel", "Excel")),
+//!         )
+//!         .item(DropdownItem::divider())
+//!         .item(DropdownItem::new("delete", "删除"))
+//!         .onselect(|key| println!("选择了 {key}"))
+//!         .children(Button::new().text("更多操作"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::popper::{Align, Placement, Popper, Side, use_popper};
+use crate::{Shortcut, ShortcutHint, Style, classnames, traits::ToElement};
+
+/// 菜单的位置
+///
+/// 方向表示菜单位于触发元素的上方还是下方，`Start` 和 `End` 表示与触发元素的左边缘或右边缘对齐。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropdownPlacement {
+    /// 下方左对齐
+    #[default]
+    BottomStart,
+    /// 下方居中
+    Bottom,
+    /// 下方右对齐
+    BottomEnd,
+    /// 上方左对齐
+    TopStart,
+    /// 上方居中
+    Top,
+    /// 上方右对齐
+    TopEnd,
+}
+
+impl DropdownPlacement {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            DropdownPlacement::BottomStart => "t-dropdown__menu--bottom-start",
+            DropdownPlacement::Bottom => "t-dropdown__menu--bottom",
+            DropdownPlacement::BottomEnd => "t-dropdown__menu--bottom-end",
+            DropdownPlacement::TopStart => "t-dropdown__menu--top-start",
+            DropdownPlacement::Top => "t-dropdown__menu--top",
+            DropdownPlacement::TopEnd => "t-dropdown__menu--top-end",
+        }
+    }
+}
+
+impl Placement for DropdownPlacement {
+    fn parts(self) -> (Side, Align) {
+        match self {
+            DropdownPlacement::BottomStart => (Side::Bottom, Align::Start),
+            DropdownPlacement::Bottom => (Side::Bottom, Align::Center),
+            DropdownPlacement::BottomEnd => (Side::Bottom, Align::End),
+            DropdownPlacement::TopStart => (Side::Top, Align::Start),
+            DropdownPlacement::Top => (Side::Top, Align::Center),
+            DropdownPlacement::TopEnd => (Side::Top, Align::End),
+        }
+    }
+
+    fn from_parts(side: Side, align: Align) -> Self {
+        // 菜单只在上下方向翻转，左右方向按下方处理
+        match (side, align) {
+            (Side::Top, Align::Start) => DropdownPlacement::TopStart,
+            (Side::Top, Align::Center) => DropdownPlacement::Top,
+            (Side::Top, Align::End) => DropdownPlacement::TopEnd,
+            (_, Align::Start) => DropdownPlacement::BottomStart,
+            (_, Align::Center) => DropdownPlacement::Bottom,
+            (_, Align::End) => DropdownPlacement::BottomEnd,
+        }
+    }
+}
+
+/// 菜单的触发方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum DropdownTrigger {
+    /// 鼠标移入时显示，移出时隐藏
+    #[default]
+    Hover,
+    /// 点击触发元素切换显示，点击其他位置或按 Escape 隐藏
+    Click,
+}
+
+/// 菜单与触发元素的距离，与样式中的 4px 一致
+const MENU_OFFSET: f64 = 4.0;
+
+/// 下拉菜单项
+#[derive(Debug, Clone, ComponentBase)]
+pub struct DropdownItem {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，设置后代替文本作为菜单项的内容
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件，选择菜单项时触发
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 菜单项的唯一标识，选择时传给 [`Dropdown::onselect`]
+    key: String,
+    /// 菜单项的文本
+    label: String,
+    /// 显示在文本前的图标
+    icon: Option<String>,
+    /// 显示在文本后的快捷键
+    shortcut: Option<Shortcut>,
+    /// 是否禁用
+    disabled: bool,
+    /// 是否为分隔线
+    divider: bool,
+    /// 子菜单项
+    items: Vec<DropdownItem>,
+}
+
+impl Default for DropdownItem {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::DROPDOWN_ITEM),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            key: String::new(),
+            label: String::new(),
+            icon: None,
+            shortcut: None,
+            disabled: false,
+            divider: false,
+            items: Vec::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl DropdownItem {
+    /// 创建菜单项
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 创建分隔线
+    pub fn divider() -> Self {
+        Self {
+            class: Cow::Borrowed(classnames::DROPDOWN_DIVIDER),
+            divider: true,
+            ..Default::default()
+        }
+    }
+
+    /// 设置显示在文本前的图标
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = Some(icon.into());
+        self
+    }
+
+    /// 设置显示在文本后的快捷键，如 `mod+C`，按当前平台格式化
+    ///
+    /// 只用于提示，快捷键需要由应用自行监听。
+    pub fn shortcut(mut self, shortcut: impl Into<Shortcut>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+
+    /// 设置禁用状态，禁用的菜单项不能选择，也不会展开子菜单
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 添加子菜单项，添加后菜单项成为子菜单，自身不能被选择
+    pub fn item(mut self, item: DropdownItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// 设置子菜单项列表
+    pub fn items(mut self, items: Vec<DropdownItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// 菜单项的唯一标识
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl ToElement for DropdownItem {
+    fn to_element(&self) -> Element {
+        if self.childrens.is_empty() {
+            let label = self.label.clone();
+            rsx! { "{label}" }
+        } else {
+            self.childrens_to_element()
+        }
+    }
+}
+
+/// 下拉菜单组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Dropdown {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，作为触发元素
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 菜单项列表
+    items: Vec<DropdownItem>,
+    /// 菜单的位置
+    placement: DropdownPlacement,
+    /// 触发方式
+    trigger: DropdownTrigger,
+    /// 鼠标移出后隐藏前的延迟，单位毫秒
+    hide_delay: u32,
+    /// 选择菜单项后是否关闭菜单
+    hide_on_click: bool,
+    /// 是否禁用
+    disabled: bool,
+    /// 选择菜单项时的回调
+    onselect: Option<EventHandler<String>>,
+}
+
+impl Default for Dropdown {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::DROPDOWN),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            items: Vec::new(),
+            placement: DropdownPlacement::default(),
+            trigger: DropdownTrigger::default(),
+            hide_delay: 150,
+            hide_on_click: true,
+            disabled: false,
+            onselect: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Dropdown {
+    /// 创建下拉菜单，通过 `children` 添加触发元素
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加菜单项
+    pub fn item(mut self, item: DropdownItem) -> Self {
+        self.items.push(item);
+        self
+    }
+
+    /// 设置菜单项列表
+    pub fn items(mut self, items: Vec<DropdownItem>) -> Self {
+        self.items = items;
+        self
+    }
+
+    /// 设置菜单的位置
+    #[prop(default = "DropdownPlacement::BottomStart")]
+    pub fn placement(mut self, placement: DropdownPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// 设置触发方式
+    #[prop(default = "DropdownTrigger::Hover")]
+    pub fn trigger(mut self, trigger: DropdownTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// 设置鼠标移出后隐藏前的延迟，单位毫秒，鼠标可以在延迟内移入菜单
+    #[prop(default = "150")]
+    pub fn hide_delay(mut self, ms: u32) -> Self {
+        self.hide_delay = ms;
+        self
+    }
+
+    /// 设置选择菜单项后是否关闭菜单
+    #[prop(default = "true")]
+    pub fn hide_on_click(mut self, hide_on_click: bool) -> Self {
+        self.hide_on_click = hide_on_click;
+        self
+    }
+
+    /// 设置是否禁用，禁用后不显示菜单
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置选择菜单项时的回调，参数为菜单项的 key
+    pub fn onselect(mut self, handler: impl FnMut(String) + 'static) -> Self {
+        self.onselect = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for Dropdown {
+    fn to_element(&self) -> Element {
+        rsx! {
+            DropdownElement { dropdown: Rc::new(self.clone()) }
+        }
+    }
+}
+
+/// 菜单的状态，在各层菜单项之间共享
+#[derive(Clone, Copy)]
+struct MenuState {
+    /// 菜单是否显示
+    open: Signal<bool>,
+    /// 每次显示或隐藏递增，延迟结束时只有最后一次请求生效
+    round: Signal<u64>,
+    /// 各层展开的子菜单 key，下标为层级
+    expanded: Signal<Vec<String>>,
+    /// 菜单的定位状态
+    popper: Popper<DropdownPlacement>,
+    onselect: Option<EventHandler<String>>,
+    hide_on_click: bool,
+}
+
+impl MenuState {
+    /// 立即关闭菜单并收起全部子菜单
+    fn close(mut self) {
+        self.round += 1;
+        self.open.set(false);
+        self.expanded.write().clear();
+        self.popper.reset();
+    }
+
+    /// 收起 `depth` 层及更深的子菜单，`key` 不为空时在 `depth` 层展开该子菜单
+    fn expand(mut self, depth: usize, key: Option<String>) {
+        let unchanged = {
+            let expanded = self.expanded.peek();
+            expanded.len() == depth + usize::from(key.is_some())
+                && key
+                    .as_ref()
+                    .is_none_or(|key| expanded.get(depth) == Some(key))
+        };
+        if unchanged {
+            return;
+        }
+        let mut expanded = self.expanded.write();
+        expanded.truncate(depth);
+        expanded.extend(key);
+    }
+
+    /// `depth` 层的子菜单 `key` 是否展开
+    fn is_expanded(&self, depth: usize, key: &str) -> bool {
+        self.expanded.read().get(depth).is_some_and(|k| k == key)
+    }
+
+    /// 选择菜单项
+    fn select(self, key: String) {
+        if let Some(handler) = self.onselect {
+            handler.call(key);
+        }
+        if self.hide_on_click {
+            self.close();
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct DropdownElementProps {
+    dropdown: Rc<Dropdown>,
+}
+
+impl PartialEq for DropdownElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.dropdown, &other.dropdown)
+    }
+}
+
+#[allow(non_snake_case)]
+fn DropdownElement(props: DropdownElementProps) -> Element {
+    let dropdown = props.dropdown;
+    let popper = use_popper::<DropdownPlacement>();
+    let state = MenuState {
+        open: use_signal(|| false),
+        round: use_signal(|| 0),
+        expanded: use_signal(Vec::new),
+        popper,
+        onselect: dropdown.onselect,
+        hide_on_click: dropdown.hide_on_click,
+    };
+    let MenuState {
+        mut open,
+        mut round,
+        ..
+    } = state;
+
+    let hide_delay = dropdown.hide_delay;
+    let mut schedule = move |value: bool| {
+        round += 1;
+        let current = *round.peek();
+        if value || hide_delay == 0 {
+            if value {
+                open.set(true);
+            } else {
+                state.close();
+            }
+            return;
+        }
+        spawn(async move {
+            sleep(hide_delay).await;
+            if *round.peek() == current {
+                state.close();
+            }
+        });
+    };
+
+    let visible = open() && !dropdown.disabled;
+    let trigger = dropdown.trigger;
+    let id = dropdown.id.clone();
+    let class = dropdown.class.to_string();
+    let style = dropdown.style.clone().map(|s| s.to_string());
+    let onclick_handler = dropdown.onclick;
+    let placement = dropdown.placement;
+    let (actual, shift) = popper.position(placement);
+    let menu_class = format!("{} {}", classnames::DROPDOWN_MENU, actual.as_class());
+
+    rsx! {
+        span {
+            id,
+            class,
+            style,
+            aria_haspopup: "menu",
+            aria_expanded: visible,
+            onmounted: move |event: MountedEvent| popper.set_anchor(event),
+            onmouseenter: move |_| {
+                if trigger == DropdownTrigger::Hover {
+                    schedule(true);
+                }
+            },
+            onmouseleave: move |_| {
+                if trigger == DropdownTrigger::Hover {
+                    schedule(false);
+                }
+            },
+            onkeydown: move |event: KeyboardEvent| {
+                if event.key() == Key::Escape && open() {
+                    state.close();
+                }
+            },
+            onclick: move |event: MouseEvent| {
+                if trigger == DropdownTrigger::Click {
+                    if open() {
+                        state.close();
+                    } else {
+                        schedule(true);
+                    }
+                }
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            {dropdown.childrens_to_element()}
+            if visible {
+                if trigger == DropdownTrigger::Click {
+                    span {
+                        class: classnames::DROPDOWN_BACKDROP,
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            state.close();
+                        },
+                    }
+                }
+                ul {
+                    class: menu_class,
+                    style: "--t-dropdown-shift: {shift}px;",
+                    role: "menu",
+                    // 每次打开都重新挂载菜单，挂载后按当前的位置重新测量
+                    onmounted: move |event: MountedEvent| popper.measure(event, placement, MENU_OFFSET),
+                    onclick: move |event: MouseEvent| event.stop_propagation(),
+                    for item in dropdown.items.iter() {
+                        {render_item(item, 0, state)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 渲染子菜单，`depth` 为子菜单的层级
+fn render_submenu(items: &[DropdownItem], depth: usize, state: MenuState) -> Element {
+    rsx! {
+        ul {
+            class: classnames::DROPDOWN_SUBMENU,
+            role: "menu",
+            onclick: move |event: MouseEvent| event.stop_propagation(),
+            for item in items {
+                {render_item(item, depth, state)}
+            }
+        }
+    }
+}
+
+/// 渲染菜单项，包含子菜单项时一并渲染展开的子菜单
+fn render_item(item: &DropdownItem, depth: usize, state: MenuState) -> Element {
+    let id = item.id.clone();
+    let style = item.style.clone().map(|s| s.to_string());
+    if item.divider {
+        let class = item.class.to_string();
+        return rsx! {
+            li { id, class, style, role: "separator" }
+        };
+    }
+
+    let key = item.key.clone();
+    let disabled = item.disabled;
+    let has_submenu = !item.items.is_empty();
+    let expanded = has_submenu && state.is_expanded(depth, &key);
+    let class = {
+        let mut class = item.class.to_string();
+        if has_submenu {
+            class.push(' ');
+            class.push_str(classnames::DROPDOWN_ITEM_SUBMENU);
+        }
+        if expanded {
+            class.push_str(" is-expanded");
+        }
+        if disabled {
+            class.push_str(" is-disabled");
+        }
+        class
+    };
+    let onclick_handler = item.onclick;
+
+    // 子菜单项切换展开，其他菜单项选择
+    let activate = {
+        let key = key.clone();
+        move || {
+            if disabled {
+                return;
+            }
+            if has_submenu {
+                let next = (!state.is_expanded(depth, &key)).then(|| key.clone());
+                state.expand(depth, next);
+            } else {
+                state.select(key.clone());
+            }
+        }
+    };
+    let on_click = activate.clone();
+    let on_key = activate;
+    let hover_key = key.clone();
+
+    rsx! {
+        li {
+            id,
+            class,
+            style,
+            role: "menuitem",
+            tabindex: if disabled { "-1" } else { "0" },
+            aria_disabled: disabled,
+            aria_haspopup: has_submenu.then_some("menu"),
+            aria_expanded: has_submenu.then_some(expanded),
+            onmouseenter: move |_| {
+                if !disabled {
+                    state.expand(depth, has_submenu.then(|| hover_key.clone()));
+                }
+            },
+            onclick: move |event: MouseEvent| {
+                event.stop_propagation();
+                if disabled {
+                    return;
+                }
+                on_click();
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            onkeydown: move |event: KeyboardEvent| {
+                match event.key() {
+                    Key::Enter => on_key(),
+                    Key::Character(c) if c == " " => on_key(),
+                    Key::ArrowRight if has_submenu && !disabled => {
+                        state.expand(depth, Some(key.clone()));
+                    }
+                    Key::ArrowLeft if depth > 0 => state.expand(depth - 1, None),
+                    _ => return,
+                }
+                event.prevent_default();
+                event.stop_propagation();
+            },
+            if let Some(icon) = item.icon.clone() {
+                span { class: classnames::DROPDOWN_ICON, "{icon}" }
+            }
+            span { class: classnames::DROPDOWN_LABEL, {item.to_element()} }
+            if let Some(shortcut) = item.shortcut.clone() {
+                {
+                    ShortcutHint::new(shortcut)
+                        .plain(true)
+                        .class(classnames::DROPDOWN_SHORTCUT)
+                        .to_element()
+                }
+            }
+            if has_submenu {
+                span { class: classnames::DROPDOWN_ARROW, "›" }
+            }
+            if expanded {
+                {render_submenu(&item.items, depth + 1, state)}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Button, Text, View};
+
+    /// 文本包含 `label` 的最内层菜单项，子菜单项的文本也包含在父菜单项中
+    fn menu_item(harness: &Harness, label: &str) -> crate::testing::ElementRef {
+        harness
+            .find_all_by_class(classnames::DROPDOWN_ITEM)
+            .into_iter()
+            .rfind(|item| item.text().contains(label))
+            .unwrap()
+    }
+
+    fn app() -> Element {
+        let mut selected = use_signal(String::new);
+        View::new()
+            .children(
+                Dropdown::new()
+                    .trigger(DropdownTrigger::Click)
+                    .item(
+                        DropdownItem::new("edit", "编辑")
+                            .icon("✎")
+                            .shortcut("mod+E"),
+                    )
+                    .item(DropdownItem::new("copy", "复制").disabled(true))
+                    .item(DropdownItem::divider())
+                    .item(
+                        DropdownItem::new("export", "导出")
+                            .item(DropdownItem::new("csv", "CSV"))
+                            .item(DropdownItem::new("excel", "Excel")),
+                    )
+                    .onselect(move |key| selected.set(key))
+                    .children(Button::new().text("更多")),
+            )
+            .children(Text::span(format!("选择：{}", selected())))
+            .to_element()
+    }
+
+    #[test]
+    fn test_dropdown_click_and_select() {
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+
+        harness.click(&harness.find_by_text("更多").unwrap());
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_some());
+        assert_eq!(
+            harness
+                .find_all(|element| element.attr("role") == Some("menuitem"))
+                .len(),
+            3
+        );
+        assert!(harness.find_by_attr("role", "separator").is_some());
+        assert!(harness.find_by_class(classnames::DROPDOWN_ICON).is_some());
+        let shortcut = harness
+            .find_by_class(classnames::DROPDOWN_SHORTCUT)
+            .unwrap();
+        assert!(shortcut.has_class(classnames::SHORTCUT_HINT_PLAIN));
+        assert!(shortcut.text().ends_with('E'));
+
+        // 禁用的菜单项不能选择
+        let copy = menu_item(&harness, "复制");
+        assert!(copy.has_class("is-disabled"));
+        harness.click(&copy);
+        assert!(harness.find_by_text("选择：").is_some());
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_some());
+
+        harness.click(&menu_item(&harness, "编辑"));
+        assert!(harness.find_by_text("选择：edit").is_some());
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+    }
+
+    #[test]
+    fn test_dropdown_submenu() {
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("更多").unwrap());
+        assert!(
+            harness
+                .find_by_class(classnames::DROPDOWN_SUBMENU)
+                .is_none()
+        );
+
+        harness.mouse(&menu_item(&harness, "导出"), "mouseenter");
+        let export = menu_item(&harness, "导出");
+        assert!(export.has_class("is-expanded"));
+        assert_eq!(export.attr("aria-expanded"), Some("true"));
+        assert!(
+            harness
+                .find_by_class(classnames::DROPDOWN_SUBMENU)
+                .is_some()
+        );
+
+        // 移入同层的其他菜单项时收起子菜单
+        harness.mouse(&menu_item(&harness, "编辑"), "mouseenter");
+        assert!(
+            harness
+                .find_by_class(classnames::DROPDOWN_SUBMENU)
+                .is_none()
+        );
+
+        // 点击子菜单项本身只切换展开，不会选择
+        harness.click(&menu_item(&harness, "导出"));
+        assert!(harness.find_by_text("选择：").is_some());
+        harness.click(&menu_item(&harness, "Excel"));
+        assert!(harness.find_by_text("选择：excel").is_some());
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+    }
+
+    #[test]
+    fn test_dropdown_keyboard_and_backdrop() {
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("更多").unwrap());
+
+        harness.keydown(&menu_item(&harness, "导出"), "ArrowRight");
+        assert!(
+            harness
+                .find_by_class(classnames::DROPDOWN_SUBMENU)
+                .is_some()
+        );
+        harness.keydown(&menu_item(&harness, "CSV"), "ArrowLeft");
+        assert!(
+            harness
+                .find_by_class(classnames::DROPDOWN_SUBMENU)
+                .is_none()
+        );
+
+        harness.keydown(&menu_item(&harness, "编辑"), "Enter");
+        assert!(harness.find_by_text("选择：edit").is_some());
+
+        harness.click(&harness.find_by_text("更多").unwrap());
+        harness.keydown(&menu_item(&harness, "编辑"), "Escape");
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+
+        harness.click(&harness.find_by_text("更多").unwrap());
+        harness.click(
+            &harness
+                .find_by_class(classnames::DROPDOWN_BACKDROP)
+                .unwrap(),
+        );
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+    }
+
+    #[test]
+    fn test_dropdown_hover() {
+        let mut harness = Harness::with_element(
+            Dropdown::new()
+                .hide_delay(0)
+                .hide_on_click(false)
+                .placement(DropdownPlacement::TopEnd)
+                .item(DropdownItem::new("a", "选项一"))
+                .children(Button::new().text("悬停")),
+        );
+        let root = harness.find_by_class(classnames::DROPDOWN).unwrap();
+        harness.click(&harness.find_by_text("悬停").unwrap());
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+
+        harness.mouse(&root, "mouseenter");
+        let menu = harness.find_by_class(classnames::DROPDOWN_MENU).unwrap();
+        assert!(menu.has_class("t-dropdown__menu--top-end"));
+        assert!(
+            harness
+                .find_by_class(classnames::DROPDOWN_BACKDROP)
+                .is_none()
+        );
+
+        // 关闭 hide_on_click 后选择菜单项不会关闭菜单
+        harness.click(&menu_item(&harness, "选项一"));
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_some());
+
+        harness.mouse(&root, "mouseleave");
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+
+        let mut harness = Harness::with_element(
+            Dropdown::new()
+                .disabled(true)
+                .item(DropdownItem::new("a", "选项一"))
+                .children(Button::new().text("禁用")),
+        );
+        let root = harness.find_by_class(classnames::DROPDOWN).unwrap();
+        harness.mouse(&root, "mouseenter");
+        assert!(harness.find_by_class(classnames::DROPDOWN_MENU).is_none());
+    }
+}
@@ -0,0 +1,682 @@
+//! FilterBar 筛选栏组件
+//!
+//! 根据配置渲染一组筛选控件（输入框、下拉选择、日期范围、数字范围），提供查询和重置按钮，
+//! 控件较多时可以折叠，通常放在表格上方。
+//!
+//! # 组件模式
+//!
+//! 筛选字段通过 [`FilterField`] 配置，字段在首次渲染后保持固定。查询条件以 [`FilterQuery`] 的形式
+//! 通过 `onsearch` 返回，只包含已填写的字段；传入 `.value(signal)` 时，查询条件会实时写回该 Signal，
+//! Signal 的初始内容作为各字段的初始值，之后在外部修改该 Signal 也会同步到各控件。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{FilterBar, FilterField, FilterQuery, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     FilterBar::new()
+//!         .field(FilterField::input("name", "名称"))
+//!         .field(FilterField::select("status", "状态", [("on", "启用"), ("off", "停用")]))
+//!         .field(FilterField::date_range("created", "创建日期"))
+//!         .field(FilterField::number_range("price", "价格"))
+//!         .onsearch(|query: FilterQuery| {
+//!             if let Some((min, max)) = query.get("price").and_then(|v| v.as_number_range()) {
+//!                 println!("{min:?} ~ {max:?}");
+//!             }
+//!         })
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Button, Input, Style, classnames, traits::ToElement};
+
+/// 筛选条件，键为字段名，只包含已填写的字段
+pub type FilterQuery = BTreeMap<String, FilterValue>;
+
+/// 筛选字段的值
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterValue {
+    /// 输入框的内容
+    Text(String),
+    /// 下拉选择的选项值
+    Select(String),
+    /// 日期范围，日期格式为 `YYYY-MM-DD`
+    DateRange(Option<String>, Option<String>),
+    /// 数字范围
+    NumberRange(Option<f64>, Option<f64>),
+}
+
+impl FilterValue {
+    /// 输入框或下拉选择的值
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            FilterValue::Text(value) | FilterValue::Select(value) => Some(value),
+            _ => None,
+        }
+    }
+
+    /// 日期范围的开始和结束日期
+    pub fn as_date_range(&self) -> Option<(Option<&str>, Option<&str>)> {
+        match self {
+            FilterValue::DateRange(start, end) => Some((start.as_deref(), end.as_deref())),
+            _ => None,
+        }
+    }
+
+    /// 数字范围的最小值和最大值
+    pub fn as_number_range(&self) -> Option<(Option<f64>, Option<f64>)> {
+        match self {
+            FilterValue::NumberRange(min, max) => Some((*min, *max)),
+            _ => None,
+        }
+    }
+}
+
+/// 筛选控件类型
+#[derive(Debug, Clone, PartialEq)]
+pub enum FilterKind {
+    /// 输入框
+    Input,
+    /// 下拉选择，元素为 (值, 显示名称)
+    Select(Vec<(String, String)>),
+    /// 日期范围
+    DateRange,
+    /// 数字范围
+    NumberRange,
+}
+
+/// 筛选字段
+#[derive(Debug, Clone, PartialEq)]
+pub struct FilterField {
+    /// 字段名
+    key: String,
+    /// 标签
+    label: String,
+    /// 控件类型
+    kind: FilterKind,
+    /// 占位符
+    placeholder: Option<String>,
+}
+
+#[builder_props]
+impl FilterField {
+    /// 创建筛选字段
+    pub fn new(key: impl Into<String>, label: impl Into<String>, kind: FilterKind) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            kind,
+            placeholder: None,
+        }
+    }
+
+    /// 输入框字段
+    pub fn input(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(key, label, FilterKind::Input)
+    }
+
+    /// 下拉选择字段
+    pub fn select<V, L>(
+        key: impl Into<String>,
+        label: impl Into<String>,
+        options: impl IntoIterator<Item = (V, L)>,
+    ) -> Self
+    where
+        V: Into<String>,
+        L: Into<String>,
+    {
+        let options = options
+            .into_iter()
+            .map(|(value, label)| (value.into(), label.into()))
+            .collect();
+        Self::new(key, label, FilterKind::Select(options))
+    }
+
+    /// 日期范围字段
+    pub fn date_range(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(key, label, FilterKind::DateRange)
+    }
+
+    /// 数字范围字段
+    pub fn number_range(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self::new(key, label, FilterKind::NumberRange)
+    }
+
+    /// 设置占位符，范围字段的占位符用于开始值
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// 字段名
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 标签
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 字段值的显示文本，下拉选择显示选项名称，只填写一端的范围显示为 `≥ 开始` 或 `≤ 结束`
+    pub fn display_value(&self, value: &FilterValue) -> String {
+        let range = |start: Option<String>, end: Option<String>| match (start, end) {
+            (Some(start), Some(end)) => format!("{start} ~ {end}"),
+            (Some(start), None) => format!("≥ {start}"),
+            (None, Some(end)) => format!("≤ {end}"),
+            (None, None) => String::new(),
+        };
+        match value {
+            FilterValue::Text(value) => value.clone(),
+            FilterValue::Select(value) => match &self.kind {
+                FilterKind::Select(options) => options
+                    .iter()
+                    .find(|(option, _)| option == value)
+                    .map(|(_, label)| label.clone())
+                    .unwrap_or_else(|| value.clone()),
+                _ => value.clone(),
+            },
+            FilterValue::DateRange(start, end) => range(start.clone(), end.clone()),
+            FilterValue::NumberRange(min, max) => {
+                range(min.map(|v| v.to_string()), max.map(|v| v.to_string()))
+            }
+        }
+    }
+
+    /// 字段对应的控件输入项，范围字段有开始和结束两个输入项
+    fn slots(&self) -> Vec<String> {
+        match self.kind {
+            FilterKind::Input | FilterKind::Select(_) => vec![self.key.clone()],
+            FilterKind::DateRange | FilterKind::NumberRange => {
+                vec![format!("{}.start", self.key), format!("{}.end", self.key)]
+            }
+        }
+    }
+
+    /// 由字段值得到各输入项的原始内容
+    fn raw_values(&self, value: Option<&FilterValue>) -> Vec<String> {
+        let text = |v: Option<&str>| v.unwrap_or_default().to_string();
+        let number = |v: Option<f64>| v.map(|n| n.to_string()).unwrap_or_default();
+        match value {
+            Some(FilterValue::Text(v) | FilterValue::Select(v)) => vec![v.clone()],
+            Some(FilterValue::DateRange(start, end)) => {
+                vec![text(start.as_deref()), text(end.as_deref())]
+            }
+            Some(FilterValue::NumberRange(min, max)) => vec![number(*min), number(*max)],
+            None => self.slots().iter().map(|_| String::new()).collect(),
+        }
+    }
+
+    /// 由各输入项的原始内容得到字段值，未填写时返回 `None`
+    fn parse(&self, raw: &[String]) -> Option<FilterValue> {
+        let text = |i: usize| {
+            raw.get(i)
+                .map(|v| v.trim())
+                .filter(|v| !v.is_empty())
+                .map(str::to_string)
+        };
+        let number = |i: usize| text(i).and_then(|v| v.parse::<f64>().ok());
+        match self.kind {
+            FilterKind::Input => text(0).map(FilterValue::Text),
+            FilterKind::Select(_) => text(0).map(FilterValue::Select),
+            FilterKind::DateRange => match (text(0), text(1)) {
+                (None, None) => None,
+                (start, end) => Some(FilterValue::DateRange(start, end)),
+            },
+            FilterKind::NumberRange => match (number(0), number(1)) {
+                (None, None) => None,
+                (min, max) => Some(FilterValue::NumberRange(min, max)),
+            },
+        }
+    }
+}
+
+/// 筛选栏组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct FilterBar {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 筛选字段
+    fields: Vec<FilterField>,
+    /// 查询条件的 Signal
+    value: Option<Signal<FilterQuery>>,
+    /// 折叠时显示的字段数量
+    collapse_after: usize,
+    /// 查询按钮文本
+    search_text: String,
+    /// 重置按钮文本
+    reset_text: String,
+    /// 查询事件
+    onsearch: Option<EventHandler<FilterQuery>>,
+    /// 重置事件
+    onreset: Option<EventHandler<()>>,
+}
+
+impl Default for FilterBar {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::FILTER_BAR),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            fields: Vec::new(),
+            value: None,
+            collapse_after: 3,
+            search_text: "查询".to_string(),
+            reset_text: "重置".to_string(),
+            onsearch: None,
+            onreset: None,
+        }
+    }
+}
+
+#[builder_props]
+impl FilterBar {
+    /// 创建筛选栏
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加筛选字段
+    pub fn field(mut self, field: FilterField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// 设置筛选字段
+    pub fn fields(mut self, fields: Vec<FilterField>) -> Self {
+        self.fields = fields;
+        self
+    }
+
+    /// 设置查询条件的 Signal，初始内容作为字段的初始值
+    pub fn value(mut self, value: Signal<FilterQuery>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置折叠时显示的字段数量，默认为 3，字段数量超过该值时显示展开按钮
+    #[prop(default = "3")]
+    pub fn collapse_after(mut self, count: usize) -> Self {
+        self.collapse_after = count;
+        self
+    }
+
+    /// 设置查询按钮文本
+    #[prop(default = "\"查询\"")]
+    pub fn search_text(mut self, text: impl Into<String>) -> Self {
+        self.search_text = text.into();
+        self
+    }
+
+    /// 设置重置按钮文本
+    #[prop(default = "\"重置\"")]
+    pub fn reset_text(mut self, text: impl Into<String>) -> Self {
+        self.reset_text = text.into();
+        self
+    }
+
+    /// 设置查询事件，点击查询按钮或在输入框中按回车时触发
+    pub fn onsearch(mut self, handler: impl FnMut(FilterQuery) + 'static) -> Self {
+        self.onsearch = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置重置事件，重置后会以空的查询条件触发一次 `onsearch`
+    pub fn onreset(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.onreset = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+/// 各输入项的原始内容
+type Slots = BTreeMap<String, Signal<String>>;
+
+/// 由各输入项的原始内容汇总查询条件
+fn collect(fields: &[FilterField], slots: &Slots) -> FilterQuery {
+    fields
+        .iter()
+        .filter_map(|field| {
+            let raw = field
+                .slots()
+                .iter()
+                .map(|slot| {
+                    slots
+                        .get(slot)
+                        .map(|s| s.peek().clone())
+                        .unwrap_or_default()
+                })
+                .collect::<Vec<_>>();
+            field.parse(&raw).map(|value| (field.key.clone(), value))
+        })
+        .collect()
+}
+
+impl ToElement for FilterBar {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(FilterQuery::new);
+        let mut value = self.value.unwrap_or(inner_value);
+        let slots = use_hook(|| {
+            let initial = value.peek();
+            self.fields
+                .iter()
+                .flat_map(|field| {
+                    field
+                        .slots()
+                        .into_iter()
+                        .zip(field.raw_values(initial.get(&field.key)))
+                })
+                .map(|(slot, raw)| (slot, Signal::new(raw)))
+                .collect::<Slots>()
+        });
+        let mut expanded = use_signal(|| false);
+
+        // 查询条件在外部被修改时（如在 ActiveFilters 中移除条件），同步回各输入项
+        {
+            let fields = self.fields.clone();
+            let slots = slots.clone();
+            use_effect(move || {
+                let query = value.read().clone();
+                if collect(&fields, &slots) == query {
+                    return;
+                }
+                for field in &fields {
+                    let raw = field.raw_values(query.get(&field.key));
+                    for (name, raw) in field.slots().iter().zip(raw) {
+                        if let Some(mut slot) = slots.get(name).copied()
+                            && *slot.peek() != raw
+                        {
+                            slot.set(raw);
+                        }
+                    }
+                }
+            });
+        }
+
+        let onsearch = self.onsearch;
+        let onreset = self.onreset;
+        let update = {
+            let fields = self.fields.clone();
+            let slots = slots.clone();
+            use_callback(move |()| value.set(collect(&fields, &slots)))
+        };
+        let search = use_callback(move |()| {
+            update.call(());
+            if let Some(handler) = onsearch {
+                handler.call(value.peek().clone());
+            }
+        });
+        let reset = {
+            let slots = slots.clone();
+            move |_| {
+                for slot in slots.values() {
+                    let mut slot = *slot;
+                    slot.set(String::new());
+                }
+                if let Some(handler) = onreset {
+                    handler.call(());
+                }
+                search.call(());
+            }
+        };
+
+        let collapsible = self.fields.len() > self.collapse_after;
+        let visible = if collapsible && !expanded() {
+            self.collapse_after
+        } else {
+            self.fields.len()
+        };
+
+        let items = self.fields.iter().take(visible).map(|field| {
+            let slot = |name: &str| {
+                slots
+                    .get(name)
+                    .copied()
+                    .unwrap_or_else(|| Signal::new(String::new()))
+            };
+            let names = field.slots();
+            let placeholder = field.placeholder.clone();
+            let control = match &field.kind {
+                FilterKind::Input => Input::new()
+                    .value(slot(&names[0]))
+                    .placeholder(placeholder.unwrap_or_else(|| format!("请输入{}", field.label)))
+                    .clearable(true)
+                    .oninput(move |_| update.call(()))
+                    .onclear(move |_| update.call(()))
+                    .onkeydown(move |event: KeyboardEvent| {
+                        if event.key() == Key::Enter {
+                            search.call(());
+                        }
+                    })
+                    .to_element(),
+                FilterKind::Select(options) => {
+                    let mut slot = slot(&names[0]);
+                    let current = slot.read().clone();
+                    let options = options.clone();
+                    rsx! {
+                        select {
+                            class: classnames::FILTER_BAR_SELECT,
+                            aria_label: "{field.label}",
+                            onchange: move |event: Event<FormData>| {
+                                slot.set(event.value());
+                                update.call(());
+                            },
+                            option { value: "", selected: current.is_empty(),
+                                {placeholder.unwrap_or_else(|| "全部".to_string())}
+                            }
+                            for (option_value, label) in options {
+                                option {
+                                    key: "{option_value}",
+                                    value: "{option_value}",
+                                    selected: option_value == current,
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+                }
+                FilterKind::DateRange | FilterKind::NumberRange => {
+                    let input_type = if field.kind == FilterKind::DateRange {
+                        "date"
+                    } else {
+                        "number"
+                    };
+                    let range_input = |mut slot: Signal<String>, placeholder: String| {
+                        let current = slot.read().clone();
+                        rsx! {
+                            input {
+                                r#type: input_type,
+                                class: classnames::FILTER_BAR_RANGE_INPUT,
+                                placeholder,
+                                value: current,
+                                oninput: move |event: Event<FormData>| {
+                                    slot.set(event.value());
+                                    update.call(());
+                                },
+                            }
+                        }
+                    };
+                    let start = range_input(
+                        slot(&names[0]),
+                        placeholder.unwrap_or_else(|| "开始".to_string()),
+                    );
+                    let end = range_input(slot(&names[1]), "结束".to_string());
+                    rsx! {
+                        div { class: classnames::FILTER_BAR_RANGE,
+                            {start}
+                            span { class: classnames::FILTER_BAR_RANGE_SEPARATOR, "~" }
+                            {end}
+                        }
+                    }
+                }
+            };
+            let key = field.key.clone();
+            let label = field.label.clone();
+
+            rsx! {
+                div { key: "{key}", class: classnames::FILTER_BAR_ITEM,
+                    label { class: classnames::FILTER_BAR_LABEL, "{label}" }
+                    div { class: classnames::FILTER_BAR_CONTROL, {control} }
+                }
+            }
+        });
+
+        let search_button = Button::new()
+            .as_primary()
+            .class(classnames::FILTER_BAR_SEARCH)
+            .text(self.search_text.clone())
+            .onclick(move |_| search.call(()));
+        let reset_button = Button::new()
+            .class(classnames::FILTER_BAR_RESET)
+            .text(self.reset_text.clone())
+            .onclick(reset);
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::FILTER_BAR_FIELDS, {items} }
+                div { class: classnames::FILTER_BAR_ACTIONS,
+                    {search_button.to_element()}
+                    {reset_button.to_element()}
+                    if collapsible {
+                        span {
+                            class: classnames::FILTER_BAR_TOGGLE,
+                            role: "button",
+                            aria_expanded: expanded(),
+                            onclick: move |_| expanded.toggle(),
+                            if expanded() {
+                                "收起 ▴"
+                            } else {
+                                "展开 ▾"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_parse_fields() {
+        let field = FilterField::number_range("price", "价格");
+        assert_eq!(
+            field.parse(&["10".to_string(), String::new()]),
+            Some(FilterValue::NumberRange(Some(10.0), None))
+        );
+        assert_eq!(field.parse(&[String::new(), "abc".to_string()]), None);
+        assert_eq!(
+            field.raw_values(Some(&FilterValue::NumberRange(None, Some(2.5)))),
+            ["", "2.5"]
+        );
+
+        let field = FilterField::input("name", "名称");
+        assert_eq!(field.parse(&["  ".to_string()]), None);
+        assert_eq!(
+            field.parse(&[" a ".to_string()]),
+            Some(FilterValue::Text("a".to_string()))
+        );
+    }
+
+    #[test]
+    fn test_filter_bar() {
+        fn app() -> Element {
+            let mut last = use_signal(|| None::<FilterQuery>);
+            let mut initial = FilterQuery::new();
+            initial.insert("status".to_string(), FilterValue::Select("on".to_string()));
+            let query = use_signal(|| initial);
+            let summary = last
+                .read()
+                .as_ref()
+                .map(|q| q.keys().cloned().collect::<Vec<_>>().join(","))
+                .unwrap_or_default();
+            rsx! {
+                {FilterBar::new()
+                    .value(query)
+                    .field(FilterField::input("name", "名称"))
+                    .field(FilterField::select("status", "状态", [("on", "启用"), ("off", "停用")]))
+                    .field(FilterField::date_range("created", "创建日期"))
+                    .field(FilterField::number_range("price", "价格"))
+                    .onsearch(move |query| last.set(Some(query)))
+                    .to_element()}
+                span { class: "last", "{summary}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        // 默认只显示前三个字段
+        assert_eq!(
+            harness.find_all_by_class(classnames::FILTER_BAR_ITEM).len(),
+            3
+        );
+        let toggle = harness
+            .find_by_class(classnames::FILTER_BAR_TOGGLE)
+            .unwrap();
+        harness.click(&toggle);
+        assert_eq!(
+            harness.find_all_by_class(classnames::FILTER_BAR_ITEM).len(),
+            4
+        );
+
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&inner, "dioxus");
+        let range = harness.find_all_by_class(classnames::FILTER_BAR_RANGE_INPUT);
+        harness.input(&range[3], "99");
+        let search = harness
+            .find_by_class(classnames::FILTER_BAR_SEARCH)
+            .unwrap();
+        harness.click(&search);
+        assert_eq!(
+            harness.find_by_class("last").unwrap().text(),
+            "name,price,status"
+        );
+
+        let reset = harness.find_by_class(classnames::FILTER_BAR_RESET).unwrap();
+        harness.click(&reset);
+        assert_eq!(harness.find_by_class("last").unwrap().text(), "");
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.attr("value"), Some(""));
+    }
+}
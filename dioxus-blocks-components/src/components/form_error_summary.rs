@@ -0,0 +1,243 @@
+//! FormErrorSummary 表单错误汇总组件
+//!
+//! 把表单当前的所有校验错误汇总为一个错误提示，每条错误都是指向对应字段的链接，
+//! 点击后滚动到该字段并使其获得焦点。适合放在较长表单的顶部，提交失败时读屏软件会朗读汇总内容，
+//! 用户也不必逐个查找出错的字段。
+//!
+//! 错误通过 [`FormError`] 描述，`field` 为字段控件的元素 id，如 [`Input::input_id`](crate::Input::input_id)
+//! 设置的值。没有错误时不渲染任何内容。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{FormError, FormErrorSummary, Input, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let email = use_signal(String::new);
+//!     let mut errors = Vec::new();
+//!     if !email.read().contains('@') {
+//!         errors.push(FormError::new("email", "请输入有效的邮箱地址").label("邮箱"));
+//!     }
+//!     View::new()
+//!         .children(FormErrorSummary::new().errors(errors))
+//!         .children(Input::new().value(email).input_id("email"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Alert, Style, classnames, traits::ToElement};
+
+/// 表单字段的校验错误
+#[derive(Debug, Clone, PartialEq)]
+pub struct FormError {
+    /// 字段控件的元素 id
+    field: String,
+    /// 字段名称
+    label: Option<String>,
+    /// 错误信息
+    message: String,
+}
+
+#[builder_props]
+impl FormError {
+    /// 创建校验错误，`field` 为字段控件的元素 id
+    pub fn new(field: impl Into<String>, message: impl Into<String>) -> Self {
+        Self {
+            field: field.into(),
+            label: None,
+            message: message.into(),
+        }
+    }
+
+    /// 设置字段名称，显示在错误信息之前
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// 链接显示的文本
+    fn text(&self) -> String {
+        match &self.label {
+            Some(label) => format!("{label}：{}", self.message),
+            None => self.message.clone(),
+        }
+    }
+}
+
+/// 表单错误汇总组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct FormErrorSummary {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在错误列表之后
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 校验错误
+    errors: Vec<FormError>,
+    /// 标题，`None` 时显示错误数量
+    title: Option<String>,
+}
+
+impl Default for FormErrorSummary {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::FORM_ERROR_SUMMARY),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            errors: Vec::new(),
+            title: None,
+        }
+    }
+}
+
+#[builder_props]
+impl FormErrorSummary {
+    /// 创建表单错误汇总
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加校验错误
+    pub fn error(mut self, error: FormError) -> Self {
+        self.errors.push(error);
+        self
+    }
+
+    /// 批量添加校验错误
+    pub fn errors(mut self, errors: impl IntoIterator<Item = FormError>) -> Self {
+        self.errors.extend(errors);
+        self
+    }
+
+    /// 设置标题，默认为「请修正以下 N 个问题」
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+}
+
+/// 滚动到字段并使其获得焦点，非浏览器环境中脚本执行失败，直接忽略
+fn focus_field(id: &str) {
+    let js = format!(
+        "const el = document.getElementById({id:?}); if (el) {{ \
+         el.scrollIntoView({{ block: 'center' }}); el.focus({{ preventScroll: true }}); }}"
+    );
+    let _ = document::eval(&js);
+}
+
+/// 错误链接列表
+#[derive(Debug, Clone)]
+struct ErrorList {
+    errors: Vec<FormError>,
+}
+
+impl ToElement for ErrorList {
+    fn to_element(&self) -> Element {
+        rsx! {
+            ul { class: classnames::FORM_ERROR_SUMMARY_LIST,
+                for error in self.errors.iter() {
+                    li { key: "{error.field}", class: classnames::FORM_ERROR_SUMMARY_ITEM,
+                        a {
+                            class: classnames::FORM_ERROR_SUMMARY_LINK,
+                            href: "#{error.field}",
+                            onclick: {
+                                let field = error.field.clone();
+                                move |event: MouseEvent| {
+                                    // 阻止修改地址栏的锚点，由脚本负责滚动和聚焦
+                                    event.prevent_default();
+                                    focus_field(&field);
+                                }
+                            },
+                            "{error.text()}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ToElement for FormErrorSummary {
+    fn to_element(&self) -> Element {
+        if self.errors.is_empty() {
+            return rsx! {};
+        }
+
+        let title = self
+            .title
+            .clone()
+            .unwrap_or_else(|| format!("请修正以下 {} 个问题", self.errors.len()));
+        let mut alert = Alert::new(title)
+            .as_error()
+            .class(self.class.to_string())
+            .children(ErrorList {
+                errors: self.errors.clone(),
+            })
+            .childrens2(self.childrens.clone());
+        if let Some(id) = &self.id {
+            alert = alert.id(id.clone());
+        }
+        if let Some(style) = self.style.clone() {
+            alert = alert.style(|_| style);
+        }
+        if let Some(handler) = self.onclick {
+            alert = alert.onclick2(handler);
+        }
+        alert.to_element()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_form_error_summary() {
+        let harness = Harness::with_element(
+            FormErrorSummary::new()
+                .error(FormError::new("username", "请输入用户名").label("用户名"))
+                .error(FormError::new("email", "请输入有效的邮箱地址")),
+        );
+        let root = harness
+            .find_by_class(classnames::FORM_ERROR_SUMMARY)
+            .unwrap();
+        assert!(root.has_class(classnames::ALERT));
+        assert_eq!(root.attr("role"), Some("alert"));
+        assert!(harness.find_by_text("请修正以下 2 个问题").is_some());
+
+        let links = harness.find_all_by_class(classnames::FORM_ERROR_SUMMARY_LINK);
+        assert_eq!(links.len(), 2);
+        assert_eq!(links[0].attr("href"), Some("#username"));
+        assert_eq!(links[0].text(), "用户名：请输入用户名");
+        assert_eq!(links[1].text(), "请输入有效的邮箱地址");
+
+        let harness = Harness::with_element(FormErrorSummary::new());
+        assert!(
+            harness
+                .find_by_class(classnames::FORM_ERROR_SUMMARY)
+                .is_none()
+        );
+    }
+}
@@ -0,0 +1,1197 @@
+//! Form 表单组件
+//!
+//! [`Form`] 为一组 [`FormItem`] 提供统一的标签布局（左对齐、右对齐或在控件上方）和校验状态，
+//! [`FormItem`] 包裹 Input、InputNumber、Select、Radio、Checkbox 等输入控件，显示标签、必填标记和校验错误。
+//!
+//! 校验状态保存在 [`use_form`] 创建的 [`FormState`] 中，表单项通过 [`FormItem::value`] 绑定控件使用的
+//! `Signal`，并通过 [`Rule`] 设置校验规则：[`Rule::required`]、[`Rule::min_len`]、[`Rule::max_len`]、
+//! [`Rule::pattern`] 和自定义闭包 [`Rule::custom`]。
+//!
+//! 提交表单或调用 [`FormState::validate`] 时校验全部字段；字段失去焦点后单独校验，之后值变化时重新校验。
+//! [`FormState::errors`] 返回的错误可以直接交给 [`FormErrorSummary`](crate::FormErrorSummary) 汇总显示，
+//! 控件的 id 与字段名相同时（如 [`Input::input_id`](crate::Input::input_id)），点击标签或错误链接会聚焦该控件。
+//!
+//! 字段之间可以声明依赖：[`FormItem::visible_when`] 根据其他字段的值显示或隐藏表单项，隐藏的表单项不注册到表单，
+//! 不参与校验；[`FormItem::depends_on`] 在依赖的字段变化时重新校验当前字段，如确认密码依赖密码。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     Button, Form, FormItem, FormLabelPosition, Input, Rule, ToElement, use_form,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let form = use_form();
+//!     let name = use_signal(String::new);
+//!     let email = use_signal(String::new);
+//!
+//!     Form::new(form)
+//!         .label_position(FormLabelPosition::Top)
+//!         .children(
+//!             FormItem::new("name", "姓名")
+//!                 .value(name)
+//!                 .rule(Rule::required())
+//!                 .rule(Rule::min_len(2))
+//!                 .children(Input::new().value(name).input_id("name")),
+//!         )
+//!         .children(
+//!             FormItem::new("email", "邮箱")
+//!                 .value(email)
+//!                 .rule(Rule::required())
+//!                 .rule(Rule::pattern(r"^[^@\s]+@[^@\s]+$").message("请输入有效的邮箱地址"))
+//!                 .children(Input::new().value(email).input_id("email")),
+//!         )
+//!         .children(Button::new().text("提交").as_primary())
+//!         .onsubmit(move |_| println!("{} {}", name(), email()))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use indexmap::IndexMap;
+use regex::Regex;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{FormError, Style, classnames, traits::ToElement};
+
+/// 校验时读取的字段值
+///
+/// 启用 `schema_form` 特性时可以序列化，文本、列表和开关分别对应字符串、字符串数组和布尔值。
+#[derive(Debug, Clone, PartialEq)]
+#[cfg_attr(
+    feature = "schema_form",
+    derive(serde::Serialize, serde::Deserialize),
+    serde(untagged)
+)]
+pub enum FormValue {
+    /// 文本，如输入框、单选框和数字输入框的值
+    Text(String),
+    /// 列表，如多选框组中选中的值
+    List(Vec<String>),
+    /// 开关，如单个复选框是否勾选
+    Bool(bool),
+}
+
+impl FormValue {
+    /// 是否为空：文本去掉首尾空白后为空、列表为空或开关未打开
+    pub fn is_empty(&self) -> bool {
+        match self {
+            FormValue::Text(text) => text.trim().is_empty(),
+            FormValue::List(items) => items.is_empty(),
+            FormValue::Bool(on) => !on,
+        }
+    }
+
+    /// 长度：文本的字符数或列表的项数，开关为 0
+    pub fn len(&self) -> usize {
+        match self {
+            FormValue::Text(text) => text.chars().count(),
+            FormValue::List(items) => items.len(),
+            FormValue::Bool(_) => 0,
+        }
+    }
+
+    /// 文本内容，列表以逗号连接
+    pub fn as_text(&self) -> String {
+        match self {
+            FormValue::Text(text) => text.clone(),
+            FormValue::List(items) => items.join(","),
+            FormValue::Bool(on) => on.to_string(),
+        }
+    }
+}
+
+impl From<String> for FormValue {
+    fn from(text: String) -> Self {
+        FormValue::Text(text)
+    }
+}
+
+impl From<&str> for FormValue {
+    fn from(text: &str) -> Self {
+        FormValue::Text(text.to_string())
+    }
+}
+
+impl From<Vec<String>> for FormValue {
+    fn from(items: Vec<String>) -> Self {
+        FormValue::List(items)
+    }
+}
+
+impl From<bool> for FormValue {
+    fn from(on: bool) -> Self {
+        FormValue::Bool(on)
+    }
+}
+
+/// 可以绑定到 [`FormItem`] 的字段值，通常是输入控件使用的 `Signal`
+pub trait FormField: 'static {
+    /// 读取当前的值，在副作用中调用时会订阅值的变化
+    fn form_value(&self) -> FormValue;
+}
+
+impl FormField for Signal<String> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Text(self.read().clone())
+    }
+}
+
+impl FormField for Signal<bool> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Bool(*self.read())
+    }
+}
+
+impl FormField for Signal<Vec<String>> {
+    fn form_value(&self) -> FormValue {
+        FormValue::List(self.read().clone())
+    }
+}
+
+#[cfg(feature = "radio")]
+impl FormField for Signal<crate::RadioValue> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Text(self.read().to_string())
+    }
+}
+
+#[cfg(feature = "checkbox")]
+impl FormField for Signal<Vec<crate::CheckboxValue>> {
+    fn form_value(&self) -> FormValue {
+        FormValue::List(self.read().iter().map(ToString::to_string).collect())
+    }
+}
+
+#[cfg(feature = "select")]
+impl FormField for Signal<Option<crate::SelectValue>> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Text(
+            self.read()
+                .as_ref()
+                .map(ToString::to_string)
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(feature = "select")]
+impl FormField for Signal<Vec<crate::SelectValue>> {
+    fn form_value(&self) -> FormValue {
+        FormValue::List(self.read().iter().map(ToString::to_string).collect())
+    }
+}
+
+#[cfg(feature = "time_picker")]
+impl FormField for Signal<Option<crate::TimeValue>> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Text(
+            (*self.read())
+                .map(|time| time.to_string())
+                .unwrap_or_default(),
+        )
+    }
+}
+
+#[cfg(feature = "input_number")]
+impl FormField for Signal<crate::InputNumberValue> {
+    fn form_value(&self) -> FormValue {
+        FormValue::Text(self.read().to_string())
+    }
+}
+
+#[cfg(feature = "upload")]
+impl FormField for Signal<Vec<crate::UploadFile>> {
+    fn form_value(&self) -> FormValue {
+        FormValue::List(
+            self.read()
+                .iter()
+                .map(|file| file.name().to_string())
+                .collect(),
+        )
+    }
+}
+
+/// 自定义校验闭包，通过时返回 `Ok(())`，否则返回错误信息
+type Validator = Rc<dyn Fn(&FormValue) -> Result<(), String>>;
+
+#[derive(Clone)]
+enum RuleKind {
+    Required,
+    MinLen(usize),
+    MaxLen(usize),
+    Pattern(Regex),
+    Custom(Validator),
+}
+
+/// 校验规则
+///
+/// 除 [`Rule::required`] 外，其他规则在值为空时不校验，需要必填时同时添加 [`Rule::required`]。
+#[derive(Clone)]
+pub struct Rule {
+    kind: RuleKind,
+    /// 自定义错误信息
+    message: Option<String>,
+}
+
+impl std::fmt::Debug for Rule {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let kind = match &self.kind {
+            RuleKind::Required => "required".to_string(),
+            RuleKind::MinLen(len) => format!("min_len({len})"),
+            RuleKind::MaxLen(len) => format!("max_len({len})"),
+            RuleKind::Pattern(regex) => format!("pattern({})", regex.as_str()),
+            RuleKind::Custom(_) => "custom".to_string(),
+        };
+        f.debug_struct("Rule")
+            .field("kind", &kind)
+            .field("message", &self.message)
+            .finish()
+    }
+}
+
+impl Rule {
+    fn new(kind: RuleKind) -> Self {
+        Self {
+            kind,
+            message: None,
+        }
+    }
+
+    /// 必填：文本不能为空白、列表至少选择一项、开关必须打开
+    pub fn required() -> Self {
+        Self::new(RuleKind::Required)
+    }
+
+    /// 最少字符数，列表为最少选择的项数
+    pub fn min_len(len: usize) -> Self {
+        Self::new(RuleKind::MinLen(len))
+    }
+
+    /// 最多字符数，列表为最多选择的项数
+    pub fn max_len(len: usize) -> Self {
+        Self::new(RuleKind::MaxLen(len))
+    }
+
+    /// 文本必须匹配正则表达式，表达式无效时 panic
+    pub fn pattern(pattern: &str) -> Self {
+        let regex = Regex::new(pattern)
+            .unwrap_or_else(|err| panic!("invalid form rule pattern `{pattern}`: {err}"));
+        Self::new(RuleKind::Pattern(regex))
+    }
+
+    /// 自定义校验闭包，通过时返回 `Ok(())`，否则返回错误信息
+    pub fn custom(f: impl Fn(&FormValue) -> Result<(), String> + 'static) -> Self {
+        Self::new(RuleKind::Custom(Rc::new(f)))
+    }
+
+    /// 设置校验失败时的错误信息，替代默认信息
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// 是否为必填规则
+    pub fn is_required(&self) -> bool {
+        matches!(self.kind, RuleKind::Required)
+    }
+
+    /// 校验值，`label` 为字段名称，用于生成默认的错误信息
+    pub fn check(&self, label: &str, value: &FormValue) -> Result<(), String> {
+        let list = matches!(value, FormValue::List(_));
+        let error = match &self.kind {
+            RuleKind::Required => value.is_empty().then(|| match value {
+                _ if label.is_empty() => "此项为必填项".to_string(),
+                FormValue::Text(_) => format!("请输入{label}"),
+                FormValue::List(_) => format!("请选择{label}"),
+                FormValue::Bool(_) => format!("请勾选{label}"),
+            }),
+            _ if value.is_empty() => None,
+            RuleKind::MinLen(len) => (value.len() < *len).then(|| match list {
+                true => format!("{label}至少选择 {len} 项"),
+                false => format!("{label}至少 {len} 个字符"),
+            }),
+            RuleKind::MaxLen(len) => (value.len() > *len).then(|| match list {
+                true => format!("{label}最多选择 {len} 项"),
+                false => format!("{label}最多 {len} 个字符"),
+            }),
+            RuleKind::Pattern(regex) => {
+                (!regex.is_match(&value.as_text())).then(|| format!("{label}格式不正确"))
+            }
+            RuleKind::Custom(validate) => validate(value).err(),
+        };
+        match error {
+            Some(error) => Err(self.message.clone().unwrap_or(error)),
+            None => Ok(()),
+        }
+    }
+}
+
+/// 注册到表单的字段
+struct FormFieldEntry {
+    label: String,
+    rules: Vec<Rule>,
+    value: Rc<dyn Fn() -> FormValue>,
+}
+
+/// 表单状态，保存注册的字段和校验错误
+///
+/// 由 [`use_form`] 创建，可以复制到事件处理闭包中使用。
+#[derive(Clone, Copy, PartialEq)]
+pub struct FormState {
+    fields: CopyValue<IndexMap<String, FormFieldEntry>>,
+    errors: Signal<IndexMap<String, String>>,
+    /// 是否已经整体校验过，之后字段的值变化时立即重新校验
+    submitted: Signal<bool>,
+}
+
+impl std::fmt::Debug for FormState {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("FormState")
+            .field("fields", &self.fields.peek().keys().collect::<Vec<_>>())
+            .field("errors", &*self.errors.peek())
+            .finish()
+    }
+}
+
+/// 创建表单状态
+pub fn use_form() -> FormState {
+    FormState {
+        fields: use_hook(|| CopyValue::new(IndexMap::new())),
+        errors: use_signal(IndexMap::new),
+        submitted: use_signal(|| false),
+    }
+}
+
+impl FormState {
+    /// 校验全部字段，全部通过时返回 `true`，之后字段的值变化时立即重新校验
+    pub fn validate(&self) -> bool {
+        let mut submitted = self.submitted;
+        submitted.set(true);
+        let props = self.fields.peek().keys().cloned().collect::<Vec<_>>();
+        // 校验每个字段以显示全部错误，不在第一个错误处停止
+        let results = props
+            .iter()
+            .map(|prop| self.validate_field(prop))
+            .collect::<Vec<_>>();
+        results.into_iter().all(|valid| valid)
+    }
+
+    /// 校验单个字段，通过或字段未注册时返回 `true`
+    pub fn validate_field(&self, prop: &str) -> bool {
+        let result = {
+            let fields = self.fields.peek();
+            let Some(field) = fields.get(prop) else {
+                return true;
+            };
+            let value = (field.value)();
+            field
+                .rules
+                .iter()
+                .try_for_each(|rule| rule.check(&field.label, &value))
+        };
+
+        let mut errors = self.errors;
+        match result {
+            Ok(()) => {
+                if errors.peek().contains_key(prop) {
+                    errors.write().shift_remove(prop);
+                }
+                true
+            }
+            Err(message) => {
+                if errors.peek().get(prop) != Some(&message) {
+                    errors.write().insert(prop.to_string(), message);
+                }
+                false
+            }
+        }
+    }
+
+    /// 已注册字段的当前值，字段未注册或已隐藏时返回 `None`
+    ///
+    /// 在组件渲染或副作用中调用时会订阅该字段的值，值变化后重新执行。
+    pub fn value(&self, prop: &str) -> Option<FormValue> {
+        let fields = self.fields.peek();
+        fields.get(prop).map(|field| (field.value)())
+    }
+
+    /// 字段的错误信息
+    pub fn error(&self, prop: &str) -> Option<String> {
+        self.errors.read().get(prop).cloned()
+    }
+
+    /// 全部校验错误，按字段注册的顺序排列，可以交给 [`FormErrorSummary`](crate::FormErrorSummary) 显示
+    pub fn errors(&self) -> Vec<FormError> {
+        let fields = self.fields.peek();
+        let errors = self.errors.read();
+        fields
+            .iter()
+            .filter_map(|(prop, field)| {
+                let message = errors.get(prop)?;
+                let error = FormError::new(prop.clone(), message.clone());
+                Some(match field.label.is_empty() {
+                    true => error,
+                    false => error.label(field.label.clone()),
+                })
+            })
+            .collect()
+    }
+
+    /// 当前是否没有校验错误
+    pub fn is_valid(&self) -> bool {
+        self.errors.read().is_empty()
+    }
+
+    /// 清除全部校验错误，之后字段的值变化时不再立即校验
+    pub fn clear_validate(&self) {
+        let mut errors = self.errors;
+        let mut submitted = self.submitted;
+        errors.write().clear();
+        submitted.set(false);
+    }
+
+    fn register(&self, prop: String, field: FormFieldEntry) {
+        let mut fields = self.fields;
+        fields.write().insert(prop, field);
+    }
+
+    fn unregister(&self, prop: &str) {
+        let mut fields = self.fields;
+        let mut errors = self.errors;
+        if let Ok(mut fields) = fields.try_write() {
+            fields.shift_remove(prop);
+        }
+        if let Ok(mut errors) = errors.try_write() {
+            errors.shift_remove(prop);
+        }
+    }
+}
+
+/// 标签的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum FormLabelPosition {
+    /// 在控件左侧，文字左对齐
+    Left,
+    /// 在控件左侧，文字右对齐
+    #[default]
+    Right,
+    /// 在控件上方
+    Top,
+}
+
+impl FormLabelPosition {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            FormLabelPosition::Left => "t-form--label-left",
+            FormLabelPosition::Right => "t-form--label-right",
+            FormLabelPosition::Top => "t-form--label-top",
+        }
+    }
+}
+
+impl std::fmt::Display for FormLabelPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            FormLabelPosition::Left => "left",
+            FormLabelPosition::Right => "right",
+            FormLabelPosition::Top => "top",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// 表单组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Form {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 表单状态
+    state: Option<FormState>,
+    /// 标签的位置
+    label_position: FormLabelPosition,
+    /// 标签的宽度
+    label_width: String,
+    /// 提交且校验通过时的回调
+    onsubmit: Option<EventHandler<()>>,
+    /// 提交但校验未通过时的回调，参数为全部校验错误
+    oninvalid: Option<EventHandler<Vec<FormError>>>,
+}
+
+impl Default for Form {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::FORM),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            state: None,
+            label_position: FormLabelPosition::default(),
+            label_width: "100px".to_string(),
+            onsubmit: None,
+            oninvalid: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Form {
+    /// 使用 [`use_form`] 创建的表单状态创建表单
+    pub fn new(state: FormState) -> Self {
+        Self {
+            state: Some(state),
+            ..Default::default()
+        }
+    }
+
+    /// 设置标签的位置
+    #[prop(default = "FormLabelPosition::Right")]
+    pub fn label_position(mut self, position: FormLabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// 设置标签的宽度，标签在控件上方时不生效
+    #[prop(default = "\"100px\"")]
+    pub fn label_width(mut self, width: impl Into<String>) -> Self {
+        self.label_width = width.into();
+        self
+    }
+
+    /// 设置提交且全部字段校验通过时的回调，在输入框中按回车或点击表单内的按钮时触发
+    pub fn onsubmit(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.onsubmit = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置提交但校验未通过时的回调，参数为全部校验错误
+    pub fn oninvalid(mut self, handler: impl FnMut(Vec<FormError>) + 'static) -> Self {
+        self.oninvalid = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for Form {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = format!("{} {}", self.class, self.label_position.as_class());
+        let style = format!(
+            "--t-form-label-width: {};{}",
+            self.label_width,
+            self.style
+                .clone()
+                .map(|s| s.to_string())
+                .unwrap_or_default()
+        );
+        let onclick_handler = self.onclick;
+        let state = self.state;
+        let onsubmit = self.onsubmit;
+        let oninvalid = self.oninvalid;
+
+        rsx! {
+            form {
+                id,
+                class,
+                style,
+                novalidate: true,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                onsubmit: move |event: FormEvent| {
+                    event.prevent_default();
+                    let Some(state) = state else {
+                        return;
+                    };
+                    if state.validate() {
+                        if let Some(handler) = onsubmit {
+                            handler.call(());
+                        }
+                    } else if let Some(handler) = oninvalid {
+                        handler.call(state.errors());
+                    }
+                },
+                if let Some(state) = state {
+                    FormProvider { state, {self.childrens_to_element()} }
+                } else {
+                    {self.childrens_to_element()}
+                }
+            }
+        }
+    }
+}
+
+/// 向表单项提供表单状态
+#[component]
+fn FormProvider(state: FormState, children: Element) -> Element {
+    use_context_provider(|| state);
+    children
+}
+
+/// 字段值的读取闭包
+#[derive(Clone)]
+struct FieldReader(Rc<dyn Fn() -> FormValue>);
+
+impl std::fmt::Debug for FieldReader {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("FieldReader")
+    }
+}
+
+/// 判断表单项是否显示的闭包
+#[derive(Clone)]
+struct VisibleWhen(Rc<dyn Fn(&FormState) -> bool>);
+
+impl std::fmt::Debug for VisibleWhen {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("VisibleWhen")
+    }
+}
+
+/// 表单项组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct FormItem {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，通常为一个输入控件
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 字段名
+    prop: String,
+    /// 标签文本
+    label: String,
+    /// 绑定的字段值
+    value: Option<FieldReader>,
+    /// 校验规则
+    rules: Vec<Rule>,
+    /// 是否必填
+    required: bool,
+    /// 标签的宽度，覆盖表单的设置
+    label_width: Option<String>,
+    /// 显示条件
+    visible_when: Option<VisibleWhen>,
+    /// 变化时重新校验当前字段的字段名
+    depends_on: Vec<String>,
+}
+
+impl Default for FormItem {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::FORM_ITEM),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            prop: String::new(),
+            label: String::new(),
+            value: None,
+            rules: Vec::new(),
+            required: false,
+            label_width: None,
+            visible_when: None,
+            depends_on: Vec::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl FormItem {
+    /// 创建表单项，`prop` 为字段名，`label` 为标签文本
+    pub fn new(prop: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            prop: prop.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 绑定字段值，通常传入输入控件使用的 `Signal`，校验时读取
+    pub fn value(mut self, value: impl FormField) -> Self {
+        self.value = Some(FieldReader(Rc::new(move || value.form_value())));
+        self
+    }
+
+    /// 添加校验规则，按添加的顺序校验，显示第一个未通过的规则的错误信息
+    pub fn rule(mut self, rule: Rule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// 批量添加校验规则
+    pub fn rules(mut self, rules: Vec<Rule>) -> Self {
+        self.rules.extend(rules);
+        self
+    }
+
+    /// 设置是否必填，必填时标签前显示星号，没有 [`Rule::required`] 规则时自动添加
+    #[prop(default = "false")]
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// 设置标签的宽度，覆盖表单的设置
+    pub fn label_width(mut self, width: impl Into<String>) -> Self {
+        self.label_width = Some(width.into());
+        self
+    }
+
+    /// 设置显示条件，参数为表单状态，可以通过 [`FormState::value`] 读取其他字段的值
+    ///
+    /// 返回 `false` 时不渲染表单项，字段从表单中移除并清除错误，不参与校验，字段的值保持不变。
+    /// 依赖的字段需要在当前表单项之前注册；不在 [`Form`] 中时始终显示。
+    ///
+    /// ```rust
+    /// use dioxus_blocks_components::{FormItem, FormValue};
+    ///
+    /// let item = FormItem::new("other", "其他来源")
+    ///     .visible_when(|form| form.value("source") == Some(FormValue::Text("other".into())));
+    /// ```
+    pub fn visible_when(mut self, f: impl Fn(&FormState) -> bool + 'static) -> Self {
+        self.visible_when = Some(VisibleWhen(Rc::new(f)));
+        self
+    }
+
+    /// 设置依赖的字段，这些字段的值变化时重新校验当前字段
+    ///
+    /// 与值变化时的校验一样，只在当前字段失去过焦点或表单整体校验后生效。
+    pub fn depends_on<I, S>(mut self, props: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: Into<String>,
+    {
+        self.depends_on.extend(props.into_iter().map(Into::into));
+        self
+    }
+
+    /// 实际生效的校验规则
+    fn effective_rules(&self) -> Vec<Rule> {
+        let mut rules = self.rules.clone();
+        if self.required && !rules.iter().any(Rule::is_required) {
+            rules.insert(0, Rule::required());
+        }
+        rules
+    }
+}
+
+impl ToElement for FormItem {
+    fn to_element(&self) -> Element {
+        rsx! {
+            FormItemElement { item: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct FormItemElementProps {
+    item: Rc<FormItem>,
+}
+
+impl PartialEq for FormItemElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.item, &other.item)
+    }
+}
+
+#[allow(non_snake_case)]
+fn FormItemElement(props: FormItemElementProps) -> Element {
+    let item = props.item;
+    let state = try_consume_context::<FormState>();
+    // 渲染时读取依赖的字段，字段变化后重新判断；隐藏时卸载内容，字段随之从表单中移除
+    let visible = match (state, &item.visible_when) {
+        (Some(state), Some(visible_when)) => (visible_when.0)(&state),
+        _ => true,
+    };
+
+    rsx! {
+        if visible {
+            FormItemContent { item }
+        }
+    }
+}
+
+#[allow(non_snake_case)]
+fn FormItemContent(props: FormItemElementProps) -> Element {
+    let item = props.item;
+    let state = try_consume_context::<FormState>();
+    let rules = item.effective_rules();
+    let required = rules.iter().any(Rule::is_required);
+
+    // 每次渲染更新注册的字段，规则和标签变化后立即生效
+    if let (Some(state), Some(value)) = (state, item.value.clone()) {
+        state.register(
+            item.prop.clone(),
+            FormFieldEntry {
+                label: item.label.clone(),
+                rules,
+                value: value.0,
+            },
+        );
+    }
+    let prop = item.prop.clone();
+    use_drop(move || {
+        if let Some(state) = state {
+            state.unregister(&prop);
+        }
+    });
+
+    // 失去焦点或整体校验后，值或依赖的字段变化时重新校验
+    let mut touched = use_signal(|| false);
+    let reader = item.value.clone();
+    let prop = item.prop.clone();
+    let depends_on = item.depends_on.clone();
+    use_effect(move || {
+        let (Some(state), Some(reader)) = (state, &reader) else {
+            return;
+        };
+        (reader.0)();
+        for dependency in &depends_on {
+            state.value(dependency);
+        }
+        if touched() || (state.submitted)() {
+            state.validate_field(&prop);
+        }
+    });
+
+    let error = state.and_then(|state| state.error(&item.prop));
+    let mut class = vec![item.class.to_string()];
+    if required {
+        class.push("is-required".to_string());
+    }
+    if error.is_some() {
+        class.push("is-error".to_string());
+    }
+    let class = class.join(" ");
+    let style = item.style.clone().map(|s| s.to_string());
+    let label_style = item
+        .label_width
+        .as_ref()
+        .map(|width| format!("flex-basis: {width}; width: {width}"));
+    let error_id = format!("{}-error", item.prop);
+    let invalid = error.is_some();
+    let describedby = invalid.then(|| error_id.clone());
+    let onclick_handler = item.onclick;
+
+    rsx! {
+        div {
+            id: item.id.clone(),
+            class,
+            style,
+            "aria-invalid": invalid.then_some("true"),
+            "aria-describedby": describedby,
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            onfocusout: move |_| touched.set(true),
+            label {
+                class: classnames::FORM_ITEM_LABEL,
+                r#for: item.prop.clone(),
+                style: label_style,
+                "{item.label}"
+            }
+            div { class: classnames::FORM_ITEM_CONTENT,
+                {item.childrens_to_element()}
+                if let Some(error) = error {
+                    div {
+                        id: error_id,
+                        class: classnames::FORM_ITEM_ERROR,
+                        role: "alert",
+                        "{error}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Input;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_rule_check() {
+        let text = |s: &str| FormValue::Text(s.to_string());
+
+        assert_eq!(
+            Rule::required().check("姓名", &text(" ")),
+            Err("请输入姓名".to_string())
+        );
+        assert_eq!(
+            Rule::required().check("爱好", &FormValue::List(Vec::new())),
+            Err("请选择爱好".to_string())
+        );
+        assert_eq!(
+            Rule::required().check("", &FormValue::Bool(false)),
+            Err("此项为必填项".to_string())
+        );
+        assert!(Rule::required().check("姓名", &text("张三")).is_ok());
+
+        // 非必填规则不校验空值
+        assert!(Rule::min_len(2).check("姓名", &text("")).is_ok());
+        assert_eq!(
+            Rule::min_len(2).check("姓名", &text("张")),
+            Err("姓名至少 2 个字符".to_string())
+        );
+        assert_eq!(
+            Rule::max_len(1).check("爱好", &FormValue::List(vec!["a".into(), "b".into()])),
+            Err("爱好最多选择 1 项".to_string())
+        );
+
+        let email = Rule::pattern(r"^[^@\s]+@[^@\s]+$").message("请输入有效的邮箱地址");
+        assert_eq!(
+            email.check("邮箱", &text("foo")),
+            Err("请输入有效的邮箱地址".to_string())
+        );
+        assert!(email.check("邮箱", &text("foo@example.com")).is_ok());
+
+        let even = Rule::custom(|value| match value.as_text().parse::<i64>() {
+            Ok(n) if n % 2 == 0 => Ok(()),
+            _ => Err("请输入偶数".to_string()),
+        });
+        assert_eq!(
+            even.check("数量", &text("3")),
+            Err("请输入偶数".to_string())
+        );
+        assert!(even.check("数量", &text("4")).is_ok());
+    }
+
+    fn app() -> Element {
+        let form = use_form();
+        let name = use_signal(String::new);
+        let mut valid = use_signal(|| None::<bool>);
+
+        rsx! {
+            {
+                Form::new(form)
+                    .label_position(FormLabelPosition::Top)
+                    .children(
+                        FormItem::new("name", "姓名")
+                            .value(name)
+                            .required(true)
+                            .rule(Rule::min_len(2))
+                            .children(Input::new().value(name).input_id("name")),
+                    )
+                    .to_element()
+            }
+            button { class: "validate", onclick: move |_| valid.set(Some(form.validate())) }
+            span { class: "summary", "{form.errors().len()}" }
+            span { class: "valid", "{valid():?}" }
+        }
+    }
+
+    #[test]
+    fn test_form_validate() {
+        let mut harness = Harness::new(app);
+        let form = harness.find_by_class(classnames::FORM).unwrap();
+        assert!(form.has_class("t-form--label-top"));
+        let item = harness.find_by_class(classnames::FORM_ITEM).unwrap();
+        assert!(item.has_class("is-required"));
+        assert!(!item.has_class("is-error"));
+        assert_eq!(
+            harness
+                .find_by_class(classnames::FORM_ITEM_LABEL)
+                .unwrap()
+                .attr("for"),
+            Some("name")
+        );
+
+        let button = harness.find_by_class("validate").unwrap();
+        harness.click(&button);
+        assert_eq!(
+            harness.find_by_class("valid").unwrap().text(),
+            "Some(false)"
+        );
+        assert_eq!(harness.find_by_class("summary").unwrap().text(), "1");
+        let error = harness.find_by_class(classnames::FORM_ITEM_ERROR).unwrap();
+        assert_eq!(error.text(), "请输入姓名");
+        assert!(
+            harness
+                .find_by_class(classnames::FORM_ITEM)
+                .unwrap()
+                .has_class("is-error")
+        );
+
+        // 整体校验后值变化时立即重新校验
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&input, "张");
+        assert_eq!(
+            harness
+                .find_by_class(classnames::FORM_ITEM_ERROR)
+                .unwrap()
+                .text(),
+            "姓名至少 2 个字符"
+        );
+        harness.input(&input, "张三");
+        assert!(harness.find_by_class(classnames::FORM_ITEM_ERROR).is_none());
+        harness.click(&button);
+        assert_eq!(harness.find_by_class("valid").unwrap().text(), "Some(true)");
+    }
+
+    #[test]
+    fn test_form_item_visible_when() {
+        fn app() -> Element {
+            let form = use_form();
+            let source = use_signal(String::new);
+            let other = use_signal(String::new);
+            let mut valid = use_signal(|| None::<bool>);
+
+            rsx! {
+                {
+                    Form::new(form)
+                        .children(
+                            FormItem::new("source", "来源")
+                                .value(source)
+                                .children(Input::new().value(source).input_id("source")),
+                        )
+                        .children(
+                            FormItem::new("other", "其他来源")
+                                .value(other)
+                                .required(true)
+                                .visible_when(|form| {
+                                    form.value("source") == Some(FormValue::Text("other".into()))
+                                })
+                                .children(Input::new().value(other).input_id("other")),
+                        )
+                        .to_element()
+                }
+                button { class: "validate", onclick: move |_| valid.set(Some(form.validate())) }
+                span { class: "valid", "{valid():?}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(harness.find_all_by_class(classnames::FORM_ITEM).len(), 1);
+        // 隐藏的必填字段不参与校验
+        let button = harness.find_by_class("validate").unwrap();
+        harness.click(&button);
+        assert_eq!(harness.find_by_class("valid").unwrap().text(), "Some(true)");
+
+        let source = harness.find_by_attr("id", "source").unwrap();
+        harness.input(&source, "other");
+        assert_eq!(harness.find_all_by_class(classnames::FORM_ITEM).len(), 2);
+        harness.click(&button);
+        assert_eq!(
+            harness.find_by_class("valid").unwrap().text(),
+            "Some(false)"
+        );
+        assert_eq!(
+            harness
+                .find_by_class(classnames::FORM_ITEM_ERROR)
+                .unwrap()
+                .text(),
+            "请输入其他来源"
+        );
+
+        // 再次隐藏时清除错误
+        harness.input(&source, "web");
+        assert_eq!(harness.find_all_by_class(classnames::FORM_ITEM).len(), 1);
+        assert!(harness.find_by_class(classnames::FORM_ITEM_ERROR).is_none());
+        harness.click(&button);
+        assert_eq!(harness.find_by_class("valid").unwrap().text(), "Some(true)");
+    }
+
+    #[test]
+    fn test_form_item_depends_on() {
+        fn app() -> Element {
+            let form = use_form();
+            let password = use_signal(String::new);
+            let confirm = use_signal(String::new);
+
+            rsx! {
+                {
+                    Form::new(form)
+                        .children(
+                            FormItem::new("password", "密码")
+                                .value(password)
+                                .children(Input::new().value(password).input_id("password")),
+                        )
+                        .children(
+                            FormItem::new("confirm", "确认密码")
+                                .value(confirm)
+                                .depends_on(["password"])
+                                .rule(Rule::custom(move |value| {
+                                    match value.as_text() == password() {
+                                        true => Ok(()),
+                                        false => Err("两次输入的密码不一致".to_string()),
+                                    }
+                                }))
+                                .children(Input::new().value(confirm).input_id("confirm")),
+                        )
+                        .to_element()
+                }
+                button { class: "validate", onclick: move |_| { form.validate(); } }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let password = harness.find_by_attr("id", "password").unwrap();
+        let confirm = harness.find_by_attr("id", "confirm").unwrap();
+        harness.input(&password, "secret");
+        harness.input(&confirm, "secret");
+        let button = harness.find_by_class("validate").unwrap();
+        harness.click(&button);
+        assert!(harness.find_by_class(classnames::FORM_ITEM_ERROR).is_none());
+
+        // 修改密码后确认密码重新校验
+        harness.input(&password, "secret2");
+        assert_eq!(
+            harness
+                .find_by_class(classnames::FORM_ITEM_ERROR)
+                .unwrap()
+                .text(),
+            "两次输入的密码不一致"
+        );
+        harness.input(&confirm, "secret2");
+        assert!(harness.find_by_class(classnames::FORM_ITEM_ERROR).is_none());
+    }
+
+    #[test]
+    fn test_form_item_validates_on_blur() {
+        let mut harness = Harness::new(app);
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&input, "张");
+        // 失去焦点前不校验
+        assert!(harness.find_by_class(classnames::FORM_ITEM_ERROR).is_none());
+
+        let data = PlatformEventData::new(Box::new(dioxus_html::SerializedFocusData::default()));
+        harness.dispatch(&input, "focusout", data, true);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::FORM_ITEM_ERROR)
+                .unwrap()
+                .text(),
+            "姓名至少 2 个字符"
+        );
+    }
+}
@@ -0,0 +1,583 @@
+//! FormWizard 分步表单组件
+//!
+//! 将一个较长的表单拆分为多个步骤，顶部显示步骤条，底部提供上一步、下一步和完成按钮。
+//! 每个步骤可以注册若干字段并设置校验闭包，只有当前步骤校验通过后才能进入下一步，
+//! 最后一步完成时会将所有步骤的字段汇总为一个提交数据。
+//!
+//! # 组件模式
+//!
+//! 步骤中的字段通过 `Signal<String>` 注册，字段的输入组件与 FormWizard 共享同一个 Signal。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{FormWizard, FormWizardStep, Input, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut name = use_signal(String::new);
+//!     let mut email = use_signal(String::new);
+//!
+//!     FormWizard::new()
+//!         .step(
+//!             FormWizardStep::new("基本信息")
+//!                 .field("name", name)
+//!                 .content(Input::new().value(name).oninput(move |v| name.set(v)))
+//!                 .validate(|values| {
+//!                     if values.get("name").is_some_and(|v| v.is_empty()) {
+//!                         return Err("请输入姓名".to_string());
+//!                     }
+//!                     Ok(())
+//!                 }),
+//!         )
+//!         .step(
+//!             FormWizardStep::new("联系方式")
+//!                 .field("email", email)
+//!                 .content(Input::new().value(email).oninput(move |v| email.set(v))),
+//!         )
+//!         .onfinish(|payload| println!("{:?}", payload))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 受控的当前步骤
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{FormWizard, FormWizardStep, Text, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let current = use_signal(|| 1);
+//!     FormWizard::new()
+//!         .current(current)
+//!         .step(FormWizardStep::new("第一步").content(Text::p("第一步内容")))
+//!         .step(FormWizardStep::new("第二步").content(Text::p("第二步内容")))
+//!         .prev_text("返回")
+//!         .finish_text("提交")
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Button, Style, classnames, traits::ToElement};
+
+/// 分步表单的提交数据，键为字段名，值为字段值
+pub type FormWizardPayload = BTreeMap<String, String>;
+
+/// 步骤状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FormWizardStatus {
+    /// 等待中
+    Wait,
+    /// 进行中
+    Process,
+    /// 已完成
+    Finish,
+    /// 校验失败
+    Error,
+}
+
+impl FormWizardStatus {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            FormWizardStatus::Wait => "is-wait",
+            FormWizardStatus::Process => "is-process",
+            FormWizardStatus::Finish => "is-finish",
+            FormWizardStatus::Error => "is-error",
+        }
+    }
+}
+
+impl std::fmt::Display for FormWizardStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+/// 分步表单的单个步骤
+#[derive(Debug, Clone)]
+pub struct FormWizardStep {
+    /// 步骤标题
+    title: String,
+    /// 步骤描述
+    description: Option<String>,
+    /// 步骤内容
+    content: Option<Rc<dyn ToElement>>,
+    /// 步骤字段
+    fields: Vec<(String, Signal<String>)>,
+    /// 步骤校验
+    validate: Option<Callback<FormWizardPayload, Result<(), String>>>,
+}
+
+#[builder_props]
+impl FormWizardStep {
+    /// 创建一个新的步骤
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            description: None,
+            content: None,
+            fields: Vec::new(),
+            validate: None,
+        }
+    }
+
+    /// 设置步骤描述
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 设置步骤内容
+    pub fn content<T>(mut self, content: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.content = Some(Rc::new(content));
+        self
+    }
+
+    /// 注册步骤字段，字段值会在校验和提交时汇总
+    pub fn field(mut self, name: impl Into<String>, value: Signal<String>) -> Self {
+        self.fields.push((name.into(), value));
+        self
+    }
+
+    /// 设置步骤校验闭包，闭包接收本步骤的字段值，返回错误信息则阻止进入下一步
+    pub fn validate(
+        mut self,
+        f: impl FnMut(FormWizardPayload) -> Result<(), String> + 'static,
+    ) -> Self {
+        self.validate = Some(Callback::new(f));
+        self
+    }
+
+    /// 获取步骤标题
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// 收集本步骤的字段值
+    pub fn values(&self) -> FormWizardPayload {
+        self.fields
+            .iter()
+            .map(|(name, value)| (name.clone(), value()))
+            .collect()
+    }
+
+    /// 执行本步骤的校验
+    pub fn check(&self) -> Result<(), String> {
+        match self.validate {
+            Some(validate) => validate.call(self.values()),
+            None => Ok(()),
+        }
+    }
+}
+
+/// FormWizard 分步表单组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct FormWizard {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 步骤列表
+    steps: Vec<FormWizardStep>,
+    /// 当前步骤索引
+    current: Option<Signal<usize>>,
+    /// 上一步按钮文本
+    prev_text: String,
+    /// 下一步按钮文本
+    next_text: String,
+    /// 完成按钮文本
+    finish_text: String,
+    /// 是否允许点击已完成的步骤返回
+    clickable: bool,
+    /// 步骤切换事件
+    onstep_change: Option<EventHandler<usize>>,
+    /// 完成事件，参数为所有步骤汇总的字段值
+    onfinish: Option<EventHandler<FormWizardPayload>>,
+}
+
+impl Default for FormWizard {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::FORM_WIZARD),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            steps: Vec::new(),
+            current: None,
+            prev_text: "上一步".to_string(),
+            next_text: "下一步".to_string(),
+            finish_text: "完成".to_string(),
+            clickable: true,
+            onstep_change: None,
+            onfinish: None,
+        }
+    }
+}
+
+#[builder_props]
+impl FormWizard {
+    /// 创建一个新的分步表单
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加一个步骤
+    pub fn step(mut self, step: FormWizardStep) -> Self {
+        self.steps.push(step);
+        self
+    }
+
+    /// 批量添加步骤
+    pub fn steps(mut self, steps: Vec<FormWizardStep>) -> Self {
+        self.steps.extend(steps);
+        self
+    }
+
+    /// 设置当前步骤（受控模式）
+    pub fn current(mut self, current: Signal<usize>) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    /// 设置上一步按钮文本
+    #[prop(default = "\"上一步\"")]
+    pub fn prev_text(mut self, text: impl Into<String>) -> Self {
+        self.prev_text = text.into();
+        self
+    }
+
+    /// 设置下一步按钮文本
+    #[prop(default = "\"下一步\"")]
+    pub fn next_text(mut self, text: impl Into<String>) -> Self {
+        self.next_text = text.into();
+        self
+    }
+
+    /// 设置完成按钮文本
+    #[prop(default = "\"完成\"")]
+    pub fn finish_text(mut self, text: impl Into<String>) -> Self {
+        self.finish_text = text.into();
+        self
+    }
+
+    /// 设置是否允许点击已完成的步骤返回
+    #[prop(default = "true")]
+    pub fn clickable(mut self, clickable: bool) -> Self {
+        self.clickable = clickable;
+        self
+    }
+
+    /// 设置步骤切换事件
+    pub fn onstep_change(mut self, handler: impl FnMut(usize) + 'static) -> Self {
+        self.onstep_change = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置步骤切换事件
+    #[prop(skip)]
+    pub fn onstep_change2(mut self, handler: EventHandler<usize>) -> Self {
+        self.onstep_change = Some(handler);
+        self
+    }
+
+    /// 设置完成事件
+    pub fn onfinish(mut self, handler: impl FnMut(FormWizardPayload) + 'static) -> Self {
+        self.onfinish = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置完成事件
+    #[prop(skip)]
+    pub fn onfinish2(mut self, handler: EventHandler<FormWizardPayload>) -> Self {
+        self.onfinish = Some(handler);
+        self
+    }
+
+    /// 汇总所有步骤的字段值
+    pub fn payload(&self) -> FormWizardPayload {
+        self.steps.iter().flat_map(|step| step.values()).collect()
+    }
+
+    /// 计算步骤状态
+    fn status_of(index: usize, current: usize, error: bool) -> FormWizardStatus {
+        if index < current {
+            FormWizardStatus::Finish
+        } else if index == current && error {
+            FormWizardStatus::Error
+        } else if index == current {
+            FormWizardStatus::Process
+        } else {
+            FormWizardStatus::Wait
+        }
+    }
+}
+
+impl ToElement for FormWizard {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_current = use_signal(|| 0usize);
+        let mut current = self.current.unwrap_or(inner_current);
+        let mut error = use_signal(|| None::<String>);
+
+        let total = self.steps.len();
+        let index = current().min(total.saturating_sub(1));
+        let is_last = index + 1 >= total;
+        let onstep_change = self.onstep_change;
+        let onfinish = self.onfinish;
+        let clickable = self.clickable;
+
+        let mut go_to = move |target: usize| {
+            error.set(None);
+            current.set(target);
+            if let Some(handler) = onstep_change {
+                handler.call(target);
+            }
+        };
+
+        let header = self.steps.iter().enumerate().map(|(i, step)| {
+            let status = Self::status_of(i, index, error.read().is_some());
+            let can_jump = clickable && i < index;
+            let mut class_names = vec![classnames::FORM_WIZARD_STEP, status.as_class()];
+            if can_jump {
+                class_names.push("is-clickable");
+            }
+            let step_class = class_names.join(" ");
+            let title = step.title.clone();
+            let description = step.description.clone();
+
+            rsx! {
+                div {
+                    key: "{i}",
+                    class: step_class,
+                    onclick: move |_| {
+                        if can_jump {
+                            go_to(i);
+                        }
+                    },
+                    div { class: classnames::FORM_WIZARD_STEP_HEAD,
+                        span { class: classnames::FORM_WIZARD_STEP_ICON,
+                            if status == FormWizardStatus::Finish {
+                                "✓"
+                            } else if status == FormWizardStatus::Error {
+                                "!"
+                            } else {
+                                "{i + 1}"
+                            }
+                        }
+                        if i + 1 < total {
+                            span { class: classnames::FORM_WIZARD_STEP_LINE }
+                        }
+                    }
+                    div { class: classnames::FORM_WIZARD_STEP_MAIN,
+                        div { class: classnames::FORM_WIZARD_STEP_TITLE, "{title}" }
+                        if let Some(description) = description {
+                            div { class: classnames::FORM_WIZARD_STEP_DESCRIPTION, "{description}" }
+                        }
+                    }
+                }
+            }
+        });
+
+        // 所有步骤的内容都会渲染，非当前步骤隐藏，以保持步骤内组件的状态和 hooks 顺序稳定
+        let body = self.steps.iter().enumerate().map(|(i, step)| {
+            let content = step.content.clone();
+            rsx! {
+                div {
+                    key: "{i}",
+                    class: classnames::FORM_WIZARD_PANEL,
+                    hidden: i != index,
+                    if let Some(content) = content {
+                        {content.to_element()}
+                    }
+                }
+            }
+        });
+
+        let current_step = self.steps.get(index).cloned();
+        let all_steps = self.steps.clone();
+        let onnext = move |_: MouseEvent| {
+            let Some(step) = &current_step else {
+                return;
+            };
+            if let Err(message) = step.check() {
+                error.set(Some(message));
+                return;
+            }
+            if is_last {
+                error.set(None);
+                if let Some(handler) = onfinish {
+                    handler.call(all_steps.iter().flat_map(|step| step.values()).collect());
+                }
+            } else {
+                go_to(index + 1);
+            }
+        };
+
+        let prev = Button::new()
+            .class(classnames::FORM_WIZARD_PREV)
+            .text(self.prev_text.clone())
+            .disabled(index == 0)
+            .onclick(move |_| {
+                if index > 0 {
+                    go_to(index - 1);
+                }
+            });
+        let next = Button::new()
+            .class(classnames::FORM_WIZARD_NEXT)
+            .text(if is_last {
+                self.finish_text.clone()
+            } else {
+                self.next_text.clone()
+            })
+            .as_primary()
+            .onclick(onnext);
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::FORM_WIZARD_STEPS, {header} }
+                div { class: classnames::FORM_WIZARD_BODY, {body} }
+                if let Some(message) = error() {
+                    div { class: classnames::FORM_WIZARD_ERROR, "{message}" }
+                }
+                div { class: classnames::FORM_WIZARD_FOOTER,
+                    if index > 0 {
+                        {prev.to_element()}
+                    }
+                    {next.to_element()}
+                }
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::Text;
+
+    #[test]
+    fn test_status_of() {
+        assert_eq!(FormWizard::status_of(0, 1, false), FormWizardStatus::Finish);
+        assert_eq!(
+            FormWizard::status_of(1, 1, false),
+            FormWizardStatus::Process
+        );
+        assert_eq!(FormWizard::status_of(1, 1, true), FormWizardStatus::Error);
+        assert_eq!(FormWizard::status_of(2, 1, true), FormWizardStatus::Wait);
+    }
+
+    #[test]
+    fn test_render_first_step() {
+        fn app() -> Element {
+            FormWizard::new()
+                .step(FormWizardStep::new("账号").content(Text::p("账号内容")))
+                .step(FormWizardStep::new("资料").content(Text::p("资料内容")))
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-form-wizard__step is-process"));
+        assert!(html.contains("t-form-wizard__step is-wait"));
+        assert!(html.contains("账号内容"));
+        assert!(html.contains(r#"<div class="t-form-wizard__panel" hidden=true><p"#));
+        assert!(!html.contains("t-form-wizard__prev"));
+        assert!(html.contains("下一步"));
+    }
+
+    #[test]
+    fn test_render_controlled_last_step() {
+        fn app() -> Element {
+            let current = use_signal(|| 1);
+            FormWizard::new()
+                .current(current)
+                .step(FormWizardStep::new("账号").content(Text::p("账号内容")))
+                .step(FormWizardStep::new("资料").content(Text::p("资料内容")))
+                .finish_text("提交")
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-form-wizard__step is-finish is-clickable"));
+        assert!(html.contains("资料内容"));
+        assert!(html.contains("t-form-wizard__prev"));
+        assert!(html.contains("提交"));
+    }
+
+    #[test]
+    fn test_step_check_and_payload() {
+        fn app() -> Element {
+            let name = use_signal(|| "张三".to_string());
+            let email = use_signal(String::new);
+            let first = FormWizardStep::new("账号").field("name", name);
+            let second = FormWizardStep::new("联系")
+                .field("email", email)
+                .validate(|values| match values.get("email") {
+                    Some(v) if !v.is_empty() => Ok(()),
+                    _ => Err("请输入邮箱".to_string()),
+                });
+
+            assert_eq!(first.check(), Ok(()));
+            assert_eq!(second.check(), Err("请输入邮箱".to_string()));
+
+            let wizard = FormWizard::new().step(first).step(second);
+            let payload = wizard.payload();
+            assert_eq!(payload.get("name").map(String::as_str), Some("张三"));
+            assert_eq!(payload.get("email").map(String::as_str), Some(""));
+
+            rsx! {}
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+    }
+}
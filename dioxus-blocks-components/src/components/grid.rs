@@ -20,13 +20,14 @@
 //!     .to_element()
 //! }
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, ToElement};
+use crate::{Style, ToElement, classnames};
 
 /// 网格列数枚举
 ///
@@ -138,7 +139,7 @@ pub struct GridItem {
     /// 网格项的唯一标识符
     id: Option<String>,
     /// 网格项的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 网格项的内联样式
     style: Option<Style>,
     /// 网格项的子元素列表
@@ -164,7 +165,7 @@ impl Default for GridItem {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-grid-item".to_string(),
+            class: Cow::Borrowed(classnames::GRID_ITEM),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -179,6 +180,7 @@ impl Default for GridItem {
     }
 }
 
+#[builder_props]
 impl GridItem {
     /// 创建一个新的网格项实例
     ///
@@ -218,6 +220,7 @@ impl GridItem {
     /// # use dioxus_blocks_components::GridItem;
     /// GridItem::default().col_span(2);
     /// ```
+    #[prop(default = "1")]
     pub fn col_span(mut self, col_span: usize) -> Self {
         self.col_span = col_span;
         self
@@ -239,6 +242,7 @@ impl GridItem {
     /// # use dioxus_blocks_components::GridItem;
     /// GridItem::default().row_span(2);
     /// ```
+    #[prop(default = "1")]
     pub fn row_span(mut self, row_span: usize) -> Self {
         self.row_span = row_span;
         self
@@ -352,7 +356,7 @@ impl ToElement for GridItem {
     /// ```
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let mut class = self.class.clone();
+        let mut class = self.class.to_string();
         let style = self
             .style
             .clone()
@@ -407,7 +411,7 @@ pub struct Grid {
     /// 网格的唯一标识符
     id: Option<String>,
     /// 网格的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 网格的内联样式
     style: Option<Style>,
     /// 网格的子元素列表
@@ -427,7 +431,7 @@ impl Default for Grid {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-grid".to_string(),
+            class: Cow::Borrowed(classnames::GRID),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -458,22 +462,20 @@ impl ToElement for Grid {
     /// ```
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let mut class = self.class.clone();
-        let mut style = self
-            .style
-            .clone()
-            .map(|s| s.to_string())
-            .unwrap_or("".to_string());
+        let mut class = self.class.to_string();
         let onclick_handler = self.onclick;
         let childrens = self.childrens_to_element();
 
+        // 组件内置的网格样式，用户样式合并在其之上
+        let mut style = Style::default();
+
         // 列数
         if let Some(cols) = self.cols.clone() {
             class.push_str(" t-grid-cols");
 
             let cols_value: u16 = cols.into();
-            style.push_str(&format!(
-                "grid-template-columns: repeat({}, minmax(0, 1fr));",
+            style = style.custom(format!(
+                "grid-template-columns: repeat({}, minmax(0, 1fr))",
                 cols_value
             ));
         }
@@ -483,14 +485,17 @@ impl ToElement for Grid {
             class.push_str(" t-grid-rows");
 
             let rows_value: u16 = rows.into();
-            style.push_str(&format!(
-                "grid-template-rows: repeat({}, minmax(0, 1fr));",
+            style = style.custom(format!(
+                "grid-template-rows: repeat({}, minmax(0, 1fr))",
                 rows_value
             ));
         }
 
         // 间距
-        style.push_str(&format!(" gap: {};", self.gap));
+        let style = style
+            .gap(self.gap.clone())
+            .merge(self.style.clone().unwrap_or_default())
+            .to_string();
 
         rsx! {
             div {
@@ -508,6 +513,7 @@ impl ToElement for Grid {
     }
 }
 
+#[builder_props]
 impl Grid {
     /// 创建一个新的网格实例
     ///
@@ -594,6 +600,7 @@ impl Grid {
     /// # use dioxus_blocks_components::Grid;
     /// let grid = Grid::default().gap(4);
     /// ```
+    #[prop(default = "4")]
     pub fn gap(mut self, gap: usize) -> Self {
         self.gap = format!("{gap}px");
         self
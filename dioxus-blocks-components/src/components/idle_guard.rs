@@ -0,0 +1,384 @@
+//! IdleGuard 空闲超时组件
+//!
+//! 用户在指定时间内没有任何操作（鼠标、键盘、滚动、触摸）时弹出“还在吗？”对话框并开始倒计时，
+//! 倒计时结束前点击“继续使用”关闭对话框，否则调用 [`IdleGuard::onlogout`] 退出登录。
+//! 适用于需要在无人值守时自动退出的后台管理系统。
+//!
+//! 对话框基于 [`Dialog`] 实现，打开后焦点限制在对话框内，按 Escape 或点击遮罩不会关闭。
+//!
+//! 只需要判断用户是否空闲时使用 [`use_idle`]。服务端渲染等无法监听浏览器事件的环境中始终不空闲。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{IdleGuard, Text, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     IdleGuard::new()
+//!         .timeout(15 * 60 * 1000)
+//!         .countdown(60)
+//!         .onlogout(|_| {
+//!             // 清除登录状态并跳转到登录页
+//!         })
+//!         .children(Text::p("管理后台"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::{Button, Dialog, Style, Text, classnames, traits::ToElement};
+
+/// 空闲状态上下文
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct IdleState {
+    idle: Signal<bool>,
+}
+
+impl IdleState {
+    /// 创建空闲状态并开始监听，需要在 hook 中调用
+    fn watch(timeout: u32) -> Self {
+        let idle = Signal::new(false);
+        spawn(listen(timeout, idle));
+        Self { idle }
+    }
+}
+
+/// 在浏览器中监听用户操作，超过指定毫秒数没有操作时标记为空闲，非浏览器环境直接返回
+async fn listen(timeout: u32, mut idle: Signal<bool>) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    let mut eval = document::eval(&format!(
+        "const timeout = {timeout}; \
+         let last = Date.now(); let idle = false; \
+         const active = () => {{ last = Date.now(); if (idle) {{ idle = false; dioxus.send(false); }} }}; \
+         for (const name of ['mousemove', 'mousedown', 'keydown', 'wheel', 'touchstart', 'scroll']) {{ \
+           window.addEventListener(name, active, {{ capture: true, passive: true }}); \
+         }} \
+         setInterval(() => {{ \
+           if (!idle && Date.now() - last >= timeout) {{ idle = true; dioxus.send(true); }} \
+         }}, Math.min(1000, timeout)); \
+         await new Promise(() => {{}});"
+    ));
+    while let Ok(value) = eval.recv::<bool>().await {
+        if *idle.peek() != value {
+            idle.set(value);
+        }
+    }
+}
+
+/// 判断用户是否空闲
+///
+/// 超过 `timeout` 毫秒没有鼠标、键盘、滚动或触摸操作时为 `true`，用户再次操作后恢复为 `false`。
+/// `timeout` 只在首次调用时生效。
+pub fn use_idle(timeout: u32) -> ReadSignal<bool> {
+    let state = use_hook(|| IdleState::watch(timeout.max(1)));
+    state.idle.into()
+}
+
+/// 空闲超时组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct IdleGuard {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 判定为空闲的时长，单位为毫秒
+    timeout: u32,
+    /// 对话框的倒计时秒数
+    countdown: u32,
+    /// 对话框标题
+    title: String,
+    /// 对话框说明
+    description: String,
+    /// 倒计时结束或点击退出登录时的回调
+    onlogout: Option<EventHandler<()>>,
+    /// 点击继续使用时的回调
+    oncontinue: Option<EventHandler<()>>,
+}
+
+impl Default for IdleGuard {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::IDLE_GUARD),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            timeout: 15 * 60 * 1000,
+            countdown: 60,
+            title: "还在吗？".to_string(),
+            description: "您已经有一段时间没有操作了，为了账户安全，倒计时结束后将自动退出登录。"
+                .to_string(),
+            onlogout: None,
+            oncontinue: None,
+        }
+    }
+}
+
+#[builder_props]
+impl IdleGuard {
+    /// 创建空闲超时组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置判定为空闲的时长，单位为毫秒
+    #[prop(default = "900000")]
+    pub fn timeout(mut self, timeout: u32) -> Self {
+        self.timeout = timeout.max(1);
+        self
+    }
+
+    /// 设置对话框的倒计时秒数
+    #[prop(default = "60")]
+    pub fn countdown(mut self, countdown: u32) -> Self {
+        self.countdown = countdown.max(1);
+        self
+    }
+
+    /// 设置对话框标题
+    #[prop(default = "\"还在吗？\"")]
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置对话框说明
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = description.into();
+        self
+    }
+
+    /// 设置倒计时结束或点击退出登录时的回调
+    pub fn onlogout(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.onlogout = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置点击继续使用时的回调
+    pub fn oncontinue(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.oncontinue = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for IdleGuard {
+    fn to_element(&self) -> Element {
+        let timeout = self.timeout;
+        let state = use_context_provider(move || IdleState::watch(timeout));
+        let idle = state.idle;
+        let mut open = use_signal(|| false);
+        let mut remaining = use_signal(|| 0u32);
+        // 每次弹出对话框递增，旧的倒计时发现编号变化后停止
+        let mut round = use_signal(|| 0u64);
+
+        let countdown = self.countdown;
+        let onlogout = self.onlogout;
+        let oncontinue = self.oncontinue;
+
+        let mut logout = move || {
+            open.set(false);
+            if let Some(handler) = onlogout {
+                handler.call(());
+            }
+        };
+
+        // 进入空闲时弹出对话框，对话框打开后的操作不会关闭对话框，需要用户明确选择
+        use_effect(move || {
+            if !idle() || *open.peek() {
+                return;
+            }
+            let current = *round.peek() + 1;
+            round.set(current);
+            remaining.set(countdown);
+            open.set(true);
+            spawn(async move {
+                loop {
+                    if !sleep(1000).await || *round.peek() != current || !*open.peek() {
+                        break;
+                    }
+                    let left = remaining.peek().saturating_sub(1);
+                    remaining.set(left);
+                    if left == 0 {
+                        logout();
+                        break;
+                    }
+                }
+            });
+        });
+
+        let logout_button = Button::new().text("退出登录").onclick(move |_| logout());
+        let continue_button = Button::new()
+            .text("继续使用")
+            .as_primary()
+            .onclick(move |_| {
+                open.set(false);
+                if let Some(handler) = oncontinue {
+                    handler.call(());
+                }
+            });
+
+        let mut dialog = Dialog::new(open)
+            .class(self.class.to_string())
+            .title(self.title.clone())
+            .width("420px")
+            .alert(true)
+            .closable(false)
+            .close_on_backdrop(false)
+            .children(Text::p(self.description.clone()).class(classnames::IDLE_GUARD_DESC))
+            .children(IdleCountdown { remaining })
+            .footer(logout_button)
+            .footer(continue_button);
+        if let Some(id) = self.id.clone() {
+            dialog = dialog.id(id);
+        }
+        if let Some(style) = self.style.clone() {
+            dialog = dialog.style(move |_| style);
+        }
+        if let Some(handler) = self.onclick {
+            dialog = dialog.onclick2(handler);
+        }
+
+        rsx! {
+            {self.childrens_to_element()}
+            {dialog.to_element()}
+        }
+    }
+}
+
+/// 倒计时剩余的秒数
+#[derive(Debug, Clone)]
+struct IdleCountdown {
+    remaining: Signal<u32>,
+}
+
+impl ToElement for IdleCountdown {
+    fn to_element(&self) -> Element {
+        let remaining = self.remaining;
+        rsx! {
+            div { class: classnames::IDLE_GUARD_COUNTDOWN, aria_live: "polite",
+                span { class: classnames::IDLE_GUARD_SECONDS, "{remaining}" }
+                " 秒后自动退出"
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Text, View};
+
+    /// 直接修改上下文中的空闲状态，模拟用户长时间没有操作
+    #[derive(Debug, Clone)]
+    struct Activity;
+
+    impl ToElement for Activity {
+        fn to_element(&self) -> Element {
+            let mut state = use_context::<IdleState>();
+            View::new()
+                .children(Text::span("空闲").onclick(move |_| state.idle.set(true)))
+                .children(Text::span("操作").onclick(move |_| state.idle.set(false)))
+                .to_element()
+        }
+    }
+
+    #[test]
+    fn test_idle_guard_dialog() {
+        fn app() -> Element {
+            let mut logouts = use_signal(|| 0);
+            View::new()
+                .children(
+                    IdleGuard::new()
+                        .countdown(30)
+                        .onlogout(move |_| logouts += 1)
+                        .children(Activity),
+                )
+                .children(Text::span(format!("退出 {logouts} 次")))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::IDLE_GUARD).is_none());
+
+        harness.click(&harness.find_by_text("空闲").unwrap());
+        let dialog = harness.find_by_attr("role", "alertdialog").unwrap();
+        let title = harness.find_by_class(classnames::DIALOG_TITLE).unwrap();
+        assert_eq!(title.text(), "还在吗？");
+        assert_eq!(dialog.attr("aria-labelledby"), title.attr("id"));
+        assert!(
+            harness
+                .find_by_class(classnames::IDLE_GUARD)
+                .unwrap()
+                .has_class(classnames::DIALOG)
+        );
+        assert_eq!(
+            harness
+                .find_by_class(classnames::IDLE_GUARD_SECONDS)
+                .unwrap()
+                .text(),
+            "30"
+        );
+
+        // 对话框打开后的操作不会关闭对话框
+        harness.click(&harness.find_by_text("操作").unwrap());
+        assert!(harness.find_by_class(classnames::IDLE_GUARD).is_some());
+
+        harness.click(&harness.find_by_text("继续使用").unwrap());
+        assert!(harness.find_by_class(classnames::IDLE_GUARD).is_none());
+
+        harness.click(&harness.find_by_text("空闲").unwrap());
+        harness.click(&harness.find_by_text("退出登录").unwrap());
+        assert!(harness.find_by_class(classnames::IDLE_GUARD).is_none());
+        assert!(harness.find_by_text("退出 1 次").is_some());
+    }
+
+    #[test]
+    fn test_idle_guard_unique_ids() {
+        fn app() -> Element {
+            View::new()
+                .children(IdleGuard::new().children(Activity))
+                .children(IdleGuard::new().children(Activity))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        for trigger in harness.find_all(|element| element.text() == "空闲") {
+            harness.click(&trigger);
+        }
+        let dialogs = harness.find_all(|element| element.attr("role") == Some("alertdialog"));
+        assert_eq!(dialogs.len(), 2);
+        assert_ne!(
+            dialogs[0].attr("aria-labelledby"),
+            dialogs[1].attr("aria-labelledby")
+        );
+        for dialog in &dialogs {
+            let body = harness
+                .find_by_attr("id", dialog.attr("aria-describedby").unwrap())
+                .unwrap();
+            assert!(body.text().contains("秒后自动退出"));
+        }
+    }
+}
@@ -46,13 +46,14 @@
 //!     }
 //! }
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{Style, classnames, traits::ToElement};
 
 /// 对象适应方式枚举
 ///
@@ -104,7 +105,7 @@ pub struct Image {
     /// 图片组件的唯一标识符
     id: Option<String>,
     /// 图片组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 图片组件的内联样式
     style: Option<Style>,
     /// 图片组件的子元素列表
@@ -127,7 +128,7 @@ impl Default for Image {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-image".to_string(),
+            class: Cow::Borrowed(classnames::IMAGE),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -140,6 +141,7 @@ impl Default for Image {
     }
 }
 
+#[builder_props]
 impl Image {
     /// 创建一个新的图片实例
     ///
@@ -261,7 +263,7 @@ impl Image {
 impl ToElement for Image {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let class = self.class.clone();
+        let class = self.class.to_string();
         let mut style = self
             .style
             .clone()
@@ -90,6 +90,25 @@
 //! dom.rebuild(&mut mutations);
 //! ```
 //!
+//! ## 输入掩码
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Input, InputMask, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut phone = use_signal(|| String::new());
+//!     Input::new()
+//!         .value(phone)
+//!         .mask(InputMask::phone())
+//!         .oninput(move |v| phone.set(v))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
 //! ## 不同尺寸
 //!
 //! ```rust
@@ -149,13 +168,15 @@
 //! dom.rebuild(&mut mutations);
 //! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use super::input_mask::{InputMask, sync_input};
+use crate::{Style, classnames, traits::ToElement, use_unique_id};
 
 /// 输入框类型枚举
 ///
@@ -192,16 +213,23 @@ pub enum InputSize {
     Large,
 }
 
-impl std::fmt::Display for InputSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl InputSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            InputSize::Medium => write!(f, ""),
-            InputSize::Small => write!(f, "t-input--small"),
-            InputSize::Large => write!(f, "t-input--large"),
+            InputSize::Medium => "",
+            InputSize::Small => "t-input--small",
+            InputSize::Large => "t-input--large",
         }
     }
 }
 
+impl std::fmt::Display for InputSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 输入框组件结构体
 ///
 /// 提供一个可自定义的单行文本输入框，支持多种输入类型、尺寸、禁用状态和事件处理。
@@ -218,7 +246,7 @@ pub struct Input {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -236,12 +264,16 @@ pub struct Input {
     size: InputSize,
     /// 占位符
     placeholder: String,
+    /// 内部输入框的 ID，未设置时自动生成
+    input_id: Option<String>,
     /// 是否可清空
     clearable: bool,
     /// 最大输入长度
     max_length: Option<usize>,
     /// 是否显示字数统计
     show_word_limit: bool,
+    /// 输入掩码
+    mask: Option<InputMask>,
     /// 前置图标
     prefix_icon: Option<String>,
     /// 后置图标
@@ -278,7 +310,7 @@ impl Default for Input {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-input".to_string(),
+            class: Cow::Borrowed(classnames::INPUT),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -287,8 +319,10 @@ impl Default for Input {
             disabled: false,
             size: InputSize::default(),
             placeholder: String::new(),
+            input_id: None,
             clearable: false,
             max_length: None,
+            mask: None,
             show_word_limit: false,
             prefix_icon: None,
             suffix_icon: None,
@@ -309,6 +343,7 @@ impl Default for Input {
     }
 }
 
+#[builder_props]
 impl Input {
     /// 创建一个新的输入框实例
     ///
@@ -328,6 +363,7 @@ impl Input {
     }
 
     /// 设置输入框类型
+    #[prop(default = "InputType::Text")]
     pub fn input_type(mut self, input_type: InputType) -> Self {
         self.input_type = input_type;
         self
@@ -340,12 +376,14 @@ impl Input {
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置输入框尺寸
+    #[prop(default = "InputSize::Medium")]
     pub fn size(mut self, size: InputSize) -> Self {
         self.size = size;
         self
@@ -357,7 +395,14 @@ impl Input {
         self
     }
 
+    /// 设置内部输入框的 ID，用于关联外部的 `<label for>`，未设置时自动生成
+    pub fn input_id(mut self, input_id: impl Into<String>) -> Self {
+        self.input_id = Some(input_id.into());
+        self
+    }
+
     /// 设置是否可清空
+    #[prop(default = "false")]
     pub fn clearable(mut self, clearable: bool) -> Self {
         self.clearable = clearable;
         self
@@ -369,7 +414,17 @@ impl Input {
         self
     }
 
+    /// 设置输入掩码
+    ///
+    /// 输入和粘贴的内容会按掩码过滤并自动补全固定字符，如 `.mask("(999) 999-9999")`。
+    /// 未设置占位符时使用掩码生成的占位符。
+    pub fn mask(mut self, mask: impl Into<InputMask>) -> Self {
+        self.mask = Some(mask.into());
+        self
+    }
+
     /// 设置是否显示字数统计
+    #[prop(default = "false")]
     pub fn show_word_limit(mut self, show: bool) -> Self {
         self.show_word_limit = show;
         self
@@ -488,24 +543,30 @@ impl ToElement for Input {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
 
-        let mut class_names = vec![self.class.clone(), self.size.to_string()];
+        let mut class_names = vec![&*self.class, self.size.as_class()];
         if self.disabled {
-            class_names.push("t-input--disabled".to_string());
+            class_names.push(classnames::INPUT_DISABLED);
         }
         if self.clearable && self.value.as_ref().is_some_and(|v| !v.read().is_empty()) {
-            class_names.push("t-input--clearable".to_string());
+            class_names.push(classnames::INPUT_CLEARABLE);
         }
         if self.prefix_icon.is_some() || self.prepend.is_some() {
-            class_names.push("t-input--prefix".to_string());
+            class_names.push(classnames::INPUT_HAS_PREFIX);
         }
         if self.suffix_icon.is_some() || self.append.is_some() || self.clearable {
-            class_names.push("t-input--suffix".to_string());
+            class_names.push(classnames::INPUT_HAS_SUFFIX);
         }
         let class = class_names.join(" ");
 
         let style = self.style.clone().map(|s| s.to_string());
         let disabled = self.disabled;
-        let placeholder = self.placeholder.clone();
+        let placeholder = match &self.mask {
+            Some(mask) if self.placeholder.is_empty() => mask.placeholder(),
+            _ => self.placeholder.clone(),
+        };
+        let generated_id = use_unique_id("input");
+        let input_id = self.input_id.clone().unwrap_or(generated_id);
+        let count_id = format!("{input_id}-count");
         let input_type_str = self.input_type.to_string();
         let max_length_attr = self.max_length.map(|l| l.to_string());
 
@@ -527,6 +588,10 @@ impl ToElement for Input {
         let clearable = self.clearable;
         let show_word_limit = self.show_word_limit;
         let max_length = self.max_length;
+        let mask = self.mask.clone();
+        let mask_for_change = self.mask.clone();
+        let input_id_for_input = input_id.clone();
+        let input_id_for_change = input_id.clone();
 
         let prefix_icon = self.prefix_icon.clone();
         let suffix_icon = self.suffix_icon.clone();
@@ -537,15 +602,15 @@ impl ToElement for Input {
             div { id, class, style,
                 // 前置元素
                 if let Some(prepend_el) = &prepend {
-                    div { class: "t-input__prepend", {prepend_el.to_element()} }
+                    div { class: classnames::INPUT_PREPEND, {prepend_el.to_element()} }
                 }
 
-                div { class: "t-input__wrapper",
+                div { class: classnames::INPUT_WRAPPER,
                     // 前置图标
                     if let Some(icon) = prefix_icon {
-                        span { class: "t-input__prefix",
+                        span { class: classnames::INPUT_PREFIX,
                             span {
-                                class: "t-input__icon",
+                                class: classnames::INPUT_ICON,
                                 dangerous_inner_html: "{icon}",
                             }
                         }
@@ -554,8 +619,10 @@ impl ToElement for Input {
                     // 输入框
                     input {
                         r#type: input_type_str,
-                        class: "t-input__inner",
+                        id: "{input_id}",
+                        class: classnames::INPUT_INNER,
                         placeholder,
+                        aria_describedby: show_word_limit.then(|| count_id.clone()),
                         disabled,
                         maxlength: max_length_attr,
                         value: value_signal.read().clone(),
@@ -563,7 +630,15 @@ impl ToElement for Input {
                             if disabled {
                                 return;
                             }
-                            let input_value = event.value();
+                            let mut input_value = event.value();
+                            if let Some(mask) = &mask {
+                                input_value = apply_mask(
+                                    mask,
+                                    &input_id_for_input,
+                                    &value_signal.peek(),
+                                    &input_value,
+                                );
+                            }
 
                             if let Some(max_len) = max_length
                                 && input_value.chars().count() > max_len {
@@ -580,7 +655,15 @@ impl ToElement for Input {
                             if disabled {
                                 return;
                             }
-                            let input_value = event.value();
+                            let mut input_value = event.value();
+                            if let Some(mask) = &mask_for_change {
+                                input_value = apply_mask(
+                                    mask,
+                                    &input_id_for_change,
+                                    &value_signal.peek(),
+                                    &input_value,
+                                );
+                            }
                             value_signal.set(input_value.clone());
 
                             if let Some(handler) = onchange_handler {
@@ -631,11 +714,11 @@ impl ToElement for Input {
 
                     // 后置图标（清空按钮 + 自定义图标）
                     if suffix_icon.is_some() || clearable || show_word_limit {
-                        span { class: "t-input__suffix",
+                        span { class: classnames::INPUT_SUFFIX,
                             // 清空按钮
                             if clearable && !value_signal.read().is_empty() && !disabled {
                                 span {
-                                    class: "t-input__clear",
+                                    class: classnames::INPUT_CLEAR,
                                     onclick: move |event: MouseEvent| {
                                         event.stop_propagation();
                                         value_signal.set(String::new());
@@ -650,17 +733,17 @@ impl ToElement for Input {
                             // 自定义后置图标
                             if let Some(icon) = suffix_icon {
                                 span {
-                                    class: "t-input__icon",
+                                    class: classnames::INPUT_ICON,
                                     dangerous_inner_html: "{icon}",
                                 }
                             }
 
                             // 字数统计
                             if show_word_limit {
-                                span { class: "t-input__count",
+                                span { id: "{count_id}", class: classnames::INPUT_COUNT,
                                     "{value_signal.read().chars().count()}"
                                     if let Some(max_len) = max_length {
-                                        span { class: "t-input__count-separator", "/" }
+                                        span { class: classnames::INPUT_COUNT_SEPARATOR, "/" }
                                         span { "{max_len}" }
                                     }
                                 }
@@ -671,9 +754,87 @@ impl ToElement for Input {
 
                 // 后置元素
                 if let Some(append_el) = &append {
-                    div { class: "t-input__append", {append_el.to_element()} }
+                    div { class: classnames::INPUT_APPEND, {append_el.to_element()} }
                 }
             }
         }
     }
 }
+
+/// 按掩码格式化输入内容，格式化结果与输入不同时同步到浏览器中的输入框
+fn apply_mask(mask: &InputMask, input_id: &str, previous: &str, raw: &str) -> String {
+    let formatted = mask.format(raw);
+    if formatted != raw {
+        sync_input(input_id, &formatted, mask.caret(previous, raw));
+    }
+    formatted
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_input_updates_value() {
+        fn app() -> Element {
+            let mut value = use_signal(String::new);
+            Input::new()
+                .value(value)
+                .oninput(move |v| value.set(v))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.tag(), "input");
+
+        harness.input(&inner, "hello");
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.attr("value"), Some("hello"));
+    }
+
+    #[test]
+    fn test_input_ids() {
+        fn app() -> Element {
+            rsx! {
+                {Input::new().show_word_limit(true).to_element()}
+                {Input::new().input_id("username").to_element()}
+            }
+        }
+
+        let harness = Harness::new(app);
+        let inputs = harness.find_all_by_class(classnames::INPUT_INNER);
+        assert_eq!(inputs[0].attr("id"), Some("t-input-1"));
+        assert_eq!(inputs[0].attr("aria-describedby"), Some("t-input-1-count"));
+        assert!(harness.find_by_attr("id", "t-input-1-count").is_some());
+        assert_eq!(inputs[1].attr("id"), Some("username"));
+        assert_eq!(inputs[1].attr("aria-describedby"), None);
+    }
+
+    #[test]
+    fn test_input_mask() {
+        fn app() -> Element {
+            let mut value = use_signal(String::new);
+            rsx! {
+                {Input::new().value(value).mask("(999) 999-9999").oninput(move |v| value.set(v)).to_element()}
+                span { class: "value", "{value}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.attr("placeholder"), Some("(___) ___-____"));
+
+        harness.input(&inner, "555a1");
+        assert_eq!(harness.find_by_class("value").unwrap().text(), "(555) 1");
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(inner.attr("value"), Some("(555) 1"));
+
+        harness.input(&inner, "(555) 123-45678");
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "(555) 123-4567"
+        );
+    }
+}
@@ -0,0 +1,244 @@
+//! 输入掩码
+//!
+//! 按掩码格式约束并格式化输入框的内容，掩码中的占位符：
+//!
+//! - `9`: 数字
+//! - `a`: 字母
+//! - `*`: 任意非空白字符
+//! - `\`: 转义下一个字符，使其作为固定字符
+//!
+//! 其他字符都是固定字符，会在用户输入时自动补全。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus_blocks_components::InputMask;
+//!
+//! let mask = InputMask::phone();
+//! assert_eq!(mask.format("5551234567"), "(555) 123-4567");
+//! assert_eq!(mask.format("555"), "(555");
+//! assert_eq!(mask.unmask("(555) 123-4567"), "5551234567");
+//! assert_eq!(mask.placeholder(), "(___) ___-____");
+//! ```
+
+use dioxus::prelude::*;
+
+/// 掩码中的单个位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum MaskToken {
+    /// 数字
+    Digit,
+    /// 字母
+    Letter,
+    /// 任意非空白字符
+    Any,
+    /// 固定字符
+    Literal(char),
+}
+
+impl MaskToken {
+    fn accepts(&self, c: char) -> bool {
+        match self {
+            MaskToken::Digit => c.is_ascii_digit(),
+            MaskToken::Letter => c.is_alphabetic(),
+            MaskToken::Any => !c.is_whitespace(),
+            MaskToken::Literal(_) => false,
+        }
+    }
+}
+
+/// 输入掩码
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InputMask {
+    /// 掩码格式
+    pattern: String,
+    /// 解析后的掩码位置
+    tokens: Vec<MaskToken>,
+}
+
+impl InputMask {
+    /// 创建输入掩码
+    ///
+    /// # 参数
+    ///
+    /// * `pattern` - 掩码格式，如 `"(999) 999-9999"`
+    pub fn new(pattern: impl Into<String>) -> Self {
+        let pattern = pattern.into();
+        let mut tokens = Vec::new();
+        let mut chars = pattern.chars();
+        while let Some(c) = chars.next() {
+            tokens.push(match c {
+                '9' => MaskToken::Digit,
+                'a' => MaskToken::Letter,
+                '*' => MaskToken::Any,
+                '\\' => MaskToken::Literal(chars.next().unwrap_or('\\')),
+                c => MaskToken::Literal(c),
+            });
+        }
+        Self { pattern, tokens }
+    }
+
+    /// 手机号码，格式为 `(999) 999-9999`
+    pub fn phone() -> Self {
+        Self::new("(999) 999-9999")
+    }
+
+    /// 日期，格式为 `9999-99-99`
+    pub fn date() -> Self {
+        Self::new("9999-99-99")
+    }
+
+    /// 银行卡号，格式为 `9999 9999 9999 9999`
+    pub fn credit_card() -> Self {
+        Self::new("9999 9999 9999 9999")
+    }
+
+    /// 掩码格式
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// 按掩码格式化输入内容
+    ///
+    /// 不符合占位符的字符会被丢弃，超出掩码长度的内容会被截断。固定字符只在其后有输入时才会补全，
+    /// 因此删除内容时不会残留多余的固定字符。
+    pub fn format(&self, value: &str) -> String {
+        let mut output = String::new();
+        let mut pending = String::new();
+        let mut chars = value.chars().peekable();
+
+        for token in &self.tokens {
+            match token {
+                MaskToken::Literal(literal) => {
+                    pending.push(*literal);
+                    if chars.peek() == Some(literal) {
+                        chars.next();
+                    }
+                }
+                slot => loop {
+                    let Some(c) = chars.next() else {
+                        return output;
+                    };
+                    if slot.accepts(c) {
+                        output.push_str(&pending);
+                        pending.clear();
+                        output.push(c);
+                        break;
+                    }
+                },
+            }
+        }
+        output
+    }
+
+    /// 去掉固定字符，只保留用户输入的内容
+    pub fn unmask(&self, value: &str) -> String {
+        self.format(value)
+            .chars()
+            .zip(&self.tokens)
+            .filter(|(_, token)| !matches!(token, MaskToken::Literal(_)))
+            .map(|(c, _)| c)
+            .collect()
+    }
+
+    /// 所有占位符都已填写
+    pub fn is_complete(&self, value: &str) -> bool {
+        self.format(value).chars().count() == self.tokens.len()
+    }
+
+    /// 用 `_` 表示占位符的提示文本，可用作输入框的占位符
+    pub fn placeholder(&self) -> String {
+        self.tokens
+            .iter()
+            .map(|token| match token {
+                MaskToken::Literal(c) => *c,
+                _ => '_',
+            })
+            .collect()
+    }
+
+    /// 推算格式化后的光标位置（按字符计）
+    ///
+    /// 通过比较上一次的值和本次输入的原始值找到编辑位置，光标保持在编辑位置之前的内容之后。
+    pub(crate) fn caret(&self, previous: &str, raw: &str) -> usize {
+        let previous = previous.chars().collect::<Vec<_>>();
+        let raw = raw.chars().collect::<Vec<_>>();
+        let prefix = previous
+            .iter()
+            .zip(&raw)
+            .take_while(|(a, b)| a == b)
+            .count();
+        let suffix = previous[prefix..]
+            .iter()
+            .rev()
+            .zip(raw[prefix..].iter().rev())
+            .take_while(|(a, b)| a == b)
+            .count();
+        let before_caret = raw[..raw.len() - suffix].iter().collect::<String>();
+        self.format(&before_caret).chars().count()
+    }
+}
+
+impl From<&str> for InputMask {
+    fn from(pattern: &str) -> Self {
+        Self::new(pattern)
+    }
+}
+
+impl From<String> for InputMask {
+    fn from(pattern: String) -> Self {
+        Self::new(pattern)
+    }
+}
+
+/// 将格式化后的值和光标位置同步到浏览器中的输入框
+///
+/// 格式化后的值与当前渲染的值相同时，虚拟 DOM 不会更新输入框，需要直接写入。
+/// 写入延迟到本次渲染完成之后执行，非浏览器环境中脚本执行失败，直接忽略。
+pub(crate) fn sync_input(id: &str, value: &str, caret: usize) {
+    let caret = value
+        .chars()
+        .take(caret)
+        .map(char::len_utf16)
+        .sum::<usize>();
+    let js = format!(
+        "setTimeout(() => {{ const el = document.getElementById({id:?}); if (!el) return; \
+         if (el.value !== {value:?}) el.value = {value:?}; \
+         if (document.activeElement === el) el.setSelectionRange({caret}, {caret}); }}, 0);"
+    );
+    let _ = document::eval(&js);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_format_inserts_literals_and_drops_invalid() {
+        let mask = InputMask::phone();
+        assert_eq!(mask.format(""), "");
+        assert_eq!(mask.format("5"), "(5");
+        assert_eq!(mask.format("5551"), "(555) 1");
+        assert_eq!(mask.format("(555) 12x3-4567890"), "(555) 123-4567");
+        assert!(mask.is_complete("5551234567"));
+        assert!(!mask.is_complete("555123"));
+
+        let mask = InputMask::new(r"aa-\9**");
+        assert_eq!(mask.format("ab12"), "ab-912");
+        assert_eq!(mask.unmask("ab-912"), "ab12");
+        assert_eq!(mask.placeholder(), "__-9__");
+    }
+
+    #[test]
+    fn test_caret() {
+        let mask = InputMask::phone();
+        // 在末尾输入
+        assert_eq!(mask.caret("(555", "(5551"), 7);
+        // 在中间插入
+        assert_eq!(mask.caret("(555) 123", "(5595) 123"), 4);
+        // 删除固定字符时光标停在固定字符之前
+        assert_eq!(mask.caret("(555) 123", "(555 123"), 4);
+        // 粘贴
+        assert_eq!(mask.caret("", "5551234567"), 14);
+    }
+}
@@ -141,6 +141,7 @@
 //! dom.rebuild(&mut mutations);
 //! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
@@ -149,9 +150,9 @@ use rust_decimal::{
     prelude::{FromPrimitive, ToPrimitive},
 };
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{Style, classnames, traits::ToElement, use_unique_id};
 
 /// 解析输入字符串为 InputNumberValue
 ///
@@ -264,16 +265,23 @@ pub enum InputNumberSize {
     Large,
 }
 
-impl std::fmt::Display for InputNumberSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl InputNumberSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            InputNumberSize::Medium => write!(f, ""),
-            InputNumberSize::Small => write!(f, "t-input-number--small"),
-            InputNumberSize::Large => write!(f, "t-input-number--large"),
+            InputNumberSize::Medium => "",
+            InputNumberSize::Small => "t-input-number--small",
+            InputNumberSize::Large => "t-input-number--large",
         }
     }
 }
 
+impl std::fmt::Display for InputNumberSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 按钮方向枚举
 ///
 /// 定义步进按钮的位置。
@@ -286,15 +294,22 @@ pub enum ControlsPosition {
     Both,
 }
 
-impl std::fmt::Display for ControlsPosition {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl ControlsPosition {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            ControlsPosition::Right => write!(f, ""),
-            ControlsPosition::Both => write!(f, "t-input-number--controls-both"),
+            ControlsPosition::Right => "",
+            ControlsPosition::Both => "t-input-number--controls-both",
         }
     }
 }
 
+impl std::fmt::Display for ControlsPosition {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 输入框值类型枚举
 ///
 /// 支持整数和浮点数两种类型。
@@ -447,7 +462,7 @@ pub struct InputNumber {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -473,6 +488,8 @@ pub struct InputNumber {
     controls_position: ControlsPosition,
     /// 占位符
     placeholder: String,
+    /// 内部输入框的 ID，未设置时自动生成
+    input_id: Option<String>,
     /// 值改变事件（接收新值，通常需要更新 signal）
     onchange: Option<EventHandler<InputNumberValue>>,
     /// 失去焦点事件
@@ -485,7 +502,7 @@ impl Default for InputNumber {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-input-number".to_string(),
+            class: Cow::Borrowed(classnames::INPUT_NUMBER),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -498,6 +515,7 @@ impl Default for InputNumber {
             size: InputNumberSize::default(),
             controls_position: ControlsPosition::default(),
             placeholder: String::new(),
+            input_id: None,
             onchange: None,
             onblur: None,
             onfocus: None,
@@ -505,6 +523,7 @@ impl Default for InputNumber {
     }
 }
 
+#[builder_props]
 impl InputNumber {
     /// 创建一个新的数字输入框实例
     ///
@@ -933,6 +952,7 @@ impl InputNumber {
     /// let mut mutations = Mutations::default();
     /// dom.rebuild(&mut mutations);
     /// ```
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -966,6 +986,7 @@ impl InputNumber {
     /// let mut mutations = Mutations::default();
     /// dom.rebuild(&mut mutations);
     /// ```
+    #[prop(default = "InputNumberSize::Medium")]
     pub fn size(mut self, size: InputNumberSize) -> Self {
         self.size = size;
         self
@@ -999,6 +1020,7 @@ impl InputNumber {
     /// let mut mutations = Mutations::default();
     /// dom.rebuild(&mut mutations);
     /// ```
+    #[prop(default = "ControlsPosition::Right")]
     pub fn controls_position(mut self, position: ControlsPosition) -> Self {
         self.controls_position = position;
         self
@@ -1037,6 +1059,29 @@ impl InputNumber {
         self
     }
 
+    /// 设置内部输入框的 ID
+    ///
+    /// 未设置时自动生成，用于关联外部的 `<label for>` 以及步进按钮的 `aria-controls`。
+    ///
+    /// # 参数
+    ///
+    /// * `input_id` - 输入框 ID
+    ///
+    /// # 返回值
+    ///
+    /// 返回修改后的数字输入框实例，支持链式调用
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::InputNumber;
+    /// InputNumber::new().input_id("quantity");
+    /// ```
+    pub fn input_id<T: Into<String>>(mut self, input_id: T) -> Self {
+        self.input_id = Some(input_id.into());
+        self
+    }
+
     /// 设置值改变事件
     ///
     /// # 参数
@@ -1096,6 +1141,7 @@ impl InputNumber {
     /// let mut mutations = Mutations::default();
     /// dom.rebuild(&mut mutations);
     /// ```
+    #[prop(skip)]
     pub fn onchange2(mut self, handler: EventHandler<InputNumberValue>) -> Self {
         self.onchange = Some(handler);
         self
@@ -1162,6 +1208,7 @@ impl InputNumber {
     /// let mut mutations = Mutations::default();
     /// dom.rebuild(&mut mutations);
     /// ```
+    #[prop(skip)]
     pub fn onblur2(mut self, handler: EventHandler<FocusEvent>) -> Self {
         self.onblur = Some(handler);
         self
@@ -1228,6 +1275,7 @@ impl InputNumber {
     /// let mut mutations = Mutations::default();
     /// dom.rebuild(&mut mutations);
     /// ```
+    #[prop(skip)]
     pub fn onfocus2(mut self, handler: EventHandler<FocusEvent>) -> Self {
         self.onfocus = Some(handler);
         self
@@ -1387,12 +1435,12 @@ impl ToElement for InputNumber {
         let id = self.id.clone();
 
         let mut class_names = vec![
-            self.class.clone(),
-            self.size.to_string(),
-            self.controls_position.to_string(),
+            &*self.class,
+            self.size.as_class(),
+            self.controls_position.as_class(),
         ];
         if self.disabled {
-            class_names.push("t-input-number--disabled".to_string());
+            class_names.push(classnames::INPUT_NUMBER_DISABLED);
         }
         let class = class_names.join(" ");
 
@@ -1402,6 +1450,8 @@ impl ToElement for InputNumber {
         let max = self.max.clone();
         let step = self.step.clone();
         let placeholder = self.placeholder.clone();
+        let generated_id = use_unique_id("input-number");
+        let input_id = self.input_id.clone().unwrap_or(generated_id);
         let precision = self.precision;
 
         // 获取 value signal，如果未设置则使用默认值
@@ -1439,12 +1489,13 @@ impl ToElement for InputNumber {
             div { id, class, style,
                 if self.controls_position == ControlsPosition::Right {
                     // 右侧按钮布局
-                    div { class: "t-input-number__wrapper" }
+                    div { class: classnames::INPUT_NUMBER_WRAPPER }
                 }
 
                 input {
                     r#type: "number",
-                    class: "t-input-number__inner",
+                    id: "{input_id}",
+                    class: classnames::INPUT_NUMBER_INNER,
                     value: format_value(&value_signal.read()),
                     placeholder,
                     disabled,
@@ -1504,10 +1555,12 @@ impl ToElement for InputNumber {
                 }
 
                 // 步进按钮
-                div { class: "t-input-number__controls",
+                div { class: classnames::INPUT_NUMBER_CONTROLS,
                     // 减号按钮
                     button {
-                        class: "t-input-number__decrease",
+                        class: classnames::INPUT_NUMBER_DECREASE,
+                        aria_label: "减少",
+                        aria_controls: "{input_id}",
                         disabled,
                         onclick: move |event: MouseEvent| {
                             if disabled {
@@ -1541,7 +1594,9 @@ impl ToElement for InputNumber {
 
                     // 加号按钮
                     button {
-                        class: "t-input-number__increase",
+                        class: classnames::INPUT_NUMBER_INCREASE,
+                        aria_label: "增加",
+                        aria_controls: "{input_id}",
                         disabled,
                         onclick: move |event: MouseEvent| {
                             if disabled {
@@ -0,0 +1,489 @@
+//! JsonViewer JSON 查看器组件
+//!
+//! 以可折叠的树形结构格式化显示 [`serde_json::Value`]，不同类型的值使用不同颜色，
+//! 支持按键名搜索（自动展开命中节点的上级）以及复制节点的 JSONPath，适用于接口调试等页面。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{JsonViewer, ToElement};
+//! use dioxus::core::Mutations;
+//! use serde_json::json;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     JsonViewer::new(json!({
+//!         "code": 0,
+//!         "data": { "items": [{ "id": 1, "name": "dioxus" }] },
+//!         "message": null
+//!     }))
+//!     .expand_depth(2)
+//!     .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use serde_json::Value;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Input, InputSize, Style, classnames, copy_text, traits::ToElement};
+
+/// JsonViewer JSON 查看器组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct JsonViewer {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 显示的 JSON 值
+    value: Value,
+    /// 默认展开的层数，`None` 表示全部展开
+    expand_depth: Option<usize>,
+    /// 是否显示键名搜索框
+    searchable: bool,
+    /// 是否显示复制路径按钮
+    copyable: bool,
+    /// 复制路径后的回调，参数为节点的 JSONPath
+    oncopy_path: Option<EventHandler<String>>,
+}
+
+impl Default for JsonViewer {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::JSON_VIEWER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: Value::Null,
+            expand_depth: None,
+            searchable: true,
+            copyable: true,
+            oncopy_path: None,
+        }
+    }
+}
+
+#[builder_props]
+impl JsonViewer {
+    /// 使用 JSON 值创建查看器
+    pub fn new(value: Value) -> Self {
+        Self {
+            value,
+            ..Default::default()
+        }
+    }
+
+    /// 设置默认展开的层数，更深的对象和数组默认折叠，`0` 表示只显示根节点的摘要
+    pub fn expand_depth(mut self, depth: usize) -> Self {
+        self.expand_depth = Some(depth);
+        self
+    }
+
+    /// 设置是否显示键名搜索框
+    #[prop(default = "true")]
+    pub fn searchable(mut self, searchable: bool) -> Self {
+        self.searchable = searchable;
+        self
+    }
+
+    /// 设置是否在鼠标移入时显示复制路径按钮
+    #[prop(default = "true")]
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
+    /// 设置复制路径后的回调，参数为节点的 JSONPath，如 `$.data.items[0].name`
+    pub fn oncopy_path(mut self, handler: impl FnMut(String) + 'static) -> Self {
+        self.oncopy_path = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置复制路径后的回调
+    #[prop(skip)]
+    pub fn oncopy_path2(mut self, handler: EventHandler<String>) -> Self {
+        self.oncopy_path = Some(handler);
+        self
+    }
+}
+
+/// 子节点的 JSONPath，标识符形式的键使用点号，其它键使用带引号的方括号
+fn child_path(parent: &str, key: &str) -> String {
+    let is_ident = key
+        .chars()
+        .next()
+        .is_some_and(|c| c.is_ascii_alphabetic() || c == '_' || c == '$')
+        && key
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || c == '_' || c == '$');
+    if is_ident {
+        format!("{parent}.{key}")
+    } else {
+        format!("{parent}[{}]", Value::String(key.to_string()))
+    }
+}
+
+/// 数组元素的 JSONPath
+fn index_path(parent: &str, index: usize) -> String {
+    format!("{parent}[{index}]")
+}
+
+/// 收集默认折叠的节点路径
+fn collect_collapsed(
+    value: &Value,
+    path: &str,
+    depth: usize,
+    max: usize,
+    set: &mut HashSet<String>,
+) {
+    let children: Vec<(String, &Value)> = match value {
+        Value::Object(map) => map.iter().map(|(k, v)| (child_path(path, k), v)).collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (index_path(path, i), v))
+            .collect(),
+        _ => return,
+    };
+    if depth >= max && !children.is_empty() {
+        set.insert(path.to_string());
+    }
+    for (child, value) in children {
+        collect_collapsed(value, &child, depth + 1, max, set);
+    }
+}
+
+/// 搜索键名，返回子树中是否有命中
+///
+/// `matches` 收集命中的节点路径，`expanded` 收集包含命中节点、需要强制展开的节点路径。
+fn search(
+    value: &Value,
+    path: &str,
+    query: &str,
+    matches: &mut HashSet<String>,
+    expanded: &mut HashSet<String>,
+) -> bool {
+    let mut found = false;
+    match value {
+        Value::Object(map) => {
+            for (key, child) in map {
+                let child_path = child_path(path, key);
+                if key.to_lowercase().contains(query) {
+                    matches.insert(child_path.clone());
+                    found = true;
+                }
+                found |= search(child, &child_path, query, matches, expanded);
+            }
+        }
+        Value::Array(items) => {
+            for (i, child) in items.iter().enumerate() {
+                found |= search(child, &index_path(path, i), query, matches, expanded);
+            }
+        }
+        _ => {}
+    }
+    if found {
+        expanded.insert(path.to_string());
+    }
+    found
+}
+
+/// 节点的键
+enum NodeKey<'a> {
+    /// 根节点
+    Root,
+    /// 对象的键
+    Key(&'a str),
+    /// 数组的下标
+    Index(usize),
+}
+
+/// 节点渲染时共享的上下文
+struct NodeContext {
+    collapsed: Signal<HashSet<String>>,
+    copied: Signal<Option<String>>,
+    matches: HashSet<String>,
+    expanded: HashSet<String>,
+    copyable: bool,
+    oncopy_path: Option<EventHandler<String>>,
+}
+
+/// 原始值的类名和显示文本
+fn primitive(value: &Value) -> (&'static str, String) {
+    match value {
+        Value::String(_) => (classnames::JSON_VIEWER_STRING, value.to_string()),
+        Value::Number(n) => (classnames::JSON_VIEWER_NUMBER, n.to_string()),
+        Value::Bool(b) => (classnames::JSON_VIEWER_BOOLEAN, b.to_string()),
+        _ => (classnames::JSON_VIEWER_NULL, "null".to_string()),
+    }
+}
+
+/// 递归渲染单个节点及其子树
+fn render_node(
+    node_key: NodeKey,
+    value: &Value,
+    path: String,
+    last: bool,
+    ctx: &NodeContext,
+) -> Element {
+    let mut collapsed = ctx.collapsed;
+    let mut copied = ctx.copied;
+    let oncopy_path = ctx.oncopy_path;
+
+    let children: Vec<(NodeKey, &Value, String)> = match value {
+        Value::Object(map) => map
+            .iter()
+            .map(|(k, v)| (NodeKey::Key(k), v, child_path(&path, k)))
+            .collect(),
+        Value::Array(items) => items
+            .iter()
+            .enumerate()
+            .map(|(i, v)| (NodeKey::Index(i), v, index_path(&path, i)))
+            .collect(),
+        _ => Vec::new(),
+    };
+    let brackets = match value {
+        Value::Object(_) => Some(("{", "}")),
+        Value::Array(_) => Some(("[", "]")),
+        _ => None,
+    };
+    let is_collapsed =
+        !children.is_empty() && collapsed.read().contains(&path) && !ctx.expanded.contains(&path);
+    let comma = if last { "" } else { "," };
+
+    let key_class = if ctx.matches.contains(&path) {
+        format!("{} is-match", classnames::JSON_VIEWER_KEY)
+    } else {
+        classnames::JSON_VIEWER_KEY.to_string()
+    };
+    let key_label = match node_key {
+        NodeKey::Root => None,
+        NodeKey::Key(key) => Some(Value::String(key.to_string()).to_string()),
+        NodeKey::Index(index) => Some(index.to_string()),
+    };
+    let is_copied = copied.read().as_deref() == Some(path.as_str());
+    let toggle_path = path.clone();
+    let copy_path = path.clone();
+    let count = children.len();
+
+    rsx! {
+        div { key: "{path}", class: classnames::JSON_VIEWER_NODE,
+            div { class: classnames::JSON_VIEWER_LINE, "data-path": "{path}",
+                if !children.is_empty() {
+                    span {
+                        class: if is_collapsed { format!("{} is-collapsed", classnames::JSON_VIEWER_TOGGLE) } else { classnames::JSON_VIEWER_TOGGLE.to_string() },
+                        title: if is_collapsed { "展开" } else { "折叠" },
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            let mut set = collapsed.write();
+                            if !set.remove(&toggle_path) {
+                                set.insert(toggle_path.clone());
+                            }
+                        },
+                        "▾"
+                    }
+                }
+                if let Some(label) = key_label {
+                    span { class: key_class, "{label}" }
+                    span { class: classnames::JSON_VIEWER_COLON, ": " }
+                }
+                match brackets {
+                    Some((open, close)) if children.is_empty() => rsx! {
+                        span { class: classnames::JSON_VIEWER_BRACKET, "{open}{close}{comma}" }
+                    },
+                    Some((open, close)) if is_collapsed => rsx! {
+                        span { class: classnames::JSON_VIEWER_BRACKET, "{open}" }
+                        span { class: classnames::JSON_VIEWER_SUMMARY, " {count} 项 " }
+                        span { class: classnames::JSON_VIEWER_BRACKET, "{close}{comma}" }
+                    },
+                    Some((open, _)) => rsx! {
+                        span { class: classnames::JSON_VIEWER_BRACKET, "{open}" }
+                    },
+                    None => {
+                        let (class, text) = primitive(value);
+                        let class = format!("{} {class}", classnames::JSON_VIEWER_VALUE);
+                        rsx! {
+                            span { class, "{text}" }
+                            "{comma}"
+                        }
+                    }
+                }
+                if ctx.copyable {
+                    button {
+                        r#type: "button",
+                        class: if is_copied { format!("{} is-copied", classnames::JSON_VIEWER_COPY) } else { classnames::JSON_VIEWER_COPY.to_string() },
+                        title: "复制路径 {path}",
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            let path = copy_path.clone();
+                            spawn(async move {
+                                if copy_text(&path).await {
+                                    copied.set(Some(path.clone()));
+                                }
+                                if let Some(handler) = oncopy_path {
+                                    handler.call(path);
+                                }
+                            });
+                        },
+                        if is_copied {
+                            "✓"
+                        } else {
+                            "⧉"
+                        }
+                    }
+                }
+            }
+            if let Some((_, close)) = brackets.filter(|_| !children.is_empty() && !is_collapsed) {
+                div { class: classnames::JSON_VIEWER_CHILDREN,
+                    for (i, (key, child, child_path)) in children.into_iter().enumerate() {
+                        {render_node(key, child, child_path, i + 1 == count, ctx)}
+                    }
+                }
+                div { class: classnames::JSON_VIEWER_LINE,
+                    span { class: classnames::JSON_VIEWER_BRACKET, "{close}{comma}" }
+                }
+            }
+        }
+    }
+}
+
+impl ToElement for JsonViewer {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let value = self.value.clone();
+        let expand_depth = self.expand_depth;
+        let collapsed = use_signal(|| {
+            let mut set = HashSet::new();
+            if let Some(max) = expand_depth {
+                collect_collapsed(&value, "$", 0, max, &mut set);
+            }
+            set
+        });
+        let copied = use_signal(|| None::<String>);
+        let query = use_signal(String::new);
+
+        let needle = query.read().trim().to_lowercase();
+        let mut matches = HashSet::new();
+        let mut expanded = HashSet::new();
+        if !needle.is_empty() {
+            search(&self.value, "$", &needle, &mut matches, &mut expanded);
+        }
+        let match_count = matches.len();
+        let ctx = NodeContext {
+            collapsed,
+            copied,
+            matches,
+            expanded,
+            copyable: self.copyable,
+            oncopy_path: self.oncopy_path,
+        };
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if self.searchable {
+                    div { class: classnames::JSON_VIEWER_SEARCH,
+                        {Input::new().value(query).placeholder("搜索键名").size(InputSize::Small).clearable(true).to_element()}
+                        if !needle.is_empty() {
+                            span { class: classnames::JSON_VIEWER_SEARCH_COUNT, "{match_count} 个匹配" }
+                        }
+                    }
+                }
+                div { class: classnames::JSON_VIEWER_TREE,
+                    {render_node(NodeKey::Root, &self.value, "$".to_string(), true, &ctx)}
+                }
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+
+    #[test]
+    fn test_child_path() {
+        assert_eq!(child_path("$", "data"), "$.data");
+        assert_eq!(
+            child_path("$.data", "first name"),
+            r#"$.data["first name"]"#
+        );
+        assert_eq!(child_path("$", "0a"), r#"$["0a"]"#);
+        assert_eq!(index_path("$.items", 2), "$.items[2]");
+    }
+
+    #[test]
+    fn test_collect_collapsed() {
+        let value = json!({ "a": { "b": { "c": 1 } }, "list": [[1], []] });
+        let mut set = HashSet::new();
+        collect_collapsed(&value, "$", 0, 1, &mut set);
+
+        let mut paths = set.into_iter().collect::<Vec<_>>();
+        paths.sort();
+        assert_eq!(paths, vec!["$.a", "$.a.b", "$.list", "$.list[0]"]);
+    }
+
+    #[test]
+    fn test_search_expands_ancestors() {
+        let value = json!({ "data": { "items": [{ "Name": "x" }] }, "name": "y" });
+        let mut matches = HashSet::new();
+        let mut expanded = HashSet::new();
+        search(&value, "$", "name", &mut matches, &mut expanded);
+
+        assert!(matches.contains("$.name"));
+        assert!(matches.contains("$.data.items[0].Name"));
+        assert!(expanded.contains("$.data.items[0]"));
+        assert!(expanded.contains("$.data"));
+        assert!(!expanded.contains("$.name"));
+    }
+
+    #[test]
+    fn test_render_json_viewer() {
+        fn app() -> Element {
+            JsonViewer::new(json!({ "id": 1, "ok": true, "tags": ["a"], "nested": { "x": null } }))
+                .expand_depth(1)
+                .searchable(false)
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains(classnames::JSON_VIEWER_NUMBER));
+        assert!(html.contains(classnames::JSON_VIEWER_BOOLEAN));
+        assert!(html.contains(r#"data-path="$.tags""#));
+        assert!(html.contains(" 1 项 "));
+        assert!(!html.contains(r#"data-path="$.nested.x""#));
+        assert!(!html.contains(classnames::JSON_VIEWER_SEARCH));
+    }
+}
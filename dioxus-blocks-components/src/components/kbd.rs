@@ -0,0 +1,480 @@
+//! Kbd 按键和 ShortcutHint 快捷键提示组件
+//!
+//! [`Kbd`] 以键帽样式显示单个按键，[`ShortcutHint`] 把快捷键定义（如 `mod+K`）按当前平台格式化后显示：
+//! macOS 和 iOS 上显示为 `⌘K`，其他平台显示为 `Ctrl+K`。[`Tooltip`](crate::Tooltip) 和
+//! [`DropdownItem`](crate::DropdownItem) 通过 `shortcut` 方法显示同样的提示。
+//!
+//! # 快捷键定义
+//!
+//! 快捷键由 `+` 连接的按键组成，不区分大小写，最后一个按键为主键，其余为修饰键：
+//!
+//! - `mod`: macOS 上为 Command，其他平台为 Ctrl，适合跨平台的快捷键
+//! - `ctrl`、`alt`（或 `option`）、`shift`、`meta`（或 `cmd`）
+//! - 主键为单个字符或按键名称，如 `enter`、`esc`、`tab`、`space`、`backspace`、`delete`、`up`、`f5`
+//!
+//! 平台在浏览器或桌面 WebView 中通过 `navigator` 检测，检测完成前以及服务端渲染时按编译目标判断。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Kbd, ShortcutHint, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     View::new()
+//!         .children(Kbd::new("Esc"))
+//!         .children(ShortcutHint::new("mod+shift+P"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 格式化
+//!
+//! ```rust
+//! use dioxus_blocks_components::Shortcut;
+//!
+//! let shortcut = Shortcut::parse("mod+shift+k");
+//! assert_eq!(shortcut.format(true), "⇧⌘K");
+//! assert_eq!(shortcut.format(false), "Ctrl+Shift+K");
+//! assert_eq!(Shortcut::parse("alt+enter").keys(true), ["⌥", "↩"]);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 当前平台是否为 macOS 或 iOS，检测前按编译目标判断
+static IS_MAC: GlobalSignal<bool> =
+    Signal::global(|| cfg!(any(target_os = "macos", target_os = "ios")));
+
+/// 是否已开始检测平台
+static DETECTED: GlobalSignal<bool> = Signal::global(|| false);
+
+/// 在浏览器中检测平台，非浏览器环境保持编译目标的判断
+async fn detect_platform() {
+    if cfg!(feature = "server") {
+        return;
+    }
+    let result = document::eval(
+        "const platform = (navigator.userAgentData && navigator.userAgentData.platform) \
+           || navigator.platform || navigator.userAgent || ''; \
+         return /mac|iphone|ipad|ipod/i.test(platform);",
+    )
+    .join::<bool>()
+    .await;
+    if let Ok(value) = result
+        && *IS_MAC.peek() != value
+    {
+        *IS_MAC.write() = value;
+    }
+}
+
+/// 当前平台是否为 macOS 或 iOS
+///
+/// 首次调用时在浏览器中检测平台，检测完成后使用该值的组件会重新渲染。
+pub fn use_is_mac() -> bool {
+    use_hook(|| {
+        if !*DETECTED.peek() {
+            *DETECTED.write() = true;
+            spawn(detect_platform());
+        }
+    });
+    IS_MAC()
+}
+
+/// 修饰键，按 macOS 的显示顺序排列
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+enum Modifier {
+    Ctrl,
+    Alt,
+    Shift,
+    Meta,
+}
+
+impl Modifier {
+    fn label(&self, mac: bool) -> &'static str {
+        match (self, mac) {
+            (Modifier::Ctrl, true) => "⌃",
+            (Modifier::Ctrl, false) => "Ctrl",
+            (Modifier::Alt, true) => "⌥",
+            (Modifier::Alt, false) => "Alt",
+            (Modifier::Shift, true) => "⇧",
+            (Modifier::Shift, false) => "Shift",
+            (Modifier::Meta, true) => "⌘",
+            (Modifier::Meta, false) => "Win",
+        }
+    }
+}
+
+/// 快捷键定义
+///
+/// 解析 `mod+K` 形式的定义，按平台格式化为显示的文本。
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Shortcut {
+    /// 修饰键，`mod` 保存为 `None`，格式化时按平台确定
+    modifiers: Vec<Option<Modifier>>,
+    /// 主键，已转换为小写
+    key: String,
+}
+
+impl Shortcut {
+    /// 解析快捷键定义，未知的修饰键会被忽略
+    pub fn parse(definition: &str) -> Self {
+        let mut parts: Vec<String> = definition
+            .split('+')
+            .map(|part| part.trim().to_lowercase())
+            .collect();
+        // `mod++` 表示主键为 `+`
+        if definition.trim_end().ends_with("++") {
+            parts.truncate(parts.len().saturating_sub(2));
+            parts.push("+".to_string());
+        }
+        let key = parts.pop().unwrap_or_default();
+        let modifiers = parts
+            .iter()
+            .filter_map(|part| match part.as_str() {
+                "mod" => Some(None),
+                "ctrl" | "control" => Some(Some(Modifier::Ctrl)),
+                "alt" | "option" | "opt" => Some(Some(Modifier::Alt)),
+                "shift" => Some(Some(Modifier::Shift)),
+                "meta" | "cmd" | "command" | "win" => Some(Some(Modifier::Meta)),
+                _ => None,
+            })
+            .collect();
+        Self { modifiers, key }
+    }
+
+    /// 按平台排列的按键文本，修饰键在前，主键在后
+    pub fn keys(&self, mac: bool) -> Vec<String> {
+        let mod_key = if mac { Modifier::Meta } else { Modifier::Ctrl };
+        let mut modifiers: Vec<Modifier> = self
+            .modifiers
+            .iter()
+            .map(|modifier| modifier.unwrap_or(mod_key))
+            .collect();
+        modifiers.sort();
+        modifiers.dedup();
+        let mut keys: Vec<String> = modifiers
+            .iter()
+            .map(|modifier| modifier.label(mac).to_string())
+            .collect();
+        if !self.key.is_empty() {
+            keys.push(key_label(&self.key, mac));
+        }
+        keys
+    }
+
+    /// 按平台格式化，macOS 上按键直接相连，其他平台以 `+` 连接
+    pub fn format(&self, mac: bool) -> String {
+        self.keys(mac).join(if mac { "" } else { "+" })
+    }
+}
+
+impl From<&str> for Shortcut {
+    fn from(definition: &str) -> Self {
+        Shortcut::parse(definition)
+    }
+}
+
+/// 主键的显示文本
+fn key_label(key: &str, mac: bool) -> String {
+    let label = match (key, mac) {
+        ("enter" | "return", true) => "↩",
+        ("enter" | "return", false) => "Enter",
+        ("esc" | "escape", _) => "Esc",
+        ("tab", true) => "⇥",
+        ("tab", false) => "Tab",
+        ("space", _) => "Space",
+        ("backspace", true) => "⌫",
+        ("backspace", false) => "Backspace",
+        ("delete" | "del", true) => "⌦",
+        ("delete" | "del", false) => "Delete",
+        ("up", _) => "↑",
+        ("down", _) => "↓",
+        ("left", _) => "←",
+        ("right", _) => "→",
+        ("pageup", _) => "PgUp",
+        ("pagedown", _) => "PgDn",
+        ("home", _) => "Home",
+        ("end", _) => "End",
+        _ => {
+            let mut chars = key.chars();
+            return match (chars.next(), chars.next()) {
+                // 单个字符和功能键大写显示，如 `k` 显示为 `K`，`f5` 显示为 `F5`
+                (Some(c), None) => c.to_uppercase().collect(),
+                (Some('f'), Some(_)) if key[1..].chars().all(|c| c.is_ascii_digit()) => {
+                    key.to_uppercase()
+                }
+                _ => {
+                    let mut chars = key.chars();
+                    chars
+                        .next()
+                        .map(|c| c.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                }
+            };
+        }
+    };
+    label.to_string()
+}
+
+/// 按键组件
+///
+/// 以键帽样式显示一个按键，内容可以是文本或子元素。
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Kbd {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，设置后代替文本显示
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 按键文本
+    text: String,
+}
+
+impl Default for Kbd {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::KBD),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            text: String::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl Kbd {
+    /// 使用按键文本创建按键
+    pub fn new(text: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            ..Default::default()
+        }
+    }
+}
+
+impl ToElement for Kbd {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let text = self.text.clone();
+
+        rsx! {
+            kbd {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if self.childrens.is_empty() {
+                    "{text}"
+                } else {
+                    {self.childrens_to_element()}
+                }
+            }
+        }
+    }
+}
+
+/// 快捷键提示组件
+///
+/// 按当前平台显示快捷键，每个按键显示为 [`Kbd`]，`plain` 模式下显示为一段文本，适合菜单项等紧凑的位置。
+/// 组件以独立的组件作用域渲染，可以在条件分支中使用。
+#[derive(Debug, Clone, ComponentBase)]
+pub struct ShortcutHint {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 快捷键定义
+    shortcut: Shortcut,
+    /// 是否显示为文本
+    plain: bool,
+    /// 指定按 macOS 或其他平台显示，为 `None` 时自动检测
+    mac: Option<bool>,
+}
+
+impl Default for ShortcutHint {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::SHORTCUT_HINT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            shortcut: Shortcut::default(),
+            plain: false,
+            mac: None,
+        }
+    }
+}
+
+#[builder_props]
+impl ShortcutHint {
+    /// 使用快捷键定义创建快捷键提示，如 `mod+K`、`shift+enter`
+    pub fn new(shortcut: impl Into<Shortcut>) -> Self {
+        Self {
+            shortcut: shortcut.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置是否显示为文本而不是键帽
+    #[prop(default = "false")]
+    pub fn plain(mut self, plain: bool) -> Self {
+        self.plain = plain;
+        self
+    }
+
+    /// 指定按 macOS（`true`）或其他平台（`false`）显示，不设置时自动检测
+    pub fn mac(mut self, mac: bool) -> Self {
+        self.mac = Some(mac);
+        self
+    }
+}
+
+impl ToElement for ShortcutHint {
+    fn to_element(&self) -> Element {
+        rsx! {
+            ShortcutHintElement { hint: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct ShortcutHintElementProps {
+    hint: Rc<ShortcutHint>,
+}
+
+impl PartialEq for ShortcutHintElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.hint, &other.hint)
+    }
+}
+
+#[allow(non_snake_case)]
+fn ShortcutHintElement(props: ShortcutHintElementProps) -> Element {
+    let hint = props.hint;
+    let detected = use_is_mac();
+    let mac = hint.mac.unwrap_or(detected);
+
+    let id = hint.id.clone();
+    let mut class = hint.class.to_string();
+    if hint.plain {
+        class.push(' ');
+        class.push_str(classnames::SHORTCUT_HINT_PLAIN);
+    }
+    let style = hint.style.clone().map(|s| s.to_string());
+    let onclick_handler = hint.onclick;
+    let label = hint.shortcut.format(mac);
+    let keys = hint.shortcut.keys(mac);
+    let plain = hint.plain;
+
+    rsx! {
+        span {
+            id,
+            class,
+            style,
+            aria_label: if !plain { Some(label.clone()) } else { None },
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            if plain {
+                "{label}"
+            } else {
+                for (index , key) in keys.into_iter().enumerate() {
+                    if index > 0 && !mac {
+                        span { class: classnames::SHORTCUT_HINT_SEPARATOR, "+" }
+                    }
+                    {Kbd::new(key).to_element()}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_format_shortcut() {
+        let shortcut = Shortcut::parse("mod+K");
+        assert_eq!(shortcut.format(true), "⌘K");
+        assert_eq!(shortcut.format(false), "Ctrl+K");
+
+        // 修饰键按平台的习惯排序并去重
+        let shortcut = Shortcut::parse("Shift+Ctrl+Alt+p");
+        assert_eq!(shortcut.format(true), "⌃⌥⇧P");
+        assert_eq!(shortcut.format(false), "Ctrl+Alt+Shift+P");
+        assert_eq!(Shortcut::parse("mod+ctrl+s").format(false), "Ctrl+S");
+
+        assert_eq!(Shortcut::parse("esc").format(true), "Esc");
+        assert_eq!(Shortcut::parse("mod+enter").format(true), "⌘↩");
+        assert_eq!(
+            Shortcut::parse("mod+backspace").format(false),
+            "Ctrl+Backspace"
+        );
+        assert_eq!(Shortcut::parse("f12").format(false), "F12");
+        assert_eq!(Shortcut::parse("cmd+up").format(true), "⌘↑");
+        assert_eq!(Shortcut::parse("mod++").format(false), "Ctrl++");
+        assert_eq!(Shortcut::parse("mod+/").keys(true), ["⌘", "/"]);
+        assert_eq!(Shortcut::parse("insert").format(false), "Insert");
+        assert_eq!(Shortcut::parse("hyper+x").format(false), "X");
+        assert_eq!(Shortcut::parse("").format(false), "");
+    }
+
+    #[test]
+    fn test_shortcut_hint() {
+        let mut harness = Harness::with_element(ShortcutHint::new("mod+shift+k").mac(false));
+        let hint = harness.find_by_class(classnames::SHORTCUT_HINT).unwrap();
+        assert_eq!(hint.attr("aria-label"), Some("Ctrl+Shift+K"));
+        let keys = harness.find_all_by_class(classnames::KBD);
+        let keys: Vec<&str> = keys.iter().map(|key| key.text()).collect();
+        assert_eq!(keys, ["Ctrl", "Shift", "K"]);
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::SHORTCUT_HINT_SEPARATOR)
+                .len(),
+            2
+        );
+
+        harness = Harness::with_element(ShortcutHint::new("mod+k").mac(true).plain(true));
+        let hint = harness.find_by_class(classnames::SHORTCUT_HINT).unwrap();
+        assert!(hint.has_class(classnames::SHORTCUT_HINT_PLAIN));
+        assert_eq!(hint.text(), "⌘K");
+        assert!(harness.find_by_class(classnames::KBD).is_none());
+    }
+}
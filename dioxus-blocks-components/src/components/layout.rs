@@ -38,13 +38,14 @@
 //!     .to_element()
 //! }
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, ToElement};
+use crate::{Style, ToElement, classnames};
 
 /// 列宽度枚举
 ///
@@ -116,7 +117,7 @@ pub struct Row {
     /// 行的唯一标识符
     id: Option<String>,
     /// 行的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 行的内联样式
     style: Option<Style>,
     /// 行的子元素列表, 当前组件不支持 childrens 子元素
@@ -140,7 +141,7 @@ impl Default for Row {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-row".to_string(),
+            class: Cow::Borrowed(classnames::ROW),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -156,24 +157,18 @@ impl Default for Row {
 impl ToElement for Row {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let mut style = self
-            .style
-            .clone()
-            .map(|s| s.to_string())
-            .unwrap_or("".to_string());
         let onclick_handler = self.onclick;
 
-        // Flexbox 布局样式
-        style.push_str("display: flex;");
-        // 使用 border-box 确保盒模型一致
-        style.push_str("box-sizing: border-box;");
-        style.push_str(&format!(
-            "flex-direction: {};",
-            if self.vertical { "column" } else { "row" }
-        ));
-
-        style.push_str(&format!("justify-content: {};", self.justify));
-        style.push_str(&format!("align-items: {};", self.align_items));
+        // 组件内置的 Flexbox 布局样式，用户样式合并在其之上
+        let style = Style::default()
+            .display("flex")
+            // 使用 border-box 确保盒模型一致
+            .custom("box-sizing: border-box")
+            .flex_direction(if self.vertical { "column" } else { "row" })
+            .justify_content(self.justify.to_string())
+            .align_items(self.align_items.to_string())
+            .merge(self.style.clone().unwrap_or_default())
+            .to_string();
 
         let childs = self
             .cols
@@ -188,7 +183,7 @@ impl ToElement for Row {
         rsx! {
             div {
                 id,
-                class: self.class.clone(),
+                class: self.class.to_string(),
                 style,
                 onclick: move |event: MouseEvent| {
                     if let Some(handler) = onclick_handler {
@@ -204,6 +199,7 @@ impl ToElement for Row {
     }
 }
 
+#[builder_props]
 impl Row {
     /// 创建一个新的行容器实例
     ///
@@ -309,6 +305,7 @@ impl Row {
     ///     Col::new(Text::new("2")),
     /// ]).gutter(20);
     /// ```
+    #[prop(default = "0")]
     pub fn gutter(mut self, gutter: usize) -> Self {
         self.gutter = gutter;
         self
@@ -330,6 +327,7 @@ impl Row {
     /// # use dioxus_blocks_components::{Justify, Row};
     /// Row::default().justify(Justify::Center);
     /// ```
+    #[prop(default = "Justify::Start")]
     pub fn justify(mut self, justify: Justify) -> Self {
         self.justify = justify;
         self
@@ -399,7 +397,7 @@ pub struct Col {
     /// 列的唯一标识符
     id: Option<String>,
     /// 列的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 列的内联样式
     style: Option<Style>,
     /// 列的子元素列表
@@ -418,7 +416,7 @@ impl Default for Col {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-col".to_string(),
+            class: Cow::Borrowed(classnames::COL),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -432,52 +430,50 @@ impl Default for Col {
 impl ToElement for Col {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let mut style = self
-            .style
-            .clone()
-            .map(|s| s.to_string())
-            .unwrap_or("".to_string());
         let onclick_handler = self.onclick;
         let childrens = self.childrens_to_element();
 
-        // Flexbox 布局样式
-        style.push_str("display: flex;");
-        // 使用 border-box 使 padding 包含在宽度内
-        style.push_str("box-sizing: border-box;");
+        // 组件内置的 Flexbox 布局样式
+        let mut style = Style::default()
+            .display("flex")
+            // 使用 border-box 使 padding 包含在宽度内
+            .custom("box-sizing: border-box");
 
         // 根据 span 类型计算 flex 属性
-        match self.span {
+        style = match self.span {
             ColSpan::Span(n) => {
                 // 将 24 等分转换为百分比: span / 24 * 100
                 // 保留小数精度，避免四舍五入导致总宽度超出 100%
                 let percent = n as f64 / 24.0 * 100.0;
-                style.push_str(&format!("flex: 0 0 {}%;", percent));
-            }
-            ColSpan::Percent(p) => {
-                style.push_str(&format!("flex: 0 0 {}%;", p));
+                style.flex(format!("0 0 {}%", percent))
             }
-            ColSpan::Auto => {
-                style.push_str("flex: 1 1 auto;");
-            }
-        }
+            ColSpan::Percent(p) => style.flex(format!("0 0 {}%", p)),
+            ColSpan::Auto => style.flex("1 1 auto"),
+        };
 
         // 偏移量（margin-left）
         if self.offset > 0 {
             let offset_percent = self.offset as f64 / 24.0 * 100.0;
-            style.push_str(&format!("margin-left: {}%;", offset_percent));
+            style = style.margin_left(format!("{}%", offset_percent));
         }
 
         // 通过 CSS 变量从父级 Row 读取 gutter 值
         if self.gutter != 0 {
             let gutter_half = self.gutter as f64 / 2.0;
-            style.push_str(&format!("padding-left: {}px;", gutter_half));
-            style.push_str(&format!("padding-right: {}px;", gutter_half));
+            style = style
+                .padding_left(format!("{}px", gutter_half))
+                .padding_right(format!("{}px", gutter_half));
         }
 
+        // 用户样式合并在内置样式之上
+        let style = style
+            .merge(self.style.clone().unwrap_or_default())
+            .to_string();
+
         rsx! {
             div {
                 id,
-                class: self.class.clone(),
+                class: self.class.to_string(),
                 style,
                 onclick: move |event: MouseEvent| {
                     if let Some(handler) = onclick_handler {
@@ -490,6 +486,7 @@ impl ToElement for Col {
     }
 }
 
+#[builder_props]
 impl Col {
     /// 创建一个新的列容器实例
     ///
@@ -592,6 +589,7 @@ impl Col {
     /// # use dioxus_blocks_components::Col;
     /// Col::default().offset(6);
     /// ```
+    #[prop(default = "0")]
     pub fn offset(mut self, offset: u8) -> Self {
         self.offset = offset;
         self
@@ -1,6 +1,7 @@
 //! Link 组件
 //!
 //! 提供一个可自定义的链接组件，支持路由跳转、字符串路径跳转、多种类型和下划线样式。
+//! 站内路径可以带有锚点，如 `/button#不同尺寸`，跳转后滚动到页面中对应 id 的元素。
 //!
 //! # 示例
 //!
@@ -64,12 +65,13 @@
 //! # dom.rebuild(&mut dioxus_core::NoOpMutations);
 //! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{Style, classnames, traits::ToElement};
 
 /// 链接类型枚举
 ///
@@ -91,19 +93,26 @@ pub enum LinkType {
     Danger,
 }
 
-impl std::fmt::Display for LinkType {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl LinkType {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            LinkType::Default => write!(f, ""),
-            LinkType::Primary => write!(f, "t-link--primary"),
-            LinkType::Success => write!(f, "t-link--success"),
-            LinkType::Info => write!(f, "t-link--info"),
-            LinkType::Warning => write!(f, "t-link--warning"),
-            LinkType::Danger => write!(f, "t-link--danger"),
+            LinkType::Default => "",
+            LinkType::Primary => "t-link--primary",
+            LinkType::Success => "t-link--success",
+            LinkType::Info => "t-link--info",
+            LinkType::Warning => "t-link--warning",
+            LinkType::Danger => "t-link--danger",
         }
     }
 }
 
+impl std::fmt::Display for LinkType {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 下划线样式枚举
 ///
 /// 定义链接的下划线显示方式。
@@ -118,16 +127,23 @@ pub enum LinkUnderline {
     Never,
 }
 
-impl std::fmt::Display for LinkUnderline {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl LinkUnderline {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            LinkUnderline::Always => write!(f, "t-link--underline-always"),
-            LinkUnderline::Hover => write!(f, "t-link--underline-hover"),
-            LinkUnderline::Never => write!(f, "t-link--underline-never"),
+            LinkUnderline::Always => "t-link--underline-always",
+            LinkUnderline::Hover => "t-link--underline-hover",
+            LinkUnderline::Never => "t-link--underline-never",
         }
     }
 }
 
+impl std::fmt::Display for LinkUnderline {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 链接组件结构体
 ///
 /// 提供一个可自定义的链接，支持多种跳转方式、样式、类型和子元素。
@@ -136,7 +152,7 @@ pub struct Link {
     /// 链接的唯一标识符
     id: Option<String>,
     /// 链接的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 链接的内联样式
     style: Option<Style>,
     /// 链接的子元素列表
@@ -162,7 +178,7 @@ impl Default for Link {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-link".to_string(),
+            class: Cow::Borrowed(classnames::LINK),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -180,14 +196,14 @@ impl ToElement for Link {
     fn to_element(&self) -> Element {
         // 构建完整的 class 列表
         let mut class_names = vec![
-            self.class.clone(),
-            self.link_type.to_string(),
-            self.underline.to_string(),
+            &*self.class,
+            self.link_type.as_class(),
+            self.underline.as_class(),
         ];
 
         // 添加状态 class
         if self.disabled {
-            class_names.push("t-link--disabled".to_string());
+            class_names.push(classnames::LINK_DISABLED);
         }
 
         let id = self.id.clone();
@@ -197,6 +213,13 @@ impl ToElement for Link {
         let childrens = self.childrens_to_element();
         let to = self.to.clone();
         let onclick_handler = self.onclick;
+        // 站内地址带有锚点时，跳转后滚动到锚点，目标为当前页面时路由不变，需要在这里处理
+        let anchor = match &self.to {
+            NavigationTarget::Internal(path) => {
+                path.split_once('#').map(|(_, anchor)| anchor.to_string())
+            }
+            NavigationTarget::External(_) => None,
+        };
 
         rsx! {
             Link {
@@ -206,6 +229,9 @@ impl ToElement for Link {
                 to,
                 new_tab: self.new_tab,
                 onclick: move |event: MouseEvent| {
+                    if let Some(anchor) = &anchor {
+                        crate::scroll_to_anchor(anchor);
+                    }
                     if let Some(handler) = onclick_handler {
                         handler.call(event);
                     }
@@ -218,6 +244,7 @@ impl ToElement for Link {
 }
 
 /// Link 组件实现
+#[builder_props]
 impl Link {
     /// 创建一个新的链接实例
     ///
@@ -343,6 +370,7 @@ impl Link {
     /// # });
     /// # dom.rebuild(&mut dioxus_core::NoOpMutations);
     /// ```
+    #[prop(default = "false")]
     pub fn new_tab(mut self, new_tab: bool) -> Self {
         self.new_tab = new_tab;
         self
@@ -372,6 +400,7 @@ impl Link {
     /// # });
     /// # dom.rebuild(&mut dioxus_core::NoOpMutations);
     /// ```
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
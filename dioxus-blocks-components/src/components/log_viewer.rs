@@ -0,0 +1,570 @@
+//! LogViewer 日志查看器组件
+//!
+//! 适合持续追加的日志流：日志保存在固定容量的环形缓冲区 [`LogBuffer`] 中，超出容量时丢弃最早的行；
+//! 只渲染可视区域内的行，数万行日志也能流畅滚动。支持解析 ANSI 颜色、按级别筛选、搜索，
+//! 以及“跟随底部”模式，开启时新日志到达后自动滚动到底部，向上滚动时自动关闭。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{LogBuffer, LogViewer, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut logs = use_signal(|| LogBuffer::new(5000));
+//!     use_hook(|| {
+//!         logs.write().push("\x1b[32mINFO\x1b[0m server started on :8080");
+//!         logs.write().push("WARN slow query: 1200ms");
+//!     });
+//!     LogViewer::new(logs).height(240).to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::{HashSet, VecDeque};
+use std::rc::Rc;
+
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use super::ansi::{self, AnsiSpan};
+use crate::{Input, InputSize, Style, classnames, traits::ToElement};
+
+/// 可视区域上下额外渲染的行数，减少快速滚动时的空白
+const OVERSCAN: usize = 10;
+
+/// 日志级别
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum LogLevel {
+    Trace,
+    Debug,
+    Info,
+    Warn,
+    Error,
+}
+
+impl LogLevel {
+    /// 全部级别，从低到高排列
+    pub const ALL: [LogLevel; 5] = [
+        LogLevel::Trace,
+        LogLevel::Debug,
+        LogLevel::Info,
+        LogLevel::Warn,
+        LogLevel::Error,
+    ];
+
+    /// 级别名称，如 `WARN`
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "TRACE",
+            LogLevel::Debug => "DEBUG",
+            LogLevel::Info => "INFO",
+            LogLevel::Warn => "WARN",
+            LogLevel::Error => "ERROR",
+        }
+    }
+
+    /// 级别对应的类名修饰符
+    fn as_class(&self) -> &'static str {
+        match self {
+            LogLevel::Trace => "trace",
+            LogLevel::Debug => "debug",
+            LogLevel::Info => "info",
+            LogLevel::Warn => "warn",
+            LogLevel::Error => "error",
+        }
+    }
+
+    /// 从日志文本中识别级别，取第一个等于级别名称的单词（不区分大小写），识别不到时返回 `None`
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::LogLevel;
+    /// assert_eq!(LogLevel::detect("2024-01-01 [warn] disk 90%"), Some(LogLevel::Warn));
+    /// assert_eq!(LogLevel::detect("listening on :8080"), None);
+    /// ```
+    pub fn detect(text: &str) -> Option<Self> {
+        text.split(|c: char| !c.is_ascii_alphabetic())
+            .find_map(|word| match word.to_ascii_uppercase().as_str() {
+                "TRACE" => Some(LogLevel::Trace),
+                "DEBUG" => Some(LogLevel::Debug),
+                "INFO" => Some(LogLevel::Info),
+                "WARN" | "WARNING" => Some(LogLevel::Warn),
+                "ERROR" | "ERR" | "FATAL" => Some(LogLevel::Error),
+                _ => None,
+            })
+    }
+}
+
+/// 一行日志
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogLine {
+    /// 从 0 开始的行号，被丢弃的行也会计数
+    seq: u64,
+    /// 日志级别
+    level: Option<LogLevel>,
+    /// 去掉 ANSI 转义序列后的文本
+    text: String,
+    /// 带样式的片段
+    spans: Vec<AnsiSpan>,
+}
+
+impl LogLine {
+    /// 从 0 开始的行号
+    pub fn seq(&self) -> u64 {
+        self.seq
+    }
+
+    /// 日志级别，未识别时为 `None`
+    pub fn level(&self) -> Option<LogLevel> {
+        self.level
+    }
+
+    /// 去掉 ANSI 转义序列后的文本
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// 固定容量的日志环形缓冲区
+///
+/// 通常保存在信号中交给 [`LogViewer`]，向信号写入新日志后查看器自动更新。
+#[derive(Debug, Clone, PartialEq)]
+pub struct LogBuffer {
+    lines: VecDeque<LogLine>,
+    capacity: usize,
+    next_seq: u64,
+}
+
+impl Default for LogBuffer {
+    fn default() -> Self {
+        Self::new(10_000)
+    }
+}
+
+impl LogBuffer {
+    /// 创建指定容量的缓冲区，容量至少为 1
+    pub fn new(capacity: usize) -> Self {
+        let capacity = capacity.max(1);
+        Self {
+            lines: VecDeque::with_capacity(capacity.min(1024)),
+            capacity,
+            next_seq: 0,
+        }
+    }
+
+    /// 追加日志，文本中的换行会拆分为多行，级别从文本中识别
+    pub fn push(&mut self, text: impl AsRef<str>) {
+        for line in text.as_ref().lines() {
+            let spans = ansi::parse(line);
+            let plain = spans
+                .iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>();
+            let level = LogLevel::detect(&plain);
+            self.push_line(level, plain, spans);
+        }
+    }
+
+    /// 追加指定级别的日志
+    pub fn push_with_level(&mut self, level: LogLevel, text: impl AsRef<str>) {
+        for line in text.as_ref().lines() {
+            let spans = ansi::parse(line);
+            let plain = spans
+                .iter()
+                .map(|span| span.text.as_str())
+                .collect::<String>();
+            self.push_line(Some(level), plain, spans);
+        }
+    }
+
+    /// 批量追加日志
+    pub fn extend<I, T>(&mut self, lines: I)
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        for line in lines {
+            self.push(line);
+        }
+    }
+
+    fn push_line(&mut self, level: Option<LogLevel>, text: String, spans: Vec<AnsiSpan>) {
+        if self.lines.len() == self.capacity {
+            self.lines.pop_front();
+        }
+        self.lines.push_back(LogLine {
+            seq: self.next_seq,
+            level,
+            text,
+            spans,
+        });
+        self.next_seq += 1;
+    }
+
+    /// 清空缓冲区，行号继续递增
+    pub fn clear(&mut self) {
+        self.lines.clear();
+    }
+
+    /// 当前保存的行数
+    pub fn len(&self) -> usize {
+        self.lines.len()
+    }
+
+    /// 是否为空
+    pub fn is_empty(&self) -> bool {
+        self.lines.is_empty()
+    }
+
+    /// 容量
+    pub fn capacity(&self) -> usize {
+        self.capacity
+    }
+
+    /// 累计追加的行数，包括已被丢弃的行
+    pub fn total(&self) -> u64 {
+        self.next_seq
+    }
+
+    /// 按追加顺序遍历当前保存的行
+    pub fn iter(&self) -> impl Iterator<Item = &LogLine> {
+        self.lines.iter()
+    }
+}
+
+/// LogViewer 日志查看器组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct LogViewer {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 日志缓冲区
+    buffer: Option<Signal<LogBuffer>>,
+    /// 日志区域高度（像素）
+    height: u32,
+    /// 行高（像素）
+    line_height: u32,
+    /// 是否显示工具栏
+    show_toolbar: bool,
+    /// 是否显示行号
+    show_line_number: bool,
+    /// 初始是否跟随底部
+    stick_to_bottom: bool,
+}
+
+impl Default for LogViewer {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::LOG_VIEWER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            buffer: None,
+            height: 320,
+            line_height: 20,
+            show_toolbar: true,
+            show_line_number: true,
+            stick_to_bottom: true,
+        }
+    }
+}
+
+#[builder_props]
+impl LogViewer {
+    /// 使用日志缓冲区信号创建查看器
+    pub fn new(buffer: Signal<LogBuffer>) -> Self {
+        Self {
+            buffer: Some(buffer),
+            ..Default::default()
+        }
+    }
+
+    /// 设置日志区域高度（像素）
+    #[prop(default = "320")]
+    pub fn height(mut self, height: u32) -> Self {
+        self.height = height.max(1);
+        self
+    }
+
+    /// 设置行高（像素），虚拟滚动按固定行高计算可视区域，日志不会自动换行
+    #[prop(default = "20")]
+    pub fn line_height(mut self, line_height: u32) -> Self {
+        self.line_height = line_height.max(1);
+        self
+    }
+
+    /// 设置是否显示包含级别筛选、搜索和跟随底部开关的工具栏
+    #[prop(default = "true")]
+    pub fn show_toolbar(mut self, show: bool) -> Self {
+        self.show_toolbar = show;
+        self
+    }
+
+    /// 设置是否显示行号
+    #[prop(default = "true")]
+    pub fn show_line_number(mut self, show: bool) -> Self {
+        self.show_line_number = show;
+        self
+    }
+
+    /// 设置初始是否跟随底部，开启时新日志到达后自动滚动到底部
+    #[prop(default = "true")]
+    pub fn stick_to_bottom(mut self, stick: bool) -> Self {
+        self.stick_to_bottom = stick;
+        self
+    }
+}
+
+/// 渲染一行日志
+fn render_line(line: &LogLine, line_height: u32, show_line_number: bool) -> Element {
+    let class = match line.level {
+        Some(level) => format!(
+            "{} {}--{}",
+            classnames::LOG_VIEWER_LINE,
+            classnames::LOG_VIEWER_LINE,
+            level.as_class()
+        ),
+        None => classnames::LOG_VIEWER_LINE.to_string(),
+    };
+    let number = line.seq + 1;
+
+    rsx! {
+        div {
+            key: "{line.seq}",
+            class,
+            style: "height: {line_height}px; line-height: {line_height}px;",
+            if show_line_number {
+                span { class: classnames::LOG_VIEWER_NUMBER, "{number}" }
+            }
+            span { class: classnames::LOG_VIEWER_TEXT,
+                for span in line.spans.iter() {
+                    span { class: span.style.class(), style: span.style.inline_style(), "{span.text}" }
+                }
+            }
+        }
+    }
+}
+
+impl ToElement for LogViewer {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let fallback = use_signal(LogBuffer::default);
+        let mut buffer = self.buffer.unwrap_or(fallback);
+        let initial_stick = self.stick_to_bottom;
+        let mut stick = use_signal(|| initial_stick);
+        let mut scroll_top = use_signal(|| 0.0);
+        let query = use_signal(String::new);
+        let mut hidden = use_signal(HashSet::<LogLevel>::new);
+        let mut body = use_signal(|| None::<Rc<MountedData>>);
+
+        // 新日志到达或重新开启跟随时滚动到底部
+        use_effect(move || {
+            buffer.read();
+            if !stick() {
+                return;
+            }
+            if let Some(body) = body.peek().clone() {
+                spawn(async move {
+                    let _ = body
+                        .scroll(PixelsVector2D::new(0.0, 1e9), ScrollBehavior::Instant)
+                        .await;
+                });
+            }
+        });
+
+        let height = self.height as usize;
+        let line_height = self.line_height as usize;
+        let needle = query.read().trim().to_lowercase();
+        let hidden_levels = hidden.read().clone();
+        let logs = buffer.read();
+        let lines = logs
+            .iter()
+            .filter(|line| {
+                line.level
+                    .is_none_or(|level| !hidden_levels.contains(&level))
+            })
+            .filter(|line| needle.is_empty() || line.text.to_lowercase().contains(&needle))
+            .collect::<Vec<_>>();
+        let total = lines.len();
+        let total_height = total * line_height;
+
+        // 跟随底部时直接按底部计算可视区域，避免等待滚动事件造成闪烁
+        let top = if stick() {
+            total_height.saturating_sub(height)
+        } else {
+            scroll_top() as usize
+        };
+        let first = (top / line_height).saturating_sub(OVERSCAN);
+        let last = ((top + height).div_ceil(line_height) + OVERSCAN).min(total);
+        let first = first.min(last);
+        let offset = first * line_height;
+        let visible = &lines[first..last];
+        let show_line_number = self.show_line_number;
+        let count = logs.len();
+
+        let stick_class = if stick() {
+            format!("{} is-active", classnames::LOG_VIEWER_BUTTON)
+        } else {
+            classnames::LOG_VIEWER_BUTTON.to_string()
+        };
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if self.show_toolbar {
+                    div { class: classnames::LOG_VIEWER_TOOLBAR,
+                        div { class: classnames::LOG_VIEWER_LEVELS,
+                            for level in LogLevel::ALL {
+                                button {
+                                    key: "{level.as_str()}",
+                                    r#type: "button",
+                                    class: if hidden_levels.contains(&level) { format!("{} {}--{}", classnames::LOG_VIEWER_LEVEL, classnames::LOG_VIEWER_LEVEL, level.as_class()) } else { format!("{} {}--{} is-active", classnames::LOG_VIEWER_LEVEL, classnames::LOG_VIEWER_LEVEL, level.as_class()) },
+                                    aria_pressed: !hidden_levels.contains(&level),
+                                    onclick: move |_| {
+                                        let mut hidden = hidden.write();
+                                        if !hidden.remove(&level) {
+                                            hidden.insert(level);
+                                        }
+                                    },
+                                    "{level.as_str()}"
+                                }
+                            }
+                        }
+                        {Input::new().value(query).placeholder("搜索日志").size(InputSize::Small).clearable(true).to_element()}
+                        span { class: classnames::LOG_VIEWER_COUNT, "{total} / {count} 行" }
+                        button {
+                            r#type: "button",
+                            class: stick_class,
+                            aria_pressed: stick(),
+                            onclick: move |_| stick.toggle(),
+                            "跟随底部"
+                        }
+                        button {
+                            r#type: "button",
+                            class: classnames::LOG_VIEWER_BUTTON,
+                            onclick: move |_| buffer.write().clear(),
+                            "清空"
+                        }
+                    }
+                }
+                div {
+                    class: classnames::LOG_VIEWER_BODY,
+                    role: "log",
+                    style: "height: {height}px;",
+                    onmounted: move |event: MountedEvent| body.set(Some(event.data())),
+                    onscroll: move |event: ScrollEvent| {
+                        let data = event.data();
+                        let top = data.scroll_top();
+                        scroll_top.set(top);
+                        // 离开底部超过一行时关闭跟随
+                        let distance = data.scroll_height() as f64 - data.client_height() as f64 - top;
+                        if distance > line_height as f64 && *stick.peek() {
+                            stick.set(false);
+                        }
+                    },
+                    if total == 0 {
+                        div { class: classnames::LOG_VIEWER_EMPTY, "暂无日志" }
+                    } else {
+                        div { style: "position: relative; height: {total_height}px;",
+                            div {
+                                class: classnames::LOG_VIEWER_LINES,
+                                style: "transform: translateY({offset}px);",
+                                for line in visible.iter() {
+                                    {render_line(line, line_height as u32, show_line_number)}
+                                }
+                            }
+                        }
+                    }
+                }
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_detect_level() {
+        assert_eq!(LogLevel::detect("[ERROR] boom"), Some(LogLevel::Error));
+        assert_eq!(LogLevel::detect("warning: unused"), Some(LogLevel::Warn));
+        assert_eq!(LogLevel::detect("information"), None);
+    }
+
+    #[test]
+    fn test_ring_buffer_drops_oldest() {
+        let mut buffer = LogBuffer::new(3);
+        buffer.extend(["a", "b", "c", "d\ne"]);
+
+        let lines = buffer.iter().map(LogLine::text).collect::<Vec<_>>();
+        assert_eq!(lines, vec!["c", "d", "e"]);
+        assert_eq!(buffer.iter().next().unwrap().seq(), 2);
+        assert_eq!(buffer.total(), 5);
+        assert_eq!(buffer.len(), 3);
+    }
+
+    #[test]
+    fn test_push_strips_ansi() {
+        let mut buffer = LogBuffer::default();
+        buffer.push("\x1b[31mERROR\x1b[0m failed");
+
+        let line = buffer.iter().next().unwrap();
+        assert_eq!(line.text(), "ERROR failed");
+        assert_eq!(line.level(), Some(LogLevel::Error));
+    }
+
+    #[test]
+    fn test_render_only_visible_lines() {
+        fn app() -> Element {
+            let mut logs = use_signal(|| LogBuffer::new(5000));
+            use_hook(|| {
+                logs.write()
+                    .extend((0..1000).map(|i| format!("INFO line {i}")))
+            });
+            LogViewer::new(logs)
+                .height(200)
+                .line_height(20)
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+
+        // 跟随底部：渲染最后 10 行以及上方的 10 行预渲染
+        assert_eq!(html.matches(r#"class="t-log-viewer__line "#).count(), 20);
+        assert!(html.contains("line 999"));
+        assert!(!html.contains("line 979<"));
+        assert!(html.contains("height: 20000px;"));
+        assert!(html.contains("1000 / 1000 行"));
+    }
+}
@@ -0,0 +1,357 @@
+//! Masonry 瀑布流布局组件
+//!
+//! 把高度不一的子元素排列到多列中，各列高度尽量接近，适合图片墙、卡片列表等页面。
+//!
+//! # 布局策略
+//!
+//! - [`MasonryStrategy::Columns`]：使用 CSS 多列布局，由浏览器按列依次填充，子元素的阅读顺序为先上下后左右，
+//!   不需要测量，服务端渲染的结果与浏览器一致。
+//! - [`MasonryStrategy::Measure`]：测量每个子元素的高度，依次放入当前最短的一列，阅读顺序大致为先左右后上下，
+//!   子元素高度变化（如图片加载完成）后重新分配。无法测量时按顺序轮流放入各列。
+//!
+//! # 响应式列数
+//!
+//! 通过 `breakpoint` 按容器宽度设置列数：容器宽度不超过断点宽度时使用该断点的列数，有多个断点满足时取宽度最小的一个。
+//! 容器宽度来自 `resize` 事件，无法测量时使用 `columns` 设置的列数。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Card, Masonry, MasonryStrategy, Text, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     (1..=6)
+//!         .fold(Masonry::new(), |masonry, i| {
+//!             masonry.children(Card::new().children(Text::p("文字".repeat(i * 5))))
+//!         })
+//!         .columns(3)
+//!         .gap(12)
+//!         .strategy(MasonryStrategy::Measure)
+//!         .breakpoint(960, 2)
+//!         .breakpoint(600, 1)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 瀑布流布局策略
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MasonryStrategy {
+    /// CSS 多列布局
+    #[default]
+    Columns,
+    /// 测量子元素高度，放入最短的一列
+    Measure,
+}
+
+/// 瀑布流布局组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Masonry {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 列数
+    columns: usize,
+    /// 列和子元素之间的间距，单位为像素
+    gap: usize,
+    /// 布局策略
+    strategy: MasonryStrategy,
+    /// 响应式断点，元素为 (容器最大宽度, 列数)
+    breakpoints: Vec<(u32, usize)>,
+}
+
+impl Default for Masonry {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::MASONRY),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            columns: 3,
+            gap: 16,
+            strategy: MasonryStrategy::default(),
+            breakpoints: Vec::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl Masonry {
+    /// 创建瀑布流布局，默认 3 列、间距 16px
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置列数，最小为 1
+    #[prop(default = "3")]
+    pub fn columns(mut self, columns: usize) -> Self {
+        self.columns = columns.max(1);
+        self
+    }
+
+    /// 设置列和子元素之间的间距，单位为像素
+    #[prop(default = "16")]
+    pub fn gap(mut self, gap: usize) -> Self {
+        self.gap = gap;
+        self
+    }
+
+    /// 设置布局策略
+    #[prop(default = "MasonryStrategy::Columns")]
+    pub fn strategy(mut self, strategy: MasonryStrategy) -> Self {
+        self.strategy = strategy;
+        self
+    }
+
+    /// 添加响应式断点，容器宽度不超过 `max_width` 像素时使用 `columns` 列
+    pub fn breakpoint(mut self, max_width: u32, columns: usize) -> Self {
+        self.breakpoints.push((max_width, columns.max(1)));
+        self
+    }
+}
+
+/// 根据容器宽度计算列数
+fn column_count(width: Option<f64>, columns: usize, breakpoints: &[(u32, usize)]) -> usize {
+    let Some(width) = width else {
+        return columns;
+    };
+    breakpoints
+        .iter()
+        .filter(|(max_width, _)| width <= f64::from(*max_width))
+        .min_by_key(|(max_width, _)| *max_width)
+        .map_or(columns, |(_, columns)| *columns)
+}
+
+/// 把子元素依次放入当前最短的一列，返回每列中子元素的下标，高度未知的子元素按 0 计算
+fn distribute(heights: &[Option<f64>], count: usize, columns: usize, gap: f64) -> Vec<Vec<usize>> {
+    let mut result: Vec<Vec<usize>> = vec![Vec::new(); columns];
+    let mut totals = vec![0.0_f64; columns];
+    for index in 0..count {
+        // 高度相同时取子元素较少、再靠左的一列，因此都未测量时按顺序轮流放入
+        let column = (0..columns)
+            .min_by(|a, b| {
+                totals[*a]
+                    .total_cmp(&totals[*b])
+                    .then(result[*a].len().cmp(&result[*b].len()))
+                    .then(a.cmp(b))
+            })
+            .unwrap_or(0);
+        let height = heights.get(index).copied().flatten().unwrap_or(0.0);
+        totals[column] += height + gap;
+        result[column].push(index);
+    }
+    result
+}
+
+impl ToElement for Masonry {
+    fn to_element(&self) -> Element {
+        let mut width = use_signal(|| None::<f64>);
+        let mut heights = use_signal(Vec::<Option<f64>>::new);
+
+        let id = self.id.clone();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let gap = self.gap;
+        let columns = column_count(width(), self.columns, &self.breakpoints);
+        let onresize = move |event: ResizeEvent| {
+            if let Ok(size) = event.data().get_content_box_size()
+                && *width.peek() != Some(size.width)
+            {
+                width.set(Some(size.width));
+            }
+        };
+
+        // 按原有顺序构建子元素，放入不同的列时子元素的 hooks 顺序保持不变
+        let elements = self
+            .childrens
+            .iter()
+            .map(|child| child.to_element())
+            .collect::<Vec<_>>();
+
+        match self.strategy {
+            MasonryStrategy::Columns => {
+                let class = format!("{} {}", self.class, classnames::MASONRY_COLUMNS);
+                let layout = format!("column-count: {columns}; column-gap: {gap}px;");
+                let style = [Some(layout), style]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                rsx! {
+                    div {
+                        id,
+                        class,
+                        style: style.join(" "),
+                        onresize,
+                        onclick: move |event: MouseEvent| {
+                            if let Some(handler) = onclick_handler {
+                                handler.call(event);
+                            }
+                        },
+                        for element in elements {
+                            div {
+                                class: classnames::MASONRY_ITEM,
+                                style: "margin-bottom: {gap}px;",
+                                {element}
+                            }
+                        }
+                    }
+                }
+            }
+            MasonryStrategy::Measure => {
+                let class = format!("{} {}", self.class, classnames::MASONRY_MEASURE);
+                let layout = format!("gap: {gap}px;");
+                let style = [Some(layout), style]
+                    .into_iter()
+                    .flatten()
+                    .collect::<Vec<_>>();
+                let mut elements = elements.into_iter().map(Some).collect::<Vec<_>>();
+                let groups = distribute(&heights.read(), elements.len(), columns, gap as f64);
+                let groups = groups
+                    .into_iter()
+                    .map(|indexes| {
+                        indexes
+                            .into_iter()
+                            .filter_map(|index| {
+                                elements[index].take().map(|element| (index, element))
+                            })
+                            .collect::<Vec<_>>()
+                    })
+                    .collect::<Vec<_>>();
+
+                rsx! {
+                    div {
+                        id,
+                        class,
+                        style: style.join(" "),
+                        onresize,
+                        onclick: move |event: MouseEvent| {
+                            if let Some(handler) = onclick_handler {
+                                handler.call(event);
+                            }
+                        },
+                        for (column , items) in groups.into_iter().enumerate() {
+                            div {
+                                key: "{column}",
+                                class: classnames::MASONRY_COLUMN,
+                                style: "gap: {gap}px;",
+                                for (index , element) in items {
+                                    div {
+                                        key: "{index}",
+                                        class: classnames::MASONRY_ITEM,
+                                        onresize: move |event: ResizeEvent| {
+                                            let Ok(size) = event.data().get_border_box_size() else {
+                                                return;
+                                            };
+                                            let current = heights.peek().get(index).copied().flatten();
+                                            if current != Some(size.height) {
+                                                let mut heights = heights.write();
+                                                if heights.len() <= index {
+                                                    heights.resize(index + 1, None);
+                                                }
+                                                heights[index] = Some(size.height);
+                                            }
+                                        },
+                                        {element}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Text;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_column_count() {
+        let breakpoints = [(960, 2), (600, 1)];
+        assert_eq!(column_count(None, 4, &breakpoints), 4);
+        assert_eq!(column_count(Some(1200.0), 4, &breakpoints), 4);
+        assert_eq!(column_count(Some(800.0), 4, &breakpoints), 2);
+        assert_eq!(column_count(Some(600.0), 4, &breakpoints), 1);
+    }
+
+    #[test]
+    fn test_distribute() {
+        assert_eq!(distribute(&[], 5, 2, 0.0), [vec![0, 2, 4], vec![1, 3]]);
+        let heights = [Some(300.0), Some(100.0), Some(100.0), Some(100.0)];
+        assert_eq!(distribute(&heights, 4, 2, 16.0), [vec![0], vec![1, 2, 3]]);
+    }
+
+    #[test]
+    fn test_masonry_measure() {
+        let masonry = ["A", "B", "C", "D"]
+            .into_iter()
+            .fold(Masonry::new(), |masonry, text| {
+                masonry.children(Text::span(text))
+            })
+            .columns(2)
+            .breakpoint(600, 1)
+            .strategy(MasonryStrategy::Measure);
+        let mut harness = Harness::with_element(masonry);
+        let columns = harness.find_all_by_class(classnames::MASONRY_COLUMN);
+        assert_eq!(columns.len(), 2);
+        assert_eq!(columns[0].text(), "AC");
+
+        // 第一项较高时，后面的项都放入第二列
+        let items = harness.find_all_by_class(classnames::MASONRY_ITEM);
+        for (item, height) in items.iter().zip([300.0, 100.0, 100.0, 100.0]) {
+            harness.resize(item, 200.0, height);
+        }
+        let columns = harness.find_all_by_class(classnames::MASONRY_COLUMN);
+        assert_eq!(columns[0].text(), "A");
+        assert_eq!(columns[1].text(), "BCD");
+
+        // 容器变窄后变为单列
+        let root = harness.find_by_class(classnames::MASONRY).unwrap();
+        harness.resize(&root, 500.0, 600.0);
+        let columns = harness.find_all_by_class(classnames::MASONRY_COLUMN);
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].text(), "ABCD");
+    }
+
+    #[test]
+    fn test_masonry_columns_breakpoint() {
+        let masonry = Masonry::new()
+            .children(Text::span("A"))
+            .columns(4)
+            .breakpoint(600, 1);
+        let mut harness = Harness::with_element(masonry);
+        let root = harness.find_by_class(classnames::MASONRY).unwrap();
+        assert!(root.attr("style").unwrap().contains("column-count: 4;"));
+
+        harness.resize(&root, 500.0, 100.0);
+        let root = harness.find_by_class(classnames::MASONRY).unwrap();
+        assert!(root.attr("style").unwrap().contains("column-count: 1;"));
+    }
+}
@@ -0,0 +1,429 @@
+//! Message 消息提示
+//!
+//! 在页面边缘显示一行简短的操作反馈，如「保存成功」，超过显示时长后自动消失。
+//!
+//! 在应用的根组件中挂载一次 [`MessageProvider`]，之后在任意组件的事件处理器中调用本模块的
+//! [`success`]、[`error`] 等函数发送消息，不需要获取上下文。消息保存在全局的队列中，
+//! 由 MessageProvider 按 [`MessagePlacement`] 堆叠显示，超过显示时长或最大数量时移除。
+//! 没有挂载 MessageProvider 时消息不会显示。
+//!
+//! 全局队列属于当前的 Dioxus 运行时，发送消息的函数需要在组件或事件处理器中调用。
+//!
+//! 需要标题、正文或撤销操作时使用 [`Notification`](crate::Notification)。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, MessagePlacement, MessageProvider, ToElement, View, message};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     View::new()
+//!         .children(MessageProvider::new().placement(MessagePlacement::Top))
+//!         .children(
+//!             Button::new()
+//!                 .text("保存")
+//!                 .onclick(|_| {
+//!                     message::success("保存成功");
+//!                 }),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::{AlertType, Style, classnames, traits::ToElement};
+
+/// 全局的消息队列，按发送顺序排列
+static MESSAGES: GlobalSignal<Vec<MessageItem>> = Signal::global(Vec::new);
+
+/// 下一条消息的编号
+static NEXT_ID: GlobalSignal<u64> = Signal::global(|| 1);
+
+/// 一条消息
+#[derive(Debug, Clone, PartialEq)]
+pub struct MessageItem {
+    /// 消息编号，发送时分配
+    id: u64,
+    /// 内容
+    content: String,
+    /// 消息类型
+    kind: AlertType,
+    /// 显示时长，未设置时使用 MessageProvider 的设置
+    duration: Option<u32>,
+    /// 是否显示关闭按钮
+    closable: bool,
+}
+
+impl MessageItem {
+    /// 创建消息
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            content: content.into(),
+            kind: AlertType::Info,
+            duration: None,
+            closable: false,
+        }
+    }
+
+    /// 设置消息类型
+    pub fn kind(mut self, kind: AlertType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// 设置显示时长，单位为毫秒，为 0 时不自动关闭
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = Some(duration);
+        self
+    }
+
+    /// 设置是否显示关闭按钮
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// 消息编号
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 内容
+    pub fn content(&self) -> &str {
+        &self.content
+    }
+
+    /// 类型对应的类名修饰符
+    fn kind_class(&self) -> &'static str {
+        match self.kind {
+            AlertType::Success => "success",
+            AlertType::Info => "info",
+            AlertType::Warning => "warning",
+            AlertType::Error => "error",
+        }
+    }
+
+    /// 类型对应的图标
+    fn icon(&self) -> &'static str {
+        match self.kind {
+            AlertType::Success => "✓",
+            AlertType::Info => "i",
+            AlertType::Warning => "!",
+            AlertType::Error => "✕",
+        }
+    }
+}
+
+/// 发送消息，返回消息编号
+pub fn show(mut item: MessageItem) -> u64 {
+    let id = *NEXT_ID.peek();
+    *NEXT_ID.write() = id + 1;
+    item.id = id;
+    MESSAGES.write().push(item);
+    id
+}
+
+/// 发送信息消息
+pub fn info(content: impl Into<String>) -> u64 {
+    show(MessageItem::new(content))
+}
+
+/// 发送成功消息
+pub fn success(content: impl Into<String>) -> u64 {
+    show(MessageItem::new(content).kind(AlertType::Success))
+}
+
+/// 发送警告消息
+pub fn warning(content: impl Into<String>) -> u64 {
+    show(MessageItem::new(content).kind(AlertType::Warning))
+}
+
+/// 发送错误消息
+pub fn error(content: impl Into<String>) -> u64 {
+    show(MessageItem::new(content).kind(AlertType::Error))
+}
+
+/// 关闭消息，已关闭的消息不做处理
+pub fn close(id: u64) {
+    if MESSAGES.peek().iter().any(|item| item.id == id) {
+        MESSAGES.write().retain(|item| item.id != id);
+    }
+}
+
+/// 关闭全部消息
+pub fn close_all() {
+    if !MESSAGES.peek().is_empty() {
+        MESSAGES.write().clear();
+    }
+}
+
+/// 消息显示的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum MessagePlacement {
+    /// 顶部居中
+    #[default]
+    Top,
+    /// 左上角
+    TopLeft,
+    /// 右上角
+    TopRight,
+    /// 底部居中
+    Bottom,
+    /// 左下角
+    BottomLeft,
+    /// 右下角
+    BottomRight,
+}
+
+impl MessagePlacement {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            MessagePlacement::Top => "t-message--top",
+            MessagePlacement::TopLeft => "t-message--top-left",
+            MessagePlacement::TopRight => "t-message--top-right",
+            MessagePlacement::Bottom => "t-message--bottom",
+            MessagePlacement::BottomLeft => "t-message--bottom-left",
+            MessagePlacement::BottomRight => "t-message--bottom-right",
+        }
+    }
+}
+
+/// 消息的挂载点，在应用中挂载一次
+#[derive(Debug, Clone, ComponentBase)]
+pub struct MessageProvider {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 显示的位置
+    placement: MessagePlacement,
+    /// 默认的显示时长，单位为毫秒
+    duration: u32,
+    /// 最多同时显示的消息数量
+    max_count: usize,
+}
+
+impl Default for MessageProvider {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::MESSAGE),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            placement: MessagePlacement::default(),
+            duration: 3000,
+            max_count: 5,
+        }
+    }
+}
+
+#[builder_props]
+impl MessageProvider {
+    /// 创建消息的挂载点
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置显示的位置，多条消息从页面边缘向内依次堆叠
+    pub fn placement(mut self, placement: MessagePlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// 设置默认的显示时长，单位为毫秒，为 0 时不自动关闭，可以通过 [`MessageItem::duration`] 为单条消息设置
+    #[prop(default = "3000")]
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// 设置最多同时显示的消息数量，超出时关闭最早的消息
+    #[prop(default = "5")]
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = max_count.max(1);
+        self
+    }
+}
+
+impl ToElement for MessageProvider {
+    fn to_element(&self) -> Element {
+        rsx! {
+            MessageElement { provider: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct MessageElementProps {
+    provider: Rc<MessageProvider>,
+}
+
+impl PartialEq for MessageElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.provider, &other.provider)
+    }
+}
+
+#[allow(non_snake_case)]
+fn MessageElement(props: MessageElementProps) -> Element {
+    let provider = props.provider;
+    let (duration, max_count) = (provider.duration, provider.max_count);
+    // 已经开始计时的消息
+    let mut scheduled = use_hook(|| CopyValue::new(HashSet::<u64>::new()));
+
+    // 移除超出数量的消息，并为新消息开始计时
+    use_effect(move || {
+        let ids = MESSAGES
+            .read()
+            .iter()
+            .map(|item| item.id)
+            .collect::<Vec<_>>();
+        let overflow = ids.len().saturating_sub(max_count);
+        if overflow > 0 {
+            MESSAGES.write().drain(..overflow);
+            return;
+        }
+        scheduled.write().retain(|id| ids.contains(id));
+        for item in MESSAGES.peek().iter() {
+            if !scheduled.write().insert(item.id) {
+                continue;
+            }
+            let (id, duration) = (item.id, item.duration.unwrap_or(duration));
+            if duration > 0 {
+                spawn(async move {
+                    if sleep(duration).await {
+                        close(id);
+                    }
+                });
+            }
+        }
+    });
+
+    let items = MESSAGES.read().clone();
+    let class = format!("{} {}", provider.class, provider.placement.as_class());
+    let style = provider.style.clone().map(|s| s.to_string());
+    let onclick_handler = provider.onclick;
+
+    rsx! {
+        if !items.is_empty() {
+            div {
+                id: provider.id.clone(),
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                for item in items {
+                    div {
+                        key: "{item.id}",
+                        class: "{classnames::MESSAGE_ITEM} {classnames::MESSAGE_ITEM}--{item.kind_class()}",
+                        role: if item.kind == AlertType::Error { "alert" } else { "status" },
+                        span { class: classnames::MESSAGE_ICON, "aria-hidden": "true", {item.icon()} }
+                        span { class: classnames::MESSAGE_CONTENT, "{item.content}" }
+                        if item.closable {
+                            button {
+                                class: classnames::MESSAGE_CLOSE,
+                                r#type: "button",
+                                aria_label: "关闭",
+                                onclick: move |_| close(item.id),
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+        {provider.childrens_to_element()}
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn app() -> Element {
+        rsx! {
+            {MessageProvider::new().placement(MessagePlacement::BottomRight).max_count(3).to_element()}
+            button {
+                class: "save",
+                onclick: move |_| {
+                    success("保存成功");
+                },
+            }
+            button {
+                class: "fail",
+                onclick: move |_| {
+                    show(MessageItem::new("网络错误").kind(AlertType::Error).closable(true));
+                },
+            }
+            button { class: "clear", onclick: move |_| close_all() }
+        }
+    }
+
+    fn contents(harness: &Harness) -> Vec<String> {
+        harness
+            .find_all_by_class(classnames::MESSAGE_CONTENT)
+            .iter()
+            .map(|content| content.text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_message_show_and_close() {
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::MESSAGE).is_none());
+
+        harness.click(&harness.find_by_class("save").unwrap());
+        let container = harness.find_by_class(classnames::MESSAGE).unwrap();
+        assert!(container.has_class("t-message--bottom-right"));
+        let item = harness.find_by_class(classnames::MESSAGE_ITEM).unwrap();
+        assert!(item.has_class("t-message__item--success"));
+        assert_eq!(item.attr("role"), Some("status"));
+        assert!(harness.find_by_class(classnames::MESSAGE_CLOSE).is_none());
+
+        harness.click(&harness.find_by_class("fail").unwrap());
+        assert_eq!(contents(&harness), ["保存成功", "网络错误"]);
+        harness.click(&harness.find_by_class(classnames::MESSAGE_CLOSE).unwrap());
+        assert_eq!(contents(&harness), ["保存成功"]);
+
+        harness.click(&harness.find_by_class("clear").unwrap());
+        assert!(harness.find_by_class(classnames::MESSAGE).is_none());
+    }
+
+    #[test]
+    fn test_message_max_count() {
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_class("save").unwrap());
+        harness.click(&harness.find_by_class("fail").unwrap());
+        harness.click(&harness.find_by_class("save").unwrap());
+        harness.click(&harness.find_by_class("fail").unwrap());
+
+        // 超出数量时关闭最早的消息
+        assert_eq!(contents(&harness), ["网络错误", "保存成功", "网络错误"]);
+    }
+}
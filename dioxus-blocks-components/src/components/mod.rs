@@ -3,43 +3,310 @@
 mod view;
 pub use view::View;
 
+#[cfg(feature = "grid")]
 mod grid;
+#[cfg(feature = "grid")]
 pub use grid::{Grid, GridCols, GridItem, GridRows};
 
+#[cfg(feature = "layout")]
 mod layout;
+#[cfg(feature = "layout")]
 pub use layout::{Col, ColSpan, Justify, Row};
 
+#[cfg(feature = "toolbar")]
+mod toolbar;
+#[cfg(feature = "toolbar")]
+pub use toolbar::Toolbar;
+
+#[cfg(feature = "masonry")]
+mod masonry;
+#[cfg(feature = "masonry")]
+pub use masonry::{Masonry, MasonryStrategy};
+
 mod text;
 pub use text::Text;
 
+mod overflow_tooltip;
+pub use overflow_tooltip::OverflowTooltip;
+
+#[cfg(feature = "button")]
 mod button;
+#[cfg(feature = "button")]
 pub use button::{Button, ButtonShape, ButtonSize, ButtonType};
 
+#[cfg(feature = "card")]
 mod card;
+#[cfg(feature = "card")]
 pub use card::{Card, CardShadow};
 
+#[cfg(feature = "link")]
 mod link;
+#[cfg(feature = "link")]
 pub use link::{Link, LinkType, LinkUnderline};
 
+#[cfg(feature = "image")]
 mod image;
+#[cfg(feature = "image")]
 pub use image::{Image, ObjectFit};
 
+#[cfg(feature = "input_number")]
 mod input_number;
+#[cfg(feature = "input_number")]
 pub use input_number::{
     ControlsPosition, InputNumber, InputNumberSize, InputNumberStep, InputNumberValue,
 };
 
+#[cfg(feature = "input")]
 mod input;
+#[cfg(feature = "input")]
 pub use input::{Input, InputSize, InputType};
+#[cfg(feature = "input")]
+mod input_mask;
+#[cfg(feature = "input")]
+pub use input_mask::InputMask;
+
+#[cfg(feature = "credit_card_input")]
+mod credit_card_input;
+#[cfg(feature = "credit_card_input")]
+pub use credit_card_input::{
+    CardBrand, CreditCardFieldState, CreditCardInput, CreditCardValidation, CreditCardValue,
+};
+
+#[cfg(feature = "phone_input")]
+mod phone_input;
+#[cfg(feature = "phone_input")]
+pub use phone_input::{PhoneCountry, PhoneInput, PhoneState, PhoneValue};
+
+#[cfg(feature = "barcode_input")]
+mod barcode_input;
+#[cfg(feature = "camera")]
+pub use barcode_input::ScanError;
+#[cfg(feature = "barcode_input")]
+pub use barcode_input::{BarcodeInput, BarcodeScan};
 
+#[cfg(feature = "address_picker")]
+mod address_picker;
+#[cfg(feature = "address_picker")]
+pub use address_picker::{AddressPicker, AddressValue};
+
+#[cfg(feature = "search_input")]
+mod search_input;
+#[cfg(feature = "search_input")]
+pub use search_input::{SearchInput, SearchQuery};
+
+#[cfg(feature = "filter_bar")]
+mod filter_bar;
+#[cfg(feature = "filter_bar")]
+pub use filter_bar::{FilterBar, FilterField, FilterKind, FilterQuery, FilterValue};
+
+#[cfg(feature = "active_filters")]
+mod active_filters;
+#[cfg(feature = "active_filters")]
+pub use active_filters::ActiveFilters;
+
+#[cfg(feature = "form_error_summary")]
+mod form_error_summary;
+#[cfg(feature = "form_error_summary")]
+pub use form_error_summary::{FormError, FormErrorSummary};
+
+#[cfg(feature = "form_item")]
+mod form_item;
+#[cfg(feature = "form_item")]
+pub use form_item::{
+    Form, FormField, FormItem, FormLabelPosition, FormState, FormValue, Rule, use_form,
+};
+
+#[cfg(feature = "schema_form")]
+mod schema_form;
+#[cfg(feature = "schema_form")]
+pub use schema_form::{
+    FormSchema, SchemaCondition, SchemaField, SchemaFieldType, SchemaForm, SchemaFormValues,
+    SchemaOption, SchemaRule,
+};
+
+#[cfg(feature = "upload")]
+mod upload;
+#[cfg(feature = "upload")]
+pub use upload::{Upload, UploadFile, UploadRejectReason, UploadRejection, UploadStatus};
+
+#[cfg(feature = "select")]
+mod select;
+#[cfg(feature = "select")]
+pub use select::{Select, SelectOption, SelectOptionGroup, SelectSize, SelectValue};
+
+#[cfg(feature = "time_picker")]
+mod time_picker;
+#[cfg(feature = "time_picker")]
+pub use time_picker::{TimeFormat, TimePicker, TimePickerSize, TimeValue};
+
+#[cfg(feature = "date_picker")]
+mod date_picker;
+#[cfg(feature = "date_picker")]
+pub use date_picker::{DateRange, DateRangePicker, DateTimePicker, RangePreset, Timezone};
+
+#[cfg(feature = "textarea")]
 mod textarea;
+#[cfg(feature = "textarea")]
 pub use textarea::{Textarea, TextareaSize};
 
+#[cfg(feature = "radio")]
 mod radio;
+#[cfg(feature = "radio")]
 pub use radio::{Radio, RadioGroup, RadioSize, RadioValue};
 
+#[cfg(feature = "checkbox")]
 mod checkbox;
+#[cfg(feature = "checkbox")]
 pub use checkbox::{Checkbox, CheckboxGroup, CheckboxSize, CheckboxValue};
 
-// mod select;
-// pub use select::{Select, SelectOption, SelectSize, SelectValue};
+#[cfg(feature = "calendar_heatmap")]
+mod calendar_heatmap;
+#[cfg(feature = "calendar_heatmap")]
+pub use calendar_heatmap::CalendarHeatmap;
+
+#[cfg(feature = "org_chart")]
+mod org_chart;
+#[cfg(feature = "org_chart")]
+pub use org_chart::{OrgChart, OrgChartNode};
+
+#[cfg(feature = "form_wizard")]
+mod form_wizard;
+#[cfg(feature = "form_wizard")]
+pub use form_wizard::{FormWizard, FormWizardPayload, FormWizardStatus, FormWizardStep};
+
+#[cfg(feature = "alert")]
+mod alert;
+#[cfg(feature = "alert")]
+pub use alert::{Alert, AlertType};
+
+#[cfg(feature = "result_panel")]
+mod result_panel;
+#[cfg(feature = "result_panel")]
+pub use result_panel::{ResultPanel, ResultStatus};
+
+#[cfg(feature = "route_progress")]
+mod route_progress;
+#[cfg(feature = "route_progress")]
+pub use route_progress::{ProgressGuard, ProgressTracker, RouteProgress};
+
+#[cfg(feature = "connection_status")]
+mod connection_status;
+#[cfg(feature = "connection_status")]
+pub use connection_status::{ConnectionStatus, use_online};
+
+#[cfg(feature = "consent_banner")]
+mod consent_banner;
+#[cfg(feature = "consent_banner")]
+pub use consent_banner::{ConsentBanner, ConsentCategory, use_consent};
+
+#[cfg(feature = "confirm_button")]
+mod confirm_button;
+#[cfg(feature = "confirm_button")]
+pub use confirm_button::ConfirmButton;
+
+#[cfg(feature = "notification")]
+mod notification;
+#[cfg(feature = "notification")]
+pub use notification::{
+    Notification, NotificationItem, NotificationPermission, Notifier, notification_permission,
+    notify, request_notification_permission, use_notification,
+};
+
+#[cfg(feature = "message")]
+pub mod message;
+#[cfg(feature = "message")]
+pub use message::{MessageItem, MessagePlacement, MessageProvider};
+
+#[cfg(feature = "idle_guard")]
+mod idle_guard;
+#[cfg(feature = "idle_guard")]
+pub use idle_guard::{IdleGuard, use_idle};
+
+#[cfg(feature = "dialog")]
+mod dialog;
+#[cfg(feature = "dialog")]
+pub use dialog::Dialog;
+
+#[cfg(feature = "skeleton")]
+mod skeleton;
+#[cfg(feature = "skeleton")]
+pub use skeleton::{Skeleton, SkeletonBoundary};
+
+#[cfg(feature = "spin")]
+mod spin;
+#[cfg(feature = "spin")]
+pub use spin::{Spin, SpinSize};
+
+#[cfg(feature = "config_provider")]
+mod config_provider;
+#[cfg(feature = "config_provider")]
+pub use config_provider::{ConfigProvider, Density, Settings, TableLayout, Theme, use_settings};
+
+#[cfg(feature = "props_table")]
+mod props_table;
+#[cfg(feature = "props_table")]
+pub use props_table::PropsTable;
+
+#[cfg(feature = "json_viewer")]
+mod json_viewer;
+#[cfg(feature = "json_viewer")]
+pub use json_viewer::JsonViewer;
+
+#[cfg(feature = "log_viewer")]
+mod ansi;
+#[cfg(feature = "log_viewer")]
+mod log_viewer;
+#[cfg(feature = "log_viewer")]
+pub use log_viewer::{LogBuffer, LogLevel, LogLine, LogViewer};
+
+#[cfg(feature = "table")]
+mod table;
+#[cfg(feature = "table")]
+pub use table::{
+    Table, TableAlign, TableColumn, TableMenuItem, TableRow, TableSort, TableSortOrder,
+};
+
+#[cfg(feature = "selection_bar")]
+mod selection_bar;
+#[cfg(feature = "selection_bar")]
+pub use selection_bar::SelectionBar;
+
+#[cfg(feature = "crud_page")]
+mod crud_page;
+#[cfg(feature = "crud_page")]
+pub use crud_page::{CrudList, CrudPage, CrudQuery, CrudRecord};
+
+#[cfg(feature = "title_provider")]
+mod title_provider;
+#[cfg(feature = "title_provider")]
+pub use title_provider::{TitleProvider, use_document_title};
+
+#[cfg(feature = "kbd")]
+mod kbd;
+#[cfg(feature = "kbd")]
+pub use kbd::{Kbd, Shortcut, ShortcutHint, use_is_mac};
+
+#[cfg(feature = "tooltip")]
+mod tooltip;
+#[cfg(feature = "tooltip")]
+pub use tooltip::{Tooltip, TooltipPlacement, TooltipTrigger};
+
+#[cfg(feature = "tabs")]
+mod tabs;
+#[cfg(feature = "tabs")]
+pub use tabs::{TabPane, Tabs, TabsPlacement, TabsType};
+
+#[cfg(feature = "dropdown")]
+mod dropdown;
+#[cfg(feature = "dropdown")]
+pub use dropdown::{Dropdown, DropdownItem, DropdownPlacement, DropdownTrigger};
+
+#[cfg(feature = "pagination")]
+mod pagination;
+#[cfg(feature = "pagination")]
+pub use pagination::{Pagination, PaginationLayout};
+
+#[cfg(feature = "popconfirm")]
+mod popconfirm;
+#[cfg(feature = "popconfirm")]
+pub use popconfirm::Popconfirm;
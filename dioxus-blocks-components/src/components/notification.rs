@@ -0,0 +1,864 @@
+//! Notification 通知组件
+//!
+//! 为子组件提供通知能力：业务代码通过 [`use_notification`] 获取 [`Notifier`] 发送通知，
+//! 通知默认以应用内提示的形式显示在页面右上角，超过显示时长后自动关闭。
+//!
+//! 通知的行为由 [`ConfigProvider`](crate::ConfigProvider) 提供的 [`Settings`] 控制：
+//!
+//! - [`Settings::notifications`] 关闭时不显示任何通知；
+//! - 设置 [`Settings::notification_sound`] 后，每条通知到达时播放提示音；
+//! - [`Settings::browser_notifications`] 开启且用户已授权时，通过浏览器的 Web Notifications API
+//!   显示系统通知，未授权、浏览器不支持或服务端渲染时退回到应用内提示。
+//!
+//! 系统通知需要用户主动开启：调用 [`Notifier::enable_browser_notifications`] 请求授权，
+//! 授权成功后自动开启 [`Settings::browser_notifications`]。
+//!
+//! 送达的通知计入 [`Notifier::unread`]，用户查看后调用 [`Notifier::mark_all_read`] 清零，
+//! 配合 [`use_app_badge`](crate::use_app_badge) 可以在标签页图标上显示未读数量。
+//!
+//! 不方便获取 Notifier 的代码（如与组件无关的工具函数）可以调用 [`notify`] 模块的函数，
+//! 发送到最近挂载的 Notification，没有挂载时忽略并输出警告。
+//!
+//! 删除等操作可以使用 [`NotificationItem::with_undo`] 延迟提交：通知中显示撤销按钮，超时前点击撤销时调用
+//! `undo`，否则在超时、手动关闭或被新通知挤出时调用 `action` 完成操作。可撤销的通知不受通知设置影响，
+//! 始终显示为应用内提示。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Notification, ToElement, use_notification};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct SaveButton;
+//!
+//! impl ToElement for SaveButton {
+//!     fn to_element(&self) -> Element {
+//!         let notifier = use_notification();
+//!         Button::new()
+//!             .text("保存")
+//!             .onclick(move |_| notifier.success("保存成功", "文章已发布"))
+//!             .to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Notification::new()
+//!         .duration(3000)
+//!         .children(SaveButton)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 可撤销的删除
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Notification, NotificationItem, ToElement, use_notification};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct DeleteButton;
+//!
+//! impl ToElement for DeleteButton {
+//!     fn to_element(&self) -> Element {
+//!         let notifier = use_notification();
+//!         let mut hidden = use_signal(|| false);
+//!         Button::new()
+//!             .text("删除")
+//!             .onclick(move |_| {
+//!                 // 先在界面上隐藏，超时后再真正删除
+//!                 hidden.set(true);
+//!                 notifier.notify(NotificationItem::new("已删除订单").with_undo(
+//!                     || { /* 调用删除接口 */ },
+//!                     move || hidden.set(false),
+//!                     5000,
+//!                 ));
+//!             })
+//!             .to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| Notification::new().children(DeleteButton).to_element());
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::{AlertType, Settings, Style, classnames, traits::ToElement};
+
+/// 浏览器通知的授权状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum NotificationPermission {
+    /// 用户尚未做出选择
+    #[default]
+    Default,
+    /// 已允许
+    Granted,
+    /// 已拒绝，需要用户在浏览器设置中手动开启
+    Denied,
+    /// 当前环境不支持浏览器通知
+    Unsupported,
+}
+
+impl NotificationPermission {
+    /// 从 `Notification.permission` 的取值转换
+    fn from_js(value: &str) -> Self {
+        match value {
+            "default" => NotificationPermission::Default,
+            "granted" => NotificationPermission::Granted,
+            "denied" => NotificationPermission::Denied,
+            _ => NotificationPermission::Unsupported,
+        }
+    }
+}
+
+/// 读取浏览器通知的授权状态
+pub async fn notification_permission() -> NotificationPermission {
+    if cfg!(feature = "server") {
+        return NotificationPermission::Unsupported;
+    }
+    let permission = document::eval(
+        "return 'Notification' in window ? Notification.permission : 'unsupported';",
+    )
+    .join::<String>()
+    .await;
+    permission
+        .map(|value| NotificationPermission::from_js(&value))
+        .unwrap_or(NotificationPermission::Unsupported)
+}
+
+/// 请求浏览器通知授权，返回用户选择后的授权状态
+///
+/// 浏览器通常只允许在用户操作（如点击按钮）的回调中请求授权。
+pub async fn request_notification_permission() -> NotificationPermission {
+    if cfg!(feature = "server") {
+        return NotificationPermission::Unsupported;
+    }
+    let permission = document::eval(
+        "if (!('Notification' in window)) return 'unsupported'; \
+         return await Notification.requestPermission();",
+    )
+    .join::<String>()
+    .await;
+    permission
+        .map(|value| NotificationPermission::from_js(&value))
+        .unwrap_or(NotificationPermission::Unsupported)
+}
+
+/// 已授权时显示浏览器通知，成功时返回 `true`
+async fn show_browser_notification(title: &str, message: &str, silent: bool) -> bool {
+    if cfg!(feature = "server") {
+        return false;
+    }
+    document::eval(&format!(
+        "if (!('Notification' in window) || Notification.permission !== 'granted') return false; \
+         try {{ new Notification({title:?}, {{ body: {message:?}, silent: {silent} }}); return true; }} \
+         catch (_) {{ return false; }}"
+    ))
+    .join::<bool>()
+    .await
+    .unwrap_or(false)
+}
+
+/// 播放提示音，浏览器阻止自动播放时忽略
+fn play_sound(url: &str) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    let _ = document::eval(&format!("new Audio({url:?}).play().catch(() => {{}});"));
+}
+
+/// 可撤销通知的延迟操作
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct UndoAction {
+    /// 未撤销时完成操作
+    commit: Callback<()>,
+    /// 撤销操作
+    undo: Callback<()>,
+    /// 可以撤销的时长，单位为毫秒
+    timeout: u32,
+}
+
+/// 把只调用一次的闭包包装为回调
+fn once(f: impl FnOnce() + 'static) -> Callback<()> {
+    let mut f = Some(f);
+    Callback::new(move |()| {
+        if let Some(f) = f.take() {
+            f();
+        }
+    })
+}
+
+/// 一条通知
+#[derive(Debug, Clone, PartialEq)]
+pub struct NotificationItem {
+    /// 通知编号，发送时分配
+    id: u64,
+    /// 标题
+    title: String,
+    /// 正文
+    message: String,
+    /// 通知类型
+    kind: AlertType,
+    /// 可撤销的操作
+    undo: Option<UndoAction>,
+}
+
+impl NotificationItem {
+    /// 创建通知
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            id: 0,
+            title: title.into(),
+            message: String::new(),
+            kind: AlertType::Info,
+            undo: None,
+        }
+    }
+
+    /// 设置正文
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// 设置通知类型
+    pub fn kind(mut self, kind: AlertType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// 设置为可撤销的通知
+    ///
+    /// 通知显示 `timeout` 毫秒并带有撤销按钮，期间点击撤销时调用 `undo`；超时、手动关闭或被新通知挤出时
+    /// 调用 `action` 完成操作。两者只会调用其中一个，且只调用一次。
+    pub fn with_undo(
+        mut self,
+        action: impl FnOnce() + 'static,
+        undo: impl FnOnce() + 'static,
+        timeout: u32,
+    ) -> Self {
+        self.undo = Some(UndoAction {
+            commit: once(action),
+            undo: once(undo),
+            timeout: timeout.max(1),
+        });
+        self
+    }
+
+    /// 通知编号
+    pub fn id(&self) -> u64 {
+        self.id
+    }
+
+    /// 标题
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// 类型对应的类名修饰符
+    fn kind_class(&self) -> &'static str {
+        match self.kind {
+            AlertType::Success => "success",
+            AlertType::Info => "info",
+            AlertType::Warning => "warning",
+            AlertType::Error => "error",
+        }
+    }
+}
+
+/// 通知发送器，通过 [`use_notification`] 获取
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Notifier {
+    items: Signal<Vec<NotificationItem>>,
+    next_id: Signal<u64>,
+    /// 未读通知数量
+    unread: Signal<usize>,
+    settings: Signal<Settings>,
+    duration: u32,
+    max_count: usize,
+}
+
+impl Notifier {
+    /// 发送通知
+    pub fn notify(&self, item: NotificationItem) {
+        // 可撤销的通知是操作的一部分，不受通知设置影响，并且始终显示为应用内提示以便点击撤销
+        if item.undo.is_some() {
+            self.show(item);
+            return;
+        }
+        let settings = self.settings.peek().clone();
+        if !settings.notifications {
+            return;
+        }
+        let mut unread = self.unread;
+        unread += 1;
+        if let Some(url) = &settings.notification_sound {
+            play_sound(url);
+        }
+        if !settings.browser_notifications {
+            self.show(item);
+            return;
+        }
+        let notifier = *self;
+        let silent = settings.notification_sound.is_some();
+        spawn(async move {
+            if !show_browser_notification(&item.title, &item.message, silent).await {
+                notifier.show(item);
+            }
+        });
+    }
+
+    /// 发送信息通知
+    pub fn info(&self, title: impl Into<String>, message: impl Into<String>) {
+        self.notify(NotificationItem::new(title).message(message));
+    }
+
+    /// 发送成功通知
+    pub fn success(&self, title: impl Into<String>, message: impl Into<String>) {
+        self.notify(
+            NotificationItem::new(title)
+                .message(message)
+                .kind(AlertType::Success),
+        );
+    }
+
+    /// 发送警告通知
+    pub fn warning(&self, title: impl Into<String>, message: impl Into<String>) {
+        self.notify(
+            NotificationItem::new(title)
+                .message(message)
+                .kind(AlertType::Warning),
+        );
+    }
+
+    /// 发送错误通知
+    pub fn error(&self, title: impl Into<String>, message: impl Into<String>) {
+        self.notify(
+            NotificationItem::new(title)
+                .message(message)
+                .kind(AlertType::Error),
+        );
+    }
+
+    /// 关闭应用内通知，可撤销的通知关闭后立即完成操作
+    pub fn close(&self, id: u64) {
+        self.finish(id, false);
+    }
+
+    /// 撤销可撤销通知的操作并关闭通知
+    pub fn undo(&self, id: u64) {
+        self.finish(id, true);
+    }
+
+    /// 关闭全部应用内通知，可撤销的通知立即完成操作
+    pub fn clear(&self) {
+        let mut items = self.items;
+        let removed = std::mem::take(&mut *items.write());
+        for item in removed {
+            Self::settle(&item, false);
+        }
+    }
+
+    /// 当前显示的应用内通知
+    pub fn items(&self) -> Vec<NotificationItem> {
+        self.items.read().clone()
+    }
+
+    /// 未读通知数量，每条送达的通知（包括已自动关闭的）计为一条，可撤销的通知不计入
+    ///
+    /// 可以配合 [`use_app_badge`](crate::use_app_badge) 在标签页图标上显示。
+    pub fn unread(&self) -> usize {
+        (self.unread)()
+    }
+
+    /// 将全部通知标记为已读
+    pub fn mark_all_read(&self) {
+        let mut unread = self.unread;
+        unread.set(0);
+    }
+
+    /// 请求浏览器通知授权，授权成功后开启 [`Settings::browser_notifications`]
+    pub async fn enable_browser_notifications(&self) -> NotificationPermission {
+        let permission = request_notification_permission().await;
+        let mut settings = self.settings;
+        settings.write().browser_notifications = permission == NotificationPermission::Granted;
+        permission
+    }
+
+    /// 关闭浏览器通知，之后的通知显示为应用内提示
+    pub fn disable_browser_notifications(&self) {
+        let mut settings = self.settings;
+        settings.write().browser_notifications = false;
+    }
+
+    /// 移除通知，可撤销的通知按 `undo` 撤销或完成操作，已移除的通知不做处理
+    fn finish(&self, id: u64, undo: bool) {
+        let mut items = self.items;
+        let Some(index) = items.peek().iter().position(|item| item.id == id) else {
+            return;
+        };
+        let item = items.write().remove(index);
+        Self::settle(&item, undo);
+    }
+
+    /// 撤销或完成通知的操作
+    fn settle(item: &NotificationItem, undo: bool) {
+        if let Some(action) = item.undo {
+            if undo {
+                action.undo.call(());
+            } else {
+                action.commit.call(());
+            }
+        }
+    }
+
+    /// 显示应用内通知，超过显示时长后自动关闭
+    fn show(&self, mut item: NotificationItem) {
+        let (mut items, mut next_id) = (self.items, self.next_id);
+        let id = *next_id.peek();
+        next_id.set(id + 1);
+        item.id = id;
+        let duration = item.undo.map_or(self.duration, |action| action.timeout);
+
+        let removed = {
+            let mut items = items.write();
+            items.push(item);
+            let overflow = items.len().saturating_sub(self.max_count);
+            items.drain(..overflow).collect::<Vec<_>>()
+        };
+        for item in removed {
+            Self::settle(&item, false);
+        }
+
+        if duration > 0 {
+            let notifier = *self;
+            spawn(async move {
+                if sleep(duration).await {
+                    notifier.close(id);
+                }
+            });
+        }
+    }
+}
+
+/// 最近挂载的 Notification 的通知发送器，供 [`notify`] 模块使用
+static MOUNTED: GlobalSignal<Option<Notifier>> = Signal::global(|| None);
+
+/// 最近挂载的 Notification 的通知发送器，没有挂载时输出警告
+fn mounted_notifier() -> Option<Notifier> {
+    let notifier = *MOUNTED.peek();
+    if notifier.is_none() {
+        dioxus::logger::tracing::warn!("没有挂载 Notification，忽略通知");
+    }
+    notifier
+}
+
+/// 不需要获取 [`Notifier`] 的通知函数
+///
+/// 通知发送到最近挂载的 [`Notification`]，需要在组件或事件处理器中调用。
+pub mod notify {
+    use super::{NotificationItem, mounted_notifier as notifier};
+
+    /// 发送通知
+    pub fn send(item: NotificationItem) {
+        if let Some(notifier) = notifier() {
+            notifier.notify(item);
+        }
+    }
+
+    /// 发送信息通知
+    pub fn info(title: impl Into<String>, message: impl Into<String>) {
+        if let Some(notifier) = notifier() {
+            notifier.info(title, message);
+        }
+    }
+
+    /// 发送成功通知
+    pub fn success(title: impl Into<String>, message: impl Into<String>) {
+        if let Some(notifier) = notifier() {
+            notifier.success(title, message);
+        }
+    }
+
+    /// 发送警告通知
+    pub fn warning(title: impl Into<String>, message: impl Into<String>) {
+        if let Some(notifier) = notifier() {
+            notifier.warning(title, message);
+        }
+    }
+
+    /// 发送错误通知
+    pub fn error(title: impl Into<String>, message: impl Into<String>) {
+        if let Some(notifier) = notifier() {
+            notifier.error(title, message);
+        }
+    }
+}
+
+/// 获取最近的 [`Notification`] 提供的通知发送器
+///
+/// 必须在 Notification 的子组件中调用，否则会 panic。
+pub fn use_notification() -> Notifier {
+    use_context::<Notifier>()
+}
+
+/// Notification 通知组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Notification {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 显示时长，单位为毫秒，为 0 时不自动关闭
+    duration: u32,
+    /// 最多同时显示的通知数量
+    max_count: usize,
+}
+
+impl Default for Notification {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::NOTIFICATION),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            duration: 4500,
+            max_count: 5,
+        }
+    }
+}
+
+#[builder_props]
+impl Notification {
+    /// 创建通知组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置应用内通知的显示时长，单位为毫秒，为 0 时不自动关闭
+    #[prop(default = "4500")]
+    pub fn duration(mut self, duration: u32) -> Self {
+        self.duration = duration;
+        self
+    }
+
+    /// 设置最多同时显示的通知数量，超出时关闭最早的通知
+    #[prop(default = "5")]
+    pub fn max_count(mut self, max_count: usize) -> Self {
+        self.max_count = max_count.max(1);
+        self
+    }
+}
+
+impl ToElement for Notification {
+    fn to_element(&self) -> Element {
+        let local_settings = use_signal(Settings::default);
+        let settings = try_consume_context::<Signal<Settings>>().unwrap_or(local_settings);
+        let (duration, max_count) = (self.duration, self.max_count);
+        let notifier = use_context_provider(move || Notifier {
+            items: Signal::new(Vec::new()),
+            next_id: Signal::new(0),
+            unread: Signal::new(0),
+            settings,
+            duration,
+            max_count,
+        });
+        use_hook(move || *MOUNTED.write() = Some(notifier));
+        use_drop(move || {
+            if *MOUNTED.peek() == Some(notifier) {
+                *MOUNTED.write() = None;
+            }
+        });
+        let items = notifier.items.read().clone();
+
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        rsx! {
+            if !items.is_empty() {
+                div {
+                    id,
+                    class,
+                    style,
+                    onclick: move |event: MouseEvent| {
+                        if let Some(handler) = onclick_handler {
+                            handler.call(event);
+                        }
+                    },
+                    for item in items {
+                        div {
+                            key: "{item.id}",
+                            class: "{classnames::NOTIFICATION_ITEM} {classnames::NOTIFICATION_ITEM}--{item.kind_class()}",
+                            role: if item.kind == AlertType::Error { "alert" } else { "status" },
+                            div { class: classnames::NOTIFICATION_CONTENT,
+                                div { class: classnames::NOTIFICATION_TITLE, "{item.title}" }
+                                if !item.message.is_empty() {
+                                    div { class: classnames::NOTIFICATION_MESSAGE, "{item.message}" }
+                                }
+                            }
+                            if item.undo.is_some() {
+                                button {
+                                    class: classnames::NOTIFICATION_UNDO,
+                                    r#type: "button",
+                                    onclick: move |_| notifier.undo(item.id),
+                                    "撤销"
+                                }
+                            }
+                            button {
+                                class: classnames::NOTIFICATION_CLOSE,
+                                r#type: "button",
+                                aria_label: "关闭",
+                                onclick: move |_| notifier.close(item.id),
+                                "×"
+                            }
+                        }
+                    }
+                }
+            }
+            {self.childrens_to_element()}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{ConfigProvider, Text, View};
+
+    /// 点击文本发送通知
+    #[derive(Debug, Clone)]
+    struct Sender;
+
+    impl ToElement for Sender {
+        fn to_element(&self) -> Element {
+            let notifier = use_notification();
+            let mut settings = use_context::<Signal<Settings>>();
+            View::new()
+                .children(Text::span("发送").onclick(move |_| {
+                    notifier.error("同步失败", "请检查网络连接");
+                }))
+                .children(Text::span("静音").onclick(move |_| {
+                    settings.write().notifications = false;
+                }))
+                .children(Text::span("系统通知").onclick(move |_| {
+                    settings.write().browser_notifications = true;
+                }))
+                .to_element()
+        }
+    }
+
+    #[test]
+    fn test_notify_and_close() {
+        fn app() -> Element {
+            ConfigProvider::new()
+                .children(Notification::new().children(Sender))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::NOTIFICATION).is_none());
+
+        harness.click(&harness.find_by_text("发送").unwrap());
+        let item = harness
+            .find_by_class(classnames::NOTIFICATION_ITEM)
+            .unwrap();
+        assert!(item.has_class("t-notification__item--error"));
+        assert_eq!(item.attr("role"), Some("alert"));
+        assert!(harness.find_by_text("请检查网络连接").is_some());
+
+        harness.click(
+            &harness
+                .find_by_class(classnames::NOTIFICATION_CLOSE)
+                .unwrap(),
+        );
+        assert!(harness.find_by_class(classnames::NOTIFICATION).is_none());
+
+        // 关闭通知后不再显示
+        harness.click(&harness.find_by_text("静音").unwrap());
+        harness.click(&harness.find_by_text("发送").unwrap());
+        assert!(harness.find_by_class(classnames::NOTIFICATION).is_none());
+    }
+
+    #[test]
+    fn test_notify_functions() {
+        fn app() -> Element {
+            rsx! {
+                {Notification::new().to_element()}
+                button {
+                    class: "send",
+                    onclick: move |_| notify::warning("存储空间不足", "剩余 5%"),
+                }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_class("send").unwrap());
+        let item = harness
+            .find_by_class(classnames::NOTIFICATION_ITEM)
+            .unwrap();
+        assert!(item.has_class("t-notification__item--warning"));
+        assert!(harness.find_by_text("剩余 5%").is_some());
+    }
+
+    #[test]
+    fn test_unread_count() {
+        #[derive(Debug, Clone)]
+        struct Unread;
+
+        impl ToElement for Unread {
+            fn to_element(&self) -> Element {
+                let notifier = use_notification();
+                View::new()
+                    .children(Text::span(format!("未读 {}", notifier.unread())))
+                    .children(Text::span("已读").onclick(move |_| notifier.mark_all_read()))
+                    .to_element()
+            }
+        }
+
+        fn app() -> Element {
+            ConfigProvider::new()
+                .children(Notification::new().children(Sender).children(Unread))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("发送").unwrap());
+        harness.click(&harness.find_by_text("发送").unwrap());
+        assert!(harness.find_by_text("未读 2").is_some());
+
+        // 关闭通知不影响未读数量
+        harness.click(
+            &harness
+                .find_by_class(classnames::NOTIFICATION_CLOSE)
+                .unwrap(),
+        );
+        assert!(harness.find_by_text("未读 2").is_some());
+
+        harness.click(&harness.find_by_text("已读").unwrap());
+        assert!(harness.find_by_text("未读 0").is_some());
+
+        // 关闭通知后不计入未读
+        harness.click(&harness.find_by_text("静音").unwrap());
+        harness.click(&harness.find_by_text("发送").unwrap());
+        assert!(harness.find_by_text("未读 0").is_some());
+    }
+
+    #[test]
+    fn test_browser_notification_falls_back_to_toast() {
+        fn app() -> Element {
+            ConfigProvider::new()
+                .children(Notification::new().children(Sender))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("系统通知").unwrap());
+        harness.click(&harness.find_by_text("发送").unwrap());
+        harness.flush();
+        assert!(harness.find_by_text("同步失败").is_some());
+    }
+
+    #[test]
+    fn test_max_count() {
+        fn app() -> Element {
+            let notifier = use_context_provider(|| Notifier {
+                items: Signal::new(Vec::new()),
+                next_id: Signal::new(0),
+                unread: Signal::new(0),
+                settings: Signal::new(Settings::default()),
+                duration: 0,
+                max_count: 2,
+            });
+            use_hook(|| {
+                for i in 0..3 {
+                    notifier.info(format!("通知 {i}"), "");
+                }
+            });
+            rsx! {
+                for item in notifier.items() {
+                    span { "{item.title()}" }
+                }
+            }
+        }
+
+        let harness = Harness::new(app);
+        assert!(harness.find_by_text("通知 0").is_none());
+        assert!(harness.find_by_text("通知 1").is_some());
+        assert!(harness.find_by_text("通知 2").is_some());
+    }
+
+    /// 点击文本删除，可以撤销
+    #[derive(Debug, Clone)]
+    struct Deleter;
+
+    impl ToElement for Deleter {
+        fn to_element(&self) -> Element {
+            let notifier = use_notification();
+            let mut settings = use_context::<Signal<Settings>>();
+            let mut log = use_signal(Vec::<&'static str>::new);
+            View::new()
+                .children(Text::span("删除").onclick(move |_| {
+                    notifier.notify(NotificationItem::new("已删除").with_undo(
+                        move || log.write().push("commit"),
+                        move || log.write().push("undo"),
+                        5000,
+                    ));
+                }))
+                .children(Text::span("静音").onclick(move |_| {
+                    settings.write().notifications = false;
+                }))
+                .children(Text::span(log.read().join(",")).class("log"))
+                .to_element()
+        }
+    }
+
+    #[test]
+    fn test_with_undo() {
+        fn app() -> Element {
+            ConfigProvider::new()
+                .children(Notification::new().children(Deleter))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        harness.click(&harness.find_by_text("删除").unwrap());
+        harness.click(&harness.find_by_text("撤销").unwrap());
+        assert!(harness.find_by_class(classnames::NOTIFICATION).is_none());
+        assert!(harness.find_by_text("undo").is_some());
+
+        // 手动关闭时立即完成操作，不受通知设置影响
+        harness.click(&harness.find_by_text("静音").unwrap());
+        harness.click(&harness.find_by_text("删除").unwrap());
+        harness.click(
+            &harness
+                .find_by_class(classnames::NOTIFICATION_CLOSE)
+                .unwrap(),
+        );
+        assert!(harness.find_by_text("undo,commit").is_some());
+    }
+}
@@ -0,0 +1,341 @@
+//! OrgChart 组织结构图组件
+//!
+//! 提供自上而下的树形层级结构图组件，节点之间使用连接线相连，支持折叠/展开分支，
+//! 以及通过闭包自定义节点的渲染内容，适用于团队架构、分类体系等页面。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{OrgChart, OrgChartNode, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     OrgChart::new(
+//!         OrgChartNode::new("ceo", "CEO")
+//!             .child(OrgChartNode::new("cto", "CTO").description("技术部"))
+//!             .child(OrgChartNode::new("cfo", "CFO").description("财务部")),
+//!     )
+//!     .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 自定义节点
+//!
+//! ```rust
+//! use std::rc::Rc;
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{OrgChart, OrgChartNode, Text, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     OrgChart::new(OrgChartNode::new("root", "Root").child(OrgChartNode::new("a", "A")))
+//!         .render_node(|node: OrgChartNode| Rc::new(Text::new(format!("★ {}", node.label()))))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 组织结构图节点
+///
+/// 每个节点包含唯一的 key、显示标签、可选的描述和子节点列表。
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct OrgChartNode {
+    /// 节点唯一标识
+    key: String,
+    /// 节点标签
+    label: String,
+    /// 节点描述
+    description: Option<String>,
+    /// 子节点列表
+    children: Vec<OrgChartNode>,
+}
+
+#[builder_props]
+impl OrgChartNode {
+    /// 创建一个新的节点
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置节点描述
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 添加子节点
+    pub fn child(mut self, child: OrgChartNode) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// 批量添加子节点
+    pub fn children(mut self, children: Vec<OrgChartNode>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// 获取节点唯一标识
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 获取节点标签
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 获取节点描述
+    pub fn get_description(&self) -> Option<&str> {
+        self.description.as_deref()
+    }
+
+    /// 获取子节点列表
+    pub fn get_children(&self) -> &[OrgChartNode] {
+        &self.children
+    }
+
+    /// 是否为叶子节点
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty()
+    }
+
+    /// 统计所有后代节点数量
+    pub fn descendant_count(&self) -> usize {
+        self.children
+            .iter()
+            .map(|child| 1 + child.descendant_count())
+            .sum()
+    }
+}
+
+/// OrgChart 组织结构图组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct OrgChart {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 根节点列表
+    roots: Vec<OrgChartNode>,
+    /// 是否允许折叠分支
+    collapsible: bool,
+    /// 默认折叠的节点 key 列表
+    default_collapsed: Vec<String>,
+    /// 自定义节点渲染
+    render_node: Option<Callback<OrgChartNode, Rc<dyn ToElement>>>,
+    /// 节点点击事件
+    onnode_click: Option<EventHandler<OrgChartNode>>,
+}
+
+impl Default for OrgChart {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::ORG_CHART),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            roots: Vec::new(),
+            collapsible: true,
+            default_collapsed: Vec::new(),
+            render_node: None,
+            onnode_click: None,
+        }
+    }
+}
+
+#[builder_props]
+impl OrgChart {
+    /// 使用根节点创建组织结构图
+    pub fn new(root: OrgChartNode) -> Self {
+        Self {
+            roots: vec![root],
+            ..Default::default()
+        }
+    }
+
+    /// 添加一个根节点（支持多棵树并排显示）
+    pub fn root(mut self, root: OrgChartNode) -> Self {
+        self.roots.push(root);
+        self
+    }
+
+    /// 设置是否允许折叠分支
+    #[prop(default = "true")]
+    pub fn collapsible(mut self, collapsible: bool) -> Self {
+        self.collapsible = collapsible;
+        self
+    }
+
+    /// 设置默认折叠的节点
+    pub fn default_collapsed<T: Into<String>>(mut self, keys: Vec<T>) -> Self {
+        self.default_collapsed = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 设置自定义节点渲染闭包
+    pub fn render_node(
+        mut self,
+        f: impl FnMut(OrgChartNode) -> Rc<dyn ToElement> + 'static,
+    ) -> Self {
+        self.render_node = Some(Callback::new(f));
+        self
+    }
+
+    /// 设置节点点击事件
+    pub fn onnode_click(mut self, handler: impl FnMut(OrgChartNode) + 'static) -> Self {
+        self.onnode_click = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置节点点击事件
+    #[prop(skip)]
+    pub fn onnode_click2(mut self, handler: EventHandler<OrgChartNode>) -> Self {
+        self.onnode_click = Some(handler);
+        self
+    }
+}
+
+/// 节点渲染时共享的上下文
+#[derive(Clone, Copy)]
+struct NodeContext {
+    collapsed: Signal<HashSet<String>>,
+    collapsible: bool,
+    render_node: Option<Callback<OrgChartNode, Rc<dyn ToElement>>>,
+    onnode_click: Option<EventHandler<OrgChartNode>>,
+}
+
+/// 递归渲染单个节点及其子树
+fn render_tree(node: &OrgChartNode, ctx: NodeContext) -> Element {
+    let mut collapsed = ctx.collapsed;
+    let is_collapsed = collapsed.read().contains(&node.key);
+    let has_children = !node.children.is_empty();
+    let key = node.key.clone();
+    let node_for_click = node.clone();
+    let onnode_click = ctx.onnode_click;
+
+    let content = match ctx.render_node {
+        Some(render) => render.call(node.clone()).to_element(),
+        None => rsx! {
+            div { class: classnames::ORG_CHART_LABEL, "{node.label}" }
+            if let Some(description) = &node.description {
+                div { class: classnames::ORG_CHART_DESCRIPTION, "{description}" }
+            }
+        },
+    };
+
+    let mut node_class = vec![classnames::ORG_CHART_NODE];
+    if ctx.render_node.is_none() {
+        node_class.push(classnames::ORG_CHART_NODE_DEFAULT);
+    }
+    if onnode_click.is_some() {
+        node_class.push("is-clickable");
+    }
+    let node_class = node_class.join(" ");
+
+    rsx! {
+        li { key: "{node.key}", class: classnames::ORG_CHART_ITEM,
+            div {
+                class: node_class,
+                "data-key": "{node.key}",
+                onclick: move |event: MouseEvent| {
+                    event.stop_propagation();
+                    if let Some(handler) = onnode_click {
+                        handler.call(node_for_click.clone());
+                    }
+                },
+                {content}
+                if ctx.collapsible && has_children {
+                    span {
+                        class: if is_collapsed { format!("{} is-collapsed", classnames::ORG_CHART_TOGGLE) } else { classnames::ORG_CHART_TOGGLE.to_string() },
+                        title: if is_collapsed { "展开" } else { "折叠" },
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            let mut set = collapsed.write();
+                            if !set.remove(&key) {
+                                set.insert(key.clone());
+                            }
+                        },
+                        if is_collapsed {
+                            "{node.descendant_count()}"
+                        } else {
+                            "−"
+                        }
+                    }
+                }
+            }
+            if has_children && !is_collapsed {
+                ul { class: classnames::ORG_CHART_CHILDREN,
+                    for child in node.children.iter() {
+                        {render_tree(child, ctx)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+impl ToElement for OrgChart {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let default_collapsed = self.default_collapsed.clone();
+        let collapsed = use_signal(|| default_collapsed.into_iter().collect::<HashSet<_>>());
+        let ctx = NodeContext {
+            collapsed,
+            collapsible: self.collapsible,
+            render_node: self.render_node,
+            onnode_click: self.onnode_click,
+        };
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                ul { class: classnames::ORG_CHART_TREE,
+                    for root in self.roots.iter() {
+                        {render_tree(root, ctx)}
+                    }
+                }
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
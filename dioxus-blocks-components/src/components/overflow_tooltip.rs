@@ -0,0 +1,262 @@
+//! OverflowTooltip 溢出提示组件
+//!
+//! 按指定行数截断文本并显示省略号，鼠标移入时测量内容是否真的被截断，只有被截断时才在上方显示完整文本，
+//! 内容较短时不会出现多余的提示。[`Text::ellipsis`](crate::Text::ellipsis) 使用该组件实现，
+//! 也可以直接放在表格单元格等宽度受限的位置。
+//!
+//! 提示通过 `Tooltip` 显示，与其他浮层组件使用相同的定位和翻转规则。未启用 `tooltip` 特性时只截断文本，不显示提示。
+//!
+//! 组件以独立的组件作用域渲染，可以在条件分支中使用。无法测量元素尺寸的渲染环境（如服务端渲染）中不显示提示。
+//! 样式包含在 [`Component::Text`](crate::css::Component::Text) 中。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{OverflowTooltip, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     View::new()
+//!         .style(|s| s.width("160px"))
+//!         .children(OverflowTooltip::new("一段可能超出容器宽度的很长很长的文本"))
+//!         .children(OverflowTooltip::new("最多显示两行的多行文本").lines(2))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 溢出提示组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct OverflowTooltip {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在文本之后，同样参与截断
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 文本内容，同时作为提示内容
+    content: String,
+    /// 最多显示的行数
+    lines: usize,
+}
+
+impl Default for OverflowTooltip {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::OVERFLOW_TOOLTIP),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            content: String::new(),
+            lines: 1,
+        }
+    }
+}
+
+#[builder_props]
+impl OverflowTooltip {
+    /// 创建溢出提示，默认单行截断
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置最多显示的行数，最小为 1
+    #[prop(default = "1")]
+    pub fn lines(mut self, lines: usize) -> Self {
+        self.lines = lines.max(1);
+        self
+    }
+}
+
+/// 内容尺寸是否超出可见区域，允许半像素的舍入误差
+fn is_truncated(scroll: (f64, f64), client: (f64, f64)) -> bool {
+    scroll.0 > client.0 + 0.5 || scroll.1 > client.1 + 0.5
+}
+
+impl ToElement for OverflowTooltip {
+    fn to_element(&self) -> Element {
+        rsx! {
+            OverflowTooltipElement { tooltip: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct OverflowTooltipElementProps {
+    tooltip: Rc<OverflowTooltip>,
+}
+
+impl PartialEq for OverflowTooltipElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.tooltip, &other.tooltip)
+    }
+}
+
+#[allow(non_snake_case)]
+fn OverflowTooltipElement(props: OverflowTooltipElementProps) -> Element {
+    let tooltip = props.tooltip;
+    let mounted = use_signal(|| None::<Rc<MountedData>>);
+    let truncated = use_signal(|| false);
+
+    let (class, style) = if tooltip.lines > 1 {
+        (
+            format!(
+                "{} {}",
+                classnames::OVERFLOW_TOOLTIP_CONTENT,
+                classnames::OVERFLOW_TOOLTIP_CONTENT_CLAMP
+            ),
+            Some(format!("-webkit-line-clamp: {};", tooltip.lines)),
+        )
+    } else {
+        (classnames::OVERFLOW_TOOLTIP_CONTENT.to_string(), None)
+    };
+    let content = OverflowContent {
+        class,
+        style,
+        content: tooltip.content.clone(),
+        childrens: tooltip.childrens.clone(),
+        mounted,
+        truncated,
+    };
+    let onclick_handler = tooltip.onclick;
+
+    #[cfg(feature = "tooltip")]
+    {
+        // 只有内容被截断时才允许显示提示
+        let mut popup = crate::Tooltip::new(tooltip.content.clone())
+            .class(tooltip.class.to_string())
+            .disabled(!truncated())
+            .onclick(move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            })
+            .children(content);
+        if let Some(id) = tooltip.id.clone() {
+            popup = popup.id(id);
+        }
+        if let Some(style) = tooltip.style.clone() {
+            popup = popup.style(move |_| style);
+        }
+        popup.to_element()
+    }
+
+    #[cfg(not(feature = "tooltip"))]
+    {
+        let id = tooltip.id.clone();
+        let class = tooltip.class.to_string();
+        let style = tooltip.style.clone().map(|s| s.to_string());
+        rsx! {
+            span {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                {content.to_element()}
+            }
+        }
+    }
+}
+
+/// 被截断的内容，鼠标移入时测量是否被截断
+#[derive(Debug, Clone)]
+struct OverflowContent {
+    class: String,
+    style: Option<String>,
+    content: String,
+    childrens: Vec<Rc<dyn ToElement>>,
+    mounted: Signal<Option<Rc<MountedData>>>,
+    truncated: Signal<bool>,
+}
+
+impl ToElement for OverflowContent {
+    fn to_element(&self) -> Element {
+        let mut mounted = self.mounted;
+        let mut truncated = self.truncated;
+
+        // 每次移入时重新测量，容器宽度或内容变化后结果仍然准确
+        let onmouseenter = move |_| async move {
+            let Some(element) = mounted.peek().clone() else {
+                return;
+            };
+            let (Ok(scroll), Ok(rect)) = (
+                element.get_scroll_size().await,
+                element.get_client_rect().await,
+            ) else {
+                return;
+            };
+            truncated.set(is_truncated(
+                (scroll.width, scroll.height),
+                (rect.size.width, rect.size.height),
+            ));
+        };
+
+        rsx! {
+            span {
+                class: self.class.clone(),
+                style: self.style.clone(),
+                onmounted: move |event: MountedEvent| mounted.set(Some(event.data())),
+                onmouseenter,
+                "{self.content}"
+                for children in self.childrens.iter() {
+                    {children.to_element()}
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_is_truncated() {
+        assert!(!is_truncated((120.0, 20.0), (120.0, 20.0)));
+        assert!(!is_truncated((120.4, 20.0), (120.0, 20.0)));
+        assert!(is_truncated((180.0, 20.0), (120.0, 20.0)));
+        assert!(is_truncated((120.0, 60.0), (120.0, 40.0)));
+    }
+
+    #[test]
+    fn test_overflow_tooltip_without_measurement() {
+        let mut harness = Harness::with_element(OverflowTooltip::new("很长的文本").lines(2));
+        let content = harness
+            .find_by_class(classnames::OVERFLOW_TOOLTIP_CONTENT_CLAMP)
+            .unwrap();
+        assert_eq!(content.attr("style"), Some("-webkit-line-clamp: 2;"));
+
+        // 无法测量尺寸时不显示提示
+        let root = harness.find_by_class(classnames::OVERFLOW_TOOLTIP).unwrap();
+        #[cfg(feature = "tooltip")]
+        assert!(root.has_class(classnames::TOOLTIP));
+        harness.mouse(&root, "mouseenter");
+        harness.mouse(&content, "mouseenter");
+        assert!(harness.find_by_class(classnames::TOOLTIP_POPUP).is_none());
+    }
+}
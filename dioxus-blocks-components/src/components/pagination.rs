@@ -0,0 +1,671 @@
+//! Pagination 分页组件
+//!
+//! 根据记录总数和每页的记录数计算页数，切换页码或每页的记录数时调用 [`Pagination::onchange`]，
+//! 参数为新的页码和每页的记录数，调用方据此重新查询数据。
+//!
+//! 当前页码和每页的记录数可以通过 [`Pagination::current`] 和 [`Pagination::page_size`] 传入的
+//! `Signal<usize>` 控制，未传入时由组件内部保存，初始为第 1 页和 [`Pagination::page_sizes`] 的第一项。
+//!
+//! 提供两种布局：
+//!
+//! - [`PaginationLayout::Full`]：总数、每页记录数选择、上一页、页码列表、下一页和跳转输入框，
+//!   页数较多时页码列表以省略号折叠，点击省略号向前或向后跳过 5 页
+//! - [`PaginationLayout::Compact`]：上一页、「当前页 / 总页数」和下一页，适合窄屏和表格底部
+//!
+//! 修改每页的记录数时保持当前页的第一条记录可见，页码换算为包含该记录的页。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Pagination, PaginationLayout, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let current = use_signal(|| 1);
+//!     let page_size = use_signal(|| 20);
+//!
+//!     Pagination::new()
+//!         .total(326)
+//!         .current(current)
+//!         .page_size(page_size)
+//!         .page_sizes(vec![20, 50, 100])
+//!         .layout(PaginationLayout::Full)
+//!         .onchange(|page, page_size| println!("第 {page} 页，每页 {page_size} 条"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 点击省略号时跳过的页数
+const JUMP_PAGES: usize = 5;
+
+/// 分页的布局
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PaginationLayout {
+    /// 总数、每页记录数、页码列表和跳转输入框
+    #[default]
+    Full,
+    /// 上一页、当前页和总页数、下一页
+    Compact,
+}
+
+impl PaginationLayout {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            PaginationLayout::Full => "t-pagination--full",
+            PaginationLayout::Compact => "t-pagination--compact",
+        }
+    }
+}
+
+/// 页码列表中的一项
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum PagerItem {
+    /// 页码
+    Page(usize),
+    /// 当前页之前折叠的页码
+    PrevMore,
+    /// 当前页之后折叠的页码
+    NextMore,
+}
+
+impl PagerItem {
+    /// 渲染列表时使用的 key
+    fn key(&self) -> String {
+        match self {
+            Self::Page(number) => number.to_string(),
+            Self::PrevMore => "prev-more".to_string(),
+            Self::NextMore => "next-more".to_string(),
+        }
+    }
+}
+
+/// 计算页码列表，最多显示 `count` 个页码（不少于 5 个），首页和末页始终显示，其余按当前页居中
+fn pager_items(current: usize, pages: usize, count: usize) -> Vec<PagerItem> {
+    let count = count.max(5);
+    if pages <= count {
+        return (1..=pages).map(PagerItem::Page).collect();
+    }
+    let half = (count - 1) / 2;
+    let prev_more = current > count - half;
+    let next_more = current + half < pages;
+
+    let (start, end) = match (prev_more, next_more) {
+        (false, _) => (2, count - 1),
+        (true, false) => (pages - count + 2, pages - 1),
+        (true, true) => {
+            let start = current - (count - 3) / 2;
+            (start, start + count - 3)
+        }
+    };
+    let mut items = vec![PagerItem::Page(1)];
+    if prev_more {
+        items.push(PagerItem::PrevMore);
+    }
+    items.extend((start..=end).map(PagerItem::Page));
+    if next_more {
+        items.push(PagerItem::NextMore);
+    }
+    items.push(PagerItem::Page(pages));
+    items
+}
+
+/// 总页数，没有记录时为 1
+fn page_count(total: usize, page_size: usize) -> usize {
+    total.div_ceil(page_size.max(1)).max(1)
+}
+
+/// 分页组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Pagination {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在总数之后
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 记录总数
+    total: usize,
+    /// 当前页码，未设置时在组件内保存
+    current: Option<Signal<usize>>,
+    /// 每页的记录数，未设置时在组件内保存
+    page_size: Option<Signal<usize>>,
+    /// 可选的每页记录数
+    page_sizes: Vec<usize>,
+    /// 布局
+    layout: PaginationLayout,
+    /// 页码列表最多显示的页码数
+    pager_count: usize,
+    /// 是否显示每页记录数选择
+    show_sizes: bool,
+    /// 是否显示跳转输入框
+    show_jumper: bool,
+    /// 只有一页时是否隐藏
+    hide_on_single_page: bool,
+    /// 是否禁用
+    disabled: bool,
+    /// 切换页码或每页记录数时的回调
+    onchange: Option<EventHandler<(usize, usize)>>,
+}
+
+impl Default for Pagination {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::PAGINATION),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            total: 0,
+            current: None,
+            page_size: None,
+            page_sizes: vec![10, 20, 50, 100],
+            layout: PaginationLayout::default(),
+            pager_count: 7,
+            show_sizes: true,
+            show_jumper: true,
+            hide_on_single_page: false,
+            disabled: false,
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Pagination {
+    /// 创建分页组件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置记录总数
+    #[prop(default = "0")]
+    pub fn total(mut self, total: usize) -> Self {
+        self.total = total;
+        self
+    }
+
+    /// 设置当前页码的 Signal，从 1 开始，切换页码时写入新的页码
+    pub fn current(mut self, current: Signal<usize>) -> Self {
+        self.current = Some(current);
+        self
+    }
+
+    /// 设置每页记录数的 Signal，修改每页记录数时写入新的值
+    pub fn page_size(mut self, page_size: Signal<usize>) -> Self {
+        self.page_size = Some(page_size);
+        self
+    }
+
+    /// 设置可选的每页记录数，默认为 10、20、50、100
+    #[prop(default = "vec![10, 20, 50, 100]")]
+    pub fn page_sizes(mut self, page_sizes: Vec<usize>) -> Self {
+        self.page_sizes = page_sizes.into_iter().filter(|size| *size > 0).collect();
+        self
+    }
+
+    /// 设置布局
+    #[prop(default = "PaginationLayout::Full")]
+    pub fn layout(mut self, layout: PaginationLayout) -> Self {
+        self.layout = layout;
+        self
+    }
+
+    /// 设置页码列表最多显示的页码数，包含首页和末页，最少为 5
+    #[prop(default = "7")]
+    pub fn pager_count(mut self, count: usize) -> Self {
+        self.pager_count = count.max(5);
+        self
+    }
+
+    /// 设置完整布局中是否显示每页记录数选择
+    #[prop(default = "true")]
+    pub fn show_sizes(mut self, show_sizes: bool) -> Self {
+        self.show_sizes = show_sizes;
+        self
+    }
+
+    /// 设置完整布局中是否显示跳转输入框
+    #[prop(default = "true")]
+    pub fn show_jumper(mut self, show_jumper: bool) -> Self {
+        self.show_jumper = show_jumper;
+        self
+    }
+
+    /// 设置只有一页时是否隐藏整个分页
+    #[prop(default = "false")]
+    pub fn hide_on_single_page(mut self, hide: bool) -> Self {
+        self.hide_on_single_page = hide;
+        self
+    }
+
+    /// 设置是否禁用，禁用后不能切换页码和每页记录数
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置切换页码或每页记录数时的回调，参数为新的页码和每页的记录数
+    pub fn onchange(mut self, mut handler: impl FnMut(usize, usize) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(move |(page, page_size)| {
+            handler(page, page_size)
+        }));
+        self
+    }
+}
+
+impl ToElement for Pagination {
+    fn to_element(&self) -> Element {
+        rsx! {
+            PaginationElement { pagination: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct PaginationElementProps {
+    pagination: Rc<Pagination>,
+}
+
+impl PartialEq for PaginationElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.pagination, &other.pagination)
+    }
+}
+
+#[allow(non_snake_case)]
+fn PaginationElement(props: PaginationElementProps) -> Element {
+    let pagination = props.pagination;
+    let inner_current = use_signal(|| 1usize);
+    let inner_size = use_signal(|| pagination.page_sizes.first().copied().unwrap_or(10));
+    let mut current = pagination.current.unwrap_or(inner_current);
+    let mut page_size = pagination.page_size.unwrap_or(inner_size);
+    let mut jumper = use_signal(String::new);
+
+    let total = pagination.total;
+    let size = page_size().max(1);
+    let pages = page_count(total, size);
+    // 总数减少后当前页可能超出范围，按最后一页显示
+    let page = current().clamp(1, pages);
+    let disabled = pagination.disabled;
+    let onchange = pagination.onchange;
+
+    let mut go = move |target: usize| {
+        let target = target.clamp(1, pages);
+        if disabled || target == *current.peek() {
+            return;
+        }
+        current.set(target);
+        if let Some(handler) = onchange {
+            handler.call((target, size));
+        }
+    };
+    let mut resize = move |new_size: usize| {
+        if disabled || new_size == size || new_size == 0 {
+            return;
+        }
+        // 保持当前页的第一条记录可见
+        let first = (page - 1) * size;
+        let target = (first / new_size + 1).min(page_count(total, new_size));
+        page_size.set(new_size);
+        current.set(target);
+        if let Some(handler) = onchange {
+            handler.call((target, new_size));
+        }
+    };
+    let mut jump = move || {
+        let value = jumper.peek().trim().parse::<usize>();
+        jumper.set(String::new());
+        if let Ok(target) = value {
+            go(target);
+        }
+    };
+
+    if pagination.hide_on_single_page && pages <= 1 {
+        return rsx! {};
+    }
+
+    let layout = pagination.layout;
+    let mut class = vec![pagination.class.to_string(), layout.as_class().to_string()];
+    if disabled {
+        class.push("is-disabled".to_string());
+    }
+    let class = class.join(" ");
+    let style = pagination.style.clone().map(|s| s.to_string());
+    let onclick_handler = pagination.onclick;
+    let mut sizes = pagination.page_sizes.clone();
+    if !sizes.contains(&size) {
+        sizes.push(size);
+        sizes.sort_unstable();
+    }
+    let items = pager_items(page, pages, pagination.pager_count);
+    let full = layout == PaginationLayout::Full;
+
+    rsx! {
+        nav {
+            id: pagination.id.clone(),
+            class,
+            style,
+            "aria-label": "分页",
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            if full {
+                span { class: classnames::PAGINATION_TOTAL, "共 {total} 条" }
+            }
+            {pagination.childrens_to_element()}
+            if full && pagination.show_sizes {
+                select {
+                    class: classnames::PAGINATION_SIZES,
+                    "aria-label": "每页条数",
+                    disabled,
+                    value: "{size}",
+                    onchange: move |event: FormEvent| {
+                        if let Ok(value) = event.value().parse::<usize>() {
+                            resize(value);
+                        }
+                    },
+                    for option in sizes {
+                        option { key: "{option}", value: "{option}", selected: option == size, "{option} 条/页" }
+                    }
+                }
+            }
+            button {
+                r#type: "button",
+                class: classnames::PAGINATION_PREV,
+                "aria-label": "上一页",
+                disabled: disabled || page <= 1,
+                onclick: move |_| go(page - 1),
+                "‹"
+            }
+            if full {
+                ul { class: classnames::PAGINATION_PAGER,
+                    for item in items {
+                        match item {
+                            PagerItem::Page(number) => rsx! {
+                                li { key: "{item.key()}",
+                                    button {
+                                        r#type: "button",
+                                        class: if number == page { format!("{} is-active", classnames::PAGINATION_ITEM) } else { classnames::PAGINATION_ITEM.to_string() },
+                                        "aria-current": (number == page).then_some("page"),
+                                        disabled,
+                                        onclick: move |_| go(number),
+                                        "{number}"
+                                    }
+                                }
+                            },
+                            PagerItem::PrevMore => rsx! {
+                                li { key: "{item.key()}",
+                                    button {
+                                        r#type: "button",
+                                        class: classnames::PAGINATION_MORE,
+                                        "aria-label": "向前 {JUMP_PAGES} 页",
+                                        disabled,
+                                        onclick: move |_| go(page.saturating_sub(JUMP_PAGES)),
+                                        "…"
+                                    }
+                                }
+                            },
+                            PagerItem::NextMore => rsx! {
+                                li { key: "{item.key()}",
+                                    button {
+                                        r#type: "button",
+                                        class: classnames::PAGINATION_MORE,
+                                        "aria-label": "向后 {JUMP_PAGES} 页",
+                                        disabled,
+                                        onclick: move |_| go(page + JUMP_PAGES),
+                                        "…"
+                                    }
+                                }
+                            },
+                        }
+                    }
+                }
+            } else {
+                span { class: classnames::PAGINATION_CURRENT, "aria-live": "polite", "{page} / {pages}" }
+            }
+            button {
+                r#type: "button",
+                class: classnames::PAGINATION_NEXT,
+                "aria-label": "下一页",
+                disabled: disabled || page >= pages,
+                onclick: move |_| go(page + 1),
+                "›"
+            }
+            if full && pagination.show_jumper {
+                label { class: classnames::PAGINATION_JUMPER,
+                    "前往"
+                    input {
+                        r#type: "number",
+                        min: "1",
+                        max: "{pages}",
+                        disabled,
+                        value: "{jumper}",
+                        oninput: move |event: FormEvent| jumper.set(event.value()),
+                        onkeydown: move |event: KeyboardEvent| {
+                            if event.key() == Key::Enter {
+                                jump();
+                            }
+                        },
+                        onchange: move |_| jump(),
+                    }
+                    "页"
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Text, View};
+    use PagerItem::{NextMore, Page, PrevMore};
+
+    #[test]
+    fn test_pager_items() {
+        assert_eq!(pager_items(1, 3, 7), [Page(1), Page(2), Page(3)]);
+        assert_eq!(
+            pager_items(2, 20, 7),
+            [
+                Page(1),
+                Page(2),
+                Page(3),
+                Page(4),
+                Page(5),
+                Page(6),
+                NextMore,
+                Page(20)
+            ]
+        );
+        assert_eq!(
+            pager_items(10, 20, 7),
+            [
+                Page(1),
+                PrevMore,
+                Page(8),
+                Page(9),
+                Page(10),
+                Page(11),
+                Page(12),
+                NextMore,
+                Page(20)
+            ]
+        );
+        assert_eq!(
+            pager_items(19, 20, 7),
+            [
+                Page(1),
+                PrevMore,
+                Page(15),
+                Page(16),
+                Page(17),
+                Page(18),
+                Page(19),
+                Page(20)
+            ]
+        );
+        // 不足 5 项时按 5 项计算
+        assert_eq!(
+            pager_items(5, 10, 3),
+            [
+                Page(1),
+                PrevMore,
+                Page(4),
+                Page(5),
+                Page(6),
+                NextMore,
+                Page(10)
+            ]
+        );
+        assert_eq!(page_count(0, 10), 1);
+        assert_eq!(page_count(21, 10), 3);
+    }
+
+    fn app() -> Element {
+        let current = use_signal(|| 1usize);
+        let page_size = use_signal(|| 10usize);
+        let mut last = use_signal(String::new);
+
+        View::new()
+            .children(
+                Pagination::new()
+                    .total(200)
+                    .current(current)
+                    .page_size(page_size)
+                    .onchange(move |page, size| last.set(format!("{page}/{size}"))),
+            )
+            .children(Text::span(format!("回调：{last}")))
+            .to_element()
+    }
+
+    fn active(harness: &Harness) -> String {
+        harness
+            .find_by_attr("aria-current", "page")
+            .unwrap()
+            .text()
+            .to_string()
+    }
+
+    #[test]
+    fn test_pagination_navigation() {
+        let mut harness = Harness::new(app);
+        assert_eq!(active(&harness), "1");
+        assert_eq!(
+            harness
+                .find_by_class(classnames::PAGINATION_TOTAL)
+                .unwrap()
+                .text(),
+            "共 200 条"
+        );
+        assert!(
+            harness
+                .find_by_class(classnames::PAGINATION_PREV)
+                .unwrap()
+                .attr("disabled")
+                .is_some()
+        );
+
+        harness.click(&harness.find_by_class(classnames::PAGINATION_NEXT).unwrap());
+        assert_eq!(active(&harness), "2");
+        assert!(harness.find_by_text("回调：2/10").is_some());
+
+        harness.click(&harness.find_by_text("20").unwrap());
+        assert_eq!(active(&harness), "20");
+        assert!(
+            harness
+                .find_by_class(classnames::PAGINATION_NEXT)
+                .unwrap()
+                .attr("disabled")
+                .is_some()
+        );
+
+        // 点击省略号向前跳过 5 页
+        harness.click(&harness.find_by_class(classnames::PAGINATION_MORE).unwrap());
+        assert_eq!(active(&harness), "15");
+
+        // 跳转输入框超出范围时跳到最后一页
+        let input = harness.find_by_attr("type", "number").unwrap();
+        harness.input(&input, "99");
+        harness.keydown(&harness.find_by_attr("type", "number").unwrap(), "Enter");
+        assert_eq!(active(&harness), "20");
+        assert_eq!(
+            harness
+                .find_by_attr("type", "number")
+                .unwrap()
+                .attr("value"),
+            Some("")
+        );
+    }
+
+    #[test]
+    fn test_pagination_page_size() {
+        let mut harness = Harness::new(app);
+        let input = harness.find_by_attr("type", "number").unwrap();
+        harness.input(&input, "5");
+        harness.change(&harness.find_by_attr("type", "number").unwrap(), "5");
+        assert_eq!(active(&harness), "5");
+
+        // 第 5 页的第一条是第 41 条，每页 20 条时位于第 3 页
+        let sizes = harness.find_by_class(classnames::PAGINATION_SIZES).unwrap();
+        harness.change(&sizes, "20");
+        assert_eq!(active(&harness), "3");
+        assert!(harness.find_by_text("回调：3/20").is_some());
+        assert!(harness.find_by_text("10").is_some());
+    }
+
+    #[test]
+    fn test_pagination_compact_and_hidden() {
+        let mut harness = Harness::with_element(
+            Pagination::new()
+                .total(45)
+                .layout(PaginationLayout::Compact),
+        );
+        assert!(
+            harness
+                .find_by_class(classnames::PAGINATION_PAGER)
+                .is_none()
+        );
+        assert!(
+            harness
+                .find_by_class(classnames::PAGINATION_SIZES)
+                .is_none()
+        );
+        harness.click(&harness.find_by_class(classnames::PAGINATION_NEXT).unwrap());
+        assert_eq!(
+            harness
+                .find_by_class(classnames::PAGINATION_CURRENT)
+                .unwrap()
+                .text(),
+            "2 / 5"
+        );
+
+        let harness = Harness::with_element(Pagination::new().total(5).hide_on_single_page(true));
+        assert!(harness.find_by_class(classnames::PAGINATION).is_none());
+
+        let mut harness = Harness::with_element(Pagination::new().total(100).disabled(true));
+        harness.click(&harness.find_by_class(classnames::PAGINATION_NEXT).unwrap());
+        assert_eq!(active(&harness), "1");
+    }
+}
@@ -0,0 +1,646 @@
+//! PhoneInput 手机号码输入组件
+//!
+//! 由国家/地区选择器和号码输入框组成的复合输入组件。选择器显示国旗和国际区号，输入框按所选国家/地区的
+//! 号码格式通过 [`InputMask`] 自动分组，输出 E.164 格式的号码（如 `+8613800138000`）。
+//!
+//! # 组件模式
+//!
+//! PhoneInput 是一个**受控组件**，通过 `Signal<PhoneValue>` 传递结构化的值，
+//! 号码的校验状态可以通过 [`PhoneValue::validate`] 获取，E.164 格式的号码通过 [`PhoneValue::e164`] 获取。
+//!
+//! 号码开头输入的 `0`（国内长途前缀）会被忽略，例如在英国输入 `07700 900123` 得到 `+447700900123`。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{PhoneInput, PhoneValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let phone = use_signal(PhoneValue::default);
+//!     PhoneInput::new()
+//!         .value(phone)
+//!         .countries(vec!["CN", "HK", "US"])
+//!         .onchange(|value: PhoneValue| {
+//!             if value.is_valid() {
+//!                 println!("{}", value.e164());
+//!             }
+//!         })
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 格式化和校验
+//!
+//! ```rust
+//! use dioxus_blocks_components::{PhoneCountry, PhoneState, PhoneValue};
+//!
+//! let value = PhoneValue::new("CN", "13800138000");
+//! assert_eq!(value.formatted(), "138 0013 8000");
+//! assert_eq!(value.e164(), "+8613800138000");
+//! assert_eq!(value.validate(), PhoneState::Valid);
+//!
+//! let value = PhoneValue::parse("+1 (415) 555-2671").unwrap();
+//! assert_eq!(value.country, PhoneCountry::find("US").unwrap());
+//! assert_eq!(value.national, "4155552671");
+//!
+//! assert_eq!(PhoneValue::new("CN", "1380013800").validate(), PhoneState::Incomplete);
+//! assert_eq!(PhoneValue::new("CN", "23800138000").validate(), PhoneState::Invalid);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use super::input_mask::sync_input;
+use crate::{Input, InputMask, InputSize, Style, classnames, traits::ToElement, use_unique_id};
+
+/// 国家/地区的号码规则
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PhoneCountry {
+    /// ISO 3166-1 二位字母代码，如 `CN`
+    pub code: &'static str,
+    /// 国旗
+    pub flag: &'static str,
+    /// 名称
+    pub name: &'static str,
+    /// 国际区号，不含 `+`
+    pub dial_code: &'static str,
+    /// 国内号码的掩码格式
+    pub pattern: &'static str,
+    /// 国内号码允许的开头数字，为空时不限制
+    pub prefixes: &'static [char],
+}
+
+/// 内置的国家/地区列表
+const COUNTRIES: &[PhoneCountry] = &[
+    PhoneCountry {
+        code: "CN",
+        flag: "🇨🇳",
+        name: "中国大陆",
+        dial_code: "86",
+        pattern: "999 9999 9999",
+        prefixes: &['1'],
+    },
+    PhoneCountry {
+        code: "HK",
+        flag: "🇭🇰",
+        name: "中国香港",
+        dial_code: "852",
+        pattern: "9999 9999",
+        prefixes: &['2', '3', '5', '6', '7', '8', '9'],
+    },
+    PhoneCountry {
+        code: "MO",
+        flag: "🇲🇴",
+        name: "中国澳门",
+        dial_code: "853",
+        pattern: "9999 9999",
+        prefixes: &['2', '6', '8'],
+    },
+    PhoneCountry {
+        code: "TW",
+        flag: "🇹🇼",
+        name: "中国台湾",
+        dial_code: "886",
+        pattern: "999 999 999",
+        prefixes: &[],
+    },
+    PhoneCountry {
+        code: "US",
+        flag: "🇺🇸",
+        name: "美国",
+        dial_code: "1",
+        pattern: "(999) 999-9999",
+        prefixes: &['2', '3', '4', '5', '6', '7', '8', '9'],
+    },
+    PhoneCountry {
+        code: "GB",
+        flag: "🇬🇧",
+        name: "英国",
+        dial_code: "44",
+        pattern: "9999 999999",
+        prefixes: &[],
+    },
+    PhoneCountry {
+        code: "JP",
+        flag: "🇯🇵",
+        name: "日本",
+        dial_code: "81",
+        pattern: "99 9999 9999",
+        prefixes: &[],
+    },
+    PhoneCountry {
+        code: "KR",
+        flag: "🇰🇷",
+        name: "韩国",
+        dial_code: "82",
+        pattern: "99 9999 9999",
+        prefixes: &[],
+    },
+    PhoneCountry {
+        code: "SG",
+        flag: "🇸🇬",
+        name: "新加坡",
+        dial_code: "65",
+        pattern: "9999 9999",
+        prefixes: &['3', '6', '8', '9'],
+    },
+    PhoneCountry {
+        code: "AU",
+        flag: "🇦🇺",
+        name: "澳大利亚",
+        dial_code: "61",
+        pattern: "999 999 999",
+        prefixes: &[],
+    },
+    PhoneCountry {
+        code: "FR",
+        flag: "🇫🇷",
+        name: "法国",
+        dial_code: "33",
+        pattern: "9 99 99 99 99",
+        prefixes: &[],
+    },
+    PhoneCountry {
+        code: "IN",
+        flag: "🇮🇳",
+        name: "印度",
+        dial_code: "91",
+        pattern: "99999 99999",
+        prefixes: &['6', '7', '8', '9'],
+    },
+    PhoneCountry {
+        code: "RU",
+        flag: "🇷🇺",
+        name: "俄罗斯",
+        dial_code: "7",
+        pattern: "(999) 999-99-99",
+        prefixes: &[],
+    },
+];
+
+impl PhoneCountry {
+    /// 内置的全部国家/地区
+    pub fn all() -> &'static [PhoneCountry] {
+        COUNTRIES
+    }
+
+    /// 按 ISO 代码查找国家/地区，不区分大小写
+    pub fn find(code: &str) -> Option<PhoneCountry> {
+        COUNTRIES
+            .iter()
+            .find(|country| country.code.eq_ignore_ascii_case(code))
+            .copied()
+    }
+
+    /// 国内号码的输入掩码
+    pub fn mask(&self) -> InputMask {
+        InputMask::new(self.pattern)
+    }
+
+    /// 国内号码的位数
+    pub fn length(&self) -> usize {
+        self.pattern.chars().filter(|c| *c == '9').count()
+    }
+
+    /// 选择器中显示的文字，如 `🇨🇳 +86`
+    pub fn label(&self) -> String {
+        format!("{} +{}", self.flag, self.dial_code)
+    }
+}
+
+impl Default for PhoneCountry {
+    fn default() -> Self {
+        COUNTRIES[0]
+    }
+}
+
+/// 号码的校验状态
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PhoneState {
+    /// 未输入
+    #[default]
+    Empty,
+    /// 未输入完整
+    Incomplete,
+    /// 有效
+    Valid,
+    /// 输入完整但无效
+    Invalid,
+}
+
+/// 手机号码输入组件的值
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct PhoneValue {
+    /// 国家/地区
+    pub country: PhoneCountry,
+    /// 国内号码，只包含数字
+    pub national: String,
+}
+
+impl PhoneValue {
+    /// 按国家/地区代码和国内号码创建值，未知的代码使用默认的国家/地区
+    ///
+    /// 号码中的非数字字符和开头的 `0` 会被去掉，超出位数的部分会被截断。
+    pub fn new(country: &str, national: &str) -> Self {
+        let country = PhoneCountry::find(country).unwrap_or_default();
+        Self {
+            national: normalize(country, national),
+            country,
+        }
+    }
+
+    /// 解析 E.164 格式的号码，如 `+8613800138000`，号码中可以包含空格、括号和 `-`
+    ///
+    /// 不以 `+` 开头或国际区号不在内置列表中时返回 `None`。
+    pub fn parse(value: &str) -> Option<Self> {
+        let digits: String = value
+            .trim()
+            .strip_prefix('+')?
+            .chars()
+            .filter(char::is_ascii_digit)
+            .collect();
+        COUNTRIES
+            .iter()
+            .filter(|country| digits.starts_with(country.dial_code))
+            .max_by_key(|country| country.dial_code.len())
+            .map(|country| Self {
+                country: *country,
+                national: normalize(*country, &digits[country.dial_code.len()..]),
+            })
+    }
+
+    /// 按国家/地区格式化的国内号码
+    pub fn formatted(&self) -> String {
+        self.country.mask().format(&self.national)
+    }
+
+    /// E.164 格式的号码，未输入号码时返回空字符串
+    pub fn e164(&self) -> String {
+        if self.national.is_empty() {
+            String::new()
+        } else {
+            format!("+{}{}", self.country.dial_code, self.national)
+        }
+    }
+
+    /// 校验号码
+    pub fn validate(&self) -> PhoneState {
+        if self.national.is_empty() {
+            return PhoneState::Empty;
+        }
+        let prefixes = self.country.prefixes;
+        if !prefixes.is_empty() && !self.national.starts_with(|c: char| prefixes.contains(&c)) {
+            return PhoneState::Invalid;
+        }
+        if self.national.len() < self.country.length() {
+            PhoneState::Incomplete
+        } else {
+            PhoneState::Valid
+        }
+    }
+
+    /// 号码有效
+    pub fn is_valid(&self) -> bool {
+        self.validate() == PhoneState::Valid
+    }
+}
+
+/// 只保留数字，去掉开头的 `0` 并截断到国家/地区的号码位数
+fn normalize(country: PhoneCountry, value: &str) -> String {
+    let digits: String = value
+        .chars()
+        .filter(char::is_ascii_digit)
+        .skip_while(|c| *c == '0')
+        .collect();
+    country.mask().unmask(&digits)
+}
+
+/// 手机号码输入组件
+///
+/// 由国家/地区选择器和号码输入框组成，号码开头的数字不符合所选国家/地区的规则时显示错误提示。
+#[derive(Debug, Clone, ComponentBase)]
+pub struct PhoneInput {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值的 Signal（受控状态）
+    value: Option<Signal<PhoneValue>>,
+    /// 可选的国家/地区，为空时显示全部内置的国家/地区
+    countries: Vec<PhoneCountry>,
+    /// 是否禁用
+    disabled: bool,
+    /// 输入框尺寸
+    size: InputSize,
+    /// 号码占位符，为空时显示掩码的格式
+    placeholder: String,
+    /// 错误提示
+    error_message: String,
+    /// 值改变事件
+    onchange: Option<EventHandler<PhoneValue>>,
+}
+
+impl Default for PhoneInput {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::PHONE_INPUT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            countries: Vec::new(),
+            disabled: false,
+            size: InputSize::default(),
+            placeholder: String::new(),
+            error_message: "手机号码无效".to_string(),
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl PhoneInput {
+    /// 创建一个新的手机号码输入组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<PhoneValue>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置可选的国家/地区，参数为 ISO 代码，未知的代码会被忽略
+    pub fn countries(mut self, codes: Vec<&str>) -> Self {
+        self.countries = codes.into_iter().filter_map(PhoneCountry::find).collect();
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置输入框尺寸
+    #[prop(default = "InputSize::Medium")]
+    pub fn size(mut self, size: InputSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置号码占位符
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置错误提示
+    #[prop(default = "\"手机号码无效\"")]
+    pub fn error_message(mut self, message: impl Into<String>) -> Self {
+        self.error_message = message.into();
+        self
+    }
+
+    /// 设置值改变事件，切换国家/地区或输入号码时触发
+    pub fn onchange(mut self, handler: impl FnMut(PhoneValue) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for PhoneInput {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(PhoneValue::default);
+        let mut value = self.value.unwrap_or(inner_value);
+
+        // 输入框显示的内容，外部修改值时同步更新
+        let mut national = use_signal(|| value.peek().formatted());
+        use_effect(move || {
+            let formatted = value.read().formatted();
+            if *national.peek() != formatted {
+                national.set(formatted);
+            }
+        });
+
+        let current = value.read().clone();
+        let state = current.validate();
+        let country = current.country;
+        let mut countries = if self.countries.is_empty() {
+            COUNTRIES.to_vec()
+        } else {
+            self.countries.clone()
+        };
+        if !countries.contains(&country) {
+            countries.insert(0, country);
+        }
+
+        let onchange = self.onchange;
+        let mut update = move |next: PhoneValue| {
+            value.set(next.clone());
+            if let Some(handler) = onchange {
+                handler.call(next);
+            }
+        };
+
+        let invalid = state == PhoneState::Invalid;
+        let mut class_names = vec![&*self.class];
+        if self.disabled {
+            class_names.push("is-disabled");
+        }
+        if invalid {
+            class_names.push("is-invalid");
+        }
+        let class = class_names.join(" ");
+        let disabled = self.disabled;
+        let error_message = self.error_message.clone();
+
+        // 号码开头的 0 需要在截断之前去掉，因此不使用输入框的掩码，由组件自行格式化
+        let mask = country.mask();
+        let placeholder = if self.placeholder.is_empty() {
+            mask.placeholder()
+        } else {
+            self.placeholder.clone()
+        };
+        let input_id = use_unique_id("phone-input");
+        let input = Input::new()
+            .class(classnames::PHONE_INPUT_CONTROL)
+            .input_id(input_id.clone())
+            .value(national)
+            .size(self.size)
+            .disabled(disabled)
+            .placeholder(placeholder)
+            .oninput(move |raw: String| {
+                let previous = value.peek().formatted();
+                let next = PhoneValue {
+                    country,
+                    national: normalize(country, &raw),
+                };
+                let formatted = next.formatted();
+                if formatted != raw {
+                    let caret = mask.caret(&previous, raw.trim_start_matches('0'));
+                    sync_input(&input_id, &formatted, caret);
+                    national.set(formatted);
+                }
+                update(next);
+            });
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                "data-country": country.code,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::PHONE_INPUT_ROW,
+                    select {
+                        class: classnames::PHONE_INPUT_COUNTRY,
+                        "aria-label": "国家/地区",
+                        title: country.name,
+                        disabled,
+                        value: country.code,
+                        onchange: move |event: FormEvent| {
+                            let Some(next) = PhoneCountry::find(&event.value()) else {
+                                return;
+                            };
+                            let national = value.peek().national.clone();
+                            update(PhoneValue {
+                                national: normalize(next, &national),
+                                country: next,
+                            });
+                        },
+                        for option in countries {
+                            option {
+                                key: "{option.code}",
+                                value: option.code,
+                                title: option.name,
+                                selected: option == country,
+                                "{option.label()}"
+                            }
+                        }
+                    }
+                    {input.to_element()}
+                }
+                if invalid {
+                    div { class: classnames::PHONE_INPUT_ERROR, role: "alert", "{error_message}" }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_phone_value() {
+        let value = PhoneValue::new("gb", "07700 900123");
+        assert_eq!(value.country.code, "GB");
+        assert_eq!(value.national, "7700900123");
+        assert_eq!(value.formatted(), "7700 900123");
+        assert_eq!(value.e164(), "+447700900123");
+        assert!(value.is_valid());
+
+        // 超出位数的部分被截断
+        let value = PhoneValue::new("HK", "6123456789");
+        assert_eq!(value.national, "61234567");
+
+        assert_eq!(
+            PhoneValue::new("US", "1415555").validate(),
+            PhoneState::Invalid
+        );
+        assert_eq!(
+            PhoneValue::new("US", "415555").validate(),
+            PhoneState::Incomplete
+        );
+        assert_eq!(PhoneValue::default().validate(), PhoneState::Empty);
+        assert_eq!(PhoneValue::default().e164(), "");
+        assert_eq!(PhoneValue::new("XX", "138").country.code, "CN");
+
+        // 区号按最长匹配
+        let value = PhoneValue::parse("+852 6123 4567").unwrap();
+        assert_eq!(value.country.code, "HK");
+        assert_eq!(value.e164(), "+85261234567");
+        let value = PhoneValue::parse("+7 (912) 345-67-89").unwrap();
+        assert_eq!(value.formatted(), "(912) 345-67-89");
+        assert!(PhoneValue::parse("13800138000").is_none());
+        assert!(PhoneValue::parse("+999123").is_none());
+    }
+
+    #[test]
+    fn test_phone_input() {
+        fn app() -> Element {
+            let phone = use_signal(PhoneValue::default);
+            rsx! {
+                {PhoneInput::new().value(phone).countries(vec!["CN", "US"]).to_element()}
+                span { class: "e164", "{phone.read().e164()}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_text("🇺🇸 +1").is_some());
+        assert!(harness.find_by_text("🇬🇧 +44").is_none());
+
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&input, "013800138000");
+        assert_eq!(
+            harness.find_by_class("e164").unwrap().text(),
+            "+8613800138000"
+        );
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(input.attr("value"), Some("138 0013 8000"));
+        assert!(
+            harness
+                .find_by_class(classnames::PHONE_INPUT_ERROR)
+                .is_none()
+        );
+
+        // 切换国家后按新的格式截断和校验
+        let select = harness
+            .find_by_class(classnames::PHONE_INPUT_COUNTRY)
+            .unwrap();
+        harness.change(&select, "US");
+        let root = harness.find_by_class(classnames::PHONE_INPUT).unwrap();
+        assert_eq!(root.attr("data-country"), Some("US"));
+        assert_eq!(
+            harness.find_by_class("e164").unwrap().text(),
+            "+11380013800"
+        );
+        let input = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        assert_eq!(input.attr("value"), Some("(138) 001-3800"));
+        let error = harness
+            .find_by_class(classnames::PHONE_INPUT_ERROR)
+            .unwrap();
+        assert_eq!(error.text(), "手机号码无效");
+    }
+}
@@ -0,0 +1,445 @@
+//! Popconfirm 气泡确认框组件
+//!
+//! 包裹触发元素，点击后在触发元素旁弹出带图标、标题、描述和「取消」「确定」按钮的确认气泡，
+//! 点击按钮后关闭气泡并调用 `onconfirm` 或 `oncancel`，点击其他位置或按 Escape 关闭气泡。
+//!
+//! 气泡与 Tooltip、Dropdown 使用相同的定位方式：首选方向的空间不足时翻转到另一侧，
+//! 超出视口时平移回视口内，箭头保持指向触发元素。
+//!
+//! 组件以独立的组件作用域渲染，可以在条件分支中使用。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, ButtonType, Popconfirm, TooltipPlacement, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Popconfirm::new("确定删除这条记录？")
+//!         .description("删除后无法恢复")
+//!         .placement(TooltipPlacement::BottomEnd)
+//!         .confirm_type(ButtonType::Danger)
+//!         .onconfirm(|_| println!("已删除"))
+//!         .oncancel(|_| println!("已取消"))
+//!         .children(Button::new().text("删除"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::popper::use_popper;
+use crate::{
+    Button, ButtonSize, ButtonType, Style, TooltipPlacement, classnames, traits::ToElement,
+    use_unique_id,
+};
+
+/// 气泡位置对应的 CSS 类名
+fn placement_class(placement: TooltipPlacement) -> &'static str {
+    match placement {
+        TooltipPlacement::Top => "t-popconfirm__popup--top",
+        TooltipPlacement::TopStart => "t-popconfirm__popup--top-start",
+        TooltipPlacement::TopEnd => "t-popconfirm__popup--top-end",
+        TooltipPlacement::Bottom => "t-popconfirm__popup--bottom",
+        TooltipPlacement::BottomStart => "t-popconfirm__popup--bottom-start",
+        TooltipPlacement::BottomEnd => "t-popconfirm__popup--bottom-end",
+        TooltipPlacement::Left => "t-popconfirm__popup--left",
+        TooltipPlacement::LeftStart => "t-popconfirm__popup--left-start",
+        TooltipPlacement::LeftEnd => "t-popconfirm__popup--left-end",
+        TooltipPlacement::Right => "t-popconfirm__popup--right",
+        TooltipPlacement::RightStart => "t-popconfirm__popup--right-start",
+        TooltipPlacement::RightEnd => "t-popconfirm__popup--right-end",
+    }
+}
+
+/// 气泡确认框组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Popconfirm {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，作为触发元素
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 标题
+    title: String,
+    /// 标题下方的描述
+    description: Option<String>,
+    /// 标题前的图标，为空时不显示
+    icon: String,
+    /// 确定按钮文本
+    confirm_text: String,
+    /// 取消按钮文本
+    cancel_text: String,
+    /// 确定按钮类型
+    confirm_type: ButtonType,
+    /// 气泡的位置
+    placement: TooltipPlacement,
+    /// 气泡与触发元素之间的距离，单位像素
+    offset: u32,
+    /// 是否禁用
+    disabled: bool,
+    /// 点击确定按钮的回调
+    onconfirm: Option<EventHandler<MouseEvent>>,
+    /// 点击取消按钮的回调
+    oncancel: Option<EventHandler<MouseEvent>>,
+}
+
+impl Default for Popconfirm {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::POPCONFIRM),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            title: String::new(),
+            description: None,
+            icon: "!".to_string(),
+            confirm_text: "确定".to_string(),
+            cancel_text: "取消".to_string(),
+            confirm_type: ButtonType::Primary,
+            placement: TooltipPlacement::Top,
+            offset: 10,
+            disabled: false,
+            onconfirm: None,
+            oncancel: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Popconfirm {
+    /// 使用标题创建气泡确认框，通过 `children` 添加触发元素
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题下方的描述
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 设置标题前的图标，传入空字符串时不显示图标
+    #[prop(default = "\"!\"")]
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        self.icon = icon.into();
+        self
+    }
+
+    /// 设置确定按钮文本
+    #[prop(default = "\"确定\"")]
+    pub fn confirm_text(mut self, text: impl Into<String>) -> Self {
+        self.confirm_text = text.into();
+        self
+    }
+
+    /// 设置取消按钮文本
+    #[prop(default = "\"取消\"")]
+    pub fn cancel_text(mut self, text: impl Into<String>) -> Self {
+        self.cancel_text = text.into();
+        self
+    }
+
+    /// 设置确定按钮类型，危险操作可以使用 [`ButtonType::Danger`]
+    #[prop(default = "ButtonType::Primary")]
+    pub fn confirm_type(mut self, confirm_type: ButtonType) -> Self {
+        self.confirm_type = confirm_type;
+        self
+    }
+
+    /// 设置气泡的位置，空间不足时自动翻转到另一侧
+    #[prop(default = "TooltipPlacement::Top")]
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// 设置气泡与触发元素之间的距离，单位像素
+    #[prop(default = "10")]
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// 设置是否禁用，禁用后点击触发元素不弹出气泡
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置点击确定按钮的回调，回调前关闭气泡
+    pub fn onconfirm(mut self, handler: impl FnMut(MouseEvent) + 'static) -> Self {
+        self.onconfirm = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置点击取消按钮的回调，回调前关闭气泡
+    pub fn oncancel(mut self, handler: impl FnMut(MouseEvent) + 'static) -> Self {
+        self.oncancel = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for Popconfirm {
+    fn to_element(&self) -> Element {
+        rsx! {
+            PopconfirmElement { popconfirm: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct PopconfirmElementProps {
+    popconfirm: Rc<Popconfirm>,
+}
+
+impl PartialEq for PopconfirmElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.popconfirm, &other.popconfirm)
+    }
+}
+
+#[allow(non_snake_case)]
+fn PopconfirmElement(props: PopconfirmElementProps) -> Element {
+    let popconfirm = props.popconfirm;
+    let title_id = use_unique_id("popconfirm");
+    let popper = use_popper::<TooltipPlacement>();
+    let mut open = use_signal(|| false);
+
+    let mut close = move || {
+        open.set(false);
+        popper.reset();
+    };
+
+    let visible = open() && !popconfirm.disabled;
+
+    let disabled = popconfirm.disabled;
+    let placement = popconfirm.placement;
+    let offset = popconfirm.offset as f64;
+    let (actual, shift) = popper.position(placement);
+    let id = popconfirm.id.clone();
+    let class = popconfirm.class.to_string();
+    let style = popconfirm.style.clone().map(|s| s.to_string());
+    let onclick_handler = popconfirm.onclick;
+    let onconfirm = popconfirm.onconfirm;
+    let oncancel = popconfirm.oncancel;
+    let popup_class = format!(
+        "{} {}",
+        classnames::POPCONFIRM_POPUP,
+        placement_class(actual)
+    );
+    let popup_style = format!(
+        "--t-popconfirm-offset: {}px; --t-popconfirm-shift: {shift}px;",
+        popconfirm.offset
+    );
+
+    let cancel = Button::new()
+        .class(classnames::POPCONFIRM_CANCEL)
+        .text(popconfirm.cancel_text.clone())
+        .size(ButtonSize::Small)
+        .onclick(move |event| {
+            close();
+            if let Some(handler) = oncancel {
+                handler.call(event);
+            }
+        });
+    let confirm = Button::new()
+        .class(classnames::POPCONFIRM_CONFIRM)
+        .text(popconfirm.confirm_text.clone())
+        .btn_type(popconfirm.confirm_type)
+        .size(ButtonSize::Small)
+        .onclick(move |event| {
+            close();
+            if let Some(handler) = onconfirm {
+                handler.call(event);
+            }
+        });
+
+    rsx! {
+        span {
+            id,
+            class,
+            style,
+            onmounted: move |event: MountedEvent| popper.set_anchor(event),
+            onkeydown: move |event: KeyboardEvent| {
+                if event.key() == Key::Escape && open() {
+                    close();
+                }
+            },
+            onclick: move |event: MouseEvent| {
+                if !disabled {
+                    if open() {
+                        close();
+                    } else {
+                        open.set(true);
+                    }
+                }
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            {popconfirm.childrens_to_element()}
+            if visible {
+                span {
+                    class: classnames::POPCONFIRM_BACKDROP,
+                    onclick: move |event: MouseEvent| {
+                        event.stop_propagation();
+                        close();
+                    },
+                }
+                div {
+                    class: popup_class,
+                    style: popup_style,
+                    role: "dialog",
+                    aria_labelledby: title_id.clone(),
+                    onmounted: move |event: MountedEvent| popper.measure(event, placement, offset),
+                    onclick: move |event: MouseEvent| event.stop_propagation(),
+                    div { class: classnames::POPCONFIRM_MAIN,
+                        if !popconfirm.icon.is_empty() {
+                            span { class: classnames::POPCONFIRM_ICON, "{popconfirm.icon}" }
+                        }
+                        div {
+                            div { id: title_id, class: classnames::POPCONFIRM_TITLE, "{popconfirm.title}" }
+                            if let Some(description) = &popconfirm.description {
+                                div { class: classnames::POPCONFIRM_DESCRIPTION, "{description}" }
+                            }
+                        }
+                    }
+                    div { class: classnames::POPCONFIRM_ACTIONS,
+                        {cancel.to_element()}
+                        {confirm.to_element()}
+                    }
+                    span { class: classnames::POPCONFIRM_ARROW }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn popup(harness: &Harness) -> Option<crate::testing::ElementRef> {
+        harness.find_by_attr("role", "dialog")
+    }
+
+    #[test]
+    fn test_popconfirm_confirm_and_cancel() {
+        fn app() -> Element {
+            let mut log = use_signal(Vec::<&str>::new);
+            rsx! {
+                {Popconfirm::new("确定删除？")
+                    .description("删除后无法恢复")
+                    .placement(TooltipPlacement::BottomStart)
+                    .confirm_text("删除")
+                    .onconfirm(move |_| log.write().push("confirm"))
+                    .oncancel(move |_| log.write().push("cancel"))
+                    .children(Button::new().text("删除记录"))
+                    .to_element()}
+                span { class: "log", {log().join(",")} }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let root = harness.find_by_class(classnames::POPCONFIRM).unwrap();
+        assert!(popup(&harness).is_none());
+
+        harness.click(&root);
+        let bubble = popup(&harness).unwrap();
+        assert!(bubble.has_class("t-popconfirm__popup--bottom-start"));
+        let title = harness.find_by_class(classnames::POPCONFIRM_TITLE).unwrap();
+        assert_eq!(title.text(), "确定删除？");
+        assert_eq!(bubble.attr("aria-labelledby"), title.attr("id"));
+        assert_eq!(
+            harness
+                .find_by_class(classnames::POPCONFIRM_DESCRIPTION)
+                .unwrap()
+                .text(),
+            "删除后无法恢复"
+        );
+        assert_eq!(
+            harness
+                .find_by_class(classnames::POPCONFIRM_ICON)
+                .unwrap()
+                .text(),
+            "!"
+        );
+
+        let cancel = harness
+            .find_by_class(classnames::POPCONFIRM_CANCEL)
+            .unwrap();
+        harness.click(&cancel);
+        assert!(popup(&harness).is_none());
+        assert_eq!(harness.find_by_class("log").unwrap().text(), "cancel");
+
+        harness.click(&root);
+        let confirm = harness
+            .find_by_class(classnames::POPCONFIRM_CONFIRM)
+            .unwrap();
+        assert_eq!(confirm.text(), "删除");
+        harness.click(&confirm);
+        assert!(popup(&harness).is_none());
+        assert_eq!(
+            harness.find_by_class("log").unwrap().text(),
+            "cancel,confirm"
+        );
+    }
+
+    #[test]
+    fn test_popconfirm_dismiss_and_disabled() {
+        let mut harness = Harness::with_element(
+            Popconfirm::new("确定提交？")
+                .icon("")
+                .children(Button::new().text("提交")),
+        );
+        let root = harness.find_by_class(classnames::POPCONFIRM).unwrap();
+        harness.click(&root);
+        assert!(
+            popup(&harness)
+                .unwrap()
+                .has_class("t-popconfirm__popup--top")
+        );
+        assert!(harness.find_by_class(classnames::POPCONFIRM_ICON).is_none());
+
+        // 点击遮罩关闭，不调用回调
+        let backdrop = harness
+            .find_by_class(classnames::POPCONFIRM_BACKDROP)
+            .unwrap();
+        harness.click(&backdrop);
+        assert!(popup(&harness).is_none());
+
+        harness.click(&root);
+        harness.keydown(&root, "Escape");
+        assert!(popup(&harness).is_none());
+
+        let mut harness = Harness::with_element(
+            Popconfirm::new("确定提交？")
+                .disabled(true)
+                .children(Button::new().text("提交")),
+        );
+        let root = harness.find_by_class(classnames::POPCONFIRM).unwrap();
+        harness.click(&root);
+        assert!(popup(&harness).is_none());
+    }
+}
@@ -0,0 +1,214 @@
+//! PropsTable 属性表组件
+//!
+//! 以表格展示组件的 API，数据来自 [`builder_props`](dioxus_blocks_macro::builder_props) 宏根据构建方法
+//! 生成的 [`ComponentProps`]，文档与代码保持一致，不需要手动维护。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Alert, PropsTable, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     PropsTable::of::<Alert>().common(true).to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{COMMON_PROPS, ComponentProps, PropInfo, Style, classnames, traits::ToElement};
+
+/// PropsTable 属性表组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct PropsTable {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 标题
+    title: Option<String>,
+    /// 属性列表
+    props: &'static [PropInfo],
+    /// 是否追加所有组件共有的属性
+    common: bool,
+    /// 是否启用打印样式
+    print_friendly: bool,
+}
+
+impl Default for PropsTable {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::PROPS_TABLE),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            title: None,
+            props: &[],
+            common: false,
+            print_friendly: false,
+        }
+    }
+}
+
+#[builder_props]
+impl PropsTable {
+    /// 创建一个空的属性表
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 创建组件的属性表，标题为组件名称
+    pub fn of<T: ComponentProps>() -> Self {
+        Self::new()
+            .title(format!("{} 属性", T::NAME))
+            .props(T::props())
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 设置属性列表
+    pub fn props(mut self, props: &'static [PropInfo]) -> Self {
+        self.props = props;
+        self
+    }
+
+    /// 设置是否追加所有组件共有的属性，如 `id`、`class`、`style`
+    #[prop(default = "false")]
+    pub fn common(mut self, common: bool) -> Self {
+        self.common = common;
+        self
+    }
+
+    /// 设置是否启用打印样式，打印时表格完整展开，每页重复表头并避免行被分页截断
+    #[prop(default = "false")]
+    pub fn print_friendly(mut self, print_friendly: bool) -> Self {
+        self.print_friendly = print_friendly;
+        self
+    }
+}
+
+impl ToElement for PropsTable {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = if self.print_friendly {
+            format!("{} {}", self.class, classnames::PROPS_TABLE_PRINT_FRIENDLY)
+        } else {
+            self.class.to_string()
+        };
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let common: &[PropInfo] = if self.common { COMMON_PROPS } else { &[] };
+        let rows = self.props.iter().chain(common);
+
+        rsx! {
+            div {
+                id,
+                class: "{class}",
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if let Some(title) = &self.title {
+                    div { class: classnames::PROPS_TABLE_TITLE, "{title}" }
+                }
+                div { class: classnames::PROPS_TABLE_WRAPPER,
+                    table { class: classnames::PROPS_TABLE_TABLE,
+                        thead {
+                            tr {
+                                th { "属性" }
+                                th { "说明" }
+                                th { "类型" }
+                                th { "默认值" }
+                            }
+                        }
+                        tbody {
+                            for prop in rows {
+                                tr { key: "{prop.name}",
+                                    td {
+                                        code { class: classnames::PROPS_TABLE_NAME, "{prop.name}" }
+                                    }
+                                    td { "{prop.doc}" }
+                                    td {
+                                        if !prop.ty.is_empty() {
+                                            code { class: classnames::PROPS_TABLE_TYPE, "{prop.ty}" }
+                                        }
+                                    }
+                                    td {
+                                        match prop.default {
+                                            Some(default) => rsx! {
+                                                code { "{default}" }
+                                            },
+                                            None => rsx! { "—" },
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+                {self.childrens_to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_render_props_table() {
+        fn app() -> Element {
+            PropsTable::of::<PropsTable>().common(true).to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("PropsTable 属性"));
+        assert!(html.contains("&#38;&#39;static [PropInfo]"));
+        assert!(html.contains("设置是否追加所有组件共有的属性"));
+        assert!(html.contains("<code>false</code>"));
+        assert!(html.contains(">onclick<"));
+    }
+
+    #[test]
+    fn test_render_print_friendly() {
+        fn app() -> Element {
+            PropsTable::of::<PropsTable>()
+                .print_friendly(true)
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("class=\"t-props-table t-props-table--print-friendly\""));
+    }
+}
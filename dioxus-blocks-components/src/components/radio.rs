@@ -1,6 +1,6 @@
 //! Radio 单选框组件
 //!
-//! 提供单选框和单选框组组件，支持边框、按钮样式等功能。
+//! 提供单选框和单选框组组件，支持边框、按钮样式、卡片样式、纵向排列和选项说明等功能。
 //!
 //! # 组件模式
 //!
@@ -12,71 +12,117 @@
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Radio, RadioGroup};
+//! use dioxus_blocks_components::{Radio, RadioGroup, RadioValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut radio = use_signal(|| 1);
-//! rsx! {
-//!     RadioGroup { value: radio, onchange: move |v| radio.set(v),
-//!         Radio { value: 1, "Option 1" }
-//!         Radio { value: 2, "Option 2" }
-//!         Radio { value: 3, "Option 3" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut radio = use_signal(|| RadioValue::from(1));
+//!     RadioGroup::new()
+//!         .value(radio)
+//!         .onchange(move |v| radio.set(v))
+//!         .radio(Radio::new().value(1).label("Option 1"))
+//!         .radio(Radio::new().value(2).label("Option 2"))
+//!         .radio(Radio::new().value(3).label("Option 3"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 //!
 //! ## 禁用状态
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Radio, RadioGroup};
+//! use dioxus_blocks_components::{Radio, RadioGroup, RadioValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut radio = use_signal(|| 1);
-//! rsx! {
-//!     RadioGroup { value: radio, onchange: move |v| radio.set(v),
-//!         Radio { value: 1, disabled: true, "Option A" }
-//!         Radio { value: 2, disabled: true, "Option B" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut radio = use_signal(|| RadioValue::from(1));
+//!     RadioGroup::new()
+//!         .value(radio)
+//!         .onchange(move |v| radio.set(v))
+//!         .radio(Radio::new().value(1).disabled(true).label("Option A"))
+//!         .radio(Radio::new().value(2).disabled(true).label("Option B"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 //!
 //! ## 带有边框
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Radio, RadioGroup};
+//! use dioxus_blocks_components::{Radio, RadioGroup, RadioValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut radio = use_signal(|| 1);
-//! rsx! {
-//!     RadioGroup { value: radio, onchange: move |v| radio.set(v),
-//!         Radio { value: 1, border: true, "Option A" }
-//!         Radio { value: 2, border: true, "Option B" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut radio = use_signal(|| RadioValue::from(1));
+//!     RadioGroup::new()
+//!         .value(radio)
+//!         .onchange(move |v| radio.set(v))
+//!         .border(true)
+//!         .radio(Radio::new().value(1).label("Option A"))
+//!         .radio(Radio::new().value(2).label("Option B"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 //!
 //! ## 单选按钮
 //!
 //! ```rust
 //! use dioxus::prelude::*;
-//! use dioxus_blocks_components::{Radio, RadioGroup};
+//! use dioxus_blocks_components::{Radio, RadioGroup, RadioValue, ToElement};
+//! use dioxus::core::Mutations;
 //!
-//! let mut radio = use_signal(|| "New York");
-//! rsx! {
-//!     RadioGroup { value: radio, onchange: move |v| radio.set(v),
-//!         Radio { value: "New York", button: true, "New York" }
-//!         Radio { value: "Washington", button: true, "Washington" }
-//!         Radio { value: "Los Angeles", button: true, "Los Angeles" }
-//!     }
-//! }
+//! let mut dom = VirtualDom::new(|| {
+//!     let mut radio = use_signal(|| RadioValue::from("New York"));
+//!     RadioGroup::new()
+//!         .value(radio)
+//!         .onchange(move |v| radio.set(v))
+//!         .button(true)
+//!         .radio(Radio::new().value("New York").label("New York"))
+//!         .radio(Radio::new().value("Washington").label("Washington"))
+//!         .radio(Radio::new().value("Los Angeles").label("Los Angeles"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 卡片样式
+//!
+//! 纵向排列的卡片，每个选项显示标题和说明，适合套餐、方案等选择。
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Radio, RadioGroup, RadioValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let plan = use_signal(|| RadioValue::from("pro"));
+//!     RadioGroup::new()
+//!         .value(plan)
+//!         .card(true)
+//!         .vertical(true)
+//!         .radio(Radio::new().value("free").label("免费版").description("适合个人体验，最多 3 个项目"))
+//!         .radio(Radio::new().value("pro").label("专业版").description("不限项目数量，支持团队协作"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
 //! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, Text, traits::ToElement};
+use crate::{Style, Text, classnames, traits::ToElement, use_unique_id};
 
 /// 单选框尺寸枚举
 ///
@@ -92,16 +138,23 @@ pub enum RadioSize {
     Large,
 }
 
-impl std::fmt::Display for RadioSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl RadioSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            RadioSize::Medium => write!(f, ""),
-            RadioSize::Small => write!(f, "t-radio--small"),
-            RadioSize::Large => write!(f, "t-radio--large"),
+            RadioSize::Medium => "",
+            RadioSize::Small => "t-radio--small",
+            RadioSize::Large => "t-radio--large",
         }
     }
 }
 
+impl std::fmt::Display for RadioSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 单选框值枚举
 ///
 /// 支持多种类型的值。
@@ -216,7 +269,7 @@ pub struct Radio {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -238,13 +291,17 @@ pub struct Radio {
     border: bool,
     /// 是否使用按钮样式
     button: bool,
+    /// 是否使用卡片样式
+    card: bool,
+    /// 标签下方的说明文字
+    description: Option<String>,
 }
 
 impl Default for Radio {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-radio".to_string(),
+            class: Cow::Borrowed(classnames::RADIO),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -255,10 +312,13 @@ impl Default for Radio {
             disabled: false,
             border: false,
             button: false,
+            card: false,
+            description: None,
         }
     }
 }
 
+#[builder_props]
 impl Radio {
     /// 创建一个新的单选框实例
     pub fn new() -> Self {
@@ -301,34 +361,52 @@ impl Radio {
     }
 
     /// 设置值改变事件
+    #[prop(skip)]
     pub fn onchange2(mut self, handler: EventHandler<RadioValue>) -> Self {
         self.onchange = Some(handler);
         self
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置单选框尺寸
+    #[prop(default = "RadioSize::Medium")]
     pub fn size(mut self, size: RadioSize) -> Self {
         self.size = size;
         self
     }
 
     /// 设置是否显示边框
+    #[prop(default = "false")]
     pub fn border(mut self, border: bool) -> Self {
         self.border = border;
         self
     }
 
     /// 设置是否使用按钮样式
+    #[prop(default = "false")]
     pub fn button(mut self, button: bool) -> Self {
         self.button = button;
         self
     }
+
+    /// 设置是否使用卡片样式，卡片以带边框的方块展示标签和说明，适合套餐、方案等选择
+    #[prop(default = "false")]
+    pub fn card(mut self, card: bool) -> Self {
+        self.card = card;
+        self
+    }
+
+    /// 设置标签下方的说明文字
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
 }
 
 /// 便捷方法
@@ -362,6 +440,7 @@ impl ToElement for Radio {
         // 判断是否选中
         let is_checked_signal = self.checked_value;
         let value_for_check = value.clone();
+        let input_id = use_unique_id("radio");
         let is_checked = use_memo(move || {
             if let Some(signal) = &is_checked_signal {
                 let current = signal.read();
@@ -372,29 +451,33 @@ impl ToElement for Radio {
         });
 
         // 计算样式类名
-        let mut class_names = vec![self.class.clone()];
+        let mut class_names = vec![&*self.class];
 
         // 按钮样式
         if self.button {
-            class_names.push("t-radio--button".to_string());
+            class_names.push(classnames::RADIO_BUTTON);
         }
         // 按钮边框样式
         if self.border {
-            class_names.push("t-radio--button__border".to_string());
+            class_names.push(classnames::RADIO_BUTTON_BORDER);
+        }
+        // 卡片样式
+        if self.card {
+            class_names.push(classnames::RADIO_CARD);
         }
 
         // 添加尺寸类名
-        let size_class = self.size.to_string();
+        let size_class = self.size.as_class();
         if !size_class.is_empty() {
             class_names.push(size_class);
         }
 
         if *is_checked.read() {
-            class_names.push("is-checked".to_string());
+            class_names.push("is-checked");
         }
 
         if self.disabled {
-            class_names.push("is-disabled".to_string());
+            class_names.push("is-disabled");
         }
 
         let class = class_names.join(" ");
@@ -438,10 +521,11 @@ impl ToElement for Radio {
         let childrens = self.childrens_to_element();
 
         rsx! {
-            label { id, class, style: style_str,
-                span { class: "t-radio__input",
-                    span { class: "t-radio__inner" }
+            label { id, class, style: style_str, r#for: "{input_id}",
+                span { class: classnames::RADIO_INPUT,
+                    span { class: classnames::RADIO_INNER }
                     input {
+                        id: "{input_id}",
                         r#type: "radio",
                         value: item_value_for_input,
                         checked: *is_checked.read(),
@@ -449,7 +533,14 @@ impl ToElement for Radio {
                         onclick,
                     }
                 }
-                span { class: "t-radio__label", {childrens} }
+                if let Some(description) = &self.description {
+                    span { class: classnames::RADIO_BODY,
+                        span { class: classnames::RADIO_LABEL, {childrens} }
+                        span { class: classnames::RADIO_DESCRIPTION, "{description}" }
+                    }
+                } else {
+                    span { class: classnames::RADIO_LABEL, {childrens} }
+                }
             }
         }
     }
@@ -461,7 +552,7 @@ pub struct RadioGroup {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -481,6 +572,10 @@ pub struct RadioGroup {
     border: bool,
     /// 是否使用按钮样式
     button: bool,
+    /// 是否使用卡片样式
+    card: bool,
+    /// 是否纵向排列
+    vertical: bool,
     /// 绑定值变化时触发的事件
     onchange: Option<EventHandler<RadioValue>>,
 }
@@ -489,7 +584,7 @@ impl Default for RadioGroup {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-radio-group".to_string(),
+            class: Cow::Borrowed(classnames::RADIO_GROUP),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -499,11 +594,14 @@ impl Default for RadioGroup {
             size: RadioSize::default(),
             border: false,
             button: false,
+            card: false,
+            vertical: false,
             onchange: None,
         }
     }
 }
 
+#[builder_props]
 impl RadioGroup {
     /// 创建一个新的单选框组实例
     pub fn new() -> Self {
@@ -524,6 +622,20 @@ impl RadioGroup {
         self
     }
 
+    /// 由数据生成单选框，闭包返回每一项的值和标签元素，生成的单选框追加到已有的单选框之后
+    pub fn options<I, V, T>(mut self, items: I, mut option: impl FnMut(I::Item) -> (V, T)) -> Self
+    where
+        I: IntoIterator,
+        V: Into<RadioValue>,
+        T: ToElement + Clone + 'static,
+    {
+        self.radios.extend(items.into_iter().map(|item| {
+            let (value, label) = option(item);
+            Radio::new().value(value).label_element(label)
+        }));
+        self
+    }
+
     /// 设置当前值的 Signal（必需）
     pub fn value(mut self, value: Signal<RadioValue>) -> Self {
         self.value = Some(value);
@@ -531,29 +643,47 @@ impl RadioGroup {
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置单选框尺寸
+    #[prop(default = "RadioSize::Medium")]
     pub fn size(mut self, size: RadioSize) -> Self {
         self.size = size;
         self
     }
 
     /// 设置是否显示边框
+    #[prop(default = "false")]
     pub fn border(mut self, border: bool) -> Self {
         self.border = border;
         self
     }
 
     /// 设置是否使用按钮样式
+    #[prop(default = "false")]
     pub fn button(mut self, button: bool) -> Self {
         self.button = button;
         self
     }
 
+    /// 设置是否使用卡片样式
+    #[prop(default = "false")]
+    pub fn card(mut self, card: bool) -> Self {
+        self.card = card;
+        self
+    }
+
+    /// 设置是否纵向排列
+    #[prop(default = "false")]
+    pub fn vertical(mut self, vertical: bool) -> Self {
+        self.vertical = vertical;
+        self
+    }
+
     /// 设置值改变事件
     pub fn onchange(mut self, handler: impl FnMut(RadioValue) + 'static) -> Self {
         self.onchange = Some(EventHandler::new(handler));
@@ -561,6 +691,7 @@ impl RadioGroup {
     }
 
     /// 设置值改变事件
+    #[prop(skip)]
     pub fn onchange2(mut self, handler: EventHandler<RadioValue>) -> Self {
         self.onchange = Some(handler);
         self
@@ -592,9 +723,12 @@ impl ToElement for RadioGroup {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
 
-        let mut class_names = vec![self.class.clone(), self.size.to_string()];
+        let mut class_names = vec![&*self.class, self.size.as_class()];
+        if self.vertical {
+            class_names.push(classnames::RADIO_GROUP_VERTICAL);
+        }
         if self.disabled {
-            class_names.push("t-radio-group--disabled".to_string());
+            class_names.push(classnames::RADIO_GROUP_DISABLED);
         }
         let class = class_names.join(" ");
 
@@ -608,6 +742,7 @@ impl ToElement for RadioGroup {
         let size = self.size;
         let button = self.button;
         let border = self.border;
+        let card = self.card;
         let onchange_handler = self.onchange;
 
         let radios = self
@@ -616,12 +751,14 @@ impl ToElement for RadioGroup {
             .into_iter()
             .map(|radio: Radio| {
                 let old_disabled = radio.disabled;
+                let old_card = radio.card;
                 let mut new_radio = radio
                     .checked_value(value_signal)
                     .disabled(old_disabled || disabled)
                     .size(size)
                     .button(button)
-                    .border(border);
+                    .border(border)
+                    .card(old_card || card);
                 if let Some(handler) = onchange_handler {
                     new_radio = new_radio.onchange2(handler);
                 }
@@ -630,7 +767,7 @@ impl ToElement for RadioGroup {
             .collect::<Vec<Radio>>();
 
         rsx! {
-            div { id, class, style,
+            div { id, class, style, role: "radiogroup",
                 for radio in radios.iter() {
                     {radio.to_element()}
                 }
@@ -0,0 +1,306 @@
+//! ResultPanel 结果组件
+//!
+//! 用于反馈一系列操作任务的处理结果，或者作为 404、500 等异常页面的主体内容。
+//! 支持成功、信息、警告、错误以及 403、404、500 等状态，可以设置标题、副标题、自定义图标和操作区域。
+//!
+//! 为避免与标准库的 `Result` 重名，组件命名为 `ResultPanel`。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{ResultPanel, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ResultPanel::new()
+//!         .as_success()
+//!         .title("提交成功")
+//!         .sub_title("订单已创建，预计 3 个工作日内发货")
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 404 页面
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, ResultPanel, ResultStatus, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ResultPanel::new()
+//!         .status(ResultStatus::NotFound)
+//!         .title("404")
+//!         .sub_title("抱歉，您访问的页面不存在")
+//!         .extra(Button::new().text("返回首页").as_primary())
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 结果状态枚举
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum ResultStatus {
+    /// 成功
+    Success,
+    /// 信息
+    #[default]
+    Info,
+    /// 警告
+    Warning,
+    /// 错误
+    Error,
+    /// 403 无权限
+    Forbidden,
+    /// 404 页面不存在
+    NotFound,
+    /// 500 服务器错误
+    ServerError,
+}
+
+impl ResultStatus {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            ResultStatus::Success => "t-result--success",
+            ResultStatus::Info => "t-result--info",
+            ResultStatus::Warning => "t-result--warning",
+            ResultStatus::Error => "t-result--error",
+            ResultStatus::Forbidden => "t-result--403",
+            ResultStatus::NotFound => "t-result--404",
+            ResultStatus::ServerError => "t-result--500",
+        }
+    }
+}
+
+impl std::fmt::Display for ResultStatus {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+impl ResultStatus {
+    /// 状态对应的默认图标
+    fn icon(&self) -> &'static str {
+        match self {
+            ResultStatus::Success => "✓",
+            ResultStatus::Info => "i",
+            ResultStatus::Warning => "!",
+            ResultStatus::Error => "✕",
+            ResultStatus::Forbidden => "403",
+            ResultStatus::NotFound => "404",
+            ResultStatus::ServerError => "500",
+        }
+    }
+}
+
+/// ResultPanel 结果组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct ResultPanel {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 结果状态
+    status: ResultStatus,
+    /// 标题
+    title: Option<String>,
+    /// 副标题
+    sub_title: Option<String>,
+    /// 自定义图标
+    icon: Option<Rc<dyn ToElement>>,
+    /// 操作区域
+    extra: Vec<Rc<dyn ToElement>>,
+}
+
+impl Default for ResultPanel {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::RESULT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            status: ResultStatus::default(),
+            title: None,
+            sub_title: None,
+            icon: None,
+            extra: Vec::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl ResultPanel {
+    /// 创建结果组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置结果状态
+    #[prop(default = "ResultStatus::Info")]
+    pub fn status(mut self, status: ResultStatus) -> Self {
+        self.status = status;
+        self
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// 设置副标题
+    pub fn sub_title(mut self, sub_title: impl Into<String>) -> Self {
+        self.sub_title = Some(sub_title.into());
+        self
+    }
+
+    /// 设置自定义图标
+    pub fn icon<T>(mut self, icon: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.icon = Some(Rc::new(icon));
+        self
+    }
+
+    /// 添加操作区域内容
+    pub fn extra<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.extra.push(Rc::new(component));
+        self
+    }
+
+    /// 添加操作区域内容
+    pub fn extra2(mut self, component: Rc<dyn ToElement>) -> Self {
+        self.extra.push(component);
+        self
+    }
+}
+
+/// 便捷方法
+impl ResultPanel {
+    /// 设置为成功状态
+    pub fn as_success(mut self) -> Self {
+        self.status = ResultStatus::Success;
+        self
+    }
+
+    /// 设置为信息状态
+    pub fn as_info(mut self) -> Self {
+        self.status = ResultStatus::Info;
+        self
+    }
+
+    /// 设置为警告状态
+    pub fn as_warning(mut self) -> Self {
+        self.status = ResultStatus::Warning;
+        self
+    }
+
+    /// 设置为错误状态
+    pub fn as_error(mut self) -> Self {
+        self.status = ResultStatus::Error;
+        self
+    }
+}
+
+impl ToElement for ResultPanel {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = [&*self.class, self.status.as_class()].join(" ");
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let icon = match &self.icon {
+            Some(icon) => icon.to_element(),
+            None => rsx! {
+                span { class: classnames::RESULT_ICON_DEFAULT, {self.status.icon()} }
+            },
+        };
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::RESULT_ICON, {icon} }
+                if let Some(title) = &self.title {
+                    div { class: classnames::RESULT_TITLE, "{title}" }
+                }
+                if let Some(sub_title) = &self.sub_title {
+                    div { class: classnames::RESULT_SUBTITLE, "{sub_title}" }
+                }
+                if !self.childrens.is_empty() {
+                    div { class: classnames::RESULT_CONTENT, {self.childrens_to_element()} }
+                }
+                if !self.extra.is_empty() {
+                    div { class: classnames::RESULT_EXTRA,
+                        for extra in self.extra.iter() {
+                            {extra.to_element()}
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "button"))]
+mod tests {
+    use super::*;
+    use crate::Button;
+
+    #[test]
+    fn test_render_not_found_result() {
+        fn app() -> Element {
+            ResultPanel::new()
+                .status(ResultStatus::NotFound)
+                .title("页面不存在")
+                .extra(Button::new().text("返回首页"))
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild(&mut dioxus_core::NoOpMutations);
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains("t-result t-result--404"));
+        assert!(html.contains("t-result__icon-default"));
+        assert!(html.contains("页面不存在"));
+        assert!(html.contains("t-result__extra"));
+        assert!(!html.contains("t-result__subtitle"));
+    }
+}
@@ -0,0 +1,253 @@
+//! RouteProgress 路由进度条组件
+//!
+//! 固定在页面顶部的细进度条：路由切换或登记的异步加载进行中时显示并缓慢前进，全部完成后补满并淡出。
+//!
+//! 进度状态保存在 [`ProgressTracker`] 中，由 RouteProgress 通过上下文提供给子组件。
+//! 子组件可以通过 [`ProgressTracker::start`] 或 [`ProgressTracker::track`] 登记异步加载，
+//! 调用了 [`ServerAction::with_progress`](crate::ServerAction::with_progress) 的提交动作在执行期间也会自动登记。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{ProgressTracker, RouteProgress, Text, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct Report;
+//!
+//! impl ToElement for Report {
+//!     fn to_element(&self) -> Element {
+//!         let tracker = ProgressTracker::current();
+//!         use_future(move || async move {
+//!             if let Some(tracker) = tracker {
+//!                 tracker.track(async { /* 加载报表数据 */ }).await;
+//!             }
+//!         });
+//!         Text::p("报表").to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| RouteProgress::new().children(Report).to_element());
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::future::Future;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::ComponentBase;
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 进度状态
+///
+/// 由 [`RouteProgress`] 通过上下文提供，内部状态都是信号，可以自由复制到异步任务中。
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct ProgressTracker {
+    /// 进行中的异步加载数量
+    pending: Signal<usize>,
+    /// 进度动画的播放次数，变化时重新播放动画
+    runs: Signal<u64>,
+}
+
+impl ProgressTracker {
+    /// 获取上下文中的进度状态，未在 RouteProgress 中使用时返回 `None`
+    pub fn current() -> Option<Self> {
+        try_consume_context::<Self>()
+    }
+
+    /// 开始一次异步加载，返回的守卫被丢弃时加载结束
+    ///
+    /// 异步任务被取消时守卫同样会被丢弃，进度条不会停在加载状态。
+    #[must_use = "守卫被丢弃时加载立即结束"]
+    pub fn start(&self) -> ProgressGuard {
+        let mut pending = self.pending;
+        let mut runs = self.runs;
+        if *pending.peek() == 0 {
+            runs += 1;
+        }
+        pending += 1;
+        ProgressGuard { pending }
+    }
+
+    /// 在异步任务执行期间显示进度条，返回任务的结果
+    pub async fn track<F: Future>(self, future: F) -> F::Output {
+        let _guard = self.start();
+        future.await
+    }
+
+    /// 标记一次路由切换，重新播放进度动画
+    pub fn navigate(&self) {
+        if *self.pending.peek() == 0 {
+            let mut runs = self.runs;
+            runs += 1;
+        }
+    }
+
+    /// 是否有进行中的异步加载
+    pub fn is_loading(&self) -> bool {
+        (self.pending)() > 0
+    }
+}
+
+/// 异步加载守卫，由 [`ProgressTracker::start`] 创建
+#[derive(Debug)]
+pub struct ProgressGuard {
+    pending: Signal<usize>,
+}
+
+impl Drop for ProgressGuard {
+    fn drop(&mut self) {
+        // 组件卸载后信号可能已经失效，此时无需更新
+        if let Ok(mut pending) = self.pending.try_write() {
+            *pending = pending.saturating_sub(1);
+        }
+    }
+}
+
+/// 路由进度条组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct RouteProgress {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+}
+
+impl Default for RouteProgress {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::ROUTE_PROGRESS),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+        }
+    }
+}
+
+impl RouteProgress {
+    /// 创建路由进度条
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+}
+
+impl ToElement for RouteProgress {
+    fn to_element(&self) -> Element {
+        let tracker = use_context_provider(|| ProgressTracker {
+            pending: Signal::new(0),
+            runs: Signal::new(0),
+        });
+
+        // 路由变化时重新播放进度动画，首次渲染不播放
+        let mut last_route = use_signal(|| None::<String>);
+        use_effect(move || {
+            let route = try_router().map(|router| router.full_route_string());
+            let previous = last_route.peek().clone();
+            if previous != route {
+                last_route.set(route);
+                if previous.is_some() {
+                    tracker.navigate();
+                }
+            }
+        });
+
+        let runs = (tracker.runs)();
+        let loading = tracker.is_loading();
+
+        let id = self.id.clone();
+        let state = if loading {
+            classnames::ROUTE_PROGRESS_LOADING
+        } else {
+            classnames::ROUTE_PROGRESS_DONE
+        };
+        let class = format!("{} {}", self.class, state);
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        rsx! {
+            if runs > 0 {
+                div {
+                    // 每次开始播放时重新创建元素，使动画从头开始
+                    key: "{runs}",
+                    id,
+                    class,
+                    style,
+                    role: "progressbar",
+                    aria_label: "页面加载进度",
+                    aria_busy: loading,
+                    onclick: move |event: MouseEvent| {
+                        if let Some(handler) = onclick_handler {
+                            handler.call(event);
+                        }
+                    },
+                    div { class: classnames::ROUTE_PROGRESS_BAR }
+                }
+            }
+            {self.childrens_to_element()}
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Text, View};
+
+    #[derive(Debug, Clone)]
+    struct Loader;
+
+    impl ToElement for Loader {
+        fn to_element(&self) -> Element {
+            let tracker = ProgressTracker::current().unwrap();
+            let mut guard = use_signal(|| None::<ProgressGuard>);
+            View::new()
+                .children(Text::span("开始").onclick(move |_| guard.set(Some(tracker.start()))))
+                .children(Text::span("结束").onclick(move |_| guard.set(None)))
+                .to_element()
+        }
+    }
+
+    #[test]
+    fn test_route_progress_tracks_loads() {
+        fn app() -> Element {
+            RouteProgress::new().children(Loader).to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::ROUTE_PROGRESS).is_none());
+
+        harness.click(&harness.find_by_text("开始").unwrap());
+        assert!(
+            harness
+                .find_by_class(classnames::ROUTE_PROGRESS_LOADING)
+                .is_some()
+        );
+
+        harness.click(&harness.find_by_text("结束").unwrap());
+        assert!(
+            harness
+                .find_by_class(classnames::ROUTE_PROGRESS_LOADING)
+                .is_none()
+        );
+        assert!(
+            harness
+                .find_by_class(classnames::ROUTE_PROGRESS_DONE)
+                .is_some()
+        );
+    }
+}
@@ -0,0 +1,1032 @@
+//! SchemaForm 配置表单组件
+//!
+//! 根据 [`FormSchema`] 在运行时生成完整的 [`Form`]：每个 [`SchemaField`] 描述字段名、标签、控件类型、
+//! 选项、默认值和校验规则，组件为每个字段创建对应的输入控件和 `Signal`，提交时汇总为 [`SchemaFormValues`]。
+//!
+//! [`FormSchema`] 可以通过 serde 反序列化，表单结构可以由服务端下发；也可以在 Rust 中用构建方法创建，
+//! 减少管理后台增删改查页面中重复的表单代码。
+//!
+//! 控件类型与提交的值：
+//!
+//! | 类型 | 控件 | 值 |
+//! | --- | --- | --- |
+//! | `text`、`password`、`textarea` | [`Input`]、[`Textarea`] | [`FormValue::Text`] |
+//! | `number` | [`InputNumber`] | [`FormValue::Text`] |
+//! | `select` | [`Select`] | [`FormValue::Text`] |
+//! | `multi_select` | 多选的 [`Select`] | [`FormValue::List`] |
+//! | `radio` | [`RadioGroup`] | [`FormValue::Text`] |
+//! | `checkbox_group` | [`CheckboxGroup`] | [`FormValue::List`] |
+//! | `checkbox` | 单个 [`Checkbox`] | [`FormValue::Bool`] |
+//! | `time` | [`TimePicker`] | [`FormValue::Text`] |
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{FormSchema, SchemaForm, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let schema: FormSchema = serde_json::from_str(r#"{
+//!     "fields": [
+//!         { "name": "name", "label": "姓名", "required": true, "rules": [{ "min_len": 2 }] },
+//!         {
+//!             "name": "role",
+//!             "label": "角色",
+//!             "type": "select",
+//!             "options": [{ "value": "admin", "label": "管理员" }, { "value": "user", "label": "用户" }],
+//!             "default": "user"
+//!         },
+//!         { "name": "active", "label": "状态", "type": "checkbox", "placeholder": "启用" }
+//!     ]
+//! }"#).unwrap();
+//!
+//! let mut dom = VirtualDom::new_with_props(
+//!     |schema: FormSchema| {
+//!         SchemaForm::new(schema)
+//!             .onsubmit(|values| println!("{values:?}"))
+//!             .to_element()
+//!     },
+//!     schema,
+//! );
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::str::FromStr;
+
+use dioxus::prelude::*;
+use indexmap::IndexMap;
+use rust_decimal::Decimal;
+use serde::{Deserialize, Serialize};
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{
+    Button, Checkbox, CheckboxGroup, CheckboxValue, Form, FormError, FormField, FormItem,
+    FormLabelPosition, FormState, FormValue, Input, InputNumber, InputNumberValue, InputType,
+    Radio, RadioGroup, RadioValue, Rule, Select, SelectOption, SelectValue, Style, Textarea,
+    TimePicker, TimeValue, classnames, traits::ToElement, use_form,
+};
+
+/// 提交的表单数据，按字段在配置中的顺序排列，不包含隐藏的字段
+pub type SchemaFormValues = IndexMap<String, FormValue>;
+
+/// 字段的控件类型
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SchemaFieldType {
+    /// 单行文本输入框
+    #[default]
+    Text,
+    /// 密码输入框
+    Password,
+    /// 多行文本输入框
+    Textarea,
+    /// 数字输入框
+    Number,
+    /// 单选的下拉选择器
+    Select,
+    /// 多选的下拉选择器
+    MultiSelect,
+    /// 单选框组
+    Radio,
+    /// 多选框组
+    CheckboxGroup,
+    /// 单个复选框
+    Checkbox,
+    /// 时间选择器
+    Time,
+}
+
+/// 选择类字段的选项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaOption {
+    /// 选项的值
+    value: String,
+    /// 选项的标签，未设置时显示值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    label: Option<String>,
+    /// 是否禁用
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+}
+
+impl SchemaOption {
+    /// 创建选项
+    pub fn new(value: impl Into<String>) -> Self {
+        Self {
+            value: value.into(),
+            label: None,
+            disabled: false,
+        }
+    }
+
+    /// 设置选项的标签
+    pub fn label(mut self, label: impl Into<String>) -> Self {
+        self.label = Some(label.into());
+        self
+    }
+
+    /// 设置是否禁用
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 显示的标签
+    fn text(&self) -> String {
+        self.label.clone().unwrap_or_else(|| self.value.clone())
+    }
+}
+
+/// 字段的校验规则
+///
+/// 一条规则可以同时设置多项限制，共用同一个错误信息，对应 [`Rule`] 的各个构造方法。
+/// 正则表达式无效时忽略该限制并输出警告。
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct SchemaRule {
+    /// 必填
+    #[serde(skip_serializing_if = "std::ops::Not::not")]
+    required: bool,
+    /// 最少字符数或选择的项数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    min_len: Option<usize>,
+    /// 最多字符数或选择的项数
+    #[serde(skip_serializing_if = "Option::is_none")]
+    max_len: Option<usize>,
+    /// 文本需要匹配的正则表达式
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pattern: Option<String>,
+    /// 校验失败时的错误信息
+    #[serde(skip_serializing_if = "Option::is_none")]
+    message: Option<String>,
+}
+
+impl SchemaRule {
+    /// 必填规则
+    pub fn required() -> Self {
+        Self {
+            required: true,
+            ..Default::default()
+        }
+    }
+
+    /// 最少字符数或选择的项数
+    pub fn min_len(len: usize) -> Self {
+        Self {
+            min_len: Some(len),
+            ..Default::default()
+        }
+    }
+
+    /// 最多字符数或选择的项数
+    pub fn max_len(len: usize) -> Self {
+        Self {
+            max_len: Some(len),
+            ..Default::default()
+        }
+    }
+
+    /// 文本需要匹配的正则表达式
+    pub fn pattern(pattern: impl Into<String>) -> Self {
+        Self {
+            pattern: Some(pattern.into()),
+            ..Default::default()
+        }
+    }
+
+    /// 设置校验失败时的错误信息
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = Some(message.into());
+        self
+    }
+
+    /// 转换为表单的校验规则
+    fn to_rules(&self) -> Vec<Rule> {
+        let mut rules = Vec::new();
+        if self.required {
+            rules.push(Rule::required());
+        }
+        if let Some(len) = self.min_len {
+            rules.push(Rule::min_len(len));
+        }
+        if let Some(len) = self.max_len {
+            rules.push(Rule::max_len(len));
+        }
+        if let Some(pattern) = &self.pattern {
+            match regex::Regex::new(pattern) {
+                Ok(_) => rules.push(Rule::pattern(pattern)),
+                Err(err) => {
+                    dioxus::logger::tracing::warn!("忽略无效的表单规则 `{pattern}`：{err}")
+                }
+            }
+        }
+        match &self.message {
+            Some(message) => rules
+                .into_iter()
+                .map(|rule| rule.message(message))
+                .collect(),
+            None => rules,
+        }
+    }
+}
+
+/// 字段的显示条件：另一个字段的值等于指定的值时显示
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaCondition {
+    /// 依赖的字段名
+    field: String,
+    /// 依赖的字段需要等于的值
+    equals: FormValue,
+}
+
+/// 表单字段的配置
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct SchemaField {
+    /// 字段名，同时作为控件的 id 和提交数据的键
+    name: String,
+    /// 标签文本
+    #[serde(default)]
+    label: String,
+    /// 控件类型
+    #[serde(rename = "type", default)]
+    kind: SchemaFieldType,
+    /// 占位符文本，单个复选框为复选框后的说明文字
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    placeholder: Option<String>,
+    /// 选择类字段的选项
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    options: Vec<SchemaOption>,
+    /// 是否必填
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    required: bool,
+    /// 校验规则
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    rules: Vec<SchemaRule>,
+    /// 默认值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    default: Option<FormValue>,
+    /// 是否禁用
+    #[serde(default, skip_serializing_if = "std::ops::Not::not")]
+    disabled: bool,
+    /// 数字字段的最小值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    min: Option<f64>,
+    /// 数字字段的最大值
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    max: Option<f64>,
+    /// 显示条件
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    visible_when: Option<SchemaCondition>,
+}
+
+impl SchemaField {
+    /// 创建字段，`name` 为字段名，`label` 为标签文本，控件类型默认为单行文本
+    pub fn new(name: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            label: label.into(),
+            kind: SchemaFieldType::default(),
+            placeholder: None,
+            options: Vec::new(),
+            required: false,
+            rules: Vec::new(),
+            default: None,
+            disabled: false,
+            min: None,
+            max: None,
+            visible_when: None,
+        }
+    }
+
+    /// 字段名
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 标签文本
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+
+    /// 设置控件类型
+    pub fn kind(mut self, kind: SchemaFieldType) -> Self {
+        self.kind = kind;
+        self
+    }
+
+    /// 设置占位符文本，单个复选框为复选框后的说明文字
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = Some(placeholder.into());
+        self
+    }
+
+    /// 添加选项，`label` 为选项的标签
+    pub fn option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.options.push(SchemaOption::new(value).label(label));
+        self
+    }
+
+    /// 添加选项列表
+    pub fn options(mut self, options: Vec<SchemaOption>) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    /// 设置是否必填
+    pub fn required(mut self, required: bool) -> Self {
+        self.required = required;
+        self
+    }
+
+    /// 添加校验规则
+    pub fn rule(mut self, rule: SchemaRule) -> Self {
+        self.rules.push(rule);
+        self
+    }
+
+    /// 设置默认值
+    pub fn default_value(mut self, value: impl Into<FormValue>) -> Self {
+        self.default = Some(value.into());
+        self
+    }
+
+    /// 设置是否禁用
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置数字字段的最小值
+    pub fn min(mut self, min: f64) -> Self {
+        self.min = Some(min);
+        self
+    }
+
+    /// 设置数字字段的最大值
+    pub fn max(mut self, max: f64) -> Self {
+        self.max = Some(max);
+        self
+    }
+
+    /// 设置显示条件，字段 `field` 的值等于 `equals` 时显示，见 [`FormItem::visible_when`]
+    pub fn visible_when(mut self, field: impl Into<String>, equals: impl Into<FormValue>) -> Self {
+        self.visible_when = Some(SchemaCondition {
+            field: field.into(),
+            equals: equals.into(),
+        });
+        self
+    }
+
+    /// 创建绑定到 `value` 的表单项，尚未添加控件
+    fn form_item(&self, value: impl FormField) -> FormItem {
+        let mut item = FormItem::new(self.name.clone(), self.label.clone())
+            .value(value)
+            .required(self.required)
+            .rules(self.rules.iter().flat_map(SchemaRule::to_rules).collect());
+        if let Some(SchemaCondition { field, equals }) = self.visible_when.clone() {
+            item = item.visible_when(move |form| form.value(&field).as_ref() == Some(&equals));
+        }
+        item
+    }
+}
+
+/// 表单的配置
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub struct FormSchema {
+    /// 字段列表，按顺序显示
+    #[serde(default)]
+    fields: Vec<SchemaField>,
+}
+
+impl FormSchema {
+    /// 创建空的表单配置
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加字段
+    pub fn field(mut self, field: SchemaField) -> Self {
+        self.fields.push(field);
+        self
+    }
+
+    /// 全部字段
+    pub fn fields(&self) -> &[SchemaField] {
+        &self.fields
+    }
+}
+
+/// 配置表单组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct SchemaForm {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在提交按钮之后，其中的按钮同样会提交表单
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 表单配置
+    schema: FormSchema,
+    /// 字段的初始值，覆盖配置中的默认值
+    values: SchemaFormValues,
+    /// 标签的位置
+    label_position: FormLabelPosition,
+    /// 标签的宽度
+    label_width: String,
+    /// 提交按钮的文本
+    submit_text: String,
+    /// 提交且校验通过时的回调
+    onsubmit: Option<EventHandler<SchemaFormValues>>,
+    /// 提交但校验未通过时的回调
+    oninvalid: Option<EventHandler<Vec<FormError>>>,
+}
+
+impl Default for SchemaForm {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::SCHEMA_FORM),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            schema: FormSchema::default(),
+            values: SchemaFormValues::new(),
+            label_position: FormLabelPosition::default(),
+            label_width: "100px".to_string(),
+            submit_text: "提交".to_string(),
+            onsubmit: None,
+            oninvalid: None,
+        }
+    }
+}
+
+#[builder_props]
+impl SchemaForm {
+    /// 根据表单配置创建表单
+    pub fn new(schema: FormSchema) -> Self {
+        Self {
+            schema,
+            ..Default::default()
+        }
+    }
+
+    /// 设置字段的初始值，覆盖配置中的默认值，如编辑已有数据时传入原来的值
+    ///
+    /// 只在字段首次渲染时读取，之后的修改不会覆盖用户的输入。
+    pub fn values(mut self, values: SchemaFormValues) -> Self {
+        self.values = values;
+        self
+    }
+
+    /// 设置标签的位置
+    #[prop(default = "FormLabelPosition::Right")]
+    pub fn label_position(mut self, position: FormLabelPosition) -> Self {
+        self.label_position = position;
+        self
+    }
+
+    /// 设置标签的宽度
+    #[prop(default = "\"100px\"")]
+    pub fn label_width(mut self, width: impl Into<String>) -> Self {
+        self.label_width = width.into();
+        self
+    }
+
+    /// 设置提交按钮的文本
+    #[prop(default = "\"提交\"")]
+    pub fn submit_text(mut self, text: impl Into<String>) -> Self {
+        self.submit_text = text.into();
+        self
+    }
+
+    /// 设置提交且全部字段校验通过时的回调，参数为各字段的值
+    pub fn onsubmit(mut self, handler: impl FnMut(SchemaFormValues) + 'static) -> Self {
+        self.onsubmit = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置提交但校验未通过时的回调，参数为全部校验错误
+    pub fn oninvalid(mut self, handler: impl FnMut(Vec<FormError>) + 'static) -> Self {
+        self.oninvalid = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for SchemaForm {
+    fn to_element(&self) -> Element {
+        rsx! {
+            SchemaFormElement { form: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct SchemaFormElementProps {
+    form: Rc<SchemaForm>,
+}
+
+impl PartialEq for SchemaFormElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.form, &other.form)
+    }
+}
+
+#[allow(non_snake_case)]
+fn SchemaFormElement(props: SchemaFormElementProps) -> Element {
+    let form = props.form;
+    let state = use_form();
+    let names = form
+        .schema
+        .fields
+        .iter()
+        .map(|field| field.name.clone())
+        .collect::<Vec<_>>();
+    let onsubmit = form.onsubmit;
+    let onclick = form.onclick;
+
+    let mut element = Form::new(state)
+        .class(form.class.clone())
+        .label_position(form.label_position)
+        .label_width(form.label_width.clone())
+        .onsubmit(move |_| {
+            if let Some(handler) = onsubmit {
+                handler.call(collect_values(state, &names));
+            }
+        })
+        .onclick(move |event| {
+            if let Some(handler) = onclick {
+                handler.call(event);
+            }
+        });
+    if let Some(id) = &form.id {
+        element = element.id(id.clone());
+    }
+    if let Some(style) = form.style.clone() {
+        element = element.style(move |_| style.clone());
+    }
+    if let Some(handler) = form.oninvalid {
+        element = element.oninvalid(move |errors| handler.call(errors));
+    }
+    for field in &form.schema.fields {
+        let initial = form
+            .values
+            .get(&field.name)
+            .or(field.default.as_ref())
+            .cloned();
+        element = element.children(SchemaFieldItem {
+            field: Rc::new(field.clone()),
+            initial,
+        });
+    }
+    element
+        .children(SchemaFormActions {
+            submit_text: form.submit_text.clone(),
+            childrens: form.childrens.clone(),
+        })
+        .to_element()
+}
+
+/// 读取已注册字段的值，隐藏的字段没有注册，不包含在结果中
+fn collect_values(state: FormState, names: &[String]) -> SchemaFormValues {
+    names
+        .iter()
+        .filter_map(|name| Some((name.clone(), state.value(name)?)))
+        .collect()
+}
+
+/// 提交按钮和额外的操作
+#[derive(Debug, Clone)]
+struct SchemaFormActions {
+    submit_text: String,
+    childrens: Vec<Rc<dyn ToElement>>,
+}
+
+impl ToElement for SchemaFormActions {
+    fn to_element(&self) -> Element {
+        rsx! {
+            div { class: classnames::SCHEMA_FORM_ACTIONS,
+                {Button::new().text(self.submit_text.clone()).as_primary().to_element()}
+                for children in self.childrens.iter() {
+                    {children.to_element()}
+                }
+            }
+        }
+    }
+}
+
+/// 单个字段，按控件类型渲染不同的组件，类型变化时重新创建字段的状态
+#[derive(Debug, Clone)]
+struct SchemaFieldItem {
+    field: Rc<SchemaField>,
+    initial: Option<FormValue>,
+}
+
+impl ToElement for SchemaFieldItem {
+    fn to_element(&self) -> Element {
+        let field = self.field.clone();
+        let initial = self.initial.clone();
+        match field.kind {
+            SchemaFieldType::Text | SchemaFieldType::Password | SchemaFieldType::Textarea => {
+                rsx! { SchemaFieldElement::<String> { field, initial } }
+            }
+            SchemaFieldType::Number => {
+                rsx! { SchemaFieldElement::<InputNumberValue> { field, initial } }
+            }
+            SchemaFieldType::Select => {
+                rsx! { SchemaFieldElement::<Option<SelectValue>> { field, initial } }
+            }
+            SchemaFieldType::MultiSelect => {
+                rsx! { SchemaFieldElement::<Vec<SelectValue>> { field, initial } }
+            }
+            SchemaFieldType::Radio => {
+                rsx! { SchemaFieldElement::<RadioValue> { field, initial } }
+            }
+            SchemaFieldType::CheckboxGroup => {
+                rsx! { SchemaFieldElement::<Vec<CheckboxValue>> { field, initial } }
+            }
+            SchemaFieldType::Checkbox => {
+                rsx! { SchemaFieldElement::<bool> { field, initial } }
+            }
+            SchemaFieldType::Time => {
+                rsx! { SchemaFieldElement::<Option<TimeValue>> { field, initial } }
+            }
+        }
+    }
+}
+
+/// 字段值的类型，负责从配置的值创建初始值和创建输入控件
+trait SchemaControl: Sized + 'static
+where
+    Signal<Self>: FormField,
+{
+    /// 由配置的默认值或传入的初始值创建
+    fn initial(value: Option<&FormValue>) -> Self;
+
+    /// 为表单项添加绑定到 `value` 的输入控件
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem;
+}
+
+/// 配置中的文本值
+fn text_of(value: Option<&FormValue>) -> String {
+    value.map(FormValue::as_text).unwrap_or_default()
+}
+
+/// 配置中的列表值，文本视为只有一项的列表
+fn list_of(value: Option<&FormValue>) -> Vec<String> {
+    match value {
+        Some(FormValue::List(items)) => items.clone(),
+        Some(FormValue::Text(text)) if !text.is_empty() => vec![text.clone()],
+        _ => Vec::new(),
+    }
+}
+
+impl SchemaControl for String {
+    fn initial(value: Option<&FormValue>) -> Self {
+        text_of(value)
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        let placeholder = field.placeholder.clone().unwrap_or_default();
+        match field.kind {
+            SchemaFieldType::Textarea => item.children(
+                Textarea::new()
+                    .value(value)
+                    .input_id(field.name.clone())
+                    .placeholder(placeholder)
+                    .disabled(field.disabled),
+            ),
+            kind => item.children(
+                Input::new()
+                    .value(value)
+                    .input_id(field.name.clone())
+                    .placeholder(placeholder)
+                    .disabled(field.disabled)
+                    .input_type(match kind {
+                        SchemaFieldType::Password => InputType::Password,
+                        _ => InputType::Text,
+                    }),
+            ),
+        }
+    }
+}
+
+impl SchemaControl for InputNumberValue {
+    fn initial(value: Option<&FormValue>) -> Self {
+        let text = text_of(value);
+        let text = text.trim();
+        match text.parse::<i64>() {
+            Ok(int) => InputNumberValue::Int(int),
+            Err(_) => Decimal::from_str(text)
+                .map(InputNumberValue::Float)
+                .unwrap_or(InputNumberValue::Int(0)),
+        }
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        let mut input = InputNumber::new()
+            .value(value)
+            .input_id(field.name.clone())
+            .disabled(field.disabled);
+        if let Some(placeholder) = &field.placeholder {
+            input = input.placeholder(placeholder.clone());
+        }
+        if let Some(min) = field.min {
+            input = input.min(InputNumberValue::from(min));
+        }
+        if let Some(max) = field.max {
+            input = input.max(InputNumberValue::from(max));
+        }
+        item.children(input)
+    }
+}
+
+/// 下拉选择器的选项
+fn select_options(field: &SchemaField) -> Vec<SelectOption> {
+    field
+        .options
+        .iter()
+        .map(|option| {
+            SelectOption::new(option.value.clone())
+                .label(option.text())
+                .disabled(option.disabled)
+        })
+        .collect()
+}
+
+/// 下拉选择器的公共配置
+fn select(field: &SchemaField) -> Select {
+    let mut select = Select::new()
+        .input_id(field.name.clone())
+        .disabled(field.disabled)
+        .options(select_options(field));
+    if let Some(placeholder) = &field.placeholder {
+        select = select.placeholder(placeholder.clone());
+    }
+    select
+}
+
+impl SchemaControl for Option<SelectValue> {
+    fn initial(value: Option<&FormValue>) -> Self {
+        Some(text_of(value))
+            .filter(|text| !text.is_empty())
+            .map(SelectValue::from)
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        item.children(select(field).value(value))
+    }
+}
+
+impl SchemaControl for Vec<SelectValue> {
+    fn initial(value: Option<&FormValue>) -> Self {
+        list_of(value).into_iter().map(SelectValue::from).collect()
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        item.children(select(field).multiple_value(value))
+    }
+}
+
+impl SchemaControl for RadioValue {
+    fn initial(value: Option<&FormValue>) -> Self {
+        RadioValue::from(text_of(value))
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        item.children(
+            RadioGroup::new()
+                .value(value)
+                .disabled(field.disabled)
+                .radios(
+                    field
+                        .options
+                        .iter()
+                        .map(|option| {
+                            Radio::new()
+                                .value(option.value.clone())
+                                .label(option.text())
+                                .disabled(option.disabled)
+                        })
+                        .collect(),
+                ),
+        )
+    }
+}
+
+impl SchemaControl for Vec<CheckboxValue> {
+    fn initial(value: Option<&FormValue>) -> Self {
+        list_of(value)
+            .into_iter()
+            .map(CheckboxValue::from)
+            .collect()
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        item.children(
+            CheckboxGroup::new()
+                .value(value)
+                .disabled(field.disabled)
+                .checkboxes(
+                    field
+                        .options
+                        .iter()
+                        .map(|option| {
+                            Checkbox::new()
+                                .value(option.value.clone())
+                                .label(option.text())
+                                .disabled(option.disabled)
+                        })
+                        .collect(),
+                ),
+        )
+    }
+}
+
+impl SchemaControl for bool {
+    fn initial(value: Option<&FormValue>) -> Self {
+        match value {
+            Some(FormValue::Bool(on)) => *on,
+            Some(FormValue::Text(text)) => text == "true",
+            _ => false,
+        }
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        item.children(
+            Checkbox::new()
+                .checked(value)
+                .label(field.placeholder.clone().unwrap_or_default())
+                .disabled(field.disabled),
+        )
+    }
+}
+
+impl SchemaControl for Option<TimeValue> {
+    fn initial(value: Option<&FormValue>) -> Self {
+        TimeValue::parse(&text_of(value))
+    }
+
+    fn control(item: FormItem, field: &SchemaField, value: Signal<Self>) -> FormItem {
+        let mut picker = TimePicker::new()
+            .value(value)
+            .input_id(field.name.clone())
+            .disabled(field.disabled);
+        if let Some(placeholder) = &field.placeholder {
+            picker = picker.placeholder(placeholder.clone());
+        }
+        item.children(picker)
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct SchemaFieldElementProps {
+    field: Rc<SchemaField>,
+    initial: Option<FormValue>,
+}
+
+#[allow(non_snake_case)]
+fn SchemaFieldElement<T: SchemaControl>(props: SchemaFieldElementProps) -> Element
+where
+    Signal<T>: FormField,
+{
+    let field = props.field;
+    let initial = props.initial;
+    let value = use_signal(|| T::initial(initial.as_ref()));
+    T::control(field.form_item(value), &field, value).to_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    const SCHEMA: &str = r#"{
+        "fields": [
+            { "name": "name", "label": "姓名", "required": true, "rules": [{ "min_len": 2 }] },
+            {
+                "name": "role",
+                "label": "角色",
+                "type": "radio",
+                "options": [
+                    { "value": "admin", "label": "管理员" },
+                    { "value": "other", "label": "其他" }
+                ],
+                "default": "admin"
+            },
+            {
+                "name": "other_role",
+                "label": "其他角色",
+                "required": true,
+                "visible_when": { "field": "role", "equals": "other" }
+            },
+            { "name": "age", "label": "年龄", "type": "number", "default": "30", "min": 0 },
+            { "name": "tags", "label": "标签", "type": "checkbox_group", "options": [{ "value": "a" }, { "value": "b" }], "default": ["b"] },
+            { "name": "active", "label": "状态", "type": "checkbox", "placeholder": "启用", "default": true }
+        ]
+    }"#;
+
+    #[test]
+    fn test_schema_deserialize() {
+        let schema: FormSchema = serde_json::from_str(SCHEMA).unwrap();
+        assert_eq!(schema.fields().len(), 6);
+        assert_eq!(
+            schema.fields()[1],
+            SchemaField::new("role", "角色")
+                .kind(SchemaFieldType::Radio)
+                .option("admin", "管理员")
+                .option("other", "其他")
+                .default_value("admin")
+        );
+        assert_eq!(
+            schema.fields()[2],
+            SchemaField::new("other_role", "其他角色")
+                .required(true)
+                .visible_when("role", "other")
+        );
+        assert_eq!(
+            schema.fields()[4].default,
+            Some(FormValue::List(vec!["b".to_string()]))
+        );
+        assert_eq!(schema.fields()[5].default, Some(FormValue::Bool(true)));
+
+        // 序列化后可以还原
+        let json = serde_json::to_string(&schema).unwrap();
+        assert_eq!(serde_json::from_str::<FormSchema>(&json).unwrap(), schema);
+
+        let rules = SchemaRule::min_len(2).message("太短").to_rules();
+        assert_eq!(rules.len(), 1);
+        assert_eq!(
+            rules[0].check("姓名", &FormValue::Text("张".into())),
+            Err("太短".to_string())
+        );
+        // 无效的正则表达式被忽略
+        assert!(SchemaRule::pattern("(").to_rules().is_empty());
+    }
+
+    fn submit(harness: &mut Harness, form: &crate::testing::ElementRef) {
+        let data = dioxus_html::SerializedFormData::new(String::new(), Vec::new());
+        harness.dispatch(
+            form,
+            "submit",
+            PlatformEventData::new(Box::new(data)),
+            false,
+        );
+    }
+
+    #[test]
+    fn test_schema_form_submit() {
+        fn app() -> Element {
+            let schema: FormSchema = serde_json::from_str(SCHEMA).unwrap();
+            let mut submitted = use_signal(|| None::<SchemaFormValues>);
+            rsx! {
+                {
+                    SchemaForm::new(schema)
+                        .values(SchemaFormValues::from([("name".to_string(), "张".into())]))
+                        .onsubmit(move |values| submitted.set(Some(values)))
+                        .to_element()
+                }
+                span { class: "submitted", "{submitted():?}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(harness.find_all_by_class(classnames::FORM_ITEM).len(), 5);
+        let input = harness.find_by_attr("id", "name").unwrap();
+        assert_eq!(input.attr("value"), Some("张"));
+
+        let form = harness.find_by_class(classnames::SCHEMA_FORM).unwrap();
+        assert_eq!(form.tag(), "form");
+        submit(&mut harness, &form);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::FORM_ITEM_ERROR)
+                .unwrap()
+                .text(),
+            "姓名至少 2 个字符"
+        );
+        assert_eq!(harness.find_by_class("submitted").unwrap().text(), "None");
+
+        harness.input(&input, "张三");
+        submit(&mut harness, &form);
+        let expected = SchemaFormValues::from([
+            ("name".to_string(), FormValue::from("张三")),
+            ("role".to_string(), FormValue::from("admin")),
+            ("age".to_string(), FormValue::from("30")),
+            ("tags".to_string(), FormValue::List(vec!["b".to_string()])),
+            ("active".to_string(), FormValue::Bool(true)),
+        ]);
+        assert_eq!(
+            harness.find_by_class("submitted").unwrap().text(),
+            format!("{:?}", Some(expected))
+        );
+    }
+}
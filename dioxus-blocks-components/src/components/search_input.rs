@@ -0,0 +1,422 @@
+//! SearchInput 搜索框组件
+//!
+//! 由搜索范围选择、输入框和搜索按钮组成的复合组件，按回车或点击按钮时触发搜索，支持加载状态和最近搜索记录。
+//!
+//! # 最近搜索
+//!
+//! 设置 `history_key` 后，每次搜索的内容会记录到 [`Settings::recent_searches`](crate::Settings) 中，
+//! 输入框获得焦点时在下拉框中列出。记录与其他全局配置保存在一起，应用持久化 [`Settings`](crate::Settings)
+//! 时会一并保存。不在 [`ConfigProvider`](crate::ConfigProvider) 中使用时，记录只保存在组件内部。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{SearchInput, SearchQuery, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let keyword = use_signal(String::new);
+//!     SearchInput::new()
+//!         .value(keyword)
+//!         .scope_option("all", "全部")
+//!         .scope_option("docs", "文档")
+//!         .history_key("global")
+//!         .onsearch(|query: SearchQuery| println!("{:?} {}", query.scope, query.query))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Button, Input, Settings, Style, classnames, traits::ToElement};
+
+/// 搜索条件
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct SearchQuery {
+    /// 搜索内容，已去除首尾空白
+    pub query: String,
+    /// 搜索范围，未设置范围选项时为 `None`
+    pub scope: Option<String>,
+}
+
+/// 搜索框组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct SearchInput {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 搜索内容的 Signal
+    value: Option<Signal<String>>,
+    /// 当前搜索范围的 Signal
+    scope: Option<Signal<String>>,
+    /// 搜索范围选项，元素为 (值, 显示名称)
+    scope_options: Vec<(String, String)>,
+    /// 占位符
+    placeholder: String,
+    /// 搜索按钮文本
+    button_text: String,
+    /// 是否正在搜索
+    loading: bool,
+    /// 是否禁用
+    disabled: bool,
+    /// 最近搜索记录的键，未设置时不记录
+    history_key: Option<String>,
+    /// 最近搜索记录的最大条数
+    history_limit: usize,
+    /// 搜索事件
+    onsearch: Option<EventHandler<SearchQuery>>,
+}
+
+impl Default for SearchInput {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::SEARCH_INPUT),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            scope: None,
+            scope_options: Vec::new(),
+            placeholder: "请输入搜索内容".to_string(),
+            button_text: "搜索".to_string(),
+            loading: false,
+            disabled: false,
+            history_key: None,
+            history_limit: 10,
+            onsearch: None,
+        }
+    }
+}
+
+#[builder_props]
+impl SearchInput {
+    /// 创建搜索框
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置搜索内容的 Signal
+    pub fn value(mut self, value: Signal<String>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置当前搜索范围的 Signal，未设置时默认选中第一个范围
+    pub fn scope(mut self, scope: Signal<String>) -> Self {
+        self.scope = Some(scope);
+        self
+    }
+
+    /// 添加搜索范围选项
+    pub fn scope_option(mut self, value: impl Into<String>, label: impl Into<String>) -> Self {
+        self.scope_options.push((value.into(), label.into()));
+        self
+    }
+
+    /// 设置占位符
+    #[prop(default = "\"请输入搜索内容\"")]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置搜索按钮文本
+    #[prop(default = "\"搜索\"")]
+    pub fn button_text(mut self, text: impl Into<String>) -> Self {
+        self.button_text = text.into();
+        self
+    }
+
+    /// 设置加载状态，加载期间不会重复触发搜索
+    #[prop(default = "false")]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置最近搜索记录的键，多个搜索框使用不同的键可以分别记录
+    pub fn history_key(mut self, key: impl Into<String>) -> Self {
+        self.history_key = Some(key.into());
+        self
+    }
+
+    /// 设置最近搜索记录的最大条数，默认为 10
+    #[prop(default = "10")]
+    pub fn history_limit(mut self, limit: usize) -> Self {
+        self.history_limit = limit;
+        self
+    }
+
+    /// 设置搜索事件
+    pub fn onsearch(mut self, handler: impl FnMut(SearchQuery) + 'static) -> Self {
+        self.onsearch = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+/// 将搜索内容添加到记录开头，已存在的记录会被移到开头
+fn push_recent(recent: &mut Vec<String>, query: &str, limit: usize) {
+    recent.retain(|item| item != query);
+    recent.insert(0, query.to_string());
+    recent.truncate(limit);
+}
+
+impl ToElement for SearchInput {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let inner_value = use_signal(String::new);
+        let mut value = self.value.unwrap_or(inner_value);
+        let first_scope = self.scope_options.first().map(|(v, _)| v.clone());
+        let inner_scope = use_signal(|| first_scope.unwrap_or_default());
+        let mut scope = self.scope.unwrap_or(inner_scope);
+        let local_settings = use_signal(Settings::default);
+        let mut settings = try_consume_context::<Signal<Settings>>().unwrap_or(local_settings);
+        let mut open = use_signal(|| false);
+
+        let has_scope = !self.scope_options.is_empty();
+        let blocked = self.loading || self.disabled;
+        let history_key = self.history_key.clone();
+        let history_limit = self.history_limit;
+        let onsearch = self.onsearch;
+
+        let search_key = history_key.clone();
+        let search = use_callback(move |query: String| {
+            let query = query.trim().to_string();
+            if blocked || query.is_empty() {
+                return;
+            }
+            open.set(false);
+            if let Some(key) = &search_key {
+                let mut settings = settings.write();
+                let recent = settings.recent_searches.entry(key.clone()).or_default();
+                push_recent(recent, &query, history_limit);
+            }
+            if let Some(handler) = onsearch {
+                handler.call(SearchQuery {
+                    query,
+                    scope: has_scope.then(|| scope.peek().clone()),
+                });
+            }
+        });
+
+        // 最近搜索中包含当前输入内容的记录
+        let keyword = value.read().trim().to_lowercase();
+        let recent = history_key
+            .as_ref()
+            .and_then(|key| settings.read().recent_searches.get(key).cloned())
+            .unwrap_or_default()
+            .into_iter()
+            .filter(|item| item.to_lowercase().contains(&keyword))
+            .collect::<Vec<_>>();
+        let show_recent = open() && !self.disabled && !recent.is_empty();
+
+        let input = Input::new()
+            .class(classnames::SEARCH_INPUT_CONTROL)
+            .value(value)
+            .placeholder(self.placeholder.clone())
+            .disabled(self.disabled)
+            .clearable(true)
+            .oninput(move |_| open.set(true))
+            .onfocus(move |_| open.set(true))
+            .onblur(move |_| open.set(false))
+            .onkeydown(move |event: KeyboardEvent| match event.key() {
+                Key::Enter => search.call(value.peek().clone()),
+                Key::Escape => open.set(false),
+                _ => {}
+            });
+        let button = Button::new()
+            .class(classnames::SEARCH_INPUT_BUTTON)
+            .as_primary()
+            .text(self.button_text.clone())
+            .loading(self.loading)
+            .disabled(blocked)
+            .onclick(move |_| search.call(value.peek().clone()));
+
+        let scope_options = self.scope_options.clone();
+        let current_scope = scope.read().clone();
+        let disabled = self.disabled;
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::SEARCH_INPUT_BAR,
+                    if has_scope {
+                        select {
+                            class: classnames::SEARCH_INPUT_SCOPE,
+                            aria_label: "搜索范围",
+                            disabled,
+                            onchange: move |event: Event<FormData>| scope.set(event.value()),
+                            for (option_value, label) in scope_options {
+                                option {
+                                    key: "{option_value}",
+                                    selected: option_value == current_scope,
+                                    value: "{option_value}",
+                                    "{label}"
+                                }
+                            }
+                        }
+                    }
+                    {input.to_element()}
+                    {button.to_element()}
+                }
+                if show_recent {
+                    div { class: classnames::SEARCH_INPUT_DROPDOWN, role: "listbox",
+                        div { class: classnames::SEARCH_INPUT_DROPDOWN_HEADER,
+                            span { "最近搜索" }
+                            span {
+                                class: classnames::SEARCH_INPUT_CLEAR_HISTORY,
+                                // 阻止输入框失去焦点，保证点击事件能够触发
+                                onmousedown: move |event: MouseEvent| event.prevent_default(),
+                                onclick: move |_| {
+                                    if let Some(key) = &history_key {
+                                        settings.write().recent_searches.remove(key);
+                                    }
+                                },
+                                "清空"
+                            }
+                        }
+                        for item in recent {
+                            div {
+                                key: "{item}",
+                                class: classnames::SEARCH_INPUT_RECENT_ITEM,
+                                role: "option",
+                                onmousedown: move |event: MouseEvent| event.prevent_default(),
+                                onclick: {
+                                    let item = item.clone();
+                                    move |_| {
+                                        value.set(item.clone());
+                                        search.call(item.clone());
+                                    }
+                                },
+                                "{item}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ConfigProvider;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_push_recent() {
+        let mut recent = vec!["a".to_string(), "b".to_string(), "c".to_string()];
+        push_recent(&mut recent, "c", 3);
+        assert_eq!(recent, ["c", "a", "b"]);
+        push_recent(&mut recent, "d", 3);
+        assert_eq!(recent, ["d", "c", "a"]);
+    }
+
+    #[test]
+    fn test_search_and_recent() {
+        #[derive(Debug, Clone)]
+        struct Page;
+
+        impl ToElement for Page {
+            fn to_element(&self) -> Element {
+                let mut last = use_signal(|| None::<SearchQuery>);
+                let summary = last
+                    .read()
+                    .as_ref()
+                    .map(|q| format!("{}:{}", q.scope.clone().unwrap_or_default(), q.query))
+                    .unwrap_or_default();
+                rsx! {
+                    {SearchInput::new()
+                        .scope_option("all", "全部")
+                        .scope_option("docs", "文档")
+                        .history_key("global")
+                        .onsearch(move |query| last.set(Some(query)))
+                        .to_element()}
+                    span { class: "last", "{summary}" }
+                }
+            }
+        }
+
+        fn app() -> Element {
+            let settings = use_signal(Settings::default);
+            let saved = settings
+                .read()
+                .recent_searches
+                .get("global")
+                .map(|recent| recent.join(","))
+                .unwrap_or_default();
+            rsx! {
+                {ConfigProvider::new().settings(settings).children(Page).to_element()}
+                span { class: "saved", "{saved}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let scope = harness
+            .find_by_class(classnames::SEARCH_INPUT_SCOPE)
+            .unwrap();
+        harness.change(&scope, "docs");
+
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&inner, " dioxus ");
+        harness.keydown(&inner, "Enter");
+        assert_eq!(harness.find_by_class("last").unwrap().text(), "docs:dioxus");
+        assert_eq!(harness.find_by_class("saved").unwrap().text(), "dioxus");
+
+        let inner = harness.find_by_class(classnames::INPUT_INNER).unwrap();
+        harness.input(&inner, "");
+        let item = harness
+            .find_by_class(classnames::SEARCH_INPUT_RECENT_ITEM)
+            .unwrap();
+        assert_eq!(item.text(), "dioxus");
+
+        let clear = harness
+            .find_by_class(classnames::SEARCH_INPUT_CLEAR_HISTORY)
+            .unwrap();
+        harness.click(&clear);
+        assert!(
+            harness
+                .find_by_class(classnames::SEARCH_INPUT_DROPDOWN)
+                .is_none()
+        );
+        assert_eq!(harness.find_by_class("saved").unwrap().text(), "");
+    }
+}
@@ -1,14 +1,95 @@
 //! Select 选择器组件
 //!
-//! 提供单选和多选下拉选择器组件，支持基础用法、禁用状态、可清空、尺寸筛选选项等功能。
+//! 提供单选和多选下拉选择器组件，支持禁用状态、可清空、可筛选、选项分组、不同尺寸和键盘操作等功能。
+//!
+//! # 组件模式
+//!
+//! Select 是**受控组件**，单选时通过 `Signal<Option<SelectValue>>` 传递值，多选时通过
+//! `Signal<Vec<SelectValue>>` 传递值，选择后组件直接更新 Signal，并触发 change 事件。
+//!
+//! # 键盘操作
+//!
+//! 获得焦点后按上下方向键打开下拉菜单并切换高亮的选项，按 Enter 选择高亮的选项，
+//! 按 Escape 关闭下拉菜单；可筛选的多选选择器在输入框为空时按 Backspace 删除最后一个已选项。
+//!
+//! # 远程搜索
+//!
+//! 通过 [`Select::remote`] 设置选项的 [`DataSource`] 后，选择器自动开启筛选，输入的文字作为查询条件的
+//! 关键字，由数据源返回匹配的选项，适合选项较多、需要在服务端搜索的场景。此时忽略通过构建方法添加的选项。
+//!
+//! # 折叠标签
+//!
+//! 已选项较多时设置 `collapse_tags(true)`，只显示前 `max_collapse_tags` 个标签，其余合并为一个 `+N` 标签，
+//! 鼠标移入 `+N` 标签时以文字提示列出被折叠的选项。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Select, SelectOption, SelectValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let value = use_signal(|| Some(SelectValue::from("beijing")));
+//!     Select::new()
+//!         .value(value)
+//!         .clearable(true)
+//!         .option(SelectOption::new("beijing").label("北京"))
+//!         .option(SelectOption::new("shanghai").label("上海"))
+//!         .option(SelectOption::new("guangzhou").label("广州").disabled(true))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 多选和分组
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Select, SelectOption, SelectOptionGroup, SelectValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let values = use_signal(Vec::<SelectValue>::new);
+//!     Select::new()
+//!         .multiple_value(values)
+//!         .filterable(true)
+//!         .group(
+//!             SelectOptionGroup::new("水果")
+//!                 .option(SelectOption::new("apple").label("苹果"))
+//!                 .option(SelectOption::new("banana").label("香蕉")),
+//!         )
+//!         .group(
+//!             SelectOptionGroup::new("蔬菜")
+//!                 .option(SelectOption::new("carrot").label("胡萝卜")),
+//!         )
+//!         .onchange_multiple(move |values| println!("{values:?}"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{
+    DataQuery, DataSource, SharedDataSource, Style, Text, Tooltip, classnames, traits::ToElement,
+    use_data_source, use_presence, use_unique_id,
+};
 
-use crate::{Style, traits::ToElement};
+/// 下拉菜单离场动画最多等待的毫秒数，略长于样式中 0.15s 的离场动画
+const LEAVE_TIMEOUT: u32 = 250;
+
+/// 远程搜索时每次查询的选项数量
+const REMOTE_PAGE_SIZE: usize = 50;
 
 /// Select 尺寸枚举
 ///
@@ -24,16 +105,28 @@ pub enum SelectSize {
     Large,
 }
 
-impl std::fmt::Display for SelectSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl SelectSize {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            SelectSize::Medium => write!(f, ""),
-            SelectSize::Small => write!(f, "t-select--small"),
-            SelectSize::Large => write!(f, "t-select--large"),
+            SelectSize::Medium => "",
+            SelectSize::Small => "t-select--small",
+            SelectSize::Large => "t-select--large",
         }
     }
 }
 
+impl std::fmt::Display for SelectSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            SelectSize::Medium => "medium",
+            SelectSize::Small => "small",
+            SelectSize::Large => "large",
+        };
+        write!(f, "{s}")
+    }
+}
+
 /// Select 值枚举
 ///
 /// 支持多种类型的值。
@@ -108,7 +201,7 @@ impl std::fmt::Display for SelectValue {
     }
 }
 
-/// SelectOption 选项组件
+/// SelectOption 选项
 #[derive(Debug, Clone, PartialEq)]
 pub struct SelectOption {
     /// 选项的值
@@ -119,8 +212,9 @@ pub struct SelectOption {
     disabled: bool,
 }
 
+#[builder_props]
 impl SelectOption {
-    /// 创建一个新的选项实例
+    /// 创建一个新的选项实例，标签默认为值的文本
     pub fn new(value: impl Into<SelectValue>) -> Self {
         let value = value.into();
         let label = value.to_string();
@@ -138,6 +232,48 @@ impl SelectOption {
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+}
+
+/// SelectOptionGroup 选项分组
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct SelectOptionGroup {
+    /// 分组的标题，未分组的选项为 `None`
+    label: Option<String>,
+    /// 分组中的选项
+    options: Vec<SelectOption>,
+    /// 是否禁用整个分组
+    disabled: bool,
+}
+
+#[builder_props]
+impl SelectOptionGroup {
+    /// 创建一个带标题的选项分组
+    pub fn new(label: impl Into<String>) -> Self {
+        Self {
+            label: Some(label.into()),
+            ..Default::default()
+        }
+    }
+
+    /// 添加选项
+    pub fn option(mut self, option: SelectOption) -> Self {
+        self.options.push(option);
+        self
+    }
+
+    /// 添加选项列表
+    pub fn options(mut self, options: Vec<SelectOption>) -> Self {
+        self.options.extend(options);
+        self
+    }
+
+    /// 设置是否禁用分组中的全部选项
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
@@ -150,7 +286,7 @@ pub struct Select {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -158,12 +294,12 @@ pub struct Select {
     /// 组件的点击事件
     onclick: Option<EventHandler<MouseEvent>>,
 
-    /// 当前值（受控状态）
-    value: Option<SelectValue>,
-    /// 当前多选值（受控状态）
+    /// 当前值（单选，受控状态）
+    value: Option<Signal<Option<SelectValue>>>,
+    /// 当前值（多选，受控状态）
     multiple_value: Option<Signal<Vec<SelectValue>>>,
-    /// 选项列表
-    options: Vec<SelectOption>,
+    /// 选项分组，未分组的选项放在没有标题的分组中
+    groups: Vec<SelectOptionGroup>,
     /// 是否多选
     multiple: bool,
     /// 是否禁用
@@ -174,40 +310,62 @@ pub struct Select {
     clearable: bool,
     /// 是否可筛选
     filterable: bool,
+    /// 多选时是否折叠超出数量的标签
+    collapse_tags: bool,
+    /// 折叠标签时最多显示的标签数量
+    max_collapse_tags: usize,
     /// 占位符文本
     placeholder: String,
+    /// 获得焦点的元素的 id，用于关联表单标签
+    input_id: Option<String>,
     /// 值改变时的回调（单选）
-    onchange: Option<EventHandler<SelectValue>>,
+    onchange: Option<EventHandler<Option<SelectValue>>>,
     /// 值改变时的回调（多选）
     onchange_multiple: Option<EventHandler<Vec<SelectValue>>>,
     /// 清空时的回调
     onclear: Option<EventHandler<MouseEvent>>,
+    /// 远程搜索的数据源
+    remote: Option<SharedDataSource<SelectOption>>,
+    /// 远程搜索时的输入内容，由远程搜索的外层组件设置
+    search: Option<Signal<String>>,
+    /// 远程搜索是否正在查询
+    loading: bool,
+    /// 远程搜索查询到过的选项，已选的值不在当前结果中时用于显示标签
+    known: Vec<SelectOption>,
 }
 
 impl Default for Select {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-select".to_string(),
+            class: Cow::Borrowed(classnames::SELECT),
             style: None,
             childrens: Vec::new(),
             onclick: None,
             value: None,
             multiple_value: None,
-            options: Vec::new(),
+            groups: Vec::new(),
             multiple: false,
             disabled: false,
             size: SelectSize::Medium,
             clearable: false,
             filterable: false,
-            placeholder: "Select".to_string(),
+            collapse_tags: false,
+            max_collapse_tags: 1,
+            placeholder: "请选择".to_string(),
+            input_id: None,
             onchange: None,
             onchange_multiple: None,
             onclear: None,
+            remote: None,
+            search: None,
+            loading: false,
+            known: Vec::new(),
         }
     }
 }
 
+#[builder_props]
 impl Select {
     /// 创建一个新的选择器实例
     pub fn new() -> Self {
@@ -216,74 +374,114 @@ impl Select {
         }
     }
 
-    /// 设置当前值（单选）
-    pub fn value(mut self, value: impl Into<SelectValue>) -> Self {
-        self.value = Some(value.into());
+    /// 设置当前值的 Signal（单选）
+    pub fn value(mut self, value: Signal<Option<SelectValue>>) -> Self {
+        self.value = Some(value);
         self
     }
 
-    /// 设置当前值（多选）
+    /// 设置当前值的 Signal（多选），同时开启多选
     pub fn multiple_value(mut self, value: Signal<Vec<SelectValue>>) -> Self {
         self.multiple_value = Some(value);
+        self.multiple = true;
         self
     }
 
     /// 添加选项
     pub fn option(mut self, option: SelectOption) -> Self {
-        self.options.push(option);
+        match self.groups.last_mut() {
+            Some(group) if group.label.is_none() => group.options.push(option),
+            _ => self.groups.push(SelectOptionGroup {
+                options: vec![option],
+                ..Default::default()
+            }),
+        }
         self
     }
 
     /// 添加选项列表
-    pub fn options(mut self, options: Vec<SelectOption>) -> Self {
-        self.options = options;
+    pub fn options(self, options: Vec<SelectOption>) -> Self {
+        options.into_iter().fold(self, Self::option)
+    }
+
+    /// 添加选项分组
+    pub fn group(mut self, group: SelectOptionGroup) -> Self {
+        self.groups.push(group);
         self
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置选择器尺寸
+    #[prop(default = "SelectSize::Medium")]
     pub fn size(mut self, size: SelectSize) -> Self {
         self.size = size;
         self
     }
 
     /// 设置是否可清空
+    #[prop(default = "false")]
     pub fn clearable(mut self, clearable: bool) -> Self {
         self.clearable = clearable;
         self
     }
 
-    /// 设置是否可筛选
+    /// 设置是否可筛选，开启后可以输入关键字按标签筛选选项
+    #[prop(default = "false")]
     pub fn filterable(mut self, filterable: bool) -> Self {
         self.filterable = filterable;
         self
     }
 
+    /// 设置多选时是否折叠标签，超出 `max_collapse_tags` 的已选项合并为一个 `+N` 标签，
+    /// 鼠标移入该标签时提示被折叠的选项
+    #[prop(default = "false")]
+    pub fn collapse_tags(mut self, collapse_tags: bool) -> Self {
+        self.collapse_tags = collapse_tags;
+        self
+    }
+
+    /// 设置折叠标签时最多显示的标签数量，至少显示一个
+    #[prop(default = "1")]
+    pub fn max_collapse_tags(mut self, max_collapse_tags: usize) -> Self {
+        self.max_collapse_tags = max_collapse_tags.max(1);
+        self
+    }
+
     /// 设置占位符文本
+    #[prop(default = "\"请选择\"")]
     pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
         self.placeholder = placeholder.into();
         self
     }
 
-    /// 设置是否多选
+    /// 设置是否多选，未设置多选值的 Signal 时由组件内部保存
+    #[prop(default = "false")]
     pub fn multiple(mut self, multiple: bool) -> Self {
         self.multiple = multiple;
         self
     }
 
-    /// 设置值改变事件（单选）
-    pub fn onchange(mut self, handler: impl FnMut(SelectValue) + 'static) -> Self {
+    /// 设置获得焦点的元素的 id，用于关联 label 的 for 属性
+    pub fn input_id(mut self, input_id: impl Into<String>) -> Self {
+        self.input_id = Some(input_id.into());
+        self
+    }
+
+    /// 设置值改变事件（单选），清空时为 `None`
+    pub fn onchange(mut self, handler: impl FnMut(Option<SelectValue>) + 'static) -> Self {
         self.onchange = Some(EventHandler::new(handler));
         self
     }
 
     /// 设置值改变事件（单选）
-    pub fn onchange2(mut self, handler: EventHandler<SelectValue>) -> Self {
+    #[prop(skip)]
+    pub fn onchange2(mut self, handler: EventHandler<Option<SelectValue>>) -> Self {
         self.onchange = Some(handler);
         self
     }
@@ -295,6 +493,7 @@ impl Select {
     }
 
     /// 设置值改变事件（多选）
+    #[prop(skip)]
     pub fn onchange_multiple2(mut self, handler: EventHandler<Vec<SelectValue>>) -> Self {
         self.onchange_multiple = Some(handler);
         self
@@ -307,10 +506,17 @@ impl Select {
     }
 
     /// 设置清空事件
+    #[prop(skip)]
     pub fn onclear2(mut self, handler: EventHandler<MouseEvent>) -> Self {
         self.onclear = Some(handler);
         self
     }
+
+    /// 设置远程搜索的数据源，开启筛选，输入的文字作为查询条件的关键字，忽略通过构建方法添加的选项
+    pub fn remote(mut self, source: impl DataSource<SelectOption>) -> Self {
+        self.remote = Some(SharedDataSource::new(source));
+        self
+    }
 }
 
 /// 便捷方法
@@ -334,285 +540,794 @@ impl Select {
     }
 }
 
+/// 在可用的选项之间移动高亮，到达一端后从另一端继续
+fn move_active(enabled: &[usize], current: Option<usize>, forward: bool) -> Option<usize> {
+    let position = current.and_then(|current| enabled.iter().position(|&i| i == current));
+    let next = match (position, forward) {
+        (_, _) if enabled.is_empty() => return None,
+        (None, true) => 0,
+        (None, false) => enabled.len() - 1,
+        (Some(position), true) => (position + 1) % enabled.len(),
+        (Some(position), false) => (position + enabled.len() - 1) % enabled.len(),
+    };
+    Some(enabled[next])
+}
+
+/// 筛选后显示的选项
+#[derive(Clone)]
+struct VisibleOption {
+    index: usize,
+    value: SelectValue,
+    label: String,
+    disabled: bool,
+    selected: bool,
+}
+
 impl ToElement for Select {
     fn to_element(&self) -> Element {
-        let mut is_opened = use_signal(|| false);
-        let mut filter_text = use_signal(|| String::new());
-        let current_value = use_signal(|| self.value.clone());
-
-        // 克隆闭包需要的所有数据
-        let class = self.class.clone();
-        let size = self.size;
-        let style = self.style.clone();
-        let disabled = self.disabled;
+        if self.remote.is_some() {
+            return rsx! {
+                RemoteSelectElement { select: Rc::new(self.clone()) }
+            };
+        }
+
+        let uid = use_unique_id("select");
+        let inner_value = use_signal(|| None::<SelectValue>);
+        let inner_values = use_signal(Vec::<SelectValue>::new);
+        let mut value = self.value.unwrap_or(inner_value);
+        let mut values = self.multiple_value.unwrap_or(inner_values);
+        let mut open = use_signal(|| false);
+        let inner_query = use_signal(String::new);
+        let mut query = self.search.unwrap_or(inner_query);
+        let mut active = use_signal(|| None::<usize>);
+
         let multiple = self.multiple;
-        let multiple_value = self.multiple_value.clone();
-        let clearable = self.clearable;
+        let disabled = self.disabled;
         let filterable = self.filterable;
-        let placeholder = self.placeholder.clone();
-        let onchange = self.onchange.clone();
-        let onchange_multiple = self.onchange_multiple.clone();
-        let onclear = self.onclear.clone();
-        let options = self.options.clone();
-
-        let display_class = if multiple {
-            "is-multiple"
-        } else if disabled {
-            "is-disabled"
+        let onchange = self.onchange;
+        let onchange_multiple = self.onchange_multiple;
+        let onclear = self.onclear;
+        let onclick_handler = self.onclick;
+
+        // 已选的值和标签
+        let label_of = |target: &SelectValue| {
+            self.groups
+                .iter()
+                .flat_map(|group| group.options.iter())
+                .chain(self.known.iter())
+                .find(|option| option.value == *target)
+                .map(|option| option.label.clone())
+                .unwrap_or_else(|| target.to_string())
+        };
+        let selected = if multiple {
+            values.read().clone()
         } else {
-            ""
+            value.read().iter().cloned().collect()
+        };
+        let selected_labels = selected
+            .iter()
+            .map(|v| (v.clone(), label_of(v)))
+            .collect::<Vec<_>>();
+
+        // 按关键字筛选选项，空的分组不显示；远程搜索的选项已经由数据源筛选
+        let keyword = match filterable {
+            true => query.read().trim().to_lowercase(),
+            false => String::new(),
+        };
+        let local_keyword = match self.search {
+            Some(_) => "",
+            None => keyword.as_str(),
         };
+        let mut visible = Vec::<VisibleOption>::new();
+        let mut groups = Vec::<(Option<String>, Vec<VisibleOption>)>::new();
+        for group in &self.groups {
+            let options = group
+                .options
+                .iter()
+                .filter(|option| option.label.to_lowercase().contains(local_keyword))
+                .map(|option| {
+                    let item = VisibleOption {
+                        index: visible.len(),
+                        value: option.value.clone(),
+                        label: option.label.clone(),
+                        disabled: group.disabled || option.disabled,
+                        selected: selected.contains(&option.value),
+                    };
+                    visible.push(item.clone());
+                    item
+                })
+                .collect::<Vec<_>>();
+            if !options.is_empty() {
+                groups.push((group.label.clone(), options));
+            }
+        }
+        let enabled = visible
+            .iter()
+            .filter(|option| !option.disabled)
+            .map(|option| option.index)
+            .collect::<Vec<_>>();
+        let first_selected = visible
+            .iter()
+            .find(|option| option.selected && !option.disabled)
+            .map(|option| option.index);
+        // 筛选后高亮的选项可能已经不存在
+        let current = active().filter(|index| enabled.contains(index));
 
-        // 计算显示的标签
-        let get_selected_label = {
-            let options = options.clone();
-            let placeholder = placeholder.clone();
-            let current_value = current_value.clone();
-            move || {
-                let current_value = current_value.read();
-                if let Some(value) = current_value.as_ref() {
-                    options
-                        .iter()
-                        .find(|opt| &opt.value == value)
-                        .map(|opt| opt.label.clone())
-                        .unwrap_or_else(|| placeholder.clone())
-                } else {
-                    placeholder.clone()
+        let choose = use_callback(move |picked: SelectValue| {
+            query.set(String::new());
+            if multiple {
+                let mut next = values.peek().clone();
+                match next.iter().position(|v| *v == picked) {
+                    Some(position) => {
+                        next.remove(position);
+                    }
+                    None => next.push(picked),
+                }
+                values.set(next.clone());
+                if let Some(handler) = onchange_multiple {
+                    handler.call(next);
+                }
+            } else {
+                value.set(Some(picked.clone()));
+                open.set(false);
+                if let Some(handler) = onchange {
+                    handler.call(Some(picked));
                 }
             }
-        };
+        });
+        let remove = use_callback(move |removed: SelectValue| {
+            let next = values
+                .peek()
+                .iter()
+                .filter(|v| **v != removed)
+                .cloned()
+                .collect::<Vec<_>>();
+            values.set(next.clone());
+            if let Some(handler) = onchange_multiple {
+                handler.call(next);
+            }
+        });
 
-        // 判断是否显示清空按钮
-        let get_show_clear = {
-            let clearable = clearable;
-            let disabled = disabled;
-            let is_opened = is_opened.clone();
-            let current_value = current_value.clone();
-            move || {
-                let current_value = current_value.read();
-                clearable
-                    && current_value.is_some()
-                    && !disabled
-                    && !is_opened()
+        // 键盘切换高亮的选项后滚动到可见区域
+        let mut scroll_pending = use_signal(|| false);
+        let listbox_id = format!("{uid}-listbox");
+        let scroll_uid = uid.clone();
+        use_effect(move || {
+            let Some(index) = active() else {
+                return;
+            };
+            if !*scroll_pending.peek() || cfg!(feature = "server") {
+                return;
             }
+            scroll_pending.set(false);
+            document::eval(&format!(
+                "document.getElementById('{}')?.scrollIntoView({{ block: 'nearest' }});",
+                option_id(&scroll_uid, index)
+            ));
+        });
+
+        let visible_values = visible
+            .iter()
+            .map(|option| option.value.clone())
+            .collect::<Vec<_>>();
+        let last_selected = selected.last().cloned();
+        let onkeydown = move |event: KeyboardEvent| {
+            if disabled {
+                return;
+            }
+            match event.key() {
+                Key::ArrowDown | Key::ArrowUp => {
+                    event.prevent_default();
+                    let next = if open() {
+                        move_active(&enabled, current, event.key() == Key::ArrowDown)
+                    } else {
+                        open.set(true);
+                        first_selected.or_else(|| move_active(&enabled, None, true))
+                    };
+                    active.set(next);
+                    scroll_pending.set(true);
+                }
+                Key::Enter => {
+                    event.prevent_default();
+                    if !open() {
+                        open.set(true);
+                        active.set(first_selected);
+                    } else if let Some(picked) = current.and_then(|i| visible_values.get(i)) {
+                        choose.call(picked.clone());
+                    }
+                }
+                Key::Character(ref c) if c == " " && !filterable && !open() => {
+                    event.prevent_default();
+                    open.set(true);
+                    active.set(first_selected);
+                }
+                Key::Escape if open() => {
+                    open.set(false);
+                    query.set(String::new());
+                }
+                Key::Backspace if multiple && filterable && query.peek().is_empty() => {
+                    if let Some(last) = &last_selected {
+                        remove.call(last.clone());
+                    }
+                }
+                _ => {}
+            }
+        };
+
+        let mut class = vec![self.class.to_string()];
+        let size_class = self.size.as_class();
+        if !size_class.is_empty() {
+            class.push(size_class.to_string());
+        }
+        if multiple {
+            class.push("is-multiple".to_string());
+        }
+        if disabled {
+            class.push("is-disabled".to_string());
+        }
+        if open() {
+            class.push("is-open".to_string());
+        }
+        let class = class.join(" ");
+        let style = self.style.clone().map(|s| s.to_string());
+        let is_open = open() && !disabled;
+        // 关闭后保持挂载直到下拉菜单的离场动画结束
+        let dropdown = use_presence(is_open, LEAVE_TIMEOUT);
+        let dropdown_class = match dropdown.is_leaving() {
+            true => format!("{} is-leaving", classnames::SELECT_DROPDOWN),
+            false => classnames::SELECT_DROPDOWN.to_string(),
+        };
+        let has_value = !selected.is_empty();
+        let show_clear = self.clearable && has_value && !disabled;
+        let single_label = (!multiple)
+            .then(|| selected_labels.first().map(|(_, label)| label.clone()))
+            .flatten();
+        // 折叠后只显示前几个标签，其余的合并为 `+N` 标签
+        let shown_tags = match self.collapse_tags {
+            true => selected_labels.len().min(self.max_collapse_tags),
+            false => selected_labels.len(),
+        };
+        let collapsed_tag = (shown_tags < selected_labels.len()).then(|| {
+            let hidden = &selected_labels[shown_tags..];
+            let labels = hidden
+                .iter()
+                .map(|(_, label)| label.as_str())
+                .collect::<Vec<_>>()
+                .join("、");
+            Tooltip::new(labels)
+                .class(classnames::SELECT_COLLAPSE_TOOLTIP)
+                .children(Text::span(format!("+{}", hidden.len())).class(format!(
+                    "{} {}",
+                    classnames::SELECT_TAG,
+                    classnames::SELECT_TAG_COLLAPSE
+                )))
+                .to_element()
+        });
+        let placeholder = self.placeholder.clone();
+        let input_id = self.input_id.clone();
+        let activedescendant = current
+            .filter(|_| is_open)
+            .map(|index| option_id(&uid, index));
+        let empty_text = match (self.loading, keyword.is_empty()) {
+            (true, _) => "加载中",
+            (false, true) => "暂无数据",
+            (false, false) => "无匹配数据",
         };
 
+        // 可筛选时由输入框获得焦点，否则由选择框获得焦点
+        let combobox_input = filterable.then(|| {
+            let input_value = match (is_open, &single_label) {
+                (true, _) => query(),
+                (false, Some(label)) => label.clone(),
+                (false, None) => String::new(),
+            };
+            let input_placeholder = match (&single_label, multiple && has_value) {
+                (_, true) => String::new(),
+                (Some(label), _) => label.clone(),
+                (None, _) => placeholder.clone(),
+            };
+            rsx! {
+                input {
+                    id: input_id.clone(),
+                    class: classnames::SELECT_INPUT,
+                    role: "combobox",
+                    autocomplete: "off",
+                    disabled,
+                    value: input_value,
+                    placeholder: input_placeholder,
+                    aria_expanded: "{is_open}",
+                    aria_controls: listbox_id.clone(),
+                    aria_autocomplete: "list",
+                    aria_activedescendant: activedescendant.clone(),
+                    oninput: move |event: FormEvent| {
+                        query.set(event.value());
+                        open.set(true);
+                        active.set(None);
+                    },
+                }
+            }
+        });
+
         rsx! {
             div {
-                class: format_args!("{} {} {}", class, size, display_class),
-                style: style.as_ref().map(|s| s.to_string()),
-                onclick: move |_e: Event<MouseData>| {
-                    if !disabled {
-                        is_opened.set(!is_opened());
+                id: self.id.clone(),
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
                     }
                 },
-
-                // 选择器输入区域
+                onfocusout: move |_| {
+                    open.set(false);
+                    query.set(String::new());
+                },
+                onkeydown,
                 div {
-                    class: "t-select__wrapper",
-                    style: if disabled { "cursor: not-allowed;" } else { "" },
-
-                    // 输入框
-                    div { class: "t-select__input",
-                        if filterable && is_opened() && !disabled {
-                            input {
-                                value: "{filter_text}",
-                                placeholder: placeholder.clone(),
-                                oninput: move |e: Event<FormData>| {
-                                    filter_text.set(e.value());
-                                },
-                                onclick: move |e: Event<MouseData>| {
-                                    e.stop_propagation();
-                                },
-                            }
-                        } else if multiple && multiple_value.is_some() {
-                            // 多选标签显示
-                            span {
-                                {
-                                    if let Some(mv) = &multiple_value {
-                                        let options = options.clone();
-                                        mv.read()
-                                            .iter()
-                                            .filter_map(|v| {
-                                                options
-                                                    .iter()
-                                                    .find(|opt| &opt.value == v)
-                                                    .map(|opt| opt.label.clone())
-                                            }
-                                            .collect::<Vec<_>>()
-                                            .join(", ")
-                                    } else {
-                                        String::new()
-                                    }
-                                }
-                            }
-                        } else {
-                            // 单选显示
-                            span { "{get_selected_label()}" }
+                    id: if !filterable { input_id.clone() },
+                    class: classnames::SELECT_WRAPPER,
+                    tabindex: if !filterable && !disabled { "0" },
+                    role: if !filterable { "combobox" },
+                    aria_haspopup: "listbox",
+                    aria_expanded: if !filterable { "{is_open}" },
+                    aria_controls: if !filterable { listbox_id.clone() },
+                    aria_disabled: disabled.then_some("true"),
+                    aria_activedescendant: if !filterable { activedescendant.clone() },
+                    onclick: move |_| {
+                        if disabled || (filterable && open()) {
+                            return;
                         }
-                    }
-
-                    // 清空按钮
-                    {
-                        let onclear = onclear.clone();
-                        let onchange = onchange.clone();
-                        let mut filter_text = filter_text.clone();
-                        let mut current_value = current_value.clone();
-                        let get_show_clear = get_show_clear.clone();
-
-                        if get_show_clear() {
-                            rsx! {
-                                span {
-                                    class: "t-select__clear",
-                                    onclick: move |e: Event<MouseData>| {
-                                        e.stop_propagation();
-                                        if let Some(ref handler) = onclear {
-                                            handler.call(e.clone());
-                                        }
-                                        if let Some(ref handler) = onchange {
-                                            handler.call(SelectValue::String(String::new()));
+                        let next = !open();
+                        open.set(next);
+                        active.set(first_selected);
+                    },
+                    div { class: classnames::SELECT_SELECTION,
+                        if multiple {
+                            for (tag_value , label) in selected_labels.iter().take(shown_tags).cloned() {
+                                span { key: "{tag_value}", class: classnames::SELECT_TAG,
+                                    span { class: classnames::SELECT_TAG_LABEL, "{label}" }
+                                    if !disabled {
+                                        span {
+                                            class: classnames::SELECT_TAG_CLOSE,
+                                            aria_label: "移除 {label}",
+                                            onmousedown: move |event: MouseEvent| event.prevent_default(),
+                                            onclick: move |event: MouseEvent| {
+                                                event.stop_propagation();
+                                                remove.call(tag_value.clone());
+                                            },
+                                            "×"
                                         }
-                                        current_value.set(None);
-                                        filter_text.set(String::new());
-                                    },
-                                    "×"
+                                    }
                                 }
                             }
-                        } else {
-                            rsx! {}
+                            {collapsed_tag}
+                        }
+                        if let Some(input) = combobox_input {
+                            {input}
+                        } else if let Some(label) = &single_label {
+                            span { class: classnames::SELECT_LABEL, "{label}" }
+                        } else if !has_value {
+                            span { class: classnames::SELECT_PLACEHOLDER, "{placeholder}" }
                         }
                     }
-
-                    // 下拉箭头
-                    span {
-                        class: "t-select__arrow",
-                        style: if is_opened() { "transform: translateY(-50%) rotate(180deg);" } else { "" },
-                        if is_opened() {
-                            "▲"
-                        } else {
-                            "▼"
+                    if show_clear {
+                        span {
+                            class: classnames::SELECT_CLEAR,
+                            aria_label: "清空",
+                            onmousedown: move |event: MouseEvent| event.prevent_default(),
+                            onclick: move |event: MouseEvent| {
+                                event.stop_propagation();
+                                query.set(String::new());
+                                if multiple {
+                                    values.set(Vec::new());
+                                    if let Some(handler) = onchange_multiple {
+                                        handler.call(Vec::new());
+                                    }
+                                } else {
+                                    value.set(None);
+                                    if let Some(handler) = onchange {
+                                        handler.call(None);
+                                    }
+                                }
+                                if let Some(handler) = onclear {
+                                    handler.call(event);
+                                }
+                            },
+                            "×"
                         }
                     }
+                    span { class: classnames::SELECT_ARROW, aria_hidden: "true", "▾" }
                 }
-
-                // 下拉菜单
-                {
-                    let dropdown_options = options.clone();
-                    let dropdown_multiple = multiple;
-                    let dropdown_multiple_value = multiple_value.clone();
-                    let dropdown_onchange = onchange.clone();
-                    let dropdown_onchange_multiple = onchange_multiple.clone();
-                    let mut dropdown_is_opened = is_opened.clone();
-                    let dropdown_current_value = current_value.clone();
-                    let dropdown_filter_text = filter_text.clone();
-                    let dropdown_filterable = filterable;
-                    let dropdown_disabled = disabled;
-
-                    if is_opened() && !dropdown_disabled {
-                        rsx! {
-                            div {
-                                class: "t-select__dropdown",
-                                onclick: move |e: Event<MouseData>| {
-                                    e.stop_propagation();
-                                },
-                                onblur: move |_| {
-                                    dropdown_is_opened.set(false);
-                                },
-
-                                if dropdown_filterable && !dropdown_filter_text().is_empty()
-                                    && dropdown_options
-                                        .iter()
-                                        .all(|opt| {
-                                            !opt
-                                                .label
-                                                .to_lowercase()
-                                                .contains(&dropdown_filter_text().to_lowercase())
-                                        })
-                                {
-                                    div { class: "t-select__empty", "暂无数据" }
-                                } else {
-                                    {
-                                        dropdown_options
-                                            .iter()
-                                            .map(|option| {
-                                                let is_hidden = dropdown_filterable && !dropdown_filter_text().is_empty()
-                                                    && !option
-                                                        .label
-                                                        .to_lowercase()
-                                                        .contains(&dropdown_filter_text().to_lowercase());
-                                                let is_selected = if dropdown_multiple {
-                                                    dropdown_multiple_value
-                                                        .as_ref()
-                                                        .map(|mv| { mv.read().contains(&option.value) })
-                                                        .unwrap_or(false)
-                                                } else {
-                                                    dropdown_current_value.read().as_ref() == Some(&option.value)
-                                                }
-                                                mv.set(values.clone());
-                                                let option_value = option.value.clone();
-                                                let option_label = option.label.clone();
-                                                let option_disabled = option.disabled;
-                                                let option_onchange = dropdown_onchange.clone();
-                                                let option_onchange_multiple = dropdown_onchange_multiple.clone();
-                                                let mut option_is_opened = dropdown_is_opened.clone();
-                                                let mut option_current_value = dropdown_current_value.clone();
-                                                let option_is_multiple = dropdown_multiple;
-                                                let option_multiple_value = dropdown_multiple_value.clone();
-                                                if is_hidden {
-                                                    rsx! {}
-                                                }
-                                                }
-                                                    rsx! {
-                                                        div {
-                                                            class: if option_disabled { "t-select__option is-disabled" } else if is_selected { "t-select__option is-selected" } else { "t-select__option" },
-                                                            onclick: move |e: Event<MouseData>| {
-                                                                e.stop_propagation();
-                                                                if !option_disabled {
-                                                                    if option_is_multiple {
-                                                                        if let Some(mut mv) = option_multiple_value {
-                                                                            let mut values = mv.read().clone();
-                                                                            if let Some(pos) = values.iter().position(|v| v == &option_value) {
-                                                                                values.remove(pos);
-                                                                            } else {
-                                                                                values.push(option_value.clone());
-                                                                            }
-                                                                            mv.set(values.clone());
-                                                                            if let Some(ref handler) = option_onchange_multiple {
-                                                                                handler.call(values);
-                                                                            }
-                                                                        }
-                                                                    } else {
-                                                                        option_current_value.set(Some(option_value.clone()));
-                                                                        option_is_opened.set(false);
-                                                                        if let Some(ref handler) = option_onchange {
-                                                                            handler.call(option_value.clone());
-                                                                        }
-                                                                    }
-                                                                }
-                                                            },
-                                                            {option_label}
-                                                        }
-                                                    }
-                                                }
-                                            })
+                if dropdown.is_present() {
+                    div {
+                        id: listbox_id.clone(),
+                        class: dropdown_class,
+                        onanimationend: move |_| dropdown.finish(),
+                        role: "listbox",
+                        aria_multiselectable: multiple.then_some("true"),
+                        // 阻止选择框失去焦点，保证点击事件能够触发
+                        onmousedown: move |event: MouseEvent| event.prevent_default(),
+                        if visible.is_empty() {
+                            div { class: classnames::SELECT_EMPTY, "{empty_text}" }
+                        }
+                        for (label , options) in groups {
+                            if let Some(label) = label {
+                                div {
+                                    class: classnames::SELECT_GROUP,
+                                    role: "group",
+                                    aria_label: label.clone(),
+                                    div { class: classnames::SELECT_GROUP_TITLE, "{label}" }
+                                    for option in options {
+                                        {render_option(option, &uid, current, choose, active)}
                                     }
                                 }
+                            } else {
+                                for option in options {
+                                    {render_option(option, &uid, current, choose, active)}
+                                }
                             }
                         }
-                    } else {
-                        rsx! {}
                     }
                 }
+            }
+        }
+    }
+}
 
-                // 点击外部关闭下拉菜单
-                div {
-                    class: "t-select__backdrop",
-                    style: if is_opened() { "position: fixed; top: 0; left: 0; right: 0; bottom: 0; z-index: 1999;" } else { "display: none;" },
-                    onclick: move |_| {
-                        is_opened.set(false);
-                    },
+#[derive(Props, Clone)]
+struct RemoteSelectElementProps {
+    select: Rc<Select>,
+}
+
+impl PartialEq for RemoteSelectElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.select, &other.select)
+    }
+}
+
+/// 远程搜索的选择器，按输入的文字从数据源查询选项
+#[allow(non_snake_case)]
+fn RemoteSelectElement(props: RemoteSelectElementProps) -> Element {
+    let mut select = (*props.select).clone();
+    let Some(source) = select.remote.take() else {
+        return select.to_element();
+    };
+    let search = use_signal(String::new);
+    let resource = use_data_source(source, move || {
+        DataQuery::new()
+            .page_size(REMOTE_PAGE_SIZE)
+            .keyword(search.read().trim())
+    });
+
+    let options = resource.items();
+    let mut known = use_hook(|| CopyValue::new(Vec::<SelectOption>::new()));
+    for option in &options {
+        if !known.peek().iter().any(|item| item.value == option.value) {
+            known.write().push(option.clone());
+        }
+    }
+
+    select.groups = vec![SelectOptionGroup {
+        options,
+        ..Default::default()
+    }];
+    select.filterable = true;
+    select.search = Some(search);
+    select.loading = resource.loading();
+    select.known = known.peek().clone();
+    select.to_element()
+}
+
+/// 选项元素的 id，用于 aria-activedescendant
+fn option_id(uid: &str, index: usize) -> String {
+    format!("{uid}-option-{index}")
+}
+
+/// 渲染一个选项
+fn render_option(
+    option: VisibleOption,
+    uid: &str,
+    current: Option<usize>,
+    choose: Callback<SelectValue>,
+    mut active: Signal<Option<usize>>,
+) -> Element {
+    let mut class = vec![classnames::SELECT_OPTION];
+    if option.selected {
+        class.push("is-selected");
+    }
+    if option.disabled {
+        class.push("is-disabled");
+    }
+    if current == Some(option.index) {
+        class.push("is-active");
+    }
+    let class = class.join(" ");
+    let VisibleOption {
+        index,
+        value,
+        label,
+        disabled,
+        selected,
+    } = option;
+
+    rsx! {
+        div {
+            key: "{value}",
+            id: option_id(uid, index),
+            class,
+            role: "option",
+            aria_selected: "{selected}",
+            aria_disabled: disabled.then_some("true"),
+            onmouseenter: move |_| {
+                if !disabled {
+                    active.set(Some(index));
+                }
+            },
+            onclick: move |_| {
+                if !disabled {
+                    choose.call(value.clone());
+                }
+            },
+            "{label}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_move_active() {
+        let enabled = [0, 2, 3];
+        assert_eq!(move_active(&enabled, None, true), Some(0));
+        assert_eq!(move_active(&enabled, None, false), Some(3));
+        assert_eq!(move_active(&enabled, Some(0), true), Some(2));
+        assert_eq!(move_active(&enabled, Some(3), true), Some(0));
+        assert_eq!(move_active(&enabled, Some(0), false), Some(3));
+        assert_eq!(move_active(&[], Some(1), true), None);
+    }
+
+    fn options(select: Select) -> Select {
+        select
+            .option(SelectOption::new("beijing").label("北京"))
+            .option(SelectOption::new("shanghai").label("上海").disabled(true))
+            .group(
+                SelectOptionGroup::new("广东")
+                    .option(SelectOption::new("guangzhou").label("广州"))
+                    .option(SelectOption::new("shenzhen").label("深圳")),
+            )
+    }
+
+    #[test]
+    fn test_select_single() {
+        fn app() -> Element {
+            let value = use_signal(|| None::<SelectValue>);
+            let mut changes = use_signal(Vec::<Option<SelectValue>>::new);
+            rsx! {
+                {
+                    options(Select::new())
+                        .value(value)
+                        .clearable(true)
+                        .onchange(move |v| changes.write().push(v))
+                        .to_element()
+                }
+                span { class: "changes", "{changes.read().len()}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::SELECT_PLACEHOLDER)
+                .unwrap()
+                .text(),
+            "请选择"
+        );
+        assert!(harness.find_by_class(classnames::SELECT_DROPDOWN).is_none());
+
+        let wrapper = harness.find_by_class(classnames::SELECT_WRAPPER).unwrap();
+        harness.click(&wrapper);
+        let groups = harness.find_all_by_class(classnames::SELECT_GROUP);
+        assert_eq!(groups.len(), 1);
+        assert_eq!(groups[0].attr("aria-label"), Some("广东"));
+        let options = harness.find_all_by_class(classnames::SELECT_OPTION);
+        assert_eq!(options.len(), 4);
+        assert!(options[1].has_class("is-disabled"));
+
+        // 禁用的选项不能选择
+        harness.click(&options[1]);
+        assert_eq!(harness.find_by_class("changes").unwrap().text(), "0");
+
+        harness.click(&options[2]);
+        assert!(harness.find_by_class(classnames::SELECT_DROPDOWN).is_none());
+        assert_eq!(
+            harness
+                .find_by_class(classnames::SELECT_LABEL)
+                .unwrap()
+                .text(),
+            "广州"
+        );
+
+        let clear = harness.find_by_class(classnames::SELECT_CLEAR).unwrap();
+        harness.click(&clear);
+        assert!(
+            harness
+                .find_by_class(classnames::SELECT_PLACEHOLDER)
+                .is_some()
+        );
+        assert_eq!(harness.find_by_class("changes").unwrap().text(), "2");
+    }
+
+    #[test]
+    fn test_select_keyboard() {
+        fn app() -> Element {
+            let value = use_signal(|| Some(SelectValue::from("beijing")));
+            options(Select::new()).value(value).to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let wrapper = harness.find_by_class(classnames::SELECT_WRAPPER).unwrap();
+        assert_eq!(wrapper.attr("role"), Some("combobox"));
+
+        // 打开时高亮已选的选项，向下跳过禁用的选项
+        harness.keydown(&wrapper, "ArrowDown");
+        let active = harness.find_by_class("is-active").unwrap();
+        assert_eq!(active.text(), "北京");
+        let wrapper = harness.find_by_class(classnames::SELECT_WRAPPER).unwrap();
+        assert_eq!(wrapper.attr("aria-activedescendant"), active.attr("id"));
+        harness.keydown(&wrapper, "ArrowDown");
+        assert_eq!(harness.find_by_class("is-active").unwrap().text(), "广州");
+        harness.keydown(&wrapper, "ArrowUp");
+        harness.keydown(&wrapper, "ArrowUp");
+        assert_eq!(harness.find_by_class("is-active").unwrap().text(), "深圳");
+
+        harness.keydown(&wrapper, "Enter");
+        assert!(harness.find_by_class(classnames::SELECT_DROPDOWN).is_none());
+        assert_eq!(
+            harness
+                .find_by_class(classnames::SELECT_LABEL)
+                .unwrap()
+                .text(),
+            "深圳"
+        );
+
+        harness.keydown(&wrapper, "Enter");
+        assert!(harness.find_by_class(classnames::SELECT_DROPDOWN).is_some());
+        harness.keydown(&wrapper, "Escape");
+        assert!(harness.find_by_class(classnames::SELECT_DROPDOWN).is_none());
+    }
+
+    #[test]
+    fn test_select_multiple_filterable() {
+        fn app() -> Element {
+            let values = use_signal(|| vec![SelectValue::from("beijing")]);
+            rsx! {
+                {
+                    options(Select::new())
+                        .multiple_value(values)
+                        .filterable(true)
+                        .to_element()
                 }
+                span { class: "values", "{values.read().len()}" }
             }
         }
+
+        let mut harness = Harness::new(app);
+        let tags = harness.find_all_by_class(classnames::SELECT_TAG_LABEL);
+        assert_eq!(tags.len(), 1);
+        assert_eq!(tags[0].text(), "北京");
+
+        let input = harness.find_by_class(classnames::SELECT_INPUT).unwrap();
+        harness.input(&input, "深");
+        let options = harness.find_all_by_class(classnames::SELECT_OPTION);
+        assert_eq!(options.len(), 1);
+        assert_eq!(options[0].text(), "深圳");
+        harness.click(&options[0]);
+        assert_eq!(harness.find_by_class("values").unwrap().text(), "2");
+        // 多选时选择后保持打开并清空关键字
+        assert_eq!(
+            harness.find_all_by_class(classnames::SELECT_OPTION).len(),
+            4
+        );
+
+        harness.input(&input, "纽约");
+        assert_eq!(
+            harness
+                .find_by_class(classnames::SELECT_EMPTY)
+                .unwrap()
+                .text(),
+            "无匹配数据"
+        );
+        harness.input(&input, "");
+
+        harness.keydown(&input, "Backspace");
+        assert_eq!(harness.find_by_class("values").unwrap().text(), "1");
+        let close = harness.find_by_class(classnames::SELECT_TAG_CLOSE).unwrap();
+        harness.click(&close);
+        assert_eq!(harness.find_by_class("values").unwrap().text(), "0");
+    }
+
+    #[test]
+    fn test_select_collapse_tags() {
+        fn app() -> Element {
+            let values = use_signal(|| {
+                vec![
+                    SelectValue::from("beijing"),
+                    SelectValue::from("guangzhou"),
+                    SelectValue::from("shenzhen"),
+                ]
+            });
+            options(Select::new())
+                .multiple_value(values)
+                .collapse_tags(true)
+                .max_collapse_tags(2)
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let tags = harness.find_all_by_class(classnames::SELECT_TAG_LABEL);
+        assert_eq!(tags.len(), 2);
+        assert_eq!(tags[1].text(), "广州");
+        let collapsed = harness
+            .find_by_class(classnames::SELECT_TAG_COLLAPSE)
+            .unwrap();
+        assert_eq!(collapsed.text(), "+1");
+
+        // 移除标签后剩余的已选项不超过显示数量，不再折叠
+        let close = harness.find_by_class(classnames::SELECT_TAG_CLOSE).unwrap();
+        harness.click(&close);
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::SELECT_TAG_LABEL)
+                .len(),
+            2
+        );
+        assert!(
+            harness
+                .find_by_class(classnames::SELECT_TAG_COLLAPSE)
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_select_remote() {
+        fn app() -> Element {
+            let value = use_signal(|| None::<SelectValue>);
+            let cities =
+                crate::MemoryDataSource::new(vec![("bj", "北京"), ("sh", "上海"), ("sz", "深圳")])
+                    .filter(|city, query| city.1.contains(query.keyword.as_str()))
+                    .map(|(code, name): (&str, &str)| SelectOption::new(code).label(name));
+            rsx! {
+                {
+                    Select::new()
+                        .value(value)
+                        .option(SelectOption::new("ignored"))
+                        .remote(cities)
+                        .to_element()
+                }
+                span { class: "value", "{value.read().clone().unwrap_or_default()}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let input = harness.find_by_class(classnames::SELECT_INPUT).unwrap();
+        harness.keydown(&input, "ArrowDown");
+        assert_eq!(
+            harness.find_all_by_class(classnames::SELECT_OPTION).len(),
+            3
+        );
+
+        // 输入的文字作为关键字由数据源筛选
+        harness.input(&input, "深");
+        let options = harness.find_all_by_class(classnames::SELECT_OPTION);
+        assert_eq!(options.len(), 1);
+        harness.click(&options[0]);
+        assert_eq!(harness.find_by_class("value").unwrap().text(), "sz");
+
+        // 已选的值不在最新的查询结果中时仍显示标签
+        let input = harness.find_by_class(classnames::SELECT_INPUT).unwrap();
+        assert_eq!(input.attr("value"), Some("深圳"));
     }
 }
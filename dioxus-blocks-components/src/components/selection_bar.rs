@@ -0,0 +1,319 @@
+//! SelectionBar 批量操作栏组件
+//!
+//! 与 [`Table::selection`](crate::Table) 共用同一个选中行信号，有选中的行时显示已选择的数量和批量操作按钮，
+//! 没有选中的行时不渲染任何内容。
+//!
+//! 服务端分页时通过 [`SelectionBar::page_keys`] 传入当前页的行、[`SelectionBar::total`] 传入全部记录数，
+//! 当前页全部选中后显示“选择全部 N 项”，点击后 [`SelectionBar::all_pages`] 传入的信号变为 `true`，
+//! 批量操作应作用于全部记录而不只是选中的行。当前页有行被取消选择后自动恢复为只选择当前页。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, SelectionBar, Table, TableColumn, TableRow, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let selection = use_signal(|| vec!["1".to_string()]);
+//!     let all_pages = use_signal(|| false);
+//!
+//!     View::new()
+//!         .children(
+//!             SelectionBar::new(selection)
+//!                 .page_keys(vec!["1", "2"])
+//!                 .total(86)
+//!                 .all_pages(all_pages)
+//!                 .action(Button::new().text("删除").as_danger().onclick(move |_| {
+//!                     if all_pages() {
+//!                         // 删除全部 86 条记录
+//!                     } else {
+//!                         // 删除 selection 中的记录
+//!                     }
+//!                 })),
+//!         )
+//!         .children(
+//!             Table::new()
+//!                 .column(TableColumn::new("name", "姓名"))
+//!                 .row(TableRow::new("1").cell("name", "张三"))
+//!                 .row(TableRow::new("2").cell("name", "李四"))
+//!                 .selection(selection),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Button, Style, classnames, traits::ToElement};
+
+/// 批量操作栏组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct SelectionBar {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 选中的行 key
+    selection: Option<Signal<Vec<String>>>,
+    /// 批量操作按钮
+    actions: Vec<Button>,
+    /// 当前页全部行的 key
+    page_keys: Vec<String>,
+    /// 服务端分页时的全部记录数
+    total: Option<u64>,
+    /// 是否已选择全部页，未设置时在组件内保存
+    all_pages: Option<Signal<bool>>,
+    /// 点击取消选择时的回调
+    onclear: Option<EventHandler<()>>,
+}
+
+impl Default for SelectionBar {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::SELECTION_BAR),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            selection: None,
+            actions: Vec::new(),
+            page_keys: Vec::new(),
+            total: None,
+            all_pages: None,
+            onclear: None,
+        }
+    }
+}
+
+#[builder_props]
+impl SelectionBar {
+    /// 使用选中行信号创建批量操作栏
+    pub fn new(selection: Signal<Vec<String>>) -> Self {
+        Self {
+            selection: Some(selection),
+            ..Default::default()
+        }
+    }
+
+    /// 添加一个批量操作按钮
+    pub fn action(mut self, button: Button) -> Self {
+        self.actions.push(button);
+        self
+    }
+
+    /// 批量添加操作按钮
+    pub fn actions(mut self, buttons: Vec<Button>) -> Self {
+        self.actions.extend(buttons);
+        self
+    }
+
+    /// 设置当前页全部行的 key，用于判断当前页是否已全部选中
+    pub fn page_keys<T: Into<String>>(mut self, keys: Vec<T>) -> Self {
+        self.page_keys = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 设置服务端分页时的全部记录数，当前页全部选中后可以选择全部页
+    pub fn total(mut self, total: u64) -> Self {
+        self.total = Some(total);
+        self
+    }
+
+    /// 设置保存是否已选择全部页的信号，批量操作根据它决定作用于选中的行还是全部记录
+    pub fn all_pages(mut self, all_pages: Signal<bool>) -> Self {
+        self.all_pages = Some(all_pages);
+        self
+    }
+
+    /// 设置点击取消选择时的回调
+    pub fn onclear(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.onclear = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for SelectionBar {
+    fn to_element(&self) -> Element {
+        let local_selection = use_signal(Vec::new);
+        let mut selection = self.selection.unwrap_or(local_selection);
+        let local_all_pages = use_signal(|| false);
+        let mut all_pages = self.all_pages.unwrap_or(local_all_pages);
+
+        let page_keys = self.page_keys.clone();
+        let page_selected =
+            !page_keys.is_empty() && page_keys.iter().all(|key| selection.read().contains(key));
+
+        // 当前页有行被取消选择后恢复为只选择当前页
+        use_effect(use_reactive!(|page_selected| {
+            if !page_selected && *all_pages.peek() {
+                all_pages.set(false);
+            }
+        }));
+
+        let count = selection.read().len();
+        if count == 0 {
+            return rsx! {};
+        }
+
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let onclear = self.onclear;
+
+        let across = all_pages() && page_selected;
+        let message = match self.total {
+            Some(total) if across => format!("已选择全部 {total} 项"),
+            _ => format!("已选择 {count} 项"),
+        };
+        let select_all = self
+            .total
+            .filter(|total| page_selected && !across && *total > count as u64);
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                role: "toolbar",
+                aria_label: "批量操作",
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                span { class: classnames::SELECTION_BAR_COUNT, aria_live: "polite", "{message}" }
+                if let Some(total) = select_all {
+                    button {
+                        r#type: "button",
+                        class: classnames::SELECTION_BAR_LINK,
+                        onclick: move |_| all_pages.set(true),
+                        "选择全部 {total} 项"
+                    }
+                }
+                button {
+                    r#type: "button",
+                    class: classnames::SELECTION_BAR_LINK,
+                    onclick: move |_| {
+                        selection.write().clear();
+                        all_pages.set(false);
+                        if let Some(handler) = onclear {
+                            handler.call(());
+                        }
+                    },
+                    "取消选择"
+                }
+                {self.childrens_to_element()}
+                div { class: classnames::SELECTION_BAR_ACTIONS,
+                    for button in self.actions.iter() {
+                        {button.to_element()}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Table, TableColumn, TableRow, Text, View};
+
+    fn table(selection: Signal<Vec<String>>) -> Table {
+        Table::new()
+            .column(TableColumn::new("name", "姓名"))
+            .row(TableRow::new("1").cell("name", "张三"))
+            .row(TableRow::new("2").cell("name", "李四"))
+            .selection(selection)
+    }
+
+    #[test]
+    fn test_selection_bar_follows_table_selection() {
+        fn app() -> Element {
+            let selection = use_signal(Vec::new);
+            let mut deleted = use_signal(Vec::new);
+            View::new()
+                .children(
+                    SelectionBar::new(selection).action(
+                        Button::new()
+                            .text("删除")
+                            .onclick(move |_| deleted.set(selection())),
+                    ),
+                )
+                .children(table(selection))
+                .children(Text::span(format!("已删除 {}", deleted.read().join(","))))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::SELECTION_BAR).is_none());
+
+        let checkboxes = harness.find_all(|el| el.tag() == "input");
+        harness.change(&checkboxes[2], "true");
+        assert_eq!(
+            harness
+                .find_by_class(classnames::SELECTION_BAR_COUNT)
+                .unwrap()
+                .text(),
+            "已选择 1 项"
+        );
+        // 服务端分页未开启时不显示选择全部页
+        assert!(harness.find_by_text("选择全部 2 项").is_none());
+
+        harness.click(&harness.find_by_text("删除").unwrap());
+        assert!(harness.find_by_text("已删除 2").is_some());
+
+        harness.click(&harness.find_by_text("取消选择").unwrap());
+        assert!(harness.find_by_class(classnames::SELECTION_BAR).is_none());
+    }
+
+    #[test]
+    fn test_selection_bar_select_all_pages() {
+        fn app() -> Element {
+            let selection = use_signal(Vec::new);
+            let all_pages = use_signal(|| false);
+            View::new()
+                .children(
+                    SelectionBar::new(selection)
+                        .page_keys(vec!["1", "2"])
+                        .total(50)
+                        .all_pages(all_pages),
+                )
+                .children(table(selection))
+                .children(Text::span(format!("全部页 {}", all_pages())))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let header = harness.find_all(|el| el.tag() == "input")[0].clone();
+        harness.change(&header, "true");
+        assert!(harness.find_by_text("已选择 2 项").is_some());
+
+        harness.click(&harness.find_by_text("选择全部 50 项").unwrap());
+        assert!(harness.find_by_text("已选择全部 50 项").is_some());
+        assert!(harness.find_by_text("全部页 true").is_some());
+        assert!(harness.find_by_text("选择全部 50 项").is_none());
+
+        // 取消选择当前页的一行后恢复为只选择当前页
+        let row = harness.find_all(|el| el.tag() == "input")[1].clone();
+        harness.change(&row, "false");
+        assert!(harness.find_by_text("已选择 1 项").is_some());
+        assert!(harness.find_by_text("全部页 false").is_some());
+    }
+}
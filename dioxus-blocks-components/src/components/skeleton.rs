@@ -0,0 +1,392 @@
+//! Skeleton 骨架屏组件
+//!
+//! 在内容加载期间以灰色占位块显示页面的大致结构，避免加载时页面空白闪烁。
+//! [`Skeleton`] 可以显示头像、标题和若干行段落，[`Skeleton::page`] 模拟包含标题、说明和卡片的文档页面；
+//! 设置 [`Skeleton::loading`] 为 `false` 后渲染子元素。
+//!
+//! [`SkeletonBoundary`] 基于 Dioxus 的 `SuspenseBoundary`，子组件中通过 `use_resource(..).suspend()?`
+//! 等待的异步资源全部完成前显示骨架屏。在 [`RouteProgress`](crate::RouteProgress) 中使用时，
+//! 显示骨架屏期间页面顶部同时显示进度条。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Skeleton, SkeletonBoundary, Text, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct Profile;
+//!
+//! impl ToElement for Profile {
+//!     fn to_element(&self) -> Element {
+//!         let name = use_resource(|| async { "张三".to_string() });
+//!         let name = name.suspend()?;
+//!         Text::p(name.read().clone()).to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     View::new()
+//!         .children(Skeleton::new().avatar(true).rows(2))
+//!         .children(
+//!             SkeletonBoundary::new()
+//!                 .fallback(Skeleton::new().rows(1))
+//!                 .children(Profile),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 骨架屏组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Skeleton {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，加载完成后显示
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 段落的行数
+    rows: usize,
+    /// 是否显示标题占位
+    title: bool,
+    /// 是否显示头像占位
+    avatar: bool,
+    /// 是否显示闪烁动画
+    animated: bool,
+    /// 是否处于加载状态
+    loading: bool,
+    /// 是否模拟文档页面的结构
+    page: bool,
+}
+
+impl Default for Skeleton {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::SKELETON),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            rows: 3,
+            title: true,
+            avatar: false,
+            animated: true,
+            loading: true,
+            page: false,
+        }
+    }
+}
+
+#[builder_props]
+impl Skeleton {
+    /// 创建骨架屏，默认显示标题和三行段落
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 创建页面骨架屏，依次显示页面标题、说明和两个卡片，适合作为整个页面的加载状态
+    pub fn page() -> Self {
+        Self {
+            page: true,
+            ..Default::default()
+        }
+    }
+
+    /// 设置段落的行数
+    #[prop(default = "3")]
+    pub fn rows(mut self, rows: usize) -> Self {
+        self.rows = rows;
+        self
+    }
+
+    /// 设置是否显示标题占位
+    #[prop(default = "true")]
+    pub fn title(mut self, title: bool) -> Self {
+        self.title = title;
+        self
+    }
+
+    /// 设置是否显示头像占位
+    #[prop(default = "false")]
+    pub fn avatar(mut self, avatar: bool) -> Self {
+        self.avatar = avatar;
+        self
+    }
+
+    /// 设置是否显示闪烁动画
+    #[prop(default = "true")]
+    pub fn animated(mut self, animated: bool) -> Self {
+        self.animated = animated;
+        self
+    }
+
+    /// 设置是否处于加载状态，为 `false` 时渲染子元素
+    #[prop(default = "true")]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+}
+
+/// 标题和段落占位
+fn paragraph(title: bool, rows: usize) -> Element {
+    rsx! {
+        div { class: classnames::SKELETON_CONTENT,
+            if title {
+                div { class: classnames::SKELETON_TITLE }
+            }
+            for _ in 0..rows {
+                div { class: classnames::SKELETON_ROW }
+            }
+        }
+    }
+}
+
+impl ToElement for Skeleton {
+    fn to_element(&self) -> Element {
+        if !self.loading {
+            return self.childrens_to_element();
+        }
+
+        let id = self.id.clone();
+        let mut class = self.class.to_string();
+        if self.animated {
+            class.push(' ');
+            class.push_str(classnames::SKELETON_ANIMATED);
+        }
+        if self.page {
+            class.push(' ');
+            class.push_str(classnames::SKELETON_PAGE);
+        }
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                role: "status",
+                aria_busy: "true",
+                aria_label: "加载中",
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                if self.page {
+                    div { class: classnames::SKELETON_HEADING }
+                    {paragraph(false, 2)}
+                    for _ in 0..2 {
+                        div { class: classnames::SKELETON_CARD, {paragraph(true, 3)} }
+                    }
+                } else {
+                    if self.avatar {
+                        div { class: classnames::SKELETON_AVATAR }
+                    }
+                    {paragraph(self.title, self.rows)}
+                }
+            }
+        }
+    }
+}
+
+/// 异步加载边界组件
+///
+/// 子组件中的异步资源完成前显示骨架屏，默认使用 [`Skeleton::page`]。
+#[derive(Debug, Clone)]
+pub struct SkeletonBoundary {
+    /// 加载期间显示的内容
+    fallback: Rc<dyn ToElement>,
+    /// 子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+}
+
+impl Default for SkeletonBoundary {
+    fn default() -> Self {
+        Self {
+            fallback: Rc::new(Skeleton::page()),
+            childrens: Vec::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl SkeletonBoundary {
+    /// 创建异步加载边界
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置加载期间显示的内容
+    #[prop(default = "Skeleton::page()")]
+    pub fn fallback<T>(mut self, fallback: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.fallback = Rc::new(fallback);
+        self
+    }
+
+    /// 添加子元素
+    pub fn children<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.childrens.push(Rc::new(component));
+        self
+    }
+}
+
+impl ToElement for SkeletonBoundary {
+    fn to_element(&self) -> Element {
+        let fallback = self.fallback.clone();
+
+        // 子元素以独立的组件作用域渲染，挂起时由当前边界显示骨架屏
+        rsx! {
+            SuspenseBoundary {
+                fallback: move |_: SuspenseContext| rsx! {
+                    SkeletonElement { element: fallback.clone(), fallback: true }
+                },
+                for children in self.childrens.iter() {
+                    SkeletonElement { element: children.clone(), fallback: false }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct SkeletonElementProps {
+    element: Rc<dyn ToElement>,
+    /// 是否为加载期间显示的内容，显示期间登记到进度条
+    fallback: bool,
+}
+
+impl PartialEq for SkeletonElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.element, &other.element) && self.fallback == other.fallback
+    }
+}
+
+#[allow(non_snake_case)]
+fn SkeletonElement(props: SkeletonElementProps) -> Element {
+    #[cfg(feature = "route_progress")]
+    {
+        let fallback = props.fallback;
+        use_hook(move || {
+            fallback
+                .then(crate::ProgressTracker::current)
+                .flatten()
+                .map(|tracker| Rc::new(tracker.start()))
+        });
+    }
+    props.element.to_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Text, View};
+
+    #[test]
+    fn test_skeleton() {
+        let harness = Harness::with_element(Skeleton::new().avatar(true).rows(2));
+        let root = harness.find_by_class(classnames::SKELETON).unwrap();
+        assert_eq!(root.attr("aria-busy"), Some("true"));
+        assert!(root.has_class(classnames::SKELETON_ANIMATED));
+        assert!(harness.find_by_class(classnames::SKELETON_AVATAR).is_some());
+        assert!(harness.find_by_class(classnames::SKELETON_TITLE).is_some());
+        assert_eq!(harness.find_all_by_class(classnames::SKELETON_ROW).len(), 2);
+
+        let harness = Harness::with_element(
+            Skeleton::new()
+                .loading(false)
+                .children(Text::span("已加载")),
+        );
+        assert!(harness.find_by_class(classnames::SKELETON).is_none());
+        assert!(harness.find_by_text("已加载").is_some());
+    }
+
+    #[test]
+    fn test_skeleton_page() {
+        let harness = Harness::with_element(Skeleton::page().animated(false));
+        let root = harness.find_by_class(classnames::SKELETON).unwrap();
+        assert!(root.has_class(classnames::SKELETON_PAGE));
+        assert!(!root.has_class(classnames::SKELETON_ANIMATED));
+        assert!(
+            harness
+                .find_by_class(classnames::SKELETON_HEADING)
+                .is_some()
+        );
+        assert_eq!(
+            harness.find_all_by_class(classnames::SKELETON_CARD).len(),
+            2
+        );
+    }
+
+    #[derive(Debug, Clone)]
+    struct Pending;
+
+    impl ToElement for Pending {
+        fn to_element(&self) -> Element {
+            let value = use_resource(std::future::pending::<String>);
+            let value = value.suspend()?;
+            Text::span(value.read().clone()).to_element()
+        }
+    }
+
+    #[derive(Debug, Clone)]
+    struct Ready;
+
+    impl ToElement for Ready {
+        fn to_element(&self) -> Element {
+            let value = use_resource(|| async { "资料".to_string() });
+            let value = value.suspend()?;
+            Text::span(value.read().clone()).to_element()
+        }
+    }
+
+    #[test]
+    fn test_skeleton_boundary() {
+        let harness = Harness::with_element(
+            View::new()
+                .children(Text::span("页头"))
+                .children(SkeletonBoundary::new().children(Pending)),
+        );
+        // 边界外的内容正常显示，挂起的子组件显示页面骨架屏
+        assert!(harness.find_by_text("页头").is_some());
+        assert!(harness.find_by_class(classnames::SKELETON_PAGE).is_some());
+
+        let harness = Harness::with_element(
+            SkeletonBoundary::new()
+                .fallback(Skeleton::new().rows(1))
+                .children(Ready),
+        );
+        assert!(harness.find_by_class(classnames::SKELETON).is_none());
+        assert!(harness.find_by_text("资料").is_some());
+    }
+}
@@ -0,0 +1,279 @@
+//! Spin 加载中组件
+//!
+//! 没有子元素时单独显示一个旋转的加载图标；包裹子元素时，`loading` 为 `true` 期间在子元素上方覆盖半透明遮罩，
+//! 居中显示加载图标和提示文字，并阻止与子元素交互。子元素始终保持渲染，加载结束后状态不会丢失。
+//!
+//! 内容的结构已知时可以使用 [`Skeleton`](crate::Skeleton) 显示占位块，需要保留已有内容时使用 Spin。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Spin, SpinSize, Text, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let loading = use_signal(|| true);
+//!     View::new()
+//!         .children(Spin::new().size(SpinSize::Small))
+//!         .children(
+//!             Spin::new()
+//!                 .loading(loading())
+//!                 .tip("加载中…")
+//!                 .children(Text::p("表格或卡片等内容")),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 加载图标尺寸
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum SpinSize {
+    /// 中等尺寸
+    #[default]
+    Medium,
+    /// 小尺寸
+    Small,
+    /// 大尺寸
+    Large,
+}
+
+impl SpinSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            SpinSize::Medium => "",
+            SpinSize::Small => "t-spin--small",
+            SpinSize::Large => "t-spin--large",
+        }
+    }
+}
+
+impl std::fmt::Display for SpinSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+/// 加载中组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Spin {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，加载期间被遮罩覆盖
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 是否处于加载状态
+    loading: bool,
+    /// 加载图标尺寸
+    size: SpinSize,
+    /// 显示在加载图标下方的提示文字
+    tip: Option<String>,
+}
+
+impl Default for Spin {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::SPIN),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            loading: true,
+            size: SpinSize::default(),
+            tip: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Spin {
+    /// 创建加载中组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置是否处于加载状态，为 `false` 时不显示加载图标和遮罩
+    #[prop(default = "true")]
+    pub fn loading(mut self, loading: bool) -> Self {
+        self.loading = loading;
+        self
+    }
+
+    /// 设置加载图标尺寸
+    #[prop(default = "SpinSize::Medium")]
+    pub fn size(mut self, size: SpinSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置显示在加载图标下方的提示文字
+    pub fn tip(mut self, tip: impl Into<String>) -> Self {
+        self.tip = Some(tip.into());
+        self
+    }
+
+    /// 设置为小尺寸
+    pub fn as_small(mut self) -> Self {
+        self.size = SpinSize::Small;
+        self
+    }
+
+    /// 设置为大尺寸
+    pub fn as_large(mut self) -> Self {
+        self.size = SpinSize::Large;
+        self
+    }
+}
+
+/// 加载图标和提示文字
+fn indicator(tip: Option<String>) -> Element {
+    rsx! {
+        span { class: classnames::SPIN_DOT, aria_hidden: "true" }
+        if let Some(tip) = tip {
+            span { class: classnames::SPIN_TIP, "{tip}" }
+        }
+    }
+}
+
+impl ToElement for Spin {
+    fn to_element(&self) -> Element {
+        let nested = !self.childrens.is_empty();
+        if !nested && !self.loading {
+            return rsx! {};
+        }
+
+        let id = self.id.clone();
+        let mut class_names = vec![&*self.class, self.size.as_class()];
+        if nested {
+            class_names.push(classnames::SPIN_NESTED);
+        }
+        if nested && self.loading {
+            class_names.push("is-loading");
+        }
+        let class = class_names
+            .into_iter()
+            .filter(|c| !c.is_empty())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let tip = self.tip.clone();
+        let label = tip.clone().unwrap_or_else(|| "加载中".to_string());
+
+        if !nested {
+            return rsx! {
+                div {
+                    id,
+                    class,
+                    style,
+                    role: "status",
+                    aria_label: label,
+                    onclick: move |event: MouseEvent| {
+                        if let Some(handler) = onclick_handler {
+                            handler.call(event);
+                        }
+                    },
+                    {indicator(tip)}
+                }
+            };
+        }
+
+        // 加载结束后保留外层结构，避免子元素重新挂载
+        let loading = self.loading;
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                aria_busy: loading.to_string(),
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::SPIN_CONTENT, {self.childrens_to_element()} }
+                if loading {
+                    div {
+                        class: classnames::SPIN_OVERLAY,
+                        role: "status",
+                        aria_label: label,
+                        {indicator(tip)}
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Text;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_spin_standalone() {
+        let harness = Harness::with_element(Spin::new().as_large().tip("加载数据"));
+        let root = harness.find_by_class(classnames::SPIN).unwrap();
+        assert!(root.has_class("t-spin--large"));
+        assert!(!root.has_class(classnames::SPIN_NESTED));
+        assert_eq!(root.attr("role"), Some("status"));
+        assert_eq!(root.attr("aria-label"), Some("加载数据"));
+        assert!(harness.find_by_class(classnames::SPIN_DOT).is_some());
+        assert_eq!(
+            harness.find_by_class(classnames::SPIN_TIP).unwrap().text(),
+            "加载数据"
+        );
+
+        let harness = Harness::with_element(Spin::new().loading(false));
+        assert!(harness.find_by_class(classnames::SPIN).is_none());
+    }
+
+    #[test]
+    fn test_spin_nested() {
+        fn app() -> Element {
+            let mut loading = use_signal(|| true);
+            rsx! {
+                {Spin::new().loading(loading()).children(Text::span("内容")).to_element()}
+                button { class: "done", onclick: move |_| loading.set(false) }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let root = harness.find_by_class(classnames::SPIN).unwrap();
+        assert!(root.has_class(classnames::SPIN_NESTED));
+        assert!(root.has_class("is-loading"));
+        assert_eq!(root.attr("aria-busy"), Some("true"));
+        assert!(harness.find_by_class(classnames::SPIN_OVERLAY).is_some());
+        assert!(harness.find_by_class(classnames::SPIN_TIP).is_none());
+        // 加载期间子元素保持渲染
+        assert!(harness.find_by_text("内容").is_some());
+
+        harness.click(&harness.find_by_class("done").unwrap());
+        let root = harness.find_by_class(classnames::SPIN).unwrap();
+        assert!(!root.has_class("is-loading"));
+        assert_eq!(root.attr("aria-busy"), Some("false"));
+        assert!(harness.find_by_class(classnames::SPIN_OVERLAY).is_none());
+        assert!(harness.find_by_text("内容").is_some());
+    }
+}
@@ -0,0 +1,2219 @@
+//! Table 表格组件
+//!
+//! 按列定义展示行数据。行可以包含子行，此时表格切换为树形模式：第一列显示展开/折叠按钮，
+//! 子行按层级缩进，适用于分类、组织架构等层级数据。子行较多或需要从服务端获取时，
+//! 将行标记为 [`TableRow::lazy`]，首次展开时调用 [`Table::load_children`] 加载。
+//!
+//! 列开启 [`TableColumn::show_overflow_tooltip`] 后，过长的内容单行截断，只有确实被截断时鼠标移入才显示完整内容；
+//! 开启 [`TableColumn::copyable`] 后，鼠标移入单元格时显示复制按钮。
+//!
+//! 通过 [`TableColumn::render`] 自定义单元格内容，如状态标签、操作按钮等；没有数据时显示 [`Table::empty`]
+//! 设置的内容。[`Table::striped`] 和 [`Table::bordered`] 分别显示斑马纹和纵向边框。
+//!
+//! 列开启 [`TableColumn::sortable`] 后点击表头排序，数字按数值比较，其他内容按文本比较，树形模式下每一层级分别排序。
+//! 排序状态变化时调用 [`Table::onsort`]；开启 [`Table::manual_sort`] 后表格不调整行的顺序，由调用方排序后重新提供行数据。
+//!
+//! 表格获得焦点后可以用方向键切换聚焦的行，在聚焦的行上按回车触发 [`Table::onrow_activate`]；
+//! 通过 [`Table::row_context_menu`] 为每一行配置右键菜单，选择的菜单项由 [`Table::onrow_menu`] 接收。
+//!
+//! 开启 [`Table::selectable`] 后每行前显示复选框，表头复选框选择或取消当前显示的全部行，选中的行 key
+//! 保存在 [`Table::selection`] 传入的信号中，可以配合 [`SelectionBar`](crate::SelectionBar) 显示批量操作。
+//!
+//! 开启 [`Table::column_chooser`] 后，工具栏中显示列设置下拉框，用户可以显示/隐藏列并拖动调整列的顺序。
+//! 设置 [`Table::layout_key`] 后，调整结果按布局键保存到 [`Settings::table_layouts`](crate::Settings) 中，
+//! 同一 [`ConfigProvider`](crate::ConfigProvider) 下布局键相同的表格共享列布局。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Table, TableColumn, TableRow, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Table::new()
+//!         .column(TableColumn::new("name", "姓名"))
+//!         .column(TableColumn::new("age", "年龄").width("80px"))
+//!         .row(TableRow::new("1").cell("name", "张三").cell("age", "28"))
+//!         .row(TableRow::new("2").cell("name", "李四").cell("age", "32"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 排序和自定义单元格
+//!
+//! ```rust
+//! use std::rc::Rc;
+//!
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     Button, Table, TableColumn, TableRow, TableSortOrder, Text, ToElement,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Table::new()
+//!         .striped(true)
+//!         .column(TableColumn::new("name", "姓名"))
+//!         .column(TableColumn::new("age", "年龄").sortable(true))
+//!         .column(TableColumn::new("action", "操作").render(|row: TableRow| {
+//!             Rc::new(Button::new().text(format!("编辑{}", row.get("name").unwrap_or_default())))
+//!         }))
+//!         .row(TableRow::new("1").cell("name", "张三").cell("age", "28"))
+//!         .row(TableRow::new("2").cell("name", "李四").cell("age", "9"))
+//!         .default_sort("age", TableSortOrder::Ascending)
+//!         .onsort(|sort| println!("{sort:?}"))
+//!         .empty(Text::p("还没有成员"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 列设置
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{ConfigProvider, Table, TableColumn, TableRow, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     ConfigProvider::new()
+//!         .children(
+//!             Table::new()
+//!                 .column(TableColumn::new("name", "姓名"))
+//!                 .column(TableColumn::new("email", "邮箱"))
+//!                 .row(TableRow::new("1").cell("name", "张三").cell("email", "zhangsan@example.com"))
+//!                 .column_chooser(true)
+//!                 .layout_key("users"),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 树形数据
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Table, TableColumn, TableRow, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Table::new()
+//!         .column(TableColumn::new("name", "分类"))
+//!         .row(
+//!             TableRow::new("digital")
+//!                 .cell("name", "数码")
+//!                 .child(TableRow::new("phone").cell("name", "手机"))
+//!                 .child(TableRow::new("camera").cell("name", "相机")),
+//!         )
+//!         .row(TableRow::new("books").cell("name", "图书").lazy(true))
+//!         .load_children(|row: TableRow| async move {
+//!             vec![TableRow::new(format!("{}-1", row.key())).cell("name", "小说")]
+//!         })
+//!         .indent(24)
+//!         .default_expand_all(true)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::cmp::Ordering;
+use std::collections::{HashMap, HashSet};
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use indexmap::IndexMap;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{
+    DataQuery, DataSort, DataSource, Settings, SharedDataSource, Style, TableLayout, Text,
+    classnames, traits::ToElement, use_data_source,
+};
+
+/// 加载子行返回的 Future
+type ChildrenFuture = Pin<Box<dyn Future<Output = Vec<TableRow>>>>;
+
+/// 单元格对齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TableAlign {
+    /// 左对齐
+    #[default]
+    Left,
+    /// 居中
+    Center,
+    /// 右对齐
+    Right,
+}
+
+impl TableAlign {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TableAlign::Left => "left",
+            TableAlign::Center => "center",
+            TableAlign::Right => "right",
+        }
+    }
+
+    /// 单元格内容横向排列时的对齐方式
+    fn justify(&self) -> &'static str {
+        match self {
+            TableAlign::Left => "flex-start",
+            TableAlign::Center => "center",
+            TableAlign::Right => "flex-end",
+        }
+    }
+}
+
+/// 排序方向
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TableSortOrder {
+    /// 升序
+    Ascending,
+    /// 降序
+    Descending,
+}
+
+impl TableSortOrder {
+    fn as_str(&self) -> &'static str {
+        match self {
+            TableSortOrder::Ascending => "ascending",
+            TableSortOrder::Descending => "descending",
+        }
+    }
+}
+
+/// 排序状态，包含排序的字段名和方向
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableSort {
+    /// 排序的字段名
+    prop: String,
+    /// 排序方向
+    order: TableSortOrder,
+}
+
+impl TableSort {
+    /// 创建排序状态
+    pub fn new(prop: impl Into<String>, order: TableSortOrder) -> Self {
+        Self {
+            prop: prop.into(),
+            order,
+        }
+    }
+
+    /// 获取排序的字段名
+    pub fn prop(&self) -> &str {
+        &self.prop
+    }
+
+    /// 获取排序方向
+    pub fn order(&self) -> TableSortOrder {
+        self.order
+    }
+}
+
+impl From<TableSort> for DataSort {
+    fn from(sort: TableSort) -> Self {
+        DataSort::new(sort.prop, sort.order == TableSortOrder::Descending)
+    }
+}
+
+/// 表格列
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableColumn {
+    /// 对应行数据中的字段名
+    prop: String,
+    /// 表头文本
+    label: String,
+    /// 列宽，如 `120px`、`20%`
+    width: Option<String>,
+    /// 对齐方式
+    align: TableAlign,
+    /// 内容过长时是否截断并在鼠标移入时显示完整内容
+    show_overflow_tooltip: bool,
+    /// 是否在单元格中显示复制按钮
+    copyable: bool,
+    /// 是否可以点击表头排序
+    sortable: bool,
+    /// 自定义单元格渲染
+    render: Option<Callback<TableRow, Rc<dyn ToElement>>>,
+}
+
+#[builder_props]
+impl TableColumn {
+    /// 创建一列
+    pub fn new(prop: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            prop: prop.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置列宽，如 `120px`、`20%`
+    pub fn width(mut self, width: impl Into<String>) -> Self {
+        self.width = Some(width.into());
+        self
+    }
+
+    /// 设置对齐方式
+    #[prop(default = "TableAlign::Left")]
+    pub fn align(mut self, align: TableAlign) -> Self {
+        self.align = align;
+        self
+    }
+
+    /// 设置内容过长时是否单行截断，只有内容确实被截断时才在鼠标移入后显示完整内容
+    #[prop(default = "false")]
+    pub fn show_overflow_tooltip(mut self, show: bool) -> Self {
+        self.show_overflow_tooltip = show;
+        self
+    }
+
+    /// 设置是否在单元格中显示复制按钮，鼠标移入单元格时显示，点击后复制单元格内容
+    #[prop(default = "false")]
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+
+    /// 设置是否可以点击表头排序，依次切换为升序、降序和不排序
+    #[prop(default = "false")]
+    pub fn sortable(mut self, sortable: bool) -> Self {
+        self.sortable = sortable;
+        self
+    }
+
+    /// 设置自定义单元格渲染闭包，根据行返回单元格的内容，如操作按钮、标签等
+    pub fn render(mut self, f: impl FnMut(TableRow) -> Rc<dyn ToElement> + 'static) -> Self {
+        self.render = Some(Callback::new(f));
+        self
+    }
+
+    /// 获取字段名
+    pub fn prop(&self) -> &str {
+        &self.prop
+    }
+
+    /// 获取表头文本
+    pub fn label(&self) -> &str {
+        &self.label
+    }
+}
+
+/// 表格行
+///
+/// 每行包含唯一的 key 和按字段名存放的单元格内容，可以包含子行。
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableRow {
+    /// 行唯一标识
+    key: String,
+    /// 单元格内容，键为列的字段名
+    cells: IndexMap<String, String>,
+    /// 子行列表
+    children: Vec<TableRow>,
+    /// 子行是否需要在展开时加载
+    lazy: bool,
+}
+
+#[builder_props]
+impl TableRow {
+    /// 创建一行
+    pub fn new(key: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置单元格内容
+    pub fn cell(mut self, prop: impl Into<String>, value: impl Into<String>) -> Self {
+        self.cells.insert(prop.into(), value.into());
+        self
+    }
+
+    /// 添加子行
+    pub fn child(mut self, child: TableRow) -> Self {
+        self.children.push(child);
+        self
+    }
+
+    /// 批量添加子行
+    pub fn children(mut self, children: Vec<TableRow>) -> Self {
+        self.children.extend(children);
+        self
+    }
+
+    /// 设置子行是否在首次展开时通过 [`Table::load_children`] 加载
+    #[prop(default = "false")]
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// 获取行唯一标识
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+
+    /// 获取单元格内容
+    pub fn get(&self, prop: &str) -> Option<&str> {
+        self.cells.get(prop).map(String::as_str)
+    }
+
+    /// 获取子行列表，不包含懒加载的子行
+    pub fn get_children(&self) -> &[TableRow] {
+        &self.children
+    }
+
+    /// 是否为叶子行，懒加载的行在加载前不视为叶子行
+    pub fn is_leaf(&self) -> bool {
+        self.children.is_empty() && !self.lazy
+    }
+}
+
+/// 行右键菜单的菜单项
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct TableMenuItem {
+    /// 菜单项标识，选择后传给 [`Table::onrow_menu`]
+    key: String,
+    /// 菜单项文本
+    label: String,
+    /// 是否禁用
+    disabled: bool,
+    /// 是否为危险操作，以错误色显示
+    danger: bool,
+}
+
+#[builder_props]
+impl TableMenuItem {
+    /// 创建菜单项
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置是否禁用
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置是否为危险操作
+    #[prop(default = "false")]
+    pub fn danger(mut self, danger: bool) -> Self {
+        self.danger = danger;
+        self
+    }
+
+    /// 获取菜单项标识
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+/// 打开的右键菜单
+#[derive(Debug, Clone, PartialEq)]
+struct RowMenu {
+    row: TableRow,
+    items: Vec<TableMenuItem>,
+    /// 菜单相对于视口的位置
+    x: f64,
+    y: f64,
+}
+
+/// Table 表格组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Table {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 列定义
+    columns: Vec<TableColumn>,
+    /// 行数据
+    rows: Vec<TableRow>,
+    /// 树形模式下每一级的缩进，单位为像素
+    indent: u32,
+    /// 是否默认展开全部行
+    default_expand_all: bool,
+    /// 默认展开的行 key 列表
+    default_expanded: Vec<String>,
+    /// 没有数据时显示的文本
+    empty_text: String,
+    /// 没有数据时显示的内容，设置后替代文本
+    empty: Option<Rc<dyn ToElement>>,
+    /// 是否显示斑马纹
+    striped: bool,
+    /// 是否显示纵向边框
+    bordered: bool,
+    /// 是否启用打印样式
+    print_friendly: bool,
+    /// 默认的排序状态
+    default_sort: Option<TableSort>,
+    /// 是否由外部排序，开启后点击表头只触发回调，不调整行的顺序
+    manual_sort: bool,
+    /// 排序状态变化时的回调
+    onsort: Option<EventHandler<Option<TableSort>>>,
+    /// 加载懒加载行的子行
+    load_children: Option<Callback<TableRow, ChildrenFuture>>,
+    /// 展开或折叠行时的回调，参数为行和展开后的状态
+    onexpand: Option<EventHandler<(TableRow, bool)>>,
+    /// 是否在工具栏中显示列设置
+    column_chooser: bool,
+    /// 列布局在全局配置中的保存键，未设置时列布局只在组件内保存
+    layout_key: Option<String>,
+    /// 在聚焦的行上按回车时的回调
+    onrow_activate: Option<EventHandler<TableRow>>,
+    /// 根据行生成右键菜单的菜单项
+    row_context_menu: Option<Callback<TableRow, Vec<TableMenuItem>>>,
+    /// 选择右键菜单项时的回调，参数为行和菜单项标识
+    onrow_menu: Option<EventHandler<(TableRow, String)>>,
+    /// 是否显示行选择列
+    selectable: bool,
+    /// 选中的行 key，未设置时在组件内保存
+    selection: Option<Signal<Vec<String>>>,
+    /// 选中的行变化时的回调
+    onselection_change: Option<EventHandler<Vec<String>>>,
+    /// 行数据的数据源，设置后替代 `rows`
+    data_source: Option<SharedDataSource<TableRow>>,
+    /// 数据源的查询条件，未设置时在组件内保存
+    query: Option<Signal<DataQuery>>,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::TABLE),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            columns: Vec::new(),
+            rows: Vec::new(),
+            indent: 16,
+            default_expand_all: false,
+            default_expanded: Vec::new(),
+            empty_text: "暂无数据".to_string(),
+            empty: None,
+            striped: false,
+            bordered: false,
+            print_friendly: false,
+            default_sort: None,
+            manual_sort: false,
+            onsort: None,
+            load_children: None,
+            onexpand: None,
+            column_chooser: false,
+            layout_key: None,
+            onrow_activate: None,
+            row_context_menu: None,
+            onrow_menu: None,
+            selectable: false,
+            selection: None,
+            onselection_change: None,
+            data_source: None,
+            query: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Table {
+    /// 创建表格
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加一列
+    pub fn column(mut self, column: TableColumn) -> Self {
+        self.columns.push(column);
+        self
+    }
+
+    /// 批量添加列
+    pub fn columns(mut self, columns: Vec<TableColumn>) -> Self {
+        self.columns.extend(columns);
+        self
+    }
+
+    /// 添加一行
+    pub fn row(mut self, row: TableRow) -> Self {
+        self.rows.push(row);
+        self
+    }
+
+    /// 批量添加行
+    pub fn rows(mut self, rows: Vec<TableRow>) -> Self {
+        self.rows.extend(rows);
+        self
+    }
+
+    /// 设置树形模式下每一级的缩进，单位为像素
+    #[prop(default = "16")]
+    pub fn indent(mut self, indent: u32) -> Self {
+        self.indent = indent;
+        self
+    }
+
+    /// 设置是否默认展开全部行，懒加载的行不会自动展开
+    #[prop(default = "false")]
+    pub fn default_expand_all(mut self, expand_all: bool) -> Self {
+        self.default_expand_all = expand_all;
+        self
+    }
+
+    /// 设置默认展开的行
+    pub fn default_expanded<T: Into<String>>(mut self, keys: Vec<T>) -> Self {
+        self.default_expanded = keys.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// 设置没有数据时显示的文本
+    #[prop(default = "\"暂无数据\"")]
+    pub fn empty_text(mut self, text: impl Into<String>) -> Self {
+        self.empty_text = text.into();
+        self
+    }
+
+    /// 设置没有数据时显示的内容，如插图和操作按钮，设置后替代 [`Table::empty_text`]
+    pub fn empty<T>(mut self, empty: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.empty = Some(Rc::new(empty));
+        self
+    }
+
+    /// 设置是否显示斑马纹，偶数行使用浅色背景
+    #[prop(default = "false")]
+    pub fn striped(mut self, striped: bool) -> Self {
+        self.striped = striped;
+        self
+    }
+
+    /// 设置是否显示单元格之间的纵向边框
+    #[prop(default = "false")]
+    pub fn bordered(mut self, bordered: bool) -> Self {
+        self.bordered = bordered;
+        self
+    }
+
+    /// 设置是否启用打印样式，打印时隐藏工具栏和分页，取消固定高度使表格完整展开
+    #[prop(default = "false")]
+    pub fn print_friendly(mut self, print_friendly: bool) -> Self {
+        self.print_friendly = print_friendly;
+        self
+    }
+
+    /// 设置默认按指定列排序
+    pub fn default_sort(mut self, prop: impl Into<String>, order: TableSortOrder) -> Self {
+        self.default_sort = Some(TableSort::new(prop, order));
+        self
+    }
+
+    /// 设置是否由外部排序，开启后点击表头只触发 [`Table::onsort`]，由调用方按排序状态提供行数据，适合服务端排序
+    #[prop(default = "false")]
+    pub fn manual_sort(mut self, manual_sort: bool) -> Self {
+        self.manual_sort = manual_sort;
+        self
+    }
+
+    /// 设置排序状态变化时的回调，取消排序时参数为 `None`
+    pub fn onsort(mut self, handler: impl FnMut(Option<TableSort>) + 'static) -> Self {
+        self.onsort = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置懒加载行的子行加载函数，每行只在首次展开时调用一次
+    pub fn load_children<F, Fut>(mut self, load: F) -> Self
+    where
+        F: Fn(TableRow) -> Fut + 'static,
+        Fut: Future<Output = Vec<TableRow>> + 'static,
+    {
+        self.load_children = Some(Callback::new(move |row: TableRow| {
+            Box::pin(load(row)) as ChildrenFuture
+        }));
+        self
+    }
+
+    /// 设置展开或折叠行时的回调，参数为行和展开后的状态
+    pub fn onexpand(mut self, handler: impl FnMut((TableRow, bool)) + 'static) -> Self {
+        self.onexpand = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置是否在工具栏中显示列设置，用于显示/隐藏列和拖动调整列的顺序
+    #[prop(default = "false")]
+    pub fn column_chooser(mut self, column_chooser: bool) -> Self {
+        self.column_chooser = column_chooser;
+        self
+    }
+
+    /// 设置列布局的保存键，列布局保存到全局配置中，刷新页面或切换路由后保持不变
+    pub fn layout_key(mut self, key: impl Into<String>) -> Self {
+        self.layout_key = Some(key.into());
+        self
+    }
+
+    /// 设置在聚焦的行上按回车时的回调
+    ///
+    /// 表格获得焦点后可以用上下方向键、Home 和 End 切换聚焦的行，树形模式下用左右方向键折叠和展开。
+    pub fn onrow_activate(mut self, handler: impl FnMut(TableRow) + 'static) -> Self {
+        self.onrow_activate = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置行的右键菜单，闭包根据行返回菜单项，返回空列表时显示浏览器默认菜单
+    pub fn row_context_menu(
+        mut self,
+        f: impl FnMut(TableRow) -> Vec<TableMenuItem> + 'static,
+    ) -> Self {
+        self.row_context_menu = Some(Callback::new(f));
+        self
+    }
+
+    /// 设置选择右键菜单项时的回调，参数为行和菜单项标识
+    pub fn onrow_menu(mut self, handler: impl FnMut((TableRow, String)) + 'static) -> Self {
+        self.onrow_menu = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置是否在每行前显示复选框，表头复选框选择或取消当前显示的全部行
+    #[prop(default = "false")]
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = selectable;
+        self
+    }
+
+    /// 设置保存选中行 key 的信号，同时开启行选择
+    pub fn selection(mut self, selection: Signal<Vec<String>>) -> Self {
+        self.selectable = true;
+        self.selection = Some(selection);
+        self
+    }
+
+    /// 设置选中的行变化时的回调，参数为全部选中行的 key
+    pub fn onselection_change(mut self, handler: impl FnMut(Vec<String>) + 'static) -> Self {
+        self.onselection_change = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置行数据的数据源，设置后忽略 [`Table::rows`]
+    ///
+    /// 表格按查询条件从数据源获取当前页的行，排序改为由数据源完成：点击表头时把排序条件写入查询条件并重新查询。
+    /// 查询期间表格显示加载状态，查询失败时以错误信息替代空数据的提示。需要分页时通过 [`Table::query`] 控制页码，
+    /// 或在组件外使用 [`use_data_source`] 获取总数后把行传给 [`Table::rows`]。
+    pub fn data_source(mut self, source: impl DataSource<TableRow>) -> Self {
+        self.data_source = Some(SharedDataSource::new(source));
+        self
+    }
+
+    /// 设置数据源的查询条件，修改后重新查询，点击表头排序时写入排序条件
+    pub fn query(mut self, query: Signal<DataQuery>) -> Self {
+        self.query = Some(query);
+        self
+    }
+}
+
+/// 比较两个单元格，都是数字时按数值比较，否则按文本比较
+fn compare_cells(a: Option<&str>, b: Option<&str>) -> Ordering {
+    let (a, b) = (a.unwrap_or_default(), b.unwrap_or_default());
+    match (a.trim().parse::<f64>(), b.trim().parse::<f64>()) {
+        (Ok(x), Ok(y)) => x.total_cmp(&y),
+        _ => a.cmp(b),
+    }
+}
+
+/// 按排序状态对同一层级的行稳定排序
+fn sort_rows(rows: &mut [TableRow], sort: &TableSort) {
+    rows.sort_by(|a, b| {
+        let ordering = compare_cells(a.get(&sort.prop), b.get(&sort.prop));
+        match sort.order {
+            TableSortOrder::Ascending => ordering,
+            TableSortOrder::Descending => ordering.reverse(),
+        }
+    });
+}
+
+/// 点击表头后的排序状态：同一列依次切换为升序、降序和不排序，切换到其他列时从升序开始
+fn next_sort(current: Option<&TableSort>, prop: &str) -> Option<TableSort> {
+    match current {
+        Some(sort) if sort.prop == prop => match sort.order {
+            TableSortOrder::Ascending => Some(TableSort::new(prop, TableSortOrder::Descending)),
+            TableSortOrder::Descending => None,
+        },
+        _ => Some(TableSort::new(prop, TableSortOrder::Ascending)),
+    }
+}
+
+/// 切换一组行的选中状态：全部已选中时取消选择，否则补充选择未选中的行
+fn toggle_selection(selected: &mut Vec<String>, keys: &[String]) {
+    if !keys.is_empty() && keys.iter().all(|key| selected.contains(key)) {
+        selected.retain(|key| !keys.contains(key));
+    } else {
+        for key in keys {
+            if !selected.contains(key) {
+                selected.push(key.clone());
+            }
+        }
+    }
+}
+
+/// 按方向键移动后聚焦的行，没有聚焦的行时向下移动聚焦第一行、向上移动聚焦最后一行
+fn step_focus(keys: &[&str], focused: Option<&str>, step: isize) -> Option<String> {
+    if keys.is_empty() {
+        return None;
+    }
+    let last = keys.len() as isize - 1;
+    let index = match focused.and_then(|key| keys.iter().position(|k| *k == key)) {
+        Some(index) => (index as isize + step).clamp(0, last),
+        None if step > 0 => 0,
+        None => last,
+    };
+    Some(keys[index as usize].to_string())
+}
+
+/// 按列布局排列列的字段名，布局中没有记录的列按定义顺序排在最后
+fn ordered_props(columns: &[TableColumn], layout: &TableLayout) -> Vec<String> {
+    let mut props = layout
+        .order
+        .iter()
+        .filter(|prop| columns.iter().any(|column| &column.prop == *prop))
+        .cloned()
+        .collect::<Vec<_>>();
+    for column in columns {
+        if !props.contains(&column.prop) {
+            props.push(column.prop.clone());
+        }
+    }
+    props
+}
+
+/// 把 `from` 列移动到 `to` 列的位置
+fn move_prop(props: &mut Vec<String>, from: &str, to: &str) {
+    let (Some(from), Some(to)) = (
+        props.iter().position(|prop| prop == from),
+        props.iter().position(|prop| prop == to),
+    ) else {
+        return;
+    };
+    let prop = props.remove(from);
+    props.insert(to, prop);
+}
+
+/// 列设置的状态
+#[derive(Clone, Copy)]
+struct LayoutState {
+    settings: Signal<Settings>,
+    /// 布局的保存键，只在首次渲染时读取，未设置布局键时为空字符串，保存到组件内的配置中
+    key: Signal<String>,
+    /// 正在拖动的列
+    dragging: Signal<Option<String>>,
+}
+
+impl LayoutState {
+    fn layout(&self) -> TableLayout {
+        self.settings
+            .read()
+            .table_layouts
+            .get(&*self.key.read())
+            .cloned()
+            .unwrap_or_default()
+    }
+
+    fn update(mut self, f: impl FnOnce(&mut TableLayout)) {
+        let key = self.key.peek().clone();
+        let mut settings = self.settings.write();
+        f(settings.table_layouts.entry(key).or_default());
+    }
+
+    fn reset(mut self) {
+        let key = self.key.peek().clone();
+        self.settings.write().table_layouts.remove(&key);
+    }
+}
+
+/// 树形模式的展开状态
+#[derive(Clone, Copy)]
+struct TreeState {
+    expanded: Signal<HashSet<String>>,
+    loaded: Signal<HashMap<String, Vec<TableRow>>>,
+    loading: Signal<HashSet<String>>,
+    load_children: Option<Callback<TableRow, ChildrenFuture>>,
+    onexpand: Option<EventHandler<(TableRow, bool)>>,
+}
+
+impl TreeState {
+    /// 行的子行，懒加载的行返回已加载的子行
+    fn children_of(&self, row: &TableRow) -> Vec<TableRow> {
+        if row.lazy {
+            self.loaded
+                .read()
+                .get(&row.key)
+                .cloned()
+                .unwrap_or_default()
+        } else {
+            row.children.clone()
+        }
+    }
+
+    /// 按展开状态展开行，返回可见的行及其层级，设置排序时每一层级分别排序
+    fn visible_rows(
+        &self,
+        rows: &[TableRow],
+        depth: usize,
+        sort: Option<&TableSort>,
+        out: &mut Vec<(TableRow, usize)>,
+    ) {
+        let mut rows = rows.to_vec();
+        if let Some(sort) = sort {
+            sort_rows(&mut rows, sort);
+        }
+        for row in rows {
+            let expanded = self.expanded.read().contains(&row.key);
+            let children = expanded.then(|| self.children_of(&row));
+            out.push((row, depth));
+            if let Some(children) = children {
+                self.visible_rows(&children, depth + 1, sort, out);
+            }
+        }
+    }
+
+    /// 切换行的展开状态，懒加载的行首次展开时先加载子行
+    fn toggle(mut self, row: TableRow) {
+        let key = row.key.clone();
+        if self.loading.peek().contains(&key) {
+            return;
+        }
+        if self.expanded.peek().contains(&key) {
+            self.expanded.write().remove(&key);
+            if let Some(handler) = self.onexpand {
+                handler.call((row, false));
+            }
+            return;
+        }
+        if let (true, Some(load)) = (
+            row.lazy && !self.loaded.peek().contains_key(&key),
+            self.load_children,
+        ) {
+            self.loading.write().insert(key.clone());
+            spawn(async move {
+                let children = load.call(row.clone()).await;
+                self.loaded.write().insert(key.clone(), children);
+                self.loading.write().remove(&key);
+                self.expanded.write().insert(key);
+                if let Some(handler) = self.onexpand {
+                    handler.call((row, true));
+                }
+            });
+            return;
+        }
+        self.expanded.write().insert(key);
+        if let Some(handler) = self.onexpand {
+            handler.call((row, true));
+        }
+    }
+}
+
+/// 收集所有包含子行的行 key，用于默认展开全部
+fn expandable_keys(rows: &[TableRow], out: &mut HashSet<String>) {
+    for row in rows {
+        if !row.children.is_empty() {
+            out.insert(row.key.clone());
+            expandable_keys(&row.children, out);
+        }
+    }
+}
+
+impl Table {
+    /// 渲染工具栏中的列设置
+    fn render_column_chooser(&self, layout: LayoutState, mut open: Signal<bool>) -> Element {
+        let current = layout.layout();
+        let props = ordered_props(&self.columns, &current);
+        let visible_count = props
+            .iter()
+            .filter(|prop| !current.hidden.contains(prop))
+            .count();
+        let columns = self.columns.clone();
+        let mut dragging = layout.dragging;
+
+        rsx! {
+            div { class: classnames::TABLE_COLUMN_CHOOSER,
+                button {
+                    r#type: "button",
+                    class: classnames::TABLE_COLUMN_CHOOSER_TRIGGER,
+                    aria_haspopup: "true",
+                    aria_expanded: open(),
+                    onclick: move |_| open.toggle(),
+                    "列设置"
+                }
+                if open() {
+                    div { class: classnames::TABLE_COLUMN_CHOOSER_PANEL,
+                        ul { class: classnames::TABLE_COLUMN_CHOOSER_LIST,
+                            for prop in props.iter().cloned() {
+                                {
+                                    let label = columns
+                                        .iter()
+                                        .find(|column| column.prop == prop)
+                                        .map(|column| column.label.clone())
+                                        .unwrap_or_default();
+                                    let visible = !current.hidden.contains(&prop);
+                                    let is_dragging = dragging.read().as_deref() == Some(prop.as_str());
+                                    let drag_prop = prop.clone();
+                                    let drop_prop = prop.clone();
+                                    let toggle_prop = prop.clone();
+                                    let props = props.clone();
+                                    rsx! {
+                                        li {
+                                            key: "{prop}",
+                                            class: if is_dragging { format!("{} is-dragging", classnames::TABLE_COLUMN_CHOOSER_ITEM) } else { classnames::TABLE_COLUMN_CHOOSER_ITEM.to_string() },
+                                            draggable: "true",
+                                            "data-prop": "{prop}",
+                                            ondragstart: move |event: DragEvent| {
+                                                let _ = event.data_transfer().set_data("text/plain", &drag_prop);
+                                                dragging.set(Some(drag_prop.clone()));
+                                            },
+                                            ondragover: move |event: DragEvent| event.prevent_default(),
+                                            ondrop: move |event: DragEvent| {
+                                                event.prevent_default();
+                                                let Some(from) = dragging.take() else {
+                                                    return;
+                                                };
+                                                let mut order = props.clone();
+                                                move_prop(&mut order, &from, &drop_prop);
+                                                layout.update(|layout| layout.order = order);
+                                            },
+                                            ondragend: move |_| dragging.set(None),
+                                            span { class: classnames::TABLE_COLUMN_CHOOSER_HANDLE, aria_hidden: "true", "⋮⋮" }
+                                            label {
+                                                input {
+                                                    r#type: "checkbox",
+                                                    checked: visible,
+                                                    // 至少保留一列
+                                                    disabled: visible && visible_count <= 1,
+                                                    onchange: move |_| {
+                                                        let prop = toggle_prop.clone();
+                                                        layout
+                                                            .update(|layout| {
+                                                                if let Some(index) = layout.hidden.iter().position(|p| *p == prop) {
+                                                                    layout.hidden.remove(index);
+                                                                } else {
+                                                                    layout.hidden.push(prop);
+                                                                }
+                                                            });
+                                                    },
+                                                }
+                                                "{label}"
+                                            }
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                        button {
+                            r#type: "button",
+                            class: classnames::TABLE_COLUMN_CHOOSER_RESET,
+                            onclick: move |_| layout.reset(),
+                            "重置"
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// 渲染单元格，`caret` 为树形模式第一列的层级和展开状态
+    fn render_cell(
+        &self,
+        row: &TableRow,
+        column: &TableColumn,
+        caret: Option<(usize, TreeState)>,
+    ) -> Element {
+        let value = row.get(&column.prop).unwrap_or_default();
+        let caret = caret.map(|(depth, state)| self.render_caret(row, depth, state));
+
+        if let Some(render) = column.render {
+            let element = render.call(row.clone());
+            return rsx! {
+                td { class: classnames::TABLE_CELL, text_align: column.align.as_str(),
+                    div {
+                        class: classnames::TABLE_CELL_CONTENT,
+                        justify_content: column.align.justify(),
+                        {caret}
+                        TableCellElement { element }
+                    }
+                }
+            };
+        }
+
+        if !column.show_overflow_tooltip && !column.copyable {
+            return rsx! {
+                td { class: classnames::TABLE_CELL, text_align: column.align.as_str(),
+                    {caret}
+                    {value}
+                }
+            };
+        }
+
+        let mut text = Text::span(value).copyable(column.copyable && !value.is_empty());
+        if column.show_overflow_tooltip {
+            text = text.ellipsis(1);
+        }
+        let class = if column.show_overflow_tooltip {
+            format!(
+                "{} {}",
+                classnames::TABLE_CELL,
+                classnames::TABLE_CELL_ELLIPSIS
+            )
+        } else {
+            classnames::TABLE_CELL.to_string()
+        };
+
+        rsx! {
+            td { class, text_align: column.align.as_str(),
+                div {
+                    class: classnames::TABLE_CELL_CONTENT,
+                    justify_content: column.align.justify(),
+                    {caret}
+                    {text.to_element()}
+                }
+            }
+        }
+    }
+
+    /// 渲染表头单元格，可排序的列显示排序按钮
+    fn render_header_cell(
+        &self,
+        column: &TableColumn,
+        mut sort: Signal<Option<TableSort>>,
+    ) -> Element {
+        let style = column.width.as_ref().map(|w| format!("width: {w}"));
+        if !column.sortable {
+            return rsx! {
+                th {
+                    class: classnames::TABLE_HEADER_CELL,
+                    style,
+                    text_align: column.align.as_str(),
+                    "{column.label}"
+                }
+            };
+        }
+
+        let order = sort
+            .read()
+            .as_ref()
+            .filter(|sort| sort.prop == column.prop)
+            .map(|sort| sort.order);
+        let mut class = vec![classnames::TABLE_SORT];
+        if let Some(order) = order {
+            class.push(match order {
+                TableSortOrder::Ascending => "is-ascending",
+                TableSortOrder::Descending => "is-descending",
+            });
+        }
+        let class = class.join(" ");
+        let prop = column.prop.clone();
+        let onsort = self.onsort;
+
+        rsx! {
+            th {
+                class: classnames::TABLE_HEADER_CELL,
+                style,
+                text_align: column.align.as_str(),
+                aria_sort: order.map(|order| order.as_str()).unwrap_or("none"),
+                button {
+                    r#type: "button",
+                    class,
+                    onclick: move |_| {
+                        let next = next_sort(sort.peek().as_ref(), &prop);
+                        sort.set(next.clone());
+                        if let Some(handler) = onsort {
+                            handler.call(next);
+                        }
+                    },
+                    "{column.label}"
+                    span { class: classnames::TABLE_SORT_CARET }
+                }
+            }
+        }
+    }
+
+    /// 渲染行的右键菜单
+    fn render_row_menu(&self, mut menu: Signal<Option<RowMenu>>) -> Element {
+        let Some(current) = menu() else {
+            return rsx! {};
+        };
+        let onrow_menu = self.onrow_menu;
+        let style = format!("left: {}px; top: {}px", current.x, current.y);
+
+        rsx! {
+            div {
+                class: classnames::TABLE_MENU_BACKDROP,
+                onclick: move |_| menu.set(None),
+                oncontextmenu: move |event: MouseEvent| {
+                    event.prevent_default();
+                    menu.set(None);
+                },
+            }
+            div {
+                class: classnames::TABLE_MENU,
+                role: "menu",
+                style,
+                onkeydown: move |event: KeyboardEvent| {
+                    if event.key() == Key::Escape {
+                        menu.set(None);
+                    }
+                },
+                for item in current.items.iter().cloned() {
+                    {
+                        let row = current.row.clone();
+                        let mut class = vec![classnames::TABLE_MENU_ITEM];
+                        if item.danger {
+                            class.push("is-danger");
+                        }
+                        rsx! {
+                            button {
+                                key: "{item.key}",
+                                r#type: "button",
+                                class: class.join(" "),
+                                role: "menuitem",
+                                disabled: item.disabled,
+                                onclick: move |_| {
+                                    menu.set(None);
+                                    if let Some(handler) = onrow_menu {
+                                        handler.call((row.clone(), item.key.clone()));
+                                    }
+                                },
+                                "{item.label}"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+
+    /// 渲染树形模式第一列的缩进和展开按钮
+    fn render_caret(&self, row: &TableRow, depth: usize, state: TreeState) -> Element {
+        let indent = format!("width: {}px", depth as u32 * self.indent);
+        let expanded = state.expanded.read().contains(&row.key);
+        let loading = state.loading.read().contains(&row.key);
+
+        let mut class = vec![classnames::TABLE_EXPAND];
+        if expanded {
+            class.push("is-expanded");
+        }
+        if loading {
+            class.push("is-loading");
+        }
+        let class = class.join(" ");
+        let row = row.clone();
+
+        rsx! {
+            span { class: classnames::TABLE_INDENT, style: indent }
+            if row.is_leaf() {
+                span { class: classnames::TABLE_EXPAND_PLACEHOLDER }
+            } else {
+                button {
+                    r#type: "button",
+                    class,
+                    aria_expanded: expanded,
+                    aria_busy: loading,
+                    aria_label: if expanded { "折叠" } else { "展开" },
+                    onclick: move |event: MouseEvent| {
+                        event.stop_propagation();
+                        state.toggle(row.clone());
+                    },
+                }
+            }
+        }
+    }
+}
+
+impl ToElement for Table {
+    fn to_element(&self) -> Element {
+        if self.data_source.is_some() {
+            return rsx! {
+                TableDataElement { table: Rc::new(self.clone()) }
+            };
+        }
+
+        let id = self.id.clone();
+        let mut class = self.class.to_string();
+        if self.striped {
+            class.push(' ');
+            class.push_str(classnames::TABLE_STRIPED);
+        }
+        if self.bordered {
+            class.push(' ');
+            class.push_str(classnames::TABLE_BORDERED);
+        }
+        if self.print_friendly {
+            class.push(' ');
+            class.push_str(classnames::TABLE_PRINT_FRIENDLY);
+        }
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+
+        let default_sort = self.default_sort.clone();
+        let sort = use_signal(move || default_sort);
+        let local_sort = sort.read().clone().filter(|_| !self.manual_sort);
+
+        let rows = self.rows.clone();
+        let default_expand_all = self.default_expand_all;
+        let default_expanded = self.default_expanded.clone();
+        let expanded = use_signal(move || {
+            let mut keys = default_expanded.into_iter().collect::<HashSet<_>>();
+            if default_expand_all {
+                expandable_keys(&rows, &mut keys);
+            }
+            keys
+        });
+        let state = TreeState {
+            expanded,
+            loaded: use_signal(HashMap::new),
+            loading: use_signal(HashSet::new),
+            load_children: self.load_children,
+            onexpand: self.onexpand,
+        };
+
+        let local_settings = use_signal(Settings::default);
+        let settings = match self.layout_key {
+            Some(_) => try_consume_context::<Signal<Settings>>().unwrap_or(local_settings),
+            None => local_settings,
+        };
+        let layout_key = self.layout_key.clone().unwrap_or_default();
+        let layout = LayoutState {
+            settings,
+            key: use_signal(move || layout_key),
+            dragging: use_signal(|| None),
+        };
+        let chooser_open = use_signal(|| false);
+
+        let current = layout.layout();
+        let columns = ordered_props(&self.columns, &current)
+            .into_iter()
+            .filter(|prop| !current.hidden.contains(prop))
+            .filter_map(|prop| self.columns.iter().find(|column| column.prop == prop))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        let tree = self.rows.iter().any(|row| !row.is_leaf());
+        let mut visible = Vec::new();
+        state.visible_rows(&self.rows, 0, local_sort.as_ref(), &mut visible);
+        let selectable = self.selectable;
+        let colspan = columns.len().max(1) + usize::from(selectable);
+
+        let local_selection = use_signal(Vec::new);
+        let mut selection = self.selection.unwrap_or(local_selection);
+        let onselection_change = self.onselection_change;
+        let mut select = move |keys: Vec<String>| {
+            toggle_selection(&mut selection.write(), &keys);
+            if let Some(handler) = onselection_change {
+                handler.call(selection.peek().clone());
+            }
+        };
+        let page_keys = visible
+            .iter()
+            .map(|(row, _)| row.key.clone())
+            .collect::<Vec<_>>();
+        let selected_count = page_keys
+            .iter()
+            .filter(|key| selection.read().contains(key))
+            .count();
+
+        let mut focused = use_signal(|| None::<String>);
+        let mut menu = use_signal(|| None::<RowMenu>);
+        let onrow_activate = self.onrow_activate;
+        let row_context_menu = self.row_context_menu;
+
+        // 键盘切换聚焦的行后滚动到可见区域
+        let mut scroll_pending = use_signal(|| false);
+        use_effect(move || {
+            if focused().is_none() || !*scroll_pending.peek() || cfg!(feature = "server") {
+                return;
+            }
+            scroll_pending.set(false);
+            document::eval(&format!(
+                "document.activeElement?.querySelector('.{} > .is-focused')?.scrollIntoView({{ block: 'nearest' }});",
+                classnames::TABLE_BODY
+            ));
+        });
+
+        let visible_rows = visible
+            .iter()
+            .map(|(row, _)| row.clone())
+            .collect::<Vec<_>>();
+        let onkeydown = move |event: KeyboardEvent| {
+            let keys = visible_rows
+                .iter()
+                .map(|row| row.key.as_str())
+                .collect::<Vec<_>>();
+            let current = focused.peek().clone();
+            let row = current
+                .as_deref()
+                .and_then(|key| visible_rows.iter().find(|row| row.key == key))
+                .cloned();
+            let next = match event.key() {
+                Key::ArrowDown => step_focus(&keys, current.as_deref(), 1),
+                Key::ArrowUp => step_focus(&keys, current.as_deref(), -1),
+                Key::Escape => {
+                    menu.set(None);
+                    return;
+                }
+                Key::Home => step_focus(&keys, None, 1),
+                Key::End => step_focus(&keys, None, -1),
+                Key::Enter => {
+                    if let (Some(row), Some(handler)) = (row, onrow_activate) {
+                        event.prevent_default();
+                        handler.call(row);
+                    }
+                    return;
+                }
+                Key::ArrowRight | Key::ArrowLeft => {
+                    let Some(row) = row else {
+                        return;
+                    };
+                    let expand = event.key() == Key::ArrowRight;
+                    if !row.is_leaf() && state.expanded.peek().contains(&row.key) != expand {
+                        event.prevent_default();
+                        state.toggle(row);
+                    }
+                    return;
+                }
+                _ => return,
+            };
+            event.prevent_default();
+            scroll_pending.set(true);
+            focused.set(next);
+        };
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                {self.childrens_to_element()}
+                if self.column_chooser {
+                    div { class: classnames::TABLE_TOOLBAR,
+                        {self.render_column_chooser(layout, chooser_open)}
+                    }
+                }
+                {self.render_row_menu(menu)}
+                div {
+                    class: classnames::TABLE_WRAPPER,
+                    tabindex: "0",
+                    onkeydown,
+                    table {
+                        class: classnames::TABLE_INNER,
+                        role: if tree { "treegrid" } else { "table" },
+                        thead {
+                            tr {
+                                if selectable {
+                                    th { class: "{classnames::TABLE_HEADER_CELL} {classnames::TABLE_SELECTION}",
+                                        input {
+                                            r#type: "checkbox",
+                                            aria_label: "选择全部行",
+                                            checked: selected_count > 0 && selected_count == page_keys.len(),
+                                            aria_checked: if selected_count > 0 && selected_count < page_keys.len() { "mixed" },
+                                            disabled: page_keys.is_empty(),
+                                            onchange: move |_| select(page_keys.clone()),
+                                        }
+                                    }
+                                }
+                                for column in columns.iter() {
+                                    {self.render_header_cell(column, sort)}
+                                }
+                            }
+                        }
+                        tbody { class: classnames::TABLE_BODY,
+                            if visible.is_empty() {
+                                tr {
+                                    td { class: classnames::TABLE_EMPTY, colspan,
+                                        if let Some(empty) = self.empty.clone() {
+                                            TableCellElement { element: empty }
+                                        } else {
+                                            "{self.empty_text}"
+                                        }
+                                    }
+                                }
+                            }
+                            for (row , depth) in visible.iter() {
+                                tr {
+                                    key: "{row.key}",
+                                    class: {
+                                        let mut class = vec![classnames::TABLE_ROW];
+                                        if focused.read().as_deref() == Some(row.key.as_str()) {
+                                            class.push("is-focused");
+                                        }
+                                        if selectable && selection.read().contains(&row.key) {
+                                            class.push("is-selected");
+                                        }
+                                        class.join(" ")
+                                    },
+                                    aria_selected: if selectable { Some(selection.read().contains(&row.key)) } else { None },
+                                    "data-key": "{row.key}",
+                                    aria_level: if tree { Some(depth + 1) } else { None },
+                                    onclick: {
+                                        let key = row.key.clone();
+                                        move |_| focused.set(Some(key.clone()))
+                                    },
+                                    oncontextmenu: {
+                                        let row = row.clone();
+                                        move |event: MouseEvent| {
+                                            let Some(build) = row_context_menu else {
+                                                return;
+                                            };
+                                            let items = build.call(row.clone());
+                                            if items.is_empty() {
+                                                return;
+                                            }
+                                            event.prevent_default();
+                                            let point = event.client_coordinates();
+                                            focused.set(Some(row.key.clone()));
+                                            menu.set(Some(RowMenu {
+                                                row: row.clone(),
+                                                items,
+                                                x: point.x,
+                                                y: point.y,
+                                            }));
+                                        }
+                                    },
+                                    if selectable {
+                                        td { class: "{classnames::TABLE_CELL} {classnames::TABLE_SELECTION}",
+                                            input {
+                                                r#type: "checkbox",
+                                                aria_label: "选择行",
+                                                checked: selection.read().contains(&row.key),
+                                                onclick: move |event: MouseEvent| event.stop_propagation(),
+                                                onchange: {
+                                                    let key = row.key.clone();
+                                                    move |_| select(vec![key.clone()])
+                                                },
+                                            }
+                                        }
+                                    }
+                                    for (index , column) in columns.iter().enumerate() {
+                                        {self.render_cell(row, column, (tree && index == 0).then_some((*depth, state)))}
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct TableDataElementProps {
+    table: Rc<Table>,
+}
+
+impl PartialEq for TableDataElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.table, &other.table)
+    }
+}
+
+/// 从数据源获取行数据的表格
+#[allow(non_snake_case)]
+fn TableDataElement(props: TableDataElementProps) -> Element {
+    let mut table = (*props.table).clone();
+    let Some(source) = table.data_source.take() else {
+        return table.to_element();
+    };
+    let default_sort = table.default_sort.clone();
+    let inner = use_signal(move || DataQuery {
+        sort: default_sort.map(DataSort::from),
+        ..Default::default()
+    });
+    let mut query = table.query.take().unwrap_or(inner);
+    let resource = use_data_source(source, move || query.read().clone());
+
+    let onsort = table.onsort;
+    if resource.loading() {
+        table = table.class("is-loading");
+    }
+    if let Some(error) = resource.error() {
+        table.empty_text = error;
+        table.empty = None;
+    }
+    table.rows = resource.items();
+    table
+        .manual_sort(true)
+        .onsort(move |sort: Option<TableSort>| {
+            query.write().sort = sort.clone().map(DataSort::from);
+            if let Some(handler) = onsort {
+                handler.call(sort);
+            }
+        })
+        .to_element()
+}
+
+#[derive(Props, Clone)]
+struct TableCellElementProps {
+    element: Rc<dyn ToElement>,
+}
+
+impl PartialEq for TableCellElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.element, &other.element)
+    }
+}
+
+/// 自定义内容以独立的组件作用域渲染，行数变化时不影响表格自身的 hook 顺序
+#[allow(non_snake_case)]
+fn TableCellElement(props: TableCellElementProps) -> Element {
+    props.element.to_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn categories() -> Table {
+        Table::new()
+            .column(TableColumn::new("name", "分类"))
+            .column(TableColumn::new("count", "商品数").align(TableAlign::Right))
+            .row(
+                TableRow::new("digital")
+                    .cell("name", "数码")
+                    .cell("count", "120")
+                    .child(
+                        TableRow::new("phone")
+                            .cell("name", "手机")
+                            .child(TableRow::new("android").cell("name", "安卓")),
+                    ),
+            )
+            .row(TableRow::new("books").cell("name", "图书").lazy(true))
+            .row(TableRow::new("food").cell("name", "食品"))
+            .indent(20)
+    }
+
+    #[test]
+    fn test_table_render() {
+        let html = Harness::with_element(
+            Table::new()
+                .column(TableColumn::new("name", "姓名").width("120px"))
+                .row(TableRow::new("1").cell("name", "张三")),
+        )
+        .html();
+        assert!(html.contains("role=\"table\""));
+        assert!(html.contains("width: 120px"));
+        assert!(html.contains("张三"));
+        assert!(!html.contains(classnames::TABLE_EXPAND));
+
+        let html =
+            Harness::with_element(Table::new().column(TableColumn::new("name", "姓名"))).html();
+        assert!(html.contains("暂无数据"));
+    }
+
+    #[test]
+    fn test_table_tree_expand() {
+        fn app() -> Element {
+            categories().to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(harness.find_all_by_class(classnames::TABLE_ROW).len(), 3);
+        assert!(harness.find_by_attr("role", "treegrid").is_some());
+
+        let caret = harness.find_all_by_class(classnames::TABLE_EXPAND)[0].clone();
+        assert_eq!(caret.attr("aria-expanded"), Some("false"));
+        harness.click(&caret);
+        assert_eq!(harness.find_all_by_class(classnames::TABLE_ROW).len(), 4);
+        let phone = harness.find_by_attr("data-key", "phone").unwrap();
+        assert_eq!(phone.attr("aria-level"), Some("2"));
+
+        // 子行的缩进按层级递增
+        harness.click(&harness.find_all_by_class(classnames::TABLE_EXPAND)[1].clone());
+        assert!(harness.html().contains("width: 40px"));
+
+        harness.click(&harness.find_all_by_class(classnames::TABLE_EXPAND)[0].clone());
+        assert_eq!(harness.find_all_by_class(classnames::TABLE_ROW).len(), 3);
+    }
+
+    #[test]
+    fn test_table_default_expand_all() {
+        fn app() -> Element {
+            categories().default_expand_all(true).to_element()
+        }
+
+        let harness = Harness::new(app);
+        // 懒加载的行不会自动展开
+        assert_eq!(harness.find_all_by_class(classnames::TABLE_ROW).len(), 5);
+    }
+
+    #[test]
+    fn test_table_lazy_children() {
+        fn app() -> Element {
+            let mut loads = use_signal(|| 0);
+            rsx! {
+                {categories()
+                    .load_children(move |row: TableRow| async move {
+                        loads += 1;
+                        vec![TableRow::new(format!("{}-novel", row.key())).cell("name", "小说")]
+                    })
+                    .to_element()}
+                span { class: "loads", "{loads}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        // 数码分类折叠时，第二个展开按钮属于图书分类
+        let toggle_books = |harness: &mut Harness| {
+            let caret = harness.find_all_by_class(classnames::TABLE_EXPAND)[1].clone();
+            harness.click(&caret);
+        };
+        toggle_books(&mut harness);
+        harness.flush();
+        assert!(harness.find_by_text("小说").is_some());
+        assert_eq!(harness.find_by_class("loads").unwrap().text(), "1");
+
+        // 折叠后再次展开不会重复加载
+        toggle_books(&mut harness);
+        assert!(harness.find_by_text("小说").is_none());
+        toggle_books(&mut harness);
+        harness.flush();
+        assert!(harness.find_by_text("小说").is_some());
+        assert_eq!(harness.find_by_class("loads").unwrap().text(), "1");
+    }
+
+    fn users() -> Table {
+        Table::new()
+            .column(TableColumn::new("name", "姓名"))
+            .column(TableColumn::new("email", "邮箱"))
+            .column(TableColumn::new("age", "年龄"))
+            .row(
+                TableRow::new("1")
+                    .cell("name", "张三")
+                    .cell("email", "zhangsan@example.com")
+                    .cell("age", "28"),
+            )
+            .column_chooser(true)
+    }
+
+    fn headers(harness: &Harness) -> Vec<String> {
+        harness
+            .find_all_by_class(classnames::TABLE_HEADER_CELL)
+            .iter()
+            .map(|cell| cell.text().to_string())
+            .collect()
+    }
+
+    #[test]
+    fn test_ordered_props() {
+        let columns = vec![
+            TableColumn::new("a", "A"),
+            TableColumn::new("b", "B"),
+            TableColumn::new("c", "C"),
+        ];
+        // 布局中已删除的列被忽略，新增的列排在最后
+        let layout = TableLayout {
+            order: vec!["c".into(), "removed".into(), "a".into()],
+            hidden: Vec::new(),
+        };
+        assert_eq!(ordered_props(&columns, &layout), ["c", "a", "b"]);
+
+        let mut props = vec!["a".to_string(), "b".into(), "c".into()];
+        move_prop(&mut props, "a", "c");
+        assert_eq!(props, ["b", "c", "a"]);
+        move_prop(&mut props, "a", "b");
+        assert_eq!(props, ["a", "b", "c"]);
+    }
+
+    #[test]
+    fn test_table_column_chooser() {
+        fn app() -> Element {
+            users().to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(
+            harness
+                .find_by_class(classnames::TABLE_COLUMN_CHOOSER_PANEL)
+                .is_none()
+        );
+        let trigger = harness
+            .find_by_class(classnames::TABLE_COLUMN_CHOOSER_TRIGGER)
+            .unwrap();
+        harness.click(&trigger);
+
+        // 隐藏邮箱列，复选框按列的顺序排列
+        let checkboxes = harness.find_all(|el| el.tag() == "input");
+        assert_eq!(checkboxes.len(), 3);
+        harness.change(&checkboxes[1], "false");
+        assert_eq!(headers(&harness), ["姓名", "年龄"]);
+        assert!(harness.find_by_text("zhangsan@example.com").is_none());
+
+        // 把年龄列拖到姓名列之前
+        let age = harness.find_by_attr("data-prop", "age").unwrap();
+        harness.drag(&age, "dragstart");
+        let name = harness.find_by_attr("data-prop", "name").unwrap();
+        harness.drag(&name, "drop");
+        assert_eq!(headers(&harness), ["年龄", "姓名"]);
+
+        let reset = harness
+            .find_by_class(classnames::TABLE_COLUMN_CHOOSER_RESET)
+            .unwrap();
+        harness.click(&reset);
+        assert_eq!(headers(&harness), ["姓名", "邮箱", "年龄"]);
+    }
+
+    #[test]
+    fn test_table_layout_persisted_in_settings() {
+        fn app() -> Element {
+            let settings = use_signal(|| {
+                let mut settings = Settings::default();
+                settings.table_layouts.insert(
+                    "users".to_string(),
+                    TableLayout {
+                        order: vec!["age".into()],
+                        hidden: vec!["email".into()],
+                    },
+                );
+                settings
+            });
+            rsx! {
+                {crate::ConfigProvider::new()
+                    .settings(settings)
+                    .children(users().layout_key("users"))
+                    .to_element()}
+                span { class: "layouts", "{settings.read().table_layouts.len()}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(headers(&harness), ["年龄", "姓名"]);
+
+        harness.click(
+            &harness
+                .find_by_class(classnames::TABLE_COLUMN_CHOOSER_TRIGGER)
+                .unwrap(),
+        );
+        harness.click(
+            &harness
+                .find_by_class(classnames::TABLE_COLUMN_CHOOSER_RESET)
+                .unwrap(),
+        );
+        assert_eq!(headers(&harness), ["姓名", "邮箱", "年龄"]);
+        assert_eq!(harness.find_by_class("layouts").unwrap().text(), "0");
+    }
+
+    #[test]
+    fn test_table_cell_tooltip_and_copy() {
+        fn app() -> Element {
+            Table::new()
+                .column(TableColumn::new("name", "姓名"))
+                .column(TableColumn::new("remark", "备注").show_overflow_tooltip(true))
+                .column(TableColumn::new("email", "邮箱").copyable(true))
+                .row(
+                    TableRow::new("1")
+                        .cell("name", "张三")
+                        .cell("remark", "一段很长很长的备注")
+                        .cell("email", "zhangsan@example.com"),
+                )
+                // 空单元格不显示复制按钮
+                .row(TableRow::new("2").cell("name", "李四"))
+                .to_element()
+        }
+
+        let harness = Harness::new(app);
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::TABLE_CELL_ELLIPSIS)
+                .len(),
+            2
+        );
+        assert_eq!(
+            harness
+                .find_all_by_class(classnames::OVERFLOW_TOOLTIP)
+                .len(),
+            2
+        );
+        assert_eq!(harness.find_all_by_class(classnames::TEXT_COPY).len(), 1);
+        assert!(harness.html().contains("zhangsan@example.com"));
+    }
+
+    #[test]
+    fn test_step_focus() {
+        let keys = ["a", "b", "c"];
+        assert_eq!(step_focus(&keys, None, 1).as_deref(), Some("a"));
+        assert_eq!(step_focus(&keys, None, -1).as_deref(), Some("c"));
+        assert_eq!(step_focus(&keys, Some("b"), 1).as_deref(), Some("c"));
+        assert_eq!(step_focus(&keys, Some("c"), 1).as_deref(), Some("c"));
+        assert_eq!(step_focus(&keys, Some("a"), -1).as_deref(), Some("a"));
+        assert_eq!(step_focus(&[], None, 1), None);
+    }
+
+    #[test]
+    fn test_toggle_selection() {
+        let keys = vec!["a".to_string(), "b".to_string()];
+        let mut selected = vec!["b".to_string(), "c".to_string()];
+        toggle_selection(&mut selected, &keys);
+        assert_eq!(selected, ["b", "c", "a"]);
+        toggle_selection(&mut selected, &keys);
+        assert_eq!(selected, ["c"]);
+        toggle_selection(&mut selected, &[]);
+        assert_eq!(selected, ["c"]);
+    }
+
+    #[test]
+    fn test_table_selection() {
+        fn app() -> Element {
+            let mut changes = use_signal(|| 0);
+            rsx! {
+                {categories().selectable(true).onselection_change(move |_| changes += 1).to_element()}
+                span { class: "changes", "{changes}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let checkboxes = harness.find_all(|el| el.tag() == "input");
+        assert_eq!(checkboxes.len(), 4);
+        assert_eq!(checkboxes[0].attr("aria-label"), Some("选择全部行"));
+
+        harness.change(&checkboxes[1], "true");
+        let rows = harness.find_all_by_class(classnames::TABLE_ROW);
+        assert!(rows[0].has_class("is-selected"));
+        assert_eq!(rows[0].attr("aria-selected"), Some("true"));
+        let header = harness.find_all(|el| el.tag() == "input")[0].clone();
+        assert_eq!(header.attr("aria-checked"), Some("mixed"));
+
+        // 部分选中时表头复选框补充选择其余行，全部选中后再次点击取消全部
+        harness.change(&header, "true");
+        assert_eq!(harness.find_all(|el| el.has_class("is-selected")).len(), 3);
+        harness.change(&header, "false");
+        assert!(
+            harness
+                .find_all(|el| el.has_class("is-selected"))
+                .is_empty()
+        );
+        assert_eq!(harness.find_by_class("changes").unwrap().text(), "3");
+    }
+
+    #[test]
+    fn test_table_keyboard_navigation() {
+        fn app() -> Element {
+            let mut activated = use_signal(String::new);
+            rsx! {
+                {categories()
+                    .onrow_activate(move |row: TableRow| activated.set(row.key().to_string()))
+                    .to_element()}
+                span { class: "activated", "{activated}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let wrapper = harness.find_by_class(classnames::TABLE_WRAPPER).unwrap();
+        let focused = |harness: &Harness| {
+            harness
+                .find_all_by_class(classnames::TABLE_ROW)
+                .into_iter()
+                .filter(|row| row.has_class("is-focused"))
+                .filter_map(|row| row.attr("data-key").map(str::to_string))
+                .collect::<Vec<_>>()
+        };
+
+        harness.keydown(&wrapper, "ArrowDown");
+        assert_eq!(focused(&harness), ["digital"]);
+
+        // 右方向键展开聚焦的行，向下移动进入子行
+        harness.keydown(&wrapper, "ArrowRight");
+        harness.keydown(&wrapper, "ArrowDown");
+        assert_eq!(focused(&harness), ["phone"]);
+
+        harness.keydown(&wrapper, "Enter");
+        assert_eq!(harness.find_by_class("activated").unwrap().text(), "phone");
+
+        harness.keydown(&wrapper, "End");
+        assert_eq!(focused(&harness), ["food"]);
+        harness.keydown(&wrapper, "ArrowUp");
+        assert_eq!(focused(&harness), ["books"]);
+
+        // 点击行同样会聚焦
+        harness.click(&harness.find_by_attr("data-key", "digital").unwrap());
+        assert_eq!(focused(&harness), ["digital"]);
+        harness.keydown(&wrapper, "ArrowLeft");
+        assert!(harness.find_by_attr("data-key", "phone").is_none());
+    }
+
+    #[test]
+    fn test_table_row_context_menu() {
+        fn app() -> Element {
+            let mut selected = use_signal(String::new);
+            rsx! {
+                {users()
+                    .row_context_menu(|row: TableRow| {
+                        vec![
+                            TableMenuItem::new("edit", "编辑"),
+                            TableMenuItem::new("delete", format!("删除 {}", row.get("name").unwrap_or_default()))
+                                .danger(true),
+                        ]
+                    })
+                    .onrow_menu(move |(row, key): (TableRow, String)| {
+                        selected.set(format!("{}:{key}", row.key()));
+                    })
+                    .to_element()}
+                span { class: "selected", "{selected}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::TABLE_MENU).is_none());
+
+        let row = harness.find_by_attr("data-key", "1").unwrap();
+        harness.mouse(&row, "contextmenu");
+        assert_eq!(
+            harness
+                .find_by_class(classnames::TABLE_MENU)
+                .unwrap()
+                .attr("role"),
+            Some("menu")
+        );
+        harness.click(&harness.find_by_text("删除 张三").unwrap());
+        assert!(harness.find_by_class(classnames::TABLE_MENU).is_none());
+        assert_eq!(
+            harness.find_by_class("selected").unwrap().text(),
+            "1:delete"
+        );
+
+        // 点击菜单外部关闭菜单
+        harness.mouse(&row, "contextmenu");
+        let backdrop = harness
+            .find_by_class(classnames::TABLE_MENU_BACKDROP)
+            .unwrap();
+        harness.click(&backdrop);
+        assert!(harness.find_by_class(classnames::TABLE_MENU).is_none());
+    }
+
+    #[test]
+    fn test_sort_rows() {
+        let mut rows = vec![
+            TableRow::new("a").cell("age", "9"),
+            TableRow::new("b").cell("age", "28"),
+            TableRow::new("c").cell("age", "9"),
+        ];
+        sort_rows(
+            &mut rows,
+            &TableSort::new("age", TableSortOrder::Descending),
+        );
+        let keys = rows.iter().map(TableRow::key).collect::<Vec<_>>();
+        assert_eq!(keys, vec!["b", "a", "c"]);
+
+        assert_eq!(compare_cells(Some("b"), Some("a")), Ordering::Greater);
+        assert_eq!(compare_cells(None, Some("a")), Ordering::Less);
+
+        let ascending = next_sort(None, "age");
+        assert_eq!(
+            ascending,
+            Some(TableSort::new("age", TableSortOrder::Ascending))
+        );
+        let descending = next_sort(ascending.as_ref(), "age");
+        assert_eq!(
+            descending.as_ref().map(TableSort::order),
+            Some(TableSortOrder::Descending)
+        );
+        assert_eq!(next_sort(descending.as_ref(), "age"), None);
+        assert_eq!(
+            next_sort(descending.as_ref(), "name"),
+            Some(TableSort::new("name", TableSortOrder::Ascending))
+        );
+    }
+
+    fn members() -> Table {
+        Table::new()
+            .column(TableColumn::new("name", "姓名").sortable(true))
+            .column(TableColumn::new("age", "年龄").sortable(true))
+            .row(TableRow::new("1").cell("name", "张三").cell("age", "28"))
+            .row(TableRow::new("2").cell("name", "李四").cell("age", "9"))
+            .row(TableRow::new("3").cell("name", "王五").cell("age", "35"))
+    }
+
+    fn row_keys(harness: &Harness) -> Vec<String> {
+        harness
+            .find_all_by_class(classnames::TABLE_ROW)
+            .iter()
+            .filter_map(|row| row.attr("data-key").map(str::to_string))
+            .collect()
+    }
+
+    #[test]
+    fn test_table_sort() {
+        fn app() -> Element {
+            let mut sorts = use_signal(Vec::<String>::new);
+            rsx! {
+                {members().onsort(move |sort: Option<TableSort>| {
+                    sorts.write().push(sort.map(|sort| format!("{}:{}", sort.prop(), sort.order().as_str())).unwrap_or_default());
+                }).to_element()}
+                span { class: "sorts", "{sorts.read().join(\",\")}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(row_keys(&harness), vec!["1", "2", "3"]);
+        let header = harness.find_all_by_class(classnames::TABLE_HEADER_CELL)[1].clone();
+        assert_eq!(header.attr("aria-sort"), Some("none"));
+
+        // 依次切换为升序、降序和不排序，数字按数值比较
+        let button = harness.find_all_by_class(classnames::TABLE_SORT)[1].clone();
+        harness.click(&button);
+        assert_eq!(row_keys(&harness), vec!["2", "1", "3"]);
+        let header = harness.find_all_by_class(classnames::TABLE_HEADER_CELL)[1].clone();
+        assert_eq!(header.attr("aria-sort"), Some("ascending"));
+        harness.click(&button);
+        assert_eq!(row_keys(&harness), vec!["3", "1", "2"]);
+        assert!(harness.find_all_by_class(classnames::TABLE_SORT)[1].has_class("is-descending"));
+        harness.click(&button);
+        assert_eq!(row_keys(&harness), vec!["1", "2", "3"]);
+        assert_eq!(
+            harness.find_by_class("sorts").unwrap().text(),
+            "age:ascending,age:descending,"
+        );
+    }
+
+    #[test]
+    fn test_table_manual_sort() {
+        let mut harness = Harness::with_element(
+            members()
+                .manual_sort(true)
+                .default_sort("age", TableSortOrder::Ascending),
+        );
+        // 外部排序时只更新表头状态，行保持传入的顺序
+        assert_eq!(row_keys(&harness), vec!["1", "2", "3"]);
+        assert!(harness.find_all_by_class(classnames::TABLE_SORT)[1].has_class("is-ascending"));
+
+        let button = harness.find_all_by_class(classnames::TABLE_SORT)[0].clone();
+        harness.click(&button);
+        assert_eq!(row_keys(&harness), vec!["1", "2", "3"]);
+        assert!(harness.find_all_by_class(classnames::TABLE_SORT)[0].has_class("is-ascending"));
+        assert!(!harness.find_all_by_class(classnames::TABLE_SORT)[1].has_class("is-ascending"));
+    }
+
+    #[test]
+    fn test_table_data_source() {
+        let people = crate::MemoryDataSource::new(vec![
+            ("1", "张三", 28),
+            ("2", "李四", 9),
+            ("3", "王五", 35),
+        ])
+        .sort_by(|a, b, prop| match prop {
+            "age" => a.2.cmp(&b.2),
+            _ => a.1.cmp(b.1),
+        })
+        .map(|(key, name, age): (&str, &str, u32)| {
+            TableRow::new(key)
+                .cell("name", name)
+                .cell("age", age.to_string())
+        });
+        let mut harness = Harness::with_element(
+            members()
+                .data_source(people)
+                .default_sort("age", TableSortOrder::Descending),
+        );
+        // 默认的排序条件传给数据源
+        assert_eq!(row_keys(&harness), vec!["3", "1", "2"]);
+
+        let button = harness.find_all_by_class(classnames::TABLE_SORT)[1].clone();
+        harness.click(&button);
+        assert_eq!(row_keys(&harness), vec!["1", "2", "3"]);
+        harness.click(&button);
+        assert_eq!(row_keys(&harness), vec!["2", "1", "3"]);
+
+        // 查询失败时显示错误信息
+        let failing =
+            |_: DataQuery| async { Err::<crate::DataPage<TableRow>, _>("服务不可用") };
+        let harness = Harness::with_element(members().data_source(failing));
+        assert_eq!(
+            harness
+                .find_by_class(classnames::TABLE_EMPTY)
+                .unwrap()
+                .text(),
+            "服务不可用"
+        );
+    }
+
+    #[test]
+    fn test_table_print_friendly() {
+        let harness = Harness::with_element(Table::new().column(TableColumn::new("name", "姓名")));
+        let root = harness.find_by_class(classnames::TABLE).unwrap();
+        assert!(!root.has_class(classnames::TABLE_PRINT_FRIENDLY));
+
+        let harness = Harness::with_element(
+            Table::new()
+                .print_friendly(true)
+                .column(TableColumn::new("name", "姓名")),
+        );
+        let root = harness.find_by_class(classnames::TABLE).unwrap();
+        assert!(root.has_class(classnames::TABLE_PRINT_FRIENDLY));
+    }
+
+    #[test]
+    fn test_table_custom_cell_and_empty() {
+        fn app() -> Element {
+            Table::new()
+                .striped(true)
+                .bordered(true)
+                .column(TableColumn::new("name", "姓名"))
+                .column(TableColumn::new("status", "状态").render(|row: TableRow| {
+                    Rc::new(Text::span(format!(
+                        "[{}]",
+                        row.get("status").unwrap_or_default()
+                    )))
+                }))
+                .row(
+                    TableRow::new("1")
+                        .cell("name", "张三")
+                        .cell("status", "在职"),
+                )
+                .to_element()
+        }
+
+        let harness = Harness::new(app);
+        let root = harness.find_by_class(classnames::TABLE).unwrap();
+        assert!(root.has_class(classnames::TABLE_STRIPED));
+        assert!(root.has_class(classnames::TABLE_BORDERED));
+        assert!(harness.find_by_text("[在职]").is_some());
+
+        let harness = Harness::with_element(
+            Table::new()
+                .column(TableColumn::new("name", "姓名"))
+                .empty(Text::span("还没有成员")),
+        );
+        let empty = harness.find_by_class(classnames::TABLE_EMPTY).unwrap();
+        assert_eq!(empty.text(), "还没有成员");
+        assert!(harness.find_by_text("暂无数据").is_none());
+    }
+}
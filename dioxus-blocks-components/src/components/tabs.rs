@@ -0,0 +1,724 @@
+//! Tabs 标签页组件
+//!
+//! 把内容分为多个 [`TabPane`]，同一时间只显示一个，点击标签切换。标签栏可以放在内容的上、下、左、右四个方向，
+//! 提供线条和卡片两种样式。
+//!
+//! 当前标签的 key 可以通过 [`Tabs::active`] 传入的 `Signal<String>` 控制，未传入时由组件内部保存，
+//! 初始为 [`Tabs::default_active`] 或第一个可用的标签。
+//!
+//! 默认开启 [`Tabs::lazy`]：标签页的内容在首次切换到该标签时才创建，之后切换到其他标签时只是隐藏，
+//! 保留其中的状态，适合内容较多或需要加载数据的标签页。
+//!
+//! 开启 [`Tabs::closable`] 或 [`TabPane::closable`] 后标签上显示关闭按钮，关闭时调用 [`Tabs::onclose`]，
+//! 由调用方从标签列表中移除；关闭的是当前标签时先切换到相邻的标签。
+//!
+//! 标签栏获得焦点后可以用方向键切换标签，Home、End 切换到第一个和最后一个标签，可关闭的标签按 Delete 关闭。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{TabPane, Tabs, TabsPlacement, TabsType, Text, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let active = use_signal(|| "profile".to_string());
+//!
+//!     Tabs::new()
+//!         .active(active)
+//!         .tab_type(TabsType::Card)
+//!         .placement(TabsPlacement::Top)
+//!         .pane(TabPane::new("profile", "个人资料").children(Text::p("个人资料")))
+//!         .pane(TabPane::new("security", "账号安全").children(Text::p("账号安全")))
+//!         .pane(TabPane::new("billing", "账单").disabled(true))
+//!         .onchange(|key| println!("切换到 {key}"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::collections::HashSet;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement, use_unique_id};
+
+/// 标签栏的位置
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabsPlacement {
+    /// 内容上方
+    #[default]
+    Top,
+    /// 内容左侧
+    Left,
+    /// 内容下方
+    Bottom,
+    /// 内容右侧
+    Right,
+}
+
+impl TabsPlacement {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            TabsPlacement::Top => "t-tabs--top",
+            TabsPlacement::Left => "t-tabs--left",
+            TabsPlacement::Bottom => "t-tabs--bottom",
+            TabsPlacement::Right => "t-tabs--right",
+        }
+    }
+
+    /// 标签是否纵向排列
+    fn is_vertical(&self) -> bool {
+        matches!(self, TabsPlacement::Left | TabsPlacement::Right)
+    }
+}
+
+/// 标签的样式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TabsType {
+    /// 当前标签下显示线条
+    #[default]
+    Line,
+    /// 标签显示为卡片
+    Card,
+}
+
+impl TabsType {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            TabsType::Line => "t-tabs--line",
+            TabsType::Card => "t-tabs--card",
+        }
+    }
+}
+
+/// 标签页
+#[derive(Debug, Clone, ComponentBase)]
+pub struct TabPane {
+    /// 组件的唯一标识符，设置在内容区域上
+    id: Option<String>,
+    /// 组件的CSS类名，设置在内容区域上
+    class: Cow<'static, str>,
+    /// 组件的内联样式，设置在内容区域上
+    style: Option<Style>,
+    /// 组件的子元素列表，作为标签页的内容
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 标签页的唯一标识
+    key: String,
+    /// 标签的文本
+    label: String,
+    /// 是否禁用
+    disabled: bool,
+    /// 是否可以关闭，未设置时使用 [`Tabs::closable`]
+    closable: Option<bool>,
+}
+
+impl Default for TabPane {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::TABS_PANE),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            key: String::new(),
+            label: String::new(),
+            disabled: false,
+            closable: None,
+        }
+    }
+}
+
+#[builder_props]
+impl TabPane {
+    /// 创建标签页
+    pub fn new(key: impl Into<String>, label: impl Into<String>) -> Self {
+        Self {
+            key: key.into(),
+            label: label.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置禁用状态，禁用的标签不能切换和关闭
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置是否可以关闭，覆盖 [`Tabs::closable`]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = Some(closable);
+        self
+    }
+
+    /// 标签页的唯一标识
+    pub fn key(&self) -> &str {
+        &self.key
+    }
+}
+
+impl ToElement for TabPane {
+    fn to_element(&self) -> Element {
+        self.childrens_to_element()
+    }
+}
+
+/// 标签页组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Tabs {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，显示在标签栏的末尾
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 标签页列表
+    panes: Vec<TabPane>,
+    /// 当前标签的 key，未设置时在组件内保存
+    active: Option<Signal<String>>,
+    /// 默认的标签 key
+    default_active: Option<String>,
+    /// 标签栏的位置
+    placement: TabsPlacement,
+    /// 标签的样式
+    tab_type: TabsType,
+    /// 标签是否可以关闭
+    closable: bool,
+    /// 是否在首次切换到标签时才创建内容
+    lazy: bool,
+    /// 切换标签时的回调
+    onchange: Option<EventHandler<String>>,
+    /// 关闭标签时的回调
+    onclose: Option<EventHandler<String>>,
+}
+
+impl Default for Tabs {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::TABS),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            panes: Vec::new(),
+            active: None,
+            default_active: None,
+            placement: TabsPlacement::default(),
+            tab_type: TabsType::default(),
+            closable: false,
+            lazy: true,
+            onchange: None,
+            onclose: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Tabs {
+    /// 创建标签页组件
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 添加标签页
+    pub fn pane(mut self, pane: TabPane) -> Self {
+        self.panes.push(pane);
+        self
+    }
+
+    /// 设置标签页列表
+    pub fn panes(mut self, panes: Vec<TabPane>) -> Self {
+        self.panes = panes;
+        self
+    }
+
+    /// 设置当前标签 key 的 Signal，切换标签时写入新的 key
+    pub fn active(mut self, active: Signal<String>) -> Self {
+        self.active = Some(active);
+        self
+    }
+
+    /// 设置未传入 [`Tabs::active`] 时默认的标签，未设置时为第一个可用的标签
+    pub fn default_active(mut self, key: impl Into<String>) -> Self {
+        self.default_active = Some(key.into());
+        self
+    }
+
+    /// 设置标签栏的位置
+    #[prop(default = "TabsPlacement::Top")]
+    pub fn placement(mut self, placement: TabsPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// 设置标签的样式
+    #[prop(default = "TabsType::Line")]
+    pub fn tab_type(mut self, tab_type: TabsType) -> Self {
+        self.tab_type = tab_type;
+        self
+    }
+
+    /// 设置标签是否可以关闭，可以通过 [`TabPane::closable`] 单独设置
+    #[prop(default = "false")]
+    pub fn closable(mut self, closable: bool) -> Self {
+        self.closable = closable;
+        self
+    }
+
+    /// 设置是否在首次切换到标签时才创建内容，关闭后全部标签页的内容在首次渲染时创建
+    #[prop(default = "true")]
+    pub fn lazy(mut self, lazy: bool) -> Self {
+        self.lazy = lazy;
+        self
+    }
+
+    /// 设置切换标签时的回调，参数为新的标签 key
+    pub fn onchange(mut self, handler: impl FnMut(String) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置关闭标签时的回调，参数为关闭的标签 key，由调用方从标签列表中移除
+    pub fn onclose(mut self, handler: impl FnMut(String) + 'static) -> Self {
+        self.onclose = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for Tabs {
+    fn to_element(&self) -> Element {
+        rsx! {
+            TabsElement { tabs: Rc::new(self.clone()) }
+        }
+    }
+}
+
+/// 把焦点移到标签上
+fn focus_tab(id: &str) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    document::eval(&format!("document.getElementById({id:?})?.focus();"));
+}
+
+/// 从 `from` 开始按 `step` 方向找到下一个可用的标签，到达末尾后从另一端继续
+fn step_enabled(panes: &[TabPane], from: usize, step: isize) -> Option<usize> {
+    let len = panes.len() as isize;
+    (1..=len)
+        .map(|offset| (from as isize + step * offset).rem_euclid(len) as usize)
+        .find(|&index| !panes[index].disabled)
+}
+
+#[derive(Props, Clone)]
+struct TabsElementProps {
+    tabs: Rc<Tabs>,
+}
+
+impl PartialEq for TabsElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.tabs, &other.tabs)
+    }
+}
+
+#[allow(non_snake_case)]
+fn TabsElement(props: TabsElementProps) -> Element {
+    let tabs = props.tabs;
+    let uid = use_unique_id("tabs");
+    let inner = use_signal(|| {
+        tabs.default_active
+            .clone()
+            .or_else(|| {
+                tabs.panes
+                    .iter()
+                    .find(|pane| !pane.disabled)
+                    .map(|pane| pane.key.clone())
+            })
+            .unwrap_or_default()
+    });
+    let mut active = tabs.active.unwrap_or(inner);
+    // 已经显示过的标签，懒加载时保留这些标签的内容
+    let mut visited = use_signal(HashSet::<String>::new);
+
+    // 当前标签无效时（如已被关闭）显示第一个可用的标签
+    let current = {
+        let key = active.read();
+        tabs.panes
+            .iter()
+            .position(|pane| pane.key == *key && !pane.disabled)
+            .or_else(|| tabs.panes.iter().position(|pane| !pane.disabled))
+    };
+    use_effect(move || {
+        let key = active();
+        if !visited.peek().contains(&key) {
+            visited.write().insert(key);
+        }
+    });
+
+    let onchange = tabs.onchange;
+    let onclose = tabs.onclose;
+    let panes = Rc::new(tabs.panes.clone());
+    let tab_id = {
+        let uid = uid.clone();
+        move |index: usize| format!("{uid}-tab-{index}")
+    };
+    let panel_id = {
+        let uid = uid.clone();
+        move |index: usize| format!("{uid}-panel-{index}")
+    };
+
+    let select = {
+        let panes = panes.clone();
+        move |index: usize| {
+            let key = panes[index].key.clone();
+            if *active.peek() == key {
+                return;
+            }
+            // 当前标签可能是未记录的默认标签，离开时同样保留内容
+            if let Some(from) = current {
+                visited.write().insert(panes[from].key.clone());
+            }
+            active.set(key.clone());
+            if let Some(handler) = onchange {
+                handler.call(key);
+            }
+        }
+    };
+    let close = {
+        let panes = panes.clone();
+        let mut select = select.clone();
+        move |index: usize| {
+            let key = panes[index].key.clone();
+            if current == Some(index)
+                && let Some(next) = step_enabled(&panes, index, 1).filter(|&next| next != index)
+            {
+                select(next);
+            }
+            visited.write().remove(&key);
+            if let Some(handler) = onclose {
+                handler.call(key);
+            }
+        }
+    };
+    let closable = |pane: &TabPane| pane.closable.unwrap_or(tabs.closable) && !pane.disabled;
+
+    let vertical = tabs.placement.is_vertical();
+    let onkeydown = {
+        let panes = panes.clone();
+        let mut select = select.clone();
+        let mut close = close.clone();
+        let tab_id = tab_id.clone();
+        let closable_keys = panes.iter().map(closable).collect::<Vec<_>>();
+        move |event: KeyboardEvent| {
+            let Some(from) = current else {
+                return;
+            };
+            let (prev, next) = match vertical {
+                true => (Key::ArrowUp, Key::ArrowDown),
+                false => (Key::ArrowLeft, Key::ArrowRight),
+            };
+            let key = event.key();
+            let target = if key == prev {
+                step_enabled(&panes, from, -1)
+            } else if key == next {
+                step_enabled(&panes, from, 1)
+            } else if key == Key::Home {
+                panes.iter().position(|pane| !pane.disabled)
+            } else if key == Key::End {
+                panes.iter().rposition(|pane| !pane.disabled)
+            } else if key == Key::Delete && closable_keys[from] {
+                event.prevent_default();
+                close(from);
+                return;
+            } else {
+                return;
+            };
+            event.prevent_default();
+            if let Some(index) = target {
+                select(index);
+                focus_tab(&tab_id(index));
+            }
+        }
+    };
+
+    let class = format!(
+        "{} {} {}",
+        tabs.class,
+        tabs.placement.as_class(),
+        tabs.tab_type.as_class()
+    );
+    let style = tabs.style.clone().map(|s| s.to_string());
+    let onclick_handler = tabs.onclick;
+    let lazy = tabs.lazy;
+    let rendered = visited.read().clone();
+
+    rsx! {
+        div {
+            id: tabs.id.clone(),
+            class,
+            style,
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            div { class: classnames::TABS_NAV,
+                div {
+                    class: classnames::TABS_LIST,
+                    role: "tablist",
+                    "aria-orientation": if vertical { "vertical" } else { "horizontal" },
+                    onkeydown,
+                    for (index , pane) in panes.iter().enumerate() {
+                        div {
+                            key: "{pane.key}",
+                            id: tab_id(index),
+                            class: {
+                                let mut class = vec![classnames::TABS_TAB];
+                                if current == Some(index) {
+                                    class.push("is-active");
+                                }
+                                if pane.disabled {
+                                    class.push("is-disabled");
+                                }
+                                class.join(" ")
+                            },
+                            role: "tab",
+                            tabindex: if current == Some(index) { "0" } else { "-1" },
+                            "aria-selected": current == Some(index),
+                            "aria-controls": panel_id(index),
+                            "aria-disabled": pane.disabled.then_some("true"),
+                            onclick: {
+                                let mut select = select.clone();
+                                let disabled = pane.disabled;
+                                move |_| {
+                                    if !disabled {
+                                        select(index);
+                                    }
+                                }
+                            },
+                            span { class: classnames::TABS_LABEL, "{pane.label}" }
+                            if closable(pane) {
+                                button {
+                                    class: classnames::TABS_CLOSE,
+                                    r#type: "button",
+                                    tabindex: "-1",
+                                    "aria-label": "关闭 {pane.label}",
+                                    onclick: {
+                                        let mut close = close.clone();
+                                        move |event: MouseEvent| {
+                                            event.stop_propagation();
+                                            close(index);
+                                        }
+                                    },
+                                    "×"
+                                }
+                            }
+                        }
+                    }
+                }
+                {tabs.childrens_to_element()}
+            }
+            div { class: classnames::TABS_CONTENT,
+                for (index , pane) in panes.iter().enumerate() {
+                    if !lazy || current == Some(index) || rendered.contains(&pane.key) {
+                        div {
+                            key: "{pane.key}",
+                            id: pane.id.clone().unwrap_or_else(|| panel_id(index)),
+                            class: "{pane.class}",
+                            style: pane.style.clone().map(|s| s.to_string()),
+                            role: "tabpanel",
+                            "aria-labelledby": tab_id(index),
+                            hidden: current != Some(index),
+                            TabPanelElement { pane: Rc::new(pane.clone()) }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct TabPanelElementProps {
+    pane: Rc<TabPane>,
+}
+
+impl PartialEq for TabPanelElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.pane, &other.pane)
+    }
+}
+
+/// 标签页的内容，作为独立的组件渲染，其中的 hooks 不受其他标签页的显示和隐藏影响
+#[allow(non_snake_case)]
+fn TabPanelElement(props: TabPanelElementProps) -> Element {
+    props.pane.to_element()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{ElementRef, Harness};
+
+    #[derive(Debug, Clone)]
+    struct Counter(&'static str);
+
+    impl ToElement for Counter {
+        fn to_element(&self) -> Element {
+            let mut count = use_signal(|| 0);
+            rsx! {
+                button { class: "counter-{self.0}", onclick: move |_| count += 1, "{count}" }
+            }
+        }
+    }
+
+    fn panes() -> Vec<TabPane> {
+        vec![
+            TabPane::new("a", "标签 A").children(Counter("a")),
+            TabPane::new("b", "标签 B").children(Counter("b")),
+            TabPane::new("c", "标签 C")
+                .disabled(true)
+                .children(Counter("c")),
+            TabPane::new("d", "标签 D").children(Counter("d")),
+        ]
+    }
+
+    fn tab(harness: &Harness, label: &str) -> ElementRef {
+        harness
+            .find_all_by_class(classnames::TABS_TAB)
+            .into_iter()
+            .find(|tab| tab.text().starts_with(label))
+            .unwrap()
+    }
+
+    fn selected(harness: &Harness) -> String {
+        harness
+            .find_by_attr("aria-selected", "true")
+            .unwrap()
+            .text()
+            .trim_end_matches('×')
+            .to_string()
+    }
+
+    #[test]
+    fn test_step_enabled() {
+        let panes = panes();
+        assert_eq!(step_enabled(&panes, 1, 1), Some(3));
+        assert_eq!(step_enabled(&panes, 3, 1), Some(0));
+        assert_eq!(step_enabled(&panes, 0, -1), Some(3));
+    }
+
+    #[test]
+    fn test_tabs_lazy_panes_keep_state() {
+        fn app() -> Element {
+            let active = use_signal(String::new);
+            rsx! {
+                {Tabs::new().active(active).panes(panes()).to_element()}
+                span { class: "active", "{active}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(selected(&harness), "标签 A");
+        // 未切换过的标签不创建内容
+        assert!(harness.find_by_class("counter-b").is_none());
+
+        let counter = harness.find_by_class("counter-a").unwrap();
+        harness.click(&counter);
+        let tab_b = tab(&harness, "标签 B");
+        harness.click(&tab_b);
+        assert_eq!(harness.find_by_class("active").unwrap().text(), "b");
+        assert_eq!(selected(&harness), "标签 B");
+        assert!(harness.find_by_class("counter-b").is_some());
+
+        // 隐藏的标签页保留状态
+        let panels = harness.find_all(|element| element.attr("role") == Some("tabpanel"));
+        assert_eq!(panels.len(), 2);
+        assert!(panels[0].attr("hidden").is_some());
+        let tab_a = tab(&harness, "标签 A");
+        harness.click(&tab_a);
+        assert_eq!(harness.find_by_class("counter-a").unwrap().text(), "1");
+
+        // 禁用的标签不能切换
+        let tab_c = tab(&harness, "标签 C");
+        harness.click(&tab_c);
+        assert_eq!(selected(&harness), "标签 A");
+    }
+
+    #[test]
+    fn test_tabs_keyboard() {
+        let mut harness = Harness::with_element(
+            Tabs::new()
+                .placement(TabsPlacement::Left)
+                .lazy(false)
+                .panes(panes()),
+        );
+        assert_eq!(harness.find_all_by_class("t-tabs__pane").len(), 4);
+        let list = harness.find_by_attr("role", "tablist").unwrap();
+        assert_eq!(list.attr("aria-orientation"), Some("vertical"));
+
+        harness.keydown(&list, "ArrowDown");
+        assert_eq!(selected(&harness), "标签 B");
+        // 跳过禁用的标签
+        harness.keydown(&list, "ArrowDown");
+        assert_eq!(selected(&harness), "标签 D");
+        harness.keydown(&list, "ArrowDown");
+        assert_eq!(selected(&harness), "标签 A");
+        harness.keydown(&list, "End");
+        assert_eq!(selected(&harness), "标签 D");
+        // 横向的方向键在纵向标签栏中无效
+        harness.keydown(&list, "ArrowRight");
+        assert_eq!(selected(&harness), "标签 D");
+    }
+
+    #[test]
+    fn test_tabs_close() {
+        fn app() -> Element {
+            let mut keys = use_signal(|| vec!["a", "b", "d"]);
+            let all = panes();
+            let panes = keys
+                .read()
+                .iter()
+                .filter_map(|key| all.iter().find(|pane| pane.key() == *key).cloned())
+                .collect();
+            rsx! {
+                {
+                    Tabs::new()
+                        .closable(true)
+                        .default_active("b")
+                        .panes(panes)
+                        .onclose(move |key: String| keys.write().retain(|k| *k != key))
+                        .to_element()
+                }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let close = harness.find_by_attr("aria-label", "关闭 标签 B").unwrap();
+        harness.click(&close);
+        assert!(harness.find_by_text("标签 B").is_none());
+        // 关闭当前标签后切换到下一个标签
+        assert_eq!(selected(&harness), "标签 D");
+
+        let list = harness.find_by_attr("role", "tablist").unwrap();
+        harness.keydown(&list, "Delete");
+        assert_eq!(harness.find_all_by_class(classnames::TABS_TAB).len(), 1);
+        assert_eq!(selected(&harness), "标签 A");
+    }
+}
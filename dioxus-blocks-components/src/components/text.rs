@@ -24,16 +24,17 @@
 //! # });
 //! # dom.rebuild(&mut dioxus_core::NoOpMutations);
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{OverflowTooltip, Style, classnames, copy_text, traits::ToElement};
 
 /// 文本标签
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
 pub enum TextTag {
     H1,
     H2,
@@ -54,7 +55,7 @@ pub struct Text {
     /// 文本组件的唯一标识符
     id: Option<String>,
     /// 文本组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 文本组件的内联样式
     style: Option<Style>,
     /// 文本组件的子元素列表
@@ -65,18 +66,27 @@ pub struct Text {
     content: String,
     /// 文本的标签（如H1, H2, P等），默认为Span
     tag: TextTag,
+    /// 超出后省略的行数，`None` 表示不省略
+    ellipsis: Option<usize>,
+    /// 是否允许选中文本，`None` 表示沿用父元素的设置
+    selectable: Option<bool>,
+    /// 是否显示复制按钮
+    copyable: bool,
 }
 
 impl Default for Text {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-text".to_string(),
+            class: Cow::Borrowed(classnames::TEXT),
             style: None,
             childrens: Vec::new(),
             onclick: None,
             content: "".to_string(),
             tag: TextTag::Span,
+            ellipsis: None,
+            selectable: None,
+            copyable: false,
         }
     }
 }
@@ -295,14 +305,78 @@ impl Text {
     }
 }
 
+#[builder_props]
+impl Text {
+    /// 超出指定行数时省略，鼠标移入且内容确实被截断时显示完整文本
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Text;
+    /// Text::p("很长的描述文本").ellipsis(2);
+    /// ```
+    pub fn ellipsis(mut self, lines: usize) -> Self {
+        self.ellipsis = Some(lines.max(1));
+        self
+    }
+
+    /// 设置是否允许选中文本
+    ///
+    /// 允许时点击即选中全部文本，便于右键复制 ID、令牌等内容；不允许时禁止选中。
+    /// 未设置时沿用父元素的 `user-select`。
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Text;
+    /// Text::span("a1b2c3d4").selectable(true);
+    /// ```
+    pub fn selectable(mut self, selectable: bool) -> Self {
+        self.selectable = Some(selectable);
+        self
+    }
+
+    /// 设置是否显示复制按钮，鼠标移入文本时显示，点击后把文本内容写入剪贴板
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Text;
+    /// Text::span("sk-2f9a7c").copyable(true);
+    /// ```
+    #[prop(default = "false")]
+    pub fn copyable(mut self, copyable: bool) -> Self {
+        self.copyable = copyable;
+        self
+    }
+}
+
 impl ToElement for Text {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let class = self.class.clone();
+        let mut class = self.class.to_string();
+        match self.selectable {
+            Some(true) => class.push_str(&format!(" {}", classnames::TEXT_SELECTABLE)),
+            Some(false) => class.push_str(&format!(" {}", classnames::TEXT_UNSELECTABLE)),
+            None => {}
+        }
+        if self.copyable {
+            class.push_str(&format!(" {}", classnames::TEXT_COPYABLE));
+        }
         let style = self.style.clone().map(|s| s.to_string());
         let onclick_handler = self.onclick;
         let childrens = self.childrens_to_element();
-        let content = self.content.clone();
+        let content = match self.ellipsis {
+            Some(lines) => OverflowTooltip::new(self.content.clone())
+                .lines(lines)
+                .to_element(),
+            None => rsx! { "{self.content}" },
+        };
+        let copy = self.copyable.then(|| {
+            rsx! {
+                TextCopy { content: self.content.clone() }
+            }
+        });
 
         match self.tag {
             TextTag::H1 => rsx! {
@@ -316,6 +390,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -330,6 +405,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -344,6 +420,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     for childrens in childrens.iter() {
                         {childrens}
                     }
@@ -360,6 +437,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -374,6 +452,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -388,6 +467,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -402,6 +482,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -416,6 +497,7 @@ impl ToElement for Text {
                         }
                     },
                     {content}
+                    {copy}
                     {childrens}
                 }
             },
@@ -423,6 +505,47 @@ impl ToElement for Text {
     }
 }
 
+#[derive(Props, Clone, PartialEq)]
+struct TextCopyProps {
+    content: String,
+}
+
+/// 复制按钮，复制成功后显示勾选图标，鼠标移出后恢复
+#[allow(non_snake_case)]
+fn TextCopy(props: TextCopyProps) -> Element {
+    let mut copied = use_signal(|| false);
+    let class = if copied() {
+        format!("{} {}", classnames::TEXT_COPY, classnames::TEXT_COPY_DONE)
+    } else {
+        classnames::TEXT_COPY.to_string()
+    };
+    let label = if copied() { "已复制" } else { "复制" };
+
+    rsx! {
+        button {
+            r#type: "button",
+            class,
+            title: label,
+            aria_label: label,
+            onclick: move |event: MouseEvent| {
+                event.stop_propagation();
+                let content = props.content.clone();
+                spawn(async move {
+                    if copy_text(&content).await {
+                        copied.set(true);
+                    }
+                });
+            },
+            onmouseleave: move |_| copied.set(false),
+            if copied() {
+                "✓"
+            } else {
+                "⧉"
+            }
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -455,4 +578,23 @@ mod tests {
             )
         );
     }
+
+    #[test]
+    fn test_render_selectable_copyable() {
+        fn app() -> Element {
+            Text::span("a1b2c3d4")
+                .selectable(true)
+                .copyable(true)
+                .to_element()
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        let html = dioxus_ssr::render(&dom);
+
+        assert!(html.contains(classnames::TEXT_SELECTABLE));
+        assert!(html.contains(classnames::TEXT_COPYABLE));
+        assert!(html.contains(r#"class="t-text__copy""#));
+        assert!(html.contains(r#"aria-label="复制""#));
+    }
 }
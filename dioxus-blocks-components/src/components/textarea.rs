@@ -130,13 +130,14 @@
 //! dom.rebuild(&mut mutations);
 //! ```
 
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
-use crate::{Style, traits::ToElement};
+use crate::{Style, classnames, traits::ToElement, use_unique_id};
 
 /// 文本域尺寸枚举
 ///
@@ -152,16 +153,23 @@ pub enum TextareaSize {
     Large,
 }
 
-impl std::fmt::Display for TextareaSize {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl TextareaSize {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
         match self {
-            TextareaSize::Medium => write!(f, ""),
-            TextareaSize::Small => write!(f, "t-textarea--small"),
-            TextareaSize::Large => write!(f, "t-textarea--large"),
+            TextareaSize::Medium => "",
+            TextareaSize::Small => "t-textarea--small",
+            TextareaSize::Large => "t-textarea--large",
         }
     }
 }
 
+impl std::fmt::Display for TextareaSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
 /// 文本域组件结构体
 ///
 /// 提供一个可自定义的多行文本输入框，支持自适应高度、行数控制和输入长度限制。
@@ -178,7 +186,7 @@ pub struct Textarea {
     /// 组件的唯一标识符
     id: Option<String>,
     /// 组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 组件的内联样式
     style: Option<Style>,
     /// 组件的子元素列表
@@ -194,6 +202,8 @@ pub struct Textarea {
     size: TextareaSize,
     /// 占位符
     placeholder: String,
+    /// 内部文本域的 ID，未设置时自动生成
+    input_id: Option<String>,
     /// 是否自适应高度
     autosize: bool,
     /// 最小行数
@@ -232,7 +242,7 @@ impl Default for Textarea {
     fn default() -> Self {
         Self {
             id: None,
-            class: "t-textarea".to_string(),
+            class: Cow::Borrowed(classnames::TEXTAREA),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -240,6 +250,7 @@ impl Default for Textarea {
             disabled: false,
             size: TextareaSize::default(),
             placeholder: String::new(),
+            input_id: None,
             autosize: false,
             min_rows: None,
             max_rows: None,
@@ -260,6 +271,7 @@ impl Default for Textarea {
     }
 }
 
+#[builder_props]
 impl Textarea {
     /// 创建一个新的文本域实例
     ///
@@ -279,12 +291,14 @@ impl Textarea {
     }
 
     /// 设置禁用状态
+    #[prop(default = "false")]
     pub fn disabled(mut self, disabled: bool) -> Self {
         self.disabled = disabled;
         self
     }
 
     /// 设置文本域尺寸
+    #[prop(default = "TextareaSize::Medium")]
     pub fn size(mut self, size: TextareaSize) -> Self {
         self.size = size;
         self
@@ -296,7 +310,14 @@ impl Textarea {
         self
     }
 
+    /// 设置内部文本域的 ID，用于关联外部的 `<label for>`，未设置时自动生成
+    pub fn input_id(mut self, input_id: impl Into<String>) -> Self {
+        self.input_id = Some(input_id.into());
+        self
+    }
+
     /// 设置是否自适应高度
+    #[prop(default = "false")]
     pub fn autosize(mut self, autosize: bool) -> Self {
         self.autosize = autosize;
         self
@@ -327,6 +348,7 @@ impl Textarea {
     }
 
     /// 设置是否显示字数统计
+    #[prop(default = "false")]
     pub fn show_word_limit(mut self, show: bool) -> Self {
         self.show_word_limit = show;
         self
@@ -415,21 +437,24 @@ impl ToElement for Textarea {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
 
-        let mut class_names = vec![self.class.clone(), self.size.to_string()];
+        let mut class_names = vec![&*self.class, self.size.as_class()];
         if self.disabled {
-            class_names.push("t-textarea--disabled".to_string());
+            class_names.push(classnames::TEXTAREA_DISABLED);
         }
         if self.max_length.is_some() {
-            class_names.push("t-textarea--limit".to_string());
+            class_names.push(classnames::TEXTAREA_LIMIT);
         }
         if self.autosize {
-            class_names.push("t-textarea--autosize".to_string());
+            class_names.push(classnames::TEXTAREA_AUTOSIZE);
         }
         let class = class_names.join(" ");
 
         let style = self.style.clone().map(|s| s.to_string());
         let disabled = self.disabled;
         let placeholder = self.placeholder.clone();
+        let generated_id = use_unique_id("textarea");
+        let input_id = self.input_id.clone().unwrap_or(generated_id);
+        let count_id = format!("{input_id}-count");
         let max_length_attr = self.max_length.map(|l| l.to_string());
 
         // 获取 value signal，如果未设置则使用默认值
@@ -489,18 +514,20 @@ impl ToElement for Textarea {
             div { id, class, style,
                 // 字数统计
                 if show_word_limit {
-                    div { class: "t-textarea__count",
+                    div { id: "{count_id}", class: classnames::TEXTAREA_COUNT,
                         "{value_signal.read().chars().count()}"
                         if let Some(max_len) = max_length {
-                            span { class: "t-textarea__count-separator", "/" }
+                            span { class: classnames::TEXTAREA_COUNT_SEPARATOR, "/" }
                             span { "{max_len}" }
                         }
                     }
                 }
 
                 textarea {
-                    class: "t-textarea__inner",
+                    id: "{input_id}",
+                    class: classnames::TEXTAREA_INNER,
                     placeholder,
+                    aria_describedby: show_word_limit.then(|| count_id.clone()),
                     disabled,
                     rows: rows_attr,
                     maxlength: max_length_attr,
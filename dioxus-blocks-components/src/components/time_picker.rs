@@ -0,0 +1,994 @@
+//! TimePicker 时间选择器组件
+//!
+//! 提供时、分、秒分列选择的时间选择器，支持步长、12/24 小时制、禁用时间段、可清空、不同尺寸和禁用状态。
+//!
+//! # 组件模式
+//!
+//! TimePicker 是**受控组件**，通过 `Signal<Option<TimeValue>>` 传递值，在面板中点击或在输入框中输入后
+//! 组件直接更新 Signal，并触发 change 事件。
+//!
+//! 输入框可以直接输入 `HH:MM:SS` 或 `HH:MM`，12 小时制时在末尾加上 `AM` 或 `PM`。按 Enter 或失去焦点时解析，
+//! 无法解析或处于禁用时间段的输入恢复为当前值。
+//!
+//! # 示例
+//!
+//! ## 基础用法
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{TimePicker, TimeValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let value = use_signal(|| Some(TimeValue::new(9, 30, 0)));
+//!     TimePicker::new()
+//!         .value(value)
+//!         .clearable(true)
+//!         .onchange(move |time| println!("{time:?}"))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+//!
+//! ## 步长、12 小时制和禁用时间段
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{TimeFormat, TimePicker, TimeValue, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let value = use_signal(|| None::<TimeValue>);
+//!     TimePicker::new()
+//!         .value(value)
+//!         .show_seconds(false)
+//!         .minute_step(15)
+//!         .format(TimeFormat::Hour12)
+//!         .disabled_range(TimeValue::new(12, 0, 0), TimeValue::new(13, 59, 59))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement, use_presence, use_unique_id};
+
+/// 面板离场动画最多等待的毫秒数，略长于样式中 0.15s 的离场动画
+const LEAVE_TIMEOUT: u32 = 250;
+
+/// TimePicker 尺寸枚举
+///
+/// 定义时间选择器的大小。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimePickerSize {
+    /// 中等尺寸
+    #[default]
+    Medium,
+    /// 小尺寸
+    Small,
+    /// 大尺寸
+    Large,
+}
+
+impl TimePickerSize {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            TimePickerSize::Medium => "",
+            TimePickerSize::Small => "t-time-picker--small",
+            TimePickerSize::Large => "t-time-picker--large",
+        }
+    }
+}
+
+impl std::fmt::Display for TimePickerSize {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TimePickerSize::Medium => "medium",
+            TimePickerSize::Small => "small",
+            TimePickerSize::Large => "large",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// 时间的显示格式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TimeFormat {
+    /// 24 小时制，如 `14:30:00`
+    #[default]
+    Hour24,
+    /// 12 小时制，如 `02:30:00 PM`
+    Hour12,
+}
+
+impl std::fmt::Display for TimeFormat {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let s = match self {
+            TimeFormat::Hour24 => "24h",
+            TimeFormat::Hour12 => "12h",
+        };
+        write!(f, "{s}")
+    }
+}
+
+/// 一天中的时间，精确到秒
+///
+/// 按时、分、秒的顺序比较大小，`Display` 输出 24 小时制的 `HH:MM:SS`。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct TimeValue {
+    hour: u8,
+    minute: u8,
+    second: u8,
+}
+
+impl TimeValue {
+    /// 创建时间
+    ///
+    /// # Panics
+    ///
+    /// 小时不在 0 到 23 之间，或分钟、秒不在 0 到 59 之间时 panic。
+    pub fn new(hour: u8, minute: u8, second: u8) -> Self {
+        assert!(
+            hour < 24 && minute < 60 && second < 60,
+            "无效的时间 {hour}:{minute}:{second}"
+        );
+        Self {
+            hour,
+            minute,
+            second,
+        }
+    }
+
+    /// 小时，0 到 23
+    pub fn hour(&self) -> u8 {
+        self.hour
+    }
+
+    /// 分钟
+    pub fn minute(&self) -> u8 {
+        self.minute
+    }
+
+    /// 秒
+    pub fn second(&self) -> u8 {
+        self.second
+    }
+
+    /// 解析 `HH:MM:SS` 或 `HH:MM`，末尾带有 `AM` 或 `PM`（不区分大小写）时按 12 小时制解析
+    ///
+    /// ```rust
+    /// use dioxus_blocks_components::TimeValue;
+    ///
+    /// assert_eq!(TimeValue::parse("08:05"), Some(TimeValue::new(8, 5, 0)));
+    /// assert_eq!(TimeValue::parse("12:30:15 am"), Some(TimeValue::new(0, 30, 15)));
+    /// assert_eq!(TimeValue::parse("02:30 PM"), Some(TimeValue::new(14, 30, 0)));
+    /// assert_eq!(TimeValue::parse("24:00"), None);
+    /// ```
+    pub fn parse(text: &str) -> Option<Self> {
+        let text = text.trim().to_ascii_uppercase();
+        let (digits, pm) = match text.strip_suffix("AM").or(text.strip_suffix("A.M.")) {
+            Some(digits) => (digits, Some(false)),
+            None => match text.strip_suffix("PM").or(text.strip_suffix("P.M.")) {
+                Some(digits) => (digits, Some(true)),
+                None => (text.as_str(), None),
+            },
+        };
+        let parts = digits
+            .trim()
+            .split(':')
+            .map(|part| part.trim().parse::<u8>().ok())
+            .collect::<Option<Vec<_>>>()?;
+        let (hour, minute, second) = match parts[..] {
+            [hour, minute] => (hour, minute, 0),
+            [hour, minute, second] => (hour, minute, second),
+            _ => return None,
+        };
+        let hour = match pm {
+            Some(_) if !(1..=12).contains(&hour) => return None,
+            Some(pm) => hour % 12 + if pm { 12 } else { 0 },
+            None => hour,
+        };
+        (hour < 24 && minute < 60 && second < 60).then(|| Self::new(hour, minute, second))
+    }
+
+    /// 按显示格式输出，`show_seconds` 为 `false` 时省略秒
+    ///
+    /// ```rust
+    /// use dioxus_blocks_components::{TimeFormat, TimeValue};
+    ///
+    /// let time = TimeValue::new(14, 5, 9);
+    /// assert_eq!(time.format(TimeFormat::Hour24, true), "14:05:09");
+    /// assert_eq!(time.format(TimeFormat::Hour12, false), "02:05 PM");
+    /// ```
+    pub fn format(&self, format: TimeFormat, show_seconds: bool) -> String {
+        let hour = match format {
+            TimeFormat::Hour24 => self.hour,
+            TimeFormat::Hour12 => display_hour_12(self.hour),
+        };
+        let mut text = format!("{hour:02}:{:02}", self.minute);
+        if show_seconds {
+            text.push_str(&format!(":{:02}", self.second));
+        }
+        if format == TimeFormat::Hour12 {
+            text.push_str(if self.hour < 12 { " AM" } else { " PM" });
+        }
+        text
+    }
+}
+
+impl std::fmt::Display for TimeValue {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.format(TimeFormat::Hour24, true))
+    }
+}
+
+/// 12 小时制显示的小时，0 点和 12 点显示为 12
+fn display_hour_12(hour: u8) -> u8 {
+    match hour % 12 {
+        0 => 12,
+        hour => hour,
+    }
+}
+
+/// 面板中的列
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Unit {
+    Hour,
+    Minute,
+    Second,
+    /// 上午或下午，值为 0 或 12
+    Meridiem,
+}
+
+impl Unit {
+    /// 列的无障碍标签
+    fn label(&self) -> &'static str {
+        match self {
+            Unit::Hour => "时",
+            Unit::Minute => "分",
+            Unit::Second => "秒",
+            Unit::Meridiem => "上午或下午",
+        }
+    }
+}
+
+/// 按步长生成的可选时间，以及禁用的时间段
+#[derive(Debug, Clone, PartialEq)]
+struct Candidates {
+    hours: Vec<u8>,
+    minutes: Vec<u8>,
+    seconds: Vec<u8>,
+    disabled_ranges: Vec<(TimeValue, TimeValue)>,
+}
+
+impl Candidates {
+    /// 时间是否处于禁用的时间段内
+    fn is_disabled(&self, time: TimeValue) -> bool {
+        self.disabled_ranges
+            .iter()
+            .any(|(start, end)| *start <= time && time <= *end)
+    }
+
+    /// 按时间顺序查找第一个可选的时间，可以固定小时和分钟
+    fn first_enabled(&self, hour: Option<u8>, minute: Option<u8>) -> Option<TimeValue> {
+        let hours = hour.map_or(self.hours.clone(), |hour| vec![hour]);
+        let minutes = minute.map_or(self.minutes.clone(), |minute| vec![minute]);
+        hours.iter().find_map(|&hour| {
+            minutes.iter().find_map(|&minute| {
+                self.seconds
+                    .iter()
+                    .map(|&second| TimeValue::new(hour, minute, second))
+                    .find(|time| !self.is_disabled(*time))
+            })
+        })
+    }
+
+    /// 在面板中点击某一列的值后得到的时间，尽量保留其他列的值，组合后被禁用时改用同一小时内第一个可选的时间
+    fn pick(&self, base: TimeValue, unit: Unit, value: u8) -> Option<TimeValue> {
+        let (hour, minute, second) = match unit {
+            Unit::Hour => (value, base.minute, base.second),
+            Unit::Minute => (base.hour, value, base.second),
+            Unit::Second => (base.hour, base.minute, value),
+            Unit::Meridiem => {
+                let hour = base.hour % 12 + value;
+                match self.hours.contains(&hour) {
+                    true => (hour, base.minute, base.second),
+                    false => {
+                        return self
+                            .hours
+                            .iter()
+                            .filter(|&&hour| (hour >= 12) == (value == 12))
+                            .find_map(|&hour| self.first_enabled(Some(hour), None));
+                    }
+                }
+            }
+        };
+        let time = TimeValue::new(hour, minute, second);
+        if !self.is_disabled(time) {
+            return Some(time);
+        }
+        match unit {
+            Unit::Minute => self.first_enabled(Some(hour), Some(minute)),
+            Unit::Second => None,
+            Unit::Hour | Unit::Meridiem => self.first_enabled(Some(hour), None),
+        }
+    }
+}
+
+/// 按步长生成 `0..limit` 内的值
+fn stepped(limit: u8, step: u8) -> Vec<u8> {
+    (0..limit).step_by(step.max(1) as usize).collect()
+}
+
+/// 面板中的一个单元格
+#[derive(Debug, Clone, PartialEq)]
+struct Cell {
+    value: u8,
+    label: String,
+    disabled: bool,
+    selected: bool,
+}
+
+/// 时间选择器组件
+///
+/// 点击输入框打开面板，在时、分、秒列中点击选择，12 小时制时增加上午和下午列。
+#[derive(Debug, Clone, ComponentBase)]
+pub struct TimePicker {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 当前值（受控状态）
+    value: Option<Signal<Option<TimeValue>>>,
+    /// 小时的步长
+    hour_step: u8,
+    /// 分钟的步长
+    minute_step: u8,
+    /// 秒的步长
+    second_step: u8,
+    /// 是否显示秒
+    show_seconds: bool,
+    /// 显示格式
+    format: TimeFormat,
+    /// 禁用的时间段，包含两端
+    disabled_ranges: Vec<(TimeValue, TimeValue)>,
+    /// 是否禁用
+    disabled: bool,
+    /// 是否可清空
+    clearable: bool,
+    /// 尺寸
+    size: TimePickerSize,
+    /// 占位符文本
+    placeholder: String,
+    /// 输入框的 id，用于关联表单标签
+    input_id: Option<String>,
+    /// 值改变时的回调
+    onchange: Option<EventHandler<Option<TimeValue>>>,
+}
+
+impl Default for TimePicker {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::TIME_PICKER),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            value: None,
+            hour_step: 1,
+            minute_step: 1,
+            second_step: 1,
+            show_seconds: true,
+            format: TimeFormat::Hour24,
+            disabled_ranges: Vec::new(),
+            disabled: false,
+            clearable: false,
+            size: TimePickerSize::Medium,
+            placeholder: "选择时间".to_string(),
+            input_id: None,
+            onchange: None,
+        }
+    }
+}
+
+#[builder_props]
+impl TimePicker {
+    /// 创建一个新的时间选择器实例
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置当前值的 Signal
+    pub fn value(mut self, value: Signal<Option<TimeValue>>) -> Self {
+        self.value = Some(value);
+        self
+    }
+
+    /// 设置小时的步长，如 `2` 表示只能选择 0、2、4 点等
+    #[prop(default = "1")]
+    pub fn hour_step(mut self, step: u8) -> Self {
+        self.hour_step = step.clamp(1, 24);
+        self
+    }
+
+    /// 设置分钟的步长，如 `15` 表示只能选择 0、15、30、45 分，`60` 表示只能选择整点
+    #[prop(default = "1")]
+    pub fn minute_step(mut self, step: u8) -> Self {
+        self.minute_step = step.clamp(1, 60);
+        self
+    }
+
+    /// 设置秒的步长
+    #[prop(default = "1")]
+    pub fn second_step(mut self, step: u8) -> Self {
+        self.second_step = step.clamp(1, 60);
+        self
+    }
+
+    /// 设置是否显示秒，不显示时只能选择整分钟
+    #[prop(default = "true")]
+    pub fn show_seconds(mut self, show_seconds: bool) -> Self {
+        self.show_seconds = show_seconds;
+        self
+    }
+
+    /// 设置 12 或 24 小时制，值始终按 24 小时制保存
+    #[prop(default = "TimeFormat::Hour24")]
+    pub fn format(mut self, format: TimeFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// 添加禁用的时间段，包含开始和结束时间，可以多次调用
+    pub fn disabled_range(mut self, start: TimeValue, end: TimeValue) -> Self {
+        self.disabled_ranges.push((start.min(end), start.max(end)));
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置是否可清空
+    #[prop(default = "false")]
+    pub fn clearable(mut self, clearable: bool) -> Self {
+        self.clearable = clearable;
+        self
+    }
+
+    /// 设置尺寸
+    #[prop(default = "TimePickerSize::Medium")]
+    pub fn size(mut self, size: TimePickerSize) -> Self {
+        self.size = size;
+        self
+    }
+
+    /// 设置占位符文本
+    #[prop(default = "\"选择时间\"")]
+    pub fn placeholder(mut self, placeholder: impl Into<String>) -> Self {
+        self.placeholder = placeholder.into();
+        self
+    }
+
+    /// 设置输入框的 id，用于关联 label 的 for 属性
+    pub fn input_id(mut self, input_id: impl Into<String>) -> Self {
+        self.input_id = Some(input_id.into());
+        self
+    }
+
+    /// 设置值改变事件，清空时为 `None`
+    pub fn onchange(mut self, handler: impl FnMut(Option<TimeValue>) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置值改变事件
+    #[prop(skip)]
+    pub fn onchange2(mut self, handler: EventHandler<Option<TimeValue>>) -> Self {
+        self.onchange = Some(handler);
+        self
+    }
+}
+
+/// 便捷方法
+impl TimePicker {
+    /// 设置为小尺寸
+    pub fn as_small(mut self) -> Self {
+        self.size = TimePickerSize::Small;
+        self
+    }
+
+    /// 设置为中等尺寸
+    pub fn as_medium(mut self) -> Self {
+        self.size = TimePickerSize::Medium;
+        self
+    }
+
+    /// 设置为大尺寸
+    pub fn as_large(mut self) -> Self {
+        self.size = TimePickerSize::Large;
+        self
+    }
+}
+
+impl ToElement for TimePicker {
+    fn to_element(&self) -> Element {
+        let uid = use_unique_id("time-picker");
+        let inner_value = use_signal(|| None::<TimeValue>);
+        let mut value = self.value.unwrap_or(inner_value);
+        let mut open = use_signal(|| false);
+        // 输入框中尚未提交的文本
+        let mut draft = use_signal(|| None::<String>);
+
+        let disabled = self.disabled;
+        let format = self.format;
+        let show_seconds = self.show_seconds;
+        let onchange = self.onchange;
+        let onclick_handler = self.onclick;
+        let candidates = Candidates {
+            hours: stepped(24, self.hour_step),
+            minutes: stepped(60, self.minute_step),
+            seconds: match show_seconds {
+                true => stepped(60, self.second_step),
+                false => vec![0],
+            },
+            disabled_ranges: self.disabled_ranges.clone(),
+        };
+
+        let current = *value.read();
+        let text = current
+            .map(|time| time.format(format, show_seconds))
+            .unwrap_or_default();
+        let is_open = open() && !disabled;
+        let panel = use_presence(is_open, LEAVE_TIMEOUT);
+
+        let mut commit = move |next: Option<TimeValue>| {
+            draft.set(None);
+            if *value.peek() == next {
+                return;
+            }
+            value.set(next);
+            if let Some(handler) = onchange {
+                handler.call(next);
+            }
+        };
+
+        // 解析输入框中的文本，无效时恢复为当前值
+        let parse_candidates = candidates.clone();
+        let mut submit_draft = move || {
+            let Some(text) = draft.peek().clone() else {
+                return;
+            };
+            match text.trim() {
+                "" => commit(None),
+                text => match TimeValue::parse(text) {
+                    Some(time) if !parse_candidates.is_disabled(time) => commit(Some(time)),
+                    _ => draft.set(None),
+                },
+            }
+        };
+
+        let pick_candidates = candidates.clone();
+        let pick = use_callback(move |(unit, picked): (Unit, u8)| {
+            let base = value.peek().unwrap_or_default();
+            if let Some(time) = pick_candidates.pick(base, unit, picked) {
+                commit(Some(time));
+            }
+        });
+
+        // 打开面板或选择后把每一列选中的值滚动到顶部
+        let panel_id = format!("{uid}-panel");
+        let scroll_panel = panel_id.clone();
+        let controls_id = panel_id.clone();
+        use_effect(move || {
+            if !open() || value().is_none() || cfg!(feature = "server") {
+                return;
+            }
+            document::eval(&format!(
+                "document.querySelectorAll('#{scroll_panel} .{}').forEach((cell) => {{ cell.parentElement.scrollTop = cell.offsetTop; }});",
+                classnames::TIME_PICKER_CELL_SELECTED
+            ));
+        });
+
+        // 当前值所在的上下文，没有值时按 00:00:00 计算各列的禁用状态
+        let base = current.unwrap_or_default();
+        let cell = |unit: Unit, value: u8, label: String| {
+            let (disabled, selected) = match unit {
+                Unit::Hour => (
+                    candidates.first_enabled(Some(value), None).is_none(),
+                    current.is_some_and(|time| time.hour == value),
+                ),
+                Unit::Minute => (
+                    candidates
+                        .first_enabled(Some(base.hour), Some(value))
+                        .is_none(),
+                    current.is_some_and(|time| time.minute == value),
+                ),
+                Unit::Second => (
+                    candidates.is_disabled(TimeValue::new(base.hour, base.minute, value)),
+                    current.is_some_and(|time| time.second == value),
+                ),
+                Unit::Meridiem => (
+                    candidates.pick(base, unit, value).is_none(),
+                    current.is_some_and(|time| (time.hour >= 12) == (value == 12)),
+                ),
+            };
+            Cell {
+                value,
+                label,
+                disabled,
+                selected,
+            }
+        };
+        let mut columns = Vec::<(Unit, Vec<Cell>)>::new();
+        let hours = match format {
+            // 12 小时制只显示当前上午或下午的小时
+            TimeFormat::Hour12 => candidates
+                .hours
+                .iter()
+                .filter(|&&hour| (hour >= 12) == (base.hour >= 12))
+                .map(|&hour| cell(Unit::Hour, hour, format!("{:02}", display_hour_12(hour))))
+                .collect(),
+            TimeFormat::Hour24 => candidates
+                .hours
+                .iter()
+                .map(|&hour| cell(Unit::Hour, hour, format!("{hour:02}")))
+                .collect(),
+        };
+        columns.push((Unit::Hour, hours));
+        columns.push((
+            Unit::Minute,
+            candidates
+                .minutes
+                .iter()
+                .map(|&minute| cell(Unit::Minute, minute, format!("{minute:02}")))
+                .collect(),
+        ));
+        if show_seconds {
+            columns.push((
+                Unit::Second,
+                candidates
+                    .seconds
+                    .iter()
+                    .map(|&second| cell(Unit::Second, second, format!("{second:02}")))
+                    .collect(),
+            ));
+        }
+        if format == TimeFormat::Hour12 {
+            columns.push((
+                Unit::Meridiem,
+                vec![
+                    cell(Unit::Meridiem, 0, "AM".to_string()),
+                    cell(Unit::Meridiem, 12, "PM".to_string()),
+                ],
+            ));
+        }
+
+        let mut class = vec![self.class.to_string()];
+        let size_class = self.size.as_class();
+        if !size_class.is_empty() {
+            class.push(size_class.to_string());
+        }
+        if disabled {
+            class.push("is-disabled".to_string());
+        }
+        if is_open {
+            class.push("is-open".to_string());
+        }
+        let class = class.join(" ");
+        let style = self.style.clone().map(|s| s.to_string());
+        let input_id = self
+            .input_id
+            .clone()
+            .unwrap_or_else(|| format!("{uid}-input"));
+        let input_value = draft().unwrap_or(text);
+        let show_clear = self.clearable && current.is_some() && !disabled;
+        let placeholder = self.placeholder.clone();
+        let panel_class = match panel.is_leaving() {
+            true => format!("{} is-leaving", classnames::TIME_PICKER_PANEL),
+            false => classnames::TIME_PICKER_PANEL.to_string(),
+        };
+        let mut submit_on_change = submit_draft.clone();
+
+        rsx! {
+            div {
+                id: self.id.clone(),
+                class,
+                style,
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                onfocusout: move |_| {
+                    open.set(false);
+                    draft.set(None);
+                },
+                div { class: classnames::TIME_PICKER_WRAPPER,
+                    span { class: classnames::TIME_PICKER_ICON, aria_hidden: "true", "🕒" }
+                    input {
+                        id: input_id,
+                        class: classnames::TIME_PICKER_INPUT,
+                        r#type: "text",
+                        role: "combobox",
+                        autocomplete: "off",
+                        disabled,
+                        value: input_value,
+                        placeholder,
+                        aria_haspopup: "dialog",
+                        aria_expanded: "{is_open}",
+                        aria_controls: controls_id,
+                        onclick: move |_| {
+                            if !disabled {
+                                open.set(true);
+                            }
+                        },
+                        oninput: move |event: FormEvent| draft.set(Some(event.value())),
+                        onchange: move |_| submit_on_change(),
+                        onkeydown: move |event: KeyboardEvent| {
+                            if disabled {
+                                return;
+                            }
+                            match event.key() {
+                                Key::Enter => {
+                                    event.prevent_default();
+                                    submit_draft();
+                                    open.set(!open());
+                                }
+                                Key::ArrowDown => {
+                                    event.prevent_default();
+                                    open.set(true);
+                                }
+                                Key::Escape if open() => {
+                                    open.set(false);
+                                    draft.set(None);
+                                }
+                                _ => {}
+                            }
+                        },
+                    }
+                    if show_clear {
+                        span {
+                            class: classnames::TIME_PICKER_CLEAR,
+                            role: "button",
+                            aria_label: "清空",
+                            onmousedown: move |event: MouseEvent| event.prevent_default(),
+                            onclick: move |event: MouseEvent| {
+                                event.stop_propagation();
+                                commit(None);
+                            },
+                            "×"
+                        }
+                    }
+                }
+                if panel.is_present() {
+                    div {
+                        id: panel_id,
+                        class: panel_class,
+                        role: "dialog",
+                        aria_label: "选择时间",
+                        // 阻止输入框失去焦点，保证点击事件能够触发
+                        onmousedown: move |event: MouseEvent| event.prevent_default(),
+                        onanimationend: move |_| panel.finish(),
+                        div { class: classnames::TIME_PICKER_COLUMNS,
+                            for (unit , cells) in columns {
+                                ul {
+                                    key: "{unit.label()}",
+                                    class: classnames::TIME_PICKER_COLUMN,
+                                    role: "listbox",
+                                    aria_label: unit.label(),
+                                    for cell in cells {
+                                        {render_cell(unit, cell, pick)}
+                                    }
+                                }
+                            }
+                        }
+                        div { class: classnames::TIME_PICKER_FOOTER,
+                            button {
+                                r#type: "button",
+                                class: classnames::TIME_PICKER_CONFIRM,
+                                onclick: move |_| open.set(false),
+                                "确定"
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 渲染面板中的一个单元格
+fn render_cell(unit: Unit, cell: Cell, pick: Callback<(Unit, u8)>) -> Element {
+    let Cell {
+        value,
+        label,
+        disabled,
+        selected,
+    } = cell;
+    let mut class = vec![classnames::TIME_PICKER_CELL];
+    if selected {
+        class.push(classnames::TIME_PICKER_CELL_SELECTED);
+    }
+    if disabled {
+        class.push("is-disabled");
+    }
+    let class = class.join(" ");
+
+    rsx! {
+        li {
+            key: "{value}",
+            class,
+            role: "option",
+            aria_selected: "{selected}",
+            aria_disabled: disabled.then_some("true"),
+            onclick: move |_| {
+                if !disabled {
+                    pick.call((unit, value));
+                }
+            },
+            "{label}"
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_time_value_parse_and_format() {
+        assert_eq!(TimeValue::parse(" 7:5:3 "), Some(TimeValue::new(7, 5, 3)));
+        assert_eq!(TimeValue::parse("12:00 PM"), Some(TimeValue::new(12, 0, 0)));
+        assert_eq!(TimeValue::parse("13:00 PM"), None);
+        assert_eq!(TimeValue::parse("10:60"), None);
+        assert_eq!(TimeValue::parse("10"), None);
+        assert_eq!(
+            TimeValue::new(0, 15, 0).format(TimeFormat::Hour12, true),
+            "12:15:00 AM"
+        );
+        assert_eq!(TimeValue::new(9, 0, 30).to_string(), "09:00:30");
+    }
+
+    #[test]
+    fn test_candidates_pick() {
+        let candidates = Candidates {
+            hours: stepped(24, 1),
+            minutes: stepped(60, 15),
+            seconds: vec![0],
+            disabled_ranges: vec![(TimeValue::new(12, 0, 0), TimeValue::new(12, 29, 59))],
+        };
+        let base = TimeValue::new(9, 15, 0);
+        assert_eq!(
+            candidates.pick(base, Unit::Hour, 10),
+            Some(TimeValue::new(10, 15, 0))
+        );
+        // 组合后被禁用时改用同一小时内第一个可选的时间
+        assert_eq!(
+            candidates.pick(base, Unit::Hour, 12),
+            Some(TimeValue::new(12, 30, 0))
+        );
+        assert_eq!(
+            candidates.pick(TimeValue::new(12, 30, 0), Unit::Minute, 15),
+            None
+        );
+        assert_eq!(
+            candidates.pick(base, Unit::Meridiem, 12),
+            Some(TimeValue::new(21, 15, 0))
+        );
+        assert_eq!(
+            candidates.first_enabled(Some(12), None),
+            Some(TimeValue::new(12, 30, 0))
+        );
+    }
+
+    #[test]
+    fn test_time_picker_pick_and_clear() {
+        fn app() -> Element {
+            let value = use_signal(|| Some(TimeValue::new(9, 30, 0)));
+            rsx! {
+                {
+                    TimePicker::new()
+                        .value(value)
+                        .show_seconds(false)
+                        .minute_step(15)
+                        .clearable(true)
+                        .disabled_range(TimeValue::new(12, 0, 0), TimeValue::new(13, 59, 59))
+                        .to_element()
+                }
+                span { class: "value", "{value:?}" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let input = harness
+            .find_by_class(classnames::TIME_PICKER_INPUT)
+            .unwrap();
+        assert_eq!(input.attr("value"), Some("09:30"));
+
+        harness.click(&input);
+        let columns = harness.find_all_by_class(classnames::TIME_PICKER_COLUMN);
+        assert_eq!(columns.len(), 2);
+        let cells = harness.find_all_by_class(classnames::TIME_PICKER_CELL);
+        assert_eq!(cells.len(), 24 + 4);
+        assert!(cells[12].has_class("is-disabled"));
+        assert!(cells[9].has_class(classnames::TIME_PICKER_CELL_SELECTED));
+
+        harness.click(&cells[12]);
+        assert_eq!(
+            harness.find_by_class("value").unwrap().text(),
+            "Some(TimeValue { hour: 9, minute: 30, second: 0 })"
+        );
+        harness.click(&cells[24 + 3]);
+        let input = harness
+            .find_by_class(classnames::TIME_PICKER_INPUT)
+            .unwrap();
+        assert_eq!(input.attr("value"), Some("09:45"));
+
+        let clear = harness
+            .find_by_class(classnames::TIME_PICKER_CLEAR)
+            .unwrap();
+        harness.click(&clear);
+        assert_eq!(harness.find_by_class("value").unwrap().text(), "None");
+    }
+
+    #[test]
+    fn test_time_picker_typed_input() {
+        fn app() -> Element {
+            let value = use_signal(|| None::<TimeValue>);
+            TimePicker::new()
+                .value(value)
+                .format(TimeFormat::Hour12)
+                .disabled_range(TimeValue::new(0, 0, 0), TimeValue::new(6, 59, 59))
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let input = harness
+            .find_by_class(classnames::TIME_PICKER_INPUT)
+            .unwrap();
+        harness.input(&input, "2:30 pm");
+        harness.keydown(&input, "Enter");
+        let input = harness
+            .find_by_class(classnames::TIME_PICKER_INPUT)
+            .unwrap();
+        assert_eq!(input.attr("value"), Some("02:30:00 PM"));
+
+        // 12 小时制显示当前下午的小时和上午、下午列
+        let columns = harness.find_all_by_class(classnames::TIME_PICKER_COLUMN);
+        assert_eq!(columns.len(), 4);
+        let selected = harness.find_all_by_class(classnames::TIME_PICKER_CELL_SELECTED);
+        assert_eq!(selected[0].text(), "02");
+        assert_eq!(selected[3].text(), "PM");
+
+        // 处于禁用时间段的输入恢复为当前值
+        harness.input(&input, "03:00 AM");
+        harness.keydown(&input, "Enter");
+        let input = harness
+            .find_by_class(classnames::TIME_PICKER_INPUT)
+            .unwrap();
+        assert_eq!(input.attr("value"), Some("02:30:00 PM"));
+    }
+}
@@ -0,0 +1,313 @@
+//! TitleProvider 文档标题组件
+//!
+//! 根据当前路由自动设置浏览器标签页标题（桌面端同时设置窗口标题），路由切换后立即更新。
+//! 通过 [`TitleProvider::route`] 为路由配置页面标题，标题与应用名称按 `页面标题 – 应用名称` 的格式组合。
+//!
+//! 页面中调用 [`use_document_title`] 可以覆盖路由配置的标题，例如详情页根据加载的数据显示标题，
+//! 页面卸载后恢复为路由配置的标题。不在 TitleProvider 中使用时，[`use_document_title`] 直接设置完整的标题。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Text, TitleProvider, ToElement, use_document_title};
+//! use dioxus::core::Mutations;
+//!
+//! #[derive(Debug, Clone)]
+//! struct Dashboard;
+//!
+//! impl ToElement for Dashboard {
+//!     fn to_element(&self) -> Element {
+//!         use_document_title("仪表盘");
+//!         Text::p("仪表盘").to_element()
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     TitleProvider::new()
+//!         .app_name("MyApp")
+//!         .route("/", "首页")
+//!         .route("/users/:id", "用户详情")
+//!         .children(Dashboard)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::builder_props;
+
+use crate::traits::ToElement;
+
+/// 页面通过 [`use_document_title`] 设置的标题，后挂载的页面优先
+#[derive(Debug, Clone, Copy, PartialEq)]
+struct TitleState {
+    pages: Signal<Vec<(u64, String)>>,
+    next_id: Signal<u64>,
+}
+
+impl TitleState {
+    /// 设置页面标题，已存在时原地更新
+    fn set(mut self, id: u64, title: String) {
+        let mut pages = self.pages.write();
+        match pages.iter_mut().find(|(page, _)| *page == id) {
+            Some(entry) => entry.1 = title,
+            None => pages.push((id, title)),
+        }
+    }
+
+    /// 移除页面标题
+    fn remove(mut self, id: u64) {
+        if let Ok(mut pages) = self.pages.try_write() {
+            pages.retain(|(page, _)| *page != id);
+        }
+    }
+}
+
+/// 路由模式是否匹配路径，`:name` 匹配任意一段
+fn match_route(pattern: &str, path: &str) -> bool {
+    let pattern = pattern.trim_matches('/').split('/');
+    let path = path.trim_matches('/').split('/');
+    pattern.clone().count() == path.clone().count()
+        && pattern
+            .zip(path)
+            .all(|(expected, actual)| expected.starts_with(':') || expected == actual)
+}
+
+/// 组合页面标题和应用名称
+fn compose(page: Option<&str>, app_name: &str, separator: &str) -> String {
+    match page.filter(|page| !page.is_empty()) {
+        Some(page) if !app_name.is_empty() => format!("{page}{separator}{app_name}"),
+        Some(page) => page.to_string(),
+        None => app_name.to_string(),
+    }
+}
+
+/// 在渲染期间设置文档标题，标题变化时更新，服务端渲染时同样生效；`None` 时不修改标题
+fn use_title(title: Option<&str>) {
+    let last = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+    let Some(title) = title else {
+        return;
+    };
+    if last.borrow().as_deref() == Some(title) {
+        return;
+    }
+    *last.borrow_mut() = Some(title.to_string());
+    document::document().set_title(title.to_string());
+    #[cfg(feature = "desktop")]
+    dioxus::desktop::window().set_title(title);
+}
+
+/// 设置文档标题
+///
+/// 在 [`TitleProvider`] 中使用时，标题作为页面标题与应用名称组合，并覆盖路由配置的标题，
+/// 组件卸载后恢复；否则直接设置为完整的文档标题。
+pub fn use_document_title(title: impl Into<String>) {
+    let title = title.into();
+    let page = use_hook(|| {
+        try_consume_context::<TitleState>().map(|mut state| {
+            let id = *state.next_id.peek();
+            state.next_id += 1;
+            (state, id)
+        })
+    });
+
+    use_title(page.is_none().then_some(title.as_str()));
+    use_effect(use_reactive!(|title| {
+        if let Some((state, id)) = page {
+            state.set(id, title);
+        }
+    }));
+    use_drop(move || {
+        if let Some((state, id)) = page {
+            state.remove(id);
+        }
+    });
+}
+
+/// 文档标题组件
+#[derive(Debug, Clone)]
+pub struct TitleProvider {
+    /// 应用名称
+    app_name: String,
+    /// 页面标题与应用名称之间的分隔符
+    separator: String,
+    /// 路由模式和对应的页面标题
+    routes: Vec<(String, String)>,
+    /// 子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+}
+
+impl Default for TitleProvider {
+    fn default() -> Self {
+        Self {
+            app_name: String::new(),
+            separator: " – ".to_string(),
+            routes: Vec::new(),
+            childrens: Vec::new(),
+        }
+    }
+}
+
+#[builder_props]
+impl TitleProvider {
+    /// 创建文档标题组件
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置应用名称，显示在页面标题之后，没有页面标题时单独作为文档标题
+    pub fn app_name(mut self, app_name: impl Into<String>) -> Self {
+        self.app_name = app_name.into();
+        self
+    }
+
+    /// 设置页面标题与应用名称之间的分隔符
+    #[prop(default = "\" – \"")]
+    pub fn separator(mut self, separator: impl Into<String>) -> Self {
+        self.separator = separator.into();
+        self
+    }
+
+    /// 为路由配置页面标题，`pattern` 中的 `:name` 匹配任意一段路径，如 `/users/:id`
+    ///
+    /// 多个模式都匹配时使用先添加的模式。
+    pub fn route(mut self, pattern: impl Into<String>, title: impl Into<String>) -> Self {
+        self.routes.push((pattern.into(), title.into()));
+        self
+    }
+
+    /// 添加子元素
+    pub fn children<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.childrens.push(Rc::new(component));
+        self
+    }
+}
+
+impl TitleProvider {
+    /// 当前路由配置的页面标题
+    fn route_title(&self) -> Option<&str> {
+        let route = try_router()?.full_route_string();
+        let path = route.split(['?', '#']).next().unwrap_or_default();
+        self.routes
+            .iter()
+            .find(|(pattern, _)| match_route(pattern, path))
+            .map(|(_, title)| title.as_str())
+    }
+}
+
+impl ToElement for TitleProvider {
+    fn to_element(&self) -> Element {
+        let state = use_context_provider(|| TitleState {
+            pages: Signal::new(Vec::new()),
+            next_id: Signal::new(0),
+        });
+
+        let pages = state.pages.read();
+        let page = pages
+            .last()
+            .map(|(_, title)| title.as_str())
+            .or_else(|| self.route_title());
+        use_title(Some(&compose(page, &self.app_name, &self.separator)));
+        drop(pages);
+
+        rsx! {
+            for children in self.childrens.iter() {
+                {children.to_element()}
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Text, View};
+
+    #[test]
+    fn test_match_route() {
+        assert!(match_route("/", "/"));
+        assert!(match_route("/users", "/users/"));
+        assert!(match_route("/users/:id", "/users/42"));
+        assert!(!match_route("/users/:id", "/users"));
+        assert!(!match_route("/users/:id", "/posts/42"));
+    }
+
+    #[test]
+    fn test_compose() {
+        assert_eq!(compose(Some("仪表盘"), "MyApp", " – "), "仪表盘 – MyApp");
+        assert_eq!(compose(Some("仪表盘"), "", " – "), "仪表盘");
+        assert_eq!(compose(Some(""), "MyApp", " – "), "MyApp");
+        assert_eq!(compose(None, "MyApp", " – "), "MyApp");
+    }
+
+    #[derive(Debug, Clone)]
+    struct Page {
+        title: &'static str,
+    }
+
+    impl ToElement for Page {
+        fn to_element(&self) -> Element {
+            use_document_title(self.title);
+            Text::span(self.title).to_element()
+        }
+    }
+
+    #[component]
+    fn PageRoute(title: &'static str) -> Element {
+        Page { title }.to_element()
+    }
+
+    #[derive(Debug, Clone)]
+    struct Pages;
+
+    impl ToElement for Pages {
+        fn to_element(&self) -> Element {
+            let mut detail = use_signal(|| false);
+            let state = use_context::<TitleState>();
+            let current = state
+                .pages
+                .read()
+                .last()
+                .map(|(_, title)| title.clone())
+                .unwrap_or_default();
+            rsx! {
+                {View::new().children(Text::span("切换").onclick(move |_| detail.toggle())).to_element()}
+                if detail() {
+                    PageRoute { title: "用户详情" }
+                }
+                span { class: "current", "{current}" }
+            }
+        }
+    }
+
+    #[test]
+    fn test_page_title_overrides_and_restores() {
+        fn app() -> Element {
+            TitleProvider::new()
+                .app_name("MyApp")
+                .children(Pages)
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(harness.find_by_class("current").unwrap().text(), "");
+
+        harness.click(&harness.find_by_text("切换").unwrap());
+        assert_eq!(harness.find_by_class("current").unwrap().text(), "用户详情");
+
+        harness.click(&harness.find_by_text("切换").unwrap());
+        assert_eq!(harness.find_by_class("current").unwrap().text(), "");
+    }
+}
@@ -0,0 +1,259 @@
+//! Toolbar 工具栏组件
+//!
+//! 分为左、中、右三个区域的工具栏布局，区域内的项使用统一的间距，适合作为表格、编辑器和页面头部的操作栏。
+//!
+//! # 溢出处理
+//!
+//! 通过 `max_items` 限制直接显示的项数，超出的项从末尾开始（右侧区域优先）收起到「更多」菜单中，
+//! 菜单中的项保持原有顺序，点击任意一项后菜单自动关闭。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Text, Toolbar, ToElement};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     Toolbar::new()
+//!         .left(Text::h3("用户列表"))
+//!         .right(Button::new().text("导出"))
+//!         .right(Button::new().text("导入"))
+//!         .right(Button::new().text("新建").as_primary())
+//!         .max_items(2)
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::{Style, classnames, traits::ToElement};
+
+/// 区域中的项
+type Items = Vec<Rc<dyn ToElement>>;
+
+/// 工具栏组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Toolbar {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 左侧区域
+    left: Items,
+    /// 中间区域
+    center: Items,
+    /// 右侧区域
+    right: Items,
+    /// 直接显示的最大项数
+    max_items: Option<usize>,
+    /// 更多菜单的按钮文本
+    more_text: String,
+}
+
+impl Default for Toolbar {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::TOOLBAR),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            left: Vec::new(),
+            center: Vec::new(),
+            right: Vec::new(),
+            max_items: None,
+            more_text: "⋯".to_string(),
+        }
+    }
+}
+
+#[builder_props]
+impl Toolbar {
+    /// 创建工具栏
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 添加左侧区域的项
+    pub fn left<T: ToElement + Clone + 'static>(mut self, item: T) -> Self {
+        self.left.push(Rc::new(item));
+        self
+    }
+
+    /// 添加中间区域的项
+    pub fn center<T: ToElement + Clone + 'static>(mut self, item: T) -> Self {
+        self.center.push(Rc::new(item));
+        self
+    }
+
+    /// 添加右侧区域的项
+    pub fn right<T: ToElement + Clone + 'static>(mut self, item: T) -> Self {
+        self.right.push(Rc::new(item));
+        self
+    }
+
+    /// 设置直接显示的最大项数，超出的项收起到更多菜单中
+    pub fn max_items(mut self, max_items: usize) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// 设置更多菜单的按钮文本，默认为 `⋯`
+    #[prop(default = "\"⋯\"")]
+    pub fn more_text(mut self, text: impl Into<String>) -> Self {
+        self.more_text = text.into();
+        self
+    }
+
+    /// 按 `max_items` 拆分各区域直接显示的项和收起的项，`children` 添加的子元素归入左侧区域
+    fn split(&self) -> ([Items; 3], Items) {
+        let mut visible = self.max_items.unwrap_or(usize::MAX);
+        let mut sections: [Items; 3] = Default::default();
+        let mut overflow = Vec::new();
+        let left = self.left.iter().chain(&self.childrens);
+        let sections_items: [Box<dyn Iterator<Item = &Rc<dyn ToElement>>>; 3] = [
+            Box::new(left),
+            Box::new(self.center.iter()),
+            Box::new(self.right.iter()),
+        ];
+        for (section, items) in sections_items.into_iter().enumerate() {
+            for item in items {
+                if visible > 0 {
+                    visible -= 1;
+                    sections[section].push(item.clone());
+                } else {
+                    overflow.push(item.clone());
+                }
+            }
+        }
+        (sections, overflow)
+    }
+}
+
+impl ToElement for Toolbar {
+    fn to_element(&self) -> Element {
+        let id = self.id.clone();
+        let class = self.class.to_string();
+        let style = self.style.clone().map(|s| s.to_string());
+        let onclick_handler = self.onclick;
+        let mut open = use_signal(|| false);
+
+        let ([left, center, right], overflow) = self.split();
+        let has_overflow = !overflow.is_empty();
+        let more_text = self.more_text.clone();
+
+        rsx! {
+            div {
+                id,
+                class,
+                style,
+                role: "toolbar",
+                onclick: move |event: MouseEvent| {
+                    if let Some(handler) = onclick_handler {
+                        handler.call(event);
+                    }
+                },
+                div { class: classnames::TOOLBAR_LEFT,
+                    for item in left {
+                        {item.to_element()}
+                    }
+                }
+                div { class: classnames::TOOLBAR_CENTER,
+                    for item in center {
+                        {item.to_element()}
+                    }
+                }
+                div { class: classnames::TOOLBAR_RIGHT,
+                    for item in right {
+                        {item.to_element()}
+                    }
+                    if has_overflow {
+                        div { class: classnames::TOOLBAR_MORE,
+                            button {
+                                r#type: "button",
+                                class: classnames::TOOLBAR_MORE_TRIGGER,
+                                aria_label: "更多",
+                                aria_haspopup: "menu",
+                                aria_expanded: open(),
+                                onclick: move |_| open.toggle(),
+                                "{more_text}"
+                            }
+                            if open() {
+                                div {
+                                    class: classnames::TOOLBAR_MENU,
+                                    role: "menu",
+                                    onclick: move |_| open.set(false),
+                                    for item in overflow {
+                                        div { class: classnames::TOOLBAR_MENU_ITEM, role: "menuitem",
+                                            {item.to_element()}
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::Text;
+    use crate::testing::Harness;
+
+    #[test]
+    fn test_toolbar_overflow() {
+        fn app() -> Element {
+            Toolbar::new()
+                .left(Text::span("标题"))
+                .center(Text::span("中间"))
+                .right(Text::span("操作一"))
+                .right(Text::span("操作二"))
+                .max_items(3)
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_text("操作一").is_some());
+        assert!(harness.find_by_text("操作二").is_none());
+
+        let trigger = harness
+            .find_by_class(classnames::TOOLBAR_MORE_TRIGGER)
+            .unwrap();
+        harness.click(&trigger);
+        let items = harness.find_all_by_class(classnames::TOOLBAR_MENU_ITEM);
+        assert_eq!(items.len(), 1);
+        assert!(harness.find_by_text("操作二").is_some());
+
+        harness.click(&harness.find_by_text("操作二").unwrap());
+        assert!(harness.find_by_class(classnames::TOOLBAR_MENU).is_none());
+    }
+
+    #[test]
+    fn test_toolbar_without_limit() {
+        let toolbar = Toolbar::new().left(Text::span("a")).right(Text::span("b"));
+        let ([left, center, right], overflow) = toolbar.split();
+        assert_eq!((left.len(), center.len(), right.len()), (1, 0, 1));
+        assert!(overflow.is_empty());
+    }
+}
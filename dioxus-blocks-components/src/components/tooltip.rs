@@ -0,0 +1,599 @@
+//! Tooltip 文字提示组件
+//!
+//! 包裹触发元素，在鼠标移入、点击或获得焦点时显示提示内容，支持上、下、左、右四个方向及各自的起始和末尾对齐，
+//! 可以设置显示和隐藏的延迟以及是否显示箭头。
+//!
+//! 提示显示后测量触发元素、提示和视口的尺寸：首选方向的空间不足而相反方向更宽裕时自动翻转，
+//! 提示在交叉轴上超出视口时平移回视口内，箭头保持指向触发元素。无法测量元素尺寸的渲染环境（如服务端渲染）中
+//! 按指定的方向显示。
+//!
+//! 组件以独立的组件作用域渲染，可以在条件分支中使用。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Tooltip, TooltipPlacement, TooltipTrigger, ToElement, View};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     View::new()
+//!         .children(Tooltip::new("保存当前的修改").children(Button::new().text("保存")))
+//!         .children(
+//!             Tooltip::new("点击后显示")
+//!                 .placement(TooltipPlacement::BottomStart)
+//!                 .trigger(TooltipTrigger::Click)
+//!                 .children(Button::new().text("点击")),
+//!         )
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::browser::sleep;
+use crate::popper::{Align, Placement, Side, use_popper};
+use crate::{Shortcut, ShortcutHint, Style, classnames, traits::ToElement, use_unique_id};
+
+/// 提示的位置
+///
+/// 方向表示提示位于触发元素的哪一侧，`Start` 和 `End` 表示在交叉轴上与触发元素的起始或末尾边缘对齐。
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TooltipPlacement {
+    /// 上方居中
+    #[default]
+    Top,
+    /// 上方左对齐
+    TopStart,
+    /// 上方右对齐
+    TopEnd,
+    /// 下方居中
+    Bottom,
+    /// 下方左对齐
+    BottomStart,
+    /// 下方右对齐
+    BottomEnd,
+    /// 左侧居中
+    Left,
+    /// 左侧顶部对齐
+    LeftStart,
+    /// 左侧底部对齐
+    LeftEnd,
+    /// 右侧居中
+    Right,
+    /// 右侧顶部对齐
+    RightStart,
+    /// 右侧底部对齐
+    RightEnd,
+}
+
+impl TooltipPlacement {
+    /// 对应的 CSS 类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            TooltipPlacement::Top => "t-tooltip__popup--top",
+            TooltipPlacement::TopStart => "t-tooltip__popup--top-start",
+            TooltipPlacement::TopEnd => "t-tooltip__popup--top-end",
+            TooltipPlacement::Bottom => "t-tooltip__popup--bottom",
+            TooltipPlacement::BottomStart => "t-tooltip__popup--bottom-start",
+            TooltipPlacement::BottomEnd => "t-tooltip__popup--bottom-end",
+            TooltipPlacement::Left => "t-tooltip__popup--left",
+            TooltipPlacement::LeftStart => "t-tooltip__popup--left-start",
+            TooltipPlacement::LeftEnd => "t-tooltip__popup--left-end",
+            TooltipPlacement::Right => "t-tooltip__popup--right",
+            TooltipPlacement::RightStart => "t-tooltip__popup--right-start",
+            TooltipPlacement::RightEnd => "t-tooltip__popup--right-end",
+        }
+    }
+}
+
+impl Placement for TooltipPlacement {
+    fn parts(self) -> (Side, Align) {
+        match self {
+            TooltipPlacement::Top => (Side::Top, Align::Center),
+            TooltipPlacement::TopStart => (Side::Top, Align::Start),
+            TooltipPlacement::TopEnd => (Side::Top, Align::End),
+            TooltipPlacement::Bottom => (Side::Bottom, Align::Center),
+            TooltipPlacement::BottomStart => (Side::Bottom, Align::Start),
+            TooltipPlacement::BottomEnd => (Side::Bottom, Align::End),
+            TooltipPlacement::Left => (Side::Left, Align::Center),
+            TooltipPlacement::LeftStart => (Side::Left, Align::Start),
+            TooltipPlacement::LeftEnd => (Side::Left, Align::End),
+            TooltipPlacement::Right => (Side::Right, Align::Center),
+            TooltipPlacement::RightStart => (Side::Right, Align::Start),
+            TooltipPlacement::RightEnd => (Side::Right, Align::End),
+        }
+    }
+
+    fn from_parts(side: Side, align: Align) -> Self {
+        match (side, align) {
+            (Side::Top, Align::Center) => TooltipPlacement::Top,
+            (Side::Top, Align::Start) => TooltipPlacement::TopStart,
+            (Side::Top, Align::End) => TooltipPlacement::TopEnd,
+            (Side::Bottom, Align::Center) => TooltipPlacement::Bottom,
+            (Side::Bottom, Align::Start) => TooltipPlacement::BottomStart,
+            (Side::Bottom, Align::End) => TooltipPlacement::BottomEnd,
+            (Side::Left, Align::Center) => TooltipPlacement::Left,
+            (Side::Left, Align::Start) => TooltipPlacement::LeftStart,
+            (Side::Left, Align::End) => TooltipPlacement::LeftEnd,
+            (Side::Right, Align::Center) => TooltipPlacement::Right,
+            (Side::Right, Align::Start) => TooltipPlacement::RightStart,
+            (Side::Right, Align::End) => TooltipPlacement::RightEnd,
+        }
+    }
+}
+
+impl std::fmt::Display for TooltipPlacement {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str(self.as_class())
+    }
+}
+
+/// 提示的触发方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum TooltipTrigger {
+    /// 鼠标移入时显示，移出时隐藏
+    #[default]
+    Hover,
+    /// 点击触发元素切换显示，点击其他位置或按 Escape 隐藏
+    Click,
+    /// 触发元素获得焦点时显示，失去焦点时隐藏
+    Focus,
+}
+
+/// 文字提示组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Tooltip {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，作为触发元素
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 提示内容
+    content: String,
+    /// 提示的位置
+    placement: TooltipPlacement,
+    /// 触发方式
+    trigger: TooltipTrigger,
+    /// 显示前的延迟，单位毫秒
+    show_delay: u32,
+    /// 隐藏前的延迟，单位毫秒
+    hide_delay: u32,
+    /// 提示与触发元素之间的距离，单位像素
+    offset: u32,
+    /// 是否显示箭头
+    show_arrow: bool,
+    /// 是否禁用
+    disabled: bool,
+    /// 显示在提示内容后的快捷键
+    shortcut: Option<Shortcut>,
+}
+
+impl Default for Tooltip {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::TOOLTIP),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            content: String::new(),
+            placement: TooltipPlacement::Top,
+            trigger: TooltipTrigger::Hover,
+            show_delay: 0,
+            hide_delay: 100,
+            offset: 8,
+            show_arrow: true,
+            disabled: false,
+            shortcut: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Tooltip {
+    /// 使用提示内容创建文字提示，通过 `children` 添加触发元素
+    pub fn new(content: impl Into<String>) -> Self {
+        Self {
+            content: content.into(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置提示的位置，空间不足时自动翻转到另一侧
+    #[prop(default = "TooltipPlacement::Top")]
+    pub fn placement(mut self, placement: TooltipPlacement) -> Self {
+        self.placement = placement;
+        self
+    }
+
+    /// 设置触发方式
+    #[prop(default = "TooltipTrigger::Hover")]
+    pub fn trigger(mut self, trigger: TooltipTrigger) -> Self {
+        self.trigger = trigger;
+        self
+    }
+
+    /// 设置显示前的延迟，单位毫秒
+    #[prop(default = "0")]
+    pub fn show_delay(mut self, ms: u32) -> Self {
+        self.show_delay = ms;
+        self
+    }
+
+    /// 设置隐藏前的延迟，单位毫秒，鼠标可以在延迟内移入提示
+    #[prop(default = "100")]
+    pub fn hide_delay(mut self, ms: u32) -> Self {
+        self.hide_delay = ms;
+        self
+    }
+
+    /// 设置提示与触发元素之间的距离，单位像素
+    #[prop(default = "8")]
+    pub fn offset(mut self, offset: u32) -> Self {
+        self.offset = offset;
+        self
+    }
+
+    /// 设置是否显示箭头
+    #[prop(default = "true")]
+    pub fn show_arrow(mut self, show_arrow: bool) -> Self {
+        self.show_arrow = show_arrow;
+        self
+    }
+
+    /// 设置是否禁用，禁用后不显示提示
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置显示在提示内容后的快捷键，如 `mod+S`，按当前平台格式化
+    pub fn shortcut(mut self, shortcut: impl Into<Shortcut>) -> Self {
+        self.shortcut = Some(shortcut.into());
+        self
+    }
+}
+
+impl ToElement for Tooltip {
+    fn to_element(&self) -> Element {
+        rsx! {
+            TooltipElement { tooltip: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct TooltipElementProps {
+    tooltip: Rc<Tooltip>,
+}
+
+impl PartialEq for TooltipElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.tooltip, &other.tooltip)
+    }
+}
+
+#[allow(non_snake_case)]
+fn TooltipElement(props: TooltipElementProps) -> Element {
+    let tooltip = props.tooltip;
+    let popup_id = use_unique_id("tooltip");
+    let popper = use_popper::<TooltipPlacement>();
+    let mut open = use_signal(|| false);
+    // 每次显示或隐藏递增，延迟结束时只有最后一次请求生效
+    let mut round = use_signal(|| 0u64);
+
+    let (show_delay, hide_delay) = (tooltip.show_delay, tooltip.hide_delay);
+    let mut schedule = move |value: bool| {
+        round += 1;
+        let current = *round.peek();
+        let delay = if value { show_delay } else { hide_delay };
+        let mut apply = move || {
+            open.set(value);
+            if !value {
+                popper.reset();
+            }
+        };
+        if delay == 0 {
+            apply();
+            return;
+        }
+        spawn(async move {
+            sleep(delay).await;
+            if *round.peek() == current {
+                apply();
+            }
+        });
+    };
+
+    let visible = open() && !tooltip.disabled;
+
+    let trigger = tooltip.trigger;
+    let placement = tooltip.placement;
+    let offset = tooltip.offset as f64;
+    let (actual, shift) = popper.position(placement);
+    let id = tooltip.id.clone();
+    let class = tooltip.class.to_string();
+    let style = tooltip.style.clone().map(|s| s.to_string());
+    let onclick_handler = tooltip.onclick;
+    let content = tooltip.content.clone();
+    let popup_class = format!("{} {}", classnames::TOOLTIP_POPUP, actual.as_class());
+    let popup_style = format!(
+        "--t-tooltip-offset: {}px; --t-tooltip-shift: {shift}px;",
+        tooltip.offset
+    );
+    let describedby = visible.then(|| popup_id.clone());
+
+    rsx! {
+        span {
+            id,
+            class,
+            style,
+            aria_describedby: describedby,
+            onmounted: move |event: MountedEvent| popper.set_anchor(event),
+            onmouseenter: move |_| {
+                if trigger == TooltipTrigger::Hover {
+                    schedule(true);
+                }
+            },
+            onmouseleave: move |_| {
+                if trigger == TooltipTrigger::Hover {
+                    schedule(false);
+                }
+            },
+            onfocusin: move |_| {
+                if trigger == TooltipTrigger::Focus {
+                    schedule(true);
+                }
+            },
+            onfocusout: move |_| {
+                if trigger == TooltipTrigger::Focus {
+                    schedule(false);
+                }
+            },
+            onkeydown: move |event: KeyboardEvent| {
+                if event.key() == Key::Escape && open() {
+                    round += 1;
+                    open.set(false);
+                    popper.reset();
+                }
+            },
+            onclick: move |event: MouseEvent| {
+                if trigger == TooltipTrigger::Click {
+                    schedule(!open());
+                }
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            {tooltip.childrens_to_element()}
+            if visible {
+                if trigger == TooltipTrigger::Click {
+                    span {
+                        class: classnames::TOOLTIP_BACKDROP,
+                        onclick: move |event: MouseEvent| {
+                            event.stop_propagation();
+                            schedule(false);
+                        },
+                    }
+                }
+                span {
+                    id: popup_id,
+                    class: popup_class,
+                    style: popup_style,
+                    role: "tooltip",
+                    onmounted: move |event: MountedEvent| popper.measure(event, placement, offset),
+                    onclick: move |event: MouseEvent| event.stop_propagation(),
+                    "{content}"
+                    if let Some(shortcut) = tooltip.shortcut.clone() {
+                        {
+                            ShortcutHint::new(shortcut)
+                                .class(classnames::TOOLTIP_SHORTCUT)
+                                .to_element()
+                        }
+                    }
+                    if tooltip.show_arrow {
+                        span { class: classnames::TOOLTIP_ARROW }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::popper::{Rect, resolve_position};
+    use crate::testing::Harness;
+    use crate::{Button, View};
+    use dioxus_html::{PlatformEventData, SerializedFocusData};
+
+    const VIEWPORT: (f64, f64) = (1000.0, 800.0);
+
+    fn trigger(x: f64, y: f64) -> Rect {
+        Rect {
+            x,
+            y,
+            width: 100.0,
+            height: 32.0,
+        }
+    }
+
+    #[test]
+    fn test_resolve_position_keeps_placement_with_enough_space() {
+        let position = resolve_position(
+            TooltipPlacement::Top,
+            trigger(400.0, 300.0),
+            (120.0, 30.0),
+            VIEWPORT,
+            8.0,
+        );
+        assert_eq!(position, (TooltipPlacement::Top, 0.0));
+    }
+
+    #[test]
+    fn test_resolve_position_flips() {
+        // 上方只有 20px，翻转到下方并保持对齐方式
+        let (placement, _) = resolve_position(
+            TooltipPlacement::TopStart,
+            trigger(400.0, 20.0),
+            (120.0, 30.0),
+            VIEWPORT,
+            8.0,
+        );
+        assert_eq!(placement, TooltipPlacement::BottomStart);
+
+        // 右侧空间不足，翻转到左侧
+        let (placement, _) = resolve_position(
+            TooltipPlacement::Right,
+            trigger(850.0, 300.0),
+            (120.0, 30.0),
+            VIEWPORT,
+            8.0,
+        );
+        assert_eq!(placement, TooltipPlacement::Left);
+
+        // 两侧都放不下时保持原来的方向
+        let (placement, _) = resolve_position(
+            TooltipPlacement::Bottom,
+            trigger(400.0, 0.0),
+            (120.0, 900.0),
+            VIEWPORT,
+            8.0,
+        );
+        assert_eq!(placement, TooltipPlacement::Bottom);
+    }
+
+    #[test]
+    fn test_resolve_position_shifts_into_viewport() {
+        // 居中时左边缘位于 -50px，平移到距视口左边缘 8px
+        let (_, shift) = resolve_position(
+            TooltipPlacement::Top,
+            trigger(0.0, 300.0),
+            (200.0, 30.0),
+            VIEWPORT,
+            8.0,
+        );
+        assert_eq!(shift, 58.0);
+
+        // 末尾对齐时右边缘超出视口
+        let (_, shift) = resolve_position(
+            TooltipPlacement::BottomStart,
+            trigger(900.0, 300.0),
+            (200.0, 30.0),
+            VIEWPORT,
+            8.0,
+        );
+        assert_eq!(shift, -108.0);
+    }
+
+    fn popup(harness: &Harness) -> Option<crate::testing::ElementRef> {
+        harness.find_by_attr("role", "tooltip")
+    }
+
+    #[test]
+    fn test_tooltip_hover() {
+        let mut harness = Harness::with_element(
+            Tooltip::new("保存修改")
+                .placement(TooltipPlacement::LeftEnd)
+                .hide_delay(0)
+                .children(Button::new().text("保存")),
+        );
+        let root = harness.find_by_class(classnames::TOOLTIP).unwrap();
+        assert!(popup(&harness).is_none());
+
+        harness.mouse(&root, "mouseenter");
+        let tip = popup(&harness).unwrap();
+        assert_eq!(tip.text(), "保存修改");
+        assert!(tip.has_class("t-tooltip__popup--left-end"));
+        assert!(harness.find_by_class(classnames::TOOLTIP_ARROW).is_some());
+        assert_eq!(
+            harness
+                .find_by_class(classnames::TOOLTIP)
+                .unwrap()
+                .attr("aria-describedby"),
+            tip.attr("id")
+        );
+
+        harness.mouse(&root, "mouseleave");
+        assert!(popup(&harness).is_none());
+    }
+
+    #[test]
+    fn test_tooltip_click() {
+        fn app() -> Element {
+            View::new()
+                .children(
+                    Tooltip::new("点击提示")
+                        .trigger(TooltipTrigger::Click)
+                        .show_arrow(false)
+                        .children(Button::new().text("打开")),
+                )
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let root = harness.find_by_class(classnames::TOOLTIP).unwrap();
+        harness.mouse(&root, "mouseenter");
+        assert!(popup(&harness).is_none());
+
+        harness.click(&harness.find_by_text("打开").unwrap());
+        assert!(popup(&harness).is_some());
+        assert!(harness.find_by_class(classnames::TOOLTIP_ARROW).is_none());
+
+        // 点击提示本身不会关闭
+        harness.click(&popup(&harness).unwrap());
+        assert!(popup(&harness).is_some());
+
+        harness.click(&harness.find_by_class(classnames::TOOLTIP_BACKDROP).unwrap());
+        assert!(popup(&harness).is_none());
+
+        harness.click(&harness.find_by_text("打开").unwrap());
+        harness.keydown(&harness.find_by_text("打开").unwrap(), "Escape");
+        assert!(popup(&harness).is_none());
+    }
+
+    #[test]
+    fn test_tooltip_focus_and_disabled() {
+        fn focus(harness: &mut Harness, name: &str) {
+            let root = harness.find_by_class(classnames::TOOLTIP).unwrap();
+            let data = PlatformEventData::new(Box::new(SerializedFocusData::default()));
+            harness.dispatch(&root, name, data, true);
+        }
+
+        let mut harness = Harness::with_element(
+            Tooltip::new("输入用户名")
+                .trigger(TooltipTrigger::Focus)
+                .hide_delay(0)
+                .children(Button::new().text("聚焦")),
+        );
+        focus(&mut harness, "focusin");
+        assert!(popup(&harness).is_some());
+        focus(&mut harness, "focusout");
+        assert!(popup(&harness).is_none());
+
+        let mut harness = Harness::with_element(
+            Tooltip::new("已禁用")
+                .disabled(true)
+                .children(Button::new().text("禁用")),
+        );
+        let root = harness.find_by_class(classnames::TOOLTIP).unwrap();
+        harness.mouse(&root, "mouseenter");
+        assert!(popup(&harness).is_none());
+    }
+}
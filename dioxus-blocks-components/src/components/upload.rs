@@ -0,0 +1,871 @@
+//! Upload 上传组件
+//!
+//! 显示一个上传区域，点击后打开系统的文件选择框，也可以把文件直接拖放到区域中，或在区域获得焦点时
+//! 粘贴剪贴板中的截图和文件（目前只支持 web 平台）。选中的文件按
+//! [`Upload::accept`]、[`Upload::max_size`] 和 [`Upload::max_count`] 校验，通过的文件加入文件列表并通过
+//! [`Upload::onchange`] 交给调用方上传，未通过的文件在区域下方显示原因并通过 [`Upload::onreject`] 通知。
+//!
+//! 文件列表保存在 [`Upload::file_list`] 绑定的 `Signal<Vec<UploadFile>>` 中，组件本身不发送请求：
+//! 调用方上传时通过 [`UploadFile::set_status`] 和 [`UploadFile::set_progress`] 更新列表中的文件，
+//! 上传中的文件显示进度条，可以通过 [`Upload::render_progress`] 自定义。
+//!
+//! 图片文件在列表中显示缩略图：web 平台上选择、拖放或粘贴的图片自动生成预览地址，
+//! 其他文件可以通过 [`UploadFile::set_preview`] 设置，如编辑时已经上传过的图片的地址。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{ToElement, Upload, UploadFile};
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let files = use_signal(Vec::<UploadFile>::new);
+//!
+//!     Upload::new()
+//!         .file_list(files)
+//!         .accept("image/*,.pdf")
+//!         .multiple(true)
+//!         .max_size(2 * 1024 * 1024)
+//!         .max_count(5)
+//!         .tip("支持图片和 PDF，单个文件不超过 2 MB")
+//!         .onchange(|files| println!("{} 个文件待上传", files.len()))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::borrow::Cow;
+use std::rc::Rc;
+use std::sync::atomic::{AtomicU64, Ordering};
+
+use dioxus::html::{FileData, HasFileData};
+use dioxus::prelude::*;
+
+use dioxus_blocks_macro::{ComponentBase, builder_props};
+
+use crate::clipboard::pasted_files;
+use crate::{Style, classnames, traits::ToElement};
+
+/// 文件的上传状态
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub enum UploadStatus {
+    /// 等待上传
+    #[default]
+    Ready,
+    /// 上传中
+    Uploading,
+    /// 上传成功
+    Success,
+    /// 上传失败，附带错误信息
+    Error(String),
+}
+
+impl UploadStatus {
+    /// 获取对应的CSS类名
+    pub fn as_class(&self) -> &'static str {
+        match self {
+            UploadStatus::Ready => "is-ready",
+            UploadStatus::Uploading => "is-uploading",
+            UploadStatus::Success => "is-success",
+            UploadStatus::Error(_) => "is-error",
+        }
+    }
+}
+
+/// 生成文件列表中唯一的标识
+fn next_uid() -> u64 {
+    static UID: AtomicU64 = AtomicU64::new(1);
+    UID.fetch_add(1, Ordering::Relaxed)
+}
+
+/// 文件列表中的文件
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadFile {
+    uid: u64,
+    name: String,
+    size: u64,
+    content_type: Option<String>,
+    file: Option<FileData>,
+    preview: Option<String>,
+    status: UploadStatus,
+    progress: u8,
+}
+
+impl UploadFile {
+    /// 创建不来自本次选择的文件，如编辑时已经上传过的文件，状态为上传成功
+    pub fn new(name: impl Into<String>, size: u64) -> Self {
+        Self {
+            uid: next_uid(),
+            name: name.into(),
+            size,
+            content_type: None,
+            file: None,
+            preview: None,
+            status: UploadStatus::Success,
+            progress: 100,
+        }
+    }
+
+    fn from_data(file: FileData) -> Self {
+        Self {
+            uid: next_uid(),
+            name: file.name(),
+            size: file.size(),
+            content_type: file.content_type(),
+            preview: object_url(&file),
+            file: Some(file),
+            status: UploadStatus::Ready,
+            progress: 0,
+        }
+    }
+
+    /// 列表中唯一的标识
+    pub fn uid(&self) -> u64 {
+        self.uid
+    }
+
+    /// 文件名
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 文件的字节数
+    pub fn size(&self) -> u64 {
+        self.size
+    }
+
+    /// 文件的 MIME 类型
+    pub fn content_type(&self) -> Option<&str> {
+        self.content_type.as_deref()
+    }
+
+    /// 选择的文件，可以读取内容后上传；通过 [`UploadFile::new`] 创建的文件为 `None`
+    pub fn file(&self) -> Option<&FileData> {
+        self.file.as_ref()
+    }
+
+    /// 图片的预览地址
+    pub fn preview(&self) -> Option<&str> {
+        self.preview.as_deref()
+    }
+
+    /// 设置图片的预览地址，列表中以缩略图显示
+    pub fn set_preview(&mut self, url: impl Into<String>) {
+        self.preview = Some(url.into());
+    }
+
+    /// 上传状态
+    pub fn status(&self) -> &UploadStatus {
+        &self.status
+    }
+
+    /// 上传进度的百分比
+    pub fn progress(&self) -> u8 {
+        self.progress
+    }
+
+    /// 设置上传状态，上传成功时进度设为 100
+    pub fn set_status(&mut self, status: UploadStatus) {
+        if status == UploadStatus::Success {
+            self.progress = 100;
+        }
+        self.status = status;
+    }
+
+    /// 设置上传进度的百分比，超过 100 时按 100 处理，状态设为上传中
+    pub fn set_progress(&mut self, percent: u8) {
+        self.progress = percent.min(100);
+        self.status = UploadStatus::Uploading;
+    }
+}
+
+/// 为选择的图片创建 `blob:` 预览地址，只在 web 平台上可用
+fn object_url(file: &FileData) -> Option<String> {
+    let is_image = file
+        .content_type()
+        .is_some_and(|content_type| content_type.starts_with("image/"));
+    if !is_image {
+        return None;
+    }
+    #[cfg(feature = "web")]
+    if let Some(file) = file.inner().downcast_ref::<web_sys::File>() {
+        return web_sys::Url::create_object_url_with_blob(file).ok();
+    }
+    None
+}
+
+/// 释放由 [`object_url`] 创建的预览地址
+fn revoke_preview(file: &UploadFile) {
+    if let (Some(_), Some(url)) = (&file.file, &file.preview)
+        && url.starts_with("blob:")
+    {
+        #[cfg(feature = "web")]
+        let _ = web_sys::Url::revoke_object_url(url);
+    }
+}
+
+/// 文件未通过校验的原因
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum UploadRejectReason {
+    /// 文件类型不在 accept 范围内
+    NotAccepted,
+    /// 超过单个文件的大小限制
+    TooLarge,
+    /// 超过文件数量限制
+    TooMany,
+}
+
+/// 未通过校验的文件
+#[derive(Debug, Clone, PartialEq)]
+pub struct UploadRejection {
+    name: String,
+    reason: UploadRejectReason,
+    message: String,
+}
+
+impl UploadRejection {
+    /// 文件名
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// 未通过的原因
+    pub fn reason(&self) -> UploadRejectReason {
+        self.reason
+    }
+
+    /// 显示的错误信息
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// 文件是否符合 accept 的规则，规则的格式与 `<input accept>` 相同：
+/// 逗号分隔的扩展名（`.pdf`）、MIME 类型（`image/png`）或通配类型（`image/*`）
+fn accepts(accept: &str, name: &str, content_type: Option<&str>) -> bool {
+    let name = name.to_lowercase();
+    let content_type = content_type.unwrap_or_default().to_lowercase();
+    let mut patterns = accept
+        .split(',')
+        .map(|pattern| pattern.trim().to_lowercase())
+        .filter(|pattern| !pattern.is_empty())
+        .peekable();
+    if patterns.peek().is_none() {
+        return true;
+    }
+    patterns.any(|pattern| {
+        if pattern.starts_with('.') {
+            name.ends_with(&pattern)
+        } else if let Some(group) = pattern.strip_suffix("/*") {
+            content_type
+                .strip_prefix(group)
+                .is_some_and(|rest| rest.starts_with('/'))
+        } else {
+            content_type == pattern
+        }
+    })
+}
+
+/// 以 B、KB、MB、GB 显示字节数
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 3] = ["KB", "MB", "GB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64 / 1024.0;
+    let mut unit = 0;
+    while size >= 1024.0 && unit < UNITS.len() - 1 {
+        size /= 1024.0;
+        unit += 1;
+    }
+    format!("{size:.1} {}", UNITS[unit])
+}
+
+/// 上传组件
+#[derive(Debug, Clone, ComponentBase)]
+pub struct Upload {
+    /// 组件的唯一标识符
+    id: Option<String>,
+    /// 组件的CSS类名
+    class: Cow<'static, str>,
+    /// 组件的内联样式
+    style: Option<Style>,
+    /// 组件的子元素列表，替代上传区域中默认的提示文字
+    childrens: Vec<Rc<dyn ToElement>>,
+    /// 组件的点击事件
+    onclick: Option<EventHandler<MouseEvent>>,
+
+    /// 文件列表的 Signal
+    file_list: Option<Signal<Vec<UploadFile>>>,
+    /// 接受的文件类型
+    accept: Option<String>,
+    /// 是否可以选择多个文件
+    multiple: bool,
+    /// 单个文件的最大字节数
+    max_size: Option<u64>,
+    /// 文件列表的最大文件数
+    max_count: Option<usize>,
+    /// 是否禁用
+    disabled: bool,
+    /// 是否显示文件列表
+    show_file_list: bool,
+    /// 上传区域下方的提示
+    tip: Option<String>,
+    /// 文件输入框的 id
+    input_id: Option<String>,
+    /// 选择文件后的回调，参数为通过校验的文件
+    onchange: Option<EventHandler<Vec<FileData>>>,
+    /// 有文件未通过校验时的回调
+    onreject: Option<EventHandler<Vec<UploadRejection>>>,
+    /// 从列表中删除文件后的回调
+    onremove: Option<EventHandler<UploadFile>>,
+    /// 自定义上传进度的渲染闭包
+    render_progress: Option<Callback<UploadFile, Rc<dyn ToElement>>>,
+}
+
+impl Default for Upload {
+    fn default() -> Self {
+        Self {
+            id: None,
+            class: Cow::Borrowed(classnames::UPLOAD),
+            style: None,
+            childrens: Vec::new(),
+            onclick: None,
+            file_list: None,
+            accept: None,
+            multiple: false,
+            max_size: None,
+            max_count: None,
+            disabled: false,
+            show_file_list: true,
+            tip: None,
+            input_id: None,
+            onchange: None,
+            onreject: None,
+            onremove: None,
+            render_progress: None,
+        }
+    }
+}
+
+#[builder_props]
+impl Upload {
+    /// 创建一个新的上传组件实例
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置文件列表的 Signal，未设置时由组件内部保存
+    pub fn file_list(mut self, files: Signal<Vec<UploadFile>>) -> Self {
+        self.file_list = Some(files);
+        self
+    }
+
+    /// 设置接受的文件类型，格式与 `<input accept>` 相同，如 `image/*,.pdf`，拖放的文件同样按此校验
+    pub fn accept(mut self, accept: impl Into<String>) -> Self {
+        self.accept = Some(accept.into());
+        self
+    }
+
+    /// 设置是否可以选择多个文件，单选时新选择的文件替换列表中的文件
+    #[prop(default = "false")]
+    pub fn multiple(mut self, multiple: bool) -> Self {
+        self.multiple = multiple;
+        self
+    }
+
+    /// 设置单个文件的最大字节数
+    pub fn max_size(mut self, bytes: u64) -> Self {
+        self.max_size = Some(bytes);
+        self
+    }
+
+    /// 设置文件列表的最大文件数，超出的文件不会加入列表
+    pub fn max_count(mut self, count: usize) -> Self {
+        self.max_count = Some(count);
+        self
+    }
+
+    /// 设置禁用状态
+    #[prop(default = "false")]
+    pub fn disabled(mut self, disabled: bool) -> Self {
+        self.disabled = disabled;
+        self
+    }
+
+    /// 设置是否显示文件列表
+    #[prop(default = "true")]
+    pub fn show_file_list(mut self, show: bool) -> Self {
+        self.show_file_list = show;
+        self
+    }
+
+    /// 设置上传区域下方的提示，如文件类型和大小的说明
+    pub fn tip(mut self, tip: impl Into<String>) -> Self {
+        self.tip = Some(tip.into());
+        self
+    }
+
+    /// 设置文件输入框的 id，用于关联 label 的 for 属性
+    pub fn input_id(mut self, input_id: impl Into<String>) -> Self {
+        self.input_id = Some(input_id.into());
+        self
+    }
+
+    /// 设置选择或拖放文件后的回调，参数为通过校验的文件
+    pub fn onchange(mut self, handler: impl FnMut(Vec<FileData>) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置有文件未通过校验时的回调
+    pub fn onreject(mut self, handler: impl FnMut(Vec<UploadRejection>) + 'static) -> Self {
+        self.onreject = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置从列表中删除文件后的回调
+    pub fn onremove(mut self, handler: impl FnMut(UploadFile) + 'static) -> Self {
+        self.onremove = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置上传中的文件的进度渲染闭包，替代默认的进度条
+    pub fn render_progress(
+        mut self,
+        f: impl FnMut(UploadFile) -> Rc<dyn ToElement> + 'static,
+    ) -> Self {
+        self.render_progress = Some(Callback::new(f));
+        self
+    }
+}
+
+impl ToElement for Upload {
+    fn to_element(&self) -> Element {
+        rsx! {
+            UploadElement { upload: Rc::new(self.clone()) }
+        }
+    }
+}
+
+#[derive(Props, Clone)]
+struct UploadElementProps {
+    upload: Rc<Upload>,
+}
+
+impl PartialEq for UploadElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.upload, &other.upload)
+    }
+}
+
+#[allow(non_snake_case)]
+fn UploadElement(props: UploadElementProps) -> Element {
+    let upload = props.upload;
+    let inner_files = use_signal(Vec::<UploadFile>::new);
+    let mut files = upload.file_list.unwrap_or(inner_files);
+    let mut dragover = use_signal(|| false);
+    let mut rejections = use_signal(Vec::<UploadRejection>::new);
+
+    let disabled = upload.disabled;
+    let accept = upload.accept.clone();
+    let multiple = upload.multiple;
+    let max_size = upload.max_size;
+    let max_count = upload.max_count;
+    let onchange = upload.onchange;
+    let onreject = upload.onreject;
+    let onremove = upload.onremove;
+
+    // 校验选择或拖放的文件，通过的加入列表
+    let add = use_callback(move |selected: Vec<FileData>| {
+        if disabled {
+            return;
+        }
+        let existing = if multiple { files.peek().len() } else { 0 };
+        let mut accepted = Vec::new();
+        let mut rejected = Vec::new();
+        let selected = match multiple {
+            true => selected,
+            false => selected.into_iter().take(1).collect(),
+        };
+        for file in selected {
+            let name = file.name();
+            let content_type = file.content_type();
+            let (reason, message) = if !accepts(
+                accept.as_deref().unwrap_or_default(),
+                &name,
+                content_type.as_deref(),
+            ) {
+                (
+                    UploadRejectReason::NotAccepted,
+                    format!("{name}：不支持的文件类型"),
+                )
+            } else if let Some(max) = max_size.filter(|max| file.size() > *max) {
+                (
+                    UploadRejectReason::TooLarge,
+                    format!("{name}：文件大小不能超过 {}", format_size(max)),
+                )
+            } else if let Some(max) = max_count.filter(|max| existing + accepted.len() >= *max) {
+                (
+                    UploadRejectReason::TooMany,
+                    format!("{name}：最多上传 {max} 个文件"),
+                )
+            } else {
+                accepted.push(file);
+                continue;
+            };
+            rejected.push(UploadRejection {
+                name,
+                reason,
+                message,
+            });
+        }
+
+        rejections.set(rejected.clone());
+        if !accepted.is_empty() {
+            let added = accepted.iter().cloned().map(UploadFile::from_data);
+            match multiple {
+                true => files.write().extend(added),
+                false => {
+                    files.peek().iter().for_each(revoke_preview);
+                    files.set(added.collect());
+                }
+            }
+            if let Some(handler) = onchange {
+                handler.call(accepted);
+            }
+        }
+        if !rejected.is_empty()
+            && let Some(handler) = onreject
+        {
+            handler.call(rejected);
+        }
+    });
+
+    let mut class = vec![upload.class.to_string()];
+    if disabled {
+        class.push("is-disabled".to_string());
+    }
+    let class = class.join(" ");
+    let style = upload.style.clone().map(|s| s.to_string());
+    let mut dragger_class = vec![classnames::UPLOAD_DRAGGER];
+    if dragover() {
+        dragger_class.push("is-dragover");
+    }
+    let dragger_class = dragger_class.join(" ");
+    let onclick_handler = upload.onclick;
+    let render_progress = upload.render_progress;
+    let list = files.read().clone();
+
+    rsx! {
+        div {
+            id: upload.id.clone(),
+            class,
+            style,
+            onclick: move |event: MouseEvent| {
+                if let Some(handler) = onclick_handler {
+                    handler.call(event);
+                }
+            },
+            label {
+                class: dragger_class,
+                ondragover: move |event: DragEvent| {
+                    event.prevent_default();
+                    if !disabled && !dragover() {
+                        dragover.set(true);
+                    }
+                },
+                ondragleave: move |_| dragover.set(false),
+                ondrop: move |event: DragEvent| {
+                    event.prevent_default();
+                    dragover.set(false);
+                    add(event.files());
+                },
+                // 文件输入框获得焦点时粘贴，粘贴的是文本时保持默认行为
+                onpaste: move |event: ClipboardEvent| {
+                    let pasted = pasted_files(&event);
+                    if !pasted.is_empty() {
+                        event.prevent_default();
+                        add(pasted);
+                    }
+                },
+                input {
+                    id: upload.input_id.clone(),
+                    class: classnames::UPLOAD_INPUT,
+                    r#type: "file",
+                    accept: upload.accept.clone(),
+                    multiple,
+                    disabled,
+                    onchange: move |event: FormEvent| add(event.files()),
+                }
+                if upload.childrens.is_empty() {
+                    span { class: classnames::UPLOAD_ICON, "aria-hidden": "true", "⇪" }
+                    span { class: classnames::UPLOAD_TEXT,
+                        "将文件拖到此处，或"
+                        em { "点击上传" }
+                    }
+                } else {
+                    {upload.childrens_to_element()}
+                }
+            }
+            if let Some(tip) = &upload.tip {
+                div { class: classnames::UPLOAD_TIP, "{tip}" }
+            }
+            if !rejections.read().is_empty() {
+                ul { class: classnames::UPLOAD_ERRORS, role: "alert",
+                    for rejection in rejections.read().iter() {
+                        li { "{rejection.message}" }
+                    }
+                }
+            }
+            if upload.show_file_list && !list.is_empty() {
+                ul { class: classnames::UPLOAD_LIST,
+                    for file in list {
+                        li {
+                            key: "{file.uid}",
+                            class: "{classnames::UPLOAD_ITEM} {file.status.as_class()}",
+                            if let Some(preview) = &file.preview {
+                                img {
+                                    class: classnames::UPLOAD_THUMBNAIL,
+                                    src: "{preview}",
+                                    alt: "",
+                                }
+                            }
+                            span { class: classnames::UPLOAD_NAME, title: file.name.clone(), "{file.name}" }
+                            span { class: classnames::UPLOAD_SIZE, {format_size(file.size)} }
+                            if let UploadStatus::Error(message) = &file.status {
+                                span { class: classnames::UPLOAD_MESSAGE, "{message}" }
+                            }
+                            button {
+                                class: classnames::UPLOAD_REMOVE,
+                                r#type: "button",
+                                disabled,
+                                "aria-label": "删除 {file.name}",
+                                onclick: {
+                                    let file = file.clone();
+                                    move |_| {
+                                        revoke_preview(&file);
+                                        files.write().retain(|item| item.uid != file.uid);
+                                        if let Some(handler) = onremove {
+                                            handler.call(file.clone());
+                                        }
+                                    }
+                                },
+                                "×"
+                            }
+                            if file.status == UploadStatus::Uploading {
+                                if let Some(render) = render_progress {
+                                    {render.call(file.clone()).to_element()}
+                                } else {
+                                    div {
+                                        class: classnames::UPLOAD_PROGRESS,
+                                        role: "progressbar",
+                                        "aria-valuemin": "0",
+                                        "aria-valuemax": "100",
+                                        "aria-valuenow": "{file.progress}",
+                                        div {
+                                            class: classnames::UPLOAD_PROGRESS_BAR,
+                                            style: "width: {file.progress}%",
+                                        }
+                                    }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{Harness, MockFile};
+
+    #[test]
+    fn test_accepts() {
+        assert!(accepts("", "a.exe", None));
+        assert!(accepts("image/*,.pdf", "photo.PNG", Some("image/png")));
+        assert!(accepts("image/*,.pdf", "report.pdf", None));
+        assert!(!accepts("image/*,.pdf", "notes.txt", Some("text/plain")));
+        assert!(!accepts("image/*", "x", Some("imagex/png")));
+        assert!(accepts(
+            "application/json",
+            "data",
+            Some("application/json")
+        ));
+    }
+
+    #[test]
+    fn test_format_size() {
+        assert_eq!(format_size(512), "512 B");
+        assert_eq!(format_size(1536), "1.5 KB");
+        assert_eq!(format_size(2 * 1024 * 1024), "2.0 MB");
+    }
+
+    fn app() -> Element {
+        let files = use_signal(|| vec![UploadFile::new("existing.pdf", 2048)]);
+        let mut changed = use_signal(|| 0);
+        rsx! {
+            {
+                Upload::new()
+                    .file_list(files)
+                    .accept("image/*,.pdf")
+                    .multiple(true)
+                    .max_size(1024 * 1024)
+                    .max_count(3)
+                    .onchange(move |selected| changed += selected.len())
+                    .to_element()
+            }
+            span { class: "changed", "{changed}" }
+        }
+    }
+
+    #[test]
+    fn test_upload_validates_selected_files() {
+        let mut harness = Harness::new(app);
+        assert_eq!(harness.find_all_by_class(classnames::UPLOAD_ITEM).len(), 1);
+
+        let input = harness.find_by_class(classnames::UPLOAD_INPUT).unwrap();
+        assert_eq!(input.attr("accept"), Some("image/*,.pdf"));
+        harness.select_files(
+            &input,
+            vec![
+                MockFile::new("a.png", 100).content_type("image/png"),
+                MockFile::new("b.txt", 100).content_type("text/plain"),
+                MockFile::new("c.pdf", 2 * 1024 * 1024),
+                MockFile::new("d.pdf", 100),
+                MockFile::new("e.pdf", 100),
+            ],
+        );
+        assert_eq!(harness.find_by_class("changed").unwrap().text(), "2");
+        let names = harness
+            .find_all_by_class(classnames::UPLOAD_NAME)
+            .iter()
+            .map(|name| name.text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["existing.pdf", "a.png", "d.pdf"]);
+        let errors = harness
+            .find_all(|element| {
+                element.tag() == "li"
+                    && element
+                        .attr("class")
+                        .is_none_or(|class| !class.contains(classnames::UPLOAD_ITEM))
+            })
+            .iter()
+            .map(|error| error.text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            errors,
+            [
+                "b.txt：不支持的文件类型",
+                "c.pdf：文件大小不能超过 1.0 MB",
+                "e.pdf：最多上传 3 个文件",
+            ]
+        );
+
+        // 删除后可以继续添加
+        let remove = harness.find_by_class(classnames::UPLOAD_REMOVE).unwrap();
+        assert_eq!(remove.attr("aria-label"), Some("删除 existing.pdf"));
+        harness.click(&remove);
+        let dragger = harness.find_by_class(classnames::UPLOAD_DRAGGER).unwrap();
+        harness.drag_files(&dragger, "drop", vec![MockFile::new("f.pdf", 10)]);
+        assert_eq!(harness.find_all_by_class(classnames::UPLOAD_ITEM).len(), 3);
+        assert!(harness.find_by_class(classnames::UPLOAD_ERRORS).is_none());
+    }
+
+    #[test]
+    fn test_upload_paste_and_preview() {
+        fn app() -> Element {
+            let files = use_signal(|| {
+                let mut file = UploadFile::new("avatar.png", 2048);
+                file.set_preview("https://example.com/avatar.png");
+                vec![file]
+            });
+            Upload::new()
+                .file_list(files)
+                .accept("image/*")
+                .multiple(true)
+                .to_element()
+        }
+
+        let mut harness = Harness::new(app);
+        let thumbnails = harness.find_all_by_class(classnames::UPLOAD_THUMBNAIL);
+        assert_eq!(thumbnails.len(), 1);
+        assert_eq!(
+            thumbnails[0].attr("src"),
+            Some("https://example.com/avatar.png")
+        );
+
+        // 粘贴的文件同样经过校验
+        let input = harness.find_by_class(classnames::UPLOAD_INPUT).unwrap();
+        harness.paste_files(
+            &input,
+            vec![
+                MockFile::new("screenshot.png", 100).content_type("image/png"),
+                MockFile::new("notes.txt", 100).content_type("text/plain"),
+            ],
+        );
+        let names = harness
+            .find_all_by_class(classnames::UPLOAD_NAME)
+            .iter()
+            .map(|name| name.text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["avatar.png", "screenshot.png"]);
+        assert_eq!(
+            harness
+                .find_by_class(classnames::UPLOAD_ERRORS)
+                .unwrap()
+                .text(),
+            "notes.txt：不支持的文件类型"
+        );
+
+        // 粘贴文本时不影响列表
+        harness.paste_files(&input, Vec::new());
+        assert_eq!(harness.find_all_by_class(classnames::UPLOAD_ITEM).len(), 2);
+    }
+
+    #[test]
+    fn test_upload_progress() {
+        fn app() -> Element {
+            let mut files = use_signal(|| {
+                vec![
+                    UploadFile::new("report.pdf", 2048),
+                    UploadFile::new("photo.png", 10),
+                ]
+            });
+            rsx! {
+                {Upload::new().file_list(files).to_element()}
+                button {
+                    class: "advance",
+                    onclick: move |_| {
+                        let mut list = files.write();
+                        list[0].set_status(UploadStatus::Error("网络错误".to_string()));
+                        list[1].set_progress(40);
+                    },
+                }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class(classnames::UPLOAD_PROGRESS).is_none());
+        let advance = harness.find_by_class("advance").unwrap();
+        harness.click(&advance);
+
+        let items = harness.find_all_by_class(classnames::UPLOAD_ITEM);
+        assert!(items[0].has_class("is-error"));
+        assert!(items[1].has_class("is-uploading"));
+        assert_eq!(
+            harness
+                .find_by_class(classnames::UPLOAD_MESSAGE)
+                .unwrap()
+                .text(),
+            "网络错误"
+        );
+        let progress = harness.find_by_class(classnames::UPLOAD_PROGRESS).unwrap();
+        assert_eq!(progress.attr("aria-valuenow"), Some("40"));
+    }
+}
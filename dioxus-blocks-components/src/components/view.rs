@@ -30,11 +30,12 @@
 //!
 //! ```
 //! ```
+use std::borrow::Cow;
 use std::rc::Rc;
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_macro::ComponentBase;
+use dioxus_blocks_macro::{ComponentBase, builder_props};
 
 use crate::{Style, traits::ToElement};
 
@@ -47,7 +48,7 @@ pub struct View {
     /// 容器组件的唯一标识符
     id: Option<String>,
     /// 容器组件的CSS类名
-    class: String,
+    class: Cow<'static, str>,
     /// 容器组件的内联样式
     style: Option<Style>,
     /// 容器组件的子元素列表
@@ -63,7 +64,7 @@ impl Default for View {
     fn default() -> Self {
         Self {
             id: None,
-            class: String::new(),
+            class: Cow::Borrowed(""),
             style: None,
             childrens: Vec::new(),
             onclick: None,
@@ -72,6 +73,7 @@ impl Default for View {
     }
 }
 
+#[builder_props]
 impl View {
     /// 创建一个新的容器实例
     ///
@@ -105,6 +107,7 @@ impl View {
     /// # use dioxus_blocks_components::View;
     /// let view = View::new().bare(true);
     /// ```
+    #[prop(default = "false")]
     pub fn bare(mut self, bare: bool) -> Self {
         self.bare = bare;
         self
@@ -114,7 +117,7 @@ impl View {
 impl ToElement for View {
     fn to_element(&self) -> Element {
         let id = self.id.clone();
-        let class = self.class.clone();
+        let class = self.class.to_string();
         let style = self.style.clone().map(|s| s.to_string());
         let onclick_handler = self.onclick;
         let childrens = self.childrens_to_element();
@@ -6,7 +6,8 @@ use dioxus::prelude::*;
 
 /// 主样式文件
 ///
-/// 包含组件库的主要样式定义，使用 SCSS 格式
+/// 包含组件库所有组件的样式定义，使用 SCSS 格式。
+/// 只需要部分组件的样式时，可以使用 [`css::collect`](crate::css::collect) 按组件加载。
 pub const MAIN_CSS: Asset = asset!("/assets/css/index.scss");
 
 /// Tailwind CSS 样式文件
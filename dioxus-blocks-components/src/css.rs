@@ -0,0 +1,658 @@
+//! 按组件拆分的样式
+//!
+//! [`MAIN_CSS`](crate::MAIN_CSS) 包含所有组件的样式。应用只用到少数组件时，可以通过 [`collect`]
+//! 只加载这些组件的样式，基础变量样式以及组件依赖的其他组件样式会被自动加入。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::css::{self, Component};
+//! use dioxus::core::Mutations;
+//!
+//! let assets = css::collect(&[Component::Button, Component::Input]);
+//! assert_eq!(assets.len(), 3);
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     rsx! {
+//!         {css::stylesheets(&[Component::FormWizard])}
+//!     }
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use dioxus::prelude::*;
+
+const BASE_CSS: Asset = asset!("/assets/css/base.scss");
+#[cfg(feature = "card")]
+const CARD_CSS: Asset = asset!("/assets/css/card.scss");
+#[cfg(feature = "grid")]
+const GRID_CSS: Asset = asset!("/assets/css/grid.scss");
+#[cfg(feature = "layout")]
+const LAYOUT_CSS: Asset = asset!("/assets/css/layout.scss");
+#[cfg(feature = "toolbar")]
+const TOOLBAR_CSS: Asset = asset!("/assets/css/toolbar.scss");
+#[cfg(feature = "masonry")]
+const MASONRY_CSS: Asset = asset!("/assets/css/masonry.scss");
+const TEXT_CSS: Asset = asset!("/assets/css/text.scss");
+#[cfg(feature = "button")]
+const BUTTON_CSS: Asset = asset!("/assets/css/button.scss");
+#[cfg(feature = "link")]
+const LINK_CSS: Asset = asset!("/assets/css/link.scss");
+#[cfg(feature = "input_number")]
+const INPUT_NUMBER_CSS: Asset = asset!("/assets/css/input_number.scss");
+#[cfg(feature = "input")]
+const INPUT_CSS: Asset = asset!("/assets/css/input.scss");
+#[cfg(feature = "textarea")]
+const TEXTAREA_CSS: Asset = asset!("/assets/css/textarea.scss");
+#[cfg(feature = "select")]
+const SELECT_CSS: Asset = asset!("/assets/css/select.scss");
+#[cfg(feature = "time_picker")]
+const TIME_PICKER_CSS: Asset = asset!("/assets/css/time_picker.scss");
+#[cfg(feature = "date_picker")]
+const DATE_PICKER_CSS: Asset = asset!("/assets/css/date_picker.scss");
+#[cfg(feature = "radio")]
+const RADIO_CSS: Asset = asset!("/assets/css/radio.scss");
+#[cfg(feature = "checkbox")]
+const CHECKBOX_CSS: Asset = asset!("/assets/css/checkbox.scss");
+#[cfg(feature = "calendar_heatmap")]
+const CALENDAR_HEATMAP_CSS: Asset = asset!("/assets/css/calendar_heatmap.scss");
+#[cfg(feature = "org_chart")]
+const ORG_CHART_CSS: Asset = asset!("/assets/css/org_chart.scss");
+#[cfg(feature = "form_wizard")]
+const FORM_WIZARD_CSS: Asset = asset!("/assets/css/form_wizard.scss");
+#[cfg(feature = "credit_card_input")]
+const CREDIT_CARD_INPUT_CSS: Asset = asset!("/assets/css/credit_card_input.scss");
+#[cfg(feature = "phone_input")]
+const PHONE_INPUT_CSS: Asset = asset!("/assets/css/phone_input.scss");
+#[cfg(feature = "barcode_input")]
+const BARCODE_INPUT_CSS: Asset = asset!("/assets/css/barcode_input.scss");
+#[cfg(feature = "address_picker")]
+const ADDRESS_PICKER_CSS: Asset = asset!("/assets/css/address_picker.scss");
+#[cfg(feature = "search_input")]
+const SEARCH_INPUT_CSS: Asset = asset!("/assets/css/search_input.scss");
+#[cfg(feature = "filter_bar")]
+const FILTER_BAR_CSS: Asset = asset!("/assets/css/filter_bar.scss");
+#[cfg(feature = "active_filters")]
+const ACTIVE_FILTERS_CSS: Asset = asset!("/assets/css/active_filters.scss");
+#[cfg(feature = "form_error_summary")]
+const FORM_ERROR_SUMMARY_CSS: Asset = asset!("/assets/css/form_error_summary.scss");
+#[cfg(feature = "form_item")]
+const FORM_ITEM_CSS: Asset = asset!("/assets/css/form_item.scss");
+#[cfg(feature = "schema_form")]
+const SCHEMA_FORM_CSS: Asset = asset!("/assets/css/schema_form.scss");
+#[cfg(feature = "upload")]
+const UPLOAD_CSS: Asset = asset!("/assets/css/upload.scss");
+#[cfg(feature = "alert")]
+const ALERT_CSS: Asset = asset!("/assets/css/alert.scss");
+#[cfg(feature = "result_panel")]
+const RESULT_PANEL_CSS: Asset = asset!("/assets/css/result_panel.scss");
+#[cfg(feature = "route_progress")]
+const ROUTE_PROGRESS_CSS: Asset = asset!("/assets/css/route_progress.scss");
+#[cfg(feature = "connection_status")]
+const CONNECTION_STATUS_CSS: Asset = asset!("/assets/css/connection_status.scss");
+#[cfg(feature = "consent_banner")]
+const CONSENT_BANNER_CSS: Asset = asset!("/assets/css/consent_banner.scss");
+#[cfg(feature = "confirm_button")]
+const CONFIRM_BUTTON_CSS: Asset = asset!("/assets/css/confirm_button.scss");
+#[cfg(feature = "notification")]
+const NOTIFICATION_CSS: Asset = asset!("/assets/css/notification.scss");
+#[cfg(feature = "message")]
+const MESSAGE_CSS: Asset = asset!("/assets/css/message.scss");
+#[cfg(feature = "idle_guard")]
+const IDLE_GUARD_CSS: Asset = asset!("/assets/css/idle_guard.scss");
+#[cfg(feature = "dialog")]
+const DIALOG_CSS: Asset = asset!("/assets/css/dialog.scss");
+#[cfg(feature = "skeleton")]
+const SKELETON_CSS: Asset = asset!("/assets/css/skeleton.scss");
+#[cfg(feature = "spin")]
+const SPIN_CSS: Asset = asset!("/assets/css/spin.scss");
+#[cfg(feature = "config_provider")]
+const CONFIG_PROVIDER_CSS: Asset = asset!("/assets/css/config_provider.scss");
+#[cfg(feature = "props_table")]
+const PROPS_TABLE_CSS: Asset = asset!("/assets/css/props_table.scss");
+#[cfg(feature = "json_viewer")]
+const JSON_VIEWER_CSS: Asset = asset!("/assets/css/json_viewer.scss");
+#[cfg(feature = "log_viewer")]
+const LOG_VIEWER_CSS: Asset = asset!("/assets/css/log_viewer.scss");
+#[cfg(feature = "table")]
+const TABLE_CSS: Asset = asset!("/assets/css/table.scss");
+#[cfg(feature = "selection_bar")]
+const SELECTION_BAR_CSS: Asset = asset!("/assets/css/selection_bar.scss");
+#[cfg(feature = "crud_page")]
+const CRUD_PAGE_CSS: Asset = asset!("/assets/css/crud_page.scss");
+#[cfg(feature = "kbd")]
+const KBD_CSS: Asset = asset!("/assets/css/kbd.scss");
+#[cfg(feature = "tooltip")]
+const TOOLTIP_CSS: Asset = asset!("/assets/css/tooltip.scss");
+#[cfg(feature = "tabs")]
+const TABS_CSS: Asset = asset!("/assets/css/tabs.scss");
+#[cfg(feature = "dropdown")]
+const DROPDOWN_CSS: Asset = asset!("/assets/css/dropdown.scss");
+#[cfg(feature = "pagination")]
+const PAGINATION_CSS: Asset = asset!("/assets/css/pagination.scss");
+#[cfg(feature = "popconfirm")]
+const POPCONFIRM_CSS: Asset = asset!("/assets/css/popconfirm.scss");
+
+/// 带有独立样式文件的组件
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Component {
+    /// 卡片
+    #[cfg(feature = "card")]
+    Card,
+    /// 网格
+    #[cfg(feature = "grid")]
+    Grid,
+    /// 布局
+    #[cfg(feature = "layout")]
+    Layout,
+    /// 工具栏
+    #[cfg(feature = "toolbar")]
+    Toolbar,
+    /// 瀑布流布局
+    #[cfg(feature = "masonry")]
+    Masonry,
+    /// 文本
+    Text,
+    /// 按钮
+    #[cfg(feature = "button")]
+    Button,
+    /// 链接
+    #[cfg(feature = "link")]
+    Link,
+    /// 数字输入框
+    #[cfg(feature = "input_number")]
+    InputNumber,
+    /// 输入框
+    #[cfg(feature = "input")]
+    Input,
+    /// 多行文本输入框
+    #[cfg(feature = "textarea")]
+    TextArea,
+    /// 选择器
+    #[cfg(feature = "select")]
+    Select,
+    /// 时间选择器
+    #[cfg(feature = "time_picker")]
+    TimePicker,
+    /// 日期时间选择器和日期范围选择器
+    #[cfg(feature = "date_picker")]
+    DatePicker,
+    /// 单选框
+    #[cfg(feature = "radio")]
+    Radio,
+    /// 多选框
+    #[cfg(feature = "checkbox")]
+    Checkbox,
+    /// 日历热力图
+    #[cfg(feature = "calendar_heatmap")]
+    CalendarHeatmap,
+    /// 组织结构图
+    #[cfg(feature = "org_chart")]
+    OrgChart,
+    /// 分步表单
+    #[cfg(feature = "form_wizard")]
+    FormWizard,
+    /// 银行卡输入框
+    #[cfg(feature = "credit_card_input")]
+    CreditCardInput,
+    /// 手机号码输入框
+    #[cfg(feature = "phone_input")]
+    PhoneInput,
+    /// 条码输入框
+    #[cfg(feature = "barcode_input")]
+    BarcodeInput,
+    /// 地址选择器
+    #[cfg(feature = "address_picker")]
+    AddressPicker,
+    /// 搜索框
+    #[cfg(feature = "search_input")]
+    SearchInput,
+    /// 筛选栏
+    #[cfg(feature = "filter_bar")]
+    FilterBar,
+    /// 已选筛选条件
+    #[cfg(feature = "active_filters")]
+    ActiveFilters,
+    /// 表单错误汇总
+    #[cfg(feature = "form_error_summary")]
+    FormErrorSummary,
+    #[cfg(feature = "form_item")]
+    FormItem,
+    /// 配置表单
+    #[cfg(feature = "schema_form")]
+    SchemaForm,
+    /// 上传
+    #[cfg(feature = "upload")]
+    Upload,
+    /// 提示
+    #[cfg(feature = "alert")]
+    Alert,
+    /// 结果
+    #[cfg(feature = "result_panel")]
+    ResultPanel,
+    /// 路由进度条
+    #[cfg(feature = "route_progress")]
+    RouteProgress,
+    /// 网络连接状态
+    #[cfg(feature = "connection_status")]
+    ConnectionStatus,
+    /// Cookie 同意横幅
+    #[cfg(feature = "consent_banner")]
+    ConsentBanner,
+    /// 确认按钮
+    #[cfg(feature = "confirm_button")]
+    ConfirmButton,
+    /// 通知
+    #[cfg(feature = "notification")]
+    Notification,
+    /// 消息提示
+    #[cfg(feature = "message")]
+    Message,
+    /// 空闲超时
+    #[cfg(feature = "idle_guard")]
+    IdleGuard,
+    /// 对话框
+    #[cfg(feature = "dialog")]
+    Dialog,
+    /// 骨架屏
+    #[cfg(feature = "skeleton")]
+    Skeleton,
+    /// 加载中
+    #[cfg(feature = "spin")]
+    Spin,
+    /// 全局配置
+    #[cfg(feature = "config_provider")]
+    ConfigProvider,
+    /// 属性表
+    #[cfg(feature = "props_table")]
+    PropsTable,
+    /// JSON 查看器
+    #[cfg(feature = "json_viewer")]
+    JsonViewer,
+    /// 日志查看器
+    #[cfg(feature = "log_viewer")]
+    LogViewer,
+    /// 表格
+    #[cfg(feature = "table")]
+    Table,
+    /// 批量操作栏
+    #[cfg(feature = "selection_bar")]
+    SelectionBar,
+    /// 增删改查页面
+    #[cfg(feature = "crud_page")]
+    CrudPage,
+    /// 按键和快捷键提示
+    #[cfg(feature = "kbd")]
+    Kbd,
+    /// 文字提示
+    #[cfg(feature = "tooltip")]
+    Tooltip,
+    /// 标签页
+    #[cfg(feature = "tabs")]
+    Tabs,
+    /// 下拉菜单
+    #[cfg(feature = "dropdown")]
+    Dropdown,
+    /// 分页
+    #[cfg(feature = "pagination")]
+    Pagination,
+    /// 气泡确认框
+    #[cfg(feature = "popconfirm")]
+    Popconfirm,
+}
+
+impl Component {
+    /// 所有组件
+    pub const ALL: &'static [Component] = &[
+        #[cfg(feature = "card")]
+        Component::Card,
+        #[cfg(feature = "grid")]
+        Component::Grid,
+        #[cfg(feature = "layout")]
+        Component::Layout,
+        #[cfg(feature = "toolbar")]
+        Component::Toolbar,
+        #[cfg(feature = "masonry")]
+        Component::Masonry,
+        Component::Text,
+        #[cfg(feature = "button")]
+        Component::Button,
+        #[cfg(feature = "link")]
+        Component::Link,
+        #[cfg(feature = "input_number")]
+        Component::InputNumber,
+        #[cfg(feature = "input")]
+        Component::Input,
+        #[cfg(feature = "textarea")]
+        Component::TextArea,
+        #[cfg(feature = "select")]
+        Component::Select,
+        #[cfg(feature = "time_picker")]
+        Component::TimePicker,
+        #[cfg(feature = "date_picker")]
+        Component::DatePicker,
+        #[cfg(feature = "radio")]
+        Component::Radio,
+        #[cfg(feature = "checkbox")]
+        Component::Checkbox,
+        #[cfg(feature = "calendar_heatmap")]
+        Component::CalendarHeatmap,
+        #[cfg(feature = "org_chart")]
+        Component::OrgChart,
+        #[cfg(feature = "form_wizard")]
+        Component::FormWizard,
+        #[cfg(feature = "credit_card_input")]
+        Component::CreditCardInput,
+        #[cfg(feature = "phone_input")]
+        Component::PhoneInput,
+        #[cfg(feature = "barcode_input")]
+        Component::BarcodeInput,
+        #[cfg(feature = "address_picker")]
+        Component::AddressPicker,
+        #[cfg(feature = "search_input")]
+        Component::SearchInput,
+        #[cfg(feature = "filter_bar")]
+        Component::FilterBar,
+        #[cfg(feature = "active_filters")]
+        Component::ActiveFilters,
+        #[cfg(feature = "form_error_summary")]
+        Component::FormErrorSummary,
+        #[cfg(feature = "form_item")]
+        Component::FormItem,
+        #[cfg(feature = "schema_form")]
+        Component::SchemaForm,
+        #[cfg(feature = "upload")]
+        Component::Upload,
+        #[cfg(feature = "alert")]
+        Component::Alert,
+        #[cfg(feature = "result_panel")]
+        Component::ResultPanel,
+        #[cfg(feature = "route_progress")]
+        Component::RouteProgress,
+        #[cfg(feature = "connection_status")]
+        Component::ConnectionStatus,
+        #[cfg(feature = "consent_banner")]
+        Component::ConsentBanner,
+        #[cfg(feature = "confirm_button")]
+        Component::ConfirmButton,
+        #[cfg(feature = "notification")]
+        Component::Notification,
+        #[cfg(feature = "message")]
+        Component::Message,
+        #[cfg(feature = "idle_guard")]
+        Component::IdleGuard,
+        #[cfg(feature = "dialog")]
+        Component::Dialog,
+        #[cfg(feature = "skeleton")]
+        Component::Skeleton,
+        #[cfg(feature = "spin")]
+        Component::Spin,
+        #[cfg(feature = "config_provider")]
+        Component::ConfigProvider,
+        #[cfg(feature = "props_table")]
+        Component::PropsTable,
+        #[cfg(feature = "json_viewer")]
+        Component::JsonViewer,
+        #[cfg(feature = "log_viewer")]
+        Component::LogViewer,
+        #[cfg(feature = "table")]
+        Component::Table,
+        #[cfg(feature = "selection_bar")]
+        Component::SelectionBar,
+        #[cfg(feature = "crud_page")]
+        Component::CrudPage,
+        #[cfg(feature = "kbd")]
+        Component::Kbd,
+        #[cfg(feature = "tooltip")]
+        Component::Tooltip,
+        #[cfg(feature = "tabs")]
+        Component::Tabs,
+        #[cfg(feature = "dropdown")]
+        Component::Dropdown,
+        #[cfg(feature = "pagination")]
+        Component::Pagination,
+        #[cfg(feature = "popconfirm")]
+        Component::Popconfirm,
+    ];
+
+    /// 组件的样式文件
+    pub fn asset(&self) -> Asset {
+        match self {
+            #[cfg(feature = "card")]
+            Component::Card => CARD_CSS,
+            #[cfg(feature = "grid")]
+            Component::Grid => GRID_CSS,
+            #[cfg(feature = "layout")]
+            Component::Layout => LAYOUT_CSS,
+            #[cfg(feature = "toolbar")]
+            Component::Toolbar => TOOLBAR_CSS,
+            #[cfg(feature = "masonry")]
+            Component::Masonry => MASONRY_CSS,
+            Component::Text => TEXT_CSS,
+            #[cfg(feature = "button")]
+            Component::Button => BUTTON_CSS,
+            #[cfg(feature = "link")]
+            Component::Link => LINK_CSS,
+            #[cfg(feature = "input_number")]
+            Component::InputNumber => INPUT_NUMBER_CSS,
+            #[cfg(feature = "input")]
+            Component::Input => INPUT_CSS,
+            #[cfg(feature = "textarea")]
+            Component::TextArea => TEXTAREA_CSS,
+            #[cfg(feature = "select")]
+            Component::Select => SELECT_CSS,
+            #[cfg(feature = "time_picker")]
+            Component::TimePicker => TIME_PICKER_CSS,
+            #[cfg(feature = "date_picker")]
+            Component::DatePicker => DATE_PICKER_CSS,
+            #[cfg(feature = "radio")]
+            Component::Radio => RADIO_CSS,
+            #[cfg(feature = "checkbox")]
+            Component::Checkbox => CHECKBOX_CSS,
+            #[cfg(feature = "calendar_heatmap")]
+            Component::CalendarHeatmap => CALENDAR_HEATMAP_CSS,
+            #[cfg(feature = "org_chart")]
+            Component::OrgChart => ORG_CHART_CSS,
+            #[cfg(feature = "form_wizard")]
+            Component::FormWizard => FORM_WIZARD_CSS,
+            #[cfg(feature = "credit_card_input")]
+            Component::CreditCardInput => CREDIT_CARD_INPUT_CSS,
+            #[cfg(feature = "phone_input")]
+            Component::PhoneInput => PHONE_INPUT_CSS,
+            #[cfg(feature = "barcode_input")]
+            Component::BarcodeInput => BARCODE_INPUT_CSS,
+            #[cfg(feature = "address_picker")]
+            Component::AddressPicker => ADDRESS_PICKER_CSS,
+            #[cfg(feature = "search_input")]
+            Component::SearchInput => SEARCH_INPUT_CSS,
+            #[cfg(feature = "filter_bar")]
+            Component::FilterBar => FILTER_BAR_CSS,
+            #[cfg(feature = "active_filters")]
+            Component::ActiveFilters => ACTIVE_FILTERS_CSS,
+            #[cfg(feature = "form_error_summary")]
+            Component::FormErrorSummary => FORM_ERROR_SUMMARY_CSS,
+            #[cfg(feature = "form_item")]
+            Component::FormItem => FORM_ITEM_CSS,
+            #[cfg(feature = "schema_form")]
+            Component::SchemaForm => SCHEMA_FORM_CSS,
+            #[cfg(feature = "upload")]
+            Component::Upload => UPLOAD_CSS,
+            #[cfg(feature = "alert")]
+            Component::Alert => ALERT_CSS,
+            #[cfg(feature = "result_panel")]
+            Component::ResultPanel => RESULT_PANEL_CSS,
+            #[cfg(feature = "route_progress")]
+            Component::RouteProgress => ROUTE_PROGRESS_CSS,
+            #[cfg(feature = "connection_status")]
+            Component::ConnectionStatus => CONNECTION_STATUS_CSS,
+            #[cfg(feature = "consent_banner")]
+            Component::ConsentBanner => CONSENT_BANNER_CSS,
+            #[cfg(feature = "confirm_button")]
+            Component::ConfirmButton => CONFIRM_BUTTON_CSS,
+            #[cfg(feature = "notification")]
+            Component::Notification => NOTIFICATION_CSS,
+            #[cfg(feature = "message")]
+            Component::Message => MESSAGE_CSS,
+            #[cfg(feature = "idle_guard")]
+            Component::IdleGuard => IDLE_GUARD_CSS,
+            #[cfg(feature = "dialog")]
+            Component::Dialog => DIALOG_CSS,
+            #[cfg(feature = "skeleton")]
+            Component::Skeleton => SKELETON_CSS,
+            #[cfg(feature = "spin")]
+            Component::Spin => SPIN_CSS,
+            #[cfg(feature = "config_provider")]
+            Component::ConfigProvider => CONFIG_PROVIDER_CSS,
+            #[cfg(feature = "props_table")]
+            Component::PropsTable => PROPS_TABLE_CSS,
+            #[cfg(feature = "json_viewer")]
+            Component::JsonViewer => JSON_VIEWER_CSS,
+            #[cfg(feature = "log_viewer")]
+            Component::LogViewer => LOG_VIEWER_CSS,
+            #[cfg(feature = "table")]
+            Component::Table => TABLE_CSS,
+            #[cfg(feature = "selection_bar")]
+            Component::SelectionBar => SELECTION_BAR_CSS,
+            #[cfg(feature = "crud_page")]
+            Component::CrudPage => CRUD_PAGE_CSS,
+            #[cfg(feature = "kbd")]
+            Component::Kbd => KBD_CSS,
+            #[cfg(feature = "tooltip")]
+            Component::Tooltip => TOOLTIP_CSS,
+            #[cfg(feature = "tabs")]
+            Component::Tabs => TABS_CSS,
+            #[cfg(feature = "dropdown")]
+            Component::Dropdown => DROPDOWN_CSS,
+            #[cfg(feature = "pagination")]
+            Component::Pagination => PAGINATION_CSS,
+            #[cfg(feature = "popconfirm")]
+            Component::Popconfirm => POPCONFIRM_CSS,
+        }
+    }
+
+    /// 组件内部使用的其他组件
+    pub fn dependencies(&self) -> &'static [Component] {
+        match self {
+            #[cfg(feature = "select")]
+            Component::Select => &[Component::Tooltip],
+            #[cfg(feature = "date_picker")]
+            Component::DatePicker => &[Component::TimePicker],
+            #[cfg(feature = "form_wizard")]
+            Component::FormWizard => &[Component::Button],
+            #[cfg(feature = "credit_card_input")]
+            Component::CreditCardInput => &[Component::Input],
+            #[cfg(feature = "phone_input")]
+            Component::PhoneInput => &[Component::Input],
+            #[cfg(feature = "barcode_input")]
+            Component::BarcodeInput => &[Component::Input, Component::Button],
+            #[cfg(feature = "address_picker")]
+            Component::AddressPicker => &[Component::Select],
+            #[cfg(feature = "search_input")]
+            Component::SearchInput => &[Component::Input, Component::Button],
+            #[cfg(feature = "filter_bar")]
+            Component::FilterBar => &[Component::Input, Component::Button],
+            #[cfg(feature = "form_error_summary")]
+            Component::FormErrorSummary => &[Component::Alert],
+            #[cfg(feature = "form_item")]
+            Component::FormItem => &[Component::FormErrorSummary],
+            #[cfg(feature = "schema_form")]
+            Component::SchemaForm => &[
+                Component::FormItem,
+                Component::Input,
+                Component::InputNumber,
+                Component::TextArea,
+                Component::Select,
+                Component::Radio,
+                Component::Checkbox,
+                Component::TimePicker,
+                Component::Button,
+            ],
+            #[cfg(feature = "connection_status")]
+            Component::ConnectionStatus => &[Component::Alert],
+            #[cfg(feature = "consent_banner")]
+            Component::ConsentBanner => &[Component::Button, Component::Checkbox],
+            #[cfg(feature = "confirm_button")]
+            Component::ConfirmButton => &[Component::Button],
+            #[cfg(feature = "idle_guard")]
+            Component::IdleGuard => &[Component::Button, Component::Dialog],
+            #[cfg(feature = "json_viewer")]
+            Component::JsonViewer => &[Component::Input],
+            #[cfg(feature = "log_viewer")]
+            Component::LogViewer => &[Component::Input],
+            #[cfg(feature = "tooltip")]
+            Component::Text => &[Component::Tooltip],
+            #[cfg(feature = "table")]
+            Component::Table => &[Component::Text],
+            #[cfg(feature = "selection_bar")]
+            Component::SelectionBar => &[Component::Button],
+            #[cfg(feature = "tooltip")]
+            Component::Tooltip => &[Component::Kbd],
+            #[cfg(feature = "dropdown")]
+            Component::Dropdown => &[Component::Kbd],
+            #[cfg(feature = "popconfirm")]
+            Component::Popconfirm => &[Component::Button],
+            #[cfg(feature = "crud_page")]
+            Component::CrudPage => &[
+                Component::FilterBar,
+                Component::Table,
+                Component::Dialog,
+                Component::SchemaForm,
+                Component::ConfirmButton,
+                Component::Notification,
+                Component::Button,
+            ],
+            _ => &[],
+        }
+    }
+}
+
+/// 收集组件的样式文件
+///
+/// 返回的列表以基础变量样式开头，随后按传入顺序排列各组件及其依赖的样式，重复的组件只保留一次。
+pub fn collect(components: &[Component]) -> Vec<Asset> {
+    let mut resolved: Vec<Component> = Vec::new();
+    let mut stack = components.iter().rev().copied().collect::<Vec<_>>();
+    while let Some(component) = stack.pop() {
+        if resolved.contains(&component) {
+            continue;
+        }
+        resolved.push(component);
+        stack.extend(component.dependencies().iter().rev().copied());
+    }
+
+    std::iter::once(BASE_CSS)
+        .chain(resolved.iter().map(|component| component.asset()))
+        .collect()
+}
+
+/// 渲染组件样式的 `<link>` 标签
+pub fn stylesheets(components: &[Component]) -> Element {
+    let assets = collect(components);
+    rsx! {
+        for asset in assets {
+            document::Link { rel: "stylesheet", href: asset }
+        }
+    }
+}
+
+#[cfg(all(test, feature = "form_wizard", feature = "input"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_collect_includes_base_and_dependencies() {
+        let assets = collect(&[Component::FormWizard, Component::Button, Component::Input]);
+
+        assert_eq!(
+            assets,
+            vec![BASE_CSS, FORM_WIZARD_CSS, BUTTON_CSS, INPUT_CSS]
+        );
+    }
+
+    #[test]
+    fn test_collect_all() {
+        assert_eq!(collect(Component::ALL).len(), Component::ALL.len() + 1);
+    }
+}
@@ -0,0 +1,782 @@
+//! # 数据源
+//!
+//! [`DataSource`] 把「按查询条件返回一页数据」抽象为一个 trait，表格、远程搜索的选择器和增删改查页面
+//! 都通过它获取数据，在模拟数据和真实接口之间切换时只需要替换数据源：
+//!
+//! - [`MemoryDataSource`]：内存中的数据，按自定义的条件筛选和排序后分页，适合原型和测试
+//! - `HttpDataSource`：启用 `http` 特性后可用，以 GET 请求查询接口并解析 JSON 响应
+//! - 闭包：`Fn(DataQuery) -> Future<Output = Result<DataPage<T>, E>>` 同样实现了 [`DataSource`]，
+//!   可以直接调用服务端函数
+//!
+//! 组件之外可以用 [`use_data_source`] 查询，查询条件变化时自动重新查询，并记录加载中和错误状态。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{
+//!     DataQuery, DataSource, MemoryDataSource, Table, TableColumn, TableRow, ToElement,
+//! };
+//! use dioxus::core::Mutations;
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let users = MemoryDataSource::new(vec!["张三", "李四", "王五"])
+//!         .filter(|name: &&str, query: &DataQuery| name.contains(query.keyword.as_str()));
+//!
+//!     Table::new()
+//!         .column(TableColumn::new("name", "姓名"))
+//!         // 替换为 HttpDataSource::new("/api/users") 即可查询真实接口
+//!         .data_source(users.map(|name: &str| TableRow::new(name).cell("name", name)))
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::cell::RefCell;
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fmt::{Debug, Display};
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+/// 数据源返回的 Future，错误已转换为字符串
+pub type DataFuture<T> = Pin<Box<dyn Future<Output = Result<DataPage<T>, String>>>>;
+
+/// 排序条件
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DataSort {
+    /// 排序的字段名
+    pub prop: String,
+    /// 是否降序
+    pub descending: bool,
+}
+
+impl DataSort {
+    /// 创建排序条件
+    pub fn new(prop: impl Into<String>, descending: bool) -> Self {
+        Self {
+            prop: prop.into(),
+            descending,
+        }
+    }
+}
+
+/// 查询条件
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataQuery {
+    /// 页码，从 1 开始
+    pub page: usize,
+    /// 每页的记录数
+    pub page_size: usize,
+    /// 搜索关键字，为空时不搜索
+    pub keyword: String,
+    /// 筛选条件，键为字段名
+    pub filters: BTreeMap<String, String>,
+    /// 排序条件
+    pub sort: Option<DataSort>,
+}
+
+impl Default for DataQuery {
+    fn default() -> Self {
+        Self {
+            page: 1,
+            page_size: 10,
+            keyword: String::new(),
+            filters: BTreeMap::new(),
+            sort: None,
+        }
+    }
+}
+
+impl DataQuery {
+    /// 创建查询条件，默认查询第 1 页，每页 10 条
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 设置页码，从 1 开始
+    pub fn page(mut self, page: usize) -> Self {
+        self.page = page.max(1);
+        self
+    }
+
+    /// 设置每页的记录数
+    pub fn page_size(mut self, page_size: usize) -> Self {
+        self.page_size = page_size.max(1);
+        self
+    }
+
+    /// 设置搜索关键字
+    pub fn keyword(mut self, keyword: impl Into<String>) -> Self {
+        self.keyword = keyword.into();
+        self
+    }
+
+    /// 添加筛选条件
+    pub fn filter(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+        self.filters.insert(key.into(), value.into());
+        self
+    }
+
+    /// 设置排序条件
+    pub fn sort(mut self, prop: impl Into<String>, descending: bool) -> Self {
+        self.sort = Some(DataSort::new(prop, descending));
+        self
+    }
+
+    /// 当前页第一条记录的下标，用于切片或数据库查询的 offset
+    pub fn offset(&self) -> usize {
+        self.page.saturating_sub(1) * self.page_size
+    }
+
+    /// 转换为 URL 查询参数
+    ///
+    /// 依次为 `page`、`page_size`、非空的 `keyword`、各筛选条件，以及排序时的 `sort` 和 `order`（`asc` 或 `desc`）。
+    pub fn params(&self) -> Vec<(String, String)> {
+        let mut params = vec![
+            ("page".to_string(), self.page.to_string()),
+            ("page_size".to_string(), self.page_size.to_string()),
+        ];
+        if !self.keyword.is_empty() {
+            params.push(("keyword".to_string(), self.keyword.clone()));
+        }
+        params.extend(
+            self.filters
+                .iter()
+                .map(|(key, value)| (key.clone(), value.clone())),
+        );
+        if let Some(sort) = &self.sort {
+            let order = if sort.descending { "desc" } else { "asc" };
+            params.push(("sort".to_string(), sort.prop.clone()));
+            params.push(("order".to_string(), order.to_string()));
+        }
+        params
+    }
+}
+
+/// 一页查询结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct DataPage<T> {
+    /// 当前页的记录
+    pub items: Vec<T>,
+    /// 符合查询条件的记录总数
+    pub total: usize,
+}
+
+impl<T> DataPage<T> {
+    /// 创建查询结果
+    pub fn new(items: Vec<T>, total: usize) -> Self {
+        Self { items, total }
+    }
+
+    /// 转换每一条记录，总数不变
+    pub fn map<U>(self, f: impl FnMut(T) -> U) -> DataPage<U> {
+        DataPage {
+            items: self.items.into_iter().map(f).collect(),
+            total: self.total,
+        }
+    }
+}
+
+/// 数据源
+///
+/// 按查询条件返回一页数据和总数。数据源只在组件内使用，返回的 Future 不要求 `Send`。
+pub trait DataSource<T>: 'static {
+    /// 查询一页数据
+    fn query(&self, query: DataQuery) -> DataFuture<T>;
+
+    /// 转换数据源返回的每一条记录，如把业务数据转换为 [`TableRow`](crate::TableRow)
+    fn map<U, F>(self, f: F) -> MapDataSource<Self, F, T>
+    where
+        Self: Sized,
+        F: Fn(T) -> U + 'static,
+    {
+        MapDataSource {
+            source: self,
+            f: Rc::new(f),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<T, F, Fut, E> DataSource<T> for F
+where
+    F: Fn(DataQuery) -> Fut + 'static,
+    Fut: Future<Output = Result<DataPage<T>, E>> + 'static,
+    E: Display + 'static,
+{
+    fn query(&self, query: DataQuery) -> DataFuture<T> {
+        let future = self(query);
+        Box::pin(async move { future.await.map_err(|e| e.to_string()) })
+    }
+}
+
+/// 共享的数据源
+///
+/// 包装任意数据源，复制时共享同一个数据源，按指针比较，组件用它保存构建方法传入的数据源。
+pub struct SharedDataSource<T: 'static>(Rc<dyn DataSource<T>>);
+
+impl<T: 'static> SharedDataSource<T> {
+    /// 包装数据源
+    pub fn new(source: impl DataSource<T>) -> Self {
+        Self(Rc::new(source))
+    }
+}
+
+impl<T: 'static> Clone for SharedDataSource<T> {
+    fn clone(&self) -> Self {
+        Self(self.0.clone())
+    }
+}
+
+impl<T: 'static> PartialEq for SharedDataSource<T> {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+impl<T: 'static> Debug for SharedDataSource<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedDataSource")
+    }
+}
+
+impl<T: 'static> DataSource<T> for SharedDataSource<T> {
+    fn query(&self, query: DataQuery) -> DataFuture<T> {
+        self.0.query(query)
+    }
+}
+
+/// 转换记录的数据源，由 [`DataSource::map`] 创建
+pub struct MapDataSource<S, F, T> {
+    source: S,
+    f: Rc<F>,
+    _marker: std::marker::PhantomData<fn() -> T>,
+}
+
+impl<S: Clone, F, T> Clone for MapDataSource<S, F, T> {
+    fn clone(&self) -> Self {
+        Self {
+            source: self.source.clone(),
+            f: self.f.clone(),
+            _marker: std::marker::PhantomData,
+        }
+    }
+}
+
+impl<S, F, T, U> DataSource<U> for MapDataSource<S, F, T>
+where
+    S: DataSource<T>,
+    F: Fn(T) -> U + 'static,
+    T: 'static,
+{
+    fn query(&self, query: DataQuery) -> DataFuture<U> {
+        let future = self.source.query(query);
+        let f = self.f.clone();
+        Box::pin(async move { future.await.map(|page| page.map(|item| f(item))) })
+    }
+}
+
+/// 记录是否符合查询条件
+type MemoryFilter<T> = Rc<dyn Fn(&T, &DataQuery) -> bool>;
+/// 按字段比较两条记录
+type MemorySort<T> = Rc<dyn Fn(&T, &T, &str) -> Ordering>;
+
+/// 内存数据源
+///
+/// 记录保存在内存中，查询时依次筛选、排序和分页。复制的数据源共享同一份记录，
+/// 通过 [`MemoryDataSource::update`] 修改后，下一次查询返回修改后的结果，可以模拟增删改接口。
+pub struct MemoryDataSource<T> {
+    items: Rc<RefCell<Vec<T>>>,
+    filter: Option<MemoryFilter<T>>,
+    sort: Option<MemorySort<T>>,
+}
+
+impl<T> Clone for MemoryDataSource<T> {
+    fn clone(&self) -> Self {
+        Self {
+            items: self.items.clone(),
+            filter: self.filter.clone(),
+            sort: self.sort.clone(),
+        }
+    }
+}
+
+impl<T> Debug for MemoryDataSource<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("MemoryDataSource")
+            .field("len", &self.items.borrow().len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: Clone + 'static> MemoryDataSource<T> {
+    /// 创建内存数据源
+    pub fn new(items: impl Into<Vec<T>>) -> Self {
+        Self {
+            items: Rc::new(RefCell::new(items.into())),
+            filter: None,
+            sort: None,
+        }
+    }
+
+    /// 设置筛选条件，参数为记录和查询条件，返回记录是否符合条件；未设置时全部记录都符合
+    pub fn filter(mut self, filter: impl Fn(&T, &DataQuery) -> bool + 'static) -> Self {
+        self.filter = Some(Rc::new(filter));
+        self
+    }
+
+    /// 设置排序方式，参数为两条记录和排序的字段名，返回升序时的顺序；未设置时忽略排序条件
+    pub fn sort_by(mut self, sort: impl Fn(&T, &T, &str) -> Ordering + 'static) -> Self {
+        self.sort = Some(Rc::new(sort));
+        self
+    }
+
+    /// 全部记录
+    pub fn items(&self) -> Vec<T> {
+        self.items.borrow().clone()
+    }
+
+    /// 修改记录
+    pub fn update<O>(&self, f: impl FnOnce(&mut Vec<T>) -> O) -> O {
+        f(&mut self.items.borrow_mut())
+    }
+
+    /// 立即执行查询
+    pub fn query_now(&self, query: &DataQuery) -> DataPage<T> {
+        let items = self.items.borrow();
+        let mut matched = items
+            .iter()
+            .filter(|item| self.filter.as_ref().is_none_or(|f| f(item, query)))
+            .collect::<Vec<_>>();
+        if let (Some(sort), Some(by)) = (&self.sort, &query.sort) {
+            matched.sort_by(|a, b| {
+                let order = sort(a, b, &by.prop);
+                if by.descending {
+                    order.reverse()
+                } else {
+                    order
+                }
+            });
+        }
+        let total = matched.len();
+        let items = matched
+            .into_iter()
+            .skip(query.offset())
+            .take(query.page_size)
+            .cloned()
+            .collect();
+        DataPage::new(items, total)
+    }
+}
+
+impl<T: Clone + 'static> DataSource<T> for MemoryDataSource<T> {
+    fn query(&self, query: DataQuery) -> DataFuture<T> {
+        let page = self.query_now(&query);
+        Box::pin(async move { Ok(page) })
+    }
+}
+
+#[cfg(feature = "http")]
+pub use http::HttpDataSource;
+
+#[cfg(feature = "http")]
+mod http {
+    use serde::de::DeserializeOwned;
+    use serde_json::Value;
+
+    use super::*;
+
+    /// HTTP 数据源
+    ///
+    /// 以 GET 请求查询接口，查询条件按 [`DataQuery::params`] 转换为 URL 参数，
+    /// 响应为 JSON 对象，默认从 `items` 字段读取记录、从 `total` 字段读取总数，
+    /// 字段名可以通过 [`HttpDataSource::items_key`] 和 [`HttpDataSource::total_key`] 修改，
+    /// 用 `.` 分隔表示嵌套的字段，如 `data.list`。响应中没有总数时以当前页的记录数作为总数。
+    pub struct HttpDataSource<T> {
+        client: reqwest::Client,
+        url: String,
+        headers: Vec<(String, String)>,
+        params: Vec<(String, String)>,
+        items_key: String,
+        total_key: String,
+        _marker: std::marker::PhantomData<fn() -> T>,
+    }
+
+    impl<T> Clone for HttpDataSource<T> {
+        fn clone(&self) -> Self {
+            Self {
+                client: self.client.clone(),
+                url: self.url.clone(),
+                headers: self.headers.clone(),
+                params: self.params.clone(),
+                items_key: self.items_key.clone(),
+                total_key: self.total_key.clone(),
+                _marker: std::marker::PhantomData,
+            }
+        }
+    }
+
+    impl<T> Debug for HttpDataSource<T> {
+        fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            f.debug_struct("HttpDataSource")
+                .field("url", &self.url)
+                .finish_non_exhaustive()
+        }
+    }
+
+    impl<T: DeserializeOwned + 'static> HttpDataSource<T> {
+        /// 创建 HTTP 数据源
+        pub fn new(url: impl Into<String>) -> Self {
+            Self {
+                client: reqwest::Client::new(),
+                url: url.into(),
+                headers: Vec::new(),
+                params: Vec::new(),
+                items_key: "items".to_string(),
+                total_key: "total".to_string(),
+                _marker: std::marker::PhantomData,
+            }
+        }
+
+        /// 添加请求头，如 `Authorization`
+        pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+            self.headers.push((name.into(), value.into()));
+            self
+        }
+
+        /// 添加固定的 URL 参数，附加在查询条件之后
+        pub fn param(mut self, key: impl Into<String>, value: impl Into<String>) -> Self {
+            self.params.push((key.into(), value.into()));
+            self
+        }
+
+        /// 设置响应中记录列表的字段名
+        pub fn items_key(mut self, key: impl Into<String>) -> Self {
+            self.items_key = key.into();
+            self
+        }
+
+        /// 设置响应中总数的字段名
+        pub fn total_key(mut self, key: impl Into<String>) -> Self {
+            self.total_key = key.into();
+            self
+        }
+    }
+
+    /// 按 `.` 分隔的字段名读取嵌套的字段
+    fn field<'a>(value: &'a Value, key: &str) -> Option<&'a Value> {
+        key.split('.').try_fold(value, |value, key| value.get(key))
+    }
+
+    /// 从响应中解析一页数据
+    fn parse_page<T: DeserializeOwned>(
+        body: Value,
+        items_key: &str,
+        total_key: &str,
+    ) -> Result<DataPage<T>, String> {
+        let items = field(&body, items_key)
+            .cloned()
+            .ok_or_else(|| format!("响应中缺少 {items_key} 字段"))?;
+        let items = serde_json::from_value::<Vec<T>>(items).map_err(|e| e.to_string())?;
+        let total = field(&body, total_key)
+            .and_then(Value::as_u64)
+            .map_or(items.len(), |total| total as usize);
+        Ok(DataPage::new(items, total))
+    }
+
+    impl<T: DeserializeOwned + 'static> DataSource<T> for HttpDataSource<T> {
+        fn query(&self, query: DataQuery) -> DataFuture<T> {
+            let mut request = self
+                .client
+                .get(&self.url)
+                .query(&query.params())
+                .query(&self.params);
+            for (name, value) in &self.headers {
+                request = request.header(name, value);
+            }
+            let items_key = self.items_key.clone();
+            let total_key = self.total_key.clone();
+            Box::pin(async move {
+                let response = request
+                    .send()
+                    .await
+                    .and_then(|response| response.error_for_status())
+                    .map_err(|e| e.to_string())?;
+                let body = response.json::<Value>().await.map_err(|e| e.to_string())?;
+                parse_page(body, &items_key, &total_key)
+            })
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use serde_json::json;
+
+        use super::*;
+
+        #[test]
+        fn test_parse_page() {
+            let page =
+                parse_page::<String>(json!({ "items": ["a", "b"], "total": 9 }), "items", "total");
+            assert_eq!(
+                page,
+                Ok(DataPage::new(vec!["a".to_string(), "b".to_string()], 9))
+            );
+
+            // 嵌套的字段，缺少总数时使用记录数
+            let page = parse_page::<u32>(
+                json!({ "data": { "list": [1, 2, 3] } }),
+                "data.list",
+                "count",
+            );
+            assert_eq!(page, Ok(DataPage::new(vec![1, 2, 3], 3)));
+
+            assert!(parse_page::<u32>(json!({}), "items", "total").is_err());
+        }
+    }
+}
+
+/// 数据源的查询状态
+///
+/// 由 [`use_data_source`] 创建，内部状态都是信号，可以自由复制到事件处理器中。
+pub struct DataResource<T: 'static> {
+    items: Signal<Vec<T>>,
+    total: Signal<usize>,
+    loading: Signal<bool>,
+    error: Signal<Option<String>>,
+    /// 最近一次查询的条件
+    query: CopyValue<Option<DataQuery>>,
+    reload: Callback<()>,
+}
+
+impl<T: 'static> Clone for DataResource<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for DataResource<T> {}
+
+impl<T: 'static> PartialEq for DataResource<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.items == other.items && self.reload == other.reload
+    }
+}
+
+impl<T: 'static> Debug for DataResource<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("DataResource")
+            .field("total", &*self.total.peek())
+            .field("loading", &*self.loading.peek())
+            .field("error", &*self.error.peek())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<T: 'static> DataResource<T> {
+    /// 当前页的记录
+    pub fn items(&self) -> Vec<T>
+    where
+        T: Clone,
+    {
+        self.items.read().clone()
+    }
+
+    /// 符合查询条件的记录总数
+    pub fn total(&self) -> usize {
+        (self.total)()
+    }
+
+    /// 是否正在查询
+    pub fn loading(&self) -> bool {
+        (self.loading)()
+    }
+
+    /// 最近一次查询的错误信息，查询成功后清空
+    pub fn error(&self) -> Option<String> {
+        self.error.read().clone()
+    }
+
+    /// 最近一次查询的条件
+    pub fn query(&self) -> Option<DataQuery> {
+        self.query.read().clone()
+    }
+
+    /// 按当前的查询条件重新查询，如新建或删除记录之后
+    pub fn reload(&self) {
+        self.reload.call(());
+    }
+}
+
+/// 从数据源查询数据
+///
+/// `query` 返回查询条件，其中读取的信号变化后条件不同时重新查询；查询期间条件再次变化时，
+/// 只保留最后一次查询的结果。首次查询在首次渲染时开始，服务端渲染时同样会发起查询。
+///
+/// 数据源在每次渲染时更新为最新传入的值，但只有查询条件变化或调用 [`DataResource::reload`] 时才会重新查询。
+pub fn use_data_source<T: 'static>(
+    source: impl DataSource<T>,
+    mut query: impl FnMut() -> DataQuery + 'static,
+) -> DataResource<T> {
+    let mut current = use_hook(|| CopyValue::new(None::<SharedDataSource<T>>));
+    current.set(Some(SharedDataSource::new(source)));
+    let query = use_callback(move |()| query());
+
+    let mut items = use_signal(Vec::new);
+    let mut total = use_signal(|| 0usize);
+    let mut loading = use_signal(|| false);
+    let mut error = use_signal(|| None::<String>);
+    let mut last = use_hook(|| CopyValue::new(None::<DataQuery>));
+    // 每次查询时递增，用于忽略过期的查询结果
+    let mut request = use_hook(|| CopyValue::new(0u32));
+
+    let load = use_callback(move |next: DataQuery| {
+        let Some(source) = current.peek().clone() else {
+            return;
+        };
+        request += 1;
+        let id = *request.peek();
+        last.set(Some(next.clone()));
+        loading.set(true);
+        let future = source.query(next);
+        spawn(async move {
+            let result = future.await;
+            if *request.peek() != id {
+                return;
+            }
+            loading.set(false);
+            match result {
+                Ok(page) => {
+                    items.set(page.items);
+                    total.set(page.total);
+                    error.set(None);
+                }
+                Err(message) => error.set(Some(message)),
+            }
+        });
+    });
+    use_hook(move || load.call(query.call(())));
+    use_effect(move || {
+        let next = query.call(());
+        if last.peek().as_ref() != Some(&next) {
+            load.call(next);
+        }
+    });
+    let reload = use_callback(move |()| {
+        let next = last.peek().clone().unwrap_or_else(|| query.call(()));
+        load.call(next);
+    });
+
+    DataResource {
+        items,
+        total,
+        loading,
+        error,
+        query: last,
+        reload,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Harness;
+
+    fn numbers() -> MemoryDataSource<u32> {
+        MemoryDataSource::new((1..=25).collect::<Vec<_>>())
+            .filter(
+                |n, query| match query.filters.get("parity").map(String::as_str) {
+                    Some("even") => n % 2 == 0,
+                    Some("odd") => n % 2 == 1,
+                    _ => true,
+                },
+            )
+            .sort_by(|a, b, _| a.cmp(b))
+    }
+
+    #[test]
+    fn test_memory_data_source() {
+        let source = numbers();
+        let page = source.query_now(&DataQuery::new().page(3));
+        assert_eq!(page, DataPage::new(vec![21, 22, 23, 24, 25], 25));
+
+        let query = DataQuery::new()
+            .page_size(3)
+            .filter("parity", "even")
+            .sort("n", true);
+        assert_eq!(
+            source.query_now(&query),
+            DataPage::new(vec![24, 22, 20], 12)
+        );
+
+        // 复制的数据源共享记录
+        source.clone().update(|items| items.retain(|n| *n <= 4));
+        assert_eq!(source.query_now(&query), DataPage::new(vec![4, 2], 2));
+    }
+
+    #[test]
+    fn test_data_query_params() {
+        let query = DataQuery::new()
+            .keyword("张")
+            .filter("status", "active")
+            .sort("name", false);
+        let params = query
+            .params()
+            .into_iter()
+            .map(|(key, value)| format!("{key}={value}"))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            params,
+            [
+                "page=1",
+                "page_size=10",
+                "keyword=张",
+                "status=active",
+                "sort=name",
+                "order=asc"
+            ]
+        );
+        assert_eq!(DataQuery::new().page(0).offset(), 0);
+    }
+
+    #[test]
+    fn test_use_data_source() {
+        fn app() -> Element {
+            let mut page = use_signal(|| 1);
+            let source = numbers().map(|n: u32| n * 10);
+            let resource =
+                use_data_source(source, move || DataQuery::new().page_size(10).page(page()));
+            let items = resource
+                .items()
+                .iter()
+                .map(u32::to_string)
+                .collect::<Vec<_>>()
+                .join(",");
+            rsx! {
+                span { class: "items", "{items}" }
+                span { class: "total", "{resource.total()}" }
+                button { class: "next", onclick: move |_| page += 1, "下一页" }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(
+            harness.find_by_class("items").unwrap().text(),
+            "10,20,30,40,50,60,70,80,90,100"
+        );
+        assert_eq!(harness.find_by_class("total").unwrap().text(), "25");
+
+        let next = harness.find_by_class("next").unwrap();
+        harness.click(&next);
+        harness.click(&next);
+        assert_eq!(
+            harness.find_by_class("items").unwrap().text(),
+            "210,220,230,240,250"
+        );
+    }
+}
@@ -0,0 +1,83 @@
+//! # 稳定的元素 ID
+//!
+//! 表单控件需要通过 `id` 与 `<label for>`、`aria-describedby` 等属性相互关联。ID 由每个
+//! `VirtualDom` 独立的计数器按组件首次渲染的顺序生成，服务端渲染与客户端水合时组件的渲染顺序相同，
+//! 因此生成的 ID 也完全一致，不会出现水合不匹配。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::use_unique_id;
+//!
+//! fn app() -> Element {
+//!     let id = use_unique_id("field");
+//!     rsx! {
+//!         label { r#for: "{id}", "用户名" }
+//!         input { id: "{id}" }
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(app);
+//! dom.rebuild_in_place();
+//! assert_eq!(
+//!     dioxus_ssr::render(&dom),
+//!     r#"<label for="t-field-1">用户名</label><input id="t-field-1"/>"#
+//! );
+//! ```
+
+use std::cell::Cell;
+use std::rc::Rc;
+
+use dioxus::core::provide_root_context;
+use dioxus::prelude::*;
+
+use crate::classnames::PREFIX;
+
+/// 每个 `VirtualDom` 独立的 ID 计数器，保存在根组件的上下文中
+#[derive(Clone, Default)]
+struct IdCounter(Rc<Cell<usize>>);
+
+/// 生成在组件生命周期内保持不变的唯一 ID
+///
+/// 返回形如 `t-{block}-{n}` 的 ID，`n` 在同一个 `VirtualDom` 内从 1 开始递增。
+/// 与其他 hooks 一样，需要在组件中无条件地调用。
+pub fn use_unique_id(block: &str) -> String {
+    use_hook(|| {
+        let counter = try_consume_context::<IdCounter>()
+            .unwrap_or_else(|| provide_root_context(IdCounter::default()));
+        let next = counter.0.get() + 1;
+        counter.0.set(next);
+        format!("{PREFIX}{block}-{next}")
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_ids_are_deterministic_per_dom() {
+        fn app() -> Element {
+            let first = use_unique_id("input");
+            let second = use_unique_id("input");
+            rsx! {
+                span { id: "{first}" }
+                span { id: "{second}" }
+            }
+        }
+
+        let render = || {
+            let mut dom = VirtualDom::new(app);
+            dom.rebuild_in_place();
+            dioxus_ssr::render(&dom)
+        };
+
+        let html = render();
+        assert_eq!(
+            html,
+            r#"<span id="t-input-1"></span><span id="t-input-2"></span>"#
+        );
+        assert_eq!(render(), html);
+    }
+}
@@ -7,6 +7,22 @@
 //! - 提供常用的 UI 组件（Card、Button 等）
 //! - 过程宏自动为组件生成对应的路由组件
 //! - 支持多平台（Web、Desktop、Mobile）
+//! - 支持按组件加载样式，见 [`css`] 模块
+//! - 提供组件类名常量和 BEM 辅助函数，见 [`classnames`] 模块
+//! - 提供 [`use_server_action`] 异步提交动作，执行状态可直接绑定到按钮和错误提示
+//! - 提供 [`use_optimistic`] 乐观更新，先修改本地状态再持久化，失败时撤销并提示错误
+//! - 提供 [`DataSource`] 数据源抽象，表格、选择器和增删改查页面可以在内存数据和 HTTP 接口之间切换
+//! - 提供 [`RegionSource`] 行政区划数据源，地址选择器逐级加载省、市、区县，可以接入自定义的数据集
+//! - 表单控件使用 [`use_unique_id`] 生成稳定的 ID，服务端渲染的 HTML 与客户端水合结果一致
+//! - 提供 [`use_presence`] 浮层挂载状态，关闭后等待离场动画结束再卸载节点
+//! - 提供 [`Memo`] 记忆化包装器，键不变时跳过大型子树的重新构建
+//! - 提供 [`copy_text`] 剪贴板工具，[`Text`] 的可复制模式基于它实现
+//! - 提供 [`export_to_pdf`] 打印工具，只打印页面中的指定元素，配合组件的打印样式开关导出报表
+//! - 提供 [`use_app_badge`] 角标工具，在标签页图标上显示未读通知等数量
+//! - 提供 [`Color`] 颜色工具，计算对比度并为自定义背景色选择可读的文字颜色
+//! - 组件的属性表由构建方法生成，[`PropsTable`] 渲染为 API 文档，见 [`ComponentProps`]
+//! - 组件的命名用例登记在 [`stories`] 模块，可以集中预览并渲染为 HTML 用于视觉回归测试
+//! - 提供交互测试工具，启用 `testing` 特性后见 `testing` 模块
 //!
 //! ## 组件
 //!
@@ -16,32 +32,167 @@
 //! - [`Grid`][]: 网格布局组件，支持自定义列数、行数和间距
 //! - [`Link`][]: 链接组件，支持路由跳转、字符串路径、多种类型和下划线样式
 //! - [`Layout`][]: 布局组件，支持水平和垂直布局
+//! - [`Toolbar`][]: 工具栏组件，分为左中右三个区域，超出的项收起到更多菜单
+//! - [`Masonry`][]: 瀑布流布局组件，支持 CSS 多列和测量高度两种策略，按容器宽度设置列数
 //! - [`Image`][]: 图片组件，支持加载状态、替代文本、尺寸等配置
 //! - [`InputNumber`][]: 数字输入框组件，支持精度控制、步进、不同尺寸和禁用状态
 //! - [`Input`][]: 输入框组件，支持单行文本输入、不同尺寸和禁用状态
 //! - [`TextArea`][]: 多行文本输入框组件，支持不同尺寸和禁用状态
-//! - [`Text`][]: 文本组件，支持标题、段落、文本对齐等
+//! - [`Select`][]: 选择器组件，支持单选、多选、折叠标签、筛选、清空、选项分组和键盘操作
+//! - [`TimePicker`][]: 时间选择器组件，时、分、秒分列选择，支持步长、12/24 小时制、禁用时间段和直接输入
+//! - [`DateTimePicker`][]: 日期时间选择器组件，组合日期和时间选择，支持时区选择
+//! - [`DateRangePicker`][]: 日期范围选择器组件，支持可配置的快捷范围和时区选择，值可以转换为筛选栏的日期范围条件
+//! - [`Text`][]: 文本组件，支持标题、段落、文本对齐和多行省略等
+//! - [`OverflowTooltip`][]: 溢出提示组件，截断文本并只在内容确实被截断时显示完整文本
+//! - [`CalendarHeatmap`][]: 日历热力图组件，类似 GitHub 贡献图，支持颜色等级、提示和图例
+//! - [`OrgChart`][]: 组织结构图组件，支持连接线、分支折叠和自定义节点渲染
+//! - [`FormWizard`][]: 分步表单组件，支持步骤条、分步校验和汇总提交数据
+//! - [`CreditCardInput`][]: 银行卡输入组件，支持卡组织识别、卡号分组和各字段的校验状态
+//! - [`AddressPicker`][]: 地址选择器组件，逐级选择省份、城市和区县，输出各级行政区划代码，数据源可替换
+//! - [`PhoneInput`][]: 手机号码输入组件，支持选择国家/地区、按国家/地区格式化号码和输出 E.164 格式的号码
+//! - [`BarcodeInput`][]: 条码输入组件，支持手动输入、扫码枪输入和摄像头扫码，扫码成功后触发回调
+//! - [`SearchInput`][]: 搜索框组件，支持搜索范围、加载状态和最近搜索记录
+//! - [`FilterBar`][]: 筛选栏组件，支持输入框、下拉选择、日期范围和数字范围筛选，可折叠
+//! - [`ActiveFilters`][]: 已选筛选条件组件，以可移除的标签显示当前条件，与 FilterBar 双向同步
+//! - [`FormErrorSummary`][]: 表单错误汇总组件，以提示列表汇总校验错误，点击错误聚焦对应字段
+//! - [`Form`][] / [`FormItem`][]: 表单组件，支持标签布局、必填标记、校验规则、逐项错误提示和字段联动，通过 [`use_form`] 校验
+//! - [`SchemaForm`][]: 配置表单组件，根据可反序列化的 [`FormSchema`] 在运行时生成完整的表单，适用于服务端下发的表单
+//! - [`Upload`][]: 上传组件，支持点击选择和拖放文件、类型、大小和数量校验，以及带进度的文件列表
+//! - [`Alert`][]: 提示组件，支持成功、信息、警告、错误类型和关闭按钮
+//! - [`ResultPanel`][]: 结果组件，用于操作结果反馈和 404、500 等异常页面
+//! - [`RouteProgress`][]: 路由进度条组件，路由切换和登记的异步加载进行中时在页面顶部显示进度
+//! - [`ConnectionStatus`][]: 网络连接状态组件，断开时显示横幅、恢复时显示提示，配合 [`use_online`] 读取状态
+//! - [`ConsentBanner`][]: Cookie 同意横幅组件，支持全部接受、全部拒绝和按类别自定义，配合 [`use_consent`] 判断是否允许
+//! - [`ConfirmButton`][]: 确认按钮组件，点击后原地切换为确认和取消按钮，确认后执行异步操作，超时自动恢复
+//! - [`Notification`][]: 通知组件，配合 [`use_notification`] 发送通知，支持提示音、经用户授权的浏览器通知和可撤销操作，也可以通过 [`notify`] 模块的函数发送
+//! - [`MessageProvider`][]: 消息提示的挂载点，挂载一次后通过 [`message`] 模块的函数在任意位置发送自动消失的简短消息，支持多种位置和堆叠
+//! - [`IdleGuard`][]: 空闲超时组件，用户长时间没有操作时弹出倒计时对话框，超时自动退出登录，配合 [`use_idle`] 判断是否空闲
+//! - [`Dialog`][]: 对话框组件，由 `Signal<bool>` 控制打开状态，支持标题、底部操作、遮罩关闭，打开期间焦点限制在对话框内
+//! - [`Skeleton`][]: 骨架屏组件，加载期间显示页面结构的占位，配合 [`SkeletonBoundary`] 在异步资源完成前显示
+//! - [`Spin`][]: 加载中组件，单独显示三种尺寸的加载图标，或在加载期间以遮罩覆盖子元素并显示提示文字
+//! - [`ConfigProvider`][]: 全局配置组件，通过上下文提供主题（含高对比度主题）、组件密度、减少动画、语言和通知配置
+//! - [`PropsTable`][]: 属性表组件，展示由构建方法生成的组件 API 文档
+//! - [`JsonViewer`][]: JSON 查看器组件，支持折叠节点、键名搜索、按类型着色和复制节点路径
+//! - [`LogViewer`][]: 日志查看器组件，基于环形缓冲区和虚拟滚动，支持 ANSI 颜色、级别筛选、搜索和跟随底部
+//! - [`Table`][]: 表格组件，支持列宽和对齐、单元格溢出提示和复制、行选择、可持久化的列设置、键盘导航和行右键菜单，以及带展开按钮、层级缩进和子行懒加载的树形数据
+//! - [`SelectionBar`][]: 批量操作栏组件，表格有选中的行时显示选中数量和批量操作按钮，支持服务端分页时选择全部页
+//! - [`CrudPage`][]: 增删改查页面组件，由查询、新建、更新、删除操作和列、表单字段定义生成带筛选、分页、编辑对话框、删除确认和操作提示的列表页
+//! - [`Kbd`][] / [`ShortcutHint`][]: 按键和快捷键提示组件，把 `mod+K` 形式的快捷键按平台显示为 `⌘K` 或 `Ctrl+K`
+//! - [`Tooltip`][]: 文字提示组件，支持十二种位置、悬停/点击/聚焦触发、显示和隐藏延迟、箭头和快捷键提示，空间不足时自动翻转和平移
+//! - [`Tabs`][] / [`TabPane`][]: 标签页组件，支持四个方向的标签栏、线条和卡片样式、可关闭的标签、键盘切换，以及在首次切换时才创建内容的懒加载
+//! - [`Dropdown`][] / [`DropdownItem`][]: 下拉菜单组件，为任意触发元素附加悬停或点击打开的菜单，支持分隔线、禁用项、图标、快捷键提示和多级子菜单
+//! - [`Pagination`][]: 分页组件，支持每页记录数选择、跳转输入框、折叠的页码列表以及完整和紧凑两种布局
+//! - [`Popconfirm`][]: 气泡确认框组件，点击触发元素后弹出带图标、标题和确定、取消按钮的确认气泡，与 Tooltip 和 Dropdown 共用定位方式
+//! - [`TitleProvider`][]: 文档标题组件，根据当前路由设置浏览器标签页或桌面窗口标题，页面可通过 [`use_document_title`] 覆盖
+//!
+//! ## Cargo 特性
+//!
+//! 每个组件都有同名的 cargo 特性（如 `button`、`input_number`、`calendar_heatmap`），[`View`] 和
+//! [`Text`] 始终可用。默认启用的 `full` 特性包含全部组件，也可以按分组启用：
+//!
+//! - `basic`: button、card、link、image
+//! - `form`: input、input_number、textarea、radio、checkbox、select、time_picker、date_picker、form_wizard、credit_card_input、phone_input、barcode_input、address_picker、search_input、filter_bar、active_filters、form_error_summary、form_item、schema_form、upload
+//! - `charts`: calendar_heatmap、org_chart
+//! - `feedback`: alert、result_panel、route_progress、connection_status、consent_banner、confirm_button、notification、message、idle_guard、dialog、skeleton、spin
+//!
+//! 启用 `http` 特性后提供 `HttpDataSource`，通过 HTTP 接口查询数据，默认不启用。
+//!
+//! 启用 `regions` 特性后提供 `ChinaRegionsSample` 内置的中国行政区划示例数据，只收录了部分城市和区县，用于演示和原型，默认不启用。
+//!
+//! 启用 `camera` 特性后 [`BarcodeInput`] 显示扫码按钮，在浏览器中打开摄像头识别条码，默认不启用。
+//!
+//! 调试构建中，样式设置方法会校验明显无效的值（如 `10pxx`）并输出警告，启用 `strict_css`
+//! 特性后改为 panic，适合在测试中开启。
+//!
+//! ```toml
+//! [dependencies]
+//! dioxus-blocks-components = { version = "0.1", default-features = false, features = ["web", "button", "input"] }
+//! ```
 //!
 //! ## 宏
 //!
 //! - [`ComponentBase`]: 为组件提供基础方法（id、class、style 等）
 //! - [`Route`][]: 为组件自动生成对应的路由组件
+//! - [`builder_props`][]: 从组件的构建方法生成属性表
+
+// 使派生宏生成的 `::dioxus_blocks_components` 路径在本 crate 内同样可用
+extern crate self as dioxus_blocks_components;
+
+// 组件和示例应用共用的浏览器脚本工具，不属于公开 API
+#[doc(hidden)]
+pub mod browser;
 
 mod constant;
 pub use constant::{MAIN_CSS, TAILWIND_CSS};
 
+pub mod classnames;
+pub mod css;
+
 pub use dioxus::prelude::{Element, NavigationTarget};
 
 mod style;
-pub use style::Style;
+pub use style::{Elevation, Style};
 
 mod outlet;
-pub use outlet::Outlet;
+pub use outlet::{Outlet, anchor_id, scroll_to_anchor};
+
+mod action;
+pub use action::{ServerAction, use_server_action};
+
+mod optimistic;
+pub use optimistic::{Optimistic, use_optimistic};
+
+mod data_source;
+#[cfg(feature = "http")]
+pub use data_source::HttpDataSource;
+pub use data_source::{
+    DataFuture, DataPage, DataQuery, DataResource, DataSort, DataSource, MapDataSource,
+    MemoryDataSource, SharedDataSource, use_data_source,
+};
+
+mod regions;
+#[cfg(feature = "regions")]
+pub use regions::ChinaRegionsSample;
+pub use regions::{Region, RegionSource};
+
+mod id;
+pub use id::use_unique_id;
+
+mod color;
+pub use color::Color;
+
+mod clipboard;
+pub use clipboard::copy_text;
+
+mod print;
+pub use print::export_to_pdf;
+
+mod app_badge;
+pub use app_badge::use_app_badge;
+
+mod props;
+pub use props::{COMMON_PROPS, ComponentProps, PropInfo};
+
+mod presence;
+pub use presence::{Presence, use_presence};
+
+#[cfg(any(feature = "tooltip", feature = "dropdown", feature = "popconfirm"))]
+mod popper;
+
+mod memo;
+pub use memo::Memo;
+#[doc(hidden)]
+pub use memo::private as __private;
 
 mod traits;
 pub use traits::ToElement;
 
+pub mod stories;
+
 mod components;
 pub use components::*;
 
+#[cfg(any(test, feature = "testing"))]
+pub mod testing;
+
 pub mod prelude;
@@ -0,0 +1,316 @@
+//! # 记忆化子树
+//!
+//! 包装开销较大的子组件树，只有在键发生变化时才重新构建，父组件因无关的信号更新而重新渲染时，
+//! 被包装的子树会被直接跳过。
+//!
+//! 子树作为独立的组件渲染，因此其中的 hooks 与外层组件互不影响。子树自身订阅的信号发生变化时，
+//! 仍然会正常更新。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus::core::Mutations;
+//! use dioxus_blocks_components::{Memo, Text, ToElement, View};
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let rows = use_signal(|| 100);
+//!     let mut clicks = use_signal(|| 0);
+//!     rsx! {
+//!         button { onclick: move |_| clicks += 1, "点击 {clicks}" }
+//!         {Memo::new(rows(), move || {
+//!             (0..rows()).fold(View::new(), |view, i| view.children(Text::span(format!("行 {i}"))))
+//!         }).to_element()}
+//!     }
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::any::Any;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use crate::ToElement;
+
+/// 记忆化子树
+#[derive(Clone)]
+pub struct Memo {
+    /// 缓存键
+    deps: MemoKey,
+    /// 子树构造函数
+    render: Rc<dyn Fn() -> Element>,
+}
+
+impl std::fmt::Debug for Memo {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Memo").finish_non_exhaustive()
+    }
+}
+
+impl Memo {
+    /// 创建记忆化子树，`render` 只在首次渲染和 `key` 变化时被调用
+    ///
+    /// 键未变化时会继续使用上一次的 `render`，因此 `render` 捕获的、会影响输出的值都应该体现在键中。
+    pub fn new<K, T, F>(key: K, render: F) -> Self
+    where
+        K: PartialEq + 'static,
+        T: ToElement + 'static,
+        F: Fn() -> T + 'static,
+    {
+        Self {
+            deps: MemoKey::new(key),
+            render: Rc::new(move || render().to_element()),
+        }
+    }
+}
+
+impl ToElement for Memo {
+    fn to_element(&self) -> Element {
+        rsx! {
+            MemoElement { deps: self.deps.clone(), render: self.render.clone() }
+        }
+    }
+}
+
+/// 类型擦除的缓存键
+#[derive(Clone)]
+struct MemoKey {
+    value: Rc<dyn Any>,
+    eq: fn(&dyn Any, &dyn Any) -> bool,
+}
+
+impl MemoKey {
+    fn new<K: PartialEq + 'static>(key: K) -> Self {
+        Self {
+            value: Rc::new(key),
+            eq: |a, b| match (a.downcast_ref::<K>(), b.downcast_ref::<K>()) {
+                (Some(a), Some(b)) => a == b,
+                _ => false,
+            },
+        }
+    }
+}
+
+impl PartialEq for MemoKey {
+    fn eq(&self, other: &Self) -> bool {
+        (self.eq)(&*self.value, &*other.value)
+    }
+}
+
+#[derive(Props, Clone)]
+struct MemoElementProps {
+    deps: MemoKey,
+    render: Rc<dyn Fn() -> Element>,
+}
+
+/// 只比较缓存键，键相同时 Dioxus 会跳过子组件的重新渲染
+impl PartialEq for MemoElementProps {
+    fn eq(&self, other: &Self) -> bool {
+        self.deps == other.deps
+    }
+}
+
+#[allow(non_snake_case)]
+fn MemoElement(props: MemoElementProps) -> Element {
+    (props.render)()
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct MemoizedElementProps<T: ToElement + Clone + PartialEq + 'static> {
+    component: T,
+}
+
+#[allow(non_snake_case)]
+fn MemoizedElement<T: ToElement + Clone + PartialEq + 'static>(
+    props: MemoizedElementProps<T>,
+) -> Element {
+    props.component.to_element()
+}
+
+/// `ComponentBase` 派生宏生成代码所需的辅助项，不属于公开 API
+#[doc(hidden)]
+pub mod private {
+    use std::marker::PhantomData;
+    use std::rc::Rc;
+
+    use super::*;
+
+    /// 以组件作为属性渲染，属性与上一次渲染相等时跳过 `to_element`
+    pub fn memoized<T: ToElement + Clone + PartialEq + 'static>(component: T) -> Element {
+        rsx! {
+            MemoizedElement { component }
+        }
+    }
+
+    /// 字段比较探针
+    ///
+    /// 通过自动引用的方法解析顺序选择比较方式：实现了 `PartialEq` 的字段按值比较，
+    /// `Rc` 包装的字段（如子组件、回调）按指针比较，其余字段视为不相等。
+    pub struct Probe<T: ?Sized>(PhantomData<T>);
+
+    impl<T: ?Sized> Probe<T> {
+        pub const fn new() -> Self {
+            Self(PhantomData)
+        }
+    }
+
+    impl<T: ?Sized> Default for Probe<T> {
+        fn default() -> Self {
+            Self::new()
+        }
+    }
+
+    pub trait ByValue<T: ?Sized> {
+        fn props_eq(self, a: &T, b: &T) -> bool;
+    }
+
+    impl<T: PartialEq + ?Sized> ByValue<T> for &&&Probe<T> {
+        fn props_eq(self, a: &T, b: &T) -> bool {
+            a == b
+        }
+    }
+
+    pub trait ByPointer<T: ?Sized> {
+        fn props_eq(self, a: &T, b: &T) -> bool;
+    }
+
+    impl<T: ?Sized> ByPointer<Rc<T>> for &&Probe<Rc<T>> {
+        fn props_eq(self, a: &Rc<T>, b: &Rc<T>) -> bool {
+            Rc::ptr_eq(a, b)
+        }
+    }
+
+    impl<T: ?Sized> ByPointer<Option<Rc<T>>> for &&Probe<Option<Rc<T>>> {
+        fn props_eq(self, a: &Option<Rc<T>>, b: &Option<Rc<T>>) -> bool {
+            match (a, b) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+        }
+    }
+
+    impl<T: ?Sized> ByPointer<Vec<Rc<T>>> for &&Probe<Vec<Rc<T>>> {
+        fn props_eq(self, a: &Vec<Rc<T>>, b: &Vec<Rc<T>>) -> bool {
+            a.len() == b.len() && a.iter().zip(b).all(|(a, b)| Rc::ptr_eq(a, b))
+        }
+    }
+
+    pub trait Fallback<T: ?Sized> {
+        fn props_eq(self, a: &T, b: &T) -> bool;
+    }
+
+    impl<T: ?Sized> Fallback<T> for &Probe<T> {
+        fn props_eq(self, _a: &T, _b: &T) -> bool {
+            false
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::cell::Cell;
+
+    use dioxus_blocks_macro::ComponentBase;
+
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Style, Text, View};
+
+    thread_local! {
+        static BUILDS: Cell<usize> = const { Cell::new(0) };
+    }
+
+    #[test]
+    fn test_memo_skips_unrelated_updates() {
+        fn app() -> Element {
+            let mut clicks = use_signal(|| 0);
+            let mut version = use_signal(|| 0);
+            rsx! {
+                button { class: "click", onclick: move |_| clicks += 1, "{clicks}" }
+                button { class: "bump", onclick: move |_| version += 1, "{version}" }
+                {Memo::new(version(), move || {
+                    BUILDS.with(|builds| builds.set(builds.get() + 1));
+                    Text::span(format!("版本 {}", version.peek()))
+                }).to_element()}
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(BUILDS.with(Cell::get), 1);
+
+        let click = harness.find_by_class("click").unwrap();
+        harness.click(&click);
+        harness.click(&click);
+        assert_eq!(harness.find_by_class("click").unwrap().text(), "2");
+        assert_eq!(BUILDS.with(Cell::get), 1);
+
+        let bump = harness.find_by_class("bump").unwrap();
+        harness.click(&bump);
+        assert_eq!(BUILDS.with(Cell::get), 2);
+        assert!(harness.find_by_text("版本 1").is_some());
+    }
+
+    #[test]
+    fn test_component_base_partial_eq() {
+        let child = Text::span("子组件");
+        let view = View::new().class("row").children(child.clone());
+
+        assert_eq!(view, view.clone());
+        assert_eq!(Text::new("a"), Text::new("a"));
+        assert_ne!(Text::new("a"), Text::new("b"));
+        assert_ne!(Text::new("a").style(|s| s.color("red")), Text::new("a"));
+        // 子组件按指针比较，重新创建的子组件视为不相等
+        assert_ne!(view, View::new().class("row").children(child));
+    }
+
+    #[test]
+    fn test_memoized_element_skips_equal_props() {
+        #[derive(Debug, Clone, Default, ComponentBase)]
+        struct Counted {
+            id: Option<String>,
+            class: String,
+            style: Option<Style>,
+            childrens: Vec<Rc<dyn ToElement>>,
+            onclick: Option<EventHandler<MouseEvent>>,
+            label: String,
+        }
+
+        impl ToElement for Counted {
+            fn to_element(&self) -> Element {
+                BUILDS.with(|builds| builds.set(builds.get() + 1));
+                rsx! {
+                    span { class: "counted", "{self.label}" }
+                }
+            }
+        }
+
+        fn app() -> Element {
+            let mut clicks = use_signal(|| 0);
+            let mut version = use_signal(|| 0);
+            let counted = Counted {
+                label: format!("版本 {version}"),
+                ..Default::default()
+            };
+            rsx! {
+                button { class: "click", onclick: move |_| clicks += 1, "{clicks}" }
+                button { class: "bump", onclick: move |_| version += 1, "{version}" }
+                {counted.memoized_element()}
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert_eq!(BUILDS.with(Cell::get), 1);
+
+        let click = harness.find_by_class("click").unwrap();
+        harness.click(&click);
+        assert_eq!(BUILDS.with(Cell::get), 1);
+
+        let bump = harness.find_by_class("bump").unwrap();
+        harness.click(&bump);
+        assert_eq!(BUILDS.with(Cell::get), 2);
+        assert_eq!(harness.find_by_class("counted").unwrap().text(), "版本 1");
+    }
+}
@@ -0,0 +1,382 @@
+//! # 乐观更新
+//!
+//! [`use_optimistic`] 先在本地修改信号中的值，让界面立即反映操作的结果，再在后台执行持久化（通常是调用服务端函数）；
+//! 持久化失败时撤销这次修改，并通过最近的 [`Notification`](crate::Notification) 提示错误，
+//! 没有 Notification 时只记录在 [`Optimistic::error`] 中。
+//!
+//! 同时进行的多个更新互不影响：某个更新失败时，从第一个更新之前的值开始重放其余的更新，只撤销失败的那一个。
+//! 更新全部完成之前由其他代码写入信号的值会在撤销时被覆盖。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus::core::Mutations;
+//! use dioxus_blocks_components::{Button, ToElement, use_optimistic};
+//!
+//! // 实际项目中通常是 `#[server]` 标注的服务端函数
+//! async fn save_like(liked: bool) -> Result<(), String> {
+//!     Ok(())
+//! }
+//!
+//! let mut dom = VirtualDom::new(|| {
+//!     let liked = use_signal(|| false);
+//!     let likes = use_optimistic(liked).with_error_title("点赞失败");
+//!     let text = if liked() { "已赞" } else { "点赞" };
+//!
+//!     Button::new()
+//!         .text(text)
+//!         .onclick(move |_| {
+//!             let next = !liked();
+//!             likes.apply(move |liked| *liked = next, save_like(next));
+//!         })
+//!         .to_element()
+//! });
+//! let mut mutations = Mutations::default();
+//! dom.rebuild(&mut mutations);
+//! ```
+
+use std::fmt::Display;
+use std::future::Future;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+#[cfg(feature = "notification")]
+use crate::Notifier;
+
+/// 对值的修改，撤销其他更新时需要重放，因此可以多次调用
+type Mutation<T> = Rc<dyn Fn(&mut T)>;
+
+/// 尚未全部完成的更新
+struct Entry<T> {
+    id: u64,
+    mutation: Mutation<T>,
+    /// 持久化是否已经成功
+    done: bool,
+}
+
+/// 更新的记录，所有更新完成后清空
+struct Ledger<T> {
+    /// 第一个更新之前的值
+    base: Option<T>,
+    /// 按应用顺序排列的更新
+    entries: Vec<Entry<T>>,
+    next_id: u64,
+}
+
+impl<T> Default for Ledger<T> {
+    fn default() -> Self {
+        Self {
+            base: None,
+            entries: Vec::new(),
+            next_id: 0,
+        }
+    }
+}
+
+/// 乐观更新
+///
+/// 由 [`use_optimistic`] 创建，内部状态都是信号，可以自由复制到事件处理器中。
+pub struct Optimistic<T: 'static> {
+    /// 被修改的信号
+    signal: Signal<T>,
+    ledger: CopyValue<Ledger<T>>,
+    /// 尚未完成的更新数量
+    pending: Signal<usize>,
+    /// 最近一次失败的错误信息
+    error: Signal<Option<String>>,
+    /// 失败提示的标题
+    error_title: &'static str,
+    #[cfg(feature = "notification")]
+    notifier: Option<Notifier>,
+}
+
+impl<T: 'static> Clone for Optimistic<T> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T: 'static> Copy for Optimistic<T> {}
+
+impl<T: 'static> PartialEq for Optimistic<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.signal == other.signal
+            && self.ledger == other.ledger
+            && self.pending == other.pending
+            && self.error == other.error
+            && self.error_title == other.error_title
+    }
+}
+
+impl<T: 'static> std::fmt::Debug for Optimistic<T> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Optimistic")
+            .field("pending", &*self.pending.peek())
+            .field("error", &*self.error.peek())
+            .finish_non_exhaustive()
+    }
+}
+
+/// 创建修改 `signal` 的乐观更新
+pub fn use_optimistic<T: Clone + 'static>(signal: Signal<T>) -> Optimistic<T> {
+    Optimistic {
+        signal,
+        ledger: use_hook(|| CopyValue::new(Ledger::default())),
+        pending: use_signal(|| 0),
+        error: use_signal(|| None),
+        error_title: "操作失败",
+        #[cfg(feature = "notification")]
+        notifier: use_hook(try_consume_context::<Notifier>),
+    }
+}
+
+impl<T: Clone + 'static> Optimistic<T> {
+    /// 设置失败提示的标题，默认为「操作失败」，提示的内容为错误信息
+    pub fn with_error_title(mut self, title: &'static str) -> Self {
+        self.error_title = title;
+        self
+    }
+
+    /// 立即用 `mutate` 修改信号的值，然后执行 `persist`，失败时撤销这次修改
+    ///
+    /// `persist` 的错误类型只需实现 `Display`，成功时的返回值会被忽略。开始执行时会清空上一次的错误信息。
+    pub fn apply<O, E, Fut>(&self, mutate: impl Fn(&mut T) + 'static, persist: Fut)
+    where
+        E: Display + 'static,
+        Fut: Future<Output = Result<O, E>> + 'static,
+    {
+        let mut signal = self.signal;
+        let mut ledger = self.ledger;
+        let mut pending = self.pending;
+        let mut error = self.error;
+
+        let mutation: Mutation<T> = Rc::new(mutate);
+        let id = {
+            let mut ledger = ledger.write();
+            if ledger.entries.is_empty() {
+                ledger.base = Some(signal.peek().clone());
+            }
+            ledger.next_id += 1;
+            let id = ledger.next_id;
+            ledger.entries.push(Entry {
+                id,
+                mutation: mutation.clone(),
+                done: false,
+            });
+            id
+        };
+        mutation(&mut signal.write());
+        pending += 1;
+        error.set(None);
+
+        let optimistic = *self;
+        spawn(async move {
+            let result = persist.await.map(|_| ()).map_err(|e| e.to_string());
+            optimistic.settle(id, result);
+        });
+    }
+
+    /// 记录更新的结果，失败时撤销该更新并重放其余的更新
+    fn settle(self, id: u64, result: Result<(), String>) {
+        let mut signal = self.signal;
+        let mut ledger = self.ledger;
+        let mut pending = self.pending;
+        let mut error = self.error;
+
+        let restored = {
+            let mut ledger = ledger.write();
+            let Some(index) = ledger.entries.iter().position(|entry| entry.id == id) else {
+                return;
+            };
+            let restored = match result {
+                Ok(()) => {
+                    ledger.entries[index].done = true;
+                    None
+                }
+                Err(_) => {
+                    ledger.entries.remove(index);
+                    ledger.base.clone().map(|mut value| {
+                        for entry in &ledger.entries {
+                            (entry.mutation)(&mut value);
+                        }
+                        value
+                    })
+                }
+            };
+            if ledger.entries.iter().all(|entry| entry.done) {
+                ledger.entries.clear();
+                ledger.base = None;
+            }
+            restored
+        };
+        pending -= 1;
+
+        if let Some(value) = restored {
+            signal.set(value);
+        }
+        if let Err(message) = result {
+            #[cfg(feature = "notification")]
+            if let Some(notifier) = self.notifier {
+                notifier.error(self.error_title, message.clone());
+            }
+            error.set(Some(message));
+        }
+    }
+
+    /// 是否有尚未完成的更新
+    pub fn pending(&self) -> bool {
+        (self.pending)() > 0
+    }
+
+    /// 最近一次失败的错误信息
+    pub fn error(&self) -> Option<String> {
+        self.error.read().clone()
+    }
+
+    /// 清空错误信息
+    pub fn clear_error(&self) {
+        let mut error = self.error;
+        error.set(None);
+    }
+}
+
+#[cfg(all(test, feature = "notification", feature = "button"))]
+mod tests {
+    use std::cell::RefCell;
+    use std::pin::Pin;
+    use std::task::{Context, Poll, Waker};
+
+    use super::*;
+    use crate::testing::Harness;
+    use crate::{Button, Notification, Text, ToElement, View};
+
+    /// 持久化的结果和等待结果的任务
+    #[derive(Default)]
+    struct GateState {
+        result: Option<Result<(), String>>,
+        waker: Option<Waker>,
+    }
+
+    /// 由测试决定何时完成的持久化操作
+    #[derive(Clone, Default)]
+    struct Gate(Rc<RefCell<GateState>>);
+
+    impl Gate {
+        fn finish(&self, result: Result<(), String>) {
+            let mut state = self.0.borrow_mut();
+            state.result = Some(result);
+            if let Some(waker) = state.waker.take() {
+                waker.wake();
+            }
+        }
+    }
+
+    impl Future for Gate {
+        type Output = Result<(), String>;
+
+        fn poll(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Self::Output> {
+            let mut state = self.0.borrow_mut();
+            match state.result.take() {
+                Some(result) => Poll::Ready(result),
+                None => {
+                    state.waker = Some(cx.waker().clone());
+                    Poll::Pending
+                }
+            }
+        }
+    }
+
+    thread_local! {
+        static GATES: RefCell<Vec<Gate>> = const { RefCell::new(Vec::new()) };
+    }
+
+    /// 每次点击追加一项，持久化的 Gate 保存到 GATES 中
+    #[derive(Debug, Clone)]
+    struct TodoList;
+
+    impl ToElement for TodoList {
+        fn to_element(&self) -> Element {
+            let todos = use_signal(Vec::<String>::new);
+            let optimistic = use_optimistic(todos).with_error_title("添加失败");
+            let status = match (optimistic.pending(), optimistic.error()) {
+                (true, _) => "保存中".to_string(),
+                (false, Some(error)) => error,
+                (false, None) => "已保存".to_string(),
+            };
+
+            View::new()
+                .children(Text::span(todos.read().join(",")).class("todos"))
+                .children(Text::span(status).class("status"))
+                .children(Button::new().text("添加").onclick(move |_| {
+                    let name = format!("任务{}", todos.peek().len() + 1);
+                    let gate = Gate::default();
+                    GATES.with(|gates| gates.borrow_mut().push(gate.clone()));
+                    optimistic.apply(
+                        move |todos: &mut Vec<String>| todos.push(name.clone()),
+                        gate,
+                    );
+                }))
+                .to_element()
+        }
+    }
+
+    fn app() -> Element {
+        Notification::new().children(TodoList).to_element()
+    }
+
+    fn text(harness: &Harness, class: &str) -> String {
+        harness.find_by_class(class).unwrap().text().to_string()
+    }
+
+    fn gate(index: usize) -> Gate {
+        GATES.with(|gates| gates.borrow()[index].clone())
+    }
+
+    #[test]
+    fn test_optimistic_rollback() {
+        GATES.with(|gates| gates.borrow_mut().clear());
+        let mut harness = Harness::new(app);
+
+        harness.click(&harness.find_by_text("添加").unwrap());
+        harness.click(&harness.find_by_text("添加").unwrap());
+        assert_eq!(text(&harness, "todos"), "任务1,任务2");
+        assert_eq!(text(&harness, "status"), "保存中");
+
+        // 第一个更新失败，只撤销该更新，保留仍在进行的第二个更新
+        gate(0).finish(Err("网络错误".to_string()));
+        harness.flush();
+        assert_eq!(text(&harness, "todos"), "任务2");
+        assert_eq!(text(&harness, "status"), "保存中");
+        assert!(harness.find_by_text("添加失败").is_some());
+
+        gate(1).finish(Ok(()));
+        harness.flush();
+        assert_eq!(text(&harness, "todos"), "任务2");
+        assert_eq!(text(&harness, "status"), "网络错误");
+    }
+
+    #[test]
+    fn test_optimistic_success_keeps_value() {
+        GATES.with(|gates| gates.borrow_mut().clear());
+        let mut harness = Harness::new(app);
+
+        harness.click(&harness.find_by_text("添加").unwrap());
+        harness.click(&harness.find_by_text("添加").unwrap());
+        gate(0).finish(Ok(()));
+        harness.flush();
+
+        // 第二个更新失败时重放已经成功的第一个更新
+        gate(1).finish(Err("冲突".to_string()));
+        harness.flush();
+        assert_eq!(text(&harness, "todos"), "任务1");
+        assert_eq!(text(&harness, "status"), "冲突");
+
+        // 全部完成后的新更新以当前值为起点
+        harness.click(&harness.find_by_text("添加").unwrap());
+        gate(2).finish(Err("冲突".to_string()));
+        harness.flush();
+        assert_eq!(text(&harness, "todos"), "任务1");
+    }
+}
@@ -1,17 +1,204 @@
 //! Outlet component for rendering routed content
+//!
+//! 渲染当前路由对应的页面，并在路由切换后管理滚动位置：
+//!
+//! - 浏览器后退或前进时恢复该路由上次离开时的滚动位置，页面内容异步加载时等待内容足够高后再恢复；
+//! - 地址中带有锚点（如 `/guide#install`）时滚动到对应 `id` 的元素；
+//! - 其他导航滚动到顶部。
+//!
+//! 默认滚动的是整个页面，页面在固定高度的容器中滚动时通过 [`Outlet::scroll_container`] 指定容器。
+//! 当前页面内跳转到锚点时路由不变，链接点击后调用 [`scroll_to_anchor`] 滚动；[`anchor_id`] 由标题生成稳定的锚点 id，
+//! 如 [`Card::anchor`](crate::Card::anchor) 为演示区块设置的 id，便于分享指向页面中某一节的链接。
+//! 桌面端没有浏览器历史事件，返回上一页后还可以前进时视为后退。启用 `server` 特性进行服务端渲染时不做处理。
+//!
+//! # 示例
+//!
+//! ```rust,ignore
+//! Outlet::<Route>::new()
+//!     .scroll_container(".app-main")
+//!     .scroll_offset(64)
+//! ```
+
+use std::cell::RefCell;
+use std::rc::Rc;
 
 use dioxus::prelude::*;
 use dioxus::router::Routable;
 
 use crate::ToElement;
 
+/// 记录滚动位置的脚本，只安装一次
+///
+/// 滚动时把位置记录到当前路由下；`current` 为 `null` 时暂停记录，避免路由切换期间内容变短导致的滚动被记到上一个路由。
+const SCROLL_SCRIPT: &str = "if (!window.__tScroll) { \
+     const state = { positions: {}, current: null, container: null, offset: 0, pop: false }; \
+     window.__tScroll = state; \
+     if ('scrollRestoration' in history) history.scrollRestoration = 'manual'; \
+     window.addEventListener('popstate', () => { state.pop = true; }); \
+     state.target = () => (state.container && document.querySelector(state.container)) \
+       || document.scrollingElement || document.documentElement; \
+     window.addEventListener('scroll', () => { \
+       if (state.current !== null) state.positions[state.current] = state.target().scrollTop; \
+     }, { capture: true, passive: true }); \
+     state.retry = (step, done) => { let n = 0; const run = () => { \
+       if (step() || n++ >= 60) { done?.(); } else { requestAnimationFrame(run); } \
+     }; run(); }; \
+     state.scrollToHash = (hash, done) => state.retry(() => { \
+       const el = document.getElementById(decodeURIComponent(hash)); if (!el) return false; \
+       const target = state.target(); \
+       const base = target === document.scrollingElement ? 0 : target.getBoundingClientRect().top; \
+       target.scrollTop += el.getBoundingClientRect().top - base - state.offset; return true; \
+     }, done); \
+   }";
+
+/// 由标题生成锚点 id：转为小写，保留字母、数字和中文等文字，其余字符合并为 `-`
+///
+/// ```rust
+/// use dioxus_blocks_components::anchor_id;
+///
+/// assert_eq!(anchor_id("Sizes"), "sizes");
+/// assert_eq!(anchor_id("不同尺寸 / Sizes"), "不同尺寸-sizes");
+/// ```
+pub fn anchor_id(title: &str) -> String {
+    let mut id = String::new();
+    for c in title.chars().flat_map(char::to_lowercase) {
+        if c.is_alphanumeric() {
+            id.push(c);
+        } else if !id.is_empty() && !id.ends_with('-') {
+            id.push('-');
+        }
+    }
+    id.trim_end_matches('-').to_string()
+}
+
+/// 滚动到当前页面中指定 id 的元素，元素尚未渲染时等待渲染完成
+///
+/// 使用 [`Outlet`] 设置的滚动容器和锚点偏移，适合在跳转到同一页面的锚点后调用，此时路由不变，
+/// [`Outlet`] 不会处理滚动。启用 `server` 特性进行服务端渲染时不做处理。
+pub fn scroll_to_anchor(id: &str) {
+    if cfg!(feature = "server") || id.is_empty() {
+        return;
+    }
+    document::eval(&format!(
+        "{SCROLL_SCRIPT} window.__tScroll.scrollToHash({id:?});"
+    ));
+}
+
+/// 拆分路由地址中的锚点
+fn split_hash(route: &str) -> (&str, Option<&str>) {
+    match route.split_once('#') {
+        Some((path, hash)) if !hash.is_empty() => (path, Some(hash)),
+        Some((path, _)) => (path, None),
+        None => (route, None),
+    }
+}
+
+/// 路由即将切换，暂停记录滚动位置
+fn pause_scroll() {
+    if cfg!(feature = "server") {
+        return;
+    }
+    document::eval(&format!("{SCROLL_SCRIPT} window.__tScroll.current = null;"));
+}
+
+/// 路由切换后恢复滚动位置、滚动到锚点或滚动到顶部，`initial` 为首次渲染，此时没有锚点时保持浏览器的位置
+fn restore_scroll(route: &str, container: Option<&str>, offset: u32, initial: bool, back: bool) {
+    if cfg!(feature = "server") {
+        return;
+    }
+    let (key, hash) = split_hash(route);
+    let container = container.unwrap_or_default();
+    let hash = hash.unwrap_or_default();
+    document::eval(&format!(
+        "{SCROLL_SCRIPT} \
+         const state = window.__tScroll; state.container = {container:?} || null; state.offset = {offset}; \
+         const key = {key:?}; const pop = state.pop || {back}; state.pop = false; \
+         const hash = {hash:?} || location.hash.slice(1); \
+         const target = state.target(); \
+         const done = () => {{ state.current = key; }}; \
+         if (pop) {{ \
+           const y = state.positions[key] || 0; \
+           state.retry(() => {{ target.scrollTop = y; return Math.abs(target.scrollTop - y) <= 1; }}, done); \
+         }} else if (hash) {{ \
+           state.scrollToHash(hash, done); \
+         }} else {{ \
+           if (!{initial}) target.scrollTop = 0; \
+           state.current = key; \
+         }}"
+    ));
+}
+
 #[derive(Debug, Clone)]
 pub struct Outlet<R: Routable + Clone> {
+    /// 是否管理路由切换后的滚动位置
+    scroll_restoration: bool,
+    /// 滚动容器的 CSS 选择器，未设置时为整个页面
+    scroll_container: Option<String>,
+    /// 滚动到锚点时与容器顶部保留的距离，单位像素
+    scroll_offset: u32,
     _phantom: std::marker::PhantomData<R>,
 }
 
+impl<R: Routable + Clone> Outlet<R> {
+    /// 创建路由出口，默认管理滚动位置
+    pub fn new() -> Self {
+        Self {
+            scroll_restoration: true,
+            scroll_container: None,
+            scroll_offset: 0,
+            _phantom: Default::default(),
+        }
+    }
+
+    /// 设置是否管理路由切换后的滚动位置，关闭后由浏览器或路由历史处理
+    pub fn scroll_restoration(mut self, enabled: bool) -> Self {
+        self.scroll_restoration = enabled;
+        self
+    }
+
+    /// 设置滚动容器的 CSS 选择器，如 `.app-main`，未设置时为整个页面
+    pub fn scroll_container(mut self, selector: impl Into<String>) -> Self {
+        self.scroll_container = Some(selector.into());
+        self
+    }
+
+    /// 设置滚动到锚点时与容器顶部保留的距离，用于避开固定的页头
+    pub fn scroll_offset(mut self, offset: u32) -> Self {
+        self.scroll_offset = offset;
+        self
+    }
+}
+
 impl<R: Routable + Clone + std::fmt::Debug> ToElement for Outlet<R> {
     fn to_element(&self) -> Element {
+        let route = use_route::<R>().to_string();
+        let enabled = self.scroll_restoration;
+
+        // 新页面渲染之前暂停记录，渲染完成后再处理滚动位置
+        let last = use_hook(|| Rc::new(RefCell::new(None::<String>)));
+        let changed = last.borrow().as_deref() != Some(route.as_str());
+        if changed {
+            if enabled && last.borrow().is_some() {
+                pause_scroll();
+            }
+            *last.borrow_mut() = Some(route.clone());
+        }
+
+        let mut initial = use_signal(|| true);
+        let container = self.scroll_container.clone();
+        let offset = self.scroll_offset;
+        use_effect(use_reactive!(|route| {
+            let first = *initial.peek();
+            if first {
+                initial.set(false);
+            }
+            if !enabled {
+                return;
+            }
+            let back = cfg!(feature = "desktop") && !first && router().can_go_forward();
+            restore_scroll(&route, container.as_deref(), offset, first, back);
+        }));
+
         rsx! {
             dioxus::prelude::Outlet::<R> {}
         }
@@ -20,8 +207,26 @@ impl<R: Routable + Clone + std::fmt::Debug> ToElement for Outlet<R> {
 
 impl<R: Routable + Clone + std::fmt::Debug> Default for Outlet<R> {
     fn default() -> Self {
-        Self {
-            _phantom: Default::default(),
-        }
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_anchor_id() {
+        assert_eq!(anchor_id("基础用法"), "基础用法");
+        assert_eq!(anchor_id("  Sizes & Colors!  "), "sizes-colors");
+        assert_eq!(anchor_id("Table 表格"), "table-表格");
+        assert_eq!(anchor_id("---"), "");
+    }
+
+    #[test]
+    fn test_split_hash() {
+        assert_eq!(split_hash("/guide"), ("/guide", None));
+        assert_eq!(split_hash("/guide#install"), ("/guide", Some("install")));
+        assert_eq!(split_hash("/guide?tab=1#"), ("/guide?tab=1", None));
     }
 }
@@ -0,0 +1,186 @@
+//! 浮层定位
+//!
+//! Tooltip、Dropdown 和 Popconfirm 共用的定位逻辑：浮层显示后测量触发元素、浮层和视口的尺寸，
+//! 首选方向的空间不足而相反方向更宽裕时翻转到相反方向，浮层在交叉轴上超出视口时平移回视口内。
+//! 无法测量元素尺寸的渲染环境（如服务端渲染）中按首选的位置显示。
+//!
+//! 各组件的位置枚举实现 [`Placement`]，通过 [`use_popper`] 获取定位状态：
+//!
+//! 1. 触发元素的 `onmounted` 中调用 [`Popper::set_anchor`]
+//! 2. 浮层的 `onmounted` 中调用 [`Popper::measure`]
+//! 3. 浮层隐藏时调用 [`Popper::reset`]，下次显示时重新测量
+//! 4. 渲染时通过 [`Popper::position`] 读取实际的位置和平移距离，平移距离通过内联的 CSS 变量传给样式
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+/// 浮层与视口边缘保持的最小距离
+const VIEWPORT_MARGIN: f64 = 8.0;
+
+/// 浮层位于触发元素的哪一侧
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Side {
+    Top,
+    Bottom,
+    Left,
+    Right,
+}
+
+impl Side {
+    /// 相反的一侧
+    fn flipped(self) -> Self {
+        match self {
+            Side::Top => Side::Bottom,
+            Side::Bottom => Side::Top,
+            Side::Left => Side::Right,
+            Side::Right => Side::Left,
+        }
+    }
+}
+
+/// 浮层在交叉轴上的对齐方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Align {
+    Start,
+    Center,
+    End,
+}
+
+/// 浮层的位置，由方向和对齐方式组成
+pub(crate) trait Placement: Copy + 'static {
+    /// 拆分为方向和对齐方式
+    fn parts(self) -> (Side, Align);
+
+    /// 由方向和对齐方式组合
+    fn from_parts(side: Side, align: Align) -> Self;
+}
+
+/// 视口坐标系中的矩形
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) struct Rect {
+    pub(crate) x: f64,
+    pub(crate) y: f64,
+    pub(crate) width: f64,
+    pub(crate) height: f64,
+}
+
+/// 计算浮层实际显示的位置和在交叉轴上的平移距离
+///
+/// 首选方向的空间放不下浮层而相反方向的空间更大时翻转到相反方向，对齐方式不变；
+/// 浮层在交叉轴上超出视口时平移回视口内，与视口边缘保持 [`VIEWPORT_MARGIN`] 的距离。
+pub(crate) fn resolve_position<P: Placement>(
+    placement: P,
+    trigger: Rect,
+    popup: (f64, f64),
+    viewport: (f64, f64),
+    offset: f64,
+) -> (P, f64) {
+    let space = |side: Side| match side {
+        Side::Top => trigger.y,
+        Side::Bottom => viewport.1 - trigger.y - trigger.height,
+        Side::Left => trigger.x,
+        Side::Right => viewport.0 - trigger.x - trigger.width,
+    };
+    let (side, align) = placement.parts();
+    let vertical = matches!(side, Side::Top | Side::Bottom);
+    let needed = if vertical { popup.1 } else { popup.0 } + offset;
+    let side = if space(side) < needed && space(side.flipped()) > space(side) {
+        side.flipped()
+    } else {
+        side
+    };
+
+    let (start, length, size, limit) = if vertical {
+        (trigger.x, trigger.width, popup.0, viewport.0)
+    } else {
+        (trigger.y, trigger.height, popup.1, viewport.1)
+    };
+    let position = match align {
+        Align::Start => start,
+        Align::Center => start + (length - size) / 2.0,
+        Align::End => start + length - size,
+    };
+    let max = (limit - size - VIEWPORT_MARGIN).max(VIEWPORT_MARGIN);
+    let shift = position.clamp(VIEWPORT_MARGIN, max) - position;
+    (P::from_parts(side, align), shift)
+}
+
+/// 读取视口的宽度和高度，服务端或非浏览器环境返回 `None`
+async fn viewport_size() -> Option<(f64, f64)> {
+    if cfg!(feature = "server") {
+        return None;
+    }
+    document::eval("return [window.innerWidth, window.innerHeight];")
+        .join::<(f64, f64)>()
+        .await
+        .ok()
+}
+
+/// 浮层的定位状态，由 [`use_popper`] 创建
+pub(crate) struct Popper<P: 'static> {
+    /// 触发元素
+    anchor: Signal<Option<Rc<MountedData>>>,
+    /// 测量后的实际位置和平移距离，隐藏后清空
+    position: Signal<Option<(P, f64)>>,
+}
+
+impl<P> Clone for Popper<P> {
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<P> Copy for Popper<P> {}
+
+/// 创建浮层的定位状态
+pub(crate) fn use_popper<P: Placement>() -> Popper<P> {
+    Popper {
+        anchor: use_signal(|| None),
+        position: use_signal(|| None),
+    }
+}
+
+impl<P: Placement> Popper<P> {
+    /// 记录触发元素，在触发元素的 `onmounted` 中调用
+    pub(crate) fn set_anchor(mut self, event: MountedEvent) {
+        self.anchor.set(Some(event.data()));
+    }
+
+    /// 测量浮层并计算实际的位置，在浮层的 `onmounted` 中调用
+    pub(crate) async fn measure(mut self, event: MountedEvent, placement: P, offset: f64) {
+        let popup = event.data();
+        let Some(anchor) = self.anchor.peek().clone() else {
+            return;
+        };
+        let (Ok(rect), Ok(popup), Some(viewport)) = (
+            anchor.get_client_rect().await,
+            popup.get_client_rect().await,
+            viewport_size().await,
+        ) else {
+            return;
+        };
+        let rect = Rect {
+            x: rect.origin.x,
+            y: rect.origin.y,
+            width: rect.size.width,
+            height: rect.size.height,
+        };
+        let size = (popup.size.width, popup.size.height);
+        self.position.set(Some(resolve_position(
+            placement, rect, size, viewport, offset,
+        )));
+    }
+
+    /// 清空测量结果，浮层隐藏时调用
+    pub(crate) fn reset(mut self) {
+        if self.position.peek().is_some() {
+            self.position.set(None);
+        }
+    }
+
+    /// 实际的位置和交叉轴上的平移距离，未测量时为首选的位置
+    pub(crate) fn position(&self, placement: P) -> (P, f64) {
+        (self.position)().unwrap_or((placement, 0.0))
+    }
+}
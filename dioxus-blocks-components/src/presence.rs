@@ -0,0 +1,170 @@
+//! # 离场动画
+//!
+//! 对话框、下拉菜单等浮层关闭时如果立即卸载节点，离场动画会被截断。[`use_presence`] 在打开状态变为
+//! `false` 后让节点保持挂载并进入离场状态，组件为节点加上 `is-leaving` 类名播放离场动画，
+//! 动画结束（`animationend`）后再卸载节点。
+//!
+//! 动画被样式覆盖、节点不可见等情况下不会触发 `animationend`，因此离场状态最多保持指定的毫秒数，
+//! 超时后同样卸载节点。服务端渲染或没有浏览器环境时不等待，下一次更新时直接卸载。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::use_presence;
+//!
+//! fn app() -> Element {
+//!     let mut open = use_signal(|| true);
+//!     let presence = use_presence(open(), 300);
+//!
+//!     rsx! {
+//!         button { onclick: move |_| open.set(false), "关闭" }
+//!         if presence.is_present() {
+//!             div {
+//!                 class: if presence.is_leaving() { "popup is-leaving" } else { "popup" },
+//!                 onanimationend: move |_| presence.finish(),
+//!                 "浮层内容"
+//!             }
+//!         }
+//!     }
+//! }
+//!
+//! let mut dom = VirtualDom::new(app);
+//! dom.rebuild_in_place();
+//! assert!(dioxus_ssr::render(&dom).contains("浮层内容"));
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::browser::sleep;
+
+/// 节点的挂载阶段
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Phase {
+    /// 未挂载
+    Hidden,
+    /// 已打开
+    Visible,
+    /// 正在播放离场动画，附带本次离场的序号
+    Leaving(u64),
+}
+
+/// 浮层的挂载状态，由 [`use_presence`] 创建
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Presence {
+    /// 当前阶段，渲染时根据打开状态更新，不触发重新渲染
+    phase: CopyValue<Phase>,
+    /// 离场结束时递增，使组件重新渲染并卸载节点
+    version: Signal<u64>,
+}
+
+impl Presence {
+    /// 节点是否需要挂载，打开时和离场动画播放期间为 `true`
+    pub fn is_present(&self) -> bool {
+        *self.phase.peek() != Phase::Hidden
+    }
+
+    /// 是否正在播放离场动画
+    pub fn is_leaving(&self) -> bool {
+        matches!(*self.phase.peek(), Phase::Leaving(_))
+    }
+
+    /// 结束离场动画并卸载节点，通常在节点的 `onanimationend` 中调用，未在离场时调用不做任何事
+    pub fn finish(&self) {
+        if self.is_leaving() {
+            self.unmount();
+        }
+    }
+
+    /// 只在仍处于指定的那一次离场时卸载节点，之后重新打开或已经卸载时忽略
+    fn finish_round(&self, round: u64) {
+        if *self.phase.peek() == Phase::Leaving(round) {
+            self.unmount();
+        }
+    }
+
+    fn unmount(&self) {
+        let (mut phase, mut version) = (self.phase, self.version);
+        phase.set(Phase::Hidden);
+        version += 1;
+    }
+}
+
+/// 跟踪浮层的打开状态，关闭后保持挂载直到离场动画结束
+///
+/// `open` 为当前的打开状态，`timeout` 为离场状态最多保持的毫秒数，应略长于离场动画的时长。
+/// 与其他 hooks 一样，需要在组件中无条件地调用。
+pub fn use_presence(open: bool, timeout: u32) -> Presence {
+    let phase = use_hook(|| {
+        CopyValue::new(match open {
+            true => Phase::Visible,
+            false => Phase::Hidden,
+        })
+    });
+    let version = use_signal(|| 0u64);
+    let mut rounds = use_hook(|| CopyValue::new(0u64));
+    // 订阅离场结束的通知
+    version.read();
+
+    let presence = Presence { phase, version };
+    let mut phase = presence.phase;
+    let current = *phase.peek();
+    match (open, current) {
+        (true, Phase::Visible) | (false, Phase::Hidden) | (false, Phase::Leaving(_)) => {}
+        (true, _) => phase.set(Phase::Visible),
+        (false, Phase::Visible) => {
+            rounds += 1;
+            let round = *rounds.peek();
+            phase.set(Phase::Leaving(round));
+            spawn(async move {
+                sleep(timeout).await;
+                presence.finish_round(round);
+            });
+        }
+    }
+    presence
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_presence_keeps_node_until_leave_finishes() {
+        #[derive(Clone, Copy)]
+        struct Open(Signal<bool>);
+
+        fn app() -> Element {
+            let open = use_signal(|| true);
+            use_context_provider(|| Open(open));
+            let presence = use_presence(open(), 300);
+            rsx! {
+                if presence.is_present() {
+                    div { class: if presence.is_leaving() { "popup is-leaving" } else { "popup" } }
+                }
+            }
+        }
+
+        let mut dom = VirtualDom::new(app);
+        dom.rebuild_in_place();
+        assert_eq!(dioxus_ssr::render(&dom), r#"<div class="popup"></div>"#);
+
+        let Open(mut open) = dom.in_scope(ScopeId::APP, consume_context::<Open>);
+        dom.in_runtime(|| open.set(false));
+        dom.render_immediate_to_vec();
+        assert_eq!(
+            dioxus_ssr::render(&dom),
+            r#"<div class="popup is-leaving"></div>"#
+        );
+
+        // 没有浏览器环境时计时立即结束，节点随后卸载
+        dom.process_events();
+        dom.render_immediate_to_vec();
+        assert_eq!(dioxus_ssr::render(&dom), "");
+
+        // 重新打开后再次挂载
+        dom.in_runtime(|| open.set(true));
+        dom.render_immediate_to_vec();
+        assert_eq!(dioxus_ssr::render(&dom), r#"<div class="popup"></div>"#);
+    }
+}
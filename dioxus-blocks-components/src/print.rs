@@ -0,0 +1,50 @@
+//! # 打印
+//!
+//! 把页面中的某个元素导出为 PDF。浏览器没有直接生成 PDF 的接口，[`export_to_pdf`] 只打印指定元素，
+//! 其它内容在打印时隐藏，用户在打印对话框中选择“另存为 PDF”即可保存；桌面端同样调用 webview 的打印对话框。
+//!
+//! 配合 [`Card::print_friendly`](crate::Card) 等打印样式开关，打印时会去掉阴影和背景色、避免卡片和表格行被分页截断。
+//! 启用 `server` 特性进行服务端渲染时直接返回 `false`。
+//!
+//! # 示例
+//!
+//! ```rust,ignore
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Button, Card, export_to_pdf};
+//!
+//! Card::new()
+//!     .id("monthly-report")
+//!     .print_friendly(true)
+//!     .footer(Button::new().text("导出 PDF").onclick(|_| {
+//!         spawn(async move {
+//!             export_to_pdf("monthly-report").await;
+//!         });
+//!     }));
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::classnames;
+
+/// 打印指定 id 的元素，打开打印对话框后返回 `true`，找不到元素时返回 `false`
+pub async fn export_to_pdf(element_id: &str) -> bool {
+    if cfg!(feature = "server") {
+        return false;
+    }
+    let js = format!(
+        "const el = document.getElementById({element_id:?}); \
+         if (!el) return false; \
+         const body = document.body; \
+         const cleanup = () => {{ \
+           el.classList.remove({target:?}); body.classList.remove({printing:?}); \
+           window.removeEventListener('afterprint', cleanup); \
+         }}; \
+         el.classList.add({target:?}); body.classList.add({printing:?}); \
+         window.addEventListener('afterprint', cleanup); \
+         window.print(); \
+         return true;",
+        target = classnames::PRINT_TARGET,
+        printing = classnames::PRINTING,
+    );
+    document::eval(&js).join::<bool>().await.unwrap_or(false)
+}
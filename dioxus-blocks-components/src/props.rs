@@ -0,0 +1,80 @@
+//! # 组件属性表
+//!
+//! 组件的 API 文档由构建方法生成：在组件的构建方法所在的 impl 块上使用
+//! [`builder_props`](dioxus_blocks_macro::builder_props) 宏，宏会记录每个构建方法的名称、参数类型、
+//! 默认值和文档注释的第一段，并为组件实现 [`ComponentProps`]。
+//! [`PropsTable`](crate::PropsTable) 读取这些信息渲染属性表，文档不需要手动维护，
+//! 修改构建方法后属性表随之更新。
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus_blocks_components::{Alert, ComponentProps};
+//!
+//! assert_eq!(Alert::NAME, "Alert");
+//! let closable = Alert::props().iter().find(|p| p.name == "closable").unwrap();
+//! assert_eq!(closable.ty, "bool");
+//! assert_eq!(closable.default, Some("false"));
+//! ```
+
+/// 单个属性，对应组件的一个构建方法
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PropInfo {
+    /// 构建方法名
+    pub name: &'static str,
+    /// 参数类型，没有参数时为空字符串，多个参数时以逗号分隔
+    pub ty: &'static str,
+    /// 默认值，`None` 表示没有默认值或未记录
+    pub default: Option<&'static str>,
+    /// 说明，取自文档注释的第一段
+    pub doc: &'static str,
+}
+
+/// 提供属性表的组件，通常由 [`builder_props`](dioxus_blocks_macro::builder_props) 宏实现
+pub trait ComponentProps {
+    /// 组件名称
+    const NAME: &'static str;
+
+    /// 组件的全部属性，按构建方法的声明顺序排列
+    fn props() -> &'static [PropInfo];
+}
+
+/// 所有组件共有的属性，由 [`ComponentBase`](dioxus_blocks_macro::ComponentBase) 派生宏提供
+pub const COMMON_PROPS: &[PropInfo] = &[
+    PropInfo {
+        name: "id",
+        ty: "impl Into<String>",
+        default: None,
+        doc: "设置组件的 ID",
+    },
+    PropInfo {
+        name: "class",
+        ty: "impl ToString",
+        default: None,
+        doc: "追加 CSS 类名",
+    },
+    PropInfo {
+        name: "style",
+        ty: "impl FnOnce(Style) -> Style",
+        default: None,
+        doc: "设置内联样式，与已有样式合并",
+    },
+    PropInfo {
+        name: "children",
+        ty: "impl ToElement + Clone + 'static",
+        default: None,
+        doc: "添加子组件",
+    },
+    PropInfo {
+        name: "childrens",
+        ty: "Vec<impl ToElement + Clone + 'static>",
+        default: None,
+        doc: "批量添加子组件",
+    },
+    PropInfo {
+        name: "onclick",
+        ty: "impl FnMut(MouseEvent) + 'static",
+        default: None,
+        doc: "设置点击事件",
+    },
+];
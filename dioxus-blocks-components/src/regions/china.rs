@@ -0,0 +1,261 @@
+//! 中国行政区划示例数据
+//!
+//! 代码和名称来自 GB/T 2260 行政区划代码，按代码排序。这是一份**不完整**的示例数据：包含全部省级行政区，
+//! 以及广东、浙江、江苏的全部地级市、其他省份的省会城市和部分城市的区县，其余地级市和区县没有收录。
+
+/// 行政区划代码和名称
+pub(super) const REGIONS: &[(&str, &str)] = &[
+    ("110000", "北京市"),
+    ("110100", "市辖区"),
+    ("110101", "东城区"),
+    ("110102", "西城区"),
+    ("110105", "朝阳区"),
+    ("110106", "丰台区"),
+    ("110107", "石景山区"),
+    ("110108", "海淀区"),
+    ("110109", "门头沟区"),
+    ("110111", "房山区"),
+    ("110112", "通州区"),
+    ("110113", "顺义区"),
+    ("110114", "昌平区"),
+    ("110115", "大兴区"),
+    ("110116", "怀柔区"),
+    ("110117", "平谷区"),
+    ("110118", "密云区"),
+    ("110119", "延庆区"),
+    ("120000", "天津市"),
+    ("120100", "市辖区"),
+    ("120101", "和平区"),
+    ("120102", "河东区"),
+    ("120103", "河西区"),
+    ("120104", "南开区"),
+    ("120105", "河北区"),
+    ("120106", "红桥区"),
+    ("120110", "东丽区"),
+    ("120111", "西青区"),
+    ("120112", "津南区"),
+    ("120113", "北辰区"),
+    ("120114", "武清区"),
+    ("120115", "宝坻区"),
+    ("120116", "滨海新区"),
+    ("120117", "宁河区"),
+    ("120118", "静海区"),
+    ("120119", "蓟州区"),
+    ("130000", "河北省"),
+    ("130100", "石家庄市"),
+    ("140000", "山西省"),
+    ("140100", "太原市"),
+    ("150000", "内蒙古自治区"),
+    ("150100", "呼和浩特市"),
+    ("210000", "辽宁省"),
+    ("210100", "沈阳市"),
+    ("210200", "大连市"),
+    ("220000", "吉林省"),
+    ("220100", "长春市"),
+    ("230000", "黑龙江省"),
+    ("230100", "哈尔滨市"),
+    ("310000", "上海市"),
+    ("310100", "市辖区"),
+    ("310101", "黄浦区"),
+    ("310104", "徐汇区"),
+    ("310105", "长宁区"),
+    ("310106", "静安区"),
+    ("310107", "普陀区"),
+    ("310109", "虹口区"),
+    ("310110", "杨浦区"),
+    ("310112", "闵行区"),
+    ("310113", "宝山区"),
+    ("310114", "嘉定区"),
+    ("310115", "浦东新区"),
+    ("310116", "金山区"),
+    ("310117", "松江区"),
+    ("310118", "青浦区"),
+    ("310120", "奉贤区"),
+    ("310151", "崇明区"),
+    ("320000", "江苏省"),
+    ("320100", "南京市"),
+    ("320102", "玄武区"),
+    ("320104", "秦淮区"),
+    ("320105", "建邺区"),
+    ("320106", "鼓楼区"),
+    ("320111", "浦口区"),
+    ("320113", "栖霞区"),
+    ("320114", "雨花台区"),
+    ("320115", "江宁区"),
+    ("320116", "六合区"),
+    ("320117", "溧水区"),
+    ("320118", "高淳区"),
+    ("320200", "无锡市"),
+    ("320300", "徐州市"),
+    ("320400", "常州市"),
+    ("320500", "苏州市"),
+    ("320600", "南通市"),
+    ("320700", "连云港市"),
+    ("320800", "淮安市"),
+    ("320900", "盐城市"),
+    ("321000", "扬州市"),
+    ("321100", "镇江市"),
+    ("321200", "泰州市"),
+    ("321300", "宿迁市"),
+    ("330000", "浙江省"),
+    ("330100", "杭州市"),
+    ("330102", "上城区"),
+    ("330105", "拱墅区"),
+    ("330106", "西湖区"),
+    ("330108", "滨江区"),
+    ("330109", "萧山区"),
+    ("330110", "余杭区"),
+    ("330111", "富阳区"),
+    ("330112", "临安区"),
+    ("330113", "临平区"),
+    ("330114", "钱塘区"),
+    ("330122", "桐庐县"),
+    ("330127", "淳安县"),
+    ("330182", "建德市"),
+    ("330200", "宁波市"),
+    ("330300", "温州市"),
+    ("330400", "嘉兴市"),
+    ("330500", "湖州市"),
+    ("330600", "绍兴市"),
+    ("330700", "金华市"),
+    ("330800", "衢州市"),
+    ("330900", "舟山市"),
+    ("331000", "台州市"),
+    ("331100", "丽水市"),
+    ("340000", "安徽省"),
+    ("340100", "合肥市"),
+    ("350000", "福建省"),
+    ("350100", "福州市"),
+    ("350200", "厦门市"),
+    ("360000", "江西省"),
+    ("360100", "南昌市"),
+    ("370000", "山东省"),
+    ("370100", "济南市"),
+    ("370200", "青岛市"),
+    ("410000", "河南省"),
+    ("410100", "郑州市"),
+    ("420000", "湖北省"),
+    ("420100", "武汉市"),
+    ("420102", "江岸区"),
+    ("420103", "江汉区"),
+    ("420104", "硚口区"),
+    ("420105", "汉阳区"),
+    ("420106", "武昌区"),
+    ("420107", "青山区"),
+    ("420111", "洪山区"),
+    ("420112", "东西湖区"),
+    ("420113", "汉南区"),
+    ("420114", "蔡甸区"),
+    ("420115", "江夏区"),
+    ("420116", "黄陂区"),
+    ("420117", "新洲区"),
+    ("430000", "湖南省"),
+    ("430100", "长沙市"),
+    ("440000", "广东省"),
+    ("440100", "广州市"),
+    ("440103", "荔湾区"),
+    ("440104", "越秀区"),
+    ("440105", "海珠区"),
+    ("440106", "天河区"),
+    ("440111", "白云区"),
+    ("440112", "黄埔区"),
+    ("440113", "番禺区"),
+    ("440114", "花都区"),
+    ("440115", "南沙区"),
+    ("440117", "从化区"),
+    ("440118", "增城区"),
+    ("440200", "韶关市"),
+    ("440300", "深圳市"),
+    ("440303", "罗湖区"),
+    ("440304", "福田区"),
+    ("440305", "南山区"),
+    ("440306", "宝安区"),
+    ("440307", "龙岗区"),
+    ("440308", "盐田区"),
+    ("440309", "龙华区"),
+    ("440310", "坪山区"),
+    ("440311", "光明区"),
+    ("440400", "珠海市"),
+    ("440500", "汕头市"),
+    ("440600", "佛山市"),
+    ("440700", "江门市"),
+    ("440800", "湛江市"),
+    ("440900", "茂名市"),
+    ("441200", "肇庆市"),
+    ("441300", "惠州市"),
+    ("441400", "梅州市"),
+    ("441500", "汕尾市"),
+    ("441600", "河源市"),
+    ("441700", "阳江市"),
+    ("441800", "清远市"),
+    ("441900", "东莞市"),
+    ("442000", "中山市"),
+    ("445100", "潮州市"),
+    ("445200", "揭阳市"),
+    ("445300", "云浮市"),
+    ("450000", "广西壮族自治区"),
+    ("450100", "南宁市"),
+    ("460000", "海南省"),
+    ("460100", "海口市"),
+    ("500000", "重庆市"),
+    ("500100", "市辖区"),
+    ("500101", "万州区"),
+    ("500102", "涪陵区"),
+    ("500103", "渝中区"),
+    ("500104", "大渡口区"),
+    ("500105", "江北区"),
+    ("500106", "沙坪坝区"),
+    ("500107", "九龙坡区"),
+    ("500108", "南岸区"),
+    ("500109", "北碚区"),
+    ("500110", "綦江区"),
+    ("500111", "大足区"),
+    ("500112", "渝北区"),
+    ("500113", "巴南区"),
+    ("500114", "黔江区"),
+    ("500115", "长寿区"),
+    ("500116", "江津区"),
+    ("500117", "合川区"),
+    ("500118", "永川区"),
+    ("500119", "南川区"),
+    ("500120", "璧山区"),
+    ("500151", "铜梁区"),
+    ("500152", "潼南区"),
+    ("500153", "荣昌区"),
+    ("500154", "开州区"),
+    ("500155", "梁平区"),
+    ("500156", "武隆区"),
+    ("510000", "四川省"),
+    ("510100", "成都市"),
+    ("510104", "锦江区"),
+    ("510105", "青羊区"),
+    ("510106", "金牛区"),
+    ("510107", "武侯区"),
+    ("510108", "成华区"),
+    ("510112", "龙泉驿区"),
+    ("510113", "青白江区"),
+    ("510114", "新都区"),
+    ("510115", "温江区"),
+    ("510116", "双流区"),
+    ("510117", "郫都区"),
+    ("510118", "新津区"),
+    ("520000", "贵州省"),
+    ("520100", "贵阳市"),
+    ("530000", "云南省"),
+    ("530100", "昆明市"),
+    ("540000", "西藏自治区"),
+    ("540100", "拉萨市"),
+    ("610000", "陕西省"),
+    ("610100", "西安市"),
+    ("620000", "甘肃省"),
+    ("620100", "兰州市"),
+    ("630000", "青海省"),
+    ("630100", "西宁市"),
+    ("640000", "宁夏回族自治区"),
+    ("640100", "银川市"),
+    ("650000", "新疆维吾尔自治区"),
+    ("650100", "乌鲁木齐市"),
+    ("710000", "台湾省"),
+    ("810000", "香港特别行政区"),
+    ("820000", "澳门特别行政区"),
+];
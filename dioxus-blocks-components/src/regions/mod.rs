@@ -0,0 +1,234 @@
+//! # 行政区划
+//!
+//! [`RegionSource`] 按上级代码返回下级行政区划，地址选择器通过它逐级加载省、市、区县的选项：
+//!
+//! - `ChinaRegionsSampleSample`：启用 `regions` 特性后可用，内置的中国行政区划**示例**数据，代码为 GB/T 2260 的六位代码，
+//!   只收录了部分城市和区县，用于演示和原型，不能作为生产环境的数据源
+//! - 闭包：`Fn(Option<&str>) -> Vec<Region>` 同样实现了 [`RegionSource`]，可以接入自定义的数据集，
+//!   例如从接口加载的完整行政区划或其他国家的地区数据
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus_blocks_components::{Region, RegionSource};
+//!
+//! let source = |parent: Option<&str>| match parent {
+//!     None => vec![Region::new("US-CA", "California")],
+//!     Some("US-CA") => vec![Region::new("US-CA-LA", "Los Angeles")],
+//!     Some(_) => Vec::new(),
+//! };
+//! assert_eq!(source.children(None)[0].name, "California");
+//!
+//! let path = source.path("US-CA-LA");
+//! assert_eq!(path.len(), 2);
+//! assert_eq!(path[0].code, "US-CA");
+//! ```
+
+#[cfg(feature = "address_picker")]
+use std::fmt::Debug;
+#[cfg(feature = "address_picker")]
+use std::rc::Rc;
+
+#[cfg(feature = "regions")]
+mod china;
+
+/// 行政区划
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct Region {
+    /// 代码
+    pub code: String,
+    /// 名称
+    pub name: String,
+}
+
+impl Region {
+    /// 创建行政区划
+    pub fn new(code: impl Into<String>, name: impl Into<String>) -> Self {
+        Self {
+            code: code.into(),
+            name: name.into(),
+        }
+    }
+}
+
+/// 行政区划数据源
+pub trait RegionSource: 'static {
+    /// 返回下级行政区划，`parent` 为 `None` 时返回第一级
+    fn children(&self, parent: Option<&str>) -> Vec<Region>;
+
+    /// 返回从第一级到指定代码的行政区划，代码不存在时返回空列表
+    ///
+    /// 默认逐级遍历 [`RegionSource::children`] 查找，数据量较大时建议按代码规则直接计算。
+    fn path(&self, code: &str) -> Vec<Region> {
+        fn search<S: RegionSource + ?Sized>(
+            source: &S,
+            parent: Option<&str>,
+            code: &str,
+            path: &mut Vec<Region>,
+        ) -> bool {
+            for region in source.children(parent) {
+                let found = region.code == code;
+                let next = region.code.clone();
+                path.push(region);
+                if found || search(source, Some(&next), code, path) {
+                    return true;
+                }
+                path.pop();
+            }
+            false
+        }
+
+        let mut path = Vec::new();
+        search(self, None, code, &mut path);
+        path
+    }
+}
+
+impl<F> RegionSource for F
+where
+    F: Fn(Option<&str>) -> Vec<Region> + 'static,
+{
+    fn children(&self, parent: Option<&str>) -> Vec<Region> {
+        self(parent)
+    }
+}
+
+/// 共享的行政区划数据源，按指针比较
+#[cfg(feature = "address_picker")]
+#[derive(Clone)]
+pub(crate) struct SharedRegionSource(Rc<dyn RegionSource>);
+
+#[cfg(feature = "address_picker")]
+impl SharedRegionSource {
+    /// 包装数据源
+    pub(crate) fn new(source: impl RegionSource) -> Self {
+        Self(Rc::new(source))
+    }
+}
+
+#[cfg(feature = "address_picker")]
+impl PartialEq for SharedRegionSource {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+#[cfg(feature = "address_picker")]
+impl Debug for SharedRegionSource {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.write_str("SharedRegionSource")
+    }
+}
+
+#[cfg(feature = "address_picker")]
+impl RegionSource for SharedRegionSource {
+    fn children(&self, parent: Option<&str>) -> Vec<Region> {
+        self.0.children(parent)
+    }
+
+    fn path(&self, code: &str) -> Vec<Region> {
+        self.0.path(code)
+    }
+}
+
+/// 内置的中国行政区划示例数据
+///
+/// 数据不完整：包含全部省级行政区，以及广东、浙江、江苏的全部地级市、其他省份的省会城市和部分城市的区县，
+/// 其余城市和区县查不到，只适合演示和原型。生产环境请从接口加载完整的行政区划，通过闭包实现 [`RegionSource`]。
+#[cfg(feature = "regions")]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct ChinaRegionsSample;
+
+#[cfg(feature = "regions")]
+impl ChinaRegionsSample {
+    /// 按代码查找行政区划
+    pub fn find(code: &str) -> Option<Region> {
+        china::REGIONS
+            .iter()
+            .find(|(c, _)| *c == code)
+            .map(|(code, name)| Region::new(*code, *name))
+    }
+}
+
+#[cfg(feature = "regions")]
+impl RegionSource for ChinaRegionsSample {
+    fn children(&self, parent: Option<&str>) -> Vec<Region> {
+        if parent.is_some_and(|parent| parent.len() != 6 || !parent.is_ascii()) {
+            return Vec::new();
+        }
+        // 六位代码中前两位为省、中间两位为市、后两位为区县
+        let is_child = |code: &str| match parent {
+            None => code.ends_with("0000"),
+            Some(parent) if parent.ends_with("0000") => {
+                code[..2] == parent[..2] && code.ends_with("00") && code != parent
+            }
+            Some(parent) if parent.ends_with("00") => code[..4] == parent[..4] && code != parent,
+            Some(_) => false,
+        };
+        china::REGIONS
+            .iter()
+            .filter(|(code, _)| is_child(code))
+            .map(|(code, name)| Region::new(*code, *name))
+            .collect()
+    }
+
+    fn path(&self, code: &str) -> Vec<Region> {
+        if code.len() != 6 || !code.is_ascii() {
+            return Vec::new();
+        }
+        let province = format!("{}0000", &code[..2]);
+        let city = format!("{}00", &code[..4]);
+        let mut codes = vec![province];
+        if city != codes[0] {
+            codes.push(city);
+        }
+        if code != codes[codes.len() - 1] {
+            codes.push(code.to_string());
+        }
+        let path: Vec<Region> = codes.iter().filter_map(|code| Self::find(code)).collect();
+        if path.len() == codes.len() {
+            path
+        } else {
+            Vec::new()
+        }
+    }
+}
+
+#[cfg(all(test, feature = "regions"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_china_regions() {
+        let provinces = ChinaRegionsSample.children(None);
+        assert_eq!(provinces.len(), 34);
+        assert_eq!(provinces[0], Region::new("110000", "北京市"));
+
+        let cities = ChinaRegionsSample.children(Some("440000"));
+        assert_eq!(cities.len(), 21);
+        assert_eq!(cities[0].name, "广州市");
+        let districts = ChinaRegionsSample.children(Some("440300"));
+        assert!(districts.iter().any(|region| region.name == "南山区"));
+        assert!(ChinaRegionsSample.children(Some("440305")).is_empty());
+        assert!(ChinaRegionsSample.children(Some("810000")).is_empty());
+        assert!(ChinaRegionsSample.children(Some("44")).is_empty());
+
+        let names = |path: Vec<Region>| path.into_iter().map(|r| r.name).collect::<Vec<_>>();
+        assert_eq!(
+            names(ChinaRegionsSample.path("440305")),
+            ["广东省", "深圳市", "南山区"]
+        );
+        assert_eq!(
+            names(ChinaRegionsSample.path("330100")),
+            ["浙江省", "杭州市"]
+        );
+        assert_eq!(names(ChinaRegionsSample.path("810000")), ["香港特别行政区"]);
+        assert!(ChinaRegionsSample.path("440399").is_empty());
+        assert!(ChinaRegionsSample.path("北京").is_empty());
+        assert!(ChinaRegionsSample.children(Some("北京")).is_empty());
+
+        // 默认的逐级查找与按代码计算的结果一致
+        let source = |parent: Option<&str>| ChinaRegionsSample.children(parent);
+        assert_eq!(source.path("310115"), ChinaRegionsSample.path("310115"));
+    }
+}
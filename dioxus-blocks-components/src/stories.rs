@@ -0,0 +1,520 @@
+//! # 组件用例
+//!
+//! 每个组件在这里登记若干命名的用例（如 `small`、`disabled`、`with-icon`），用于集中预览组件的各种状态，
+//! 以及渲染为 HTML 交给视觉对比工具检查样式回归。
+//!
+//! 用例的渲染函数作为独立的组件渲染，其中可以使用 hooks。[`Story::render_html`] 在服务端渲染单个用例，
+//! 示例 `dump_stories` 把全部用例渲染为 HTML 文件：
+//!
+//! ```sh
+//! cargo run -p dioxus-blocks-components --example dump_stories -- target/stories
+//! ```
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus_blocks_components::stories;
+//!
+//! let story = stories::find("button/disabled").unwrap();
+//! assert_eq!(story.component(), "Button");
+//! assert!(story.render_html().contains("t-button--disabled"));
+//! ```
+
+use dioxus::prelude::*;
+
+use crate::{Text, ToElement, View};
+
+#[cfg(feature = "json_viewer")]
+use crate::JsonViewer;
+#[cfg(feature = "alert")]
+use crate::{Alert, AlertType};
+#[cfg(feature = "button")]
+use crate::{Button, ButtonShape, ButtonSize, ButtonType};
+#[cfg(feature = "card")]
+use crate::{Card, CardShadow};
+#[cfg(feature = "checkbox")]
+use crate::{Checkbox, CheckboxSize};
+#[cfg(feature = "input")]
+use crate::{Input, InputSize};
+#[cfg(feature = "input_number")]
+use crate::{InputNumber, InputNumberSize};
+#[cfg(feature = "log_viewer")]
+use crate::{LogBuffer, LogViewer};
+#[cfg(feature = "radio")]
+use crate::{Radio, RadioGroup, RadioValue};
+#[cfg(feature = "select")]
+use crate::{Select, SelectOption, SelectValue};
+#[cfg(feature = "table")]
+use crate::{Table, TableColumn, TableRow};
+#[cfg(feature = "textarea")]
+use crate::{Textarea, TextareaSize};
+
+/// 组件的一个命名用例
+#[derive(Debug, Clone, Copy)]
+pub struct Story {
+    /// 组件名称
+    component: &'static str,
+    /// 用例名称
+    name: &'static str,
+    /// 渲染函数
+    render: fn() -> Element,
+}
+
+/// 只有渲染函数相同才视为同一个用例
+impl PartialEq for Story {
+    fn eq(&self, other: &Self) -> bool {
+        self.component == other.component
+            && self.name == other.name
+            && std::ptr::fn_addr_eq(self.render, other.render)
+    }
+}
+
+impl Story {
+    /// 创建用例
+    pub const fn new(component: &'static str, name: &'static str, render: fn() -> Element) -> Self {
+        Self {
+            component,
+            name,
+            render,
+        }
+    }
+
+    /// 组件名称，如 `InputNumber`
+    pub fn component(&self) -> &'static str {
+        self.component
+    }
+
+    /// 用例名称，如 `disabled`
+    pub fn name(&self) -> &'static str {
+        self.name
+    }
+
+    /// 用例标识，由短横线形式的组件名称和用例名称组成，如 `input-number/disabled`
+    pub fn id(&self) -> String {
+        let mut id = String::new();
+        for (i, c) in self.component.char_indices() {
+            if c.is_uppercase() && i > 0 {
+                id.push('-');
+            }
+            id.push(c.to_ascii_lowercase());
+        }
+        format!("{id}/{}", self.name)
+    }
+
+    /// 在服务端渲染用例，返回 HTML 片段
+    pub fn render_html(&self) -> String {
+        let mut dom = VirtualDom::new(self.render);
+        dom.rebuild_in_place();
+        dioxus_ssr::render(&dom)
+    }
+}
+
+impl ToElement for Story {
+    fn to_element(&self) -> Element {
+        rsx! {
+            StoryElement { story: *self }
+        }
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct StoryElementProps {
+    story: Story,
+}
+
+#[allow(non_snake_case)]
+fn StoryElement(props: StoryElementProps) -> Element {
+    (props.story.render)()
+}
+
+/// 全部用例，按组件分组排列
+pub fn all() -> Vec<Story> {
+    let groups: &[&[Story]] = &[
+        TEXT,
+        #[cfg(feature = "button")]
+        BUTTON,
+        #[cfg(feature = "card")]
+        CARD,
+        #[cfg(feature = "input")]
+        INPUT,
+        #[cfg(feature = "input_number")]
+        INPUT_NUMBER,
+        #[cfg(feature = "textarea")]
+        TEXTAREA,
+        #[cfg(feature = "radio")]
+        RADIO,
+        #[cfg(feature = "checkbox")]
+        CHECKBOX,
+        #[cfg(feature = "select")]
+        SELECT,
+        #[cfg(feature = "alert")]
+        ALERT,
+        #[cfg(feature = "json_viewer")]
+        JSON_VIEWER,
+        #[cfg(feature = "log_viewer")]
+        LOG_VIEWER,
+        #[cfg(feature = "table")]
+        TABLE,
+    ];
+    groups
+        .iter()
+        .flat_map(|group| group.iter().copied())
+        .collect()
+}
+
+/// 按标识查找用例，见 [`Story::id`]
+pub fn find(id: &str) -> Option<Story> {
+    all().into_iter().find(|story| story.id() == id)
+}
+
+const TEXT: &[Story] = &[
+    Story::new("Text", "headings", || {
+        View::new()
+            .childrens(vec![
+                Text::h1("一级标题"),
+                Text::h2("二级标题"),
+                Text::h3("三级标题"),
+            ])
+            .to_element()
+    }),
+    Story::new("Text", "ellipsis", || {
+        Text::p("这是一段很长的文本，超出两行后会显示省略号。".repeat(6))
+            .ellipsis(2)
+            .style(|s| s.width("240px"))
+            .to_element()
+    }),
+    Story::new("Text", "copyable", || {
+        Text::span("sk-2f9a7c41e0b84d6a")
+            .selectable(true)
+            .copyable(true)
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "button")]
+const BUTTON: &[Story] = &[
+    Story::new("Button", "default", || {
+        Button::new().text("默认按钮").to_element()
+    }),
+    Story::new("Button", "primary", || {
+        Button::new()
+            .text("主要按钮")
+            .btn_type(ButtonType::Primary)
+            .to_element()
+    }),
+    Story::new("Button", "small", || {
+        Button::new()
+            .text("小型按钮")
+            .size(ButtonSize::Small)
+            .to_element()
+    }),
+    Story::new("Button", "large", || {
+        Button::new()
+            .text("大型按钮")
+            .size(ButtonSize::Large)
+            .to_element()
+    }),
+    Story::new("Button", "round", || {
+        Button::new()
+            .text("圆角按钮")
+            .shape(ButtonShape::Round)
+            .to_element()
+    }),
+    Story::new("Button", "disabled", || {
+        Button::new().text("禁用按钮").disabled(true).to_element()
+    }),
+    Story::new("Button", "loading", || {
+        Button::new()
+            .text("加载中")
+            .btn_type(ButtonType::Primary)
+            .loading(true)
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "card")]
+const CARD: &[Story] = &[
+    Story::new("Card", "default", || {
+        Card::new()
+            .header(Text::h3("卡片标题"))
+            .body(Text::p("卡片内容"))
+            .to_element()
+    }),
+    Story::new("Card", "with-footer", || {
+        Card::new()
+            .header(Text::h3("卡片标题"))
+            .body(Text::p("卡片内容"))
+            .footer(Text::p("卡片底部"))
+            .to_element()
+    }),
+    Story::new("Card", "hover-shadow", || {
+        Card::new()
+            .shadow(CardShadow::Hover)
+            .body(Text::p("鼠标悬停时显示阴影"))
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "input")]
+const INPUT: &[Story] = &[
+    Story::new("Input", "default", || {
+        Input::new().placeholder("请输入内容").to_element()
+    }),
+    Story::new("Input", "small", || {
+        Input::new()
+            .placeholder("小型输入框")
+            .size(InputSize::Small)
+            .to_element()
+    }),
+    Story::new("Input", "disabled", || {
+        Input::new()
+            .placeholder("禁用输入框")
+            .disabled(true)
+            .to_element()
+    }),
+    Story::new("Input", "with-icon", || {
+        Input::new()
+            .placeholder("搜索")
+            .prefix_icon("🔍")
+            .to_element()
+    }),
+    Story::new("Input", "word-limit", || {
+        let value = use_signal(|| "dioxus".to_string());
+        Input::new()
+            .value(value)
+            .max_length(20)
+            .show_word_limit(true)
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "input_number")]
+const INPUT_NUMBER: &[Story] = &[
+    Story::new("InputNumber", "default", || InputNumber::new().to_element()),
+    Story::new("InputNumber", "small", || {
+        InputNumber::new().size(InputNumberSize::Small).to_element()
+    }),
+    Story::new("InputNumber", "disabled", || {
+        InputNumber::new().disabled(true).to_element()
+    }),
+];
+
+#[cfg(feature = "textarea")]
+const TEXTAREA: &[Story] = &[
+    Story::new("Textarea", "default", || {
+        Textarea::new().placeholder("请输入内容").to_element()
+    }),
+    Story::new("Textarea", "small", || {
+        Textarea::new()
+            .placeholder("小型文本框")
+            .size(TextareaSize::Small)
+            .to_element()
+    }),
+    Story::new("Textarea", "disabled", || {
+        Textarea::new()
+            .placeholder("禁用文本框")
+            .disabled(true)
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "radio")]
+const RADIO: &[Story] = &[
+    Story::new("Radio", "group", || {
+        let value = use_signal(|| RadioValue::from("a"));
+        RadioGroup::new()
+            .value(value)
+            .radios(vec![
+                Radio::new().label("选项 A").value("a"),
+                Radio::new().label("选项 B").value("b"),
+            ])
+            .to_element()
+    }),
+    Story::new("Radio", "button", || {
+        let value = use_signal(|| RadioValue::from("a"));
+        RadioGroup::new()
+            .value(value)
+            .button(true)
+            .radios(vec![
+                Radio::new().label("选项 A").value("a"),
+                Radio::new().label("选项 B").value("b"),
+            ])
+            .to_element()
+    }),
+    Story::new("Radio", "disabled", || {
+        Radio::new()
+            .label("禁用")
+            .value("a")
+            .disabled(true)
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "checkbox")]
+const CHECKBOX: &[Story] = &[
+    Story::new("Checkbox", "checked", || {
+        let checked = use_signal(|| true);
+        Checkbox::new()
+            .label("已选中")
+            .checked(checked)
+            .to_element()
+    }),
+    Story::new("Checkbox", "small", || {
+        Checkbox::new()
+            .label("小型多选框")
+            .size(CheckboxSize::Small)
+            .to_element()
+    }),
+    Story::new("Checkbox", "indeterminate", || {
+        Checkbox::new()
+            .label("部分选中")
+            .indeterminate(true)
+            .to_element()
+    }),
+    Story::new("Checkbox", "disabled", || {
+        Checkbox::new().label("禁用").disabled(true).to_element()
+    }),
+];
+
+#[cfg(feature = "select")]
+const SELECT: &[Story] = &[
+    Story::new("Select", "placeholder", || {
+        let value = use_signal(|| None::<SelectValue>);
+        Select::new()
+            .value(value)
+            .options(vec![
+                SelectOption::new("a").label("选项 A"),
+                SelectOption::new("b").label("选项 B"),
+            ])
+            .to_element()
+    }),
+    Story::new("Select", "multiple", || {
+        let values = use_signal(|| vec![SelectValue::from("a"), SelectValue::from("b")]);
+        Select::new()
+            .multiple_value(values)
+            .clearable(true)
+            .options(vec![
+                SelectOption::new("a").label("选项 A"),
+                SelectOption::new("b").label("选项 B"),
+                SelectOption::new("c").label("选项 C"),
+            ])
+            .to_element()
+    }),
+    Story::new("Select", "disabled", || {
+        let value = use_signal(|| Some(SelectValue::from("a")));
+        Select::new()
+            .value(value)
+            .disabled(true)
+            .options(vec![SelectOption::new("a").label("选项 A")])
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "alert")]
+const ALERT: &[Story] = &[
+    Story::new("Alert", "success", || {
+        Alert::new("操作成功")
+            .alert_type(AlertType::Success)
+            .to_element()
+    }),
+    Story::new("Alert", "error", || {
+        Alert::new("操作失败")
+            .alert_type(AlertType::Error)
+            .description("请检查网络连接后重试。")
+            .to_element()
+    }),
+    Story::new("Alert", "closable", || {
+        Alert::new("可关闭的提示").closable(true).to_element()
+    }),
+];
+
+#[cfg(feature = "json_viewer")]
+const JSON_VIEWER: &[Story] = &[
+    Story::new("JsonViewer", "default", || {
+        JsonViewer::new(serde_json::json!({
+            "id": 1,
+            "name": "dioxus",
+            "tags": ["rust", "ui"],
+            "owner": null
+        }))
+        .to_element()
+    }),
+    Story::new("JsonViewer", "collapsed", || {
+        JsonViewer::new(serde_json::json!({ "data": { "items": [1, 2, 3] } }))
+            .expand_depth(1)
+            .searchable(false)
+            .to_element()
+    }),
+];
+
+#[cfg(feature = "log_viewer")]
+const LOG_VIEWER: &[Story] = &[Story::new("LogViewer", "default", || {
+    let logs = use_signal(|| {
+        let mut logs = LogBuffer::new(100);
+        logs.extend([
+            "\x1b[32mINFO\x1b[0m server started on :8080",
+            "\x1b[33mWARN\x1b[0m slow query: 1200ms",
+            "\x1b[31mERROR\x1b[0m upstream timeout",
+        ]);
+        logs
+    });
+    LogViewer::new(logs).height(120).to_element()
+})];
+
+#[cfg(feature = "table")]
+const TABLE: &[Story] = &[
+    Story::new("Table", "default", || {
+        Table::new()
+            .column(TableColumn::new("name", "姓名"))
+            .column(TableColumn::new("age", "年龄").width("80px"))
+            .row(TableRow::new("1").cell("name", "张三").cell("age", "28"))
+            .row(TableRow::new("2").cell("name", "李四").cell("age", "32"))
+            .to_element()
+    }),
+    Story::new("Table", "tree", || {
+        Table::new()
+            .column(TableColumn::new("name", "分类"))
+            .row(
+                TableRow::new("digital")
+                    .cell("name", "数码")
+                    .child(TableRow::new("phone").cell("name", "手机")),
+            )
+            .row(TableRow::new("books").cell("name", "图书"))
+            .default_expand_all(true)
+            .to_element()
+    }),
+    Story::new("Table", "empty", || {
+        Table::new()
+            .column(TableColumn::new("name", "姓名"))
+            .to_element()
+    }),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_story_ids_are_unique() {
+        let stories = all();
+        let mut ids = stories.iter().map(Story::id).collect::<Vec<_>>();
+        ids.sort();
+        ids.dedup();
+        assert_eq!(ids.len(), stories.len());
+    }
+
+    #[test]
+    fn test_render_every_story() {
+        for story in all() {
+            assert!(!story.render_html().is_empty(), "{} 渲染为空", story.id());
+        }
+    }
+
+    #[cfg(feature = "input_number")]
+    #[test]
+    fn test_story_id() {
+        let story = find("input-number/disabled").unwrap();
+        assert_eq!(story.component(), "InputNumber");
+        assert_eq!(story.name(), "disabled");
+    }
+}
@@ -7,7 +7,7 @@ use indexmap::IndexMap;
 /// CSS 样式构建器
 ///
 /// 支持链式调用的 CSS 样式构建器，包含伪类支持。
-#[derive(Debug, Default, Clone)]
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
 pub struct Style {
     styles: IndexMap<String, String>,
 }
@@ -77,6 +77,32 @@ impl Style {
         self
     }
 
+    /// 合并样式
+    ///
+    /// 将 `other` 中的样式合并到当前样式之上，同名属性以 `other` 为准，
+    /// 其余属性保持不变。
+    ///
+    /// # 参数
+    ///
+    /// * `other` - 要合并的样式
+    ///
+    /// # 返回值
+    ///
+    /// 返回合并后的样式
+    ///
+    /// # 示例
+    ///
+    /// ```rust
+    /// # use dioxus_blocks_components::Style;
+    /// let base = Style::default().display("flex").gap("8px");
+    /// let style = base.merge(Style::default().gap("16px").color("red"));
+    /// assert_eq!(style.to_string(), "display: flex; gap: 16px; color: red;");
+    /// ```
+    pub fn merge(mut self, other: Style) -> Self {
+        self.styles.extend(other.styles);
+        self
+    }
+
     /// 生成内联样式字符串
     ///
     /// 将样式属性转换为内联样式格式的字符串
@@ -100,6 +126,8 @@ impl Style {
 
     /// 插入样式属性
     pub(crate) fn insert_style(mut self, key: &str, value: String) -> Self {
+        #[cfg(any(debug_assertions, feature = "strict_css"))]
+        super::validate::report(key, &value);
         self.styles.insert(key.to_string(), value);
         self
     }
@@ -183,6 +211,20 @@ mod tests {
         assert!(!result.contains("#"));
     }
 
+    #[test]
+    fn test_merge_keeps_base_rules() {
+        let base = Style::default()
+            .display("flex")
+            .flex_direction("row")
+            .gap("8px");
+        let style = base.merge(Style::default().flex_direction("column").width("100%"));
+
+        assert_eq!(
+            style.to_string(),
+            "display: flex; flex-direction: column; gap: 8px; width: 100%;"
+        );
+    }
+
     #[test]
     fn test_empty_style_returns_empty_string() {
         // 空 style 对象
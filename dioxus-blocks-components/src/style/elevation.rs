@@ -0,0 +1,85 @@
+//! 阴影层级
+//!
+//! 把阴影统一为 0～5 六个层级，层级越高元素看起来离页面越远。每个层级对应一个主题变量
+//! `--t-elevation-N`，亮色和暗色主题分别定义了合适的阴影，切换主题时阴影随之变化。
+//!
+//! 常见用法：卡片使用 [`Elevation::Two`]，下拉菜单、弹出提示使用 [`Elevation::Three`]，
+//! 对话框使用 [`Elevation::Four`] 或更高。
+
+use super::Style;
+
+/// 阴影层级
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub enum Elevation {
+    /// 无阴影
+    #[default]
+    Zero,
+    /// 轻微浮起，如悬停的列表项
+    One,
+    /// 卡片
+    Two,
+    /// 下拉菜单、弹出提示
+    Three,
+    /// 抽屉、对话框
+    Four,
+    /// 最高层级，如全屏遮罩上的内容
+    Five,
+}
+
+impl Elevation {
+    /// 所有层级，按从低到高的顺序排列
+    pub const ALL: [Elevation; 6] = [
+        Elevation::Zero,
+        Elevation::One,
+        Elevation::Two,
+        Elevation::Three,
+        Elevation::Four,
+        Elevation::Five,
+    ];
+
+    /// 层级数值，范围为 0～5
+    pub fn level(&self) -> u8 {
+        *self as u8
+    }
+
+    /// 对应的主题变量名，如 `--t-elevation-2`
+    pub fn token(&self) -> &'static str {
+        match self {
+            Elevation::Zero => "--t-elevation-0",
+            Elevation::One => "--t-elevation-1",
+            Elevation::Two => "--t-elevation-2",
+            Elevation::Three => "--t-elevation-3",
+            Elevation::Four => "--t-elevation-4",
+            Elevation::Five => "--t-elevation-5",
+        }
+    }
+
+    /// 引用主题变量的 CSS 值，如 `var(--t-elevation-2)`，可以直接用作 `box-shadow` 的值
+    pub fn as_var(&self) -> String {
+        format!("var({})", self.token())
+    }
+}
+
+impl Style {
+    /// 阴影层级
+    ///
+    /// 设置 `box-shadow` 为对应层级的主题变量，阴影随亮色、暗色主题变化。
+    ///
+    /// # 参数
+    /// * `elevation` - 阴影层级
+    ///
+    /// # 返回值
+    /// * 返回修改后的样式实例，支持链式调用
+    ///
+    /// # 示例
+    ///
+    /// ```
+    /// # use dioxus_blocks_components::{Elevation, Style};
+    /// let style = Style::default().elevation(Elevation::Two);
+    /// assert_eq!(style.to_string(), "box-shadow: var(--t-elevation-2);");
+    /// ```
+    ///
+    pub fn elevation(self, elevation: Elevation) -> Self {
+        self.insert_style("box-shadow", elevation.as_var())
+    }
+}
@@ -11,13 +11,19 @@
 //! - `background` - 背景样式（background-color、background-image 等）
 //! - `text` - 文本样式（font、color、text-align 等）
 //! - `visual` - 视觉效果样式（opacity、box-shadow、transition 等）
+//! - `elevation` - 阴影层级（Elevation 及对应的主题变量）
+//! - `validate` - 调试构建中的样式值校验
 
 mod background;
 mod border;
 mod builder;
+mod elevation;
 mod layout;
 mod spacing;
 mod text;
+#[cfg(any(debug_assertions, feature = "strict_css"))]
+mod validate;
 mod visual;
 
 pub use builder::Style;
+pub use elevation::Elevation;
@@ -0,0 +1,387 @@
+//! 样式值校验
+//!
+//! 调试构建中检查样式设置方法收到的值，发现明显无效的值（如 `10pxx`、未知的 `display` 关键字）时输出警告，
+//! 避免拼写错误悄无声息地生成无效的内联样式。启用 `strict_css` 特性后警告会改为 panic，便于在测试中发现问题。
+//!
+//! 只校验长度、数值和关键字类属性，包含 `var()`、`calc()` 等函数的值以及简写属性不做校验。
+
+/// 所有属性都接受的全局关键字
+const GLOBAL_KEYWORDS: &[&str] = &["inherit", "initial", "unset", "revert", "revert-layer"];
+
+/// 长度单位
+const LENGTH_UNITS: &[&str] = &[
+    "px", "em", "rem", "%", "vh", "vw", "vmin", "vmax", "dvh", "dvw", "svh", "svw", "lvh", "lvw",
+    "ch", "ex", "pt", "pc", "cm", "mm", "in", "q", "fr",
+];
+
+/// 长度类属性可以使用的关键字
+const LENGTH_KEYWORDS: &[&str] = &[
+    "auto",
+    "none",
+    "normal",
+    "fit-content",
+    "min-content",
+    "max-content",
+];
+
+const FONT_SIZE_KEYWORDS: &[&str] = &[
+    "xx-small",
+    "x-small",
+    "small",
+    "medium",
+    "large",
+    "x-large",
+    "xx-large",
+    "xxx-large",
+    "smaller",
+    "larger",
+];
+
+const DISPLAY: &[&str] = &[
+    "block",
+    "inline",
+    "inline-block",
+    "flex",
+    "inline-flex",
+    "grid",
+    "inline-grid",
+    "flow-root",
+    "none",
+    "contents",
+    "list-item",
+    "table",
+    "inline-table",
+    "table-row",
+    "table-cell",
+    "table-column",
+    "table-caption",
+    "table-row-group",
+    "table-header-group",
+    "table-footer-group",
+    "table-column-group",
+];
+const POSITION: &[&str] = &["static", "relative", "absolute", "fixed", "sticky"];
+const FLEX_DIRECTION: &[&str] = &["row", "row-reverse", "column", "column-reverse"];
+const FLEX_WRAP: &[&str] = &["nowrap", "wrap", "wrap-reverse"];
+const ALIGN_ITEMS: &[&str] = &[
+    "normal",
+    "stretch",
+    "center",
+    "start",
+    "end",
+    "flex-start",
+    "flex-end",
+    "self-start",
+    "self-end",
+    "baseline",
+    "first",
+    "last",
+    "safe",
+    "unsafe",
+];
+const JUSTIFY_CONTENT: &[&str] = &[
+    "normal",
+    "center",
+    "start",
+    "end",
+    "flex-start",
+    "flex-end",
+    "left",
+    "right",
+    "space-between",
+    "space-around",
+    "space-evenly",
+    "stretch",
+    "safe",
+    "unsafe",
+];
+const OVERFLOW: &[&str] = &["visible", "hidden", "clip", "scroll", "auto"];
+const TEXT_ALIGN: &[&str] = &[
+    "left",
+    "right",
+    "center",
+    "justify",
+    "justify-all",
+    "start",
+    "end",
+    "match-parent",
+];
+const TEXT_TRANSFORM: &[&str] = &[
+    "none",
+    "capitalize",
+    "uppercase",
+    "lowercase",
+    "full-width",
+    "full-size-kana",
+];
+const WHITE_SPACE: &[&str] = &[
+    "normal",
+    "nowrap",
+    "pre",
+    "pre-wrap",
+    "pre-line",
+    "break-spaces",
+];
+const VISIBILITY: &[&str] = &["visible", "hidden", "collapse"];
+const POINTER_EVENTS: &[&str] = &[
+    "auto",
+    "none",
+    "visiblepainted",
+    "visiblefill",
+    "visiblestroke",
+    "visible",
+    "painted",
+    "fill",
+    "stroke",
+    "all",
+];
+const USER_SELECT: &[&str] = &["auto", "none", "text", "all", "contain"];
+const CURSOR: &[&str] = &[
+    "auto",
+    "default",
+    "none",
+    "context-menu",
+    "help",
+    "pointer",
+    "progress",
+    "wait",
+    "cell",
+    "crosshair",
+    "text",
+    "vertical-text",
+    "alias",
+    "copy",
+    "move",
+    "no-drop",
+    "not-allowed",
+    "grab",
+    "grabbing",
+    "all-scroll",
+    "col-resize",
+    "row-resize",
+    "n-resize",
+    "e-resize",
+    "s-resize",
+    "w-resize",
+    "ne-resize",
+    "nw-resize",
+    "se-resize",
+    "sw-resize",
+    "ew-resize",
+    "ns-resize",
+    "nesw-resize",
+    "nwse-resize",
+    "zoom-in",
+    "zoom-out",
+];
+const OBJECT_FIT: &[&str] = &["fill", "contain", "cover", "none", "scale-down"];
+const FONT_STYLE: &[&str] = &["normal", "italic", "oblique"];
+const TEXT_OVERFLOW: &[&str] = &["clip", "ellipsis"];
+const BACKGROUND_REPEAT: &[&str] = &[
+    "repeat",
+    "repeat-x",
+    "repeat-y",
+    "no-repeat",
+    "space",
+    "round",
+];
+
+/// 属性值的类型
+enum Kind {
+    /// 一个或多个长度，如 `margin: 0 auto`
+    Length(&'static [&'static str]),
+    /// 长度或无单位数值，如 `line-height`
+    LengthOrNumber,
+    /// 数值或百分比，如 `opacity`
+    Number,
+    /// 整数，如 `z-index`
+    Integer,
+    /// 字重
+    FontWeight,
+    /// flex 简写
+    Flex,
+    /// 每个值都必须是给定的关键字
+    Keywords(&'static [&'static str]),
+}
+
+/// 属性对应的值类型，未知或不校验的属性返回 `None`
+fn kind(property: &str) -> Option<Kind> {
+    let kind = match property {
+        "width"
+        | "height"
+        | "min-width"
+        | "min-height"
+        | "max-width"
+        | "max-height"
+        | "margin"
+        | "margin-top"
+        | "margin-right"
+        | "margin-bottom"
+        | "margin-left"
+        | "padding"
+        | "padding-top"
+        | "padding-right"
+        | "padding-bottom"
+        | "padding-left"
+        | "gap"
+        | "letter-spacing"
+        | "word-spacing"
+        | "text-indent"
+        | "border-radius"
+        | "border-top-left-radius"
+        | "border-top-right-radius"
+        | "border-bottom-left-radius"
+        | "border-bottom-right-radius" => Kind::Length(LENGTH_KEYWORDS),
+        "font-size" => Kind::Length(FONT_SIZE_KEYWORDS),
+        "line-height" => Kind::LengthOrNumber,
+        "opacity" => Kind::Number,
+        "z-index" => Kind::Integer,
+        "font-weight" => Kind::FontWeight,
+        "flex" => Kind::Flex,
+        "display" => Kind::Keywords(DISPLAY),
+        "position" => Kind::Keywords(POSITION),
+        "flex-direction" => Kind::Keywords(FLEX_DIRECTION),
+        "flex-wrap" => Kind::Keywords(FLEX_WRAP),
+        "align-items" => Kind::Keywords(ALIGN_ITEMS),
+        "justify-content" => Kind::Keywords(JUSTIFY_CONTENT),
+        "overflow" => Kind::Keywords(OVERFLOW),
+        "text-align" => Kind::Keywords(TEXT_ALIGN),
+        "text-transform" => Kind::Keywords(TEXT_TRANSFORM),
+        "white-space" => Kind::Keywords(WHITE_SPACE),
+        "visibility" => Kind::Keywords(VISIBILITY),
+        "pointer-events" => Kind::Keywords(POINTER_EVENTS),
+        "user-select" => Kind::Keywords(USER_SELECT),
+        "cursor" => Kind::Keywords(CURSOR),
+        "object-fit" => Kind::Keywords(OBJECT_FIT),
+        "font-style" => Kind::Keywords(FONT_STYLE),
+        "text-overflow" => Kind::Keywords(TEXT_OVERFLOW),
+        "background-repeat" => Kind::Keywords(BACKGROUND_REPEAT),
+        _ => return None,
+    };
+    Some(kind)
+}
+
+/// 拆分数值和单位，如 `-1.5rem` 拆分为 `(-1.5, "rem")`
+fn split_number(token: &str) -> Option<(f64, &str)> {
+    let end = token
+        .char_indices()
+        .find(|&(i, c)| !(c.is_ascii_digit() || c == '.' || (i == 0 && (c == '-' || c == '+'))))
+        .map_or(token.len(), |(i, _)| i);
+    let number = token[..end].parse::<f64>().ok()?;
+    Some((number, &token[end..]))
+}
+
+fn is_length(token: &str, keywords: &[&str]) -> bool {
+    if keywords.contains(&token) {
+        return true;
+    }
+    match split_number(token) {
+        Some((number, "")) => number == 0.0,
+        Some((_, unit)) => LENGTH_UNITS.contains(&unit.to_ascii_lowercase().as_str()),
+        None => false,
+    }
+}
+
+fn is_number(token: &str) -> bool {
+    matches!(split_number(token), Some((_, "")))
+}
+
+/// 校验样式值，无效时返回原因
+pub(crate) fn check(property: &str, value: &str) -> Result<(), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Err("值为空".to_string());
+    }
+    // 函数、字符串和全局关键字不做校验
+    if value.contains(['(', '"', '\'']) || GLOBAL_KEYWORDS.contains(&value) {
+        return Ok(());
+    }
+    let Some(kind) = kind(property) else {
+        return Ok(());
+    };
+
+    let tokens = value.split_whitespace().collect::<Vec<_>>();
+    let invalid = match kind {
+        Kind::Length(keywords) => tokens.iter().find(|t| !is_length(t, keywords)),
+        Kind::LengthOrNumber => tokens
+            .iter()
+            .find(|t| !(is_number(t) || is_length(t, LENGTH_KEYWORDS))),
+        Kind::Number => tokens
+            .iter()
+            .find(|t| !matches!(split_number(t), Some((_, "")) | Some((_, "%")))),
+        Kind::Integer => tokens
+            .iter()
+            .find(|t| !(**t == "auto" || t.parse::<i64>().is_ok())),
+        Kind::FontWeight => tokens.iter().find(|t| {
+            !(["normal", "bold", "bolder", "lighter"].contains(*t)
+                || matches!(split_number(t), Some((n, "")) if (1.0..=1000.0).contains(&n)))
+        }),
+        Kind::Flex => tokens
+            .iter()
+            .find(|t| !(is_number(t) || is_length(t, &["auto", "none", "content"]))),
+        Kind::Keywords(keywords) => tokens
+            .iter()
+            .find(|t| !keywords.contains(&t.to_ascii_lowercase().as_str())),
+    };
+
+    match invalid {
+        Some(token) => Err(format!("无法识别 `{token}`")),
+        None => Ok(()),
+    }
+}
+
+/// 校验样式值，无效时输出警告，启用 `strict_css` 特性时 panic
+pub(crate) fn report(property: &str, value: &str) {
+    if let Err(reason) = check(property, value) {
+        #[cfg(feature = "strict_css")]
+        panic!("无效的样式 `{property}: {value}`：{reason}");
+        #[cfg(not(feature = "strict_css"))]
+        dioxus::logger::tracing::warn!("无效的样式 `{property}: {value}`：{reason}");
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_check_lengths() {
+        assert!(check("width", "100px").is_ok());
+        assert!(check("margin", "0 auto").is_ok());
+        assert!(check("margin-top", "-1.5rem").is_ok());
+        assert!(check("width", "calc(100% - 10px)").is_ok());
+        assert!(check("padding", "var(--t-padding)").is_ok());
+        assert!(check("font-size", "small").is_ok());
+
+        assert!(check("width", "10pxx").is_err());
+        assert!(check("height", "100").is_err());
+        assert!(check("gap", "").is_err());
+    }
+
+    #[test]
+    fn test_check_keywords_and_numbers() {
+        assert!(check("display", "flex").is_ok());
+        assert!(check("display", "inherit").is_ok());
+        assert!(check("align-items", "first baseline").is_ok());
+        assert!(check("overflow", "hidden auto").is_ok());
+        assert!(check("line-height", "1.5").is_ok());
+        assert!(check("opacity", "50%").is_ok());
+        assert!(check("z-index", "10").is_ok());
+        assert!(check("font-weight", "600").is_ok());
+        assert!(check("flex", "1 1 0%").is_ok());
+        // 未校验的属性
+        assert!(check("border", "1px solid red").is_ok());
+
+        assert!(check("display", "flexx").is_err());
+        assert!(check("position", "absolut").is_err());
+        assert!(check("z-index", "1.5").is_err());
+        assert!(check("font-weight", "heavy").is_err());
+    }
+
+    #[cfg(feature = "strict_css")]
+    #[test]
+    #[should_panic(expected = "无效的样式 `width: 10pxx`")]
+    fn test_strict_css_panics() {
+        let _ = crate::Style::default().width("10pxx");
+    }
+}
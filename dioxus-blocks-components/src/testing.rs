@@ -0,0 +1,800 @@
+//! 交互测试工具
+//!
+//! [`Harness`] 包装 [`VirtualDom`]，在内存中维护一棵与真实 DOM 对应的节点树，
+//! 可以按类名或属性查找元素、模拟点击、输入、键盘等事件并刷新调度器，
+//! 从而对组件的交互行为进行单元测试，而不只是检查 SSR 输出的字符串。
+//!
+//! 需要启用 `testing` 特性：
+//!
+//! ```toml
+//! [dev-dependencies]
+//! dioxus-blocks-components = { version = "0.1", features = ["testing"] }
+//! ```
+//!
+//! # 示例
+//!
+//! ```rust
+//! use dioxus::prelude::*;
+//! use dioxus_blocks_components::{Checkbox, ToElement, classnames, testing::Harness};
+//!
+//! fn app() -> Element {
+//!     let checked = use_signal(|| false);
+//!     Checkbox::new().label("同意").checked(checked).to_element()
+//! }
+//!
+//! let mut harness = Harness::new(app);
+//! let checkbox = harness.find_by_class(classnames::CHECKBOX).unwrap();
+//! assert!(!checkbox.has_class("is-checked"));
+//!
+//! let input = harness.find_by_attr("type", "checkbox").unwrap();
+//! harness.click(&input);
+//!
+//! let checkbox = harness.find_by_class(classnames::CHECKBOX).unwrap();
+//! assert!(checkbox.has_class("is-checked"));
+//! ```
+
+use std::collections::HashMap;
+use std::rc::Rc;
+use std::sync::Once;
+
+use dioxus::core::{
+    AttributeValue, ElementId, Event, Template, TemplateAttribute, TemplateNode, WriteMutations,
+};
+use dioxus::prelude::*;
+use dioxus_html::geometry::PixelsSize;
+use dioxus_html::{
+    ClipboardData, Code, FileData, HtmlEventConverter, Location, Modifiers, PlatformEventData,
+    SerializedDataTransfer, SerializedDragData, SerializedFileData, SerializedFormData,
+    SerializedFormObject, SerializedHtmlEventConverter, SerializedKeyboardData,
+    SerializedMouseData, SerializedPointInteraction, SerializedResizeData, set_event_converter,
+};
+
+use crate::ToElement;
+use crate::clipboard::PastedFiles;
+
+/// 模拟选择或拖放的文件
+#[derive(Debug, Clone)]
+pub struct MockFile {
+    name: String,
+    size: u64,
+    content_type: Option<String>,
+}
+
+impl MockFile {
+    /// 创建指定文件名和字节数的文件
+    pub fn new(name: impl Into<String>, size: u64) -> Self {
+        Self {
+            name: name.into(),
+            size,
+            content_type: None,
+        }
+    }
+
+    /// 设置文件的 MIME 类型，如 `image/png`
+    pub fn content_type(mut self, content_type: impl Into<String>) -> Self {
+        self.content_type = Some(content_type.into());
+        self
+    }
+
+    fn into_serialized(self) -> SerializedFileData {
+        SerializedFileData {
+            path: self.name.into(),
+            size: self.size,
+            last_modified: 0,
+            content_type: self.content_type,
+            contents: None,
+        }
+    }
+}
+
+/// 在序列化事件的基础上支持携带文件的粘贴事件
+struct TestEventConverter;
+
+/// 除粘贴事件外都交给 [`SerializedHtmlEventConverter`] 转换
+macro_rules! delegate_converter {
+    ($($method:ident -> $data:ident),* $(,)?) => {
+        $(
+            fn $method(&self, event: &PlatformEventData) -> dioxus_html::$data {
+                SerializedHtmlEventConverter.$method(event)
+            }
+        )*
+    };
+}
+
+impl HtmlEventConverter for TestEventConverter {
+    fn convert_clipboard_data(&self, event: &PlatformEventData) -> ClipboardData {
+        match event.downcast::<PastedFiles>() {
+            Some(files) => ClipboardData::new(files.clone()),
+            None => SerializedHtmlEventConverter.convert_clipboard_data(event),
+        }
+    }
+
+    delegate_converter! {
+        convert_animation_data -> AnimationData,
+        convert_cancel_data -> CancelData,
+        convert_composition_data -> CompositionData,
+        convert_drag_data -> DragData,
+        convert_focus_data -> FocusData,
+        convert_form_data -> FormData,
+        convert_image_data -> ImageData,
+        convert_keyboard_data -> KeyboardData,
+        convert_media_data -> MediaData,
+        convert_mounted_data -> MountedData,
+        convert_mouse_data -> MouseData,
+        convert_pointer_data -> PointerData,
+        convert_resize_data -> ResizeData,
+        convert_scroll_data -> ScrollData,
+        convert_selection_data -> SelectionData,
+        convert_toggle_data -> ToggleData,
+        convert_touch_data -> TouchData,
+        convert_transition_data -> TransitionData,
+        convert_visible_data -> VisibleData,
+        convert_wheel_data -> WheelData,
+    }
+}
+
+/// 设置测试使用的事件转换器，事件转换器是全局的，测试中需要转换事件时都应通过它设置
+pub(crate) fn install_event_converter() {
+    static CONVERTER: Once = Once::new();
+    CONVERTER.call_once(|| set_event_converter(Box::new(TestEventConverter)));
+}
+
+/// 刷新调度器时处理的轮数，防止组件反复触发更新导致死循环
+const FLUSH_ROUNDS: usize = 8;
+
+/// 节点类型
+#[derive(Debug, Clone)]
+enum NodeKind {
+    /// 元素
+    Element {
+        tag: &'static str,
+        attrs: Vec<(&'static str, String)>,
+        listeners: Vec<&'static str>,
+    },
+    /// 文本
+    Text(String),
+    /// 占位符
+    Placeholder,
+}
+
+/// 节点树中的节点
+#[derive(Debug, Clone)]
+struct Node {
+    kind: NodeKind,
+    id: Option<ElementId>,
+    parent: Option<usize>,
+    children: Vec<usize>,
+}
+
+/// 根据 VirtualDom 产生的变更维护的节点树
+#[derive(Debug)]
+struct Tree {
+    nodes: Vec<Node>,
+    ids: HashMap<ElementId, usize>,
+    stack: Vec<usize>,
+}
+
+impl Default for Tree {
+    fn default() -> Self {
+        let root = Node {
+            kind: NodeKind::Element {
+                tag: "div",
+                attrs: Vec::new(),
+                listeners: Vec::new(),
+            },
+            id: Some(ElementId(0)),
+            parent: None,
+            children: Vec::new(),
+        };
+        Self {
+            nodes: vec![root],
+            ids: HashMap::from([(ElementId(0), 0)]),
+            stack: Vec::new(),
+        }
+    }
+}
+
+impl Tree {
+    fn push_node(&mut self, kind: NodeKind) -> usize {
+        self.nodes.push(Node {
+            kind,
+            id: None,
+            parent: None,
+            children: Vec::new(),
+        });
+        self.nodes.len() - 1
+    }
+
+    fn assign(&mut self, index: usize, id: ElementId) {
+        self.nodes[index].id = Some(id);
+        self.ids.insert(id, index);
+    }
+
+    fn node(&self, id: ElementId) -> usize {
+        *self
+            .ids
+            .get(&id)
+            .unwrap_or_else(|| panic!("unknown element id {id:?}"))
+    }
+
+    fn clone_template(&mut self, node: &TemplateNode) -> usize {
+        match node {
+            TemplateNode::Element {
+                tag,
+                attrs,
+                children,
+                ..
+            } => {
+                let attrs = attrs
+                    .iter()
+                    .filter_map(|attr| match attr {
+                        TemplateAttribute::Static { name, value, .. } => {
+                            Some((*name, value.to_string()))
+                        }
+                        TemplateAttribute::Dynamic { .. } => None,
+                    })
+                    .collect();
+                let index = self.push_node(NodeKind::Element {
+                    tag,
+                    attrs,
+                    listeners: Vec::new(),
+                });
+                for child in children.iter() {
+                    let child = self.clone_template(child);
+                    self.nodes[child].parent = Some(index);
+                    self.nodes[index].children.push(child);
+                }
+                index
+            }
+            TemplateNode::Text { text } => self.push_node(NodeKind::Text(text.to_string())),
+            TemplateNode::Dynamic { .. } => self.push_node(NodeKind::Placeholder),
+        }
+    }
+
+    fn pop(&mut self, m: usize) -> Vec<usize> {
+        let at = self.stack.len() - m;
+        self.stack.split_off(at)
+    }
+
+    fn walk(&self, path: &[u8]) -> usize {
+        let mut index = *self.stack.last().expect("empty stack");
+        for &child in path {
+            index = self.nodes[index].children[child as usize];
+        }
+        index
+    }
+
+    fn detach(&mut self, index: usize) {
+        if let Some(parent) = self.nodes[index].parent.take() {
+            self.nodes[parent].children.retain(|&child| child != index);
+        }
+    }
+
+    /// 用 `nodes` 替换 `target` 在父节点中的位置，`offset` 为 0 时插入到前面，为 1 时插入到后面
+    fn insert_at(&mut self, target: usize, nodes: Vec<usize>, offset: usize) {
+        let Some(parent) = self.nodes[target].parent else {
+            return;
+        };
+        for &node in &nodes {
+            self.detach(node);
+            self.nodes[node].parent = Some(parent);
+        }
+        let position = self.nodes[parent]
+            .children
+            .iter()
+            .position(|&child| child == target)
+            .unwrap_or_default();
+        self.nodes[parent]
+            .children
+            .splice(position + offset..position + offset, nodes);
+    }
+
+    fn replace(&mut self, target: usize, nodes: Vec<usize>) {
+        self.insert_at(target, nodes, 0);
+        self.detach(target);
+    }
+
+    fn text(&self, index: usize) -> String {
+        match &self.nodes[index].kind {
+            NodeKind::Text(text) => text.clone(),
+            NodeKind::Element { .. } => self.nodes[index]
+                .children
+                .iter()
+                .map(|&child| self.text(child))
+                .collect(),
+            NodeKind::Placeholder => String::new(),
+        }
+    }
+
+    /// 按文档顺序遍历根节点下的所有元素
+    fn descendants(&self) -> Vec<usize> {
+        let mut result = Vec::new();
+        let mut stack = self.nodes[0]
+            .children
+            .iter()
+            .rev()
+            .copied()
+            .collect::<Vec<_>>();
+        while let Some(index) = stack.pop() {
+            if matches!(self.nodes[index].kind, NodeKind::Element { .. }) {
+                result.push(index);
+            }
+            stack.extend(self.nodes[index].children.iter().rev().copied());
+        }
+        result
+    }
+}
+
+/// 属性值转为字符串，`None` 表示属性被移除
+fn attribute_to_string(value: &AttributeValue) -> Option<String> {
+    match value {
+        AttributeValue::Text(text) => Some(text.clone()),
+        AttributeValue::Float(value) => Some(value.to_string()),
+        AttributeValue::Int(value) => Some(value.to_string()),
+        AttributeValue::Bool(value) => Some(value.to_string()),
+        AttributeValue::Listener(_) | AttributeValue::Any(_) | AttributeValue::None => None,
+    }
+}
+
+impl WriteMutations for Tree {
+    fn append_children(&mut self, id: ElementId, m: usize) {
+        let parent = self.node(id);
+        for child in self.pop(m) {
+            self.detach(child);
+            self.nodes[child].parent = Some(parent);
+            self.nodes[parent].children.push(child);
+        }
+    }
+
+    fn assign_node_id(&mut self, path: &'static [u8], id: ElementId) {
+        let index = self.walk(path);
+        self.assign(index, id);
+    }
+
+    fn create_placeholder(&mut self, id: ElementId) {
+        let index = self.push_node(NodeKind::Placeholder);
+        self.assign(index, id);
+        self.stack.push(index);
+    }
+
+    fn create_text_node(&mut self, value: &str, id: ElementId) {
+        let index = self.push_node(NodeKind::Text(value.to_string()));
+        self.assign(index, id);
+        self.stack.push(index);
+    }
+
+    fn load_template(&mut self, template: Template, index: usize, id: ElementId) {
+        let index = self.clone_template(&template.roots[index]);
+        self.assign(index, id);
+        self.stack.push(index);
+    }
+
+    fn replace_node_with(&mut self, id: ElementId, m: usize) {
+        let target = self.node(id);
+        let nodes = self.pop(m);
+        self.replace(target, nodes);
+    }
+
+    fn replace_placeholder_with_nodes(&mut self, path: &'static [u8], m: usize) {
+        let nodes = self.pop(m);
+        let target = self.walk(path);
+        self.replace(target, nodes);
+    }
+
+    fn insert_nodes_after(&mut self, id: ElementId, m: usize) {
+        let target = self.node(id);
+        let nodes = self.pop(m);
+        self.insert_at(target, nodes, 1);
+    }
+
+    fn insert_nodes_before(&mut self, id: ElementId, m: usize) {
+        let target = self.node(id);
+        let nodes = self.pop(m);
+        self.insert_at(target, nodes, 0);
+    }
+
+    fn set_attribute(
+        &mut self,
+        name: &'static str,
+        _ns: Option<&'static str>,
+        value: &AttributeValue,
+        id: ElementId,
+    ) {
+        let index = self.node(id);
+        if let NodeKind::Element { attrs, .. } = &mut self.nodes[index].kind {
+            attrs.retain(|(attr, _)| *attr != name);
+            if let Some(value) = attribute_to_string(value) {
+                attrs.push((name, value));
+            }
+        }
+    }
+
+    fn set_node_text(&mut self, value: &str, id: ElementId) {
+        let index = self.node(id);
+        self.nodes[index].kind = NodeKind::Text(value.to_string());
+    }
+
+    fn create_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let index = self.node(id);
+        if let NodeKind::Element { listeners, .. } = &mut self.nodes[index].kind {
+            listeners.push(name);
+        }
+    }
+
+    fn remove_event_listener(&mut self, name: &'static str, id: ElementId) {
+        let index = self.node(id);
+        if let NodeKind::Element { listeners, .. } = &mut self.nodes[index].kind {
+            listeners.retain(|listener| *listener != name);
+        }
+    }
+
+    fn remove_node(&mut self, id: ElementId) {
+        let index = self.node(id);
+        self.detach(index);
+    }
+
+    fn push_root(&mut self, id: ElementId) {
+        let index = self.node(id);
+        self.stack.push(index);
+    }
+}
+
+/// 查找到的元素
+///
+/// 元素是查找时的快照，触发事件后需要重新查找才能拿到最新的属性和文本。
+#[derive(Debug, Clone, PartialEq)]
+pub struct ElementRef {
+    /// 节点在节点树中的位置
+    index: usize,
+    /// 标签名
+    tag: String,
+    /// 属性列表
+    attrs: Vec<(String, String)>,
+    /// 文本内容
+    text: String,
+}
+
+impl ElementRef {
+    /// 标签名
+    pub fn tag(&self) -> &str {
+        &self.tag
+    }
+
+    /// 属性值
+    pub fn attr(&self, name: &str) -> Option<&str> {
+        self.attrs
+            .iter()
+            .rev()
+            .find(|(attr, _)| attr == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    /// 类名列表
+    pub fn classes(&self) -> Vec<&str> {
+        self.attr("class")
+            .map(|class| class.split_whitespace().collect())
+            .unwrap_or_default()
+    }
+
+    /// 是否包含指定类名
+    pub fn has_class(&self, class: &str) -> bool {
+        self.classes().contains(&class)
+    }
+
+    /// 文本内容，包含所有子节点的文本
+    pub fn text(&self) -> &str {
+        &self.text
+    }
+}
+
+/// 交互测试工具
+pub struct Harness {
+    dom: VirtualDom,
+    tree: Tree,
+}
+
+impl std::fmt::Debug for Harness {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Harness")
+            .field("nodes", &self.tree.nodes.len())
+            .finish()
+    }
+}
+
+impl Harness {
+    /// 使用根组件创建测试工具，会立即完成首次渲染
+    pub fn new(app: fn() -> Element) -> Self {
+        Self::from_dom(VirtualDom::new(app))
+    }
+
+    /// 使用组件创建测试工具，组件作为根组件渲染
+    pub fn with_element<T>(component: T) -> Self
+    where
+        T: ToElement + 'static,
+    {
+        let component: Rc<dyn ToElement> = Rc::new(component);
+        Self::from_dom(VirtualDom::new_with_props(
+            |component: Rc<dyn ToElement>| component.to_element(),
+            component,
+        ))
+    }
+
+    fn from_dom(mut dom: VirtualDom) -> Self {
+        install_event_converter();
+
+        let mut tree = Tree::default();
+        dom.rebuild(&mut tree);
+        let mut harness = Self { dom, tree };
+        harness.flush();
+        harness
+    }
+
+    /// 当前渲染结果的 HTML
+    pub fn html(&self) -> String {
+        dioxus_ssr::render(&self.dom)
+    }
+
+    /// 在 VirtualDom 的运行时中执行闭包，可用于读写组件外部持有的 Signal
+    pub fn in_runtime<O>(&self, f: impl FnOnce() -> O) -> O {
+        self.dom.in_runtime(f)
+    }
+
+    /// 处理所有待执行的任务和更新，并把变更应用到节点树
+    ///
+    /// 任务执行后可能再次触发更新，因此会重复处理若干轮，没有待处理的工作时每一轮都不会产生变更。
+    pub fn flush(&mut self) {
+        for _ in 0..FLUSH_ROUNDS {
+            self.dom.process_events();
+            self.dom.render_immediate(&mut self.tree);
+        }
+    }
+
+    fn element(&self, index: usize) -> ElementRef {
+        let NodeKind::Element { tag, attrs, .. } = &self.tree.nodes[index].kind else {
+            unreachable!("only elements are returned by queries");
+        };
+        ElementRef {
+            index,
+            tag: tag.to_string(),
+            attrs: attrs
+                .iter()
+                .map(|(name, value)| (name.to_string(), value.clone()))
+                .collect(),
+            text: self.tree.text(index),
+        }
+    }
+
+    /// 按条件查找所有元素，按文档顺序排列
+    pub fn find_all(&self, predicate: impl Fn(&ElementRef) -> bool) -> Vec<ElementRef> {
+        self.tree
+            .descendants()
+            .into_iter()
+            .map(|index| self.element(index))
+            .filter(|element| predicate(element))
+            .collect()
+    }
+
+    /// 查找第一个包含指定类名的元素
+    pub fn find_by_class(&self, class: &str) -> Option<ElementRef> {
+        self.find_all_by_class(class).into_iter().next()
+    }
+
+    /// 查找所有包含指定类名的元素
+    pub fn find_all_by_class(&self, class: &str) -> Vec<ElementRef> {
+        self.find_all(|element| element.has_class(class))
+    }
+
+    /// 查找第一个属性值等于指定值的元素
+    pub fn find_by_attr(&self, name: &str, value: &str) -> Option<ElementRef> {
+        self.find_all(|element| element.attr(name) == Some(value))
+            .into_iter()
+            .next()
+    }
+
+    /// 查找文本内容等于指定文本的元素，有多个时返回最内层的元素
+    pub fn find_by_text(&self, text: &str) -> Option<ElementRef> {
+        self.find_all(|element| element.text() == text)
+            .into_iter()
+            .last()
+    }
+
+    /// 向元素派发事件并刷新调度器
+    ///
+    /// 事件从元素自身（或最近的带有节点 ID 的祖先元素）开始派发，`bubbles` 为 true 时会向上冒泡。
+    pub fn dispatch(
+        &mut self,
+        element: &ElementRef,
+        name: &str,
+        data: PlatformEventData,
+        bubbles: bool,
+    ) {
+        let mut index = Some(element.index);
+        let id = loop {
+            match index {
+                Some(i) => match self.tree.nodes[i].id {
+                    Some(id) => break id,
+                    None => index = self.tree.nodes[i].parent,
+                },
+                None => panic!("element is not mounted"),
+            }
+        };
+
+        let event = Event::new(Rc::new(data) as Rc<dyn std::any::Any>, bubbles);
+        self.dom.runtime().handle_event(name, event, id);
+        self.flush();
+    }
+
+    /// 模拟鼠标点击
+    pub fn click(&mut self, element: &ElementRef) {
+        self.mouse(element, "click");
+    }
+
+    /// 模拟鼠标事件，如 `mouseenter`、`mousedown`
+    pub fn mouse(&mut self, element: &ElementRef, name: &str) {
+        let bubbles = !matches!(name, "mouseenter" | "mouseleave");
+        let data = PlatformEventData::new(Box::new(SerializedMouseData::default()));
+        self.dispatch(element, name, data, bubbles);
+    }
+
+    /// 模拟拖放事件，如 `dragstart`、`dragover`、`drop`、`dragend`
+    pub fn drag(&mut self, element: &ElementRef, name: &str) {
+        self.drag_files(element, name, Vec::new());
+    }
+
+    /// 模拟携带文件的拖放事件，如将文件拖放到上传区域时的 `drop`
+    pub fn drag_files(&mut self, element: &ElementRef, name: &str, files: Vec<MockFile>) {
+        let data = PlatformEventData::new(Box::new(SerializedDragData {
+            mouse: SerializedPointInteraction::default(),
+            data_transfer: SerializedDataTransfer {
+                items: Vec::new(),
+                files: files.into_iter().map(MockFile::into_serialized).collect(),
+                effect_allowed: "all".to_string(),
+                drop_effect: "none".to_string(),
+            },
+        }));
+        self.dispatch(element, name, data, true);
+    }
+
+    /// 模拟粘贴剪贴板中的文件，触发 `paste` 事件
+    pub fn paste_files(&mut self, element: &ElementRef, files: Vec<MockFile>) {
+        let files = files
+            .into_iter()
+            .map(|file| FileData::new(file.into_serialized()))
+            .collect();
+        let data = PlatformEventData::new(Box::new(PastedFiles(files)));
+        self.dispatch(element, "paste", data, true);
+    }
+
+    /// 模拟在文件输入框中选择文件，触发 `change` 事件
+    pub fn select_files(&mut self, element: &ElementRef, files: Vec<MockFile>) {
+        let values = files
+            .into_iter()
+            .map(|file| SerializedFormObject {
+                key: "files".to_string(),
+                text: None,
+                file: Some(file.into_serialized()),
+            })
+            .collect();
+        let data = PlatformEventData::new(Box::new(SerializedFormData::new(String::new(), values)));
+        self.dispatch(element, "change", data, true);
+    }
+
+    /// 模拟输入，触发 `input` 事件
+    pub fn input(&mut self, element: &ElementRef, value: &str) {
+        self.form(element, "input", value);
+    }
+
+    /// 模拟修改，触发 `change` 事件
+    pub fn change(&mut self, element: &ElementRef, value: &str) {
+        self.form(element, "change", value);
+    }
+
+    fn form(&mut self, element: &ElementRef, name: &str, value: &str) {
+        let data = PlatformEventData::new(Box::new(SerializedFormData::new(
+            value.to_string(),
+            Vec::new(),
+        )));
+        self.dispatch(element, name, data, true);
+    }
+
+    /// 模拟按键，触发 `keydown` 事件，`key` 为按键名称，如 `Enter`、`ArrowDown`、`a`
+    pub fn keydown(&mut self, element: &ElementRef, key: &str) {
+        let key = key
+            .parse::<Key>()
+            .unwrap_or(Key::Character(key.to_string()));
+        let data = PlatformEventData::new(Box::new(SerializedKeyboardData::new(
+            key,
+            Code::Unidentified,
+            Location::Standard,
+            false,
+            Modifiers::empty(),
+            false,
+        )));
+        self.dispatch(element, "keydown", data, true);
+    }
+
+    /// 模拟获得焦点
+    pub fn focus(&mut self, element: &ElementRef) {
+        self.focus_event(element, "focus");
+    }
+
+    /// 模拟失去焦点
+    pub fn blur(&mut self, element: &ElementRef) {
+        self.focus_event(element, "blur");
+    }
+
+    fn focus_event(&mut self, element: &ElementRef, name: &str) {
+        let data = PlatformEventData::new(Box::new(dioxus_html::SerializedFocusData::default()));
+        self.dispatch(element, name, data, false);
+    }
+
+    /// 模拟元素尺寸变化，触发 `resize` 事件，`width` 和 `height` 为边框盒的尺寸
+    pub fn resize(&mut self, element: &ElementRef, width: f64, height: f64) {
+        let size = PixelsSize::new(width, height);
+        let data = PlatformEventData::new(Box::new(SerializedResizeData::new(size, size)));
+        self.dispatch(element, "resize", data, false);
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_conditional_rendering() {
+        fn app() -> Element {
+            let mut open = use_signal(|| false);
+            rsx! {
+                button { class: "toggle", onclick: move |_| open.toggle(), "切换" }
+                if open() {
+                    div { class: "panel", "内容" }
+                }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_class("panel").is_none());
+
+        let toggle = harness.find_by_class("toggle").unwrap();
+        harness.click(&toggle);
+        assert_eq!(harness.find_by_class("panel").unwrap().text(), "内容");
+
+        harness.click(&toggle);
+        assert!(harness.find_by_class("panel").is_none());
+    }
+
+    #[test]
+    fn test_keyed_list_and_keydown() {
+        fn app() -> Element {
+            let mut items = use_signal(|| vec!["b".to_string()]);
+            rsx! {
+                input {
+                    class: "editor",
+                    onkeydown: move |event: KeyboardEvent| match event.key() {
+                        Key::Enter => items.write().push("c".to_string()),
+                        Key::Character(c) => items.write().insert(0, c),
+                        _ => {}
+                    },
+                }
+                ul {
+                    for item in items() {
+                        li { key: "{item}", class: "item", "{item}" }
+                    }
+                }
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        let editor = harness.find_by_class("editor").unwrap();
+        harness.keydown(&editor, "a");
+        harness.keydown(&editor, "Enter");
+
+        let items = harness
+            .find_all_by_class("item")
+            .iter()
+            .map(|item| item.text().to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(items, vec!["a", "b", "c"]);
+        assert!(harness.html().contains("<li class=\"item\">a</li>"));
+    }
+}
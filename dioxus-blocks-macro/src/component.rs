@@ -3,7 +3,7 @@
 //! 提供用于实现组件基础功能的宏，包括 `ComponentBase` 派生宏的实现。
 use proc_macro::TokenStream;
 use quote::quote;
-use syn::{parse_macro_input, DeriveInput};
+use syn::{parse_macro_input, Data, DeriveInput, Fields, Index, Member};
 
 /// 为结构体自动实现 ComponentBase 的宏
 ///
@@ -12,10 +12,47 @@ use syn::{parse_macro_input, DeriveInput};
 pub fn impl_component_base(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
     let name = input.ident;
+    let (impl_generics, ty_generics, where_clause) = input.generics.split_for_impl();
+    // 转换为 Element 需要组件实现 ToElement，泛型组件的实现可能带有额外的约束
+    let mut element_generics = input.generics.clone();
+    element_generics
+        .make_where_clause()
+        .predicates
+        .push(syn::parse_quote!(#name #ty_generics: ::dioxus_blocks_components::ToElement));
+    let element_where_clause = &element_generics.where_clause;
+
+    // 逐字段比较，字段类型决定比较方式，见 `dioxus_blocks_components::__private::Probe`
+    let members: Vec<(Member, syn::Type)> = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => fields
+                .named
+                .iter()
+                .map(|f| (Member::Named(f.ident.clone().unwrap()), f.ty.clone()))
+                .collect(),
+            Fields::Unnamed(fields) => fields
+                .unnamed
+                .iter()
+                .enumerate()
+                .map(|(i, f)| (Member::Unnamed(Index::from(i)), f.ty.clone()))
+                .collect(),
+            Fields::Unit => Vec::new(),
+        },
+        _ => {
+            return syn::Error::new_spanned(&name, "ComponentBase 只能用于结构体")
+                .to_compile_error()
+                .into()
+        }
+    };
+    let comparisons = members.iter().map(|(member, ty)| {
+        quote! {
+            (&&&::dioxus_blocks_components::__private::Probe::<#ty>::new())
+                .props_eq(&self.#member, &other.#member)
+        }
+    });
 
     // Generate the implementation
     let expanded = quote! {
-        impl #name {
+        impl #impl_generics #name #ty_generics #where_clause {
             /// 设置组件的 ID
             ///
             /// # 参数
@@ -55,19 +92,22 @@ pub fn impl_component_base(input: TokenStream) -> TokenStream {
             /// ```
             pub fn class<T: ToString>(mut self, class: T) -> Self {
                 let default_class = Self::default().class.clone();
-                self.class = format!("{} {}", default_class, class.to_string());
+                self.class = format!("{} {}", default_class, class.to_string()).into();
                 self
             }
 
             /// 使用闭包设置样式
             ///
+            /// 闭包构建的样式会合并到组件已有的样式（包括组件默认样式）之上，
+            /// 同名属性以新设置的值为准，其余属性保持不变。
+            ///
             /// # 参数
             ///
-            /// * `f` - 一个闭包，接受样式构建器并返回样式字符串
+            /// * `f` - 一个闭包，接受样式构建器并返回样式
             ///
             /// # 返回值
             ///
-            /// 返回修改后的文本实例，支持链式调用
+            /// 返回修改后的组件实例，支持链式调用
             ///
             /// # 示例
             ///
@@ -79,8 +119,41 @@ pub fn impl_component_base(input: TokenStream) -> TokenStream {
             where
                 F: FnOnce(Style) -> Style,
             {
-                let style = self.style.unwrap_or_default();
-                self.style = Some(f(style));
+                let base = self
+                    .style
+                    .take()
+                    .or_else(|| Self::default().style)
+                    .unwrap_or_default();
+                self.style = Some(base.merge(f(Style::default())));
+                self
+            }
+
+            /// 使用闭包替换样式
+            ///
+            /// 与 [`style`](Self::style) 不同，此方法会丢弃组件已有的样式（包括组件默认样式），
+            /// 完全使用闭包构建的样式。
+            ///
+            /// # 参数
+            ///
+            /// * `f` - 一个闭包，接受空的样式构建器并返回样式
+            ///
+            /// # 返回值
+            ///
+            /// 返回修改后的组件实例，支持链式调用
+            ///
+            /// # 示例
+            ///
+            /// ```rust
+            /// # use dioxus_blocks_components::{Text, Style};
+            /// Text::new("Hello")
+            ///     .style(|s| s.color("red"))
+            ///     .style_override(|s| s.font_size("16px"));
+            /// ```
+            pub fn style_override<F>(mut self, f: F) -> Self
+            where
+                F: FnOnce(Style) -> Style,
+            {
+                self.style = Some(f(Style::default()));
                 self
             }
 
@@ -261,12 +334,50 @@ pub fn impl_component_base(input: TokenStream) -> TokenStream {
                 self.onclick = Some(handler);
                 self
             }
+
+            /// 以带属性比较的独立组件渲染
+            ///
+            /// 组件会作为 Dioxus 组件的属性渲染，父组件重新渲染时，只有组件与上一次渲染时不相等才会重新调用
+            /// `to_element`。组件内部的 hooks 也因此与父组件相互隔离。
+            ///
+            /// # 返回值
+            ///
+            /// 返回一个新的 Element 实例
+            ///
+            /// # 示例
+            ///
+            /// ```rust
+            /// # use dioxus::prelude::*;
+            /// # use dioxus::core::Mutations;
+            /// # use dioxus_blocks_components::Text;
+            /// # let mut dom = VirtualDom::new(|| {
+            ///     Text::new("Hello").memoized_element()
+            /// # });
+            /// # let mut mutations = Mutations::default();
+            /// # dom.rebuild(&mut mutations);
+            /// ```
+            pub fn memoized_element(&self) -> Element
+            where
+                Self: ::dioxus_blocks_components::ToElement + Clone + 'static,
+            {
+                ::dioxus_blocks_components::__private::memoized(self.clone())
+            }
         }
 
+        /// 逐字段比较组件
+        ///
+        /// 实现了 `PartialEq` 的字段按值比较，`Rc` 包装的字段（如子组件）按指针比较，其余字段视为不相等。
+        impl #impl_generics PartialEq for #name #ty_generics #where_clause {
+            #[allow(unused_imports)]
+            fn eq(&self, other: &Self) -> bool {
+                use ::dioxus_blocks_components::__private::{ByPointer, ByValue, Fallback};
+                true #(&& #comparisons)*
+            }
+        }
 
         /// 将组件转换为 Element
-        impl From<#name> for Element {
-            fn from(component: #name) -> Self {
+        impl #impl_generics From<#name #ty_generics> for Element #element_where_clause {
+            fn from(component: #name #ty_generics) -> Self {
                 component.to_element()
             }
         }
@@ -6,9 +6,11 @@
 //!
 //! - [`Route`][]: 为组件自动生成对应的路由组件
 //! - [`ComponentBase`]: 为组件提供基础方法（id、class、style 等）
+//! - [`macro@builder_props`]: 从组件的构建方法生成属性表，用于渲染 API 文档
 use proc_macro::TokenStream;
 
 mod component;
+mod props;
 mod route;
 
 /// 为给定的结构体实现 `Route` 派生宏
@@ -16,6 +18,11 @@ mod route;
 /// 此宏会自动生成一个与结构体同名的组件函数（后缀为"Route"），
 /// 该组件会创建并渲染原始结构体的实例。
 ///
+/// 结构体的字段会作为路由组件的参数，支持以下属性：
+///
+/// - `#[route(props)]`: 显式声明将结构体字段作为路由参数
+/// - `#[route(skip)]`: 字段不作为路由参数，使用 `Default` 填充，需配合 `#[route(props)]` 使用
+///
 /// # 示例
 ///
 /// ```rust
@@ -34,7 +41,7 @@ mod route;
 ///     }
 /// }
 /// ```
-#[proc_macro_derive(Route)]
+#[proc_macro_derive(Route, attributes(route))]
 pub fn derive_route(input: TokenStream) -> TokenStream {
     route::impl_derive_route(input)
 }
@@ -43,6 +50,15 @@ pub fn derive_route(input: TokenStream) -> TokenStream {
 ///
 /// 此宏会为结构体自动实现基础方法，包括 id/class/style/children/class 等。
 ///
+/// `class` 字段可以是 `String`，也可以是 `Cow<'static, str>`。后者可以在默认值中直接借用静态类名，
+/// 只有调用 `class()` 添加自定义类名时才会分配新的字符串。
+///
+/// 此宏还会逐字段实现 `PartialEq`：实现了 `PartialEq` 的字段按值比较，`Rc` 包装的字段（如子组件）按指针比较，
+/// 其余字段视为不相等。配合生成的 `memoized_element()`，组件可以作为 Dioxus 组件的属性渲染，
+/// 属性未变化时跳过 `to_element`。
+///
+/// 结构体可以带有泛型参数，生成的实现沿用结构体声明的约束，`memoized_element()` 还要求组件满足 `'static`。
+///
 /// # 示例
 ///
 /// ```rust
@@ -71,3 +87,65 @@ pub fn derive_route(input: TokenStream) -> TokenStream {
 pub fn derive_component_base(input: TokenStream) -> TokenStream {
     component::impl_component_base(input)
 }
+
+/// 从 impl 块中的构建方法生成组件的属性表
+///
+/// 公开的、按值接收 `self` 并返回 `Self` 的方法会被记录为一个属性，包括方法名、参数类型和
+/// 文档注释的第一段，并为组件实现 `ComponentProps`，`PropsTable` 组件据此渲染 API 文档。
+/// 文档随代码一起维护，修改构建方法后属性表自动更新。
+///
+/// 方法上可以使用以下属性：
+///
+/// - `#[prop(default = "...")]`: 属性的默认值
+/// - `#[prop(skip)]`: 不记录该方法，如与其它方法重复的事件处理器
+///
+/// 同一个组件只能有一个 impl 块使用此宏。
+///
+/// # 示例
+///
+/// ```rust
+/// use dioxus_blocks_components::ComponentProps;
+/// use dioxus_blocks_macro::builder_props;
+///
+/// #[derive(Debug, Default, Clone)]
+/// pub struct Badge {
+///     count: u32,
+///     dot: bool,
+/// }
+///
+/// #[builder_props]
+/// impl Badge {
+///     /// 创建徽标
+///     pub fn new() -> Self {
+///         Self::default()
+///     }
+///
+///     /// 设置显示的数字
+///     ///
+///     /// 超过 99 时显示为 `99+`。
+///     #[prop(default = "0")]
+///     pub fn count(mut self, count: u32) -> Self {
+///         self.count = count;
+///         self
+///     }
+///
+///     /// 设置是否只显示小圆点
+///     pub fn dot(mut self, dot: impl Into<bool>) -> Self {
+///         self.dot = dot.into();
+///         self
+///     }
+/// }
+///
+/// assert_eq!(Badge::NAME, "Badge");
+/// let props = Badge::props();
+/// assert_eq!(props.len(), 2);
+/// assert_eq!(props[0].name, "count");
+/// assert_eq!(props[0].ty, "u32");
+/// assert_eq!(props[0].default, Some("0"));
+/// assert_eq!(props[0].doc, "设置显示的数字");
+/// assert_eq!(props[1].ty, "impl Into<bool>");
+/// ```
+#[proc_macro_attribute]
+pub fn builder_props(_attr: TokenStream, item: TokenStream) -> TokenStream {
+    props::impl_builder_props(item)
+}
@@ -0,0 +1,265 @@
+//! 组件属性表宏实现
+//!
+//! 扫描 impl 块中的构建方法，生成 `ComponentProps` 的实现，供 `PropsTable` 渲染 API 文档。
+use proc_macro::TokenStream;
+use quote::{quote, ToTokens};
+use syn::{
+    parse_macro_input, Attribute, Expr, FnArg, GenericParam, Generics, ImplItem, ImplItemFn,
+    ItemImpl, Lit, LitStr, Meta, Pat, ReturnType, Type, TypeParamBound, Visibility, WherePredicate,
+};
+
+/// 方法上的 `#[prop(...)]` 配置
+#[derive(Default)]
+struct PropAttr {
+    /// 不记录该方法
+    skip: bool,
+    /// 默认值
+    default: Option<LitStr>,
+}
+
+impl PropAttr {
+    fn parse(attrs: &[Attribute]) -> syn::Result<Self> {
+        let mut prop = Self::default();
+        for attr in attrs.iter().filter(|a| a.path().is_ident("prop")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    prop.skip = true;
+                    Ok(())
+                } else if meta.path.is_ident("default") {
+                    prop.default = Some(meta.value()?.parse()?);
+                    Ok(())
+                } else {
+                    Err(meta.error("不支持的 prop 属性，可用：skip、default = \"...\""))
+                }
+            })?;
+        }
+        Ok(prop)
+    }
+}
+
+/// 为 impl 块中的构建方法生成属性表
+pub fn impl_builder_props(input: TokenStream) -> TokenStream {
+    let mut item = parse_macro_input!(input as ItemImpl);
+    if let Some((_, path, _)) = &item.trait_ {
+        return syn::Error::new_spanned(path, "builder_props 只能用于固有 impl 块")
+            .to_compile_error()
+            .into();
+    }
+
+    let mut props = Vec::new();
+    let mut errors = Vec::new();
+    for impl_item in &mut item.items {
+        let ImplItem::Fn(method) = impl_item else {
+            continue;
+        };
+        match PropAttr::parse(&method.attrs) {
+            Ok(prop) => {
+                if !prop.skip && is_builder(method) {
+                    props.push(prop_info(method, prop.default));
+                }
+            }
+            Err(err) => errors.push(err.to_compile_error()),
+        }
+        method.attrs.retain(|a| !a.path().is_ident("prop"));
+    }
+
+    let self_ty = &item.self_ty;
+    let name = self_ty
+        .to_token_stream()
+        .to_string()
+        .split('<')
+        .next()
+        .unwrap_or_default()
+        .trim()
+        .to_string();
+    let (impl_generics, _, where_clause) = item.generics.split_for_impl();
+
+    quote! {
+        #item
+
+        impl #impl_generics ::dioxus_blocks_components::ComponentProps for #self_ty #where_clause {
+            const NAME: &'static str = #name;
+
+            fn props() -> &'static [::dioxus_blocks_components::PropInfo] {
+                &[#(#props),*]
+            }
+        }
+
+        #(#errors)*
+    }
+    .into()
+}
+
+/// 公开的、按值接收 `self` 并返回 `Self` 的方法视为构建方法
+fn is_builder(method: &ImplItemFn) -> bool {
+    let takes_self = matches!(
+        method.sig.inputs.first(),
+        Some(FnArg::Receiver(receiver)) if receiver.reference.is_none()
+    );
+    let returns_self = matches!(
+        &method.sig.output,
+        ReturnType::Type(_, ty) if matches!(&**ty, Type::Path(p) if p.path.is_ident("Self"))
+    );
+    matches!(method.vis, Visibility::Public(_)) && takes_self && returns_self
+}
+
+/// 生成单个方法的 `PropInfo`
+fn prop_info(method: &ImplItemFn, default: Option<LitStr>) -> proc_macro2::TokenStream {
+    let name = method.sig.ident.to_string();
+    let ty = method
+        .sig
+        .inputs
+        .iter()
+        .filter_map(|arg| match arg {
+            FnArg::Typed(arg) => Some(arg),
+            FnArg::Receiver(_) => None,
+        })
+        .map(|arg| {
+            let ty = type_name(&arg.ty, &method.sig.generics);
+            match &*arg.pat {
+                Pat::Ident(ident) if method.sig.inputs.len() > 2 => {
+                    format!("{}: {ty}", ident.ident)
+                }
+                _ => ty,
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(", ");
+    let doc = doc_summary(&method.attrs);
+    let default = match default {
+        Some(default) => quote!(::core::option::Option::Some(#default)),
+        None => quote!(::core::option::Option::None),
+    };
+    quote! {
+        ::dioxus_blocks_components::PropInfo {
+            name: #name,
+            ty: #ty,
+            default: #default,
+            doc: #doc,
+        }
+    }
+}
+
+/// 参数类型的显示名称，泛型参数替换为 `impl 约束`
+fn type_name(ty: &Type, generics: &Generics) -> String {
+    let mut name = normalize(&ty.to_token_stream().to_string());
+    for param in &generics.params {
+        if let GenericParam::Type(param) = param {
+            let bounds = generic_bounds(&param.ident, generics);
+            if !bounds.is_empty() {
+                let bounds = format!("impl {}", bounds.join(" + "));
+                name = replace_ident(&name, &param.ident.to_string(), &bounds);
+            }
+        }
+    }
+    name
+}
+
+/// 替换完整的标识符，不替换其它标识符中的同名片段
+fn replace_ident(s: &str, ident: &str, to: &str) -> String {
+    let is_ident_char = |c: char| c.is_alphanumeric() || c == '_';
+    let mut out = String::new();
+    let mut rest = s;
+    while let Some(pos) = rest.find(ident) {
+        let before = rest[..pos].chars().next_back();
+        let after = rest[pos + ident.len()..].chars().next();
+        out.push_str(&rest[..pos]);
+        if before.is_some_and(is_ident_char) || after.is_some_and(is_ident_char) {
+            out.push_str(ident);
+        } else {
+            out.push_str(to);
+        }
+        rest = &rest[pos + ident.len()..];
+    }
+    out.push_str(rest);
+    out
+}
+
+/// 泛型参数在参数列表和 where 子句中声明的约束
+fn generic_bounds(ident: &syn::Ident, generics: &Generics) -> Vec<String> {
+    let bound_names = |bounds: &syn::punctuated::Punctuated<TypeParamBound, syn::Token![+]>| {
+        bounds
+            .iter()
+            .map(|b| normalize(&b.to_token_stream().to_string()))
+            .collect::<Vec<_>>()
+    };
+    let mut bounds = Vec::new();
+    for param in &generics.params {
+        if let GenericParam::Type(param) = param {
+            if &param.ident == ident {
+                bounds.extend(bound_names(&param.bounds));
+            }
+        }
+    }
+    if let Some(where_clause) = &generics.where_clause {
+        for predicate in &where_clause.predicates {
+            if let WherePredicate::Type(predicate) = predicate {
+                if matches!(&predicate.bounded_ty, Type::Path(p) if p.path.is_ident(ident)) {
+                    bounds.extend(bound_names(&predicate.bounds));
+                }
+            }
+        }
+    }
+    bounds
+}
+
+/// 去掉 `to_string` 在标点两侧插入的空格
+fn normalize(tokens: &str) -> String {
+    let mut s = tokens.to_string();
+    for (from, to) in [
+        (" <", "<"),
+        ("< ", "<"),
+        (" >", ">"),
+        (" ::", "::"),
+        (":: ", "::"),
+        (" ,", ","),
+        ("& ", "&"),
+        ("( ", "("),
+        (" )", ")"),
+        ("[ ", "["),
+        (" ]", "]"),
+        (" ;", ";"),
+    ] {
+        s = s.replace(from, to);
+    }
+    // 只去掉函数名与参数列表之间的空格，保留 `Item = (A, B)` 中的空格
+    let mut out = String::with_capacity(s.len());
+    let mut chars = s.chars().peekable();
+    while let Some(c) = chars.next() {
+        let prev = out.chars().next_back();
+        if c == ' '
+            && chars.peek() == Some(&'(')
+            && prev.is_some_and(|p: char| p.is_alphanumeric() || p == '_')
+        {
+            continue;
+        }
+        out.push(c);
+    }
+    out
+}
+
+/// 文档注释的第一段
+fn doc_summary(attrs: &[Attribute]) -> String {
+    let mut lines = Vec::new();
+    for attr in attrs {
+        let Meta::NameValue(meta) = &attr.meta else {
+            continue;
+        };
+        if !meta.path.is_ident("doc") {
+            continue;
+        }
+        if let Expr::Lit(expr) = &meta.value {
+            if let Lit::Str(s) = &expr.lit {
+                let line = s.value().trim().to_string();
+                if line.is_empty() {
+                    if !lines.is_empty() {
+                        break;
+                    }
+                    continue;
+                }
+                lines.push(line);
+            }
+        }
+    }
+    lines.join(" ")
+}
@@ -4,7 +4,7 @@
 use proc_macro::TokenStream;
 use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
+use syn::{parse_macro_input, Attribute, Data, DeriveInput, Fields, Ident};
 
 /// 为给定的结构体实现 `Route` 派生宏
 ///
@@ -40,12 +40,14 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
 ///
 /// ## 带参数结构体
 ///
+/// 结构体的字段会作为路由组件的参数，可以显式添加 `#[route(props)]` 声明。
 /// ```rust
 /// use dioxus::prelude::*;
 /// use dioxus_blocks_macro::Route;
 /// use dioxus_blocks_components::{ToElement, View};
 ///
 /// #[derive(Debug, Clone, Route)]
+/// #[route(props)]
 /// struct BlogView {
 ///     id: i32,
 /// }
@@ -56,38 +58,115 @@ use syn::{parse_macro_input, Data, DeriveInput, Fields, Ident};
 ///     }
 /// }
 /// ```
+///
+/// ## 跳过非路由字段
+///
+/// 在 `#[route(props)]` 模式下，标记了 `#[route(skip)]` 的字段不会作为路由参数，
+/// 而是使用结构体的 `Default` 实现填充。
+///
+/// ```rust
+/// use dioxus::prelude::*;
+/// use dioxus_blocks_macro::Route;
+/// use dioxus_blocks_components::{Text, ToElement};
+///
+/// #[derive(Debug, Default, Clone, Route)]
+/// #[route(props)]
+/// struct BlogPost {
+///     id: String,
+///     #[route(skip)]
+///     show_comments: bool,
+/// }
+///
+/// impl ToElement for BlogPost {
+///     fn to_element(&self) -> Element {
+///         Text::h1(format!("{} {}", self.id, self.show_comments)).to_element()
+///     }
+/// }
+///
+/// // 生成的路由组件只接收 id 参数
+/// fn app() -> Element {
+///     rsx! { BlogPostRoute { id: "hello-dioxus" } }
+/// }
+/// ```
 pub fn impl_derive_route(input: TokenStream) -> TokenStream {
     let input = parse_macro_input!(input as DeriveInput);
 
+    match expand(&input) {
+        Ok(expanded) => TokenStream::from(expanded),
+        Err(err) => TokenStream::from(err.to_compile_error()),
+    }
+}
+
+/// 解析 `#[route(...)]` 属性，返回其中的标识符列表
+fn route_args(attrs: &[Attribute]) -> syn::Result<Vec<Ident>> {
+    let mut args = Vec::new();
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("route")) {
+        attr.parse_nested_meta(|meta| match meta.path.get_ident() {
+            Some(ident) => {
+                args.push(ident.clone());
+                Ok(())
+            }
+            None => Err(meta.error("unsupported route attribute")),
+        })?;
+    }
+    Ok(args)
+}
+
+fn expand(input: &DeriveInput) -> syn::Result<TokenStream2> {
     let struct_name = &input.ident;
     let route_name = Ident::new(&format!("{}Route", struct_name), struct_name.span());
 
-    // 判断结构体字段
+    // 结构体级别的属性，目前仅支持 props
+    let mut props = false;
+    for arg in route_args(&input.attrs)? {
+        if arg == "props" {
+            props = true;
+        } else {
+            return Err(syn::Error::new(
+                arg.span(),
+                "unsupported route attribute, expected `props`",
+            ));
+        }
+    }
+
+    // 判断结构体字段，返回 (路由参数, 构造字段, 是否存在跳过的字段)
     let component_params = match &input.data {
         Data::Struct(data_struct) => match &data_struct.fields {
             Fields::Named(fields) => {
-                let field_names: Vec<_> = fields
-                    .named
-                    .iter()
-                    .map(|f| f.ident.as_ref().unwrap())
-                    .collect();
-                let field_types: Vec<_> = fields.named.iter().map(|f| &f.ty).collect();
+                let mut params = Vec::new();
+                let mut construct_fields = Vec::new();
+                let mut skipped = false;
+                for field in fields.named.iter() {
+                    let name = field.ident.as_ref().unwrap();
+                    let ty = &field.ty;
 
-                let params = field_names
-                    .iter()
-                    .zip(field_types.iter())
-                    .map(|(name, ty)| {
-                        quote! { #name: #ty }
-                    });
+                    // 字段级别的属性，目前仅支持 skip
+                    let mut skip = false;
+                    for arg in route_args(&field.attrs)? {
+                        if arg != "skip" {
+                            return Err(syn::Error::new(
+                                arg.span(),
+                                "unsupported route attribute, expected `skip`",
+                            ));
+                        }
+                        if !props {
+                            return Err(syn::Error::new(
+                                arg.span(),
+                                "`#[route(skip)]` requires `#[route(props)]` on the struct",
+                            ));
+                        }
+                        skip = true;
+                    }
 
-                let construct_fields = field_names.iter().map(|name| {
-                    quote! { #name }
-                });
+                    if skip {
+                        skipped = true;
+                        continue;
+                    }
+                    params.push(quote! { #name: #ty });
+                    construct_fields.push(quote! { #name });
+                }
 
-                Some((
-                    params.collect::<Vec<_>>(),
-                    construct_fields.collect::<Vec<_>>(),
-                ))
+                Some((params, construct_fields, skipped))
             }
             Fields::Unnamed(fields) => {
                 let field_types: Vec<_> = fields.unnamed.iter().map(|f| &f.ty).collect();
@@ -113,6 +192,7 @@ pub fn impl_derive_route(input: TokenStream) -> TokenStream {
                 Some((
                     params.collect::<Vec<_>>(),
                     construct_fields.collect::<Vec<_>>(),
+                    false,
                 ))
             }
             Fields::Unit => None,
@@ -120,23 +200,32 @@ pub fn impl_derive_route(input: TokenStream) -> TokenStream {
         _ => None,
     };
 
-    let expanded: TokenStream2 = if let Some((params, construct_fields)) = component_params {
-        quote! {
+    let expanded = match component_params {
+        Some((params, construct_fields, true)) => quote! {
+            #[component]
+            pub fn #route_name(#(#params),*) -> Element {
+                let ele = #struct_name {
+                    #(#construct_fields,)*
+                    ..::core::default::Default::default()
+                };
+                ele.to_element()
+            }
+        },
+        Some((params, construct_fields, false)) => quote! {
             #[component]
             pub fn #route_name(#(#params),*) -> Element {
                 let ele = #struct_name { #(#construct_fields),* };
                 ele.to_element()
             }
-        }
-    } else {
-        quote! {
+        },
+        None => quote! {
             #[component]
             pub fn #route_name() -> Element {
                 let ele = #struct_name::default();
                 ele.to_element()
             }
-        }
+        },
     };
 
-    TokenStream::from(expanded)
+    Ok(expanded)
 }
@@ -0,0 +1,27 @@
+//! # 独立渲染的元素
+//!
+//! 将共享的组件作为独立的子组件渲染。守卫、懒加载等包装器会根据状态切换渲染内容，
+//! 如果直接在当前组件中调用 `to_element`，被包装页面的 hooks 会和外层组件混在一起，
+//! 状态切换后 hooks 的调用顺序就会错乱。
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::ToElement;
+
+/// 共享的组件，用于作为组件属性传递
+#[derive(Debug, Clone)]
+pub(crate) struct SharedElement(pub(crate) Rc<dyn ToElement>);
+
+impl PartialEq for SharedElement {
+    fn eq(&self, other: &Self) -> bool {
+        Rc::ptr_eq(&self.0, &other.0)
+    }
+}
+
+/// 以独立组件渲染共享的组件
+#[component]
+pub(crate) fn IsolatedElement(element: SharedElement) -> Element {
+    element.0.to_element()
+}
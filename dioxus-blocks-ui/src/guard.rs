@@ -0,0 +1,442 @@
+//! # 路由守卫
+//!
+//! 在渲染受保护的页面前执行 [`RouteGuard`] 检查，检查不通过时重定向到指定路由。
+//! 登录状态由 [`AuthProvider`] 通过上下文提供，内置的 [`RequireAuth`] 守卫会在未登录时跳转到登录页。
+//!
+//! ```rust,ignore
+//! // 在布局中提供登录状态
+//! AuthProvider::new().children(Body::default())
+//!
+//! // 保护页面
+//! Guarded::new(DashboardTemplate::new())
+//!     .guard(RequireAuth::new(Route::LoginViewRoute {}))
+//!     .guard(|| match AuthContext::current().and_then(|auth| auth.user()) {
+//!         Some(user) if user.has_role("admin") => GuardResult::Allow,
+//!         _ => GuardResult::redirect("/403"),
+//!     })
+//!
+//! // 登录成功后
+//! use_auth().login(AuthUser::new("admin").role("admin"));
+//! ```
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::ToElement;
+
+use crate::element::{IsolatedElement, SharedElement};
+use crate::permission::{PermissionProvider, Permissions};
+
+/// 守卫检查结果
+#[derive(Debug, Clone, PartialEq)]
+pub enum GuardResult {
+    /// 允许访问
+    Allow,
+    /// 重定向到指定路由
+    RedirectTo(NavigationTarget),
+}
+
+impl GuardResult {
+    /// 创建重定向结果
+    pub fn redirect(target: impl Into<NavigationTarget>) -> Self {
+        GuardResult::RedirectTo(target.into())
+    }
+}
+
+/// 路由守卫
+///
+/// 在渲染受保护的页面前调用，可以读取上下文中的登录状态等信息。
+pub trait RouteGuard {
+    /// 检查是否允许访问
+    fn check(&self) -> GuardResult;
+}
+
+impl<F> RouteGuard for F
+where
+    F: Fn() -> GuardResult,
+{
+    fn check(&self) -> GuardResult {
+        self()
+    }
+}
+
+/// 登录用户
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct AuthUser {
+    /// 用户名
+    pub username: String,
+    /// 显示名称，未设置时显示用户名
+    pub nickname: Option<String>,
+    /// 头像地址
+    pub avatar: Option<String>,
+    /// 角色列表
+    pub roles: Vec<String>,
+}
+
+impl AuthUser {
+    /// 创建登录用户
+    pub fn new(username: impl Into<String>) -> Self {
+        Self {
+            username: username.into(),
+            nickname: None,
+            avatar: None,
+            roles: Vec::new(),
+        }
+    }
+
+    /// 设置显示名称
+    pub fn nickname(mut self, nickname: impl Into<String>) -> Self {
+        self.nickname = Some(nickname.into());
+        self
+    }
+
+    /// 设置头像地址
+    pub fn avatar(mut self, avatar: impl Into<String>) -> Self {
+        self.avatar = Some(avatar.into());
+        self
+    }
+
+    /// 显示名称，未设置时返回用户名
+    pub fn display_name(&self) -> &str {
+        self.nickname.as_deref().unwrap_or(&self.username)
+    }
+
+    /// 添加角色
+    pub fn role(mut self, role: impl Into<String>) -> Self {
+        self.roles.push(role.into());
+        self
+    }
+
+    /// 是否拥有指定角色
+    pub fn has_role(&self, role: &str) -> bool {
+        self.roles.iter().any(|r| r == role)
+    }
+}
+
+/// 登录状态上下文
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct AuthContext {
+    user: Signal<Option<AuthUser>>,
+}
+
+impl AuthContext {
+    /// 当前登录用户
+    pub fn user(&self) -> Option<AuthUser> {
+        self.user.read().clone()
+    }
+
+    /// 是否已登录
+    pub fn is_authenticated(&self) -> bool {
+        self.user.read().is_some()
+    }
+
+    /// 登录
+    pub fn login(&mut self, user: AuthUser) {
+        self.user.set(Some(user));
+    }
+
+    /// 退出登录
+    pub fn logout(&mut self) {
+        self.user.set(None);
+    }
+
+    /// 获取上下文中的登录状态，未提供时返回 `None`
+    pub fn current() -> Option<Self> {
+        try_consume_context::<Self>()
+    }
+}
+
+/// 获取最近的 [`AuthProvider`] 提供的登录状态
+///
+/// 必须在 AuthProvider 的子组件中调用，否则会 panic。
+pub fn use_auth() -> AuthContext {
+    use_context::<AuthContext>()
+}
+
+/// 登录状态提供者
+#[derive(Clone, Default)]
+pub struct AuthProvider {
+    /// 初始登录用户
+    user: Option<AuthUser>,
+    /// 权限规则
+    permissions: Option<Rc<dyn PermissionProvider>>,
+    /// 子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+}
+
+impl std::fmt::Debug for AuthProvider {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("AuthProvider")
+            .field("user", &self.user)
+            .field("permissions", &self.permissions.is_some())
+            .field("childrens", &self.childrens)
+            .finish()
+    }
+}
+
+impl AuthProvider {
+    /// 创建登录状态提供者
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置初始登录用户
+    pub fn user(mut self, user: AuthUser) -> Self {
+        self.user = Some(user);
+        self
+    }
+
+    /// 设置权限规则，供 [`Can`](crate::Can) 和 [`use_permission`](crate::use_permission) 使用
+    pub fn permissions(mut self, permissions: impl PermissionProvider + 'static) -> Self {
+        self.permissions = Some(Rc::new(permissions));
+        self
+    }
+
+    /// 添加子元素
+    pub fn children<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.childrens.push(Rc::new(component));
+        self
+    }
+}
+
+impl ToElement for AuthProvider {
+    fn to_element(&self) -> Element {
+        let initial = self.user.clone();
+        use_context_provider(move || AuthContext {
+            user: Signal::new(initial),
+        });
+        let permissions = self.permissions.clone();
+        use_hook(move || {
+            if let Some(permissions) = permissions {
+                provide_context(Permissions(permissions));
+            }
+        });
+
+        rsx! {
+            for children in self.childrens.iter() {
+                {children.to_element()}
+            }
+        }
+    }
+}
+
+/// 要求登录的守卫，未登录时重定向到指定路由
+#[derive(Debug, Clone)]
+pub struct RequireAuth {
+    redirect: NavigationTarget,
+}
+
+impl RequireAuth {
+    /// 创建登录守卫
+    pub fn new(redirect: impl Into<NavigationTarget>) -> Self {
+        Self {
+            redirect: redirect.into(),
+        }
+    }
+}
+
+impl RouteGuard for RequireAuth {
+    fn check(&self) -> GuardResult {
+        match AuthContext::current() {
+            Some(auth) if auth.is_authenticated() => GuardResult::Allow,
+            _ => GuardResult::RedirectTo(self.redirect.clone()),
+        }
+    }
+}
+
+/// 受守卫保护的页面
+///
+/// 按添加顺序依次执行守卫，全部通过时渲染页面，否则重定向到第一个不通过的守卫指定的路由。
+#[derive(Clone)]
+pub struct Guarded {
+    /// 受保护的页面
+    view: Rc<dyn ToElement>,
+    /// 守卫列表
+    guards: Vec<Rc<dyn RouteGuard>>,
+}
+
+impl std::fmt::Debug for Guarded {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Guarded")
+            .field("view", &self.view)
+            .field("guards", &self.guards.len())
+            .finish()
+    }
+}
+
+impl Guarded {
+    /// 保护页面
+    pub fn new<T>(view: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        Self {
+            view: Rc::new(view),
+            guards: Vec::new(),
+        }
+    }
+
+    /// 添加守卫
+    pub fn guard(mut self, guard: impl RouteGuard + 'static) -> Self {
+        self.guards.push(Rc::new(guard));
+        self
+    }
+}
+
+impl ToElement for Guarded {
+    fn to_element(&self) -> Element {
+        let redirect = self.guards.iter().find_map(|guard| match guard.check() {
+            GuardResult::Allow => None,
+            GuardResult::RedirectTo(target) => Some(target),
+        });
+
+        match redirect {
+            Some(target) => {
+                // 渲染过程中不能直接跳转，交给任务在渲染结束后执行
+                let navigator = navigator();
+                spawn(async move {
+                    navigator.replace(target);
+                });
+                rsx! {}
+            }
+            // 以独立组件渲染，守卫结果变化时页面的 hooks 不会影响外层组件
+            None => rsx! {
+                IsolatedElement { element: SharedElement(self.view.clone()) }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus::history::{History, MemoryHistory};
+    use dioxus::router::components::HistoryProvider;
+
+    use super::*;
+
+    #[derive(Debug, Clone, PartialEq, Routable)]
+    enum TestRoute {
+        #[route("/")]
+        Protected {},
+        #[route("/login")]
+        Login {},
+        #[route("/403")]
+        Forbidden {},
+    }
+
+    #[component]
+    fn Protected() -> Element {
+        Guarded::new(Page("受保护的页面"))
+            .guard(RequireAuth::new(TestRoute::Login {}))
+            .guard(
+                || match AuthContext::current().and_then(|auth| auth.user()) {
+                    Some(user) if user.has_role("admin") => GuardResult::Allow,
+                    _ => GuardResult::redirect(TestRoute::Forbidden {}),
+                },
+            )
+            .to_element()
+    }
+
+    #[component]
+    fn Login() -> Element {
+        rsx! { p { "登录页" } }
+    }
+
+    #[component]
+    fn Forbidden() -> Element {
+        rsx! { p { "无权访问" } }
+    }
+
+    /// 测试用的页面内容
+    #[derive(Debug, Clone)]
+    struct Page(&'static str);
+
+    impl ToElement for Page {
+        fn to_element(&self) -> Element {
+            rsx! { p { "{self.0}" } }
+        }
+    }
+
+    /// 从 `/` 开始的路由
+    #[derive(Debug, Clone)]
+    struct TestRouter;
+
+    impl ToElement for TestRouter {
+        fn to_element(&self) -> Element {
+            rsx! {
+                HistoryProvider {
+                    history: |_| Rc::new(MemoryHistory::with_initial_path("/")) as Rc<dyn History>,
+                    Router::<TestRoute> {}
+                }
+            }
+        }
+    }
+
+    /// 渲染路由，`auth` 为 `None` 时不提供 AuthProvider，否则以其中的用户作为初始登录用户
+    fn render(auth: Option<Option<AuthUser>>) -> String {
+        let mut dom = VirtualDom::new_with_props(
+            |auth: Option<Option<AuthUser>>| match auth {
+                None => TestRouter.to_element(),
+                Some(user) => {
+                    let provider = AuthProvider::new().children(TestRouter);
+                    match user {
+                        Some(user) => provider.user(user),
+                        None => provider,
+                    }
+                    .to_element()
+                }
+            },
+            auth,
+        );
+        dom.rebuild_in_place();
+        // 重定向在渲染结束后的任务中执行
+        for _ in 0..3 {
+            dom.process_events();
+            dom.render_immediate_to_vec();
+        }
+        dioxus_ssr::render(&dom)
+    }
+
+    #[test]
+    fn test_unauthenticated_redirects() {
+        let html = render(Some(None));
+        assert!(html.contains("登录页"), "{html}");
+        assert!(!html.contains("受保护的页面"), "{html}");
+    }
+
+    #[test]
+    fn test_authenticated_renders_children() {
+        let html = render(Some(Some(AuthUser::new("root").role("admin"))));
+        assert!(html.contains("受保护的页面"), "{html}");
+        assert!(!html.contains("登录页"), "{html}");
+    }
+
+    #[test]
+    fn test_first_failing_guard_redirects() {
+        // 已登录但没有 admin 角色时，由第二个守卫重定向
+        let html = render(Some(Some(AuthUser::new("alice").role("editor"))));
+        assert!(html.contains("无权访问"), "{html}");
+        assert!(!html.contains("受保护的页面"), "{html}");
+    }
+
+    #[test]
+    fn test_missing_auth_provider() {
+        // 没有 AuthProvider 时视为未登录
+        let html = render(None);
+        assert!(html.contains("登录页"), "{html}");
+        assert!(!html.contains("受保护的页面"), "{html}");
+
+        let mut dom = VirtualDom::new(|| {
+            let redirect = RequireAuth::new("/login").check() == GuardResult::redirect("/login");
+            rsx! { "{AuthContext::current().is_none()} {redirect}" }
+        });
+        dom.rebuild_in_place();
+        assert_eq!(dioxus_ssr::render(&dom), "true true");
+    }
+}
@@ -1,8 +1,9 @@
 //! # Body
 
-use dioxus_blocks_components::{Element, Outlet, ToElement, View};
+use dioxus_blocks_components::{Element, Outlet, SkeletonBoundary, ToElement, View};
 
 use crate::Route;
+use crate::layout::{Sidebar, Toc};
 
 #[derive(Debug, Default, Clone)]
 pub struct Body {}
@@ -11,12 +12,23 @@ impl ToElement for Body {
     fn to_element(&self) -> Element {
         View::new()
             .class("t_body")
-            .children(Outlet::<Route>::default())
             .style(|s| {
-                s.padding("20px")
-                    .background_color("#f5f7fa")
+                s.display("flex")
+                    .background_color("var(--t-fill-color-light)")
                     .min_height("100vh")
             })
+            .children(Sidebar::default())
+            .children(
+                View::new()
+                    .class("t_body__main")
+                    // 页面中的异步资源完成前显示页面骨架屏，而不是空白；
+                    // 后退时恢复离开时的滚动位置，跳转到锚点时与窗口顶部保留内边距的距离
+                    .children(
+                        SkeletonBoundary::new().children(Outlet::<Route>::new().scroll_offset(20)),
+                    )
+                    .style(|s| s.flex("1").min_width("0").padding("20px")),
+            )
+            .children(Toc::default())
             .to_element()
     }
 }
@@ -1,12 +1,304 @@
 //! # Footer
+//!
+//! 网站底部区域，包含链接分栏、社交图标、版权和备案信息。
+//!
+//! 页脚的内容完全由 [`FooterConfig`] 描述，配置支持 serde 序列化，可以从配置文件或接口加载：
+//!
+//! ```json
+//! {
+//!     "theme": "dark",
+//!     "columns": [{ "title": "资源", "links": [{ "text": "文档", "href": "/docs" }] }],
+//!     "socials": [{ "name": "GitHub", "icon": "🐙", "href": "https://github.com" }],
+//!     "copyright": "© 2025 Dioxus Blocks",
+//!     "icp": { "text": "京ICP备00000000号", "href": "https://beian.miit.gov.cn" }
+//! }
+//! ```
 
-use dioxus_blocks_components::{Element, ToElement, View};
+use dioxus::prelude::*;
+use dioxus_blocks_components::{Link, Text, ToElement, View};
+use serde::{Deserialize, Serialize};
 
+/// 页脚主题
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum FooterTheme {
+    /// 浅色
+    #[default]
+    Light,
+    /// 深色
+    Dark,
+}
+
+/// 页脚链接
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FooterLink {
+    /// 链接文本
+    pub text: String,
+    /// 链接地址，站内路径或外部地址
+    pub href: String,
+}
+
+impl FooterLink {
+    /// 创建页脚链接
+    pub fn new(text: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            text: text.into(),
+            href: href.into(),
+        }
+    }
+}
+
+/// 页脚链接分栏
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FooterColumn {
+    /// 分栏标题
+    pub title: String,
+    /// 分栏中的链接
+    pub links: Vec<FooterLink>,
+}
+
+impl FooterColumn {
+    /// 创建链接分栏
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            links: Vec::new(),
+        }
+    }
+
+    /// 添加链接
+    pub fn link(mut self, text: impl Into<String>, href: impl Into<String>) -> Self {
+        self.links.push(FooterLink::new(text, href));
+        self
+    }
+}
+
+/// 社交图标
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+pub struct FooterSocial {
+    /// 名称，作为图标的无障碍标签
+    pub name: String,
+    /// 图标文本，如 emoji 或图标字体字符
+    pub icon: String,
+    /// 链接地址
+    pub href: String,
+}
+
+impl FooterSocial {
+    /// 创建社交图标
+    pub fn new(name: impl Into<String>, icon: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            name: name.into(),
+            icon: icon.into(),
+            href: href.into(),
+        }
+    }
+}
+
+/// 页脚配置
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FooterConfig {
+    /// 主题
+    pub theme: FooterTheme,
+    /// 链接分栏
+    pub columns: Vec<FooterColumn>,
+    /// 社交图标
+    pub socials: Vec<FooterSocial>,
+    /// 版权信息
+    pub copyright: Option<String>,
+    /// ICP 备案信息
+    pub icp: Option<FooterLink>,
+}
+
+impl FooterConfig {
+    /// 本站使用的页脚配置
+    pub fn site() -> Self {
+        Self {
+            theme: FooterTheme::Light,
+            columns: vec![
+                FooterColumn::new("资源")
+                    .link("快速开始", "/")
+                    .link("布局组件", "/layout")
+                    .link("仪表盘模板", "/dashboard"),
+                FooterColumn::new("社区")
+                    .link("GitHub", "https://github.com/silent-rain/dioxus-blocks")
+                    .link("Dioxus", "https://dioxuslabs.com"),
+            ],
+            socials: vec![FooterSocial::new(
+                "GitHub",
+                "🐙",
+                "https://github.com/silent-rain/dioxus-blocks",
+            )],
+            copyright: Some("© 2025 Dioxus Blocks".to_string()),
+            icp: None,
+        }
+    }
+}
+
+/// 页脚组件
 #[derive(Debug, Default, Clone)]
-pub struct Footer {}
+pub struct Footer {
+    config: FooterConfig,
+}
+
+impl Footer {
+    /// 根据配置创建页脚
+    pub fn new(config: FooterConfig) -> Self {
+        Self { config }
+    }
+
+    /// 链接分栏
+    fn columns(&self, theme: FooterTheme) -> View {
+        let columns = self
+            .config
+            .columns
+            .iter()
+            .map(|column| {
+                let links = column
+                    .links
+                    .iter()
+                    .map(|link| {
+                        Link::new(link.href.clone())
+                            .children(Text::span(link.text.clone()))
+                            .style(move |s| {
+                                s.color(secondary_color(theme))
+                                    .font_size("14px")
+                                    .text_decoration("none")
+                            })
+                    })
+                    .collect::<Vec<_>>();
+                View::new()
+                    .style(|s| s.display("flex").flex_direction("column").gap("8px"))
+                    .children(Text::h4(column.title.clone()).style(move |s| {
+                        s.margin("0 0 4px")
+                            .font_size("16px")
+                            .color(primary_color(theme))
+                    }))
+                    .childrens(links)
+            })
+            .collect::<Vec<_>>();
+
+        View::new()
+            .style(|s| s.display("flex").flex_wrap("wrap").gap("48px"))
+            .childrens(columns)
+    }
+
+    /// 版权和备案信息
+    fn legal(&self, theme: FooterTheme) -> View {
+        let mut legal = View::new().style(move |s| {
+            s.display("flex")
+                .flex_wrap("wrap")
+                .gap("16px")
+                .font_size("12px")
+                .color(secondary_color(theme))
+        });
+        if let Some(copyright) = &self.config.copyright {
+            legal = legal.children(Text::span(copyright.clone()));
+        }
+        if let Some(icp) = &self.config.icp {
+            legal = legal.children(
+                Link::new(icp.href.clone())
+                    .new_tab(true)
+                    .children(Text::span(icp.text.clone()))
+                    .style(move |s| s.color(secondary_color(theme)).text_decoration("none")),
+            );
+        }
+        legal
+    }
+}
 
 impl ToElement for Footer {
     fn to_element(&self) -> Element {
-        View::new().class("t_footer").to_element()
+        let theme = self.config.theme;
+        let has_bottom = !self.config.socials.is_empty()
+            || self.config.copyright.is_some()
+            || self.config.icp.is_some();
+
+        let mut footer = View::new()
+            .class("t_footer")
+            .class(match theme {
+                FooterTheme::Light => "t_footer--light",
+                FooterTheme::Dark => "t_footer--dark",
+            })
+            .style(move |s| {
+                let s = s
+                    .display("flex")
+                    .flex_direction("column")
+                    .gap("24px")
+                    .padding("32px 24px");
+                match theme {
+                    FooterTheme::Light => s
+                        .background_color("var(--t-bg-color)")
+                        .border_top("1px solid var(--t-border-color-light)"),
+                    FooterTheme::Dark => s.background_color("#1d1e1f"),
+                }
+            });
+        if !self.config.columns.is_empty() {
+            footer = footer.children(self.columns(theme));
+        }
+        if has_bottom {
+            footer = footer.children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .flex_wrap("wrap")
+                            .justify_content("space-between")
+                            .align_items("center")
+                            .gap("16px")
+                    })
+                    .children(self.legal(theme))
+                    .children(Socials {
+                        socials: self.config.socials.clone(),
+                        theme,
+                    }),
+            );
+        }
+        footer.to_element()
+    }
+}
+
+/// 社交图标行，图标均为外部链接，在新标签页打开
+#[derive(Debug, Clone)]
+struct Socials {
+    socials: Vec<FooterSocial>,
+    theme: FooterTheme,
+}
+
+impl ToElement for Socials {
+    fn to_element(&self) -> Element {
+        let color = secondary_color(self.theme);
+        rsx! {
+            div { style: "display: flex; gap: 16px;",
+                for social in self.socials.iter() {
+                    a {
+                        href: social.href.clone(),
+                        target: "_blank",
+                        rel: "noopener noreferrer",
+                        aria_label: social.name.clone(),
+                        title: social.name.clone(),
+                        style: "font-size: 20px; color: {color}; text-decoration: none;",
+                        "{social.icon}"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 标题文字颜色
+fn primary_color(theme: FooterTheme) -> &'static str {
+    match theme {
+        FooterTheme::Light => "var(--t-text-color-primary)",
+        FooterTheme::Dark => "rgba(255, 255, 255, 0.88)",
+    }
+}
+
+/// 次要文字颜色
+fn secondary_color(theme: FooterTheme) -> &'static str {
+    match theme {
+        FooterTheme::Light => "var(--t-text-color-secondary)",
+        FooterTheme::Dark => "rgba(255, 255, 255, 0.55)",
     }
 }
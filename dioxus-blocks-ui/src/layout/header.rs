@@ -1,12 +1,12 @@
 //! Header 头部组件
 //!
-//! 提供网站顶部导航栏，包含 logo、项目名称和导航菜单。
+//! 提供网站顶部导航栏，包含 logo、项目名称、文档搜索、导航菜单和用户菜单。
 
 use dioxus::prelude::{asset, manganis};
 use dioxus_blocks_components::{Element, Image, Link, Text, ToElement, View};
 
 use crate::Route;
-use crate::layout::Navbar;
+use crate::layout::{HeaderSearch, Navbar, NavbarConfig, UserMenu};
 
 #[derive(Debug, Default, Clone)]
 pub struct Header {}
@@ -22,7 +22,7 @@ impl ToElement for Header {
                     .align_items("center")
                     .padding("0 24px")
                     .height("64px")
-                    .background_color("#ffffff")
+                    .background_color("var(--t-bg-color)")
                     .border_bottom("1px solid var(--t-border-color-light)")
                     .box_shadow("0 2px 8px rgba(0, 0, 0, 0.06)")
             })
@@ -51,7 +51,14 @@ impl ToElement for Header {
             .children(
                 View::new()
                     .style(|s| s.display("flex").align_items("center"))
-                    .children(Navbar::default()),
+                    .children(HeaderSearch::default())
+                    .children(Navbar::new(NavbarConfig::site()))
+                    .children(
+                        UserMenu::new()
+                            .login(Route::LoginViewRoute {})
+                            .settings(Route::SettingsViewRoute {})
+                            .after_logout(Route::HomeViewRoute {}),
+                    ),
             )
             .to_element()
     }
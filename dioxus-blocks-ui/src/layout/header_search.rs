@@ -0,0 +1,124 @@
+//! HeaderSearch 头部搜索框
+//!
+//! 使用上下文中的 [`SearchIndex`] 搜索站内文档，输入时实时显示结果并高亮关键字。
+//! `↑`/`↓` 切换结果，`Enter` 跳转到选中的结果，`Esc` 清空输入。未提供索引时不渲染。
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::ToElement;
+
+use crate::search::{Fragment, SearchIndex};
+
+/// CSS 模块样式
+#[css_module("/assets/css/header_search.css")]
+struct Styles;
+
+/// 最多显示的结果数量
+const MAX_RESULTS: usize = 8;
+
+#[derive(Debug, Default, Clone)]
+pub struct HeaderSearch {}
+
+impl ToElement for HeaderSearch {
+    fn to_element(&self) -> Element {
+        let mut query = use_signal(String::new);
+        let mut focused = use_signal(|| false);
+        let mut active = use_signal(|| 0usize);
+        let navigator = navigator();
+
+        let Some(index) = SearchIndex::current() else {
+            return rsx! {};
+        };
+
+        let hits = index
+            .search(&query.read())
+            .into_iter()
+            .take(MAX_RESULTS)
+            .collect::<Vec<_>>();
+        let count = hits.len();
+        let routes = hits.iter().map(|hit| hit.route.clone()).collect::<Vec<_>>();
+        let open = focused() && !query.read().trim().is_empty();
+        let current = active().min(count.saturating_sub(1));
+
+        let mut go = move |route: String| {
+            query.set(String::new());
+            active.set(0);
+            navigator.push(route);
+        };
+
+        rsx! {
+            div { class: Styles::search, role: "search",
+                input {
+                    class: Styles::input,
+                    r#type: "search",
+                    placeholder: "搜索文档",
+                    aria_label: "搜索文档",
+                    autocomplete: "off",
+                    value: "{query}",
+                    oninput: move |event: FormEvent| {
+                        query.set(event.value());
+                        active.set(0);
+                    },
+                    onfocus: move |_| focused.set(true),
+                    onblur: move |_| focused.set(false),
+                    onkeydown: move |event: KeyboardEvent| match event.key() {
+                        Key::ArrowDown if count > 0 => {
+                            event.prevent_default();
+                            active.set((current + 1) % count);
+                        }
+                        Key::ArrowUp if count > 0 => {
+                            event.prevent_default();
+                            active.set((current + count - 1) % count);
+                        }
+                        Key::Enter => {
+                            if let Some(route) = routes.get(current) {
+                                go(route.clone());
+                            }
+                        }
+                        Key::Escape => {
+                            query.set(String::new());
+                            active.set(0);
+                        }
+                        _ => {}
+                    },
+                }
+                if open {
+                    div { class: Styles::results, role: "listbox",
+                        if hits.is_empty() {
+                            div { class: Styles::empty, "没有找到相关文档" }
+                        }
+                        for (i , hit) in hits.into_iter().enumerate() {
+                            div {
+                                key: "{hit.route}",
+                                class: if i == current { "{Styles::result} {Styles::active}" } else { "{Styles::result}" },
+                                role: "option",
+                                aria_selected: i == current,
+                                // 阻止输入框失去焦点，保证点击能够完成
+                                onmousedown: move |event: MouseEvent| event.prevent_default(),
+                                onmouseenter: move |_| active.set(i),
+                                onclick: {
+                                    let route = hit.route.clone();
+                                    move |_| go(route.clone())
+                                },
+                                div { class: Styles::title, {fragments(&hit.title)} }
+                                div { class: Styles::snippet, {fragments(&hit.snippet)} }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 渲染高亮片段，命中的关键字使用 `mark` 标记
+fn fragments(fragments: &[Fragment]) -> Element {
+    rsx! {
+        for fragment in fragments {
+            if fragment.matched {
+                mark { "{fragment.text}" }
+            } else {
+                "{fragment.text}"
+            }
+        }
+    }
+}
@@ -2,23 +2,71 @@
 use std::rc::Rc;
 
 use dioxus::prelude::*;
-use dioxus_blocks_components::{ToElement, View};
+use dioxus_blocks_components::{
+    ConfigProvider, ConnectionStatus, ConsentBanner, ConsentCategory, MessageProvider,
+    Notification, RouteProgress, TitleProvider, ToElement, View,
+};
 use dioxus_blocks_macro::Route as DbmRoute;
 
-use crate::layout::{Body, Footer, Header};
+use crate::layout::{Body, Footer, FooterConfig, Header};
+use crate::search::site_documents;
+use crate::{AuthProvider, RolePermissions};
 
 #[derive(Debug, Default, Clone, DbmRoute)]
 pub struct Layout {}
 
 impl ToElement for Layout {
     fn to_element(&self) -> Element {
-        View::new()
-            .class("t_layout")
-            .childrens2(vec![
-                Rc::new(Header::default()),
-                Rc::new(Body::default()),
-                Rc::new(Footer::default()),
-            ])
+        // 登录状态和权限规则供路由守卫和权限控制使用；全局配置修改后主题和密度会实时作用于整个应用；
+        // 路由切换和懒加载页面加载期间在页面顶部显示进度条，网络断开时显示提示横幅；
+        // 用户对 Cookie 的选择保存在全局配置中；页面通过 use_notification 发送的通知遵循全局的通知配置，
+        // 通过 message 模块发送的消息提示显示在页面顶部；
+        // 浏览器标签页标题使用文档中登记的页面标题，页面可以通过 use_document_title 覆盖
+        let title = site_documents().into_iter().fold(
+            TitleProvider::new()
+                .app_name("Dioxus Blocks")
+                .route("/", "首页"),
+            |title, document| title.route(document.route, document.title),
+        );
+
+        AuthProvider::new()
+            .permissions(
+                RolePermissions::new()
+                    .grant("admin", ["*"])
+                    .grant("editor", ["posts:read", "posts:write"])
+                    .guest(["posts:read"]),
+            )
+            .children(
+                title.children(
+                    RouteProgress::new().children(
+                        ConfigProvider::new().children(
+                            Notification::new().children(
+                                ConnectionStatus::new()
+                                    .children(View::new().class("t_layout").childrens2(vec![
+                                        Rc::new(Header::default()),
+                                        Rc::new(Body::default()),
+                                        Rc::new(Footer::new(FooterConfig::site())),
+                                    ]))
+                                    .children(
+                                        ConsentBanner::new()
+                                            .category(
+                                                ConsentCategory::new("necessary", "必需")
+                                                    .description(
+                                                        "保证登录、偏好设置等基础功能正常运行",
+                                                    )
+                                                    .required(true),
+                                            )
+                                            .category(
+                                                ConsentCategory::new("analytics", "统计分析")
+                                                    .description("帮助我们了解组件文档的访问情况"),
+                                            ),
+                                    )
+                                    .children(MessageProvider::new()),
+                            ),
+                        ),
+                    ),
+                ),
+            )
             .to_element()
     }
 }
@@ -3,14 +3,23 @@ mod header;
 pub use header::Header;
 
 mod navbar;
-pub use navbar::Navbar;
+pub use navbar::{NavGroup, NavItem, NavLink, Navbar, NavbarConfig};
+
+mod header_search;
+pub use header_search::HeaderSearch;
+
+mod user_menu;
+pub use user_menu::UserMenu;
 
 mod footer;
-pub use footer::Footer;
+pub use footer::{Footer, FooterColumn, FooterConfig, FooterLink, FooterSocial, FooterTheme};
 
 mod sidebar;
 pub use sidebar::Sidebar;
 
+mod toc;
+pub use toc::Toc;
+
 mod body;
 pub use body::Body;
 
@@ -1,82 +1,476 @@
 //! Navbar 导航栏组件
 //!
-//! 提供网站主导航菜单，包含多个导航链接。
+//! 提供网站主导航菜单，菜单由 [`NavbarConfig`] 描述，支持普通链接、下拉菜单和多列的大菜单面板。
+//!
+//! # 交互
+//!
+//! - 鼠标悬停或点击菜单按钮时展开面板，移出菜单区域或点击面板中的链接后收起
+//! - 菜单按钮上按 `Enter`、空格或 `↓` 展开面板并聚焦第一个链接
+//! - 面板中按 `↑`/`↓` 在链接间移动，`Home`/`End` 跳到首尾，`Esc` 收起面板并把焦点还给菜单按钮
+//! - 从面板的首尾链接按 `Tab` 离开时自动收起
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::{ToElement, scroll_to_anchor};
+use serde::{Deserialize, Serialize};
+
 use crate::Route;
-use dioxus::prelude::css_module;
-use dioxus_blocks_components::{Element, Link, NavigationTarget, ToElement, View};
 
 /// CSS 模块样式
 #[css_module("/assets/css/navbar.css")]
 struct Styles;
 
+/// 导航链接
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NavLink {
+    /// 链接文本
+    pub label: String,
+    /// 链接地址，站内路径或以 `http` 开头的外部地址
+    pub href: String,
+    /// 说明文字，显示在大菜单面板中的链接下方
+    pub description: Option<String>,
+}
+
+impl NavLink {
+    /// 创建导航链接
+    pub fn new(label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self {
+            label: label.into(),
+            href: href.into(),
+            description: None,
+        }
+    }
+
+    /// 设置说明文字
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+
+    /// 是否为外部链接，外部链接在新标签页打开
+    pub fn is_external(&self) -> bool {
+        self.href.starts_with("http://") || self.href.starts_with("https://")
+    }
+}
+
+/// 大菜单中的链接分组
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NavGroup {
+    /// 分组标题
+    pub title: String,
+    /// 分组中的链接
+    pub links: Vec<NavLink>,
+}
+
+impl NavGroup {
+    /// 创建链接分组
+    pub fn new(title: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            links: Vec::new(),
+        }
+    }
+
+    /// 添加链接
+    pub fn link(mut self, link: NavLink) -> Self {
+        self.links.push(link);
+        self
+    }
+}
+
+/// 导航菜单项
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+pub enum NavItem {
+    /// 普通链接
+    Link(NavLink),
+    /// 单列下拉菜单
+    Dropdown {
+        /// 菜单标题
+        label: String,
+        /// 菜单中的链接
+        links: Vec<NavLink>,
+    },
+    /// 多列大菜单
+    Mega {
+        /// 菜单标题
+        label: String,
+        /// 按列显示的链接分组
+        groups: Vec<NavGroup>,
+    },
+}
+
+impl NavItem {
+    /// 创建普通链接
+    pub fn link(label: impl Into<String>, href: impl Into<String>) -> Self {
+        Self::Link(NavLink::new(label, href))
+    }
+
+    /// 创建下拉菜单
+    pub fn dropdown(label: impl Into<String>, links: Vec<NavLink>) -> Self {
+        Self::Dropdown {
+            label: label.into(),
+            links,
+        }
+    }
+
+    /// 创建大菜单
+    pub fn mega(label: impl Into<String>, groups: Vec<NavGroup>) -> Self {
+        Self::Mega {
+            label: label.into(),
+            groups,
+        }
+    }
+
+    /// 菜单标题
+    pub fn label(&self) -> &str {
+        match self {
+            Self::Link(link) => &link.label,
+            Self::Dropdown { label, .. } | Self::Mega { label, .. } => label,
+        }
+    }
+
+    /// 面板中的所有链接，按键盘导航的顺序排列
+    fn panel_links(&self) -> Vec<&NavLink> {
+        match self {
+            Self::Link(_) => Vec::new(),
+            Self::Dropdown { links, .. } => links.iter().collect(),
+            Self::Mega { groups, .. } => groups.iter().flat_map(|group| &group.links).collect(),
+        }
+    }
+}
+
+/// 导航栏配置
+#[derive(Debug, Default, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(default)]
+pub struct NavbarConfig {
+    /// 菜单项
+    pub items: Vec<NavItem>,
+}
+
+impl NavbarConfig {
+    /// 本站使用的导航菜单
+    pub fn site() -> Self {
+        Self {
+            items: vec![
+                NavItem::link("首页", Route::HomeViewRoute {}.to_string()),
+                NavItem::link("博客", Route::BlogRoute { id: 1 }.to_string()),
+                NavItem::mega(
+                    "组件",
+                    vec![
+                        NavGroup::new("基础")
+                            .link(
+                                NavLink::new("Button 按钮", "/button")
+                                    .description("触发操作的按钮"),
+                            )
+                            .link(
+                                NavLink::new("Text 文本", "/text")
+                                    .description("标题、段落和行内文本"),
+                            )
+                            .link(
+                                NavLink::new("Link 链接", "/link")
+                                    .description("站内跳转和外部链接"),
+                            )
+                            .link(
+                                NavLink::new("Image 图片", "/image")
+                                    .description("图片展示与懒加载"),
+                            ),
+                        NavGroup::new("表单")
+                            .link(
+                                NavLink::new("Input 输入框", "/input").description("单行文本输入"),
+                            )
+                            .link(
+                                NavLink::new("InputNumber 数字输入框", "/input-number")
+                                    .description("带步进的数字输入"),
+                            )
+                            .link(
+                                NavLink::new("Checkbox 多选框", "/checkbox")
+                                    .description("一组选项中多选"),
+                            )
+                            .link(
+                                NavLink::new("SearchInput 搜索框", "/search-input")
+                                    .description("带范围和搜索记录"),
+                            ),
+                        NavGroup::new("数据展示")
+                            .link(NavLink::new("Card 卡片", "/card").description("承载内容的容器"))
+                            .link(
+                                NavLink::new("CalendarHeatmap 日历热力图", "/calendar-heatmap")
+                                    .description("按天展示活跃度"),
+                            )
+                            .link(
+                                NavLink::new("OrgChart 组织架构图", "/org-chart")
+                                    .description("树形层级结构"),
+                            )
+                            .link(
+                                NavLink::new("FilterBar 筛选栏", "/filter-bar")
+                                    .description("表格上方的筛选条件"),
+                            ),
+                        NavGroup::new("布局")
+                            .link(NavLink::new("Grid 栅格", "/grid").description("响应式栅格布局"))
+                            .link(
+                                NavLink::new("Layout 布局", "/layout").description("页面整体框架"),
+                            )
+                            .link(
+                                NavLink::new("Toolbar 工具栏", "/toolbar")
+                                    .description("左中右三段操作栏"),
+                            ),
+                    ],
+                ),
+                NavItem::dropdown(
+                    "模板",
+                    vec![
+                        NavLink::new("登录", "/login"),
+                        NavLink::new("注册", "/register"),
+                        NavLink::new("仪表盘", "/dashboard"),
+                        NavLink::new("设置", "/settings"),
+                        NavLink::new("错误页", "/500"),
+                    ],
+                ),
+                NavItem::link("GitHub", "https://github.com/silent-rain/dioxus-blocks"),
+            ],
+        }
+    }
+}
+
+/// 导航栏
 #[derive(Debug, Default, Clone)]
-pub struct Navbar {}
+pub struct Navbar {
+    config: NavbarConfig,
+}
+
+impl Navbar {
+    /// 根据配置创建导航栏
+    pub fn new(config: NavbarConfig) -> Self {
+        Self { config }
+    }
+}
 
 impl ToElement for Navbar {
     fn to_element(&self) -> Element {
-        View::new()
-            .style(|s| s.display("flex").align_items("center").gap("8px"))
-            .childrens(vec![
-                self.create_nav_link(Route::HomeViewRoute {}, "首页"),
-                self.create_nav_link(Route::BlogRoute { id: 1 }, "博客"),
-                self.create_nav_link("/guide", "指南"),
-                self.create_nav_link(NavigationTarget::<String>::from("/component"), "组件"),
-                self.create_external_link("https://github.com/silent-rain/dioxus-blocks", "GitHub"),
-            ])
-            .to_element()
+        // 同一时间只展开一个面板，保存展开的菜单项下标
+        let open = use_signal(|| None::<usize>);
+        let items = self.config.items.clone();
+
+        rsx! {
+            nav { class: Styles::navbar, aria_label: "主导航",
+                for (index , item) in items.into_iter().enumerate() {
+                    NavMenuItem { key: "{index}", index, item, open }
+                }
+            }
+        }
     }
 }
 
-impl Navbar {
-    /// 创建导航链接
-    ///
-    /// # 参数
-    ///
-    /// * `target` - 导航目标
-    /// * `text` - 链接文本
-    ///
-    /// # 返回值
-    ///
-    /// 返回一个导航链接组件
-    fn create_nav_link<T>(&self, target: T, text: &str) -> Link
-    where
-        T: Into<NavigationTarget>,
-    {
-        Link::default()
-            .to(target)
-            .text(text)
-            .class(Styles::nav_link)
-            .style(|s| {
-                s.padding("8px 16px")
-                    .color("var(--t-text-color-primary)")
-                    .text_decoration("none")
-                    .border_radius("4px")
-                    .transition("all var(--t-transition-duration)")
-            })
-    }
-
-    /// 创建外部链接
-    ///
-    /// # 参数
-    ///
-    /// * `url` - 外部 URL
-    /// * `text` - 链接文本
-    ///
-    /// # 返回值
-    ///
-    /// 返回一个外部链接组件
-    fn create_external_link(&self, url: &str, text: &str) -> Link {
-        Link::default()
-            .to(url)
-            .text(text)
-            .class(Styles::external_link)
-            .new_tab(true)
-            .style(|s| {
-                s.padding("8px 16px")
-                    .color("var(--t-text-color-primary)")
-                    .text_decoration("none")
-                    .border_radius("4px")
-                    .transition("all var(--t-transition-duration)")
-            })
+/// 渲染菜单项，普通链接直接渲染，下拉菜单和大菜单渲染为按钮和面板
+#[component]
+fn NavMenuItem(index: usize, item: NavItem, open: Signal<Option<usize>>) -> Element {
+    let count = item.panel_links().len();
+    let mut handles = use_signal(move || vec![None::<Rc<MountedData>>; count]);
+    let mut trigger = use_signal(|| None::<Rc<MountedData>>);
+    let mut active = use_signal(|| 0usize);
+    let mut focus_first = use_signal(|| false);
+
+    let focus = move |target: Option<Rc<MountedData>>| {
+        if let Some(target) = target {
+            spawn(async move {
+                let _ = target.set_focus(true).await;
+            });
+        }
+    };
+    let focus_link = move |i: usize| focus(handles.peek().get(i).cloned().flatten());
+    let mut close = move || {
+        if open() == Some(index) {
+            open.set(None);
+        }
+    };
+
+    if let NavItem::Link(link) = &item {
+        return rsx! {
+            NavAnchor { link: link.clone(), class: Styles::nav_link }
+        };
+    }
+
+    let is_open = open() == Some(index);
+    let panel_class = match &item {
+        NavItem::Mega { .. } => format!("{} {}", Styles::panel, Styles::mega),
+        _ => Styles::panel.to_string(),
+    };
+    let label = item.label().to_string();
+
+    // 面板中的链接，保存在线性序号上以便键盘导航
+    let mut seq = 0;
+    let mut anchor = |link: &NavLink| {
+        let i = seq;
+        seq += 1;
+        rsx! {
+            NavAnchor {
+                link: link.clone(),
+                class: Styles::menu_link,
+                role: "menuitem",
+                onmounted: move |event: MountedEvent| {
+                    let data = event.data();
+                    if let Some(slot) = handles.write().get_mut(i) {
+                        *slot = Some(data.clone());
+                    }
+                    if i == 0 && focus_first() {
+                        focus_first.set(false);
+                        focus(Some(data));
+                    }
+                },
+                onfocus: move |_| active.set(i),
+                onnavigate: move |_| close(),
+            }
+        }
+    };
+    let panel = match &item {
+        NavItem::Mega { groups, .. } => rsx! {
+            for group in groups.iter() {
+                div { class: Styles::group,
+                    div { class: Styles::group_title, "{group.title}" }
+                    for link in group.links.iter() {
+                        {anchor(link)}
+                    }
+                }
+            }
+        },
+        NavItem::Dropdown { links, .. } => rsx! {
+            for link in links.iter() {
+                {anchor(link)}
+            }
+        },
+        NavItem::Link(_) => rsx! {},
+    };
+
+    rsx! {
+        div {
+            class: Styles::item,
+            onmouseenter: move |_| open.set(Some(index)),
+            onmouseleave: move |_| close(),
+            button {
+                r#type: "button",
+                class: Styles::trigger,
+                aria_haspopup: "menu",
+                aria_expanded: is_open,
+                onmounted: move |event: MountedEvent| trigger.set(Some(event.data())),
+                // 悬停时面板已经展开，点击只负责展开，避免在触屏设备上展开后又立即收起
+                onclick: move |_| open.set(Some(index)),
+                onkeydown: move |event: KeyboardEvent| match event.key() {
+                    Key::Enter | Key::ArrowDown => {
+                        event.prevent_default();
+                        if is_open {
+                            focus_link(0);
+                        } else {
+                            focus_first.set(true);
+                            open.set(Some(index));
+                        }
+                    }
+                    Key::Character(c) if c == " " => {
+                        event.prevent_default();
+                        focus_first.set(true);
+                        open.set(Some(index));
+                    }
+                    Key::Escape => close(),
+                    _ => {}
+                },
+                "{label}"
+                span { class: Styles::caret, aria_hidden: "true", "▾" }
+            }
+            if is_open {
+                div {
+                    class: panel_class,
+                    role: "menu",
+                    aria_label: label.clone(),
+                    onkeydown: move |event: KeyboardEvent| {
+                        let current = active();
+                        let last = count.saturating_sub(1);
+                        match event.key() {
+                            Key::ArrowDown => {
+                                event.prevent_default();
+                                focus_link(if current >= last { 0 } else { current + 1 });
+                            }
+                            Key::ArrowUp => {
+                                event.prevent_default();
+                                focus_link(if current == 0 { last } else { current - 1 });
+                            }
+                            Key::Home => {
+                                event.prevent_default();
+                                focus_link(0);
+                            }
+                            Key::End => {
+                                event.prevent_default();
+                                focus_link(last);
+                            }
+                            Key::Escape => {
+                                event.prevent_default();
+                                close();
+                                focus(trigger.peek().clone());
+                            }
+                            Key::Tab => {
+                                let shift = event.modifiers().shift();
+                                if (shift && current == 0) || (!shift && current == last) {
+                                    close();
+                                }
+                            }
+                            _ => {}
+                        }
+                    },
+                    {panel}
+                }
+            }
+        }
+    }
+}
+
+/// 渲染导航链接，站内链接通过路由跳转，外部链接在新标签页打开
+#[component]
+fn NavAnchor(
+    link: NavLink,
+    class: String,
+    #[props(into)] role: Option<String>,
+    onmounted: Option<EventHandler<MountedEvent>>,
+    onfocus: Option<EventHandler<FocusEvent>>,
+    onnavigate: Option<EventHandler<()>>,
+) -> Element {
+    let external = link.is_external();
+    let href = link.href.clone();
+
+    rsx! {
+        a {
+            class,
+            role,
+            href: link.href.clone(),
+            target: if external { "_blank" },
+            rel: if external { "noopener noreferrer" },
+            onmounted: move |event| {
+                if let Some(handler) = onmounted {
+                    handler.call(event);
+                }
+            },
+            onfocus: move |event| {
+                if let Some(handler) = onfocus {
+                    handler.call(event);
+                }
+            },
+            onclick: move |event: MouseEvent| {
+                if !external {
+                    event.prevent_default();
+                    navigator().push(href.clone());
+                    // 带有锚点的地址跳转后滚动到对应的区块，如 `/button#不同尺寸`
+                    if let Some((_, anchor)) = href.split_once('#') {
+                        scroll_to_anchor(anchor);
+                    }
+                }
+                if let Some(handler) = onnavigate {
+                    handler.call(());
+                }
+            },
+            span { class: Styles::menu_label, "{link.label}" }
+            if let Some(description) = &link.description {
+                span { class: Styles::description, "{description}" }
+            }
+        }
     }
 }
@@ -1,11 +1,162 @@
 //! # Sidebar
-use dioxus_blocks_components::{Element, ToElement, View};
+//!
+//! 根据动态路由注册表生成的侧边栏菜单，未提供注册表或没有可显示的菜单时不渲染。
+//!
+//! 菜单项按注册时设置的分组显示在分组标题下，并可以显示徽标计数。点击底部按钮可以收起为只显示图标的窄栏，
+//! 窄栏中悬停菜单项时通过提示显示标题。收起状态和滚动位置保存在全局信号中，
+//! 离开布局的页面再返回时侧边栏会恢复到之前的状态，长菜单不会回到顶部。
+
+use dioxus::html::geometry::PixelsVector2D;
+use dioxus::prelude::*;
+use dioxus_blocks_components::{Element, ToElement};
+
+use crate::{RouteEntry, RouteRegistry};
+
+/// 侧边栏是否收起为窄栏
+static COLLAPSED: GlobalSignal<bool> = Signal::global(|| false);
+
+/// 侧边栏菜单的滚动位置
+static SCROLL_TOP: GlobalSignal<f64> = Signal::global(|| 0.0);
 
 #[derive(Debug, Default, Clone)]
 pub struct Sidebar {}
 
 impl ToElement for Sidebar {
     fn to_element(&self) -> Element {
-        View::new().class("t_sidebar").to_element()
+        let Some(registry) = RouteRegistry::current() else {
+            return rsx! {};
+        };
+        // 带参数的路由无法直接跳转，不显示在菜单中
+        let entries = registry
+            .entries()
+            .iter()
+            .filter(|entry| !entry.is_dynamic())
+            .collect::<Vec<_>>();
+        if entries.is_empty() {
+            return rsx! {};
+        }
+
+        let collapsed = COLLAPSED();
+        let current = router().full_route_string();
+        let width = if collapsed { "64px" } else { "200px" };
+        let groups = group_entries(entries);
+
+        rsx! {
+            nav {
+                class: "t_sidebar",
+                aria_label: "侧边栏菜单",
+                style: "display: flex; flex-direction: column; flex-shrink: 0; position: sticky; top: 0; width: {width}; height: 100vh; overflow-y: auto; box-sizing: border-box; padding: 16px 8px; background-color: var(--t-bg-color); border-right: 1px solid var(--t-border-color-light); transition: width 0.2s;",
+                onmounted: move |event: MountedEvent| async move {
+                    let top = *SCROLL_TOP.peek();
+                    if top > 0.0 {
+                        let _ = event
+                            .data()
+                            .scroll(PixelsVector2D::new(0.0, top), ScrollBehavior::Instant)
+                            .await;
+                    }
+                },
+                onscroll: move |event: ScrollEvent| {
+                    *SCROLL_TOP.write() = event.data().scroll_top();
+                },
+                div { style: "display: flex; flex-direction: column; gap: 4px; flex: 1;",
+                    for (group , items) in groups {
+                        if let Some(group) = group {
+                            if collapsed {
+                                div { style: "margin: 8px 12px; border-top: 1px solid var(--t-border-color-light);" }
+                            } else {
+                                div {
+                                    class: "t_sidebar__group",
+                                    style: "padding: 12px 16px 4px; font-size: 12px; color: var(--t-text-color-secondary);",
+                                    "{group}"
+                                }
+                            }
+                        }
+                        for entry in items {
+                            {menu_item(entry, current == entry.path(), collapsed)}
+                        }
+                    }
+                }
+                button {
+                    r#type: "button",
+                    class: "t_sidebar__toggle",
+                    aria_label: if collapsed { "展开菜单" } else { "收起菜单" },
+                    title: if collapsed { "展开菜单" } else { "收起菜单" },
+                    style: "margin-top: 8px; padding: 8px; border: none; border-radius: var(--t-border-radius-base); background: transparent; color: var(--t-text-color-secondary); cursor: pointer;",
+                    onclick: move |_| COLLAPSED.with_mut(|collapsed| *collapsed = !*collapsed),
+                    if collapsed {
+                        "»"
+                    } else {
+                        "«"
+                    }
+                }
+            }
+        }
+    }
+}
+
+/// 按分组整理菜单项，分组按首次出现的顺序排列，组内保持注册顺序
+fn group_entries(entries: Vec<&RouteEntry>) -> Vec<(Option<String>, Vec<&RouteEntry>)> {
+    let mut groups: Vec<(Option<String>, Vec<&RouteEntry>)> = Vec::new();
+    for entry in entries {
+        let group = entry.group().map(str::to_string);
+        match groups.iter_mut().find(|(name, _)| *name == group) {
+            Some((_, items)) => items.push(entry),
+            None => groups.push((group, vec![entry])),
+        }
+    }
+    groups
+}
+
+/// 渲染菜单项，收起时只显示图标，标题通过悬停提示显示
+fn menu_item(entry: &RouteEntry, active: bool, collapsed: bool) -> Element {
+    let title = entry.title().to_string();
+    // 没有图标的菜单项在窄栏中显示标题的首字
+    let icon = entry
+        .icon()
+        .map(str::to_string)
+        .or_else(|| collapsed.then(|| title.chars().take(1).collect()));
+    let badge = entry.badge().filter(|count| *count > 0).map(|count| {
+        if count > 99 {
+            "99+".to_string()
+        } else {
+            count.to_string()
+        }
+    });
+    let tooltip = collapsed.then(|| match &badge {
+        Some(badge) => format!("{title} ({badge})"),
+        None => title.clone(),
+    });
+    let colors = if active {
+        "color: var(--t-color-primary); background-color: var(--t-color-primary-light);"
+    } else {
+        "color: var(--t-text-color-regular);"
+    };
+    let justify = if collapsed { "center" } else { "flex-start" };
+    let badge_position = if collapsed {
+        "position: absolute; top: 2px; right: 6px;"
+    } else {
+        "margin-left: auto;"
+    };
+
+    rsx! {
+        Link {
+            key: "{entry.path()}",
+            to: entry.path().to_string(),
+            title: tooltip,
+            style: "position: relative; display: flex; align-items: center; justify-content: {justify}; gap: 8px; padding: 8px 16px; border-radius: var(--t-border-radius-base); text-decoration: none; {colors}",
+            if let Some(icon) = icon {
+                span { "{icon}" }
+            }
+            if !collapsed {
+                span { "{title}" }
+            }
+            if let Some(badge) = badge {
+                span {
+                    class: "t_sidebar__badge",
+                    style: "{badge_position} min-width: 18px; padding: 0 6px; box-sizing: border-box; border-radius: 9px; font-size: 12px; line-height: 18px; text-align: center; color: #fff; background-color: var(--t-color-danger);",
+                    "{badge}"
+                }
+            }
+        }
     }
 }
@@ -0,0 +1,169 @@
+//! Toc 页面目录
+//!
+//! 扫描主内容区（`.t_body__main`）中的 `h2`/`h3` 标题（通常由 `Text::h2`/`Text::h3` 渲染），在页面右侧显示固定的目录，
+//! 滚动时高亮当前阅读的章节，点击目录项平滑滚动到对应标题，并把锚点写入地址栏便于分享。
+//! 位于设置了锚点的卡片（[`Card::anchor`](dioxus_blocks_components::Card::anchor)）中的标题使用卡片的 id，
+//! 其他没有 id 的标题会自动分配 id。
+//!
+//! 切换路由后重新扫描，懒加载的页面内容渲染完成后也会更新。标题少于两个、
+//! 窗口较窄或无法执行脚本（如服务端渲染）时不显示。
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::{ToElement, browser::run_script};
+use serde::Deserialize;
+
+/// CSS 模块样式
+#[css_module("/assets/css/toc.css")]
+struct Styles;
+
+/// 扫描标题并监听滚动，标题或当前章节变化时发送给组件
+const TOC_SCRIPT: &str = r#"
+window.__tToc?.();
+const root = document.querySelector('.t_body__main');
+if (!root) return;
+let nodes = [];
+let sent = '';
+let active = null;
+const scan = () => {
+  nodes = [...root.querySelectorAll('h2, h3')].filter((el) => el.textContent.trim());
+  const items = nodes.map((el, i) => {
+    const card = el.tagName === 'H2' ? el.closest('.t-card[id]') : null;
+    if (!el.id && !card) el.id = `section-${i + 1}`;
+    el.dataset.tocId = card?.id || el.id;
+    return { id: el.dataset.tocId, text: el.textContent.trim(), level: el.tagName === 'H2' ? 2 : 3 };
+  });
+  const json = JSON.stringify(items);
+  if (json !== sent) {
+    sent = json;
+    active = null;
+    dioxus.send({ type: 'headings', items });
+  }
+};
+const update = () => {
+  let current = nodes[0]?.dataset.tocId ?? null;
+  for (const el of nodes) {
+    if (el.getBoundingClientRect().top > 80) break;
+    current = el.dataset.tocId;
+  }
+  if (current && current !== active) {
+    active = current;
+    dioxus.send({ type: 'active', id: current });
+  }
+};
+let frame = 0;
+const schedule = () => {
+  if (frame) return;
+  frame = requestAnimationFrame(() => {
+    frame = 0;
+    scan();
+    update();
+  });
+};
+const observer = new MutationObserver(schedule);
+observer.observe(root, { childList: true, subtree: true });
+window.addEventListener('scroll', schedule, { passive: true });
+window.__tToc = () => {
+  observer.disconnect();
+  window.removeEventListener('scroll', schedule);
+};
+schedule();
+await new Promise(() => {});
+"#;
+
+/// 目录中的标题
+#[derive(Debug, Clone, PartialEq, Deserialize)]
+struct Heading {
+    id: String,
+    text: String,
+    level: u8,
+}
+
+/// 脚本发送的消息
+#[derive(Debug, Deserialize)]
+#[serde(tag = "type", rename_all = "lowercase")]
+enum TocMessage {
+    /// 标题列表
+    Headings { items: Vec<Heading> },
+    /// 当前章节的标题 id
+    Active { id: String },
+}
+
+#[derive(Debug, Default, Clone)]
+pub struct Toc {}
+
+impl ToElement for Toc {
+    fn to_element(&self) -> Element {
+        // 以路由作为 key，切换页面时重新创建目录并扫描标题
+        let path = router().full_route_string();
+        rsx! {
+            TocOutline { key: "{path}" }
+        }
+    }
+}
+
+#[component]
+fn TocOutline() -> Element {
+    let mut headings = use_signal(Vec::<Heading>::new);
+    let mut active = use_signal(|| None::<String>);
+
+    use_future(move || async move {
+        if cfg!(feature = "server") {
+            return;
+        }
+        let mut eval = document::eval(TOC_SCRIPT);
+        while let Ok(message) = eval.recv::<TocMessage>().await {
+            match message {
+                TocMessage::Headings { items } => headings.set(items),
+                TocMessage::Active { id } => active.set(Some(id)),
+            }
+        }
+    });
+
+    if headings.read().len() < 2 {
+        return rsx! {};
+    }
+
+    let current = active();
+    rsx! {
+        nav { class: Styles::toc, aria_label: "页面目录",
+            div { class: Styles::title, "目录" }
+            ul { class: Styles::list,
+                for heading in headings() {
+                    li { key: "{heading.id}",
+                        a {
+                            class: {
+                                let mut class = Styles::link.to_string();
+                                if heading.level > 2 {
+                                    class = format!("{class} {}", Styles::sub);
+                                }
+                                if current.as_deref() == Some(heading.id.as_str()) {
+                                    class = format!("{class} {}", Styles::active);
+                                }
+                                class
+                            },
+                            href: "#{heading.id}",
+                            title: "{heading.text}",
+                            aria_current: if current.as_deref() == Some(heading.id.as_str()) { "location" },
+                            onclick: {
+                                let id = heading.id.clone();
+                                move |event: MouseEvent| {
+                                    // 由脚本负责滚动，锚点通过 replaceState 写入地址栏，不触发路由跳转
+                                    event.prevent_default();
+                                    active.set(Some(id.clone()));
+                                    let js = format!(
+                                        "document.getElementById({id:?})?.scrollIntoView({{ behavior: 'smooth', block: 'start' }}); \
+                                         history.replaceState(history.state, '', '#' + encodeURIComponent({id:?}));"
+                                    );
+                                    spawn(async move {
+                                        run_script(&js).await;
+                                    });
+                                }
+                            },
+                            "{heading.text}"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
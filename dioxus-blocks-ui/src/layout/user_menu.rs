@@ -0,0 +1,184 @@
+//! UserMenu 用户菜单
+//!
+//! 显示在头部右侧的用户会话入口，读取 [`AuthProvider`](crate::AuthProvider) 提供的登录状态：
+//! 未登录时显示登录按钮，登录后显示头像和名称，点击展开个人资料、设置和退出登录菜单。
+//! 未设置跳转目标的菜单项不显示，不在 AuthProvider 中使用时不渲染任何内容。
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::{Button, ToElement};
+
+use crate::AuthContext;
+
+/// CSS 模块样式
+#[css_module("/assets/css/user_menu.css")]
+struct Styles;
+
+/// 用户菜单
+#[derive(Debug, Clone, Default)]
+pub struct UserMenu {
+    /// 登录页
+    login: Option<NavigationTarget>,
+    /// 个人资料页
+    profile: Option<NavigationTarget>,
+    /// 设置页
+    settings: Option<NavigationTarget>,
+    /// 退出登录后跳转的页面
+    after_logout: Option<NavigationTarget>,
+}
+
+impl UserMenu {
+    /// 创建用户菜单
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置登录页，未登录时点击登录按钮跳转到该页面
+    pub fn login(mut self, target: impl Into<NavigationTarget>) -> Self {
+        self.login = Some(target.into());
+        self
+    }
+
+    /// 设置个人资料页
+    pub fn profile(mut self, target: impl Into<NavigationTarget>) -> Self {
+        self.profile = Some(target.into());
+        self
+    }
+
+    /// 设置设置页
+    pub fn settings(mut self, target: impl Into<NavigationTarget>) -> Self {
+        self.settings = Some(target.into());
+        self
+    }
+
+    /// 设置退出登录后跳转的页面，未设置时停留在当前页面
+    pub fn after_logout(mut self, target: impl Into<NavigationTarget>) -> Self {
+        self.after_logout = Some(target.into());
+        self
+    }
+}
+
+impl ToElement for UserMenu {
+    fn to_element(&self) -> Element {
+        let mut open = use_signal(|| false);
+        let mut trigger = use_signal(|| None::<Rc<MountedData>>);
+        let navigator = navigator();
+
+        let Some(mut auth) = AuthContext::current() else {
+            return rsx! {};
+        };
+        let Some(user) = auth.user() else {
+            let login = self.login.clone();
+            return Button::new()
+                .text("登录")
+                .as_primary()
+                .onclick(move |_| {
+                    if let Some(login) = login.clone() {
+                        navigator.push(login);
+                    }
+                })
+                .to_element();
+        };
+
+        let name = user.display_name().to_string();
+        let initial = name
+            .chars()
+            .next()
+            .unwrap_or('?')
+            .to_uppercase()
+            .to_string();
+        let avatar = user.avatar.clone();
+        let profile = self.profile.clone();
+        let settings = self.settings.clone();
+        let after_logout = self.after_logout.clone();
+
+        let mut close = move || {
+            open.set(false);
+            if let Some(trigger) = trigger.peek().clone() {
+                spawn(async move {
+                    let _ = trigger.set_focus(true).await;
+                });
+            }
+        };
+
+        rsx! {
+            div { class: Styles::user_menu,
+                button {
+                    r#type: "button",
+                    class: Styles::trigger,
+                    aria_haspopup: "menu",
+                    aria_expanded: open(),
+                    onmounted: move |event: MountedEvent| trigger.set(Some(event.data())),
+                    onclick: move |_| open.toggle(),
+                    span { class: Styles::avatar, aria_hidden: "true",
+                        if let Some(avatar) = avatar {
+                            img { src: avatar, alt: "" }
+                        } else {
+                            "{initial}"
+                        }
+                    }
+                    span { class: Styles::name, "{name}" }
+                    span { class: Styles::caret, aria_hidden: "true", "▾" }
+                }
+                if open() {
+                    // 点击菜单以外的区域时收起
+                    div { class: Styles::backdrop, onclick: move |_| open.set(false) }
+                    div {
+                        class: Styles::menu,
+                        role: "menu",
+                        onkeydown: move |event: KeyboardEvent| {
+                            if event.key() == Key::Escape {
+                                close();
+                            }
+                        },
+                        div { class: Styles::header,
+                            div { class: Styles::header_name, "{name}" }
+                            div { class: Styles::header_username, "@{user.username}" }
+                        }
+                        if let Some(profile) = profile {
+                            button {
+                                r#type: "button",
+                                class: Styles::item,
+                                role: "menuitem",
+                                onclick: move |_| {
+                                    open.set(false);
+                                    navigator.push(profile.clone());
+                                },
+                                "个人资料"
+                            }
+                        }
+                        if let Some(settings) = settings {
+                            button {
+                                r#type: "button",
+                                class: Styles::item,
+                                role: "menuitem",
+                                onclick: move |_| {
+                                    open.set(false);
+                                    navigator.push(settings.clone());
+                                },
+                                "设置"
+                            }
+                        }
+                        div { class: Styles::divider, role: "separator" }
+                        button {
+                            r#type: "button",
+                            class: "{Styles::item} {Styles::danger}",
+                            role: "menuitem",
+                            onclick: move |_| {
+                                open.set(false);
+                                auth.logout();
+                                if let Some(target) = after_logout.clone() {
+                                    navigator.push(target);
+                                }
+                            },
+                            "退出登录"
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
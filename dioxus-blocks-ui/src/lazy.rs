@@ -0,0 +1,97 @@
+//! # 懒加载路由
+//!
+//! 首次渲染时挂起，在后台任务中创建被包装的页面（并可选地预先下载页面依赖的资源），
+//! 完成后再渲染页面，从而降低首屏渲染开销。
+//!
+//! 加载期间由外层的 [`SkeletonBoundary`](dioxus_blocks_components::SkeletonBoundary) 显示页面骨架屏，
+//! 在 [`RouteProgress`](dioxus_blocks_components::RouteProgress) 中使用时页面顶部同时显示进度条。
+//!
+//! ```rust,ignore
+//! LazyRoute::new(|| HeavyChartView::default()).asset("/assets/echarts.min.js")
+//! ```
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{ProgressTracker, ToElement, browser};
+
+use crate::element::{IsolatedElement, SharedElement};
+
+/// 懒加载路由
+#[derive(Clone)]
+pub struct LazyRoute {
+    /// 页面构造函数
+    init: Rc<dyn Fn() -> Rc<dyn ToElement>>,
+    /// 渲染前需要下载的资源地址
+    assets: Vec<String>,
+}
+
+impl std::fmt::Debug for LazyRoute {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("LazyRoute")
+            .field("assets", &self.assets)
+            .finish()
+    }
+}
+
+impl LazyRoute {
+    /// 创建懒加载路由，`init` 会在首次渲染之后才被调用
+    pub fn new<T, F>(init: F) -> Self
+    where
+        T: ToElement + 'static,
+        F: Fn() -> T + 'static,
+    {
+        Self {
+            init: Rc::new(move || Rc::new(init()) as Rc<dyn ToElement>),
+            assets: Vec::new(),
+        }
+    }
+
+    /// 添加渲染前需要下载的资源，仅在浏览器环境中生效
+    pub fn asset(mut self, url: impl Into<String>) -> Self {
+        self.assets.push(url.into());
+        self
+    }
+}
+
+/// 下载资源，非浏览器环境直接跳过
+async fn fetch_assets(assets: &[String]) {
+    if assets.is_empty() {
+        return;
+    }
+    let urls = assets
+        .iter()
+        .map(|url| format!("{:?}", url))
+        .collect::<Vec<_>>()
+        .join(", ");
+    let js = format!(
+        "await Promise.all([{}].map((url) => fetch(url).catch(() => null))); return true;",
+        urls
+    );
+    browser::run_script(&js).await;
+}
+
+impl ToElement for LazyRoute {
+    fn to_element(&self) -> Element {
+        let init = self.init.clone();
+        let assets = self.assets.clone();
+        let progress = ProgressTracker::current();
+        let view = use_resource(move || {
+            let init = init.clone();
+            let assets = assets.clone();
+            async move {
+                let _guard = progress.map(|progress| progress.start());
+                // 先让出一帧，保证骨架屏能先绘制出来
+                browser::sleep(0).await;
+                fetch_assets(&assets).await;
+                SharedElement(init())
+            }
+        });
+
+        let element = view.suspend()?.read().clone();
+        rsx! {
+            IsolatedElement { element }
+        }
+    }
+}
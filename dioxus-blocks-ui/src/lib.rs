@@ -6,4 +6,24 @@ pub use layout::{Layout, LayoutRoute};
 mod route;
 pub use route::Route;
 
+mod element;
+
+mod guard;
+pub use guard::{
+    AuthContext, AuthProvider, AuthUser, GuardResult, Guarded, RequireAuth, RouteGuard, use_auth,
+};
+
+mod permission;
+pub use permission::{Can, Denied, PermissionProvider, RolePermissions, use_permission};
+
+mod lazy;
+pub use lazy::LazyRoute;
+
+mod registry;
+pub use registry::{RouteEntry, RouteParams, RouteRegistry, RouteRegistryBuilder};
+
+pub mod search;
+
+pub mod templates;
+
 pub mod views;
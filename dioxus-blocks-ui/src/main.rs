@@ -1,7 +1,11 @@
 // The dioxus prelude contains a ton of common items used in dioxus apps. It's a good idea to import wherever you
 // need dioxus
 use dioxus::prelude::*;
-use dioxus_blocks_ui::Route;
+use dioxus_blocks_ui::{
+    Route, RouteRegistry,
+    search::{Document, SearchIndex, site_documents},
+    views::{RegistryView, registry_detail},
+};
 
 // We can import assets in dioxus with the `asset!` macro. This macro takes a path to an asset relative to the crate root.
 // The macro returns an `Asset` type that will display as the path to the asset in the browser or a local path in desktop bundles.
@@ -22,6 +26,29 @@ fn main() {
 /// Components should be annotated with `#[component]` to support props, better error messages, and autocomplete
 #[component]
 fn App() -> Element {
+    // Pages registered at runtime are resolved by the catch-all route and listed in the sidebar,
+    // so embedding applications can add their own pages without touching the Route enum.
+    use_context_provider(|| {
+        RouteRegistry::builder()
+            .register::<RegistryView>("/registry")
+            .title("动态路由")
+            .icon("🧭")
+            .group("扩展页面")
+            .register_with("/registry/:name", registry_detail)
+            .build()
+    });
+
+    // The search index is built once at startup from the site documents and powers the header search.
+    use_context_provider(|| {
+        SearchIndex::builder()
+            .documents(site_documents())
+            .document(
+                Document::new("动态路由", "/registry")
+                    .body("在运行时注册页面，无需修改 Route 枚举，侧边栏菜单根据注册表自动生成。"),
+            )
+            .build()
+    });
+
     // The `rsx!` macro lets us define HTML inside of rust. It expands to an Element with all of our HTML inside.
     rsx! {
         // In addition to element and text (which we will see later), rsx can contain other components. In this case,
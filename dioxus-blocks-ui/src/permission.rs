@@ -0,0 +1,347 @@
+//! # 权限控制
+//!
+//! 根据当前登录用户判断是否拥有某项权限，用于隐藏或禁用按钮、菜单项等操作入口。
+//! 权限的判断规则由 [`PermissionProvider`] 决定，通过 [`AuthProvider::permissions`] 注册，
+//! 内置的 [`RolePermissions`] 按角色授予权限，也可以传入闭包对接后端返回的权限列表。
+//!
+//! 权限使用 `资源:操作` 的格式，如 `posts:write`。授予 `posts:*` 表示拥有 `posts` 下的全部操作，
+//! 授予 `*` 表示拥有全部权限。未注册 PermissionProvider 时拒绝所有权限。
+//!
+//! [`RolePermissions`] 中为未登录用户授予的权限同样授予所有已登录用户，登录后不会失去访客可用的操作。
+//!
+//! ```rust,ignore
+//! // 在布局中注册权限规则
+//! AuthProvider::new().permissions(
+//!     RolePermissions::new()
+//!         .grant("admin", ["*"])
+//!         .grant("editor", ["posts:read", "posts:write"]),
+//! )
+//!
+//! // 无权限时隐藏
+//! Can::new("posts:write").children(Button::new().text("发布文章"))
+//!
+//! // 无权限时禁用
+//! Can::new("posts:delete").denied(Denied::Disable).children(Button::new().text("删除"))
+//!
+//! // 在组件中读取
+//! let can_write = use_permission("posts:write");
+//! Button::new().text("保存").disabled(!can_write())
+//! ```
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::ToElement;
+
+use crate::element::{IsolatedElement, SharedElement};
+use crate::{AuthContext, AuthUser};
+
+/// 权限判断规则
+pub trait PermissionProvider {
+    /// 判断用户是否拥有指定权限，未登录时 `user` 为 `None`
+    fn can(&self, user: Option<&AuthUser>, permission: &str) -> bool;
+}
+
+impl<F> PermissionProvider for F
+where
+    F: Fn(Option<&AuthUser>, &str) -> bool,
+{
+    fn can(&self, user: Option<&AuthUser>, permission: &str) -> bool {
+        self(user, permission)
+    }
+}
+
+/// 按角色授予权限
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RolePermissions {
+    /// 角色拥有的权限，键为角色
+    grants: BTreeMap<String, Vec<String>>,
+    /// 未登录用户拥有的权限，已登录用户同样拥有
+    guest: Vec<String>,
+}
+
+impl RolePermissions {
+    /// 创建按角色授予的权限规则
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// 为角色授予权限
+    pub fn grant<P>(
+        mut self,
+        role: impl Into<String>,
+        permissions: impl IntoIterator<Item = P>,
+    ) -> Self
+    where
+        P: Into<String>,
+    {
+        self.grants
+            .entry(role.into())
+            .or_default()
+            .extend(permissions.into_iter().map(Into::into));
+        self
+    }
+
+    /// 为未登录用户授予权限，已登录用户无论角色如何都同样拥有这些权限
+    pub fn guest<P>(mut self, permissions: impl IntoIterator<Item = P>) -> Self
+    where
+        P: Into<String>,
+    {
+        self.guest.extend(permissions.into_iter().map(Into::into));
+        self
+    }
+}
+
+impl PermissionProvider for RolePermissions {
+    fn can(&self, user: Option<&AuthUser>, permission: &str) -> bool {
+        let granted = |grants: &[String]| grants.iter().any(|grant| matches(grant, permission));
+        if granted(&self.guest) {
+            return true;
+        }
+        user.is_some_and(|user| {
+            user.roles
+                .iter()
+                .filter_map(|role| self.grants.get(role))
+                .any(|grants| granted(grants))
+        })
+    }
+}
+
+/// 授予的权限是否包含指定权限，支持 `*` 和 `资源:*` 通配
+///
+/// 通配只在 `:` 之后生效，`user:*` 不包含 `username:read`，`user*` 只匹配字面量 `user*`。
+fn matches(grant: &str, permission: &str) -> bool {
+    if grant == "*" || grant == permission {
+        return true;
+    }
+    grant
+        .strip_suffix('*')
+        .is_some_and(|prefix| prefix.ends_with(':') && permission.starts_with(prefix))
+}
+
+/// 权限规则上下文，由 [`AuthProvider`](crate::AuthProvider) 提供
+#[derive(Clone)]
+pub(crate) struct Permissions(pub(crate) Rc<dyn PermissionProvider>);
+
+/// 判断当前登录用户是否拥有指定权限
+///
+/// 登录状态变化时自动更新。不在 [`AuthProvider`](crate::AuthProvider) 中调用或未注册权限规则时返回 `false`。
+pub fn use_permission(permission: impl Into<String>) -> Memo<bool> {
+    let permission = use_hook(|| permission.into());
+    let auth = AuthContext::current();
+    let provider = try_consume_context::<Permissions>();
+    use_memo(move || {
+        let Some(provider) = &provider else {
+            return false;
+        };
+        let user = auth.and_then(|auth| auth.user());
+        provider.0.can(user.as_ref(), &permission)
+    })
+}
+
+/// 无权限时的处理方式
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Denied {
+    /// 隐藏内容，设置了 [`Can::fallback`] 时显示替代内容
+    #[default]
+    Hide,
+    /// 显示为禁用状态，内容无法点击和聚焦
+    Disable,
+}
+
+/// 按权限显示内容
+#[derive(Clone)]
+pub struct Can {
+    /// 需要的权限
+    permission: String,
+    /// 无权限时的处理方式
+    denied: Denied,
+    /// 无权限时的提示文本，禁用时显示在鼠标悬停提示中
+    reason: String,
+    /// 无权限且隐藏时显示的替代内容
+    fallback: Option<Rc<dyn ToElement>>,
+    /// 子元素列表
+    childrens: Vec<Rc<dyn ToElement>>,
+}
+
+impl std::fmt::Debug for Can {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("Can")
+            .field("permission", &self.permission)
+            .field("denied", &self.denied)
+            .field("reason", &self.reason)
+            .field("fallback", &self.fallback.is_some())
+            .field("childrens", &self.childrens.len())
+            .finish()
+    }
+}
+
+impl Can {
+    /// 创建权限包装，拥有 `permission` 权限时显示子元素
+    pub fn new(permission: impl Into<String>) -> Self {
+        Self {
+            permission: permission.into(),
+            denied: Denied::default(),
+            reason: "没有操作权限".to_string(),
+            fallback: None,
+            childrens: Vec::new(),
+        }
+    }
+
+    /// 设置无权限时的处理方式
+    pub fn denied(mut self, denied: Denied) -> Self {
+        self.denied = denied;
+        self
+    }
+
+    /// 设置无权限时的提示文本
+    pub fn reason(mut self, reason: impl Into<String>) -> Self {
+        self.reason = reason.into();
+        self
+    }
+
+    /// 设置无权限且隐藏时显示的替代内容
+    pub fn fallback<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.fallback = Some(Rc::new(component));
+        self
+    }
+
+    /// 添加子元素
+    pub fn children<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.childrens.push(Rc::new(component));
+        self
+    }
+}
+
+impl ToElement for Can {
+    fn to_element(&self) -> Element {
+        let allowed = use_permission(self.permission.clone());
+
+        // 子元素以独立组件渲染，权限变化时它们的 hooks 不会影响外层组件
+        let childrens = self
+            .childrens
+            .iter()
+            .map(|children| SharedElement(children.clone()))
+            .collect::<Vec<_>>();
+
+        if allowed() {
+            return rsx! {
+                for element in childrens {
+                    IsolatedElement { element }
+                }
+            };
+        }
+
+        match self.denied {
+            Denied::Hide => match &self.fallback {
+                Some(fallback) => rsx! {
+                    IsolatedElement { element: SharedElement(fallback.clone()) }
+                },
+                None => rsx! {},
+            },
+            Denied::Disable => rsx! {
+                span {
+                    title: "{self.reason}",
+                    aria_disabled: "true",
+                    style: "display: inline-block; cursor: not-allowed; opacity: 0.5;",
+                    span {
+                        // inert 阻止内容被点击和聚焦，pointer-events 兼容不支持 inert 的浏览器
+                        "inert": "true",
+                        style: "display: contents; pointer-events: none;",
+                        for element in childrens {
+                            IsolatedElement { element }
+                        }
+                    }
+                }
+            },
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_matches() {
+        // 全部权限
+        assert!(matches("*", "posts:write"));
+        assert!(matches("*", "anything"));
+        // 资源通配
+        assert!(matches("posts:*", "posts:write"));
+        assert!(matches("posts:*", "posts:comments:delete"));
+        assert!(!matches("posts:*", "posts"));
+        assert!(!matches("posts:*", "users:write"));
+        // 精确匹配
+        assert!(matches("posts:read", "posts:read"));
+        assert!(!matches("posts:read", "posts:write"));
+        assert!(!matches("posts:read", "posts:reader"));
+        assert!(!matches("posts:read", "Posts:read"));
+        // 前缀相同的资源不能通过通配匹配
+        assert!(!matches("user:*", "username:read"));
+        assert!(!matches("user*", "username:read"));
+        assert!(!matches("user:r*", "user:read"));
+        assert!(matches("user*", "user*"));
+    }
+
+    fn rules() -> RolePermissions {
+        RolePermissions::new()
+            .grant("admin", ["*"])
+            .grant("editor", ["posts:*", "comments:read"])
+            .grant("viewer", ["posts:read"])
+            .guest(["posts:read", "home:view"])
+    }
+
+    #[test]
+    fn test_role_permissions() {
+        let rules = rules();
+        let admin = AuthUser::new("root").role("admin");
+        assert!(rules.can(Some(&admin), "users:delete"));
+
+        let editor = AuthUser::new("alice").role("editor");
+        assert!(rules.can(Some(&editor), "posts:delete"));
+        assert!(rules.can(Some(&editor), "comments:read"));
+        assert!(!rules.can(Some(&editor), "comments:delete"));
+
+        // 多个角色的权限合并
+        let both = AuthUser::new("bob").role("viewer").role("editor");
+        assert!(rules.can(Some(&both), "posts:write"));
+        assert!(rules.can(Some(&both), "comments:read"));
+    }
+
+    #[test]
+    fn test_unknown_role() {
+        let rules = rules();
+        let user = AuthUser::new("eve").role("intruder");
+        assert!(!rules.can(Some(&user), "posts:write"));
+        assert!(!rules.can(Some(&user), "users:delete"));
+        let no_roles = AuthUser::new("nobody");
+        assert!(!rules.can(Some(&no_roles), "comments:read"));
+        assert!(!RolePermissions::new().can(Some(&user), "posts:read"));
+    }
+
+    #[test]
+    fn test_guest_and_logged_in() {
+        let rules = rules();
+        // 未登录用户只拥有访客权限
+        assert!(rules.can(None, "posts:read"));
+        assert!(rules.can(None, "home:view"));
+        assert!(!rules.can(None, "posts:write"));
+
+        // 已登录用户同样拥有访客权限，包括没有任何角色或角色未注册的用户
+        let viewer = AuthUser::new("carol").role("viewer");
+        assert!(rules.can(Some(&viewer), "home:view"));
+        assert!(!rules.can(Some(&viewer), "posts:write"));
+        let user = AuthUser::new("eve").role("intruder");
+        assert!(rules.can(Some(&user), "home:view"));
+        assert!(!rules.can(Some(&user), "comments:read"));
+    }
+}
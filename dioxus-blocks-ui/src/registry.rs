@@ -0,0 +1,417 @@
+//! # 动态路由注册
+//!
+//! 在运行时注册页面，嵌入本布局的应用无需修改 [`Route`](crate::Route) 枚举即可添加自己的页面。
+//! 注册表通过上下文提供，未被 `Route` 枚举匹配的路径会交给注册表解析，
+//! 侧边栏菜单也会根据注册表中的元数据自动生成。
+//!
+//! 路径支持 `:name` 形式的动态参数，参数值经过百分号解码后通过 [`RouteParams`] 传给渲染函数。
+//! 多个路由都能匹配同一路径时使用最先注册的路由，因此静态路径应注册在同一层级的动态路径之前。
+//!
+//! ```rust,ignore
+//! let registry = RouteRegistry::builder()
+//!     .register::<ReportView>("/reports")
+//!     .title("报表")
+//!     .icon("📈")
+//!     .group("数据分析")
+//!     .badge(move || unread())
+//!     .register_with("/reports/:id", |params| {
+//!         ReportDetail::new(params["id"].clone()).to_element()
+//!     })
+//!     .build();
+//!
+//! // 在 Router 之上提供注册表
+//! use_context_provider(|| registry);
+//! ```
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::ToElement;
+
+/// 路径参数，键为参数名，值为路径中对应的片段解码后的内容
+pub type RouteParams = BTreeMap<String, String>;
+
+/// 注册的路由条目
+#[derive(Clone)]
+pub struct RouteEntry {
+    /// 路径模式，如 `/blog/:id`
+    path: String,
+    /// 菜单标题
+    title: String,
+    /// 菜单图标
+    icon: Option<String>,
+    /// 菜单分组标题
+    group: Option<String>,
+    /// 菜单徽标计数
+    badge: Option<Rc<dyn Fn() -> usize>>,
+    /// 页面渲染函数
+    render: Rc<dyn Fn(&RouteParams) -> Element>,
+}
+
+impl std::fmt::Debug for RouteEntry {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("RouteEntry")
+            .field("path", &self.path)
+            .field("title", &self.title)
+            .field("icon", &self.icon)
+            .field("group", &self.group)
+            .field("badge", &self.badge.as_ref().map(|badge| badge()))
+            .finish()
+    }
+}
+
+impl PartialEq for RouteEntry {
+    fn eq(&self, other: &Self) -> bool {
+        self.path == other.path
+            && self.title == other.title
+            && self.icon == other.icon
+            && self.group == other.group
+            && match (&self.badge, &other.badge) {
+                (Some(a), Some(b)) => Rc::ptr_eq(a, b),
+                (None, None) => true,
+                _ => false,
+            }
+            && Rc::ptr_eq(&self.render, &other.render)
+    }
+}
+
+impl RouteEntry {
+    /// 路径模式
+    pub fn path(&self) -> &str {
+        &self.path
+    }
+
+    /// 菜单标题
+    pub fn title(&self) -> &str {
+        &self.title
+    }
+
+    /// 菜单图标
+    pub fn icon(&self) -> Option<&str> {
+        self.icon.as_deref()
+    }
+
+    /// 菜单分组标题
+    pub fn group(&self) -> Option<&str> {
+        self.group.as_deref()
+    }
+
+    /// 菜单徽标计数，未设置徽标时返回 `None`
+    ///
+    /// 计数函数在渲染时调用，函数中读取的信号变化后侧边栏会自动更新。
+    pub fn badge(&self) -> Option<usize> {
+        self.badge.as_ref().map(|badge| badge())
+    }
+
+    /// 是否包含动态参数，带参数的路由不会出现在侧边栏菜单中
+    pub fn is_dynamic(&self) -> bool {
+        segments(&self.path).any(|segment| segment.starts_with(':'))
+    }
+
+    /// 渲染页面
+    pub fn render(&self, params: &RouteParams) -> Element {
+        (self.render)(params)
+    }
+
+    /// 匹配路径，成功时返回解析出的参数
+    ///
+    /// 先按 `/` 拆分再逐段解码，参数中编码的 `%2F` 不会被当作分隔符。
+    fn matches(&self, path: &str) -> Option<RouteParams> {
+        let pattern = segments(&self.path).collect::<Vec<_>>();
+        let actual = segments(path).map(decode).collect::<Vec<_>>();
+        if pattern.len() != actual.len() {
+            return None;
+        }
+
+        let mut params = RouteParams::new();
+        for (expected, value) in pattern.into_iter().zip(actual) {
+            match expected.strip_prefix(':') {
+                Some(name) => {
+                    params.insert(name.to_string(), value);
+                }
+                None if expected == value => {}
+                None => return None,
+            }
+        }
+        Some(params)
+    }
+}
+
+/// 解码路径片段中的百分号编码，编码不完整或解码结果不是合法的 UTF-8 时保留原文
+fn decode(segment: &str) -> String {
+    let bytes = segment.as_bytes();
+    let mut decoded = Vec::with_capacity(bytes.len());
+    let mut i = 0;
+    while i < bytes.len() {
+        if bytes[i] != b'%' {
+            decoded.push(bytes[i]);
+            i += 1;
+            continue;
+        }
+        let byte = bytes
+            .get(i + 1..i + 3)
+            .filter(|hex| hex.iter().all(u8::is_ascii_hexdigit))
+            .and_then(|hex| std::str::from_utf8(hex).ok())
+            .and_then(|hex| u8::from_str_radix(hex, 16).ok());
+        match byte {
+            Some(byte) => {
+                decoded.push(byte);
+                i += 3;
+            }
+            None => return segment.to_string(),
+        }
+    }
+    String::from_utf8(decoded).unwrap_or_else(|_| segment.to_string())
+}
+
+/// 按 `/` 拆分路径，忽略查询参数和空片段
+fn segments(path: &str) -> impl Iterator<Item = &str> {
+    path.split(['?', '#'])
+        .next()
+        .unwrap_or_default()
+        .split('/')
+        .filter(|segment| !segment.is_empty())
+}
+
+/// 路由注册表
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RouteRegistry {
+    entries: Rc<Vec<RouteEntry>>,
+}
+
+impl RouteRegistry {
+    /// 创建注册表构建器
+    pub fn builder() -> RouteRegistryBuilder {
+        RouteRegistryBuilder::default()
+    }
+
+    /// 所有注册的路由，按注册顺序排列
+    pub fn entries(&self) -> &[RouteEntry] {
+        &self.entries
+    }
+
+    /// 是否没有注册任何路由
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// 解析路径，按注册顺序返回第一个匹配的路由及其参数
+    pub fn resolve(&self, path: &str) -> Option<(&RouteEntry, RouteParams)> {
+        self.entries
+            .iter()
+            .find_map(|entry| entry.matches(path).map(|params| (entry, params)))
+    }
+
+    /// 获取上下文中的注册表，未提供时返回 `None`
+    pub fn current() -> Option<Self> {
+        try_consume_context::<Self>()
+    }
+}
+
+/// 路由注册表构建器
+#[derive(Debug, Default)]
+pub struct RouteRegistryBuilder {
+    entries: Vec<RouteEntry>,
+}
+
+impl RouteRegistryBuilder {
+    /// 注册页面组件，页面使用 `Default` 创建，菜单标题默认为去掉 `View` 后缀的类型名
+    pub fn register<T>(self, path: impl Into<String>) -> Self
+    where
+        T: ToElement + Default + 'static,
+    {
+        let name = std::any::type_name::<T>()
+            .rsplit("::")
+            .next()
+            .unwrap_or_default();
+        let title = name.strip_suffix("View").unwrap_or(name).to_string();
+        self.push(path.into(), title, Rc::new(|_| T::default().to_element()))
+    }
+
+    /// 使用渲染函数注册页面，渲染函数可以读取路径参数
+    pub fn register_with(
+        self,
+        path: impl Into<String>,
+        render: impl Fn(&RouteParams) -> Element + 'static,
+    ) -> Self {
+        let path = path.into();
+        let title = path.clone();
+        self.push(path, title, Rc::new(render))
+    }
+
+    /// 设置最近注册的路由的菜单标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.title = title.into();
+        }
+        self
+    }
+
+    /// 设置最近注册的路由的菜单图标
+    pub fn icon(mut self, icon: impl Into<String>) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.icon = Some(icon.into());
+        }
+        self
+    }
+
+    /// 设置最近注册的路由的菜单分组，同一分组的菜单项显示在同一个分组标题下
+    pub fn group(mut self, group: impl Into<String>) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.group = Some(group.into());
+        }
+        self
+    }
+
+    /// 设置最近注册的路由的菜单徽标，如未读数量，计数为 0 时不显示
+    pub fn badge(mut self, badge: impl Fn() -> usize + 'static) -> Self {
+        if let Some(entry) = self.entries.last_mut() {
+            entry.badge = Some(Rc::new(badge));
+        }
+        self
+    }
+
+    /// 构建注册表
+    pub fn build(self) -> RouteRegistry {
+        RouteRegistry {
+            entries: Rc::new(self.entries),
+        }
+    }
+
+    fn push(
+        mut self,
+        path: String,
+        title: String,
+        render: Rc<dyn Fn(&RouteParams) -> Element>,
+    ) -> Self {
+        self.entries.push(RouteEntry {
+            path,
+            title,
+            icon: None,
+            group: None,
+            badge: None,
+            render,
+        });
+        self
+    }
+}
+
+/// 渲染注册的页面
+///
+/// 以独立组件渲染并以路径模式作为 key，切换页面时各页面的 hooks 互不影响。
+#[component]
+pub(crate) fn RegisteredRoute(entry: RouteEntry, params: RouteParams) -> Element {
+    entry.render(&params)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn build(paths: &[&str]) -> RouteRegistry {
+        paths
+            .iter()
+            .fold(RouteRegistry::builder(), |builder, path| {
+                builder.register_with(*path, |_| rsx! {})
+            })
+            .build()
+    }
+
+    /// 解析路径，返回匹配的路径模式和参数
+    fn resolve(registry: &RouteRegistry, path: &str) -> Option<(String, Vec<(String, String)>)> {
+        registry
+            .resolve(path)
+            .map(|(entry, params)| (entry.path().to_string(), params.into_iter().collect()))
+    }
+
+    fn param(name: &str, value: &str) -> (String, String) {
+        (name.to_string(), value.to_string())
+    }
+
+    #[test]
+    fn test_static_route() {
+        let registry = build(&["/reports", "/reports/summary"]);
+        assert_eq!(
+            resolve(&registry, "/reports"),
+            Some(("/reports".to_string(), vec![]))
+        );
+        assert_eq!(
+            resolve(&registry, "/reports/summary"),
+            Some(("/reports/summary".to_string(), vec![]))
+        );
+        assert!(!registry.entries()[0].is_dynamic());
+    }
+
+    #[test]
+    fn test_param_route() {
+        let registry = build(&["/reports/:id", "/users/:user/posts/:post"]);
+        assert_eq!(
+            resolve(&registry, "/reports/42"),
+            Some(("/reports/:id".to_string(), vec![param("id", "42")]))
+        );
+        assert_eq!(
+            resolve(&registry, "/users/alice/posts/7"),
+            Some((
+                "/users/:user/posts/:post".to_string(),
+                vec![param("post", "7"), param("user", "alice")]
+            ))
+        );
+        assert!(registry.entries()[0].is_dynamic());
+    }
+
+    #[test]
+    fn test_param_decoding() {
+        let registry = build(&["/tags/:tag"]);
+        let tag = |path: &str| resolve(&registry, path).map(|(_, params)| params[0].1.clone());
+        assert_eq!(tag("/tags/%E4%B8%AD%E6%96%87").as_deref(), Some("中文"));
+        assert_eq!(tag("/tags/hello%20world").as_deref(), Some("hello world"));
+        // 编码的斜杠属于参数本身，不拆分为两段
+        assert_eq!(tag("/tags/a%2Fb").as_deref(), Some("a/b"));
+        // 编码不完整或不是合法的 UTF-8 时保留原文
+        assert_eq!(tag("/tags/100%").as_deref(), Some("100%"));
+        assert_eq!(tag("/tags/%zz").as_deref(), Some("%zz"));
+        assert_eq!(tag("/tags/%E4").as_deref(), Some("%E4"));
+    }
+
+    #[test]
+    fn test_trailing_slash_and_query() {
+        let registry = build(&["/reports/:id"]);
+        let expected = Some(("/reports/:id".to_string(), vec![param("id", "42")]));
+        assert_eq!(resolve(&registry, "/reports/42/"), expected);
+        assert_eq!(resolve(&registry, "//reports//42//"), expected);
+        assert_eq!(resolve(&registry, "/reports/42?tab=1#top"), expected);
+        assert_eq!(resolve(&registry, "reports/42"), expected);
+    }
+
+    #[test]
+    fn test_no_match() {
+        let registry = build(&["/reports", "/reports/:id"]);
+        assert_eq!(resolve(&registry, "/"), None);
+        assert_eq!(resolve(&registry, "/report"), None);
+        assert_eq!(resolve(&registry, "/reports/42/edit"), None);
+        assert_eq!(resolve(&registry, "/other/42"), None);
+        assert_eq!(resolve(&RouteRegistry::default(), "/reports"), None);
+    }
+
+    #[test]
+    fn test_first_registered_wins() {
+        let registry = build(&["/reports/new", "/reports/:id"]);
+        assert_eq!(
+            resolve(&registry, "/reports/new"),
+            Some(("/reports/new".to_string(), vec![]))
+        );
+        assert_eq!(
+            resolve(&registry, "/reports/42"),
+            Some(("/reports/:id".to_string(), vec![param("id", "42")]))
+        );
+
+        // 动态路径先注册时会匹配所有片段
+        let registry = build(&["/reports/:id", "/reports/new"]);
+        assert_eq!(
+            resolve(&registry, "/reports/new"),
+            Some(("/reports/:id".to_string(), vec![param("id", "new")]))
+        );
+    }
+}
@@ -5,9 +5,21 @@ use dioxus::prelude::*;
 use crate::{
     LayoutRoute,
     views::{
-        BlogRoute, ButtonViewRoute, CardViewRoute, CheckboxViewRoute, GridViewRoute, HomeViewRoute,
-        ImageViewRoute, InputNumberViewRoute, InputViewRoute, LayoutViewRoute, LinkViewRoute,
-        RadioViewRoute, TextViewRoute, TextareaViewRoute, ViewExampleRoute,
+        AddressPickerViewRoute, BarcodeInputViewRoute, BlogRoute, ButtonViewRoute,
+        CalendarHeatmapViewRoute, CardViewRoute, CheckboxViewRoute, CreditCardInputViewRoute,
+        CrudPageViewRoute, DashboardViewRoute, DataSourceViewRoute, DatePickerViewRoute,
+        DialogViewRoute, DropdownViewRoute, FilterBarViewRoute, FormViewRoute, FormWizardViewRoute,
+        GridPlaygroundViewRoute, GridViewRoute, HomeViewRoute, IdleGuardViewRoute, ImageViewRoute,
+        InputNumberViewRoute, InputViewRoute, JsonViewerViewRoute, KbdViewRoute, LayoutViewRoute,
+        LinkViewRoute, LogViewerViewRoute, LoginViewRoute, MaintenanceViewRoute, MasonryViewRoute,
+        MessageViewRoute, NotFoundViewRoute, NotificationViewRoute, OrgChartViewRoute,
+        PaginationViewRoute, PermissionViewRoute, PhoneInputViewRoute, PopconfirmViewRoute,
+        RadioViewRoute, RegisterViewRoute, SchemaFormViewRoute, SearchInputViewRoute,
+        SelectViewRoute, SelectionBarViewRoute, ServerErrorViewRoute, SettingsViewRoute,
+        SkeletonViewRoute, SpinViewRoute, StoriesViewRoute, TableViewRoute, TabsViewRoute,
+        TextViewRoute, TextareaViewRoute, ThemeEditorViewRoute, TimePickerViewRoute,
+        TitleProviderViewRoute, ToolbarViewRoute, TooltipViewRoute, UploadViewRoute,
+        ViewExampleRoute,
     },
 };
 
@@ -58,6 +70,99 @@ pub enum Route {
         RadioViewRoute {},
         #[route("/checkbox")]
         CheckboxViewRoute {},
-        // #[route("/select")]
-        // SelectViewRoute {},
+        #[route("/calendar-heatmap")]
+        CalendarHeatmapViewRoute {},
+        #[route("/org-chart")]
+        OrgChartViewRoute {},
+        #[route("/json-viewer")]
+        JsonViewerViewRoute {},
+        #[route("/log-viewer")]
+        LogViewerViewRoute {},
+        #[route("/table")]
+        TableViewRoute {},
+        #[route("/selection-bar")]
+        SelectionBarViewRoute {},
+        #[route("/crud-page")]
+        CrudPageViewRoute {},
+        #[route("/data-source")]
+        DataSourceViewRoute {},
+        #[route("/notification")]
+        NotificationViewRoute {},
+        #[route("/message")]
+        MessageViewRoute {},
+        #[route("/idle-guard")]
+        IdleGuardViewRoute {},
+        #[route("/dialog")]
+        DialogViewRoute {},
+        #[route("/skeleton")]
+        SkeletonViewRoute {},
+        #[route("/spin")]
+        SpinViewRoute {},
+        #[route("/form")]
+        FormViewRoute {},
+        #[route("/title-provider")]
+        TitleProviderViewRoute {},
+        #[route("/kbd")]
+        KbdViewRoute {},
+        #[route("/tooltip")]
+        TooltipViewRoute {},
+        #[route("/tabs")]
+        TabsViewRoute {},
+        #[route("/dropdown")]
+        DropdownViewRoute {},
+        #[route("/pagination")]
+        PaginationViewRoute {},
+        #[route("/popconfirm")]
+        PopconfirmViewRoute {},
+        #[route("/form-wizard")]
+        FormWizardViewRoute {},
+        #[route("/login")]
+        LoginViewRoute {},
+        #[route("/register")]
+        RegisterViewRoute {},
+        #[route("/dashboard")]
+        DashboardViewRoute {},
+        #[route("/500")]
+        ServerErrorViewRoute {},
+        #[route("/maintenance")]
+        MaintenanceViewRoute {},
+        #[route("/settings")]
+        SettingsViewRoute {},
+        #[route("/credit-card-input")]
+        CreditCardInputViewRoute {},
+        #[route("/phone-input")]
+        PhoneInputViewRoute {},
+        #[route("/barcode-input")]
+        BarcodeInputViewRoute {},
+        #[route("/address-picker")]
+        AddressPickerViewRoute {},
+        #[route("/search-input")]
+        SearchInputViewRoute {},
+        #[route("/filter-bar")]
+        FilterBarViewRoute {},
+        #[route("/toolbar")]
+        ToolbarViewRoute {},
+        #[route("/permission")]
+        PermissionViewRoute {},
+        #[route("/masonry")]
+        MasonryViewRoute {},
+        #[route("/grid-playground")]
+        GridPlaygroundViewRoute {},
+        #[route("/theme-editor")]
+        ThemeEditorViewRoute {},
+        #[route("/stories")]
+        StoriesViewRoute {},
+        #[route("/select")]
+        SelectViewRoute {},
+        #[route("/time-picker")]
+        TimePickerViewRoute {},
+        #[route("/date-picker")]
+        DatePickerViewRoute {},
+        #[route("/schema-form")]
+        SchemaFormViewRoute {},
+        #[route("/upload")]
+        UploadViewRoute {},
+        // 未匹配任何路由时显示 404 页面
+        #[route("/:..segments")]
+        NotFoundViewRoute { segments: Vec<String> },
 }
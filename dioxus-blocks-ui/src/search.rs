@@ -0,0 +1,689 @@
+//! # 站内搜索
+//!
+//! 启动时根据注册的文档（标题、正文和路由）在内存中构建倒排索引，提供带排序和高亮的全文搜索，
+//! 头部的搜索框通过上下文中的 [`SearchIndex`] 查询。
+//!
+//! 分词规则：英文和数字按单词切分并转为小写，中文等其他文字按单字和相邻两字切分，
+//! 因此中英文混合的查询无需额外的分词库。查询中的每个词都必须命中，最后一个英文单词按前缀匹配，
+//! 输入过程中即可得到结果。
+//!
+//! ```rust
+//! use dioxus_blocks_ui::search::{Document, SearchIndex};
+//!
+//! let index = SearchIndex::builder()
+//!     .document(Document::new("Button 按钮", "/button").body("触发操作的按钮，支持多种类型和尺寸。"))
+//!     .document(Document::new("Input 输入框", "/input").body("单行文本输入，支持一键清空。"))
+//!     .build();
+//!
+//! let hits = index.search("按钮");
+//! assert_eq!(hits.len(), 1);
+//! assert_eq!(hits[0].route, "/button");
+//! assert!(hits[0].title.iter().any(|fragment| fragment.matched && fragment.text == "按钮"));
+//!
+//! // 英文按前缀匹配
+//! assert_eq!(index.search("inp")[0].route, "/input");
+//! ```
+
+use std::collections::BTreeMap;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+/// 标题命中的权重，标题比正文更能代表文档的主题
+const TITLE_WEIGHT: f32 = 3.0;
+
+/// 前缀匹配的权重
+const PREFIX_WEIGHT: f32 = 0.5;
+
+/// 摘要在首个命中位置前后保留的字符数
+const SNIPPET_RADIUS: usize = 24;
+
+/// 可搜索的文档
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct Document {
+    /// 标题
+    pub title: String,
+    /// 正文
+    pub body: String,
+    /// 文档对应的路由
+    pub route: String,
+}
+
+impl Document {
+    /// 创建文档
+    pub fn new(title: impl Into<String>, route: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            body: String::new(),
+            route: route.into(),
+        }
+    }
+
+    /// 设置正文
+    pub fn body(mut self, body: impl Into<String>) -> Self {
+        self.body = body.into();
+        self
+    }
+}
+
+/// 高亮片段
+#[derive(Debug, Clone, PartialEq)]
+pub struct Fragment {
+    /// 片段文本
+    pub text: String,
+    /// 是否为命中的关键字
+    pub matched: bool,
+}
+
+/// 搜索结果
+#[derive(Debug, Clone, PartialEq)]
+pub struct Hit {
+    /// 文档对应的路由
+    pub route: String,
+    /// 按关键字拆分的标题
+    pub title: Vec<Fragment>,
+    /// 正文中首个命中位置附近的摘要，按关键字拆分
+    pub snippet: Vec<Fragment>,
+    /// 相关度得分，越大越相关
+    pub score: f32,
+}
+
+/// 词条在文档中出现的位置
+#[derive(Debug, Clone, PartialEq)]
+struct Posting {
+    /// 文档下标
+    doc: usize,
+    /// 在标题中出现的次数
+    title: u32,
+    /// 在正文中出现的次数
+    body: u32,
+}
+
+/// 站内搜索索引
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct SearchIndex {
+    documents: Rc<Vec<Document>>,
+    /// 倒排表，键为词条
+    terms: Rc<BTreeMap<String, Vec<Posting>>>,
+}
+
+impl SearchIndex {
+    /// 创建索引构建器
+    pub fn builder() -> SearchIndexBuilder {
+        SearchIndexBuilder::default()
+    }
+
+    /// 索引中的文档数量
+    pub fn len(&self) -> usize {
+        self.documents.len()
+    }
+
+    /// 索引是否为空
+    pub fn is_empty(&self) -> bool {
+        self.documents.is_empty()
+    }
+
+    /// 获取上下文中的索引，未提供时返回 `None`
+    pub fn current() -> Option<Self> {
+        try_consume_context::<Self>()
+    }
+
+    /// 搜索文档，结果按相关度从高到低排列
+    pub fn search(&self, query: &str) -> Vec<Hit> {
+        let tokens = tokenize(query);
+        if tokens.is_empty() {
+            return Vec::new();
+        }
+        // 只有最后一个英文单词按前缀匹配，避免前面已输入完整的单词匹配过宽
+        let prefix = query
+            .chars()
+            .last()
+            .is_some_and(|c| c.is_ascii_alphanumeric())
+            .then(|| tokens.last().cloned())
+            .flatten();
+
+        let total = self.documents.len() as f32;
+        let mut scores: BTreeMap<usize, (f32, usize)> = BTreeMap::new();
+        let mut unique = tokens.clone();
+        unique.sort();
+        unique.dedup();
+        for token in &unique {
+            let mut matched: BTreeMap<usize, f32> = BTreeMap::new();
+            for (term, postings, weight) in self.lookup(token, prefix.as_deref() == Some(token)) {
+                let idf = (1.0 + total / postings.len() as f32).ln();
+                for posting in postings {
+                    let tf = posting.title as f32 * TITLE_WEIGHT + posting.body as f32;
+                    let score = tf * idf * weight * term_boost(term, token);
+                    let entry = matched.entry(posting.doc).or_default();
+                    *entry = entry.max(score);
+                }
+            }
+            for (doc, score) in matched {
+                let entry = scores.entry(doc).or_default();
+                entry.0 += score;
+                entry.1 += 1;
+            }
+        }
+
+        let keywords = keywords(query);
+        let mut hits = scores
+            .into_iter()
+            .filter(|(_, (_, count))| *count == unique.len())
+            .map(|(doc, (score, _))| {
+                let document = &self.documents[doc];
+                Hit {
+                    route: document.route.clone(),
+                    title: highlight(&document.title, &keywords),
+                    snippet: snippet(&document.body, &keywords),
+                    score,
+                }
+            })
+            .collect::<Vec<_>>();
+        hits.sort_by(|a, b| {
+            b.score
+                .total_cmp(&a.score)
+                .then_with(|| a.route.cmp(&b.route))
+        });
+        hits
+    }
+
+    /// 查找词条的倒排表，返回 (词条, 倒排表, 权重)
+    fn lookup<'a>(
+        &'a self,
+        token: &'a str,
+        prefix: bool,
+    ) -> Box<dyn Iterator<Item = (&'a str, &'a [Posting], f32)> + 'a> {
+        if prefix {
+            Box::new(
+                self.terms
+                    .range(token.to_string()..)
+                    .take_while(move |(term, _)| term.starts_with(token))
+                    .map(move |(term, postings)| {
+                        let weight = if term == token { 1.0 } else { PREFIX_WEIGHT };
+                        (term.as_str(), postings.as_slice(), weight)
+                    }),
+            )
+        } else {
+            Box::new(
+                self.terms
+                    .get_key_value(token)
+                    .map(|(term, postings)| (term.as_str(), postings.as_slice(), 1.0))
+                    .into_iter(),
+            )
+        }
+    }
+}
+
+/// 较长的词条更能区分文档，两字的中文词比单字的权重更高
+fn term_boost(term: &str, token: &str) -> f32 {
+    if term == token && token.chars().count() > 1 && !token.is_ascii() {
+        2.0
+    } else {
+        1.0
+    }
+}
+
+/// 搜索索引构建器
+#[derive(Debug, Default)]
+pub struct SearchIndexBuilder {
+    documents: Vec<Document>,
+}
+
+impl SearchIndexBuilder {
+    /// 添加文档
+    pub fn document(mut self, document: Document) -> Self {
+        self.documents.push(document);
+        self
+    }
+
+    /// 批量添加文档
+    pub fn documents(mut self, documents: impl IntoIterator<Item = Document>) -> Self {
+        self.documents.extend(documents);
+        self
+    }
+
+    /// 构建索引
+    pub fn build(self) -> SearchIndex {
+        let mut terms: BTreeMap<String, Vec<Posting>> = BTreeMap::new();
+        for (doc, document) in self.documents.iter().enumerate() {
+            let mut counts: BTreeMap<String, (u32, u32)> = BTreeMap::new();
+            for token in tokenize(&document.title) {
+                counts.entry(token).or_default().0 += 1;
+            }
+            for token in tokenize(&document.body) {
+                counts.entry(token).or_default().1 += 1;
+            }
+            for (term, (title, body)) in counts {
+                terms
+                    .entry(term)
+                    .or_default()
+                    .push(Posting { doc, title, body });
+            }
+        }
+        SearchIndex {
+            documents: Rc::new(self.documents),
+            terms: Rc::new(terms),
+        }
+    }
+}
+
+/// 切分词条：英文和数字按单词切分并转为小写，其他文字按单字和相邻两字切分
+fn tokenize(text: &str) -> Vec<String> {
+    let mut tokens = Vec::new();
+    let mut word = String::new();
+    let mut previous: Option<char> = None;
+    for c in text.chars() {
+        if c.is_ascii_alphanumeric() {
+            word.push(c.to_ascii_lowercase());
+            previous = None;
+            continue;
+        }
+        if !word.is_empty() {
+            tokens.push(std::mem::take(&mut word));
+        }
+        if c.is_alphanumeric() {
+            tokens.push(c.to_string());
+            if let Some(p) = previous {
+                tokens.push(format!("{p}{c}"));
+            }
+            previous = Some(c);
+        } else {
+            previous = None;
+        }
+    }
+    if !word.is_empty() {
+        tokens.push(word);
+    }
+    tokens
+}
+
+/// 用于高亮的关键字，按空白切分查询并转为小写
+fn keywords(query: &str) -> Vec<Vec<char>> {
+    query
+        .split_whitespace()
+        .map(|keyword| keyword.chars().map(|c| c.to_ascii_lowercase()).collect())
+        .collect()
+}
+
+/// 查找关键字在文本中出现的位置，返回按字符计的区间
+fn matches(text: &[char], keywords: &[Vec<char>]) -> Vec<(usize, usize)> {
+    let lower = text
+        .iter()
+        .map(|c| c.to_ascii_lowercase())
+        .collect::<Vec<_>>();
+    let mut ranges = Vec::new();
+    let mut i = 0;
+    while i < lower.len() {
+        let longest = keywords
+            .iter()
+            .filter(|keyword| lower[i..].starts_with(keyword))
+            .map(Vec::len)
+            .max();
+        match longest {
+            Some(len) => {
+                ranges.push((i, i + len));
+                i += len;
+            }
+            None => i += 1,
+        }
+    }
+    ranges
+}
+
+/// 按关键字把文本拆分为高亮片段
+fn fragments(text: &[char], ranges: &[(usize, usize)]) -> Vec<Fragment> {
+    let mut fragments = Vec::new();
+    let mut cursor = 0;
+    for &(start, end) in ranges {
+        if start > cursor {
+            fragments.push(Fragment {
+                text: text[cursor..start].iter().collect(),
+                matched: false,
+            });
+        }
+        fragments.push(Fragment {
+            text: text[start..end].iter().collect(),
+            matched: true,
+        });
+        cursor = end;
+    }
+    if cursor < text.len() {
+        fragments.push(Fragment {
+            text: text[cursor..].iter().collect(),
+            matched: false,
+        });
+    }
+    fragments
+}
+
+/// 高亮文本中的关键字
+fn highlight(text: &str, keywords: &[Vec<char>]) -> Vec<Fragment> {
+    let chars = text.chars().collect::<Vec<_>>();
+    fragments(&chars, &matches(&chars, keywords))
+}
+
+/// 截取首个命中位置附近的正文作为摘要，正文中没有命中时截取开头
+fn snippet(body: &str, keywords: &[Vec<char>]) -> Vec<Fragment> {
+    let chars = body.chars().collect::<Vec<_>>();
+    let ranges = matches(&chars, keywords);
+    let center = ranges.first().map(|(start, _)| *start).unwrap_or(0);
+    let start = center.saturating_sub(SNIPPET_RADIUS);
+    let end = (center + SNIPPET_RADIUS * 2).min(chars.len());
+
+    let ranges = ranges
+        .into_iter()
+        .filter(|(s, e)| *s >= start && *e <= end)
+        .map(|(s, e)| (s - start, e - start))
+        .collect::<Vec<_>>();
+    let mut result = fragments(&chars[start..end], &ranges);
+    if start > 0 {
+        result.insert(
+            0,
+            Fragment {
+                text: "…".to_string(),
+                matched: false,
+            },
+        );
+    }
+    if end < chars.len() {
+        result.push(Fragment {
+            text: "…".to_string(),
+            matched: false,
+        });
+    }
+    result
+}
+
+/// 本站的文档内容
+pub fn site_documents() -> Vec<Document> {
+    [
+        (
+            "Button 按钮",
+            "/button",
+            "按钮组件，支持多种类型、变体、形状和尺寸，可以显示加载状态，自定义颜色时自动选择可读的文字颜色。",
+        ),
+        (
+            "Text 文本",
+            "/text",
+            "文本组件，用于显示标题、段落和行内文本等不同类型的文本内容，支持多行省略和溢出提示。",
+        ),
+        (
+            "Link 链接",
+            "/link",
+            "链接组件，用于页面导航和跳转，支持多种类型和下划线样式。",
+        ),
+        (
+            "Image 图片",
+            "/image",
+            "图片组件，用于展示图片，支持加载状态和替代文本。",
+        ),
+        (
+            "Card 卡片",
+            "/card",
+            "卡片组件，用于展示相关内容，支持头部、主体和底部等多种布局，阴影使用统一的 0～5 级阴影层级。",
+        ),
+        (
+            "View 容器",
+            "/view",
+            "通用容器组件，用于包装其他元素，支持丰富的样式配置。",
+        ),
+        (
+            "Grid 网格布局",
+            "/grid",
+            "网格布局组件，用于创建灵活的网格结构，支持自定义列数和间距。",
+        ),
+        (
+            "Grid 演练场",
+            "/grid-playground",
+            "交互式调整网格的列数、间距和网格项跨度，实时预览布局并生成构建代码。",
+        ),
+        (
+            "主题编辑器",
+            "/theme-editor",
+            "调整主色、圆角和组件密度，实时预览组件效果并导出对应的 Settings 代码。",
+        ),
+        (
+            "组件用例",
+            "/stories",
+            "按组件分组展示登记的命名用例，如小尺寸、禁用和带图标，并可渲染为 HTML 用于视觉回归测试。",
+        ),
+        (
+            "Layout 布局",
+            "/layout",
+            "基于 Flexbox 的布局组件，包含 Row 行和 Col 列两个组件。",
+        ),
+        (
+            "Toolbar 工具栏",
+            "/toolbar",
+            "分为左、中、右三个区域的操作栏，超出的项收起到更多菜单。",
+        ),
+        (
+            "Masonry 瀑布流",
+            "/masonry",
+            "瀑布流布局，把高度不一的子元素排列到多列中，支持 CSS 多列和测量高度两种策略以及响应式列数。",
+        ),
+        (
+            "Input 输入框",
+            "/input",
+            "单行文本输入框，支持禁用状态、一键清空、密码框、不同尺寸和输入长度限制。",
+        ),
+        (
+            "InputNumber 数字输入框",
+            "/input-number",
+            "数字输入框，支持精度控制、步进、不同尺寸和禁用状态。",
+        ),
+        (
+            "Textarea 多行文本框",
+            "/textarea",
+            "多行文本输入框，支持自适应高度、不同尺寸和输入长度限制。",
+        ),
+        (
+            "Radio 单选框",
+            "/radio",
+            "在一组备选项中进行单选，支持单选框组、带有边框和单选按钮。",
+        ),
+        (
+            "Checkbox 多选框",
+            "/checkbox",
+            "在一组备选项中进行多选，支持中间状态、可选数量限制和按钮样式。",
+        ),
+        (
+            "CreditCardInput 银行卡输入",
+            "/credit-card-input",
+            "由卡号、有效期和安全码组成的复合输入框，自动识别卡组织并按格式分组。",
+        ),
+        (
+            "PhoneInput 手机号码输入",
+            "/phone-input",
+            "由国家/地区选择器和号码输入框组成，按国家/地区格式化号码并输出 E.164 格式。",
+        ),
+        (
+            "BarcodeInput 条码输入",
+            "/barcode-input",
+            "录入商品条码和二维码，支持手动输入、扫码枪输入和浏览器摄像头扫码。",
+        ),
+        (
+            "AddressPicker 地址选择器",
+            "/address-picker",
+            "逐级选择省份、城市和区县，输出各级行政区划代码，支持自定义数据源。",
+        ),
+        (
+            "SearchInput 搜索框",
+            "/search-input",
+            "带搜索范围、加载状态和最近搜索记录的搜索框。",
+        ),
+        (
+            "FilterBar 筛选栏",
+            "/filter-bar",
+            "根据配置渲染一组筛选控件，汇总为查询条件，通常放在表格上方。",
+        ),
+        (
+            "Select 选择器",
+            "/select",
+            "以下拉菜单展示并选择选项，支持多选、筛选、清空、选项分组和键盘操作。",
+        ),
+        (
+            "TimePicker 时间选择器",
+            "/time-picker",
+            "按时、分、秒分列选择时间，支持步长、12/24 小时制、禁用时间段和直接输入。",
+        ),
+        (
+            "DatePicker 日期选择",
+            "/date-picker",
+            "组合选择日期和时间，选择日期范围并提供可配置的快捷范围，支持时区选择。",
+        ),
+        (
+            "Form 表单",
+            "/form",
+            "统一表单项的标签布局，按必填、长度、正则和自定义规则校验输入，在字段下方显示错误信息。",
+        ),
+        (
+            "SchemaForm 配置表单",
+            "/schema-form",
+            "根据可反序列化的表单配置在运行时生成完整的表单，支持多种控件、校验规则和显示条件。",
+        ),
+        (
+            "Upload 上传",
+            "/upload",
+            "点击选择、拖放或粘贴文件，按类型、大小和数量校验，在文件列表中显示图片缩略图和上传进度。",
+        ),
+        (
+            "FormWizard 分步表单",
+            "/form-wizard",
+            "将较长的表单拆分为多个步骤，逐步填写并校验，最后汇总提交。",
+        ),
+        (
+            "CalendarHeatmap 日历热力图",
+            "/calendar-heatmap",
+            "以日历格子的形式展示每日数值的分布，常用于贡献记录、活跃度等活动数据的可视化。",
+        ),
+        (
+            "OrgChart 组织结构图",
+            "/org-chart",
+            "以自上而下的树形图展示层级关系，适用于团队架构、分类体系等场景。",
+        ),
+        (
+            "JsonViewer JSON 查看器",
+            "/json-viewer",
+            "以可折叠的树形结构显示 JSON 数据，支持键名搜索、按类型着色和复制节点路径。",
+        ),
+        (
+            "LogViewer 日志查看器",
+            "/log-viewer",
+            "显示持续追加的日志流，支持 ANSI 颜色、级别筛选、搜索和自动滚动到底部。",
+        ),
+        (
+            "Table 表格",
+            "/table",
+            "按列展示结构化数据，支持树形数据的展开折叠、层级缩进和子行懒加载。",
+        ),
+        (
+            "SelectionBar 批量操作栏",
+            "/selection-bar",
+            "表格有选中的行时显示选中数量和批量操作按钮，服务端分页时支持选择全部页。",
+        ),
+        (
+            "CrudPage 增删改查页面",
+            "/crud-page",
+            "由查询、新建、更新、删除操作和列、表单字段定义生成带筛选、分页、编辑对话框和删除确认的列表页。",
+        ),
+        (
+            "DataSource 数据源",
+            "/data-source",
+            "按查询条件返回一页数据的数据源抽象，Table、远程搜索的 Select 和 CrudPage 可以在内存数据和 HTTP 接口之间切换。",
+        ),
+        (
+            "Notification 通知",
+            "/notification",
+            "在页面右上角显示通知提醒，支持提示音和经用户授权的浏览器系统通知。",
+        ),
+        (
+            "Message 消息提示",
+            "/message",
+            "挂载一次后在任意位置发送自动消失的简短消息，支持四种类型、多种位置和堆叠。",
+        ),
+        (
+            "IdleGuard 空闲超时",
+            "/idle-guard",
+            "检测用户长时间没有操作，弹出倒计时对话框并在超时后自动退出登录。",
+        ),
+        (
+            "Dialog 对话框",
+            "/dialog",
+            "在页面上方弹出带遮罩的对话框，支持标题、底部操作和焦点管理，用于确认操作或填写表单。",
+        ),
+        (
+            "Skeleton 骨架屏",
+            "/skeleton",
+            "加载期间显示页面结构的占位，配合 SkeletonBoundary 在异步资源完成前显示骨架屏。",
+        ),
+        (
+            "Spin 加载中",
+            "/spin",
+            "显示三种尺寸的加载图标和提示文字，或在加载期间以遮罩覆盖表格、卡片等内容。",
+        ),
+        (
+            "TitleProvider 文档标题",
+            "/title-provider",
+            "根据当前路由设置浏览器标签页或桌面窗口标题，页面可以通过 use_document_title 覆盖。",
+        ),
+        (
+            "Kbd 按键",
+            "/kbd",
+            "以键帽样式显示按键，按平台显示 ⌘K 或 Ctrl+K 形式的快捷键，可用于文字提示和菜单项。",
+        ),
+        (
+            "Tooltip 文字提示",
+            "/tooltip",
+            "鼠标移入、点击或聚焦时显示说明文字，支持多种位置、延迟和箭头，空间不足时自动翻转。",
+        ),
+        (
+            "Tabs 标签页",
+            "/tabs",
+            "把内容分为多个标签页，支持四种标签位置、线条和卡片样式、可关闭的标签和懒加载内容。",
+        ),
+        (
+            "Dropdown 下拉菜单",
+            "/dropdown",
+            "为按钮、链接或任意元素附加悬停或点击打开的菜单，支持分隔线、禁用项、图标和多级子菜单。",
+        ),
+        (
+            "Pagination 分页",
+            "/pagination",
+            "根据记录总数切换页码，支持每页记录数选择、快速跳转、折叠页码和紧凑布局。",
+        ),
+        (
+            "Popconfirm 气泡确认框",
+            "/popconfirm",
+            "点击按钮后在旁边弹出确认气泡，支持描述、自定义图标和按钮文本，确认或取消后调用对应的回调。",
+        ),
+        (
+            "登录页",
+            "/login",
+            "登录页面模板，包含账号密码表单、第三方登录和忘记密码链接。",
+        ),
+        (
+            "注册页",
+            "/register",
+            "注册页面模板，包含用户名、密码确认和服务条款。",
+        ),
+        (
+            "Dashboard 仪表盘",
+            "/dashboard",
+            "仪表盘页面模板，展示统计卡片、图表和最近动态。",
+        ),
+        (
+            "Settings 设置",
+            "/settings",
+            "设置页面模板，修改主题、组件密度、语言和通知等全局配置。",
+        ),
+        (
+            "Permission 权限控制",
+            "/permission",
+            "根据用户角色隐藏或禁用按钮、菜单项等操作入口，支持 Can 组件和 use_permission。",
+        ),
+    ]
+    .into_iter()
+    .map(|(title, route, body)| Document::new(title, route).body(body))
+    .collect()
+}
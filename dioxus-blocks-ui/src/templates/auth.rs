@@ -0,0 +1,628 @@
+//! # 登录与注册页面模板
+//!
+//! 居中卡片布局的登录页和注册页，内置字段校验、记住我、第三方登录插槽，
+//! 以及带加载状态和错误提示的异步提交。
+//!
+//! ```rust,ignore
+//! LoginPage::new()
+//!     .title("欢迎回来")
+//!     .social(Button::new().text("GitHub").as_plain())
+//!     .onsubmit(|form: LoginForm| async move {
+//!         api::login(form.username, form.password).await.map_err(|e| e.to_string())
+//!     })
+//! ```
+
+use std::collections::BTreeMap;
+use std::future::Future;
+use std::pin::Pin;
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Alert, Button, Card, Checkbox, FormError, FormErrorSummary, Input, Text, ToElement, View,
+    use_server_action,
+};
+
+/// 异步提交返回的 Future，返回错误信息时会在页面顶部显示错误提示
+pub type SubmitFuture = Pin<Box<dyn Future<Output = Result<(), String>>>>;
+
+/// 字段校验错误，键为字段名，值为错误信息
+type FieldErrors = BTreeMap<&'static str, String>;
+
+/// 注册表单的字段名和名称，按页面中的顺序排列
+const REGISTER_FIELDS: [(&str, &str); 5] = [
+    ("username", "用户名"),
+    ("email", "邮箱"),
+    ("password", "密码"),
+    ("confirm", "确认密码"),
+    ("agreement", "用户协议"),
+];
+
+/// 登录表单数据
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct LoginForm {
+    /// 用户名
+    pub username: String,
+    /// 密码
+    pub password: String,
+    /// 是否记住我
+    pub remember: bool,
+}
+
+impl LoginForm {
+    /// 校验表单字段
+    fn validate(&self) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+        if self.username.trim().is_empty() {
+            errors.insert("username", "请输入用户名".to_string());
+        }
+        if self.password.is_empty() {
+            errors.insert("password", "请输入密码".to_string());
+        }
+        errors
+    }
+}
+
+/// 注册表单数据
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct RegisterForm {
+    /// 用户名
+    pub username: String,
+    /// 邮箱
+    pub email: String,
+    /// 密码
+    pub password: String,
+}
+
+impl RegisterForm {
+    /// 校验表单字段
+    fn validate(&self, confirm: &str, min_password_length: usize) -> FieldErrors {
+        let mut errors = FieldErrors::new();
+        if self.username.trim().is_empty() {
+            errors.insert("username", "请输入用户名".to_string());
+        }
+        if !self.email.contains('@') {
+            errors.insert("email", "请输入有效的邮箱地址".to_string());
+        }
+        if self.password.chars().count() < min_password_length {
+            errors.insert(
+                "password",
+                format!("密码长度不能少于 {} 位", min_password_length),
+            );
+        }
+        if confirm != self.password {
+            errors.insert("confirm", "两次输入的密码不一致".to_string());
+        }
+        errors
+    }
+}
+
+/// 登录页面模板
+#[derive(Debug, Clone)]
+pub struct LoginPage {
+    /// 标题
+    title: String,
+    /// 副标题
+    subtitle: Option<String>,
+    /// 提交按钮文本
+    submit_text: String,
+    /// 是否显示记住我
+    show_remember: bool,
+    /// 记住我右侧的额外内容，如忘记密码链接
+    extra: Option<Rc<dyn ToElement>>,
+    /// 第三方登录按钮
+    socials: Vec<Rc<dyn ToElement>>,
+    /// 卡片底部内容，如注册链接
+    footer: Option<Rc<dyn ToElement>>,
+    /// 提交事件
+    onsubmit: Option<Callback<LoginForm, SubmitFuture>>,
+}
+
+impl Default for LoginPage {
+    fn default() -> Self {
+        Self {
+            title: "登录".to_string(),
+            subtitle: None,
+            submit_text: "登录".to_string(),
+            show_remember: true,
+            extra: None,
+            socials: Vec::new(),
+            footer: None,
+            onsubmit: None,
+        }
+    }
+}
+
+impl LoginPage {
+    /// 创建登录页面
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置副标题
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// 设置提交按钮文本
+    pub fn submit_text(mut self, text: impl Into<String>) -> Self {
+        self.submit_text = text.into();
+        self
+    }
+
+    /// 设置是否显示记住我
+    pub fn show_remember(mut self, show: bool) -> Self {
+        self.show_remember = show;
+        self
+    }
+
+    /// 设置记住我右侧的额外内容
+    pub fn extra<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.extra = Some(Rc::new(component));
+        self
+    }
+
+    /// 添加第三方登录按钮
+    pub fn social<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.socials.push(Rc::new(component));
+        self
+    }
+
+    /// 设置卡片底部内容
+    pub fn footer<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.footer = Some(Rc::new(component));
+        self
+    }
+
+    /// 设置异步提交事件，返回错误信息时显示错误提示
+    pub fn onsubmit<F>(mut self, mut handler: impl FnMut(LoginForm) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        self.onsubmit = Some(Callback::new(move |form| {
+            Box::pin(handler(form)) as SubmitFuture
+        }));
+        self
+    }
+}
+
+impl ToElement for LoginPage {
+    fn to_element(&self) -> Element {
+        let mut username = use_signal(String::new);
+        let mut password = use_signal(String::new);
+        let remember = use_signal(|| false);
+        let mut errors = use_signal(FieldErrors::new);
+        let onsubmit = self.onsubmit;
+        let action = use_server_action(move |form| call_submit(onsubmit, form));
+
+        let mut submit = move || {
+            let form = LoginForm {
+                username: username(),
+                password: password(),
+                remember: remember(),
+            };
+            let invalid = form.validate();
+            let valid = invalid.is_empty();
+            errors.set(invalid);
+            if valid {
+                action.dispatch(form);
+            }
+        };
+        let onenter = move |event: KeyboardEvent| {
+            if event.key() == Key::Enter {
+                submit();
+            }
+        };
+
+        let mut body = View::new()
+            .children(form_item(
+                "用户名",
+                Input::new()
+                    .value(username)
+                    .placeholder("请输入用户名")
+                    .oninput(move |v| username.set(v))
+                    .onkeydown(onenter),
+                errors.read().get("username").cloned(),
+            ))
+            .children(form_item(
+                "密码",
+                Input::new()
+                    .value(password)
+                    .as_password()
+                    .placeholder("请输入密码")
+                    .oninput(move |v| password.set(v))
+                    .onkeydown(onenter),
+                errors.read().get("password").cloned(),
+            ));
+
+        if self.show_remember || self.extra.is_some() {
+            let mut row = View::new().style(|s| {
+                s.display("flex")
+                    .align_items("center")
+                    .justify_content("space-between")
+                    .margin_bottom("16px")
+            });
+            row = if self.show_remember {
+                row.children(Checkbox::new().label("记住我").checked(remember))
+            } else {
+                row.children(View::new())
+            };
+            if let Some(extra) = &self.extra {
+                row = row.childrens2(vec![extra.clone()]);
+            }
+            body = body.children(row);
+        }
+
+        body = body.children(submit_button(&self.submit_text, action.pending(), submit));
+
+        if !self.socials.is_empty() {
+            body = body.children(social_section(self.socials.clone()));
+        }
+
+        auth_card(
+            &self.title,
+            self.subtitle.as_deref(),
+            action.alert(),
+            body,
+            self.footer.clone(),
+        )
+        .into()
+    }
+}
+
+/// 注册页面模板
+#[derive(Debug, Clone)]
+pub struct RegisterPage {
+    /// 标题
+    title: String,
+    /// 副标题
+    subtitle: Option<String>,
+    /// 提交按钮文本
+    submit_text: String,
+    /// 密码最小长度
+    min_password_length: usize,
+    /// 用户协议文本，为 None 时不显示协议勾选框
+    agreement: Option<String>,
+    /// 第三方注册按钮
+    socials: Vec<Rc<dyn ToElement>>,
+    /// 卡片底部内容，如登录链接
+    footer: Option<Rc<dyn ToElement>>,
+    /// 提交事件
+    onsubmit: Option<Callback<RegisterForm, SubmitFuture>>,
+}
+
+impl Default for RegisterPage {
+    fn default() -> Self {
+        Self {
+            title: "注册".to_string(),
+            subtitle: None,
+            submit_text: "注册".to_string(),
+            min_password_length: 6,
+            agreement: Some("我已阅读并同意用户协议和隐私政策".to_string()),
+            socials: Vec::new(),
+            footer: None,
+            onsubmit: None,
+        }
+    }
+}
+
+impl RegisterPage {
+    /// 创建注册页面
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置副标题
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// 设置提交按钮文本
+    pub fn submit_text(mut self, text: impl Into<String>) -> Self {
+        self.submit_text = text.into();
+        self
+    }
+
+    /// 设置密码最小长度
+    pub fn min_password_length(mut self, length: usize) -> Self {
+        self.min_password_length = length;
+        self
+    }
+
+    /// 设置用户协议文本，传入 None 时不显示协议勾选框
+    pub fn agreement(mut self, agreement: Option<impl Into<String>>) -> Self {
+        self.agreement = agreement.map(Into::into);
+        self
+    }
+
+    /// 添加第三方注册按钮
+    pub fn social<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.socials.push(Rc::new(component));
+        self
+    }
+
+    /// 设置卡片底部内容
+    pub fn footer<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.footer = Some(Rc::new(component));
+        self
+    }
+
+    /// 设置异步提交事件，返回错误信息时显示错误提示
+    pub fn onsubmit<F>(mut self, mut handler: impl FnMut(RegisterForm) -> F + 'static) -> Self
+    where
+        F: Future<Output = Result<(), String>> + 'static,
+    {
+        self.onsubmit = Some(Callback::new(move |form| {
+            Box::pin(handler(form)) as SubmitFuture
+        }));
+        self
+    }
+}
+
+impl ToElement for RegisterPage {
+    fn to_element(&self) -> Element {
+        let mut username = use_signal(String::new);
+        let mut email = use_signal(String::new);
+        let mut password = use_signal(String::new);
+        let mut confirm = use_signal(String::new);
+        let agreed = use_signal(|| false);
+        let mut errors = use_signal(FieldErrors::new);
+        let onsubmit = self.onsubmit;
+        let action = use_server_action(move |form| call_submit(onsubmit, form));
+
+        let min_password_length = self.min_password_length;
+        let need_agreement = self.agreement.is_some();
+        let mut submit = move || {
+            let form = RegisterForm {
+                username: username(),
+                email: email(),
+                password: password(),
+            };
+            let mut invalid = form.validate(&confirm(), min_password_length);
+            if need_agreement && !agreed() {
+                invalid.insert("agreement", "请先阅读并同意用户协议".to_string());
+            }
+            let valid = invalid.is_empty();
+            errors.set(invalid);
+            if valid {
+                action.dispatch(form);
+            }
+        };
+        let onenter = move |event: KeyboardEvent| {
+            if event.key() == Key::Enter {
+                submit();
+            }
+        };
+
+        // 注册表单字段较多，提交失败时在顶部汇总错误，点击可跳转到对应字段
+        let summary = REGISTER_FIELDS
+            .iter()
+            .filter_map(|(key, label)| {
+                errors.read().get(key).map(|message| {
+                    FormError::new(format!("register-{key}"), message.clone()).label(*label)
+                })
+            })
+            .collect::<Vec<_>>();
+
+        let mut body = View::new()
+            .children(FormErrorSummary::new().errors(summary))
+            .children(form_item(
+                "用户名",
+                Input::new()
+                    .value(username)
+                    .input_id("register-username")
+                    .placeholder("请输入用户名")
+                    .oninput(move |v| username.set(v))
+                    .onkeydown(onenter),
+                errors.read().get("username").cloned(),
+            ))
+            .children(form_item(
+                "邮箱",
+                Input::new()
+                    .value(email)
+                    .input_id("register-email")
+                    .placeholder("name@example.com")
+                    .oninput(move |v| email.set(v))
+                    .onkeydown(onenter),
+                errors.read().get("email").cloned(),
+            ))
+            .children(form_item(
+                "密码",
+                Input::new()
+                    .value(password)
+                    .input_id("register-password")
+                    .as_password()
+                    .placeholder(format!("至少 {} 位", min_password_length))
+                    .oninput(move |v| password.set(v))
+                    .onkeydown(onenter),
+                errors.read().get("password").cloned(),
+            ))
+            .children(form_item(
+                "确认密码",
+                Input::new()
+                    .value(confirm)
+                    .input_id("register-confirm")
+                    .as_password()
+                    .placeholder("请再次输入密码")
+                    .oninput(move |v| confirm.set(v))
+                    .onkeydown(onenter),
+                errors.read().get("confirm").cloned(),
+            ));
+
+        if let Some(agreement) = &self.agreement {
+            body = body.children(form_item(
+                "",
+                Checkbox::new()
+                    .id("register-agreement")
+                    .label(agreement.clone())
+                    .checked(agreed),
+                errors.read().get("agreement").cloned(),
+            ));
+        }
+
+        body = body.children(submit_button(&self.submit_text, action.pending(), submit));
+
+        if !self.socials.is_empty() {
+            body = body.children(social_section(self.socials.clone()));
+        }
+
+        auth_card(
+            &self.title,
+            self.subtitle.as_deref(),
+            action.alert(),
+            body,
+            self.footer.clone(),
+        )
+        .into()
+    }
+}
+
+/// 调用提交事件，未设置提交事件时直接视为成功
+fn call_submit<T: 'static>(onsubmit: Option<Callback<T, SubmitFuture>>, form: T) -> SubmitFuture {
+    match onsubmit {
+        Some(onsubmit) => onsubmit.call(form),
+        None => Box::pin(async { Ok(()) }),
+    }
+}
+
+/// 居中的卡片外壳
+fn auth_card(
+    title: &str,
+    subtitle: Option<&str>,
+    alert: Option<Alert>,
+    body: View,
+    footer: Option<Rc<dyn ToElement>>,
+) -> View {
+    let mut header = View::new()
+        .style(|s| s.text_align("center"))
+        .children(Text::h2(title.to_string()).style(|s| s.margin("0")));
+    if let Some(subtitle) = subtitle {
+        header = header.children(
+            Text::p(subtitle.to_string())
+                .style(|s| s.margin("8px 0 0").color("var(--t-text-color-secondary)")),
+        );
+    }
+
+    let mut content = View::new();
+    if let Some(alert) = alert {
+        content = content.children(alert.style(|s| s.margin_bottom("16px")));
+    }
+    content = content.children(body);
+
+    let mut card = Card::new()
+        .header(header)
+        .children(content)
+        .style(|s| s.width("100%").max_width("420px"));
+    if let Some(footer) = footer {
+        card = card.footer(
+            View::new()
+                .style(|s| s.text_align("center"))
+                .childrens2(vec![footer]),
+        );
+    }
+
+    View::new()
+        .style(|s| {
+            s.display("flex")
+                .align_items("center")
+                .justify_content("center")
+                .min_height("80vh")
+                .padding("24px")
+        })
+        .children(card)
+}
+
+/// 带标签和错误信息的表单项
+fn form_item(label: &str, field: impl ToElement + Clone + 'static, error: Option<String>) -> View {
+    let mut item = View::new().style(|s| s.margin_bottom("16px"));
+    if !label.is_empty() {
+        item = item.children(Text::new(label.to_string()).style(|s| {
+            s.display("block")
+                .margin_bottom("6px")
+                .font_size("14px")
+                .color("var(--t-text-color-regular)")
+        }));
+    }
+    item = item.children(field);
+    if let Some(error) = error {
+        item = item.children(Text::new(error).style(|s| {
+            s.display("block")
+                .margin_top("4px")
+                .font_size("12px")
+                .color("var(--t-color-danger)")
+        }));
+    }
+    item
+}
+
+/// 提交按钮
+fn submit_button(text: &str, loading: bool, mut submit: impl FnMut() + 'static) -> Button {
+    let text = if loading {
+        format!("{}中...", text)
+    } else {
+        text.to_string()
+    };
+    Button::new()
+        .text(text)
+        .as_primary()
+        .loading(loading)
+        .disabled(loading)
+        .style(|s| s.width("100%"))
+        .onclick(move |_| submit())
+}
+
+/// 第三方登录区域
+fn social_section(socials: Vec<Rc<dyn ToElement>>) -> View {
+    View::new()
+        .style(|s| s.margin_top("24px"))
+        .children(Text::p("其他方式").style(|s| {
+            s.text_align("center")
+                .font_size("12px")
+                .color("var(--t-text-color-secondary)")
+                .margin("0 0 12px")
+        }))
+        .children(
+            View::new()
+                .style(|s| {
+                    s.display("flex")
+                        .justify_content("center")
+                        .flex_wrap("wrap")
+                        .gap("12px")
+                })
+                .childrens2(socials),
+        )
+}
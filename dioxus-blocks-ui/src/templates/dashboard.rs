@@ -0,0 +1,688 @@
+//! # 管理后台仪表盘模板
+//!
+//! 由统计卡片、趋势图表、占比图、最近动态时间线和数据表格组成的响应式仪表盘页面，
+//! 既可以作为演示，也可以作为管理后台首页的起点直接复制修改。
+//!
+//! 趋势图支持滚轮缩放、拖动平移和按住 Shift 拖动框选，框选的范围通过 `onbrush` 发出，用于下钻查看明细。
+//!
+//! ```rust,ignore
+//! DashboardTemplate::new()
+//!     .title("运营概览")
+//!     .stat(DashboardStat::new("今日订单", "1,280").trend(12.5))
+//!     .trend("近 7 日销售额", vec![("周一", 120.0), ("周二", 180.0)])
+//!     .onbrush(|(first, last)| println!("选择了第 {first}～{last} 项"))
+//!     .breakdown("渠道占比", vec![("搜索", 45.0), ("直接访问", 30.0)])
+//!     .activity("10:24", "张三 创建了订单 #10086")
+//!     .table("最新订单", vec!["订单号", "客户", "金额"], vec![vec!["#10086", "张三", "¥299"]])
+//! ```
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Card, Text, ToElement, View};
+
+/// 图表配色
+const CHART_COLORS: [&str; 5] = [
+    "var(--t-color-primary)",
+    "var(--t-color-success)",
+    "var(--t-color-warning)",
+    "var(--t-color-danger)",
+    "var(--t-color-info)",
+];
+
+/// 统计卡片数据
+#[derive(Debug, Clone, PartialEq)]
+pub struct DashboardStat {
+    /// 标题
+    title: String,
+    /// 数值
+    value: String,
+    /// 环比变化百分比
+    trend: Option<f64>,
+    /// 说明文本
+    description: Option<String>,
+}
+
+impl DashboardStat {
+    /// 创建统计卡片
+    pub fn new(title: impl Into<String>, value: impl Into<String>) -> Self {
+        Self {
+            title: title.into(),
+            value: value.into(),
+            trend: None,
+            description: None,
+        }
+    }
+
+    /// 设置环比变化百分比，正数为上升，负数为下降
+    pub fn trend(mut self, trend: f64) -> Self {
+        self.trend = Some(trend);
+        self
+    }
+
+    /// 设置说明文本
+    pub fn description(mut self, description: impl Into<String>) -> Self {
+        self.description = Some(description.into());
+        self
+    }
+}
+
+/// 仪表盘页面模板
+#[derive(Debug, Clone, Default)]
+pub struct DashboardTemplate {
+    /// 页面标题
+    title: String,
+    /// 页面副标题
+    subtitle: Option<String>,
+    /// 统计卡片
+    stats: Vec<DashboardStat>,
+    /// 趋势图标题和数据
+    trend: Option<(String, Vec<(String, f64)>)>,
+    /// 趋势图框选事件
+    onbrush: Option<EventHandler<(usize, usize)>>,
+    /// 占比图标题和数据
+    breakdown: Option<(String, Vec<(String, f64)>)>,
+    /// 最近动态
+    activities: Vec<(String, String)>,
+    /// 表格标题、列名和行数据
+    table: Option<(String, Vec<String>, Vec<Vec<String>>)>,
+}
+
+impl DashboardTemplate {
+    /// 创建仪表盘页面
+    pub fn new() -> Self {
+        Self {
+            title: "仪表盘".to_string(),
+            ..Default::default()
+        }
+    }
+
+    /// 设置页面标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置页面副标题
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// 添加统计卡片
+    pub fn stat(mut self, stat: DashboardStat) -> Self {
+        self.stats.push(stat);
+        self
+    }
+
+    /// 设置趋势柱状图
+    pub fn trend<L: Into<String>>(mut self, title: impl Into<String>, data: Vec<(L, f64)>) -> Self {
+        let data = data.into_iter().map(|(l, v)| (l.into(), v)).collect();
+        self.trend = Some((title.into(), data));
+        self
+    }
+
+    /// 设置趋势图框选事件，参数为所选范围首尾两项在趋势数据中的索引
+    pub fn onbrush(mut self, handler: impl FnMut((usize, usize)) + 'static) -> Self {
+        self.onbrush = Some(EventHandler::new(handler));
+        self
+    }
+
+    /// 设置占比图
+    pub fn breakdown<L: Into<String>>(
+        mut self,
+        title: impl Into<String>,
+        data: Vec<(L, f64)>,
+    ) -> Self {
+        let data = data.into_iter().map(|(l, v)| (l.into(), v)).collect();
+        self.breakdown = Some((title.into(), data));
+        self
+    }
+
+    /// 添加一条最近动态
+    pub fn activity(mut self, time: impl Into<String>, content: impl Into<String>) -> Self {
+        self.activities.push((time.into(), content.into()));
+        self
+    }
+
+    /// 设置数据表格
+    pub fn table<C: Into<String>, V: Into<String>>(
+        mut self,
+        title: impl Into<String>,
+        columns: Vec<C>,
+        rows: Vec<Vec<V>>,
+    ) -> Self {
+        let columns = columns.into_iter().map(Into::into).collect();
+        let rows = rows
+            .into_iter()
+            .map(|row| row.into_iter().map(Into::into).collect())
+            .collect();
+        self.table = Some((title.into(), columns, rows));
+        self
+    }
+}
+
+impl ToElement for DashboardTemplate {
+    fn to_element(&self) -> Element {
+        let mut page = View::new()
+            .style(|s| s.display("flex").flex_direction("column").gap("24px"))
+            .children(self.header());
+
+        if !self.stats.is_empty() {
+            page = page.children(self.stats_section());
+        }
+
+        let mut charts = Vec::new();
+        if let Some((title, data)) = &self.trend {
+            charts.push(panel(
+                title,
+                BarChart {
+                    data: data.clone(),
+                    onbrush: self.onbrush,
+                },
+            ));
+        }
+        if let Some((title, data)) = &self.breakdown {
+            charts.push(panel(title, Breakdown { data: data.clone() }));
+        }
+        if !charts.is_empty() {
+            page = page.children(responsive_grid("320px").childrens(charts));
+        }
+
+        let mut lists = Vec::new();
+        if let Some((title, columns, rows)) = &self.table {
+            lists.push(panel(
+                title,
+                DataTable {
+                    columns: columns.clone(),
+                    rows: rows.clone(),
+                },
+            ));
+        }
+        if !self.activities.is_empty() {
+            lists.push(panel(
+                "最近动态",
+                Timeline {
+                    items: self.activities.clone(),
+                },
+            ));
+        }
+        if !lists.is_empty() {
+            page = page.children(responsive_grid("360px").childrens(lists));
+        }
+
+        page.into()
+    }
+}
+
+impl DashboardTemplate {
+    /// 页面头部
+    fn header(&self) -> View {
+        let mut header =
+            View::new().children(Text::h1(self.title.clone()).style(|s| s.margin("0")));
+        if let Some(subtitle) = &self.subtitle {
+            header = header.children(
+                Text::p(subtitle.clone())
+                    .style(|s| s.margin("8px 0 0").color("var(--t-text-color-secondary)")),
+            );
+        }
+        header
+    }
+
+    /// 统计卡片区域
+    fn stats_section(&self) -> View {
+        let cards = self
+            .stats
+            .iter()
+            .map(|stat| {
+                let mut body = View::new()
+                    .children(
+                        Text::span(stat.title.clone())
+                            .style(|s| s.font_size("14px").color("var(--t-text-color-secondary)")),
+                    )
+                    .children(Text::new(stat.value.clone()).style(|s| {
+                        s.display("block")
+                            .margin("8px 0")
+                            .font_size("28px")
+                            .font_weight("600")
+                            .color("var(--t-text-color-primary)")
+                    }));
+                if let Some(trend) = stat.trend {
+                    let (arrow, color) = if trend >= 0.0 {
+                        ("▲", "var(--t-color-success)")
+                    } else {
+                        ("▼", "var(--t-color-danger)")
+                    };
+                    body = body.children(
+                        Text::span(format!("{} {:.1}%", arrow, trend.abs()))
+                            .style(move |s| s.font_size("13px").color(color)),
+                    );
+                }
+                if let Some(description) = &stat.description {
+                    body = body.children(
+                        Text::span(format!(" {}", description))
+                            .style(|s| s.font_size("13px").color("var(--t-text-color-secondary)")),
+                    );
+                }
+                Card::new().children(body)
+            })
+            .collect();
+        responsive_grid("220px").childrens(cards)
+    }
+}
+
+/// 自适应列数的网格容器
+fn responsive_grid(min_width: &str) -> View {
+    let columns = format!(
+        "display: grid; grid-template-columns: repeat(auto-fit, minmax({}, 1fr)); gap: 16px;",
+        min_width
+    );
+    View::new().style(move |s| s.custom(columns))
+}
+
+/// 带标题的面板
+fn panel(title: &str, content: impl ToElement + Clone + 'static) -> Card {
+    Card::new()
+        .header(Text::h3(title.to_string()).style(|s| s.margin("0")))
+        .children(content)
+}
+
+/// 柱状图可视窗口最少显示的柱数
+const MIN_VISIBLE_BARS: usize = 2;
+
+/// 柱状图的拖动状态
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Drag {
+    /// 平移，记录按下时的横坐标和可视窗口的起点
+    Pan { x: f64, start: usize },
+    /// 框选，记录按下时所在柱的索引
+    Brush { anchor: usize },
+}
+
+/// 柱状图
+///
+/// 数据较多时可以在图表上滚动滚轮缩放、拖动平移，按住 Shift 拖动框选一段连续的柱，
+/// 松开后通过 `onbrush` 发出所选范围的首尾索引，双击还原。
+#[derive(Debug, Clone)]
+struct BarChart {
+    data: Vec<(String, f64)>,
+    onbrush: Option<EventHandler<(usize, usize)>>,
+}
+
+impl ToElement for BarChart {
+    fn to_element(&self) -> Element {
+        let width = 600.0;
+        let height = 220.0;
+        let bottom = 24.0;
+        let total = self.data.len();
+        let onbrush = self.onbrush;
+
+        // 可视窗口为数据索引的左闭右开区间，同时记录窗口对应的数据条数
+        let mut window = use_signal(move || (total, 0, total));
+        let mut drag = use_signal(|| None::<Drag>);
+        let mut brush = use_signal(|| None::<(usize, usize)>);
+        // 数据条数变化后原来的窗口和框选范围不再有效，还原为显示全部数据
+        use_effect(use_reactive!(|total| {
+            if window.peek().0 != total {
+                window.set((total, 0, total));
+                brush.set(None);
+            }
+        }));
+        let mut container = use_signal(|| None::<Rc<MountedData>>);
+        // 容器在视口中的横坐标和宽度，用于把鼠标位置换算为柱的索引
+        let mut bounds = use_signal(|| (0.0, width));
+
+        let measure = move || {
+            if let Some(element) = container.peek().clone() {
+                spawn(async move {
+                    if let Ok(rect) = element.get_client_rect().await
+                        && rect.width() > 0.0
+                    {
+                        bounds.set((rect.min_x(), rect.width()));
+                    }
+                });
+            }
+        };
+
+        let (start, end) = match window() {
+            (len, start, end) if len == total => (start.min(end), end),
+            _ => (0, total),
+        };
+        let visible = (end - start).max(1);
+        let zoomed = end - start < total;
+        let offset_x = move |client_x: f64| {
+            let (left, width) = *bounds.peek();
+            ((client_x - left) / width).clamp(0.0, 1.0)
+        };
+        let index_at = move |ratio: f64| (start + (ratio * visible as f64) as usize).min(end - 1);
+
+        let max = self.data[start..end]
+            .iter()
+            .map(|(_, v)| *v)
+            .fold(0.0_f64, f64::max);
+        let slot = width / visible as f64;
+        let bar_width = slot * 0.5;
+
+        let bars = self.data[start..end]
+            .iter()
+            .enumerate()
+            .map(|(i, (label, value))| {
+                let bar_height = if max > 0.0 {
+                    value / max * (height - bottom - 20.0)
+                } else {
+                    0.0
+                };
+                let x = slot * i as f64 + (slot - bar_width) / 2.0;
+                let y = height - bottom - bar_height;
+                let center = x + bar_width / 2.0;
+                let key = start + i;
+                rsx! {
+                    g { key: "{key}",
+                        rect {
+                            x: "{x}",
+                            y: "{y}",
+                            width: "{bar_width}",
+                            height: "{bar_height}",
+                            rx: "4",
+                            fill: "var(--t-color-primary)",
+                            title { "{label}: {value}" }
+                        }
+                        text {
+                            x: "{center}",
+                            y: "{y - 6.0}",
+                            "text-anchor": "middle",
+                            "font-size": "12",
+                            fill: "var(--t-text-color-regular)",
+                            "{value}"
+                        }
+                        text {
+                            x: "{center}",
+                            y: "{height - 6.0}",
+                            "text-anchor": "middle",
+                            "font-size": "12",
+                            fill: "var(--t-text-color-secondary)",
+                            "{label}"
+                        }
+                    }
+                }
+            });
+
+        // 框选范围与可视窗口的交集
+        let selection = brush().and_then(|(first, last)| {
+            let first = first.max(start);
+            let last = last.min(end.saturating_sub(1));
+            (first <= last && total > 0).then(|| {
+                (
+                    (first - start) as f64 * slot,
+                    (last - first + 1) as f64 * slot,
+                )
+            })
+        });
+
+        let cursor = match drag() {
+            Some(Drag::Pan { .. }) => "grabbing",
+            Some(Drag::Brush { .. }) => "crosshair",
+            None if zoomed => "grab",
+            None => "default",
+        };
+        let hint = if zoomed {
+            format!(
+                "显示第 {}～{} 项，共 {} 项 · 拖动平移 · Shift + 拖动框选 · 双击还原",
+                start + 1,
+                end,
+                total
+            )
+        } else {
+            "滚轮缩放 · Shift + 拖动框选 · 双击还原".to_string()
+        };
+
+        let mut finish = move || {
+            if let Some(Drag::Brush { .. }) = drag()
+                && window.peek().0 == total
+                && let (Some(range), Some(handler)) = (brush(), onbrush)
+            {
+                handler.call(range);
+            }
+            drag.set(None);
+        };
+
+        rsx! {
+            div {
+                style: "position: relative; cursor: {cursor}; user-select: none; touch-action: none;",
+                onmounted: move |event: MountedEvent| {
+                    container.set(Some(event.data()));
+                    measure();
+                },
+                onresize: move |_| measure(),
+                onwheel: move |event: WheelEvent| {
+                    if total <= MIN_VISIBLE_BARS {
+                        return;
+                    }
+                    event.prevent_default();
+                    let ratio = offset_x(event.client_coordinates().x);
+                    let step = (visible / 5).max(1);
+                    let next = if event.delta().strip_units().y < 0.0 {
+                        visible.saturating_sub(step).max(MIN_VISIBLE_BARS)
+                    } else {
+                        (visible + step).min(total)
+                    };
+                    // 以鼠标所在位置为中心缩放
+                    let anchor = start as f64 + ratio * visible as f64;
+                    let first = (anchor - ratio * next as f64)
+                        .round()
+                        .clamp(0.0, (total - next) as f64) as usize;
+                    window.set((total, first, first + next));
+                },
+                onmousedown: move |event: MouseEvent| {
+                    if total == 0 {
+                        return;
+                    }
+                    let x = event.client_coordinates().x;
+                    if event.modifiers().shift() {
+                        let index = index_at(offset_x(x));
+                        brush.set(Some((index, index)));
+                        drag.set(Some(Drag::Brush { anchor: index }));
+                    } else if zoomed {
+                        drag.set(Some(Drag::Pan { x, start }));
+                    }
+                },
+                onmousemove: move |event: MouseEvent| {
+                    let x = event.client_coordinates().x;
+                    match drag() {
+                        Some(Drag::Pan { x: origin, start: origin_start }) => {
+                            let (_, width) = *bounds.peek();
+                            let shift = ((origin - x) / width * visible as f64).round() as isize;
+                            let first = (origin_start as isize + shift)
+                                .clamp(0, (total - visible) as isize) as usize;
+                            if first != start {
+                                window.set((total, first, first + visible));
+                            }
+                        }
+                        Some(Drag::Brush { anchor }) => {
+                            let index = index_at(offset_x(x));
+                            brush.set(Some((anchor.min(index), anchor.max(index))));
+                        }
+                        None => {}
+                    }
+                },
+                onmouseup: move |_| finish(),
+                onmouseleave: move |_| finish(),
+                ondoubleclick: move |_| {
+                    window.set((total, 0, total));
+                    brush.set(None);
+                },
+                svg {
+                    width: "100%",
+                    "viewBox": "0 0 {width} {height}",
+                    "preserveAspectRatio": "none",
+                    line {
+                        x1: "0",
+                        y1: "{height - bottom}",
+                        x2: "{width}",
+                        y2: "{height - bottom}",
+                        stroke: "var(--t-border-color)",
+                    }
+                    if let Some((x, w)) = selection {
+                        rect {
+                            x: "{x}",
+                            y: "0",
+                            width: "{w}",
+                            height: "{height - bottom}",
+                            fill: "var(--t-color-primary)",
+                            "fill-opacity": "0.12",
+                            stroke: "var(--t-color-primary)",
+                            "stroke-dasharray": "4 2",
+                        }
+                    }
+                    {bars}
+                }
+                div { style: "margin-top: 8px; font-size: 12px; color: var(--t-text-color-secondary);",
+                    "{hint}"
+                }
+            }
+        }
+    }
+}
+
+/// 占比图
+#[derive(Debug, Clone)]
+struct Breakdown {
+    data: Vec<(String, f64)>,
+}
+
+impl ToElement for Breakdown {
+    fn to_element(&self) -> Element {
+        let total: f64 = self.data.iter().map(|(_, v)| *v).sum();
+
+        let rows = self.data.iter().enumerate().map(|(i, (label, value))| {
+            let percent = if total > 0.0 { value / total * 100.0 } else { 0.0 };
+            let color = CHART_COLORS[i % CHART_COLORS.len()];
+            rsx! {
+                div { key: "{i}", style: "margin-bottom: 16px;",
+                    div { style: "display: flex; justify-content: space-between; font-size: 14px; margin-bottom: 6px;",
+                        span { "{label}" }
+                        span { style: "color: var(--t-text-color-secondary);", "{percent:.1}%" }
+                    }
+                    div { style: "height: 8px; border-radius: 4px; background-color: var(--t-fill-color, #f0f2f5); overflow: hidden;",
+                        div { style: "height: 100%; width: {percent}%; background-color: {color};" }
+                    }
+                }
+            }
+        });
+
+        rsx! {
+            div { {rows} }
+        }
+    }
+}
+
+/// 时间线
+#[derive(Debug, Clone)]
+struct Timeline {
+    items: Vec<(String, String)>,
+}
+
+impl ToElement for Timeline {
+    fn to_element(&self) -> Element {
+        let last = self.items.len().saturating_sub(1);
+        let items = self.items.iter().enumerate().map(|(i, (time, content))| {
+            rsx! {
+                li {
+                    key: "{i}",
+                    style: "position: relative; padding: 0 0 20px 20px; list-style: none;",
+                    if i != last {
+                        span { style: "position: absolute; left: 4px; top: 12px; bottom: 0; border-left: 2px solid var(--t-border-color);" }
+                    }
+                    span { style: "position: absolute; left: 0; top: 4px; width: 10px; height: 10px; border-radius: 50%; background-color: var(--t-color-primary);" }
+                    div { style: "font-size: 14px; color: var(--t-text-color-primary);",
+                        "{content}"
+                    }
+                    div { style: "margin-top: 4px; font-size: 12px; color: var(--t-text-color-secondary);",
+                        "{time}"
+                    }
+                }
+            }
+        });
+
+        rsx! {
+            ul { style: "margin: 0; padding: 0;", {items} }
+        }
+    }
+}
+
+/// 数据表格
+#[derive(Debug, Clone)]
+struct DataTable {
+    columns: Vec<String>,
+    rows: Vec<Vec<String>>,
+}
+
+impl ToElement for DataTable {
+    fn to_element(&self) -> Element {
+        let cell =
+            "padding: 10px 12px; text-align: left; border-bottom: 1px solid var(--t-border-color);";
+
+        rsx! {
+            div { style: "overflow-x: auto;",
+                table { style: "width: 100%; border-collapse: collapse; font-size: 14px;",
+                    thead {
+                        tr {
+                            for (i , column) in self.columns.iter().enumerate() {
+                                th {
+                                    key: "{i}",
+                                    style: "{cell} color: var(--t-text-color-secondary); font-weight: 500;",
+                                    "{column}"
+                                }
+                            }
+                        }
+                    }
+                    tbody {
+                        for (i , row) in self.rows.iter().enumerate() {
+                            tr { key: "{i}",
+                                for (j , value) in row.iter().enumerate() {
+                                    td { key: "{j}", style: "{cell}", "{value}" }
+                                }
+                            }
+                        }
+                    }
+                }
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use dioxus_blocks_components::testing::Harness;
+
+    use super::*;
+
+    #[test]
+    fn test_bar_chart_data_grows() {
+        fn app() -> Element {
+            let mut count = use_signal(|| 3);
+            let data = (1..=count())
+                .map(|i| (format!("第{i}项"), i as f64))
+                .collect();
+            rsx! {
+                button { class: "more", onclick: move |_| count += 3, "更多" }
+                {BarChart { data, onbrush: None }.to_element()}
+            }
+        }
+
+        let mut harness = Harness::new(app);
+        assert!(harness.find_by_text("第3项").is_some());
+        assert!(harness.find_by_text("第4项").is_none());
+
+        let more = harness.find_by_class("more").unwrap();
+        harness.click(&more);
+        for i in 1..=6 {
+            assert!(
+                harness.find_by_text(&format!("第{i}项")).is_some(),
+                "第{i}项"
+            );
+        }
+        assert!(!harness.html().contains("显示第"));
+    }
+}
@@ -0,0 +1,320 @@
+//! # 异常页面模板
+//!
+//! 基于 [`ResultPanel`] 的 404、500 页面以及带倒计时的维护模式页面。
+//!
+//! ```rust,ignore
+//! NotFoundPage::new().path("/unknown")
+//! ServerErrorPage::new().detail("数据库连接超时")
+//! MaintenancePage::new().countdown(2 * 60 * 60).onfinish(|_| reload())
+//! ```
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Link, ResultPanel, ResultStatus, Text, ToElement, View};
+
+/// 页面外层容器，使结果内容在可视区域内居中
+fn page_shell(panel: ResultPanel) -> View {
+    View::new()
+        .style(|s| {
+            s.display("flex")
+                .align_items("center")
+                .justify_content("center")
+                .min_height("70vh")
+        })
+        .children(panel)
+}
+
+/// 默认的返回首页按钮
+fn home_action() -> Link {
+    Link::new("/").children(Button::new().text("返回首页").as_primary())
+}
+
+/// 404 页面模板
+#[derive(Debug, Clone)]
+pub struct NotFoundPage {
+    /// 标题
+    title: String,
+    /// 副标题
+    sub_title: String,
+    /// 访问的路径
+    path: Option<String>,
+    /// 操作区域，为空时显示返回首页按钮
+    actions: Vec<Rc<dyn ToElement>>,
+}
+
+impl Default for NotFoundPage {
+    fn default() -> Self {
+        Self {
+            title: "页面不存在".to_string(),
+            sub_title: "抱歉，您访问的页面不存在或已被删除。".to_string(),
+            path: None,
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl NotFoundPage {
+    /// 创建 404 页面
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置副标题
+    pub fn sub_title(mut self, sub_title: impl Into<String>) -> Self {
+        self.sub_title = sub_title.into();
+        self
+    }
+
+    /// 设置访问的路径
+    pub fn path(mut self, path: impl Into<String>) -> Self {
+        self.path = Some(path.into());
+        self
+    }
+
+    /// 添加操作按钮
+    pub fn action<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.actions.push(Rc::new(component));
+        self
+    }
+}
+
+impl ToElement for NotFoundPage {
+    fn to_element(&self) -> Element {
+        let mut panel = ResultPanel::new()
+            .status(ResultStatus::NotFound)
+            .title(self.title.clone())
+            .sub_title(self.sub_title.clone());
+        if let Some(path) = &self.path {
+            panel = panel.children(
+                Text::p(format!("请求路径：{}", path))
+                    .style(|s| s.font_size("13px").color("var(--t-text-color-secondary)")),
+            );
+        }
+        panel = if self.actions.is_empty() {
+            panel.extra(home_action())
+        } else {
+            self.actions
+                .iter()
+                .fold(panel, |panel, action| panel.extra2(action.clone()))
+        };
+        page_shell(panel).into()
+    }
+}
+
+/// 500 页面模板
+#[derive(Debug, Clone)]
+pub struct ServerErrorPage {
+    /// 标题
+    title: String,
+    /// 副标题
+    sub_title: String,
+    /// 错误详情
+    detail: Option<String>,
+    /// 操作区域，为空时显示返回首页按钮
+    actions: Vec<Rc<dyn ToElement>>,
+}
+
+impl Default for ServerErrorPage {
+    fn default() -> Self {
+        Self {
+            title: "服务器错误".to_string(),
+            sub_title: "抱歉，服务器出了点问题，请稍后再试。".to_string(),
+            detail: None,
+            actions: Vec::new(),
+        }
+    }
+}
+
+impl ServerErrorPage {
+    /// 创建 500 页面
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置副标题
+    pub fn sub_title(mut self, sub_title: impl Into<String>) -> Self {
+        self.sub_title = sub_title.into();
+        self
+    }
+
+    /// 设置错误详情
+    pub fn detail(mut self, detail: impl Into<String>) -> Self {
+        self.detail = Some(detail.into());
+        self
+    }
+
+    /// 添加操作按钮
+    pub fn action<T>(mut self, component: T) -> Self
+    where
+        T: ToElement + Clone + 'static,
+    {
+        self.actions.push(Rc::new(component));
+        self
+    }
+}
+
+impl ToElement for ServerErrorPage {
+    fn to_element(&self) -> Element {
+        let mut panel = ResultPanel::new()
+            .status(ResultStatus::ServerError)
+            .title(self.title.clone())
+            .sub_title(self.sub_title.clone());
+        if let Some(detail) = &self.detail {
+            panel = panel.children(Text::p(detail.clone()).style(|s| {
+                s.padding("8px 12px")
+                    .font_size("13px")
+                    .font_family("monospace")
+                    .color("var(--t-color-danger)")
+                    .background_color("var(--t-color-danger-light)")
+                    .border_radius("4px")
+            }));
+        }
+        panel = if self.actions.is_empty() {
+            panel.extra(home_action())
+        } else {
+            self.actions
+                .iter()
+                .fold(panel, |panel, action| panel.extra2(action.clone()))
+        };
+        page_shell(panel).into()
+    }
+}
+
+/// 维护模式页面模板
+#[derive(Debug, Clone)]
+pub struct MaintenancePage {
+    /// 标题
+    title: String,
+    /// 维护说明
+    message: String,
+    /// 距离维护结束的秒数
+    countdown: Option<u64>,
+    /// 倒计时结束事件
+    onfinish: Option<EventHandler<()>>,
+}
+
+impl Default for MaintenancePage {
+    fn default() -> Self {
+        Self {
+            title: "系统维护中".to_string(),
+            message: "为了给您提供更好的服务，系统正在进行升级维护，请稍后访问。".to_string(),
+            countdown: None,
+            onfinish: None,
+        }
+    }
+}
+
+impl MaintenancePage {
+    /// 创建维护模式页面
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置维护说明
+    pub fn message(mut self, message: impl Into<String>) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// 设置距离维护结束的秒数，页面会显示倒计时
+    pub fn countdown(mut self, seconds: u64) -> Self {
+        self.countdown = Some(seconds);
+        self
+    }
+
+    /// 设置倒计时结束事件
+    pub fn onfinish(mut self, handler: impl FnMut(()) + 'static) -> Self {
+        self.onfinish = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+/// 将秒数格式化为 HH:MM:SS
+fn format_countdown(seconds: u64) -> String {
+    format!(
+        "{:02}:{:02}:{:02}",
+        seconds / 3600,
+        seconds % 3600 / 60,
+        seconds % 60
+    )
+}
+
+impl ToElement for MaintenancePage {
+    fn to_element(&self) -> Element {
+        let initial = self.countdown;
+        let onfinish = self.onfinish;
+        let mut remaining = use_signal(|| initial);
+
+        use_future(move || async move {
+            while let Some(seconds) = remaining() {
+                if seconds == 0 {
+                    if let Some(handler) = onfinish {
+                        handler.call(());
+                    }
+                    break;
+                }
+                // 非浏览器环境无法计时，此时停止倒计时
+                if !dioxus_blocks_components::browser::sleep(1000).await {
+                    break;
+                }
+                remaining.set(Some(seconds - 1));
+            }
+        });
+
+        let mut panel = ResultPanel::new()
+            .status(ResultStatus::Warning)
+            .icon(Text::span("🛠").style(|s| s.font_size("72px")))
+            .title(self.title.clone())
+            .sub_title(self.message.clone());
+        if let Some(seconds) = remaining() {
+            let text = if seconds == 0 {
+                "维护已结束，请刷新页面".to_string()
+            } else {
+                format_countdown(seconds)
+            };
+            panel = panel.children(
+                View::new()
+                    .children(
+                        Text::p("预计恢复剩余时间")
+                            .style(|s| s.margin("0 0 8px").color("var(--t-text-color-secondary)")),
+                    )
+                    .children(Text::new(text).style(|s| {
+                        s.font_size("32px")
+                            .font_weight("600")
+                            .font_family("monospace")
+                            .color("var(--t-color-primary)")
+                    })),
+            );
+        }
+        page_shell(panel).into()
+    }
+}
@@ -0,0 +1,15 @@
+//! # 页面模板
+//!
+//! 提供开箱即用的整页模板，下游应用可以直接复用，并通过构建器方法定制文案、插槽和提交逻辑。
+
+mod auth;
+pub use auth::{LoginForm, LoginPage, RegisterForm, RegisterPage, SubmitFuture};
+
+mod dashboard;
+pub use dashboard::{DashboardStat, DashboardTemplate};
+
+mod error;
+pub use error::{MaintenancePage, NotFoundPage, ServerErrorPage};
+
+mod settings;
+pub use settings::SettingsPage;
@@ -0,0 +1,318 @@
+//! # 设置页面模板
+//!
+//! 包含主题、组件密度、语言和通知四个分区的设置页，直接读写 [`ConfigProvider`] 提供的全局配置，
+//! 修改后主题和密度会实时作用于 ConfigProvider 内的整个应用。
+//!
+//! 需要在 [`ConfigProvider`] 的子组件中使用。
+//!
+//! ```rust,ignore
+//! SettingsPage::new()
+//!     .locales(vec![("zh-CN", "简体中文"), ("en-US", "English")])
+//!     .onchange(|settings: Settings| storage::save(&settings))
+//! ```
+//!
+//! [`ConfigProvider`]: dioxus_blocks_components::ConfigProvider
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, Checkbox, Density, NotificationPermission, Radio, RadioGroup, RadioValue,
+    Settings, Text, Theme, ToElement, View, request_notification_permission, use_settings,
+};
+
+/// 主题选项
+const THEMES: [(Theme, &str); 3] = [
+    (Theme::Light, "亮色"),
+    (Theme::Dark, "暗色"),
+    (Theme::HighContrast, "高对比度"),
+];
+
+/// 组件密度选项
+const DENSITIES: [(Density, &str); 3] = [
+    (Density::Compact, "紧凑"),
+    (Density::Default, "默认"),
+    (Density::Comfortable, "宽松"),
+];
+
+/// 设置页面模板
+#[derive(Debug, Clone)]
+pub struct SettingsPage {
+    /// 标题
+    title: String,
+    /// 副标题
+    subtitle: Option<String>,
+    /// 可选语言列表，元素为 (语言代码, 显示名称)
+    locales: Vec<(String, String)>,
+    /// 配置变更事件
+    onchange: Option<EventHandler<Settings>>,
+}
+
+impl Default for SettingsPage {
+    fn default() -> Self {
+        Self {
+            title: "设置".to_string(),
+            subtitle: None,
+            locales: vec![
+                ("zh-CN".to_string(), "简体中文".to_string()),
+                ("en-US".to_string(), "English".to_string()),
+                ("ja-JP".to_string(), "日本語".to_string()),
+            ],
+            onchange: None,
+        }
+    }
+}
+
+impl SettingsPage {
+    /// 创建设置页面
+    pub fn new() -> Self {
+        Self {
+            ..Default::default()
+        }
+    }
+
+    /// 设置标题
+    pub fn title(mut self, title: impl Into<String>) -> Self {
+        self.title = title.into();
+        self
+    }
+
+    /// 设置副标题
+    pub fn subtitle(mut self, subtitle: impl Into<String>) -> Self {
+        self.subtitle = Some(subtitle.into());
+        self
+    }
+
+    /// 设置可选语言列表，元素为 (语言代码, 显示名称)
+    pub fn locales<C, L>(mut self, locales: Vec<(C, L)>) -> Self
+    where
+        C: Into<String>,
+        L: Into<String>,
+    {
+        self.locales = locales
+            .into_iter()
+            .map(|(code, label)| (code.into(), label.into()))
+            .collect();
+        self
+    }
+
+    /// 设置配置变更事件，可用于持久化配置
+    pub fn onchange(mut self, handler: impl FnMut(Settings) + 'static) -> Self {
+        self.onchange = Some(EventHandler::new(handler));
+        self
+    }
+}
+
+impl ToElement for SettingsPage {
+    fn to_element(&self) -> Element {
+        let mut settings = use_settings();
+        let current = settings.read().clone();
+
+        let mut theme = use_signal(|| RadioValue::from(theme_index(current.theme)));
+        let mut density = use_signal(|| RadioValue::from(density_index(current.density)));
+        let mut locale = use_signal(|| RadioValue::from(current.locale.clone()));
+        let mut reduced_motion = use_signal(|| current.reduced_motion);
+        let mut notifications = use_signal(|| current.notifications);
+        let mut browser_notifications = use_signal(|| current.browser_notifications);
+        let mut permission_hint = use_signal(|| None::<&'static str>);
+
+        let onchange = self.onchange;
+        let mut update = move |change: &dyn Fn(&mut Settings)| {
+            change(&mut settings.write());
+            if let Some(handler) = onchange {
+                handler.call(settings.read().clone());
+            }
+        };
+
+        let theme_group = RadioGroup::new()
+            .value(theme)
+            .button(true)
+            .radios(
+                THEMES
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_, label))| Radio::new().value(i as i64).label(*label))
+                    .collect(),
+            )
+            .onchange(move |value: RadioValue| {
+                if let Some((theme, _)) = value.get_int().and_then(|i| THEMES.get(i as usize)) {
+                    update(&|s| s.theme = *theme);
+                }
+            });
+
+        let density_group = RadioGroup::new()
+            .value(density)
+            .button(true)
+            .radios(
+                DENSITIES
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_, label))| Radio::new().value(i as i64).label(*label))
+                    .collect(),
+            )
+            .onchange(move |value: RadioValue| {
+                if let Some((density, _)) = value.get_int().and_then(|i| DENSITIES.get(i as usize))
+                {
+                    update(&|s| s.density = *density);
+                }
+            });
+
+        let locale_group = RadioGroup::new()
+            .value(locale)
+            .radios(
+                self.locales
+                    .iter()
+                    .map(|(code, label)| Radio::new().value(code.clone()).label(label.clone()))
+                    .collect(),
+            )
+            .onchange(move |value: RadioValue| {
+                if let Some(code) = value.get_string() {
+                    let code = code.to_string();
+                    update(&move |s| s.locale = code.clone());
+                }
+            });
+
+        let motion_checkbox = Checkbox::new()
+            .label("减少动画")
+            .checked(reduced_motion)
+            .onchange_checked(move |enabled| update(&move |s| s.reduced_motion = enabled));
+
+        let notifications_checkbox = Checkbox::new()
+            .label("接收系统通知")
+            .checked(notifications)
+            .onchange_checked(move |enabled| update(&move |s| s.notifications = enabled));
+
+        // 开启浏览器通知前先请求授权，未获授权时恢复勾选状态并提示原因
+        let browser_checkbox = Checkbox::new()
+            .label("使用浏览器通知")
+            .checked(browser_notifications)
+            .onchange_checked(move |enabled| {
+                permission_hint.set(None);
+                if !enabled {
+                    update(&|s| s.browser_notifications = false);
+                    return;
+                }
+                spawn(async move {
+                    let permission = request_notification_permission().await;
+                    let granted = permission == NotificationPermission::Granted;
+                    browser_notifications.set(granted);
+                    update(&move |s| s.browser_notifications = granted);
+                    permission_hint.set(match permission {
+                        NotificationPermission::Granted => None,
+                        NotificationPermission::Denied => {
+                            Some("浏览器已拒绝通知权限，请在浏览器设置中允许后重试")
+                        }
+                        NotificationPermission::Default => Some("未授予通知权限"),
+                        NotificationPermission::Unsupported => {
+                            Some("当前环境不支持浏览器通知，将以页面内提示显示")
+                        }
+                    });
+                });
+            });
+
+        let mut notification_controls = View::new()
+            .style(|s| s.display("flex").flex_direction("column").gap("8px"))
+            .children(notifications_checkbox)
+            .children(browser_checkbox);
+        if let Some(hint) = permission_hint() {
+            notification_controls = notification_controls.children(
+                Text::span(hint)
+                    .style(|s| s.font_size("12px").color("var(--t-text-color-secondary)")),
+            );
+        }
+
+        let reset = Button::new().text("恢复默认").onclick(move |_| {
+            let defaults = Settings::default();
+            theme.set(RadioValue::from(theme_index(defaults.theme)));
+            density.set(RadioValue::from(density_index(defaults.density)));
+            locale.set(RadioValue::from(defaults.locale.clone()));
+            reduced_motion.set(defaults.reduced_motion);
+            notifications.set(defaults.notifications);
+            browser_notifications.set(defaults.browser_notifications);
+            permission_hint.set(None);
+            update(&move |s| *s = defaults.clone());
+        });
+
+        let mut header =
+            View::new().children(Text::h2(self.title.clone()).style(|s| s.margin("0")));
+        if let Some(subtitle) = &self.subtitle {
+            header = header.children(
+                Text::p(subtitle.clone())
+                    .style(|s| s.margin("8px 0 0").color("var(--t-text-color-secondary)")),
+            );
+        }
+
+        View::new()
+            .style(|s| {
+                s.display("flex")
+                    .flex_direction("column")
+                    .gap("16px")
+                    .max_width("800px")
+                    .margin("0 auto")
+            })
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .justify_content("space-between")
+                            .align_items("flex-end")
+                    })
+                    .children(header)
+                    .children(reset),
+            )
+            .children(section("主题", "切换亮色、暗色或高对比度外观", theme_group))
+            .children(section(
+                "组件密度",
+                "调整按钮、卡片等组件的尺寸和间距",
+                density_group,
+            ))
+            .children(section(
+                "动画",
+                "关闭过渡和动画效果，系统开启减少动态效果时会自动生效",
+                motion_checkbox,
+            ))
+            .children(section("语言", "设置界面显示语言", locale_group))
+            .children(section(
+                "通知",
+                "开启后将接收系统消息和任务提醒，使用浏览器通知需要授予通知权限",
+                notification_controls,
+            ))
+            .to_element()
+    }
+}
+
+/// 主题在选项列表中的索引
+fn theme_index(theme: Theme) -> i64 {
+    THEMES.iter().position(|(t, _)| *t == theme).unwrap_or(0) as i64
+}
+
+/// 组件密度在选项列表中的索引
+fn density_index(density: Density) -> i64 {
+    DENSITIES
+        .iter()
+        .position(|(d, _)| *d == density)
+        .unwrap_or(0) as i64
+}
+
+/// 设置分区，左侧为标题和说明，右侧为设置项
+fn section(title: &str, description: &str, control: impl ToElement + Clone + 'static) -> Card {
+    Card::new().children(
+        View::new()
+            .style(|s| {
+                s.display("flex")
+                    .justify_content("space-between")
+                    .align_items("center")
+                    .flex_wrap("wrap")
+                    .gap("16px")
+            })
+            .children(
+                View::new()
+                    .children(Text::h3(title.to_string()).style(|s| s.margin("0")))
+                    .children(Text::p(description.to_string()).style(|s| {
+                        s.margin("4px 0 0")
+                            .font_size("13px")
+                            .color("var(--t-text-color-secondary)")
+                    })),
+            )
+            .children(control),
+    )
+}
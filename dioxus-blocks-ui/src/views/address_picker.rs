@@ -0,0 +1,185 @@
+//! AddressPicker 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    AddressPicker, AddressValue, Card, ChinaRegionsSample, PropsTable, Region, Text, ToElement,
+    View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct AddressPickerView {}
+
+impl ToElement for AddressPickerView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl AddressPickerView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("AddressPicker 地址选择器"),
+            Text::p(
+                "逐级选择省份、城市和区县，输出各级行政区划的代码和名称。示例使用 regions 特性内置的中国行政区划示例数据，只收录了部分城市和区县，生产环境需要通过 source 接入完整数据。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.levels(),
+            self.custom_source(),
+            api_reference(vec![PropsTable::of::<AddressPicker>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "每一级都可以输入文字筛选。选择上级后清空下级，没有下级的地区（如香港）不显示下一级。已保存的地址可以通过 AddressValue::from_code 按区县代码回显。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 选择级数
+    fn levels(&self) -> Card {
+        Card::new()
+            .anchor("选择级数")
+            .header(View::new().childrens(vec![
+                Text::h2("选择级数"),
+                Text::p(
+                    "levels(2) 只选择到城市，clearable 为每一级添加清空按钮，清空时同时清空下级。",
+                ),
+            ]))
+            .children(LevelsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义数据源
+    fn custom_source(&self) -> Card {
+        Card::new()
+            .anchor("自定义数据源")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义数据源"),
+                Text::p(
+                    "source 接收实现了 RegionSource 的数据源，闭包 Fn(Option<&str>) -> Vec<Region> 按上级代码返回下级地区，可以接入完整的行政区划或其他国家的地区数据。",
+                ),
+            ]))
+            .children(CustomSourceExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 显示地址的名称和代码
+fn address_text(value: &AddressValue) -> Text {
+    let text = if value.is_empty() {
+        "未选择".to_string()
+    } else {
+        format!("{}（{}）", value.text(" / "), value.codes().join(", "))
+    };
+    Text::p(text).style(|s| {
+        s.margin_top("16px")
+            .color("var(--t-text-color-secondary)")
+            .font_size("13px")
+    })
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let address = use_signal(|| AddressValue::from_code(&ChinaRegionsSample, "440305"));
+        let value = address.read().clone();
+
+        View::new()
+            .style(|s| s.max_width("520px"))
+            .children(
+                AddressPicker::new()
+                    .value(address)
+                    .source(ChinaRegionsSample),
+            )
+            .children(address_text(&value))
+            .to_element()
+    }
+}
+
+/// 选择级数示例
+#[derive(Debug, Default, Clone)]
+struct LevelsExample {}
+
+impl ToElement for LevelsExample {
+    fn to_element(&self) -> Element {
+        let address = use_signal(AddressValue::default);
+        let value = address.read().clone();
+
+        View::new()
+            .style(|s| s.max_width("360px"))
+            .children(
+                AddressPicker::new()
+                    .value(address)
+                    .source(ChinaRegionsSample)
+                    .levels(2)
+                    .clearable(true),
+            )
+            .children(address_text(&value))
+            .to_element()
+    }
+}
+
+/// 美国的州和城市
+fn us_regions(parent: Option<&str>) -> Vec<Region> {
+    match parent {
+        None => vec![
+            Region::new("US-CA", "California"),
+            Region::new("US-NY", "New York"),
+            Region::new("US-WA", "Washington"),
+        ],
+        Some("US-CA") => vec![
+            Region::new("US-CA-LA", "Los Angeles"),
+            Region::new("US-CA-SF", "San Francisco"),
+        ],
+        Some("US-NY") => vec![Region::new("US-NY-NYC", "New York City")],
+        Some("US-WA") => vec![Region::new("US-WA-SEA", "Seattle")],
+        Some(_) => Vec::new(),
+    }
+}
+
+/// 自定义数据源示例
+#[derive(Debug, Default, Clone)]
+struct CustomSourceExample {}
+
+impl ToElement for CustomSourceExample {
+    fn to_element(&self) -> Element {
+        let address = use_signal(AddressValue::default);
+        let value = address.read().clone();
+
+        View::new()
+            .style(|s| s.max_width("360px"))
+            .children(
+                AddressPicker::new()
+                    .value(address)
+                    .source(us_regions)
+                    .levels(2)
+                    .province_placeholder("State")
+                    .city_placeholder("City"),
+            )
+            .children(address_text(&value))
+            .to_element()
+    }
+}
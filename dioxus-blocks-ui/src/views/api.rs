@@ -0,0 +1,24 @@
+//! 组件 API 属性表
+
+use dioxus_blocks_components::{Card, PropsTable, Text, View};
+
+/// 组件示例页末尾的 API 卡片，属性表由组件的构建方法生成
+pub(crate) fn api_reference(tables: Vec<PropsTable>) -> Card {
+    Card::new()
+        .anchor("API")
+        .header(View::new().childrens(vec![
+            Text::h2("API"),
+            Text::p("属性即组件的构建方法，可以链式调用。"),
+        ]))
+        .children(
+            View::new()
+                .style(|s| {
+                    s.padding("20px")
+                        .display("flex")
+                        .flex_direction("column")
+                        .gap("24px")
+                })
+                .childrens(tables),
+        )
+        .style(|s| s.margin_top("32px"))
+}
@@ -0,0 +1,85 @@
+//! 登录与注册页面模板示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Link, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use crate::templates::{LoginForm, LoginPage, RegisterForm, RegisterPage};
+use crate::{AuthUser, use_auth};
+
+/// 模拟网络请求耗时
+async fn fake_request() {
+    dioxus_blocks_components::browser::sleep(800).await;
+}
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct LoginView {}
+
+impl ToElement for LoginView {
+    fn to_element(&self) -> Element {
+        let navigator = navigator();
+        let mut auth = use_auth();
+
+        LoginPage::new()
+            .title("欢迎回来")
+            .subtitle("演示账号：admin / 123456")
+            .extra(Link::new("#").text("忘记密码？").as_primary())
+            .social(Button::new().text("GitHub").as_plain())
+            .social(Button::new().text("微信").as_plain())
+            .footer(
+                View::new().children(Text::span("还没有账号？")).children(
+                    Link::default()
+                        .to(crate::Route::RegisterViewRoute {})
+                        .text("立即注册")
+                        .as_primary(),
+                ),
+            )
+            .onsubmit(move |form: LoginForm| async move {
+                fake_request().await;
+                if form.username == "admin" && form.password == "123456" {
+                    auth.login(
+                        AuthUser::new(form.username)
+                            .nickname("管理员")
+                            .role("admin"),
+                    );
+                    navigator.push(crate::Route::DashboardViewRoute {});
+                    Ok(())
+                } else {
+                    Err("用户名或密码错误".to_string())
+                }
+            })
+            .to_element()
+    }
+}
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct RegisterView {}
+
+impl ToElement for RegisterView {
+    fn to_element(&self) -> Element {
+        let navigator = navigator();
+
+        RegisterPage::new()
+            .title("创建账号")
+            .subtitle("用户名 admin 已被占用")
+            .social(Button::new().text("GitHub").as_plain())
+            .footer(
+                View::new().children(Text::span("已有账号？")).children(
+                    Link::default()
+                        .to(crate::Route::LoginViewRoute {})
+                        .text("去登录")
+                        .as_primary(),
+                ),
+            )
+            .onsubmit(move |form: RegisterForm| async move {
+                fake_request().await;
+                if form.username == "admin" {
+                    return Err("用户名已存在".to_string());
+                }
+                navigator.push(crate::Route::LoginViewRoute {});
+                Ok(())
+            })
+            .to_element()
+    }
+}
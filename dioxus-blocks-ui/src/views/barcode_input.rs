@@ -0,0 +1,145 @@
+//! BarcodeInput 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    BarcodeInput, BarcodeScan, Card, PropsTable, Text, ToElement, View, message,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct BarcodeInputView {}
+
+impl ToElement for BarcodeInputView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl BarcodeInputView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("BarcodeInput 条码输入"),
+            Text::p(
+                "用于录入商品条码、二维码等编码。支持手动输入和扫码枪输入，启用 camera 特性后可以在浏览器中打开摄像头扫码。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.inventory(),
+            api_reference(vec![PropsTable::of::<BarcodeInput>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "点击「扫码」打开摄像头，识别成功后写入输入框并调用 onscan。浏览器不支持或未授权摄像头时显示提示，仍可以手动输入后按回车确认。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 库存盘点
+    fn inventory(&self) -> Card {
+        Card::new()
+            .anchor("库存盘点")
+            .header(View::new().childrens(vec![
+                Text::h2("库存盘点"),
+                Text::p(
+                    "formats 限定识别的条码格式。每次扫码后清空输入框，连续扫描时累计各商品的数量。",
+                ),
+            ]))
+            .children(InventoryExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的说明文字
+fn hint(text: String) -> Text {
+    Text::p(text).style(|s| {
+        s.margin_top("16px")
+            .color("var(--t-text-color-secondary)")
+            .font_size("13px")
+    })
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let code = use_signal(String::new);
+        let mut last = use_signal(BarcodeScan::default);
+        let scan = last.read().clone();
+        let text = if scan.value.is_empty() {
+            "尚未扫码".to_string()
+        } else {
+            let format = scan.format.unwrap_or_else(|| "手动输入".to_string());
+            format!("{}（{format}）", scan.value)
+        };
+
+        View::new()
+            .style(|s| s.width("360px"))
+            .children(
+                BarcodeInput::new()
+                    .value(code)
+                    .onscan(move |scan| last.set(scan)),
+            )
+            .children(hint(text))
+            .to_element()
+    }
+}
+
+/// 库存盘点示例
+#[derive(Debug, Default, Clone)]
+struct InventoryExample {}
+
+impl ToElement for InventoryExample {
+    fn to_element(&self) -> Element {
+        let mut code = use_signal(String::new);
+        let mut counts = use_signal(Vec::<(String, u32)>::new);
+        let items = counts.read().clone();
+        let total: u32 = items.iter().map(|(_, count)| count).sum();
+
+        let mut list = View::new();
+        for (code, count) in items {
+            list = list.children(Text::p(format!("{code} × {count}")));
+        }
+
+        View::new()
+            .style(|s| s.width("360px"))
+            .children(
+                BarcodeInput::new()
+                    .value(code)
+                    .placeholder("扫描商品条码")
+                    .formats(vec!["ean_13", "ean_8", "upc_a", "code_128"])
+                    .onscan(move |scan: BarcodeScan| {
+                        let mut counts = counts.write();
+                        match counts.iter_mut().find(|(code, _)| *code == scan.value) {
+                            Some((_, count)) => *count += 1,
+                            None => counts.push((scan.value.clone(), 1)),
+                        }
+                        code.set(String::new());
+                        message::success(format!("已录入 {}", scan.value));
+                    }),
+            )
+            .children(hint(format!("共 {total} 件")))
+            .children(list)
+            .to_element()
+    }
+}
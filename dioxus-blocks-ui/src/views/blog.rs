@@ -1,19 +1,25 @@
 //! Blog 组件
 
 use dioxus::prelude::*;
-use dioxus_blocks_components::{Card, Link, Text, ToElement, View};
+use dioxus_blocks_components::{
+    CalendarHeatmap, Card, Link, Text, ToElement, View, use_document_title,
+};
 use dioxus_blocks_macro::Route;
 
 #[derive(Debug, Clone, Route)]
+#[route(props)]
 pub struct Blog {
     id: i32,
 }
 
 impl ToElement for Blog {
     fn to_element(&self) -> Element {
+        use_document_title(format!("Blog #{}", self.id));
+
         View::new()
             .children(self.title())
             .children(self.content())
+            .children(self.activity())
             .to_element()
     }
 }
@@ -28,6 +34,7 @@ impl Blog {
 
     fn content(&self) -> Card {
         Card::new()
+            .anchor("博客详情")
             .header(
                 View::new()
                     .children(Text::h2("博客详情"))
@@ -54,4 +61,23 @@ impl Blog {
                     ),
             )
     }
+
+    /// 作者活动记录
+    fn activity(&self) -> Card {
+        Card::new()
+            .anchor("作者活动")
+            .header(View::new().childrens(vec![
+                Text::h2("作者活动"),
+                Text::p("最近一年的写作与更新记录。"),
+            ]))
+            .body(
+                CalendarHeatmap::new()
+                    .year(2025)
+                    .data(super::calendar_heatmap::sample_activity(
+                        2025,
+                        self.id.unsigned_abs(),
+                    )),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
 }
@@ -3,10 +3,13 @@
 use dioxus::prelude::*;
 
 use dioxus_blocks_components::{
-    Button, ButtonShape, ButtonSize, ButtonType, Card, Text, ToElement, View,
+    Button, ButtonShape, ButtonSize, ButtonType, Card, Color, ConfirmButton, PropsTable, Text,
+    ToElement, View,
 };
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct ButtonView {}
 
@@ -37,13 +40,17 @@ impl ButtonView {
             self.state_buttons(),
             self.link_buttons(),
             self.text_buttons(),
+            self.custom_color_buttons(),
             self.counter_example(),
+            self.confirm_example(),
+            api_reference(vec![PropsTable::of::<Button>().common(true)]),
         ])
     }
 
     /// 基础按钮（实心）
     fn basic_buttons(&self) -> Card {
         Card::new()
+            .anchor("基础按钮")
             .header(
                 View::new().childrens(vec![Text::h2("基础按钮"), Text::p("不同类型的实心按钮。")]),
             )
@@ -67,6 +74,7 @@ impl ButtonView {
     /// 椭圆按钮
     fn round_buttons(&self) -> Card {
         Card::new()
+            .anchor("椭圆按钮")
             .header(View::new().childrens(vec![Text::h2("椭圆按钮"), Text::p("椭圆形状的按钮。")]))
             .children(
                 View::new()
@@ -101,6 +109,7 @@ impl ButtonView {
     /// 圆形按钮
     fn circle_buttons(&self) -> Card {
         Card::new()
+            .anchor("圆形按钮")
             .header(View::new().childrens(vec![
                 Text::h2("圆形按钮"),
                 Text::p("圆形形状的按钮，适合配合图标使用。"),
@@ -141,6 +150,7 @@ impl ButtonView {
     /// 不同尺寸按钮
     fn size_buttons(&self) -> Card {
         Card::new()
+            .anchor("按钮尺寸")
             .header(View::new().childrens(vec![Text::h2("按钮尺寸"), Text::p("不同大小的按钮。")]))
             .children(
                 View::new()
@@ -168,9 +178,41 @@ impl ButtonView {
             .style(|s| s.margin_top("32px"))
     }
 
+    /// 自定义颜色按钮
+    fn custom_color_buttons(&self) -> Card {
+        let colors = ["#722ed1", "#13c2c2", "#ffd666", "#f5f5f5", "#262626"];
+        Card::new()
+            .anchor("自定义颜色")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义颜色"),
+                Text::p("通过 color 设置任意颜色，文字颜色根据背景色自动选择黑色或白色，保证对比度满足 WCAG AA 级要求。"),
+            ]))
+            .children(
+                View::new()
+                    .style(|s| s.padding("20px").display("flex").gap("12px"))
+                    .childrens(
+                        colors
+                            .into_iter()
+                            .filter_map(|hex| Color::parse(hex).map(|color| Button::new().text(hex).color(color)))
+                            .collect(),
+                    ),
+            )
+            .children(
+                View::new()
+                    .style(|s| s.padding("20px").display("flex").gap("12px"))
+                    .childrens(vec![
+                        Button::new().text("Plain").color(Color::rgb(114, 46, 209)).as_plain(),
+                        Button::new().text("Link").color(Color::rgb(114, 46, 209)).as_link(),
+                        Button::new().text("Text").color(Color::rgb(114, 46, 209)).as_text(),
+                    ]),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
     /// 状态按钮
     fn state_buttons(&self) -> Card {
         Card::new()
+            .anchor("按钮状态")
             .header(View::new().childrens(vec![
                 Text::h2("按钮状态"),
                 Text::p("禁用和加载状态的按钮。"),
@@ -196,6 +238,7 @@ impl ButtonView {
     /// 朴素按钮
     fn plain_buttons(&self) -> Card {
         Card::new()
+            .anchor("朴素按钮")
             .header(View::new().childrens(vec![
                 Text::h2("朴素按钮"),
                 Text::p("朴素样式的按钮，带有边框和浅色背景。"),
@@ -246,6 +289,7 @@ impl ButtonView {
     /// 链接按钮
     fn link_buttons(&self) -> Card {
         Card::new()
+            .anchor("链接按钮")
             .header(View::new().childrens(vec![
                 Text::h2("链接按钮"),
                 Text::p("链接样式的按钮，常用于页面内导航。"),
@@ -296,6 +340,7 @@ impl ButtonView {
     /// 文字按钮
     fn text_buttons(&self) -> Card {
         Card::new()
+            .anchor("文字按钮")
             .header(View::new().childrens(vec![
                 Text::h2("文字按钮"),
                 Text::p("文字样式的按钮，hover 时显示背景色。"),
@@ -346,6 +391,7 @@ impl ButtonView {
     /// 计数器示例 - 按钮与文本联动
     fn counter_example(&self) -> Card {
         Card::new()
+            .anchor("计数器示例")
             .header(View::new().childrens(vec![
                 Text::h2("计数器示例"),
                 Text::p("按钮与 Text 组件的联动，点击按钮更新文本内容。"),
@@ -353,6 +399,59 @@ impl ButtonView {
             .children(CounterExample::default())
             .style(|s| s.margin_top("32px"))
     }
+
+    /// 确认按钮示例
+    fn confirm_example(&self) -> Card {
+        Card::new()
+            .anchor("确认按钮")
+            .header(View::new().childrens(vec![
+                Text::h2("确认按钮"),
+                Text::p(
+                    "ConfirmButton 点击后原地切换为取消和确认按钮，确认后执行异步操作并显示加载状态，3 秒内未确认时自动恢复。",
+                ),
+            ]))
+            .children(ConfirmExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 确认按钮示例 - 删除列表项
+#[derive(Debug, Default, Clone)]
+pub struct ConfirmExample {}
+
+impl ToElement for ConfirmExample {
+    fn to_element(&self) -> Element {
+        let mut items = use_signal(|| 3);
+        let mut message = use_signal(String::new);
+
+        View::new()
+            .style(|s| {
+                s.padding("20px")
+                    .display("flex")
+                    .gap("16px")
+                    .align_items("center")
+            })
+            .children(
+                ConfirmButton::new("删除一项")
+                    .prompt("确定删除？")
+                    .disabled(items() == 0)
+                    .onconfirm(move || async move {
+                        dioxus_blocks_components::browser::sleep(800).await;
+                        if items() == 1 {
+                            return Err("至少保留一项");
+                        }
+                        items -= 1;
+                        message.set(String::new());
+                        Ok(())
+                    })
+                    .onerror(move |error| message.set(error)),
+            )
+            .children(
+                Text::new(format!("剩余 {} 项 {}", items(), message()))
+                    .style(|s| s.font_size("14px").color("#606266")),
+            )
+            .into()
+    }
 }
 
 /// 状态组件 - 用于演示按钮和文本的联动
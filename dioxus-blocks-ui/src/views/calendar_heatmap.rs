@@ -0,0 +1,149 @@
+//! CalendarHeatmap 组件使用示例
+
+use chrono::{Datelike, Duration, NaiveDate};
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{CalendarHeatmap, Card, PropsTable, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+use crate::LazyRoute;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct CalendarHeatmapView {}
+
+impl ToElement for CalendarHeatmapView {
+    fn to_element(&self) -> Element {
+        // 热力图需要生成一整年的示例数据，首次进入时懒加载
+        let view = self.clone();
+        LazyRoute::new(move || view.page()).to_element()
+    }
+}
+
+impl CalendarHeatmapView {
+    fn page(&self) -> View {
+        View::new().children(self.title()).children(self.content())
+    }
+
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("CalendarHeatmap 日历热力图"),
+            Text::p(
+                "以日历格子的形式展示每日数值的分布，常用于贡献记录、活跃度等活动数据的可视化。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.custom_colors(),
+            self.custom_tooltip(),
+            api_reference(vec![PropsTable::of::<CalendarHeatmap>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "通过 year 设置显示的年份，通过 data 传入日期与数值，悬停格子可查看具体数值。",
+                ),
+            ]))
+            .children(
+                CalendarHeatmap::new()
+                    .year(2025)
+                    .data(sample_activity(2025, 7)),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义颜色
+    fn custom_colors(&self) -> Card {
+        Card::new()
+            .anchor("自定义颜色")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义颜色"),
+                Text::p("通过 colors 设置从 0 值到最大值的颜色刻度，等级数量与颜色数量保持一致。"),
+            ]))
+            .children(
+                CalendarHeatmap::new()
+                    .year(2025)
+                    .colors(vec!["#ebedf0", "#9be9a8", "#40c463", "#30a14e", "#216e39"])
+                    .data(sample_activity(2025, 3)),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义提示与日期范围
+    fn custom_tooltip(&self) -> Card {
+        Card::new()
+            .anchor("日期范围与提示")
+            .header(View::new().childrens(vec![
+                Text::h2("日期范围与提示"),
+                Text::p("通过 range 设置任意日期范围，通过 tooltip 自定义悬停提示文本。"),
+            ]))
+            .children(CustomTooltipExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 自定义提示示例
+#[derive(Debug, Default, Clone)]
+struct CustomTooltipExample {}
+
+impl ToElement for CustomTooltipExample {
+    fn to_element(&self) -> Element {
+        let mut selected = use_signal(|| None::<(NaiveDate, u32)>);
+        let start = NaiveDate::from_ymd_opt(2025, 1, 1).unwrap_or_default();
+        let end = NaiveDate::from_ymd_opt(2025, 6, 30).unwrap_or_default();
+
+        let tip = match selected() {
+            Some((date, value)) => format!("已选择 {}，共 {} 次提交", date, value),
+            None => "点击任意格子查看详情".to_string(),
+        };
+
+        View::new()
+            .style(|s| s.display("flex").flex_direction("column").gap("12px"))
+            .children(
+                CalendarHeatmap::new()
+                    .range(start, end)
+                    .data(sample_activity(2025, 11))
+                    .show_legend(false)
+                    .tooltip(|(date, value)| {
+                        format!("{} 提交了 {} 次", date.format("%m月%d日"), value)
+                    })
+                    .oncell_click(move |cell| selected.set(Some(cell))),
+            )
+            .children(Text::p(tip).style(|s| s.color("var(--t-text-color-secondary)")))
+            .into()
+    }
+}
+
+/// 生成一年的示例活动数据
+///
+/// 使用日期序号计算伪随机数值，保证每次渲染结果一致。
+pub fn sample_activity(year: i32, seed: u32) -> Vec<(NaiveDate, u32)> {
+    let Some(start) = NaiveDate::from_ymd_opt(year, 1, 1) else {
+        return Vec::new();
+    };
+    (0..366)
+        .map(|offset| start + Duration::days(offset))
+        .filter(|date| date.year() == year)
+        .map(|date| {
+            let n = (date.ordinal() * 31)
+                .wrapping_add(seed.wrapping_mul(17))
+                .wrapping_add(date.weekday().number_from_monday());
+            let value = if n % 5 == 0 {
+                0
+            } else {
+                n.wrapping_mul(7) % 12
+            };
+            (date, value)
+        })
+        .collect()
+}
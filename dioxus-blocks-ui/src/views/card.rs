@@ -2,9 +2,13 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, CardShadow, Text, View};
+use dioxus_blocks_components::{
+    Button, Card, CardShadow, Elevation, PropsTable, Text, View, export_to_pdf,
+};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct CardView {}
 
@@ -34,12 +38,16 @@ impl CardView {
             self.header_body_footer_card(),
             self.children_card(),
             self.shadow_card(),
+            self.elevation_card(),
+            self.print_card(),
+            api_reference(vec![PropsTable::of::<Card>().common(true)]),
         ])
     }
 
     /// 基础卡片
     fn basic_card(&self) -> Card {
         Card::new()
+            .anchor("极简卡片")
             .header(
                 View::new().childrens(vec![Text::h2("极简卡片"), Text::p("仅有内容的卡片形式。")]),
             )
@@ -52,6 +60,7 @@ impl CardView {
     /// 带标题栏的卡片
     fn header_and_body_card(&self) -> Card {
         Card::new()
+            .anchor("带 header 的卡片")
             .header(View::new().childrens(vec![
                 Text::h2("带 header 的卡片"),
                 Text::p(
@@ -70,6 +79,7 @@ impl CardView {
     /// 边框与分割线控制
     fn border_divider_card(&self) -> Card {
         Card::new()
+            .anchor("边框与分割线控制")
             .header(View::new().childrens(vec![
                 Text::h2("边框与分割线控制"),
                 Text::p("通过 border 属性控制卡片是否显示边框，通过 header_divider 属性控制标题与内容之间是否显示分割线。"),
@@ -87,6 +97,7 @@ impl CardView {
     /// 带底部栏的卡片
     fn footer_and_body_card(&self) -> Card {
         Card::new()
+            .anchor("带 footer 的卡片")
             .header(View::new().childrens(vec![
                 Text::h2("带 footer 的卡片"),
                 Text::p("由极简卡片下方的操作栏组成，操作栏中可包含按钮、链接、操作区等内容。"),
@@ -104,6 +115,7 @@ impl CardView {
     /// 完整卡片（header + body + footer）
     fn header_body_footer_card(&self) -> Card {
         Card::new()
+            .anchor("同时带 header 和 footer 的卡片")
             .header(View::new().childrens(vec![
                 Text::h2("同时带 header 和 footer 的卡片"),
                 Text::p("由顶部栏、底部栏和极简卡片组成的复杂卡片，三个区域内容可根据需要对内容进行配置。"),
@@ -121,6 +133,7 @@ impl CardView {
     /// 使用 children 的卡片
     fn children_card(&self) -> Card {
         Card::new()
+            .anchor("带 children 的卡片")
             .header(View::new().childrens(vec![
                 Text::h2("带 children 的卡片"),
                 Text::p("使用 children 属性替代 body 属性，可以使用任意元素作为卡片内容。"),
@@ -135,6 +148,7 @@ impl CardView {
     /// 阴影效果控制
     fn shadow_card(&self) -> Card {
         Card::new()
+            .anchor("阴影效果控制")
             .header(View::new().childrens(vec![
                 Text::h2("阴影效果控制"),
                 Text::p("通过 shadow 属性设置卡片阴影出现的时机。该属性的值可以是：always、hover 或 never。"),
@@ -159,4 +173,50 @@ impl CardView {
             ])
             .style(|s| s.margin_top("32px"))
     }
+
+    /// 阴影层级
+    fn elevation_card(&self) -> Card {
+        Card::new()
+            .anchor("阴影层级")
+            .header(View::new().childrens(vec![
+                Text::h2("阴影层级"),
+                Text::p("通过 CardShadow::Elevation 使用 0～5 级的统一阴影，其他元素可以通过 Style::elevation 使用相同的阴影。阴影来自主题变量，切换暗色主题时随之变化。"),
+            ]))
+            .childrens(vec![
+                View::new()
+                    .style(|s| s.display("flex").gap("20px").flex_wrap("wrap"))
+                    .childrens(
+                        Elevation::ALL
+                            .into_iter()
+                            .map(|elevation| {
+                                Card::new()
+                                    .shadow(CardShadow::Elevation(elevation))
+                                    .childrens(vec![Text::p(format!("Elevation {}", elevation.level()))])
+                                    .style(|s| s.width("120px"))
+                            })
+                            .collect(),
+                    ),
+            ])
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 打印与导出
+    fn print_card(&self) -> Card {
+        Card::new()
+            .id("card-print-report")
+            .print_friendly(true)
+            .header(View::new().childrens(vec![
+                Text::h2("打印与导出"),
+                Text::p("通过 print_friendly 启用打印样式，打印时去掉阴影和背景色，并避免卡片被分页截断。export_to_pdf 只打印指定 id 的元素，在打印对话框中选择“另存为 PDF”即可保存。"),
+            ]))
+            .childrens(vec![
+                Text::p("2024 年 6 月销售报表：订单 1,284 笔，销售额 ¥356,920，环比增长 12.6%。"),
+            ])
+            .footer(Button::new().text("导出 PDF").as_primary().onclick(|_| {
+                spawn(async move {
+                    export_to_pdf("card-print-report").await;
+                });
+            }))
+            .style(|s| s.margin_top("32px"))
+    }
 }
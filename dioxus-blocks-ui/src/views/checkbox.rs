@@ -3,10 +3,12 @@
 use dioxus::prelude::*;
 
 use dioxus_blocks_components::{
-    Card, Checkbox, CheckboxGroup, CheckboxValue, Text, ToElement, View,
+    Card, Checkbox, CheckboxGroup, CheckboxValue, PropsTable, Text, ToElement, View,
 };
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct CheckboxView {}
 
@@ -34,16 +36,22 @@ impl CheckboxView {
             self.basic_usage(),
             self.disabled_state(),
             self.checkbox_group(),
+            self.options(),
             self.indeterminate(),
             self.min_max(),
             self.button_style(),
             self.with_border(),
+            api_reference(vec![
+                PropsTable::of::<Checkbox>().common(true),
+                PropsTable::of::<CheckboxGroup>(),
+            ]),
         ])
     }
 
     /// 基础用法
     fn basic_usage(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
                 Text::p(
@@ -57,6 +65,7 @@ impl CheckboxView {
     /// 禁用状态
     fn disabled_state(&self) -> Card {
         Card::new()
+            .anchor("禁用状态")
             .header(View::new().childrens(vec![
                 Text::h2("禁用状态"),
                 Text::p("多选框不可用状态。设置 disabled 属性即可。"),
@@ -68,6 +77,7 @@ impl CheckboxView {
     /// 多选框组
     fn checkbox_group(&self) -> Card {
         Card::new()
+            .anchor("多选框组")
             .header(View::new().childrens(vec![
                 Text::h2("多选框组"),
                 Text::p("适用于多个勾选框绑定到同一个数组的情景，通过是否勾选来表示这一组选项中选中的项。"),
@@ -79,10 +89,11 @@ impl CheckboxView {
     /// 中间状态
     fn indeterminate(&self) -> Card {
         Card::new()
+            .anchor("中间状态")
             .header(View::new().childrens(vec![
                 Text::h2("中间状态"),
                 Text::p(
-                    "indeterminate 属性用以表示 checkbox 的不确定状态，一般用于实现全选的效果。",
+                    "with_check_all 在选项前添加全选框，部分选中时自动显示为 indeterminate 不确定状态。",
                 ),
             ]))
             .children(IndeterminateExample::default())
@@ -92,6 +103,7 @@ impl CheckboxView {
     /// 可选项目数量的限制
     fn min_max(&self) -> Card {
         Card::new()
+            .anchor("可选项目数量的限制")
             .header(View::new().childrens(vec![
                 Text::h2("可选项目数量的限制"),
                 Text::p("使用 min 和 max 属性能够限制可以被勾选的项目的数量。"),
@@ -103,6 +115,7 @@ impl CheckboxView {
     /// 按钮样式
     fn button_style(&self) -> Card {
         Card::new()
+            .anchor("按钮样式")
             .header(
                 View::new().childrens(vec![Text::h2("按钮样式"), Text::p("按钮样式的多选组合。")]),
             )
@@ -110,9 +123,22 @@ impl CheckboxView {
             .style(|s| s.margin_top("32px"))
     }
 
+    /// 由数据生成选项
+    fn options(&self) -> Card {
+        Card::new()
+            .anchor("由数据生成选项")
+            .header(View::new().childrens(vec![
+                Text::h2("由数据生成选项"),
+                Text::p("options 由一组数据生成多选框，闭包返回每一项的值和标签元素，不需要手动循环构建 Checkbox。"),
+            ]))
+            .children(OptionsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
     /// 带有边框
     fn with_border(&self) -> Card {
         Card::new()
+            .anchor("带有边框")
             .header(View::new().childrens(vec![
                 Text::h2("带有边框"),
                 Text::p("设置 border 属性可以渲染为带有边框的多选框。"),
@@ -301,67 +327,23 @@ pub struct IndeterminateExample {}
 
 impl ToElement for IndeterminateExample {
     fn to_element(&self) -> Element {
-        let cities = vec![
-            "Shanghai".to_string(),
-            "Beijing".to_string(),
-            "Guangzhou".to_string(),
-            "Shenzhen".to_string(),
-        ];
-        let mut checked_cities = use_signal(|| {
+        let checked_cities = use_signal(|| {
             vec![
                 CheckboxValue::String("Shanghai".to_string()),
                 CheckboxValue::String("Beijing".to_string()),
             ]
         });
-        let mut check_all = use_signal(|| false);
-        let mut is_indeterminate = use_signal(|| true);
-
-        let cities_clone = cities.clone();
-        let _handle_check_all_change = move |_val: CheckboxValue| {
-            let current_checked = checked_cities.read().clone();
-            if current_checked.len() == cities_clone.len() {
-                // 全部选中，取消全选
-                checked_cities.set(vec![]);
-                is_indeterminate.set(false);
-            } else {
-                // 全选
-                checked_cities.set(
-                    cities_clone
-                        .iter()
-                        .map(|s| CheckboxValue::String(s.clone()))
-                        .collect(),
-                );
-                is_indeterminate.set(false);
-            }
-        };
-
-        let handle_checked_cities_change = move |values: Vec<CheckboxValue>| {
-            let checked_count = values.len();
-            check_all.set(checked_count == cities.len());
-            is_indeterminate.set(checked_count > 0 && checked_count < cities.len());
-        };
 
         View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("16px")
-            })
+            .style(|s| s.padding("20px"))
             .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("12px"))
-                    .children(
-                        Checkbox::new()
-                            .value("all")
-                            .label("Check all")
-                            .indeterminate(*is_indeterminate.read()),
-                    )
-                    .children(
-                        CheckboxGroup::new()
-                            .value(checked_cities)
-                            .onchange(handle_checked_cities_change),
-                    ),
+                CheckboxGroup::new()
+                    .value(checked_cities)
+                    .with_check_all("Check all")
+                    .checkbox(Checkbox::new().value("Shanghai").label("Shanghai"))
+                    .checkbox(Checkbox::new().value("Beijing").label("Beijing"))
+                    .checkbox(Checkbox::new().value("Guangzhou").label("Guangzhou"))
+                    .checkbox(Checkbox::new().value("Shenzhen").label("Shenzhen")),
             )
             .into()
     }
@@ -488,6 +470,63 @@ impl ToElement for ButtonStyleExample {
     }
 }
 
+/// 权限
+struct Permission {
+    code: &'static str,
+    name: &'static str,
+    description: &'static str,
+}
+
+const PERMISSIONS: [Permission; 3] = [
+    Permission {
+        code: "read",
+        name: "查看",
+        description: "查看项目和文档",
+    },
+    Permission {
+        code: "write",
+        name: "编辑",
+        description: "创建和修改文档",
+    },
+    Permission {
+        code: "admin",
+        name: "管理",
+        description: "管理成员和项目设置",
+    },
+];
+
+/// 由数据生成选项示例
+#[derive(Debug, Default, Clone)]
+pub struct OptionsExample {}
+
+impl ToElement for OptionsExample {
+    fn to_element(&self) -> Element {
+        let permissions = use_signal(|| vec![CheckboxValue::from("read")]);
+
+        View::new()
+            .style(|s| s.padding("20px"))
+            .children(
+                CheckboxGroup::new()
+                    .value(permissions)
+                    .options(&PERMISSIONS, |permission| {
+                        (
+                            permission.code,
+                            View::new().children(Text::span(permission.name)).children(
+                                Text::span(format!("（{}）", permission.description))
+                                    .style(|s| s.color("var(--t-text-color-secondary)")),
+                            ),
+                        )
+                    }),
+            )
+            .children(
+                View::new()
+                    .style(|s| s.margin_top("16px"))
+                    .children(Text::new(format!("当前值: {:?}", permissions.read()))),
+            )
+            .into()
+    }
+}
+
 /// 带有边框示例
 #[derive(Debug, Default, Clone)]
 pub struct WithBorder {}
@@ -0,0 +1,96 @@
+//! CreditCardInput 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, CreditCardFieldState, CreditCardInput, CreditCardValue, PropsTable, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct CreditCardInputView {}
+
+impl ToElement for CreditCardInputView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl CreditCardInputView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("CreditCardInput 银行卡输入"),
+            Text::p("由卡号、有效期和安全码组成的复合输入框，自动识别卡组织并按格式分组。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            api_reference(vec![PropsTable::of::<CreditCardInput>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "输入 4242 4242 4242 4242 或 3782 822463 10005 试试，各字段的校验状态通过 CreditCardValue::validate 获取。",
+                ),
+            ]))
+            .children(PaymentExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 支付信息示例
+#[derive(Debug, Default, Clone)]
+struct PaymentExample {}
+
+impl ToElement for PaymentExample {
+    fn to_element(&self) -> Element {
+        let card = use_signal(CreditCardValue::default);
+        let value = card.read().clone();
+        let validation = value.validate();
+        let state = |state: CreditCardFieldState| match state {
+            CreditCardFieldState::Empty => "未填写",
+            CreditCardFieldState::Incomplete => "未完成",
+            CreditCardFieldState::Invalid => "无效",
+            CreditCardFieldState::Valid => "有效",
+        };
+        let brand = match value.brand.label() {
+            "" => "未识别",
+            label => label,
+        };
+
+        View::new()
+            .style(|s| s.width("360px"))
+            .children(CreditCardInput::new().value(card))
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.margin_top("16px")
+                            .color("var(--t-text-color-secondary)")
+                            .font_size("13px")
+                    })
+                    .childrens(vec![
+                        Text::p(format!("卡组织：{brand}")),
+                        Text::p(format!(
+                            "卡号：{}，有效期：{}，安全码：{}",
+                            state(validation.number),
+                            state(validation.expiry),
+                            state(validation.cvc)
+                        )),
+                    ]),
+            )
+            .to_element()
+    }
+}
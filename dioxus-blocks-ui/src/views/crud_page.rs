@@ -0,0 +1,281 @@
+//! CrudPage 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, CrudList, CrudPage, CrudQuery, CrudRecord, FilterField, FormSchema, FormValue,
+    PropsTable, SchemaField, SchemaFieldType, SchemaFormValues, SchemaOption, TableColumn, Text,
+    ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct CrudPageView {}
+
+impl ToElement for CrudPageView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl CrudPageView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("CrudPage 增删改查页面"),
+            Text::p(
+                "组合筛选栏、表格、表单对话框和确认按钮，只需提供查询、新建、更新、删除操作以及列和表单字段的定义，即可得到完整的列表页。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.optimistic_usage(),
+            api_reference(vec![PropsTable::of::<CrudPage<Product>>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "记录类型实现 CrudRecord，提供行的 key、各列的内容和编辑时表单的初始值。本示例的数据保存在内存中，每次操作模拟 300 毫秒的网络延迟，名称为「测试」的商品保存失败。",
+                ),
+            ]))
+            .children(BasicUsage::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 乐观删除
+    fn optimistic_usage(&self) -> Card {
+        Card::new()
+            .anchor("乐观删除")
+            .header(View::new().childrens(vec![
+                Text::h2("乐观删除"),
+                Text::p(
+                    "optimistic(true) 时确认删除后立即从列表中移除记录，不等待删除完成；删除失败时恢复记录并提示错误。本示例的删除需要 1.5 秒，数码分类的商品删除失败。",
+                ),
+            ]))
+            .children(OptimisticUsage::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的商品
+#[derive(Debug, Clone, PartialEq)]
+pub struct Product {
+    id: u32,
+    name: String,
+    category: String,
+    price: String,
+}
+
+impl CrudRecord for Product {
+    fn key(&self) -> String {
+        self.id.to_string()
+    }
+
+    fn cell(&self, prop: &str) -> String {
+        match prop {
+            "id" => self.id.to_string(),
+            "name" => self.name.clone(),
+            "category" => category_label(&self.category).to_string(),
+            "price" => format!("¥{}", self.price),
+            _ => String::new(),
+        }
+    }
+
+    fn form_values(&self) -> SchemaFormValues {
+        SchemaFormValues::from([
+            ("product-name".to_string(), self.name.as_str().into()),
+            (
+                "product-category".to_string(),
+                self.category.as_str().into(),
+            ),
+            ("product-price".to_string(), self.price.as_str().into()),
+        ])
+    }
+}
+
+/// 商品分类
+const CATEGORIES: [(&str, &str); 3] = [("digital", "数码"), ("book", "图书"), ("food", "食品")];
+
+/// 分类的显示名称
+fn category_label(value: &str) -> &str {
+    CATEGORIES
+        .iter()
+        .find(|(key, _)| *key == value)
+        .map_or(value, |(_, label)| label)
+}
+
+/// 初始的商品数据
+fn products() -> Vec<Product> {
+    [
+        ("无线耳机", "digital", "299"),
+        ("机械键盘", "digital", "459"),
+        ("Rust 程序设计", "book", "89"),
+        ("算法导论", "book", "128"),
+        ("坚果礼盒", "food", "99"),
+        ("挂耳咖啡", "food", "59"),
+        ("显示器支架", "digital", "169"),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, (name, category, price))| Product {
+        id: i as u32 + 1,
+        name: name.to_string(),
+        category: category.to_string(),
+        price: price.to_string(),
+    })
+    .collect()
+}
+
+/// 由表单的值更新商品
+fn apply(product: &mut Product, values: &SchemaFormValues) {
+    let text = |name: &str| values.get(name).map(FormValue::as_text).unwrap_or_default();
+    product.name = text("product-name");
+    product.category = text("product-category");
+    product.price = text("product-price");
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+pub struct BasicUsage {}
+
+impl ToElement for BasicUsage {
+    fn to_element(&self) -> Element {
+        let mut store = use_signal(products);
+
+        let schema = FormSchema::new()
+            .field(
+                SchemaField::new("product-name", "名称")
+                    .required(true)
+                    .placeholder("请输入商品名称"),
+            )
+            .field(
+                SchemaField::new("product-category", "分类")
+                    .kind(SchemaFieldType::Select)
+                    .required(true)
+                    .options(
+                        CATEGORIES
+                            .iter()
+                            .map(|(value, label)| SchemaOption::new(*value).label(*label))
+                            .collect(),
+                    ),
+            )
+            .field(
+                SchemaField::new("product-price", "价格")
+                    .kind(SchemaFieldType::Number)
+                    .required(true)
+                    .min(0.0),
+            );
+
+        CrudPage::<Product>::new()
+            .item_name("商品")
+            .page_size(5)
+            .column(TableColumn::new("id", "编号").width("80px"))
+            .column(TableColumn::new("name", "名称"))
+            .column(TableColumn::new("category", "分类").width("120px"))
+            .column(TableColumn::new("price", "价格").width("120px"))
+            .filter(FilterField::input("name", "名称"))
+            .filter(FilterField::select("category", "分类", CATEGORIES))
+            .schema(schema)
+            .fetch(move |query: CrudQuery| async move {
+                dioxus_blocks_components::browser::sleep(300).await;
+                let name = query.filters.get("name").and_then(|v| v.as_str());
+                let category = query.filters.get("category").and_then(|v| v.as_str());
+                let matched = store
+                    .read()
+                    .iter()
+                    .filter(|product| name.is_none_or(|name| product.name.contains(name)))
+                    .filter(|product| category.is_none_or(|category| product.category == category))
+                    .cloned()
+                    .collect::<Vec<_>>();
+                let total = matched.len();
+                let items = matched
+                    .into_iter()
+                    .skip(query.offset())
+                    .take(query.page_size)
+                    .collect();
+                Ok::<_, String>(CrudList::new(items, total))
+            })
+            .create(move |values: SchemaFormValues| async move {
+                dioxus_blocks_components::browser::sleep(300).await;
+                let mut product = Product {
+                    id: store.peek().iter().map(|p| p.id).max().unwrap_or(0) + 1,
+                    name: String::new(),
+                    category: String::new(),
+                    price: String::new(),
+                };
+                apply(&mut product, &values);
+                if product.name == "测试" {
+                    return Err("名称「测试」已被占用".to_string());
+                }
+                store.write().push(product);
+                Ok(())
+            })
+            .update(
+                move |product: Product, values: SchemaFormValues| async move {
+                    dioxus_blocks_components::browser::sleep(300).await;
+                    if let Some(target) = store.write().iter_mut().find(|p| p.id == product.id) {
+                        apply(target, &values);
+                    }
+                    Ok::<_, String>(())
+                },
+            )
+            .delete(move |product: Product| async move {
+                dioxus_blocks_components::browser::sleep(300).await;
+                store.write().retain(|p| p.id != product.id);
+                Ok::<_, String>(())
+            })
+            .into()
+    }
+}
+
+/// 乐观删除示例
+#[derive(Debug, Default, Clone)]
+pub struct OptimisticUsage {}
+
+impl ToElement for OptimisticUsage {
+    fn to_element(&self) -> Element {
+        let mut store = use_signal(products);
+
+        CrudPage::<Product>::new()
+            .item_name("商品")
+            .page_size(5)
+            .optimistic(true)
+            .column(TableColumn::new("id", "编号").width("80px"))
+            .column(TableColumn::new("name", "名称"))
+            .column(TableColumn::new("category", "分类").width("120px"))
+            .column(TableColumn::new("price", "价格").width("120px"))
+            .fetch(move |query: CrudQuery| {
+                let total = store.peek().len();
+                let items = store
+                    .peek()
+                    .iter()
+                    .skip(query.offset())
+                    .take(query.page_size)
+                    .cloned()
+                    .collect();
+                async move { Ok::<_, String>(CrudList::new(items, total)) }
+            })
+            .delete(move |product: Product| async move {
+                dioxus_blocks_components::browser::sleep(1500).await;
+                if product.category == "digital" {
+                    return Err(format!("「{}」有未完成的订单", product.name));
+                }
+                store.write().retain(|p| p.id != product.id);
+                Ok(())
+            })
+            .into()
+    }
+}
@@ -0,0 +1,101 @@
+//! 管理后台仪表盘模板示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::ToElement;
+use dioxus_blocks_macro::Route;
+
+use crate::templates::{DashboardStat, DashboardTemplate};
+use crate::{Guarded, RequireAuth};
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct DashboardView {}
+
+impl ToElement for DashboardView {
+    fn to_element(&self) -> Element {
+        // 在趋势图中框选后，在副标题中汇总所选日期的销售额
+        let selected = use_signal(|| None::<(usize, usize)>);
+
+        // 未登录时跳转到登录页
+        Guarded::new(self.template(selected))
+            .guard(RequireAuth::new(crate::Route::LoginViewRoute {}))
+            .to_element()
+    }
+}
+
+impl DashboardView {
+    fn template(&self, mut selected: Signal<Option<(usize, usize)>>) -> DashboardTemplate {
+        let subtitle = match selected() {
+            Some((first, last)) => {
+                let sum: f64 = SALES[first..=last].iter().map(|(_, v)| v).sum();
+                format!(
+                    "数据更新于今日 09:30 · 已选择{}至{}，销售额合计 {sum} 千元",
+                    SALES[first].0, SALES[last].0
+                )
+            }
+            None => "数据更新于今日 09:30".to_string(),
+        };
+
+        DashboardTemplate::new()
+            .title("运营概览")
+            .subtitle(subtitle)
+            .stat(
+                DashboardStat::new("今日销售额", "¥126,560")
+                    .trend(12.5)
+                    .description("较昨日"),
+            )
+            .stat(
+                DashboardStat::new("访问量", "8,846")
+                    .trend(-3.2)
+                    .description("较昨日"),
+            )
+            .stat(
+                DashboardStat::new("订单数", "1,280")
+                    .trend(8.1)
+                    .description("较昨日"),
+            )
+            .stat(
+                DashboardStat::new("转化率", "14.5%")
+                    .trend(1.6)
+                    .description("较上周"),
+            )
+            .trend("近 7 日销售额（千元）", SALES.to_vec())
+            .onbrush(move |range| selected.set(Some(range)))
+            .breakdown(
+                "流量来源",
+                vec![
+                    ("搜索引擎", 4520.0),
+                    ("直接访问", 2380.0),
+                    ("社交媒体", 1260.0),
+                    ("邮件营销", 486.0),
+                    ("其他", 200.0),
+                ],
+            )
+            .table(
+                "最新订单",
+                vec!["订单号", "客户", "金额", "状态"],
+                vec![
+                    vec!["#10086", "张三", "¥299.00", "已支付"],
+                    vec!["#10085", "李四", "¥1,280.00", "待发货"],
+                    vec!["#10084", "王五", "¥56.80", "已完成"],
+                    vec!["#10083", "赵六", "¥420.00", "已退款"],
+                    vec!["#10082", "钱七", "¥888.00", "已完成"],
+                ],
+            )
+            .activity("09:24", "张三 创建了订单 #10086")
+            .activity("09:02", "李四 申请了发票")
+            .activity("昨天 18:40", "王五 完成了商品评价")
+            .activity("昨天 16:15", "管理员 上架了 12 件新商品")
+    }
+}
+
+/// 近 7 日销售额，单位为千元
+const SALES: [(&str, f64); 7] = [
+    ("周一", 82.0),
+    ("周二", 96.0),
+    ("周三", 74.0),
+    ("周四", 128.0),
+    ("周五", 142.0),
+    ("周六", 168.0),
+    ("周日", 126.0),
+];
@@ -0,0 +1,339 @@
+//! DataSource 数据源使用示例
+
+use std::cmp::Ordering;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, DataPage, DataQuery, DataSource, Input, MemoryDataSource, Select, SelectOption,
+    SelectValue, Spin, Table, TableColumn, TableRow, Text, ToElement, View, use_data_source,
+};
+use dioxus_blocks_macro::Route;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct DataSourceView {}
+
+impl ToElement for DataSourceView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl DataSourceView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("DataSource 数据源"),
+            Text::p(
+                "DataSource 按查询条件返回一页数据和总数，Table、远程搜索的 Select 和 CrudPage 都可以直接使用。在模拟数据和真实接口之间切换时只需要替换数据源。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.hook(),
+            self.table(),
+            self.remote_select(),
+            self.http(),
+        ])
+    }
+
+    /// 查询数据
+    fn hook(&self) -> Card {
+        Card::new()
+            .anchor("查询数据")
+            .header(View::new().childrens(vec![
+                Text::h2("查询数据"),
+                Text::p(
+                    "use_data_source 的查询条件变化时自动重新查询，只保留最后一次查询的结果，并提供加载中、错误和总数，适合需要自行分页的场景。",
+                ),
+            ]))
+            .children(HookExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 表格
+    fn table(&self) -> Card {
+        Card::new()
+            .anchor("表格")
+            .header(View::new().childrens(vec![
+                Text::h2("表格"),
+                Text::p(
+                    "Table::data_source 设置数据源后，表格自行查询当前页的行，点击可排序的表头时由数据源排序，查询期间显示加载状态。示例的数据源延迟 500 毫秒返回。",
+                ),
+            ]))
+            .children(TableExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 远程搜索
+    fn remote_select(&self) -> Card {
+        Card::new()
+            .anchor("远程搜索")
+            .header(View::new().childrens(vec![
+                Text::h2("远程搜索"),
+                Text::p(
+                    "Select::remote 设置选项的数据源后，输入的文字作为查询关键字，由数据源返回匹配的选项，查询期间下拉菜单显示加载中。",
+                ),
+            ]))
+            .children(RemoteSelectExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// HTTP 接口
+    fn http(&self) -> Card {
+        Card::new()
+            .anchor("HTTP 接口")
+            .header(View::new().childrens(vec![
+                Text::h2("HTTP 接口"),
+                Text::p(
+                    "启用 http 特性后，HttpDataSource::new(\"/api/employees\") 以 GET 请求查询接口，查询条件转换为 page、page_size、keyword、筛选字段以及 sort 和 order 参数，从响应的 items 和 total 字段读取记录和总数，字段名可以通过 items_key 和 total_key 修改。",
+                ),
+                Text::p(
+                    "返回 Future 的闭包同样是数据源，可以直接调用服务端函数：.data_source(|query: DataQuery| list_employees(query))。",
+                ),
+            ]))
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 员工
+#[derive(Debug, Clone, PartialEq)]
+struct Employee {
+    id: u32,
+    name: &'static str,
+    department: &'static str,
+    age: u32,
+}
+
+impl Employee {
+    fn row(self) -> TableRow {
+        TableRow::new(self.id.to_string())
+            .cell("name", self.name)
+            .cell("department", self.department)
+            .cell("age", self.age.to_string())
+    }
+}
+
+/// 示例使用的员工数据源，按姓名或部门搜索，按姓名或年龄排序
+fn employees() -> MemoryDataSource<Employee> {
+    let items = [
+        ("张伟", "研发部", 31),
+        ("王芳", "市场部", 27),
+        ("李娜", "研发部", 35),
+        ("刘洋", "财务部", 42),
+        ("陈静", "设计部", 25),
+        ("杨磊", "研发部", 29),
+        ("赵敏", "市场部", 33),
+        ("黄强", "运维部", 38),
+        ("周婷", "设计部", 26),
+        ("吴刚", "财务部", 45),
+        ("徐丽", "研发部", 30),
+        ("孙杰", "运维部", 36),
+    ]
+    .into_iter()
+    .enumerate()
+    .map(|(i, (name, department, age))| Employee {
+        id: i as u32 + 1,
+        name,
+        department,
+        age,
+    })
+    .collect::<Vec<_>>();
+
+    MemoryDataSource::new(items)
+        .filter(|employee: &Employee, query: &DataQuery| {
+            let keyword = query.keyword.trim();
+            employee.name.contains(keyword) || employee.department.contains(keyword)
+        })
+        .sort_by(|a: &Employee, b: &Employee, prop: &str| match prop {
+            "age" => a.age.cmp(&b.age),
+            "name" => a.name.cmp(b.name),
+            _ => Ordering::Equal,
+        })
+}
+
+/// 说明文字的样式
+fn hint(text: String) -> Text {
+    Text::span(text).style(|s| s.color("var(--t-text-color-secondary)"))
+}
+
+/// 查询数据示例
+#[derive(Debug, Default, Clone)]
+struct HookExample {}
+
+impl ToElement for HookExample {
+    fn to_element(&self) -> Element {
+        let source = use_hook(employees);
+        let keyword = use_signal(String::new);
+        let mut page = use_signal(|| 1usize);
+        let resource = use_data_source(source.map(Employee::row), move || {
+            DataQuery::new()
+                .page(page())
+                .page_size(4)
+                .keyword(keyword())
+        });
+
+        let pages = resource.total().div_ceil(4).max(1);
+        let status = match (resource.loading(), resource.error()) {
+            (true, _) => "查询中".to_string(),
+            (false, Some(error)) => error,
+            (false, None) => format!("共 {} 条，第 {} / {pages} 页", resource.total(), page()),
+        };
+
+        View::new()
+            .children(
+                Input::new()
+                    .value(keyword)
+                    .placeholder("搜索姓名或部门")
+                    .oninput(move |_| page.set(1))
+                    .style(|s| s.max_width("280px").margin_bottom("12px")),
+            )
+            .children(
+                Spin::new().loading(resource.loading()).children(
+                    Table::new()
+                        .column(TableColumn::new("name", "姓名"))
+                        .column(TableColumn::new("department", "部门"))
+                        .column(TableColumn::new("age", "年龄").width("100px"))
+                        .rows(resource.items()),
+                ),
+            )
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .align_items("center")
+                            .gap("12px")
+                            .margin_top("12px")
+                    })
+                    .children(hint(status))
+                    .children(
+                        Button::new()
+                            .text("上一页")
+                            .as_small()
+                            .disabled(page() <= 1)
+                            .onclick(move |_| page -= 1),
+                    )
+                    .children(
+                        Button::new()
+                            .text("下一页")
+                            .as_small()
+                            .disabled(page() >= pages)
+                            .onclick(move |_| page += 1),
+                    ),
+            )
+            .into()
+    }
+}
+
+/// 表格示例
+#[derive(Debug, Default, Clone)]
+struct TableExample {}
+
+impl ToElement for TableExample {
+    fn to_element(&self) -> Element {
+        let source = use_hook(employees);
+        let query = use_signal(|| DataQuery::new().page_size(5));
+
+        // 模拟接口的延迟
+        let delayed = move |query: DataQuery| {
+            let future = source.query(query);
+            async move {
+                dioxus_blocks_components::browser::sleep(500).await;
+                future
+                    .await
+                    .map(|page: DataPage<Employee>| page.map(Employee::row))
+            }
+        };
+
+        View::new()
+            .children(
+                Table::new()
+                    .column(TableColumn::new("name", "姓名").sortable(true))
+                    .column(TableColumn::new("department", "部门"))
+                    .column(
+                        TableColumn::new("age", "年龄")
+                            .width("100px")
+                            .sortable(true),
+                    )
+                    .query(query)
+                    .data_source(delayed),
+            )
+            .children(
+                hint(format!(
+                    "排序条件：{}",
+                    query
+                        .read()
+                        .sort
+                        .as_ref()
+                        .map(|sort| format!(
+                            "{} {}",
+                            sort.prop,
+                            if sort.descending { "desc" } else { "asc" }
+                        ))
+                        .unwrap_or_else(|| "无".to_string())
+                ))
+                .style(|s| s.display("block").margin_top("12px")),
+            )
+            .into()
+    }
+}
+
+/// 远程搜索示例
+#[derive(Debug, Default, Clone)]
+struct RemoteSelectExample {}
+
+impl ToElement for RemoteSelectExample {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| None::<SelectValue>);
+        let cities = use_hook(|| {
+            MemoryDataSource::new(vec![
+                ("beijing", "北京"),
+                ("shanghai", "上海"),
+                ("guangzhou", "广州"),
+                ("shenzhen", "深圳"),
+                ("hangzhou", "杭州"),
+                ("chengdu", "成都"),
+                ("wuhan", "武汉"),
+                ("xian", "西安"),
+            ])
+            .filter(|city: &(&str, &str), query: &DataQuery| {
+                city.0.contains(&query.keyword.to_lowercase()) || city.1.contains(&query.keyword)
+            })
+        });
+
+        let search = move |query: DataQuery| {
+            let future = cities.query(query);
+            async move {
+                dioxus_blocks_components::browser::sleep(300).await;
+                future.await.map(|page: DataPage<(&str, &str)>| {
+                    page.map(|(value, label)| SelectOption::new(value).label(label))
+                })
+            }
+        };
+
+        View::new()
+            .children(
+                Select::new()
+                    .value(value)
+                    .placeholder("输入城市名称或拼音")
+                    .clearable(true)
+                    .remote(search)
+                    .style(|s| s.width("280px")),
+            )
+            .children(
+                hint(format!(
+                    "当前值：{}",
+                    value
+                        .read()
+                        .as_ref()
+                        .map_or("无".to_string(), ToString::to_string)
+                ))
+                .style(|s| s.display("block").margin_top("12px")),
+            )
+            .into()
+    }
+}
@@ -0,0 +1,258 @@
+//! DateTimePicker 和 DateRangePicker 组件使用示例
+
+use chrono::{DateTime, Duration, FixedOffset};
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, DateRange, DateRangePicker, DateTimePicker, FilterBar, FilterField, FilterQuery,
+    FilterValue, PropsTable, RangePreset, Text, Timezone, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct DatePickerView {}
+
+impl ToElement for DatePickerView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl DatePickerView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("DatePicker 日期选择"),
+            Text::p(
+                "DateTimePicker 组合日期和时间选择，DateRangePicker 选择日期范围并提供快捷范围。两者都可以选择时区，时区以相对 UTC 的固定偏移表示。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.date_time(),
+            self.range(),
+            self.custom_presets(),
+            self.filter_bar(),
+            api_reference(vec![
+                PropsTable::of::<DateTimePicker>().common(true),
+                PropsTable::of::<DateRangePicker>().common(true),
+            ]),
+        ])
+    }
+
+    /// 日期时间
+    fn date_time(&self) -> Card {
+        Card::new()
+            .anchor("日期时间")
+            .header(View::new().childrens(vec![
+                Text::h2("日期时间"),
+                Text::p(
+                    "传入 timezones 后显示时区选择框。切换时区时保留已选择的日期和时间，按新的时区重新解释。",
+                ),
+            ]))
+            .children(DateTimeExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 日期范围
+    fn range(&self) -> Card {
+        Card::new()
+            .anchor("日期范围")
+            .header(View::new().childrens(vec![
+                Text::h2("日期范围"),
+                Text::p(
+                    "默认提供今天、最近 7 天、最近 30 天和本月四个快捷范围，按所选时区的当前日期计算。不选择时间时范围从开始日期的 00:00:00 到结束日期的 23:59:59。",
+                ),
+            ]))
+            .children(RangeExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义快捷范围
+    fn custom_presets(&self) -> Card {
+        Card::new()
+            .anchor("自定义快捷范围")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义快捷范围"),
+                Text::p(
+                    "presets 替换快捷范围，内置范围可以通过 with_label 修改显示文本，RangePreset::new 由当前时间计算任意范围。show_time 同时选择时间。",
+                ),
+            ]))
+            .children(CustomPresetsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 配合筛选栏
+    fn filter_bar(&self) -> Card {
+        Card::new()
+            .anchor("配合筛选栏")
+            .header(View::new().childrens(vec![
+                Text::h2("配合筛选栏"),
+                Text::p(
+                    "DateRange 可以转换为 FilterBar 的日期范围条件。写入 FilterBar 绑定的查询条件后，筛选栏中的日期同步更新。",
+                ),
+            ]))
+            .children(FilterBarExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的说明文字
+fn hint(text: String) -> Text {
+    Text::p(text).style(|s| {
+        s.margin_top("16px")
+            .color("var(--t-text-color-secondary)")
+            .font_size("13px")
+    })
+}
+
+/// 范围的显示文本
+fn range_text(range: Option<DateRange>) -> String {
+    match range {
+        Some(range) => format!(
+            "{} ~ {}",
+            range.start().format("%Y-%m-%d %H:%M:%S %:z"),
+            range.end().format("%Y-%m-%d %H:%M:%S %:z")
+        ),
+        None => "未选择".to_string(),
+    }
+}
+
+/// 日期时间示例
+#[derive(Debug, Default, Clone)]
+struct DateTimeExample {}
+
+impl ToElement for DateTimeExample {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| None::<DateTime<FixedOffset>>);
+        let text = match value() {
+            Some(time) => format!(
+                "{}（UTC：{}）",
+                time.to_rfc3339(),
+                time.naive_utc().format("%Y-%m-%d %H:%M:%S")
+            ),
+            None => "未选择".to_string(),
+        };
+
+        View::new()
+            .children(
+                DateTimePicker::new()
+                    .value(value)
+                    .timezone(Timezone::named("北京", 8 * 60))
+                    .timezones(Timezone::common()),
+            )
+            .children(hint(text))
+            .to_element()
+    }
+}
+
+/// 日期范围示例
+#[derive(Debug, Default, Clone)]
+struct RangeExample {}
+
+impl ToElement for RangeExample {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| None);
+
+        View::new()
+            .children(DateRangePicker::new().value(value))
+            .children(hint(range_text(value())))
+            .to_element()
+    }
+}
+
+/// 自定义快捷范围示例
+#[derive(Debug, Default, Clone)]
+struct CustomPresetsExample {}
+
+impl ToElement for CustomPresetsExample {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| None);
+
+        View::new()
+            .children(
+                DateRangePicker::new()
+                    .value(value)
+                    .show_time(true)
+                    .timezone(Timezone::utc())
+                    .timezones(Timezone::common())
+                    .presets(vec![
+                        RangePreset::last_days(7).with_label("Last 7 days"),
+                        RangePreset::this_month().with_label("This month"),
+                        RangePreset::last_month().with_label("Last month"),
+                        RangePreset::new("Last 24 hours", |now| {
+                            DateRange::new(now - Duration::hours(24), now)
+                        }),
+                    ]),
+            )
+            .children(hint(range_text(value())))
+            .to_element()
+    }
+}
+
+/// 配合筛选栏示例
+#[derive(Debug, Default, Clone)]
+struct FilterBarExample {}
+
+impl ToElement for FilterBarExample {
+    fn to_element(&self) -> Element {
+        let mut query = use_signal(FilterQuery::new);
+        let mut submitted = use_signal(|| None::<FilterQuery>);
+        let timezone = Timezone::local();
+        let range = query
+            .read()
+            .get("created")
+            .and_then(|value| DateRange::from_filter_value(value, &timezone));
+
+        let summary = match &*submitted.read() {
+            None => "尚未查询".to_string(),
+            Some(query) => match query.get("created").and_then(FilterValue::as_date_range) {
+                Some((start, end)) => format!(
+                    "查询下单日期：{} ~ {}",
+                    start.unwrap_or(""),
+                    end.unwrap_or("")
+                ),
+                None => "查询下单日期：不限".to_string(),
+            },
+        };
+
+        View::new()
+            .children(
+                DateRangePicker::new()
+                    .presets(vec![
+                        RangePreset::today(),
+                        RangePreset::this_week(),
+                        RangePreset::this_month(),
+                        RangePreset::last_month(),
+                    ])
+                    .onchange(move |range: Option<DateRange>| {
+                        let mut query = query.write();
+                        match range {
+                            Some(range) => {
+                                query.insert("created".to_string(), range.into());
+                            }
+                            None => {
+                                query.remove("created");
+                            }
+                        }
+                    })
+                    .style(|s| s.margin_bottom("16px")),
+            )
+            .children(
+                FilterBar::new()
+                    .value(query)
+                    .field(FilterField::input("order_no", "订单号"))
+                    .field(FilterField::date_range("created", "下单日期"))
+                    .onsearch(move |query| submitted.set(Some(query))),
+            )
+            .children(hint(format!("当前范围：{}", range_text(range))))
+            .children(hint(summary))
+            .to_element()
+    }
+}
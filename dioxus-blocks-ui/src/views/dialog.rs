@@ -0,0 +1,191 @@
+//! Dialog 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Card, Dialog, Input, PropsTable, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct DialogView {}
+
+impl ToElement for DialogView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl DialogView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Dialog 对话框"),
+            Text::p(
+                "在当前页面上方弹出对话框，用于确认操作或填写简短的表单，打开期间焦点限制在对话框内。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.form_dialog(),
+            self.persistent(),
+            api_reference(vec![PropsTable::of::<Dialog>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "打开状态由 Signal<bool> 控制，点击关闭按钮、遮罩或按 Escape 时关闭并调用 onclose。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 表单对话框
+    fn form_dialog(&self) -> Card {
+        Card::new()
+            .anchor("表单对话框")
+            .header(View::new().childrens(vec![
+                Text::h2("表单对话框"),
+                Text::p(
+                    "打开后焦点移到第一个输入框，Tab 键只在对话框内循环，关闭后焦点回到打开对话框的按钮。",
+                ),
+            ]))
+            .children(FormExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 必须明确操作
+    fn persistent(&self) -> Card {
+        Card::new()
+            .anchor("必须明确操作")
+            .header(View::new().childrens(vec![
+                Text::h2("必须明确操作"),
+                Text::p(
+                    "closable(false) 隐藏关闭按钮并忽略 Escape，close_on_backdrop(false) 忽略遮罩点击，只能通过底部按钮关闭。",
+                ),
+            ]))
+            .children(PersistentExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let mut open = use_signal(|| false);
+        let mut status = use_signal(|| "尚未操作".to_string());
+
+        View::new()
+            .children(
+                Button::new()
+                    .text("删除项目")
+                    .onclick(move |_| open.set(true)),
+            )
+            .children(
+                Dialog::new(open)
+                    .title("删除项目")
+                    .width("420px")
+                    .onclose(move |_| status.set("已取消".to_string()))
+                    .children(Text::p("删除后项目中的全部数据都无法恢复，确定要删除吗？"))
+                    .footer(Button::new().text("取消").onclick(move |_| {
+                        open.set(false);
+                        status.set("已取消".to_string());
+                    }))
+                    .footer(Button::new().text("删除").as_danger().onclick(move |_| {
+                        open.set(false);
+                        status.set("已删除".to_string());
+                    })),
+            )
+            .children(
+                Text::p(format!("最近操作：{}", status()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 表单对话框示例
+#[derive(Debug, Default, Clone)]
+struct FormExample {}
+
+impl ToElement for FormExample {
+    fn to_element(&self) -> Element {
+        let mut open = use_signal(|| false);
+        let name = use_signal(|| "张三".to_string());
+        let email = use_signal(|| "zhangsan@example.com".to_string());
+
+        View::new()
+            .children(
+                Button::new()
+                    .text("编辑资料")
+                    .as_primary()
+                    .onclick(move |_| open.set(true)),
+            )
+            .children(
+                Dialog::new(open)
+                    .title("编辑资料")
+                    .children(
+                        View::new()
+                            .style(|s| s.display("flex").flex_direction("column").gap("12px"))
+                            .children(Input::new().value(name).placeholder("姓名"))
+                            .children(Input::new().value(email).placeholder("邮箱")),
+                    )
+                    .footer(Button::new().text("取消").onclick(move |_| open.set(false)))
+                    .footer(
+                        Button::new()
+                            .text("保存")
+                            .as_primary()
+                            .onclick(move |_| open.set(false)),
+                    ),
+            )
+            .children(
+                Text::p(format!("{}，{}", name(), email()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 必须明确操作示例
+#[derive(Debug, Default, Clone)]
+struct PersistentExample {}
+
+impl ToElement for PersistentExample {
+    fn to_element(&self) -> Element {
+        let mut open = use_signal(|| false);
+
+        View::new()
+            .children(Button::new().text("查看服务条款").onclick(move |_| open.set(true)))
+            .children(
+                Dialog::new(open)
+                    .title("服务条款已更新")
+                    .closable(false)
+                    .close_on_backdrop(false)
+                    .children(Text::p(
+                        "我们更新了服务条款和隐私政策，请阅读后确认，继续使用即表示同意更新后的条款。",
+                    ))
+                    .footer(
+                        Button::new()
+                            .text("我已阅读并同意")
+                            .as_primary()
+                            .onclick(move |_| open.set(false)),
+                    ),
+            )
+            .into()
+    }
+}
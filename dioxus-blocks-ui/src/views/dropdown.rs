@@ -0,0 +1,257 @@
+//! Dropdown 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, ButtonType, Card, Dropdown, DropdownItem, DropdownPlacement, DropdownTrigger,
+    PropsTable, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct DropdownView {}
+
+impl ToElement for DropdownView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl DropdownView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Dropdown 下拉菜单"),
+            Text::p(
+                "为按钮、链接或任意元素附加一个下拉菜单，收纳不常用的操作。菜单打开时按 Escape 关闭，菜单项获得焦点后按 Enter 选择。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic(),
+            self.trigger(),
+            self.submenu(),
+            self.custom_trigger(),
+            api_reference(vec![
+                PropsTable::of::<Dropdown>().common(true),
+                PropsTable::of::<DropdownItem>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "默认在鼠标移入时显示菜单，选择菜单项时以该项的 key 调用 onselect 并关闭菜单。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 触发方式和位置
+    fn trigger(&self) -> Card {
+        Card::new()
+            .anchor("触发方式和位置")
+            .header(View::new().childrens(vec![
+                Text::h2("触发方式和位置"),
+                Text::p(
+                    "trigger 设置为 Click 时点击触发元素切换菜单，点击其他位置关闭。placement 设置菜单位于触发元素的上方或下方，以及左对齐、居中或右对齐。",
+                ),
+            ]))
+            .children(TriggerExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 分隔线、禁用和子菜单
+    fn submenu(&self) -> Card {
+        Card::new()
+            .anchor("分隔线、禁用和子菜单")
+            .header(View::new().childrens(vec![
+                Text::h2("分隔线、禁用和子菜单"),
+                Text::p(
+                    "DropdownItem::divider 创建分隔线，disabled 的菜单项不能选择。菜单项通过 item 添加子菜单项后成为子菜单，鼠标移入或按方向右键时展开。",
+                ),
+            ]))
+            .children(SubmenuExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义触发元素
+    fn custom_trigger(&self) -> Card {
+        Card::new()
+            .anchor("自定义触发元素")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义触发元素"),
+                Text::p(
+                    "任意组件都可以作为触发元素，如页面头部的用户名。hide_on_click(false) 时选择菜单项后保持菜单打开。",
+                ),
+            ]))
+            .children(CustomTriggerExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 显示最近一次选择的菜单项
+fn selected_text(selected: Signal<String>) -> Text {
+    let text = match selected.read().as_str() {
+        "" => "尚未选择".to_string(),
+        key => format!("选择了：{key}"),
+    };
+    Text::span(text).style(|s| {
+        s.display("block")
+            .margin_top("12px")
+            .color("var(--t-text-color-secondary)")
+    })
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let mut selected = use_signal(String::new);
+
+        View::new()
+            .children(
+                Dropdown::new()
+                    .item(DropdownItem::new("edit", "编辑"))
+                    .item(DropdownItem::new("copy", "复制"))
+                    .item(DropdownItem::new("archive", "归档"))
+                    .onselect(move |key| selected.set(key))
+                    .children(Button::new().text("更多操作 ▾")),
+            )
+            .children(selected_text(selected))
+            .into()
+    }
+}
+
+/// 触发方式和位置示例
+#[derive(Debug, Default, Clone)]
+struct TriggerExample {}
+
+impl ToElement for TriggerExample {
+    fn to_element(&self) -> Element {
+        let mut selected = use_signal(String::new);
+        let placements = [
+            (DropdownPlacement::BottomStart, "bottom-start"),
+            (DropdownPlacement::Bottom, "bottom"),
+            (DropdownPlacement::BottomEnd, "bottom-end"),
+            (DropdownPlacement::TopStart, "top-start"),
+            (DropdownPlacement::Top, "top"),
+            (DropdownPlacement::TopEnd, "top-end"),
+        ];
+
+        View::new()
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .flex_wrap("wrap")
+                            .gap("12px")
+                            .padding("48px 0")
+                    })
+                    .childrens(
+                        placements
+                            .into_iter()
+                            .map(|(placement, label)| {
+                                Dropdown::new()
+                                    .trigger(DropdownTrigger::Click)
+                                    .placement(placement)
+                                    .item(DropdownItem::new("new", "新建"))
+                                    .item(DropdownItem::new("open", "打开"))
+                                    .item(DropdownItem::new("save", "保存"))
+                                    .onselect(move |key| selected.set(format!("{label} / {key}")))
+                                    .children(Button::new().text(label))
+                            })
+                            .collect(),
+                    ),
+            )
+            .children(selected_text(selected))
+            .into()
+    }
+}
+
+/// 分隔线、禁用和子菜单示例
+#[derive(Debug, Default, Clone)]
+struct SubmenuExample {}
+
+impl ToElement for SubmenuExample {
+    fn to_element(&self) -> Element {
+        let mut selected = use_signal(String::new);
+
+        View::new()
+            .children(
+                Dropdown::new()
+                    .item(DropdownItem::new("rename", "重命名").icon("✎"))
+                    .item(DropdownItem::new("share", "共享").icon("⇪").disabled(true))
+                    .item(
+                        DropdownItem::new("export", "导出为")
+                            .icon("⤓")
+                            .item(DropdownItem::new("pdf", "PDF"))
+                            .item(DropdownItem::new("docx", "Word"))
+                            .item(
+                                DropdownItem::new("image", "图片")
+                                    .item(DropdownItem::new("png", "PNG"))
+                                    .item(DropdownItem::new("jpg", "JPG")),
+                            ),
+                    )
+                    .item(DropdownItem::divider())
+                    .item(
+                        DropdownItem::new("delete", "删除")
+                            .icon("✕")
+                            .style(|s| s.color("var(--t-color-danger)")),
+                    )
+                    .onselect(move |key| selected.set(key))
+                    .children(
+                        Button::new()
+                            .text("文件操作 ▾")
+                            .btn_type(ButtonType::Primary),
+                    ),
+            )
+            .children(selected_text(selected))
+            .into()
+    }
+}
+
+/// 自定义触发元素示例
+#[derive(Debug, Default, Clone)]
+struct CustomTriggerExample {}
+
+impl ToElement for CustomTriggerExample {
+    fn to_element(&self) -> Element {
+        let mut status = use_signal(|| "online".to_string());
+        let label = match status.read().as_str() {
+            "busy" => "忙碌",
+            "away" => "离开",
+            _ => "在线",
+        };
+
+        View::new()
+            .children(
+                Dropdown::new()
+                    .trigger(DropdownTrigger::Click)
+                    .hide_on_click(false)
+                    .item(DropdownItem::new("online", "在线"))
+                    .item(DropdownItem::new("busy", "忙碌"))
+                    .item(DropdownItem::new("away", "离开"))
+                    .onselect(move |key| status.set(key))
+                    .children(
+                        Text::span(format!("张三（{label}） ▾"))
+                            .style(|s| s.cursor("pointer").color("var(--t-color-primary)")),
+                    ),
+            )
+            .into()
+    }
+}
@@ -0,0 +1,59 @@
+//! 异常页面模板示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Link, ToElement};
+use dioxus_blocks_macro::Route;
+
+use crate::RouteRegistry;
+use crate::registry::RegisteredRoute;
+use crate::templates::{MaintenancePage, NotFoundPage, ServerErrorPage};
+
+/// 未匹配任何路由时优先从动态路由注册表中查找页面，仍未找到时显示 404 页面
+#[derive(Debug, Clone, Route)]
+#[route(props)]
+pub struct NotFoundView {
+    segments: Vec<String>,
+}
+
+impl ToElement for NotFoundView {
+    fn to_element(&self) -> Element {
+        let path = format!("/{}", self.segments.join("/"));
+        if let Some(registry) = RouteRegistry::current()
+            && let Some((entry, params)) = registry.resolve(&path)
+        {
+            let key = entry.path().to_string();
+            let entry = entry.clone();
+            return rsx! {
+                RegisteredRoute { key: "{key}", entry, params }
+            };
+        }
+
+        NotFoundPage::new().path(path).to_element()
+    }
+}
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct ServerErrorView {}
+
+impl ToElement for ServerErrorView {
+    fn to_element(&self) -> Element {
+        ServerErrorPage::new()
+            .detail("Error: upstream request timed out after 30s")
+            .action(Link::new("/").children(Button::new().text("返回首页").as_primary()))
+            .action(Link::new("/500").children(Button::new().text("重试")))
+            .to_element()
+    }
+}
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct MaintenanceView {}
+
+impl ToElement for MaintenanceView {
+    fn to_element(&self) -> Element {
+        MaintenancePage::new()
+            .message("系统正在升级数据库，预计 2 小时后恢复，给您带来不便敬请谅解。")
+            .countdown(2 * 60 * 60)
+            .to_element()
+    }
+}
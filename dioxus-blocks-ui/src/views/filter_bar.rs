@@ -0,0 +1,171 @@
+//! FilterBar 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    ActiveFilters, Card, FilterBar, FilterField, FilterQuery, FilterValue, PropsTable, Text,
+    ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct FilterBarView {}
+
+impl ToElement for FilterBarView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl FilterBarView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("FilterBar 筛选栏"),
+            Text::p("根据配置渲染一组筛选控件，汇总为查询条件，通常放在表格上方。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.active_filters(),
+            api_reference(vec![
+                PropsTable::of::<FilterBar>().common(true),
+                PropsTable::of::<FilterField>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "支持输入框、下拉选择、日期范围和数字范围四种控件，超过 collapse_after 个字段时可以展开和收起，查询条件只包含已填写的字段。",
+                ),
+            ]))
+            .children(OrderFilter::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 已选条件
+    fn active_filters(&self) -> Card {
+        Card::new()
+            .anchor("已选条件")
+            .header(View::new().childrens(vec![
+                Text::h2("已选条件"),
+                Text::p(
+                    "ActiveFilters 与 FilterBar 共用同一个查询条件 Signal，以标签显示已填写的条件，移除标签或清除全部时筛选栏同步清空。",
+                ),
+            ]))
+            .children(ProductFilter::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 商品筛选示例
+#[derive(Debug, Default, Clone)]
+struct ProductFilter {}
+
+impl ToElement for ProductFilter {
+    fn to_element(&self) -> Element {
+        let query = use_signal(|| {
+            let mut query = FilterQuery::new();
+            query.insert(
+                "category".to_string(),
+                FilterValue::Select("book".to_string()),
+            );
+            query.insert(
+                "price".to_string(),
+                FilterValue::NumberRange(Some(20.0), Some(100.0)),
+            );
+            query
+        });
+        let mut removed = use_signal(|| 0usize);
+        let fields = vec![
+            FilterField::input("keyword", "关键字"),
+            FilterField::select(
+                "category",
+                "分类",
+                [("book", "图书"), ("digital", "数码"), ("food", "食品")],
+            ),
+            FilterField::number_range("price", "价格"),
+        ];
+
+        View::new()
+            .children(FilterBar::new().fields(fields.clone()).value(query))
+            .children(
+                ActiveFilters::new()
+                    .fields(fields)
+                    .value(query)
+                    .onchange(move |_| removed += 1),
+            )
+            .children(
+                Text::p(format!("已通过标签移除 {} 次", removed()))
+                    .style(|s| s.color("var(--t-text-color-secondary)")),
+            )
+            .to_element()
+    }
+}
+
+/// 订单筛选示例
+#[derive(Debug, Default, Clone)]
+struct OrderFilter {}
+
+impl ToElement for OrderFilter {
+    fn to_element(&self) -> Element {
+        let mut submitted = use_signal(|| None::<FilterQuery>);
+
+        let summary = match &*submitted.read() {
+            None => "尚未查询".to_string(),
+            Some(query) if query.is_empty() => "查询条件：无".to_string(),
+            Some(query) => {
+                let parts = query
+                    .iter()
+                    .map(|(key, value)| match value {
+                        FilterValue::Text(v) | FilterValue::Select(v) => format!("{key}={v}"),
+                        FilterValue::DateRange(start, end) => format!(
+                            "{key}={}~{}",
+                            start.as_deref().unwrap_or(""),
+                            end.as_deref().unwrap_or("")
+                        ),
+                        FilterValue::NumberRange(min, max) => format!(
+                            "{key}={}~{}",
+                            min.map(|v| v.to_string()).unwrap_or_default(),
+                            max.map(|v| v.to_string()).unwrap_or_default()
+                        ),
+                    })
+                    .collect::<Vec<_>>();
+                format!("查询条件：{}", parts.join("，"))
+            }
+        };
+
+        View::new()
+            .children(
+                FilterBar::new()
+                    .field(FilterField::input("order_no", "订单号"))
+                    .field(FilterField::select(
+                        "status",
+                        "状态",
+                        [
+                            ("pending", "待支付"),
+                            ("paid", "已支付"),
+                            ("closed", "已关闭"),
+                        ],
+                    ))
+                    .field(FilterField::date_range("created", "下单日期"))
+                    .field(FilterField::number_range("amount", "金额").placeholder("最低"))
+                    .field(FilterField::input("customer", "客户"))
+                    .onsearch(move |query| submitted.set(Some(query))),
+            )
+            .children(Text::p(summary).style(|s| s.color("var(--t-text-color-secondary)")))
+            .to_element()
+    }
+}
@@ -0,0 +1,405 @@
+//! Form 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, Checkbox, CheckboxGroup, CheckboxValue, Form, FormErrorSummary, FormItem,
+    FormLabelPosition, FormValue, Input, InputNumber, InputNumberValue, InputType, PropsTable,
+    Radio, RadioGroup, RadioValue, Rule, Select, SelectOption, SelectValue, Text, ToElement, View,
+    use_form,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct FormView {}
+
+impl ToElement for FormView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl FormView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Form 表单"),
+            Text::p(
+                "由输入框、单选框、多选框等控件组成，统一标签布局并按规则校验用户输入，在每个字段下方显示错误信息。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.label_position(),
+            self.validation(),
+            self.error_summary(),
+            self.dependencies(),
+            api_reference(vec![
+                PropsTable::of::<Form>().common(true),
+                PropsTable::of::<FormItem>().common(true),
+            ]),
+        ])
+    }
+
+    /// 标签位置
+    fn label_position(&self) -> Card {
+        Card::new()
+            .anchor("标签位置")
+            .header(View::new().childrens(vec![
+                Text::h2("标签位置"),
+                Text::p(
+                    "label_position 设置标签在控件左侧右对齐、左对齐或在控件上方，label_width 设置标签的宽度。",
+                ),
+            ]))
+            .children(LabelPositionExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 表单校验
+    fn validation(&self) -> Card {
+        Card::new()
+            .anchor("表单校验")
+            .header(View::new().childrens(vec![
+                Text::h2("表单校验"),
+                Text::p(
+                    "FormItem 通过 value 绑定控件的 Signal，通过 rule 添加必填、长度、正则和自定义闭包规则。提交时校验全部字段，字段失去焦点后单独校验，之后输入时立即更新错误信息。",
+                ),
+            ]))
+            .children(ValidationExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 错误汇总
+    fn error_summary(&self) -> Card {
+        Card::new()
+            .anchor("错误汇总")
+            .header(View::new().childrens(vec![
+                Text::h2("错误汇总"),
+                Text::p(
+                    "FormState::errors 返回的错误可以交给 FormErrorSummary 在表单顶部汇总显示，点击错误聚焦对应的输入框。",
+                ),
+            ]))
+            .children(SummaryExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 字段联动
+    fn dependencies(&self) -> Card {
+        Card::new()
+            .anchor("字段联动")
+            .header(View::new().childrens(vec![
+                Text::h2("字段联动"),
+                Text::p(
+                    "visible_when 根据其他字段的值显示表单项，隐藏的表单项不参与校验；depends_on 在依赖的字段变化时重新校验，如修改密码后重新校验确认密码。",
+                ),
+            ]))
+            .children(DependencyExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 标签位置示例
+#[derive(Debug, Default, Clone)]
+struct LabelPositionExample {}
+
+impl ToElement for LabelPositionExample {
+    fn to_element(&self) -> Element {
+        let form = use_form();
+        let position = use_signal(|| RadioValue::from("right"));
+        let name = use_signal(String::new);
+        let city = use_signal(String::new);
+
+        let label_position = match position.read().get_string() {
+            Some("left") => FormLabelPosition::Left,
+            Some("top") => FormLabelPosition::Top,
+            _ => FormLabelPosition::Right,
+        };
+
+        View::new()
+            .children(RadioGroup::new().value(position).button(true).radios(vec![
+                Radio::new().label("左对齐").value("left"),
+                Radio::new().label("右对齐").value("right"),
+                Radio::new().label("顶部").value("top"),
+            ]))
+            .children(
+                Form::new(form)
+                    .label_position(label_position)
+                    .label_width("80px")
+                    .style(|s| s.margin_top("24px").max_width("460px"))
+                    .children(
+                        FormItem::new("position-name", "活动名称")
+                            .value(name)
+                            .children(Input::new().value(name).input_id("position-name")),
+                    )
+                    .children(
+                        FormItem::new("position-city", "举办城市")
+                            .value(city)
+                            .children(Input::new().value(city).input_id("position-city")),
+                    ),
+            )
+            .into()
+    }
+}
+
+/// 表单校验示例
+#[derive(Debug, Default, Clone)]
+struct ValidationExample {}
+
+impl ToElement for ValidationExample {
+    fn to_element(&self) -> Element {
+        let form = use_form();
+        let name = use_signal(String::new);
+        let email = use_signal(String::new);
+        let age = use_signal(|| InputNumberValue::Int(16));
+        let city = use_signal(|| None::<SelectValue>);
+        let gender = use_signal(|| RadioValue::from(""));
+        let hobbies = use_signal(Vec::<CheckboxValue>::new);
+        let agree = use_signal(|| false);
+        let mut status = use_signal(|| "尚未提交".to_string());
+
+        View::new()
+            .children(
+                Form::new(form)
+                    .style(|s| s.max_width("520px"))
+                    .onsubmit(move |_| status.set(format!("提交成功：{}，{}", name(), email())))
+                    .oninvalid(move |errors| {
+                        status.set(format!("有 {} 个字段未通过校验", errors.len()))
+                    })
+                    .children(
+                        FormItem::new("validation-name", "姓名")
+                            .value(name)
+                            .required(true)
+                            .rule(Rule::min_len(2))
+                            .rule(Rule::max_len(10))
+                            .children(
+                                Input::new()
+                                    .value(name)
+                                    .input_id("validation-name")
+                                    .placeholder("2 到 10 个字符"),
+                            ),
+                    )
+                    .children(
+                        FormItem::new("validation-email", "邮箱")
+                            .value(email)
+                            .required(true)
+                            .rule(
+                                Rule::pattern(r"^[^@\s]+@[^@\s]+\.[^@\s]+$")
+                                    .message("请输入有效的邮箱地址"),
+                            )
+                            .children(
+                                Input::new()
+                                    .value(email)
+                                    .input_id("validation-email")
+                                    .placeholder("name@example.com"),
+                            ),
+                    )
+                    .children(
+                        FormItem::new("validation-age", "年龄")
+                            .value(age)
+                            .rule(Rule::custom(|value| match value.as_text().parse::<i64>() {
+                                Ok(age) if age >= 18 => Ok(()),
+                                _ => Err("年满 18 周岁才能注册".to_string()),
+                            }))
+                            .children(InputNumber::new().value(age).input_id("validation-age")),
+                    )
+                    .children(
+                        FormItem::new("validation-city", "城市")
+                            .value(city)
+                            .required(true)
+                            .children(
+                                Select::new()
+                                    .value(city)
+                                    .input_id("validation-city")
+                                    .filterable(true)
+                                    .options(vec![
+                                        SelectOption::new("beijing").label("北京"),
+                                        SelectOption::new("shanghai").label("上海"),
+                                        SelectOption::new("guangzhou").label("广州"),
+                                    ]),
+                            ),
+                    )
+                    .children(
+                        FormItem::new("validation-gender", "性别")
+                            .value(gender)
+                            .required(true)
+                            .children(RadioGroup::new().value(gender).radios(vec![
+                                Radio::new().label("男").value("male"),
+                                Radio::new().label("女").value("female"),
+                            ])),
+                    )
+                    .children(
+                        FormItem::new("validation-hobbies", "兴趣")
+                            .value(hobbies)
+                            .required(true)
+                            .rule(Rule::max_len(2))
+                            .children(CheckboxGroup::new().value(hobbies).checkboxes(vec![
+                                Checkbox::new().label("阅读").value("reading"),
+                                Checkbox::new().label("旅行").value("travel"),
+                                Checkbox::new().label("音乐").value("music"),
+                            ])),
+                    )
+                    .children(
+                        FormItem::new("validation-agree", "")
+                            .value(agree)
+                            .rule(Rule::required().message("请阅读并同意用户协议"))
+                            .children(
+                                Checkbox::new()
+                                    .label("我已阅读并同意用户协议")
+                                    .checked(agree),
+                            ),
+                    )
+                    .children(
+                        FormItem::new("validation-submit", "")
+                            .children(Button::new().text("提交").as_primary()),
+                    ),
+            )
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .align_items("center")
+                            .gap("12px")
+                            .margin_top("16px")
+                    })
+                    .children(
+                        Button::new()
+                            .text("清除校验")
+                            .onclick(move |_| form.clear_validate()),
+                    )
+                    .children(
+                        Text::span(status()).style(|s| s.color("var(--t-text-color-secondary)")),
+                    ),
+            )
+            .into()
+    }
+}
+
+/// 错误汇总示例
+#[derive(Debug, Default, Clone)]
+struct SummaryExample {}
+
+impl ToElement for SummaryExample {
+    fn to_element(&self) -> Element {
+        let form = use_form();
+        let username = use_signal(String::new);
+        let password = use_signal(String::new);
+
+        Form::new(form)
+            .label_position(FormLabelPosition::Top)
+            .style(|s| s.max_width("420px"))
+            .children(FormErrorSummary::new().errors(form.errors()))
+            .children(
+                FormItem::new("summary-username", "用户名")
+                    .value(username)
+                    .required(true)
+                    .rule(
+                        Rule::pattern("^[a-zA-Z0-9_]+$")
+                            .message("用户名只能包含字母、数字和下划线"),
+                    )
+                    .children(Input::new().value(username).input_id("summary-username")),
+            )
+            .children(
+                FormItem::new("summary-password", "密码")
+                    .value(password)
+                    .required(true)
+                    .rule(Rule::min_len(8))
+                    .children(
+                        Input::new()
+                            .value(password)
+                            .input_id("summary-password")
+                            .input_type(InputType::Password),
+                    ),
+            )
+            .children(Button::new().text("注册").as_primary())
+            .into()
+    }
+}
+
+/// 字段联动示例
+#[derive(Debug, Default, Clone)]
+struct DependencyExample {}
+
+impl ToElement for DependencyExample {
+    fn to_element(&self) -> Element {
+        let form = use_form();
+        let source = use_signal(|| RadioValue::from("search"));
+        let other = use_signal(String::new);
+        let password = use_signal(String::new);
+        let confirm = use_signal(String::new);
+        let mut status = use_signal(|| "尚未提交".to_string());
+
+        Form::new(form)
+            .style(|s| s.max_width("520px"))
+            .onsubmit(move |_| status.set("提交成功".to_string()))
+            .oninvalid(move |errors| status.set(format!("有 {} 个字段未通过校验", errors.len())))
+            .children(
+                FormItem::new("dependency-source", "了解渠道")
+                    .value(source)
+                    .children(RadioGroup::new().value(source).radios(vec![
+                        Radio::new().label("搜索引擎").value("search"),
+                        Radio::new().label("朋友推荐").value("friend"),
+                        Radio::new().label("其他").value("other"),
+                    ])),
+            )
+            .children(
+                FormItem::new("dependency-other", "请说明")
+                    .value(other)
+                    .required(true)
+                    .visible_when(|form| {
+                        form.value("dependency-source") == Some(FormValue::Text("other".into()))
+                    })
+                    .children(
+                        Input::new()
+                            .value(other)
+                            .input_id("dependency-other")
+                            .placeholder("选择其他时填写"),
+                    ),
+            )
+            .children(
+                FormItem::new("dependency-password", "密码")
+                    .value(password)
+                    .required(true)
+                    .rule(Rule::min_len(6))
+                    .children(
+                        Input::new()
+                            .value(password)
+                            .input_id("dependency-password")
+                            .input_type(InputType::Password),
+                    ),
+            )
+            .children(
+                FormItem::new("dependency-confirm", "确认密码")
+                    .value(confirm)
+                    .required(true)
+                    .depends_on(["dependency-password"])
+                    .rule(Rule::custom(move |value| {
+                        match value.as_text() == password() {
+                            true => Ok(()),
+                            false => Err("两次输入的密码不一致".to_string()),
+                        }
+                    }))
+                    .children(
+                        Input::new()
+                            .value(confirm)
+                            .input_id("dependency-confirm")
+                            .input_type(InputType::Password),
+                    ),
+            )
+            .children(
+                FormItem::new("dependency-submit", "")
+                    .children(Button::new().text("提交").as_primary())
+                    .children(
+                        Text::span(status()).style(|s| {
+                            s.margin_left("12px").color("var(--t-text-color-secondary)")
+                        }),
+                    ),
+            )
+            .into()
+    }
+}
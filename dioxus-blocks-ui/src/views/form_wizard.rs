@@ -0,0 +1,226 @@
+//! FormWizard 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, FormWizard, FormWizardPayload, FormWizardStep, Input, PropsTable, Text, Textarea,
+    ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct FormWizardView {}
+
+impl ToElement for FormWizardView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl FormWizardView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("FormWizard 分步表单"),
+            Text::p("将较长的表单拆分为多个步骤，逐步填写并校验，最后汇总提交。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.controlled(),
+            api_reference(vec![
+                PropsTable::of::<FormWizard>().common(true),
+                PropsTable::of::<FormWizardStep>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "每个步骤通过 field 注册字段，通过 validate 设置校验，校验通过后才能进入下一步，完成时 onfinish 返回所有字段的汇总数据。",
+                ),
+            ]))
+            .children(RegisterExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 受控步骤
+    fn controlled(&self) -> Card {
+        Card::new()
+            .anchor("受控步骤")
+            .header(View::new().childrens(vec![
+                Text::h2("受控步骤"),
+                Text::p(
+                    "通过 current 传入 Signal 控制当前步骤，通过 onstep_change 监听步骤切换，并可自定义按钮文本。",
+                ),
+            ]))
+            .children(ControlledExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 表单行
+fn form_row(label: &str, field: impl ToElement + Clone + 'static) -> View {
+    View::new()
+        .style(|s| {
+            s.display("flex")
+                .align_items("center")
+                .gap("12px")
+                .margin_bottom("16px")
+        })
+        .children(Text::new(label.to_string()).style(|s| s.width("80px")))
+        .children(View::new().style(|s| s.flex("1")).children(field))
+}
+
+/// 注册示例
+#[derive(Debug, Default, Clone)]
+struct RegisterExample {}
+
+impl ToElement for RegisterExample {
+    fn to_element(&self) -> Element {
+        let mut username = use_signal(String::new);
+        let mut password = use_signal(String::new);
+        let mut email = use_signal(String::new);
+        let mut bio = use_signal(String::new);
+        let mut submitted = use_signal(|| None::<FormWizardPayload>);
+
+        let account = FormWizardStep::new("账号信息")
+            .description("设置用户名和密码")
+            .field("username", username)
+            .field("password", password)
+            .content(
+                View::new()
+                    .children(form_row(
+                        "用户名",
+                        Input::new()
+                            .value(username)
+                            .placeholder("请输入用户名")
+                            .oninput(move |v| username.set(v)),
+                    ))
+                    .children(form_row(
+                        "密码",
+                        Input::new()
+                            .value(password)
+                            .as_password()
+                            .placeholder("至少 6 位")
+                            .oninput(move |v| password.set(v)),
+                    )),
+            )
+            .validate(|values| {
+                if values.get("username").is_none_or(|v| v.trim().is_empty()) {
+                    return Err("请输入用户名".to_string());
+                }
+                if values.get("password").is_none_or(|v| v.chars().count() < 6) {
+                    return Err("密码长度不能少于 6 位".to_string());
+                }
+                Ok(())
+            });
+
+        let profile = FormWizardStep::new("个人资料")
+            .description("填写联系方式和简介")
+            .field("email", email)
+            .field("bio", bio)
+            .content(
+                View::new()
+                    .children(form_row(
+                        "邮箱",
+                        Input::new()
+                            .value(email)
+                            .placeholder("name@example.com")
+                            .oninput(move |v| email.set(v)),
+                    ))
+                    .children(form_row(
+                        "简介",
+                        Textarea::new()
+                            .value(bio)
+                            .rows(3)
+                            .placeholder("介绍一下自己（选填）")
+                            .oninput(move |v| bio.set(v)),
+                    )),
+            )
+            .validate(|values| match values.get("email") {
+                Some(v) if v.contains('@') => Ok(()),
+                _ => Err("请输入有效的邮箱地址".to_string()),
+            });
+
+        let confirm = FormWizardStep::new("确认提交")
+            .description("核对填写的信息")
+            .content(View::new().childrens(vec![
+                Text::p(format!("用户名：{}", username())),
+                Text::p(format!("邮箱：{}", email())),
+                Text::p(format!(
+                    "简介：{}",
+                    if bio().is_empty() {
+                        "未填写".to_string()
+                    } else {
+                        bio()
+                    }
+                )),
+            ]));
+
+        let result = match submitted() {
+            Some(payload) => format!("提交数据：{:?}", payload),
+            None => "尚未提交".to_string(),
+        };
+
+        View::new()
+            .children(
+                FormWizard::new()
+                    .step(account)
+                    .step(profile)
+                    .step(confirm)
+                    .onfinish(move |payload| submitted.set(Some(payload))),
+            )
+            .children(
+                Text::p(result)
+                    .style(|s| s.margin_top("16px").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 受控示例
+#[derive(Debug, Default, Clone)]
+struct ControlledExample {}
+
+impl ToElement for ControlledExample {
+    fn to_element(&self) -> Element {
+        let current = use_signal(|| 0usize);
+        let mut changes = use_signal(|| 0usize);
+
+        View::new()
+            .children(
+                FormWizard::new()
+                    .current(current)
+                    .step(FormWizardStep::new("选择套餐").content(Text::p("请选择适合您的套餐。")))
+                    .step(FormWizardStep::new("支付").content(Text::p("请确认订单并完成支付。")))
+                    .step(
+                        FormWizardStep::new("完成").content(Text::p("订单已创建，感谢您的购买！")),
+                    )
+                    .prev_text("返回")
+                    .next_text("继续")
+                    .finish_text("关闭")
+                    .onstep_change(move |_| changes += 1),
+            )
+            .children(
+                Text::p(format!(
+                    "当前步骤：{}，已切换 {} 次",
+                    current() + 1,
+                    changes()
+                ))
+                .style(|s| s.margin_top("16px").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
@@ -1,9 +1,13 @@
 //! Grid 组件
 
 use dioxus::prelude::*;
-use dioxus_blocks_components::{Card, Grid, GridCols, GridItem, GridRows, Text, ToElement, View};
+use dioxus_blocks_components::{
+    Card, Grid, GridCols, GridItem, GridRows, PropsTable, Text, ToElement, View,
+};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 struct GridView {}
 
@@ -30,6 +34,10 @@ impl GridView {
             self.rows_section(),
             self.col_span_section(),
             self.row_span_section(),
+            api_reference(vec![
+                PropsTable::of::<Grid>().common(true),
+                PropsTable::of::<GridItem>(),
+            ]),
         ])
     }
 
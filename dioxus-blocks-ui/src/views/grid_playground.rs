@@ -0,0 +1,302 @@
+//! Grid 演练场
+//!
+//! 通过 InputNumber 调整列数、间距和网格项的跨列、跨行，实时预览 Grid 并生成对应的构建代码。
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::{
+    Card, Grid, GridCols, GridItem, InputNumber, InputNumberValue, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+/// 最多的网格项数量
+const MAX_ITEMS: usize = 12;
+
+/// 预览使用的配色，按网格项的序号循环使用
+const COLORS: [&str; 4] = [
+    "var(--t-color-primary-light-8)",
+    "var(--t-color-success-light-8)",
+    "var(--t-color-warning-light-8, #faecd8)",
+    "var(--t-color-info-light-8)",
+];
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct GridPlaygroundView {}
+
+impl ToElement for GridPlaygroundView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl GridPlaygroundView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Grid 演练场"),
+            Text::p("调整列数、间距和网格项的跨度，实时预览网格布局并生成对应的代码。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().children(
+            Card::new()
+                .anchor("交互式配置")
+                .header(View::new().childrens(vec![
+                    Text::h2("交互式配置"),
+                    Text::p(
+                        "选择网格项后可以单独设置它的跨列和跨行数量，跨列数超过列数时按列数计算。",
+                    ),
+                ]))
+                .children(GridPlayground::default())
+                .style(|s| s.margin_top("32px")),
+        )
+    }
+}
+
+/// 演练场的配置
+#[derive(Debug, Clone, PartialEq)]
+struct PlaygroundConfig {
+    /// 列数
+    cols: usize,
+    /// 水平间距
+    gap_x: usize,
+    /// 垂直间距
+    gap_y: usize,
+    /// 每个网格项的 (跨列, 跨行)，长度为网格项数量
+    spans: Vec<(usize, usize)>,
+}
+
+impl PlaygroundConfig {
+    /// 生成构建网格的代码
+    fn code(&self) -> String {
+        let mut code = String::from("Grid::new(vec![\n");
+        for (i, (col_span, row_span)) in self.spans.iter().enumerate() {
+            code.push_str(&format!("    GridItem::new(Text::new(\"{}\"))", i + 1));
+            if *col_span > 1 {
+                code.push_str(&format!(".col_span({col_span})"));
+            }
+            if *row_span > 1 {
+                code.push_str(&format!(".row_span({row_span})"));
+            }
+            code.push_str(",\n");
+        }
+        code.push_str(&format!("])\n.cols(GridCols::Col{})\n", self.cols));
+        if self.gap_x == self.gap_y {
+            code.push_str(&format!(".gap({})", self.gap_x));
+        } else {
+            code.push_str(&format!(".gap_xy({}, {})", self.gap_x, self.gap_y));
+        }
+        code
+    }
+
+    /// 构建预览的网格
+    fn grid(&self) -> Grid {
+        let items = self
+            .spans
+            .iter()
+            .enumerate()
+            .map(|(i, (col_span, row_span))| {
+                let color = COLORS[i % COLORS.len()];
+                GridItem::new(Text::new(format!("{}", i + 1)))
+                    .col_span((*col_span).min(self.cols))
+                    .row_span(*row_span)
+                    .style(|s| {
+                        s.display("flex")
+                            .align_items("center")
+                            .custom("justify-content: center")
+                            .min_height("48px")
+                            .background_color(color)
+                            .border_radius("6px")
+                            .color("var(--t-text-color-primary)")
+                            .font_weight("500")
+                    })
+            })
+            .collect();
+        let grid = Grid::new(items).cols(grid_cols(self.cols));
+        if self.gap_x == self.gap_y {
+            grid.gap(self.gap_x)
+        } else {
+            grid.gap_xy(self.gap_x, self.gap_y)
+        }
+    }
+}
+
+/// 列数对应的 GridCols，超出范围时取最接近的值
+fn grid_cols(cols: usize) -> GridCols {
+    match cols {
+        0 | 1 => GridCols::Col1,
+        2 => GridCols::Col2,
+        3 => GridCols::Col3,
+        4 => GridCols::Col4,
+        5 => GridCols::Col5,
+        6 => GridCols::Col6,
+        7 => GridCols::Col7,
+        8 => GridCols::Col8,
+        9 => GridCols::Col9,
+        10 => GridCols::Col10,
+        11 => GridCols::Col11,
+        _ => GridCols::Col12,
+    }
+}
+
+/// 读取 InputNumber 的整数值，限制在指定范围内
+fn int_value(value: &InputNumberValue, min: usize, max: usize) -> usize {
+    value
+        .get_int()
+        .map_or(min, |v| v.clamp(min as i64, max as i64) as usize)
+}
+
+/// 带标签的控件
+fn field(label: &str, control: impl ToElement + Clone + 'static) -> View {
+    View::new()
+        .style(|s| {
+            s.display("flex")
+                .flex_direction("column")
+                .gap("6px")
+                .min_width("140px")
+        })
+        .children(
+            Text::span(label).style(|s| s.font_size("13px").color("var(--t-text-color-secondary)")),
+        )
+        .children(control)
+}
+
+/// 交互式配置
+#[derive(Debug, Default, Clone)]
+struct GridPlayground {}
+
+impl ToElement for GridPlayground {
+    fn to_element(&self) -> Element {
+        let mut cols = use_signal(|| InputNumberValue::Int(4));
+        let mut gap_x = use_signal(|| InputNumberValue::Int(12));
+        let mut gap_y = use_signal(|| InputNumberValue::Int(12));
+        let mut count = use_signal(|| InputNumberValue::Int(8));
+        let mut spans = use_signal(|| {
+            let mut spans = vec![(1usize, 1usize); MAX_ITEMS];
+            spans[0] = (2, 2);
+            spans
+        });
+        let mut selected = use_signal(|| InputNumberValue::Int(1));
+        let mut col_span = use_signal(|| InputNumberValue::Int(2));
+        let mut row_span = use_signal(|| InputNumberValue::Int(2));
+
+        let item_count = int_value(&count.read(), 1, MAX_ITEMS);
+        let config = PlaygroundConfig {
+            cols: int_value(&cols.read(), 1, 12),
+            gap_x: int_value(&gap_x.read(), 0, 48),
+            gap_y: int_value(&gap_y.read(), 0, 48),
+            spans: spans.read()[..item_count].to_vec(),
+        };
+
+        // 当前编辑的网格项下标
+        let index = move || int_value(&selected.peek(), 1, MAX_ITEMS) - 1;
+
+        let controls = View::new()
+            .style(|s| s.display("flex").flex_wrap("wrap").gap("16px"))
+            .children(field(
+                "列数",
+                InputNumber::new()
+                    .value(cols)
+                    .min_int(1)
+                    .max_int(12)
+                    .onchange(move |v| cols.set(v)),
+            ))
+            .children(field(
+                "网格项数量",
+                InputNumber::new()
+                    .value(count)
+                    .min_int(1)
+                    .max_int(MAX_ITEMS as i64)
+                    .onchange(move |v| count.set(v)),
+            ))
+            .children(field(
+                "水平间距 (px)",
+                InputNumber::new()
+                    .value(gap_x)
+                    .min_int(0)
+                    .max_int(48)
+                    .step_int(4)
+                    .onchange(move |v| gap_x.set(v)),
+            ))
+            .children(field(
+                "垂直间距 (px)",
+                InputNumber::new()
+                    .value(gap_y)
+                    .min_int(0)
+                    .max_int(48)
+                    .step_int(4)
+                    .onchange(move |v| gap_y.set(v)),
+            ))
+            .children(field(
+                "编辑第几项",
+                InputNumber::new()
+                    .value(selected)
+                    .min_int(1)
+                    .max_int(item_count as i64)
+                    .onchange(move |v| {
+                        // 切换网格项时，跨度输入框显示该项当前的值
+                        selected.set(v);
+                        let (col, row) = spans.peek()[index()];
+                        col_span.set(InputNumberValue::Int(col as i64));
+                        row_span.set(InputNumberValue::Int(row as i64));
+                    }),
+            ))
+            .children(field(
+                "跨列",
+                InputNumber::new()
+                    .value(col_span)
+                    .min_int(1)
+                    .max_int(12)
+                    .onchange(move |v| {
+                        col_span.set(v);
+                        spans.write()[index()].0 = int_value(&col_span.peek(), 1, 12);
+                    }),
+            ))
+            .children(field(
+                "跨行",
+                InputNumber::new()
+                    .value(row_span)
+                    .min_int(1)
+                    .max_int(12)
+                    .onchange(move |v| {
+                        row_span.set(v);
+                        spans.write()[index()].1 = int_value(&row_span.peek(), 1, 12);
+                    }),
+            ));
+
+        let preview = View::new()
+            .style(|s| {
+                s.padding("16px")
+                    .border("1px dashed var(--t-border-color)")
+                    .border_radius("8px")
+            })
+            .children(config.grid());
+
+        let code = Text::p(config.code()).style(|s| {
+            s.margin("0")
+                .padding("16px")
+                .font_family("monospace")
+                .font_size("13px")
+                .line_height("20px")
+                .white_space("pre")
+                .overflow("auto")
+                .color("var(--t-text-color-primary)")
+                .background_color("var(--t-fill-color-light)")
+                .border_radius("8px")
+        });
+
+        View::new()
+            .style(|s| {
+                s.padding("20px")
+                    .display("flex")
+                    .flex_direction("column")
+                    .gap("20px")
+            })
+            .children(controls)
+            .children(preview)
+            .children(code)
+            .into()
+    }
+}
@@ -82,6 +82,190 @@ impl HomeView {
                 Text::new("Checkbox"),
                 crate::Route::CheckboxViewRoute {},
             ),
+            (
+                "📅",
+                Text::new("CalendarHeatmap"),
+                crate::Route::CalendarHeatmapViewRoute {},
+            ),
+            (
+                "🌳",
+                Text::new("OrgChart"),
+                crate::Route::OrgChartViewRoute {},
+            ),
+            (
+                "🧾",
+                Text::new("JsonViewer"),
+                crate::Route::JsonViewerViewRoute {},
+            ),
+            (
+                "🖥",
+                Text::new("LogViewer"),
+                crate::Route::LogViewerViewRoute {},
+            ),
+            ("📋", Text::new("Table"), crate::Route::TableViewRoute {}),
+            (
+                "🗂️",
+                Text::new("SelectionBar"),
+                crate::Route::SelectionBarViewRoute {},
+            ),
+            (
+                "🗃️",
+                Text::new("CrudPage"),
+                crate::Route::CrudPageViewRoute {},
+            ),
+            (
+                "🔌",
+                Text::new("DataSource"),
+                crate::Route::DataSourceViewRoute {},
+            ),
+            (
+                "🔔",
+                Text::new("Notification"),
+                crate::Route::NotificationViewRoute {},
+            ),
+            (
+                "✉️",
+                Text::new("Message"),
+                crate::Route::MessageViewRoute {},
+            ),
+            (
+                "⏳",
+                Text::new("IdleGuard"),
+                crate::Route::IdleGuardViewRoute {},
+            ),
+            ("🪟", Text::new("Dialog"), crate::Route::DialogViewRoute {}),
+            (
+                "🦴",
+                Text::new("Skeleton"),
+                crate::Route::SkeletonViewRoute {},
+            ),
+            ("🌀", Text::new("Spin"), crate::Route::SpinViewRoute {}),
+            (
+                "🏷️",
+                Text::new("TitleProvider"),
+                crate::Route::TitleProviderViewRoute {},
+            ),
+            ("⌨️", Text::new("Kbd"), crate::Route::KbdViewRoute {}),
+            (
+                "💬",
+                Text::new("Tooltip"),
+                crate::Route::TooltipViewRoute {},
+            ),
+            ("🗂️", Text::new("Tabs"), crate::Route::TabsViewRoute {}),
+            (
+                "📑",
+                Text::new("Dropdown"),
+                crate::Route::DropdownViewRoute {},
+            ),
+            (
+                "📄",
+                Text::new("Pagination"),
+                crate::Route::PaginationViewRoute {},
+            ),
+            (
+                "❓",
+                Text::new("Popconfirm"),
+                crate::Route::PopconfirmViewRoute {},
+            ),
+            ("🔽", Text::new("Select"), crate::Route::SelectViewRoute {}),
+            (
+                "⏰",
+                Text::new("TimePicker"),
+                crate::Route::TimePickerViewRoute {},
+            ),
+            (
+                "🗓️",
+                Text::new("DatePicker"),
+                crate::Route::DatePickerViewRoute {},
+            ),
+            ("🧩", Text::new("Form"), crate::Route::FormViewRoute {}),
+            (
+                "📋",
+                Text::new("SchemaForm"),
+                crate::Route::SchemaFormViewRoute {},
+            ),
+            ("📤", Text::new("Upload"), crate::Route::UploadViewRoute {}),
+            (
+                "🪜",
+                Text::new("FormWizard"),
+                crate::Route::FormWizardViewRoute {},
+            ),
+            ("🔐", Text::new("Login"), crate::Route::LoginViewRoute {}),
+            (
+                "📋",
+                Text::new("Register"),
+                crate::Route::RegisterViewRoute {},
+            ),
+            (
+                "📊",
+                Text::new("Dashboard"),
+                crate::Route::DashboardViewRoute {},
+            ),
+            (
+                "⚙️",
+                Text::new("Settings"),
+                crate::Route::SettingsViewRoute {},
+            ),
+            (
+                "💳",
+                Text::new("CreditCardInput"),
+                crate::Route::CreditCardInputViewRoute {},
+            ),
+            (
+                "📱",
+                Text::new("PhoneInput"),
+                crate::Route::PhoneInputViewRoute {},
+            ),
+            (
+                "🏷️",
+                Text::new("BarcodeInput"),
+                crate::Route::BarcodeInputViewRoute {},
+            ),
+            (
+                "📍",
+                Text::new("AddressPicker"),
+                crate::Route::AddressPickerViewRoute {},
+            ),
+            (
+                "🔍",
+                Text::new("SearchInput"),
+                crate::Route::SearchInputViewRoute {},
+            ),
+            (
+                "🧰",
+                Text::new("FilterBar"),
+                crate::Route::FilterBarViewRoute {},
+            ),
+            (
+                "🧱",
+                Text::new("Toolbar"),
+                crate::Route::ToolbarViewRoute {},
+            ),
+            (
+                "🔐",
+                Text::new("Permission 权限控制"),
+                crate::Route::PermissionViewRoute {},
+            ),
+            (
+                "🧱",
+                Text::new("Masonry 瀑布流"),
+                crate::Route::MasonryViewRoute {},
+            ),
+            (
+                "🎛️",
+                Text::new("Grid 演练场"),
+                crate::Route::GridPlaygroundViewRoute {},
+            ),
+            (
+                "🎨",
+                Text::new("主题编辑器"),
+                crate::Route::ThemeEditorViewRoute {},
+            ),
+            (
+                "📚",
+                Text::new("组件用例"),
+                crate::Route::StoriesViewRoute {},
+            ),
         ];
 
         Grid::new(
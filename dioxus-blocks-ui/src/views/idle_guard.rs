@@ -0,0 +1,106 @@
+//! IdleGuard 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Card, IdleGuard, PropsTable, Text, ToElement, View, use_idle};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct IdleGuardView {}
+
+impl ToElement for IdleGuardView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl IdleGuardView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("IdleGuard 空闲超时"),
+            Text::p(
+                "用户长时间没有操作时弹出“还在吗？”对话框并倒计时，倒计时结束后自动退出登录，适用于后台管理系统。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.idle_hook(),
+            api_reference(vec![PropsTable::of::<IdleGuard>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "为了便于演示，本页设置为 10 秒没有操作即弹出对话框，倒计时 10 秒。对话框打开后移动鼠标不会关闭对话框，需要明确点击继续使用。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 空闲状态
+    fn idle_hook(&self) -> Card {
+        Card::new()
+            .anchor("空闲状态")
+            .header(View::new().childrens(vec![
+                Text::h2("空闲状态"),
+                Text::p(
+                    "只需要判断用户是否空闲时使用 use_idle，例如空闲时暂停轮询或视频播放。下面的状态在 5 秒没有操作后变为空闲。",
+                ),
+            ]))
+            .children(IdleHookExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let mut status = use_signal(|| "已登录".to_string());
+
+        IdleGuard::new()
+            .timeout(10_000)
+            .countdown(10)
+            .onlogout(move |_| status.set("已自动退出登录".to_string()))
+            .oncontinue(move |_| status.set("已继续使用".to_string()))
+            .children(
+                Text::p(format!("登录状态：{}", status()))
+                    .style(|s| s.color("var(--t-text-color-secondary)")),
+            )
+            .to_element()
+    }
+}
+
+/// 空闲状态示例
+#[derive(Debug, Default, Clone)]
+struct IdleHookExample {}
+
+impl ToElement for IdleHookExample {
+    fn to_element(&self) -> Element {
+        let idle = use_idle(5000);
+
+        Text::p(if idle() {
+            "当前状态：空闲"
+        } else {
+            "当前状态：活跃"
+        })
+        .style(|s| s.color("var(--t-text-color-secondary)"))
+        .to_element()
+    }
+}
@@ -1,7 +1,9 @@
 use dioxus::prelude::*;
-use dioxus_blocks_components::{Card, Image, ObjectFit, Style, Text, ToElement, View};
+use dioxus_blocks_components::{Card, Image, ObjectFit, PropsTable, Style, Text, ToElement, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 struct ImageView {}
 
@@ -10,6 +12,7 @@ impl ToElement for ImageView {
         View::new()
             .children(self.title())
             .children(self.content())
+            .children(api_reference(vec![PropsTable::of::<Image>().common(true)]))
             .to_element()
     }
 }
@@ -90,6 +93,7 @@ impl ImageView {
             .style(text_img_style);
 
         Card::new()
+            .anchor("基础用法")
             .header(View::new().children(Text::h2("基础用法")).children(Text::p(
                 "可通过fit确定图片如何适应到容器框，同原生 object-fit 。",
             )))
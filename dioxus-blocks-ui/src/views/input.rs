@@ -2,9 +2,11 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Input, Text, ToElement, View};
+use dioxus_blocks_components::{Card, Input, InputMask, PropsTable, Text, ToElement, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct InputView {}
 
@@ -33,15 +35,18 @@ impl InputView {
             self.password(),
             self.size_control(),
             self.length_limit(),
+            self.masking(),
             self.prefix_suffix(),
             self.prepend_append(),
             self.events_example(),
+            api_reference(vec![PropsTable::of::<Input>().common(true)]),
         ])
     }
 
     /// 基础用法
     fn basic_usage(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
                 Text::p("基本的文本输入框，可使用 v-model 双向绑定。"),
@@ -53,6 +58,7 @@ impl InputView {
     /// 禁用状态
     fn disabled_state(&self) -> Card {
         Card::new()
+            .anchor("禁用状态")
             .header(View::new().childrens(vec![
                 Text::h2("禁用状态"),
                 Text::p("禁用状态的输入框，不可编辑。"),
@@ -64,6 +70,7 @@ impl InputView {
     /// 一键清空
     fn clearable(&self) -> Card {
         Card::new()
+            .anchor("一键清空")
             .header(View::new().childrens(vec![
                 Text::h2("一键清空"),
                 Text::p("通过 clearable 属性设置可清空的输入框。"),
@@ -75,6 +82,7 @@ impl InputView {
     /// 密码框
     fn password(&self) -> Card {
         Card::new()
+            .anchor("密码框")
             .header(View::new().childrens(vec![
                 Text::h2("密码框"),
                 Text::p("通过设置 input_type 为 Password 来创建密码输入框。"),
@@ -86,6 +94,7 @@ impl InputView {
     /// 不同尺寸
     fn size_control(&self) -> Card {
         Card::new()
+            .anchor("不同尺寸")
             .header(View::new().childrens(vec![
                 Text::h2("不同尺寸"),
                 Text::p("提供小、中、大三种尺寸的输入框。"),
@@ -97,6 +106,7 @@ impl InputView {
     /// 输入长度限制
     fn length_limit(&self) -> Card {
         Card::new()
+            .anchor("输入长度限制")
             .header(View::new().childrens(vec![
                 Text::h2("输入长度限制"),
                 Text::p("通过 max_length 和 show_word_limit 设置输入长度限制和字数统计。"),
@@ -105,9 +115,22 @@ impl InputView {
             .style(|s| s.margin_top("32px"))
     }
 
+    /// 输入掩码
+    fn masking(&self) -> Card {
+        Card::new()
+            .anchor("输入掩码")
+            .header(View::new().childrens(vec![
+                Text::h2("输入掩码"),
+                Text::p("通过 mask 按格式约束输入内容并自动补全固定字符，9 表示数字，a 表示字母，* 表示任意字符。"),
+            ]))
+            .children(Masking::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
     /// 前置和后置图标
     fn prefix_suffix(&self) -> Card {
         Card::new()
+            .anchor("前置和后置图标")
             .header(View::new().childrens(vec![
                 Text::h2("前置和后置图标"),
                 Text::p("通过 prefix_icon 和 suffix_icon 设置前置和后置图标。"),
@@ -119,6 +142,7 @@ impl InputView {
     /// 前置和后置元素
     fn prepend_append(&self) -> Card {
         Card::new()
+            .anchor("前置和后置元素")
             .header(View::new().childrens(vec![
                 Text::h2("前置和后置元素"),
                 Text::p("通过 prepend 和 append 设置前置和后置元素。"),
@@ -130,6 +154,7 @@ impl InputView {
     /// 事件示例
     fn events_example(&self) -> Card {
         Card::new()
+            .anchor("事件示例")
             .header(View::new().childrens(vec![
                 Text::h2("事件示例"),
                 Text::p("演示各种事件的触发时机。"),
@@ -407,6 +432,55 @@ impl ToElement for LengthLimit {
     }
 }
 
+/// 输入掩码示例
+#[derive(Debug, Default, Clone)]
+pub struct Masking {}
+
+impl ToElement for Masking {
+    fn to_element(&self) -> Element {
+        let mut phone = use_signal(String::new);
+        let mut date = use_signal(String::new);
+        let mut card = use_signal(String::new);
+
+        let field = |label: &str, input: Input| {
+            View::new()
+                .style(|s| s.display("flex").align_items("center").gap("12px"))
+                .children(Text::new(label.to_string()).style(|s| s.width("80px")))
+                .children(input.style(|s| s.width("240px")))
+        };
+
+        View::new()
+            .style(|s| {
+                s.padding("20px")
+                    .display("flex")
+                    .flex_direction("column")
+                    .gap("16px")
+            })
+            .children(field(
+                "手机号码:",
+                Input::new()
+                    .value(phone)
+                    .mask(InputMask::phone())
+                    .oninput(move |v| phone.set(v)),
+            ))
+            .children(field(
+                "日期:",
+                Input::new()
+                    .value(date)
+                    .mask(InputMask::date())
+                    .oninput(move |v| date.set(v)),
+            ))
+            .children(field(
+                "银行卡号:",
+                Input::new()
+                    .value(card)
+                    .mask(InputMask::credit_card())
+                    .oninput(move |v| card.set(v)),
+            ))
+            .into()
+    }
+}
+
 /// 前置和后置图标示例
 #[derive(Debug, Default, Clone)]
 pub struct PrefixSuffix {}
@@ -2,8 +2,12 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, InputNumber, InputNumberValue, Text, ToElement, View};
+use dioxus_blocks_components::{
+    Card, InputNumber, InputNumberValue, PropsTable, Text, ToElement, View,
+};
 use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
 use rust_decimal::{Decimal, prelude::FromPrimitive};
 
 #[derive(Debug, Default, Clone, Route)]
@@ -37,12 +41,14 @@ impl InputNumberView {
             self.placeholder_example(),
             self.counter_example(),
             self.component_linkage(),
+            api_reference(vec![PropsTable::of::<InputNumber>().common(true)]),
         ])
     }
 
     /// 基础用法
     fn basic_usage(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
                 Text::p("基本数字输入框，默认步进为 1。"),
@@ -54,6 +60,7 @@ impl InputNumberView {
     /// 禁用状态
     fn disabled_state(&self) -> Card {
         Card::new()
+            .anchor("禁用状态")
             .header(View::new().childrens(vec![
                 Text::h2("禁用状态"),
                 Text::p("禁用状态的数字输入框，不可编辑。"),
@@ -65,6 +72,7 @@ impl InputNumberView {
     /// 精度控制
     fn precision_control(&self) -> Card {
         Card::new()
+            .anchor("精度控制")
             .header(
                 View::new().childrens(vec![Text::h2("精度控制"), Text::p("控制显示的小数位数。")]),
             )
@@ -75,6 +83,7 @@ impl InputNumberView {
     /// 步进设置
     fn step_control(&self) -> Card {
         Card::new()
+            .anchor("步进设置")
             .header(View::new().childrens(vec![
                 Text::h2("步进设置"),
                 Text::p("设置每次增加或减少的步进值。"),
@@ -86,6 +95,7 @@ impl InputNumberView {
     /// 不同尺寸
     fn size_control(&self) -> Card {
         Card::new()
+            .anchor("不同尺寸")
             .header(View::new().childrens(vec![
                 Text::h2("不同尺寸"),
                 Text::p("不同大小的数字输入框。"),
@@ -97,6 +107,7 @@ impl InputNumberView {
     /// 最小值和最大值
     fn min_max_values(&self) -> Card {
         Card::new()
+            .anchor("最小值和最大值")
             .header(View::new().childrens(vec![
                 Text::h2("最小值和最大值"),
                 Text::p("设置输入范围的最小值和最大值。"),
@@ -108,6 +119,7 @@ impl InputNumberView {
     /// 占位符示例
     fn placeholder_example(&self) -> Card {
         Card::new()
+            .anchor("占位符")
             .header(View::new().childrens(vec![
                 Text::h2("占位符"),
                 Text::p("设置输入框的占位符文本。"),
@@ -119,6 +131,7 @@ impl InputNumberView {
     /// 计数器示例 - 实时显示值
     fn counter_example(&self) -> Card {
         Card::new()
+            .anchor("实时显示值")
             .header(View::new().childrens(vec![
                 Text::h2("实时显示值"),
                 Text::p("通过 onchange 事件实时获取并显示当前值。"),
@@ -130,6 +143,7 @@ impl InputNumberView {
     /// 组件联动示例
     fn component_linkage(&self) -> Card {
         Card::new()
+            .anchor("组件联动")
             .header(View::new().childrens(vec![
                 Text::h2("组件联动"),
                 Text::p("多个 InputNumber 组件与其他组件之间的数据联动。"),
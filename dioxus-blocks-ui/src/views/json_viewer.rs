@@ -0,0 +1,133 @@
+//! JsonViewer 组件使用示例
+
+use dioxus::prelude::*;
+use serde_json::{Value, json};
+
+use dioxus_blocks_components::{Card, JsonViewer, PropsTable, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct JsonViewerView {}
+
+impl ToElement for JsonViewerView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl JsonViewerView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("JsonViewer JSON 查看器"),
+            Text::p("以可折叠的树形结构显示 JSON 数据，适用于接口调试、日志详情等页面。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.expand_depth(),
+            self.copy_path(),
+            api_reference(vec![PropsTable::of::<JsonViewer>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "字符串、数字、布尔值和 null 使用不同颜色显示。在搜索框中输入键名，命中的键会高亮，其所在的分支自动展开。",
+                ),
+            ]))
+            .children(JsonViewer::new(response()))
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 默认展开层数
+    fn expand_depth(&self) -> Card {
+        Card::new()
+            .anchor("默认展开层数")
+            .header(View::new().childrens(vec![
+                Text::h2("默认展开层数"),
+                Text::p(
+                    "通过 expand_depth 设置默认展开的层数，更深的节点折叠为摘要，点击左侧的箭头展开或折叠。",
+                ),
+            ]))
+            .children(JsonViewer::new(response()).expand_depth(1))
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 复制路径
+    fn copy_path(&self) -> Card {
+        Card::new()
+            .anchor("复制路径")
+            .header(View::new().childrens(vec![
+                Text::h2("复制路径"),
+                Text::p(
+                    "鼠标移入任意一行时显示复制按钮，点击后复制该节点的 JSONPath，通过 oncopy_path 获取复制的路径。",
+                ),
+            ]))
+            .children(CopyPathExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 复制路径示例
+#[derive(Debug, Default, Clone)]
+struct CopyPathExample {}
+
+impl ToElement for CopyPathExample {
+    fn to_element(&self) -> Element {
+        let mut path = use_signal(|| "尚未复制".to_string());
+
+        View::new()
+            .children(
+                JsonViewer::new(response())
+                    .searchable(false)
+                    .expand_depth(2)
+                    .oncopy_path(move |copied| path.set(copied)),
+            )
+            .children(
+                Text::p(format!("最近复制：{}", path()))
+                    .style(|s| s.margin_top("16px").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 示例接口响应
+fn response() -> Value {
+    json!({
+        "code": 0,
+        "message": "ok",
+        "data": {
+            "total": 2,
+            "page": { "current": 1, "size": 20 },
+            "items": [
+                {
+                    "id": 1024,
+                    "name": "dioxus-blocks",
+                    "private": false,
+                    "owner": { "login": "silent-rain", "avatar url": null },
+                    "topics": ["rust", "dioxus", "ui"]
+                },
+                {
+                    "id": 2048,
+                    "name": "dioxus",
+                    "private": false,
+                    "owner": { "login": "DioxusLabs", "avatar url": null },
+                    "topics": []
+                }
+            ]
+        },
+        "trace_id": "7f3a9c21e0b8"
+    })
+}
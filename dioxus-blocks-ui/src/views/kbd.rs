@@ -0,0 +1,168 @@
+//! Kbd 和 ShortcutHint 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, Dropdown, DropdownItem, Kbd, PropsTable, ShortcutHint, Text, ToElement, Tooltip,
+    View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct KbdView {}
+
+impl ToElement for KbdView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl KbdView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Kbd 按键"),
+            Text::p(
+                "Kbd 以键帽样式显示按键，ShortcutHint 把 mod+K 形式的快捷键按当前平台显示：macOS 上为 ⌘K，其他平台为 Ctrl+K。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.shortcut(),
+            self.overlay(),
+            api_reference(vec![
+                PropsTable::of::<Kbd>().common(true),
+                PropsTable::of::<ShortcutHint>().common(true),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p("Kbd 显示单个按键，可以和文字混排。"),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 快捷键提示
+    fn shortcut(&self) -> Card {
+        Card::new()
+            .anchor("快捷键提示")
+            .header(View::new().childrens(vec![
+                Text::h2("快捷键提示"),
+                Text::p(
+                    "mod 在 macOS 上表示 Command，其他平台表示 Ctrl。mac 可以指定按某个平台显示，plain 模式下显示为一段文本。",
+                ),
+            ]))
+            .children(ShortcutExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 在提示和菜单中使用
+    fn overlay(&self) -> Card {
+        Card::new()
+            .anchor("在提示和菜单中使用")
+            .header(View::new().childrens(vec![
+                Text::h2("在提示和菜单中使用"),
+                Text::p(
+                    "Tooltip 和 DropdownItem 的 shortcut 在提示内容和菜单项文本后显示快捷键。快捷键只用于提示，需要由应用自行监听。",
+                ),
+            ]))
+            .children(OverlayExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的一行内容
+fn row() -> View {
+    View::new().style(|s| {
+        s.display("flex")
+            .flex_wrap("wrap")
+            .align_items("center")
+            .gap("12px")
+    })
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        row()
+            .children(Text::span("按"))
+            .children(Kbd::new("Esc"))
+            .children(Text::span("关闭对话框，按"))
+            .children(Kbd::new("Enter"))
+            .children(Text::span("确认"))
+            .to_element()
+    }
+}
+
+/// 快捷键提示示例
+#[derive(Debug, Default, Clone)]
+struct ShortcutExample {}
+
+impl ToElement for ShortcutExample {
+    fn to_element(&self) -> Element {
+        View::new()
+            .style(|s| s.display("flex").flex_direction("column").gap("12px"))
+            .children(
+                row()
+                    .children(ShortcutHint::new("mod+K"))
+                    .children(ShortcutHint::new("mod+shift+P"))
+                    .children(ShortcutHint::new("alt+enter"))
+                    .children(ShortcutHint::new("ctrl+up")),
+            )
+            .children(
+                row()
+                    .children(Text::span("macOS："))
+                    .children(ShortcutHint::new("mod+shift+P").mac(true))
+                    .children(Text::span("Windows / Linux："))
+                    .children(ShortcutHint::new("mod+shift+P").mac(false)),
+            )
+            .children(
+                row()
+                    .children(Text::span("文本模式："))
+                    .children(ShortcutHint::new("mod+S").plain(true)),
+            )
+            .to_element()
+    }
+}
+
+/// 在提示和菜单中使用示例
+#[derive(Debug, Default, Clone)]
+struct OverlayExample {}
+
+impl ToElement for OverlayExample {
+    fn to_element(&self) -> Element {
+        row()
+            .children(
+                Tooltip::new("保存")
+                    .shortcut("mod+S")
+                    .children(Button::new().text("保存")),
+            )
+            .children(
+                Dropdown::new()
+                    .item(DropdownItem::new("copy", "复制").shortcut("mod+C"))
+                    .item(DropdownItem::new("paste", "粘贴").shortcut("mod+V"))
+                    .item(DropdownItem::divider())
+                    .item(DropdownItem::new("find", "查找").shortcut("mod+F"))
+                    .item(DropdownItem::new("replace", "替换").shortcut("mod+alt+F"))
+                    .children(Button::new().text("编辑")),
+            )
+            .to_element()
+    }
+}
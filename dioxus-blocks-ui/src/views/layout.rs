@@ -1,9 +1,11 @@
 //! Layout 组件 (Row/Col)
 
 use dioxus::prelude::*;
-use dioxus_blocks_components::{Card, Col, Justify, Row, Text, ToElement, View};
+use dioxus_blocks_components::{Card, Col, Justify, PropsTable, Row, Text, ToElement, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 struct LayoutView {}
 
@@ -31,6 +33,10 @@ impl LayoutView {
             self.justify_section(),
             self.col_span_section(),
             self.responsive_section(),
+            api_reference(vec![
+                PropsTable::of::<Row>().common(true),
+                PropsTable::of::<Col>(),
+            ]),
         ])
     }
 
@@ -2,9 +2,11 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Link, Text, View};
+use dioxus_blocks_components::{Card, Link, PropsTable, Text, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct LinkView {}
 
@@ -31,12 +33,15 @@ impl LinkView {
             self.type_links(),
             self.underline_links(),
             self.disabled_links(),
+            self.anchor_links(),
+            api_reference(vec![PropsTable::of::<Link>().common(true)]),
         ])
     }
 
     /// 基础链接
     fn basic_links(&self) -> Card {
         Card::new()
+            .anchor("基础链接")
             .header(View::new().childrens(vec![
                 Text::h2("基础链接"),
                 Text::p("使用 .to() 方法设置链接目标。"),
@@ -52,6 +57,7 @@ impl LinkView {
     /// 类型链接
     fn type_links(&self) -> Card {
         Card::new()
+            .anchor("类型链接")
             .header(View::new().childrens(vec![Text::h2("类型链接"), Text::p("不同类型的链接。")]))
             .childrens(vec![
                 View::new()
@@ -91,6 +97,7 @@ impl LinkView {
     /// 下划线样式
     fn underline_links(&self) -> Card {
         Card::new()
+            .anchor("下划线样式")
             .header(View::new().childrens(vec![
                 Text::h2("下划线样式"),
                 Text::p("不同的下划线显示方式。"),
@@ -136,9 +143,34 @@ impl LinkView {
             .style(|s| s.margin_top("32px"))
     }
 
+    /// 锚点链接
+    fn anchor_links(&self) -> Card {
+        Card::new()
+            .anchor("锚点链接")
+            .header(View::new().childrens(vec![
+                Text::h2("锚点链接"),
+                Text::p(
+                    "站内路径可以带有锚点，跳转后滚动到对应的区块。演示页面的每个区块都以标题作为锚点，点击目录后地址栏中的链接可以直接分享。",
+                ),
+            ]))
+            .children(
+                View::new()
+                    .style(|s| s.padding("20px").display("flex").gap("12px"))
+                    .childrens(vec![
+                        Link::default().to("/link#基础链接").text("本页的基础链接"),
+                        Link::default()
+                            .to("/table#排序和自定义单元格")
+                            .text("表格的排序示例")
+                            .as_primary(),
+                    ]),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
     /// 禁用链接
     fn disabled_links(&self) -> Card {
         Card::new()
+            .anchor("禁用链接")
             .header(View::new().childrens(vec![Text::h2("禁用链接"), Text::p("禁用状态的链接。")]))
             .childrens(vec![
                 View::new()
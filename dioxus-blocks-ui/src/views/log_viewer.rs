@@ -0,0 +1,179 @@
+//! LogViewer 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, LogBuffer, LogLevel, LogViewer, PropsTable, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct LogViewerView {}
+
+impl ToElement for LogViewerView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl LogViewerView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("LogViewer 日志查看器"),
+            Text::p(
+                "显示持续追加的日志流，只渲染可视区域内的行，支持 ANSI 颜色、级别筛选、搜索和跟随底部。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.streaming(),
+            self.simple(),
+            api_reference(vec![PropsTable::of::<LogViewer>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "日志保存在 LogBuffer 中，级别从文本中自动识别。点击工具栏中的级别隐藏对应的日志，在搜索框中输入关键字筛选日志。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 日志流
+    fn streaming(&self) -> Card {
+        Card::new()
+            .anchor("日志流")
+            .header(View::new().childrens(vec![
+                Text::h2("日志流"),
+                Text::p(
+                    "缓冲区容量为 5000 行，超出后丢弃最早的日志。开启跟随底部时，新日志到达后自动滚动到底部；向上滚动时自动关闭。",
+                ),
+            ]))
+            .children(StreamingExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 精简模式
+    fn simple(&self) -> Card {
+        Card::new()
+            .anchor("精简模式")
+            .header(View::new().childrens(vec![
+                Text::h2("精简模式"),
+                Text::p("通过 show_toolbar 和 show_line_number 隐藏工具栏和行号。"),
+            ]))
+            .children(SimpleExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let logs = use_signal(|| {
+            let mut logs = LogBuffer::new(1000);
+            logs.extend(SAMPLE_LINES);
+            logs
+        });
+
+        LogViewer::new(logs).height(240).to_element()
+    }
+}
+
+/// 日志流示例
+#[derive(Debug, Default, Clone)]
+struct StreamingExample {}
+
+impl ToElement for StreamingExample {
+    fn to_element(&self) -> Element {
+        let mut logs = use_signal(|| LogBuffer::new(5000));
+        let mut cursor = use_signal(|| 0usize);
+
+        let mut append = move |count: usize| {
+            let start = cursor();
+            let mut logs = logs.write();
+            for i in start..start + count {
+                let line = SAMPLE_LINES[i % SAMPLE_LINES.len()];
+                logs.push(format!("\x1b[90m#{:05}\x1b[0m {line}", i + 1));
+            }
+            cursor.set(start + count);
+        };
+
+        View::new()
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").gap("12px").margin_bottom("16px"))
+                    .childrens(vec![
+                        Button::new()
+                            .text("追加 1 行")
+                            .as_primary()
+                            .onclick(move |_| append(1)),
+                        Button::new()
+                            .text("追加 1000 行")
+                            .onclick(move |_| append(1000)),
+                        Button::new()
+                            .text("追加错误")
+                            .as_danger()
+                            .onclick(move |_| {
+                                logs.write().push_with_level(
+                                    LogLevel::Error,
+                                    "\x1b[1;31mpanicked\x1b[0m at src/main.rs:42:5: connection reset",
+                                )
+                            }),
+                    ]),
+            )
+            .children(LogViewer::new(logs).height(320))
+            .into()
+    }
+}
+
+/// 精简模式示例
+#[derive(Debug, Default, Clone)]
+struct SimpleExample {}
+
+impl ToElement for SimpleExample {
+    fn to_element(&self) -> Element {
+        let logs = use_signal(|| {
+            let mut logs = LogBuffer::new(100);
+            logs.extend(&SAMPLE_LINES[..4]);
+            logs
+        });
+
+        LogViewer::new(logs)
+            .height(100)
+            .show_toolbar(false)
+            .show_line_number(false)
+            .to_element()
+    }
+}
+
+/// 示例日志，包含 ANSI 颜色
+const SAMPLE_LINES: [&str; 10] = [
+    "\x1b[32m INFO\x1b[0m server: listening on \x1b[4mhttp://127.0.0.1:8080\x1b[0m",
+    "\x1b[34mDEBUG\x1b[0m db: pool created, size=\x1b[1m16\x1b[0m",
+    "\x1b[32m INFO\x1b[0m http: GET /api/users \x1b[32m200\x1b[0m 12ms",
+    "\x1b[90mTRACE\x1b[0m http: headers parsed in 43µs",
+    "\x1b[33m WARN\x1b[0m db: slow query \x1b[33m1200ms\x1b[0m: SELECT * FROM orders",
+    "\x1b[32m INFO\x1b[0m http: POST /api/orders \x1b[32m201\x1b[0m 35ms",
+    "\x1b[31mERROR\x1b[0m http: GET /api/report \x1b[1;31m500\x1b[0m upstream timeout",
+    "\x1b[32m INFO\x1b[0m cache: hit ratio \x1b[38;5;208m87.5%\x1b[0m",
+    "\x1b[34mDEBUG\x1b[0m job: cleanup finished, removed 3 sessions",
+    "\x1b[32m INFO\x1b[0m http: GET /health \x1b[32m200\x1b[0m 1ms",
+];
@@ -0,0 +1,104 @@
+//! Masonry 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Card, Masonry, MasonryStrategy, PropsTable, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+/// 示例卡片的标题和正文长度
+const NOTES: [(&str, usize); 8] = [
+    ("晨间计划", 2),
+    ("读书笔记", 6),
+    ("待办事项", 1),
+    ("旅行清单", 4),
+    ("会议纪要", 8),
+    ("灵感记录", 3),
+    ("周报草稿", 5),
+    ("购物清单", 2),
+];
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct MasonryView {}
+
+impl ToElement for MasonryView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl MasonryView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Masonry 瀑布流"),
+            Text::p("把高度不一的子元素排列到多列中，适合图片墙和卡片列表。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.columns_usage(),
+            self.measure_usage(),
+            api_reference(vec![PropsTable::of::<Masonry>().common(true)]),
+        ])
+    }
+
+    /// 多列布局
+    fn columns_usage(&self) -> Card {
+        Card::new()
+            .anchor("多列布局")
+            .header(View::new().childrens(vec![
+                Text::h2("多列布局"),
+                Text::p(
+                    "默认使用 CSS 多列布局，子元素先上下后左右排列；通过 breakpoint 按容器宽度减少列数。",
+                ),
+            ]))
+            .children(
+                notes(Masonry::new())
+                    .columns(3)
+                    .breakpoint(720, 2)
+                    .breakpoint(480, 1),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 测量高度
+    fn measure_usage(&self) -> Card {
+        Card::new()
+            .anchor("测量高度")
+            .header(View::new().childrens(vec![
+                Text::h2("测量高度"),
+                Text::p(
+                    "MasonryStrategy::Measure 测量每个子元素的高度并放入最短的一列，子元素大致按先左右后上下的顺序排列。",
+                ),
+            ]))
+            .children(
+                notes(Masonry::new())
+                    .columns(4)
+                    .gap(12)
+                    .strategy(MasonryStrategy::Measure)
+                    .breakpoint(960, 3)
+                    .breakpoint(720, 2)
+                    .breakpoint(480, 1),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 添加高度不一的示例卡片
+fn notes(masonry: Masonry) -> Masonry {
+    NOTES
+        .iter()
+        .enumerate()
+        .fold(masonry, |masonry, (i, (title, lines))| {
+            masonry.children(
+                Card::new()
+                    .header(Text::h4(format!("{}. {title}", i + 1)))
+                    .children(Text::p("记录一些想法和需要完成的事情。".repeat(*lines))),
+            )
+        })
+}
@@ -0,0 +1,186 @@
+//! Message 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    AlertType, Button, ButtonType, Card, MessageItem, MessageProvider, PropsTable, Text, ToElement,
+    View, message, notify,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct MessageView {}
+
+impl ToElement for MessageView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl MessageView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Message 消息提示"),
+            Text::p(
+                "在页面顶部显示一行简短的操作反馈，几秒后自动消失。在应用根组件中挂载一次 MessageProvider，之后在任意位置调用 message 模块的函数发送消息，不需要获取上下文。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic(),
+            self.options(),
+            self.anywhere(),
+            api_reference(vec![PropsTable::of::<MessageProvider>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "message::success、info、warning、error 发送四种类型的消息，默认 3 秒后自动关闭。多条消息依次堆叠，超过 max_count 时关闭最早的消息。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 可关闭和显示时长
+    fn options(&self) -> Card {
+        Card::new()
+            .anchor("可关闭和显示时长")
+            .header(View::new().childrens(vec![
+                Text::h2("可关闭和显示时长"),
+                Text::p(
+                    "通过 MessageItem 设置类型、显示时长和关闭按钮后用 message::show 发送。显示时长为 0 的消息不会自动关闭，message::close 按编号关闭，message::close_all 关闭全部消息。",
+                ),
+            ]))
+            .children(OptionsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 在任意位置发送
+    fn anywhere(&self) -> Card {
+        Card::new()
+            .anchor("在任意位置发送")
+            .header(View::new().childrens(vec![
+                Text::h2("在任意位置发送"),
+                Text::p(
+                    "与组件无关的工具函数也可以直接发送消息。需要标题和正文时使用 notify 模块的函数，通知显示在最近挂载的 Notification 中。MessageProvider 的 placement 可以把消息放在页面的四个角或底部。",
+                ),
+            ]))
+            .children(AnywhereExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的一行按钮
+fn buttons(buttons: Vec<Button>) -> View {
+    View::new()
+        .style(|s| s.display("flex").flex_wrap("wrap").gap("12px"))
+        .childrens(buttons)
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        buttons(vec![
+            Button::new()
+                .text("成功")
+                .btn_type(ButtonType::Success)
+                .onclick(|_| {
+                    message::success("保存成功");
+                }),
+            Button::new().text("信息").onclick(|_| {
+                message::info("已复制到剪贴板");
+            }),
+            Button::new()
+                .text("警告")
+                .btn_type(ButtonType::Warning)
+                .onclick(|_| {
+                    message::warning("草稿尚未保存");
+                }),
+            Button::new()
+                .text("错误")
+                .btn_type(ButtonType::Danger)
+                .onclick(|_| {
+                    message::error("网络连接失败，请稍后重试");
+                }),
+        ])
+        .into()
+    }
+}
+
+/// 可关闭和显示时长示例
+#[derive(Debug, Default, Clone)]
+struct OptionsExample {}
+
+impl ToElement for OptionsExample {
+    fn to_element(&self) -> Element {
+        let mut last = use_signal(|| None::<u64>);
+
+        buttons(vec![
+            Button::new().text("可关闭的消息").onclick(|_| {
+                message::show(
+                    MessageItem::new("这条消息可以手动关闭")
+                        .kind(AlertType::Info)
+                        .closable(true),
+                );
+            }),
+            Button::new().text("不自动关闭").onclick(move |_| {
+                let id = message::show(
+                    MessageItem::new("正在同步数据……")
+                        .kind(AlertType::Warning)
+                        .duration(0)
+                        .closable(true),
+                );
+                last.set(Some(id));
+            }),
+            Button::new().text("关闭上一条").onclick(move |_| {
+                if let Some(id) = last.take() {
+                    message::close(id);
+                }
+            }),
+            Button::new()
+                .text("全部关闭")
+                .onclick(|_| message::close_all()),
+        ])
+        .into()
+    }
+}
+
+/// 与组件无关的保存函数，完成后直接发送消息
+fn save_draft(title: &str) {
+    message::success(format!("「{title}」已保存为草稿"));
+}
+
+/// 在任意位置发送示例
+#[derive(Debug, Default, Clone)]
+struct AnywhereExample {}
+
+impl ToElement for AnywhereExample {
+    fn to_element(&self) -> Element {
+        buttons(vec![
+            Button::new()
+                .text("保存草稿")
+                .onclick(|_| save_draft("季度总结")),
+            Button::new().text("发送通知").onclick(|_| {
+                notify::info("版本更新", "新版本已发布，刷新页面后生效");
+            }),
+        ])
+        .into()
+    }
+}
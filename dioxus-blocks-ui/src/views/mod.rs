@@ -2,6 +2,8 @@
 //!
 //! This module contains various view components used in the application.
 
+mod api;
+
 mod text;
 pub use text::TextViewRoute;
 
@@ -47,5 +49,137 @@ pub use radio::RadioViewRoute;
 mod checkbox;
 pub use checkbox::CheckboxViewRoute;
 
-// mod select;
-// pub use select::SelectViewRoute;
+mod calendar_heatmap;
+pub use calendar_heatmap::CalendarHeatmapViewRoute;
+
+mod org_chart;
+pub use org_chart::OrgChartViewRoute;
+
+mod json_viewer;
+pub use json_viewer::JsonViewerViewRoute;
+
+mod log_viewer;
+pub use log_viewer::LogViewerViewRoute;
+
+mod table;
+pub use table::TableViewRoute;
+
+mod selection_bar;
+pub use selection_bar::SelectionBarViewRoute;
+
+mod crud_page;
+pub use crud_page::CrudPageViewRoute;
+
+mod data_source;
+pub use data_source::DataSourceViewRoute;
+
+mod notification;
+pub use notification::NotificationViewRoute;
+
+mod message;
+pub use message::MessageViewRoute;
+
+mod title_provider;
+pub use title_provider::TitleProviderViewRoute;
+
+mod kbd;
+pub use kbd::KbdViewRoute;
+
+mod tooltip;
+pub use tooltip::TooltipViewRoute;
+
+mod tabs;
+pub use tabs::TabsViewRoute;
+
+mod dropdown;
+pub use dropdown::DropdownViewRoute;
+
+mod pagination;
+pub use pagination::PaginationViewRoute;
+
+mod popconfirm;
+pub use popconfirm::PopconfirmViewRoute;
+
+mod idle_guard;
+pub use idle_guard::IdleGuardViewRoute;
+
+mod dialog;
+pub use dialog::DialogViewRoute;
+
+mod skeleton;
+pub use skeleton::SkeletonViewRoute;
+
+mod spin;
+pub use spin::SpinViewRoute;
+
+mod form;
+pub use form::FormViewRoute;
+
+mod form_wizard;
+pub use form_wizard::FormWizardViewRoute;
+
+mod auth;
+pub use auth::{LoginViewRoute, RegisterViewRoute};
+
+mod dashboard;
+pub use dashboard::DashboardViewRoute;
+
+mod error;
+pub use error::{MaintenanceViewRoute, NotFoundViewRoute, ServerErrorViewRoute};
+
+mod settings;
+pub use settings::SettingsViewRoute;
+
+mod registry;
+pub use registry::{RegistryView, registry_detail};
+
+mod credit_card_input;
+pub use credit_card_input::CreditCardInputViewRoute;
+
+mod phone_input;
+pub use phone_input::PhoneInputViewRoute;
+
+mod barcode_input;
+pub use barcode_input::BarcodeInputViewRoute;
+
+mod address_picker;
+pub use address_picker::AddressPickerViewRoute;
+
+mod search_input;
+pub use search_input::SearchInputViewRoute;
+
+mod filter_bar;
+pub use filter_bar::FilterBarViewRoute;
+
+mod toolbar;
+pub use toolbar::ToolbarViewRoute;
+
+mod permission;
+pub use permission::PermissionViewRoute;
+
+mod masonry;
+pub use masonry::MasonryViewRoute;
+
+mod grid_playground;
+pub use grid_playground::GridPlaygroundViewRoute;
+
+mod theme_editor;
+pub use theme_editor::ThemeEditorViewRoute;
+
+mod stories;
+pub use stories::StoriesViewRoute;
+
+mod select;
+pub use select::SelectViewRoute;
+
+mod time_picker;
+pub use time_picker::TimePickerViewRoute;
+
+mod date_picker;
+pub use date_picker::DatePickerViewRoute;
+
+mod schema_form;
+pub use schema_form::SchemaFormViewRoute;
+
+mod upload;
+pub use upload::UploadViewRoute;
@@ -0,0 +1,294 @@
+//! Notification 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, Notification, NotificationItem, NotificationPermission, PropsTable, Settings,
+    Text, ToElement, View, notification_permission, use_app_badge, use_notification, use_settings,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct NotificationView {}
+
+impl ToElement for NotificationView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl NotificationView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Notification 通知"),
+            Text::p(
+                "在页面右上角显示通知提醒。通知遵循全局配置：可以关闭通知、播放提示音，或在用户授权后使用浏览器的系统通知。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.undo_delete(),
+            self.unread_badge(),
+            self.browser_notification(),
+            api_reference(vec![
+                PropsTable::of::<Notification>().common(true),
+                PropsTable::of::<Settings>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "在 Notification 的子组件中通过 use_notification 获取发送器，提供成功、信息、警告和错误四种类型，默认 4.5 秒后自动关闭。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 可撤销的删除
+    fn undo_delete(&self) -> Card {
+        Card::new()
+            .anchor("可撤销的删除")
+            .header(View::new().childrens(vec![
+                Text::h2("可撤销的删除"),
+                Text::p(
+                    "NotificationItem::with_undo 让删除先在界面上生效，通知中显示撤销按钮，5 秒内点击撤销即可恢复，否则才真正执行删除。",
+                ),
+            ]))
+            .children(UndoExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 未读角标
+    fn unread_badge(&self) -> Card {
+        Card::new()
+            .anchor("未读角标")
+            .header(View::new().childrens(vec![
+                Text::h2("未读角标"),
+                Text::p(
+                    "Notifier::unread 记录送达的通知数量，配合 use_app_badge 在标签页图标上显示未读数量，浏览器支持 Badging API 时同时设置应用图标角标。",
+                ),
+            ]))
+            .children(BadgeExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 浏览器通知
+    fn browser_notification(&self) -> Card {
+        Card::new()
+            .anchor("浏览器通知")
+            .header(View::new().childrens(vec![
+                Text::h2("浏览器通知"),
+                Text::p(
+                    "浏览器通知需要用户主动开启。调用 enable_browser_notifications 请求授权，授权成功后通知以系统通知显示，页面在后台时也能收到；未授权或不支持时退回到页面内提示。",
+                ),
+            ]))
+            .children(BrowserExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let notifier = use_notification();
+
+        View::new()
+            .style(|s| s.display("flex").gap("12px").flex_wrap("wrap"))
+            .childrens(vec![
+                Button::new()
+                    .text("成功")
+                    .as_success()
+                    .onclick(move |_| notifier.success("保存成功", "文章已发布到博客首页")),
+                Button::new()
+                    .text("信息")
+                    .as_info()
+                    .onclick(move |_| notifier.info("新版本可用", "刷新页面即可更新到最新版本")),
+                Button::new()
+                    .text("警告")
+                    .as_warning()
+                    .onclick(move |_| notifier.warning("存储空间不足", "已使用 90% 的存储空间")),
+                Button::new()
+                    .text("错误")
+                    .as_danger()
+                    .onclick(move |_| notifier.error("同步失败", "请检查网络连接后重试")),
+            ])
+            .into()
+    }
+}
+
+/// 可撤销的删除示例
+#[derive(Debug, Default, Clone)]
+struct UndoExample {}
+
+impl ToElement for UndoExample {
+    fn to_element(&self) -> Element {
+        let notifier = use_notification();
+        let mut files = use_signal(|| {
+            vec![
+                "季度报告.pdf".to_string(),
+                "产品原型.fig".to_string(),
+                "会议纪要.docx".to_string(),
+            ]
+        });
+        // 等待撤销的文件，界面上暂时隐藏
+        let mut pending = use_signal(Vec::<String>::new);
+        let mut deleted = use_signal(|| 0);
+
+        let visible = files
+            .read()
+            .iter()
+            .filter(|file| !pending.read().contains(file))
+            .cloned()
+            .collect::<Vec<_>>();
+
+        View::new()
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").flex_direction("column").gap("8px"))
+                    .childrens(
+                        visible
+                            .into_iter()
+                            .map(|file| {
+                                let name = file.clone();
+                                View::new()
+                                    .style(|s| {
+                                        s.display("flex")
+                                            .align_items("center")
+                                            .justify_content("space-between")
+                                            .max_width("360px")
+                                    })
+                                    .children(Text::span(file.clone()))
+                                    .children(Button::new().text("删除").as_danger().onclick(
+                                        move |_| {
+                                            let name = name.clone();
+                                            pending.write().push(name.clone());
+                                            let (commit, undo) = (name.clone(), name.clone());
+                                            notifier.notify(
+                                                NotificationItem::new(format!("已删除 {name}"))
+                                                    .with_undo(
+                                                        move || {
+                                                            files.write().retain(|f| *f != commit);
+                                                            pending
+                                                                .write()
+                                                                .retain(|f| *f != commit);
+                                                            deleted += 1;
+                                                        },
+                                                        move || {
+                                                            pending.write().retain(|f| *f != undo)
+                                                        },
+                                                        5000,
+                                                    ),
+                                            );
+                                        },
+                                    ))
+                            })
+                            .collect(),
+                    ),
+            )
+            .children(
+                Text::p(format!("已真正删除 {} 个文件", deleted()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 未读角标示例
+#[derive(Debug, Default, Clone)]
+struct BadgeExample {}
+
+impl ToElement for BadgeExample {
+    fn to_element(&self) -> Element {
+        let notifier = use_notification();
+        let unread = notifier.unread();
+        use_app_badge(unread);
+
+        View::new()
+            .style(|s| s.display("flex").align_items("center").gap("12px"))
+            .children(Text::span(format!("未读通知：{unread}")))
+            .children(
+                Button::new()
+                    .text("收到新消息")
+                    .onclick(move |_| notifier.info("新消息", "张三评论了你的文章")),
+            )
+            .children(
+                Button::new()
+                    .text("全部已读")
+                    .disabled(unread == 0)
+                    .onclick(move |_| notifier.mark_all_read()),
+            )
+            .into()
+    }
+}
+
+/// 浏览器通知示例
+#[derive(Debug, Default, Clone)]
+struct BrowserExample {}
+
+impl ToElement for BrowserExample {
+    fn to_element(&self) -> Element {
+        let notifier = use_notification();
+        let settings = use_settings();
+        let mut permission = use_signal(NotificationPermission::default);
+        use_future(move || async move {
+            permission.set(notification_permission().await);
+        });
+
+        let enabled = settings.read().browser_notifications;
+        let status = match permission() {
+            NotificationPermission::Default => "尚未授权",
+            NotificationPermission::Granted => "已授权",
+            NotificationPermission::Denied => "已拒绝，请在浏览器设置中允许通知",
+            NotificationPermission::Unsupported => "当前环境不支持",
+        };
+
+        View::new()
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").gap("12px").flex_wrap("wrap"))
+                    .children(if enabled {
+                        Button::new()
+                            .text("关闭浏览器通知")
+                            .onclick(move |_| notifier.disable_browser_notifications())
+                    } else {
+                        Button::new()
+                            .text("开启浏览器通知")
+                            .as_primary()
+                            .onclick(move |_| {
+                                spawn(async move {
+                                    permission.set(notifier.enable_browser_notifications().await);
+                                });
+                            })
+                    })
+                    .children(Button::new().text("发送通知").onclick(move |_| {
+                        notifier.info("构建完成", "dioxus-blocks-ui 构建成功，用时 12.4 秒")
+                    })),
+            )
+            .children(
+                Text::p(format!(
+                    "授权状态：{status}；浏览器通知{}",
+                    if enabled { "已开启" } else { "未开启" }
+                ))
+                .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
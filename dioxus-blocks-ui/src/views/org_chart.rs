@@ -0,0 +1,148 @@
+//! OrgChart 组件使用示例
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Card, OrgChart, OrgChartNode, PropsTable, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+use crate::LazyRoute;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct OrgChartView {}
+
+impl ToElement for OrgChartView {
+    fn to_element(&self) -> Element {
+        // 组织结构图节点较多，首次进入时懒加载
+        let view = self.clone();
+        LazyRoute::new(move || view.page()).to_element()
+    }
+}
+
+impl OrgChartView {
+    fn page(&self) -> View {
+        View::new().children(self.title()).children(self.content())
+    }
+
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("OrgChart 组织结构图"),
+            Text::p("以自上而下的树形图展示层级关系，适用于团队架构、分类体系等场景。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.collapsed(),
+            self.custom_node(),
+            api_reference(vec![
+                PropsTable::of::<OrgChart>().common(true),
+                PropsTable::of::<OrgChartNode>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p("通过 OrgChartNode 构建树形数据，节点之间自动绘制连接线，点击节点下方的按钮可折叠分支。"),
+            ]))
+            .children(OrgChart::new(team()))
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 默认折叠
+    fn collapsed(&self) -> Card {
+        Card::new()
+            .anchor("默认折叠")
+            .header(View::new().childrens(vec![
+                Text::h2("默认折叠"),
+                Text::p(
+                    "通过 default_collapsed 设置默认折叠的节点，折叠后按钮显示隐藏的后代节点数量。",
+                ),
+            ]))
+            .children(OrgChart::new(team()).default_collapsed(vec!["engineering", "design"]))
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义节点
+    fn custom_node(&self) -> Card {
+        Card::new()
+            .anchor("自定义节点")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义节点"),
+                Text::p("通过 render_node 闭包自定义节点内容，通过 onnode_click 响应节点点击。"),
+            ]))
+            .children(CustomNodeExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 自定义节点示例
+#[derive(Debug, Default, Clone)]
+struct CustomNodeExample {}
+
+impl ToElement for CustomNodeExample {
+    fn to_element(&self) -> Element {
+        let mut selected = use_signal(|| "点击任意节点".to_string());
+
+        View::new()
+            .children(
+                OrgChart::new(team())
+                    .render_node(|node: OrgChartNode| {
+                        let color = if node.is_leaf() {
+                            "var(--t-color-success)"
+                        } else {
+                            "var(--t-color-primary)"
+                        };
+                        Rc::new(
+                            View::new()
+                                .style(move |s| {
+                                    s.padding("8px 16px")
+                                        .border_radius("16px")
+                                        .color("#ffffff")
+                                        .background_color(color)
+                                        .white_space("nowrap")
+                                })
+                                .children(Text::new(node.label().to_string())),
+                        )
+                    })
+                    .onnode_click(move |node| {
+                        let description = node.get_description().unwrap_or("暂无描述");
+                        selected.set(format!("{}：{}", node.label(), description))
+                    }),
+            )
+            .children(
+                Text::p(selected())
+                    .style(|s| s.margin_top("16px").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 示例团队数据
+fn team() -> OrgChartNode {
+    OrgChartNode::new("ceo", "张三")
+        .description("首席执行官")
+        .child(
+            OrgChartNode::new("engineering", "李四")
+                .description("研发中心")
+                .child(OrgChartNode::new("frontend", "王五").description("前端组"))
+                .child(OrgChartNode::new("backend", "赵六").description("后端组"))
+                .child(OrgChartNode::new("qa", "钱七").description("测试组")),
+        )
+        .child(
+            OrgChartNode::new("design", "孙八")
+                .description("设计中心")
+                .child(OrgChartNode::new("ui", "周九").description("视觉设计"))
+                .child(OrgChartNode::new("ux", "吴十").description("交互设计")),
+        )
+        .child(OrgChartNode::new("operation", "郑一").description("运营中心"))
+}
@@ -0,0 +1,162 @@
+//! Pagination 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, Checkbox, Pagination, PaginationLayout, PropsTable, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct PaginationView {}
+
+impl ToElement for PaginationView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl PaginationView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Pagination 分页"),
+            Text::p(
+                "数据较多时分页显示，切换页码或每页的记录数时以新的页码和每页记录数调用 onchange，由调用方重新查询数据。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic(),
+            self.compact(),
+            self.options(),
+            api_reference(vec![PropsTable::of::<Pagination>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "显示总数、每页记录数选择、页码列表和跳转输入框。页数较多时页码列表以省略号折叠，点击省略号向前或向后跳过 5 页；修改每页的记录数时保持当前页的第一条记录可见。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 紧凑布局
+    fn compact(&self) -> Card {
+        Card::new()
+            .anchor("紧凑布局")
+            .header(View::new().childrens(vec![
+                Text::h2("紧凑布局"),
+                Text::p(
+                    "layout 设置为 Compact 时只显示上一页、「当前页 / 总页数」和下一页，适合窄屏和表格底部。",
+                ),
+            ]))
+            .children(CompactExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 更多配置
+    fn options(&self) -> Card {
+        Card::new()
+            .anchor("更多配置")
+            .header(View::new().childrens(vec![
+                Text::h2("更多配置"),
+                Text::p(
+                    "page_sizes 设置可选的每页记录数，pager_count 设置页码列表最多显示的页码数，show_sizes 和 show_jumper 控制是否显示对应的部分，hide_on_single_page 在只有一页时隐藏分页。",
+                ),
+            ]))
+            .children(OptionsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 显示当前页码和每页的记录数
+fn page_text(page: usize, page_size: usize) -> Text {
+    Text::span(format!("第 {page} 页，每页 {page_size} 条")).style(|s| {
+        s.display("block")
+            .margin_top("12px")
+            .color("var(--t-text-color-secondary)")
+    })
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let current = use_signal(|| 1);
+        let page_size = use_signal(|| 10);
+
+        View::new()
+            .children(
+                Pagination::new()
+                    .total(326)
+                    .current(current)
+                    .page_size(page_size),
+            )
+            .children(page_text(current(), page_size()))
+            .into()
+    }
+}
+
+/// 紧凑布局示例
+#[derive(Debug, Default, Clone)]
+struct CompactExample {}
+
+impl ToElement for CompactExample {
+    fn to_element(&self) -> Element {
+        let mut changed = use_signal(|| (1, 10));
+        let (page, page_size) = changed();
+
+        View::new()
+            .children(
+                Pagination::new()
+                    .total(95)
+                    .layout(PaginationLayout::Compact)
+                    .onchange(move |page, page_size| changed.set((page, page_size))),
+            )
+            .children(page_text(page, page_size))
+            .into()
+    }
+}
+
+/// 更多配置示例
+#[derive(Debug, Default, Clone)]
+struct OptionsExample {}
+
+impl ToElement for OptionsExample {
+    fn to_element(&self) -> Element {
+        let single = use_signal(|| false);
+        let total = if single() { 8 } else { 1000 };
+
+        View::new()
+            .children(
+                View::new()
+                    .style(|s| s.margin_bottom("16px"))
+                    .children(Checkbox::new().label("只有 8 条记录").checked(single)),
+            )
+            .children(
+                Pagination::new()
+                    .total(total)
+                    .page_sizes(vec![25, 50, 100])
+                    .pager_count(5)
+                    .show_jumper(false)
+                    .hide_on_single_page(true),
+            )
+            .into()
+    }
+}
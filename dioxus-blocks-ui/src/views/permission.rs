@@ -0,0 +1,150 @@
+//! 权限控制使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Card, Text, ToElement, View};
+use dioxus_blocks_macro::Route;
+
+use crate::{AuthUser, Can, Denied, use_auth, use_permission};
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct PermissionView {}
+
+impl ToElement for PermissionView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl PermissionView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Permission 权限控制"),
+            Text::p("根据当前用户的角色隐藏或禁用操作入口，权限规则在 AuthProvider 中注册。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new()
+            .children(self.switch_user())
+            .children(self.can_usage())
+            .children(self.hook_usage())
+    }
+
+    /// 切换用户
+    fn switch_user(&self) -> Card {
+        Card::new()
+            .anchor("切换用户")
+            .header(View::new().childrens(vec![
+                Text::h2("切换用户"),
+                Text::p("本站为 admin 角色授予全部权限，editor 角色只能查看和编辑文章，未登录时只能查看。"),
+            ]))
+            .children(RoleSwitcher {})
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 隐藏与禁用
+    fn can_usage(&self) -> Card {
+        Card::new()
+            .anchor("隐藏与禁用")
+            .header(View::new().childrens(vec![
+                Text::h2("隐藏与禁用"),
+                Text::p("Can 默认在无权限时隐藏内容，可以设置替代内容；设置 Denied::Disable 后显示为禁用状态。"),
+            ]))
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").gap("12px").flex_wrap("wrap"))
+                    .children(Can::new("posts:read").children(Button::new().text("查看文章")))
+                    .children(
+                        Can::new("posts:write")
+                            .fallback(Text::span("登录编辑账号后可以发布文章"))
+                            .children(Button::new().text("发布文章").as_primary()),
+                    )
+                    .children(
+                        Can::new("posts:delete")
+                            .denied(Denied::Disable)
+                            .reason("只有管理员可以删除文章")
+                            .children(Button::new().text("删除文章").as_danger()),
+                    ),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 在组件中读取
+    fn hook_usage(&self) -> Card {
+        Card::new()
+            .anchor("在组件中读取")
+            .header(View::new().childrens(vec![
+                Text::h2("在组件中读取"),
+                Text::p(
+                    "use_permission 返回随登录状态更新的权限判断结果，适合直接控制组件的属性。",
+                ),
+            ]))
+            .children(PublishButton {})
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 以不同角色登录
+#[derive(Debug, Clone)]
+struct RoleSwitcher {}
+
+impl ToElement for RoleSwitcher {
+    fn to_element(&self) -> Element {
+        let auth = use_auth();
+        let current = auth
+            .user()
+            .map(|user| {
+                format!(
+                    "当前用户：{}（{}）",
+                    user.display_name(),
+                    user.roles.join("、")
+                )
+            })
+            .unwrap_or_else(|| "当前未登录".to_string());
+
+        View::new()
+            .style(|s| {
+                s.display("flex")
+                    .gap("12px")
+                    .align_items("center")
+                    .flex_wrap("wrap")
+            })
+            .children(Button::new().text("管理员").onclick(move |_| {
+                let mut auth = auth;
+                auth.login(AuthUser::new("admin").nickname("管理员").role("admin"));
+            }))
+            .children(Button::new().text("编辑").onclick(move |_| {
+                let mut auth = auth;
+                auth.login(AuthUser::new("editor").nickname("编辑").role("editor"));
+            }))
+            .children(Button::new().text("退出登录").onclick(move |_| {
+                let mut auth = auth;
+                auth.logout();
+            }))
+            .children(Text::span(current).style(|s| s.color("var(--t-text-color-secondary)")))
+            .to_element()
+    }
+}
+
+/// 无权限时禁用的发布按钮
+#[derive(Debug, Clone)]
+struct PublishButton {}
+
+impl ToElement for PublishButton {
+    fn to_element(&self) -> Element {
+        let can_write = use_permission("posts:write");
+        Button::new()
+            .text(if can_write() {
+                "保存并发布"
+            } else {
+                "没有发布权限"
+            })
+            .as_primary()
+            .disabled(!can_write())
+            .to_element()
+    }
+}
@@ -0,0 +1,123 @@
+//! PhoneInput 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, PhoneInput, PhoneState, PhoneValue, PropsTable, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct PhoneInputView {}
+
+impl ToElement for PhoneInputView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl PhoneInputView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("PhoneInput 手机号码输入"),
+            Text::p(
+                "由国家/地区选择器和号码输入框组成，按所选国家/地区的格式分组显示号码，输出 E.164 格式的号码。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.countries(),
+            api_reference(vec![PropsTable::of::<PhoneInput>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "切换国家/地区后号码按新的格式重新分组，开头输入的 0 会被忽略。校验状态通过 PhoneValue::validate 获取，E.164 格式的号码通过 PhoneValue::e164 获取。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 限定国家/地区
+    fn countries(&self) -> Card {
+        Card::new()
+            .anchor("限定国家/地区")
+            .header(View::new().childrens(vec![
+                Text::h2("限定国家/地区"),
+                Text::p(
+                    "countries 设置可选的国家/地区。已保存的 E.164 号码可以通过 PhoneValue::parse 还原为组件的值。",
+                ),
+            ]))
+            .children(CountriesExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 显示号码的校验状态和 E.164 格式
+fn phone_text(value: &PhoneValue) -> Text {
+    let state = match value.validate() {
+        PhoneState::Empty => "未填写",
+        PhoneState::Incomplete => "未完成",
+        PhoneState::Invalid => "无效",
+        PhoneState::Valid => "有效",
+    };
+    Text::p(format!("{state}：{}", value.e164())).style(|s| {
+        s.margin_top("16px")
+            .color("var(--t-text-color-secondary)")
+            .font_size("13px")
+    })
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let phone = use_signal(PhoneValue::default);
+        let value = phone.read().clone();
+
+        View::new()
+            .style(|s| s.width("360px"))
+            .children(PhoneInput::new().value(phone))
+            .children(phone_text(&value))
+            .to_element()
+    }
+}
+
+/// 限定国家/地区示例
+#[derive(Debug, Default, Clone)]
+struct CountriesExample {}
+
+impl ToElement for CountriesExample {
+    fn to_element(&self) -> Element {
+        let phone = use_signal(|| PhoneValue::parse("+85261234567").unwrap_or_default());
+        let value = phone.read().clone();
+
+        View::new()
+            .style(|s| s.width("360px"))
+            .children(
+                PhoneInput::new()
+                    .value(phone)
+                    .countries(vec!["CN", "HK", "MO", "TW"])
+                    .error_message("请输入有效的手机号码"),
+            )
+            .children(phone_text(&value))
+            .to_element()
+    }
+}
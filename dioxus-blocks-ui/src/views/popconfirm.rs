@@ -0,0 +1,167 @@
+//! Popconfirm 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, ButtonType, Card, Popconfirm, PropsTable, Text, ToElement, TooltipPlacement, View,
+    message,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct PopconfirmView {}
+
+impl ToElement for PopconfirmView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl PopconfirmView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Popconfirm 气泡确认框"),
+            Text::p(
+                "点击触发元素后在旁边弹出确认气泡，比对话框更轻量，适合删除、撤回等需要二次确认的操作。气泡与 Tooltip、Dropdown 使用相同的定位方式，空间不足时自动翻转。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic(),
+            self.custom(),
+            self.placement(),
+            api_reference(vec![PropsTable::of::<Popconfirm>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "点击「确定」或「取消」后关闭气泡并调用 onconfirm 或 oncancel，点击其他位置或按 Escape 直接关闭。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 自定义内容
+    fn custom(&self) -> Card {
+        Card::new()
+            .anchor("自定义内容")
+            .header(View::new().childrens(vec![
+                Text::h2("自定义内容"),
+                Text::p(
+                    "description 在标题下方显示补充说明，icon 替换标题前的图标，传入空字符串时不显示图标。按钮文本和确定按钮的类型同样可以修改。",
+                ),
+            ]))
+            .children(CustomExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 位置
+    fn placement(&self) -> Card {
+        Card::new()
+            .anchor("位置")
+            .header(View::new().childrens(vec![
+                Text::h2("位置"),
+                Text::p("与 Tooltip 相同，placement 支持四个方向及各自的起始和末尾对齐。"),
+            ]))
+            .children(PlacementExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的一行触发元素
+fn row(items: Vec<Popconfirm>) -> View {
+    View::new()
+        .style(|s| s.display("flex").flex_wrap("wrap").gap("12px"))
+        .childrens(items)
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        row(vec![
+            Popconfirm::new("确定删除这条记录？")
+                .onconfirm(|_| {
+                    message::success("已删除");
+                })
+                .oncancel(|_| {
+                    message::info("已取消");
+                })
+                .children(Button::new().text("删除")),
+        ])
+        .into()
+    }
+}
+
+/// 自定义内容示例
+#[derive(Debug, Default, Clone)]
+struct CustomExample {}
+
+impl ToElement for CustomExample {
+    fn to_element(&self) -> Element {
+        row(vec![
+            Popconfirm::new("确定删除这个项目？")
+                .description("项目中的全部文件会一并删除，且无法恢复。")
+                .confirm_text("删除")
+                .confirm_type(ButtonType::Danger)
+                .onconfirm(|_| {
+                    message::success("项目已删除");
+                })
+                .children(Button::new().text("删除项目").btn_type(ButtonType::Danger)),
+            Popconfirm::new("确定发布到正式环境？")
+                .icon("?")
+                .confirm_text("发布")
+                .cancel_text("再想想")
+                .onconfirm(|_| {
+                    message::success("已发布");
+                })
+                .children(Button::new().text("发布")),
+            Popconfirm::new("确定退出登录？")
+                .icon("")
+                .placement(TooltipPlacement::Bottom)
+                .children(Button::new().text("退出登录")),
+        ])
+        .into()
+    }
+}
+
+/// 位置示例
+#[derive(Debug, Default, Clone)]
+struct PlacementExample {}
+
+impl ToElement for PlacementExample {
+    fn to_element(&self) -> Element {
+        let items = [
+            ("上方", TooltipPlacement::Top),
+            ("下方", TooltipPlacement::Bottom),
+            ("左侧", TooltipPlacement::Left),
+            ("右侧", TooltipPlacement::Right),
+            ("下方右对齐", TooltipPlacement::BottomEnd),
+        ];
+        row(items
+            .into_iter()
+            .map(|(text, placement)| {
+                Popconfirm::new("确定执行此操作？")
+                    .placement(placement)
+                    .children(Button::new().text(text))
+            })
+            .collect())
+        .into()
+    }
+}
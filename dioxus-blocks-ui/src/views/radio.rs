@@ -2,9 +2,13 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Radio, RadioGroup, RadioValue, Text, ToElement, View};
+use dioxus_blocks_components::{
+    Card, PropsTable, Radio, RadioGroup, RadioValue, Text, ToElement, View,
+};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct RadioView {}
 
@@ -21,7 +25,7 @@ impl RadioView {
     fn title(&self) -> View {
         View::new().childrens(vec![
             Text::h1("Radio 单选框"),
-            Text::p("在一组备选项中进行单选，支持基础用法、禁用状态、单选框组、带有边框和单选按钮等功能。"),
+            Text::p("在一组备选项中进行单选，支持基础用法、禁用状态、单选框组、带有边框、单选按钮和卡片样式等功能。"),
         ])
     }
 
@@ -30,15 +34,23 @@ impl RadioView {
             self.basic_usage(),
             self.disabled_state(),
             self.radio_group(),
+            self.options(),
             self.with_border(),
             self.radio_button(),
             self.different_sizes(),
+            self.vertical_description(),
+            self.card_style(),
+            api_reference(vec![
+                PropsTable::of::<Radio>().common(true),
+                PropsTable::of::<RadioGroup>(),
+            ]),
         ])
     }
 
     /// 基础用法
     fn basic_usage(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
                 Text::p("单选框不应该有太多的可选项，如果你有很多的可选项你应该使用选择框而不是单选框。"),
@@ -50,6 +62,7 @@ impl RadioView {
     /// 禁用状态
     fn disabled_state(&self) -> Card {
         Card::new()
+            .anchor("禁用状态")
             .header(View::new().childrens(vec![
                 Text::h2("禁用状态"),
                 Text::p("disabled 属性可以用来控制单选框的禁用状态。"),
@@ -61,6 +74,7 @@ impl RadioView {
     /// 单选框组
     fn radio_group(&self) -> Card {
         Card::new()
+            .anchor("单选框组")
             .header(View::new().childrens(vec![
                 Text::h2("单选框组"),
                 Text::p("适用于在多个互斥的选项中选择的场景。"),
@@ -69,9 +83,22 @@ impl RadioView {
             .style(|s| s.margin_top("32px"))
     }
 
+    /// 由数据生成选项
+    fn options(&self) -> Card {
+        Card::new()
+            .anchor("由数据生成选项")
+            .header(View::new().childrens(vec![
+                Text::h2("由数据生成选项"),
+                Text::p("options 由一组数据生成单选框，闭包返回每一项的值和标签元素，不需要手动循环构建 Radio。"),
+            ]))
+            .children(OptionsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
     /// 带有边框
     fn with_border(&self) -> Card {
         Card::new()
+            .anchor("带有边框")
             .header(View::new().childrens(vec![
                 Text::h2("带有边框"),
                 Text::p("设置 border 属性为 true 可以渲染为带有边框的单选框。"),
@@ -83,6 +110,7 @@ impl RadioView {
     /// 单选按钮
     fn radio_button(&self) -> Card {
         Card::new()
+            .anchor("单选按钮")
             .header(View::new().childrens(vec![
                 Text::h2("单选按钮"),
                 Text::p("带有按钮组视觉效果的单选框。"),
@@ -94,6 +122,7 @@ impl RadioView {
     /// 不同尺寸
     fn different_sizes(&self) -> Card {
         Card::new()
+            .anchor("不同尺寸")
             .header(View::new().childrens(vec![
                 Text::h2("不同尺寸"),
                 Text::p("提供大、中、小三种尺寸。"),
@@ -101,6 +130,30 @@ impl RadioView {
             .children(DifferentSizes::default())
             .style(|s| s.margin_top("32px"))
     }
+
+    /// 纵向排列与选项说明
+    fn vertical_description(&self) -> Card {
+        Card::new()
+            .anchor("纵向排列与选项说明")
+            .header(View::new().childrens(vec![
+                Text::h2("纵向排列与选项说明"),
+                Text::p("设置 vertical 后选项纵向排列，通过 description 在标签下方显示说明文字。"),
+            ]))
+            .children(VerticalDescription::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 卡片样式
+    fn card_style(&self) -> Card {
+        Card::new()
+            .anchor("卡片样式")
+            .header(View::new().childrens(vec![
+                Text::h2("卡片样式"),
+                Text::p("设置 card 后选项显示为带边框的卡片，常用于套餐、方案等选择。"),
+            ]))
+            .children(CardStyle::default())
+            .style(|s| s.margin_top("32px"))
+    }
 }
 
 /// 基础用法示例
@@ -364,6 +417,61 @@ impl ToElement for RadioGroupExample {
     }
 }
 
+/// 套餐
+struct Plan {
+    id: &'static str,
+    name: &'static str,
+    price: u32,
+}
+
+const PLANS: [Plan; 3] = [
+    Plan {
+        id: "basic",
+        name: "基础版",
+        price: 0,
+    },
+    Plan {
+        id: "pro",
+        name: "专业版",
+        price: 99,
+    },
+    Plan {
+        id: "team",
+        name: "团队版",
+        price: 299,
+    },
+];
+
+/// 由数据生成选项示例
+#[derive(Debug, Default, Clone)]
+pub struct OptionsExample {}
+
+impl ToElement for OptionsExample {
+    fn to_element(&self) -> Element {
+        let plan = use_signal(|| RadioValue::from("pro"));
+
+        View::new()
+            .style(|s| s.padding("20px"))
+            .children(
+                RadioGroup::new()
+                    .value(plan)
+                    .border(true)
+                    .options(&PLANS, |plan| {
+                        (
+                            plan.id,
+                            Text::span(format!("{}（¥{}/月）", plan.name, plan.price)),
+                        )
+                    }),
+            )
+            .children(
+                View::new()
+                    .style(|s| s.margin_top("16px"))
+                    .children(Text::new(format!("当前值: {}", plan.read()))),
+            )
+            .into()
+    }
+}
+
 /// 带有边框示例
 #[derive(Debug, Default, Clone)]
 pub struct WithBorder {}
@@ -685,3 +793,103 @@ impl ToElement for DifferentSizes {
             .into()
     }
 }
+
+/// 纵向排列与选项说明示例
+#[derive(Debug, Default, Clone)]
+pub struct VerticalDescription {}
+
+impl ToElement for VerticalDescription {
+    fn to_element(&self) -> Element {
+        let delivery = use_signal(|| RadioValue::from("standard"));
+
+        View::new()
+            .style(|s| s.padding("20px"))
+            .children(
+                RadioGroup::new()
+                    .value(delivery)
+                    .vertical(true)
+                    .radio(
+                        Radio::new()
+                            .value("standard")
+                            .label("标准配送")
+                            .description("3-5 个工作日送达，订单满 99 元免运费"),
+                    )
+                    .radio(
+                        Radio::new()
+                            .value("express")
+                            .label("加急配送")
+                            .description("次日送达，需额外支付 15 元"),
+                    )
+                    .radio(
+                        Radio::new()
+                            .value("pickup")
+                            .label("到店自提")
+                            .description("暂未开放")
+                            .disabled(true),
+                    ),
+            )
+            .into()
+    }
+}
+
+/// 卡片样式示例
+#[derive(Debug, Default, Clone)]
+pub struct CardStyle {}
+
+impl ToElement for CardStyle {
+    fn to_element(&self) -> Element {
+        let plan = use_signal(|| RadioValue::from("pro"));
+        let region = use_signal(|| RadioValue::from("cn"));
+
+        View::new()
+            .style(|s| {
+                s.padding("20px")
+                    .display("flex")
+                    .flex_direction("column")
+                    .gap("20px")
+            })
+            .children(
+                RadioGroup::new()
+                    .value(plan)
+                    .card(true)
+                    .radio(
+                        Radio::new()
+                            .value("free")
+                            .label("免费版 ¥0/月")
+                            .description("适合个人体验，最多 3 个项目"),
+                    )
+                    .radio(
+                        Radio::new()
+                            .value("pro")
+                            .label("专业版 ¥99/月")
+                            .description("不限项目数量，支持团队协作"),
+                    )
+                    .radio(
+                        Radio::new()
+                            .value("enterprise")
+                            .label("企业版")
+                            .description("私有化部署与专属技术支持"),
+                    ),
+            )
+            .children(
+                RadioGroup::new()
+                    .value(region)
+                    .card(true)
+                    .vertical(true)
+                    .style(|s| s.width("360px"))
+                    .radio(
+                        Radio::new()
+                            .value("cn")
+                            .label("华东 1（杭州）")
+                            .description("延迟最低，推荐国内用户使用"),
+                    )
+                    .radio(
+                        Radio::new()
+                            .value("sg")
+                            .label("新加坡")
+                            .description("适合东南亚用户"),
+                    ),
+            )
+            .into()
+    }
+}
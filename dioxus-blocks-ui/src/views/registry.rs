@@ -0,0 +1,49 @@
+//! 动态路由注册示例
+//!
+//! 这里的页面没有出现在 `Route` 枚举中，而是在 `main.rs` 中通过 [`RouteRegistry`](crate::RouteRegistry) 注册。
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Card, Link, Text, ToElement, View};
+
+use crate::RouteParams;
+
+/// 通过注册表添加的页面
+#[derive(Debug, Default, Clone)]
+pub struct RegistryView {}
+
+impl ToElement for RegistryView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(View::new().childrens(vec![
+                Text::h1("动态路由"),
+                Text::p("本页面在运行时注册，无需修改 Route 枚举，左侧菜单根据注册表自动生成。"),
+            ]))
+            .children(
+                Card::new()
+                    .anchor("路径参数")
+                    .header(View::new().childrens(vec![
+                        Text::h2("路径参数"),
+                        Text::p("注册路径中的 :name 片段会被解析为参数传给渲染函数。"),
+                    ]))
+                    .children(
+                        View::new()
+                            .style(|s| s.display("flex").gap("16px"))
+                            .children(Link::new("/registry/alpha").text("/registry/alpha"))
+                            .children(Link::new("/registry/beta").text("/registry/beta")),
+                    )
+                    .style(|s| s.margin_top("32px")),
+            )
+            .to_element()
+    }
+}
+
+/// 带路径参数的注册页面
+pub fn registry_detail(params: &RouteParams) -> Element {
+    let name = params.get("name").cloned().unwrap_or_default();
+    View::new()
+        .children(Text::h1(format!("动态页面：{}", name)))
+        .children(Text::p("参数来自注册路径 /registry/:name。"))
+        .children(Link::new("/registry").text("返回"))
+        .to_element()
+}
@@ -0,0 +1,208 @@
+//! SchemaForm 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, FormLabelPosition, FormSchema, JsonViewer, PropsTable, SchemaField, SchemaFieldType,
+    SchemaForm, SchemaFormValues, SchemaRule, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct SchemaFormView {}
+
+impl ToElement for SchemaFormView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl SchemaFormView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("SchemaForm 配置表单"),
+            Text::p(
+                "根据 FormSchema 描述的字段类型、标签、选项和校验规则在运行时生成完整的表单，表单结构可以由服务端以 JSON 下发。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.json_schema(),
+            self.builder(),
+            api_reference(vec![PropsTable::of::<SchemaForm>().common(true)]),
+        ])
+    }
+
+    /// JSON 配置
+    fn json_schema(&self) -> Card {
+        Card::new()
+            .anchor("JSON 配置")
+            .header(View::new().childrens(vec![
+                Text::h2("JSON 配置"),
+                Text::p(
+                    "FormSchema 实现了 serde 的反序列化，type 指定控件类型，rules 设置校验规则，visible_when 设置显示条件。提交时回调收到按字段顺序排列的值，隐藏的字段不包含在内。",
+                ),
+            ]))
+            .children(JsonExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 构建方法
+    fn builder(&self) -> Card {
+        Card::new()
+            .anchor("构建方法")
+            .header(View::new().childrens(vec![
+                Text::h2("构建方法"),
+                Text::p(
+                    "也可以在 Rust 中用构建方法创建配置。values 传入字段的初始值，覆盖配置中的默认值，适用于编辑已有数据。",
+                ),
+            ]))
+            .children(BuilderExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例使用的表单配置
+const SCHEMA: &str = r#"{
+    "fields": [
+        { "name": "schema-name", "label": "姓名", "required": true, "rules": [{ "min_len": 2, "max_len": 10 }] },
+        {
+            "name": "schema-email",
+            "label": "邮箱",
+            "placeholder": "name@example.com",
+            "rules": [{ "pattern": "^[^@\\s]+@[^@\\s]+$", "message": "请输入有效的邮箱地址" }]
+        },
+        { "name": "schema-age", "label": "年龄", "type": "number", "default": "18", "min": 0, "max": 120 },
+        {
+            "name": "schema-role",
+            "label": "角色",
+            "type": "select",
+            "required": true,
+            "options": [
+                { "value": "admin", "label": "管理员" },
+                { "value": "editor", "label": "编辑" },
+                { "value": "viewer", "label": "访客" }
+            ]
+        },
+        {
+            "name": "schema-source",
+            "label": "了解渠道",
+            "type": "radio",
+            "default": "search",
+            "options": [
+                { "value": "search", "label": "搜索引擎" },
+                { "value": "other", "label": "其他" }
+            ]
+        },
+        {
+            "name": "schema-source-other",
+            "label": "请说明",
+            "required": true,
+            "visible_when": { "field": "schema-source", "equals": "other" }
+        },
+        {
+            "name": "schema-topics",
+            "label": "关注领域",
+            "type": "checkbox_group",
+            "options": [
+                { "value": "frontend", "label": "前端" },
+                { "value": "backend", "label": "后端" },
+                { "value": "design", "label": "设计" }
+            ]
+        },
+        { "name": "schema-agree", "label": "", "type": "checkbox", "placeholder": "订阅更新", "default": true }
+    ]
+}"#;
+
+/// 提交结果的 JSON
+fn values_json(values: &SchemaFormValues) -> serde_json::Value {
+    serde_json::Value::Object(
+        values
+            .iter()
+            .map(|(name, value)| {
+                (
+                    name.clone(),
+                    serde_json::to_value(value).unwrap_or_default(),
+                )
+            })
+            .collect(),
+    )
+}
+
+/// JSON 配置示例
+#[derive(Debug, Default, Clone)]
+struct JsonExample {}
+
+impl ToElement for JsonExample {
+    fn to_element(&self) -> Element {
+        let schema = use_hook(|| serde_json::from_str::<FormSchema>(SCHEMA).unwrap_or_default());
+        let mut submitted = use_signal(|| None::<SchemaFormValues>);
+
+        let mut view = View::new().children(
+            SchemaForm::new(schema)
+                .style(|s| s.max_width("520px"))
+                .onsubmit(move |values| submitted.set(Some(values))),
+        );
+        if let Some(values) = submitted() {
+            view = view
+                .children(JsonViewer::new(values_json(&values)).style(|s| s.margin_top("16px")));
+        }
+        view.into()
+    }
+}
+
+/// 构建方法示例
+#[derive(Debug, Default, Clone)]
+struct BuilderExample {}
+
+impl ToElement for BuilderExample {
+    fn to_element(&self) -> Element {
+        let mut saved = use_signal(|| "尚未保存".to_string());
+
+        let schema = FormSchema::new()
+            .field(
+                SchemaField::new("builder-title", "标题")
+                    .required(true)
+                    .rule(SchemaRule::max_len(20)),
+            )
+            .field(
+                SchemaField::new("builder-summary", "摘要")
+                    .kind(SchemaFieldType::Textarea)
+                    .placeholder("选填"),
+            )
+            .field(
+                SchemaField::new("builder-publish", "发布时间")
+                    .kind(SchemaFieldType::Time)
+                    .required(true),
+            );
+        let values = SchemaFormValues::from([
+            ("builder-title".to_string(), "发布说明".into()),
+            ("builder-publish".to_string(), "09:00:00".into()),
+        ]);
+
+        View::new()
+            .children(
+                SchemaForm::new(schema)
+                    .values(values)
+                    .label_position(FormLabelPosition::Top)
+                    .submit_text("保存")
+                    .style(|s| s.max_width("420px"))
+                    .onsubmit(move |values| {
+                        saved.set(format!("已保存 {} 个字段", values.len()));
+                    }),
+            )
+            .children(Text::span(saved()).style(|s| {
+                s.display("block")
+                    .margin_top("12px")
+                    .color("var(--t-text-color-secondary)")
+            }))
+            .into()
+    }
+}
@@ -0,0 +1,116 @@
+//! SearchInput 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, PropsTable, SearchInput, SearchQuery, Text, ToElement, View, use_server_action,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+/// 示例数据，元素为 (范围, 标题)
+const DOCUMENTS: [(&str, &str); 6] = [
+    ("docs", "快速开始"),
+    ("docs", "主题定制"),
+    ("components", "Button 按钮"),
+    ("components", "Input 输入框"),
+    ("components", "SearchInput 搜索框"),
+    ("blog", "Dioxus 0.7 发布"),
+];
+
+/// 模拟的搜索接口
+async fn fake_search(query: SearchQuery) -> Result<Vec<String>, String> {
+    dioxus_blocks_components::browser::sleep(600).await;
+    let keyword = query.query.to_lowercase();
+    Ok(DOCUMENTS
+        .iter()
+        .filter(|(scope, _)| {
+            query
+                .scope
+                .as_deref()
+                .is_none_or(|s| s == "all" || s == *scope)
+        })
+        .filter(|(_, title)| title.to_lowercase().contains(&keyword))
+        .map(|(_, title)| title.to_string())
+        .collect())
+}
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct SearchInputView {}
+
+impl ToElement for SearchInputView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl SearchInputView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("SearchInput 搜索框"),
+            Text::p("带搜索范围、加载状态和最近搜索记录的搜索框。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            api_reference(vec![PropsTable::of::<SearchInput>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "按回车或点击按钮搜索，搜索期间按钮显示加载状态，页面顶部显示进度条。设置 history_key 后搜索记录保存在全局配置中，输入框获得焦点时显示。",
+                ),
+            ]))
+            .children(SearchExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 搜索示例
+#[derive(Debug, Default, Clone)]
+struct SearchExample {}
+
+impl ToElement for SearchExample {
+    fn to_element(&self) -> Element {
+        let keyword = use_signal(String::new);
+        let action = use_server_action(fake_search).with_progress();
+
+        let results = match action.value() {
+            None => vec![Text::p("输入关键字后开始搜索")],
+            Some(results) if results.is_empty() => vec![Text::p("没有找到相关内容")],
+            Some(results) => results.into_iter().map(Text::p).collect(),
+        };
+
+        View::new()
+            .style(|s| s.max_width("560px"))
+            .children(
+                SearchInput::new()
+                    .value(keyword)
+                    .scope_option("all", "全部")
+                    .scope_option("docs", "文档")
+                    .scope_option("components", "组件")
+                    .scope_option("blog", "博客")
+                    .history_key("demo")
+                    .loading(action.pending())
+                    .onsearch(move |query| action.dispatch(query)),
+            )
+            .children(
+                View::new()
+                    .style(|s| s.margin_top("16px").color("var(--t-text-color-secondary)"))
+                    .childrens(results),
+            )
+            .to_element()
+    }
+}
@@ -2,9 +2,14 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Select, SelectOption, SelectSize, SelectValue, Text, ToElement, View};
+use dioxus_blocks_components::{
+    Card, PropsTable, Select, SelectOption, SelectOptionGroup, SelectSize, SelectValue, Text,
+    ToElement, View,
+};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct SelectView {}
 
@@ -28,42 +33,42 @@ impl SelectView {
     fn content(&self) -> View {
         View::new().childrens(vec![
             self.basic_usage(),
-            self.disabled_options(),
             self.disabled_state(),
             self.clearable(),
             self.different_sizes(),
+            self.multiple(),
+            self.collapse_tags(),
             self.filterable(),
+            self.option_groups(),
+            api_reference(vec![
+                PropsTable::of::<Select>().common(true),
+                PropsTable::of::<SelectOption>(),
+                PropsTable::of::<SelectOptionGroup>(),
+            ]),
         ])
     }
 
     /// 基础用法
     fn basic_usage(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
-                Text::p("适用广泛的基础单选，支持 String、Int、Float、Bool 多种类型。"),
+                Text::p(
+                    "当前值由 Signal<Option<SelectValue>> 控制，支持 String、Int、Float、Bool 多种类型。获得焦点后按上下方向键切换选项，按 Enter 选择，按 Escape 关闭。",
+                ),
             ]))
             .children(BasicUsage::default())
             .style(|s| s.margin_top("32px"))
     }
 
-    /// 有禁用选项
-    fn disabled_options(&self) -> Card {
-        Card::new()
-            .header(View::new().childrens(vec![
-                Text::h2("有禁用选项"),
-                Text::p("在 `SelectOption` 中设置 `disabled` 属性来禁用该选项。"),
-            ]))
-            .children(DisabledOptions::default())
-            .style(|s| s.margin_top("32px"))
-    }
-
     /// 禁用状态
     fn disabled_state(&self) -> Card {
         Card::new()
+            .anchor("禁用状态")
             .header(View::new().childrens(vec![
                 Text::h2("禁用状态"),
-                Text::p("选择器本身不可用。"),
+                Text::p("在 SelectOption 中设置 disabled 禁用单个选项，在 Select 中设置 disabled 禁用整个选择器。"),
             ]))
             .children(DisabledState::default())
             .style(|s| s.margin_top("32px"))
@@ -72,6 +77,7 @@ impl SelectView {
     /// 可清空
     fn clearable(&self) -> Card {
         Card::new()
+            .anchor("可清空")
             .header(View::new().childrens(vec![
                 Text::h2("可清空"),
                 Text::p("包含清空按钮，可将选择器清空为初始状态。"),
@@ -83,6 +89,7 @@ impl SelectView {
     /// 不同尺寸
     fn different_sizes(&self) -> Card {
         Card::new()
+            .anchor("不同尺寸")
             .header(View::new().childrens(vec![
                 Text::h2("不同尺寸"),
                 Text::p("提供大、中、小三种尺寸。"),
@@ -91,130 +98,123 @@ impl SelectView {
             .style(|s| s.margin_top("32px"))
     }
 
+    /// 多选
+    fn multiple(&self) -> Card {
+        Card::new()
+            .anchor("多选")
+            .header(View::new().childrens(vec![
+                Text::h2("多选"),
+                Text::p("通过 multiple_value 传入 Signal<Vec<SelectValue>> 开启多选，已选项以标签显示，点击标签上的 × 移除。"),
+            ]))
+            .children(MultipleExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 折叠标签
+    fn collapse_tags(&self) -> Card {
+        Card::new()
+            .anchor("折叠标签")
+            .header(View::new().childrens(vec![
+                Text::h2("折叠标签"),
+                Text::p("设置 collapse_tags 后超出 max_collapse_tags 的已选项合并为 +N 标签，鼠标移入时提示被折叠的选项。"),
+            ]))
+            .children(CollapseTagsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
     /// 可筛选
     fn filterable(&self) -> Card {
         Card::new()
+            .anchor("可筛选")
             .header(View::new().childrens(vec![
                 Text::h2("可筛选"),
-                Text::p("利用搜索功能快速查找选项。"),
+                Text::p("输入关键字按标签筛选选项，多选时在输入框为空时按 Backspace 删除最后一个已选项。"),
             ]))
-            .children(Filterable::default())
+            .children(FilterableExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 选项分组
+    fn option_groups(&self) -> Card {
+        Card::new()
+            .anchor("选项分组")
+            .header(View::new().childrens(vec![
+                Text::h2("选项分组"),
+                Text::p("使用 SelectOptionGroup 对选项分组，可以禁用整个分组。"),
+            ]))
+            .children(GroupExample::default())
             .style(|s| s.margin_top("32px"))
     }
 }
 
-/// 基础用法示例
-#[derive(Debug, Default, Clone)]
-pub struct BasicUsage {}
+/// 示例行：说明文字、选择器和当前值
+fn example_row(title: &str, select: Select, current: String) -> View {
+    View::new()
+        .style(|s| s.display("flex").align_items("center").gap("16px"))
+        .children(Text::new(title).style(|s| s.width("120px")))
+        .children(View::new().style(|s| s.width("240px")).children(select))
+        .children(Text::new(current).style(|s| s.color("var(--t-text-color-secondary)")))
+}
 
-impl ToElement for BasicUsage {
-    fn to_element(&self) -> Element {
-        let mut value_string = use_signal(|| SelectValue::String("选项1".to_string()));
-        let mut value_int = use_signal(|| SelectValue::Int(1));
-        let mut value_float = use_signal(|| SelectValue::Float(1.5));
+/// 示例列表的容器
+fn examples(rows: Vec<View>) -> View {
+    View::new()
+        .style(|s| {
+            s.padding("20px")
+                .display("flex")
+                .flex_direction("column")
+                .gap("24px")
+        })
+        .childrens(rows)
+}
 
-        View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("24px")
-            })
-            // String 类型
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("String 类型: "))
-                    .children(
-                        Select::new()
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange(move |v| value_string.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value_string.read()
-                    ))),
-            )
-            // Int 类型
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("Int 类型: "))
-                    .children(
-                        Select::new()
-                            .options(vec![
-                                SelectOption::new(1),
-                                SelectOption::new(2),
-                                SelectOption::new(3),
-                            ])
-                            .onchange(move |v| value_int.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value_int.read()
-                    ))),
-            )
-            // Float 类型
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("Float 类型: "))
-                    .children(
-                        Select::new()
-                            .options(vec![
-                                SelectOption::new(1.5),
-                                SelectOption::new(2.5),
-                                SelectOption::new(3.5),
-                            ])
-                            .onchange(move |v| value_float.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value_float.read()
-                    ))),
-            )
-            .into()
-    }
+/// 城市选项
+fn cities() -> Vec<SelectOption> {
+    vec![
+        SelectOption::new("beijing").label("北京"),
+        SelectOption::new("shanghai").label("上海"),
+        SelectOption::new("guangzhou").label("广州"),
+        SelectOption::new("shenzhen").label("深圳"),
+        SelectOption::new("hangzhou").label("杭州"),
+    ]
 }
 
-/// 有禁用选项示例
+/// 基础用法示例
 #[derive(Debug, Default, Clone)]
-pub struct DisabledOptions {}
+pub struct BasicUsage {}
 
-impl ToElement for DisabledOptions {
+impl ToElement for BasicUsage {
     fn to_element(&self) -> Element {
-        let mut value = use_signal(|| SelectValue::String("选项1".to_string()));
+        let value_string = use_signal(|| Some(SelectValue::from("beijing")));
+        let value_int = use_signal(|| Some(SelectValue::Int(1)));
+        let value_float = use_signal(|| None::<SelectValue>);
 
-        View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("24px")
-            })
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("部分选项禁用: "))
-                    .children(
-                        Select::new()
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2").disabled(true),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange(move |v| value.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value.read()
-                    ))),
-            )
-            .into()
+        examples(vec![
+            example_row(
+                "String 类型",
+                Select::new().value(value_string).options(cities()),
+                format!("{:?}", value_string.read()),
+            ),
+            example_row(
+                "Int 类型",
+                Select::new().value(value_int).options(vec![
+                    SelectOption::new(1).label("一级"),
+                    SelectOption::new(2).label("二级"),
+                    SelectOption::new(3).label("三级"),
+                ]),
+                format!("{:?}", value_int.read()),
+            ),
+            example_row(
+                "Float 类型",
+                Select::new().value(value_float).options(vec![
+                    SelectOption::new(0.5),
+                    SelectOption::new(1.0),
+                    SelectOption::new(1.5),
+                ]),
+                format!("{:?}", value_float.read()),
+            ),
+        ])
+        .into()
     }
 }
 
@@ -224,54 +224,26 @@ pub struct DisabledState {}
 
 impl ToElement for DisabledState {
     fn to_element(&self) -> Element {
-        let mut value1 = use_signal(|| SelectValue::String("选项1".to_string()));
-        let mut value2 = use_signal(|| SelectValue::String("选项1".to_string()));
+        let value1 = use_signal(|| None::<SelectValue>);
+        let value2 = use_signal(|| Some(SelectValue::from("beijing")));
 
-        View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("24px")
-            })
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("正常状态: "))
-                    .children(
-                        Select::new()
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange(move |v| value1.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value1.read()
-                    ))),
-            )
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("禁用状态: "))
-                    .children(
-                        Select::new()
-                            .disabled(true)
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange(move |v| value2.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value2.read()
-                    ))),
-            )
-            .into()
+        examples(vec![
+            example_row(
+                "部分选项禁用",
+                Select::new().value(value1).options(vec![
+                    SelectOption::new("beijing").label("北京"),
+                    SelectOption::new("shanghai").label("上海").disabled(true),
+                    SelectOption::new("guangzhou").label("广州"),
+                ]),
+                format!("{:?}", value1.read()),
+            ),
+            example_row(
+                "禁用选择器",
+                Select::new().value(value2).disabled(true).options(cities()),
+                format!("{:?}", value2.read()),
+            ),
+        ])
+        .into()
     }
 }
 
@@ -281,38 +253,19 @@ pub struct ClearableExample {}
 
 impl ToElement for ClearableExample {
     fn to_element(&self) -> Element {
-        let mut value = use_signal(|| SelectValue::String("选项1".to_string()));
+        let value = use_signal(|| Some(SelectValue::from("shanghai")));
+        let mut cleared = use_signal(|| 0);
 
-        View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("24px")
-            })
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("可清空: "))
-                    .children(
-                        Select::new()
-                            .clearable(true)
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange(move |v| value.set(v))
-                            .onclear(move |_| {
-                                value.set(SelectValue::String(String::new()));
-                            }),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value.read()
-                    ))),
-            )
-            .into()
+        examples(vec![example_row(
+            "可清空",
+            Select::new()
+                .value(value)
+                .clearable(true)
+                .options(cities())
+                .onclear(move |_| cleared += 1),
+            format!("{:?}，已清空 {} 次", value.read(), cleared()),
+        )])
+        .into()
     }
 }
 
@@ -322,121 +275,151 @@ pub struct DifferentSizes {}
 
 impl ToElement for DifferentSizes {
     fn to_element(&self) -> Element {
-        let mut value_small = use_signal(|| SelectValue::String("选项1".to_string()));
-        let mut value_medium = use_signal(|| SelectValue::String("选项1".to_string()));
-        let mut value_large = use_signal(|| SelectValue::String("选项1".to_string()));
+        let value = use_signal(|| Some(SelectValue::from("beijing")));
 
-        View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("24px")
+        examples(
+            [
+                ("大尺寸", SelectSize::Large),
+                ("默认尺寸", SelectSize::Medium),
+                ("小尺寸", SelectSize::Small),
+            ]
+            .into_iter()
+            .map(|(title, size)| {
+                example_row(
+                    title,
+                    Select::new().value(value).size(size).options(cities()),
+                    String::new(),
+                )
             })
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("小尺寸: "))
-                    .children(
-                        Select::new()
-                            .size(SelectSize::Small)
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange2(move |v| value_small.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value_small.read()
-                    ))),
-            )
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("中等尺寸: "))
-                    .children(
-                        Select::new()
-                            .size(SelectSize::Medium)
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange2(move |v| value_medium.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value_medium.read()
-                    ))),
-            )
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("大尺寸: "))
-                    .children(
-                        Select::new()
-                            .size(SelectSize::Large)
-                            .options(vec![
-                                SelectOption::new("选项1"),
-                                SelectOption::new("选项2"),
-                                SelectOption::new("选项3"),
-                            ])
-                            .onchange2(move |v| value_large.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value_large.read()
-                    ))),
-            )
-            .into()
+            .collect(),
+        )
+        .into()
+    }
+}
+
+/// 多选示例
+#[derive(Debug, Default, Clone)]
+pub struct MultipleExample {}
+
+impl ToElement for MultipleExample {
+    fn to_element(&self) -> Element {
+        let values =
+            use_signal(|| vec![SelectValue::from("beijing"), SelectValue::from("shanghai")]);
+
+        examples(vec![example_row(
+            "多选",
+            Select::new()
+                .multiple_value(values)
+                .clearable(true)
+                .options(cities()),
+            format!("已选 {} 项", values.read().len()),
+        )])
+        .into()
+    }
+}
+
+/// 折叠标签示例
+#[derive(Debug, Default, Clone)]
+pub struct CollapseTagsExample {}
+
+impl ToElement for CollapseTagsExample {
+    fn to_element(&self) -> Element {
+        let values = use_signal(|| {
+            vec![
+                SelectValue::from("beijing"),
+                SelectValue::from("shanghai"),
+                SelectValue::from("guangzhou"),
+                SelectValue::from("shenzhen"),
+            ]
+        });
+
+        examples(vec![
+            example_row(
+                "折叠标签",
+                Select::new()
+                    .multiple_value(values)
+                    .collapse_tags(true)
+                    .options(cities()),
+                format!("已选 {} 项", values.read().len()),
+            ),
+            example_row(
+                "最多显示 2 个",
+                Select::new()
+                    .multiple_value(values)
+                    .collapse_tags(true)
+                    .max_collapse_tags(2)
+                    .filterable(true)
+                    .options(cities()),
+                String::new(),
+            ),
+        ])
+        .into()
     }
 }
 
 /// 可筛选示例
 #[derive(Debug, Default, Clone)]
-pub struct Filterable {}
+pub struct FilterableExample {}
 
-impl ToElement for Filterable {
+impl ToElement for FilterableExample {
     fn to_element(&self) -> Element {
-        let mut value = use_signal(|| SelectValue::String("选项1".to_string()));
-
-        let options = vec![
-            SelectOption::new("选项1"),
-            SelectOption::new("选项2"),
-            SelectOption::new("选项3"),
-            SelectOption::new("选项4"),
-            SelectOption::new("选项5"),
-            SelectOption::new("选项6"),
-            SelectOption::new("选项7"),
-            SelectOption::new("选项8"),
-            SelectOption::new("选项9"),
-            SelectOption::new("选项10"),
-        ];
+        let value = use_signal(|| None::<SelectValue>);
+        let values = use_signal(Vec::<SelectValue>::new);
 
-        View::new()
-            .style(|s| {
-                s.padding("20px")
-                    .display("flex")
-                    .flex_direction("column")
-                    .gap("24px")
-            })
-            .children(
-                View::new()
-                    .style(|s| s.display("flex").align_items("center").gap("16px"))
-                    .children(Text::new("可筛选: "))
-                    .children(
-                        Select::new()
-                            .filterable(true)
-                            .options(options)
-                            .onchange(move |v| value.set(v)),
-                    )
-                    .children(Text::new(format!(
-                        "{:?}",
-                        value.read()
-                    ))),
-            )
-            .into()
+        examples(vec![
+            example_row(
+                "单选筛选",
+                Select::new()
+                    .value(value)
+                    .filterable(true)
+                    .placeholder("输入城市名称")
+                    .options(cities()),
+                format!("{:?}", value.read()),
+            ),
+            example_row(
+                "多选筛选",
+                Select::new()
+                    .multiple_value(values)
+                    .filterable(true)
+                    .options(cities()),
+                format!("已选 {} 项", values.read().len()),
+            ),
+        ])
+        .into()
+    }
+}
+
+/// 选项分组示例
+#[derive(Debug, Default, Clone)]
+pub struct GroupExample {}
+
+impl ToElement for GroupExample {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| None::<SelectValue>);
+
+        examples(vec![example_row(
+            "选项分组",
+            Select::new()
+                .value(value)
+                .filterable(true)
+                .group(SelectOptionGroup::new("热门城市").options(vec![
+                    SelectOption::new("beijing").label("北京"),
+                    SelectOption::new("shanghai").label("上海"),
+                ]))
+                .group(SelectOptionGroup::new("华南").options(vec![
+                    SelectOption::new("guangzhou").label("广州"),
+                    SelectOption::new("shenzhen").label("深圳"),
+                ]))
+                .group(
+                    SelectOptionGroup::new("暂未开通")
+                        .disabled(true)
+                        .options(vec![
+                            SelectOption::new("lhasa").label("拉萨"),
+                            SelectOption::new("urumqi").label("乌鲁木齐"),
+                        ]),
+                ),
+            format!("{:?}", value.read()),
+        )])
+        .into()
     }
 }
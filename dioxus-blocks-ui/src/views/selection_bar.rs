@@ -0,0 +1,196 @@
+//! SelectionBar 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, PropsTable, SelectionBar, Table, TableColumn, TableRow, Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+/// 服务端分页示例的总记录数
+const TOTAL: u64 = 23;
+/// 服务端分页示例每页的行数
+const PAGE_SIZE: u64 = 5;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct SelectionBarView {}
+
+impl ToElement for SelectionBarView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl SelectionBarView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("SelectionBar 批量操作栏"),
+            Text::p(
+                "表格有选中的行时显示选中数量和批量操作按钮，适用于批量删除、导出和修改状态等场景。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.server_side(),
+            api_reference(vec![PropsTable::of::<SelectionBar>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "SelectionBar 与 Table::selection 共用同一个选中行信号，选中行后显示操作栏，取消全部选择后自动隐藏。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 服务端分页
+    fn server_side(&self) -> Card {
+        Card::new()
+            .anchor("选择全部页")
+            .header(View::new().childrens(vec![
+                Text::h2("选择全部页"),
+                Text::p(
+                    "服务端分页时传入当前页的行和全部记录数，当前页全部选中后可以选择全部页，批量操作根据 all_pages 决定作用范围。",
+                ),
+            ]))
+            .children(ServerSideExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 生成一页用户数据
+fn users(page: u64) -> Vec<TableRow> {
+    let start = page * PAGE_SIZE;
+    (start..(start + PAGE_SIZE).min(TOTAL))
+        .map(|index| {
+            TableRow::new(format!("{}", index + 1))
+                .cell("name", format!("用户 {}", index + 1))
+                .cell("email", format!("user{}@example.com", index + 1))
+        })
+        .collect()
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let mut rows = use_signal(|| users(0));
+        let mut selection = use_signal(Vec::<String>::new);
+
+        View::new()
+            .children(
+                SelectionBar::new(selection)
+                    .action(Button::new().text("导出"))
+                    .action(Button::new().text("删除").as_danger().onclick(move |_| {
+                        let selected = selection();
+                        rows.write()
+                            .retain(|row| !selected.iter().any(|key| key == row.key()));
+                        selection.write().clear();
+                    })),
+            )
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "姓名").width("160px"),
+                        TableColumn::new("email", "邮箱"),
+                    ])
+                    .rows(rows())
+                    .selection(selection),
+            )
+            .into()
+    }
+}
+
+/// 服务端分页示例
+#[derive(Debug, Default, Clone)]
+struct ServerSideExample {}
+
+impl ToElement for ServerSideExample {
+    fn to_element(&self) -> Element {
+        let mut page = use_signal(|| 0u64);
+        let mut selection = use_signal(Vec::<String>::new);
+        let mut all_pages = use_signal(|| false);
+        let mut status = use_signal(|| "尚未操作".to_string());
+
+        let rows = users(page());
+        let page_keys = rows
+            .iter()
+            .map(|row| row.key().to_string())
+            .collect::<Vec<_>>();
+        let pages = TOTAL.div_ceil(PAGE_SIZE);
+
+        View::new()
+            .children(
+                SelectionBar::new(selection)
+                    .page_keys(page_keys)
+                    .total(TOTAL)
+                    .all_pages(all_pages)
+                    .action(Button::new().text("批量禁用").onclick(move |_| {
+                        let message = if all_pages() {
+                            format!("禁用全部 {TOTAL} 个用户")
+                        } else {
+                            format!("禁用用户 {}", selection.read().join("、"))
+                        };
+                        status.set(message);
+                    })),
+            )
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "姓名").width("160px"),
+                        TableColumn::new("email", "邮箱"),
+                    ])
+                    .rows(rows)
+                    .selection(selection),
+            )
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .align_items("center")
+                            .gap("12px")
+                            .margin_top("16px")
+                    })
+                    .children(Button::new().text("上一页").disabled(page() == 0).onclick(
+                        move |_| {
+                            page -= 1;
+                            selection.write().clear();
+                            all_pages.set(false);
+                        },
+                    ))
+                    .children(Text::span(format!("第 {} / {pages} 页", page() + 1)))
+                    .children(
+                        Button::new()
+                            .text("下一页")
+                            .disabled(page() + 1 >= pages)
+                            .onclick(move |_| {
+                                page += 1;
+                                selection.write().clear();
+                                all_pages.set(false);
+                            }),
+                    ),
+            )
+            .children(
+                Text::p(format!("最近操作：{}", status()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
@@ -0,0 +1,63 @@
+//! 设置页面模板示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Card, Input, Text, ToElement, View, use_settings};
+use dioxus_blocks_macro::Route;
+
+use crate::templates::SettingsPage;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct SettingsView {}
+
+impl ToElement for SettingsView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .style(|s| s.display("flex").flex_direction("column").gap("24px"))
+            .children(
+                SettingsPage::new()
+                    .subtitle("修改后主题和组件密度会立即作用于整个应用，包括顶部导航和侧边栏"),
+            )
+            .children(Preview {})
+            .to_element()
+    }
+}
+
+/// 展示当前配置效果的预览区域
+#[derive(Debug, Clone)]
+struct Preview {}
+
+impl ToElement for Preview {
+    fn to_element(&self) -> Element {
+        let settings = use_settings();
+        let value = use_signal(String::new);
+        let current = settings.read().clone();
+
+        Card::new()
+            .header(Text::h3("效果预览").style(|s| s.margin("0")))
+            .style(|s| s.max_width("800px").margin("0 auto").width("100%"))
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").gap("12px").flex_wrap("wrap"))
+                    .children(Button::new().text("主要按钮").as_primary())
+                    .children(Button::new().text("默认按钮"))
+                    .children(Button::new().text("朴素按钮").as_plain())
+                    .children(Input::new().value(value).placeholder("输入框")),
+            )
+            .children(
+                Text::p(format!(
+                    "当前配置：主题 {:?}，密度 {:?}，语言 {}，通知{}",
+                    current.theme,
+                    current.density,
+                    current.locale,
+                    if current.notifications {
+                        "已开启"
+                    } else {
+                        "已关闭"
+                    }
+                ))
+                .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .to_element()
+    }
+}
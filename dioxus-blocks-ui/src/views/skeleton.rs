@@ -0,0 +1,168 @@
+//! Skeleton 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, PropsTable, Skeleton, SkeletonBoundary, Text, ToElement, View, browser,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct SkeletonView {}
+
+impl ToElement for SkeletonView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl SkeletonView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Skeleton 骨架屏"),
+            Text::p("在内容加载期间显示页面结构的占位，避免加载时页面空白闪烁。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.loading_state(),
+            self.suspense(),
+            api_reference(vec![
+                PropsTable::of::<Skeleton>().common(true),
+                PropsTable::of::<SkeletonBoundary>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p("默认显示标题和三行段落，可以设置行数、头像占位和是否显示闪烁动画。"),
+            ]))
+            .childrens(vec![
+                Skeleton::new(),
+                Skeleton::new()
+                    .avatar(true)
+                    .rows(2)
+                    .style(|s| s.margin_top("24px")),
+                Skeleton::new()
+                    .title(false)
+                    .animated(false)
+                    .style(|s| s.margin_top("24px")),
+            ])
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 加载状态
+    fn loading_state(&self) -> Card {
+        Card::new()
+            .anchor("加载状态")
+            .header(View::new().childrens(vec![
+                Text::h2("加载状态"),
+                Text::p("loading 为 false 时渲染子元素，适合由组件自己管理加载状态的场景。"),
+            ]))
+            .children(LoadingExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 异步加载边界
+    fn suspense(&self) -> Card {
+        Card::new()
+            .anchor("异步加载边界")
+            .header(View::new().childrens(vec![
+                Text::h2("异步加载边界"),
+                Text::p(
+                    "SkeletonBoundary 中的组件通过 use_resource(..).suspend()? 等待数据，完成前显示骨架屏。本站的布局用它包裹页面，懒加载的页面加载期间显示页面骨架屏。",
+                ),
+            ]))
+            .children(SuspenseExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 加载状态示例
+#[derive(Debug, Default, Clone)]
+struct LoadingExample {}
+
+impl ToElement for LoadingExample {
+    fn to_element(&self) -> Element {
+        let mut loading = use_signal(|| true);
+
+        View::new()
+            .children(
+                Button::new()
+                    .text(if loading() {
+                        "加载完成"
+                    } else {
+                        "重新加载"
+                    })
+                    .onclick(move |_| loading.toggle()),
+            )
+            .children(
+                Skeleton::new()
+                    .avatar(true)
+                    .loading(loading())
+                    .style(|s| s.margin_top("16px"))
+                    .children(
+                        View::new()
+                            .style(|s| s.margin_top("16px"))
+                            .children(Text::h3("张三"))
+                            .children(Text::p("前端工程师，负责组件库和设计系统的维护。")),
+                    ),
+            )
+            .into()
+    }
+}
+
+/// 异步加载边界示例
+#[derive(Debug, Default, Clone)]
+struct SuspenseExample {}
+
+impl ToElement for SuspenseExample {
+    fn to_element(&self) -> Element {
+        let mut round = use_signal(|| 0u32);
+
+        View::new()
+            .children(Button::new().text("重新加载").onclick(move |_| round += 1))
+            .children(
+                View::new().style(|s| s.margin_top("16px")).children(
+                    SkeletonBoundary::new()
+                        .fallback(Skeleton::new().rows(2))
+                        .children(Profile { round: round() }),
+                ),
+            )
+            .into()
+    }
+}
+
+/// 模拟从接口加载的用户资料
+#[derive(Debug, Clone)]
+struct Profile {
+    round: u32,
+}
+
+impl ToElement for Profile {
+    fn to_element(&self) -> Element {
+        let round = self.round;
+        let profile = use_resource(use_reactive!(|round| async move {
+            browser::sleep(1500).await;
+            format!("第 {} 次加载的用户资料", round + 1)
+        }));
+        let profile = profile.suspend()?.read().clone();
+
+        View::new()
+            .children(Text::h3("张三"))
+            .children(Text::p(profile))
+            .into()
+    }
+}
@@ -0,0 +1,142 @@
+//! Spin 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, PropsTable, Spin, SpinSize, Text, ToElement, View, browser,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct SpinView {}
+
+impl ToElement for SpinView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl SpinView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Spin 加载中"),
+            Text::p(
+                "在等待异步操作时显示加载图标。包裹内容时以遮罩覆盖内容并保留已显示的数据，适合刷新表格、卡片等区域。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.tip(),
+            self.nested(),
+            api_reference(vec![PropsTable::of::<Spin>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p("没有子元素时单独显示加载图标，提供小、中、大三种尺寸。"),
+            ]))
+            .children(
+                row()
+                    .children(Spin::new().size(SpinSize::Small))
+                    .children(Spin::new())
+                    .children(Spin::new().size(SpinSize::Large)),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 提示文字
+    fn tip(&self) -> Card {
+        Card::new()
+            .anchor("提示文字")
+            .header(View::new().childrens(vec![
+                Text::h2("提示文字"),
+                Text::p("tip 在加载图标下方显示提示文字，同时作为读屏软件读出的状态。"),
+            ]))
+            .children(
+                row()
+                    .children(Spin::new().as_small().tip("加载中"))
+                    .children(Spin::new().tip("正在同步数据…"))
+                    .children(Spin::new().as_large().tip("正在生成报表…")),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 包裹内容
+    fn nested(&self) -> Card {
+        Card::new()
+            .anchor("包裹内容")
+            .header(View::new().childrens(vec![
+                Text::h2("包裹内容"),
+                Text::p(
+                    "包裹子元素时，loading 为 true 期间在内容上方覆盖遮罩并居中显示加载图标，内容不能交互。加载结束后内容不会重新创建，其中的状态得以保留。",
+                ),
+            ]))
+            .children(NestedExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例中的一行内容
+fn row() -> View {
+    View::new().style(|s| s.display("flex").align_items("flex-end").gap("48px"))
+}
+
+/// 包裹内容示例
+#[derive(Debug, Default, Clone)]
+struct NestedExample {}
+
+impl ToElement for NestedExample {
+    fn to_element(&self) -> Element {
+        let mut loading = use_signal(|| false);
+        let mut round = use_signal(|| 1u32);
+
+        let refresh = move |_| {
+            if loading() {
+                return;
+            }
+            loading.set(true);
+            spawn(async move {
+                browser::sleep(1500).await;
+                round += 1;
+                loading.set(false);
+            });
+        };
+
+        View::new()
+            .children(
+                Button::new()
+                    .text("刷新")
+                    .loading(loading())
+                    .onclick(refresh),
+            )
+            .children(
+                Spin::new()
+                    .loading(loading())
+                    .tip("刷新中…")
+                    .style(|s| s.margin_top("16px").max_width("480px"))
+                    .children(
+                        Card::new()
+                            .children(Text::h3("本月销售额"))
+                            .children(Text::p(format!(
+                                "第 {} 次刷新：¥ {}",
+                                round(),
+                                128_000 + round() * 3_600
+                            ))),
+                    ),
+            )
+            .into()
+    }
+}
@@ -0,0 +1,91 @@
+//! 组件用例
+//!
+//! 按组件分组列出 [`stories`] 模块中登记的全部用例，每个用例直接渲染在网格中，
+//! 不使用 iframe，便于一次检查组件在各种状态下的样式。
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::{
+    Card, Grid, GridCols, GridItem, Text, ToElement, View,
+    stories::{self, Story},
+};
+use dioxus_blocks_macro::Route;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct StoriesView {}
+
+impl ToElement for StoriesView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl StoriesView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("组件用例"),
+            Text::p(
+                "集中展示各组件登记的命名用例。运行 dump_stories 示例可以把这些用例渲染为 HTML，用于视觉回归测试。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        let mut groups: Vec<(&'static str, Vec<Story>)> = Vec::new();
+        for story in stories::all() {
+            match groups.last_mut() {
+                Some((component, stories)) if *component == story.component() => {
+                    stories.push(story)
+                }
+                _ => groups.push((story.component(), vec![story])),
+            }
+        }
+
+        View::new().childrens(
+            groups
+                .into_iter()
+                .map(|(component, stories)| self.group(component, stories))
+                .collect(),
+        )
+    }
+
+    /// 单个组件的全部用例
+    fn group(&self, component: &'static str, stories: Vec<Story>) -> Card {
+        Card::new()
+            .header(
+                View::new()
+                    .children(Text::h3(component))
+                    .children(Text::p(format!("{} 个用例", stories.len()))),
+            )
+            .body(
+                Grid::new(
+                    stories
+                        .into_iter()
+                        .map(|story| {
+                            GridItem::new(
+                                View::new()
+                                    .children(Text::span(story.id()).style(|s| {
+                                        s.display("block")
+                                            .margin_bottom("12px")
+                                            .font_size("12px")
+                                            .color("#909399")
+                                            .custom("font-family: monospace")
+                                    }))
+                                    .children(story),
+                            )
+                            .style(|s| {
+                                s.padding("16px")
+                                    .border("1px dashed #dcdfe6")
+                                    .border_radius("8px")
+                            })
+                        })
+                        .collect(),
+                )
+                .cols(GridCols::Col3)
+                .gap(16),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+}
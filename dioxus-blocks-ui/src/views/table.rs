@@ -0,0 +1,427 @@
+//! Table 组件使用示例
+
+use std::rc::Rc;
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, PropsTable, Table, TableAlign, TableColumn, TableMenuItem, TableRow, TableSort,
+    Text, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct TableView {}
+
+impl ToElement for TableView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl TableView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Table 表格"),
+            Text::p("按列展示结构化数据，支持树形数据，适用于列表、分类和组织架构等页面。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.sort_and_render(),
+            self.empty_state(),
+            self.cell_tooltip(),
+            self.column_chooser(),
+            self.keyboard(),
+            self.tree_data(),
+            self.lazy_load(),
+            api_reference(vec![
+                PropsTable::of::<Table>().common(true),
+                PropsTable::of::<TableColumn>().title("TableColumn"),
+                PropsTable::of::<TableRow>().title("TableRow"),
+                PropsTable::of::<TableMenuItem>().title("TableMenuItem"),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "使用 TableColumn 定义列的字段名、表头、宽度和对齐方式，TableRow 按字段名填写单元格。",
+                ),
+            ]))
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "姓名").width("120px"),
+                        TableColumn::new("department", "部门"),
+                        TableColumn::new("email", "邮箱"),
+                        TableColumn::new("age", "年龄").width("80px").align(TableAlign::Right),
+                    ])
+                    .rows(users()),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 排序和自定义单元格
+    fn sort_and_render(&self) -> Card {
+        Card::new()
+            .anchor("排序和自定义单元格")
+            .header(View::new().childrens(vec![
+                Text::h2("排序和自定义单元格"),
+                Text::p(
+                    "sortable 的列点击表头依次切换为升序、降序和不排序，排序状态通过 onsort 通知；render 根据行返回单元格内容。striped 和 bordered 显示斑马纹和纵向边框。",
+                ),
+            ]))
+            .children(SortExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 空状态
+    fn empty_state(&self) -> Card {
+        Card::new()
+            .anchor("空状态")
+            .header(View::new().childrens(vec![
+                Text::h2("空状态"),
+                Text::p("没有数据时默认显示 empty_text 的文本，empty 可以替换为任意内容，如引导操作的按钮。"),
+            ]))
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "姓名"),
+                        TableColumn::new("email", "邮箱"),
+                    ])
+                    .empty(
+                        View::new()
+                            .children(Text::p("还没有成员").style(|s| s.margin("0 0 12px")))
+                            .children(Button::new().text("邀请成员").as_primary()),
+                    ),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 单元格提示和复制
+    fn cell_tooltip(&self) -> Card {
+        Card::new()
+            .anchor("单元格提示和复制")
+            .header(View::new().childrens(vec![
+                Text::h2("单元格提示和复制"),
+                Text::p(
+                    "show_overflow_tooltip 让过长的内容单行截断，只有确实被截断时鼠标移入才显示完整内容；copyable 在鼠标移入单元格时显示复制按钮。",
+                ),
+            ]))
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("id", "订单号").width("200px").copyable(true),
+                        TableColumn::new("address", "收货地址").show_overflow_tooltip(true),
+                        TableColumn::new("amount", "金额")
+                            .width("100px")
+                            .align(TableAlign::Right),
+                    ])
+                    .rows(vec![
+                        TableRow::new("1")
+                            .cell("id", "20240101000001")
+                            .cell(
+                                "address",
+                                "浙江省杭州市西湖区文三路 478 号华星时代广场 A 座 12 层 1203 室，工作日 9:00-18:00 收货",
+                            )
+                            .cell("amount", "¥1,280.00"),
+                        TableRow::new("2")
+                            .cell("id", "20240101000002")
+                            .cell("address", "上海市浦东新区世纪大道 100 号")
+                            .cell("amount", "¥356.50"),
+                    ]),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 列设置
+    fn column_chooser(&self) -> Card {
+        Card::new()
+            .anchor("列设置")
+            .header(View::new().childrens(vec![
+                Text::h2("列设置"),
+                Text::p(
+                    "开启 column_chooser 后可以在列设置中显示/隐藏列，拖动列名调整顺序。设置 layout_key 后列布局保存到全局配置，切换页面后保持不变。",
+                ),
+            ]))
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "姓名").width("120px"),
+                        TableColumn::new("department", "部门"),
+                        TableColumn::new("email", "邮箱"),
+                        TableColumn::new("age", "年龄")
+                            .width("80px")
+                            .align(TableAlign::Right),
+                    ])
+                    .rows(users())
+                    .column_chooser(true)
+                    .layout_key("table-view-users"),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 键盘导航和右键菜单
+    fn keyboard(&self) -> Card {
+        Card::new()
+            .anchor("键盘导航和右键菜单")
+            .header(View::new().childrens(vec![
+                Text::h2("键盘导航和右键菜单"),
+                Text::p(
+                    "点击表格后可以用上下方向键、Home 和 End 切换聚焦的行，按回车触发 onrow_activate。row_context_menu 根据行返回右键菜单的菜单项。",
+                ),
+            ]))
+            .children(KeyboardExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 树形数据
+    fn tree_data(&self) -> Card {
+        Card::new()
+            .anchor("树形数据")
+            .header(View::new().childrens(vec![
+                Text::h2("树形数据"),
+                Text::p(
+                    "行包含子行时第一列显示展开按钮，子行按层级缩进，缩进宽度通过 indent 设置。",
+                ),
+            ]))
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "分类"),
+                        TableColumn::new("count", "商品数")
+                            .width("120px")
+                            .align(TableAlign::Right),
+                    ])
+                    .rows(categories())
+                    .indent(24)
+                    .default_expanded(vec!["digital"]),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 懒加载子行
+    fn lazy_load(&self) -> Card {
+        Card::new()
+            .anchor("懒加载子行")
+            .header(View::new().childrens(vec![
+                Text::h2("懒加载子行"),
+                Text::p(
+                    "将行标记为 lazy，首次展开时调用 load_children 加载子行，加载期间按钮显示加载状态。",
+                ),
+            ]))
+            .children(LazyExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 用户列表
+fn users() -> Vec<TableRow> {
+    vec![
+        TableRow::new("1")
+            .cell("name", "张三")
+            .cell("department", "研发部")
+            .cell("email", "zhangsan@example.com")
+            .cell("age", "28"),
+        TableRow::new("2")
+            .cell("name", "李四")
+            .cell("department", "设计部")
+            .cell("email", "lisi@example.com")
+            .cell("age", "32"),
+        TableRow::new("3")
+            .cell("name", "王五")
+            .cell("department", "市场部")
+            .cell("email", "wangwu@example.com")
+            .cell("age", "25"),
+    ]
+}
+
+/// 商品分类
+fn categories() -> Vec<TableRow> {
+    vec![
+        TableRow::new("digital")
+            .cell("name", "数码")
+            .cell("count", "1280")
+            .children(vec![
+                TableRow::new("phone")
+                    .cell("name", "手机")
+                    .cell("count", "860")
+                    .children(vec![
+                        TableRow::new("android")
+                            .cell("name", "安卓手机")
+                            .cell("count", "520"),
+                        TableRow::new("ios")
+                            .cell("name", "苹果手机")
+                            .cell("count", "340"),
+                    ]),
+                TableRow::new("camera")
+                    .cell("name", "相机")
+                    .cell("count", "420"),
+            ]),
+        TableRow::new("books")
+            .cell("name", "图书")
+            .cell("count", "3600")
+            .children(vec![
+                TableRow::new("novel")
+                    .cell("name", "小说")
+                    .cell("count", "2100"),
+                TableRow::new("tech")
+                    .cell("name", "科技")
+                    .cell("count", "1500"),
+            ]),
+        TableRow::new("food")
+            .cell("name", "食品")
+            .cell("count", "940"),
+    ]
+}
+
+/// 排序和自定义单元格示例
+#[derive(Debug, Default, Clone)]
+struct SortExample {}
+
+impl ToElement for SortExample {
+    fn to_element(&self) -> Element {
+        let mut status = use_signal(|| "未排序".to_string());
+
+        View::new()
+            .children(
+                Table::new()
+                    .striped(true)
+                    .bordered(true)
+                    .columns(vec![
+                        TableColumn::new("name", "姓名")
+                            .width("120px")
+                            .sortable(true),
+                        TableColumn::new("department", "部门"),
+                        TableColumn::new("age", "年龄")
+                            .width("100px")
+                            .align(TableAlign::Right)
+                            .sortable(true),
+                        TableColumn::new("action", "操作")
+                            .width("120px")
+                            .align(TableAlign::Center)
+                            .render(move |row: TableRow| {
+                                let name = row.get("name").unwrap_or_default().to_string();
+                                Rc::new(
+                                    Button::new()
+                                        .text("编辑")
+                                        .as_link()
+                                        .onclick(move |_| status.set(format!("编辑 {name}"))),
+                                )
+                            }),
+                    ])
+                    .rows(users())
+                    .onsort(move |sort: Option<TableSort>| {
+                        status.set(match sort {
+                            Some(sort) => format!("{} {:?}", sort.prop(), sort.order()),
+                            None => "未排序".to_string(),
+                        })
+                    }),
+            )
+            .children(
+                Text::p(format!("最近操作：{}", status()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 键盘导航和右键菜单示例
+#[derive(Debug, Default, Clone)]
+struct KeyboardExample {}
+
+impl ToElement for KeyboardExample {
+    fn to_element(&self) -> Element {
+        let mut status = use_signal(|| "尚未操作".to_string());
+
+        View::new()
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "姓名").width("120px"),
+                        TableColumn::new("department", "部门"),
+                        TableColumn::new("email", "邮箱"),
+                    ])
+                    .rows(users())
+                    .onrow_activate(move |row: TableRow| {
+                        status.set(format!("打开 {}", row.get("name").unwrap_or_default()))
+                    })
+                    .row_context_menu(|row: TableRow| {
+                        vec![
+                            TableMenuItem::new("open", "打开"),
+                            TableMenuItem::new("copy", "复制邮箱"),
+                            TableMenuItem::new("delete", "删除")
+                                .danger(true)
+                                .disabled(row.key() == "1"),
+                        ]
+                    })
+                    .onrow_menu(move |(row, key): (TableRow, String)| {
+                        status.set(format!("{} {}", row.get("name").unwrap_or_default(), key))
+                    }),
+            )
+            .children(
+                Text::p(format!("最近操作：{}", status()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
+
+/// 懒加载示例
+#[derive(Debug, Default, Clone)]
+struct LazyExample {}
+
+impl ToElement for LazyExample {
+    fn to_element(&self) -> Element {
+        let mut loads = use_signal(|| 0);
+
+        View::new()
+            .children(
+                Table::new()
+                    .columns(vec![
+                        TableColumn::new("name", "部门"),
+                        TableColumn::new("leader", "负责人").width("160px"),
+                    ])
+                    .rows(vec![
+                        TableRow::new("rd")
+                            .cell("name", "研发中心")
+                            .cell("leader", "张三")
+                            .lazy(true),
+                        TableRow::new("sales")
+                            .cell("name", "销售中心")
+                            .cell("leader", "李四")
+                            .lazy(true),
+                    ])
+                    .load_children(move |row: TableRow| async move {
+                        loads += 1;
+                        let key = row.key().to_string();
+                        vec![
+                            TableRow::new(format!("{key}-1"))
+                                .cell("name", "一部")
+                                .cell("leader", "王五"),
+                            TableRow::new(format!("{key}-2"))
+                                .cell("name", "二部")
+                                .cell("leader", "赵六"),
+                        ]
+                    }),
+            )
+            .children(
+                Text::p(format!("已加载 {} 次", loads()))
+                    .style(|s| s.margin("16px 0 0").color("var(--t-text-color-secondary)")),
+            )
+            .into()
+    }
+}
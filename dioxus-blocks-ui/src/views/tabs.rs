@@ -0,0 +1,307 @@
+//! Tabs 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, ButtonType, Card, PropsTable, TabPane, Tabs, TabsPlacement, TabsType, Text, ToElement,
+    View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct TabsView {}
+
+impl ToElement for TabsView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl TabsView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Tabs 标签页"),
+            Text::p(
+                "把内容分为多个标签页，同一时间只显示一个。标签栏获得焦点后可以用方向键切换，Home、End 切换到第一个和最后一个标签。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic(),
+            self.placements(),
+            self.editable(),
+            self.lazy(),
+            api_reference(vec![
+                PropsTable::of::<Tabs>().common(true),
+                PropsTable::of::<TabPane>(),
+            ]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "active 传入 Signal<String> 控制当前标签，切换时写入新的 key，也可以只用 default_active 设置初始标签。禁用的标签不能切换。",
+                ),
+            ]))
+            .children(BasicExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 位置和样式
+    fn placements(&self) -> Card {
+        Card::new()
+            .anchor("位置和样式")
+            .header(View::new().childrens(vec![
+                Text::h2("位置和样式"),
+                Text::p(
+                    "placement 设置标签栏位于内容的哪一侧，左右两侧时标签纵向排列，方向键改为上下键。tab_type 设置线条或卡片样式。",
+                ),
+            ]))
+            .children(PlacementExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 可关闭的标签
+    fn editable(&self) -> Card {
+        Card::new()
+            .anchor("可关闭的标签")
+            .header(View::new().childrens(vec![
+                Text::h2("可关闭的标签"),
+                Text::p(
+                    "closable 在标签上显示关闭按钮，也可以通过 TabPane::closable 单独设置。关闭时调用 onclose，由调用方移除标签；关闭的是当前标签时先切换到相邻的标签。",
+                ),
+            ]))
+            .children(EditableExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 懒加载
+    fn lazy(&self) -> Card {
+        Card::new()
+            .anchor("懒加载")
+            .header(View::new().childrens(vec![
+                Text::h2("懒加载"),
+                Text::p(
+                    "默认开启 lazy，标签页的内容在首次切换到该标签时才创建，之后只是隐藏，点击计数等状态会保留。lazy(false) 时全部内容在首次渲染时创建。",
+                ),
+            ]))
+            .children(LazyExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 标签页中的说明文字
+fn pane_text(text: &str) -> Text {
+    Text::p(text).style(|s| s.margin("0").color("var(--t-text-color-regular)"))
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+struct BasicExample {}
+
+impl ToElement for BasicExample {
+    fn to_element(&self) -> Element {
+        let active = use_signal(|| "profile".to_string());
+
+        View::new()
+            .children(
+                Tabs::new()
+                    .active(active)
+                    .pane(
+                        TabPane::new("profile", "个人资料")
+                            .children(pane_text("修改头像、昵称和个人简介。")),
+                    )
+                    .pane(
+                        TabPane::new("security", "账号安全")
+                            .children(pane_text("修改密码，管理登录设备和两步验证。")),
+                    )
+                    .pane(
+                        TabPane::new("notification", "消息通知")
+                            .children(pane_text("选择接收哪些消息通知。")),
+                    )
+                    .pane(TabPane::new("billing", "账单").disabled(true)),
+            )
+            .children(Text::span(format!("当前标签：{active}")).style(|s| {
+                s.display("block")
+                    .margin_top("12px")
+                    .color("var(--t-text-color-secondary)")
+            }))
+            .into()
+    }
+}
+
+/// 位置和样式示例
+#[derive(Debug, Default, Clone)]
+struct PlacementExample {}
+
+impl ToElement for PlacementExample {
+    fn to_element(&self) -> Element {
+        let mut placement = use_signal(TabsPlacement::default);
+        let mut tab_type = use_signal(TabsType::default);
+
+        let placement_button = |value: TabsPlacement, label: &str| {
+            Button::new()
+                .text(label)
+                .btn_type(if placement() == value {
+                    ButtonType::Primary
+                } else {
+                    ButtonType::Default
+                })
+                .onclick(move |_| placement.set(value))
+        };
+        let type_button = |value: TabsType, label: &str| {
+            Button::new()
+                .text(label)
+                .btn_type(if tab_type() == value {
+                    ButtonType::Primary
+                } else {
+                    ButtonType::Default
+                })
+                .onclick(move |_| tab_type.set(value))
+        };
+
+        View::new()
+            .children(
+                View::new()
+                    .style(|s| {
+                        s.display("flex")
+                            .flex_wrap("wrap")
+                            .gap("8px")
+                            .margin_bottom("16px")
+                    })
+                    .childrens(vec![
+                        placement_button(TabsPlacement::Top, "top"),
+                        placement_button(TabsPlacement::Right, "right"),
+                        placement_button(TabsPlacement::Bottom, "bottom"),
+                        placement_button(TabsPlacement::Left, "left"),
+                        type_button(TabsType::Line, "线条").style(|s| s.margin_left("16px")),
+                        type_button(TabsType::Card, "卡片"),
+                    ]),
+            )
+            .children(
+                Tabs::new()
+                    .placement(placement())
+                    .tab_type(tab_type())
+                    .style(|s| s.min_height("160px"))
+                    .panes(
+                        ["一月", "二月", "三月"]
+                            .into_iter()
+                            .enumerate()
+                            .map(|(index, label)| {
+                                TabPane::new(format!("month-{index}"), label)
+                                    .children(pane_text(&format!("{label}的销售数据。")))
+                            })
+                            .collect(),
+                    ),
+            )
+            .into()
+    }
+}
+
+/// 可关闭的标签示例
+#[derive(Debug, Default, Clone)]
+struct EditableExample {}
+
+impl ToElement for EditableExample {
+    fn to_element(&self) -> Element {
+        let mut files = use_signal(|| vec![1, 2, 3]);
+        let mut next = use_signal(|| 4);
+        let mut active = use_signal(|| "file-1".to_string());
+
+        let panes = files
+            .read()
+            .iter()
+            .map(|id| {
+                let pane = TabPane::new(format!("file-{id}"), format!("文件 {id}"))
+                    .children(pane_text(&format!("文件 {id} 的内容。")));
+                // 第一个文件固定，不能关闭
+                if *id == 1 { pane.closable(false) } else { pane }
+            })
+            .collect();
+
+        View::new()
+            .children(
+                Tabs::new()
+                    .active(active)
+                    .tab_type(TabsType::Card)
+                    .closable(true)
+                    .panes(panes)
+                    .onclose(move |key: String| {
+                        files.write().retain(|id| format!("file-{id}") != key);
+                    })
+                    .children(Button::new().text("新建").onclick(move |_| {
+                        let id = next();
+                        next += 1;
+                        files.write().push(id);
+                        active.set(format!("file-{id}"));
+                    })),
+            )
+            .into()
+    }
+}
+
+/// 懒加载示例
+#[derive(Debug, Default, Clone)]
+struct LazyExample {}
+
+impl ToElement for LazyExample {
+    fn to_element(&self) -> Element {
+        let created = use_signal(Vec::<&'static str>::new);
+
+        let panes = ["订单", "退款", "评价"]
+            .into_iter()
+            .map(|label| TabPane::new(label, label).children(Counter { label, created }))
+            .collect();
+        let log = match created.read().as_slice() {
+            [] => "尚未创建标签页".to_string(),
+            labels => format!("已创建：{}", labels.join("、")),
+        };
+
+        View::new()
+            .children(Tabs::new().panes(panes))
+            .children(Text::span(log).style(|s| {
+                s.display("block")
+                    .margin_top("12px")
+                    .color("var(--t-text-color-secondary)")
+            }))
+            .into()
+    }
+}
+
+/// 带状态的标签页内容，创建时记录到列表中
+#[derive(Debug, Clone)]
+struct Counter {
+    label: &'static str,
+    created: Signal<Vec<&'static str>>,
+}
+
+impl ToElement for Counter {
+    fn to_element(&self) -> Element {
+        let mut count = use_signal(|| 0);
+        let label = self.label;
+        let mut created = self.created;
+        use_hook(move || created.write().push(label));
+
+        View::new()
+            .style(|s| s.display("flex").align_items("center").gap("12px"))
+            .children(pane_text(&format!("{label}列表")))
+            .children(
+                Button::new()
+                    .text(format!("点击了 {count} 次"))
+                    .onclick(move |_| count += 1),
+            )
+            .into()
+    }
+}
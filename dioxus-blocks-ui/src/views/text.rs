@@ -2,9 +2,11 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Text, View};
+use dioxus_blocks_components::{Card, PropsTable, Text, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 struct TextView {}
 
@@ -26,12 +28,18 @@ impl TextView {
     }
 
     fn content(&self) -> View {
-        View::new().childrens(vec![self.text_tag()])
+        View::new().childrens(vec![
+            self.text_tag(),
+            self.ellipsis(),
+            self.copyable(),
+            api_reference(vec![PropsTable::of::<Text>().common(true)]),
+        ])
     }
 
     /// 文本标签示例
     pub fn text_tag(&self) -> Card {
         Card::new()
+            .anchor("标签用法")
             .header(View::new().childrens(vec![
                 Text::h2("标签用法"),
                 Text::p("由tag属性来选择文本标签类型，支持H1-H6, P, Span等标签。"),
@@ -48,4 +56,50 @@ impl TextView {
             ])
             .style(|s| s.margin_top("32px"))
     }
+
+    /// 省略示例
+    pub fn ellipsis(&self) -> Card {
+        Card::new()
+            .anchor("省略")
+            .header(View::new().childrens(vec![
+                Text::h2("省略"),
+                Text::p(
+                    "ellipsis 设置超出后省略的行数，鼠标移入时只有内容确实被截断才会显示完整文本，短文本不会出现提示。",
+                ),
+            ]))
+            .childrens(vec![
+                Text::p("短文本不会显示提示").ellipsis(1),
+                Text::p(
+                    "这是一段很长的单行文本，宽度超出容器后会以省略号结尾，鼠标移入时在上方显示完整内容，方便在表格单元格等宽度受限的位置使用。",
+                )
+                .ellipsis(1),
+                Text::p(
+                    "这是一段最多显示两行的描述文本。多行省略适用于卡片摘要、列表描述等场景，超出两行的部分会被截断并显示省略号，鼠标移入时同样可以查看完整内容，而内容没有超出时不会出现多余的提示。",
+                )
+                .ellipsis(2),
+            ])
+            .style(|s| s.margin_top("32px").max_width("360px"))
+    }
+
+    /// 选择与复制示例
+    pub fn copyable(&self) -> Card {
+        Card::new()
+            .anchor("选择与复制")
+            .header(View::new().childrens(vec![
+                Text::h2("选择与复制"),
+                Text::p(
+                    "selectable 控制文本能否被选中，允许时点击即选中全部内容，便于右键复制；copyable 在鼠标移入时显示复制按钮，点击后写入剪贴板。适合在管理后台的表格中展示 ID、令牌等内容。",
+                ),
+            ]))
+            .childrens(vec![
+                Text::p("订单号：").children(Text::span("ORD-20240101-8F3A2C").selectable(true)),
+                Text::p("访问令牌：").children(
+                    Text::span("sk-2f9a7c41e0b84d6a")
+                        .selectable(true)
+                        .copyable(true),
+                ),
+                Text::p("这段文本不可选中").selectable(false),
+            ])
+            .style(|s| s.margin_top("32px"))
+    }
 }
@@ -2,9 +2,11 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Text, Textarea, ToElement, View};
+use dioxus_blocks_components::{Card, PropsTable, Text, Textarea, ToElement, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct TextareaView {}
 
@@ -34,12 +36,14 @@ impl TextareaView {
             self.size_control(),
             self.length_limit(),
             self.events_example(),
+            api_reference(vec![PropsTable::of::<Textarea>().common(true)]),
         ])
     }
 
     /// 基础用法
     fn basic_usage(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
                 Text::p("基本的多行文本输入框，可使用 v-model 双向绑定。"),
@@ -51,6 +55,7 @@ impl TextareaView {
     /// 禁用状态
     fn disabled_state(&self) -> Card {
         Card::new()
+            .anchor("禁用状态")
             .header(View::new().childrens(vec![
                 Text::h2("禁用状态"),
                 Text::p("禁用状态的文本域，不可编辑。"),
@@ -62,6 +67,7 @@ impl TextareaView {
     /// 行数控制
     fn rows_control(&self) -> Card {
         Card::new()
+            .anchor("行数控制")
             .header(View::new().childrens(vec![
                 Text::h2("行数控制"),
                 Text::p("通过 rows 属性设置文本域的行数。"),
@@ -73,6 +79,7 @@ impl TextareaView {
     /// 自适应高度
     fn autosize(&self) -> Card {
         Card::new()
+            .anchor("自适应高度")
             .header(View::new().childrens(vec![
                 Text::h2("自适应高度"),
                 Text::p(
@@ -86,6 +93,7 @@ impl TextareaView {
     /// 不同尺寸
     fn size_control(&self) -> Card {
         Card::new()
+            .anchor("不同尺寸")
             .header(View::new().childrens(vec![
                 Text::h2("不同尺寸"),
                 Text::p("提供小、中、大三种尺寸的文本域。"),
@@ -97,6 +105,7 @@ impl TextareaView {
     /// 输入长度限制
     fn length_limit(&self) -> Card {
         Card::new()
+            .anchor("输入长度限制")
             .header(View::new().childrens(vec![
                 Text::h2("输入长度限制"),
                 Text::p("通过 max_length 和 show_word_limit 设置输入长度限制和字数统计。"),
@@ -108,6 +117,7 @@ impl TextareaView {
     /// 事件示例
     fn events_example(&self) -> Card {
         Card::new()
+            .anchor("事件示例")
             .header(View::new().childrens(vec![
                 Text::h2("事件示例"),
                 Text::p("演示各种事件的触发时机。"),
@@ -0,0 +1,368 @@
+//! 主题编辑器
+//!
+//! 调整主色、圆角、密度和亮暗主题，右侧的组件通过嵌套的 ConfigProvider 实时预览，
+//! 并可以导出构建对应 [`Settings`] 的 Rust 代码。
+
+use dioxus::prelude::*;
+use dioxus_blocks_components::{
+    Alert, Button, Card, CardShadow, Checkbox, Color, ConfigProvider, Density, Input, Radio,
+    RadioGroup, RadioValue, Settings, Text, Theme, ToElement, View, copy_text, use_settings,
+};
+use dioxus_blocks_macro::Route;
+
+/// 默认主色
+const DEFAULT_PRIMARY: Color = Color::rgb(64, 158, 255);
+
+/// 默认圆角
+const DEFAULT_RADIUS: u32 = 4;
+
+/// 最大圆角
+const MAX_RADIUS: u32 = 20;
+
+/// 预设主色
+const PRESETS: [(&str, Color); 6] = [
+    ("拂晓蓝", Color::rgb(64, 158, 255)),
+    ("极客蓝", Color::rgb(47, 84, 235)),
+    ("酱紫", Color::rgb(114, 46, 209)),
+    ("明青", Color::rgb(19, 194, 194)),
+    ("极光绿", Color::rgb(82, 196, 26)),
+    ("火山", Color::rgb(250, 84, 28)),
+];
+
+/// 主题选项
+const THEMES: [(Theme, &str); 3] = [
+    (Theme::Light, "亮色"),
+    (Theme::Dark, "暗色"),
+    (Theme::HighContrast, "高对比度"),
+];
+
+/// 组件密度选项
+const DENSITIES: [(Density, &str); 3] = [
+    (Density::Compact, "紧凑"),
+    (Density::Default, "默认"),
+    (Density::Comfortable, "宽松"),
+];
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct ThemeEditorView {}
+
+impl ToElement for ThemeEditorView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl ThemeEditorView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("主题编辑器"),
+            Text::p(
+                "调整主色、圆角和组件密度，预览区域中的组件实时更新，确认后可以导出对应的 Rust 代码。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().children(
+            Card::new()
+                .anchor("编辑主题")
+                .header(View::new().childrens(vec![
+                    Text::h2("编辑主题"),
+                    Text::p(
+                        "预览区域使用独立的 ConfigProvider，修改不会影响站点的其它页面。主色的浅色变体根据亮暗主题自动计算。",
+                    ),
+                ]))
+                .children(ThemeEditor::default())
+                .style(|s| s.margin_top("32px")),
+        )
+    }
+}
+
+/// 生成构建配置的代码
+fn settings_code(settings: &Settings) -> String {
+    let mut code = String::from("Settings::default()");
+    if settings.theme != Theme::Light {
+        code.push_str(&format!("\n    .theme(Theme::{:?})", settings.theme));
+    }
+    if settings.density != Density::Default {
+        code.push_str(&format!("\n    .density(Density::{:?})", settings.density));
+    }
+    if let Some(Color { r, g, b }) = settings.primary_color {
+        code.push_str(&format!("\n    .primary_color(Color::rgb({r}, {g}, {b}))"));
+    }
+    if let Some(radius) = settings.radius {
+        code.push_str(&format!("\n    .radius({radius})"));
+    }
+    if settings.reduced_motion {
+        code.push_str("\n    .reduced_motion(true)");
+    }
+    code
+}
+
+/// 带标签的控件
+fn field(label: &str, control: impl ToElement + Clone + 'static) -> View {
+    View::new()
+        .style(|s| s.display("flex").flex_direction("column").gap("8px"))
+        .children(
+            Text::span(label).style(|s| s.font_size("13px").color("var(--t-text-color-secondary)")),
+        )
+        .children(control)
+}
+
+/// 主色选择：原生颜色选择器和预设色块
+#[derive(Debug, Clone)]
+struct PrimaryField {
+    value: Signal<Color>,
+}
+
+impl ToElement for PrimaryField {
+    fn to_element(&self) -> Element {
+        let mut value = self.value;
+        let current = value();
+        rsx! {
+            div { style: "display: flex; align-items: center; flex-wrap: wrap; gap: 8px;",
+                input {
+                    r#type: "color",
+                    aria_label: "主色",
+                    value: "{current}",
+                    style: "width: 40px; height: 32px; padding: 0; border: none; background: none; cursor: pointer;",
+                    oninput: move |event| {
+                        if let Some(color) = Color::parse(&event.value()) {
+                            value.set(color);
+                        }
+                    },
+                }
+                for (name , color) in PRESETS {
+                    button {
+                        key: "{name}",
+                        r#type: "button",
+                        title: "{name}",
+                        aria_label: "{name}",
+                        aria_pressed: color == current,
+                        style: format!(
+                            "width: 24px; height: 24px; border-radius: 50%; cursor: pointer; background: {color}; border: 2px solid {};",
+                            if color == current { "var(--t-text-color-primary)" } else { "transparent" },
+                        ),
+                        onclick: move |_| value.set(color),
+                    }
+                }
+                span { style: "font-family: monospace; font-size: 13px; color: var(--t-text-color-regular);",
+                    "{current}"
+                }
+            }
+        }
+    }
+}
+
+/// 圆角滑块
+#[derive(Debug, Clone)]
+struct RadiusField {
+    value: Signal<u32>,
+}
+
+impl ToElement for RadiusField {
+    fn to_element(&self) -> Element {
+        let mut value = self.value;
+        rsx! {
+            div { style: "display: flex; align-items: center; gap: 12px;",
+                input {
+                    r#type: "range",
+                    aria_label: "圆角",
+                    min: "0",
+                    max: "{MAX_RADIUS}",
+                    value: "{value}",
+                    style: "flex: 1; max-width: 240px;",
+                    oninput: move |event| {
+                        if let Ok(radius) = event.value().parse::<u32>() {
+                            value.set(radius.min(MAX_RADIUS));
+                        }
+                    },
+                }
+                span { style: "min-width: 40px; font-size: 13px; color: var(--t-text-color-regular);",
+                    "{value}px"
+                }
+            }
+        }
+    }
+}
+
+/// 预览区域中的组件
+fn preview() -> View {
+    let buttons = View::new()
+        .style(|s| s.display("flex").flex_wrap("wrap").gap("12px"))
+        .childrens(vec![
+            Button::new().text("主要按钮").as_primary(),
+            Button::new().text("朴素按钮").as_primary().as_plain(),
+            Button::new().text("默认按钮"),
+            Button::new().text("链接按钮").as_primary().as_link(),
+        ]);
+
+    let form = View::new()
+        .style(|s| {
+            s.display("flex")
+                .flex_direction("column")
+                .gap("12px")
+                .max_width("360px")
+        })
+        .children(Input::new().placeholder("请输入内容"))
+        .children(RadioGroup::new().radios(vec![
+            Radio::new().value(1).label("选项一"),
+            Radio::new().value(2).label("选项二"),
+        ]))
+        .children(Checkbox::new().value("agree").label("同意用户协议"));
+
+    View::new()
+        .style(|s| {
+            s.padding("20px")
+                .display("flex")
+                .flex_direction("column")
+                .gap("16px")
+        })
+        .children(buttons)
+        .children(form)
+        .children(
+            Alert::new("主题已更新")
+                .description("提示、按钮和表单控件都会使用新的主色和圆角。")
+                .as_info()
+                .show_icon(true),
+        )
+        .children(
+            Card::new()
+                .shadow(CardShadow::Always)
+                .header(Text::h3("卡片标题"))
+                .children(Text::p("卡片的圆角同样跟随主题变化。")),
+        )
+}
+
+/// 主题编辑器
+#[derive(Debug, Default, Clone)]
+struct ThemeEditor {}
+
+impl ToElement for ThemeEditor {
+    fn to_element(&self) -> Element {
+        let site = use_settings();
+        // 预览区域的独立配置，初始亮暗主题与站点一致
+        let mut settings = use_signal(|| Settings::default().theme(site.peek().theme));
+        let primary = use_signal(|| DEFAULT_PRIMARY);
+        let radius = use_signal(|| DEFAULT_RADIUS);
+        let mut theme = use_signal(|| {
+            RadioValue::from(
+                THEMES
+                    .iter()
+                    .position(|(t, _)| *t == site.peek().theme)
+                    .unwrap_or(0) as i64,
+            )
+        });
+        let mut density = use_signal(|| RadioValue::from(1));
+        let mut show_code = use_signal(|| false);
+
+        use_effect(move || {
+            let (primary, radius) = (primary(), radius());
+            let mut settings = settings.write();
+            settings.primary_color = (primary != DEFAULT_PRIMARY).then_some(primary);
+            settings.radius = (radius != DEFAULT_RADIUS).then_some(radius);
+        });
+
+        let theme_group = RadioGroup::new()
+            .value(theme)
+            .button(true)
+            .radios(
+                THEMES
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_, label))| Radio::new().value(i as i64).label(*label))
+                    .collect(),
+            )
+            .onchange(move |value: RadioValue| {
+                theme.set(value.clone());
+                if let Some((t, _)) = value.get_int().and_then(|i| THEMES.get(i as usize)) {
+                    settings.write().theme = *t;
+                }
+            });
+
+        let density_group = RadioGroup::new()
+            .value(density)
+            .button(true)
+            .radios(
+                DENSITIES
+                    .iter()
+                    .enumerate()
+                    .map(|(i, (_, label))| Radio::new().value(i as i64).label(*label))
+                    .collect(),
+            )
+            .onchange(move |value: RadioValue| {
+                density.set(value.clone());
+                if let Some((d, _)) = value.get_int().and_then(|i| DENSITIES.get(i as usize)) {
+                    settings.write().density = *d;
+                }
+            });
+
+        let code = settings_code(&settings.read());
+        let copy = code.clone();
+        let actions = View::new()
+            .style(|s| s.display("flex").gap("12px"))
+            .children(
+                Button::new()
+                    .text(if show_code() {
+                        "隐藏代码"
+                    } else {
+                        "导出代码"
+                    })
+                    .as_primary()
+                    .onclick(move |_| show_code.toggle()),
+            )
+            .children(Button::new().text("复制代码").onclick(move |_| {
+                let copy = copy.clone();
+                spawn(async move {
+                    copy_text(&copy).await;
+                });
+            }));
+
+        let controls = View::new()
+            .style(|s| {
+                s.padding("20px")
+                    .display("flex")
+                    .flex_direction("column")
+                    .gap("20px")
+            })
+            .children(field("主色", PrimaryField { value: primary }))
+            .children(field("圆角", RadiusField { value: radius }))
+            .children(field("组件密度", density_group))
+            .children(field("主题", theme_group))
+            .children(actions);
+
+        let mut content = View::new()
+            .style(|s| s.display("flex").flex_direction("column").gap("20px"))
+            .children(controls)
+            .children(
+                ConfigProvider::new()
+                    .settings(settings)
+                    .style(|s| {
+                        s.min_height("auto")
+                            .border("1px dashed var(--t-border-color)")
+                            .border_radius("8px")
+                    })
+                    .children(preview()),
+            );
+
+        if show_code() {
+            content = content.children(Text::p(code).style(|s| {
+                s.margin("0")
+                    .padding("16px")
+                    .font_family("monospace")
+                    .font_size("13px")
+                    .line_height("20px")
+                    .white_space("pre")
+                    .overflow("auto")
+                    .color("var(--t-text-color-primary)")
+                    .background_color("var(--t-fill-color-light)")
+                    .border_radius("8px")
+            }));
+        }
+
+        content.into()
+    }
+}
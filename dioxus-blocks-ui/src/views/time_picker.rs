@@ -0,0 +1,267 @@
+//! TimePicker 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, PropsTable, Text, TimeFormat, TimePicker, TimePickerSize, TimeValue, ToElement, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct TimePickerView {}
+
+impl ToElement for TimePickerView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl TimePickerView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("TimePicker 时间选择器"),
+            Text::p("用于选择或输入一天中的时间，在时、分、秒列中点击选择。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.step(),
+            self.hour_12(),
+            self.disabled_ranges(),
+            self.different_sizes(),
+            api_reference(vec![PropsTable::of::<TimePicker>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "当前值由 Signal<Option<TimeValue>> 控制。点击输入框打开面板选择，也可以直接输入 HH:MM:SS 后按 Enter。",
+                ),
+            ]))
+            .children(BasicUsage::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 步长
+    fn step(&self) -> Card {
+        Card::new()
+            .anchor("步长")
+            .header(View::new().childrens(vec![
+                Text::h2("步长"),
+                Text::p(
+                    "hour_step、minute_step、second_step 设置各列的步长，show_seconds(false) 隐藏秒。",
+                ),
+            ]))
+            .children(StepExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 12 小时制
+    fn hour_12(&self) -> Card {
+        Card::new()
+            .anchor("12 小时制")
+            .header(View::new().childrens(vec![
+                Text::h2("12 小时制"),
+                Text::p(
+                    "format(TimeFormat::Hour12) 按 12 小时制显示并增加上午、下午列，值始终按 24 小时制保存。",
+                ),
+            ]))
+            .children(Hour12Example::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 禁用时间段
+    fn disabled_ranges(&self) -> Card {
+        Card::new()
+            .anchor("禁用时间段")
+            .header(View::new().childrens(vec![
+                Text::h2("禁用时间段"),
+                Text::p(
+                    "disabled_range 添加不可选择的时间段，整段被禁用的小时和分钟不可点击，输入处于禁用时间段的时间会被忽略。",
+                ),
+            ]))
+            .children(DisabledExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 不同尺寸
+    fn different_sizes(&self) -> Card {
+        Card::new()
+            .anchor("不同尺寸")
+            .header(View::new().childrens(vec![
+                Text::h2("不同尺寸"),
+                Text::p("提供大、中、小三种尺寸。"),
+            ]))
+            .children(DifferentSizes::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例行：说明文字、时间选择器和当前值
+fn example_row(title: &str, picker: TimePicker, current: String) -> View {
+    View::new()
+        .style(|s| s.display("flex").align_items("center").gap("16px"))
+        .children(Text::new(title).style(|s| s.width("120px")))
+        .children(View::new().style(|s| s.width("220px")).children(picker))
+        .children(Text::new(current).style(|s| s.color("var(--t-text-color-secondary)")))
+}
+
+/// 示例列表的容器
+fn examples(rows: Vec<View>) -> View {
+    View::new()
+        .style(|s| {
+            s.padding("20px")
+                .display("flex")
+                .flex_direction("column")
+                .gap("24px")
+        })
+        .childrens(rows)
+}
+
+/// 当前值的文本
+fn describe(value: Option<TimeValue>) -> String {
+    value.map_or("未选择".to_string(), |time| time.to_string())
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+pub struct BasicUsage {}
+
+impl ToElement for BasicUsage {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| Some(TimeValue::new(9, 30, 0)));
+        let empty = use_signal(|| None::<TimeValue>);
+
+        examples(vec![
+            example_row(
+                "可清空",
+                TimePicker::new().value(value).clearable(true),
+                describe(value()),
+            ),
+            example_row(
+                "禁用",
+                TimePicker::new().value(empty).disabled(true),
+                describe(empty()),
+            ),
+        ])
+        .into()
+    }
+}
+
+/// 步长示例
+#[derive(Debug, Default, Clone)]
+pub struct StepExample {}
+
+impl ToElement for StepExample {
+    fn to_element(&self) -> Element {
+        let meeting = use_signal(|| Some(TimeValue::new(14, 15, 0)));
+        let shift = use_signal(|| None::<TimeValue>);
+
+        examples(vec![
+            example_row(
+                "每 15 分钟",
+                TimePicker::new()
+                    .value(meeting)
+                    .show_seconds(false)
+                    .minute_step(15),
+                describe(meeting()),
+            ),
+            example_row(
+                "每 2 小时整点",
+                TimePicker::new()
+                    .value(shift)
+                    .show_seconds(false)
+                    .hour_step(2)
+                    .minute_step(60)
+                    .placeholder("选择交班时间"),
+                describe(shift()),
+            ),
+        ])
+        .into()
+    }
+}
+
+/// 12 小时制示例
+#[derive(Debug, Default, Clone)]
+pub struct Hour12Example {}
+
+impl ToElement for Hour12Example {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| Some(TimeValue::new(19, 45, 0)));
+
+        examples(vec![example_row(
+            "12 小时制",
+            TimePicker::new()
+                .value(value)
+                .format(TimeFormat::Hour12)
+                .show_seconds(false)
+                .minute_step(5),
+            describe(value()),
+        )])
+        .into()
+    }
+}
+
+/// 禁用时间段示例
+#[derive(Debug, Default, Clone)]
+pub struct DisabledExample {}
+
+impl ToElement for DisabledExample {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| None::<TimeValue>);
+
+        examples(vec![example_row(
+            "营业时间",
+            TimePicker::new()
+                .value(value)
+                .show_seconds(false)
+                .minute_step(30)
+                .placeholder("选择预约时间")
+                .disabled_range(TimeValue::new(0, 0, 0), TimeValue::new(8, 59, 59))
+                .disabled_range(TimeValue::new(12, 0, 0), TimeValue::new(13, 29, 59))
+                .disabled_range(TimeValue::new(18, 0, 0), TimeValue::new(23, 59, 59)),
+            describe(value()),
+        )])
+        .into()
+    }
+}
+
+/// 不同尺寸示例
+#[derive(Debug, Default, Clone)]
+pub struct DifferentSizes {}
+
+impl ToElement for DifferentSizes {
+    fn to_element(&self) -> Element {
+        let value = use_signal(|| Some(TimeValue::new(8, 0, 0)));
+
+        examples(
+            [
+                ("大尺寸", TimePickerSize::Large),
+                ("默认尺寸", TimePickerSize::Medium),
+                ("小尺寸", TimePickerSize::Small),
+            ]
+            .into_iter()
+            .map(|(title, size)| {
+                example_row(
+                    title,
+                    TimePicker::new().value(value).size(size),
+                    String::new(),
+                )
+            })
+            .collect(),
+        )
+        .into()
+    }
+}
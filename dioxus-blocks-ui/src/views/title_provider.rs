@@ -0,0 +1,88 @@
+//! TitleProvider 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, Input, PropsTable, Text, TitleProvider, ToElement, View, use_document_title,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct TitleProviderView {}
+
+impl ToElement for TitleProviderView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl TitleProviderView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("TitleProvider 文档标题"),
+            Text::p(
+                "根据当前路由自动设置浏览器标签页标题，桌面端同时设置窗口标题，页面可以通过 use_document_title 覆盖。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.route_titles(),
+            self.page_title(),
+            api_reference(vec![PropsTable::of::<TitleProvider>()]),
+        ])
+    }
+
+    /// 路由标题
+    fn route_titles(&self) -> Card {
+        Card::new()
+            .anchor("路由标题")
+            .header(View::new().childrens(vec![
+                Text::h2("路由标题"),
+                Text::p(
+                    "本站在布局中使用 TitleProvider，为每个文档页面登记路由和标题，切换页面后标签页标题显示为“页面标题 – Dioxus Blocks”。",
+                ),
+            ]))
+            .children(
+                Text::p("路由模式中的 :name 匹配任意一段路径，如 /blog/:id；没有匹配的路由时只显示应用名称。")
+                    .style(|s| s.color("var(--t-text-color-secondary)")),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 页面标题
+    fn page_title(&self) -> Card {
+        Card::new()
+            .anchor("页面标题")
+            .header(View::new().childrens(vec![
+                Text::h2("页面标题"),
+                Text::p(
+                    "在页面中调用 use_document_title 覆盖路由登记的标题，适用于根据数据决定标题的详情页。修改下面的内容后观察标签页标题的变化，离开本页后恢复为路由标题。",
+                ),
+            ]))
+            .children(PageTitleExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 页面标题示例
+#[derive(Debug, Default, Clone)]
+struct PageTitleExample {}
+
+impl ToElement for PageTitleExample {
+    fn to_element(&self) -> Element {
+        let title = use_signal(|| "文档标题".to_string());
+        use_document_title(title());
+
+        View::new()
+            .style(|s| s.max_width("360px"))
+            .children(Input::new().value(title).placeholder("输入页面标题"))
+            .to_element()
+    }
+}
@@ -0,0 +1,76 @@
+//! Toolbar 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{Button, Card, Input, PropsTable, Text, ToElement, Toolbar, View};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct ToolbarView {}
+
+impl ToElement for ToolbarView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl ToolbarView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Toolbar 工具栏"),
+            Text::p("分为左、中、右三个区域的操作栏，适合放在表格、编辑器和页面头部。"),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.overflow_usage(),
+            api_reference(vec![PropsTable::of::<Toolbar>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p("通过 left、center 和 right 向对应区域添加项，区域内的项使用统一的间距。"),
+            ]))
+            .children(
+                Toolbar::new()
+                    .left(Text::h3("用户列表"))
+                    .center(Input::new().placeholder("搜索用户"))
+                    .right(Button::new().text("导出"))
+                    .right(Button::new().text("新建").as_primary()),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 溢出收起
+    fn overflow_usage(&self) -> Card {
+        Card::new()
+            .anchor("溢出收起")
+            .header(View::new().childrens(vec![
+                Text::h2("溢出收起"),
+                Text::p("设置 max_items 后，超出的项从右侧开始收起到更多菜单中。"),
+            ]))
+            .children(
+                Toolbar::new()
+                    .left(Button::new().text("加粗"))
+                    .left(Button::new().text("斜体"))
+                    .left(Button::new().text("下划线"))
+                    .left(Button::new().text("删除线"))
+                    .right(Button::new().text("插入链接"))
+                    .right(Button::new().text("插入图片"))
+                    .max_items(4),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+}
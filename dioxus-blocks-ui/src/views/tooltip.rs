@@ -0,0 +1,165 @@
+//! Tooltip 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Button, Card, Input, PropsTable, Text, ToElement, Tooltip, TooltipPlacement, TooltipTrigger,
+    View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct TooltipView {}
+
+impl ToElement for TooltipView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .to_element()
+    }
+}
+
+impl TooltipView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Tooltip 文字提示"),
+            Text::p(
+                "鼠标移入、点击或聚焦时显示简短的说明文字，空间不足时自动翻转到另一侧并保持在视口内。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.placements(),
+            self.triggers(),
+            self.delays(),
+            api_reference(vec![PropsTable::of::<Tooltip>().common(true)]),
+        ])
+    }
+
+    /// 位置
+    fn placements(&self) -> Card {
+        let row = |placements: [(TooltipPlacement, &str); 3]| {
+            View::new()
+                .style(|s| {
+                    s.display("flex")
+                        .justify_content("center")
+                        .gap("12px")
+                        .margin("12px 0")
+                })
+                .childrens(
+                    placements
+                        .into_iter()
+                        .map(|(placement, label)| {
+                            Tooltip::new(format!("提示位于 {label}"))
+                                .placement(placement)
+                                .children(Button::new().text(label).style(|s| s.width("120px")))
+                        })
+                        .collect(),
+                )
+        };
+
+        Card::new()
+            .anchor("位置")
+            .header(View::new().childrens(vec![
+                Text::h2("位置"),
+                Text::p(
+                    "placement 设置提示位于触发元素的哪一侧，start 和 end 表示与触发元素的起始或末尾边缘对齐。",
+                ),
+            ]))
+            .childrens(vec![
+                row([
+                    (TooltipPlacement::TopStart, "top-start"),
+                    (TooltipPlacement::Top, "top"),
+                    (TooltipPlacement::TopEnd, "top-end"),
+                ]),
+                row([
+                    (TooltipPlacement::LeftStart, "left-start"),
+                    (TooltipPlacement::Left, "left"),
+                    (TooltipPlacement::LeftEnd, "left-end"),
+                ]),
+                row([
+                    (TooltipPlacement::RightStart, "right-start"),
+                    (TooltipPlacement::Right, "right"),
+                    (TooltipPlacement::RightEnd, "right-end"),
+                ]),
+                row([
+                    (TooltipPlacement::BottomStart, "bottom-start"),
+                    (TooltipPlacement::Bottom, "bottom"),
+                    (TooltipPlacement::BottomEnd, "bottom-end"),
+                ]),
+            ])
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 触发方式
+    fn triggers(&self) -> Card {
+        Card::new()
+            .anchor("触发方式")
+            .header(View::new().childrens(vec![
+                Text::h2("触发方式"),
+                Text::p(
+                    "默认鼠标移入时显示；点击触发时再次点击、点击其他位置或按 Escape 关闭；聚焦触发适合为输入框提供填写说明。",
+                ),
+            ]))
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").align_items("center").gap("12px"))
+                    .children(Tooltip::new("鼠标移入时显示").children(Button::new().text("悬停")))
+                    .children(
+                        Tooltip::new("再次点击或点击其他位置关闭")
+                            .trigger(TooltipTrigger::Click)
+                            .children(Button::new().text("点击").as_primary()),
+                    )
+                    .children(
+                        Tooltip::new("用户名由 4 到 16 位字母或数字组成")
+                            .trigger(TooltipTrigger::Focus)
+                            .placement(TooltipPlacement::Right)
+                            .children(Input::new().placeholder("聚焦时显示说明")),
+                    ),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 延迟和箭头
+    fn delays(&self) -> Card {
+        Card::new()
+            .anchor("延迟和箭头")
+            .header(View::new().childrens(vec![
+                Text::h2("延迟和箭头"),
+                Text::p(
+                    "show_delay 和 hide_delay 设置显示和隐藏前等待的毫秒数，避免鼠标快速划过时频繁闪烁；show_arrow 关闭箭头。",
+                ),
+            ]))
+            .children(
+                View::new()
+                    .style(|s| s.display("flex").align_items("center").gap("12px"))
+                    .children(
+                        Tooltip::new("等待 500 毫秒后显示")
+                            .show_delay(500)
+                            .children(Button::new().text("延迟显示")),
+                    )
+                    .children(
+                        Tooltip::new("移出 1 秒后才隐藏")
+                            .hide_delay(1000)
+                            .children(Button::new().text("延迟隐藏")),
+                    )
+                    .children(
+                        Tooltip::new("没有箭头的提示")
+                            .show_arrow(false)
+                            .offset(4)
+                            .children(Button::new().text("无箭头")),
+                    )
+                    .children(
+                        Tooltip::new("不会显示")
+                            .disabled(true)
+                            .children(Button::new().text("已禁用")),
+                    ),
+            )
+            .style(|s| s.margin_top("32px"))
+    }
+}
@@ -0,0 +1,242 @@
+//! Upload 组件使用示例
+
+use dioxus::prelude::*;
+
+use dioxus_blocks_components::{
+    Card, PropsTable, Text, ToElement, Upload, UploadFile, UploadStatus, View,
+};
+use dioxus_blocks_macro::Route;
+
+use super::api::api_reference;
+
+#[derive(Debug, Default, Clone, Route)]
+pub struct UploadView {}
+
+impl ToElement for UploadView {
+    fn to_element(&self) -> Element {
+        View::new()
+            .children(self.title())
+            .children(self.content())
+            .into()
+    }
+}
+
+impl UploadView {
+    fn title(&self) -> View {
+        View::new().childrens(vec![
+            Text::h1("Upload 上传"),
+            Text::p(
+                "点击选择、拖放或粘贴文件到上传区域，校验后显示在文件列表中，由调用方完成上传。",
+            ),
+        ])
+    }
+
+    fn content(&self) -> View {
+        View::new().childrens(vec![
+            self.basic_usage(),
+            self.limits(),
+            self.progress(),
+            self.paste(),
+            api_reference(vec![PropsTable::of::<Upload>().common(true)]),
+        ])
+    }
+
+    /// 基础用法
+    fn basic_usage(&self) -> Card {
+        Card::new()
+            .anchor("基础用法")
+            .header(View::new().childrens(vec![
+                Text::h2("基础用法"),
+                Text::p(
+                    "点击上传区域打开文件选择框，也可以把文件拖放到区域中。单选时新选择的文件替换已有的文件，onchange 回调收到选中的 FileData。",
+                ),
+            ]))
+            .children(BasicUsage::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 类型、大小和数量限制
+    fn limits(&self) -> Card {
+        Card::new()
+            .anchor("类型、大小和数量限制")
+            .header(View::new().childrens(vec![
+                Text::h2("类型、大小和数量限制"),
+                Text::p(
+                    "accept 限制文件类型，max_size 限制单个文件的字节数，max_count 限制列表中的文件数。未通过校验的文件显示在区域下方，并通过 onreject 通知。",
+                ),
+            ]))
+            .children(LimitsExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 上传进度
+    fn progress(&self) -> Card {
+        Card::new()
+            .anchor("上传进度")
+            .header(View::new().childrens(vec![
+                Text::h2("上传进度"),
+                Text::p(
+                    "通过 file_list 绑定的 Signal 更新文件的进度和状态，上传中的文件显示进度条，render_progress 可以自定义进度的显示。本示例模拟上传过程，名称包含 error 的文件上传失败。",
+                ),
+            ]))
+            .children(ProgressExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+
+    /// 粘贴和图片预览
+    fn paste(&self) -> Card {
+        Card::new()
+            .anchor("粘贴和图片预览")
+            .header(View::new().childrens(vec![
+                Text::h2("粘贴和图片预览"),
+                Text::p(
+                    "按 Tab 键让上传区域获得焦点后粘贴截图或复制的文件，粘贴的文件与选择的文件一样经过校验。图片在列表中显示缩略图，已经上传过的图片可以通过 UploadFile::set_preview 设置预览地址。",
+                ),
+            ]))
+            .children(PasteExample::default())
+            .style(|s| s.margin_top("32px"))
+    }
+}
+
+/// 示例的容器
+fn example(upload: Upload, summary: String) -> View {
+    View::new()
+        .style(|s| {
+            s.padding("20px")
+                .display("flex")
+                .flex_direction("column")
+                .gap("12px")
+                .max_width("480px")
+        })
+        .children(upload)
+        .children(Text::span(summary).style(|s| s.color("var(--t-text-color-secondary)")))
+}
+
+/// 基础用法示例
+#[derive(Debug, Default, Clone)]
+pub struct BasicUsage {}
+
+impl ToElement for BasicUsage {
+    fn to_element(&self) -> Element {
+        let mut selected = use_signal(|| "尚未选择文件".to_string());
+
+        example(
+            Upload::new().onchange(move |files| {
+                let names = files
+                    .iter()
+                    .map(|file| file.name())
+                    .collect::<Vec<_>>()
+                    .join("、");
+                selected.set(format!("已选择：{names}"));
+            }),
+            selected(),
+        )
+        .into()
+    }
+}
+
+/// 限制示例
+#[derive(Debug, Default, Clone)]
+pub struct LimitsExample {}
+
+impl ToElement for LimitsExample {
+    fn to_element(&self) -> Element {
+        let files = use_signal(|| vec![UploadFile::new("合同扫描件.pdf", 356 * 1024)]);
+        let mut rejected = use_signal(|| 0);
+
+        example(
+            Upload::new()
+                .file_list(files)
+                .accept("image/*,.pdf")
+                .multiple(true)
+                .max_size(1024 * 1024)
+                .max_count(3)
+                .tip("支持图片和 PDF，单个文件不超过 1 MB，最多 3 个")
+                .onreject(move |rejections| rejected += rejections.len()),
+            format!(
+                "已添加 {} 个文件，累计拒绝 {} 个",
+                files.read().len(),
+                rejected()
+            ),
+        )
+        .into()
+    }
+}
+
+/// 上传进度示例
+#[derive(Debug, Default, Clone)]
+pub struct ProgressExample {}
+
+impl ToElement for ProgressExample {
+    fn to_element(&self) -> Element {
+        let mut files = use_signal(Vec::<UploadFile>::new);
+        let mut running = use_signal(|| false);
+
+        // 模拟上传：每隔一段时间推进所有待上传文件的进度，同一时间只运行一个任务
+        let mut simulate = move || {
+            if running() {
+                return;
+            }
+            running.set(true);
+            spawn(async move {
+                loop {
+                    let pending = files.read().iter().any(|file| {
+                        matches!(file.status(), UploadStatus::Ready | UploadStatus::Uploading)
+                    });
+                    if !pending || !dioxus_blocks_components::browser::sleep(300).await {
+                        break;
+                    }
+                    for file in files.write().iter_mut() {
+                        if !matches!(file.status(), UploadStatus::Ready | UploadStatus::Uploading) {
+                            continue;
+                        }
+                        let progress = file.progress().saturating_add(20);
+                        if file.name().contains("error") && progress >= 60 {
+                            file.set_status(UploadStatus::Error("上传失败，请重试".to_string()));
+                        } else if progress >= 100 {
+                            file.set_status(UploadStatus::Success);
+                        } else {
+                            file.set_progress(progress);
+                        }
+                    }
+                }
+                running.set(false);
+            });
+        };
+
+        let done = files
+            .read()
+            .iter()
+            .filter(|file| *file.status() == UploadStatus::Success)
+            .count();
+        example(
+            Upload::new()
+                .file_list(files)
+                .multiple(true)
+                .onchange(move |_| simulate()),
+            format!("已完成 {done} / {} 个文件", files.read().len()),
+        )
+        .into()
+    }
+}
+
+/// 粘贴和图片预览示例
+#[derive(Debug, Default, Clone)]
+pub struct PasteExample {}
+
+impl ToElement for PasteExample {
+    fn to_element(&self) -> Element {
+        let files = use_signal(Vec::<UploadFile>::new);
+
+        example(
+            Upload::new()
+                .file_list(files)
+                .accept("image/*")
+                .multiple(true)
+                .tip("支持粘贴截图，只接受图片")
+                .children(Text::span("将图片拖到此处、点击选择或粘贴截图")),
+            format!("已添加 {} 张图片", files.read().len()),
+        )
+        .into()
+    }
+}
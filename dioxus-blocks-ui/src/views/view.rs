@@ -2,9 +2,11 @@
 
 use dioxus::prelude::*;
 
-use dioxus_blocks_components::{Card, Text, View};
+use dioxus_blocks_components::{Card, PropsTable, Text, View};
 use dioxus_blocks_macro::Route;
 
+use super::api::api_reference;
+
 #[derive(Debug, Default, Clone, Route)]
 pub struct ViewExample {}
 
@@ -30,12 +32,14 @@ impl ViewExample {
             self.basic_example(),
             self.style_example(),
             self.layout_example(),
+            api_reference(vec![PropsTable::of::<View>().common(true)]),
         ])
     }
 
     /// 基础容器示例
     fn basic_example(&self) -> Card {
         Card::new()
+            .anchor("基础用法")
             .header(View::new().childrens(vec![
                 Text::h2("基础用法"),
                 Text::p("View 组件的基本使用方式。"),
@@ -54,6 +58,7 @@ impl ViewExample {
     /// 样式示例
     fn style_example(&self) -> Card {
         Card::new()
+            .anchor("样式配置")
             .header(View::new().childrens(vec![
                 Text::h2("样式配置"),
                 Text::p("通过链式调用配置各种样式属性。"),
@@ -85,6 +90,7 @@ impl ViewExample {
     /// 布局示例
     fn layout_example(&self) -> Card {
         Card::new()
+            .anchor("布局功能")
             .header(View::new().childrens(vec![
                 Text::h2("布局功能"),
                 Text::p("使用 Flexbox 布局实现元素排列。"),
@@ -0,0 +1,101 @@
+//! 演示页面快照测试
+//!
+//! 在服务端渲染每个静态路由对应的演示页面，并与 `tests/golden` 目录下提交的 HTML 快照比较，
+//! 用于发现组件标记的意外变化。
+//!
+//! 修改组件后如果渲染结果的变化符合预期，设置 `BLESS=1` 重新运行测试即可更新快照：
+//!
+//! ```sh
+//! BLESS=1 cargo test -p dioxus-blocks-ui --test golden
+//! ```
+
+use std::path::{Path, PathBuf};
+use std::rc::Rc;
+
+use dioxus::history::{History, MemoryHistory};
+use dioxus::prelude::*;
+use dioxus::router::components::HistoryProvider;
+
+use dioxus_blocks_ui::Route;
+
+/// 快照文件目录
+fn golden_dir() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests")
+        .join("golden")
+}
+
+/// 由路由路径得到快照文件名，如 `/input-number` 对应 `input-number.html`
+fn golden_name(path: &str) -> String {
+    let name = path.trim_matches('/').replace('/', "_");
+    if name.is_empty() {
+        "home.html".to_string()
+    } else {
+        format!("{name}.html")
+    }
+}
+
+#[derive(Props, Clone, PartialEq)]
+struct AppProps {
+    path: String,
+}
+
+#[allow(non_snake_case)]
+fn App(props: AppProps) -> Element {
+    let path = props.path.clone();
+    rsx! {
+        HistoryProvider {
+            history: move |_| Rc::new(MemoryHistory::with_initial_path(path.clone())) as Rc<dyn History>,
+            Router::<Route> {}
+        }
+    }
+}
+
+/// 服务端渲染指定路径的页面
+fn render(path: &str) -> String {
+    let mut dom = VirtualDom::new_with_props(
+        App,
+        AppProps {
+            path: path.to_string(),
+        },
+    );
+    dom.rebuild_in_place();
+    // 非打包环境下资源路径为本机绝对路径，替换为固定前缀以保证快照与机器无关
+    let mut html = dioxus_ssr::render(&dom).replace(env!("CARGO_MANIFEST_DIR"), "$CRATE");
+    html.push('\n');
+    html
+}
+
+#[test]
+fn test_views_match_golden_html() {
+    let bless = std::env::var_os("BLESS").is_some();
+    let dir = golden_dir();
+    let mut failures = Vec::new();
+
+    let routes = Route::static_routes();
+    assert!(!routes.is_empty(), "no static routes found");
+
+    for route in routes {
+        let path = route.to_string();
+        let file = dir.join(golden_name(&path));
+        let actual = render(&path);
+
+        if bless {
+            std::fs::create_dir_all(&dir).unwrap();
+            std::fs::write(&file, &actual).unwrap();
+            continue;
+        }
+
+        match std::fs::read_to_string(&file) {
+            Ok(expected) if expected == actual => {}
+            Ok(_) => failures.push(format!("{path}: 渲染结果与 {} 不一致", file.display())),
+            Err(_) => failures.push(format!("{path}: 缺少快照文件 {}", file.display())),
+        }
+    }
+
+    assert!(
+        failures.is_empty(),
+        "快照测试失败，确认变化符合预期后使用 `BLESS=1` 重新运行以更新快照：\n{}",
+        failures.join("\n")
+    );
+}